@@ -1,19 +1,148 @@
 use crate::EnglishCore;
 use crate::grammar::*;
+#[cfg(feature = "alloc")]
+use crate::utils::collect;
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
 
 impl EnglishCore {
+    #[cfg(feature = "alloc")]
     pub fn adjective(word: &str, degree: &Degree) -> String {
-        match degree {
-            Degree::Positive => word.to_string(),
-            Degree::Comparative => Self::comparative(word),
-            Degree::Superlative => Self::superlative(word),
+        collect(|out| EnglishCore::write_adjective(out, word, degree))
+    }
+
+    /// Compares an adjective like [`EnglishCore::adjective`], borrowing
+    /// irregular forms (`"better"`) instead of copying them.
+    #[cfg(feature = "alloc")]
+    pub fn adjective_cow(word: &str, degree: &Degree) -> Cow<'static, str> {
+        match (degree, EnglishCore::irregular_comparison(word)) {
+            (Degree::Comparative, Some((comp, _))) => Cow::Borrowed(comp),
+            (Degree::Superlative, Some((_, sup))) => Cow::Borrowed(sup),
+            _ => Cow::Owned(EnglishCore::adjective(word, degree)),
+        }
+    }
+
+    /// Writes the compared adjective to `out` without building an
+    /// intermediate `String`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{Degree, EnglishCore};
+    ///
+    /// let mut out = String::from("the ");
+    /// EnglishCore::write_adjective(&mut out, "big", &Degree::Superlative).unwrap();
+    /// assert_eq!(out, "the biggest");
+    /// ```
+    pub fn write_adjective<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        degree: &Degree,
+    ) -> fmt::Result {
+        let (ending, periphrasis, irregular) = match degree {
+            Degree::Positive => return out.write_str(word),
+            Degree::Comparative => (
+                "er",
+                "more",
+                EnglishCore::irregular_comparison(word).map(|(comp, _)| comp),
+            ),
+            Degree::Superlative => (
+                "est",
+                "most",
+                EnglishCore::irregular_comparison(word).map(|(_, sup)| sup),
+            ),
+        };
+        if let Some(irregular) = irregular {
+            return out.write_str(irregular);
+        }
+        match EnglishCore::comparison_stem(word) {
+            Some((stem, extra)) => write!(out, "{stem}{extra}{ending}"),
+            None => write!(out, "{periphrasis} {word}"),
         }
     }
+
+    #[cfg(feature = "alloc")]
     pub fn superlative(word: &str) -> String {
-        format!("most {}", word)
+        EnglishCore::adjective(word, &Degree::Superlative)
     }
+    #[cfg(feature = "alloc")]
     pub fn comparative(word: &str) -> String {
-        format!("more {}", word)
+        EnglishCore::adjective(word, &Degree::Comparative)
+    }
+
+    fn irregular_comparison(word: &str) -> Option<(&'static str, &'static str)> {
+        IRREGULAR_COMPARISON
+            .iter()
+            .find(|(positive, _, _)| *positive == word)
+            .map(|(_, comp, sup)| (*comp, *sup))
+    }
+
+    /// Returns the stem that takes `-er`/`-est`, as a prefix of `word` and
+    /// the letter appended to it, or `None` if the adjective compares
+    /// periphrastically with "more"/"most".
+    ///
+    /// One-syllable adjectives and two-syllable ones ending in `-y`, `-le`,
+    /// `-ow` or `-er` are synthetic; everything else uses "more". The stem
+    /// drops a silent `e` ("large" -> "larg"), turns a consonant + `y` into
+    /// `i` ("happy" -> "happi") and doubles a final CVC consonant
+    /// ("big" -> "bigg").
+    fn comparison_stem(word: &str) -> Option<(&str, &str)> {
+        if PERIPHRASTIC_ONLY.contains(&word) || word.contains(' ') || word.contains('-') {
+            return None;
+        }
+        let syllables = EnglishCore::syllable_count(word);
+        let synthetic = match syllables {
+            1 => !(word.ends_with("ed") && word.len() > 3),
+            2 => ["y", "le", "ow", "er"]
+                .iter()
+                .any(|end| word.ends_with(end)),
+            _ => false,
+        };
+        if !synthetic {
+            return None;
+        }
+
+        if let Some(stem) = word.strip_suffix('e') {
+            return Some((stem, ""));
+        }
+        if let Some(stem) = word.strip_suffix('y')
+            && stem
+                .chars()
+                .last()
+                .is_some_and(|c| !EnglishCore::is_vowel(c))
+        {
+            return Some((stem, "i"));
+        }
+        if syllables == 1 && EnglishCore::ends_with_cvc(word) {
+            let last = word.chars().last()?;
+            return Some((word, &word[word.len() - last.len_utf8()..]));
+        }
+        Some((word, ""))
+    }
+
+    /// Undoes the regular comparison rules, returning every positive form
+    /// that [`EnglishCore::comparative`] or [`EnglishCore::superlative`]
+    /// might have turned into `word`.
+    #[cfg(feature = "alloc")]
+    pub fn adjective_lemma_candidates(word: &str) -> Vec<String> {
+        let mut candidates: Vec<String> = IRREGULAR_COMPARISON
+            .iter()
+            .filter(|(_, comp, sup)| *comp == word || *sup == word)
+            .map(|(positive, _, _)| positive.to_string())
+            .collect();
+        for prefix in ["more ", "most "] {
+            if let Some(stem) = word.strip_prefix(prefix) {
+                candidates.push(stem.to_string());
+            }
+        }
+        for suffix in ["er", "est"] {
+            candidates.extend(EnglishCore::suffix_stems(word, suffix));
+        }
+        candidates
     }
     pub fn pronoun(person: &Person, number: &Number, gender: &Gender, case: &Case) -> &'static str {
         match number {
@@ -23,14 +152,14 @@ impl EnglishCore {
                     Case::Accusative => "me",
                     Case::Reflexive => "myself",
                     Case::Possessive => "mine",
-                    Case::PersonalPossesive => "my",
+                    Case::PersonalPossessive => "my",
                 },
                 Person::Second => match case {
                     Case::Nominative => "you",
                     Case::Accusative => "you",
                     Case::Reflexive => "yourself",
                     Case::Possessive => "yours",
-                    Case::PersonalPossesive => "your",
+                    Case::PersonalPossessive => "your",
                 },
                 Person::Third => match gender {
                     Gender::Masculine => match case {
@@ -38,22 +167,24 @@ impl EnglishCore {
                         Case::Accusative => "him",
                         Case::Reflexive => "himself",
                         Case::Possessive => "his",
-                        Case::PersonalPossesive => "his",
+                        Case::PersonalPossessive => "his",
                     },
                     Gender::Feminine => match case {
                         Case::Nominative => "she",
                         Case::Accusative => "her",
                         Case::Reflexive => "herself",
                         Case::Possessive => "hers",
-                        Case::PersonalPossesive => "her",
+                        Case::PersonalPossessive => "her",
                     },
                     Gender::Neuter => match case {
                         Case::Nominative => "it",
                         Case::Accusative => "it",
                         Case::Reflexive => "itself",
                         Case::Possessive => "its",
-                        Case::PersonalPossesive => "its",
+                        Case::PersonalPossessive => "its",
                     },
+                    Gender::Custom(_) => EnglishCore::pronoun_form(&Pronoun::SingularThey, case)
+                        .expect("singular they has every case"),
                 },
             },
             Number::Plural => match person {
@@ -62,25 +193,52 @@ impl EnglishCore {
                     Case::Accusative => "us",
                     Case::Reflexive => "ourselves",
                     Case::Possessive => "ours",
-                    Case::PersonalPossesive => "our",
+                    Case::PersonalPossessive => "our",
                 },
                 Person::Second => match case {
                     Case::Nominative => "you",
                     Case::Accusative => "you",
                     Case::Reflexive => "yourselves",
                     Case::Possessive => "yours",
-                    Case::PersonalPossesive => "your",
+                    Case::PersonalPossessive => "your",
                 },
                 Person::Third => match case {
                     Case::Nominative => "they",
                     Case::Accusative => "them",
                     Case::Reflexive => "themselves",
                     Case::Possessive => "theirs",
-                    Case::PersonalPossesive => "their",
+                    Case::PersonalPossessive => "their",
                 },
             },
         }
     }
-    //dog's -> dogs', child's -> children's, Mary's -> Marys'
-    //  pub fn genitive_adjective(word: &str, number: &Number) -> String {}
+    /// Inflects a noun for number and makes it possessive:
+    /// dog's -> dogs', mouse's -> mice's.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Number};
+    ///
+    /// assert_eq!(EnglishCore::genitive_adjective("dog", &Number::Plural), "dogs'");
+    /// assert_eq!(EnglishCore::genitive_adjective("mouse", &Number::Plural), "mice's");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn genitive_adjective(word: &str, number: &Number) -> String {
+        let inflected = EnglishCore::noun(word, number);
+        EnglishCore::genitive(&inflected, number, &PossessiveStyle::Chicago)
+    }
 }
+
+/// (positive, comparative, superlative)
+const IRREGULAR_COMPARISON: &[(&str, &str, &str)] = &[
+    ("good", "better", "best"),
+    ("well", "better", "best"),
+    ("bad", "worse", "worst"),
+    ("ill", "worse", "worst"),
+    ("far", "farther", "farthest"),
+    ("much", "more", "most"),
+    ("many", "more", "most"),
+];
+
+//One-syllable adjectives that still prefer "more"/"most"
+const PERIPHRASTIC_ONLY: &[&str] = &["fun", "real", "right", "wrong", "just", "like", "worth"];
@@ -0,0 +1,110 @@
+use crate::EnglishCore;
+
+impl EnglishCore {
+    /// Picks `"a"` or `"an"` for the word that follows the article.
+    ///
+    /// Only the first word of `word` is considered, and hyphenated compounds
+    /// are judged by their first element (`"one-off"`). Digits are read as
+    /// spoken numbers (`"an 8"`, `"an 11"`, `"a 100"`), all-caps words as
+    /// initialisms spelled out letter by letter (`"an MRI"`, `"a UFO"`), and
+    /// everything else by its spelling, corrected for the common silent-h and
+    /// /j/- or /w/-initial prefixes (`"an hour"`, `"a university"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::EnglishCore;
+    ///
+    /// assert_eq!(EnglishCore::indefinite_article("apple"), "an");
+    /// assert_eq!(EnglishCore::indefinite_article("hour"), "an");
+    /// assert_eq!(EnglishCore::indefinite_article("university"), "a");
+    /// assert_eq!(EnglishCore::indefinite_article("MRI"), "an");
+    /// assert_eq!(EnglishCore::indefinite_article("one-off"), "a");
+    /// assert_eq!(EnglishCore::indefinite_article("8"), "an");
+    /// ```
+    pub fn indefinite_article(word: &str) -> &'static str {
+        let first = EnglishCore::article_head(word);
+        let Some(initial) = first.chars().next() else {
+            return "a";
+        };
+        if initial.is_ascii_digit() {
+            return EnglishCore::numeral_article(first);
+        }
+        let is_initialism =
+            first.chars().count() > 1 && first.chars().all(|c| c.is_ascii_uppercase());
+        if is_initialism || first.chars().count() == 1 {
+            return EnglishCore::letter_article(initial);
+        }
+
+        let starts_with = |prefix: &&str| {
+            first
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        };
+        if SILENT_H_PREFIXES.iter().any(starts_with) {
+            return "an";
+        }
+        let consonant_sound = ["one", "once", "oneself"]
+            .iter()
+            .any(|word| first.eq_ignore_ascii_case(word))
+            || (CONSONANT_SOUND_PREFIXES.iter().any(starts_with)
+                && !VOWEL_SOUND_PREFIXES.iter().any(starts_with));
+        if consonant_sound {
+            return "a";
+        }
+        if EnglishCore::is_vowel(initial.to_ascii_lowercase()) {
+            "an"
+        } else {
+            "a"
+        }
+    }
+
+    /// The part of `word` that decides the article: its first word, or the
+    /// first element of a hyphenated compound.
+    pub fn article_head(word: &str) -> &str {
+        let word = word.trim_start();
+        word.split([' ', '-']).next().unwrap_or(word)
+    }
+
+    /// Article for a numeral written in digits, read as it would be spoken.
+    fn numeral_article(numeral: &str) -> &'static str {
+        let digits = || {
+            numeral
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || *c == ',')
+                .filter(char::is_ascii_digit)
+        };
+        let mut leading = digits();
+        let (first, second) = (leading.next(), leading.next());
+        // "eight", "eighty", "eight hundred"; "eleven" and "eighteen" only
+        // when they head a group of three ("eleven thousand", not "a 110").
+        let eleven_or_eighteen =
+            first == Some('1') && matches!(second, Some('1' | '8')) && digits().count() % 3 == 2;
+        if first == Some('8') || eleven_or_eighteen {
+            "an"
+        } else {
+            "a"
+        }
+    }
+
+    /// Article for a letter read by its name (`"an F"`, `"a U"`).
+    fn letter_article(letter: char) -> &'static str {
+        if "aefhilmnorsx".contains(letter.to_ascii_lowercase()) {
+            "an"
+        } else {
+            "a"
+        }
+    }
+}
+
+/// Consonant-letter starts that are pronounced with a vowel.
+const SILENT_H_PREFIXES: &[&str] = &["hour", "honest", "honor", "honour", "heir"];
+
+/// Vowel-letter starts that are pronounced with /j/ or /w/.
+const CONSONANT_SOUND_PREFIXES: &[&str] = &[
+    "uni", "use", "usu", "usa", "uti", "ute", "uto", "ure", "uri", "eu", "ewe", "ubiq", "ufo",
+    "uku",
+];
+
+/// Negative `un-` compounds caught by [`CONSONANT_SOUND_PREFIXES`] that keep
+/// the vowel (`"an unidentified"`).
+const VOWEL_SOUND_PREFIXES: &[&str] = &["unim", "unin", "unid", "unir"];
@@ -0,0 +1,44 @@
+use crate::EnglishCore;
+
+impl EnglishCore {
+    /// Returns the `n't` contraction of an auxiliary (`"is" -> "isn't"`,
+    /// `"will" -> "won't"`), or `None` if it has no standard one (`"am"`).
+    pub fn negative_contraction(aux: &str) -> Option<&'static str> {
+        match aux {
+            "is" => Some("isn't"),
+            "are" => Some("aren't"),
+            "was" => Some("wasn't"),
+            "were" => Some("weren't"),
+            "has" => Some("hasn't"),
+            "have" => Some("haven't"),
+            "had" => Some("hadn't"),
+            "do" => Some("don't"),
+            "does" => Some("doesn't"),
+            "did" => Some("didn't"),
+            "can" => Some("can't"),
+            "could" => Some("couldn't"),
+            "will" => Some("won't"),
+            "would" => Some("wouldn't"),
+            "shall" => Some("shan't"),
+            "should" => Some("shouldn't"),
+            "must" => Some("mustn't"),
+            "might" => Some("mightn't"),
+            "need" => Some("needn't"),
+            _ => None,
+        }
+    }
+
+    /// Returns the clitic an auxiliary reduces to after a subject pronoun
+    /// (`"am" -> "'m"`, `"would" -> "'d"`), or `None` if it does not reduce.
+    pub fn auxiliary_clitic(aux: &str) -> Option<&'static str> {
+        match aux {
+            "am" => Some("'m"),
+            "is" | "has" => Some("'s"),
+            "are" => Some("'re"),
+            "have" => Some("'ve"),
+            "had" | "would" => Some("'d"),
+            "will" => Some("'ll"),
+            _ => None,
+        }
+    }
+}
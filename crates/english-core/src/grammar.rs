@@ -8,14 +8,22 @@ pub enum Case {
     Nominative,
     Accusative,
     Reflexive,
+    /// Independent possessive: "mine", "theirs".
     Possessive,
-    PersonalPossesive,
+    /// Possessive determiner: "my", "their".
+    PersonalPossessive,
+}
+
+impl Case {
+    #[deprecated(note = "renamed to `Case::PersonalPossessive`")]
+    #[allow(non_upper_case_globals)]
+    pub const PersonalPossesive: Case = Case::PersonalPossessive;
 }
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Tense {
     Present,
     Past,
-    // Future could be added too
+    Future,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -32,11 +40,19 @@ pub enum Person {
     Second,
     Third,
 }
+/// Grammatical gender of a pronoun's referent.
+///
+/// Non-exhaustive, since pronoun sets beyond [`Gender::Custom`] may be added
+/// without a breaking release; match with a wildcard arm.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
 pub enum Gender {
     Masculine,
     Feminine,
     Neuter,
+    /// A pronoun set registered at runtime by the `english` crate. Without
+    /// the registry it is rendered like singular "they".
+    Custom(u16),
 }
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Degree {
@@ -51,23 +67,160 @@ pub enum Animacy {
     Inanimate,
 }
 
-/*#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Mood {
     Indicative,
     Subjunctive,
     Imperative,
     // Conditional, Interrogative, etc.
-} */
-/*#[derive(Debug, PartialEq, Clone)]
+}
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Det {
     Definite,
     Indefinite,
 }
- */
-/*#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Aspect {
+    Simple,
+    Progressive,
+    Perfect,
+    PerfectProgressive,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Voice {
     Active,
     Passive,
     // Middle, Reflexive, etc.
 }
-*/
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Polarity {
+    Positive,
+    Negative,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Register {
+    Formal,
+    Contracted,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Modal {
+    Can,
+    Could,
+    May,
+    Might,
+    Must,
+    Shall,
+    Should,
+    Will,
+    Would,
+}
+
+/// How a singular name ending in "s" forms its possessive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PossessiveStyle {
+    /// Chicago Manual of Style: always `'s` (`"James's"`).
+    Chicago,
+    /// Associated Press: a bare apostrophe after names ending in "s"
+    /// (`"James'"`).
+    AssociatedPress,
+}
+
+/// Regional spelling and inflection preference (`"traveled"`/`"travelled"`,
+/// `"learned"`/`"learnt"`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Dialect {
+    US,
+    UK,
+    /// Follows [`Dialect::UK`] where it has no form of its own.
+    AU,
+    /// Follows [`Dialect::US`] where it has no form of its own.
+    CA,
+}
+
+impl Dialect {
+    /// Short lowercase code used to key the generated tables (`"uk"`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            Dialect::US => "us",
+            Dialect::UK => "uk",
+            Dialect::AU => "au",
+            Dialect::CA => "ca",
+        }
+    }
+
+    /// The dialect whose forms are used when this one has none of its own.
+    pub fn fallback(&self) -> Option<Dialect> {
+        match self {
+            Dialect::AU => Some(Dialect::UK),
+            Dialect::CA => Some(Dialect::US),
+            Dialect::US | Dialect::UK => None,
+        }
+    }
+}
+
+/// A pronoun lexeme, inflected for [`Case`] by `EnglishCore::pronoun_form`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pronoun {
+    /// "I", "you", "she", "they"...
+    Personal(Person, Number, Gender),
+    /// "they" referring to one person, with the reflexive "themself".
+    SingularThey,
+    /// Generic "one": "one", "oneself", "one's".
+    Generic,
+    /// "this", "that", "these", "those".
+    Demonstrative(Proximity, Number),
+    Interrogative(Interrogative),
+    Relative(Relative),
+    /// "someone", "anything", "nobody", "everyone"...
+    Indefinite(Quantifier, Referent),
+    Reciprocal(Reciprocal),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Proximity {
+    /// "this", "these"
+    Near,
+    /// "that", "those"
+    Far,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Interrogative {
+    Who,
+    What,
+    Which,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Relative {
+    Who,
+    Which,
+    That,
+}
+
+/// First half of an indefinite pronoun: *some*one, *no*thing.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Quantifier {
+    Some,
+    Any,
+    No,
+    Every,
+}
+
+/// Second half of an indefinite pronoun: some*one*, no*thing*.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Referent {
+    Body,
+    One,
+    Thing,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reciprocal {
+    EachOther,
+    OneAnother,
+}
@@ -1,8 +1,27 @@
+//! English inflection rules that need no lookup tables.
+//!
+//! The crate is `no_std`. The default `std` feature enables `alloc`, which
+//! provides the functions returning `String`; without it only the
+//! `write_*` functions, which write into any [`core::fmt::Write`], and the
+//! functions returning `&'static str` remain.
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod adj;
+mod article;
+mod contraction;
 pub mod grammar;
 mod noun;
+mod pronoun;
 
 mod utils;
 mod verb;
 pub use crate::grammar::*;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use crate::utils::collect;
 pub struct EnglishCore {}
@@ -1,29 +1,266 @@
 use crate::EnglishCore;
 use crate::grammar::*;
+#[cfg(feature = "alloc")]
+use crate::utils::collect;
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
 
 impl EnglishCore {
+    #[cfg(feature = "alloc")]
     pub fn noun(word: &str, number: &Number) -> String {
+        collect(|out| EnglishCore::write_noun(out, word, number))
+    }
+
+    /// Writes the singular or plural of `word` to `out` without building
+    /// an intermediate `String`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Number};
+    ///
+    /// let mut out = String::from("two ");
+    /// EnglishCore::write_noun(&mut out, "box", &Number::Plural).unwrap();
+    /// assert_eq!(out, "two boxes");
+    /// ```
+    pub fn write_noun<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        number: &Number,
+    ) -> fmt::Result {
+        match number {
+            Number::Singular => out.write_str(word),
+            Number::Plural => EnglishCore::write_plural(out, word),
+        }
+    }
+    /// Inflects a proper noun, which never takes the irregular or `y -> ies`
+    /// plurals of common nouns.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Number};
+    ///
+    /// assert_eq!(EnglishCore::proper_noun("Kennedy", &Number::Plural), "Kennedys");
+    /// assert_eq!(EnglishCore::proper_noun("Jones", &Number::Plural), "Joneses");
+    /// assert_eq!(EnglishCore::proper_noun("Mann", &Number::Plural), "Manns");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn proper_noun(word: &str, number: &Number) -> String {
         match number {
-            Number::Singular => return word.to_string(),
-            Number::Plural => return EnglishCore::pluralize_noun(word),
+            Number::Singular => word.to_string(),
+            Number::Plural => EnglishCore::pluralize_proper_noun(word),
         }
     }
+
+    /// True if the rules give `word` as a whole an irregular plural
+    /// (`"mouse"`, `"foot"`), rather than only its ending.
+    pub fn is_irregular_noun(word: &str) -> bool {
+        IRREGULAR_SUFFIXES
+            .iter()
+            .any(|(singular, plural)| *singular == word && !plural.ends_with('s'))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn pluralize_proper_noun(word: &str) -> String {
+        let lower = word.to_lowercase();
+        let sibilant = ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|ending| lower.ends_with(ending));
+        let suffix = if sibilant { "es" } else { "s" };
+        if word.chars().count() > 1 && word.chars().all(|c| !c.is_lowercase()) {
+            format!("{word}{}", suffix.to_uppercase())
+        } else {
+            format!("{word}{suffix}")
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     pub fn add_possessive(word: &str) -> String {
-        if word.ends_with('s') {
+        if word.ends_with('s') && !word.ends_with("ss") {
             format!("{word}'") // Regular plural: dogs'
         } else {
-            format!("{word}'s") // Irregular plural: children’s
+            format!("{word}'s") // Irregular plural: children’s, singular: boss's
         }
     }
 
+    /// Adds the genitive ending to an already inflected noun.
+    ///
+    /// Plurals ending in "s" take a bare apostrophe (`"dogs'"`); other
+    /// plurals and all singulars take `'s` (`"children's"`, `"boss's"`),
+    /// except names ending in "s" in the [`PossessiveStyle::AssociatedPress`]
+    /// style (`"James'"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Number, PossessiveStyle};
+    ///
+    /// let chicago = &PossessiveStyle::Chicago;
+    /// assert_eq!(EnglishCore::genitive("boss", &Number::Singular, chicago), "boss's");
+    /// assert_eq!(EnglishCore::genitive("bosses", &Number::Plural, chicago), "bosses'");
+    /// assert_eq!(EnglishCore::genitive("children", &Number::Plural, chicago), "children's");
+    /// assert_eq!(
+    ///     EnglishCore::genitive("James", &Number::Singular, &PossessiveStyle::AssociatedPress),
+    ///     "James'"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn genitive(inflected: &str, number: &Number, style: &PossessiveStyle) -> String {
+        let ends_in_s = inflected.ends_with(['s', 'S']);
+        let bare_apostrophe = match number {
+            Number::Plural => ends_in_s,
+            Number::Singular => {
+                ends_in_s
+                    && *style == PossessiveStyle::AssociatedPress
+                    && EnglishCore::starts_with_uppercase(inflected)
+            }
+        };
+        if bare_apostrophe {
+            format!("{inflected}'")
+        } else {
+            format!("{inflected}'s")
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     pub fn pluralize_noun(word: &str) -> String {
-        if let Some(irr) = EnglishCore::iter_replace_last(word, IRREGULAR_SUFFIXES) {
-            return irr;
+        collect(|out| EnglishCore::write_plural(out, word))
+    }
+
+    /// Writes the regular plural of `word` to `out`; see
+    /// [`EnglishCore::pluralize_noun`].
+    pub fn write_plural<W: fmt::Write + ?Sized>(out: &mut W, word: &str) -> fmt::Result {
+        if let Some((start, end)) = EnglishCore::compound_head(word) {
+            out.write_str(&word[..start])?;
+            EnglishCore::write_plural(out, &word[start..end])?;
+            return out.write_str(&word[end..]);
+        }
+        if let Some((stem, ending)) = EnglishCore::split_replace_last(word, IRREGULAR_SUFFIXES) {
+            out.write_str(stem)?;
+            return out.write_str(ending);
         }
-        format!("{}{}", word, "s")
+        out.write_str(word)?;
+        out.write_str("s")
+    }
+
+    /// Byte range of the word that takes the plural ending in a compound
+    /// written with spaces or hyphens, or `None` for a single word.
+    ///
+    /// The head is the word before a linking preposition (`"mother-in-law"`,
+    /// `"man-of-war"`), the agent noun of a verb before a trailing particle
+    /// (`"passer-by"`, `"runner-up"`, but `"cover-up"`), or the noun before a
+    /// postpositive adjective (`"attorney general"`, but `"major general"`);
+    /// otherwise it is the last word. Compounds ending in an indefinite
+    /// pronoun are nouns only as a whole (`"good-for-nothing"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::EnglishCore;
+    ///
+    /// let word = "mother-in-law";
+    /// let (start, end) = EnglishCore::compound_head(word).unwrap();
+    /// assert_eq!(&word[start..end], "mother");
+    /// assert_eq!(EnglishCore::compound_head("mother"), None);
+    /// ```
+    pub fn compound_head(word: &str) -> Option<(usize, usize)> {
+        let tokens = || word.split([' ', '-']);
+        let last = tokens().count() - 1;
+        if last == 0 {
+            return None;
+        }
+
+        let token = |i: usize| tokens().nth(i).unwrap_or_default();
+        let trailing_modifier = (TRAILING_PARTICLES.contains(&token(last))
+            && EnglishCore::is_agent_noun(token(last - 1)))
+            || (POSTPOSITIVE_ADJECTIVES.contains(&token(last))
+                && !(token(last) == "general" && MILITARY_RANKS.contains(&token(last - 1))));
+        let head = match (1..last).find(|&i| LINKING_PREPOSITIONS.contains(&token(i))) {
+            _ if INDEFINITE_PRONOUNS.contains(&token(last)) => last,
+            Some(linking) => linking - 1,
+            None if trailing_modifier => last - 1,
+            None => last,
+        };
+        let head = token(head);
+        let start = head.as_ptr() as usize - word.as_ptr() as usize;
+        let end = start + head.len();
+        (start < end).then_some((start, end))
+    }
+
+    /// True if `word` is an `-er` agent noun of a verb that takes a
+    /// particle (`"passer"`, `"runner"`, `"diner"`).
+    fn is_agent_noun(word: &str) -> bool {
+        let Some(stem) = word.strip_suffix("er") else {
+            return false;
+        };
+        let mut chars = stem.chars().rev();
+        let undoubled = match (chars.next(), chars.next()) {
+            (Some(last), Some(before)) if last == before => &stem[..stem.len() - last.len_utf8()],
+            _ => stem,
+        };
+        AGENT_VERBS
+            .iter()
+            .any(|verb| [stem, undoubled].contains(verb) || verb.strip_suffix('e') == Some(stem))
+    }
+
+    /// Undoes the regular pluralization rules, returning every singular that
+    /// [`EnglishCore::pluralize_noun`] might have turned into `word`.
+    ///
+    /// Candidates are unverified; callers should re-inflect them and keep the
+    /// ones that reproduce `word`.
+    #[cfg(feature = "alloc")]
+    pub fn noun_lemma_candidates(word: &str) -> Vec<String> {
+        let mut candidates = Vec::new();
+        for (sing, plur) in IRREGULAR_SUFFIXES {
+            if word.ends_with(plur) {
+                candidates.push(EnglishCore::replace_last_occurence(word, plur, sing));
+            }
+        }
+        if let Some(stem) = word.strip_suffix('s') {
+            candidates.push(stem.to_string());
+        }
+        candidates
     }
 }
 
+/// Prepositions that follow the head of a compound (`"son-in-law"`).
+const LINKING_PREPOSITIONS: &[&str] = &["of", "in", "on", "at", "for", "de", "du"];
+
+/// Particles that trail an agent noun head (`"hanger-on"`).
+const TRAILING_PARTICLES: &[&str] = &["by", "on", "in", "up", "out", "off", "down", "about"];
+
+/// Verbs whose agent nouns head a compound with a trailing particle
+/// (`"looker-on"`, `"washer-up"`).
+const AGENT_VERBS: &[&str] = &[
+    "break", "bring", "call", "carry", "cast", "come", "cut", "dine", "drop", "fall", "fill",
+    "fly", "get", "go", "hand", "hang", "hold", "kick", "knock", "lay", "leave", "lie", "live",
+    "look", "make", "pass", "pick", "pull", "push", "put", "run", "sell", "set", "sit", "stand",
+    "stay", "stop", "sum", "take", "tell", "throw", "turn", "walk", "wash",
+];
+
+/// Ranks before "general" that make "general" the head noun
+/// (`"major generals"`).
+const MILITARY_RANKS: &[&str] = &["brigadier", "lieutenant", "major"];
+
+/// Pronouns that end compounds used as nouns only as a whole
+/// (`"free-for-all"`, `"know-it-all"`).
+const INDEFINITE_PRONOUNS: &[&str] = &["all", "anything", "everything", "nothing", "something"];
+
+/// Adjectives that follow the noun they modify in fixed compounds.
+const POSTPOSITIVE_ADJECTIVES: &[&str] = &[
+    "general",
+    "martial",
+    "public",
+    "apparent",
+    "presumptive",
+    "elect",
+    "laureate",
+    "royal",
+    "politic",
+];
+
 //These are most of the irregular suffixes, not counted so far are wolves,potatoes,compound words
 //some are commented out due to not positively affecting performance/size
 const IRREGULAR_SUFFIXES: &[(&str, &str)] = &[
@@ -0,0 +1,137 @@
+use crate::EnglishCore;
+use crate::grammar::*;
+
+/// (nominative/accusative, possessive) for each indefinite pronoun.
+fn indefinite(quantifier: &Quantifier, referent: &Referent) -> (&'static str, &'static str) {
+    match (quantifier, referent) {
+        (Quantifier::Some, Referent::Body) => ("somebody", "somebody's"),
+        (Quantifier::Some, Referent::One) => ("someone", "someone's"),
+        (Quantifier::Some, Referent::Thing) => ("something", "something's"),
+        (Quantifier::Any, Referent::Body) => ("anybody", "anybody's"),
+        (Quantifier::Any, Referent::One) => ("anyone", "anyone's"),
+        (Quantifier::Any, Referent::Thing) => ("anything", "anything's"),
+        (Quantifier::No, Referent::Body) => ("nobody", "nobody's"),
+        (Quantifier::No, Referent::One) => ("no one", "no one's"),
+        (Quantifier::No, Referent::Thing) => ("nothing", "nothing's"),
+        (Quantifier::Every, Referent::Body) => ("everybody", "everybody's"),
+        (Quantifier::Every, Referent::One) => ("everyone", "everyone's"),
+        (Quantifier::Every, Referent::Thing) => ("everything", "everything's"),
+    }
+}
+
+impl EnglishCore {
+    /// Returns the form of `pronoun` in `case`, or `None` where English has
+    /// no such form (there is no reflexive of "who" and no nominative of
+    /// "each other").
+    ///
+    /// [`Case::PersonalPossessive`] is the determiner ("their book") and
+    /// [`Case::Possessive`] the independent form ("the book is theirs").
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::*;
+    ///
+    /// assert_eq!(EnglishCore::pronoun_form(&Pronoun::SingularThey, &Case::Reflexive), Some("themself"));
+    /// assert_eq!(EnglishCore::pronoun_form(&Pronoun::Generic, &Case::Possessive), Some("one's"));
+    /// assert_eq!(
+    ///     EnglishCore::pronoun_form(&Pronoun::Interrogative(Interrogative::Who), &Case::Accusative),
+    ///     Some("whom")
+    /// );
+    /// assert_eq!(
+    ///     EnglishCore::pronoun_form(&Pronoun::Reciprocal(Reciprocal::EachOther), &Case::Nominative),
+    ///     None
+    /// );
+    /// ```
+    pub fn pronoun_form(pronoun: &Pronoun, case: &Case) -> Option<&'static str> {
+        match pronoun {
+            Pronoun::Personal(person, number, gender) => {
+                Some(EnglishCore::pronoun(person, number, gender, case))
+            }
+            Pronoun::SingularThey => Some(match case {
+                Case::Nominative => "they",
+                Case::Accusative => "them",
+                Case::Reflexive => "themself",
+                Case::Possessive => "theirs",
+                Case::PersonalPossessive => "their",
+            }),
+            Pronoun::Generic => Some(match case {
+                Case::Nominative | Case::Accusative => "one",
+                Case::Reflexive => "oneself",
+                Case::Possessive | Case::PersonalPossessive => "one's",
+            }),
+            Pronoun::Demonstrative(proximity, number) => match case {
+                Case::Nominative | Case::Accusative => Some(match (proximity, number) {
+                    (Proximity::Near, Number::Singular) => "this",
+                    (Proximity::Near, Number::Plural) => "these",
+                    (Proximity::Far, Number::Singular) => "that",
+                    (Proximity::Far, Number::Plural) => "those",
+                }),
+                _ => None,
+            },
+            Pronoun::Interrogative(interrogative) => match (interrogative, case) {
+                (Interrogative::Who, Case::Nominative) => Some("who"),
+                (Interrogative::Who, Case::Accusative) => Some("whom"),
+                (Interrogative::Who, Case::Possessive | Case::PersonalPossessive) => Some("whose"),
+                (Interrogative::What, Case::Nominative | Case::Accusative) => Some("what"),
+                (Interrogative::Which, Case::Nominative | Case::Accusative) => Some("which"),
+                _ => None,
+            },
+            Pronoun::Relative(relative) => match (relative, case) {
+                (Relative::Who, Case::Nominative) => Some("who"),
+                (Relative::Who, Case::Accusative) => Some("whom"),
+                (Relative::Who | Relative::Which, Case::PersonalPossessive) => Some("whose"),
+                (Relative::Which, Case::Nominative | Case::Accusative) => Some("which"),
+                (Relative::That, Case::Nominative | Case::Accusative) => Some("that"),
+                _ => None,
+            },
+            Pronoun::Indefinite(quantifier, referent) => {
+                let (plain, possessive) = indefinite(quantifier, referent);
+                match case {
+                    Case::Nominative | Case::Accusative => Some(plain),
+                    Case::Possessive | Case::PersonalPossessive => Some(possessive),
+                    Case::Reflexive => None,
+                }
+            }
+            Pronoun::Reciprocal(reciprocal) => {
+                let (plain, possessive) = match reciprocal {
+                    Reciprocal::EachOther => ("each other", "each other's"),
+                    Reciprocal::OneAnother => ("one another", "one another's"),
+                };
+                match case {
+                    Case::Accusative => Some(plain),
+                    Case::Possessive | Case::PersonalPossessive => Some(possessive),
+                    Case::Nominative | Case::Reflexive => None,
+                }
+            }
+        }
+    }
+
+    /// Person and number a pronoun agrees with as a subject: singular "they"
+    /// takes plural verbs ("they are") while indefinites take singular ones
+    /// ("everyone is").
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::*;
+    ///
+    /// assert_eq!(
+    ///     EnglishCore::pronoun_agreement(&Pronoun::SingularThey),
+    ///     (Person::Third, Number::Plural)
+    /// );
+    /// assert_eq!(
+    ///     EnglishCore::pronoun_agreement(&Pronoun::Indefinite(Quantifier::Every, Referent::One)),
+    ///     (Person::Third, Number::Singular)
+    /// );
+    /// ```
+    pub fn pronoun_agreement(pronoun: &Pronoun) -> (Person, Number) {
+        match pronoun {
+            Pronoun::Personal(person, number, _) => (*person, *number),
+            Pronoun::SingularThey | Pronoun::Reciprocal(_) => (Person::Third, Number::Plural),
+            Pronoun::Demonstrative(_, number) => (Person::Third, *number),
+            Pronoun::Generic
+            | Pronoun::Interrogative(_)
+            | Pronoun::Relative(_)
+            | Pronoun::Indefinite(_, _) => (Person::Third, Number::Singular),
+        }
+    }
+}
@@ -1,5 +1,25 @@
 use crate::EnglishCore;
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+/// Collects what `write` writes into a new `String`. Shared with `english`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn collect(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut out = String::new();
+    write(&mut out).expect("writing to a String cannot fail");
+    out
+}
+
 impl EnglishCore {
+    #[cfg(feature = "alloc")]
     pub fn pair_match(word: &str, listik: &[(&str, &str)]) -> Option<String> {
         listik
             .iter()
@@ -7,6 +27,7 @@ impl EnglishCore {
             .map(|(_, plur)| plur.to_string())
     }
 
+    #[cfg(feature = "alloc")]
     pub fn replace_last_occurence(input: &str, pattern: &str, replacement: &str) -> String {
         if let Some(last_index) = input.rfind(pattern) {
             let (before_last, _after_last) = input.split_at(last_index);
@@ -15,13 +36,20 @@ impl EnglishCore {
             input.into()
         }
     }
+    #[cfg(feature = "alloc")]
     pub fn iter_replace_last(word: &str, pairs: &[(&str, &str)]) -> Option<String> {
-        for (sing, plur) in pairs {
-            if word.ends_with(sing) {
-                return Some(EnglishCore::replace_last_occurence(word, sing, plur));
-            }
-        }
-        None
+        EnglishCore::split_replace_last(word, pairs).map(|(stem, ending)| format!("{stem}{ending}"))
+    }
+
+    /// Like [`EnglishCore::iter_replace_last`], but returns the kept stem
+    /// and the replacement ending separately instead of joining them.
+    pub fn split_replace_last<'a, 'b>(
+        word: &'a str,
+        pairs: &[(&str, &'b str)],
+    ) -> Option<(&'a str, &'b str)> {
+        pairs
+            .iter()
+            .find_map(|(sing, plur)| Some((word.strip_suffix(sing)?, *plur)))
     }
 
     pub fn starts_with_uppercase(word: &str) -> bool {
@@ -30,4 +58,85 @@ impl EnglishCore {
             .map(|c| c.is_uppercase())
             .unwrap_or(false)
     }
+
+    pub fn is_vowel(c: char) -> bool {
+        matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+    }
+
+    /// Estimates the number of syllables by counting vowel groups, treating
+    /// a non-initial `y` as a vowel and ignoring a silent final `e`.
+    pub fn syllable_count(word: &str) -> usize {
+        let mut count = 0;
+        let mut previous_vowel = false;
+        for (i, c) in word.chars().enumerate() {
+            let vowel = EnglishCore::is_vowel(c) || (c == 'y' && i > 0);
+            if vowel && !previous_vowel {
+                count += 1;
+            }
+            previous_vowel = vowel;
+        }
+        let silent_e = word.ends_with('e')
+            && !word.ends_with("le")
+            && !word.ends_with("ee")
+            && word.chars().count() > 2
+            && word
+                .chars()
+                .rev()
+                .nth(1)
+                .is_some_and(|c| !EnglishCore::is_vowel(c));
+        if silent_e && count > 1 {
+            count -= 1;
+        }
+        count.max(1)
+    }
+
+    /// True if `word` ends consonant-vowel-consonant with a single vowel
+    /// letter and a final consonant other than `w`, `x` or `y` ("big",
+    /// "stop"), the pattern that doubles its last letter before a suffix.
+    pub fn ends_with_cvc(word: &str) -> bool {
+        let mut chars = word.chars().rev();
+        let (Some(last), Some(vowel), Some(first)) = (chars.next(), chars.next(), chars.next())
+        else {
+            return false;
+        };
+        // The `u` in "qu" is a consonant glide ("quit" -> "quitting").
+        let after_q = chars.next() == Some('q');
+        let first_is_consonant = !EnglishCore::is_vowel(first) || (first == 'u' && after_q);
+        first_is_consonant
+            && EnglishCore::is_vowel(vowel)
+            && !EnglishCore::is_vowel(last)
+            && !matches!(last, 'w' | 'x' | 'y')
+    }
+
+    /// Undoes a suffix that was attached with the usual spelling changes,
+    /// returning every stem it could have come from: the bare stem, the
+    /// stem with a silent `e` restored, `i` turned back into `y`, `y` turned
+    /// back into `ie`, a `ck` after `c` undone, and a doubled final consonant
+    /// undoubled.
+    #[cfg(feature = "alloc")]
+    pub fn suffix_stems(word: &str, suffix: &str) -> Vec<String> {
+        let Some(stem) = word.strip_suffix(suffix) else {
+            return Vec::new();
+        };
+        if stem.is_empty() {
+            return Vec::new();
+        }
+        let mut stems = vec![stem.to_string(), format!("{stem}e")];
+        if let Some(rest) = stem.strip_suffix('i') {
+            stems.push(format!("{rest}y"));
+        }
+        if let Some(rest) = stem.strip_suffix('y') {
+            stems.push(format!("{rest}ie"));
+        }
+        if let Some(rest) = stem.strip_suffix('k')
+            && rest.ends_with('c')
+        {
+            stems.push(rest.to_string());
+        }
+        let bytes = stem.as_bytes();
+        if bytes.len() > 1 && bytes[bytes.len() - 1] == bytes[bytes.len() - 2] {
+            stems.push(stem[..stem.len() - 1].to_string());
+        }
+        stems
+    }
 }
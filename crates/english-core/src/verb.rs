@@ -1,6 +1,16 @@
 use crate::EnglishCore;
 use crate::grammar::*;
+#[cfg(feature = "alloc")]
+use crate::utils::collect;
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
 impl EnglishCore {
+    #[cfg(feature = "alloc")]
     pub fn verb(
         word: &str,
         person: &Person,
@@ -8,40 +18,244 @@ impl EnglishCore {
         tense: &Tense,
         form: &Form,
     ) -> String {
-        match word {
-            "be" => {
-                return EnglishCore::to_be(person, number, tense, form).to_string();
-            }
-            _ => (),
+        collect(|out| EnglishCore::write_verb(out, word, person, number, tense, form))
+    }
+
+    /// Conjugates a verb like [`EnglishCore::verb`], borrowing the forms of
+    /// "be", "have" and "do" instead of copying them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Form, Number, Person, Tense};
+    /// use std::borrow::Cow;
+    ///
+    /// let is = EnglishCore::verb_cow("be", &Person::Third, &Number::Singular, &Tense::Present, &Form::Finite);
+    /// assert!(matches!(is, Cow::Borrowed("is")));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn verb_cow(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> Cow<'static, str> {
+        match EnglishCore::auxiliary(word, person, number, tense, form) {
+            Some(aux) => Cow::Borrowed(aux),
+            None => Cow::Owned(EnglishCore::verb(word, person, number, tense, form)),
+        }
+    }
+
+    /// Writes the conjugated verb to `out` without building an intermediate
+    /// `String`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english_core::{EnglishCore, Form, Number, Person, Tense};
+    ///
+    /// let mut out = String::from("she ");
+    /// EnglishCore::write_verb(&mut out, "try", &Person::Third, &Number::Singular, &Tense::Past, &Form::Finite).unwrap();
+    /// assert_eq!(out, "she tried");
+    /// ```
+    pub fn write_verb<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> fmt::Result {
+        if let Some(aux) = EnglishCore::auxiliary(word, person, number, tense, form) {
+            return out.write_str(aux);
         }
         match (person, number, tense, form) {
-            (_, _, _, Form::Infinitive) => {
-                return word.to_string();
-            }
+            (_, _, _, Form::Infinitive) => out.write_str(word),
+            (_, _, Tense::Future, Form::Finite) => write!(out, "will {word}"),
 
             (Person::Third, Number::Singular, Tense::Present, Form::Finite) => {
-                if let Some(irr) = EnglishCore::iter_replace_last(word, IRREGULAR_THIRD) {
-                    return irr;
+                // A doubling final "z" doubles before "-es" too ("quizzes").
+                if word.ends_with('z') && EnglishCore::doubles_final_consonant(word) {
+                    return write!(out, "{word}zes");
                 }
-                format!("{}{}", word, "s")
-            }
-            (_, _, Tense::Present, Form::Finite) => {
-                return word.to_string();
-            }
-            (_, _, Tense::Present, Form::Participle) => {
-                if let Some(irr) = EnglishCore::iter_replace_last(word, IRREGULAR_PRES_PART) {
-                    return irr;
+                match EnglishCore::split_replace_last(word, IRREGULAR_THIRD) {
+                    Some((stem, ending)) => write!(out, "{stem}{ending}"),
+                    None => write!(out, "{word}s"),
                 }
-                format!("{}{}", word, "ing")
+            }
+            (_, _, Tense::Present, Form::Finite) => out.write_str(word),
+            (_, _, Tense::Present | Tense::Future, Form::Participle) => {
+                EnglishCore::write_verb_suffix(out, word, "ing")
             }
 
-            (_, _, Tense::Past, _) => {
-                if let Some(irr) = EnglishCore::iter_replace_last(word, IRREGULAR_PAST) {
-                    return irr;
+            (_, _, Tense::Past, _) => match EnglishCore::split_replace_last(word, IRREGULAR_PAST) {
+                Some((stem, ending)) => write!(out, "{stem}{ending}"),
+                None => EnglishCore::write_verb_suffix(out, word, "ed"),
+            },
+        }
+    }
+
+    /// Forms of the verbs the rules leave to fixed tables.
+    fn auxiliary(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> Option<&'static str> {
+        match word {
+            "be" => Some(EnglishCore::to_be(person, number, tense, form)),
+            "have" => Some(EnglishCore::to_have(person, number, tense, form)),
+            "do" => Some(EnglishCore::to_do(person, number, tense, form)),
+            _ => None,
+        }
+    }
+
+    /// Conjugates a verb in the given mood.
+    ///
+    /// The present subjunctive and the imperative use the bare form for
+    /// every person ("that he go", "go!"); the past subjunctive only differs
+    /// from the indicative for "be" ("if I were"). Non-finite forms ignore
+    /// the mood.
+    #[cfg(feature = "alloc")]
+    pub fn verb_mood(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+        mood: &Mood,
+    ) -> String {
+        if word == "be" {
+            return EnglishCore::to_be_mood(person, number, tense, form, mood).to_string();
+        }
+        match (mood, tense, form) {
+            (Mood::Imperative, _, Form::Finite)
+            | (Mood::Subjunctive, Tense::Present, Form::Finite) => word.to_string(),
+            _ => EnglishCore::verb(word, person, number, tense, form),
+        }
+    }
+
+    /// Attaches `-ed` or `-ing` to a regular verb with the usual spelling
+    /// changes: `-ie` becomes `-y` before `-ing` ("tie" -> "tying"), a
+    /// silent `e` is dropped ("bake" -> "baked") but `-ee`/`-oe`/`-ye` keep
+    /// theirs before `-ing` ("agree" -> "agreeing"), consonant + `y` becomes
+    /// `-ied`, a final `c` after a vowel gains a `k` ("panic" -> "panicked"),
+    /// and a stressed final consonant is doubled ("zot" -> "zotted").
+    #[cfg(feature = "alloc")]
+    pub fn add_verb_suffix(word: &str, suffix: &str) -> String {
+        collect(|out| EnglishCore::write_verb_suffix(out, word, suffix))
+    }
+
+    /// Writes `word` with `suffix` attached to `out`; see
+    /// [`EnglishCore::add_verb_suffix`].
+    pub fn write_verb_suffix<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        suffix: &str,
+    ) -> fmt::Result {
+        if suffix == "ing"
+            && let Some(stem) = word.strip_suffix("ie")
+        {
+            return write!(out, "{stem}ying");
+        }
+        if ["ee", "oe", "ye"].iter().any(|end| word.ends_with(end)) {
+            return match suffix.strip_prefix('e') {
+                Some(rest) => write!(out, "{word}{rest}"),
+                None => write!(out, "{word}{suffix}"),
+            };
+        }
+        if let Some(stem) = word.strip_suffix('e')
+            && !stem.is_empty()
+        {
+            return write!(out, "{stem}{suffix}");
+        }
+        if suffix == "ed"
+            && let Some(stem) = word.strip_suffix('y')
+            && stem
+                .chars()
+                .last()
+                .is_some_and(|c| !EnglishCore::is_vowel(c))
+        {
+            return write!(out, "{stem}ied");
+        }
+        let mut chars = word.chars().rev();
+        if let (Some('c'), Some(before)) = (chars.next(), chars.next())
+            && EnglishCore::is_vowel(before)
+        {
+            return write!(out, "{word}k{suffix}");
+        }
+        if EnglishCore::doubles_final_consonant(word) {
+            let last = word.chars().last().unwrap_or_default();
+            return write!(out, "{word}{last}{suffix}");
+        }
+        write!(out, "{word}{suffix}")
+    }
+
+    /// True if a regular verb doubles its final consonant before `-ed` and
+    /// `-ing`.
+    ///
+    /// The word must end in a single-vowel CVC (see
+    /// [`EnglishCore::ends_with_cvc`]) and the final syllable must be
+    /// stressed: one-syllable verbs always are, longer ones only when they
+    /// end in a known stressed root ("admit", "refer", "control") or are a
+    /// one-syllable root behind a prefix like "re-" or "mis-" ("refit",
+    /// "outrun"). Final `-p` and `-g` double even
+    /// after an unstressed syllable ("worship" -> "worshipped",
+    /// "zigzag" -> "zigzagged"); the exceptions ("develop") ship in the
+    /// generated verb table.
+    pub fn doubles_final_consonant(word: &str) -> bool {
+        if !EnglishCore::ends_with_cvc(word) {
+            return false;
+        }
+        if EnglishCore::syllable_count(word) == 1 || word.ends_with(['p', 'g']) {
+            return true;
+        }
+        let prefixed_root = VERB_PREFIXES.iter().any(|prefix| {
+            word.strip_prefix(prefix).is_some_and(|root| {
+                // "reckon", "redden" and "render" are not "re-" + a root
+                root.len() > 2
+                    && root.chars().any(EnglishCore::is_vowel)
+                    && starts_like_a_word(root)
+                    && EnglishCore::syllable_count(root) == 1
+            })
+        });
+        prefixed_root
+            || STRESSED_FINAL_SYLLABLES.iter().any(|root| {
+                word.strip_suffix(root)
+                    .is_some_and(|prefix| prefix.is_empty() || VERB_PREFIXES.contains(&prefix))
+            })
+    }
+    /// Undoes the regular conjugation rules, returning every infinitive that
+    /// [`EnglishCore::verb`] might have turned into `word`.
+    ///
+    /// Forms of "to be" yield `"be"`. Candidates are unverified; callers
+    /// should re-conjugate them and keep the ones that reproduce `word`.
+    #[cfg(feature = "alloc")]
+    pub fn verb_lemma_candidates(word: &str) -> Vec<String> {
+        let mut candidates = Vec::new();
+        if matches!(
+            word,
+            "am" | "is" | "are" | "was" | "were" | "been" | "being"
+        ) {
+            candidates.push("be".to_string());
+        }
+        for table in [IRREGULAR_THIRD, IRREGULAR_PAST] {
+            for (base, inflected) in table {
+                if word.ends_with(inflected) {
+                    candidates.push(EnglishCore::replace_last_occurence(word, inflected, base));
                 }
-                format!("{}{}", word, "ed")
             }
         }
+        if let Some(stem) = word.strip_suffix('s') {
+            candidates.push(stem.to_string());
+        }
+        if let Some(stem) = word.strip_suffix("zes") {
+            candidates.extend(stem.strip_suffix('z').map(ToString::to_string));
+        }
+        for suffix in ["ed", "d", "ing"] {
+            candidates.extend(EnglishCore::suffix_stems(word, suffix));
+        }
+        candidates
     }
     pub fn to_be(person: &Person, number: &Number, tense: &Tense, form: &Form) -> &'static str {
         match (tense, form) {
@@ -62,34 +276,76 @@ impl EnglishCore {
                 },
                 Number::Plural => "were",
             },
+            (Tense::Future, Form::Finite) => "will be",
             (Tense::Past, Form::Participle) => "been",
-            (Tense::Present, Form::Participle) => "being",
+            (Tense::Present | Tense::Future, Form::Participle) => "being",
+        }
+    }
+    pub fn to_be_mood(
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+        mood: &Mood,
+    ) -> &'static str {
+        match (mood, tense, form) {
+            (Mood::Subjunctive, Tense::Present, Form::Finite) => "be",
+            (Mood::Subjunctive, Tense::Past, Form::Finite) => "were",
+            (Mood::Imperative, _, Form::Finite) => "be",
+            _ => EnglishCore::to_be(person, number, tense, form),
+        }
+    }
+    pub fn to_have(person: &Person, number: &Number, tense: &Tense, form: &Form) -> &'static str {
+        match (person, number, tense, form) {
+            (_, _, _, Form::Infinitive) => "have",
+            (Person::Third, Number::Singular, Tense::Present, Form::Finite) => "has",
+            (_, _, Tense::Present, Form::Finite) => "have",
+            (_, _, Tense::Past, _) => "had",
+            (_, _, Tense::Future, Form::Finite) => "will have",
+            (_, _, Tense::Present | Tense::Future, Form::Participle) => "having",
+        }
+    }
+    pub fn to_do(person: &Person, number: &Number, tense: &Tense, form: &Form) -> &'static str {
+        match (person, number, tense, form) {
+            (_, _, _, Form::Infinitive) => "do",
+            (Person::Third, Number::Singular, Tense::Present, Form::Finite) => "does",
+            (_, _, Tense::Present, Form::Finite) => "do",
+            (_, _, Tense::Past, Form::Finite) => "did",
+            (_, _, Tense::Past, Form::Participle) => "done",
+            (_, _, Tense::Future, Form::Finite) => "will do",
+            (_, _, Tense::Present | Tense::Future, Form::Participle) => "doing",
+        }
+    }
+    pub fn modal(modal: &Modal) -> &'static str {
+        match modal {
+            Modal::Can => "can",
+            Modal::Could => "could",
+            Modal::May => "may",
+            Modal::Might => "might",
+            Modal::Must => "must",
+            Modal::Shall => "shall",
+            Modal::Should => "should",
+            Modal::Will => "will",
+            Modal::Would => "would",
+        }
+    }
+    pub fn as_modal(word: &str) -> Option<Modal> {
+        match word {
+            "can" => Some(Modal::Can),
+            "could" => Some(Modal::Could),
+            "may" => Some(Modal::May),
+            "might" => Some(Modal::Might),
+            "must" => Some(Modal::Must),
+            "shall" => Some(Modal::Shall),
+            "should" => Some(Modal::Should),
+            "will" => Some(Modal::Will),
+            "would" => Some(Modal::Would),
+            _ => None,
         }
     }
 }
 
-static IRREGULAR_PRES_PART: &[(&str, &str)] = &[
-    ("e", "ing"),
-    ("p", "pping"),
-    ("ng", "nging"),
-    ("g", "gging"),
-    //  ("b", "bbing"),
-    //   ("d", "dding"),
-    //  ("t", "tting"),
-];
-
-static IRREGULAR_PAST: &[(&str, &str)] = &[
-    ("fight", "fought"),
-    ("buy", "bought"),
-    ("e", "ed"),
-    ("p", "pped"),
-    ("y", "ied"),
-    ("ng", "nged"),
-    ("g", "gged"),
-    // ("b", "bbed"),
-    //("d", "dded"),
-    //  ("t", "tted"),
-];
+static IRREGULAR_PAST: &[(&str, &str)] = &[("fight", "fought"), ("buy", "bought")];
 
 static IRREGULAR_THIRD: &[(&str, &str)] = &[
     ("sh", "shes"),
@@ -97,6 +353,38 @@ static IRREGULAR_THIRD: &[(&str, &str)] = &[
     ("s", "ses"),
     ("z", "zes"),
     ("x", "xes"),
-    ("buy", "buys"),
+    ("ay", "ays"),
+    ("ey", "eys"),
+    ("oy", "oys"),
+    ("uy", "uys"),
     ("y", "ies"),
 ];
+
+//Final syllables that carry the stress in longer verbs, so their consonant doubles
+static STRESSED_FINAL_SYLLABLES: &[&str] = &[
+    "admit", "commit", "emit", "omit", "permit", "submit", "transmit", "remit", "acquit", "refer",
+    "prefer", "confer", "defer", "infer", "transfer", "deter", "occur", "recur", "incur", "concur",
+    "compel", "expel", "propel", "repel", "dispel", "excel", "control", "patrol", "begin",
+    "forget", "beget", "regret", "embed", "rebut", "abet", "allot", "outwit", "upset", "abhor",
+    "inter", "aver", "demur",
+];
+
+static VERB_PREFIXES: &[&str] = &["re", "un", "mis", "pre", "over", "out", "under"];
+
+//Consonant clusters that can begin an English word
+static WORD_ONSETS: &[&str] = &[
+    "bl", "br", "ch", "cl", "cr", "dr", "dw", "fl", "fr", "gl", "gr", "kn", "ph", "pl", "pr", "sc",
+    "scr", "sh", "shr", "sk", "sl", "sm", "sn", "sp", "spl", "spr", "sq", "st", "str", "sw", "th",
+    "thr", "tr", "tw", "wh", "wr",
+];
+
+/// True if the consonants before the first vowel of `root` could begin a
+/// word, so that stripping a prefix left a plausible root ("fit") rather
+/// than the middle of one ("nder", "ckon").
+fn starts_like_a_word(root: &str) -> bool {
+    let onset = root
+        .char_indices()
+        .find(|&(i, c)| EnglishCore::is_vowel(c) || (c == 'y' && i > 0))
+        .map_or(root, |(i, _)| &root[..i]);
+    onset.chars().count() <= 1 || WORD_ONSETS.contains(&onset)
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use english_core::{Degree, EnglishCore};
 
 #[test]
@@ -27,3 +29,38 @@ fn adjective_degree_dispatch_stays_consistent_with_specific_helpers() {
         EnglishCore::superlative("quiet")
     );
 }
+
+#[test]
+fn short_adjectives_take_synthetic_suffixes() {
+    assert_eq!(EnglishCore::comparative("fast"), "faster");
+    assert_eq!(EnglishCore::superlative("fast"), "fastest");
+    assert_eq!(EnglishCore::comparative("big"), "bigger");
+    assert_eq!(EnglishCore::superlative("hot"), "hottest");
+    assert_eq!(EnglishCore::comparative("large"), "larger");
+    assert_eq!(EnglishCore::superlative("free"), "freest");
+    assert_eq!(EnglishCore::comparative("cool"), "cooler");
+    assert_eq!(EnglishCore::comparative("new"), "newer");
+    assert_eq!(EnglishCore::comparative("gray"), "grayer");
+}
+
+#[test]
+fn two_syllable_adjectives_follow_their_ending() {
+    assert_eq!(EnglishCore::comparative("happy"), "happier");
+    assert_eq!(EnglishCore::superlative("happy"), "happiest");
+    assert_eq!(EnglishCore::comparative("simple"), "simpler");
+    assert_eq!(EnglishCore::comparative("narrow"), "narrower");
+    assert_eq!(EnglishCore::comparative("clever"), "cleverer");
+    assert_eq!(EnglishCore::comparative("careful"), "more careful");
+    assert_eq!(EnglishCore::superlative("modern"), "most modern");
+}
+
+#[test]
+fn long_and_exceptional_adjectives_stay_periphrastic() {
+    assert_eq!(EnglishCore::comparative("beautiful"), "more beautiful");
+    assert_eq!(EnglishCore::superlative("important"), "most important");
+    assert_eq!(EnglishCore::comparative("bored"), "more bored");
+    assert_eq!(EnglishCore::comparative("fun"), "more fun");
+    assert_eq!(EnglishCore::comparative("red"), "redder");
+    assert_eq!(EnglishCore::comparative("good"), "better");
+    assert_eq!(EnglishCore::superlative("bad"), "worst");
+}
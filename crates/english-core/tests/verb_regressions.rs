@@ -0,0 +1,122 @@
+#![cfg(feature = "alloc")]
+
+use english_core::{EnglishCore, Form, Mood, Number, Person, Tense};
+
+fn past(word: &str) -> String {
+    EnglishCore::verb(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Past,
+        &Form::Finite,
+    )
+}
+
+fn present_participle(word: &str) -> String {
+    EnglishCore::verb(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Present,
+        &Form::Participle,
+    )
+}
+
+fn third_singular(word: &str) -> String {
+    EnglishCore::verb(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Present,
+        &Form::Finite,
+    )
+}
+
+#[test]
+fn stressed_cvc_endings_double_their_consonant() {
+    assert_eq!(past("zot"), "zotted");
+    assert_eq!(present_participle("zot"), "zotting");
+    assert_eq!(past("blog"), "blogged");
+    assert_eq!(past("stab"), "stabbed");
+    assert_eq!(present_participle("plod"), "plodding");
+    assert_eq!(past("admit"), "admitted");
+    assert_eq!(present_participle("refer"), "referring");
+    assert_eq!(past("refit"), "refitted");
+    assert_eq!(present_participle("quiz"), "quizzing");
+    assert_eq!(past("quiz"), "quizzed");
+    assert_eq!(third_singular("quiz"), "quizzes");
+    assert_eq!(third_singular("whiz"), "whizzes");
+    assert_eq!(third_singular("buzz"), "buzzes");
+    assert_eq!(third_singular("waltz"), "waltzes");
+}
+
+#[test]
+fn unstressed_and_non_cvc_endings_do_not_double() {
+    assert_eq!(past("visit"), "visited");
+    assert_eq!(present_participle("open"), "opening");
+    assert_eq!(past("reckon"), "reckoned");
+    assert_eq!(past("redden"), "reddened");
+    assert_eq!(past("render"), "rendered");
+    assert_eq!(present_participle("render"), "rendering");
+    assert_eq!(past("wonder"), "wondered");
+    assert_eq!(present_participle("wonder"), "wondering");
+    assert_eq!(past("enter"), "entered");
+    assert_eq!(present_participle("enter"), "entering");
+    assert_eq!(past("help"), "helped");
+    assert_eq!(past("cool"), "cooled");
+    assert_eq!(present_participle("row"), "rowing");
+    assert_eq!(past("fix"), "fixed");
+    assert_eq!(past("play"), "played");
+}
+
+#[test]
+fn spelling_changes_before_suffixes() {
+    assert_eq!(present_participle("tie"), "tying");
+    assert_eq!(past("tie"), "tied");
+    assert_eq!(present_participle("bake"), "baking");
+    assert_eq!(past("bake"), "baked");
+    assert_eq!(present_participle("agree"), "agreeing");
+    assert_eq!(past("agree"), "agreed");
+    assert_eq!(past("panic"), "panicked");
+    assert_eq!(present_participle("picnic"), "picnicking");
+    assert_eq!(past("sync"), "synced");
+    assert_eq!(past("carry"), "carried");
+    assert_eq!(third_singular("carry"), "carries");
+    assert_eq!(third_singular("play"), "plays");
+    assert_eq!(third_singular("buy"), "buys");
+}
+
+#[test]
+fn to_be_follows_mood() {
+    assert_eq!(
+        EnglishCore::to_be_mood(
+            &Person::First,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite,
+            &Mood::Subjunctive
+        ),
+        "were"
+    );
+    assert_eq!(
+        EnglishCore::to_be_mood(
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Finite,
+            &Mood::Indicative
+        ),
+        "is"
+    );
+    assert_eq!(
+        EnglishCore::verb_mood(
+            "zot",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Finite,
+            &Mood::Subjunctive
+        ),
+        "zot"
+    );
+}
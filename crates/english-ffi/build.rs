@@ -0,0 +1,199 @@
+//! Generates `english.h` in `OUT_DIR` from the public constants, `#[repr(C)]`
+//! enums and `extern "C"` functions of `src/lib.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use syn::{Attribute, Expr, FnArg, Item, Lit, Pat, ReturnType, Type, Visibility};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    let source = fs::read_to_string("src/lib.rs").expect("failed to read src/lib.rs");
+    let file = syn::parse_file(&source).expect("failed to parse src/lib.rs");
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out.join("english.h"), header(&file.items)).expect("failed to write english.h");
+}
+
+fn header(items: &[Item]) -> String {
+    let enums: Vec<String> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Enum(item) if is_public(&item.vis) => Some(item.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(
+        "/* Generated by crates/english-ffi/build.rs from src/lib.rs. Do not edit. */\n\n",
+    );
+    out.push_str("#ifndef ENGLISH_H\n#define ENGLISH_H\n\n");
+    out.push_str("#include <stddef.h>\n#include <stdint.h>\n\n");
+    out.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+
+    for item in items {
+        match item {
+            Item::Const(item) if is_public(&item.vis) => {
+                out.push('\n');
+                write_docs(&mut out, &item.attrs, "");
+                writeln!(out, "#define {} {}", item.ident, c_value(&item.expr)).unwrap();
+            }
+            Item::Enum(item) if is_public(&item.vis) => {
+                let name = item.ident.to_string();
+                let prefix = screaming_snake(&name);
+                out.push('\n');
+                write_docs(&mut out, &item.attrs, "");
+                writeln!(out, "typedef enum {name} {{").unwrap();
+                for (i, variant) in item.variants.iter().enumerate() {
+                    write_docs(&mut out, &variant.attrs, "    ");
+                    let variant = screaming_snake(&variant.ident.to_string());
+                    writeln!(out, "    {prefix}_{variant} = {i},").unwrap();
+                }
+                writeln!(out, "}} {name};").unwrap();
+            }
+            Item::Fn(item) if is_public(&item.vis) && item.sig.abi.is_some() => {
+                let sig = &item.sig;
+                let params: Vec<String> = sig
+                    .inputs
+                    .iter()
+                    .map(|input| match input {
+                        FnArg::Typed(param) => match &*param.pat {
+                            Pat::Ident(name) => {
+                                declaration(&c_type(&param.ty, &enums), &name.ident.to_string())
+                            }
+                            _ => panic!("unsupported parameter pattern in `{}`", sig.ident),
+                        },
+                        FnArg::Receiver(_) => panic!("`{}` takes `self`", sig.ident),
+                    })
+                    .collect();
+                let ret = match &sig.output {
+                    ReturnType::Default => "void".to_string(),
+                    ReturnType::Type(_, ty) => c_type(ty, &enums),
+                };
+                out.push('\n');
+                write_docs(&mut out, &item.attrs, "");
+                writeln!(
+                    out,
+                    "{};",
+                    declaration(&ret, &format!("{}({})", sig.ident, params.join(", ")))
+                )
+                .unwrap();
+            }
+            _ => {}
+        }
+    }
+
+    out.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif /* ENGLISH_H */\n");
+    out
+}
+
+fn is_public(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+/// Writes the `///` comments of an item as a C block comment, with links
+/// turned back into plain code spans.
+fn write_docs(out: &mut String, attrs: &[Attribute], indent: &str) {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(lit) => match &lit.lit {
+                    Lit::Str(doc) => Some(doc.value()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(&line);
+            line.replace("[`", "`").replace("`]", "`")
+        })
+        .collect();
+    if lines.is_empty() {
+        return;
+    }
+    writeln!(out, "{indent}/**").unwrap();
+    for line in lines {
+        if line.is_empty() {
+            writeln!(out, "{indent} *").unwrap();
+        } else {
+            writeln!(out, "{indent} * {line}").unwrap();
+        }
+    }
+    writeln!(out, "{indent} */").unwrap();
+}
+
+fn c_type(ty: &Type, enums: &[String]) -> String {
+    match ty {
+        Type::Ptr(ptr) => {
+            let pointee = c_type(&ptr.elem, enums);
+            match ptr.mutability {
+                Some(_) => format!("{pointee} *"),
+                None => format!("const {pointee} *"),
+            }
+        }
+        Type::Path(path) => {
+            let name = path
+                .path
+                .segments
+                .last()
+                .expect("empty type path")
+                .ident
+                .to_string();
+            match name.as_str() {
+                "c_char" => "char".to_string(),
+                "usize" => "size_t".to_string(),
+                "u8" | "u16" | "u32" | "u64" => format!("uint{}_t", &name[1..]),
+                "i8" | "i16" | "i32" | "i64" => format!("int{}_t", &name[1..]),
+                _ if enums.contains(&name) => name,
+                _ => panic!("no C type for `{name}`"),
+            }
+        }
+        _ => panic!("unsupported type in the C ABI"),
+    }
+}
+
+fn c_value(expr: &Expr) -> String {
+    match expr {
+        Expr::Path(path) if path.path.segments.len() == 2 => {
+            let ty = &path.path.segments[0].ident;
+            match (
+                ty.to_string().as_str(),
+                path.path.segments[1].ident.to_string().as_str(),
+            ) {
+                ("usize", "MAX") => "SIZE_MAX".to_string(),
+                ("u32", "MAX") => "UINT32_MAX".to_string(),
+                _ => panic!("no C value for a constant of `{ty}`"),
+            }
+        }
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_digits().to_string(),
+            _ => panic!("unsupported literal in a constant"),
+        },
+        _ => panic!("unsupported constant expression"),
+    }
+}
+
+/// `const char *` and `word` make `const char *word`.
+fn declaration(ty: &str, name: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{ty}{name}")
+    } else {
+        format!("{ty} {name}")
+    }
+}
+
+/// `EnglishNumber` becomes `ENGLISH_NUMBER`.
+fn screaming_snake(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
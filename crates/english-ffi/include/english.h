@@ -0,0 +1,149 @@
+/* Generated by crates/english-ffi/build.rs from src/lib.rs. Do not edit. */
+
+#ifndef ENGLISH_H
+#define ENGLISH_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Returned instead of a length when a word is null or not valid UTF-8, or
+ * when a category argument is not one of its enum's constants.
+ */
+#define ENGLISH_ERROR SIZE_MAX
+
+/**
+ * Grammatical number, as `english::Number`.
+ */
+typedef enum EnglishNumber {
+    ENGLISH_NUMBER_SINGULAR = 0,
+    ENGLISH_NUMBER_PLURAL = 1,
+} EnglishNumber;
+
+/**
+ * Grammatical person, as `english::Person`.
+ */
+typedef enum EnglishPerson {
+    ENGLISH_PERSON_FIRST = 0,
+    ENGLISH_PERSON_SECOND = 1,
+    ENGLISH_PERSON_THIRD = 2,
+} EnglishPerson;
+
+/**
+ * Verb tense, as `english::Tense`.
+ */
+typedef enum EnglishTense {
+    ENGLISH_TENSE_PRESENT = 0,
+    ENGLISH_TENSE_PAST = 1,
+    ENGLISH_TENSE_FUTURE = 2,
+} EnglishTense;
+
+/**
+ * Verb form, as `english::Form`.
+ */
+typedef enum EnglishForm {
+    ENGLISH_FORM_FINITE = 0,
+    ENGLISH_FORM_PARTICIPLE = 1,
+    ENGLISH_FORM_INFINITIVE = 2,
+} EnglishForm;
+
+/**
+ * Adjective degree, as `english::Degree`.
+ */
+typedef enum EnglishDegree {
+    ENGLISH_DEGREE_POSITIVE = 0,
+    ENGLISH_DEGREE_COMPARATIVE = 1,
+    ENGLISH_DEGREE_SUPERLATIVE = 2,
+} EnglishDegree;
+
+/**
+ * Pronoun gender, as `english::Gender` without runtime pronoun sets.
+ */
+typedef enum EnglishGender {
+    ENGLISH_GENDER_MASCULINE = 0,
+    ENGLISH_GENDER_FEMININE = 1,
+    ENGLISH_GENDER_NEUTER = 2,
+} EnglishGender;
+
+/**
+ * Pronoun case, as `english::Case`.
+ */
+typedef enum EnglishCase {
+    ENGLISH_CASE_NOMINATIVE = 0,
+    ENGLISH_CASE_ACCUSATIVE = 1,
+    ENGLISH_CASE_REFLEXIVE = 2,
+    /**
+     * Independent possessive: "mine", "theirs".
+     */
+    ENGLISH_CASE_POSSESSIVE = 3,
+    /**
+     * Possessive determiner: "my", "their".
+     */
+    ENGLISH_CASE_PERSONAL_POSSESSIVE = 4,
+} EnglishCase;
+
+/**
+ * Inflects a noun for number, as `English::noun`. `number` is an
+ * `EnglishNumber`.
+ *
+ * # Safety
+ * `word` must be null or a NUL-terminated string, and `buf` must be null or
+ * valid for writes of `len` bytes.
+ */
+size_t english_noun(const char *word, uint32_t number, char *buf, size_t len);
+
+/**
+ * Conjugates a verb, as `English::verb`. `person`, `number`, `tense` and
+ * `form` are an `EnglishPerson`, `EnglishNumber`, `EnglishTense` and
+ * `EnglishForm`.
+ *
+ * # Safety
+ * See `english_noun`.
+ */
+size_t english_verb(const char *word, uint32_t person, uint32_t number, uint32_t tense, uint32_t form, char *buf, size_t len);
+
+/**
+ * Compares an adjective, as `English::adj`. `degree` is an
+ * `EnglishDegree`.
+ *
+ * # Safety
+ * See `english_noun`.
+ */
+size_t english_adj(const char *word, uint32_t degree, char *buf, size_t len);
+
+/**
+ * Writes a personal pronoun, as `English::pronoun`. `person`, `number`,
+ * `gender` and `pronoun_case` are an `EnglishPerson`, `EnglishNumber`,
+ * `EnglishGender` and `EnglishCase`.
+ *
+ * # Safety
+ * `buf` must be null or valid for writes of `len` bytes.
+ */
+size_t english_pronoun(uint32_t person, uint32_t number, uint32_t gender, uint32_t pronoun_case, char *buf, size_t len);
+
+/**
+ * Adds a possessive suffix (`'s` or `'`), as `English::add_possessive`.
+ *
+ * # Safety
+ * See `english_noun`.
+ */
+size_t english_add_possessive(const char *word, char *buf, size_t len);
+
+/**
+ * Writes a count followed by the noun inflected for it ("3 cats"), as
+ * `count_with_number`.
+ *
+ * # Safety
+ * See `english_noun`.
+ */
+size_t english_count_with_number(const char *word, uint32_t amount, char *buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ENGLISH_H */
@@ -0,0 +1,436 @@
+//! C ABI for [`english`], built as `libenglish_ffi.so` / `.dylib` / `.dll`
+//! and `libenglish_ffi.a`, with the header `include/english.h`.
+//!
+//! Words go in as NUL-terminated UTF-8 strings and come out in a buffer the
+//! caller owns, `snprintf` style: each function writes as much of the result
+//! as fits in `len - 1` bytes, without splitting a character, NUL-terminates
+//! it, and returns the length of the whole result. A return value of `len` or
+//! more means the output was cut short, so a caller can ask for the length
+//! with a null `buf` and a `len` of 0, then call again with enough room.
+//! Nothing is allocated on the caller's behalf, so there is nothing to free.
+//!
+//! Grammatical categories are passed as `uint32_t` holding one of the
+//! constants of their enum (`ENGLISH_NUMBER_PLURAL`). C lets an enum hold any
+//! value, so each argument is checked and anything out of range is an error.
+//!
+//! The header is generated from this file by `build.rs`; the
+//! `header_is_up_to_date` test fails when the checked-in copy falls behind.
+
+use core::ffi::{CStr, c_char};
+use core::fmt::{self, Write};
+use core::mem::MaybeUninit;
+use core::slice;
+use english::{Case, Degree, English, Form, Gender, Number, Person, Tense};
+
+/// Returned instead of a length when a word is null or not valid UTF-8, or
+/// when a category argument is not one of its enum's constants.
+pub const ENGLISH_ERROR: usize = usize::MAX;
+
+/// Grammatical number, as [`english::Number`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishNumber {
+    Singular,
+    Plural,
+}
+
+/// Grammatical person, as [`english::Person`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishPerson {
+    First,
+    Second,
+    Third,
+}
+
+/// Verb tense, as [`english::Tense`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishTense {
+    Present,
+    Past,
+    Future,
+}
+
+/// Verb form, as [`english::Form`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishForm {
+    Finite,
+    Participle,
+    Infinitive,
+}
+
+/// Adjective degree, as [`english::Degree`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishDegree {
+    Positive,
+    Comparative,
+    Superlative,
+}
+
+/// Pronoun gender, as [`english::Gender`] without runtime pronoun sets.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishGender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// Pronoun case, as [`english::Case`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum EnglishCase {
+    Nominative,
+    Accusative,
+    Reflexive,
+    /// Independent possessive: "mine", "theirs".
+    Possessive,
+    /// Possessive determiner: "my", "their".
+    PersonalPossessive,
+}
+
+/// Implements `TryFrom<u32>` for the C enums, checking that the value C
+/// passed is one of the variants.
+macro_rules! try_from_u32 {
+    ($($name:ident { $($variant:ident),+ $(,)? })+) => {$(
+        impl TryFrom<u32> for $name {
+            type Error = ();
+
+            fn try_from(value: u32) -> Result<Self, ()> {
+                $(
+                    if value == $name::$variant as u32 {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err(())
+            }
+        }
+    )+};
+}
+
+try_from_u32! {
+    EnglishNumber { Singular, Plural }
+    EnglishPerson { First, Second, Third }
+    EnglishTense { Present, Past, Future }
+    EnglishForm { Finite, Participle, Infinitive }
+    EnglishDegree { Positive, Comparative, Superlative }
+    EnglishGender { Masculine, Feminine, Neuter }
+    EnglishCase { Nominative, Accusative, Reflexive, Possessive, PersonalPossessive }
+}
+
+impl From<EnglishNumber> for Number {
+    fn from(number: EnglishNumber) -> Self {
+        match number {
+            EnglishNumber::Singular => Number::Singular,
+            EnglishNumber::Plural => Number::Plural,
+        }
+    }
+}
+
+impl From<EnglishPerson> for Person {
+    fn from(person: EnglishPerson) -> Self {
+        match person {
+            EnglishPerson::First => Person::First,
+            EnglishPerson::Second => Person::Second,
+            EnglishPerson::Third => Person::Third,
+        }
+    }
+}
+
+impl From<EnglishTense> for Tense {
+    fn from(tense: EnglishTense) -> Self {
+        match tense {
+            EnglishTense::Present => Tense::Present,
+            EnglishTense::Past => Tense::Past,
+            EnglishTense::Future => Tense::Future,
+        }
+    }
+}
+
+impl From<EnglishForm> for Form {
+    fn from(form: EnglishForm) -> Self {
+        match form {
+            EnglishForm::Finite => Form::Finite,
+            EnglishForm::Participle => Form::Participle,
+            EnglishForm::Infinitive => Form::Infinitive,
+        }
+    }
+}
+
+impl From<EnglishDegree> for Degree {
+    fn from(degree: EnglishDegree) -> Self {
+        match degree {
+            EnglishDegree::Positive => Degree::Positive,
+            EnglishDegree::Comparative => Degree::Comparative,
+            EnglishDegree::Superlative => Degree::Superlative,
+        }
+    }
+}
+
+impl From<EnglishGender> for Gender {
+    fn from(gender: EnglishGender) -> Self {
+        match gender {
+            EnglishGender::Masculine => Gender::Masculine,
+            EnglishGender::Feminine => Gender::Feminine,
+            EnglishGender::Neuter => Gender::Neuter,
+        }
+    }
+}
+
+impl From<EnglishCase> for Case {
+    fn from(case: EnglishCase) -> Self {
+        match case {
+            EnglishCase::Nominative => Case::Nominative,
+            EnglishCase::Accusative => Case::Accusative,
+            EnglishCase::Reflexive => Case::Reflexive,
+            EnglishCase::Possessive => Case::Possessive,
+            EnglishCase::PersonalPossessive => Case::PersonalPossessive,
+        }
+    }
+}
+
+/// Inflects a noun for number, as `English::noun`. `number` is an
+/// `EnglishNumber`.
+///
+/// # Safety
+/// `word` must be null or a NUL-terminated string, and `buf` must be null or
+/// valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_noun(
+    word: *const c_char,
+    number: u32,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    let Ok(number) = EnglishNumber::try_from(number) else {
+        return unsafe { write_error(buf, len) };
+    };
+    unsafe {
+        write_word(word, buf, len, |out, word| {
+            English::write_noun(out, word, &number.into())
+        })
+    }
+}
+
+/// Conjugates a verb, as `English::verb`. `person`, `number`, `tense` and
+/// `form` are an `EnglishPerson`, `EnglishNumber`, `EnglishTense` and
+/// `EnglishForm`.
+///
+/// # Safety
+/// See `english_noun`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_verb(
+    word: *const c_char,
+    person: u32,
+    number: u32,
+    tense: u32,
+    form: u32,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    let (Ok(person), Ok(number), Ok(tense), Ok(form)) = (
+        EnglishPerson::try_from(person),
+        EnglishNumber::try_from(number),
+        EnglishTense::try_from(tense),
+        EnglishForm::try_from(form),
+    ) else {
+        return unsafe { write_error(buf, len) };
+    };
+    unsafe {
+        write_word(word, buf, len, |out, word| {
+            English::write_verb(
+                out,
+                word,
+                &person.into(),
+                &number.into(),
+                &tense.into(),
+                &form.into(),
+            )
+        })
+    }
+}
+
+/// Compares an adjective, as `English::adj`. `degree` is an
+/// `EnglishDegree`.
+///
+/// # Safety
+/// See `english_noun`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_adj(
+    word: *const c_char,
+    degree: u32,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    let Ok(degree) = EnglishDegree::try_from(degree) else {
+        return unsafe { write_error(buf, len) };
+    };
+    unsafe {
+        write_word(word, buf, len, |out, word| {
+            English::write_adj(out, word, &degree.into())
+        })
+    }
+}
+
+/// Writes a personal pronoun, as `English::pronoun`. `person`, `number`,
+/// `gender` and `pronoun_case` are an `EnglishPerson`, `EnglishNumber`,
+/// `EnglishGender` and `EnglishCase`.
+///
+/// # Safety
+/// `buf` must be null or valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_pronoun(
+    person: u32,
+    number: u32,
+    gender: u32,
+    pronoun_case: u32,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    let (Ok(person), Ok(number), Ok(gender), Ok(pronoun_case)) = (
+        EnglishPerson::try_from(person),
+        EnglishNumber::try_from(number),
+        EnglishGender::try_from(gender),
+        EnglishCase::try_from(pronoun_case),
+    ) else {
+        return unsafe { write_error(buf, len) };
+    };
+    let pronoun = English::pronoun(
+        &person.into(),
+        &number.into(),
+        &gender.into(),
+        &pronoun_case.into(),
+    );
+    unsafe { write_output(buf, len, |out| out.write_str(pronoun)) }
+}
+
+/// Adds a possessive suffix (`'s` or `'`), as `English::add_possessive`.
+///
+/// # Safety
+/// See `english_noun`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_add_possessive(
+    word: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    unsafe {
+        write_word(word, buf, len, |out, word| {
+            out.write_str(&English::add_possessive(word))
+        })
+    }
+}
+
+/// Writes a count followed by the noun inflected for it ("3 cats"), as
+/// `count_with_number`.
+///
+/// # Safety
+/// See `english_noun`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn english_count_with_number(
+    word: *const c_char,
+    amount: u32,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    unsafe {
+        write_word(word, buf, len, |out, word| {
+            out.write_str(&english::count_with_number(word, amount))
+        })
+    }
+}
+
+/// Reads `word` and writes what `f` makes of it to `buf`, or writes an empty
+/// string and returns [`ENGLISH_ERROR`] when `word` can't be read.
+unsafe fn write_word(
+    word: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+    f: impl FnOnce(&mut Output<'_>, &str) -> fmt::Result,
+) -> usize {
+    let word = if word.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(word) }.to_str().ok()
+    };
+    unsafe {
+        write_output(buf, len, |out| match word {
+            Some(word) => f(out, word),
+            None => Err(fmt::Error),
+        })
+    }
+}
+
+/// Writes an empty string to `buf` and returns [`ENGLISH_ERROR`].
+unsafe fn write_error(buf: *mut c_char, len: usize) -> usize {
+    unsafe { write_output(buf, len, |_| Err(fmt::Error)) }
+}
+
+unsafe fn write_output(
+    buf: *mut c_char,
+    len: usize,
+    f: impl FnOnce(&mut Output<'_>) -> fmt::Result,
+) -> usize {
+    // The caller's buffer may be uninitialized, so it is never read or
+    // viewed as bytes.
+    let buf: &mut [MaybeUninit<u8>] = if buf.is_null() || len == 0 {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(buf.cast(), len) }
+    };
+    let mut out = Output::new(buf);
+    let result = f(&mut out);
+    out.finish(result)
+}
+
+/// A caller's buffer, filled up to the byte before its end and counting
+/// what didn't fit.
+struct Output<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    written: usize,
+    len: usize,
+}
+
+impl<'a> Output<'a> {
+    fn new(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        Output {
+            buf,
+            written: 0,
+            len: 0,
+        }
+    }
+
+    fn finish(mut self, result: fmt::Result) -> usize {
+        if result.is_err() {
+            self.written = 0;
+            self.len = ENGLISH_ERROR;
+        }
+        if let Some(end) = self.buf.get_mut(self.written) {
+            end.write(0);
+        }
+        self.len
+    }
+}
+
+impl Write for Output<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Once a character has been left out, nothing after it may follow.
+        if self.written == self.len {
+            let room = self.buf.len().saturating_sub(self.written + 1);
+            let mut end = s.len().min(room);
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            let dest = &mut self.buf[self.written..self.written + end];
+            for (dest, byte) in dest.iter_mut().zip(s.as_bytes()) {
+                dest.write(*byte);
+            }
+            self.written += end;
+        }
+        self.len += s.len();
+        Ok(())
+    }
+}
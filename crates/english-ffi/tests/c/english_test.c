@@ -0,0 +1,100 @@
+/* Calls every function of english.h and checks what comes back. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "english.h"
+
+static int failures = 0;
+
+static void check(const char *what, size_t len, const char *buf, const char *expected) {
+    if (len != strlen(expected) || strcmp(buf, expected) != 0) {
+        fprintf(stderr, "%s: got \"%s\" (%zu), expected \"%s\"\n", what, buf, len, expected);
+        failures++;
+    }
+}
+
+int main(void) {
+    char buf[64];
+    size_t len;
+
+    len = english_noun("child", ENGLISH_NUMBER_PLURAL, buf, sizeof buf);
+    check("noun", len, buf, "children");
+    len = english_noun("Mother-in-law", ENGLISH_NUMBER_PLURAL, buf, sizeof buf);
+    check("capitalized noun", len, buf, "Mothers-in-law");
+
+    len = english_verb("swim", ENGLISH_PERSON_THIRD, ENGLISH_NUMBER_SINGULAR, ENGLISH_TENSE_PAST,
+                       ENGLISH_FORM_FINITE, buf, sizeof buf);
+    check("verb", len, buf, "swam");
+    len = english_verb("be", ENGLISH_PERSON_FIRST, ENGLISH_NUMBER_SINGULAR, ENGLISH_TENSE_PRESENT,
+                       ENGLISH_FORM_FINITE, buf, sizeof buf);
+    check("be", len, buf, "am");
+
+    len = english_adj("bad", ENGLISH_DEGREE_SUPERLATIVE, buf, sizeof buf);
+    check("adj", len, buf, "worst");
+
+    len = english_pronoun(ENGLISH_PERSON_FIRST, ENGLISH_NUMBER_SINGULAR, ENGLISH_GENDER_NEUTER,
+                          ENGLISH_CASE_PERSONAL_POSSESSIVE, buf, sizeof buf);
+    check("pronoun", len, buf, "my");
+
+    len = english_add_possessive("dogs", buf, sizeof buf);
+    check("possessive", len, buf, "dogs'");
+
+    len = english_count_with_number("potato", 7, buf, sizeof buf);
+    check("count", len, buf, "7 potatoes");
+
+    /* Ask for the length first, then fill a buffer of exactly that size. */
+    len = english_noun("mother-in-law", ENGLISH_NUMBER_PLURAL, NULL, 0);
+    if (len != strlen("mothers-in-law")) {
+        fprintf(stderr, "length query: got %zu\n", len);
+        failures++;
+    } else {
+        char exact[sizeof "mothers-in-law"];
+        len = english_noun("mother-in-law", ENGLISH_NUMBER_PLURAL, exact, len + 1);
+        check("exact buffer", len, exact, "mothers-in-law");
+    }
+
+    /* Output that doesn't fit is cut short but still terminated. */
+    char small[5];
+    len = english_count_with_number("potato", 7, small, sizeof small);
+    if (len != strlen("7 potatoes") || strcmp(small, "7 po") != 0) {
+        fprintf(stderr, "truncation: got \"%s\" (%zu)\n", small, len);
+        failures++;
+    }
+
+    /* Characters are never split: "café" has a two byte "é". */
+    len = english_noun("café", ENGLISH_NUMBER_PLURAL, small, sizeof small);
+    if (len != strlen("cafés") || strcmp(small, "caf") != 0) {
+        fprintf(stderr, "character boundary: got \"%s\" (%zu)\n", small, len);
+        failures++;
+    }
+
+    len = english_noun(NULL, ENGLISH_NUMBER_PLURAL, buf, sizeof buf);
+    if (len != ENGLISH_ERROR || buf[0] != '\0') {
+        fprintf(stderr, "null word: got \"%s\" (%zu)\n", buf, len);
+        failures++;
+    }
+    len = english_noun("\xff", ENGLISH_NUMBER_PLURAL, buf, sizeof buf);
+    if (len != ENGLISH_ERROR || buf[0] != '\0') {
+        fprintf(stderr, "invalid UTF-8: got \"%s\" (%zu)\n", buf, len);
+        failures++;
+    }
+
+    /* A category outside its enum is refused rather than trusted. */
+    len = english_noun("cat", 7, buf, sizeof buf);
+    if (len != ENGLISH_ERROR || buf[0] != '\0') {
+        fprintf(stderr, "out-of-range number: got \"%s\" (%zu)\n", buf, len);
+        failures++;
+    }
+    len = english_pronoun(ENGLISH_PERSON_FIRST, ENGLISH_NUMBER_SINGULAR, ENGLISH_GENDER_NEUTER, 5,
+                          buf, sizeof buf);
+    if (len != ENGLISH_ERROR || buf[0] != '\0') {
+        fprintf(stderr, "out-of-range case: got \"%s\" (%zu)\n", buf, len);
+        failures++;
+    }
+
+    if (failures == 0) {
+        puts("ok");
+    }
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,63 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = fs::read_to_string(concat!(env!("OUT_DIR"), "/english.h")).unwrap();
+    let checked_in = Path::new(MANIFEST_DIR).join("include/english.h");
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&checked_in, &generated).unwrap();
+    }
+    assert!(
+        fs::read_to_string(&checked_in).unwrap_or_default() == generated,
+        "include/english.h is stale; rerun with UPDATE_HEADER=1 to regenerate it"
+    );
+}
+
+/// Compiles `tests/c/english_test.c` against the header and the shared
+/// library, and runs it.
+#[cfg(unix)]
+#[test]
+fn c_program_runs() {
+    // Integration tests run from `target/<profile>/deps`, where cargo also
+    // leaves the shared library it built for them. It is only copied one
+    // level up when the library itself is built, so don't look there.
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("english_test");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(cc)
+        .arg("-std=c99")
+        .args(["-Wall", "-Wextra", "-Werror"])
+        .arg("-I")
+        .arg(Path::new(MANIFEST_DIR).join("include"))
+        .arg(Path::new(MANIFEST_DIR).join("tests/c/english_test.c"))
+        .arg("-o")
+        .arg(&out)
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lenglish_ffi")
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "compiling english_test.c failed");
+
+    // Cargo puts `target/<profile>` on the library path, which outranks the
+    // rpath and may hold a copy of the library from an earlier build.
+    let output = Command::new(&out)
+        .env("LD_LIBRARY_PATH", lib_dir)
+        .env("DYLD_LIBRARY_PATH", lib_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "english_test failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
@@ -8,6 +8,12 @@ fn main() {
         "generated/noun_phf.rs",
         "generated/adj_phf.rs",
         "generated/verb_phf.rs",
+        "generated/noun_trie.rs",
+        "generated/adj_trie.rs",
+        "generated/verb_trie.rs",
+        "generated/dialect_phf.rs",
+        "generated/variant_phf.rs",
+        "generated/sense_phf.rs",
     ] {
         let absolute_path = manifest_dir.join(relative_path);
         println!("cargo:rerun-if-changed={}", absolute_path.display());
@@ -5,6 +5,7 @@ fn main() {
     benchmark_verb();
     benchmark_noun();
     benchmark_adj();
+    benchmark_noun_buffer();
 }
 use std::hint::black_box;
 use std::time::Instant;
@@ -57,6 +58,30 @@ pub fn benchmark_adj() {
     });
 }
 
+/// Same words as `benchmark_noun`, appended to one reused buffer.
+pub fn benchmark_noun_buffer() {
+    let words = ["zzzzzzzzzzzzzzzzz", "child"];
+    let iterations = 1_000_000;
+    let total_calls = iterations * words.len();
+    let mut buffer = String::new();
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        for &word in &words {
+            buffer.clear();
+            English::write_noun(&mut buffer, black_box(word), &Number::Plural).unwrap();
+            black_box(&buffer);
+        }
+    }
+
+    let nanos = start.elapsed().as_nanos() as f64;
+    println!("[noun buffer] Last result: {buffer}");
+    println!(
+        "[noun buffer] Time per call: {:.2} ns",
+        nanos / total_calls as f64
+    );
+}
+
 fn run_benchmark<F>(label: &str, words: &[&str], mut f: F)
 where
     F: FnMut(&str) -> String,
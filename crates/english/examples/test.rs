@@ -107,8 +107,8 @@ fn main() {
 
     // --- Adjectives ---
     // Add a number 2-9 to the end of the word to try different forms. (Bad has the most forms at 3)
-    assert_eq!(English::adj("bad", &Degree::Comparative), "more bad");
-    assert_eq!(English::adj("bad", &Degree::Superlative), "most bad");
+    assert_eq!(English::adj("bad", &Degree::Comparative), "worse");
+    assert_eq!(English::adj("bad", &Degree::Superlative), "worst");
     assert_eq!(English::adj("bad2", &Degree::Comparative), "badder");
     assert_eq!(English::adj("bad2", &Degree::Superlative), "baddest");
     assert_eq!(English::adj("bad3", &Degree::Comparative), "worse");
@@ -121,7 +121,7 @@ fn main() {
             &Person::First,
             &Number::Singular,
             &Gender::Neuter,
-            &Case::PersonalPossesive
+            &Case::PersonalPossessive
         ),
         "my"
     );
@@ -1,2937 +1,2933 @@
 use phf::phf_map;
 
 /// (comparative, superlative)
-pub static ADJECTIVE_MAP: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
-    "abgeschmackt" => ("abgeschmackter", "abgeschmacktest"),
-    "abject" => ("abjecter", "abjectest"),
-    "able" => ("abler", "ablest"),
-    "abrupt" => ("abrupter", "abruptest"),
-    "absent" => ("absenter", "absentest"),
-    "absolute" => ("absoluter", "absolutest"),
-    "abstract" => ("abstracter", "abstractest"),
-    "abstruse" => ("abstruser", "abstrusest"),
-    "absurd" => ("absurder", "absurdest"),
-    "acerb" => ("acerber", "acerbest"),
-    "achey" => ("achier", "achiest"),
-    "achy" => ("achier", "achiest"),
-    "acid" => ("acider", "acidest"),
-    "acrid" => ("acrider", "acridest"),
-    "acute" => ("acuter", "acutest"),
-    "adept" => ("adepter", "adeptest"),
-    "adroit" => ("adroiter", "adroitest"),
-    "adulty" => ("adultier", "adultiest"),
-    "aery" => ("aerier", "aeriest"),
-    "agile2" => ("agiler", "agilest"),
-    "ail" => ("ailer", "ailest"),
-    "airworthy" => ("airworthier", "airworthiest"),
-    "airy" => ("airier", "airiest"),
-    "almondy" => ("almondier", "almondiest"),
-    "alonely" => ("alonelier", "aloneliest"),
-    "ambitious" => ("ambitiouser", "ambitiousest"),
-    "ample" => ("ampler", "amplest"),
-    "amusing" => ("amusinger", "amusingest"),
-    "ancient" => ("ancienter", "ancientest"),
-    "angry" => ("angrier", "angriest"),
-    "angsty" => ("angstier", "angstiest"),
-    "antient" => ("antienter", "antientest"),
-    "antique" => ("antiquer", "antiquest"),
-    "antly" => ("antlier", "antliest"),
-    "antsy" => ("antsier", "antsiest"),
-    "anxious" => ("anxiouser", "anxiousest"),
-    "apt" => ("apter", "aptest"),
-    "arch2" => ("archer", "archest"),
-    "archpriestly" => ("archpriestlier", "archpriestliest"),
-    "arid" => ("arider", "aridest"),
-    "arm2" => ("armer", "armest"),
-    "arrant" => ("arranter", "arrantest"),
-    "arsy" => ("arsier", "arsiest"),
-    "artsie" => ("artsier", "artsiest"),
-    "artsy" => ("artsier", "artsiest"),
-    "arty" => ("artier", "artiest"),
-    "ashy" => ("ashier", "ashiest"),
-    "assy" => ("assier", "assiest"),
-    "astute" => ("astuter", "astutest"),
-    "august" => ("auguster", "augustest"),
-    "auld" => ("aulder", "auldest"),
-    "auncient" => ("auncienter", "auncientest"),
-    "auntient" => ("auntienter", "auntientest"),
-    "auntly" => ("auntlier", "auntliest"),
-    "austere" => ("austerer", "austerest"),
-    "avid" => ("avider", "avidest"),
-    "aware" => ("awarer", "awarest"),
-    "awesome" => ("awesomer", "awesomest"),
-    "awful" => ("awfuller", "awfullest"),
-    "awnry" => ("awnrier", "awnriest"),
-    "babbly" => ("babblier", "babbliest"),
-    "baby" => ("babier", "babiest"),
-    "backstabby" => ("backstabbier", "backstabbiest"),
-    "bad2" => ("badder", "baddest"),
-    "bad3" => ("worse", "worst"),
-    "badassy" => ("badassier", "badassiest"),
-    "baggy" => ("baggier", "baggiest"),
-    "bait" => ("baiter", "baitest"),
-    "baity" => ("baitier", "baitiest"),
-    "bald" => ("balder", "baldest"),
-    "balky" => ("balkier", "balkiest"),
-    "balloony" => ("balloonier", "ballooniest"),
-    "ballsy" => ("ballsier", "ballsiest"),
-    "balmy" => ("balmier", "balmiest"),
-    "bammy" => ("bammier", "bammiest"),
-    "banal" => ("banaler", "banalest"),
-    "bandy" => ("bandier", "bandiest"),
-    "bangy" => ("bangier", "bangiest"),
-    "bare" => ("barer", "barest"),
-    "barky" => ("barkier", "barkiest"),
-    "barmy" => ("barmier", "barmiest"),
-    "barny" => ("barnier", "barniest"),
-    "baroque2" => ("baroquer", "baroquest"),
-    "barren" => ("barrener", "barrenest"),
-    "base" => ("baser", "basest"),
-    "bashy" => ("bashier", "bashiest"),
-    "bass" => ("basser", "bassest"),
-    "bassy" => ("bassier", "bassiest"),
-    "batty" => ("battier", "battiest"),
-    "bawdy" => ("bawdier", "bawdiest"),
-    "bay" => ("bayer", "bayest"),
-    "beachy" => ("beachier", "beachiest"),
-    "beady" => ("beadier", "beadiest"),
-    "beaky" => ("beakier", "beakiest"),
-    "beamy" => ("beamier", "beamiest"),
-    "beany" => ("beanier", "beaniest"),
-    "beardy" => ("beardier", "beardiest"),
-    "beary" => ("bearier", "beariest"),
-    "beastly" => ("beastlier", "beastliest"),
-    "beaut" => ("beauter", "beautest"),
-    "beefy" => ("beefier", "beefiest"),
-    "beeg" => ("beeger", "beegest"),
-    "beepy" => ("beepier", "beepiest"),
-    "beery" => ("beerier", "beeriest"),
-    "beggarly" => ("beggarlier", "beggarliest"),
-    "beige" => ("beiger", "beigest"),
-    "beigey" => ("beigier", "beigiest"),
-    "beigy" => ("beigier", "beigiest"),
-    "beloved" => ("beloveder", "belovedest"),
-    "ben" => ("benar", "benat"),
-    "ben2" => ("benner", "benmost"),
-    "bendly" => ("bendlier", "bendliest"),
-    "bendy2" => ("bendier", "bendiest"),
-    "bene" => ("benar", "benat"),
-    "benign" => ("benigner", "benignest"),
-    "bent" => ("benter", "bentest"),
-    "big" => ("bigger", "biggest"),
-    "bilgy" => ("bilgier", "bilgiest"),
-    "billowy" => ("billowier", "billowiest"),
-    "birsy" => ("birsier", "birsiest"),
-    "bitchin" => ("more bitchin", "bitchinest"),
-    "bitching" => ("more bitching", "bitchingest"),
-    "bitchly" => ("bitchlier", "bitchliest"),
-    "bitchy" => ("bitchier", "bitchiest"),
-    "bitey" => ("bitier", "bitiest"),
-    "bitsy" => ("bitsier", "bitsiest"),
-    "bitter" => ("bitterer", "bitterest"),
-    "bittie" => ("bittier", "bittiest"),
-    "bitty" => ("bittier", "bittiest"),
-    "bizarre" => ("bizarrer", "bizarrest"),
-    "blabby" => ("blabbier", "blabbiest"),
-    "black2" => ("blacker", "blackest"),
-    "blak2" => ("blakker", "blakkest"),
-    "blake" => ("blaker", "blakest"),
-    "bland" => ("blander", "blandest"),
-    "blank" => ("blanker", "blankest"),
-    "blashy" => ("blashier", "blashiest"),
-    "blasty" => ("blastier", "blastiest"),
-    "blate" => ("blater", "blatest"),
-    "bleach" => ("bleacher", "bleachest"),
-    "bleachy" => ("bleachier", "bleachiest"),
-    "bleak" => ("bleaker", "bleakest"),
-    "bleary" => ("blearier", "bleariest"),
-    "bleedy" => ("bleedier", "bleediest"),
-    "bleepy" => ("bleepier", "bleepiest"),
-    "blimpy" => ("blimpier", "blimpiest"),
-    "blind" => ("blinder", "blindest"),
-    "blingy" => ("blingier", "blingiest"),
-    "blippy" => ("blippier", "blippiest"),
-    "blirty" => ("blirtier", "blirtiest"),
-    "blissy" => ("blissier", "blissiest"),
-    "blithe" => ("blither", "blithest"),
-    "bloaty" => ("bloatier", "bloatiest"),
-    "blobby" => ("blobbier", "blobbiest"),
-    "blocky" => ("blockier", "blockiest"),
-    "bloggy" => ("bloggier", "bloggiest"),
-    "blogworthy" => ("blogworthier", "blogworthiest"),
-    "blokey" => ("blokier", "blokiest"),
-    "blond" => ("blonder", "blondest"),
-    "blonde" => ("blonder", "blondest"),
-    "bloodthirsty" => ("bloodthirstier", "bloodthirstiest"),
-    "bloody" => ("bloodier", "bloodiest"),
-    "bloomly" => ("bloomlier", "bloomliest"),
-    "blossomy" => ("blossomier", "blossomiest"),
-    "blotchy" => ("blotchier", "blotchiest"),
-    "blotty" => ("blottier", "blottiest"),
-    "blousy" => ("blousier", "blousiest"),
-    "blouzy" => ("blouzier", "blouziest"),
-    "blow" => ("blower", "blowest"),
-    "blowsey" => ("blowsier", "blowsiest"),
-    "blowsy" => ("blowsier", "blowsiest"),
-    "blowy" => ("blowier", "blowiest"),
-    "blowzy" => ("blowzier", "blowziest"),
-    "blue" => ("bluer", "bluest"),
-    "blueberry" => ("blueberrier", "blueberriest"),
-    "bluesy" => ("bluesier", "bluesiest"),
-    "bluey" => ("bluier", "bluiest"),
-    "bluff" => ("bluffer", "bluffest"),
-    "bluggy" => ("bluggier", "bluggiest"),
-    "blunt" => ("blunter", "bluntest"),
-    "blurry" => ("blurrier", "blurriest"),
-    "blushy" => ("blushier", "blushiest"),
-    "boardy" => ("boardier", "boardiest"),
-    "bobbly" => ("bobblier", "bobbliest"),
-    "bog" => ("bogger", "boggest"),
-    "boggy" => ("boggier", "boggiest"),
-    "bold2" => ("bolder", "boldest"),
-    "bolshie" => ("bolshier", "bolshiest"),
-    "bolshy" => ("bolshier", "bolshiest"),
-    "boney" => ("bonier", "boniest"),
-    "bonnie" => ("bonnier", "bonniest"),
-    "bonny" => ("bonnier", "bonniest"),
-    "bony" => ("bonier", "boniest"),
-    "booby2" => ("boobier", "boobiest"),
-    "boofy" => ("boofier", "boofiest"),
-    "bookey" => ("bookier", "bookiest"),
-    "bookly" => ("booklier", "bookliest"),
-    "booky" => ("bookier", "bookiest"),
-    "boomy" => ("boomier", "boomiest"),
-    "boon" => ("booner", "boonest"),
-    "boosy" => ("boosier", "boosiest"),
-    "bootlicky" => ("bootlickier", "bootlickiest"),
-    "boozy" => ("boozier", "booziest"),
-    "boppy" => ("boppier", "boppiest"),
-    "bored" => ("boreder", "boredest"),
-    "boring" => ("boringer", "boringest"),
-    "bosky" => ("boskier", "boskiest"),
-    "bosomy" => ("bosomier", "bosomiest"),
-    "boss" => ("bosser", "bossest"),
-    "bossy2" => ("bossier", "bossiest"),
-    "bougie" => ("bougier", "bougiest"),
-    "bould" => ("boulder", "bouldest"),
-    "bouncy" => ("bouncier", "bounciest"),
-    "bowery" => ("bowerier", "boweriest"),
-    "bowsy" => ("bowsier", "bowsiest"),
-    "boxy" => ("boxier", "boxiest"),
-    "boyey" => ("boyier", "boyiest"),
-    "brack" => ("bracker", "brackest"),
-    "brag" => ("bragger", "braggest"),
-    "braggy" => ("braggier", "braggiest"),
-    "brainy" => ("brainier", "brainiest"),
-    "braky" => ("brakier", "brakiest"),
-    "brambly" => ("bramblier", "brambliest"),
-    "branchy" => ("branchier", "branchiest"),
-    "branny" => ("brannier", "branniest"),
-    "brash" => ("brasher", "brashest"),
-    "brassy" => ("brassier", "brassiest"),
-    "brath" => ("brather", "brathest"),
-    "bratty" => ("brattier", "brattiest"),
-    "brave" => ("braver", "bravest"),
-    "brawny" => ("brawnier", "brawniest"),
-    "brazen" => ("brazener", "brazenest"),
-    "bready" => ("breadier", "breadiest"),
-    "breaky" => ("breakier", "breakiest"),
-    "breasty" => ("breastier", "breastiest"),
-    "breathly" => ("breathlier", "breathliest"),
-    "breathy" => ("breathier", "breathiest"),
-    "breezey" => ("breezier", "breeziest"),
-    "breezy" => ("breezier", "breeziest"),
-    "bricky" => ("brickier", "brickiest"),
-    "brief" => ("briefer", "briefest"),
-    "bright" => ("brighter", "brightest"),
-    "briny" => ("brinier", "briniest"),
-    "brisk" => ("brisker", "briskest"),
-    "bristly" => ("bristlier", "bristliest"),
-    "brittle" => ("brittler", "brittlest"),
-    "broad" => ("broader", "broadest"),
-    "broey" => ("broier", "broiest"),
-    "broke2" => ("broker", "brokest"),
-    "bronzy" => ("bronzier", "bronziest"),
-    "broody" => ("broodier", "broodiest"),
-    "brothy" => ("brothier", "brothiest"),
-    "brown2" => ("browner", "brownest"),
-    "brummie" => ("brummier", "brummiest"),
-    "brummy" => ("brummier", "brummiest"),
-    "brushy" => ("brushier", "brushiest"),
-    "brusk" => ("brusker", "bruskest"),
-    "brusque" => ("brusquer", "brusquest"),
-    "bubbly" => ("bubblier", "bubbliest"),
-    "buff" => ("buffer", "buffest"),
-    "buffy" => ("buffier", "buffiest"),
-    "buggy" => ("buggier", "buggiest"),
-    "bugly" => ("buglier", "bugliest"),
-    "bugsy" => ("bugsier", "bugsiest"),
-    "buirdly" => ("buirdlier", "buirdliest"),
-    "bulgy" => ("bulgier", "bulgiest"),
-    "bulky" => ("bulkier", "bulkiest"),
-    "bullshitty" => ("bullshittier", "bullshittiest"),
-    "bully" => ("bullier", "bulliest"),
-    "bum" => ("bummer", "bummest"),
-    "bummy" => ("bummier", "bummiest"),
-    "bumpy" => ("bumpier", "bumpiest"),
-    "bunchy" => ("bunchier", "bunchiest"),
-    "bunny" => ("bunnier", "bunniest"),
-    "burbly" => ("burblier", "burbliest"),
-    "burly2" => ("burlier", "burliest"),
-    "burry" => ("burrier", "burriest"),
-    "bursty" => ("burstier", "burstiest"),
-    "bushy" => ("bushier", "bushiest"),
-    "bussy2" => ("bussier", "bussiest"),
-    "busty" => ("bustier", "bustiest"),
-    "busy" => ("busier", "busiest"),
-    "butch" => ("butcher", "butchest"),
-    "butchy" => ("butchier", "butchiest"),
-    "buttery" => ("butterier", "butteriest"),
-    "buxom" => ("buxomer", "buxomest"),
-    "buzzy" => ("buzzier", "buzziest"),
-    "cacky2" => ("cackier", "cackiest"),
-    "cagey" => ("cagier", "cagiest"),
-    "cagy" => ("cagier", "cagiest"),
-    "cakey" => ("cakier", "cakiest"),
-    "caky" => ("cakier", "cakiest"),
-    "callow2" => ("callower", "callowest"),
-    "calm" => ("calmer", "calmest"),
-    "camp" => ("camper", "campest"),
-    "campy" => ("campier", "campiest"),
-    "candid" => ("candider", "candidest"),
-    "canny2" => ("cannier", "canniest"),
-    "canty" => ("cantier", "cantiest"),
-    "carty" => ("cartier", "cartiest"),
-    "castworthy" => ("castworthier", "castworthiest"),
-    "catchy" => ("catchier", "catchiest"),
-    "catty" => ("cattier", "cattiest"),
-    "central" => ("more central", "centralmost"),
-    "certain" => ("certainer", "certainest"),
-    "chaffy" => ("chaffier", "chaffiest"),
-    "chalky" => ("chalkier", "chalkiest"),
-    "chancy" => ("chancier", "chanciest"),
-    "charry" => ("charrier", "charriest"),
-    "chary" => ("charier", "chariest"),
-    "chaste" => ("chaster", "chastest"),
-    "chatty" => ("chattier", "chattiest"),
-    "chavvie" => ("chavvier", "chavviest"),
-    "chavvy" => ("chavvier", "chavviest"),
-    "cheap" => ("cheaper", "cheapest"),
-    "cheaty" => ("cheatier", "cheatiest"),
-    "cheeky" => ("cheekier", "cheekiest"),
-    "cheepy" => ("cheepier", "cheepiest"),
-    "cheerful" => ("cheerfuler", "cheerfulest"),
-    "cheerly" => ("cheerlier", "cheerliest"),
-    "cheery" => ("cheerier", "cheeriest"),
-    "cheesy" => ("cheesier", "cheesiest"),
-    "cheffy" => ("cheffier", "cheffiest"),
-    "cherry" => ("cherrier", "cherriest"),
-    "cherty" => ("chertier", "chertiest"),
-    "chesty" => ("chestier", "chestiest"),
-    "cheugy" => ("cheugier", "cheugiest"),
-    "chewy" => ("chewier", "chewiest"),
-    "chic" => ("chicer", "chicest"),
-    "chickeny" => ("chickenier", "chickeniest"),
-    "chief" => ("chiefer", "chiefest"),
-    "chiefly" => ("chieflier", "chiefliest"),
-    "childly" => ("childlier", "childliest"),
-    "chilly" => ("chillier", "chilliest"),
-    "chinchy" => ("chinchier", "chinchiest"),
-    "chincy" => ("chincier", "chinciest"),
-    "chinky2" => ("chinkier", "chinkiest"),
-    "chinny" => ("chinnier", "chinniest"),
-    "chintzy" => ("chintzier", "chintziest"),
-    "chipper" => ("chipperer", "chipperest"),
-    "chippy" => ("chippier", "chippiest"),
-    "chirk" => ("chirker", "chirkest"),
-    "chirky" => ("chirkier", "chirkiest"),
-    "chirpy" => ("chirpier", "chirpiest"),
-    "chocolatey" => ("chocolatier", "chocolatiest"),
-    "chocolaty" => ("chocolatier", "chocolatiest"),
-    "choice" => ("choicer", "choicest"),
-    "choiceworthy" => ("choiceworthier", "choiceworthiest"),
-    "choicy" => ("choicier", "choiciest"),
-    "choise" => ("choiser", "choisest"),
-    "choky" => ("chokier", "chokiest"),
-    "chonky" => ("chonkier", "chonkiest"),
-    "choong" => ("choonger", "choongest"),
-    "choosy" => ("choosier", "choosiest"),
-    "choppy" => ("choppier", "choppiest"),
-    "choyse" => ("choyser", "choysest"),
-    "christmassy" => ("christmassier", "christmassiest"),
-    "chromy" => ("chromier", "chromiest"),
-    "chubby" => ("chubbier", "chubbiest"),
-    "chuffy" => ("chuffier", "chuffiest"),
-    "chummy" => ("chummier", "chummiest"),
-    "chumpy" => ("chumpier", "chumpiest"),
-    "chunky" => ("chunkier", "chunkiest"),
-    "churchly" => ("churchlier", "churchliest"),
-    "churchy" => ("churchier", "churchiest"),
-    "churly" => ("churlier", "churliest"),
-    "churny" => ("churnier", "churniest"),
-    "civil" => ("civiler", "civilest"),
-    "claggy" => ("claggier", "claggiest"),
-    "clam" => ("clammer", "clammest"),
-    "clammy" => ("clammier", "clammiest"),
-    "clangy" => ("clangier", "clangiest"),
-    "clanky" => ("clankier", "clankiest"),
-    "clarty" => ("clartier", "clartiest"),
-    "clashy" => ("clashier", "clashiest"),
-    "classy" => ("classier", "classiest"),
-    "clatty" => ("clattier", "clattiest"),
-    "clawy" => ("clawier", "clawiest"),
-    "clayey" => ("clayier", "clayiest"),
-    "clean" => ("cleaner", "cleanest"),
-    "cleanly" => ("cleanlier", "cleanliest"),
-    "clear" => ("clearer", "clearest"),
-    "cleer" => ("cleerer", "cleerest"),
-    "clerkly" => ("clerklier", "clerkliest"),
-    "clever" => ("cleverer", "cleverest"),
-    "clickbaity" => ("clickbaitier", "clickbaitiest"),
-    "clicky" => ("clickier", "clickiest"),
-    "cliffy" => ("cliffier", "cliffiest"),
-    "climby" => ("climbier", "climbiest"),
-    "clingy" => ("clingier", "clingiest"),
-    "clinky" => ("clinkier", "clinkiest"),
-    "cliquey" => ("cliquier", "cliquiest"),
-    "cliquy" => ("cliquier", "cliquiest"),
-    "clocky" => ("clockier", "clockiest"),
-    "cloddy" => ("cloddier", "cloddiest"),
-    "cloggy" => ("cloggier", "cloggiest"),
-    "close" => ("closer", "closest"),
-    "cloudly" => ("cloudlier", "cloudliest"),
-    "cloudy" => ("cloudier", "cloudiest"),
-    "clowdy" => ("clowdier", "clowdiest"),
-    "clowny" => ("clownier", "clowniest"),
-    "clubby" => ("clubbier", "clubbiest"),
-    "clucky" => ("cluckier", "cluckiest"),
-    "clumpy" => ("clumpier", "clumpiest"),
-    "clumsy" => ("clumsier", "clumsiest"),
-    "clunky" => ("clunkier", "clunkiest"),
-    "coachy" => ("coachier", "coachiest"),
-    "coaly" => ("coalier", "coaliest"),
-    "coarse" => ("coarser", "coarsest"),
-    "cobby" => ("cobbier", "cobbiest"),
-    "cobwebby" => ("cobwebbier", "cobwebbiest"),
-    "cockey" => ("cockier", "cockiest"),
-    "cockie" => ("cockier", "cockiest"),
-    "cocky" => ("cockier", "cockiest"),
-    "coconutty" => ("coconuttier", "coconuttiest"),
-    "codgy" => ("codgier", "codgiest"),
-    "coily" => ("coilier", "coiliest"),
-    "coiny" => ("coinier", "coiniest"),
-    "cold" => ("colder", "coldest"),
-    "colly" => ("collier", "colliest"),
-    "comely" => ("comelier", "comeliest"),
-    "comfortable" => ("comfortabler", "comfortablest"),
-    "comfy" => ("comfier", "comfiest"),
-    "comly" => ("comlier", "comliest"),
-    "common" => ("commoner", "commonest"),
-    "compact2" => ("compacter", "compactest"),
-    "complete" => ("completer", "completest"),
-    "concrete" => ("concreter", "concretest"),
-    "content2" => ("contenter", "contentest"),
-    "cool" => ("cooler", "coolest"),
-    "cooool" => ("cooooler", "coooolest"),
-    "cooty" => ("cootier", "cootiest"),
-    "corky" => ("corkier", "corkiest"),
-    "corny2" => ("cornier", "corniest"),
-    "cosie" => ("cosier", "cosiest"),
-    "costly" => ("costlier", "costliest"),
-    "cosy" => ("cosier", "cosiest"),
-    "couchy" => ("couchier", "couchiest"),
-    "coughy" => ("coughier", "coughiest"),
-    "country" => ("countrier", "countriest"),
-    "courtly" => ("courtlier", "courtliest"),
-    "couthie" => ("couthier", "couthiest"),
-    "cowardly" => ("cowardlier", "cowardliest"),
-    "cowy" => ("cowier", "cowiest"),
-    "coxy" => ("coxier", "coxiest"),
-    "coy" => ("coyer", "coyest"),
-    "cozie" => ("cozier", "coziest"),
-    "cozy" => ("cozier", "coziest"),
-    "crabby" => ("crabbier", "crabbiest"),
-    "crackly" => ("cracklier", "crackliest"),
-    "crackpotty" => ("crackpottier", "crackpottiest"),
-    "cracky" => ("crackier", "crackiest"),
-    "crafty" => ("craftier", "craftiest"),
-    "craggy" => ("craggier", "craggiest"),
-    "crampy" => ("crampier", "crampiest"),
-    "crank" => ("cranker", "crankest"),
-    "cranky" => ("crankier", "crankiest"),
-    "crap" => ("crapper", "crappest"),
-    "crapey" => ("crapier", "crapiest"),
-    "crappy" => ("crappier", "crappiest"),
-    "crapy" => ("crapier", "crapiest"),
-    "crashy" => ("crashier", "crashiest"),
-    "crass" => ("crasser", "crassest"),
-    "crawly" => ("crawlier", "crawliest"),
-    "crazy" => ("crazier", "craziest"),
-    "creaky" => ("creakier", "creakiest"),
-    "creamy" => ("creamier", "creamiest"),
-    "creasy" => ("creasier", "creasiest"),
-    "creeky" => ("creekier", "creekiest"),
-    "creepy" => ("creepier", "creepiest"),
-    "crepy" => ("crepier", "crepiest"),
-    "crimpy" => ("crimpier", "crimpiest"),
-    "cringeworthy" => ("cringeworthier", "cringeworthiest"),
-    "cringey" => ("cringier", "cringiest"),
-    "cringy" => ("cringier", "cringiest"),
-    "crinkly" => ("crinklier", "crinkliest"),
-    "crisp" => ("crisper", "crispest"),
-    "crispy" => ("crispier", "crispiest"),
-    "croaky" => ("croakier", "croakiest"),
-    "crook" => ("crooker", "crookest"),
-    "crool" => ("crooler", "croolest"),
-    "cross" => ("crosser", "crossest"),
-    "crotchety" => ("crotchetier", "crotchetiest"),
-    "crouchy" => ("crouchier", "crouchiest"),
-    "croupy" => ("croupier", "croupiest"),
-    "crowdy" => ("crowdier", "crowdiest"),
-    "cruddy" => ("cruddier", "cruddiest"),
-    "crude" => ("cruder", "crudest"),
-    "cruel" => ("crueler", "cruelest"),
-    "crufty" => ("cruftier", "cruftiest"),
-    "cruisy" => ("cruisier", "cruisiest"),
-    "crumbly" => ("crumblier", "crumbliest"),
-    "crumby" => ("crumbier", "crumbiest"),
-    "crummy" => ("crummier", "crummiest"),
-    "crumply" => ("crumplier", "crumpliest"),
-    "crunchy" => ("crunchier", "crunchiest"),
-    "crunk" => ("crunker", "crunkest"),
-    "crusty" => ("crustier", "crustiest"),
-    "cryey" => ("cryier", "cryiest"),
-    "cuddly" => ("cuddlier", "cuddliest"),
-    "cunty" => ("cuntier", "cuntiest"),
-    "cuppy" => ("cuppier", "cuppiest"),
-    "curdy" => ("curdier", "curdiest"),
-    "curious2" => ("curiouser", "curiousest"),
-    "curly" => ("curlier", "curliest"),
-    "curmudgeonly" => ("curmudgeonlier", "curmudgeonliest"),
-    "current" => ("currenter", "currentest"),
-    "cursed" => ("curseder", "cursedest"),
-    "curseworthy" => ("curseworthier", "curseworthiest"),
-    "curst" => ("curster", "curstest"),
-    "curt" => ("curter", "curtest"),
-    "curvy" => ("curvier", "curviest"),
-    "cushy" => ("cushier", "cushiest"),
-    "cuspy" => ("cuspier", "cuspiest"),
-    "cute" => ("cuter", "cutest"),
-    "cutesie" => ("cutesier", "cutesiest"),
-    "cutesy" => ("cutesier", "cutesiest"),
-    "cuuute" => ("cuuuter", "cuuutest"),
-    "daddy" => ("daddier", "daddiest"),
-    "dadgum" => ("more dadgum", "dadgummest"),
-    "dadly" => ("dadlier", "dadliest"),
-    "daffy" => ("daffier", "daffiest"),
-    "daft" => ("dafter", "daftest"),
-    "daggone" => ("more daggone", "daggonest"),
-    "daggy" => ("daggier", "daggiest"),
-    "dahk" => ("dahker", "dahkest"),
-    "dainty" => ("daintier", "daintiest"),
-    "damned" => ("damneder", "damnedest"),
-    "damp" => ("damper", "dampest"),
-    "dancy2" => ("dancier", "danciest"),
-    "dandy" => ("dandier", "dandiest"),
-    "dangly" => ("danglier", "dangliest"),
-    "dank" => ("danker", "dankest"),
-    "dapper" => ("dapperer", "dapperest"),
-    "dappy" => ("dappier", "dappiest"),
-    "dardy" => ("dardier", "dardiest"),
-    "dark" => ("darker", "darkest"),
-    "darkly" => ("darklier", "darkliest"),
-    "darned" => ("more darned", "darnedest"),
-    "darty" => ("dartier", "dartiest"),
-    "dashy" => ("dashier", "dashiest"),
-    "dastardly" => ("dastardlier", "dastardliest"),
-    "dead" => ("deader", "deadest"),
-    "deadly" => ("deadlier", "deadliest"),
-    "deaf2" => ("deafer", "deafest"),
-    "dear2" => ("dearer", "dearest"),
-    "deare" => ("dearer", "dearest"),
-    "dearthy" => ("dearthier", "dearthiest"),
-    "deathly" => ("deathlier", "deathliest"),
-    "debby" => ("debbier", "debbiest"),
-    "deedy" => ("deedier", "deediest"),
-    "deep" => ("deeper", "deepest"),
-    "deere" => ("deerer", "deerest"),
-    "def" => ("deffer", "deffest"),
-    "deft" => ("defter", "deftest"),
-    "delicate" => ("delicater", "delicatest"),
-    "delightful" => ("delightfuler", "delightfulest"),
-    "deluxe" => ("deluxer", "deluxest"),
-    "demure" => ("demurer", "demurest"),
-    "dense" => ("denser", "densest"),
-    "derpy" => ("derpier", "derpiest"),
-    "desperate" => ("more desperate", "desperatest"),
-    "devout" => ("devouter", "devoutest"),
-    "dewy" => ("dewier", "dewiest"),
-    "dicey" => ("dicier", "diciest"),
-    "dickey" => ("dickier", "dickiest"),
-    "dicky" => ("dickier", "dickiest"),
-    "dicty" => ("dictier", "dictiest"),
-    "diddy" => ("diddier", "diddiest"),
-    "dight" => ("dighter", "dightest"),
-    "dilfy" => ("dilfier", "dilfiest"),
-    "dilly2" => ("dillier", "dilliest"),
-    "dim2" => ("dimmer", "dimmest"),
-    "dimply" => ("dimplier", "dimpliest"),
-    "dingy" => ("dingier", "dingiest"),
-    "dinky" => ("dinkier", "dinkiest"),
-    "dippy" => ("dippier", "dippiest"),
-    "dire" => ("direr", "direst"),
-    "dirty" => ("dirtier", "dirtiest"),
-    "discreet" => ("discreeter", "discreetest"),
-    "discrete2" => ("discreter", "discretest"),
-    "disgusting" => ("disgustinger", "disgustingest"),
-    "dishy" => ("dishier", "dishiest"),
-    "distinct" => ("distincter", "distinctest"),
-    "ditsy2" => ("ditsier", "ditsiest"),
-    "ditzy" => ("ditzier", "ditziest"),
-    "dizzy" => ("dizzier", "dizziest"),
-    "doddery" => ("dodderier", "dodderiest"),
-    "dodgy" => ("dodgier", "dodgiest"),
-    "doggone" => ("more doggone", "doggonest"),
-    "doggoned" => ("more doggoned", "doggonedest"),
-    "doggy2" => ("doggier", "doggiest"),
-    "doleful" => ("dolefuller", "dolefullest"),
-    "doofy" => ("doofier", "doofiest"),
-    "doomy" => ("doomier", "doomiest"),
-    "doozy" => ("doozier", "dooziest"),
-    "dope" => ("doper", "dopest"),
-    "dopey" => ("dopier", "dopiest"),
-    "dopy" => ("dopier", "dopiest"),
-    "dorky" => ("dorkier", "dorkiest"),
-    "dotty" => ("dottier", "dottiest"),
-    "doty" => ("dotier", "dotiest"),
-    "douce" => ("doucer", "doucest"),
-    "douchey" => ("douchier", "douchiest"),
-    "douchy" => ("douchier", "douchiest"),
-    "doughty" => ("doughtier", "doughtiest"),
-    "doughy" => ("doughier", "doughiest"),
-    "dour" => ("dourer", "dourest"),
-    "dowdy" => ("dowdier", "dowdiest"),
-    "downy" => ("downier", "downiest"),
-    "dozy" => ("dozier", "doziest"),
-    "drab" => ("drabber", "drabbest"),
-    "drabby" => ("drabbier", "drabbiest"),
-    "drafty" => ("draftier", "draftiest"),
-    "draggy" => ("draggier", "draggiest"),
-    "drapey" => ("drapier", "drapiest"),
-    "draughty" => ("draughtier", "draughtiest"),
-    "drawly" => ("drawlier", "drawliest"),
-    "dread" => ("dreader", "dreadest"),
-    "dready" => ("dreadier", "dreadiest"),
-    "dreamy" => ("dreamier", "dreamiest"),
-    "drear" => ("drearer", "drearest"),
-    "dreary" => ("drearier", "dreariest"),
-    "drecky" => ("dreckier", "dreckiest"),
-    "dree" => ("dreer", "dreest"),
-    "dreggy" => ("dreggier", "dreggiest"),
-    "dreich" => ("dreicher", "dreichest"),
-    "dreigh" => ("dreigher", "dreighest"),
-    "drekky" => ("drekkier", "drekkiest"),
-    "dressy" => ("dressier", "dressiest"),
-    "dribbly" => ("dribblier", "dribbliest"),
-    "drifty" => ("driftier", "driftiest"),
-    "drinkworthy" => ("drinkworthier", "drinkworthiest"),
-    "drippy" => ("drippier", "drippiest"),
-    "drizzly" => ("drizzlier", "drizzliest"),
-    "droff" => ("droffer", "droffest"),
-    "drole" => ("droler", "drolest"),
-    "droll" => ("droller", "drollest"),
-    "drony" => ("dronier", "droniest"),
-    "drooly" => ("droolier", "drooliest"),
-    "droopy" => ("droopier", "droopiest"),
-    "drossy" => ("drossier", "drossiest"),
-    "droughty" => ("droughtier", "droughtiest"),
-    "drouthy" => ("drouthier", "drouthiest"),
-    "drowsy" => ("drowsier", "drowsiest"),
-    "drubly" => ("drublier", "drubliest"),
-    "drudgey" => ("drudgier", "drudgiest"),
-    "drudgy" => ("drudgier", "drudgiest"),
-    "druggy" => ("druggier", "druggiest"),
-    "drumly" => ("drumlier", "drumliest"),
-    "drunk" => ("drunker", "drunkest"),
-    "dry" => ("drier", "driest"),
-    "dry2" => ("dryer", "dryest"),
-    "ducky2" => ("duckier", "duckiest"),
-    "dud" => ("more dud", "duddest"),
-    "duddy" => ("duddier", "duddiest"),
-    "dudey" => ("dudier", "dudiest"),
-    "duff" => ("duffer", "duffest"),
-    "dull" => ("duller", "dullest"),
-    "dumb2" => ("dumber", "dumbest"),
-    "dumbass" => ("dumberass", "dumbestass"),
-    "dumpty" => ("dumptier", "dumptiest"),
-    "dumpy" => ("dumpier", "dumpiest"),
-    "dungy" => ("dungier", "dungiest"),
-    "dunny" => ("dunnier", "dunniest"),
-    "dusk" => ("dusker", "duskest"),
-    "dusky" => ("duskier", "duskiest"),
-    "dusty" => ("dustier", "dustiest"),
-    "dutch" => ("dutcher", "dutchest"),
-    "dutchy2" => ("dutchier", "dutchiest"),
-    "dutty" => ("duttier", "duttiest"),
-    "dwarf" => ("dwarfer", "dwarfest"),
-    "dweeby" => ("dweebier", "dweebiest"),
-    "dykey" => ("dykier", "dykiest"),
-    "dyky" => ("dykier", "dykiest"),
-    "eager" => ("eagerer", "eagerest"),
-    "early" => ("earlier", "earliest"),
-    "earnest" => ("earnester", "earnestest"),
-    "earthly2" => ("earthlier", "earthliest"),
-    "earthy" => ("earthier", "earthiest"),
-    "eastern2" => ("more eastern", "easternmost"),
-    "easy" => ("easier", "easiest"),
-    "easygoing" => ("easygoinger", "easygoingest"),
-    "eath" => ("eather", "eathest"),
-    "eathy" => ("eathier", "eathiest"),
-    "eazy" => ("eazier", "eaziest"),
-    "ebb" => ("ebber", "ebbest"),
-    "ebbe" => ("ebber", "ebbest"),
-    "echoey" => ("echoier", "echoiest"),
-    "edgelordy" => ("edgelordier", "edgelordiest"),
-    "edgy" => ("edgier", "edgiest"),
-    "eeby" => ("eebier", "eebiest"),
-    "eely" => ("eelier", "eeliest"),
-    "eepy" => ("eepier", "eepiest"),
-    "eerie" => ("eerier", "eeriest"),
-    "eery" => ("eerier", "eeriest"),
-    "eggy" => ("eggier", "eggiest"),
-    "eld" => ("elder", "eldest"),
-    "elite" => ("eliter", "elitest"),
-    "empty" => ("emptier", "emptiest"),
-    "erky" => ("erkier", "erkiest"),
-    "evil" => ("eviller", "evillest"),
-    "exact" => ("exacter", "exactest"),
-    "exploity" => ("exploitier", "exploitiest"),
-    "extreme" => ("extremer", "extremest"),
-    "fab2" => ("fabber", "fabbest"),
-    "fabby" => ("fabbier", "fabbiest"),
-    "fabe" => ("faber", "fabest"),
-    "faddy" => ("faddier", "faddiest"),
-    "fade" => ("fader", "fadest"),
-    "faffy" => ("faffier", "faffiest"),
-    "faggoty" => ("faggotier", "faggotiest"),
-    "faggy" => ("faggier", "faggiest"),
-    "faint" => ("fainter", "faintest"),
-    "fainty" => ("faintier", "faintiest"),
-    "fair" => ("fairer", "fairest"),
-    "faithfull" => ("faithfuller", "faithfullest"),
-    "faithy" => ("faithier", "faithiest"),
-    "fake" => ("faker", "fakest"),
-    "faky" => ("fakier", "fakiest"),
-    "fallen" => ("more fallen", "fallenest"),
-    "false2" => ("falser", "falsest"),
-    "fameworthy" => ("fameworthier", "fameworthiest"),
-    "fancy" => ("fancier", "fanciest"),
-    "fappy" => ("fappier", "fappiest"),
-    "far" => ("farther", "farthest"),
-    "farmy" => ("farmier", "farmiest"),
-    "farty" => ("fartier", "fartiest"),
-    "fashy" => ("fashier", "fashiest"),
-    "fast2" => ("faster", "fastest"),
-    "fat" => ("fatter", "fattest"),
-    "fatty" => ("fattier", "fattiest"),
-    "faulty" => ("faultier", "faultiest"),
-    "fausty" => ("faustier", "faustiest"),
-    "favourite" => ("more favourite", "favouritemost"),
-    "fayre" => ("fayrer", "fayrest"),
-    "feal" => ("fealer", "fealest"),
-    "feasty" => ("feastier", "feastiest"),
-    "feat" => ("feater", "featest"),
-    "feathery" => ("featherier", "featheriest"),
-    "featly" => ("featlier", "featliest"),
-    "fecky" => ("feckier", "feckiest"),
-    "feeble" => ("feebler", "feeblest"),
-    "feely2" => ("feelier", "feeliest"),
-    "feisty" => ("feistier", "feistiest"),
-    "fele" => ("feler", "felest"),
-    "fell" => ("feller", "fellest"),
-    "female" => ("femaler", "femalest"),
-    "fendy" => ("fendier", "fendiest"),
-    "ferny" => ("fernier", "ferniest"),
-    "fervid" => ("fervider", "fervidest"),
-    "fetchy" => ("fetchier", "fetchiest"),
-    "fey" => ("feyer", "feyest"),
-    "fickle" => ("fickler", "ficklest"),
-    "fiddly" => ("fiddlier", "fiddliest"),
-    "fidgety" => ("fidgetier", "fidgetiest"),
-    "fiendy" => ("fiendier", "fiendiest"),
-    "fierce" => ("fiercer", "fiercest"),
-    "fiery" => ("fierier", "fieriest"),
-    "fiesty" => ("fiestier", "fiestiest"),
-    "figgy" => ("figgier", "figgiest"),
-    "fighty" => ("fightier", "fightiest"),
-    "fiine" => ("fiiner", "fiinest"),
-    "filmy" => ("filmier", "filmiest"),
-    "filthy" => ("filthier", "filthiest"),
-    "fine" => ("finer", "finest"),
-    "finicky" => ("finickier", "finickiest"),
-    "finnicky" => ("finnickier", "finnickiest"),
-    "finny" => ("finnier", "finniest"),
-    "firm" => ("firmer", "firmest"),
-    "first" => ("more first", "firstmost"),
-    "fishy" => ("fishier", "fishiest"),
-    "fit" => ("fitter", "fittest"),
-    "fizzy" => ("fizzier", "fizziest"),
-    "flabby" => ("flabbier", "flabbiest"),
-    "flaggy" => ("flaggier", "flaggiest"),
-    "flakey" => ("flakier", "flakiest"),
-    "flaky" => ("flakier", "flakiest"),
-    "flamy" => ("flamier", "flamiest"),
-    "flappy" => ("flappier", "flappiest"),
-    "flashy" => ("flashier", "flashiest"),
-    "flat" => ("flatter", "flattest"),
-    "flaunty" => ("flauntier", "flauntiest"),
-    "fleecy" => ("fleecier", "fleeciest"),
-    "fleek" => ("fleeker", "fleekest"),
-    "fleeky" => ("fleekier", "fleekiest"),
-    "fleet" => ("fleeter", "fleetest"),
-    "fleshly" => ("fleshlier", "fleshliest"),
-    "fleshy" => ("fleshier", "fleshiest"),
-    "flexy" => ("flexier", "flexiest"),
-    "flicky" => ("flickier", "flickiest"),
-    "flighty" => ("flightier", "flightiest"),
-    "flimsy" => ("flimsier", "flimsiest"),
-    "flingy" => ("flingier", "flingiest"),
-    "flinty" => ("flintier", "flintiest"),
-    "flip" => ("flipper", "flippest"),
-    "flippy" => ("flippier", "flippiest"),
-    "flirty" => ("flirtier", "flirtiest"),
-    "flitty" => ("flittier", "flittiest"),
-    "floaty" => ("floatier", "floatiest"),
-    "flobby" => ("flobbier", "flobbiest"),
-    "flocky" => ("flockier", "flockiest"),
-    "floody" => ("floodier", "floodiest"),
-    "floofy" => ("floofier", "floofiest"),
-    "floppy" => ("floppier", "floppiest"),
-    "flossy" => ("flossier", "flossiest"),
-    "flouncy" => ("flouncier", "flounciest"),
-    "floury" => ("flourier", "flouriest"),
-    "flowery" => ("flowerier", "floweriest"),
-    "flowy" => ("flowier", "flowiest"),
-    "fluey2" => ("fluier", "fluiest"),
-    "fluffy" => ("fluffier", "fluffiest"),
-    "flufy" => ("flufier", "flufiest"),
-    "fluky" => ("flukier", "flukiest"),
-    "flush" => ("flusher", "flushest"),
-    "flushy" => ("flushier", "flushiest"),
-    "fluttery" => ("flutterier", "flutteriest"),
-    "fluty" => ("flutier", "flutiest"),
-    "fly" => ("flier", "fliest"),
-    "foamy" => ("foamier", "foamiest"),
-    "foggy" => ("foggier", "foggiest"),
-    "foine" => ("foiner", "foinest"),
-    "folkie" => ("folkier", "folkiest"),
-    "folksy" => ("folksier", "folksiest"),
-    "folky" => ("folkier", "folkiest"),
-    "fond" => ("fonder", "fondest"),
-    "foody" => ("foodier", "foodiest"),
-    "foofy" => ("foofier", "foofiest"),
-    "fool" => ("fooler", "foolest"),
-    "foolhardy" => ("foolhardier", "foolhardiest"),
-    "forblack" => ("forblacker", "forblackest"),
-    "fordry" => ("fordrier", "fordriest"),
-    "fore" => ("more fore", "foremost"),
-    "forky" => ("forkier", "forkiest"),
-    "forlorn" => ("forlorner", "forlornest"),
-    "forward" => ("forwarder", "forwardmost"),
-    "foul" => ("fouler", "foulest"),
-    "fouthy" => ("fouthier", "fouthiest"),
-    "fowl" => ("fowler", "fowlest"),
-    "foxy" => ("foxier", "foxiest"),
-    "fragile" => ("fragiler", "fragilest"),
-    "fraidy" => ("fraidier", "fraidiest"),
-    "frail" => ("frailer", "frailest"),
-    "frank" => ("franker", "frankest"),
-    "frass" => ("frasser", "frassest"),
-    "fratchy" => ("fratchier", "fratchiest"),
-    "fratty" => ("frattier", "frattiest"),
-    "freaky" => ("freakier", "freakiest"),
-    "freash" => ("freasher", "freashest"),
-    "freckly" => ("frecklier", "freckliest"),
-    "free" => ("freer", "freest"),
-    "freely" => ("freelier", "freeliest"),
-    "freezy" => ("freezier", "freeziest"),
-    "fremd" => ("fremder", "fremdest"),
-    "french" => ("frencher", "frenchest"),
-    "frenchy" => ("frenchier", "frenchiest"),
-    "frequent" => ("frequenter", "frequentest"),
-    "fresh" => ("fresher", "freshest"),
-    "friendly" => ("friendlier", "friendliest"),
-    "frighty" => ("frightier", "frightiest"),
-    "frigid" => ("frigider", "frigidest"),
-    "frilly" => ("frillier", "frilliest"),
-    "frim2" => ("frimmer", "frimmest"),
-    "fringe" => ("fringer", "fringest"),
-    "fringey" => ("fringier", "fringiest"),
-    "fringy" => ("fringier", "fringiest"),
-    "frisky" => ("friskier", "friskiest"),
-    "frizzly" => ("frizzlier", "frizzliest"),
-    "frizzy" => ("frizzier", "frizziest"),
-    "froggy" => ("froggier", "froggiest"),
-    "frood" => ("frooder", "froodest"),
-    "frosty" => ("frostier", "frostiest"),
-    "frothy" => ("frothier", "frothiest"),
-    "frousy" => ("frousier", "frousiest"),
-    "frowny" => ("frownier", "frowniest"),
-    "frowsty" => ("frowstier", "frowstiest"),
-    "frowsy" => ("frowsier", "frowsiest"),
-    "frowzy" => ("frowzier", "frowziest"),
-    "fruity" => ("fruitier", "fruitiest"),
-    "frum" => ("frummer", "frummest"),
-    "frumpy" => ("frumpier", "frumpiest"),
-    "fubsy" => ("fubsier", "fubsiest"),
-    "fucky" => ("fuckier", "fuckiest"),
-    "fudgey" => ("fudgier", "fudgiest"),
-    "fudgy" => ("fudgier", "fudgiest"),
-    "fuggy" => ("fuggier", "fuggiest"),
-    "fugly" => ("fuglier", "fugliest"),
-    "full" => ("fuller", "fullest"),
-    "fulsome" => ("fulsomer", "fulsomest"),
-    "fumy" => ("fumier", "fumiest"),
-    "funky" => ("funkier", "funkiest"),
-    "funni" => ("funnier", "funniest"),
-    "funny" => ("funnier", "funniest"),
-    "furry" => ("furrier", "furriest"),
-    "fussy" => ("fussier", "fussiest"),
-    "fusty" => ("fustier", "fustiest"),
-    "fuzzy" => ("fuzzier", "fuzziest"),
-    "gabby" => ("gabbier", "gabbiest"),
-    "gaggy" => ("gaggier", "gaggiest"),
-    "gainly" => ("gainlier", "gainliest"),
-    "game2" => ("gamer", "gamest"),
-    "gamely" => ("gamelier", "gameliest"),
-    "gamey" => ("gamier", "gamiest"),
-    "gammie" => ("gammier", "gammiest"),
-    "gammy" => ("gammier", "gammiest"),
-    "gangly" => ("ganglier", "gangliest"),
-    "gapey" => ("gapier", "gapiest"),
-    "gappy" => ("gappier", "gappiest"),
-    "garlicky" => ("garlickier", "garlickiest"),
-    "gas" => ("gasser", "gassest"),
-    "gaspy" => ("gaspier", "gaspiest"),
-    "gassy" => ("gassier", "gassiest"),
-    "gaudy" => ("gaudier", "gaudiest"),
-    "gaunt" => ("gaunter", "gauntest"),
-    "gauzy" => ("gauzier", "gauziest"),
-    "gawdy" => ("gawdier", "gawdiest"),
-    "gawky" => ("gawkier", "gawkiest"),
-    "gay" => ("gayer", "gayest"),
-    "geeky" => ("geekier", "geekiest"),
-    "gemmy" => ("gemmier", "gemmiest"),
-    "gentle" => ("gentler", "gentlest"),
-    "gentlemanly" => ("gentlemanlier", "gentlemanliest"),
-    "german2" => ("more german", "germanest"),
-    "germy" => ("germier", "germiest"),
-    "gey" => ("geyer", "geyest"),
-    "gezellig" => ("gezelliger", "gezelligest"),
-    "ghastly" => ("ghastlier", "ghastliest"),
-    "ghey" => ("gheyer", "gheyest"),
-    "ghostly" => ("ghostlier", "ghostliest"),
-    "ghosty" => ("ghostier", "ghostiest"),
-    "giddie" => ("giddier", "giddiest"),
-    "giddy" => ("giddier", "giddiest"),
-    "giggly" => ("gigglier", "giggliest"),
-    "gimmicky" => ("gimmickier", "gimmickiest"),
-    "gimpy" => ("gimpier", "gimpiest"),
-    "ginchy" => ("ginchier", "ginchiest"),
-    "girlie" => ("girlier", "girliest"),
-    "girly" => ("girlier", "girliest"),
-    "girthy" => ("girthier", "girthiest"),
-    "glad" => ("gladder", "gladdest"),
-    "gladsome" => ("gladsomer", "gladsomest"),
-    "glam" => ("glammer", "glammest"),
-    "glary" => ("glarier", "glariest"),
-    "glassy" => ("glassier", "glassiest"),
-    "glazy" => ("glazier", "glaziest"),
-    "gleamy" => ("gleamier", "gleamiest"),
-    "gleg" => ("glegger", "gleggest"),
-    "glib" => ("glibber", "glibbest"),
-    "glimmery" => ("glimmerier", "glimmeriest"),
-    "glinty" => ("glintier", "glintiest"),
-    "glisky" => ("gliskier", "gliskiest"),
-    "glissy" => ("glissier", "glissiest"),
-    "glitchy" => ("glitchier", "glitchiest"),
-    "glittery" => ("glitterier", "glitteriest"),
-    "glitzy" => ("glitzier", "glitziest"),
-    "globby" => ("globbier", "globbiest"),
-    "gloomy" => ("gloomier", "gloomiest"),
-    "gloopy" => ("gloopier", "gloopiest"),
-    "gloppy" => ("gloppier", "gloppiest"),
-    "glorious" => ("gloriouser", "gloriousest"),
-    "glossy" => ("glossier", "glossiest"),
-    "glowy" => ("glowier", "glowiest"),
-    "gluey" => ("gluier", "gluiest"),
-    "gluggy" => ("gluggier", "gluggiest"),
-    "glum" => ("glummer", "glummest"),
-    "glumpy" => ("glumpier", "glumpiest"),
-    "glurgy" => ("glurgier", "glurgiest"),
-    "gnarly" => ("gnarlier", "gnarliest"),
-    "gnashy" => ("gnashier", "gnashiest"),
-    "gnatty" => ("gnattier", "gnattiest"),
-    "goaty" => ("goatier", "goatiest"),
-    "gobby" => ("gobbier", "gobbiest"),
-    "godly" => ("godlier", "godliest"),
-    "goel" => ("goeler", "goelest"),
-    "gold2" => ("golder", "goldest"),
-    "golden" => ("goldener", "goldenest"),
-    "goldy" => ("goldier", "goldiest"),
-    "golfy" => ("golfier", "golfiest"),
-    "gone" => ("goner", "gonest"),
-    "good2" => ("better", "best"),
-    "goodly" => ("goodlier", "goodliest"),
-    "goody" => ("goodier", "goodiest"),
-    "gooey" => ("gooeyer", "gooeyest"),
-    "goofy2" => ("goofier", "goofiest"),
-    "googly" => ("googlier", "googliest"),
-    "gooky" => ("gookier", "gookiest"),
-    "goony" => ("goonier", "gooniest"),
-    "goopy" => ("goopier", "goopiest"),
-    "goosy" => ("goosier", "goosiest"),
-    "gorky" => ("gorkier", "gorkiest"),
-    "gorsy" => ("gorsier", "gorsiest"),
-    "gory" => ("gorier", "goriest"),
-    "gossipy" => ("gossipier", "gossipiest"),
-    "goth2" => ("gother", "gothest"),
-    "gothy" => ("gothier", "gothiest"),
-    "goundy" => ("goundier", "goundiest"),
-    "gouty" => ("goutier", "goutiest"),
-    "grabby" => ("grabbier", "grabbiest"),
-    "gradely" => ("gradelier", "gradeliest"),
-    "grainy" => ("grainier", "grainiest"),
-    "grand" => ("grander", "grandest"),
-    "grapey" => ("grapier", "grapiest"),
-    "grapy" => ("grapier", "grapiest"),
-    "grassy" => ("grassier", "grassiest"),
-    "grateful" => ("gratefuller", "gratefullest"),
-    "graund" => ("graunder", "graundest"),
-    "grave" => ("graver", "gravest"),
-    "gray" => ("grayer", "grayest"),
-    "grayey" => ("grayier", "grayiest"),
-    "greasey" => ("greasier", "greasiest"),
-    "greasy" => ("greasier", "greasiest"),
-    "great" => ("greater", "greatest"),
-    "greedy" => ("greedier", "greediest"),
-    "greek" => ("greeker", "greekest"),
-    "green2" => ("greener", "greenest"),
-    "greene" => ("greener", "greenest"),
-    "greeny" => ("greenier", "greeniest"),
-    "grey" => ("greyer", "greyest"),
-    "greyey" => ("greyier", "greyiest"),
-    "grill" => ("griller", "grillest"),
-    "grim" => ("grimmer", "grimmest"),
-    "grimly" => ("grimlier", "grimliest"),
-    "grimy" => ("grimier", "grimiest"),
-    "grindy" => ("grindier", "grindiest"),
-    "grippy2" => ("grippier", "grippiest"),
-    "grislie" => ("grislier", "grisliest"),
-    "grisly" => ("grislier", "grisliest"),
-    "gristly" => ("gristlier", "gristliest"),
-    "gritty" => ("grittier", "grittiest"),
-    "grizzly" => ("grizzlier", "grizzliest"),
-    "groaty2" => ("groatier", "groatiest"),
-    "grode" => ("groder", "grodest"),
-    "grody" => ("grodier", "grodiest"),
-    "groggy" => ("groggier", "groggiest"),
-    "groomy" => ("groomier", "groomiest"),
-    "groovy" => ("groovier", "grooviest"),
-    "gross" => ("grosser", "grossest"),
-    "grotesque" => ("grotesquer", "grotesquest"),
-    "grotty" => ("grottier", "grottiest"),
-    "groty" => ("grotier", "grotiest"),
-    "grouchy" => ("grouchier", "grouchiest"),
-    "groundy" => ("groundier", "groundiest"),
-    "grouse" => ("grouser", "grousest"),
-    "grouty" => ("groutier", "groutiest"),
-    "growly" => ("growlier", "growliest"),
-    "growthy" => ("growthier", "growthiest"),
-    "grubby" => ("grubbier", "grubbiest"),
-    "grudgy" => ("grudgier", "grudgiest"),
-    "gruesome" => ("gruesomer", "gruesomest"),
-    "gruff2" => ("gruffer", "gruffest"),
-    "gruffy" => ("gruffier", "gruffiest"),
-    "grufty" => ("gruftier", "gruftiest"),
-    "grum" => ("grummer", "grummest"),
-    "grumbly" => ("grumblier", "grumbliest"),
-    "grumly" => ("grumlier", "grumliest"),
-    "grumpy" => ("grumpier", "grumpiest"),
-    "grundy" => ("grundier", "grundiest"),
-    "grungy" => ("grungier", "grungiest"),
-    "grunty" => ("gruntier", "gruntiest"),
-    "gucky" => ("guckier", "guckiest"),
-    "guilty" => ("guiltier", "guiltiest"),
-    "gummy" => ("gummier", "gummiest"),
-    "gungy" => ("gungier", "gungiest"),
-    "gunky" => ("gunkier", "gunkiest"),
-    "gurly2" => ("gurlier", "gurliest"),
-    "gushy" => ("gushier", "gushiest"),
-    "gusty" => ("gustier", "gustiest"),
-    "gutsy" => ("gutsier", "gutsiest"),
-    "gutty2" => ("guttier", "guttiest"),
-    "hacky2" => ("hackier", "hackiest"),
-    "hail" => ("hailer", "hailest"),
-    "hairy" => ("hairier", "hairiest"),
-    "hale" => ("haler", "halest"),
-    "halloweeny" => ("halloweenier", "halloweeniest"),
-    "hammy" => ("hammier", "hammiest"),
-    "handsome" => ("handsomer", "handsomest"),
-    "handsy" => ("handsier", "handsiest"),
-    "handy" => ("handier", "handiest"),
-    "hangry" => ("hangrier", "hangriest"),
-    "happy" => ("happier", "happiest"),
-    "hard2" => ("harder", "hardest"),
-    "hardy" => ("hardier", "hardiest"),
-    "harmful" => ("harmfuller", "harmfullest"),
-    "harsh" => ("harsher", "harshest"),
-    "hashy" => ("hashier", "hashiest"),
-    "hasty" => ("hastier", "hastiest"),
-    "hateful" => ("hatefuller", "hatefullest"),
-    "hatty" => ("hattier", "hattiest"),
-    "haughty" => ("haughtier", "haughtiest"),
-    "haunted" => ("more haunted", "hauntedest"),
-    "hayey" => ("hayier", "hayiest"),
-    "hazy" => ("hazier", "haziest"),
-    "headliney" => ("headlinier", "headliniest"),
-    "headliny" => ("headlinier", "headliniest"),
-    "heady" => ("headier", "headiest"),
-    "healthful" => ("healthfuller", "healthfullest"),
-    "healthy" => ("healthier", "healthiest"),
-    "heapy" => ("heapier", "heapiest"),
-    "heartly" => ("heartlier", "heartliest"),
-    "heartthrobby" => ("heartthrobbier", "heartthrobbiest"),
-    "hearty" => ("heartier", "heartiest"),
-    "heathen2" => ("heathener", "heathenest"),
-    "heathy" => ("heathier", "heathiest"),
-    "heauie" => ("heauier", "heauiest"),
-    "heauy" => ("heauier", "heauiest"),
-    "heavenly2" => ("heavenlier", "heavenliest"),
-    "heavy2" => ("heavier", "heaviest"),
-    "hedgy" => ("hedgier", "hedgiest"),
-    "hefty" => ("heftier", "heftiest"),
-    "helply" => ("helplier", "helpliest"),
-    "helpy" => ("helpier", "helpiest"),
-    "hench" => ("hencher", "henchest"),
-    "henny" => ("hennier", "henniest"),
-    "heppen" => ("heppener", "heppenest"),
-    "herby" => ("herbier", "herbiest"),
-    "hetty" => ("hettier", "hettiest"),
-    "high" => ("higher", "highest"),
-    "hilly" => ("hillier", "hilliest"),
-    "hincty" => ("hinctier", "hinctiest"),
-    "hind" => ("hinder", "hindmost"),
-    "hinky" => ("hinkier", "hinkiest"),
-    "hip" => ("hipper", "hippest"),
-    "hippie" => ("hippier", "hippiest"),
-    "hippy" => ("hippier", "hippiest"),
-    "hissy" => ("hissier", "hissiest"),
-    "hoaky" => ("hoakier", "hoakiest"),
-    "hoardy" => ("hoardier", "hoardiest"),
-    "hoarse" => ("hoarser", "hoarsest"),
-    "hoary" => ("hoarier", "hoariest"),
-    "hoaxy" => ("hoaxier", "hoaxiest"),
-    "hoggy" => ("hoggier", "hoggiest"),
-    "hogwartsy" => ("hogwartsier", "hogwartsiest"),
-    "hokey" => ("hokier", "hokiest"),
-    "hokie" => ("hokier", "hokiest"),
-    "hoky" => ("hokier", "hokiest"),
-    "hole" => ("holer", "holest"),
-    "holey" => ("holier", "holiest"),
-    "hollow" => ("hollower", "hollowest"),
-    "holy2" => ("holier", "holiest"),
-    "homely" => ("homelier", "homeliest"),
-    "homey" => ("homier", "homiest"),
-    "honey" => ("honeyer", "honeyest"),
-    "hoody" => ("hoodier", "hoodiest"),
-    "hooky" => ("hookier", "hookiest"),
-    "hooly" => ("hoolier", "hooliest"),
-    "hooty" => ("hootier", "hootiest"),
-    "hoppy2" => ("hoppier", "hoppiest"),
-    "horny" => ("hornier", "horniest"),
-    "horrible" => ("horribler", "horriblest"),
-    "horrid" => ("horrider", "horridest"),
-    "horry" => ("horrier", "horriest"),
-    "horsey" => ("horsier", "horsiest"),
-    "horsy" => ("horsier", "horsiest"),
-    "hory" => ("horier", "horiest"),
-    "hot2" => ("hotter", "hottest"),
-    "houndly" => ("houndlier", "houndliest"),
-    "houndy" => ("houndier", "houndiest"),
-    "hubby" => ("hubbier", "hubbiest"),
-    "huffy" => ("huffier", "huffiest"),
-    "huge" => ("huger", "hugest"),
-    "huggly" => ("hugglier", "huggliest"),
-    "huggy" => ("huggier", "huggiest"),
-    "hugy" => ("hugier", "hugiest"),
-    "hulky" => ("hulkier", "hulkiest"),
-    "humane" => ("humaner", "humanest"),
-    "humble" => ("humbler", "humblest"),
-    "humid" => ("humider", "humidest"),
-    "humpy" => ("humpier", "humpiest"),
-    "hungry" => ("hungrier", "hungriest"),
-    "hungy2" => ("hungier", "hungiest"),
-    "hunky" => ("hunkier", "hunkiest"),
-    "hurty2" => ("hurtier", "hurtiest"),
-    "husky" => ("huskier", "huskiest"),
-    "hwite" => ("hwiter", "hwitest"),
-    "hye" => ("hyer", "hyest"),
-    "hypey" => ("hypier", "hypiest"),
-    "hyphy" => ("hyphier", "hyphiest"),
-    "icey" => ("icier", "iciest"),
-    "ickle" => ("ickler", "icklest"),
-    "icky" => ("ickier", "ickiest"),
-    "icy" => ("icier", "iciest"),
-    "iffy" => ("iffier", "iffiest"),
-    "ignoble" => ("ignobler", "ignoblest"),
-    "ignorant" => ("ignoranter", "ignorantest"),
-    "ill" => ("iller", "illest"),
-    "immense" => ("immenser", "immensest"),
-    "impeachy" => ("impeachier", "impeachiest"),
-    "impolite" => ("impoliter", "impolitest"),
-    "impudent" => ("impudenter", "impudentest"),
-    "inane" => ("inaner", "inanest"),
-    "indiscreet" => ("indiscreeter", "indiscreetest"),
-    "indiscrete" => ("indiscreter", "indiscretest"),
-    "infirm" => ("infirmer", "infirmest"),
-    "inky" => ("inkier", "inkiest"),
-    "inner" => ("more inner", "innermost"),
-    "insane" => ("insaner", "insanest"),
-    "intense" => ("intenser", "intensest"),
-    "irate" => ("irater", "iratest"),
-    "irky" => ("irkier", "irkiest"),
-    "itchy" => ("itchier", "itchiest"),
-    "itsy" => ("itsier", "itsiest"),
-    "itty" => ("ittier", "ittiest"),
-    "jabby" => ("jabbier", "jabbiest"),
-    "jaggy" => ("jaggier", "jaggiest"),
-    "jammie" => ("jammier", "jammiest"),
-    "jammy" => ("jammier", "jammiest"),
-    "jangly" => ("janglier", "jangliest"),
-    "janky" => ("jankier", "jankiest"),
-    "jargony" => ("jargonier", "jargoniest"),
-    "jaunty" => ("jauntier", "jauntiest"),
-    "jazzy" => ("jazzier", "jazziest"),
-    "jealous" => ("jealouser", "jealousest"),
-    "jemmy" => ("jemmier", "jemmiest"),
-    "jerky" => ("jerkier", "jerkiest"),
-    "jet2" => ("jetter", "jettest"),
-    "jetty2" => ("jettier", "jettiest"),
-    "jewish" => ("jewisher", "jewishest"),
-    "jiggly" => ("jigglier", "jiggliest"),
-    "jiggy" => ("jiggier", "jiggiest"),
-    "jimp" => ("jimper", "jimpest"),
-    "jingly" => ("jinglier", "jingliest"),
-    "jinky" => ("jinkier", "jinkiest"),
-    "jittery" => ("jitterier", "jitteriest"),
-    "jive" => ("jiver", "jivest"),
-    "jivey" => ("jivier", "jiviest"),
-    "jokey" => ("jokier", "jokiest"),
-    "joky" => ("jokier", "jokiest"),
-    "jolly" => ("jollier", "jolliest"),
-    "jolty" => ("joltier", "joltiest"),
-    "jouncy" => ("jouncier", "jounciest"),
-    "jowly" => ("jowlier", "jowliest"),
-    "joyful" => ("joyfuller", "joyfullest"),
-    "judgy" => ("judgier", "judgiest"),
-    "juicy" => ("juicier", "juiciest"),
-    "jumpy" => ("jumpier", "jumpiest"),
-    "jungly" => ("junglier", "jungliest"),
-    "junky" => ("junkier", "junkiest"),
-    "just" => ("juster", "justest"),
-    "keen" => ("keener", "keenest"),
-    "kene" => ("kener", "kenest"),
-    "kewl" => ("kewler", "kewlest"),
-    "key" => ("keyer", "keyest"),
-    "kibbley" => ("kibblier", "kibbliest"),
-    "kibbly" => ("kibblier", "kibbliest"),
-    "kicky" => ("kickier", "kickiest"),
-    "kiddy" => ("kiddier", "kiddiest"),
-    "kind" => ("kinder", "kindest"),
-    "kindly" => ("kindlier", "kindliest"),
-    "kingly" => ("kinglier", "kingliest"),
-    "kinky" => ("kinkier", "kinkiest"),
-    "kissy" => ("kissier", "kissiest"),
-    "kitsch" => ("kitscher", "kitschest"),
-    "kitschy" => ("kitschier", "kitschiest"),
-    "kittle" => ("kittler", "kittlest"),
-    "kludgey" => ("kludgier", "kludgiest"),
-    "kludgy" => ("kludgier", "kludgiest"),
-    "klugey" => ("klugier", "klugiest"),
-    "kluggy" => ("kluggier", "kluggiest"),
-    "klugy" => ("klugier", "klugiest"),
-    "klunky" => ("klunkier", "klunkiest"),
-    "klutzy" => ("klutzier", "klutziest"),
-    "knaggy" => ("knaggier", "knaggiest"),
-    "knightly" => ("knightlier", "knightliest"),
-    "knobbly" => ("knobblier", "knobbliest"),
-    "knobby" => ("knobbier", "knobbiest"),
-    "knotty" => ("knottier", "knottiest"),
-    "knuckly" => ("knucklier", "knuckliest"),
-    "knurly" => ("knurlier", "knurliest"),
-    "kooky" => ("kookier", "kookiest"),
-    "kool" => ("kooler", "koolest"),
-    "krazy" => ("krazier", "kraziest"),
-    "kvetchy" => ("kvetchier", "kvetchiest"),
-    "kway" => ("kwayer", "kwayest"),
-    "kwik" => ("kwikker", "kwikkest"),
-    "lacey" => ("lacier", "laciest"),
-    "lacy" => ("lacier", "laciest"),
-    "laggy" => ("laggier", "laggiest"),
-    "lairy" => ("lairier", "lairiest"),
-    "laky" => ("lakier", "lakiest"),
-    "lamby" => ("lambier", "lambiest"),
-    "lame" => ("lamer", "lamest"),
-    "lang" => ("langer", "langest"),
-    "lank" => ("lanker", "lankest"),
-    "lanky2" => ("lankier", "lankiest"),
-    "lardy" => ("lardier", "lardiest"),
-    "large" => ("larger", "largest"),
-    "larky" => ("larkier", "larkiest"),
-    "late" => ("later", "latest"),
-    "lathy" => ("lathier", "lathiest"),
-    "laughy" => ("laughier", "laughiest"),
-    "lavish" => ("lavisher", "lavishest"),
-    "lavy" => ("lavier", "laviest"),
-    "lax" => ("laxer", "laxest"),
-    "lazy" => ("lazier", "laziest"),
-    "leafy" => ("leafier", "leafiest"),
-    "leaky" => ("leakier", "leakiest"),
-    "leal" => ("lealer", "lealest"),
-    "lean" => ("leaner", "leanest"),
-    "leary" => ("learier", "leariest"),
-    "leathery" => ("leatherier", "leatheriest"),
-    "ledgy" => ("ledgier", "ledgiest"),
-    "leeky" => ("leekier", "leekiest"),
-    "leery" => ("leerier", "leeriest"),
-    "leet" => ("leeter", "leetest"),
-    "leetle" => ("leetler", "leetlest"),
-    "leeward" => ("more leeward", "leewardmost"),
-    "left" => ("lefter", "leftmost"),
-    "leftern" => ("more leftern", "lefternmost"),
-    "leggy" => ("leggier", "leggiest"),
-    "lemony" => ("lemonier", "lemoniest"),
-    "leng" => ("lenger", "lengest"),
-    "lengthy" => ("lengthier", "lengthiest"),
-    "level" => ("leveler", "levelest"),
-    "lew2" => ("lewer", "lewest"),
-    "lewd" => ("lewder", "lewdest"),
-    "licky" => ("lickier", "lickiest"),
-    "lief" => ("liefer", "liefest"),
-    "light" => ("lighter", "lightest"),
-    "lightweight" => ("lighterweight", "lightestweight"),
-    "likable" => ("likabler", "likablest"),
-    "likeable" => ("likeabler", "likeablest"),
-    "likely" => ("likelier", "likeliest"),
-    "likeworthy" => ("likeworthier", "likeworthiest"),
-    "limber" => ("limberer", "limberest"),
-    "limby" => ("limbier", "limbiest"),
-    "limey" => ("limier", "limiest"),
-    "limp" => ("limper", "limpest"),
-    "limy" => ("limier", "limiest"),
-    "linty" => ("lintier", "lintiest"),
-    "liny" => ("linier", "liniest"),
-    "lippy" => ("lippier", "lippiest"),
-    "lissom" => ("lissomer", "lissomest"),
-    "lissome" => ("lissomer", "lissomest"),
-    "lit2" => ("litter", "littest"),
-    "lite2" => ("liter", "litest"),
-    "lithe" => ("lither", "lithest"),
-    "little" => ("littler", "littlest"),
-    "lively" => ("livelier", "liveliest"),
-    "livid" => ("livider", "lividest"),
-    "loamy" => ("loamier", "loamiest"),
-    "loath" => ("loather", "loathest"),
-    "loathly" => ("loathlier", "loathliest"),
-    "loathy" => ("loathier", "loathiest"),
-    "lofty" => ("loftier", "loftiest"),
-    "logy" => ("logier", "logiest"),
-    "lolly" => ("lollier", "lolliest"),
-    "lonely" => ("lonelier", "loneliest"),
-    "long2" => ("longer", "longest"),
-    "loony" => ("loonier", "looniest"),
-    "loopy" => ("loopier", "loopiest"),
-    "loose" => ("looser", "loosest"),
-    "lordly" => ("lordlier", "lordliest"),
-    "lossy" => ("lossier", "lossiest"),
-    "lost" => ("loster", "lostest"),
-    "loth" => ("lother", "lothest"),
-    "louche" => ("loucher", "louchest"),
-    "loud" => ("louder", "loudest"),
-    "louely" => ("louelier", "loueliest"),
-    "loungy" => ("loungier", "loungiest"),
-    "lousey" => ("lousier", "lousiest"),
-    "lousy" => ("lousier", "lousiest"),
-    "lovable" => ("lovabler", "lovablest"),
-    "loveable" => ("loveabler", "loveablest"),
-    "lovely" => ("lovelier", "loveliest"),
-    "loving" => ("lovinger", "lovingest"),
-    "low" => ("lower", "lowest"),
-    "lowd" => ("lowder", "lowdest"),
-    "lowly" => ("lowlier", "lowliest"),
-    "lown" => ("lowner", "lownest"),
-    "lubbly" => ("lubblier", "lubbliest"),
-    "lubly" => ("lublier", "lubliest"),
-    "lucid" => ("lucider", "lucidest"),
-    "lucky" => ("luckier", "luckiest"),
-    "lukewarm" => ("lukewarmer", "lukewarmest"),
-    "lulzy" => ("lulzier", "lulziest"),
-    "lumpy" => ("lumpier", "lumpiest"),
-    "lunky" => ("lunkier", "lunkiest"),
-    "luny" => ("lunier", "luniest"),
-    "lush" => ("lusher", "lushest"),
-    "lushy" => ("lushier", "lushiest"),
-    "lusty" => ("lustier", "lustiest"),
-    "luxe" => ("luxier", "luxest"),
-    "lying" => ("lyinger", "lyingest"),
-    "lyke" => ("lyker", "lykest"),
-    "mad" => ("madder", "maddest"),
-    "maidly" => ("maidlier", "maidliest"),
-    "mald" => ("malder", "maldest"),
-    "male" => ("maler", "malest"),
-    "malty" => ("maltier", "maltiest"),
-    "manful" => ("manfuller", "manfullest"),
-    "mangy" => ("mangier", "mangiest"),
-    "manky" => ("mankier", "mankiest"),
-    "manly" => ("manlier", "manliest"),
-    "many" => ("more", "most"),
-    "marchy2" => ("marchier", "marchiest"),
-    "mardy" => ("mardier", "mardiest"),
-    "marly" => ("marlier", "marliest"),
-    "marshmallowy" => ("marshmallowier", "marshmallowiest"),
-    "marshy" => ("marshier", "marshiest"),
-    "mashy" => ("mashier", "mashiest"),
-    "mass" => ("masser", "massest"),
-    "massy" => ("massier", "massiest"),
-    "matey" => ("matier", "matiest"),
-    "mathy" => ("mathier", "mathiest"),
-    "mauve" => ("mauver", "mauvest"),
-    "mawky" => ("mawkier", "mawkiest"),
-    "mazy" => ("mazier", "maziest"),
-    "meager" => ("meagerer", "meagerest"),
-    "meagre" => ("meagrer", "meagrest"),
-    "mealy" => ("mealier", "mealiest"),
-    "mean2" => ("meaner", "meanest"),
-    "measly" => ("measlier", "measliest"),
-    "meaty" => ("meatier", "meatiest"),
-    "meek" => ("meeker", "meekest"),
-    "meer" => ("meerer", "meerest"),
-    "meet" => ("meeter", "meetest"),
-    "mellow" => ("mellower", "mellowest"),
-    "melty" => ("meltier", "meltiest"),
-    "memey" => ("memier", "memiest"),
-    "menschy" => ("menschier", "menschiest"),
-    "meowy" => ("meowier", "meowiest"),
-    "mere" => ("merer", "merest"),
-    "merie" => ("merier", "meriest"),
-    "merrie" => ("merrier", "merriest"),
-    "merry" => ("merrier", "merriest"),
-    "messy" => ("messier", "messiest"),
-    "mid" => ("more mid", "midmost"),
-    "middle" => ("more middle", "middlemost"),
-    "miffy" => ("miffier", "miffiest"),
-    "mifty" => ("miftier", "miftiest"),
-    "might" => ("mighter", "mightest"),
-    "mighty" => ("mightier", "mightiest"),
-    "milchy" => ("milchier", "milchiest"),
-    "mild" => ("milder", "mildest"),
-    "milde" => ("milder", "mildest"),
-    "milfy" => ("milfier", "milfiest"),
-    "milky" => ("milkier", "milkiest"),
-    "mim" => ("mimmer", "mimmest"),
-    "mimsy" => ("mimsier", "mimsiest"),
-    "mincy" => ("mincier", "minciest"),
-    "minecrafty" => ("minecraftier", "minecraftiest"),
-    "mingy" => ("mingier", "mingiest"),
-    "minty" => ("mintier", "mintiest"),
-    "minute" => ("minuter", "minutest"),
-    "mirk" => ("mirker", "mirkest"),
-    "mirky" => ("mirkier", "mirkiest"),
-    "mirthful" => ("mirthfuller", "mirthfullest"),
-    "mirthy" => ("mirthier", "mirthiest"),
-    "miry" => ("mirier", "miriest"),
-    "misty" => ("mistier", "mistiest"),
-    "mity" => ("mitier", "mitiest"),
-    "mixy" => ("mixier", "mixiest"),
-    "moany" => ("moanier", "moaniest"),
-    "mod2" => ("modder", "moddest"),
-    "modern" => ("moderner", "modernest"),
-    "modest" => ("modester", "modestest"),
-    "moist" => ("moister", "moistest"),
-    "moisty" => ("moistier", "moistiest"),
-    "moldy" => ("moldier", "moldiest"),
-    "mommy" => ("mommier", "mommiest"),
-    "moody" => ("moodier", "moodiest"),
-    "moony" => ("moonier", "mooniest"),
-    "moory" => ("moorier", "mooriest"),
-    "mopey" => ("mopier", "mopiest"),
-    "moppy" => ("moppier", "moppiest"),
-    "mopy" => ("mopier", "mopiest"),
-    "mormony" => ("mormonier", "mormoniest"),
-    "morose" => ("moroser", "morosest"),
-    "mossy" => ("mossier", "mossiest"),
-    "mothy" => ("mothier", "mothiest"),
-    "motley" => ("motlier", "motliest"),
-    "mouldy" => ("mouldier", "mouldiest"),
-    "moulty" => ("moultier", "moultiest"),
-    "mournful" => ("mournfuller", "mournfullest"),
-    "mousey" => ("mousier", "mousiest"),
-    "mousy" => ("mousier", "mousiest"),
-    "mouthy" => ("mouthier", "mouthiest"),
-    "muckle" => ("muckler", "mucklest"),
-    "mucky" => ("muckier", "muckiest"),
-    "muddy" => ("muddier", "muddiest"),
-    "mug" => ("mugger", "muggest"),
-    "muggy" => ("muggier", "muggiest"),
-    "mumbly" => ("mumblier", "mumbliest"),
-    "mumsy" => ("mumsier", "mumsiest"),
-    "munchy" => ("munchier", "munchiest"),
-    "mundane" => ("mundaner", "mundanest"),
-    "murk" => ("murker", "murkest"),
-    "murky" => ("murkier", "murkiest"),
-    "muscly" => ("musclier", "muscliest"),
-    "mushroomy" => ("mushroomier", "mushroomiest"),
-    "mushy" => ("mushier", "mushiest"),
-    "musky" => ("muskier", "muskiest"),
-    "mussolini" => ("mussolinier", "mussoliniest"),
-    "mussy" => ("mussier", "mussiest"),
-    "musty2" => ("mustier", "mustiest"),
-    "mute" => ("muter", "mutest"),
-    "muttly" => ("muttlier", "muttliest"),
-    "muzzy" => ("muzzier", "muzziest"),
-    "myrie" => ("myrier", "myriest"),
-    "mythy" => ("mythier", "mythiest"),
-    "naff" => ("naffer", "naffest"),
-    "naggy" => ("naggier", "naggiest"),
-    "naif" => ("naifer", "naifest"),
-    "nait" => ("naiter", "naitest"),
-    "naive" => ("naiver", "naivest"),
-    "namely" => ("namelier", "nameliest"),
-    "nappy" => ("nappier", "nappiest"),
-    "narky" => ("narkier", "narkiest"),
-    "narrow" => ("narrower", "narrowest"),
-    "nasky" => ("naskier", "naskiest"),
-    "nasty" => ("nastier", "nastiest"),
-    "natty2" => ("nattier", "nattiest"),
-    "naughty" => ("naughtier", "naughtiest"),
-    "neapy" => ("neapier", "neapiest"),
-    "near" => ("nearer", "nearest"),
-    "neat" => ("neater", "neatest"),
-    "nebbie" => ("nebbier", "nebbiest"),
-    "nebby" => ("nebbier", "nebbiest"),
-    "necessary" => ("necessarier", "necessariest"),
-    "neckbeardy" => ("neckbeardier", "neckbeardiest"),
-    "needly" => ("needlier", "needliest"),
-    "needy" => ("needier", "neediest"),
-    "neighborly" => ("neighborlier", "neighborliest"),
-    "nelly" => ("nellier", "nelliest"),
-    "nerdy" => ("nerdier", "nerdiest"),
-    "nervy" => ("nervier", "nerviest"),
-    "nesh" => ("nesher", "neshest"),
-    "nether" => ("more nether", "nethermost"),
-    "netty" => ("nettier", "nettiest"),
-    "new" => ("newer", "newest"),
-    "newsworthy" => ("newsworthier", "newsworthiest"),
-    "newsy" => ("newsier", "newsiest"),
-    "nice" => ("nicer", "nicest"),
-    "niche" => ("nicher", "nichest"),
-    "niffy" => ("niffier", "niffiest"),
-    "nifty" => ("niftier", "niftiest"),
-    "nigh" => ("nigher", "nighest"),
-    "nimble" => ("nimbler", "nimblest"),
-    "nippley" => ("nipplier", "nippliest"),
-    "nipply" => ("nipplier", "nippliest"),
-    "nippy" => ("nippier", "nippiest"),
-    "nither" => ("nithermore", "nithermost"),
-    "nitpicky" => ("nitpickier", "nitpickiest"),
-    "nittie" => ("nittier", "nittiest"),
-    "nitty2" => ("nittier", "nittiest"),
-    "nobbly" => ("nobblier", "nobbliest"),
-    "nobby" => ("nobbier", "nobbiest"),
-    "noble" => ("nobler", "noblest"),
-    "noice" => ("noicer", "noicest"),
-    "noisey" => ("noisier", "noisiest"),
-    "noisy" => ("noisier", "noisiest"),
-    "noncey" => ("noncier", "nonciest"),
-    "noncy" => ("noncier", "nonciest"),
-    "nooby" => ("noobier", "noobiest"),
-    "noodly" => ("noodlier", "noodliest"),
-    "nooky" => ("nookier", "nookiest"),
-    "normie" => ("normier", "normiest"),
-    "northern2" => ("more northern", "northernmost"),
-    "nosey" => ("nosier", "nosiest"),
-    "nosy" => ("nosier", "nosiest"),
-    "notchy" => ("notchier", "notchiest"),
-    "noteworthy" => ("noteworthier", "noteworthiest"),
-    "nouny" => ("nounier", "nouniest"),
-    "nubbly" => ("nubblier", "nubbliest"),
-    "nubby" => ("nubbier", "nubbiest"),
-    "nude" => ("nuder", "nudest"),
-    "nudy" => ("nudier", "nudiest"),
-    "numb" => ("number", "numbest"),
-    "nummy" => ("nummier", "nummiest"),
-    "nutsy" => ("nutsier", "nutsiest"),
-    "nutty" => ("nuttier", "nuttiest"),
-    "nyc" => ("nycer", "nycest"),
-    "oaky" => ("oakier", "oakiest"),
-    "oaty" => ("oatier", "oatiest"),
-    "obese" => ("obeser", "obesest"),
-    "oblique" => ("obliquer", "obliquest"),
-    "obscene" => ("obscener", "obscenest"),
-    "obscure" => ("obscurer", "obscurest"),
-    "obstruse" => ("obstruser", "obstrusest"),
-    "obtuse" => ("obtuser", "obtusest"),
-    "odd" => ("odder", "oddest"),
-    "offally" => ("offallier", "offalliest"),
-    "offaly" => ("offalier", "offaliest"),
-    "oily" => ("oilier", "oiliest"),
-    "ok2" => ("oker", "okest"),
-    "okay" => ("okayer", "okayest"),
-    "old" => ("older", "oldest"),
-    "onery" => ("onerier", "oneriest"),
-    "oniony2" => ("onionier", "onioniest"),
-    "onry" => ("onrier", "onriest"),
-    "oomphy" => ("oomphier", "oomphiest"),
-    "oozy" => ("oozier", "ooziest"),
-    "opaque" => ("opaquer", "opaquest"),
-    "open" => ("opener", "openest"),
-    "orange2" => ("oranger", "orangest"),
-    "orangey" => ("orangier", "orangiest"),
-    "orangy" => ("orangier", "orangiest"),
-    "ornary" => ("ornarier", "ornariest"),
-    "ornery" => ("ornerier", "orneriest"),
-    "orsum" => ("orsumer", "orsumest"),
-    "ould" => ("oulder", "ouldest"),
-    "outdoorsy" => ("outdoorsier", "outdoorsiest"),
-    "outer2" => ("more outer", "outermost"),
-    "owld" => ("owlder", "owldest"),
-    "owly" => ("owlier", "owliest"),
-    "pacey" => ("pacier", "paciest"),
-    "pacy" => ("pacier", "paciest"),
-    "painful" => ("painfuller", "painfullest"),
-    "painty" => ("paintier", "paintiest"),
-    "pale" => ("paler", "palest"),
-    "pallid" => ("pallider", "pallidest"),
-    "pally" => ("pallier", "palliest"),
-    "palmy" => ("palmier", "palmiest"),
-    "paltry" => ("paltrier", "paltriest"),
-    "pappy" => ("pappier", "pappiest"),
-    "parky" => ("parkier", "parkiest"),
-    "pastey" => ("pastier", "pastiest"),
-    "pasty" => ("pastier", "pastiest"),
-    "patchy" => ("patchier", "patchiest"),
-    "paunchy" => ("paunchier", "paunchiest"),
-    "pawky" => ("pawkier", "pawkiest"),
-    "pawy" => ("pawier", "pawiest"),
-    "peaceful" => ("peacefuller", "peacefullest"),
-    "peachy" => ("peachier", "peachiest"),
-    "peaky" => ("peakier", "peakiest"),
-    "pearly" => ("pearlier", "pearliest"),
-    "peart" => ("pearter", "peartest"),
-    "peary" => ("pearier", "peariest"),
-    "peaty" => ("peatier", "peatiest"),
-    "pebbly" => ("pebblier", "pebbliest"),
-    "pecky" => ("peckier", "peckiest"),
-    "peepy" => ("peepier", "peepiest"),
-    "peerie" => ("peerier", "peeriest"),
-    "peery" => ("peerier", "peeriest"),
-    "peng2" => ("penger", "pengest"),
-    "peppery" => ("pepperier", "pepperiest"),
-    "peppy" => ("peppier", "peppiest"),
-    "perfect" => ("perfecter", "perfectest"),
-    "perky" => ("perkier", "perkiest"),
-    "pert" => ("perter", "pertest"),
-    "perverse" => ("perverser", "perversest"),
-    "pervy" => ("pervier", "perviest"),
-    "pesky" => ("peskier", "peskiest"),
-    "pesty" => ("pestier", "pestiest"),
-    "petty" => ("pettier", "pettiest"),
-    "phat" => ("phatter", "phattest"),
-    "phlegmy" => ("phlegmier", "phlegmiest"),
-    "phoney" => ("phonier", "phoniest"),
-    "phony" => ("phonier", "phoniest"),
-    "phunny" => ("phunnier", "phunniest"),
-    "picky" => ("pickier", "pickiest"),
-    "piddly" => ("piddlier", "piddliest"),
-    "piggy" => ("piggier", "piggiest"),
-    "pilly" => ("pillier", "pilliest"),
-    "pimply" => ("pimplier", "pimpliest"),
-    "pinchy" => ("pinchier", "pinchiest"),
-    "pink2" => ("pinker", "pinkest"),
-    "pinky" => ("pinkier", "pinkiest"),
-    "piny" => ("pinier", "piniest"),
-    "pissy" => ("pissier", "pissiest"),
-    "pitch" => ("pitcher", "pitchest"),
-    "pitchy" => ("pitchier", "pitchiest"),
-    "pithy" => ("pithier", "pithiest"),
-    "pitiful" => ("pitifuller", "pitifullest"),
-    "pizazzy" => ("pizazzier", "pizazziest"),
-    "pizzazzy" => ("pizzazzier", "pizzazziest"),
-    "placid" => ("placider", "placidest"),
-    "plaguey" => ("plaguier", "plaguiest"),
-    "plaguy" => ("plaguier", "plaguiest"),
-    "plain" => ("plainer", "plainest"),
-    "plane" => ("planer", "planest"),
-    "plantly" => ("plantlier", "plantliest"),
-    "platy" => ("platier", "platiest"),
-    "playful" => ("playfuller", "playfullest"),
-    "pleasant" => ("pleasanter", "pleasantest"),
-    "pleasaunt" => ("pleasaunter", "pleasauntest"),
-    "pleasy" => ("pleasier", "pleasiest"),
-    "plebby" => ("plebbier", "plebbiest"),
-    "plentiful" => ("plentifuller", "plentifullest"),
-    "plesaunt" => ("plesaunter", "plesauntest"),
-    "plighty" => ("plightier", "plightiest"),
-    "plinky" => ("plinkier", "plinkiest"),
-    "plonky" => ("plonkier", "plonkiest"),
-    "ploppy" => ("ploppier", "ploppiest"),
-    "plosive" => ("plosiver", "plosivest"),
-    "plotty" => ("plottier", "plottiest"),
-    "plucky" => ("pluckier", "pluckiest"),
-    "plummy" => ("plummier", "plummiest"),
-    "plump" => ("plumper", "plumpest"),
-    "plumpy" => ("plumpier", "plumpiest"),
-    "plunky" => ("plunkier", "plunkiest"),
-    "plush" => ("plusher", "plushest"),
-    "plushy" => ("plushier", "plushiest"),
-    "poachy" => ("poachier", "poachiest"),
-    "pocky" => ("pockier", "pockiest"),
-    "poddy" => ("poddier", "poddiest"),
-    "podgy" => ("podgier", "podgiest"),
-    "pointy" => ("pointier", "pointiest"),
-    "poky" => ("pokier", "pokiest"),
-    "polite" => ("politer", "politest"),
-    "poncey" => ("poncier", "ponciest"),
-    "poncy" => ("poncier", "ponciest"),
-    "pongy" => ("pongier", "pongiest"),
-    "pooey" => ("pooier", "pooiest"),
-    "poofy" => ("poofier", "poofiest"),
-    "poopy" => ("poopier", "poopiest"),
-    "poor" => ("poorer", "poorest"),
-    "poorly" => ("poorlier", "poorliest"),
-    "pooty" => ("pootier", "pootiest"),
-    "popply" => ("popplier", "poppliest"),
-    "poppy2" => ("poppier", "poppiest"),
-    "porky" => ("porkier", "porkiest"),
-    "porny" => ("pornier", "porniest"),
-    "portly" => ("portlier", "portliest"),
-    "posey" => ("posier", "posiest"),
-    "posh" => ("posher", "poshest"),
-    "poshy" => ("poshier", "poshiest"),
-    "potty" => ("pottier", "pottiest"),
-    "pouchy" => ("pouchier", "pouchiest"),
-    "pouffy" => ("pouffier", "pouffiest"),
-    "poufy" => ("poufier", "poufiest"),
-    "pouncy" => ("pouncier", "pounciest"),
-    "pouty" => ("poutier", "poutiest"),
-    "poxy" => ("poxier", "poxiest"),
-    "praiseworthy" => ("praiseworthier", "praiseworthiest"),
-    "prancy" => ("prancier", "pranciest"),
-    "pranky" => ("prankier", "prankiest"),
-    "preachy" => ("preachier", "preachiest"),
-    "precise" => ("preciser", "precisest"),
-    "preddy" => ("preddier", "preddiest"),
-    "preppy" => ("preppier", "preppiest"),
-    "pretty" => ("prettier", "prettiest"),
-    "pricey" => ("pricier", "priciest"),
-    "prickly" => ("pricklier", "prickliest"),
-    "pricky" => ("prickier", "prickiest"),
-    "pricy" => ("pricier", "priciest"),
-    "priestly2" => ("priestlier", "priestliest"),
-    "prim" => ("primmer", "primmest"),
-    "prime" => ("primer", "primest"),
-    "princely" => ("princelier", "princeliest"),
-    "princessy" => ("princessier", "princessiest"),
-    "princy" => ("princier", "princiest"),
-    "prissy" => ("prissier", "prissiest"),
-    "profane" => ("profaner", "profanest"),
-    "proggy" => ("proggier", "proggiest"),
-    "prone" => ("proner", "pronest"),
-    "prophane" => ("prophaner", "prophanest"),
-    "proppy" => ("proppier", "proppiest"),
-    "prosey" => ("prosier", "prosiest"),
-    "prosy" => ("prosier", "prosiest"),
-    "proud" => ("prouder", "proudest"),
-    "prow" => ("prower", "prowest"),
-    "prowd" => ("prowder", "prowdest"),
-    "pubby" => ("pubbier", "pubbiest"),
-    "pubey" => ("pubier", "pubiest"),
-    "puce" => ("pucer", "pucest"),
-    "puddingy" => ("puddingier", "puddingiest"),
-    "puddly" => ("puddlier", "puddliest"),
-    "pudgy" => ("pudgier", "pudgiest"),
-    "pudsy" => ("pudsier", "pudsiest"),
-    "puffy" => ("puffier", "puffiest"),
-    "puggy" => ("puggier", "puggiest"),
-    "pukey" => ("pukier", "pukiest"),
-    "puky" => ("pukier", "pukiest"),
-    "pulpy" => ("pulpier", "pulpiest"),
-    "punchy" => ("punchier", "punchiest"),
-    "punk" => ("punker", "punkest"),
-    "punky" => ("punkier", "punkiest"),
-    "punny" => ("punnier", "punniest"),
-    "puny" => ("punier", "puniest"),
-    "purdy" => ("purdier", "purdiest"),
-    "pure" => ("purer", "purest"),
-    "purple" => ("purpler", "purplest"),
-    "purply" => ("purplier", "purpliest"),
-    "purry" => ("purrier", "purriest"),
-    "pursy" => ("pursier", "pursiest"),
-    "purty" => ("purtier", "purtiest"),
-    "pushy" => ("pushier", "pushiest"),
-    "pussy2" => ("pussier", "pussiest"),
-    "pwetty" => ("pwettier", "pwettiest"),
-    "qool" => ("qooler", "qoolest"),
-    "quack" => ("quacker", "quackest"),
-    "quacky" => ("quackier", "quackiest"),
-    "quaggy" => ("quaggier", "quaggiest"),
-    "quaint" => ("quainter", "quaintest"),
-    "quakey" => ("quakier", "quakiest"),
-    "quaky" => ("quakier", "quakiest"),
-    "qualmy" => ("qualmier", "qualmiest"),
-    "quare" => ("quarer", "quarest"),
-    "quartzy" => ("quartzier", "quartziest"),
-    "quay" => ("quayer", "quayest"),
-    "queasy" => ("queasier", "queasiest"),
-    "queazy" => ("queazier", "queaziest"),
-    "queefy" => ("queefier", "queefiest"),
-    "queenly" => ("queenlier", "queenliest"),
-    "queeny" => ("queenier", "queeniest"),
-    "queer2" => ("queerer", "queerest"),
-    "queerbaity" => ("queerbaitier", "queerbaitiest"),
-    "queezy" => ("queezier", "queeziest"),
-    "quenchy" => ("quenchier", "quenchiest"),
-    "quick" => ("quicker", "quickest"),
-    "quiet" => ("quieter", "quietest"),
-    "quippy" => ("quippier", "quippiest"),
-    "quirky" => ("quirkier", "quirkiest"),
-    "quobby" => ("quobbier", "quobbiest"),
-    "quoggy" => ("quoggier", "quoggiest"),
-    "qway" => ("qwayer", "qwayest"),
-    "rabid" => ("rabider", "rabidest"),
-    "rackety" => ("racketier", "racketiest"),
-    "racy" => ("racier", "raciest"),
-    "rad" => ("radder", "raddest"),
-    "radge" => ("radger", "radgest"),
-    "radgie" => ("radgier", "radgiest"),
-    "radqueer" => ("radqueerer", "radqueerest"),
-    "rafty" => ("raftier", "raftiest"),
-    "ragey" => ("ragier", "ragiest"),
-    "raggedy" => ("raggedier", "raggediest"),
-    "raggy2" => ("raggier", "raggiest"),
-    "rainy" => ("rainier", "rainiest"),
-    "randy" => ("randier", "randiest"),
-    "rangy" => ("rangier", "rangiest"),
-    "rank" => ("ranker", "rankest"),
-    "ranty2" => ("rantier", "rantiest"),
-    "rapey" => ("rapier", "rapiest"),
-    "rapid" => ("rapider", "rapidest"),
-    "rare2" => ("rarer", "rarest"),
-    "rascally" => ("rascallier", "rascalliest"),
-    "rash" => ("rasher", "rashest"),
-    "raspberry" => ("raspberrier", "raspberriest"),
-    "raspy" => ("raspier", "raspiest"),
-    "rathe" => ("rather", "rathest"),
-    "rattly" => ("rattlier", "rattliest"),
-    "ratty" => ("rattier", "rattiest"),
-    "raughty" => ("raughtier", "raughtiest"),
-    "raunchy" => ("raunchier", "raunchiest"),
-    "raw" => ("rawer", "rawest"),
-    "ready" => ("readier", "readiest"),
-    "real" => ("realer", "realest"),
-    "reamy" => ("reamier", "reamiest"),
-    "rear2" => ("rearer", "rearest"),
-    "reckless" => ("recklesser", "recklessest"),
-    "red2" => ("redder", "reddest"),
-    "ree" => ("reer", "reest"),
-    "reedy" => ("reedier", "reediest"),
-    "reef" => ("reefer", "reefest"),
-    "reefy" => ("reefier", "reefiest"),
-    "reeky" => ("reekier", "reekiest"),
-    "reem" => ("reemer", "reemest"),
-    "remote" => ("remoter", "remotest"),
-    "rethe" => ("rether", "rethest"),
-    "revvy" => ("revvier", "revviest"),
-    "rheumy" => ("rheumier", "rheumiest"),
-    "rhymey" => ("rhymier", "rhymiest"),
-    "rhymy" => ("rhymier", "rhymiest"),
-    "ribald" => ("ribalder", "ribaldest"),
-    "rich" => ("richer", "richest"),
-    "ricketty" => ("rickettier", "rickettiest"),
-    "rickety" => ("ricketier", "ricketiest"),
-    "ridgy" => ("ridgier", "ridgiest"),
-    "rife" => ("rifer", "rifest"),
-    "riffy" => ("riffier", "riffiest"),
-    "right" => ("righter", "rightmost"),
-    "rightern" => ("more rightern", "righternmost"),
-    "rigid" => ("rigider", "rigidest"),
-    "rimy" => ("rimier", "rimiest"),
-    "ripe2" => ("riper", "ripest"),
-    "ripply" => ("ripplier", "rippliest"),
-    "risky" => ("riskier", "riskiest"),
-    "ritzy" => ("ritzier", "ritziest"),
-    "rizzy" => ("rizzier", "rizziest"),
-    "roasty" => ("roastier", "roastiest"),
-    "robust" => ("robuster", "robustest"),
-    "rocky" => ("rockier", "rockiest"),
-    "roguey" => ("roguier", "roguiest"),
-    "roguy" => ("roguier", "roguiest"),
-    "roily" => ("roilier", "roiliest"),
-    "rookie" => ("rookier", "rookiest"),
-    "roolie" => ("roolier", "rooliest"),
-    "roomy" => ("roomier", "roomiest"),
-    "roopy" => ("roopier", "roopiest"),
-    "rootsy" => ("rootsier", "rootsiest"),
-    "rooty" => ("rootier", "rootiest"),
-    "ropey" => ("ropier", "ropiest"),
-    "ropy" => ("ropier", "ropiest"),
-    "rorty" => ("rortier", "rortiest"),
-    "rosy" => ("rosier", "rosiest"),
-    "rotten" => ("rottener", "rottenest"),
-    "rotund" => ("rotunder", "rotundest"),
-    "rough" => ("rougher", "roughest"),
-    "round" => ("rounder", "roundest"),
-    "roupy" => ("roupier", "roupiest"),
-    "rowdy" => ("rowdier", "rowdiest"),
-    "royal2" => ("royaler", "royalest"),
-    "rubbery" => ("rubberier", "rubberiest"),
-    "rubbish" => ("rubbisher", "rubbishest"),
-    "rubbishy" => ("rubbishier", "rubbishiest"),
-    "rubbly" => ("rubblier", "rubbliest"),
-    "ruddy" => ("ruddier", "ruddiest"),
-    "rude" => ("ruder", "rudest"),
-    "ruff" => ("ruffer", "ruffest"),
-    "ruffly" => ("rufflier", "ruffliest"),
-    "ruggy" => ("ruggier", "ruggiest"),
-    "rulesy" => ("rulesier", "rulesiest"),
-    "ruly" => ("rulier", "ruliest"),
-    "rum" => ("rummer", "rummest"),
-    "rumbly" => ("rumblier", "rumbliest"),
-    "rummy" => ("rummier", "rummiest"),
-    "rumply" => ("rumplier", "rumpliest"),
-    "runny" => ("runnier", "runniest"),
-    "runty" => ("runtier", "runtiest"),
-    "rushy2" => ("rushier", "rushiest"),
-    "rusty2" => ("rustier", "rustiest"),
-    "rutty" => ("ruttier", "ruttiest"),
-    "sable" => ("sabler", "sablest"),
-    "sad" => ("sadder", "saddest"),
-    "safe" => ("safer", "safest"),
-    "sage" => ("sager", "sagest"),
-    "sagey" => ("sagier", "sagiest"),
-    "saggy" => ("saggier", "saggiest"),
-    "sagy" => ("sagier", "sagiest"),
-    "saintly" => ("saintlier", "saintliest"),
-    "sainty" => ("saintier", "saintiest"),
-    "sallow" => ("sallower", "sallowest"),
-    "salty" => ("saltier", "saltiest"),
-    "sandy" => ("sandier", "sandiest"),
-    "sane2" => ("saner", "sanest"),
-    "sappy2" => ("sappier", "sappiest"),
-    "sare2" => ("sarer", "sarest"),
-    "sarky" => ("sarkier", "sarkiest"),
-    "sassy" => ("sassier", "sassiest"),
-    "saucy" => ("saucier", "sauciest"),
-    "savory" => ("savorier", "savoriest"),
-    "savoury" => ("savourier", "savouriest"),
-    "savvy" => ("savvier", "savviest"),
-    "sawcy" => ("sawcier", "sawciest"),
-    "scabby" => ("scabbier", "scabbiest"),
-    "scace" => ("scacer", "scacest"),
-    "scaley" => ("scalier", "scaliest"),
-    "scaly" => ("scalier", "scaliest"),
-    "scammy" => ("scammier", "scammiest"),
-    "scant" => ("scanter", "scantest"),
-    "scanty" => ("scantier", "scantiest"),
-    "scarce" => ("scarcer", "scarcest"),
-    "scared" => ("scareder", "scaredest"),
-    "scarry" => ("scarrier", "scarriest"),
-    "scary" => ("scarier", "scariest"),
-    "scatty2" => ("scattier", "scattiest"),
-    "schemey" => ("schemier", "schemiest"),
-    "schizy" => ("schizier", "schiziest"),
-    "schizzy" => ("schizzier", "schizziest"),
-    "schlocky" => ("schlockier", "schlockiest"),
-    "schlubby" => ("schlubbier", "schlubbiest"),
-    "schlumpy" => ("schlumpier", "schlumpiest"),
-    "schmaltzy" => ("schmaltzier", "schmaltziest"),
-    "schmalzy" => ("schmalzier", "schmalziest"),
-    "schmancy" => ("schmancier", "schmanciest"),
-    "schmexy" => ("schmexier", "schmexiest"),
-    "schmoopy" => ("schmoopier", "schmoopiest"),
-    "schmoozy" => ("schmoozier", "schmooziest"),
-    "schnazzy" => ("schnazzier", "schnazziest"),
-    "scorchy" => ("scorchier", "scorchiest"),
-    "scorpy" => ("scorpier", "scorpiest"),
-    "scowly" => ("scowlier", "scowliest"),
-    "scraggly" => ("scragglier", "scraggliest"),
-    "scraggy" => ("scraggier", "scraggiest"),
-    "scranny" => ("scrannier", "scranniest"),
-    "scrappy" => ("scrappier", "scrappiest"),
-    "scratchy" => ("scratchier", "scratchiest"),
-    "scrawly" => ("scrawlier", "scrawliest"),
-    "scrawny" => ("scrawnier", "scrawniest"),
-    "screamy" => ("screamier", "screamiest"),
-    "screechy" => ("screechier", "screechiest"),
-    "screwy" => ("screwier", "screwiest"),
-    "scribbley" => ("scribblier", "scribbliest"),
-    "scribbly" => ("scribblier", "scribbliest"),
-    "scrimpy" => ("scrimpier", "scrimpiest"),
-    "scrimy" => ("scrimier", "scrimiest"),
-    "scroungy" => ("scroungier", "scroungiest"),
-    "scrubby" => ("scrubbier", "scrubbiest"),
-    "scruffy" => ("scruffier", "scruffiest"),
-    "scrummy" => ("scrummier", "scrummiest"),
-    "scrunchy" => ("scrunchier", "scrunchiest"),
-    "scrungly" => ("scrunglier", "scrungliest"),
-    "scrungy" => ("scrungier", "scrungiest"),
-    "scrunkly" => ("scrunklier", "scrunkliest"),
-    "scuffy" => ("scuffier", "scuffiest"),
-    "scummy" => ("scummier", "scummiest"),
-    "scungy" => ("scungier", "scungiest"),
-    "scurfy" => ("scurfier", "scurfiest"),
-    "scurvy" => ("scurvier", "scurviest"),
-    "scuzzy" => ("scuzzier", "scuzziest"),
-    "seamy" => ("seamier", "seamiest"),
-    "sear" => ("searer", "searest"),
-    "seaworthy" => ("seaworthier", "seaworthiest"),
-    "secure" => ("securer", "securest"),
-    "sedate" => ("sedater", "sedatest"),
-    "seedy" => ("seedier", "seediest"),
-    "seely" => ("seelier", "seeliest"),
-    "seemly" => ("seemlier", "seemliest"),
-    "seepy" => ("seepier", "seepiest"),
-    "seldom" => ("seldomer", "seldomest"),
-    "selfish" => ("selfisher", "selfishest"),
-    "selly" => ("sellier", "selliest"),
-    "sely" => ("selier", "seliest"),
-    "sendy" => ("sendier", "sendiest"),
-    "sere2" => ("serer", "serest"),
-    "serene" => ("serener", "serenest"),
-    "seveare" => ("sevearer", "sevearest"),
-    "severe" => ("severer", "severest"),
-    "sexy2" => ("sexier", "sexiest"),
-    "shabby" => ("shabbier", "shabbiest"),
-    "shacky" => ("shackier", "shackiest"),
-    "shadowy" => ("shadowier", "shadowiest"),
-    "shady" => ("shadier", "shadiest"),
-    "shafty" => ("shaftier", "shaftiest"),
-    "shaggy" => ("shaggier", "shaggiest"),
-    "shaky" => ("shakier", "shakiest"),
-    "shallow" => ("shallower", "shallowest"),
-    "shaly" => ("shalier", "shaliest"),
-    "shandy" => ("shandier", "shandiest"),
-    "shank" => ("shanker", "shankest"),
-    "shapely" => ("shapelier", "shapeliest"),
-    "sharp" => ("sharper", "sharpest"),
-    "sheen" => ("sheener", "sheenest"),
-    "sheene" => ("sheener", "sheenest"),
-    "sheeny" => ("sheenier", "sheeniest"),
-    "sheepy" => ("sheepier", "sheepiest"),
-    "sheer" => ("sheerer", "sheerest"),
-    "sheisty" => ("sheistier", "sheistiest"),
-    "shelly" => ("shellier", "shelliest"),
-    "shewy" => ("shewier", "shewiest"),
-    "shiesty" => ("shiestier", "shiestiest"),
-    "shifty" => ("shiftier", "shiftiest"),
-    "shimmery" => ("shimmerier", "shimmeriest"),
-    "shingly" => ("shinglier", "shingliest"),
-    "shiny" => ("shinier", "shiniest"),
-    "shiply" => ("shiplier", "shipliest"),
-    "shippy" => ("shippier", "shippiest"),
-    "shirty" => ("shirtier", "shirtiest"),
-    "shit" => ("shitter", "shittest"),
-    "shite" => ("shiter", "shitest"),
-    "shitey" => ("shitier", "shitiest"),
-    "shitty" => ("shittier", "shittiest"),
-    "shity" => ("shitier", "shitiest"),
-    "shivery" => ("shiverier", "shiveriest"),
-    "shlocky" => ("shlockier", "shlockiest"),
-    "shlubby" => ("shlubbier", "shlubbiest"),
-    "shmaltzy" => ("shmaltzier", "shmaltziest"),
-    "shmancy" => ("shmancier", "shmanciest"),
-    "shmexy" => ("shmexier", "shmexiest"),
-    "shnazzy" => ("shnazzier", "shnazziest"),
-    "shoal" => ("shoaler", "shoalest"),
-    "shoaly" => ("shoalier", "shoaliest"),
-    "shocky" => ("shockier", "shockiest"),
-    "shoddy" => ("shoddier", "shoddiest"),
-    "shonky" => ("shonkier", "shonkiest"),
-    "shoogly" => ("shooglier", "shoogliest"),
-    "shoppy" => ("shoppier", "shoppiest"),
-    "short" => ("shorter", "shortest"),
-    "shouty" => ("shoutier", "shoutiest"),
-    "showery" => ("showerier", "showeriest"),
-    "showy" => ("showier", "showiest"),
-    "shreddy" => ("shreddier", "shreddiest"),
-    "shrewd" => ("shrewder", "shrewdest"),
-    "shrewde" => ("shrewder", "shrewdest"),
-    "shrieky" => ("shriekier", "shriekiest"),
-    "shrill" => ("shriller", "shrillest"),
-    "shrimpy" => ("shrimpier", "shrimpiest"),
-    "shrinky" => ("shrinkier", "shrinkiest"),
-    "shroomy" => ("shroomier", "shroomiest"),
-    "shrubby" => ("shrubbier", "shrubbiest"),
-    "shticky" => ("shtickier", "shtickiest"),
-    "shucky" => ("shuckier", "shuckiest"),
-    "shweet" => ("shweeter", "shweetest"),
-    "shy" => ("shier", "shiest"),
-    "shystie" => ("shystier", "shystiest"),
-    "shysty" => ("shystier", "shystiest"),
-    "sib" => ("sibber", "sibbest"),
-    "sick" => ("sicker", "sickest"),
-    "sickly" => ("sicklier", "sickliest"),
-    "sightly" => ("sightlier", "sightliest"),
-    "silent" => ("silenter", "silentest"),
-    "silky" => ("silkier", "silkiest"),
-    "silly" => ("sillier", "silliest"),
-    "silty" => ("siltier", "siltiest"),
-    "silvery" => ("silverier", "silveriest"),
-    "simple" => ("simpler", "simplest"),
-    "simpy" => ("simpier", "simpiest"),
-    "sincere" => ("sincerer", "sincerest"),
-    "sinful" => ("sinfuller", "sinfullest"),
-    "sinky" => ("sinkier", "sinkiest"),
-    "sinny" => ("sinnier", "sinniest"),
-    "sissy" => ("sissier", "sissiest"),
-    "skanky" => ("skankier", "skankiest"),
-    "skeely" => ("skeelier", "skeeliest"),
-    "skeery" => ("skeerier", "skeeriest"),
-    "skeevy" => ("skeevier", "skeeviest"),
-    "skeezy" => ("skeezier", "skeeziest"),
-    "sketchy" => ("sketchier", "sketchiest"),
-    "sketty" => ("skettier", "skettiest"),
-    "skew" => ("skewer", "skewest"),
-    "skiddy" => ("skiddier", "skiddiest"),
-    "skill" => ("skiller", "skillest"),
-    "skimpy" => ("skimpier", "skimpiest"),
-    "skinchy" => ("skinchier", "skinchiest"),
-    "skinny" => ("skinnier", "skinniest"),
-    "skint" => ("skinter", "skintest"),
-    "skippy" => ("skippier", "skippiest"),
-    "skody" => ("skodier", "skodiest"),
-    "skrunkly" => ("skrunklier", "skrunkliest"),
-    "skunky" => ("skunkier", "skunkiest"),
-    "skuzzy" => ("skuzzier", "skuzziest"),
-    "skyey" => ("skyier", "skyiest"),
-    "slab" => ("slabber", "slabbest"),
-    "slabby" => ("slabbier", "slabbiest"),
-    "slack" => ("slacker", "slackest"),
-    "slaggy" => ("slaggier", "slaggiest"),
-    "slangy" => ("slangier", "slangiest"),
-    "slanty" => ("slantier", "slantiest"),
-    "slaphappy" => ("slaphappier", "slaphappiest"),
-    "slappy" => ("slappier", "slappiest"),
-    "slapsticky" => ("slapstickier", "slapstickiest"),
-    "slashy" => ("slashier", "slashiest"),
-    "slatey" => ("slatier", "slatiest"),
-    "slaty" => ("slatier", "slatiest"),
-    "sleazy" => ("sleazier", "sleaziest"),
-    "slee" => ("sleer", "sleest"),
-    "sleechy" => ("sleechier", "sleechiest"),
-    "sleek" => ("sleeker", "sleekest"),
-    "sleeky" => ("sleekier", "sleekiest"),
-    "sleepy" => ("sleepier", "sleepiest"),
-    "sleety" => ("sleetier", "sleetiest"),
-    "sleezy" => ("sleezier", "sleeziest"),
-    "sleigh" => ("sleigher", "sleighest"),
-    "slender" => ("slenderer", "slenderest"),
-    "slick" => ("slicker", "slickest"),
-    "sliddery" => ("slidderier", "slidderiest"),
-    "slidy" => ("slidier", "slidiest"),
-    "slight" => ("slighter", "slightest"),
-    "slim" => ("slimmer", "slimmest"),
-    "slimey" => ("slimier", "slimiest"),
-    "slimpsy" => ("slimpsier", "slimpsiest"),
-    "slimy" => ("slimier", "slimiest"),
-    "slinky" => ("slinkier", "slinkiest"),
-    "slippery" => ("slipperier", "slipperiest"),
-    "slippy" => ("slippier", "slippiest"),
-    "slithery" => ("slitherier", "slitheriest"),
-    "slitty" => ("slittier", "slittiest"),
-    "sloaney" => ("sloanier", "sloaniest"),
-    "slobbery" => ("slobberier", "slobberiest"),
-    "slobby" => ("slobbier", "slobbiest"),
-    "sloppy" => ("sloppier", "sloppiest"),
-    "slopy" => ("slopier", "slopiest"),
-    "sloshy" => ("sloshier", "sloshiest"),
-    "slouchy" => ("slouchier", "slouchiest"),
-    "slovenly" => ("slovenlier", "slovenliest"),
-    "slow" => ("slower", "slowest"),
-    "sludgy" => ("sludgier", "sludgiest"),
-    "sluggish" => ("sluggisher", "sluggishest"),
-    "slummy" => ("slummier", "slummiest"),
-    "slumpy" => ("slumpier", "slumpiest"),
-    "slurpy" => ("slurpier", "slurpiest"),
-    "slurry" => ("slurrier", "slurriest"),
-    "slushy" => ("slushier", "slushiest"),
-    "slutty" => ("sluttier", "sluttiest"),
-    "sly" => ("slier", "sliest"),
-    "smacky" => ("smackier", "smackiest"),
-    "small" => ("smaller", "smallest"),
-    "smarmy" => ("smarmier", "smarmiest"),
-    "smart2" => ("smarter", "smartest"),
-    "smeary" => ("smearier", "smeariest"),
-    "smelly" => ("smellier", "smelliest"),
-    "smexy" => ("smexier", "smexiest"),
-    "smidgey" => ("smidgier", "smidgiest"),
-    "smidgy" => ("smidgier", "smidgiest"),
-    "smiley" => ("smilier", "smiliest"),
-    "smirky" => ("smirkier", "smirkiest"),
-    "smoggy" => ("smoggier", "smoggiest"),
-    "smoky" => ("smokier", "smokiest"),
-    "smol" => ("smoler", "smolest"),
-    "smoochy" => ("smoochier", "smoochiest"),
-    "smooth" => ("smoother", "smoothest"),
-    "smoothy" => ("smoothier", "smoothiest"),
-    "smoove" => ("smoover", "smoovest"),
-    "smudgey" => ("smudgier", "smudgiest"),
-    "smudgy" => ("smudgier", "smudgiest"),
-    "smug" => ("smugger", "smuggest"),
-    "smurfy" => ("smurfier", "smurfiest"),
-    "smushy" => ("smushier", "smushiest"),
-    "smutty" => ("smuttier", "smuttiest"),
-    "snacky" => ("snackier", "snackiest"),
-    "snaggy" => ("snaggier", "snaggiest"),
-    "snaily" => ("snailier", "snailiest"),
-    "snaky" => ("snakier", "snakiest"),
-    "snappy" => ("snappier", "snappiest"),
-    "snarky" => ("snarkier", "snarkiest"),
-    "snarly" => ("snarlier", "snarliest"),
-    "snassy" => ("snassier", "snassiest"),
-    "snatchy" => ("snatchier", "snatchiest"),
-    "snazzy" => ("snazzier", "snazziest"),
-    "sneaky" => ("sneakier", "sneakiest"),
-    "sneery" => ("sneerier", "sneeriest"),
-    "sneezy" => ("sneezier", "sneeziest"),
-    "snell" => ("sneller", "snellest"),
-    "snide" => ("snider", "snidest"),
-    "sniffly" => ("snifflier", "sniffliest"),
-    "sniffy" => ("sniffier", "sniffiest"),
-    "snipey" => ("snipier", "snipiest"),
-    "snippy" => ("snippier", "snippiest"),
-    "snipy" => ("snipier", "snipiest"),
-    "snitchy" => ("snitchier", "snitchiest"),
-    "snithy" => ("snithier", "snithiest"),
-    "snitty" => ("snittier", "snittiest"),
-    "snobby" => ("snobbier", "snobbiest"),
-    "snoopy" => ("snoopier", "snoopiest"),
-    "snooty" => ("snootier", "snootiest"),
-    "snoozy" => ("snoozier", "snooziest"),
-    "snorty" => ("snortier", "snortiest"),
-    "snory" => ("snorier", "snoriest"),
-    "snotty" => ("snottier", "snottiest"),
-    "snouty" => ("snoutier", "snoutiest"),
-    "snowy" => ("snowier", "snowiest"),
-    "snubby" => ("snubbier", "snubbiest"),
-    "snuffy" => ("snuffier", "snuffiest"),
-    "snug" => ("snugger", "snuggest"),
-    "snuggly" => ("snugglier", "snuggliest"),
-    "soaky" => ("soakier", "soakiest"),
-    "soapy" => ("soapier", "soapiest"),
-    "sobby" => ("sobbier", "sobbiest"),
-    "sober" => ("soberer", "soberest"),
-    "soft" => ("softer", "softest"),
-    "soggy" => ("soggier", "soggiest"),
-    "soily" => ("soilier", "soiliest"),
-    "solemn" => ("solemner", "solemnest"),
-    "solid" => ("solider", "solidest"),
-    "solwy" => ("solwier", "solwiest"),
-    "somber" => ("somberer", "somberest"),
-    "sombre" => ("sombrer", "sombrest"),
-    "songly" => ("songlier", "songliest"),
-    "songy" => ("songier", "songiest"),
-    "sonly" => ("sonlier", "sonliest"),
-    "sooky" => ("sookier", "sookiest"),
-    "soon" => ("sooner", "soonest"),
-    "sooth" => ("soother", "soothest"),
-    "soothy" => ("soothier", "soothiest"),
-    "sooty" => ("sootier", "sootiest"),
-    "soppy" => ("soppier", "soppiest"),
-    "sordid" => ("sordider", "sordidest"),
-    "sore" => ("sorer", "sorest"),
-    "sorry" => ("sorrier", "sorriest"),
-    "sound" => ("sounder", "soundest"),
-    "soupy" => ("soupier", "soupiest"),
-    "sour" => ("sourer", "sourest"),
-    "southern2" => ("more southern", "southernmost"),
-    "sower" => ("sowerer", "sowerest"),
-    "soy" => ("soyer", "soyest"),
-    "spacey" => ("spacier", "spaciest"),
-    "spacky" => ("spackier", "spackiest"),
-    "spacy" => ("spacier", "spaciest"),
-    "spammy" => ("spammier", "spammiest"),
-    "spangly" => ("spanglier", "spangliest"),
-    "spare" => ("sparer", "sparest"),
-    "sparkly" => ("sparklier", "sparkliest"),
-    "sparky" => ("sparkier", "sparkiest"),
-    "sparse" => ("sparser", "sparsest"),
-    "spazzy" => ("spazzier", "spazziest"),
-    "special" => ("specialer", "specialest"),
-    "specky2" => ("speckier", "speckiest"),
-    "speechworthy" => ("speechworthier", "speechworthiest"),
-    "speedy" => ("speedier", "speediest"),
-    "spendy" => ("spendier", "spendiest"),
-    "spergy" => ("spergier", "spergiest"),
-    "spermy" => ("spermier", "spermiest"),
-    "spick" => ("spicker", "spickest"),
-    "spicy" => ("spicier", "spiciest"),
-    "spiffy" => ("spiffier", "spiffiest"),
-    "spikey" => ("spikier", "spikiest"),
-    "spiky" => ("spikier", "spikiest"),
-    "spindly" => ("spindlier", "spindliest"),
-    "spinny" => ("spinnier", "spinniest"),
-    "spiny" => ("spinier", "spiniest"),
-    "spivvy" => ("spivvier", "spivviest"),
-    "splashy" => ("splashier", "splashiest"),
-    "splendid" => ("splendider", "splendidest"),
-    "splishy" => ("splishier", "splishiest"),
-    "splitty" => ("splittier", "splittiest"),
-    "splodgy" => ("splodgier", "splodgiest"),
-    "sploshy" => ("sploshier", "sploshiest"),
-    "splotchy" => ("splotchier", "splotchiest"),
-    "splurgy" => ("splurgier", "splurgiest"),
-    "spluttery" => ("splutterier", "splutteriest"),
-    "spongey" => ("spongier", "spongiest"),
-    "spongy" => ("spongier", "spongiest"),
-    "spoofy" => ("spoofier", "spoofiest"),
-    "spooky" => ("spookier", "spookiest"),
-    "spooney" => ("spoonier", "spooniest"),
-    "spoony" => ("spoonier", "spooniest"),
-    "spoopy" => ("spoopier", "spoopiest"),
-    "sporty" => ("sportier", "sportiest"),
-    "spotty" => ("spottier", "spottiest"),
-    "sprauncy" => ("sprauncier", "spraunciest"),
-    "sprawly" => ("sprawlier", "sprawliest"),
-    "sprayey" => ("sprayier", "sprayiest"),
-    "spriggy" => ("spriggier", "spriggiest"),
-    "sprightlie" => ("sprightlier", "sprightliest"),
-    "sprightly" => ("sprightlier", "sprightliest"),
-    "springy" => ("springier", "springiest"),
-    "spritely" => ("spritelier", "spriteliest"),
-    "spritzy" => ("spritzier", "spritziest"),
-    "sprouty" => ("sproutier", "sproutiest"),
-    "spruce" => ("sprucer", "sprucest"),
-    "sprucy" => ("sprucier", "spruciest"),
-    "spry" => ("sprier", "spriest"),
-    "spumy" => ("spumier", "spumiest"),
-    "spunky" => ("spunkier", "spunkiest"),
-    "squabby" => ("squabbier", "squabbiest"),
-    "squalid" => ("squalider", "squalidest"),
-    "squally" => ("squallier", "squalliest"),
-    "squammy" => ("squammier", "squammiest"),
-    "square" => ("squarer", "squarest"),
-    "squashy" => ("squashier", "squashiest"),
-    "squat" => ("squatter", "squattest"),
-    "squatty" => ("squattier", "squattiest"),
-    "squawky" => ("squawkier", "squawkiest"),
-    "squeaky" => ("squeakier", "squeakiest"),
-    "squealy" => ("squealier", "squealiest"),
-    "squeezy" => ("squeezier", "squeeziest"),
-    "squelchy" => ("squelchier", "squelchiest"),
-    "squicky" => ("squickier", "squickiest"),
-    "squidgy" => ("squidgier", "squidgiest"),
-    "squiffy" => ("squiffier", "squiffiest"),
-    "squiggley" => ("squigglier", "squiggliest"),
-    "squiggly" => ("squigglier", "squiggliest"),
-    "squinchy" => ("squinchier", "squinchiest"),
-    "squinty" => ("squintier", "squintiest"),
-    "squirmy" => ("squirmier", "squirmiest"),
-    "squirrelly" => ("squirrellier", "squirrelliest"),
-    "squishy" => ("squishier", "squishiest"),
-    "squooshy" => ("squooshier", "squooshiest"),
-    "squshy" => ("squshier", "squshiest"),
-    "squushy" => ("squushier", "squushiest"),
-    "stabby" => ("stabbier", "stabbiest"),
-    "stable" => ("stabler", "stablest"),
-    "stageworthy" => ("stageworthier", "stageworthiest"),
-    "staggy" => ("staggier", "staggiest"),
-    "stagy" => ("stagier", "stagiest"),
-    "staid" => ("staider", "staidest"),
-    "stale2" => ("staler", "stalest"),
-    "stalky" => ("stalkier", "stalkiest"),
-    "stanch" => ("stancher", "stanchest"),
-    "stanky2" => ("stankier", "stankiest"),
-    "starchy" => ("starchier", "starchiest"),
-    "starey" => ("starier", "stariest"),
-    "stark" => ("starker", "starkest"),
-    "starry" => ("starrier", "starriest"),
-    "stary" => ("starier", "stariest"),
-    "stately" => ("statelier", "stateliest"),
-    "staunch" => ("stauncher", "staunchest"),
-    "stay" => ("stayer", "stayest"),
-    "steadfast" => ("steadfaster", "steadfastest"),
-    "steady" => ("steadier", "steadiest"),
-    "steaky" => ("steakier", "steakiest"),
-    "stealthy" => ("stealthier", "stealthiest"),
-    "steamy" => ("steamier", "steamiest"),
-    "stedfast" => ("stedfaster", "stedfastest"),
-    "steely" => ("steelier", "steeliest"),
-    "steep" => ("steeper", "steepest"),
-    "steepy" => ("steepier", "steepiest"),
-    "steezy" => ("steezier", "steeziest"),
-    "stemmy" => ("stemmier", "stemmiest"),
-    "stenchy" => ("stenchier", "stenchiest"),
-    "stern" => ("sterner", "sternest"),
-    "sterne" => ("sterner", "sternest"),
-    "stick" => ("sticker", "stickest"),
-    "sticky" => ("stickier", "stickiest"),
-    "stiddy" => ("stiddier", "stiddiest"),
-    "stiff" => ("stiffer", "stiffest"),
-    "still" => ("stiller", "stillest"),
-    "stilly" => ("stillier", "stilliest"),
-    "stilty" => ("stiltier", "stiltiest"),
-    "stingy" => ("stingier", "stingiest"),
-    "stinky" => ("stinkier", "stinkiest"),
-    "stocky" => ("stockier", "stockiest"),
-    "stodgy" => ("stodgier", "stodgiest"),
-    "stolid" => ("stolider", "stolidest"),
-    "stompy" => ("stompier", "stompiest"),
-    "stony" => ("stonier", "stoniest"),
-    "stoopid" => ("stoopider", "stoopidest"),
-    "stoor" => ("stoorer", "stoorest"),
-    "storky" => ("storkier", "storkiest"),
-    "stormy" => ("stormier", "stormiest"),
-    "stout" => ("stouter", "stoutest"),
-    "straggly" => ("stragglier", "straggliest"),
-    "straicht" => ("straichter", "straichtest"),
-    "straight" => ("straighter", "straightest"),
-    "strait" => ("straiter", "straitest"),
-    "strang" => ("stranger", "strangest"),
-    "strange" => ("stranger", "strangest"),
-    "strappy" => ("strappier", "strappiest"),
-    "straung" => ("straunger", "straungest"),
-    "strawberry" => ("strawberrier", "strawberriest"),
-    "strawy" => ("strawier", "strawiest"),
-    "streaky" => ("streakier", "streakiest"),
-    "streamy" => ("streamier", "streamiest"),
-    "streetworthy" => ("streetworthier", "streetworthiest"),
-    "strengthy" => ("strengthier", "strengthiest"),
-    "stressy" => ("stressier", "stressiest"),
-    "stretchy" => ("stretchier", "stretchiest"),
-    "strict" => ("stricter", "strictest"),
-    "strikt" => ("strikter", "striktest"),
-    "stringy" => ("stringier", "stringiest"),
-    "stripey" => ("stripier", "stripiest"),
-    "stripy" => ("stripier", "stripiest"),
-    "strong" => ("stronger", "strongest"),
-    "stronk" => ("stronker", "stronkest"),
-    "stroppy" => ("stroppier", "stroppiest"),
-    "stubbly" => ("stubblier", "stubbliest"),
-    "stubborn" => ("stubborner", "stubbornest"),
-    "stubby" => ("stubbier", "stubbiest"),
-    "studly" => ("studlier", "studliest"),
-    "stuffy" => ("stuffier", "stuffiest"),
-    "stumbly" => ("stumblier", "stumbliest"),
-    "stumpy" => ("stumpier", "stumpiest"),
-    "stupid" => ("stupider", "stupidest"),
-    "sturdy" => ("sturdier", "sturdiest"),
-    "stuttery" => ("stutterier", "stutteriest"),
-    "suave" => ("suaver", "suavest"),
-    "subby" => ("subbier", "subbiest"),
-    "sublime" => ("sublimer", "sublimest"),
-    "subtile" => ("subtiler", "subtilest"),
-    "subtle" => ("subtler", "subtlest"),
-    "succinct" => ("succincter", "succinctest"),
-    "suckly" => ("sucklier", "suckliest"),
-    "sucky" => ("suckier", "suckiest"),
-    "sudden" => ("suddener", "suddenest"),
-    "sudsy" => ("sudsier", "sudsiest"),
-    "sugary" => ("sugarier", "sugariest"),
-    "sulky" => ("sulkier", "sulkiest"),
-    "sullen" => ("sullener", "sullenest"),
-    "sultry" => ("sultrier", "sultriest"),
-    "summery" => ("summerier", "summeriest"),
-    "sunly" => ("sunlier", "sunliest"),
-    "sunny" => ("sunnier", "sunniest"),
-    "superb" => ("superber", "superbest"),
-    "supple" => ("suppler", "supplest"),
-    "supream" => ("supreamer", "supreamest"),
-    "supreme" => ("supremer", "supremest"),
-    "sure" => ("surer", "surest"),
-    "surfy" => ("surfier", "surfiest"),
-    "surly" => ("surlier", "surliest"),
-    "sus2" => ("susser", "sussest"),
-    "sussy" => ("sussier", "sussiest"),
-    "svelte" => ("svelter", "sveltest"),
-    "swack" => ("swacker", "swackest"),
-    "swaggy" => ("swaggier", "swaggiest"),
-    "swaly" => ("swalier", "swaliest"),
-    "swampy" => ("swampier", "swampiest"),
-    "swank" => ("swanker", "swankest"),
-    "swanky" => ("swankier", "swankiest"),
-    "swanny" => ("swannier", "swanniest"),
-    "swarmy" => ("swarmier", "swarmiest"),
-    "swart" => ("swarter", "swartest"),
-    "swarthy" => ("swarthier", "swarthiest"),
-    "swash" => ("swasher", "swashest"),
-    "swear" => ("swearer", "swearest"),
-    "sweary" => ("swearier", "sweariest"),
-    "sweaty" => ("sweatier", "sweatiest"),
-    "swebby" => ("swebbier", "swebbiest"),
-    "sweepy" => ("sweepier", "sweepiest"),
-    "sweet" => ("sweeter", "sweetest"),
-    "swell" => ("sweller", "swellest"),
-    "swerfy" => ("swerfier", "swerfiest"),
-    "swicy" => ("swicier", "swiciest"),
-    "swift" => ("swifter", "swiftest"),
-    "swimmy" => ("swimmier", "swimmiest"),
-    "swinging2" => ("swinginger", "swingingest"),
-    "swingy" => ("swingier", "swingiest"),
-    "swirly" => ("swirlier", "swirliest"),
-    "swish" => ("swisher", "swishest"),
-    "swishy" => ("swishier", "swishiest"),
-    "switchy" => ("switchier", "switchiest"),
-    "swith" => ("swither", "swithest"),
-    "swole" => ("swoler", "swolest"),
-    "swoopy" => ("swoopier", "swoopiest"),
-    "swotty" => ("swottier", "swottiest"),
-    "syrupy" => ("syrupier", "syrupiest"),
-    "tabby" => ("tabbier", "tabbiest"),
-    "tacky" => ("tackier", "tackiest"),
-    "taggy" => ("taggier", "taggiest"),
-    "talksy" => ("talksier", "talksiest"),
-    "talky" => ("talkier", "talkiest"),
-    "tall" => ("taller", "tallest"),
-    "tame" => ("tamer", "tamest"),
-    "tan" => ("tanner", "tannest"),
-    "tangly" => ("tanglier", "tangliest"),
-    "tangy" => ("tangier", "tangiest"),
-    "tanky" => ("tankier", "tankiest"),
-    "tardy" => ("tardier", "tardiest"),
-    "tarry" => ("tarrier", "tarriest"),
-    "tart" => ("tarter", "tartest"),
-    "tarty" => ("tartier", "tartiest"),
-    "tasky" => ("taskier", "taskiest"),
-    "tasty" => ("tastier", "tastiest"),
-    "tatty" => ("tattier", "tattiest"),
-    "taut" => ("tauter", "tautest"),
-    "tawdry" => ("tawdrier", "tawdriest"),
-    "tawny" => ("tawnier", "tawniest"),
-    "teary" => ("tearier", "teariest"),
-    "techy2" => ("techier", "techiest"),
-    "teensy" => ("teensier", "teensiest"),
-    "teentsy" => ("teentsier", "teentsiest"),
-    "teenty" => ("teentier", "teentiest"),
-    "teeny" => ("teenier", "teeniest"),
-    "tender" => ("tenderer", "tenderest"),
-    "tense" => ("tenser", "tensest"),
-    "terfy" => ("terfier", "terfiest"),
-    "terrible" => ("terribler", "terriblest"),
-    "terse" => ("terser", "tersest"),
-    "testy" => ("testier", "testiest"),
-    "tetchy" => ("tetchier", "tetchiest"),
-    "teuch" => ("teucher", "teuchest"),
-    "thankworthy" => ("thankworthier", "thankworthiest"),
-    "thic" => ("thiccer", "thiccest"),
-    "thicc" => ("thiccer", "thiccest"),
-    "thick" => ("thicker", "thickest"),
-    "thin" => ("thinner", "thinnest"),
-    "thingy" => ("thingier", "thingiest"),
-    "thirsty" => ("thirstier", "thirstiest"),
-    "thistly" => ("thistlier", "thistliest"),
-    "thither" => ("more thither", "thithermost"),
-    "thoan" => ("thoaner", "thoanest"),
-    "thone" => ("thoner", "thonest"),
-    "thorny" => ("thornier", "thorniest"),
-    "thoro" => ("thoroer", "thoroest"),
-    "thorough" => ("thorougher", "thoroughest"),
-    "thotty" => ("thottier", "thottiest"),
-    "thoughtworthy" => ("thoughtworthier", "thoughtworthiest"),
-    "thoughty" => ("thoughtier", "thoughtiest"),
-    "thrashy" => ("thrashier", "thrashiest"),
-    "thready" => ("threadier", "threadiest"),
-    "thrifty" => ("thriftier", "thriftiest"),
-    "thrilly" => ("thrillier", "thrilliest"),
-    "throaty" => ("throatier", "throatiest"),
-    "throbby" => ("throbbier", "throbbiest"),
-    "throng" => ("thronger", "throngest"),
-    "thuggy" => ("thuggier", "thuggiest"),
-    "thumby" => ("thumbier", "thumbiest"),
-    "thumpy" => ("thumpier", "thumpiest"),
-    "thungry" => ("thungrier", "thungriest"),
-    "thwarty" => ("thwartier", "thwartiest"),
-    "thymey" => ("thymier", "thymiest"),
-    "thymy" => ("thymier", "thymiest"),
-    "tichy" => ("tichier", "tichiest"),
-    "tickly" => ("ticklier", "tickliest"),
-    "ticky" => ("tickier", "tickiest"),
-    "tiddly2" => ("tiddlier", "tiddliest"),
-    "tiddy" => ("tiddier", "tiddiest"),
-    "tidy" => ("tidier", "tidiest"),
-    "tight" => ("tighter", "tightest"),
-    "timely" => ("timelier", "timeliest"),
-    "timid" => ("timider", "timidest"),
-    "tine" => ("tiner", "tinest"),
-    "tinfoily" => ("tinfoilier", "tinfoiliest"),
-    "tingly" => ("tinglier", "tingliest"),
-    "tinkly" => ("tinklier", "tinkliest"),
-    "tinny" => ("tinnier", "tinniest"),
-    "tiny" => ("tinier", "tiniest"),
-    "tippy" => ("tippier", "tippiest"),
-    "tipsy" => ("tipsier", "tipsiest"),
-    "tired2" => ("tireder", "tiredest"),
-    "titchy" => ("titchier", "titchiest"),
-    "toasty" => ("toastier", "toastiest"),
-    "toey" => ("toeier", "toeiest"),
-    "toffy" => ("toffier", "toffiest"),
-    "tomatoey" => ("tomatoier", "tomatoiest"),
-    "toney" => ("tonier", "toniest"),
-    "tonguey" => ("tonguier", "tonguiest"),
-    "tonguy" => ("tonguier", "tonguiest"),
-    "tonk" => ("tonker", "tonkest"),
-    "tony" => ("tonier", "toniest"),
-    "toolsy" => ("toolsier", "toolsiest"),
-    "toothly" => ("toothlier", "toothliest"),
-    "toothy" => ("toothier", "toothiest"),
-    "toplofty" => ("toploftier", "toploftiest"),
-    "torchy" => ("torchier", "torchiest"),
-    "torquey" => ("torquier", "torquiest"),
-    "torrid" => ("torrider", "torridest"),
-    "tort2" => ("torter", "tortest"),
-    "tosh" => ("tosher", "toshest"),
-    "toshy" => ("toshier", "toshiest"),
-    "tottery" => ("totterier", "totteriest"),
-    "touchy" => ("touchier", "touchiest"),
-    "tough" => ("tougher", "toughest"),
-    "touristy" => ("touristier", "touristiest"),
-    "towny" => ("townier", "towniest"),
-    "towzy" => ("towzier", "towziest"),
-    "trace" => ("more trace", "tracest"),
-    "traddy" => ("traddier", "traddiest"),
-    "trampy" => ("trampier", "trampiest"),
-    "tranquil" => ("tranquiler", "tranquilest"),
-    "tranty" => ("trantier", "trantiest"),
-    "trappy" => ("trappier", "trappiest"),
-    "trashy" => ("trashier", "trashiest"),
-    "treacly" => ("treaclier", "treacliest"),
-    "trebly" => ("treblier", "trebliest"),
-    "treey" => ("treeier", "treeiest"),
-    "trembly" => ("tremblier", "trembliest"),
-    "trendy" => ("trendier", "trendiest"),
-    "trick" => ("tricker", "trickest"),
-    "tricksy" => ("tricksier", "tricksiest"),
-    "tricky" => ("trickier", "trickiest"),
-    "trig" => ("trigger", "triggest"),
-    "trill" => ("triller", "trillest"),
-    "trim" => ("trimmer", "trimmest"),
-    "tripey" => ("tripier", "tripiest"),
-    "trippy" => ("trippier", "trippiest"),
-    "tripy" => ("tripier", "tripiest"),
-    "trite" => ("triter", "tritest"),
-    "trouty" => ("troutier", "troutiest"),
-    "trucky" => ("truckier", "truckiest"),
-    "true2" => ("truer", "truest"),
-    "trumpy" => ("trumpier", "trumpiest"),
-    "trusty" => ("trustier", "trustiest"),
-    "truthy" => ("truthier", "truthiest"),
-    "tubby" => ("tubbier", "tubbiest"),
-    "tuff" => ("tuffer", "tuffest"),
-    "tufty" => ("tuftier", "tuftiest"),
-    "tuny" => ("tunier", "tuniest"),
-    "turfy" => ("turfier", "turfiest"),
-    "turpsy" => ("turpsier", "turpsiest"),
-    "tusky" => ("tuskier", "tuskiest"),
-    "twangy" => ("twangier", "twangiest"),
-    "twatty" => ("twattier", "twattiest"),
-    "tweaky" => ("tweakier", "tweakiest"),
-    "twee" => ("tweer", "tweest"),
-    "tweedy" => ("tweedier", "tweediest"),
-    "tweeny" => ("tweenier", "tweeniest"),
-    "tweety" => ("tweetier", "tweetiest"),
-    "twiddly" => ("twiddlier", "twiddliest"),
-    "twiggy" => ("twiggier", "twiggiest"),
-    "twinkly" => ("twinklier", "twinkliest"),
-    "twinky" => ("twinkier", "twinkiest"),
-    "twirly" => ("twirlier", "twirliest"),
-    "twisty" => ("twistier", "twistiest"),
-    "twitchy" => ("twitchier", "twitchiest"),
-    "twitty" => ("twittier", "twittiest"),
-    "twunky" => ("twunkier", "twunkiest"),
-    "typey" => ("typier", "typiest"),
-    "typy" => ("typier", "typiest"),
-    "u2" => ("uer", "uest"),
-    "ugly" => ("uglier", "ugliest"),
-    "ultradry" => ("ultradrier", "ultradriest"),
-    "unable" => ("unabler", "unablest"),
-    "unbusy" => ("unbusier", "unbusiest"),
-    "uncanny" => ("uncannier", "uncanniest"),
-    "unclean" => ("uncleaner", "uncleanest"),
-    "unclear" => ("unclearer", "unclearest"),
-    "uncomely" => ("uncomelier", "uncomeliest"),
-    "uncommon" => ("uncommoner", "uncommonest"),
-    "uncool" => ("uncooler", "uncoolest"),
-    "uncouth" => ("uncouther", "uncouthest"),
-    "undirty" => ("undirtier", "undirtiest"),
-    "unearthly" => ("unearthlier", "unearthliest"),
-    "uneasy" => ("uneasier", "uneasiest"),
-    "unfair" => ("unfairer", "unfairest"),
-    "unfit" => ("unfitter", "unfittest"),
-    "unfree" => ("unfreer", "unfreest"),
-    "unfriendly" => ("unfriendlier", "unfriendliest"),
-    "unfull" => ("unfuller", "unfullest"),
-    "unfunny" => ("unfunnier", "unfunniest"),
-    "unfussy" => ("unfussier", "unfussiest"),
-    "ungainly" => ("ungainlier", "ungainliest"),
-    "ungentle" => ("ungentler", "ungentlest"),
-    "ungodly" => ("ungodlier", "ungodliest"),
-    "ungood" => ("ungooder", "ungoodest"),
-    "unhandsome" => ("unhandsomer", "unhandsomest"),
-    "unhandy" => ("unhandier", "unhandiest"),
-    "unhappy" => ("unhappier", "unhappiest"),
-    "unhealthy" => ("unhealthier", "unhealthiest"),
-    "unholy" => ("unholier", "unholiest"),
-    "unique" => ("uniquer", "uniquest"),
-    "unkind" => ("unkinder", "unkindest"),
-    "unlikely" => ("unlikelier", "unlikeliest"),
-    "unlovely" => ("unlovelier", "unloveliest"),
-    "unlucky" => ("unluckier", "unluckiest"),
-    "unmad" => ("unmadder", "unmaddest"),
-    "unmanly" => ("unmanlier", "unmanliest"),
-    "unmannerly" => ("unmannerlier", "unmannerliest"),
-    "unpleasant" => ("unpleasanter", "unpleasantest"),
-    "unpretty" => ("unprettier", "unprettiest"),
-    "unquiet" => ("unquieter", "unquietest"),
-    "unripe" => ("unriper", "unripest"),
-    "unruly" => ("unrulier", "unruliest"),
-    "unsad" => ("unsadder", "unsaddest"),
-    "unsafe" => ("unsafer", "unsafest"),
-    "unseemly" => ("unseemlier", "unseemliest"),
-    "unsexy" => ("unsexier", "unsexiest"),
-    "unskinny" => ("unskinnier", "unskinniest"),
-    "unsound" => ("unsounder", "unsoundest"),
-    "unsteady" => ("unsteadier", "unsteadiest"),
-    "unsubtle" => ("unsubtler", "unsubtlest"),
-    "unsure" => ("unsurer", "unsurest"),
-    "unthrifty" => ("unthriftier", "unthriftiest"),
-    "untidy" => ("untidier", "untidiest"),
-    "untimely" => ("untimelier", "untimeliest"),
-    "unwary" => ("unwarier", "unwariest"),
-    "unwelcome" => ("unwelcomer", "unwelcomest"),
-    "unwide" => ("unwider", "unwidest"),
-    "unwise" => ("unwiser", "unwisest"),
-    "unworldly" => ("unworldlier", "unworldliest"),
-    "unworthy" => ("unworthier", "unworthiest"),
-    "upper" => ("more upper", "uppermost"),
-    "uppity" => ("uppitier", "uppitiest"),
-    "uppy" => ("uppier", "uppiest"),
-    "uptight" => ("uptighter", "uptightest"),
-    "urbane" => ("urbaner", "urbanest"),
-    "useful" => ("usefuller", "usefullest"),
-    "useless" => ("uselesser", "uselessest"),
-    "utter" => ("more utter", "utmost"),
-    "vague" => ("vaguer", "vaguest"),
-    "vain" => ("vainer", "vainest"),
-    "vampy" => ("vampier", "vampiest"),
-    "vast" => ("vaster", "vastest"),
-    "vasty" => ("vastier", "vastiest"),
-    "veally" => ("veallier", "vealliest"),
-    "vealy" => ("vealier", "vealiest"),
-    "veiny" => ("veinier", "veiniest"),
-    "verie" => ("verier", "veriest"),
-    "very" => ("verier", "veriest"),
-    "verye" => ("verier", "veriest"),
-    "vibey" => ("vibier", "vibiest"),
-    "viby" => ("vibier", "vibiest"),
-    "vicious" => ("viciouser", "viciousest"),
-    "viewy" => ("viewier", "viewiest"),
-    "vile" => ("viler", "vilest"),
-    "viny" => ("vinier", "viniest"),
-    "violent" => ("violenter", "violentest"),
-    "violet" => ("violeter", "violetest"),
-    "vivid" => ("vivider", "vividest"),
-    "vulgar" => ("vulgarer", "vulgarest"),
-    "wack" => ("wacker", "wackest"),
-    "wacky" => ("wackier", "wackiest"),
-    "waffley" => ("wafflier", "waffliest"),
-    "waffly" => ("wafflier", "waffliest"),
-    "waffy" => ("waffier", "waffiest"),
-    "waggly" => ("wagglier", "waggliest"),
-    "waggy" => ("waggier", "waggiest"),
-    "waily" => ("wailier", "wailiest"),
-    "waltzy" => ("waltzier", "waltziest"),
-    "wambly" => ("wamblier", "wambliest"),
-    "wan" => ("wanner", "wannest"),
-    "wanky" => ("wankier", "wankiest"),
-    "wanton" => ("wantoner", "wantonest"),
-    "warby" => ("warbier", "warbiest"),
-    "warm" => ("warmer", "warmest"),
-    "warmy" => ("warmier", "warmiest"),
-    "warry2" => ("warrier", "warriest"),
-    "warty" => ("wartier", "wartiest"),
-    "warweary" => ("warwearier", "warweariest"),
-    "wary" => ("warier", "wariest"),
-    "washy" => ("washier", "washiest"),
-    "waspy" => ("waspier", "waspiest"),
-    "wassy" => ("wassier", "wassiest"),
-    "wasty" => ("wastier", "wastiest"),
-    "watery" => ("waterier", "wateriest"),
-    "watry" => ("watrier", "watriest"),
-    "wavey" => ("wavier", "waviest"),
-    "wavy" => ("wavier", "waviest"),
-    "waxy2" => ("waxier", "waxiest"),
-    "weak" => ("weaker", "weakest"),
-    "weakly" => ("weaklier", "weakliest"),
-    "weaky" => ("weakier", "weakiest"),
-    "wealthy" => ("wealthier", "wealthiest"),
-    "weary" => ("wearier", "weariest"),
-    "weaselly" => ("weasellier", "weaselliest"),
-    "weasely" => ("weaselier", "weaseliest"),
-    "webby" => ("webbier", "webbiest"),
-    "wedgy" => ("wedgier", "wedgiest"),
-    "wee" => ("weer", "weest"),
-    "weeby" => ("weebier", "weebiest"),
-    "weedy" => ("weedier", "weediest"),
-    "weensy" => ("weensier", "weensiest"),
-    "weeny" => ("weenier", "weeniest"),
-    "weepy" => ("weepier", "weepiest"),
-    "weighty" => ("weightier", "weightiest"),
-    "weird2" => ("weirder", "weirdest"),
-    "welcome" => ("welcomer", "welcomest"),
-    "well" => ("better", "best"),
-    "wenchy" => ("wenchier", "wenchiest"),
-    "wersh" => ("wersher", "wershest"),
-    "western2" => ("more western", "westernmost"),
-    "wet" => ("wetter", "wettest"),
-    "whack" => ("whacker", "whackest"),
-    "whacky" => ("whackier", "whackiest"),
-    "wheaty" => ("wheatier", "wheatiest"),
-    "wheelie" => ("wheelier", "wheeliest"),
-    "wheely" => ("wheelier", "wheeliest"),
-    "wheezy" => ("wheezier", "wheeziest"),
-    "whelpy" => ("whelpier", "whelpiest"),
-    "wheyey" => ("wheyier", "wheyiest"),
-    "whiffy" => ("whiffier", "whiffiest"),
-    "whifty" => ("whiftier", "whiftiest"),
-    "whimpy" => ("whimpier", "whimpiest"),
-    "whimsy" => ("whimsier", "whimsiest"),
-    "whiney" => ("whinier", "whiniest"),
-    "whiny" => ("whinier", "whiniest"),
-    "whippy" => ("whippier", "whippiest"),
-    "whirly" => ("whirlier", "whirliest"),
-    "white2" => ("whiter", "whitest"),
-    "whitey" => ("whitier", "whitiest"),
-    "whity" => ("whitier", "whitiest"),
-    "whizzy" => ("whizzier", "whizziest"),
-    "whole" => ("wholer", "wholest"),
-    "wholesome" => ("wholesomer", "wholesomest"),
-    "wholle" => ("wholler", "whollest"),
-    "wholsom" => ("wholsomer", "wholsomest"),
-    "wholsome" => ("wholsomer", "wholsomest"),
-    "whorey" => ("whorier", "whoriest"),
-    "whott" => ("whotter", "whottest"),
-    "wibbly" => ("wibblier", "wibbliest"),
-    "wick" => ("wicker", "wickest"),
-    "wicked2" => ("wickeder", "wickedest"),
-    "widdle" => ("widdler", "widdlest"),
-    "wide" => ("wider", "widest"),
-    "wifely" => ("wifelier", "wifeliest"),
-    "wifey" => ("wifier", "wifiest"),
-    "wifty" => ("wiftier", "wiftiest"),
-    "wiggly" => ("wigglier", "wiggliest"),
-    "wiggy" => ("wiggier", "wiggiest"),
-    "wild" => ("wilder", "wildest"),
-    "wilful" => ("wilfuler", "wilfulest"),
-    "willful" => ("willfuler", "willfulest"),
-    "willowy" => ("willowier", "willowiest"),
-    "willy" => ("willier", "williest"),
-    "wily" => ("wilier", "wiliest"),
-    "wimpy" => ("wimpier", "wimpiest"),
-    "windy" => ("windier", "windiest"),
-    "winning" => ("winninger", "winningest"),
-    "winsome" => ("winsomer", "winsomest"),
-    "wintery" => ("winterier", "winteriest"),
-    "wintry" => ("wintrier", "wintriest"),
-    "winy" => ("winier", "winiest"),
-    "wiry" => ("wirier", "wiriest"),
-    "wise" => ("wiser", "wisest"),
-    "wisht" => ("wishter", "wishtest"),
-    "wispy" => ("wispier", "wispiest"),
-    "witchy" => ("witchier", "witchiest"),
-    "withy" => ("withier", "withiest"),
-    "wittle" => ("wittler", "wittlest"),
-    "witty" => ("wittier", "wittiest"),
-    "wobbly" => ("wobblier", "wobbliest"),
-    "wode" => ("woder", "wodest"),
-    "wodgy" => ("wodgier", "wodgiest"),
-    "woeful" => ("woefuller", "woefullest"),
-    "wofull" => ("wofuller", "wofullest"),
-    "woggy" => ("woggier", "woggiest"),
-    "woke" => ("woker", "wokest"),
-    "wold" => ("wolder", "woldest"),
-    "wolfly" => ("wolflier", "wolfliest"),
-    "wolfy" => ("wolfier", "wolfiest"),
-    "womanly" => ("womanlier", "womanliest"),
-    "wonderful" => ("wonderfuller", "wonderfullest"),
-    "wonky" => ("wonkier", "wonkiest"),
-    "wood" => ("wooder", "woodest"),
-    "woodsy" => ("woodsier", "woodsiest"),
-    "woody" => ("woodier", "woodiest"),
-    "woofy" => ("woofier", "woofiest"),
-    "woolly" => ("woollier", "woolliest"),
-    "wooly" => ("woolier", "wooliest"),
-    "woozy" => ("woozier", "wooziest"),
-    "wordy" => ("wordier", "wordiest"),
-    "worky" => ("workier", "workiest"),
-    "worldly" => ("worldlier", "worldliest"),
-    "worldweary" => ("worldwearier", "worldweariest"),
-    "wormy" => ("wormier", "wormiest"),
-    "worthless" => ("worthlesser", "worthlessest"),
-    "worthly" => ("worthlier", "worthliest"),
-    "worthy" => ("worthier", "worthiest"),
-    "wrathy" => ("wrathier", "wrathiest"),
-    "wriggly" => ("wrigglier", "wriggliest"),
-    "wrinkly" => ("wrinklier", "wrinkliest"),
-    "wristy" => ("wristier", "wristiest"),
-    "wry" => ("wrier", "wriest"),
-    "wurlie" => ("wurlier", "wurliest"),
-    "wurly" => ("wurlier", "wurliest"),
-    "wussy" => ("wussier", "wussiest"),
-    "wyde" => ("wyder", "wydest"),
-    "wyse" => ("wyser", "wysest"),
-    "yachty" => ("yachtier", "yachtiest"),
-    "yakky" => ("yakkier", "yakkiest"),
-    "yald" => ("yalder", "yaldest"),
-    "yaller" => ("yallerer", "yallerest"),
-    "yammy" => ("yammier", "yammiest"),
-    "yappy" => ("yappier", "yappiest"),
-    "yar2" => ("yarer", "yarest"),
-    "yare" => ("yarer", "yarest"),
-    "yary" => ("yarier", "yariest"),
-    "yauld" => ("yaulder", "yauldest"),
-    "yawny" => ("yawnier", "yawniest"),
-    "yearny" => ("yearnier", "yearniest"),
-    "yeasty" => ("yeastier", "yeastiest"),
-    "yecchy" => ("yecchier", "yecchiest"),
-    "yechy" => ("yechier", "yechiest"),
-    "yellow" => ("yellower", "yellowest"),
-    "yellowy" => ("yellowier", "yellowiest"),
-    "yelly" => ("yellier", "yelliest"),
-    "yelpy" => ("yelpier", "yelpiest"),
-    "yiffy" => ("yiffier", "yiffiest"),
-    "yolky" => ("yolkier", "yolkiest"),
-    "yong" => ("yonger", "yongest"),
-    "yoong" => ("yoonger", "yoongest"),
-    "young" => ("younger", "youngest"),
-    "yucky" => ("yuckier", "yuckiest"),
-    "yukky" => ("yukkier", "yukkiest"),
-    "yummy" => ("yummier", "yummiest"),
-    "zany" => ("zanier", "zaniest"),
-    "zappy" => ("zappier", "zappiest"),
-    "zate" => ("zater", "zatest"),
-    "zazzy" => ("zazzier", "zazziest"),
-    "zeitgeisty" => ("zeitgeistier", "zeitgeistiest"),
-    "zesty" => ("zestier", "zestiest"),
-    "zhlubby" => ("zhlubbier", "zhlubbiest"),
-    "zhooshy" => ("zhooshier", "zhooshiest"),
-    "zingy" => ("zingier", "zingiest"),
-    "zippy" => ("zippier", "zippiest"),
-    "zitty" => ("zittier", "zittiest"),
-    "zooey" => ("zooier", "zooiest"),
-    "zoomy" => ("zoomier", "zoomiest"),
+pub static ADJECTIVE_MAP: phf::Map<&'static str, [&'static str; 2]> = phf_map! {
+    "abgeschmackt" => ["abgeschmackter", "abgeschmacktest"],
+    "abject" => ["abjecter", "abjectest"],
+    "able" => ["abler", "ablest"],
+    "abrupt" => ["abrupter", "abruptest"],
+    "absent" => ["absenter", "absentest"],
+    "absolute" => ["absoluter", "absolutest"],
+    "abstract" => ["abstracter", "abstractest"],
+    "abstruse" => ["abstruser", "abstrusest"],
+    "absurd" => ["absurder", "absurdest"],
+    "acerb" => ["acerber", "acerbest"],
+    "achey" => ["achier", "achiest"],
+    "achy" => ["achier", "achiest"],
+    "acid" => ["acider", "acidest"],
+    "acrid" => ["acrider", "acridest"],
+    "acute" => ["acuter", "acutest"],
+    "adept" => ["adepter", "adeptest"],
+    "adroit" => ["adroiter", "adroitest"],
+    "adulty" => ["adultier", "adultiest"],
+    "aery" => ["aerier", "aeriest"],
+    "agile2" => ["agiler", "agilest"],
+    "ail" => ["ailer", "ailest"],
+    "airworthy" => ["airworthier", "airworthiest"],
+    "airy" => ["airier", "airiest"],
+    "almondy" => ["almondier", "almondiest"],
+    "alonely" => ["alonelier", "aloneliest"],
+    "ambitious" => ["ambitiouser", "ambitiousest"],
+    "ample" => ["ampler", "amplest"],
+    "amusing" => ["amusinger", "amusingest"],
+    "ancient" => ["ancienter", "ancientest"],
+    "angry" => ["angrier", "angriest"],
+    "angsty" => ["angstier", "angstiest"],
+    "antient" => ["antienter", "antientest"],
+    "antique" => ["antiquer", "antiquest"],
+    "antly" => ["antlier", "antliest"],
+    "antsy" => ["antsier", "antsiest"],
+    "anxious" => ["anxiouser", "anxiousest"],
+    "apt" => ["apter", "aptest"],
+    "arch2" => ["archer", "archest"],
+    "archpriestly" => ["archpriestlier", "archpriestliest"],
+    "arid" => ["arider", "aridest"],
+    "arm2" => ["armer", "armest"],
+    "arrant" => ["arranter", "arrantest"],
+    "arsy" => ["arsier", "arsiest"],
+    "artsie" => ["artsier", "artsiest"],
+    "artsy" => ["artsier", "artsiest"],
+    "arty" => ["artier", "artiest"],
+    "ashy" => ["ashier", "ashiest"],
+    "assy" => ["assier", "assiest"],
+    "astute" => ["astuter", "astutest"],
+    "august" => ["auguster", "augustest"],
+    "auld" => ["aulder", "auldest"],
+    "auncient" => ["auncienter", "auncientest"],
+    "auntient" => ["auntienter", "auntientest"],
+    "auntly" => ["auntlier", "auntliest"],
+    "austere" => ["austerer", "austerest"],
+    "avid" => ["avider", "avidest"],
+    "aware" => ["awarer", "awarest"],
+    "awesome" => ["awesomer", "awesomest"],
+    "awful" => ["awfuller", "awfullest"],
+    "awnry" => ["awnrier", "awnriest"],
+    "babbly" => ["babblier", "babbliest"],
+    "baby" => ["babier", "babiest"],
+    "backstabby" => ["backstabbier", "backstabbiest"],
+    "bad2" => ["badder", "baddest"],
+    "bad3" => ["worse", "worst"],
+    "badassy" => ["badassier", "badassiest"],
+    "baggy" => ["baggier", "baggiest"],
+    "bait" => ["baiter", "baitest"],
+    "baity" => ["baitier", "baitiest"],
+    "bald" => ["balder", "baldest"],
+    "balky" => ["balkier", "balkiest"],
+    "balloony" => ["balloonier", "ballooniest"],
+    "ballsy" => ["ballsier", "ballsiest"],
+    "balmy" => ["balmier", "balmiest"],
+    "bammy" => ["bammier", "bammiest"],
+    "banal" => ["banaler", "banalest"],
+    "bandy" => ["bandier", "bandiest"],
+    "bangy" => ["bangier", "bangiest"],
+    "bare" => ["barer", "barest"],
+    "barky" => ["barkier", "barkiest"],
+    "barmy" => ["barmier", "barmiest"],
+    "barny" => ["barnier", "barniest"],
+    "baroque2" => ["baroquer", "baroquest"],
+    "barren" => ["barrener", "barrenest"],
+    "base" => ["baser", "basest"],
+    "bashy" => ["bashier", "bashiest"],
+    "bass" => ["basser", "bassest"],
+    "bassy" => ["bassier", "bassiest"],
+    "batty" => ["battier", "battiest"],
+    "bawdy" => ["bawdier", "bawdiest"],
+    "bay" => ["bayer", "bayest"],
+    "beachy" => ["beachier", "beachiest"],
+    "beady" => ["beadier", "beadiest"],
+    "beaky" => ["beakier", "beakiest"],
+    "beamy" => ["beamier", "beamiest"],
+    "beany" => ["beanier", "beaniest"],
+    "beardy" => ["beardier", "beardiest"],
+    "beary" => ["bearier", "beariest"],
+    "beastly" => ["beastlier", "beastliest"],
+    "beaut" => ["beauter", "beautest"],
+    "beefy" => ["beefier", "beefiest"],
+    "beeg" => ["beeger", "beegest"],
+    "beepy" => ["beepier", "beepiest"],
+    "beery" => ["beerier", "beeriest"],
+    "beggarly" => ["beggarlier", "beggarliest"],
+    "beige" => ["beiger", "beigest"],
+    "beigey" => ["beigier", "beigiest"],
+    "beigy" => ["beigier", "beigiest"],
+    "beloved" => ["beloveder", "belovedest"],
+    "ben" => ["benar", "benat"],
+    "ben2" => ["benner", "benmost"],
+    "bendly" => ["bendlier", "bendliest"],
+    "bendy2" => ["bendier", "bendiest"],
+    "bene" => ["benar", "benat"],
+    "benign" => ["benigner", "benignest"],
+    "bent" => ["benter", "bentest"],
+    "big" => ["bigger", "biggest"],
+    "bilgy" => ["bilgier", "bilgiest"],
+    "billowy" => ["billowier", "billowiest"],
+    "birsy" => ["birsier", "birsiest"],
+    "bitchin" => ["more bitchin", "bitchinest"],
+    "bitching" => ["more bitching", "bitchingest"],
+    "bitchly" => ["bitchlier", "bitchliest"],
+    "bitchy" => ["bitchier", "bitchiest"],
+    "bitey" => ["bitier", "bitiest"],
+    "bitsy" => ["bitsier", "bitsiest"],
+    "bitter" => ["bitterer", "bitterest"],
+    "bittie" => ["bittier", "bittiest"],
+    "bitty" => ["bittier", "bittiest"],
+    "bizarre" => ["bizarrer", "bizarrest"],
+    "blabby" => ["blabbier", "blabbiest"],
+    "black2" => ["blacker", "blackest"],
+    "blak2" => ["blakker", "blakkest"],
+    "blake" => ["blaker", "blakest"],
+    "bland" => ["blander", "blandest"],
+    "blank" => ["blanker", "blankest"],
+    "blashy" => ["blashier", "blashiest"],
+    "blasty" => ["blastier", "blastiest"],
+    "blate" => ["blater", "blatest"],
+    "bleach" => ["bleacher", "bleachest"],
+    "bleachy" => ["bleachier", "bleachiest"],
+    "bleak" => ["bleaker", "bleakest"],
+    "bleary" => ["blearier", "bleariest"],
+    "bleedy" => ["bleedier", "bleediest"],
+    "bleepy" => ["bleepier", "bleepiest"],
+    "blimpy" => ["blimpier", "blimpiest"],
+    "blind" => ["blinder", "blindest"],
+    "blingy" => ["blingier", "blingiest"],
+    "blippy" => ["blippier", "blippiest"],
+    "blirty" => ["blirtier", "blirtiest"],
+    "blissy" => ["blissier", "blissiest"],
+    "blithe" => ["blither", "blithest"],
+    "bloaty" => ["bloatier", "bloatiest"],
+    "blobby" => ["blobbier", "blobbiest"],
+    "blocky" => ["blockier", "blockiest"],
+    "bloggy" => ["bloggier", "bloggiest"],
+    "blogworthy" => ["blogworthier", "blogworthiest"],
+    "blokey" => ["blokier", "blokiest"],
+    "blond" => ["blonder", "blondest"],
+    "blonde" => ["blonder", "blondest"],
+    "bloodthirsty" => ["bloodthirstier", "bloodthirstiest"],
+    "bloody" => ["bloodier", "bloodiest"],
+    "bloomly" => ["bloomlier", "bloomliest"],
+    "blossomy" => ["blossomier", "blossomiest"],
+    "blotchy" => ["blotchier", "blotchiest"],
+    "blotty" => ["blottier", "blottiest"],
+    "blousy" => ["blousier", "blousiest"],
+    "blouzy" => ["blouzier", "blouziest"],
+    "blow" => ["blower", "blowest"],
+    "blowsey" => ["blowsier", "blowsiest"],
+    "blowsy" => ["blowsier", "blowsiest"],
+    "blowy" => ["blowier", "blowiest"],
+    "blowzy" => ["blowzier", "blowziest"],
+    "blue" => ["bluer", "bluest"],
+    "blueberry" => ["blueberrier", "blueberriest"],
+    "bluesy" => ["bluesier", "bluesiest"],
+    "bluey" => ["bluier", "bluiest"],
+    "bluff" => ["bluffer", "bluffest"],
+    "bluggy" => ["bluggier", "bluggiest"],
+    "blunt" => ["blunter", "bluntest"],
+    "blurry" => ["blurrier", "blurriest"],
+    "blushy" => ["blushier", "blushiest"],
+    "boardy" => ["boardier", "boardiest"],
+    "bobbly" => ["bobblier", "bobbliest"],
+    "bog" => ["bogger", "boggest"],
+    "boggy" => ["boggier", "boggiest"],
+    "bold2" => ["bolder", "boldest"],
+    "bolshie" => ["bolshier", "bolshiest"],
+    "bolshy" => ["bolshier", "bolshiest"],
+    "boney" => ["bonier", "boniest"],
+    "bonnie" => ["bonnier", "bonniest"],
+    "bonny" => ["bonnier", "bonniest"],
+    "bony" => ["bonier", "boniest"],
+    "booby2" => ["boobier", "boobiest"],
+    "boofy" => ["boofier", "boofiest"],
+    "bookey" => ["bookier", "bookiest"],
+    "bookly" => ["booklier", "bookliest"],
+    "booky" => ["bookier", "bookiest"],
+    "boomy" => ["boomier", "boomiest"],
+    "boon" => ["booner", "boonest"],
+    "boosy" => ["boosier", "boosiest"],
+    "bootlicky" => ["bootlickier", "bootlickiest"],
+    "boozy" => ["boozier", "booziest"],
+    "boppy" => ["boppier", "boppiest"],
+    "bored" => ["boreder", "boredest"],
+    "boring" => ["boringer", "boringest"],
+    "bosky" => ["boskier", "boskiest"],
+    "bosomy" => ["bosomier", "bosomiest"],
+    "boss" => ["bosser", "bossest"],
+    "bossy2" => ["bossier", "bossiest"],
+    "bougie" => ["bougier", "bougiest"],
+    "bould" => ["boulder", "bouldest"],
+    "bouncy" => ["bouncier", "bounciest"],
+    "bowery" => ["bowerier", "boweriest"],
+    "bowsy" => ["bowsier", "bowsiest"],
+    "boxy" => ["boxier", "boxiest"],
+    "boyey" => ["boyier", "boyiest"],
+    "brack" => ["bracker", "brackest"],
+    "brag" => ["bragger", "braggest"],
+    "braggy" => ["braggier", "braggiest"],
+    "brainy" => ["brainier", "brainiest"],
+    "braky" => ["brakier", "brakiest"],
+    "brambly" => ["bramblier", "brambliest"],
+    "branchy" => ["branchier", "branchiest"],
+    "branny" => ["brannier", "branniest"],
+    "brash" => ["brasher", "brashest"],
+    "brassy" => ["brassier", "brassiest"],
+    "brath" => ["brather", "brathest"],
+    "bratty" => ["brattier", "brattiest"],
+    "brave" => ["braver", "bravest"],
+    "brawny" => ["brawnier", "brawniest"],
+    "brazen" => ["brazener", "brazenest"],
+    "bready" => ["breadier", "breadiest"],
+    "breaky" => ["breakier", "breakiest"],
+    "breasty" => ["breastier", "breastiest"],
+    "breathly" => ["breathlier", "breathliest"],
+    "breathy" => ["breathier", "breathiest"],
+    "breezey" => ["breezier", "breeziest"],
+    "breezy" => ["breezier", "breeziest"],
+    "bricky" => ["brickier", "brickiest"],
+    "brief" => ["briefer", "briefest"],
+    "bright" => ["brighter", "brightest"],
+    "briny" => ["brinier", "briniest"],
+    "brisk" => ["brisker", "briskest"],
+    "bristly" => ["bristlier", "bristliest"],
+    "brittle" => ["brittler", "brittlest"],
+    "broad" => ["broader", "broadest"],
+    "broey" => ["broier", "broiest"],
+    "broke2" => ["broker", "brokest"],
+    "bronzy" => ["bronzier", "bronziest"],
+    "broody" => ["broodier", "broodiest"],
+    "brothy" => ["brothier", "brothiest"],
+    "brown2" => ["browner", "brownest"],
+    "brummie" => ["brummier", "brummiest"],
+    "brummy" => ["brummier", "brummiest"],
+    "brushy" => ["brushier", "brushiest"],
+    "brusk" => ["brusker", "bruskest"],
+    "brusque" => ["brusquer", "brusquest"],
+    "bubbly" => ["bubblier", "bubbliest"],
+    "buff" => ["buffer", "buffest"],
+    "buffy" => ["buffier", "buffiest"],
+    "buggy" => ["buggier", "buggiest"],
+    "bugly" => ["buglier", "bugliest"],
+    "bugsy" => ["bugsier", "bugsiest"],
+    "buirdly" => ["buirdlier", "buirdliest"],
+    "bulgy" => ["bulgier", "bulgiest"],
+    "bulky" => ["bulkier", "bulkiest"],
+    "bullshitty" => ["bullshittier", "bullshittiest"],
+    "bully" => ["bullier", "bulliest"],
+    "bum" => ["bummer", "bummest"],
+    "bummy" => ["bummier", "bummiest"],
+    "bumpy" => ["bumpier", "bumpiest"],
+    "bunchy" => ["bunchier", "bunchiest"],
+    "bunny" => ["bunnier", "bunniest"],
+    "burbly" => ["burblier", "burbliest"],
+    "burly2" => ["burlier", "burliest"],
+    "burry" => ["burrier", "burriest"],
+    "bursty" => ["burstier", "burstiest"],
+    "bushy" => ["bushier", "bushiest"],
+    "bussy2" => ["bussier", "bussiest"],
+    "busty" => ["bustier", "bustiest"],
+    "busy" => ["busier", "busiest"],
+    "butch" => ["butcher", "butchest"],
+    "butchy" => ["butchier", "butchiest"],
+    "buttery" => ["butterier", "butteriest"],
+    "buxom" => ["buxomer", "buxomest"],
+    "buzzy" => ["buzzier", "buzziest"],
+    "cacky2" => ["cackier", "cackiest"],
+    "cagey" => ["cagier", "cagiest"],
+    "cagy" => ["cagier", "cagiest"],
+    "cakey" => ["cakier", "cakiest"],
+    "caky" => ["cakier", "cakiest"],
+    "callow2" => ["callower", "callowest"],
+    "calm" => ["calmer", "calmest"],
+    "camp" => ["camper", "campest"],
+    "campy" => ["campier", "campiest"],
+    "candid" => ["candider", "candidest"],
+    "canny2" => ["cannier", "canniest"],
+    "canty" => ["cantier", "cantiest"],
+    "carty" => ["cartier", "cartiest"],
+    "castworthy" => ["castworthier", "castworthiest"],
+    "catchy" => ["catchier", "catchiest"],
+    "catty" => ["cattier", "cattiest"],
+    "central" => ["more central", "centralmost"],
+    "certain" => ["certainer", "certainest"],
+    "chaffy" => ["chaffier", "chaffiest"],
+    "chalky" => ["chalkier", "chalkiest"],
+    "chancy" => ["chancier", "chanciest"],
+    "charry" => ["charrier", "charriest"],
+    "chary" => ["charier", "chariest"],
+    "chaste" => ["chaster", "chastest"],
+    "chatty" => ["chattier", "chattiest"],
+    "chavvie" => ["chavvier", "chavviest"],
+    "chavvy" => ["chavvier", "chavviest"],
+    "cheap" => ["cheaper", "cheapest"],
+    "cheaty" => ["cheatier", "cheatiest"],
+    "cheeky" => ["cheekier", "cheekiest"],
+    "cheepy" => ["cheepier", "cheepiest"],
+    "cheerful" => ["cheerfuler", "cheerfulest"],
+    "cheerly" => ["cheerlier", "cheerliest"],
+    "cheery" => ["cheerier", "cheeriest"],
+    "cheesy" => ["cheesier", "cheesiest"],
+    "cheffy" => ["cheffier", "cheffiest"],
+    "cherry" => ["cherrier", "cherriest"],
+    "cherty" => ["chertier", "chertiest"],
+    "chesty" => ["chestier", "chestiest"],
+    "cheugy" => ["cheugier", "cheugiest"],
+    "chewy" => ["chewier", "chewiest"],
+    "chic" => ["chicer", "chicest"],
+    "chickeny" => ["chickenier", "chickeniest"],
+    "chief" => ["chiefer", "chiefest"],
+    "chiefly" => ["chieflier", "chiefliest"],
+    "childly" => ["childlier", "childliest"],
+    "chilly" => ["chillier", "chilliest"],
+    "chinchy" => ["chinchier", "chinchiest"],
+    "chincy" => ["chincier", "chinciest"],
+    "chinky2" => ["chinkier", "chinkiest"],
+    "chinny" => ["chinnier", "chinniest"],
+    "chintzy" => ["chintzier", "chintziest"],
+    "chipper" => ["chipperer", "chipperest"],
+    "chippy" => ["chippier", "chippiest"],
+    "chirk" => ["chirker", "chirkest"],
+    "chirky" => ["chirkier", "chirkiest"],
+    "chirpy" => ["chirpier", "chirpiest"],
+    "chocolatey" => ["chocolatier", "chocolatiest"],
+    "chocolaty" => ["chocolatier", "chocolatiest"],
+    "choice" => ["choicer", "choicest"],
+    "choiceworthy" => ["choiceworthier", "choiceworthiest"],
+    "choicy" => ["choicier", "choiciest"],
+    "choise" => ["choiser", "choisest"],
+    "choky" => ["chokier", "chokiest"],
+    "chonky" => ["chonkier", "chonkiest"],
+    "choong" => ["choonger", "choongest"],
+    "choosy" => ["choosier", "choosiest"],
+    "choppy" => ["choppier", "choppiest"],
+    "choyse" => ["choyser", "choysest"],
+    "christmassy" => ["christmassier", "christmassiest"],
+    "chromy" => ["chromier", "chromiest"],
+    "chubby" => ["chubbier", "chubbiest"],
+    "chuffy" => ["chuffier", "chuffiest"],
+    "chummy" => ["chummier", "chummiest"],
+    "chumpy" => ["chumpier", "chumpiest"],
+    "chunky" => ["chunkier", "chunkiest"],
+    "churchly" => ["churchlier", "churchliest"],
+    "churchy" => ["churchier", "churchiest"],
+    "churly" => ["churlier", "churliest"],
+    "churny" => ["churnier", "churniest"],
+    "civil" => ["civiler", "civilest"],
+    "claggy" => ["claggier", "claggiest"],
+    "clam" => ["clammer", "clammest"],
+    "clammy" => ["clammier", "clammiest"],
+    "clangy" => ["clangier", "clangiest"],
+    "clanky" => ["clankier", "clankiest"],
+    "clarty" => ["clartier", "clartiest"],
+    "clashy" => ["clashier", "clashiest"],
+    "classy" => ["classier", "classiest"],
+    "clatty" => ["clattier", "clattiest"],
+    "clawy" => ["clawier", "clawiest"],
+    "clayey" => ["clayier", "clayiest"],
+    "clean" => ["cleaner", "cleanest"],
+    "cleanly" => ["cleanlier", "cleanliest"],
+    "clear" => ["clearer", "clearest"],
+    "cleer" => ["cleerer", "cleerest"],
+    "clerkly" => ["clerklier", "clerkliest"],
+    "clever" => ["cleverer", "cleverest"],
+    "clickbaity" => ["clickbaitier", "clickbaitiest"],
+    "clicky" => ["clickier", "clickiest"],
+    "cliffy" => ["cliffier", "cliffiest"],
+    "climby" => ["climbier", "climbiest"],
+    "clingy" => ["clingier", "clingiest"],
+    "clinky" => ["clinkier", "clinkiest"],
+    "cliquey" => ["cliquier", "cliquiest"],
+    "cliquy" => ["cliquier", "cliquiest"],
+    "clocky" => ["clockier", "clockiest"],
+    "cloddy" => ["cloddier", "cloddiest"],
+    "cloggy" => ["cloggier", "cloggiest"],
+    "close" => ["closer", "closest"],
+    "cloudly" => ["cloudlier", "cloudliest"],
+    "cloudy" => ["cloudier", "cloudiest"],
+    "clowdy" => ["clowdier", "clowdiest"],
+    "clowny" => ["clownier", "clowniest"],
+    "clubby" => ["clubbier", "clubbiest"],
+    "clucky" => ["cluckier", "cluckiest"],
+    "clumpy" => ["clumpier", "clumpiest"],
+    "clumsy" => ["clumsier", "clumsiest"],
+    "clunky" => ["clunkier", "clunkiest"],
+    "coachy" => ["coachier", "coachiest"],
+    "coaly" => ["coalier", "coaliest"],
+    "coarse" => ["coarser", "coarsest"],
+    "cobby" => ["cobbier", "cobbiest"],
+    "cobwebby" => ["cobwebbier", "cobwebbiest"],
+    "cockey" => ["cockier", "cockiest"],
+    "cockie" => ["cockier", "cockiest"],
+    "cocky" => ["cockier", "cockiest"],
+    "coconutty" => ["coconuttier", "coconuttiest"],
+    "codgy" => ["codgier", "codgiest"],
+    "coily" => ["coilier", "coiliest"],
+    "coiny" => ["coinier", "coiniest"],
+    "cold" => ["colder", "coldest"],
+    "colly" => ["collier", "colliest"],
+    "comely" => ["comelier", "comeliest"],
+    "comfortable" => ["comfortabler", "comfortablest"],
+    "comfy" => ["comfier", "comfiest"],
+    "comly" => ["comlier", "comliest"],
+    "common" => ["commoner", "commonest"],
+    "compact2" => ["compacter", "compactest"],
+    "complete" => ["completer", "completest"],
+    "concrete" => ["concreter", "concretest"],
+    "content2" => ["contenter", "contentest"],
+    "cool" => ["cooler", "coolest"],
+    "cooool" => ["cooooler", "coooolest"],
+    "cooty" => ["cootier", "cootiest"],
+    "corky" => ["corkier", "corkiest"],
+    "corny2" => ["cornier", "corniest"],
+    "cosie" => ["cosier", "cosiest"],
+    "costly" => ["costlier", "costliest"],
+    "cosy" => ["cosier", "cosiest"],
+    "couchy" => ["couchier", "couchiest"],
+    "coughy" => ["coughier", "coughiest"],
+    "country" => ["countrier", "countriest"],
+    "courtly" => ["courtlier", "courtliest"],
+    "couthie" => ["couthier", "couthiest"],
+    "cowardly" => ["cowardlier", "cowardliest"],
+    "cowy" => ["cowier", "cowiest"],
+    "coxy" => ["coxier", "coxiest"],
+    "coy" => ["coyer", "coyest"],
+    "cozie" => ["cozier", "coziest"],
+    "cozy" => ["cozier", "coziest"],
+    "crabby" => ["crabbier", "crabbiest"],
+    "crackly" => ["cracklier", "crackliest"],
+    "crackpotty" => ["crackpottier", "crackpottiest"],
+    "cracky" => ["crackier", "crackiest"],
+    "crafty" => ["craftier", "craftiest"],
+    "craggy" => ["craggier", "craggiest"],
+    "crampy" => ["crampier", "crampiest"],
+    "crank" => ["cranker", "crankest"],
+    "cranky" => ["crankier", "crankiest"],
+    "crap" => ["crapper", "crappest"],
+    "crapey" => ["crapier", "crapiest"],
+    "crappy" => ["crappier", "crappiest"],
+    "crapy" => ["crapier", "crapiest"],
+    "crashy" => ["crashier", "crashiest"],
+    "crass" => ["crasser", "crassest"],
+    "crawly" => ["crawlier", "crawliest"],
+    "crazy" => ["crazier", "craziest"],
+    "creaky" => ["creakier", "creakiest"],
+    "creamy" => ["creamier", "creamiest"],
+    "creasy" => ["creasier", "creasiest"],
+    "creeky" => ["creekier", "creekiest"],
+    "creepy" => ["creepier", "creepiest"],
+    "crepy" => ["crepier", "crepiest"],
+    "crimpy" => ["crimpier", "crimpiest"],
+    "cringeworthy" => ["cringeworthier", "cringeworthiest"],
+    "cringey" => ["cringier", "cringiest"],
+    "cringy" => ["cringier", "cringiest"],
+    "crinkly" => ["crinklier", "crinkliest"],
+    "crisp" => ["crisper", "crispest"],
+    "crispy" => ["crispier", "crispiest"],
+    "croaky" => ["croakier", "croakiest"],
+    "crook" => ["crooker", "crookest"],
+    "crool" => ["crooler", "croolest"],
+    "cross" => ["crosser", "crossest"],
+    "crotchety" => ["crotchetier", "crotchetiest"],
+    "crouchy" => ["crouchier", "crouchiest"],
+    "croupy" => ["croupier", "croupiest"],
+    "crowdy" => ["crowdier", "crowdiest"],
+    "cruddy" => ["cruddier", "cruddiest"],
+    "crude" => ["cruder", "crudest"],
+    "cruel" => ["crueler", "cruelest"],
+    "crufty" => ["cruftier", "cruftiest"],
+    "cruisy" => ["cruisier", "cruisiest"],
+    "crumbly" => ["crumblier", "crumbliest"],
+    "crumby" => ["crumbier", "crumbiest"],
+    "crummy" => ["crummier", "crummiest"],
+    "crumply" => ["crumplier", "crumpliest"],
+    "crunchy" => ["crunchier", "crunchiest"],
+    "crunk" => ["crunker", "crunkest"],
+    "crusty" => ["crustier", "crustiest"],
+    "cryey" => ["cryier", "cryiest"],
+    "cuddly" => ["cuddlier", "cuddliest"],
+    "cunty" => ["cuntier", "cuntiest"],
+    "cuppy" => ["cuppier", "cuppiest"],
+    "curdy" => ["curdier", "curdiest"],
+    "curious2" => ["curiouser", "curiousest"],
+    "curly" => ["curlier", "curliest"],
+    "curmudgeonly" => ["curmudgeonlier", "curmudgeonliest"],
+    "current" => ["currenter", "currentest"],
+    "cursed" => ["curseder", "cursedest"],
+    "curseworthy" => ["curseworthier", "curseworthiest"],
+    "curst" => ["curster", "curstest"],
+    "curt" => ["curter", "curtest"],
+    "curvy" => ["curvier", "curviest"],
+    "cushy" => ["cushier", "cushiest"],
+    "cuspy" => ["cuspier", "cuspiest"],
+    "cute" => ["cuter", "cutest"],
+    "cutesie" => ["cutesier", "cutesiest"],
+    "cutesy" => ["cutesier", "cutesiest"],
+    "cuuute" => ["cuuuter", "cuuutest"],
+    "daddy" => ["daddier", "daddiest"],
+    "dadgum" => ["more dadgum", "dadgummest"],
+    "dadly" => ["dadlier", "dadliest"],
+    "daffy" => ["daffier", "daffiest"],
+    "daft" => ["dafter", "daftest"],
+    "daggone" => ["more daggone", "daggonest"],
+    "daggy" => ["daggier", "daggiest"],
+    "dahk" => ["dahker", "dahkest"],
+    "dainty" => ["daintier", "daintiest"],
+    "damned" => ["damneder", "damnedest"],
+    "damp" => ["damper", "dampest"],
+    "dancy2" => ["dancier", "danciest"],
+    "dandy" => ["dandier", "dandiest"],
+    "dangly" => ["danglier", "dangliest"],
+    "dank" => ["danker", "dankest"],
+    "dapper" => ["dapperer", "dapperest"],
+    "dappy" => ["dappier", "dappiest"],
+    "dardy" => ["dardier", "dardiest"],
+    "dark" => ["darker", "darkest"],
+    "darkly" => ["darklier", "darkliest"],
+    "darned" => ["more darned", "darnedest"],
+    "darty" => ["dartier", "dartiest"],
+    "dashy" => ["dashier", "dashiest"],
+    "dastardly" => ["dastardlier", "dastardliest"],
+    "dead" => ["deader", "deadest"],
+    "deadly" => ["deadlier", "deadliest"],
+    "deaf2" => ["deafer", "deafest"],
+    "dear2" => ["dearer", "dearest"],
+    "deare" => ["dearer", "dearest"],
+    "dearthy" => ["dearthier", "dearthiest"],
+    "deathly" => ["deathlier", "deathliest"],
+    "debby" => ["debbier", "debbiest"],
+    "deedy" => ["deedier", "deediest"],
+    "deep" => ["deeper", "deepest"],
+    "deere" => ["deerer", "deerest"],
+    "def" => ["deffer", "deffest"],
+    "deft" => ["defter", "deftest"],
+    "delicate" => ["delicater", "delicatest"],
+    "delightful" => ["delightfuler", "delightfulest"],
+    "deluxe" => ["deluxer", "deluxest"],
+    "demure" => ["demurer", "demurest"],
+    "dense" => ["denser", "densest"],
+    "derpy" => ["derpier", "derpiest"],
+    "desperate" => ["more desperate", "desperatest"],
+    "devout" => ["devouter", "devoutest"],
+    "dewy" => ["dewier", "dewiest"],
+    "dicey" => ["dicier", "diciest"],
+    "dickey" => ["dickier", "dickiest"],
+    "dicky" => ["dickier", "dickiest"],
+    "dicty" => ["dictier", "dictiest"],
+    "diddy" => ["diddier", "diddiest"],
+    "dight" => ["dighter", "dightest"],
+    "dilfy" => ["dilfier", "dilfiest"],
+    "dilly2" => ["dillier", "dilliest"],
+    "dim2" => ["dimmer", "dimmest"],
+    "dimply" => ["dimplier", "dimpliest"],
+    "dingy" => ["dingier", "dingiest"],
+    "dinky" => ["dinkier", "dinkiest"],
+    "dippy" => ["dippier", "dippiest"],
+    "dire" => ["direr", "direst"],
+    "dirty" => ["dirtier", "dirtiest"],
+    "discreet" => ["discreeter", "discreetest"],
+    "discrete2" => ["discreter", "discretest"],
+    "disgusting" => ["disgustinger", "disgustingest"],
+    "dishy" => ["dishier", "dishiest"],
+    "distinct" => ["distincter", "distinctest"],
+    "ditsy2" => ["ditsier", "ditsiest"],
+    "ditzy" => ["ditzier", "ditziest"],
+    "dizzy" => ["dizzier", "dizziest"],
+    "doddery" => ["dodderier", "dodderiest"],
+    "dodgy" => ["dodgier", "dodgiest"],
+    "doggone" => ["more doggone", "doggonest"],
+    "doggoned" => ["more doggoned", "doggonedest"],
+    "doggy2" => ["doggier", "doggiest"],
+    "doleful" => ["dolefuller", "dolefullest"],
+    "doofy" => ["doofier", "doofiest"],
+    "doomy" => ["doomier", "doomiest"],
+    "doozy" => ["doozier", "dooziest"],
+    "dope" => ["doper", "dopest"],
+    "dopey" => ["dopier", "dopiest"],
+    "dopy" => ["dopier", "dopiest"],
+    "dorky" => ["dorkier", "dorkiest"],
+    "dotty" => ["dottier", "dottiest"],
+    "doty" => ["dotier", "dotiest"],
+    "douce" => ["doucer", "doucest"],
+    "douchey" => ["douchier", "douchiest"],
+    "douchy" => ["douchier", "douchiest"],
+    "doughty" => ["doughtier", "doughtiest"],
+    "doughy" => ["doughier", "doughiest"],
+    "dour" => ["dourer", "dourest"],
+    "dowdy" => ["dowdier", "dowdiest"],
+    "downy" => ["downier", "downiest"],
+    "dozy" => ["dozier", "doziest"],
+    "drab" => ["drabber", "drabbest"],
+    "drabby" => ["drabbier", "drabbiest"],
+    "drafty" => ["draftier", "draftiest"],
+    "draggy" => ["draggier", "draggiest"],
+    "drapey" => ["drapier", "drapiest"],
+    "draughty" => ["draughtier", "draughtiest"],
+    "drawly" => ["drawlier", "drawliest"],
+    "dread" => ["dreader", "dreadest"],
+    "dready" => ["dreadier", "dreadiest"],
+    "dreamy" => ["dreamier", "dreamiest"],
+    "drear" => ["drearer", "drearest"],
+    "dreary" => ["drearier", "dreariest"],
+    "drecky" => ["dreckier", "dreckiest"],
+    "dree" => ["dreer", "dreest"],
+    "dreggy" => ["dreggier", "dreggiest"],
+    "dreich" => ["dreicher", "dreichest"],
+    "dreigh" => ["dreigher", "dreighest"],
+    "drekky" => ["drekkier", "drekkiest"],
+    "dressy" => ["dressier", "dressiest"],
+    "dribbly" => ["dribblier", "dribbliest"],
+    "drifty" => ["driftier", "driftiest"],
+    "drinkworthy" => ["drinkworthier", "drinkworthiest"],
+    "drippy" => ["drippier", "drippiest"],
+    "drizzly" => ["drizzlier", "drizzliest"],
+    "droff" => ["droffer", "droffest"],
+    "drole" => ["droler", "drolest"],
+    "droll" => ["droller", "drollest"],
+    "drony" => ["dronier", "droniest"],
+    "drooly" => ["droolier", "drooliest"],
+    "droopy" => ["droopier", "droopiest"],
+    "drossy" => ["drossier", "drossiest"],
+    "droughty" => ["droughtier", "droughtiest"],
+    "drouthy" => ["drouthier", "drouthiest"],
+    "drowsy" => ["drowsier", "drowsiest"],
+    "drubly" => ["drublier", "drubliest"],
+    "drudgey" => ["drudgier", "drudgiest"],
+    "drudgy" => ["drudgier", "drudgiest"],
+    "druggy" => ["druggier", "druggiest"],
+    "drumly" => ["drumlier", "drumliest"],
+    "drunk" => ["drunker", "drunkest"],
+    "dry" => ["drier", "driest"],
+    "dry2" => ["dryer", "dryest"],
+    "ducky2" => ["duckier", "duckiest"],
+    "dud" => ["more dud", "duddest"],
+    "duddy" => ["duddier", "duddiest"],
+    "dudey" => ["dudier", "dudiest"],
+    "duff" => ["duffer", "duffest"],
+    "dull" => ["duller", "dullest"],
+    "dumb2" => ["dumber", "dumbest"],
+    "dumbass" => ["dumberass", "dumbestass"],
+    "dumpty" => ["dumptier", "dumptiest"],
+    "dumpy" => ["dumpier", "dumpiest"],
+    "dungy" => ["dungier", "dungiest"],
+    "dunny" => ["dunnier", "dunniest"],
+    "dusk" => ["dusker", "duskest"],
+    "dusky" => ["duskier", "duskiest"],
+    "dusty" => ["dustier", "dustiest"],
+    "dutch" => ["dutcher", "dutchest"],
+    "dutchy2" => ["dutchier", "dutchiest"],
+    "dutty" => ["duttier", "duttiest"],
+    "dwarf" => ["dwarfer", "dwarfest"],
+    "dweeby" => ["dweebier", "dweebiest"],
+    "dykey" => ["dykier", "dykiest"],
+    "dyky" => ["dykier", "dykiest"],
+    "eager" => ["eagerer", "eagerest"],
+    "early" => ["earlier", "earliest"],
+    "earnest" => ["earnester", "earnestest"],
+    "earthly2" => ["earthlier", "earthliest"],
+    "earthy" => ["earthier", "earthiest"],
+    "eastern2" => ["more eastern", "easternmost"],
+    "easy" => ["easier", "easiest"],
+    "easygoing" => ["easygoinger", "easygoingest"],
+    "eath" => ["eather", "eathest"],
+    "eathy" => ["eathier", "eathiest"],
+    "eazy" => ["eazier", "eaziest"],
+    "ebb" => ["ebber", "ebbest"],
+    "ebbe" => ["ebber", "ebbest"],
+    "echoey" => ["echoier", "echoiest"],
+    "edgelordy" => ["edgelordier", "edgelordiest"],
+    "edgy" => ["edgier", "edgiest"],
+    "eeby" => ["eebier", "eebiest"],
+    "eely" => ["eelier", "eeliest"],
+    "eepy" => ["eepier", "eepiest"],
+    "eerie" => ["eerier", "eeriest"],
+    "eery" => ["eerier", "eeriest"],
+    "eggy" => ["eggier", "eggiest"],
+    "eld" => ["elder", "eldest"],
+    "elite" => ["eliter", "elitest"],
+    "empty" => ["emptier", "emptiest"],
+    "erky" => ["erkier", "erkiest"],
+    "evil" => ["eviller", "evillest"],
+    "exact" => ["exacter", "exactest"],
+    "exploity" => ["exploitier", "exploitiest"],
+    "extreme" => ["extremer", "extremest"],
+    "fab2" => ["fabber", "fabbest"],
+    "fabby" => ["fabbier", "fabbiest"],
+    "fabe" => ["faber", "fabest"],
+    "faddy" => ["faddier", "faddiest"],
+    "fade" => ["fader", "fadest"],
+    "faffy" => ["faffier", "faffiest"],
+    "faggoty" => ["faggotier", "faggotiest"],
+    "faggy" => ["faggier", "faggiest"],
+    "faint" => ["fainter", "faintest"],
+    "fainty" => ["faintier", "faintiest"],
+    "fair" => ["fairer", "fairest"],
+    "faithfull" => ["faithfuller", "faithfullest"],
+    "faithy" => ["faithier", "faithiest"],
+    "fake" => ["faker", "fakest"],
+    "faky" => ["fakier", "fakiest"],
+    "fallen" => ["more fallen", "fallenest"],
+    "false2" => ["falser", "falsest"],
+    "fameworthy" => ["fameworthier", "fameworthiest"],
+    "fancy" => ["fancier", "fanciest"],
+    "fappy" => ["fappier", "fappiest"],
+    "far" => ["farther", "farthest"],
+    "farmy" => ["farmier", "farmiest"],
+    "farty" => ["fartier", "fartiest"],
+    "fashy" => ["fashier", "fashiest"],
+    "fast2" => ["faster", "fastest"],
+    "fat" => ["fatter", "fattest"],
+    "fatty" => ["fattier", "fattiest"],
+    "faulty" => ["faultier", "faultiest"],
+    "fausty" => ["faustier", "faustiest"],
+    "favourite" => ["more favourite", "favouritemost"],
+    "fayre" => ["fayrer", "fayrest"],
+    "feal" => ["fealer", "fealest"],
+    "feasty" => ["feastier", "feastiest"],
+    "feat" => ["feater", "featest"],
+    "feathery" => ["featherier", "featheriest"],
+    "featly" => ["featlier", "featliest"],
+    "fecky" => ["feckier", "feckiest"],
+    "feeble" => ["feebler", "feeblest"],
+    "feely2" => ["feelier", "feeliest"],
+    "feisty" => ["feistier", "feistiest"],
+    "fele" => ["feler", "felest"],
+    "fell" => ["feller", "fellest"],
+    "female" => ["femaler", "femalest"],
+    "fendy" => ["fendier", "fendiest"],
+    "ferny" => ["fernier", "ferniest"],
+    "fervid" => ["fervider", "fervidest"],
+    "fetchy" => ["fetchier", "fetchiest"],
+    "fey" => ["feyer", "feyest"],
+    "fickle" => ["fickler", "ficklest"],
+    "fiddly" => ["fiddlier", "fiddliest"],
+    "fidgety" => ["fidgetier", "fidgetiest"],
+    "fiendy" => ["fiendier", "fiendiest"],
+    "fierce" => ["fiercer", "fiercest"],
+    "fiery" => ["fierier", "fieriest"],
+    "fiesty" => ["fiestier", "fiestiest"],
+    "figgy" => ["figgier", "figgiest"],
+    "fighty" => ["fightier", "fightiest"],
+    "fiine" => ["fiiner", "fiinest"],
+    "filmy" => ["filmier", "filmiest"],
+    "filthy" => ["filthier", "filthiest"],
+    "fine" => ["finer", "finest"],
+    "finicky" => ["finickier", "finickiest"],
+    "finnicky" => ["finnickier", "finnickiest"],
+    "finny" => ["finnier", "finniest"],
+    "firm" => ["firmer", "firmest"],
+    "first" => ["more first", "firstmost"],
+    "fishy" => ["fishier", "fishiest"],
+    "fit" => ["fitter", "fittest"],
+    "fizzy" => ["fizzier", "fizziest"],
+    "flabby" => ["flabbier", "flabbiest"],
+    "flaggy" => ["flaggier", "flaggiest"],
+    "flakey" => ["flakier", "flakiest"],
+    "flaky" => ["flakier", "flakiest"],
+    "flamy" => ["flamier", "flamiest"],
+    "flappy" => ["flappier", "flappiest"],
+    "flashy" => ["flashier", "flashiest"],
+    "flat" => ["flatter", "flattest"],
+    "flaunty" => ["flauntier", "flauntiest"],
+    "fleecy" => ["fleecier", "fleeciest"],
+    "fleek" => ["fleeker", "fleekest"],
+    "fleeky" => ["fleekier", "fleekiest"],
+    "fleet" => ["fleeter", "fleetest"],
+    "fleshly" => ["fleshlier", "fleshliest"],
+    "fleshy" => ["fleshier", "fleshiest"],
+    "flexy" => ["flexier", "flexiest"],
+    "flicky" => ["flickier", "flickiest"],
+    "flighty" => ["flightier", "flightiest"],
+    "flimsy" => ["flimsier", "flimsiest"],
+    "flingy" => ["flingier", "flingiest"],
+    "flinty" => ["flintier", "flintiest"],
+    "flip" => ["flipper", "flippest"],
+    "flippy" => ["flippier", "flippiest"],
+    "flirty" => ["flirtier", "flirtiest"],
+    "flitty" => ["flittier", "flittiest"],
+    "floaty" => ["floatier", "floatiest"],
+    "flobby" => ["flobbier", "flobbiest"],
+    "flocky" => ["flockier", "flockiest"],
+    "floody" => ["floodier", "floodiest"],
+    "floofy" => ["floofier", "floofiest"],
+    "floppy" => ["floppier", "floppiest"],
+    "flossy" => ["flossier", "flossiest"],
+    "flouncy" => ["flouncier", "flounciest"],
+    "floury" => ["flourier", "flouriest"],
+    "flowery" => ["flowerier", "floweriest"],
+    "flowy" => ["flowier", "flowiest"],
+    "fluey2" => ["fluier", "fluiest"],
+    "fluffy" => ["fluffier", "fluffiest"],
+    "flufy" => ["flufier", "flufiest"],
+    "fluky" => ["flukier", "flukiest"],
+    "flush" => ["flusher", "flushest"],
+    "flushy" => ["flushier", "flushiest"],
+    "fluttery" => ["flutterier", "flutteriest"],
+    "fluty" => ["flutier", "flutiest"],
+    "fly" => ["flier", "fliest"],
+    "foamy" => ["foamier", "foamiest"],
+    "foggy" => ["foggier", "foggiest"],
+    "foine" => ["foiner", "foinest"],
+    "folkie" => ["folkier", "folkiest"],
+    "folksy" => ["folksier", "folksiest"],
+    "folky" => ["folkier", "folkiest"],
+    "fond" => ["fonder", "fondest"],
+    "foody" => ["foodier", "foodiest"],
+    "foofy" => ["foofier", "foofiest"],
+    "fool" => ["fooler", "foolest"],
+    "foolhardy" => ["foolhardier", "foolhardiest"],
+    "forblack" => ["forblacker", "forblackest"],
+    "fordry" => ["fordrier", "fordriest"],
+    "fore" => ["more fore", "foremost"],
+    "forky" => ["forkier", "forkiest"],
+    "forlorn" => ["forlorner", "forlornest"],
+    "forward" => ["forwarder", "forwardmost"],
+    "foul" => ["fouler", "foulest"],
+    "fouthy" => ["fouthier", "fouthiest"],
+    "fowl" => ["fowler", "fowlest"],
+    "foxy" => ["foxier", "foxiest"],
+    "fragile" => ["fragiler", "fragilest"],
+    "fraidy" => ["fraidier", "fraidiest"],
+    "frail" => ["frailer", "frailest"],
+    "frank" => ["franker", "frankest"],
+    "frass" => ["frasser", "frassest"],
+    "fratchy" => ["fratchier", "fratchiest"],
+    "fratty" => ["frattier", "frattiest"],
+    "freaky" => ["freakier", "freakiest"],
+    "freash" => ["freasher", "freashest"],
+    "freckly" => ["frecklier", "freckliest"],
+    "free" => ["freer", "freest"],
+    "freely" => ["freelier", "freeliest"],
+    "freezy" => ["freezier", "freeziest"],
+    "fremd" => ["fremder", "fremdest"],
+    "french" => ["frencher", "frenchest"],
+    "frenchy" => ["frenchier", "frenchiest"],
+    "frequent" => ["frequenter", "frequentest"],
+    "fresh" => ["fresher", "freshest"],
+    "friendly" => ["friendlier", "friendliest"],
+    "frighty" => ["frightier", "frightiest"],
+    "frigid" => ["frigider", "frigidest"],
+    "frilly" => ["frillier", "frilliest"],
+    "frim2" => ["frimmer", "frimmest"],
+    "fringe" => ["fringer", "fringest"],
+    "fringey" => ["fringier", "fringiest"],
+    "fringy" => ["fringier", "fringiest"],
+    "frisky" => ["friskier", "friskiest"],
+    "frizzly" => ["frizzlier", "frizzliest"],
+    "frizzy" => ["frizzier", "frizziest"],
+    "froggy" => ["froggier", "froggiest"],
+    "frood" => ["frooder", "froodest"],
+    "frosty" => ["frostier", "frostiest"],
+    "frothy" => ["frothier", "frothiest"],
+    "frousy" => ["frousier", "frousiest"],
+    "frowny" => ["frownier", "frowniest"],
+    "frowsty" => ["frowstier", "frowstiest"],
+    "frowsy" => ["frowsier", "frowsiest"],
+    "frowzy" => ["frowzier", "frowziest"],
+    "fruity" => ["fruitier", "fruitiest"],
+    "frum" => ["frummer", "frummest"],
+    "frumpy" => ["frumpier", "frumpiest"],
+    "fubsy" => ["fubsier", "fubsiest"],
+    "fucky" => ["fuckier", "fuckiest"],
+    "fudgey" => ["fudgier", "fudgiest"],
+    "fudgy" => ["fudgier", "fudgiest"],
+    "fuggy" => ["fuggier", "fuggiest"],
+    "fugly" => ["fuglier", "fugliest"],
+    "full" => ["fuller", "fullest"],
+    "fulsome" => ["fulsomer", "fulsomest"],
+    "fumy" => ["fumier", "fumiest"],
+    "funky" => ["funkier", "funkiest"],
+    "funni" => ["funnier", "funniest"],
+    "funny" => ["funnier", "funniest"],
+    "furry" => ["furrier", "furriest"],
+    "fussy" => ["fussier", "fussiest"],
+    "fusty" => ["fustier", "fustiest"],
+    "fuzzy" => ["fuzzier", "fuzziest"],
+    "gabby" => ["gabbier", "gabbiest"],
+    "gaggy" => ["gaggier", "gaggiest"],
+    "gainly" => ["gainlier", "gainliest"],
+    "game2" => ["gamer", "gamest"],
+    "gamely" => ["gamelier", "gameliest"],
+    "gamey" => ["gamier", "gamiest"],
+    "gammie" => ["gammier", "gammiest"],
+    "gammy" => ["gammier", "gammiest"],
+    "gangly" => ["ganglier", "gangliest"],
+    "gapey" => ["gapier", "gapiest"],
+    "gappy" => ["gappier", "gappiest"],
+    "garlicky" => ["garlickier", "garlickiest"],
+    "gas" => ["gasser", "gassest"],
+    "gaspy" => ["gaspier", "gaspiest"],
+    "gassy" => ["gassier", "gassiest"],
+    "gaudy" => ["gaudier", "gaudiest"],
+    "gaunt" => ["gaunter", "gauntest"],
+    "gauzy" => ["gauzier", "gauziest"],
+    "gawdy" => ["gawdier", "gawdiest"],
+    "gawky" => ["gawkier", "gawkiest"],
+    "gay" => ["gayer", "gayest"],
+    "geeky" => ["geekier", "geekiest"],
+    "gemmy" => ["gemmier", "gemmiest"],
+    "gentle" => ["gentler", "gentlest"],
+    "gentlemanly" => ["gentlemanlier", "gentlemanliest"],
+    "german2" => ["more german", "germanest"],
+    "germy" => ["germier", "germiest"],
+    "gey" => ["geyer", "geyest"],
+    "gezellig" => ["gezelliger", "gezelligest"],
+    "ghastly" => ["ghastlier", "ghastliest"],
+    "ghey" => ["gheyer", "gheyest"],
+    "ghostly" => ["ghostlier", "ghostliest"],
+    "ghosty" => ["ghostier", "ghostiest"],
+    "giddie" => ["giddier", "giddiest"],
+    "giddy" => ["giddier", "giddiest"],
+    "giggly" => ["gigglier", "giggliest"],
+    "gimmicky" => ["gimmickier", "gimmickiest"],
+    "gimpy" => ["gimpier", "gimpiest"],
+    "ginchy" => ["ginchier", "ginchiest"],
+    "girlie" => ["girlier", "girliest"],
+    "girly" => ["girlier", "girliest"],
+    "girthy" => ["girthier", "girthiest"],
+    "glad" => ["gladder", "gladdest"],
+    "gladsome" => ["gladsomer", "gladsomest"],
+    "glam" => ["glammer", "glammest"],
+    "glary" => ["glarier", "glariest"],
+    "glassy" => ["glassier", "glassiest"],
+    "glazy" => ["glazier", "glaziest"],
+    "gleamy" => ["gleamier", "gleamiest"],
+    "gleg" => ["glegger", "gleggest"],
+    "glib" => ["glibber", "glibbest"],
+    "glimmery" => ["glimmerier", "glimmeriest"],
+    "glinty" => ["glintier", "glintiest"],
+    "glisky" => ["gliskier", "gliskiest"],
+    "glissy" => ["glissier", "glissiest"],
+    "glitchy" => ["glitchier", "glitchiest"],
+    "glittery" => ["glitterier", "glitteriest"],
+    "glitzy" => ["glitzier", "glitziest"],
+    "globby" => ["globbier", "globbiest"],
+    "gloomy" => ["gloomier", "gloomiest"],
+    "gloopy" => ["gloopier", "gloopiest"],
+    "gloppy" => ["gloppier", "gloppiest"],
+    "glorious" => ["gloriouser", "gloriousest"],
+    "glossy" => ["glossier", "glossiest"],
+    "glowy" => ["glowier", "glowiest"],
+    "gluey" => ["gluier", "gluiest"],
+    "gluggy" => ["gluggier", "gluggiest"],
+    "glum" => ["glummer", "glummest"],
+    "glumpy" => ["glumpier", "glumpiest"],
+    "glurgy" => ["glurgier", "glurgiest"],
+    "gnarly" => ["gnarlier", "gnarliest"],
+    "gnashy" => ["gnashier", "gnashiest"],
+    "gnatty" => ["gnattier", "gnattiest"],
+    "goaty" => ["goatier", "goatiest"],
+    "gobby" => ["gobbier", "gobbiest"],
+    "godly" => ["godlier", "godliest"],
+    "goel" => ["goeler", "goelest"],
+    "gold2" => ["golder", "goldest"],
+    "golden" => ["goldener", "goldenest"],
+    "goldy" => ["goldier", "goldiest"],
+    "golfy" => ["golfier", "golfiest"],
+    "gone" => ["goner", "gonest"],
+    "good2" => ["better", "best"],
+    "goodly" => ["goodlier", "goodliest"],
+    "goody" => ["goodier", "goodiest"],
+    "gooey" => ["gooeyer", "gooeyest"],
+    "goofy2" => ["goofier", "goofiest"],
+    "googly" => ["googlier", "googliest"],
+    "gooky" => ["gookier", "gookiest"],
+    "goony" => ["goonier", "gooniest"],
+    "goopy" => ["goopier", "goopiest"],
+    "goosy" => ["goosier", "goosiest"],
+    "gorky" => ["gorkier", "gorkiest"],
+    "gorsy" => ["gorsier", "gorsiest"],
+    "gory" => ["gorier", "goriest"],
+    "gossipy" => ["gossipier", "gossipiest"],
+    "goth2" => ["gother", "gothest"],
+    "gothy" => ["gothier", "gothiest"],
+    "goundy" => ["goundier", "goundiest"],
+    "gouty" => ["goutier", "goutiest"],
+    "grabby" => ["grabbier", "grabbiest"],
+    "gradely" => ["gradelier", "gradeliest"],
+    "grainy" => ["grainier", "grainiest"],
+    "grand" => ["grander", "grandest"],
+    "grapey" => ["grapier", "grapiest"],
+    "grapy" => ["grapier", "grapiest"],
+    "grassy" => ["grassier", "grassiest"],
+    "grateful" => ["gratefuller", "gratefullest"],
+    "graund" => ["graunder", "graundest"],
+    "grave" => ["graver", "gravest"],
+    "gray" => ["grayer", "grayest"],
+    "grayey" => ["grayier", "grayiest"],
+    "greasey" => ["greasier", "greasiest"],
+    "greasy" => ["greasier", "greasiest"],
+    "great" => ["greater", "greatest"],
+    "greedy" => ["greedier", "greediest"],
+    "greek" => ["greeker", "greekest"],
+    "green2" => ["greener", "greenest"],
+    "greene" => ["greener", "greenest"],
+    "greeny" => ["greenier", "greeniest"],
+    "grey" => ["greyer", "greyest"],
+    "greyey" => ["greyier", "greyiest"],
+    "grill" => ["griller", "grillest"],
+    "grim" => ["grimmer", "grimmest"],
+    "grimly" => ["grimlier", "grimliest"],
+    "grimy" => ["grimier", "grimiest"],
+    "grindy" => ["grindier", "grindiest"],
+    "grippy2" => ["grippier", "grippiest"],
+    "grislie" => ["grislier", "grisliest"],
+    "grisly" => ["grislier", "grisliest"],
+    "gristly" => ["gristlier", "gristliest"],
+    "gritty" => ["grittier", "grittiest"],
+    "grizzly" => ["grizzlier", "grizzliest"],
+    "groaty2" => ["groatier", "groatiest"],
+    "grode" => ["groder", "grodest"],
+    "grody" => ["grodier", "grodiest"],
+    "groggy" => ["groggier", "groggiest"],
+    "groomy" => ["groomier", "groomiest"],
+    "groovy" => ["groovier", "grooviest"],
+    "gross" => ["grosser", "grossest"],
+    "grotesque" => ["grotesquer", "grotesquest"],
+    "grotty" => ["grottier", "grottiest"],
+    "groty" => ["grotier", "grotiest"],
+    "grouchy" => ["grouchier", "grouchiest"],
+    "groundy" => ["groundier", "groundiest"],
+    "grouse" => ["grouser", "grousest"],
+    "grouty" => ["groutier", "groutiest"],
+    "growly" => ["growlier", "growliest"],
+    "growthy" => ["growthier", "growthiest"],
+    "grubby" => ["grubbier", "grubbiest"],
+    "grudgy" => ["grudgier", "grudgiest"],
+    "gruesome" => ["gruesomer", "gruesomest"],
+    "gruff2" => ["gruffer", "gruffest"],
+    "gruffy" => ["gruffier", "gruffiest"],
+    "grufty" => ["gruftier", "gruftiest"],
+    "grum" => ["grummer", "grummest"],
+    "grumbly" => ["grumblier", "grumbliest"],
+    "grumly" => ["grumlier", "grumliest"],
+    "grumpy" => ["grumpier", "grumpiest"],
+    "grundy" => ["grundier", "grundiest"],
+    "grungy" => ["grungier", "grungiest"],
+    "grunty" => ["gruntier", "gruntiest"],
+    "gucky" => ["guckier", "guckiest"],
+    "guilty" => ["guiltier", "guiltiest"],
+    "gummy" => ["gummier", "gummiest"],
+    "gungy" => ["gungier", "gungiest"],
+    "gunky" => ["gunkier", "gunkiest"],
+    "gurly2" => ["gurlier", "gurliest"],
+    "gushy" => ["gushier", "gushiest"],
+    "gusty" => ["gustier", "gustiest"],
+    "gutsy" => ["gutsier", "gutsiest"],
+    "gutty2" => ["guttier", "guttiest"],
+    "hacky2" => ["hackier", "hackiest"],
+    "hail" => ["hailer", "hailest"],
+    "hairy" => ["hairier", "hairiest"],
+    "hale" => ["haler", "halest"],
+    "halloweeny" => ["halloweenier", "halloweeniest"],
+    "hammy" => ["hammier", "hammiest"],
+    "handsome" => ["handsomer", "handsomest"],
+    "handsy" => ["handsier", "handsiest"],
+    "handy" => ["handier", "handiest"],
+    "hangry" => ["hangrier", "hangriest"],
+    "happy" => ["happier", "happiest"],
+    "hard2" => ["harder", "hardest"],
+    "hardy" => ["hardier", "hardiest"],
+    "harmful" => ["harmfuller", "harmfullest"],
+    "harsh" => ["harsher", "harshest"],
+    "hashy" => ["hashier", "hashiest"],
+    "hasty" => ["hastier", "hastiest"],
+    "hateful" => ["hatefuller", "hatefullest"],
+    "hatty" => ["hattier", "hattiest"],
+    "haughty" => ["haughtier", "haughtiest"],
+    "haunted" => ["more haunted", "hauntedest"],
+    "hayey" => ["hayier", "hayiest"],
+    "hazy" => ["hazier", "haziest"],
+    "headliney" => ["headlinier", "headliniest"],
+    "headliny" => ["headlinier", "headliniest"],
+    "heady" => ["headier", "headiest"],
+    "healthful" => ["healthfuller", "healthfullest"],
+    "healthy" => ["healthier", "healthiest"],
+    "heapy" => ["heapier", "heapiest"],
+    "heartly" => ["heartlier", "heartliest"],
+    "heartthrobby" => ["heartthrobbier", "heartthrobbiest"],
+    "hearty" => ["heartier", "heartiest"],
+    "heathen2" => ["heathener", "heathenest"],
+    "heathy" => ["heathier", "heathiest"],
+    "heauie" => ["heauier", "heauiest"],
+    "heauy" => ["heauier", "heauiest"],
+    "heavenly2" => ["heavenlier", "heavenliest"],
+    "heavy2" => ["heavier", "heaviest"],
+    "hedgy" => ["hedgier", "hedgiest"],
+    "hefty" => ["heftier", "heftiest"],
+    "helply" => ["helplier", "helpliest"],
+    "helpy" => ["helpier", "helpiest"],
+    "hench" => ["hencher", "henchest"],
+    "henny" => ["hennier", "henniest"],
+    "heppen" => ["heppener", "heppenest"],
+    "herby" => ["herbier", "herbiest"],
+    "hetty" => ["hettier", "hettiest"],
+    "high" => ["higher", "highest"],
+    "hilly" => ["hillier", "hilliest"],
+    "hincty" => ["hinctier", "hinctiest"],
+    "hind" => ["hinder", "hindmost"],
+    "hinky" => ["hinkier", "hinkiest"],
+    "hip" => ["hipper", "hippest"],
+    "hippie" => ["hippier", "hippiest"],
+    "hippy" => ["hippier", "hippiest"],
+    "hissy" => ["hissier", "hissiest"],
+    "hoaky" => ["hoakier", "hoakiest"],
+    "hoardy" => ["hoardier", "hoardiest"],
+    "hoarse" => ["hoarser", "hoarsest"],
+    "hoary" => ["hoarier", "hoariest"],
+    "hoaxy" => ["hoaxier", "hoaxiest"],
+    "hoggy" => ["hoggier", "hoggiest"],
+    "hogwartsy" => ["hogwartsier", "hogwartsiest"],
+    "hokey" => ["hokier", "hokiest"],
+    "hokie" => ["hokier", "hokiest"],
+    "hoky" => ["hokier", "hokiest"],
+    "hole" => ["holer", "holest"],
+    "holey" => ["holier", "holiest"],
+    "hollow" => ["hollower", "hollowest"],
+    "holy2" => ["holier", "holiest"],
+    "homely" => ["homelier", "homeliest"],
+    "homey" => ["homier", "homiest"],
+    "honey" => ["honeyer", "honeyest"],
+    "hoody" => ["hoodier", "hoodiest"],
+    "hooky" => ["hookier", "hookiest"],
+    "hooly" => ["hoolier", "hooliest"],
+    "hooty" => ["hootier", "hootiest"],
+    "hoppy2" => ["hoppier", "hoppiest"],
+    "horny" => ["hornier", "horniest"],
+    "horrible" => ["horribler", "horriblest"],
+    "horrid" => ["horrider", "horridest"],
+    "horry" => ["horrier", "horriest"],
+    "horsey" => ["horsier", "horsiest"],
+    "horsy" => ["horsier", "horsiest"],
+    "hory" => ["horier", "horiest"],
+    "hot2" => ["hotter", "hottest"],
+    "houndly" => ["houndlier", "houndliest"],
+    "houndy" => ["houndier", "houndiest"],
+    "hubby" => ["hubbier", "hubbiest"],
+    "huffy" => ["huffier", "huffiest"],
+    "huge" => ["huger", "hugest"],
+    "huggly" => ["hugglier", "huggliest"],
+    "huggy" => ["huggier", "huggiest"],
+    "hugy" => ["hugier", "hugiest"],
+    "hulky" => ["hulkier", "hulkiest"],
+    "humane" => ["humaner", "humanest"],
+    "humble" => ["humbler", "humblest"],
+    "humid" => ["humider", "humidest"],
+    "humpy" => ["humpier", "humpiest"],
+    "hungry" => ["hungrier", "hungriest"],
+    "hungy2" => ["hungier", "hungiest"],
+    "hunky" => ["hunkier", "hunkiest"],
+    "hurty2" => ["hurtier", "hurtiest"],
+    "husky" => ["huskier", "huskiest"],
+    "hwite" => ["hwiter", "hwitest"],
+    "hye" => ["hyer", "hyest"],
+    "hypey" => ["hypier", "hypiest"],
+    "hyphy" => ["hyphier", "hyphiest"],
+    "icey" => ["icier", "iciest"],
+    "ickle" => ["ickler", "icklest"],
+    "icky" => ["ickier", "ickiest"],
+    "icy" => ["icier", "iciest"],
+    "iffy" => ["iffier", "iffiest"],
+    "ignoble" => ["ignobler", "ignoblest"],
+    "ignorant" => ["ignoranter", "ignorantest"],
+    "ill" => ["iller", "illest"],
+    "immense" => ["immenser", "immensest"],
+    "impeachy" => ["impeachier", "impeachiest"],
+    "impolite" => ["impoliter", "impolitest"],
+    "impudent" => ["impudenter", "impudentest"],
+    "inane" => ["inaner", "inanest"],
+    "indiscreet" => ["indiscreeter", "indiscreetest"],
+    "indiscrete" => ["indiscreter", "indiscretest"],
+    "infirm" => ["infirmer", "infirmest"],
+    "inky" => ["inkier", "inkiest"],
+    "inner" => ["more inner", "innermost"],
+    "insane" => ["insaner", "insanest"],
+    "intense" => ["intenser", "intensest"],
+    "irate" => ["irater", "iratest"],
+    "irky" => ["irkier", "irkiest"],
+    "itchy" => ["itchier", "itchiest"],
+    "itsy" => ["itsier", "itsiest"],
+    "itty" => ["ittier", "ittiest"],
+    "jabby" => ["jabbier", "jabbiest"],
+    "jaggy" => ["jaggier", "jaggiest"],
+    "jammie" => ["jammier", "jammiest"],
+    "jammy" => ["jammier", "jammiest"],
+    "jangly" => ["janglier", "jangliest"],
+    "janky" => ["jankier", "jankiest"],
+    "jargony" => ["jargonier", "jargoniest"],
+    "jaunty" => ["jauntier", "jauntiest"],
+    "jazzy" => ["jazzier", "jazziest"],
+    "jealous" => ["jealouser", "jealousest"],
+    "jemmy" => ["jemmier", "jemmiest"],
+    "jerky" => ["jerkier", "jerkiest"],
+    "jet2" => ["jetter", "jettest"],
+    "jetty2" => ["jettier", "jettiest"],
+    "jewish" => ["jewisher", "jewishest"],
+    "jiggly" => ["jigglier", "jiggliest"],
+    "jiggy" => ["jiggier", "jiggiest"],
+    "jimp" => ["jimper", "jimpest"],
+    "jingly" => ["jinglier", "jingliest"],
+    "jinky" => ["jinkier", "jinkiest"],
+    "jittery" => ["jitterier", "jitteriest"],
+    "jive" => ["jiver", "jivest"],
+    "jivey" => ["jivier", "jiviest"],
+    "jokey" => ["jokier", "jokiest"],
+    "joky" => ["jokier", "jokiest"],
+    "jolly" => ["jollier", "jolliest"],
+    "jolty" => ["joltier", "joltiest"],
+    "jouncy" => ["jouncier", "jounciest"],
+    "jowly" => ["jowlier", "jowliest"],
+    "joyful" => ["joyfuller", "joyfullest"],
+    "judgy" => ["judgier", "judgiest"],
+    "juicy" => ["juicier", "juiciest"],
+    "jumpy" => ["jumpier", "jumpiest"],
+    "jungly" => ["junglier", "jungliest"],
+    "junky" => ["junkier", "junkiest"],
+    "just" => ["juster", "justest"],
+    "keen" => ["keener", "keenest"],
+    "kene" => ["kener", "kenest"],
+    "kewl" => ["kewler", "kewlest"],
+    "key" => ["keyer", "keyest"],
+    "kibbley" => ["kibblier", "kibbliest"],
+    "kibbly" => ["kibblier", "kibbliest"],
+    "kicky" => ["kickier", "kickiest"],
+    "kiddy" => ["kiddier", "kiddiest"],
+    "kind" => ["kinder", "kindest"],
+    "kindly" => ["kindlier", "kindliest"],
+    "kingly" => ["kinglier", "kingliest"],
+    "kinky" => ["kinkier", "kinkiest"],
+    "kissy" => ["kissier", "kissiest"],
+    "kitsch" => ["kitscher", "kitschest"],
+    "kitschy" => ["kitschier", "kitschiest"],
+    "kittle" => ["kittler", "kittlest"],
+    "kludgey" => ["kludgier", "kludgiest"],
+    "kludgy" => ["kludgier", "kludgiest"],
+    "klugey" => ["klugier", "klugiest"],
+    "kluggy" => ["kluggier", "kluggiest"],
+    "klugy" => ["klugier", "klugiest"],
+    "klunky" => ["klunkier", "klunkiest"],
+    "klutzy" => ["klutzier", "klutziest"],
+    "knaggy" => ["knaggier", "knaggiest"],
+    "knightly" => ["knightlier", "knightliest"],
+    "knobbly" => ["knobblier", "knobbliest"],
+    "knobby" => ["knobbier", "knobbiest"],
+    "knotty" => ["knottier", "knottiest"],
+    "knuckly" => ["knucklier", "knuckliest"],
+    "knurly" => ["knurlier", "knurliest"],
+    "kooky" => ["kookier", "kookiest"],
+    "kool" => ["kooler", "koolest"],
+    "krazy" => ["krazier", "kraziest"],
+    "kvetchy" => ["kvetchier", "kvetchiest"],
+    "kway" => ["kwayer", "kwayest"],
+    "kwik" => ["kwikker", "kwikkest"],
+    "lacey" => ["lacier", "laciest"],
+    "lacy" => ["lacier", "laciest"],
+    "laggy" => ["laggier", "laggiest"],
+    "lairy" => ["lairier", "lairiest"],
+    "laky" => ["lakier", "lakiest"],
+    "lamby" => ["lambier", "lambiest"],
+    "lame" => ["lamer", "lamest"],
+    "lang" => ["langer", "langest"],
+    "lank" => ["lanker", "lankest"],
+    "lanky2" => ["lankier", "lankiest"],
+    "lardy" => ["lardier", "lardiest"],
+    "large" => ["larger", "largest"],
+    "larky" => ["larkier", "larkiest"],
+    "late" => ["later", "latest"],
+    "lathy" => ["lathier", "lathiest"],
+    "laughy" => ["laughier", "laughiest"],
+    "lavish" => ["lavisher", "lavishest"],
+    "lavy" => ["lavier", "laviest"],
+    "lax" => ["laxer", "laxest"],
+    "lazy" => ["lazier", "laziest"],
+    "leafy" => ["leafier", "leafiest"],
+    "leaky" => ["leakier", "leakiest"],
+    "leal" => ["lealer", "lealest"],
+    "lean" => ["leaner", "leanest"],
+    "leary" => ["learier", "leariest"],
+    "leathery" => ["leatherier", "leatheriest"],
+    "ledgy" => ["ledgier", "ledgiest"],
+    "leeky" => ["leekier", "leekiest"],
+    "leery" => ["leerier", "leeriest"],
+    "leet" => ["leeter", "leetest"],
+    "leetle" => ["leetler", "leetlest"],
+    "leeward" => ["more leeward", "leewardmost"],
+    "left" => ["lefter", "leftmost"],
+    "leftern" => ["more leftern", "lefternmost"],
+    "leggy" => ["leggier", "leggiest"],
+    "lemony" => ["lemonier", "lemoniest"],
+    "leng" => ["lenger", "lengest"],
+    "lengthy" => ["lengthier", "lengthiest"],
+    "level" => ["leveler", "levelest"],
+    "lew2" => ["lewer", "lewest"],
+    "lewd" => ["lewder", "lewdest"],
+    "licky" => ["lickier", "lickiest"],
+    "lief" => ["liefer", "liefest"],
+    "light" => ["lighter", "lightest"],
+    "lightweight" => ["lighterweight", "lightestweight"],
+    "likable" => ["likabler", "likablest"],
+    "likeable" => ["likeabler", "likeablest"],
+    "likely" => ["likelier", "likeliest"],
+    "likeworthy" => ["likeworthier", "likeworthiest"],
+    "limber" => ["limberer", "limberest"],
+    "limby" => ["limbier", "limbiest"],
+    "limey" => ["limier", "limiest"],
+    "limp" => ["limper", "limpest"],
+    "limy" => ["limier", "limiest"],
+    "linty" => ["lintier", "lintiest"],
+    "liny" => ["linier", "liniest"],
+    "lippy" => ["lippier", "lippiest"],
+    "lissom" => ["lissomer", "lissomest"],
+    "lissome" => ["lissomer", "lissomest"],
+    "lit2" => ["litter", "littest"],
+    "lite2" => ["liter", "litest"],
+    "lithe" => ["lither", "lithest"],
+    "little" => ["littler", "littlest"],
+    "lively" => ["livelier", "liveliest"],
+    "livid" => ["livider", "lividest"],
+    "loamy" => ["loamier", "loamiest"],
+    "loath" => ["loather", "loathest"],
+    "loathly" => ["loathlier", "loathliest"],
+    "loathy" => ["loathier", "loathiest"],
+    "lofty" => ["loftier", "loftiest"],
+    "logy" => ["logier", "logiest"],
+    "lolly" => ["lollier", "lolliest"],
+    "lonely" => ["lonelier", "loneliest"],
+    "long2" => ["longer", "longest"],
+    "loony" => ["loonier", "looniest"],
+    "loopy" => ["loopier", "loopiest"],
+    "loose" => ["looser", "loosest"],
+    "lordly" => ["lordlier", "lordliest"],
+    "lossy" => ["lossier", "lossiest"],
+    "lost" => ["loster", "lostest"],
+    "loth" => ["lother", "lothest"],
+    "louche" => ["loucher", "louchest"],
+    "loud" => ["louder", "loudest"],
+    "louely" => ["louelier", "loueliest"],
+    "loungy" => ["loungier", "loungiest"],
+    "lousey" => ["lousier", "lousiest"],
+    "lousy" => ["lousier", "lousiest"],
+    "lovable" => ["lovabler", "lovablest"],
+    "loveable" => ["loveabler", "loveablest"],
+    "lovely" => ["lovelier", "loveliest"],
+    "loving" => ["lovinger", "lovingest"],
+    "low" => ["lower", "lowest"],
+    "lowd" => ["lowder", "lowdest"],
+    "lowly" => ["lowlier", "lowliest"],
+    "lown" => ["lowner", "lownest"],
+    "lubbly" => ["lubblier", "lubbliest"],
+    "lubly" => ["lublier", "lubliest"],
+    "lucid" => ["lucider", "lucidest"],
+    "lucky" => ["luckier", "luckiest"],
+    "lukewarm" => ["lukewarmer", "lukewarmest"],
+    "lulzy" => ["lulzier", "lulziest"],
+    "lumpy" => ["lumpier", "lumpiest"],
+    "lunky" => ["lunkier", "lunkiest"],
+    "luny" => ["lunier", "luniest"],
+    "lush" => ["lusher", "lushest"],
+    "lushy" => ["lushier", "lushiest"],
+    "lusty" => ["lustier", "lustiest"],
+    "luxe" => ["luxier", "luxest"],
+    "lying" => ["lyinger", "lyingest"],
+    "lyke" => ["lyker", "lykest"],
+    "mad" => ["madder", "maddest"],
+    "maidly" => ["maidlier", "maidliest"],
+    "mald" => ["malder", "maldest"],
+    "male" => ["maler", "malest"],
+    "malty" => ["maltier", "maltiest"],
+    "manful" => ["manfuller", "manfullest"],
+    "mangy" => ["mangier", "mangiest"],
+    "manky" => ["mankier", "mankiest"],
+    "manly" => ["manlier", "manliest"],
+    "many" => ["more", "most"],
+    "marchy2" => ["marchier", "marchiest"],
+    "mardy" => ["mardier", "mardiest"],
+    "marly" => ["marlier", "marliest"],
+    "marshmallowy" => ["marshmallowier", "marshmallowiest"],
+    "marshy" => ["marshier", "marshiest"],
+    "mashy" => ["mashier", "mashiest"],
+    "mass" => ["masser", "massest"],
+    "massy" => ["massier", "massiest"],
+    "matey" => ["matier", "matiest"],
+    "mathy" => ["mathier", "mathiest"],
+    "mauve" => ["mauver", "mauvest"],
+    "mawky" => ["mawkier", "mawkiest"],
+    "mazy" => ["mazier", "maziest"],
+    "meager" => ["meagerer", "meagerest"],
+    "meagre" => ["meagrer", "meagrest"],
+    "mealy" => ["mealier", "mealiest"],
+    "mean2" => ["meaner", "meanest"],
+    "measly" => ["measlier", "measliest"],
+    "meaty" => ["meatier", "meatiest"],
+    "meek" => ["meeker", "meekest"],
+    "meer" => ["meerer", "meerest"],
+    "meet" => ["meeter", "meetest"],
+    "mellow" => ["mellower", "mellowest"],
+    "melty" => ["meltier", "meltiest"],
+    "memey" => ["memier", "memiest"],
+    "menschy" => ["menschier", "menschiest"],
+    "meowy" => ["meowier", "meowiest"],
+    "mere" => ["merer", "merest"],
+    "merie" => ["merier", "meriest"],
+    "merrie" => ["merrier", "merriest"],
+    "merry" => ["merrier", "merriest"],
+    "messy" => ["messier", "messiest"],
+    "mid" => ["more mid", "midmost"],
+    "middle" => ["more middle", "middlemost"],
+    "miffy" => ["miffier", "miffiest"],
+    "mifty" => ["miftier", "miftiest"],
+    "might" => ["mighter", "mightest"],
+    "mighty" => ["mightier", "mightiest"],
+    "milchy" => ["milchier", "milchiest"],
+    "mild" => ["milder", "mildest"],
+    "milde" => ["milder", "mildest"],
+    "milfy" => ["milfier", "milfiest"],
+    "milky" => ["milkier", "milkiest"],
+    "mim" => ["mimmer", "mimmest"],
+    "mimsy" => ["mimsier", "mimsiest"],
+    "mincy" => ["mincier", "minciest"],
+    "minecrafty" => ["minecraftier", "minecraftiest"],
+    "mingy" => ["mingier", "mingiest"],
+    "minty" => ["mintier", "mintiest"],
+    "minute" => ["minuter", "minutest"],
+    "mirk" => ["mirker", "mirkest"],
+    "mirky" => ["mirkier", "mirkiest"],
+    "mirthful" => ["mirthfuller", "mirthfullest"],
+    "mirthy" => ["mirthier", "mirthiest"],
+    "miry" => ["mirier", "miriest"],
+    "misty" => ["mistier", "mistiest"],
+    "mity" => ["mitier", "mitiest"],
+    "mixy" => ["mixier", "mixiest"],
+    "moany" => ["moanier", "moaniest"],
+    "mod2" => ["modder", "moddest"],
+    "modern" => ["moderner", "modernest"],
+    "modest" => ["modester", "modestest"],
+    "moist" => ["moister", "moistest"],
+    "moisty" => ["moistier", "moistiest"],
+    "moldy" => ["moldier", "moldiest"],
+    "mommy" => ["mommier", "mommiest"],
+    "moody" => ["moodier", "moodiest"],
+    "moony" => ["moonier", "mooniest"],
+    "moory" => ["moorier", "mooriest"],
+    "mopey" => ["mopier", "mopiest"],
+    "moppy" => ["moppier", "moppiest"],
+    "mopy" => ["mopier", "mopiest"],
+    "mormony" => ["mormonier", "mormoniest"],
+    "morose" => ["moroser", "morosest"],
+    "mossy" => ["mossier", "mossiest"],
+    "mothy" => ["mothier", "mothiest"],
+    "motley" => ["motlier", "motliest"],
+    "mouldy" => ["mouldier", "mouldiest"],
+    "moulty" => ["moultier", "moultiest"],
+    "mournful" => ["mournfuller", "mournfullest"],
+    "mousey" => ["mousier", "mousiest"],
+    "mousy" => ["mousier", "mousiest"],
+    "mouthy" => ["mouthier", "mouthiest"],
+    "muckle" => ["muckler", "mucklest"],
+    "mucky" => ["muckier", "muckiest"],
+    "muddy" => ["muddier", "muddiest"],
+    "mug" => ["mugger", "muggest"],
+    "muggy" => ["muggier", "muggiest"],
+    "mumbly" => ["mumblier", "mumbliest"],
+    "mumsy" => ["mumsier", "mumsiest"],
+    "munchy" => ["munchier", "munchiest"],
+    "mundane" => ["mundaner", "mundanest"],
+    "murk" => ["murker", "murkest"],
+    "murky" => ["murkier", "murkiest"],
+    "muscly" => ["musclier", "muscliest"],
+    "mushroomy" => ["mushroomier", "mushroomiest"],
+    "mushy" => ["mushier", "mushiest"],
+    "musky" => ["muskier", "muskiest"],
+    "mussolini" => ["mussolinier", "mussoliniest"],
+    "mussy" => ["mussier", "mussiest"],
+    "musty2" => ["mustier", "mustiest"],
+    "mute" => ["muter", "mutest"],
+    "muttly" => ["muttlier", "muttliest"],
+    "muzzy" => ["muzzier", "muzziest"],
+    "myrie" => ["myrier", "myriest"],
+    "mythy" => ["mythier", "mythiest"],
+    "naff" => ["naffer", "naffest"],
+    "naggy" => ["naggier", "naggiest"],
+    "naif" => ["naifer", "naifest"],
+    "nait" => ["naiter", "naitest"],
+    "naive" => ["naiver", "naivest"],
+    "namely" => ["namelier", "nameliest"],
+    "nappy" => ["nappier", "nappiest"],
+    "narky" => ["narkier", "narkiest"],
+    "narrow" => ["narrower", "narrowest"],
+    "nasky" => ["naskier", "naskiest"],
+    "nasty" => ["nastier", "nastiest"],
+    "natty2" => ["nattier", "nattiest"],
+    "naughty" => ["naughtier", "naughtiest"],
+    "neapy" => ["neapier", "neapiest"],
+    "near" => ["nearer", "nearest"],
+    "neat" => ["neater", "neatest"],
+    "nebbie" => ["nebbier", "nebbiest"],
+    "nebby" => ["nebbier", "nebbiest"],
+    "necessary" => ["necessarier", "necessariest"],
+    "neckbeardy" => ["neckbeardier", "neckbeardiest"],
+    "needly" => ["needlier", "needliest"],
+    "needy" => ["needier", "neediest"],
+    "neighborly" => ["neighborlier", "neighborliest"],
+    "nelly" => ["nellier", "nelliest"],
+    "nerdy" => ["nerdier", "nerdiest"],
+    "nervy" => ["nervier", "nerviest"],
+    "nesh" => ["nesher", "neshest"],
+    "nether" => ["more nether", "nethermost"],
+    "netty" => ["nettier", "nettiest"],
+    "new" => ["newer", "newest"],
+    "newsworthy" => ["newsworthier", "newsworthiest"],
+    "newsy" => ["newsier", "newsiest"],
+    "nice" => ["nicer", "nicest"],
+    "niche" => ["nicher", "nichest"],
+    "niffy" => ["niffier", "niffiest"],
+    "nifty" => ["niftier", "niftiest"],
+    "nigh" => ["nigher", "nighest"],
+    "nimble" => ["nimbler", "nimblest"],
+    "nippley" => ["nipplier", "nippliest"],
+    "nipply" => ["nipplier", "nippliest"],
+    "nippy" => ["nippier", "nippiest"],
+    "nither" => ["nithermore", "nithermost"],
+    "nitpicky" => ["nitpickier", "nitpickiest"],
+    "nittie" => ["nittier", "nittiest"],
+    "nitty2" => ["nittier", "nittiest"],
+    "nobbly" => ["nobblier", "nobbliest"],
+    "nobby" => ["nobbier", "nobbiest"],
+    "noble" => ["nobler", "noblest"],
+    "noice" => ["noicer", "noicest"],
+    "noisey" => ["noisier", "noisiest"],
+    "noisy" => ["noisier", "noisiest"],
+    "noncey" => ["noncier", "nonciest"],
+    "noncy" => ["noncier", "nonciest"],
+    "nooby" => ["noobier", "noobiest"],
+    "noodly" => ["noodlier", "noodliest"],
+    "nooky" => ["nookier", "nookiest"],
+    "normie" => ["normier", "normiest"],
+    "northern2" => ["more northern", "northernmost"],
+    "nosey" => ["nosier", "nosiest"],
+    "nosy" => ["nosier", "nosiest"],
+    "notchy" => ["notchier", "notchiest"],
+    "noteworthy" => ["noteworthier", "noteworthiest"],
+    "nouny" => ["nounier", "nouniest"],
+    "nubbly" => ["nubblier", "nubbliest"],
+    "nubby" => ["nubbier", "nubbiest"],
+    "nude" => ["nuder", "nudest"],
+    "nudy" => ["nudier", "nudiest"],
+    "numb" => ["number", "numbest"],
+    "nummy" => ["nummier", "nummiest"],
+    "nutsy" => ["nutsier", "nutsiest"],
+    "nutty" => ["nuttier", "nuttiest"],
+    "nyc" => ["nycer", "nycest"],
+    "oaky" => ["oakier", "oakiest"],
+    "oaty" => ["oatier", "oatiest"],
+    "obese" => ["obeser", "obesest"],
+    "oblique" => ["obliquer", "obliquest"],
+    "obscene" => ["obscener", "obscenest"],
+    "obscure" => ["obscurer", "obscurest"],
+    "obstruse" => ["obstruser", "obstrusest"],
+    "obtuse" => ["obtuser", "obtusest"],
+    "odd" => ["odder", "oddest"],
+    "offally" => ["offallier", "offalliest"],
+    "offaly" => ["offalier", "offaliest"],
+    "oily" => ["oilier", "oiliest"],
+    "ok2" => ["oker", "okest"],
+    "okay" => ["okayer", "okayest"],
+    "old" => ["older", "oldest"],
+    "onery" => ["onerier", "oneriest"],
+    "oniony2" => ["onionier", "onioniest"],
+    "onry" => ["onrier", "onriest"],
+    "oomphy" => ["oomphier", "oomphiest"],
+    "oozy" => ["oozier", "ooziest"],
+    "opaque" => ["opaquer", "opaquest"],
+    "open" => ["opener", "openest"],
+    "orange2" => ["oranger", "orangest"],
+    "orangey" => ["orangier", "orangiest"],
+    "orangy" => ["orangier", "orangiest"],
+    "ornary" => ["ornarier", "ornariest"],
+    "ornery" => ["ornerier", "orneriest"],
+    "orsum" => ["orsumer", "orsumest"],
+    "ould" => ["oulder", "ouldest"],
+    "outdoorsy" => ["outdoorsier", "outdoorsiest"],
+    "outer2" => ["more outer", "outermost"],
+    "owld" => ["owlder", "owldest"],
+    "owly" => ["owlier", "owliest"],
+    "pacey" => ["pacier", "paciest"],
+    "pacy" => ["pacier", "paciest"],
+    "painful" => ["painfuller", "painfullest"],
+    "painty" => ["paintier", "paintiest"],
+    "pale" => ["paler", "palest"],
+    "pallid" => ["pallider", "pallidest"],
+    "pally" => ["pallier", "palliest"],
+    "palmy" => ["palmier", "palmiest"],
+    "paltry" => ["paltrier", "paltriest"],
+    "pappy" => ["pappier", "pappiest"],
+    "parky" => ["parkier", "parkiest"],
+    "pastey" => ["pastier", "pastiest"],
+    "pasty" => ["pastier", "pastiest"],
+    "patchy" => ["patchier", "patchiest"],
+    "paunchy" => ["paunchier", "paunchiest"],
+    "pawky" => ["pawkier", "pawkiest"],
+    "pawy" => ["pawier", "pawiest"],
+    "peaceful" => ["peacefuller", "peacefullest"],
+    "peachy" => ["peachier", "peachiest"],
+    "peaky" => ["peakier", "peakiest"],
+    "pearly" => ["pearlier", "pearliest"],
+    "peart" => ["pearter", "peartest"],
+    "peary" => ["pearier", "peariest"],
+    "peaty" => ["peatier", "peatiest"],
+    "pebbly" => ["pebblier", "pebbliest"],
+    "pecky" => ["peckier", "peckiest"],
+    "peepy" => ["peepier", "peepiest"],
+    "peerie" => ["peerier", "peeriest"],
+    "peery" => ["peerier", "peeriest"],
+    "peng2" => ["penger", "pengest"],
+    "peppery" => ["pepperier", "pepperiest"],
+    "peppy" => ["peppier", "peppiest"],
+    "perfect" => ["perfecter", "perfectest"],
+    "perky" => ["perkier", "perkiest"],
+    "pert" => ["perter", "pertest"],
+    "perverse" => ["perverser", "perversest"],
+    "pervy" => ["pervier", "perviest"],
+    "pesky" => ["peskier", "peskiest"],
+    "pesty" => ["pestier", "pestiest"],
+    "petty" => ["pettier", "pettiest"],
+    "phat" => ["phatter", "phattest"],
+    "phlegmy" => ["phlegmier", "phlegmiest"],
+    "phoney" => ["phonier", "phoniest"],
+    "phony" => ["phonier", "phoniest"],
+    "phunny" => ["phunnier", "phunniest"],
+    "picky" => ["pickier", "pickiest"],
+    "piddly" => ["piddlier", "piddliest"],
+    "piggy" => ["piggier", "piggiest"],
+    "pilly" => ["pillier", "pilliest"],
+    "pimply" => ["pimplier", "pimpliest"],
+    "pinchy" => ["pinchier", "pinchiest"],
+    "pink2" => ["pinker", "pinkest"],
+    "pinky" => ["pinkier", "pinkiest"],
+    "piny" => ["pinier", "piniest"],
+    "pissy" => ["pissier", "pissiest"],
+    "pitch" => ["pitcher", "pitchest"],
+    "pitchy" => ["pitchier", "pitchiest"],
+    "pithy" => ["pithier", "pithiest"],
+    "pitiful" => ["pitifuller", "pitifullest"],
+    "pizazzy" => ["pizazzier", "pizazziest"],
+    "pizzazzy" => ["pizzazzier", "pizzazziest"],
+    "placid" => ["placider", "placidest"],
+    "plaguey" => ["plaguier", "plaguiest"],
+    "plaguy" => ["plaguier", "plaguiest"],
+    "plain" => ["plainer", "plainest"],
+    "plane" => ["planer", "planest"],
+    "plantly" => ["plantlier", "plantliest"],
+    "platy" => ["platier", "platiest"],
+    "playful" => ["playfuller", "playfullest"],
+    "pleasant" => ["pleasanter", "pleasantest"],
+    "pleasaunt" => ["pleasaunter", "pleasauntest"],
+    "pleasy" => ["pleasier", "pleasiest"],
+    "plebby" => ["plebbier", "plebbiest"],
+    "plentiful" => ["plentifuller", "plentifullest"],
+    "plesaunt" => ["plesaunter", "plesauntest"],
+    "plighty" => ["plightier", "plightiest"],
+    "plinky" => ["plinkier", "plinkiest"],
+    "plonky" => ["plonkier", "plonkiest"],
+    "ploppy" => ["ploppier", "ploppiest"],
+    "plosive" => ["plosiver", "plosivest"],
+    "plotty" => ["plottier", "plottiest"],
+    "plucky" => ["pluckier", "pluckiest"],
+    "plummy" => ["plummier", "plummiest"],
+    "plump" => ["plumper", "plumpest"],
+    "plumpy" => ["plumpier", "plumpiest"],
+    "plunky" => ["plunkier", "plunkiest"],
+    "plush" => ["plusher", "plushest"],
+    "plushy" => ["plushier", "plushiest"],
+    "poachy" => ["poachier", "poachiest"],
+    "pocky" => ["pockier", "pockiest"],
+    "poddy" => ["poddier", "poddiest"],
+    "podgy" => ["podgier", "podgiest"],
+    "pointy" => ["pointier", "pointiest"],
+    "poky" => ["pokier", "pokiest"],
+    "polite" => ["politer", "politest"],
+    "poncey" => ["poncier", "ponciest"],
+    "poncy" => ["poncier", "ponciest"],
+    "pongy" => ["pongier", "pongiest"],
+    "pooey" => ["pooier", "pooiest"],
+    "poofy" => ["poofier", "poofiest"],
+    "poopy" => ["poopier", "poopiest"],
+    "poor" => ["poorer", "poorest"],
+    "poorly" => ["poorlier", "poorliest"],
+    "pooty" => ["pootier", "pootiest"],
+    "popply" => ["popplier", "poppliest"],
+    "poppy2" => ["poppier", "poppiest"],
+    "porky" => ["porkier", "porkiest"],
+    "porny" => ["pornier", "porniest"],
+    "portly" => ["portlier", "portliest"],
+    "posey" => ["posier", "posiest"],
+    "posh" => ["posher", "poshest"],
+    "poshy" => ["poshier", "poshiest"],
+    "potty" => ["pottier", "pottiest"],
+    "pouchy" => ["pouchier", "pouchiest"],
+    "pouffy" => ["pouffier", "pouffiest"],
+    "poufy" => ["poufier", "poufiest"],
+    "pouncy" => ["pouncier", "pounciest"],
+    "pouty" => ["poutier", "poutiest"],
+    "poxy" => ["poxier", "poxiest"],
+    "praiseworthy" => ["praiseworthier", "praiseworthiest"],
+    "prancy" => ["prancier", "pranciest"],
+    "pranky" => ["prankier", "prankiest"],
+    "preachy" => ["preachier", "preachiest"],
+    "precise" => ["preciser", "precisest"],
+    "preddy" => ["preddier", "preddiest"],
+    "preppy" => ["preppier", "preppiest"],
+    "pretty" => ["prettier", "prettiest"],
+    "pricey" => ["pricier", "priciest"],
+    "prickly" => ["pricklier", "prickliest"],
+    "pricky" => ["prickier", "prickiest"],
+    "pricy" => ["pricier", "priciest"],
+    "priestly2" => ["priestlier", "priestliest"],
+    "prim" => ["primmer", "primmest"],
+    "prime" => ["primer", "primest"],
+    "princely" => ["princelier", "princeliest"],
+    "princessy" => ["princessier", "princessiest"],
+    "princy" => ["princier", "princiest"],
+    "prissy" => ["prissier", "prissiest"],
+    "profane" => ["profaner", "profanest"],
+    "proggy" => ["proggier", "proggiest"],
+    "prone" => ["proner", "pronest"],
+    "prophane" => ["prophaner", "prophanest"],
+    "proppy" => ["proppier", "proppiest"],
+    "prosey" => ["prosier", "prosiest"],
+    "prosy" => ["prosier", "prosiest"],
+    "proud" => ["prouder", "proudest"],
+    "prow" => ["prower", "prowest"],
+    "prowd" => ["prowder", "prowdest"],
+    "pubby" => ["pubbier", "pubbiest"],
+    "pubey" => ["pubier", "pubiest"],
+    "puce" => ["pucer", "pucest"],
+    "puddingy" => ["puddingier", "puddingiest"],
+    "puddly" => ["puddlier", "puddliest"],
+    "pudgy" => ["pudgier", "pudgiest"],
+    "pudsy" => ["pudsier", "pudsiest"],
+    "puffy" => ["puffier", "puffiest"],
+    "puggy" => ["puggier", "puggiest"],
+    "pukey" => ["pukier", "pukiest"],
+    "puky" => ["pukier", "pukiest"],
+    "pulpy" => ["pulpier", "pulpiest"],
+    "punchy" => ["punchier", "punchiest"],
+    "punk" => ["punker", "punkest"],
+    "punky" => ["punkier", "punkiest"],
+    "punny" => ["punnier", "punniest"],
+    "puny" => ["punier", "puniest"],
+    "purdy" => ["purdier", "purdiest"],
+    "pure" => ["purer", "purest"],
+    "purple" => ["purpler", "purplest"],
+    "purply" => ["purplier", "purpliest"],
+    "purry" => ["purrier", "purriest"],
+    "pursy" => ["pursier", "pursiest"],
+    "purty" => ["purtier", "purtiest"],
+    "pushy" => ["pushier", "pushiest"],
+    "pussy2" => ["pussier", "pussiest"],
+    "pwetty" => ["pwettier", "pwettiest"],
+    "qool" => ["qooler", "qoolest"],
+    "quack" => ["quacker", "quackest"],
+    "quacky" => ["quackier", "quackiest"],
+    "quaggy" => ["quaggier", "quaggiest"],
+    "quaint" => ["quainter", "quaintest"],
+    "quakey" => ["quakier", "quakiest"],
+    "quaky" => ["quakier", "quakiest"],
+    "qualmy" => ["qualmier", "qualmiest"],
+    "quare" => ["quarer", "quarest"],
+    "quartzy" => ["quartzier", "quartziest"],
+    "quay" => ["quayer", "quayest"],
+    "queasy" => ["queasier", "queasiest"],
+    "queazy" => ["queazier", "queaziest"],
+    "queefy" => ["queefier", "queefiest"],
+    "queenly" => ["queenlier", "queenliest"],
+    "queeny" => ["queenier", "queeniest"],
+    "queer2" => ["queerer", "queerest"],
+    "queerbaity" => ["queerbaitier", "queerbaitiest"],
+    "queezy" => ["queezier", "queeziest"],
+    "quenchy" => ["quenchier", "quenchiest"],
+    "quick" => ["quicker", "quickest"],
+    "quiet" => ["quieter", "quietest"],
+    "quippy" => ["quippier", "quippiest"],
+    "quirky" => ["quirkier", "quirkiest"],
+    "quobby" => ["quobbier", "quobbiest"],
+    "quoggy" => ["quoggier", "quoggiest"],
+    "qway" => ["qwayer", "qwayest"],
+    "rabid" => ["rabider", "rabidest"],
+    "rackety" => ["racketier", "racketiest"],
+    "racy" => ["racier", "raciest"],
+    "rad" => ["radder", "raddest"],
+    "radge" => ["radger", "radgest"],
+    "radgie" => ["radgier", "radgiest"],
+    "radqueer" => ["radqueerer", "radqueerest"],
+    "rafty" => ["raftier", "raftiest"],
+    "ragey" => ["ragier", "ragiest"],
+    "raggedy" => ["raggedier", "raggediest"],
+    "raggy2" => ["raggier", "raggiest"],
+    "rainy" => ["rainier", "rainiest"],
+    "randy" => ["randier", "randiest"],
+    "rangy" => ["rangier", "rangiest"],
+    "rank" => ["ranker", "rankest"],
+    "ranty2" => ["rantier", "rantiest"],
+    "rapey" => ["rapier", "rapiest"],
+    "rapid" => ["rapider", "rapidest"],
+    "rare2" => ["rarer", "rarest"],
+    "rascally" => ["rascallier", "rascalliest"],
+    "rash" => ["rasher", "rashest"],
+    "raspberry" => ["raspberrier", "raspberriest"],
+    "raspy" => ["raspier", "raspiest"],
+    "rathe" => ["rather", "rathest"],
+    "rattly" => ["rattlier", "rattliest"],
+    "ratty" => ["rattier", "rattiest"],
+    "raughty" => ["raughtier", "raughtiest"],
+    "raunchy" => ["raunchier", "raunchiest"],
+    "raw" => ["rawer", "rawest"],
+    "ready" => ["readier", "readiest"],
+    "real" => ["realer", "realest"],
+    "reamy" => ["reamier", "reamiest"],
+    "rear2" => ["rearer", "rearest"],
+    "reckless" => ["recklesser", "recklessest"],
+    "red2" => ["redder", "reddest"],
+    "ree" => ["reer", "reest"],
+    "reedy" => ["reedier", "reediest"],
+    "reef" => ["reefer", "reefest"],
+    "reefy" => ["reefier", "reefiest"],
+    "reeky" => ["reekier", "reekiest"],
+    "reem" => ["reemer", "reemest"],
+    "remote" => ["remoter", "remotest"],
+    "rethe" => ["rether", "rethest"],
+    "revvy" => ["revvier", "revviest"],
+    "rheumy" => ["rheumier", "rheumiest"],
+    "rhymey" => ["rhymier", "rhymiest"],
+    "rhymy" => ["rhymier", "rhymiest"],
+    "ribald" => ["ribalder", "ribaldest"],
+    "rich" => ["richer", "richest"],
+    "ricketty" => ["rickettier", "rickettiest"],
+    "rickety" => ["ricketier", "ricketiest"],
+    "ridgy" => ["ridgier", "ridgiest"],
+    "rife" => ["rifer", "rifest"],
+    "riffy" => ["riffier", "riffiest"],
+    "right" => ["righter", "rightmost"],
+    "rightern" => ["more rightern", "righternmost"],
+    "rigid" => ["rigider", "rigidest"],
+    "rimy" => ["rimier", "rimiest"],
+    "ripe2" => ["riper", "ripest"],
+    "ripply" => ["ripplier", "rippliest"],
+    "risky" => ["riskier", "riskiest"],
+    "ritzy" => ["ritzier", "ritziest"],
+    "rizzy" => ["rizzier", "rizziest"],
+    "roasty" => ["roastier", "roastiest"],
+    "robust" => ["robuster", "robustest"],
+    "rocky" => ["rockier", "rockiest"],
+    "roguey" => ["roguier", "roguiest"],
+    "roguy" => ["roguier", "roguiest"],
+    "roily" => ["roilier", "roiliest"],
+    "rookie" => ["rookier", "rookiest"],
+    "roolie" => ["roolier", "rooliest"],
+    "roomy" => ["roomier", "roomiest"],
+    "roopy" => ["roopier", "roopiest"],
+    "rootsy" => ["rootsier", "rootsiest"],
+    "rooty" => ["rootier", "rootiest"],
+    "ropey" => ["ropier", "ropiest"],
+    "ropy" => ["ropier", "ropiest"],
+    "rorty" => ["rortier", "rortiest"],
+    "rosy" => ["rosier", "rosiest"],
+    "rotten" => ["rottener", "rottenest"],
+    "rotund" => ["rotunder", "rotundest"],
+    "rough" => ["rougher", "roughest"],
+    "round" => ["rounder", "roundest"],
+    "roupy" => ["roupier", "roupiest"],
+    "rowdy" => ["rowdier", "rowdiest"],
+    "royal2" => ["royaler", "royalest"],
+    "rubbery" => ["rubberier", "rubberiest"],
+    "rubbish" => ["rubbisher", "rubbishest"],
+    "rubbishy" => ["rubbishier", "rubbishiest"],
+    "rubbly" => ["rubblier", "rubbliest"],
+    "ruddy" => ["ruddier", "ruddiest"],
+    "rude" => ["ruder", "rudest"],
+    "ruff" => ["ruffer", "ruffest"],
+    "ruffly" => ["rufflier", "ruffliest"],
+    "ruggy" => ["ruggier", "ruggiest"],
+    "rulesy" => ["rulesier", "rulesiest"],
+    "ruly" => ["rulier", "ruliest"],
+    "rum" => ["rummer", "rummest"],
+    "rumbly" => ["rumblier", "rumbliest"],
+    "rummy" => ["rummier", "rummiest"],
+    "rumply" => ["rumplier", "rumpliest"],
+    "runny" => ["runnier", "runniest"],
+    "runty" => ["runtier", "runtiest"],
+    "rushy2" => ["rushier", "rushiest"],
+    "rusty2" => ["rustier", "rustiest"],
+    "rutty" => ["ruttier", "ruttiest"],
+    "sable" => ["sabler", "sablest"],
+    "sad" => ["sadder", "saddest"],
+    "safe" => ["safer", "safest"],
+    "sage" => ["sager", "sagest"],
+    "sagey" => ["sagier", "sagiest"],
+    "saggy" => ["saggier", "saggiest"],
+    "sagy" => ["sagier", "sagiest"],
+    "saintly" => ["saintlier", "saintliest"],
+    "sainty" => ["saintier", "saintiest"],
+    "sallow" => ["sallower", "sallowest"],
+    "salty" => ["saltier", "saltiest"],
+    "sandy" => ["sandier", "sandiest"],
+    "sane2" => ["saner", "sanest"],
+    "sappy2" => ["sappier", "sappiest"],
+    "sare2" => ["sarer", "sarest"],
+    "sarky" => ["sarkier", "sarkiest"],
+    "sassy" => ["sassier", "sassiest"],
+    "saucy" => ["saucier", "sauciest"],
+    "savory" => ["savorier", "savoriest"],
+    "savoury" => ["savourier", "savouriest"],
+    "savvy" => ["savvier", "savviest"],
+    "sawcy" => ["sawcier", "sawciest"],
+    "scabby" => ["scabbier", "scabbiest"],
+    "scace" => ["scacer", "scacest"],
+    "scaley" => ["scalier", "scaliest"],
+    "scaly" => ["scalier", "scaliest"],
+    "scammy" => ["scammier", "scammiest"],
+    "scant" => ["scanter", "scantest"],
+    "scanty" => ["scantier", "scantiest"],
+    "scarce" => ["scarcer", "scarcest"],
+    "scared" => ["scareder", "scaredest"],
+    "scarry" => ["scarrier", "scarriest"],
+    "scary" => ["scarier", "scariest"],
+    "scatty2" => ["scattier", "scattiest"],
+    "schemey" => ["schemier", "schemiest"],
+    "schizy" => ["schizier", "schiziest"],
+    "schizzy" => ["schizzier", "schizziest"],
+    "schlocky" => ["schlockier", "schlockiest"],
+    "schlubby" => ["schlubbier", "schlubbiest"],
+    "schlumpy" => ["schlumpier", "schlumpiest"],
+    "schmaltzy" => ["schmaltzier", "schmaltziest"],
+    "schmalzy" => ["schmalzier", "schmalziest"],
+    "schmancy" => ["schmancier", "schmanciest"],
+    "schmexy" => ["schmexier", "schmexiest"],
+    "schmoopy" => ["schmoopier", "schmoopiest"],
+    "schmoozy" => ["schmoozier", "schmooziest"],
+    "schnazzy" => ["schnazzier", "schnazziest"],
+    "scorchy" => ["scorchier", "scorchiest"],
+    "scorpy" => ["scorpier", "scorpiest"],
+    "scowly" => ["scowlier", "scowliest"],
+    "scraggly" => ["scragglier", "scraggliest"],
+    "scraggy" => ["scraggier", "scraggiest"],
+    "scranny" => ["scrannier", "scranniest"],
+    "scrappy" => ["scrappier", "scrappiest"],
+    "scratchy" => ["scratchier", "scratchiest"],
+    "scrawly" => ["scrawlier", "scrawliest"],
+    "scrawny" => ["scrawnier", "scrawniest"],
+    "screamy" => ["screamier", "screamiest"],
+    "screechy" => ["screechier", "screechiest"],
+    "screwy" => ["screwier", "screwiest"],
+    "scribbley" => ["scribblier", "scribbliest"],
+    "scribbly" => ["scribblier", "scribbliest"],
+    "scrimpy" => ["scrimpier", "scrimpiest"],
+    "scrimy" => ["scrimier", "scrimiest"],
+    "scroungy" => ["scroungier", "scroungiest"],
+    "scrubby" => ["scrubbier", "scrubbiest"],
+    "scruffy" => ["scruffier", "scruffiest"],
+    "scrummy" => ["scrummier", "scrummiest"],
+    "scrunchy" => ["scrunchier", "scrunchiest"],
+    "scrungly" => ["scrunglier", "scrungliest"],
+    "scrungy" => ["scrungier", "scrungiest"],
+    "scrunkly" => ["scrunklier", "scrunkliest"],
+    "scuffy" => ["scuffier", "scuffiest"],
+    "scummy" => ["scummier", "scummiest"],
+    "scungy" => ["scungier", "scungiest"],
+    "scurfy" => ["scurfier", "scurfiest"],
+    "scurvy" => ["scurvier", "scurviest"],
+    "scuzzy" => ["scuzzier", "scuzziest"],
+    "seamy" => ["seamier", "seamiest"],
+    "sear" => ["searer", "searest"],
+    "seaworthy" => ["seaworthier", "seaworthiest"],
+    "secure" => ["securer", "securest"],
+    "sedate" => ["sedater", "sedatest"],
+    "seedy" => ["seedier", "seediest"],
+    "seely" => ["seelier", "seeliest"],
+    "seemly" => ["seemlier", "seemliest"],
+    "seepy" => ["seepier", "seepiest"],
+    "seldom" => ["seldomer", "seldomest"],
+    "selfish" => ["selfisher", "selfishest"],
+    "selly" => ["sellier", "selliest"],
+    "sely" => ["selier", "seliest"],
+    "sendy" => ["sendier", "sendiest"],
+    "sere2" => ["serer", "serest"],
+    "serene" => ["serener", "serenest"],
+    "seveare" => ["sevearer", "sevearest"],
+    "severe" => ["severer", "severest"],
+    "sexy2" => ["sexier", "sexiest"],
+    "shabby" => ["shabbier", "shabbiest"],
+    "shacky" => ["shackier", "shackiest"],
+    "shadowy" => ["shadowier", "shadowiest"],
+    "shady" => ["shadier", "shadiest"],
+    "shafty" => ["shaftier", "shaftiest"],
+    "shaggy" => ["shaggier", "shaggiest"],
+    "shaky" => ["shakier", "shakiest"],
+    "shallow" => ["shallower", "shallowest"],
+    "shaly" => ["shalier", "shaliest"],
+    "shandy" => ["shandier", "shandiest"],
+    "shank" => ["shanker", "shankest"],
+    "shapely" => ["shapelier", "shapeliest"],
+    "sharp" => ["sharper", "sharpest"],
+    "sheen" => ["sheener", "sheenest"],
+    "sheene" => ["sheener", "sheenest"],
+    "sheeny" => ["sheenier", "sheeniest"],
+    "sheepy" => ["sheepier", "sheepiest"],
+    "sheer" => ["sheerer", "sheerest"],
+    "sheisty" => ["sheistier", "sheistiest"],
+    "shelly" => ["shellier", "shelliest"],
+    "shewy" => ["shewier", "shewiest"],
+    "shiesty" => ["shiestier", "shiestiest"],
+    "shifty" => ["shiftier", "shiftiest"],
+    "shimmery" => ["shimmerier", "shimmeriest"],
+    "shingly" => ["shinglier", "shingliest"],
+    "shiny" => ["shinier", "shiniest"],
+    "shiply" => ["shiplier", "shipliest"],
+    "shippy" => ["shippier", "shippiest"],
+    "shirty" => ["shirtier", "shirtiest"],
+    "shit" => ["shitter", "shittest"],
+    "shite" => ["shiter", "shitest"],
+    "shitey" => ["shitier", "shitiest"],
+    "shitty" => ["shittier", "shittiest"],
+    "shity" => ["shitier", "shitiest"],
+    "shivery" => ["shiverier", "shiveriest"],
+    "shlocky" => ["shlockier", "shlockiest"],
+    "shlubby" => ["shlubbier", "shlubbiest"],
+    "shmaltzy" => ["shmaltzier", "shmaltziest"],
+    "shmancy" => ["shmancier", "shmanciest"],
+    "shmexy" => ["shmexier", "shmexiest"],
+    "shnazzy" => ["shnazzier", "shnazziest"],
+    "shoal" => ["shoaler", "shoalest"],
+    "shoaly" => ["shoalier", "shoaliest"],
+    "shocky" => ["shockier", "shockiest"],
+    "shoddy" => ["shoddier", "shoddiest"],
+    "shonky" => ["shonkier", "shonkiest"],
+    "shoogly" => ["shooglier", "shoogliest"],
+    "shoppy" => ["shoppier", "shoppiest"],
+    "short" => ["shorter", "shortest"],
+    "shouty" => ["shoutier", "shoutiest"],
+    "showery" => ["showerier", "showeriest"],
+    "showy" => ["showier", "showiest"],
+    "shreddy" => ["shreddier", "shreddiest"],
+    "shrewd" => ["shrewder", "shrewdest"],
+    "shrewde" => ["shrewder", "shrewdest"],
+    "shrieky" => ["shriekier", "shriekiest"],
+    "shrill" => ["shriller", "shrillest"],
+    "shrimpy" => ["shrimpier", "shrimpiest"],
+    "shrinky" => ["shrinkier", "shrinkiest"],
+    "shroomy" => ["shroomier", "shroomiest"],
+    "shrubby" => ["shrubbier", "shrubbiest"],
+    "shticky" => ["shtickier", "shtickiest"],
+    "shucky" => ["shuckier", "shuckiest"],
+    "shweet" => ["shweeter", "shweetest"],
+    "shy" => ["shier", "shiest"],
+    "shystie" => ["shystier", "shystiest"],
+    "shysty" => ["shystier", "shystiest"],
+    "sib" => ["sibber", "sibbest"],
+    "sick" => ["sicker", "sickest"],
+    "sickly" => ["sicklier", "sickliest"],
+    "sightly" => ["sightlier", "sightliest"],
+    "silent" => ["silenter", "silentest"],
+    "silky" => ["silkier", "silkiest"],
+    "silly" => ["sillier", "silliest"],
+    "silty" => ["siltier", "siltiest"],
+    "silvery" => ["silverier", "silveriest"],
+    "simple" => ["simpler", "simplest"],
+    "simpy" => ["simpier", "simpiest"],
+    "sincere" => ["sincerer", "sincerest"],
+    "sinful" => ["sinfuller", "sinfullest"],
+    "sinky" => ["sinkier", "sinkiest"],
+    "sinny" => ["sinnier", "sinniest"],
+    "sissy" => ["sissier", "sissiest"],
+    "skanky" => ["skankier", "skankiest"],
+    "skeely" => ["skeelier", "skeeliest"],
+    "skeery" => ["skeerier", "skeeriest"],
+    "skeevy" => ["skeevier", "skeeviest"],
+    "skeezy" => ["skeezier", "skeeziest"],
+    "sketchy" => ["sketchier", "sketchiest"],
+    "sketty" => ["skettier", "skettiest"],
+    "skew" => ["skewer", "skewest"],
+    "skiddy" => ["skiddier", "skiddiest"],
+    "skill" => ["skiller", "skillest"],
+    "skimpy" => ["skimpier", "skimpiest"],
+    "skinchy" => ["skinchier", "skinchiest"],
+    "skinny" => ["skinnier", "skinniest"],
+    "skint" => ["skinter", "skintest"],
+    "skippy" => ["skippier", "skippiest"],
+    "skody" => ["skodier", "skodiest"],
+    "skrunkly" => ["skrunklier", "skrunkliest"],
+    "skunky" => ["skunkier", "skunkiest"],
+    "skuzzy" => ["skuzzier", "skuzziest"],
+    "skyey" => ["skyier", "skyiest"],
+    "slab" => ["slabber", "slabbest"],
+    "slabby" => ["slabbier", "slabbiest"],
+    "slack" => ["slacker", "slackest"],
+    "slaggy" => ["slaggier", "slaggiest"],
+    "slangy" => ["slangier", "slangiest"],
+    "slanty" => ["slantier", "slantiest"],
+    "slaphappy" => ["slaphappier", "slaphappiest"],
+    "slappy" => ["slappier", "slappiest"],
+    "slapsticky" => ["slapstickier", "slapstickiest"],
+    "slashy" => ["slashier", "slashiest"],
+    "slatey" => ["slatier", "slatiest"],
+    "slaty" => ["slatier", "slatiest"],
+    "sleazy" => ["sleazier", "sleaziest"],
+    "slee" => ["sleer", "sleest"],
+    "sleechy" => ["sleechier", "sleechiest"],
+    "sleek" => ["sleeker", "sleekest"],
+    "sleeky" => ["sleekier", "sleekiest"],
+    "sleepy" => ["sleepier", "sleepiest"],
+    "sleety" => ["sleetier", "sleetiest"],
+    "sleezy" => ["sleezier", "sleeziest"],
+    "sleigh" => ["sleigher", "sleighest"],
+    "slender" => ["slenderer", "slenderest"],
+    "slick" => ["slicker", "slickest"],
+    "sliddery" => ["slidderier", "slidderiest"],
+    "slidy" => ["slidier", "slidiest"],
+    "slight" => ["slighter", "slightest"],
+    "slim" => ["slimmer", "slimmest"],
+    "slimey" => ["slimier", "slimiest"],
+    "slimpsy" => ["slimpsier", "slimpsiest"],
+    "slimy" => ["slimier", "slimiest"],
+    "slinky" => ["slinkier", "slinkiest"],
+    "slippery" => ["slipperier", "slipperiest"],
+    "slippy" => ["slippier", "slippiest"],
+    "slithery" => ["slitherier", "slitheriest"],
+    "slitty" => ["slittier", "slittiest"],
+    "sloaney" => ["sloanier", "sloaniest"],
+    "slobbery" => ["slobberier", "slobberiest"],
+    "slobby" => ["slobbier", "slobbiest"],
+    "sloppy" => ["sloppier", "sloppiest"],
+    "slopy" => ["slopier", "slopiest"],
+    "sloshy" => ["sloshier", "sloshiest"],
+    "slouchy" => ["slouchier", "slouchiest"],
+    "slovenly" => ["slovenlier", "slovenliest"],
+    "slow" => ["slower", "slowest"],
+    "sludgy" => ["sludgier", "sludgiest"],
+    "sluggish" => ["sluggisher", "sluggishest"],
+    "slummy" => ["slummier", "slummiest"],
+    "slumpy" => ["slumpier", "slumpiest"],
+    "slurpy" => ["slurpier", "slurpiest"],
+    "slurry" => ["slurrier", "slurriest"],
+    "slushy" => ["slushier", "slushiest"],
+    "slutty" => ["sluttier", "sluttiest"],
+    "sly" => ["slier", "sliest"],
+    "smacky" => ["smackier", "smackiest"],
+    "small" => ["smaller", "smallest"],
+    "smarmy" => ["smarmier", "smarmiest"],
+    "smart2" => ["smarter", "smartest"],
+    "smeary" => ["smearier", "smeariest"],
+    "smelly" => ["smellier", "smelliest"],
+    "smexy" => ["smexier", "smexiest"],
+    "smidgey" => ["smidgier", "smidgiest"],
+    "smidgy" => ["smidgier", "smidgiest"],
+    "smiley" => ["smilier", "smiliest"],
+    "smirky" => ["smirkier", "smirkiest"],
+    "smoggy" => ["smoggier", "smoggiest"],
+    "smoky" => ["smokier", "smokiest"],
+    "smol" => ["smoler", "smolest"],
+    "smoochy" => ["smoochier", "smoochiest"],
+    "smooth" => ["smoother", "smoothest"],
+    "smoothy" => ["smoothier", "smoothiest"],
+    "smoove" => ["smoover", "smoovest"],
+    "smudgey" => ["smudgier", "smudgiest"],
+    "smudgy" => ["smudgier", "smudgiest"],
+    "smug" => ["smugger", "smuggest"],
+    "smurfy" => ["smurfier", "smurfiest"],
+    "smushy" => ["smushier", "smushiest"],
+    "smutty" => ["smuttier", "smuttiest"],
+    "snacky" => ["snackier", "snackiest"],
+    "snaggy" => ["snaggier", "snaggiest"],
+    "snaily" => ["snailier", "snailiest"],
+    "snaky" => ["snakier", "snakiest"],
+    "snappy" => ["snappier", "snappiest"],
+    "snarky" => ["snarkier", "snarkiest"],
+    "snarly" => ["snarlier", "snarliest"],
+    "snassy" => ["snassier", "snassiest"],
+    "snatchy" => ["snatchier", "snatchiest"],
+    "snazzy" => ["snazzier", "snazziest"],
+    "sneaky" => ["sneakier", "sneakiest"],
+    "sneery" => ["sneerier", "sneeriest"],
+    "sneezy" => ["sneezier", "sneeziest"],
+    "snell" => ["sneller", "snellest"],
+    "snide" => ["snider", "snidest"],
+    "sniffly" => ["snifflier", "sniffliest"],
+    "sniffy" => ["sniffier", "sniffiest"],
+    "snipey" => ["snipier", "snipiest"],
+    "snippy" => ["snippier", "snippiest"],
+    "snipy" => ["snipier", "snipiest"],
+    "snitchy" => ["snitchier", "snitchiest"],
+    "snithy" => ["snithier", "snithiest"],
+    "snitty" => ["snittier", "snittiest"],
+    "snobby" => ["snobbier", "snobbiest"],
+    "snoopy" => ["snoopier", "snoopiest"],
+    "snooty" => ["snootier", "snootiest"],
+    "snoozy" => ["snoozier", "snooziest"],
+    "snorty" => ["snortier", "snortiest"],
+    "snory" => ["snorier", "snoriest"],
+    "snotty" => ["snottier", "snottiest"],
+    "snouty" => ["snoutier", "snoutiest"],
+    "snowy" => ["snowier", "snowiest"],
+    "snubby" => ["snubbier", "snubbiest"],
+    "snuffy" => ["snuffier", "snuffiest"],
+    "snug" => ["snugger", "snuggest"],
+    "snuggly" => ["snugglier", "snuggliest"],
+    "soaky" => ["soakier", "soakiest"],
+    "soapy" => ["soapier", "soapiest"],
+    "sobby" => ["sobbier", "sobbiest"],
+    "sober" => ["soberer", "soberest"],
+    "soft" => ["softer", "softest"],
+    "soggy" => ["soggier", "soggiest"],
+    "soily" => ["soilier", "soiliest"],
+    "solemn" => ["solemner", "solemnest"],
+    "solid" => ["solider", "solidest"],
+    "solwy" => ["solwier", "solwiest"],
+    "somber" => ["somberer", "somberest"],
+    "sombre" => ["sombrer", "sombrest"],
+    "songly" => ["songlier", "songliest"],
+    "songy" => ["songier", "songiest"],
+    "sonly" => ["sonlier", "sonliest"],
+    "sooky" => ["sookier", "sookiest"],
+    "soon" => ["sooner", "soonest"],
+    "sooth" => ["soother", "soothest"],
+    "soothy" => ["soothier", "soothiest"],
+    "sooty" => ["sootier", "sootiest"],
+    "soppy" => ["soppier", "soppiest"],
+    "sordid" => ["sordider", "sordidest"],
+    "sore" => ["sorer", "sorest"],
+    "sorry" => ["sorrier", "sorriest"],
+    "sound" => ["sounder", "soundest"],
+    "soupy" => ["soupier", "soupiest"],
+    "sour" => ["sourer", "sourest"],
+    "southern2" => ["more southern", "southernmost"],
+    "sower" => ["sowerer", "sowerest"],
+    "soy" => ["soyer", "soyest"],
+    "spacey" => ["spacier", "spaciest"],
+    "spacky" => ["spackier", "spackiest"],
+    "spacy" => ["spacier", "spaciest"],
+    "spammy" => ["spammier", "spammiest"],
+    "spangly" => ["spanglier", "spangliest"],
+    "spare" => ["sparer", "sparest"],
+    "sparkly" => ["sparklier", "sparkliest"],
+    "sparky" => ["sparkier", "sparkiest"],
+    "sparse" => ["sparser", "sparsest"],
+    "spazzy" => ["spazzier", "spazziest"],
+    "special" => ["specialer", "specialest"],
+    "specky2" => ["speckier", "speckiest"],
+    "speechworthy" => ["speechworthier", "speechworthiest"],
+    "speedy" => ["speedier", "speediest"],
+    "spendy" => ["spendier", "spendiest"],
+    "spergy" => ["spergier", "spergiest"],
+    "spermy" => ["spermier", "spermiest"],
+    "spick" => ["spicker", "spickest"],
+    "spicy" => ["spicier", "spiciest"],
+    "spiffy" => ["spiffier", "spiffiest"],
+    "spikey" => ["spikier", "spikiest"],
+    "spiky" => ["spikier", "spikiest"],
+    "spindly" => ["spindlier", "spindliest"],
+    "spinny" => ["spinnier", "spinniest"],
+    "spiny" => ["spinier", "spiniest"],
+    "spivvy" => ["spivvier", "spivviest"],
+    "splashy" => ["splashier", "splashiest"],
+    "splendid" => ["splendider", "splendidest"],
+    "splishy" => ["splishier", "splishiest"],
+    "splitty" => ["splittier", "splittiest"],
+    "splodgy" => ["splodgier", "splodgiest"],
+    "sploshy" => ["sploshier", "sploshiest"],
+    "splotchy" => ["splotchier", "splotchiest"],
+    "splurgy" => ["splurgier", "splurgiest"],
+    "spluttery" => ["splutterier", "splutteriest"],
+    "spongey" => ["spongier", "spongiest"],
+    "spongy" => ["spongier", "spongiest"],
+    "spoofy" => ["spoofier", "spoofiest"],
+    "spooky" => ["spookier", "spookiest"],
+    "spooney" => ["spoonier", "spooniest"],
+    "spoony" => ["spoonier", "spooniest"],
+    "spoopy" => ["spoopier", "spoopiest"],
+    "sporty" => ["sportier", "sportiest"],
+    "spotty" => ["spottier", "spottiest"],
+    "sprauncy" => ["sprauncier", "spraunciest"],
+    "sprawly" => ["sprawlier", "sprawliest"],
+    "sprayey" => ["sprayier", "sprayiest"],
+    "spriggy" => ["spriggier", "spriggiest"],
+    "sprightlie" => ["sprightlier", "sprightliest"],
+    "sprightly" => ["sprightlier", "sprightliest"],
+    "springy" => ["springier", "springiest"],
+    "spritely" => ["spritelier", "spriteliest"],
+    "spritzy" => ["spritzier", "spritziest"],
+    "sprouty" => ["sproutier", "sproutiest"],
+    "spruce" => ["sprucer", "sprucest"],
+    "sprucy" => ["sprucier", "spruciest"],
+    "spry" => ["sprier", "spriest"],
+    "spumy" => ["spumier", "spumiest"],
+    "spunky" => ["spunkier", "spunkiest"],
+    "squabby" => ["squabbier", "squabbiest"],
+    "squalid" => ["squalider", "squalidest"],
+    "squally" => ["squallier", "squalliest"],
+    "squammy" => ["squammier", "squammiest"],
+    "square" => ["squarer", "squarest"],
+    "squashy" => ["squashier", "squashiest"],
+    "squat" => ["squatter", "squattest"],
+    "squatty" => ["squattier", "squattiest"],
+    "squawky" => ["squawkier", "squawkiest"],
+    "squeaky" => ["squeakier", "squeakiest"],
+    "squealy" => ["squealier", "squealiest"],
+    "squeezy" => ["squeezier", "squeeziest"],
+    "squelchy" => ["squelchier", "squelchiest"],
+    "squicky" => ["squickier", "squickiest"],
+    "squidgy" => ["squidgier", "squidgiest"],
+    "squiffy" => ["squiffier", "squiffiest"],
+    "squiggley" => ["squigglier", "squiggliest"],
+    "squiggly" => ["squigglier", "squiggliest"],
+    "squinchy" => ["squinchier", "squinchiest"],
+    "squinty" => ["squintier", "squintiest"],
+    "squirmy" => ["squirmier", "squirmiest"],
+    "squirrelly" => ["squirrellier", "squirrelliest"],
+    "squishy" => ["squishier", "squishiest"],
+    "squooshy" => ["squooshier", "squooshiest"],
+    "squshy" => ["squshier", "squshiest"],
+    "squushy" => ["squushier", "squushiest"],
+    "stabby" => ["stabbier", "stabbiest"],
+    "stable" => ["stabler", "stablest"],
+    "stageworthy" => ["stageworthier", "stageworthiest"],
+    "staggy" => ["staggier", "staggiest"],
+    "stagy" => ["stagier", "stagiest"],
+    "staid" => ["staider", "staidest"],
+    "stale2" => ["staler", "stalest"],
+    "stalky" => ["stalkier", "stalkiest"],
+    "stanch" => ["stancher", "stanchest"],
+    "stanky2" => ["stankier", "stankiest"],
+    "starchy" => ["starchier", "starchiest"],
+    "starey" => ["starier", "stariest"],
+    "stark" => ["starker", "starkest"],
+    "starry" => ["starrier", "starriest"],
+    "stary" => ["starier", "stariest"],
+    "stately" => ["statelier", "stateliest"],
+    "staunch" => ["stauncher", "staunchest"],
+    "stay" => ["stayer", "stayest"],
+    "steadfast" => ["steadfaster", "steadfastest"],
+    "steady" => ["steadier", "steadiest"],
+    "steaky" => ["steakier", "steakiest"],
+    "stealthy" => ["stealthier", "stealthiest"],
+    "steamy" => ["steamier", "steamiest"],
+    "stedfast" => ["stedfaster", "stedfastest"],
+    "steely" => ["steelier", "steeliest"],
+    "steep" => ["steeper", "steepest"],
+    "steepy" => ["steepier", "steepiest"],
+    "steezy" => ["steezier", "steeziest"],
+    "stemmy" => ["stemmier", "stemmiest"],
+    "stenchy" => ["stenchier", "stenchiest"],
+    "stern" => ["sterner", "sternest"],
+    "sterne" => ["sterner", "sternest"],
+    "stick" => ["sticker", "stickest"],
+    "sticky" => ["stickier", "stickiest"],
+    "stiddy" => ["stiddier", "stiddiest"],
+    "stiff" => ["stiffer", "stiffest"],
+    "still" => ["stiller", "stillest"],
+    "stilly" => ["stillier", "stilliest"],
+    "stilty" => ["stiltier", "stiltiest"],
+    "stingy" => ["stingier", "stingiest"],
+    "stinky" => ["stinkier", "stinkiest"],
+    "stocky" => ["stockier", "stockiest"],
+    "stodgy" => ["stodgier", "stodgiest"],
+    "stolid" => ["stolider", "stolidest"],
+    "stompy" => ["stompier", "stompiest"],
+    "stony" => ["stonier", "stoniest"],
+    "stoopid" => ["stoopider", "stoopidest"],
+    "stoor" => ["stoorer", "stoorest"],
+    "storky" => ["storkier", "storkiest"],
+    "stormy" => ["stormier", "stormiest"],
+    "stout" => ["stouter", "stoutest"],
+    "straggly" => ["stragglier", "straggliest"],
+    "straicht" => ["straichter", "straichtest"],
+    "straight" => ["straighter", "straightest"],
+    "strait" => ["straiter", "straitest"],
+    "strang" => ["stranger", "strangest"],
+    "strange" => ["stranger", "strangest"],
+    "strappy" => ["strappier", "strappiest"],
+    "straung" => ["straunger", "straungest"],
+    "strawberry" => ["strawberrier", "strawberriest"],
+    "strawy" => ["strawier", "strawiest"],
+    "streaky" => ["streakier", "streakiest"],
+    "streamy" => ["streamier", "streamiest"],
+    "streetworthy" => ["streetworthier", "streetworthiest"],
+    "strengthy" => ["strengthier", "strengthiest"],
+    "stressy" => ["stressier", "stressiest"],
+    "stretchy" => ["stretchier", "stretchiest"],
+    "strict" => ["stricter", "strictest"],
+    "strikt" => ["strikter", "striktest"],
+    "stringy" => ["stringier", "stringiest"],
+    "stripey" => ["stripier", "stripiest"],
+    "stripy" => ["stripier", "stripiest"],
+    "strong" => ["stronger", "strongest"],
+    "stronk" => ["stronker", "stronkest"],
+    "stroppy" => ["stroppier", "stroppiest"],
+    "stubbly" => ["stubblier", "stubbliest"],
+    "stubborn" => ["stubborner", "stubbornest"],
+    "stubby" => ["stubbier", "stubbiest"],
+    "studly" => ["studlier", "studliest"],
+    "stuffy" => ["stuffier", "stuffiest"],
+    "stumbly" => ["stumblier", "stumbliest"],
+    "stumpy" => ["stumpier", "stumpiest"],
+    "stupid" => ["stupider", "stupidest"],
+    "sturdy" => ["sturdier", "sturdiest"],
+    "stuttery" => ["stutterier", "stutteriest"],
+    "suave" => ["suaver", "suavest"],
+    "subby" => ["subbier", "subbiest"],
+    "sublime" => ["sublimer", "sublimest"],
+    "subtile" => ["subtiler", "subtilest"],
+    "subtle" => ["subtler", "subtlest"],
+    "succinct" => ["succincter", "succinctest"],
+    "suckly" => ["sucklier", "suckliest"],
+    "sucky" => ["suckier", "suckiest"],
+    "sudden" => ["suddener", "suddenest"],
+    "sudsy" => ["sudsier", "sudsiest"],
+    "sugary" => ["sugarier", "sugariest"],
+    "sulky" => ["sulkier", "sulkiest"],
+    "sullen" => ["sullener", "sullenest"],
+    "sultry" => ["sultrier", "sultriest"],
+    "summery" => ["summerier", "summeriest"],
+    "sunly" => ["sunlier", "sunliest"],
+    "sunny" => ["sunnier", "sunniest"],
+    "superb" => ["superber", "superbest"],
+    "supple" => ["suppler", "supplest"],
+    "supream" => ["supreamer", "supreamest"],
+    "supreme" => ["supremer", "supremest"],
+    "sure" => ["surer", "surest"],
+    "surfy" => ["surfier", "surfiest"],
+    "surly" => ["surlier", "surliest"],
+    "sus2" => ["susser", "sussest"],
+    "sussy" => ["sussier", "sussiest"],
+    "svelte" => ["svelter", "sveltest"],
+    "swack" => ["swacker", "swackest"],
+    "swaggy" => ["swaggier", "swaggiest"],
+    "swaly" => ["swalier", "swaliest"],
+    "swampy" => ["swampier", "swampiest"],
+    "swank" => ["swanker", "swankest"],
+    "swanky" => ["swankier", "swankiest"],
+    "swanny" => ["swannier", "swanniest"],
+    "swarmy" => ["swarmier", "swarmiest"],
+    "swart" => ["swarter", "swartest"],
+    "swarthy" => ["swarthier", "swarthiest"],
+    "swash" => ["swasher", "swashest"],
+    "swear" => ["swearer", "swearest"],
+    "sweary" => ["swearier", "sweariest"],
+    "sweaty" => ["sweatier", "sweatiest"],
+    "swebby" => ["swebbier", "swebbiest"],
+    "sweepy" => ["sweepier", "sweepiest"],
+    "sweet" => ["sweeter", "sweetest"],
+    "swell" => ["sweller", "swellest"],
+    "swerfy" => ["swerfier", "swerfiest"],
+    "swicy" => ["swicier", "swiciest"],
+    "swift" => ["swifter", "swiftest"],
+    "swimmy" => ["swimmier", "swimmiest"],
+    "swinging2" => ["swinginger", "swingingest"],
+    "swingy" => ["swingier", "swingiest"],
+    "swirly" => ["swirlier", "swirliest"],
+    "swish" => ["swisher", "swishest"],
+    "swishy" => ["swishier", "swishiest"],
+    "switchy" => ["switchier", "switchiest"],
+    "swith" => ["swither", "swithest"],
+    "swole" => ["swoler", "swolest"],
+    "swoopy" => ["swoopier", "swoopiest"],
+    "swotty" => ["swottier", "swottiest"],
+    "syrupy" => ["syrupier", "syrupiest"],
+    "tabby" => ["tabbier", "tabbiest"],
+    "tacky" => ["tackier", "tackiest"],
+    "taggy" => ["taggier", "taggiest"],
+    "talksy" => ["talksier", "talksiest"],
+    "talky" => ["talkier", "talkiest"],
+    "tall" => ["taller", "tallest"],
+    "tame" => ["tamer", "tamest"],
+    "tan" => ["tanner", "tannest"],
+    "tangly" => ["tanglier", "tangliest"],
+    "tangy" => ["tangier", "tangiest"],
+    "tanky" => ["tankier", "tankiest"],
+    "tardy" => ["tardier", "tardiest"],
+    "tarry" => ["tarrier", "tarriest"],
+    "tart" => ["tarter", "tartest"],
+    "tarty" => ["tartier", "tartiest"],
+    "tasky" => ["taskier", "taskiest"],
+    "tasty" => ["tastier", "tastiest"],
+    "tatty" => ["tattier", "tattiest"],
+    "taut" => ["tauter", "tautest"],
+    "tawdry" => ["tawdrier", "tawdriest"],
+    "tawny" => ["tawnier", "tawniest"],
+    "teary" => ["tearier", "teariest"],
+    "techy2" => ["techier", "techiest"],
+    "teensy" => ["teensier", "teensiest"],
+    "teentsy" => ["teentsier", "teentsiest"],
+    "teenty" => ["teentier", "teentiest"],
+    "teeny" => ["teenier", "teeniest"],
+    "tender" => ["tenderer", "tenderest"],
+    "tense" => ["tenser", "tensest"],
+    "terfy" => ["terfier", "terfiest"],
+    "terrible" => ["terribler", "terriblest"],
+    "terse" => ["terser", "tersest"],
+    "testy" => ["testier", "testiest"],
+    "tetchy" => ["tetchier", "tetchiest"],
+    "teuch" => ["teucher", "teuchest"],
+    "thankworthy" => ["thankworthier", "thankworthiest"],
+    "thic" => ["thiccer", "thiccest"],
+    "thicc" => ["thiccer", "thiccest"],
+    "thick" => ["thicker", "thickest"],
+    "thin" => ["thinner", "thinnest"],
+    "thingy" => ["thingier", "thingiest"],
+    "thirsty" => ["thirstier", "thirstiest"],
+    "thistly" => ["thistlier", "thistliest"],
+    "thither" => ["more thither", "thithermost"],
+    "thoan" => ["thoaner", "thoanest"],
+    "thone" => ["thoner", "thonest"],
+    "thorny" => ["thornier", "thorniest"],
+    "thoro" => ["thoroer", "thoroest"],
+    "thorough" => ["thorougher", "thoroughest"],
+    "thotty" => ["thottier", "thottiest"],
+    "thoughtworthy" => ["thoughtworthier", "thoughtworthiest"],
+    "thoughty" => ["thoughtier", "thoughtiest"],
+    "thrashy" => ["thrashier", "thrashiest"],
+    "thready" => ["threadier", "threadiest"],
+    "thrifty" => ["thriftier", "thriftiest"],
+    "thrilly" => ["thrillier", "thrilliest"],
+    "throaty" => ["throatier", "throatiest"],
+    "throbby" => ["throbbier", "throbbiest"],
+    "throng" => ["thronger", "throngest"],
+    "thuggy" => ["thuggier", "thuggiest"],
+    "thumby" => ["thumbier", "thumbiest"],
+    "thumpy" => ["thumpier", "thumpiest"],
+    "thungry" => ["thungrier", "thungriest"],
+    "thwarty" => ["thwartier", "thwartiest"],
+    "thymey" => ["thymier", "thymiest"],
+    "thymy" => ["thymier", "thymiest"],
+    "tichy" => ["tichier", "tichiest"],
+    "tickly" => ["ticklier", "tickliest"],
+    "ticky" => ["tickier", "tickiest"],
+    "tiddly2" => ["tiddlier", "tiddliest"],
+    "tiddy" => ["tiddier", "tiddiest"],
+    "tidy" => ["tidier", "tidiest"],
+    "tight" => ["tighter", "tightest"],
+    "timely" => ["timelier", "timeliest"],
+    "timid" => ["timider", "timidest"],
+    "tine" => ["tiner", "tinest"],
+    "tinfoily" => ["tinfoilier", "tinfoiliest"],
+    "tingly" => ["tinglier", "tingliest"],
+    "tinkly" => ["tinklier", "tinkliest"],
+    "tinny" => ["tinnier", "tinniest"],
+    "tiny" => ["tinier", "tiniest"],
+    "tippy" => ["tippier", "tippiest"],
+    "tipsy" => ["tipsier", "tipsiest"],
+    "tired2" => ["tireder", "tiredest"],
+    "titchy" => ["titchier", "titchiest"],
+    "toasty" => ["toastier", "toastiest"],
+    "toey" => ["toeier", "toeiest"],
+    "toffy" => ["toffier", "toffiest"],
+    "tomatoey" => ["tomatoier", "tomatoiest"],
+    "toney" => ["tonier", "toniest"],
+    "tonguey" => ["tonguier", "tonguiest"],
+    "tonguy" => ["tonguier", "tonguiest"],
+    "tonk" => ["tonker", "tonkest"],
+    "tony" => ["tonier", "toniest"],
+    "toolsy" => ["toolsier", "toolsiest"],
+    "toothly" => ["toothlier", "toothliest"],
+    "toothy" => ["toothier", "toothiest"],
+    "toplofty" => ["toploftier", "toploftiest"],
+    "torchy" => ["torchier", "torchiest"],
+    "torquey" => ["torquier", "torquiest"],
+    "torrid" => ["torrider", "torridest"],
+    "tort2" => ["torter", "tortest"],
+    "tosh" => ["tosher", "toshest"],
+    "toshy" => ["toshier", "toshiest"],
+    "tottery" => ["totterier", "totteriest"],
+    "touchy" => ["touchier", "touchiest"],
+    "tough" => ["tougher", "toughest"],
+    "touristy" => ["touristier", "touristiest"],
+    "towny" => ["townier", "towniest"],
+    "towzy" => ["towzier", "towziest"],
+    "trace" => ["more trace", "tracest"],
+    "traddy" => ["traddier", "traddiest"],
+    "trampy" => ["trampier", "trampiest"],
+    "tranquil" => ["tranquiler", "tranquilest"],
+    "tranty" => ["trantier", "trantiest"],
+    "trappy" => ["trappier", "trappiest"],
+    "trashy" => ["trashier", "trashiest"],
+    "treacly" => ["treaclier", "treacliest"],
+    "trebly" => ["treblier", "trebliest"],
+    "treey" => ["treeier", "treeiest"],
+    "trembly" => ["tremblier", "trembliest"],
+    "trendy" => ["trendier", "trendiest"],
+    "trick" => ["tricker", "trickest"],
+    "tricksy" => ["tricksier", "tricksiest"],
+    "tricky" => ["trickier", "trickiest"],
+    "trig" => ["trigger", "triggest"],
+    "trill" => ["triller", "trillest"],
+    "trim" => ["trimmer", "trimmest"],
+    "tripey" => ["tripier", "tripiest"],
+    "trippy" => ["trippier", "trippiest"],
+    "tripy" => ["tripier", "tripiest"],
+    "trite" => ["triter", "tritest"],
+    "trouty" => ["troutier", "troutiest"],
+    "trucky" => ["truckier", "truckiest"],
+    "true2" => ["truer", "truest"],
+    "trumpy" => ["trumpier", "trumpiest"],
+    "trusty" => ["trustier", "trustiest"],
+    "truthy" => ["truthier", "truthiest"],
+    "tubby" => ["tubbier", "tubbiest"],
+    "tuff" => ["tuffer", "tuffest"],
+    "tufty" => ["tuftier", "tuftiest"],
+    "tuny" => ["tunier", "tuniest"],
+    "turfy" => ["turfier", "turfiest"],
+    "turpsy" => ["turpsier", "turpsiest"],
+    "tusky" => ["tuskier", "tuskiest"],
+    "twangy" => ["twangier", "twangiest"],
+    "twatty" => ["twattier", "twattiest"],
+    "tweaky" => ["tweakier", "tweakiest"],
+    "twee" => ["tweer", "tweest"],
+    "tweedy" => ["tweedier", "tweediest"],
+    "tweeny" => ["tweenier", "tweeniest"],
+    "tweety" => ["tweetier", "tweetiest"],
+    "twiddly" => ["twiddlier", "twiddliest"],
+    "twiggy" => ["twiggier", "twiggiest"],
+    "twinkly" => ["twinklier", "twinkliest"],
+    "twinky" => ["twinkier", "twinkiest"],
+    "twirly" => ["twirlier", "twirliest"],
+    "twisty" => ["twistier", "twistiest"],
+    "twitchy" => ["twitchier", "twitchiest"],
+    "twitty" => ["twittier", "twittiest"],
+    "twunky" => ["twunkier", "twunkiest"],
+    "typey" => ["typier", "typiest"],
+    "typy" => ["typier", "typiest"],
+    "u2" => ["uer", "uest"],
+    "ugly" => ["uglier", "ugliest"],
+    "ultradry" => ["ultradrier", "ultradriest"],
+    "unable" => ["unabler", "unablest"],
+    "unbusy" => ["unbusier", "unbusiest"],
+    "uncanny" => ["uncannier", "uncanniest"],
+    "unclean" => ["uncleaner", "uncleanest"],
+    "unclear" => ["unclearer", "unclearest"],
+    "uncomely" => ["uncomelier", "uncomeliest"],
+    "uncommon" => ["uncommoner", "uncommonest"],
+    "uncool" => ["uncooler", "uncoolest"],
+    "uncouth" => ["uncouther", "uncouthest"],
+    "undirty" => ["undirtier", "undirtiest"],
+    "unearthly" => ["unearthlier", "unearthliest"],
+    "uneasy" => ["uneasier", "uneasiest"],
+    "unfair" => ["unfairer", "unfairest"],
+    "unfit" => ["unfitter", "unfittest"],
+    "unfree" => ["unfreer", "unfreest"],
+    "unfriendly" => ["unfriendlier", "unfriendliest"],
+    "unfull" => ["unfuller", "unfullest"],
+    "unfunny" => ["unfunnier", "unfunniest"],
+    "unfussy" => ["unfussier", "unfussiest"],
+    "ungainly" => ["ungainlier", "ungainliest"],
+    "ungentle" => ["ungentler", "ungentlest"],
+    "ungodly" => ["ungodlier", "ungodliest"],
+    "ungood" => ["ungooder", "ungoodest"],
+    "unhandsome" => ["unhandsomer", "unhandsomest"],
+    "unhandy" => ["unhandier", "unhandiest"],
+    "unhappy" => ["unhappier", "unhappiest"],
+    "unhealthy" => ["unhealthier", "unhealthiest"],
+    "unholy" => ["unholier", "unholiest"],
+    "unique" => ["uniquer", "uniquest"],
+    "unkind" => ["unkinder", "unkindest"],
+    "unlikely" => ["unlikelier", "unlikeliest"],
+    "unlovely" => ["unlovelier", "unloveliest"],
+    "unlucky" => ["unluckier", "unluckiest"],
+    "unmad" => ["unmadder", "unmaddest"],
+    "unmanly" => ["unmanlier", "unmanliest"],
+    "unmannerly" => ["unmannerlier", "unmannerliest"],
+    "unpleasant" => ["unpleasanter", "unpleasantest"],
+    "unpretty" => ["unprettier", "unprettiest"],
+    "unquiet" => ["unquieter", "unquietest"],
+    "unripe" => ["unriper", "unripest"],
+    "unruly" => ["unrulier", "unruliest"],
+    "unsad" => ["unsadder", "unsaddest"],
+    "unsafe" => ["unsafer", "unsafest"],
+    "unseemly" => ["unseemlier", "unseemliest"],
+    "unsexy" => ["unsexier", "unsexiest"],
+    "unskinny" => ["unskinnier", "unskinniest"],
+    "unsound" => ["unsounder", "unsoundest"],
+    "unsteady" => ["unsteadier", "unsteadiest"],
+    "unsubtle" => ["unsubtler", "unsubtlest"],
+    "unsure" => ["unsurer", "unsurest"],
+    "unthrifty" => ["unthriftier", "unthriftiest"],
+    "untidy" => ["untidier", "untidiest"],
+    "untimely" => ["untimelier", "untimeliest"],
+    "unwary" => ["unwarier", "unwariest"],
+    "unwelcome" => ["unwelcomer", "unwelcomest"],
+    "unwide" => ["unwider", "unwidest"],
+    "unwise" => ["unwiser", "unwisest"],
+    "unworldly" => ["unworldlier", "unworldliest"],
+    "unworthy" => ["unworthier", "unworthiest"],
+    "upper" => ["more upper", "uppermost"],
+    "uppity" => ["uppitier", "uppitiest"],
+    "uppy" => ["uppier", "uppiest"],
+    "uptight" => ["uptighter", "uptightest"],
+    "urbane" => ["urbaner", "urbanest"],
+    "useful" => ["usefuller", "usefullest"],
+    "useless" => ["uselesser", "uselessest"],
+    "utter" => ["more utter", "utmost"],
+    "vague" => ["vaguer", "vaguest"],
+    "vain" => ["vainer", "vainest"],
+    "vampy" => ["vampier", "vampiest"],
+    "vast" => ["vaster", "vastest"],
+    "vasty" => ["vastier", "vastiest"],
+    "veally" => ["veallier", "vealliest"],
+    "vealy" => ["vealier", "vealiest"],
+    "veiny" => ["veinier", "veiniest"],
+    "verie" => ["verier", "veriest"],
+    "very" => ["verier", "veriest"],
+    "verye" => ["verier", "veriest"],
+    "vibey" => ["vibier", "vibiest"],
+    "viby" => ["vibier", "vibiest"],
+    "vicious" => ["viciouser", "viciousest"],
+    "viewy" => ["viewier", "viewiest"],
+    "vile" => ["viler", "vilest"],
+    "viny" => ["vinier", "viniest"],
+    "violent" => ["violenter", "violentest"],
+    "violet" => ["violeter", "violetest"],
+    "vivid" => ["vivider", "vividest"],
+    "vulgar" => ["vulgarer", "vulgarest"],
+    "wack" => ["wacker", "wackest"],
+    "wacky" => ["wackier", "wackiest"],
+    "waffley" => ["wafflier", "waffliest"],
+    "waffly" => ["wafflier", "waffliest"],
+    "waffy" => ["waffier", "waffiest"],
+    "waggly" => ["wagglier", "waggliest"],
+    "waggy" => ["waggier", "waggiest"],
+    "waily" => ["wailier", "wailiest"],
+    "waltzy" => ["waltzier", "waltziest"],
+    "wambly" => ["wamblier", "wambliest"],
+    "wan" => ["wanner", "wannest"],
+    "wanky" => ["wankier", "wankiest"],
+    "wanton" => ["wantoner", "wantonest"],
+    "warby" => ["warbier", "warbiest"],
+    "warm" => ["warmer", "warmest"],
+    "warmy" => ["warmier", "warmiest"],
+    "warry2" => ["warrier", "warriest"],
+    "warty" => ["wartier", "wartiest"],
+    "warweary" => ["warwearier", "warweariest"],
+    "wary" => ["warier", "wariest"],
+    "washy" => ["washier", "washiest"],
+    "waspy" => ["waspier", "waspiest"],
+    "wassy" => ["wassier", "wassiest"],
+    "wasty" => ["wastier", "wastiest"],
+    "watery" => ["waterier", "wateriest"],
+    "watry" => ["watrier", "watriest"],
+    "wavey" => ["wavier", "waviest"],
+    "wavy" => ["wavier", "waviest"],
+    "waxy2" => ["waxier", "waxiest"],
+    "weak" => ["weaker", "weakest"],
+    "weakly" => ["weaklier", "weakliest"],
+    "weaky" => ["weakier", "weakiest"],
+    "wealthy" => ["wealthier", "wealthiest"],
+    "weary" => ["wearier", "weariest"],
+    "weaselly" => ["weasellier", "weaselliest"],
+    "weasely" => ["weaselier", "weaseliest"],
+    "webby" => ["webbier", "webbiest"],
+    "wedgy" => ["wedgier", "wedgiest"],
+    "wee" => ["weer", "weest"],
+    "weeby" => ["weebier", "weebiest"],
+    "weedy" => ["weedier", "weediest"],
+    "weensy" => ["weensier", "weensiest"],
+    "weeny" => ["weenier", "weeniest"],
+    "weepy" => ["weepier", "weepiest"],
+    "weighty" => ["weightier", "weightiest"],
+    "weird2" => ["weirder", "weirdest"],
+    "welcome" => ["welcomer", "welcomest"],
+    "well" => ["better", "best"],
+    "wenchy" => ["wenchier", "wenchiest"],
+    "wersh" => ["wersher", "wershest"],
+    "western2" => ["more western", "westernmost"],
+    "wet" => ["wetter", "wettest"],
+    "whack" => ["whacker", "whackest"],
+    "whacky" => ["whackier", "whackiest"],
+    "wheaty" => ["wheatier", "wheatiest"],
+    "wheelie" => ["wheelier", "wheeliest"],
+    "wheely" => ["wheelier", "wheeliest"],
+    "wheezy" => ["wheezier", "wheeziest"],
+    "whelpy" => ["whelpier", "whelpiest"],
+    "wheyey" => ["wheyier", "wheyiest"],
+    "whiffy" => ["whiffier", "whiffiest"],
+    "whifty" => ["whiftier", "whiftiest"],
+    "whimpy" => ["whimpier", "whimpiest"],
+    "whimsy" => ["whimsier", "whimsiest"],
+    "whiney" => ["whinier", "whiniest"],
+    "whiny" => ["whinier", "whiniest"],
+    "whippy" => ["whippier", "whippiest"],
+    "whirly" => ["whirlier", "whirliest"],
+    "white2" => ["whiter", "whitest"],
+    "whitey" => ["whitier", "whitiest"],
+    "whity" => ["whitier", "whitiest"],
+    "whizzy" => ["whizzier", "whizziest"],
+    "whole" => ["wholer", "wholest"],
+    "wholesome" => ["wholesomer", "wholesomest"],
+    "wholle" => ["wholler", "whollest"],
+    "wholsom" => ["wholsomer", "wholsomest"],
+    "wholsome" => ["wholsomer", "wholsomest"],
+    "whorey" => ["whorier", "whoriest"],
+    "whott" => ["whotter", "whottest"],
+    "wibbly" => ["wibblier", "wibbliest"],
+    "wick" => ["wicker", "wickest"],
+    "wicked2" => ["wickeder", "wickedest"],
+    "widdle" => ["widdler", "widdlest"],
+    "wide" => ["wider", "widest"],
+    "wifely" => ["wifelier", "wifeliest"],
+    "wifey" => ["wifier", "wifiest"],
+    "wifty" => ["wiftier", "wiftiest"],
+    "wiggly" => ["wigglier", "wiggliest"],
+    "wiggy" => ["wiggier", "wiggiest"],
+    "wild" => ["wilder", "wildest"],
+    "wilful" => ["wilfuler", "wilfulest"],
+    "willful" => ["willfuler", "willfulest"],
+    "willowy" => ["willowier", "willowiest"],
+    "willy" => ["willier", "williest"],
+    "wily" => ["wilier", "wiliest"],
+    "wimpy" => ["wimpier", "wimpiest"],
+    "windy" => ["windier", "windiest"],
+    "winning" => ["winninger", "winningest"],
+    "winsome" => ["winsomer", "winsomest"],
+    "wintery" => ["winterier", "winteriest"],
+    "wintry" => ["wintrier", "wintriest"],
+    "winy" => ["winier", "winiest"],
+    "wiry" => ["wirier", "wiriest"],
+    "wise" => ["wiser", "wisest"],
+    "wisht" => ["wishter", "wishtest"],
+    "wispy" => ["wispier", "wispiest"],
+    "witchy" => ["witchier", "witchiest"],
+    "withy" => ["withier", "withiest"],
+    "wittle" => ["wittler", "wittlest"],
+    "witty" => ["wittier", "wittiest"],
+    "wobbly" => ["wobblier", "wobbliest"],
+    "wode" => ["woder", "wodest"],
+    "wodgy" => ["wodgier", "wodgiest"],
+    "woeful" => ["woefuller", "woefullest"],
+    "wofull" => ["wofuller", "wofullest"],
+    "woggy" => ["woggier", "woggiest"],
+    "woke" => ["woker", "wokest"],
+    "wold" => ["wolder", "woldest"],
+    "wolfly" => ["wolflier", "wolfliest"],
+    "wolfy" => ["wolfier", "wolfiest"],
+    "womanly" => ["womanlier", "womanliest"],
+    "wonderful" => ["wonderfuller", "wonderfullest"],
+    "wonky" => ["wonkier", "wonkiest"],
+    "wood" => ["wooder", "woodest"],
+    "woodsy" => ["woodsier", "woodsiest"],
+    "woody" => ["woodier", "woodiest"],
+    "woofy" => ["woofier", "woofiest"],
+    "woolly" => ["woollier", "woolliest"],
+    "wooly" => ["woolier", "wooliest"],
+    "woozy" => ["woozier", "wooziest"],
+    "wordy" => ["wordier", "wordiest"],
+    "worky" => ["workier", "workiest"],
+    "worldly" => ["worldlier", "worldliest"],
+    "worldweary" => ["worldwearier", "worldweariest"],
+    "wormy" => ["wormier", "wormiest"],
+    "worthless" => ["worthlesser", "worthlessest"],
+    "worthly" => ["worthlier", "worthliest"],
+    "worthy" => ["worthier", "worthiest"],
+    "wrathy" => ["wrathier", "wrathiest"],
+    "wriggly" => ["wrigglier", "wriggliest"],
+    "wrinkly" => ["wrinklier", "wrinkliest"],
+    "wristy" => ["wristier", "wristiest"],
+    "wry" => ["wrier", "wriest"],
+    "wurlie" => ["wurlier", "wurliest"],
+    "wurly" => ["wurlier", "wurliest"],
+    "wussy" => ["wussier", "wussiest"],
+    "wyde" => ["wyder", "wydest"],
+    "wyse" => ["wyser", "wysest"],
+    "yachty" => ["yachtier", "yachtiest"],
+    "yakky" => ["yakkier", "yakkiest"],
+    "yald" => ["yalder", "yaldest"],
+    "yaller" => ["yallerer", "yallerest"],
+    "yammy" => ["yammier", "yammiest"],
+    "yappy" => ["yappier", "yappiest"],
+    "yar2" => ["yarer", "yarest"],
+    "yare" => ["yarer", "yarest"],
+    "yary" => ["yarier", "yariest"],
+    "yauld" => ["yaulder", "yauldest"],
+    "yawny" => ["yawnier", "yawniest"],
+    "yearny" => ["yearnier", "yearniest"],
+    "yeasty" => ["yeastier", "yeastiest"],
+    "yecchy" => ["yecchier", "yecchiest"],
+    "yechy" => ["yechier", "yechiest"],
+    "yellow" => ["yellower", "yellowest"],
+    "yellowy" => ["yellowier", "yellowiest"],
+    "yelly" => ["yellier", "yelliest"],
+    "yelpy" => ["yelpier", "yelpiest"],
+    "yiffy" => ["yiffier", "yiffiest"],
+    "yolky" => ["yolkier", "yolkiest"],
+    "yong" => ["yonger", "yongest"],
+    "yoong" => ["yoonger", "yoongest"],
+    "young" => ["younger", "youngest"],
+    "yucky" => ["yuckier", "yuckiest"],
+    "yukky" => ["yukkier", "yukkiest"],
+    "yummy" => ["yummier", "yummiest"],
+    "zany" => ["zanier", "zaniest"],
+    "zappy" => ["zappier", "zappiest"],
+    "zate" => ["zater", "zatest"],
+    "zazzy" => ["zazzier", "zazziest"],
+    "zeitgeisty" => ["zeitgeistier", "zeitgeistiest"],
+    "zesty" => ["zestier", "zestiest"],
+    "zhlubby" => ["zhlubbier", "zhlubbiest"],
+    "zhooshy" => ["zhooshier", "zhooshiest"],
+    "zingy" => ["zingier", "zingiest"],
+    "zippy" => ["zippier", "zippiest"],
+    "zitty" => ["zittier", "zittiest"],
+    "zooey" => ["zooier", "zooiest"],
+    "zoomy" => ["zoomier", "zoomiest"],
 };
-
-pub fn get_adjective_forms(positive: &str) -> Option<(&'static str, &'static str)> {
-    ADJECTIVE_MAP.get(positive).copied()
-}
@@ -0,0 +1,775 @@
+use crate::trie::{Edit, Trie};
+
+/// (comparative, superlative)
+pub static ADJECTIVE_MAP: Trie<2> = Trie::new(
+    b"\x00abcdefghijklmnopqrstuvwyzbcdegilmnprsuvwaeiloruaehiloruaeioruwyabcdeglmrvxaeiloruaehilnoruaeiou\
+        wycfglmnrtaeioueilnorvwaeiouyaeiouyaeiouyabdfiklnopruwaehiloruwouwaehiouacehiklmnopqtuvwyaehioru\
+        wy2glnprstaeiuaehioruyaeiouaehiogjlrsehiruerulrmobpucgtxcimrsthstglnsaefnbcdgilmnrstwyaegilnglrt\
+        zaeiouabglnoprsuwxyaeioubfgilmnrstxzcgklmnrstnraeioruaeiouabcdilmnorsuwxyzaeiouydnprstudfghimnpr\
+        sabeflmnrsvwcdglmnprstzdgloprtuwzaeiouycdflmnstaeeygrstzeeyblprdiaptbdfgiklmnprstuvyaceilmnrtycd\
+        egilnrstzaeiouyagilnoruwxaeioubcdglmnrszbgimnprsuwyemnryzaeodgmnraeiourstabdelnorstuaeioucimnrst\
+        cilmnprstuyzadflnprtglnpsagklmnoprtubfglmnrsepekybrmpadfknstakcstbgmnruzamrtwgmntvkluwydimnsenwy\
+        bcdnstdgntaiouklaicgikmnrtuvxzadefgmnvwcegkmnpstvafglnorstuvwbcklmnsxikdilnrstuwzaelmnorsdfglmnr\
+        stxadilmoprstucdgmnrstzrtfgimprstuabceilrstwcfgmptbinorstubdmtktelstly2aeirmzaeansltdycilprstuwa\
+        bcenprstaloucdglmnstzaeiouacdiklnoprstuxaeiobcdfgklnrsaeiobcdfginprstuwacdemtveybcdfgmpstzabcgio\
+        prstuwybdfglmnstbdfgilnprsuvwahoruacdelnrvxaeilmnortuwybcglmnsaeioruyaeiouyaeiouaeiouabfgilmnopr\
+        uwyaeiloruaeiosuaeioruabcdglmnprsaeiobcglmnrstuwacenrstuaioruwycdgmnprtaefmnoprstuwaeioubfnrsaei\
+        ueyabcdefghiklmpqrstwptflgimsairbcelnovcfgilmnrstvxabdeilnrstaeiobcdfglmnrstbdefgklmnoraiyrsdsck\
+        lmpruwacllnouckmnptzislonptemeotueywyrsils2psyctby23aydklmadgekmnorehscdkmnrsufgprey2deitglcestb\
+        cknstaemnprstabcgknostuwefgnrsgdsenybfkmnstzeikosglnescgikmnstvwzaecegnstaeknotwmsyglsgklmpcnblr\
+        shstycteyeylmydntctflnrstvaefrsuwcelnprciknopyiobfmnrgmnrstwyaervcfmnqcdgsuwbcmnclrbwkolndleflmp\
+        ctlotknitycgnrtayiybcfgmnpswzaepmnsaostuwdefimnsdilmrstvhpsdglftoynpcdgkeydknthtdfrtdprtiuektfl2\
+        pgketcghtszdgoyfmzeytycgrdnbfgpuwacegiksbfnpzflnosuwbdgmn2debpgnktctlnttyyiy2bedeoynrteylsmthttl\
+        slstblelnvdgnrsghmteinmsbgkmpstuesxcgmnprtabcopsuwefkstisydflbdeklwltginstacemnqseglmnszgostuwim\
+        eygllsknstemeppsdnzdkmaylyiympltdmrszagbmnstboprswegmrdfdefgknpsksy2yntbdinpstuvyaeylmnpstzadgos\
+        tuwbdefmngkhtstlreldgdmshtetgndlprtuvlycncdkpkrxgweiyelylydkltnrsylyegyabipgkehlyeouetiygk2tlygk\
+        yeyltgkeydgksteyegybtcreybegkdgkehiyfklnrtkrtwet2dwaebepytye2ehteimtegnpsstcdensaeidlnblikkyhtde\
+        tfgklycdlshsehglnstkrtlteirdftycdfkscegtukty2eydnrepymohllrstlygbscdychkstetftvkrktprtiyeklydvht\
+        sehftlyhptblcseybdkmteycelyeybmstcteyaedeeyftelmteykyckrtpreyfktvkteyckychiazcgintyabnsgnnpstcmn\
+        sdgcgefprtlykntehcfntinacdptcemnsfgnpsuwbedgseycknydeprsthscgiklryaenceprbgkygqegdgkteichphtgndl\
+        mrdfkmeyhkefhiepeyklmpteytugnpeildeleybmpnthtegylyltdeekovbclmnrteilmnrwaeioufmnrzmrwdlmpdfly2na\
+        rbcdfgklnpreilwefmnprtvouaeacdnopruweiouslekltvlycfknetwdlmnpnzbcgnpstaeincdgmnptabpsuvwdgmrstcl\
+        ralxdlrgklodgrstcgikprstzaeldfptbortuwbfgkpbeeiwerglkntdernprtcmnrzcenrcfknvaeiounortaiouymnblmr\
+        stwaelcdfgnrsbgilnrtuyademnrcdflncdlmnoruaeiobdfmprtbltckdskltlneprefl2scglmnrsabelrcfmnrstlotkl\
+        gkdrtktdnstydsfrscnrstanrtuaeiogmneyhkdyeiefgknypsegkyltcqrtycgrnzcdmnpsabemncglmptcemstftfpntae\
+        dgnrstalorsairuaeoaeoioudnlriuaekotuhiaeioeiyylyiyeyntylylyktbmrtwyhpstereyklrsbdnpgrclyaelyfmnp\
+        rtzlrtedeetlydflydnstyehpchtegdfdkdflzdklmtgnsiydl2eyrschlpaueqiyilosydyylyilyeiy2edkhtckrdpdggw\
+        edmctszsyzbsyiyiyelyygcnhshtdksteyktiyhkqsyyryiyptkprsrtkldlcknteykpeycsnsmpclnmgkhsnrbygkeylydn\
+        pseiyoyallpyyepyhskmskpgkyklcpdebmpcketiyll2etcgeyetdyehtybdmrcgellpgtey2atyyylygyfyhlcyiyeycktl\
+        ygtpdfnreyfyytyhdlctkslzyhieylynszpiy2lyiyiysksctzmpp2ey2lyeyestdknelylteymvetycnstltftblpdgtsy2\
+        ylyfyltyeyiyeyiydsyyiyeylyrylylyylalweylyeyalymyyeriyeyfyrsdteyryoywyeyiylyeyeu2yiyehltyiyey2yye\
+        yetaympyeylyfyckekyeeyhpeydilyeyyeyetszfnrzeieybylyytyesyyyrueyyceryyzouaeocpgnptwaewbmbfmnfvoyl\
+        ydknprgylpetylnyeydwelmniylrvzctcntbgthpseyckptzdyepyeyhteypympprmteyctveyklrzlyepychtptztyglyhy\
+        ekyeksikcdgmkyeydnystdstrteyfknpuwygnteyiltkleyctmrblegyekckcekrydklmlpzeyltgkprkmginpuwaenstckn\
+        pnploybpillyamknmtrtptiyychsylyysyckgnowyflabnbplylyeylyqtsyepydntlyctnrmoueilnsdonplndfexbrdmds\
+        lteeyylylysylzftpseyeyelslyfoyerlylysylylwlyoygytyeylyflyhyyeylyleylydweyyyyytyey2eyiy2yoyeybdnu\
+        ylylsly2bbmlnpzlylneypycgkeyyeyyylyeyghezfyyycgtebykmeygkyhyuemsyiy2yseeyywwytzlyiy",
+    b"ryile2tidvilkbhoeyggyptykyilaitefynoluoazyetchycdfallydlettyolayuelterupyplytradrybaneeterlgarff\
+        yoeschmacktecteuptrbdidteptoitltyondynelyitiouslesingientioushd2antyyyuteustdtereresomeulrykstab\
+        bygytmytydygarlygovedsyarrerdyblypyyeyblyfrdlyomzyky2ptytworthytraltaingyyeydlytypyeutegkntypbyu\
+        resepyperateoutydyhtpyzygefulkyyky2flrfebyyerhylordyyyytectloityremefygeworthycypyouriterekystya\
+        ledychyklenehyzymygynekdlysykygyryzybygynlyglylickykymytlemelligstlyystdglychylyhytytybylylesipy\
+        hkyltymyly2ky2mypyeyygytyppenbytyhlysyeeypy22ndbyfykyty2kyyleantenseneiscreirmyeraneenseteyhyyyb\
+        ygymgonyntyzylousmykyishgpteryencylyfulgycypytnelblkydysygkyzyggyghtlyyykgyryyghyygytgyonygelkyf\
+        htpysomtyylydlyhewarmzypyengedlyvekyyeyschywysyhttyyynystdymysykdykzyiehyfgyelypyty2ghtybdghborl\
+        ylyhhblepcnybyyseiqueuseyyryony2yphyyquenngumdnpykytchynchyblykyg2ptytegmynnnykydlygylyplysychyk\
+        yntyyiteptyyefygypyidtynye2kless2otehevyumymaldgyykyzyzystyustkyulytyydyal2bfgytyleentpy2sycycyu\
+        reatedyeey2azzyickyckyeetkhtlypsynkydyunklyeytgylybpyeroshyhyshyvearymerybykygyetytryhy2ntychych\
+        nkworthyartymhted2chystyyfyatoeyloftyhteryutybyykynkyybleusyirtyaqueindauietightulessuenpytlnyio\
+        uswyeyleidkfglytzyblyy2bygychyshtern2ckblykgpyyblyfulullgyeanlythylsyeehtykymypyldnykygngngkykym\
+        yypyezytgeistytyubbyoshygypytyyyntluterrdyorthyytyyyriestlyientlyssyyylyyyyyque2enyhyyyyytlytyyy\
+        gnyowyhyybyk2epypytysyhetybykyeydsomyfgytryhyy2hyy2yyylickyydngymyiedcyryyknyyblyenyenzkyfhtytle\
+        dye2zydyhyn2myyyyyyyhyylyy2ytyyy2yheryyyow2idy2yhyyfykycytetyvfytygyyfpolatykypysestmassymybyfyk\
+        ygytytyyeyrklyerkfybyukydygyedbykykyhyyseyebbynuttyyyylyyonreteent2olyyy2elyhyhytrytlyhierdlyeby\
+        ktygypyklyyypypkyschetydyltysytyyous2yudgeonlyentyyyyumyyneedy2ylyryedyyardly2hlyyexeyyyy2lyyyyr\
+        eustingyincty2yeryyne2yyyyhyytygyeyghtylykygykysyblytykworthypyzlyfysysylyggylykyyyyyhyyesthern2\
+        eyytythene2yyy2ytytytyley2yidlyetydytyytyyhyckytbygyypyhyntyhykyhtysytytytybykypysyy2yheyyylackr\
+        yyornardhyileksklydchuenthndlyly2gkyzgydtyhysytyyyyomeyyyyyyyytyyyanlyn2yeickyyhyysyymymerytybyp\
+        yioussyyygygyyyy2lyyyyyyydyybyelynydsyefulndeldypy2tyzlyty2gysbygysomeyyyyyy2yoweenyryfulhyyfuly\
+        htytedthythyhytyyyyyartsyyeow2yyyyyyyneledyyyyeachylitedentteelyyy2ylyyyyylyyylyychleyyybtyklyly\
+        yyyeyyshyyyheryyyardrnhyeightbleyy2elelydyhly2yyeyhelygyblengylyydyyyyulyyyhy2yyhyyyy2lyyowyeley\
+        yhyyyyycraftyyytehyyyyyonyseyeynfulhyeylyyhyanelyyy2lyeyowyyyessarybeardyyyyeryeyyerickyeeyylyyi\
+        ehern2yhyworthyyyyyruseryryoorsyr2yulyyryyyyyyryectyyyyhyhyfulzzyazzyidunyfulsbytifulaunthtykyky\
+        pyivetykykyhyyyyyyyy2yylyyhycyyseworthychyisedypytystly2csyanegyedyyyyyhyyylyyyyy2kgyntmychyktpy\
+        kybygyetyueeryyyy2ydallyehtychyyy2yyyetytd2lyyieieyyyenndhdyryshyyysylyylyyyy2y2yyowyy22yybyemyt\
+        ty2meyzazzylyungyfymygyzyyorthyyylyyomishyereebykytygyyelypstylyystytymerytyeryckybbyxylkydykygl\
+        ypyttyomybbytyntyyyeryeereulyydylpypykyzykgyhyzyghderkhtkypneybhychyenlygygishhytykylrylyygeykyg\
+        yygfyhytykygylyypysychyzykylefbytytyybyfyyyyrmndyreyyidydyhern2myglyzydyfyvyshyndidgtytyutycykyb\
+        ymyehykyzychykygyfyglhydelynchfastmychynkdyfkygyidpyytblyfyiddyteryyimeinctenyyenryyyrbleeyyykgy\
+        ypyhbylfyytmyglyhepytyyyyyyryyyereyibleestytlyhernetyghtshyadygygryyylydoilylylyyyyyusyhhyueyid2\
+        hyhistyyyedypypyhyclylyyblydykleky2pytyhyyysygytykydlygyklytynnyeathlyyirtinlyntlealthylykelyvel\
+        yckyeasantettypelyinnyundeadyriftyrylcomerrtyyeytyyonyy2yearyyyyyryyythyyelyyyhtyd2ometypyeypyly\
+        zyeytd2leyyulyingometyhyyyerfulyyyyydyhglyklytyeernytyhyyyctsentueeentyonyyyy2nyreyyhyyyyyorthyl\
+        yhyyyyyerryyeeyy2yhyyyyyytyhylyeyuehittyyeyyyyyyenyylyyy2yzyryyegyyyhyyyyyyyaityyyyyyyyyyertable\
+        ct2eteyottyyyyyyyyylyhyyyylyhyeyyhyatehtfulyte2yyyhhyyhtyhyyssy2y2oingulleryyeckyyyyyyyyycyyryye\
+        ryardyyhyyyhyytydyyyyyeeomeyyhyeryyyyynyyyyyylyyysqueyhydyeyyhyylyyyyyyinulyhrobbyn2enly22eyeyyy\
+        2yy2ebleyorthyryybleyallowyreeulntyyyoomyliniorthyye2yyneredfulyyerseylyyyyyyyyaneyyngyyyzyyyyye\
+        dy2erryyyrnyyryyedyyckyxyohyygnypychymychyyblbyfymyyywyowyylyyyytzycyrydydkylpykyeyyyyhyyhyyyyya\
+        ppyytickyyhyyyyeryysyryeryyryyyyyyy2yhyheyyyyyyyyhyyyyyyylyyyyealy2hworthyyyyylyyhytygyhychygyte\
+        ryyyyyncylyeygydyyyyyhyyyellyyeworthyy2yhy2hyyyythyyyyyyyidyyglygpyngtworthygthysychyttgypyyrnly\
+        yleeymeyyyyng2hyyyyyyorthytylytybygyyyy2yyuilyyyyyyyyhyyltheendlylnysylyddpyyerlyemlyytleeyelyee\
+        dlyhyyyyyyyyyyyeomyulwyryyeyyyyearywhirstyyyulyyyyyorthyyyyemeyletntyysyyaitybypycyyyyyyyyhylyyy\
+        ytllyyasththterryyyyyghlyonomeeomessorthyorthyzyyye",
+    &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 6, 6, 6, 6, 6, 7, 7, 7, 7,
+        9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+        12, 12, 12, 12, 13, 13, 14, 18, 19, 19, 21, 21, 24, 26, 28, 28, 28, 28, 28, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 29, 29, 29, 32, 32, 32,
+        34, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 39, 42, 47, 47,
+        47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 48, 48, 48,
+        49, 52, 54, 54, 55, 55, 55, 55, 55, 55, 56, 56, 56, 56, 56, 56, 56, 56, 56, 60,
+        62, 62, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
+        65, 65, 65, 69, 69, 73, 73, 73, 73, 73, 73, 73, 73, 73, 74, 74, 76, 82, 82, 82,
+        86, 87, 90, 90, 90, 90, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 94, 97, 97, 97,
+        97, 97, 97, 97, 98, 107, 110, 111, 114, 114, 116, 116, 117, 119, 121, 123, 126, 129, 129, 129,
+        133, 137, 143, 145, 149, 153, 153, 153, 157, 158, 159, 160, 163, 164, 164, 165, 166, 169, 172, 173,
+        173, 177, 179, 183, 185, 187, 187, 194, 194, 196, 197, 197, 199, 199, 199, 199, 201, 203, 203, 203,
+        203, 208, 209, 213, 213, 213, 213, 215, 215, 219, 219, 219, 219, 219, 219, 222, 225, 225, 225, 225,
+        225, 227, 227, 227, 227, 227, 228, 230, 230, 230, 230, 230, 230, 233, 234, 234, 238, 238, 238, 238,
+        238, 238, 238, 240, 242, 245, 245, 245, 245, 246, 246, 248, 255, 255, 259, 263, 263, 263, 263, 263,
+        263, 263, 263, 263, 263, 263, 263, 263, 263, 263, 265, 265, 265, 265, 265, 265, 265, 265, 265, 265,
+        266, 266, 266, 266, 266, 266, 266, 266, 268, 271, 273, 275, 275, 275, 276, 279, 279, 279, 280, 281,
+        284, 284, 284, 285, 285, 285, 285, 287, 287, 287, 287, 290, 292, 294, 300, 303, 304, 304, 306, 308,
+        308, 308, 308, 310, 310, 310, 310, 312, 312, 313, 317, 317, 317, 319, 319, 319, 319, 320, 320, 320,
+        320, 320, 320, 320, 323, 323, 324, 325, 325, 325, 325, 325, 327, 330, 331, 331, 333, 333, 333, 334,
+        335, 335, 340, 340, 341, 342, 343, 343, 343, 345, 347, 352, 356, 356, 356, 358, 359, 359, 359, 359,
+        366, 368, 370, 370, 370, 370, 370, 376, 378, 378, 380, 380, 383, 383, 386, 388, 388, 391, 391, 394,
+        394, 394, 394, 396, 396, 396, 396, 398, 398, 400, 400, 400, 400, 400, 400, 400, 402, 404, 406, 407,
+        408, 408, 408, 408, 409, 410, 410, 410, 410, 410, 410, 412, 414, 415, 415, 415, 416, 416, 418, 418,
+        420, 422, 424, 427, 427, 430, 430, 435, 435, 435, 435, 435, 437, 439, 442, 443, 443, 448, 452, 453,
+        455, 456, 459, 459, 462, 462, 462, 462, 462, 462, 462, 464, 466, 468, 470, 472, 474, 475, 475, 476,
+        476, 476, 480, 481, 481, 481, 481, 481, 481, 481, 483, 486, 488, 488, 491, 491, 491, 494, 494, 494,
+        496, 496, 498, 498, 498, 498, 498, 500, 501, 501, 503, 505, 506, 506, 509, 511, 513, 514, 516, 516,
+        516, 518, 518, 518, 518, 518, 519, 521, 521, 524, 524, 525, 527, 529, 531, 531, 533, 533, 533, 536,
+        538, 538, 538, 539, 539, 539, 541, 544, 548, 548, 550, 555, 558, 559, 561, 564, 568, 570, 571, 573,
+        574, 575, 577, 579, 580, 580, 584, 587, 589, 593, 595, 597, 597, 600, 601, 602, 602, 606, 607, 607,
+        607, 610, 612, 615, 617, 619, 621, 621, 622, 623, 624, 625, 625, 627, 629, 631, 631, 633, 633, 634,
+        634, 636, 638, 641, 646, 646, 646, 647, 647, 648, 649, 649, 651, 653, 654, 654, 654, 654, 654, 657,
+        657, 657, 658, 658, 660, 660, 661, 663, 666, 667, 669, 669, 671, 672, 674, 674, 674, 674, 676, 679,
+        679, 679, 679, 681, 682, 684, 684, 684, 687, 687, 688, 688, 688, 688, 688, 688, 693, 695, 697, 697,
+        697, 698, 700, 701, 701, 704, 704, 704, 704, 704, 704, 706, 708, 709, 709, 709, 709, 711, 715, 717,
+        717, 719, 719, 719, 721, 721, 721, 721, 721, 723, 724, 725, 727, 727, 729, 731, 733, 733, 733, 733,
+        735, 735, 735, 736, 738, 738, 738, 738, 739, 739, 739, 741, 743, 745, 746, 748, 748, 751, 753, 753,
+        753, 756, 760, 760, 761, 761, 762, 769, 771, 771, 772, 772, 772, 772, 772, 773, 776, 777, 777, 777,
+        777, 778, 778, 778, 778, 778, 780, 781, 781, 781, 781, 782, 783, 785, 789, 789, 792, 793, 793, 793,
+        794, 796, 800, 801, 804, 805, 808, 809, 811, 811, 813, 814, 814, 814, 814, 814, 815, 815, 817, 819,
+        820, 823, 827, 827, 827, 830, 832, 832, 834, 835, 835, 835, 837, 838, 842, 843, 846, 848, 851, 853,
+        855, 858, 858, 860, 860, 860, 860, 860, 860, 860, 860, 863, 865, 865, 868, 869, 872, 872, 872, 873,
+        873, 873, 875, 875, 876, 876, 876, 876, 876, 876, 877, 877, 879, 881, 881, 883, 883, 883, 883, 883,
+        883, 883, 883, 885, 885, 885, 887, 887, 889, 889, 889, 891, 891, 891, 891, 891, 891, 896, 897, 897,
+        900, 902, 904, 907, 908, 911, 911, 913, 913, 913, 914, 914, 916, 918, 920, 923, 926, 928, 929, 931,
+        931, 931, 933, 934, 934, 934, 936, 939, 940, 940, 941, 943, 943, 943, 943, 943, 945, 947, 947, 948,
+        948, 950, 950, 950, 953, 953, 955, 957, 957, 959, 959, 959, 959, 959, 959, 959, 962, 965, 965, 965,
+        967, 968, 969, 971, 971, 971, 971, 971, 971, 975, 975, 975, 979, 982, 985, 985, 985, 986, 990, 990,
+        991, 991, 993, 996, 996, 996, 998, 1003, 1003, 1005, 1005, 1005, 1005, 1005, 1005, 1005, 1005, 1005, 1005, 1005,
+        1005, 1005, 1005, 1005, 1005, 1005, 1005, 1005, 1006, 1008, 1010, 1010, 1011, 1011, 1011, 1013, 1013, 1013, 1015, 1015,
+        1015, 1015, 1015, 1015, 1015, 1015, 1015, 1015, 1015, 1015, 1019, 1021, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1026,
+        1026, 1026, 1026, 1029, 1029, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1033, 1035, 1037, 1039, 1039, 1040, 1040,
+        1040, 1040, 1042, 1043, 1043, 1045, 1048, 1049, 1049, 1049, 1051, 1054, 1056, 1064, 1064, 1064, 1064, 1064, 1068, 1069,
+        1069, 1069, 1071, 1071, 1071, 1071, 1074, 1077, 1080, 1081, 1083, 1088, 1088, 1088, 1093, 1093, 1094, 1098, 1098, 1098,
+        1098, 1098, 1098, 1101, 1101, 1103, 1103, 1104, 1104, 1106, 1106, 1106, 1106, 1109, 1110, 1110, 1113, 1116, 1116, 1120,
+        1121, 1121, 1121, 1121, 1124, 1127, 1127, 1128, 1128, 1132, 1132, 1132, 1132, 1132, 1132, 1136, 1138, 1141, 1143, 1144,
+        1146, 1147, 1148, 1150, 1150, 1150, 1154, 1156, 1157, 1158, 1160, 1162, 1163, 1164, 1165, 1167, 1170, 1173, 1173, 1173,
+        1173, 1173, 1173, 1175, 1175, 1177, 1179, 1179, 1179, 1179, 1182, 1184, 1189, 1189, 1191, 1191, 1191, 1191, 1194, 1195,
+        1195, 1195, 1196, 1196, 1198, 1198, 1199, 1199, 1199, 1202, 1202, 1205, 1208, 1210, 1211, 1211, 1215, 1215, 1215, 1215,
+        1218, 1218, 1218, 1219, 1221, 1222, 1223, 1226, 1228, 1228, 1230, 1232, 1232, 1234, 1236, 1236, 1236, 1236, 1238, 1239,
+        1241, 1243, 1245, 1247, 1249, 1250, 1252, 1253, 1255, 1262, 1264, 1268, 1272, 1274, 1276, 1278, 1279, 1280, 1282, 1286,
+        1287, 1289, 1290, 1290, 1295, 1295, 1296, 1298, 1298, 1299, 1300, 1300, 1307, 1307, 1307, 1311, 1311, 1313, 1313, 1313,
+        1313, 1316, 1316, 1316, 1317, 1317, 1318, 1319, 1320, 1321, 1321, 1322, 1323, 1324, 1328, 1330, 1330, 1331, 1331, 1333,
+        1334, 1335, 1336, 1337, 1337, 1340, 1341, 1342, 1342, 1343, 1344, 1344, 1344, 1344, 1344, 1344, 1346, 1346, 1347, 1350,
+        1351, 1352, 1353, 1353, 1355, 1357, 1357, 1357, 1357, 1358, 1358, 1358, 1360, 1360, 1362, 1364, 1366, 1368, 1370, 1372,
+        1374, 1374, 1376, 1377, 1377, 1381, 1381, 1381, 1381, 1381, 1382, 1384, 1385, 1387, 1389, 1390, 1391, 1392, 1393, 1393,
+        1393, 1395, 1396, 1396, 1397, 1397, 1398, 1403, 1404, 1405, 1407, 1408, 1410, 1410, 1412, 1413, 1415, 1417, 1418, 1419,
+        1419, 1421, 1422, 1425, 1425, 1425, 1425, 1426, 1428, 1430, 1430, 1431, 1433, 1434, 1436, 1437, 1437, 1440, 1441, 1442,
+        1444, 1446, 1448, 1450, 1452, 1453, 1453, 1453, 1454, 1455, 1456, 1457, 1458, 1458, 1459, 1460, 1462, 1463, 1465, 1467,
+        1468, 1470, 1471, 1473, 1474, 1474, 1475, 1478, 1479, 1479, 1480, 1480, 1483, 1483, 1483, 1485, 1487, 1488, 1490, 1491,
+        1493, 1495, 1497, 1497, 1499, 1501, 1502, 1502, 1502, 1504, 1504, 1506, 1508, 1509, 1509, 1510, 1510, 1510, 1511, 1511,
+        1515, 1515, 1516, 1518, 1518, 1520, 1522, 1529, 1531, 1533, 1535, 1535, 1537, 1537, 1539, 1539, 1539, 1541, 1541, 1543,
+        1544, 1546, 1546, 1547, 1550, 1552, 1553, 1555, 1557, 1557, 1558, 1560, 1562, 1564, 1565, 1566, 1566, 1568, 1570, 1570,
+        1572, 1574, 1575, 1577, 1578, 1582, 1582, 1587, 1588, 1589, 1589, 1590, 1592, 1592, 1593, 1595, 1595, 1599, 1603, 1603,
+        1605, 1606, 1607, 1609, 1610, 1612, 1612, 1614, 1616, 1619, 1622, 1625, 1629, 1629, 1630, 1630, 1632, 1633, 1635, 1637,
+        1639, 1640, 1640, 1640, 1642, 1643, 1643, 1643, 1644, 1646, 1646, 1647, 1649, 1649, 1650, 1655, 1655, 1657, 1657, 1658,
+        1660, 1662, 1662, 1662, 1664, 1665, 1669, 1670, 1678, 1681, 1681, 1681, 1682, 1683, 1684, 1684, 1685, 1687, 1688, 1689,
+        1689, 1691, 1691, 1693, 1693, 1695, 1696, 1698, 1698, 1699, 1699, 1700, 1700, 1702, 1703, 1704, 1709, 1709, 1710, 1710,
+        1713, 1714, 1714, 1715, 1715, 1715, 1717, 1718, 1718, 1719, 1720, 1722, 1722, 1724, 1725, 1726, 1726, 1727, 1729, 1735,
+        1736, 1740, 1742, 1743, 1746, 1747, 1749, 1750, 1751, 1752, 1753, 1753, 1753, 1754, 1754, 1754, 1755, 1755, 1756, 1757,
+        1757, 1759, 1761, 1763, 1767, 1769, 1769, 1771, 1771, 1773, 1773, 1775, 1777, 1780, 1782, 1789, 1791, 1794, 1795, 1795,
+        1796, 1796, 1798, 1798, 1800, 1802, 1803, 1805, 1807, 1808, 1808, 1809, 1810, 1810, 1810, 1811, 1812, 1812, 1813, 1814,
+        1815, 1816, 1819, 1820, 1824, 1824, 1824, 1825, 1825, 1825, 1826, 1826, 1827, 1827, 1829, 1829, 1830, 1830, 1831, 1831,
+        1831, 1833, 1835, 1836, 1837, 1838, 1839, 1840, 1842, 1844, 1844, 1846, 1846, 1848, 1850, 1850, 1850, 1851, 1853, 1855,
+        1858, 1860, 1860, 1862, 1863, 1865, 1866, 1868, 1868, 1871, 1871, 1871, 1872, 1874, 1876, 1876, 1877, 1879, 1881, 1881,
+        1884, 1884, 1885, 1886, 1888, 1891, 1893, 1893, 1893, 1895, 1897, 1899, 1901, 1903, 1903, 1905, 1907, 1907, 1907, 1909,
+        1909, 1910, 1911, 1911, 1912, 1913, 1913, 1914, 1915, 1915, 1919, 1921, 1921, 1922, 1925, 1928, 1928, 1930, 1933, 1933,
+        1934, 1935, 1935, 1935, 1938, 1938, 1939, 1941, 1945, 1946, 1950, 1950, 1952, 1953, 1954, 1956, 1957, 1959, 1960, 1962,
+        1964, 1966, 1966, 1968, 1968, 1969, 1969, 1970, 1971, 1971, 1974, 1975, 1975, 1976, 1977, 1977, 1977, 1978, 1979, 1980,
+        1981, 1982, 1983, 1984, 1985, 1986, 1990, 1992, 1992, 1993, 1993, 1994, 1994, 1998, 1999, 1999, 2001, 2001, 2001, 2002,
+        2004, 2005, 2007, 2007, 2007, 2011, 2013, 2013, 2013, 2015, 2015, 2017, 2021, 2023, 2024, 2025, 2027, 2027, 2029, 2029,
+        2030, 2030, 2031, 2033, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2041, 2041, 2041, 2043, 2044, 2046, 2049, 2051, 2052,
+        2052, 2054, 2058, 2060, 2061, 2061, 2061, 2061, 2061, 2062, 2062, 2064, 2067, 2067, 2069, 2072, 2075, 2075, 2077, 2077,
+        2078, 2078, 2078, 2078, 2080, 2082, 2086, 2086, 2086, 2086, 2087, 2088, 2089, 2090, 2091, 2093, 2093, 2094, 2094, 2100,
+        2100, 2102, 2102, 2105, 2106, 2107, 2108, 2111, 2112, 2115, 2118, 2118, 2120, 2121, 2122, 2123, 2123, 2123, 2124, 2124,
+        2125, 2126, 2128, 2128, 2129, 2129, 2130, 2130, 2131, 2132, 2137, 2138, 2139, 2139, 2139, 2141, 2142, 2143, 2143, 2144,
+        2145, 2146, 2147, 2148, 2148, 2148, 2148, 2149, 2149, 2149, 2149, 2149, 2151, 2153, 2154, 2155, 2155, 2156, 2157, 2158,
+        2159, 2159, 2163, 2167, 2171, 2172, 2173, 2174, 2174, 2176, 2177, 2177, 2179, 2180, 2180, 2182, 2183, 2183, 2184, 2184,
+        2185, 2186, 2188, 2189, 2190, 2190, 2190, 2192, 2193, 2195, 2197, 2198, 2198, 2199, 2200, 2200, 2201, 2203, 2206, 2208,
+        2209, 2209, 2210, 2210, 2210, 2210, 2211, 2212, 2213, 2213, 2214, 2216, 2216, 2217, 2218, 2218, 2218, 2219, 2223, 2224,
+        2225, 2225, 2228, 2230, 2232, 2232, 2232, 2237, 2240, 2240, 2240, 2241, 2241, 2241, 2242, 2242, 2242, 2242, 2243, 2244,
+        2246, 2248, 2249, 2250, 2251, 2253, 2254, 2255, 2256, 2257, 2258, 2258, 2260, 2260, 2262, 2264, 2264, 2267, 2267, 2269,
+        2269, 2270, 2270, 2272, 2273, 2274, 2275, 2276, 2276, 2276, 2277, 2277, 2277, 2278, 2280, 2281, 2282, 2283, 2283, 2286,
+        2287, 2288, 2289, 2290, 2290, 2291, 2292, 2292, 2293, 2294, 2296, 2297, 2297, 2297, 2297, 2299, 2300, 2300, 2301, 2301,
+        2303, 2304, 2305, 2305, 2307, 2307, 2308, 2309, 2310, 2311, 2317, 2318, 2319, 2321, 2321, 2322, 2322, 2322, 2322, 2322,
+        2323, 2324, 2325, 2326, 2327, 2327, 2330, 2332, 2333, 2335, 2335, 2339, 2339, 2341, 2342, 2342, 2343, 2345, 2346, 2348,
+        2351, 2351, 2353, 2353, 2354, 2354, 2356, 2356, 2358, 2358, 2358, 2359, 2360, 2362, 2363, 2364, 2365, 2365, 2365, 2366,
+        2366, 2371, 2377, 2378, 2378, 2379, 2380, 2382, 2383, 2383, 2383, 2384, 2385, 2386, 2386, 2386, 2388, 2392, 2392, 2392,
+        2393, 2394, 2394, 2395, 2395, 2396, 2398, 2399, 2401, 2406, 2407, 2407, 2409, 2415, 2416, 2416, 2416, 2416, 2416, 2417,
+        2418, 2419, 2419, 2423, 2423, 2423, 2425, 2427, 2432, 2434, 2435, 2435, 2437, 2438, 2438, 2438, 2439, 2441, 2442, 2442,
+        2443, 2443, 2443, 2444, 2444, 2445, 2446, 2446, 2448, 2448, 2451, 2452, 2452, 2452, 2453, 2454, 2455, 2455, 2457, 2457,
+        2457, 2458, 2459, 2462, 2465, 2469, 2471, 2472, 2473, 2473, 2474, 2477, 2478, 2480, 2485, 2489, 2492, 2494, 2496, 2498,
+        2501, 2503, 2505, 2505, 2507, 2508, 2509, 2510, 2510, 2511, 2512, 2513, 2514, 2514, 2515, 2516, 2517, 2518, 2519, 2521,
+        2522, 2522, 2524, 2524, 2526, 2527, 2535, 2535, 2538, 2541, 2543, 2545, 2547, 2547, 2552, 2552, 2553, 2555, 2558, 2560,
+        2561, 2561, 2561, 2562, 2562, 2563, 2564, 2564, 2565, 2566, 2567, 2567, 2569, 2569, 2570, 2570, 2571, 2571, 2572, 2573,
+        2574, 2575, 2576, 2578, 2579, 2581, 2583, 2583, 2585, 2585, 2585, 2585, 2585, 2588, 2589, 2590, 2592, 2594, 2596, 2598,
+        2601, 2601, 2601, 2605, 2606, 2606, 2607, 2608, 2608, 2610, 2611, 2612, 2616, 2616, 2616, 2617, 2617, 2620, 2623, 2624,
+        2624, 2625, 2626, 2627, 2627, 2628, 2628, 2629, 2629, 2629, 2631, 2631, 2632, 2633, 2634, 2635, 2637, 2638, 2638, 2640,
+        2642, 2643, 2644, 2644, 2645, 2645, 2647, 2649, 2650, 2651, 2652, 2654, 2656, 2657, 2658, 2658, 2659, 2661, 2661, 2663,
+        2664, 2666, 2667, 2668, 2670, 2672, 2672, 2673, 2673, 2674, 2674, 2676, 2677, 2678, 2679, 2680, 2681, 2681, 2682, 2684,
+        2685, 2685, 2687, 2688, 2688, 2691, 2694, 2695, 2695, 2695, 2699, 2699, 2701, 2701, 2701, 2701, 2705, 2705, 2707, 2709,
+        2711, 2711, 2713, 2714, 2714, 2719, 2720, 2721, 2723, 2724, 2726, 2729, 2730, 2730, 2730, 2731, 2733, 2734, 2736, 2738,
+        2738, 2740, 2742, 2743, 2743, 2743, 2746, 2747, 2747, 2750, 2752, 2753, 2756, 2758, 2762, 2762, 2762, 2764, 2764, 2767,
+        2770, 2773, 2773, 2775, 2776, 2778, 2780, 2782, 2785, 2787, 2788, 2790, 2790, 2790, 2790, 2793, 2796, 2797, 2798, 2800,
+        2801, 2802, 2803, 2806, 2807, 2807, 2810, 2812, 2813, 2814, 2814, 2814, 2814, 2816, 2817, 2819, 2819, 2821, 2823, 2825,
+        2825, 2826, 2828, 2828, 2828, 2830, 2830, 2832, 2832, 2834, 2837, 2838, 2838, 2840, 2840, 2842, 2843, 2843, 2846, 2847,
+        2847, 2849, 2852, 2856, 2856, 2858, 2862, 2862, 2862, 2864, 2866, 2868, 2869, 2869, 2871, 2873, 2874, 2875, 2877, 2879,
+        2881, 2882, 2882, 2882, 2883, 2883, 2885, 2887, 2889, 2891, 2893, 2895, 2896, 2898, 2898, 2900, 2903, 2905, 2907, 2907,
+        2908, 2909, 2910, 2910, 2910, 2912, 2912, 2912, 2914, 2916, 2917, 2919, 2921, 2921, 2922, 2923, 2924, 2925, 2927, 2928,
+        2929, 2930, 2931, 2931, 2932, 2933, 2933, 2933, 2935, 2935, 2936, 2937, 2938, 2938, 2943, 2943, 2945, 2948, 2948, 2950,
+        2950, 2950, 2952, 2952, 2952, 2954, 2954, 2954, 2956, 2959, 2963, 2963, 2963, 2963, 2964, 2964, 2966, 2968, 2968, 2968,
+        2971, 2972, 2972, 2973, 2975, 2977, 2977, 2979, 2980, 2982, 2982, 2984, 2984, 2986, 2989, 2991, 2993, 2995, 2997, 2997,
+        2997, 2999, 2999, 2999, 3000, 3000, 3000, 3000, 3003, 3006, 3006, 3006, 3010, 3010, 3012, 3015, 3016, 3017, 3019, 3020,
+        3020, 3020, 3022, 3024, 3026, 3028, 3029, 3029, 3029, 3030, 3030, 3030, 3030, 3030, 3031, 3033, 3035, 3035, 3037, 3039,
+        3043, 3044, 3047, 3047, 3051, 3051, 3053, 3054, 3055, 3057, 3059, 3060, 3061, 3063, 3065, 3066, 3066, 3067, 3068, 3068,
+        3069, 3070, 3072, 3073, 3075, 3075, 3075, 3076, 3076, 3078, 3078, 3079, 3081, 3082, 3083, 3085, 3086, 3088, 3089, 3089,
+        3090, 3092, 3094, 3094, 3094, 3094, 3095, 3096, 3097, 3097, 3098, 3099, 3101, 3102, 3103, 3103, 3103, 3105, 3106, 3107,
+        3111, 3112, 3112, 3112, 3115, 3118, 3121, 3122, 3123, 3123, 3125, 3128, 3131, 3134, 3134, 3134, 3136, 3136, 3139, 3140,
+        3140, 3141, 3141, 3141, 3141, 3143, 3144, 3144, 3148, 3150, 3152, 3153, 3153, 3154, 3155, 3156, 3157, 3157, 3157, 3159,
+        3160, 3162, 3165, 3167, 3168, 3168, 3170, 3171, 3175, 3176, 3177, 3178, 3180, 3182, 3182, 3184, 3186, 3189, 3191, 3192,
+        3195, 3197, 3198, 3198, 3199, 3199, 3199, 3200, 3202, 3203, 3205, 3207, 3209, 3209, 3210, 3211, 3213, 3215, 3217, 3219,
+        3219, 3222, 3224, 3225, 3227, 3229, 3229, 3232, 3234, 3234, 3238, 3239, 3241, 3242, 3242, 3242, 3246, 3250, 3250, 3250,
+        3255, 3257, 3261, 3265, 3268, 3268, 3268, 3274, 3278, 3280, 3282, 3282, 3282, 3286, 3289, 3293, 3293, 3298, 3298, 3300,
+        3305, 3305, 3306, 3307, 3309, 3309, 3309, 3310, 3310, 3311, 3311, 3312, 3312, 3313, 3313, 3313, 3313, 3313, 3314, 3314,
+        3315, 3317, 3318, 3318, 3320, 3321, 3325, 3325, 3326, 3327, 3328, 3329, 3331, 3332, 3333, 3333, 3333, 3336, 3337, 3339,
+        3340, 3341, 3341, 3342, 3345, 3347, 3350, 3350, 3350, 3352, 3352, 3354, 3356, 3356, 3356, 3356, 3358, 3360, 3360, 3362,
+        3362, 3364, 3365, 3367, 3369, 3369, 3369, 3370, 3371, 3371, 3371, 3373, 3373, 3373, 3374, 3377, 3380, 3380, 3380, 3380,
+        3381, 3382, 3384, 3385, 3385, 3385, 3386, 3386, 3386, 3391, 3392, 3392, 3393, 3393, 3394, 3395, 3396, 3397, 3398, 3399,
+        3402, 3405, 3407, 3408, 3408, 3408, 3410, 3410, 3410, 3410, 3412, 3414, 3416, 3417, 3417, 3418, 3420, 3422, 3424, 3426,
+        3427, 3427, 3430, 3431, 3434, 3435, 3435, 3436, 3436, 3436, 3437, 3438, 3439, 3440, 3440, 3441, 3442, 3442, 3442, 3442,
+        3442, 3442, 3443, 3444, 3445, 3445, 3446, 3447, 3448, 3448, 3449, 3450, 3455, 3455, 3455, 3457, 3459, 3460, 3461, 3462,
+        3462, 3462, 3463, 3467, 3468, 3468, 3469, 3469, 3470, 3470, 3471, 3472, 3472, 3473, 3474, 3476, 3477, 3477, 3478, 3478,
+        3479, 3480, 3481, 3483, 3484, 3485, 3485, 3485, 3487, 3488, 3488, 3489, 3489, 3491, 3496, 3496, 3496, 3497, 3497, 3498,
+        3498, 3498, 3499, 3500, 3501, 3501, 3502, 3503, 3504, 3507, 3508, 3510, 3511, 3511, 3513, 3514, 3516, 3517, 3517, 3517,
+        3518, 3519, 3519, 3519, 3520, 3521, 3522, 3523, 3524, 3525, 3526, 3527, 3528, 3529, 3530, 3531, 3532, 3532, 3532, 3536,
+        3536, 3537, 3538, 3539, 3539, 3540, 3540, 3541, 3542, 3543, 3544, 3545, 3546, 3546, 3552, 3552, 3555, 3558, 3559, 3563,
+        3563, 3563, 3564, 3565, 3565, 3566, 3566, 3567, 3568, 3569, 3570, 3571, 3571, 3573, 3573, 3573, 3573, 3575, 3576, 3577,
+        3577, 3577, 3578, 3580, 3582, 3582, 3582, 3582, 3583, 3583, 3584, 3585, 3585, 3585, 3587, 3590, 3595, 3596, 3596, 3597,
+        3599, 3599, 3599, 3599, 3599, 3600, 3600, 3601, 3601, 3602, 3602, 3603, 3604, 3604, 3604, 3605, 3606, 3609, 3611, 3612,
+        3612, 3612, 3614, 3615, 3615, 3615, 3616, 3618, 3618, 3622, 3622, 3625, 3625, 3628, 3629, 3630, 3630, 3633, 3633, 3634,
+        3634, 3635, 3635, 3635, 3636, 3636, 3637, 3638, 3639, 3640, 3641, 3643, 3644, 3646, 3646, 3647, 3647, 3647, 3650, 3650,
+        3654, 3655, 3655, 3657, 3658, 3659, 3660, 3661, 3662, 3662, 3664, 3665, 3665, 3665, 3666, 3666, 3667, 3667, 3668, 3669,
+        3669, 3669, 3669, 3670, 3670, 3671, 3671, 3672, 3672, 3675, 3676, 3677, 3679, 3682, 3683, 3684, 3685, 3686, 3686, 3687,
+        3687, 3687, 3688, 3688, 3689, 3689, 3690, 3690, 3690, 3691, 3691, 3691, 3692, 3693, 3693, 3693, 3695, 3695, 3695, 3696,
+        3697, 3701, 3702, 3702, 3704, 3706, 3707, 3708, 3709, 3711, 3711, 3712, 3714, 3715, 3716, 3717, 3718, 3719, 3719, 3719,
+        3719, 3719, 3721, 3721, 3723, 3723, 3724, 3730, 3730, 3732, 3732, 3733, 3733, 3737, 3738, 3739, 3739, 3740, 3741, 3741,
+        3741, 3741, 3741, 3742, 3742, 3743, 3743, 3744, 3745, 3746, 3746, 3747, 3747, 3748, 3749, 3752, 3753, 3758, 3759, 3759,
+        3760, 3760, 3761, 3761, 3764, 3765, 3765, 3771, 3771, 3771, 3772, 3773, 3774, 3774, 3774, 3774, 3776, 3776, 3777, 3778,
+        3779, 3780, 3781, 3781, 3785, 3785, 3789, 3789, 3794, 3794, 3795, 3795, 3796, 3797, 3798, 3798, 3799, 3799, 3801, 3803,
+        3803, 3803, 3804, 3804, 3807, 3808, 3809, 3809, 3809, 3810, 3810, 3813, 3813, 3813, 3813, 3813, 3814, 3814, 3814, 3816,
+        3816, 3816, 3817, 3817, 3817, 3818, 3818, 3819, 3819, 3820, 3820, 3821, 3822, 3823, 3823, 3823, 3823, 3823, 3823, 3826,
+        3827, 3828, 3828, 3828, 3831, 3832, 3832, 3832, 3832, 3832, 3833, 3833, 3833, 3835, 3836, 3837, 3838, 3838, 3838, 3839,
+        3839, 3840, 3842, 3843, 3847, 3847, 3848, 3848, 3848, 3849, 3849, 3851, 3852, 3852, 3852, 3852, 3853, 3855, 3856, 3856,
+        3856, 3857, 3858, 3859, 3859, 3859, 3860, 3863, 3863, 3863, 3865, 3866, 3868, 3869, 3870, 3872, 3874, 3877, 3877, 3879,
+        3882, 3883, 3885, 3885, 3887, 3889, 3891, 3891, 3892, 3893, 3895, 3895, 3897, 3897, 3898, 3898, 3898, 3899, 3899, 3901,
+        3901, 3902, 3903, 3903, 3904, 3904, 3907, 3909, 3909, 3911, 3911, 3913, 3914, 3916, 3917, 3919, 3921, 3922, 3922, 3923,
+        3924, 3925, 3926, 3928, 3929, 3931, 3932, 3932, 3933, 3934, 3935, 3939, 3940, 3945, 3946, 3946, 3948, 3948, 3949, 3950,
+        3951, 3954, 3954, 3955, 3957, 3957, 3959, 3959, 3962, 3963, 3965, 3965, 3966, 3966, 3967, 3968, 3969, 3970, 3971, 3972,
+        3973, 3973, 3975, 3976, 3977, 3978, 3978, 3979, 3980, 3981, 3982, 3983, 3983, 3984, 3985, 3985, 3987, 3988, 3989, 3990,
+        3991, 3992, 3993, 3993, 3995, 3996, 3996, 3996, 3996, 3997, 3998, 3998, 3998, 3998, 3999, 4001, 4003, 4010, 4011, 4012,
+        4013, 4013, 4013, 4014, 4014, 4016, 4017, 4017, 4019, 4021, 4023, 4025, 4028, 4030, 4034, 4035, 4035, 4036, 4037, 4037,
+        4038, 4041, 4043, 4045, 4045, 4047, 4047, 4047, 4047, 4048, 4049, 4050, 4051, 4052, 4053, 4053, 4055, 4056, 4057, 4061,
+        4062, 4063, 4069, 4070, 4070, 4071, 4072, 4073, 4075, 4077, 4078, 4078, 4079, 4079, 4079, 4080, 4083, 4084, 4085, 4085,
+        4086, 4086, 4086, 4086, 4087, 4088, 4089, 4091, 4091, 4092, 4093, 4096, 4096, 4097, 4099, 4101, 4101, 4101, 4108, 4112,
+        4114, 4117, 4118, 4119, 4121, 4121, 4121, 4123, 4124, 4126, 4126, 4128, 4129, 4131, 4132, 4133, 4133, 4134, 4135, 4135,
+        4136, 4137, 4137, 4137, 4138, 4139, 4139, 4142, 4142, 4142, 4144, 4144, 4145, 4145, 4146, 4146, 4146, 4147, 4147, 4147,
+        4147, 4148, 4149, 4149, 4154, 4154, 4156, 4158, 4160, 4162, 4163, 4164, 4165, 4166, 4166, 4168, 4168, 4169, 4169, 4170,
+        4170, 4173, 4174, 4175, 4175, 4176, 4177, 4177, 4178, 4179, 4180, 4181, 4181, 4183, 4184, 4184, 4184, 4184, 4185, 4187,
+        4188, 4193, 4194, 4196, 4198, 4200, 4201, 4202, 4204, 4205, 4209, 4209, 4210, 4213, 4214, 4217, 4218, 4219, 4222, 4223,
+        4224, 4227, 4229, 4229, 4230, 4230, 4230, 4231, 4231, 4232, 4232, 4233, 4233, 4233, 4234, 4235, 4236, 4237, 4238, 4239,
+        4239, 4239, 4239, 4239, 4240, 4242, 4243, 4243, 4245, 4247, 4247, 4249, 4250, 4251, 4251, 4252, 4252, 4253, 4253, 4254,
+        4254, 4255, 4259, 4259, 4259, 4260, 4260, 4260, 4260, 4266, 4266, 4267, 4267, 4268, 4268, 4270, 4271, 4271, 4272, 4272,
+        4272, 4272, 4272, 4273, 4273, 4274, 4274, 4274, 4275, 4275, 4275, 4280, 4281, 4281, 4281, 4281, 4281, 4281, 4282, 4282,
+        4283, 4283, 4283, 4283, 4283, 4284, 4284, 4284, 4284, 4286, 4286, 4287, 4287, 4289, 4289, 4290, 4292, 4292, 4293, 4293,
+        4294, 4296, 4297, 4297, 4297, 4301, 4303, 4305, 4305, 4307, 4308, 4309, 4310, 4310, 4311, 4312, 4313, 4313, 4314, 4314,
+        4316, 4316, 4318, 4318, 4318, 4318, 4318, 4318, 4318, 4318, 4319, 4319, 4320, 4320, 4321, 4323, 4325, 4326, 4329, 4329,
+        4329, 4329, 4331, 4333, 4333, 4333, 4337, 4337, 4338, 4339, 4340, 4340, 4340, 4340, 4340, 4341, 4341, 4343, 4345, 4347,
+        4350, 4350, 4351, 4351, 4351, 4351, 4354, 4354, 4356, 4356, 4356, 4361, 4366, 4366, 4368, 4369, 4370, 4370, 4371, 4371,
+    ],
+    &[
+        1, 26, 41, 48, 56, 64, 75, 82, 91, 98, 106, 111, 119, 125, 131, 137, 150, 159, 162, 168,
+        185, 194, 202, 206, 214, 219, 224, 229, 234, 237, 237, 237, 239, 241, 244, 248, 248, 254, 257, 261,
+        261, 265, 278, 284, 289, 294, 307, 312, 324, 333, 335, 341, 341, 346, 362, 368, 375, 385, 396, 407,
+        417, 423, 431, 433, 435, 440, 441, 441, 443, 447, 447, 449, 449, 449, 449, 452, 468, 478, 489, 495,
+        505, 510, 520, 531, 537, 540, 545, 550, 553, 564, 569, 576, 588, 596, 601, 612, 620, 620, 622, 625,
+        625, 627, 627, 629, 636, 638, 641, 648, 653, 658, 663, 668, 672, 678, 682, 686, 688, 688, 688, 690,
+        702, 711, 721, 733, 741, 743, 753, 761, 771, 782, 791, 793, 802, 812, 818, 826, 830, 830, 832, 836,
+        836, 838, 838, 840, 840, 843, 845, 847, 850, 852, 854, 863, 872, 876, 885, 890, 904, 908, 918, 918,
+        918, 922, 922, 935, 942, 944, 954, 967, 976, 989, 994, 1003, 1015, 1022, 1029, 1035, 1040, 1045, 1059, 1066,
+        1072, 1078, 1089, 1089, 1093, 1093, 1104, 1112, 1119, 1127, 1139, 1144, 1149, 1153, 1155, 1155, 1155, 1155, 1173, 1175,
+        1175, 1177, 1177, 1181, 1184, 1191, 1191, 1203, 1213, 1217, 1228, 1239, 1242, 1244, 1246, 1254, 1257, 1257, 1261, 1264,
+        1268, 1270, 1272, 1275, 1277, 1277, 1277, 1277, 1277, 1281, 1281, 1283, 1283, 1283, 1283, 1283, 1283, 1283, 1283, 1285,
+        1285, 1285, 1285, 1285, 1285, 1285, 1287, 1290, 1290, 1292, 1292, 1292, 1292, 1292, 1294, 1294, 1294, 1294, 1294, 1294,
+        1296, 1296, 1296, 1296, 1296, 1296, 1298, 1298, 1301, 1301, 1302, 1306, 1306, 1309, 1315, 1318, 1318, 1318, 1318, 1326,
+        1330, 1330, 1332, 1332, 1337, 1337, 1339, 1339, 1343, 1343, 1349, 1351, 1357, 1368, 1374, 1374, 1374, 1375, 1377, 1380,
+        1388, 1388, 1390, 1393, 1396, 1398, 1398, 1398, 1409, 1411, 1417, 1424, 1426, 1426, 1427, 1430, 1430, 1433, 1435, 1437,
+        1441, 1445, 1447, 1447, 1447, 1447, 1449, 1451, 1453, 1454, 1457, 1457, 1457, 1459, 1459, 1459, 1466, 1473, 1479, 1486,
+        1488, 1493, 1501, 1505, 1510, 1516, 1520, 1523, 1525, 1527, 1527, 1529, 1531, 1536, 1538, 1541, 1543, 1546, 1551, 1553,
+        1553, 1553, 1555, 1565, 1568, 1571, 1577, 1584, 1584, 1584, 1584, 1584, 1592, 1594, 1595, 1595, 1598, 1600, 1602, 1602,
+        1602, 1604, 1608, 1610, 1614, 1616, 1620, 1620, 1623, 1624, 1626, 1626, 1626, 1626, 1626, 1626, 1626, 1629, 1629, 1629,
+        1631, 1633, 1635, 1635, 1637, 1641, 1643, 1643, 1645, 1647, 1647, 1650, 1652, 1652, 1654, 1657, 1659, 1659, 1665, 1672,
+        1677, 1684, 1689, 1690, 1690, 1692, 1692, 1692, 1694, 1696, 1698, 1700, 1700, 1700, 1700, 1700, 1700, 1703, 1705, 1706,
+        1706, 1706, 1706, 1706, 1706, 1706, 1706, 1708, 1708, 1708, 1708, 1708, 1708, 1711, 1713, 1713, 1715, 1718, 1720, 1722,
+        1722, 1722, 1722, 1724, 1726, 1727, 1729, 1729, 1729, 1732, 1732, 1734, 1734, 1736, 1736, 1736, 1738, 1738, 1738, 1738,
+        1740, 1743, 1745, 1745, 1747, 1750, 1752, 1752, 1752, 1752, 1760, 1763, 1770, 1778, 1783, 1783, 1783, 1783, 1783, 1786,
+        1786, 1789, 1795, 1797, 1797, 1797, 1802, 1809, 1816, 1822, 1824, 1824, 1824, 1826, 1828, 1830, 1830, 1832, 1832, 1834,
+        1834, 1834, 1834, 1834, 1836, 1836, 1838, 1838, 1840, 1843, 1845, 1845, 1845, 1845, 1846, 1848, 1848, 1848, 1848, 1848,
+        1850, 1852, 1852, 1854, 1854, 1856, 1861, 1863, 1868, 1874, 1878, 1878, 1878, 1878, 1878, 1878, 1878, 1878, 1880, 1880,
+        1888, 1891, 1891, 1893, 1895, 1905, 1908, 1915, 1923, 1929, 1929, 1929, 1929, 1931, 1931, 1933, 1935, 1935, 1937, 1939,
+        1939, 1941, 1941, 1944, 1946, 1948, 1950, 1950, 1950, 1957, 1957, 1957, 1959, 1961, 1961, 1961, 1961, 1961, 1961, 1964,
+        1965, 1965, 1968, 1970, 1973, 1976, 1978, 1978, 1982, 1982, 1986, 1986, 1988, 1988, 1988, 1991, 1991, 1995, 1997, 1997,
+        1997, 1997, 1999, 1999, 2001, 2001, 2001, 2001, 2001, 2004, 2004, 2006, 2006, 2006, 2006, 2006, 2006, 2006, 2006, 2006,
+        2006, 2006, 2006, 2006, 2008, 2010, 2010, 2010, 2010, 2010, 2010, 2010, 2012, 2012, 2014, 2014, 2016, 2016, 2017, 2019,
+        2021, 2021, 2021, 2021, 2021, 2021, 2021, 2023, 2023, 2023, 2023, 2023, 2023, 2025, 2025, 2025, 2028, 2028, 2030, 2032,
+        2035, 2035, 2035, 2035, 2035, 2037, 2039, 2039, 2039, 2039, 2039, 2041, 2041, 2041, 2041, 2043, 2045, 2048, 2050, 2050,
+        2052, 2052, 2052, 2058, 2058, 2062, 2063, 2063, 2063, 2064, 2064, 2066, 2066, 2066, 2067, 2069, 2073, 2075, 2075, 2076,
+        2080, 2082, 2084, 2084, 2084, 2084, 2086, 2089, 2089, 2091, 2091, 2096, 2099, 2102, 2104, 2106, 2106, 2106, 2106, 2108,
+        2110, 2110, 2110, 2110, 2110, 2110, 2113, 2118, 2122, 2124, 2126, 2126, 2126, 2126, 2131, 2134, 2136, 2136, 2136, 2136,
+        2139, 2139, 2140, 2142, 2143, 2147, 2148, 2153, 2156, 2156, 2156, 2156, 2156, 2158, 2159, 2159, 2159, 2162, 2165, 2167,
+        2167, 2169, 2173, 2175, 2175, 2176, 2178, 2180, 2181, 2186, 2188, 2188, 2188, 2188, 2188, 2188, 2191, 2191, 2191, 2193,
+        2195, 2195, 2195, 2198, 2200, 2202, 2204, 2204, 2204, 2206, 2206, 2208, 2209, 2211, 2213, 2213, 2213, 2215, 2218, 2220,
+        2222, 2224, 2227, 2229, 2231, 2233, 2233, 2235, 2237, 2239, 2241, 2241, 2241, 2241, 2241, 2243, 2243, 2243, 2243, 2243,
+        2243, 2243, 2243, 2243, 2243, 2243, 2243, 2243, 2245, 2247, 2247, 2247, 2249, 2249, 2249, 2251, 2253, 2257, 2257, 2257,
+        2259, 2259, 2259, 2261, 2265, 2265, 2265, 2267, 2267, 2269, 2273, 2275, 2275, 2275, 2275, 2277, 2277, 2277, 2277, 2277,
+        2277, 2277, 2280, 2280, 2283, 2285, 2291, 2295, 2297, 2301, 2305, 2305, 2305, 2307, 2307, 2307, 2307, 2309, 2314, 2316,
+        2319, 2321, 2321, 2325, 2325, 2327, 2332, 2337, 2344, 2346, 2346, 2349, 2349, 2349, 2351, 2351, 2355, 2361, 2363, 2370,
+        2373, 2377, 2379, 2379, 2381, 2383, 2383, 2385, 2385, 2389, 2391, 2391, 2394, 2396, 2398, 2398, 2402, 2402, 2402, 2406,
+        2406, 2406, 2406, 2406, 2408, 2408, 2410, 2410, 2412, 2414, 2414, 2416, 2416, 2416, 2416, 2416, 2416, 2416, 2418, 2418,
+        2423, 2425, 2425, 2425, 2427, 2430, 2430, 2430, 2433, 2435, 2436, 2436, 2438, 2441, 2443, 2445, 2445, 2445, 2445, 2445,
+        2448, 2450, 2452, 2454, 2454, 2456, 2456, 2456, 2458, 2458, 2465, 2470, 2472, 2477, 2482, 2485, 2485, 2485, 2489, 2493,
+        2493, 2495, 2497, 2497, 2507, 2511, 2519, 2521, 2523, 2523, 2532, 2536, 2536, 2536, 2536, 2537, 2537, 2538, 2538, 2543,
+        2545, 2549, 2549, 2549, 2552, 2557, 2557, 2557, 2559, 2559, 2566, 2570, 2577, 2584, 2590, 2590, 2593, 2596, 2599, 2603,
+        2608, 2617, 2620, 2624, 2630, 2633, 2635, 2637, 2637, 2637, 2637, 2640, 2642, 2644, 2647, 2647, 2650, 2654, 2654, 2654,
+        2659, 2663, 2668, 2673, 2677, 2682, 2684, 2691, 2694, 2701, 2701, 2701, 2701, 2710, 2716, 2721, 2729, 2733, 2740, 2740,
+        2743, 2745, 2747, 2747, 2750, 2750, 2752, 2755, 2758, 2760, 2767, 2772, 2779, 2782, 2782, 2782, 2782, 2784, 2784, 2786,
+        2789, 2791, 2791, 2791, 2793, 2793, 2793, 2796, 2798, 2801, 2801, 2801, 2801, 2801, 2806, 2811, 2815, 2818, 2818, 2820,
+        2822, 2824, 2824, 2826, 2832, 2834, 2834, 2834, 2834, 2834, 2834, 2834, 2838, 2840, 2840, 2844, 2845, 2845, 2848, 2850,
+        2856, 2861, 2867, 2867, 2872, 2872, 2874, 2874, 2876, 2876, 2878, 2880, 2886, 2886, 2886, 2886, 2886, 2886, 2889, 2889,
+        2891, 2895, 2898, 2901, 2901, 2901, 2904, 2906, 2908, 2908, 2910, 2916, 2918, 2922, 2925, 2925, 2925, 2925, 2925, 2925,
+        2925, 2926, 2928, 2928, 2930, 2932, 2932, 2932, 2932, 2932, 2934, 2934, 2935, 2937, 2939, 2939, 2939, 2939, 2941, 2947,
+        2951, 2953, 2955, 2955, 2959, 2959, 2959, 2963, 2965, 2967, 2967, 2967, 2967, 2967, 2968, 2972, 2979, 2982, 2982, 2983,
+        2985, 2987, 2989, 2993, 2993, 2998, 2998, 3001, 3004, 3004, 3006, 3006, 3006, 3006, 3006, 3008, 3008, 3010, 3014, 3019,
+        3019, 3022, 3022, 3024, 3024, 3024, 3024, 3024, 3024, 3026, 3026, 3026, 3029, 3029, 3029, 3031, 3033, 3035, 3035, 3035,
+        3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035,
+        3037, 3037, 3037, 3037, 3037, 3037, 3037, 3037, 3039, 3039, 3039, 3039, 3039, 3041, 3041, 3041, 3043, 3043, 3043, 3043,
+        3043, 3043, 3043, 3043, 3043, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3045, 3046, 3046,
+        3046, 3046, 3046, 3046, 3048, 3048, 3048, 3048, 3048, 3048, 3048, 3049, 3049, 3049, 3051, 3051, 3051, 3051, 3051, 3051,
+        3054, 3054, 3054, 3057, 3057, 3057, 3059, 3061, 3063, 3063, 3066, 3068, 3068, 3070, 3070, 3070, 3070, 3070, 3070, 3070,
+        3070, 3072, 3072, 3073, 3075, 3075, 3077, 3079, 3082, 3085, 3085, 3085, 3085, 3085, 3085, 3085, 3085, 3087, 3087, 3089,
+        3089, 3089, 3089, 3092, 3092, 3092, 3092, 3092, 3092, 3092, 3092, 3092, 3092, 3093, 3093, 3093, 3093, 3093, 3093, 3093,
+        3094, 3094, 3094, 3094, 3096, 3098, 3100, 3100, 3100, 3100, 3104, 3106, 3106, 3106, 3106, 3106, 3108, 3108, 3108, 3108,
+        3108, 3108, 3108, 3108, 3108, 3110, 3113, 3113, 3113, 3113, 3113, 3113, 3113, 3115, 3115, 3115, 3115, 3115, 3115, 3115,
+        3115, 3115, 3115, 3115, 3115, 3115, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116, 3116,
+        3116, 3116, 3116, 3118, 3118, 3118, 3120, 3122, 3126, 3126, 3128, 3128, 3128, 3128, 3129, 3130, 3132, 3136, 3138, 3140,
+        3142, 3144, 3144, 3144, 3146, 3146, 3146, 3146, 3146, 3146, 3146, 3148, 3148, 3151, 3151, 3152, 3154, 3154, 3156, 3156,
+        3156, 3156, 3158, 3158, 3158, 3158, 3160, 3160, 3160, 3162, 3164, 3164, 3164, 3164, 3164, 3166, 3168, 3168, 3168, 3170,
+        3170, 3170, 3170, 3170, 3170, 3170, 3173, 3173, 3173, 3173, 3173, 3173, 3173, 3175, 3175, 3175, 3177, 3177, 3177, 3177,
+        3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3177, 3180, 3180, 3180,
+        3180, 3181, 3184, 3186, 3186, 3186, 3189, 3191, 3191, 3191, 3193, 3194, 3194, 3196, 3196, 3196, 3198, 3198, 3200, 3200,
+        3200, 3200, 3203, 3205, 3205, 3205, 3205, 3205, 3205, 3205, 3207, 3207, 3207, 3207, 3207, 3209, 3209, 3209, 3209, 3209,
+        3209, 3209, 3209, 3209, 3209, 3209, 3209, 3209, 3209, 3209, 3209, 3209, 3210, 3210, 3210, 3210, 3210, 3211, 3211, 3214,
+        3214, 3214, 3214, 3214, 3214, 3216, 3216, 3216, 3218, 3218, 3218, 3218, 3218, 3218, 3218, 3218, 3218, 3218, 3220, 3220,
+        3220, 3220, 3220, 3220, 3220, 3220, 3221, 3221, 3221, 3221, 3221, 3222, 3222, 3222, 3222, 3224, 3226, 3226, 3226, 3226,
+        3227, 3227, 3227, 3227, 3227, 3227, 3230, 3230, 3230, 3230, 3232, 3232, 3232, 3232, 3232, 3232, 3232, 3232, 3232, 3234,
+        3234, 3236, 3236, 3238, 3238, 3238, 3240, 3240, 3240, 3240, 3240, 3240, 3240, 3242, 3244, 3244, 3244, 3245, 3245, 3246,
+        3246, 3246, 3246, 3248, 3248, 3249, 3249, 3249, 3249, 3249, 3249, 3249, 3249, 3249, 3249, 3249, 3250, 3250, 3252, 3252,
+        3252, 3252, 3252, 3252, 3252, 3252, 3252, 3252, 3252, 3252, 3252, 3252, 3254, 3254, 3254, 3254, 3254, 3254, 3254, 3254,
+        3254, 3254, 3256, 3256, 3256, 3256, 3256, 3256, 3256, 3256, 3258, 3258, 3258, 3258, 3258, 3260, 3260, 3260, 3260, 3260,
+        3260, 3263, 3265, 3265, 3265, 3265, 3265, 3267, 3268, 3268, 3268, 3268, 3268, 3268, 3270, 3270, 3270, 3272, 3274, 3274,
+        3276, 3276, 3277, 3279, 3279, 3279, 3279, 3279, 3279, 3280, 3280, 3280, 3280, 3280, 3280, 3280, 3280, 3280, 3280, 3282,
+        3282, 3282, 3284, 3286, 3286, 3288, 3288, 3289, 3289, 3289, 3289, 3291, 3291, 3291, 3293, 3293, 3295, 3295, 3295, 3295,
+        3295, 3295, 3298, 3298, 3299, 3299, 3299, 3299, 3299, 3299, 3299, 3299, 3301, 3301, 3301, 3304, 3306, 3306, 3306, 3306,
+        3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3306, 3308, 3308, 3309, 3309, 3309,
+        3309, 3309, 3309, 3309, 3309, 3309, 3309, 3311, 3314, 3314, 3316, 3316, 3316, 3316, 3316, 3316, 3316, 3317, 3317, 3320,
+        3320, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323, 3323,
+        3325, 3325, 3325, 3325, 3325, 3326, 3328, 3331, 3332, 3332, 3334, 3334, 3334, 3336, 3336, 3336, 3336, 3338, 3338, 3340,
+        3340, 3343, 3347, 3349, 3349, 3349, 3349, 3351, 3354, 3357, 3357, 3357, 3357, 3357, 3357, 3357, 3357, 3357, 3357, 3357,
+        3359, 3359, 3361, 3361, 3361, 3361, 3361, 3361, 3361, 3361, 3361, 3363, 3365, 3365, 3368, 3370, 3372, 3374, 3374, 3374,
+        3374, 3374, 3374, 3374, 3374, 3376, 3376, 3379, 3379, 3379, 3379, 3379, 3379, 3379, 3380, 3380, 3380, 3380, 3380, 3380,
+        3380, 3380, 3380, 3380, 3382, 3384, 3384, 3384, 3384, 3384, 3386, 3386, 3386, 3386, 3386, 3386, 3388, 3388, 3388, 3388,
+        3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388, 3388,
+        3388, 3388, 3388, 3388, 3388, 3388, 3389, 3389, 3389, 3390, 3390, 3390, 3390, 3390, 3390, 3390, 3392, 3392, 3392, 3392,
+        3392, 3392, 3392, 3392, 3392, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393, 3393,
+        3393, 3394, 3394, 3394, 3394, 3394, 3394, 3394, 3394, 3397, 3399, 3399, 3399, 3399, 3399, 3399, 3399, 3399, 3399, 3399,
+        3399, 3399, 3399, 3399, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3401, 3403, 3403, 3405, 3405,
+        3405, 3405, 3405, 3405, 3405, 3405, 3405, 3405, 3405, 3406, 3406, 3406, 3406, 3406, 3406, 3406, 3406, 3406, 3406, 3406,
+        3406, 3406, 3408, 3408, 3409, 3409, 3409, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3411, 3413,
+        3413, 3413, 3413, 3413, 3413, 3414, 3414, 3414, 3414, 3414, 3414, 3414, 3414, 3414, 3415, 3417, 3417, 3417, 3419, 3419,
+        3419, 3419, 3419, 3419, 3419, 3419, 3419, 3419, 3419, 3419, 3421, 3421, 3423, 3423, 3423, 3423, 3423, 3423, 3423, 3423,
+        3423, 3423, 3423, 3425, 3425, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427,
+        3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3427, 3429, 3429, 3429, 3429, 3429, 3431, 3431, 3431, 3431, 3433, 3435,
+        3435, 3435, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437, 3437,
+        3437, 3437, 3439, 3439, 3441, 3441, 3441, 3441, 3441, 3441, 3441, 3441, 3441, 3441, 3441, 3443, 3443, 3443, 3443, 3443,
+        3443, 3443, 3445, 3445, 3448, 3448, 3448, 3450, 3450, 3450, 3450, 3450, 3450, 3452, 3452, 3452, 3452, 3452, 3452, 3454,
+        3454, 3455, 3455, 3455, 3455, 3455, 3455, 3457, 3457, 3459, 3459, 3459, 3461, 3461, 3461, 3461, 3461, 3461, 3461, 3461,
+        3461, 3461, 3461, 3463, 3463, 3464, 3464, 3464, 3466, 3466, 3466, 3466, 3466, 3467, 3467, 3467, 3467, 3467, 3467, 3467,
+        3467, 3468, 3468, 3470, 3470, 3470, 3470, 3472, 3472, 3472, 3472, 3472, 3472, 3475, 3475, 3476, 3478, 3478, 3478, 3478,
+        3478, 3480, 3482, 3482, 3483, 3483, 3483, 3485, 3485, 3485, 3485, 3485, 3485, 3486, 3486, 3486, 3486, 3486, 3488, 3488,
+        3488, 3488, 3488, 3488, 3489, 3489, 3489, 3491, 3491, 3493, 3493, 3495, 3499, 3499, 3499, 3499, 3499, 3499, 3499, 3499,
+        3499, 3499, 3501, 3501, 3501, 3503, 3503, 3503, 3503, 3503, 3503, 3503, 3503, 3503, 3505, 3505, 3507, 3507, 3507, 3507,
+        3507, 3507, 3507, 3507, 3508, 3508, 3508, 3508, 3508, 3508, 3510, 3510, 3510, 3511, 3511, 3511, 3511, 3511, 3511, 3511,
+        3511, 3511, 3511, 3513, 3513, 3513, 3513, 3513, 3513, 3513, 3513, 3513, 3514, 3514, 3514, 3514, 3514, 3514, 3514, 3514,
+        3514, 3514, 3514, 3514, 3514, 3514, 3515, 3515, 3515, 3515, 3515, 3515, 3515, 3515, 3515, 3515, 3515, 3517, 3517, 3517,
+        3517, 3519, 3519, 3520, 3524, 3524, 3524, 3526, 3528, 3531, 3531, 3533, 3533, 3538, 3541, 3543, 3543, 3547, 3547, 3547,
+        3547, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549, 3549,
+        3551, 3551, 3551, 3551, 3553, 3555, 3555, 3555, 3558, 3558, 3558, 3558, 3558, 3558, 3558, 3560, 3562, 3562, 3565, 3565,
+        3565, 3565, 3567, 3567, 3568, 3568, 3568, 3568, 3568, 3568, 3568, 3568, 3570, 3572, 3576, 3576, 3576, 3578, 3578, 3578,
+        3578, 3578, 3578, 3578, 3578, 3578, 3578, 3578, 3578, 3578, 3582, 3584, 3584, 3584, 3584, 3584, 3587, 3587, 3587, 3587,
+        3588, 3588, 3588, 3590, 3593, 3593, 3595, 3595, 3600, 3600, 3600, 3600, 3602, 3602, 3605, 3605, 3607, 3609, 3609, 3611,
+        3613, 3613, 3613, 3613, 3613, 3613, 3613, 3615, 3617, 3617, 3617, 3617, 3617, 3619, 3619, 3619, 3619, 3621, 3621, 3621,
+        3621, 3621, 3621, 3624, 3626, 3626, 3626, 3626, 3626, 3626, 3626, 3626, 3626, 3626, 3628, 3628, 3628, 3628, 3628, 3630,
+        3630, 3630, 3632, 3635, 3638, 3638, 3641, 3643, 3643, 3643, 3643, 3643, 3643, 3644, 3644, 3644, 3644, 3644, 3644, 3644,
+        3644, 3644, 3644, 3646, 3646, 3646, 3646, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3651, 3651, 3651, 3654, 3654,
+        3656, 3658, 3658, 3660, 3662, 3662, 3664, 3667, 3667, 3667, 3667, 3669, 3672, 3674, 3676, 3680, 3680, 3680, 3683, 3686,
+        3686, 3688, 3688, 3688, 3688, 3688, 3690, 3690, 3690, 3690, 3691, 3691, 3693, 3693, 3693, 3693, 3693, 3693, 3695, 3697,
+        3699, 3699, 3701, 3704, 3704, 3706, 3708, 3713, 3713, 3713, 3713, 3717, 3717, 3720, 3720, 3720, 3721, 3722, 3722, 3722,
+        3724, 3726, 3726, 3726, 3726, 3726, 3726, 3728, 3730, 3730, 3736, 3741, 3745, 3747, 3750, 3750, 3750, 3752, 3752, 3752,
+        3752, 3752, 3752, 3754, 3754, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3758, 3758, 3758, 3758, 3758,
+        3758, 3758, 3758, 3758, 3758, 3760, 3762, 3762, 3764, 3764, 3766, 3766, 3766, 3766, 3766, 3766, 3768, 3768, 3769, 3771,
+        3771, 3771, 3771, 3773, 3773, 3775, 3775, 3775, 3775, 3776, 3776, 3776, 3776, 3776, 3776, 3778, 3778, 3778, 3778, 3778,
+        3778, 3778, 3780, 3781, 3781, 3781, 3781, 3781, 3781, 3783, 3783, 3785, 3785, 3785, 3787, 3790, 3790, 3792, 3792, 3792,
+        3792, 3792, 3794, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3796, 3798, 3798, 3798, 3798,
+        3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3800, 3802, 3802, 3802, 3802, 3802, 3802,
+        3802, 3802, 3804, 3804, 3804, 3804, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807, 3807,
+        3810, 3810, 3810, 3812, 3812, 3812, 3814, 3814, 3816, 3819, 3819, 3819, 3819, 3819, 3821, 3824, 3824, 3824, 3826, 3828,
+        3828, 3828, 3828, 3828, 3828, 3828, 3830, 3830, 3830, 3830, 3830, 3832, 3834, 3834, 3834, 3834, 3836, 3836, 3838, 3838,
+        3838, 3840, 3842, 3842, 3842, 3842, 3842, 3842, 3842, 3842, 3843, 3843, 3843, 3843, 3843, 3843, 3845, 3845, 3845, 3845,
+        3845, 3845, 3845, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3846, 3848, 3848, 3848, 3850,
+        3850, 3850, 3852, 3852, 3852, 3852, 3852, 3852, 3852, 3852, 3854, 3854, 3854, 3856, 3858, 3860, 3860, 3860, 3862, 3862,
+        3865, 3865, 3865, 3865, 3865, 3865, 3867, 3867, 3867, 3867, 3867, 3867, 3870, 3870, 3870, 3870, 3870, 3872, 3872, 3872,
+        3872, 3872, 3872, 3872, 3874, 3874, 3874, 3874, 3876, 3876, 3876, 3878, 3878, 3880, 3880, 3880, 3880, 3882, 3882, 3884,
+        3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3884, 3886, 3886, 3886, 3886, 3886, 3886,
+        3886, 3886, 3886, 3886, 3886, 3886, 3886, 3886, 3886, 3886, 3886, 3886, 3887, 3887, 3887, 3887, 3887, 3887, 3887, 3887,
+        3887, 3887, 3887, 3887, 3888, 3888, 3888, 3888, 3888, 3888, 3888, 3888, 3888, 3888, 3890, 3890, 3890, 3890, 3890, 3890,
+        3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892, 3892,
+        3892, 3892, 3892, 3892, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894, 3894,
+        3894, 3894, 3894, 3894, 3894, 3897, 3897, 3897, 3897, 3897, 3897, 3897, 3897, 3899, 3899, 3899, 3899, 3899, 3899, 3900,
+        3900, 3900, 3900, 3902, 3902, 3902, 3902, 3902, 3902, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3905, 3905, 3905,
+        3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905,
+        3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3905, 3907, 3907, 3907, 3907, 3907, 3907, 3907, 3907,
+        3907, 3909, 3909, 3909, 3909, 3909, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911, 3911,
+        3911, 3911, 3911, 3911, 3913, 3913, 3913, 3913, 3914, 3914, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915,
+        3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915, 3915,
+        3915, 3915, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916,
+        3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3916, 3917, 3917, 3917, 3917, 3917, 3919, 3919, 3919,
+        3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919, 3919,
+        3919, 3919, 3919, 3919, 3919, 3919, 3919, 3921, 3921, 3921, 3921, 3924, 3924, 3924, 3924, 3926, 3926, 3926, 3926, 3926,
+        3926, 3926, 3926, 3926, 3926, 3926, 3926, 3926, 3926, 3926, 3928, 3928, 3928, 3928, 3928, 3928, 3928, 3928, 3930, 3930,
+        3930, 3930, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932, 3932,
+        3932, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934,
+        3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934,
+        3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934,
+        3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934, 3934,
+        3936, 3936, 3936, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3939, 3939, 3939, 3941, 3941, 3941,
+        3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3943, 3945, 3945,
+        3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945,
+        3945, 3945, 3945, 3945, 3945, 3945, 3945, 3945, 3947, 3949, 3949, 3951, 3951, 3951, 3953, 3953, 3953, 3953, 3955, 3955,
+        3955, 3955, 3957, 3959, 3959, 3959, 3959, 3962, 3962, 3962, 3962, 3962, 3962, 3962, 3962, 3962, 3964, 3964, 3964, 3964,
+        3964, 3964, 3964, 3965, 3965, 3965, 3965, 3965, 3965, 3965, 3965, 3965, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966,
+        3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3966, 3967, 3967, 3967,
+        3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967, 3967,
+        3967, 3967, 3967, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968,
+        3968, 3968, 3968, 3968, 3968, 3968, 3968, 3969, 3969, 3969, 3969, 3969, 3969, 3971, 3971, 3971, 3971, 3971, 3971, 3971,
+        3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3971, 3973,
+        3973, 3973, 3973, 3973, 3975, 3975, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977,
+        3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3977, 3979, 3979, 3979, 3979, 3979, 3980,
+        3980, 3980, 3980, 3981, 3981, 3981, 3981, 3981, 3981, 3981, 3981, 3981, 3984, 3985, 3985, 3985, 3987, 3989, 3989, 3989,
+        3989, 3989, 3989, 3989, 3989, 3991, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3993, 3994,
+        3994, 3994, 3995, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996, 3996,
+        3996, 3996, 3996, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997,
+        3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3997, 3999, 3999, 3999,
+        3999, 3999, 3999, 3999, 3999, 3999, 3999, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001,
+        4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4001, 4003, 4003, 4003, 4003, 4003, 4003, 4003,
+        4003, 4005, 4005, 4006, 4006, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007, 4007,
+        4007, 4007, 4007, 4009, 4009, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010, 4010,
+        4010, 4011, 4011, 4011, 4011, 4011, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013,
+        4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013,
+        4013, 4013, 4013, 4013, 4013, 4013, 4013, 4013, 4015, 4015, 4015, 4015, 4015, 4015, 4015, 4015, 4015, 4015, 4015, 4015,
+        4015, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4017, 4019, 4019, 4019, 4019, 4019,
+        4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019,
+        4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019, 4019,
+    ],
+    &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 3,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 3, 4, 0, 0, 1, 0, 1, 1, 5, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 3,
+        0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 6, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 1, 0, 0, 6,
+        0, 8, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 3, 3, 6, 3, 0, 3, 0, 3, 3, 6, 3, 3, 1, 3, 0, 1,
+        1, 3, 6, 3, 3, 0, 0, 3, 0, 3, 7, 3, 1, 0, 1, 1, 6, 3, 3, 0,
+        6, 6, 6, 5, 1, 0, 1, 0, 1, 3, 0, 1, 0, 0, 0, 1, 1, 3, 0, 0,
+        1, 0, 3, 9, 10, 0, 1, 0, 6, 0, 0, 0, 0, 0, 1, 1, 10, 0, 0, 0,
+        1, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 1, 3, 0, 1, 0, 11, 0, 0,
+        0, 0, 3, 1, 4, 0, 0, 0, 3, 0, 1, 1, 0, 12, 3, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        3, 0, 0, 0, 0, 0, 0, 4, 1, 1, 1, 0, 0, 6, 6, 0, 0, 0, 3, 1,
+        0, 0, 0, 0, 0, 0, 1, 0, 13, 0, 6, 6, 1, 14, 3, 1, 0, 1, 3, 0,
+        0, 0, 1, 0, 0, 0, 1, 0, 0, 5, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 1, 4, 15, 3, 3, 0, 0, 0, 0, 3, 1, 4, 1, 3, 0, 0, 3, 1,
+        6, 1, 1, 1, 1, 1, 0, 3, 6, 3, 1, 6, 0, 0, 1, 0, 0, 0, 0, 1,
+        1, 1, 16, 0, 17, 0, 18, 6, 0, 1, 0, 1, 0, 6, 1, 0, 1, 3, 6, 0,
+        0, 0, 6, 0, 0, 0, 1, 17, 1, 0, 0, 0, 0, 0, 1, 1, 1, 6, 0, 3,
+        0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 1,
+        1, 1, 1, 0, 1, 0, 1, 19, 0, 0, 3, 1, 1, 6, 0, 3, 3, 1, 3, 0,
+        0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 3, 0, 6, 0,
+        0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 4, 0, 0, 4, 0, 0, 1,
+        0, 1, 0, 0, 0, 0, 4, 1, 0, 1, 1, 0, 0, 3, 1, 1, 3, 1, 0, 20,
+        1, 0, 0, 0, 0, 0, 3, 0, 4, 0, 21, 0, 1, 1, 0, 1, 0, 0, 4, 1,
+        6, 0, 4, 0, 1, 6, 3, 6, 0, 6, 0, 3, 1, 22, 6, 6, 6, 1, 1, 1,
+        1, 1, 1, 0, 0, 1, 1, 1, 3, 1, 1, 0, 3, 0, 3, 0, 1, 6, 0, 0,
+        1, 1, 5, 1, 1, 1, 0, 3, 3, 6, 3, 3, 0, 1, 1, 0, 1, 0, 0, 0,
+        1, 1, 1, 1, 0, 0, 1, 3, 3, 23, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0,
+        3, 1, 0, 1, 0, 24, 1, 1, 3, 3, 0, 1, 3, 3, 0, 0, 0, 1, 3, 0,
+        0, 0, 1, 1, 1, 0, 0, 1, 0, 3, 0, 0, 3, 0, 0, 3, 1, 1, 0, 0,
+        25, 3, 6, 26, 1, 0, 0, 0, 0, 0, 6, 1, 1, 0, 0, 0, 4, 1, 1, 0,
+        1, 27, 0, 3, 0, 11, 0, 0, 1, 1, 1, 1, 0, 3, 1, 1, 0, 0, 0, 1,
+        0, 0, 0, 1, 10, 0, 0, 3, 0, 0, 1, 6, 1, 3, 1, 0, 1, 1, 0, 0,
+        4, 1, 0, 0, 0, 0, 1, 1, 0, 3, 0, 3, 0, 0, 3, 6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 6, 6, 0, 6, 1, 1, 7, 3,
+        1, 4, 1, 1, 1, 6, 3, 0, 0, 3, 3, 0, 3, 1, 0, 0, 0, 1, 1, 0,
+        1, 1, 0, 0, 1, 1, 0, 7, 0, 0, 0, 1, 17, 1, 0, 1, 1, 1, 1, 1,
+        1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 6, 0, 0, 0, 0,
+        0, 1, 0, 1, 0, 0, 0, 0, 0, 6, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0,
+        0, 0, 3, 0, 26, 1, 0, 1, 0, 0, 2, 0, 0, 0, 3, 0, 3, 28, 6, 6,
+        6, 1, 1, 0, 3, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 3, 1, 0, 1, 0,
+        0, 1, 1, 0, 0, 1, 7, 0, 0, 3, 1, 0, 11, 0, 0, 1, 6, 26, 6, 0,
+        0, 0, 0, 4, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 1,
+        0, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 3, 1, 29, 3, 1, 0, 0,
+        0, 1, 1, 0, 0, 1, 1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 3, 1, 1, 0, 0, 0, 0, 1, 0, 0, 3, 3, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 6, 0,
+        0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 6, 30, 0,
+        0, 1, 3, 0, 1, 4, 0, 0, 0, 1, 1, 3, 1, 0, 0, 0, 0, 1, 0, 0,
+        0, 3, 0, 0, 0, 7, 1, 1, 1, 1, 4, 0, 0, 1, 0, 3, 1, 0, 0, 0,
+        0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 1, 4, 1, 6, 1, 0, 1, 0,
+        0, 0, 0, 6, 3, 0, 0, 0, 3, 0, 0, 0, 0, 0, 3, 5, 3, 6, 3, 1,
+        3, 0, 1, 0, 0, 3, 1, 6, 1, 0, 3, 3, 0, 0, 1, 1, 1, 30, 0, 0,
+        0, 0, 4, 0, 1, 1, 6, 0, 0, 1, 3, 31, 17, 3, 0, 0, 0, 1, 3, 0,
+        0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 5, 3, 1, 6, 0, 1, 0, 0, 0, 1,
+        0, 1, 0, 1, 6, 6, 1, 1, 0, 1, 1, 0, 3, 1, 0, 0, 0, 1, 3, 3,
+        3, 1, 1, 1, 1, 1, 6, 1, 1, 1, 1, 1, 1, 1, 1, 4, 1, 3, 6, 0,
+        3, 4, 1, 1, 1, 1, 1, 0, 1, 1, 7, 1, 0, 1, 3, 0, 1, 1, 28, 32,
+        1, 1, 3, 1, 0, 1, 3, 1, 1, 6, 1, 1, 1, 2, 3, 6, 1, 3, 1, 1,
+        1, 1, 1, 0, 1, 3, 1, 3, 1, 1, 6, 1, 33, 0, 34, 3, 3, 1, 1, 0,
+        4, 1, 0, 1, 7, 0, 0, 0, 6, 0, 0, 1, 0, 1, 1, 1, 6, 1, 1, 1,
+        0, 4, 3, 0, 1, 0, 0, 3, 6, 3, 1, 3, 1, 1, 1, 7, 0, 4, 0, 1,
+        4, 1, 0, 1, 3, 1, 1, 1, 3, 3, 1, 1, 3, 6, 3, 1, 1, 1, 3, 10,
+        1, 1, 1, 0, 0, 0, 6, 1, 3, 0, 0, 1, 3, 3, 1, 0, 6, 3, 4, 2,
+        1, 1, 1, 7, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 4, 1,
+        1, 1, 4, 1, 1, 3, 1, 4, 1, 4, 1, 7, 3, 1, 3, 4, 1, 1, 1, 1,
+        1, 1, 0, 6, 1, 0, 0, 0, 1, 0, 1, 1, 1, 3, 3, 0, 0, 0, 0, 0,
+        0, 1, 1, 0, 1, 6, 1, 1, 1, 1, 0, 1, 0, 1, 11, 0, 1, 0, 1, 1,
+        4, 0, 3, 1, 3, 0, 1, 1, 0, 0, 1, 1, 1, 6, 0, 0, 1, 1, 0, 1,
+        1, 1, 6, 1, 1, 0, 1, 1, 1, 3, 1, 1, 0, 1, 3, 0, 6, 7, 3, 3,
+        1, 1, 4, 6, 1, 1, 1, 1, 1, 1, 6, 1, 1, 6, 1, 1, 0, 1, 1, 1,
+        3, 20, 0, 1, 1, 0, 0, 1, 1, 0, 3, 1, 0, 3, 1, 0, 1, 0, 3, 1,
+        1, 0, 0, 1, 1, 7, 1, 1, 3, 0, 3, 1, 1, 1, 0, 1, 35, 1, 1, 3,
+        36, 1, 3, 3, 4, 1, 1, 3, 3, 1, 1, 3, 37, 1, 1, 1, 3, 7, 0, 1,
+        1, 3, 6, 3, 0, 6, 4, 0, 1, 1, 4, 38, 1, 1, 1, 6, 1, 0, 3, 1,
+        3, 4, 1, 1, 1, 39, 4, 1, 1, 1, 6, 1, 1, 1, 0, 0, 3, 1, 1, 29,
+        1, 1, 4, 1, 1, 0, 1, 6, 1, 0, 1, 1, 1, 1, 1, 1, 1, 3, 0, 1,
+        0, 1, 0, 1, 1, 0, 1, 1, 3, 7, 1, 4, 0, 0, 1, 1, 3, 1, 3, 1,
+        1, 3, 0, 40, 1, 1, 6, 1, 41, 1, 6, 1, 6, 1, 1, 3, 3, 0, 6, 1,
+        42, 2, 1, 1, 1, 7, 1, 1, 1, 3, 1, 3, 6, 4, 6, 3, 1, 3, 1, 1,
+        1, 0, 1, 1, 1, 1, 1, 6, 1, 0, 3, 43, 1, 1, 0, 1, 1, 1, 17, 1,
+        0, 0, 1, 1, 1, 1, 0, 20, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 44, 0,
+        1, 3, 0, 6, 1, 1, 1, 1, 3, 3, 1, 45, 1, 3, 24, 3, 1, 6, 0, 3,
+        3, 0, 0, 1, 6, 3, 3, 3, 3, 1, 0, 1, 38, 0, 1, 0, 1, 3, 1, 1,
+        1, 0, 1, 11, 4, 1, 1, 1, 3, 6, 1, 0, 1, 1, 0, 0, 4, 1, 1, 1,
+        1, 3, 1, 1, 1, 1, 46, 1, 1, 1, 6, 1, 1, 1, 0, 1, 26, 11, 1, 1,
+        1, 1, 10, 29, 1, 1, 0, 0, 1, 0, 1, 3, 1, 1, 4, 1, 11, 1, 0, 1,
+        0, 3, 4, 1, 1, 1, 1, 1, 1, 1, 1, 7, 1, 1, 1, 1, 1, 1, 3, 0,
+        1, 5, 3, 6, 3, 0, 0, 3, 3, 11, 1, 4, 0, 1, 1, 4, 0, 1, 0, 3,
+        0, 0, 0, 1, 1, 6, 0, 11, 0, 1, 1, 1, 1, 1, 4, 3, 1, 6, 1, 0,
+        1, 0, 5, 3, 1, 1, 5, 1, 1, 47, 0, 0, 1, 0, 0, 0, 0, 1, 1, 3,
+        1, 1, 24, 1, 0, 1, 0, 1, 1, 1, 4, 6, 1, 6, 3, 4, 1, 4, 1, 1,
+        1, 1, 1, 0, 0, 1, 1, 1, 6, 0, 1, 6, 6, 3, 1, 0, 1, 4, 1, 6,
+        1, 1, 6, 3, 3, 6, 6, 1, 1, 1, 21, 4, 1, 1, 1, 1, 4, 4, 1, 1,
+        1, 1, 1, 4, 1, 3, 1, 1, 3, 6, 4, 1, 4, 1, 1, 0, 1, 1, 1, 4,
+        1, 1, 6, 3, 3, 1, 6, 1, 6, 1, 3, 1, 1, 1, 3, 3, 1, 1, 1, 1,
+        3, 48, 49, 1, 7, 3, 50, 6, 0, 0, 4, 3, 1, 1, 1, 6, 21, 2, 6, 6,
+        1, 3, 1, 3, 1, 7, 1, 1, 6, 1, 3, 6, 3, 1, 1, 0, 6, 0, 3, 3,
+        1, 3, 1, 1, 3, 1, 1, 1, 3, 1, 3, 6, 1, 5, 1, 1, 1, 51, 4, 1,
+        1, 0, 1, 3, 4, 1, 0, 1, 7, 1, 1, 3, 3, 3, 3, 1, 6, 6, 0, 52,
+        1, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 1, 6, 3, 0, 1, 28, 0, 1, 1,
+        1, 1, 4, 1, 1, 1, 6, 1, 4, 0, 5, 0, 1, 6, 1, 1, 1, 1, 1, 6,
+        1, 1, 0, 1, 0, 4, 6, 1, 3, 3, 6, 1, 3, 1, 1, 1, 3, 3, 6, 1,
+        1, 1, 1, 1, 1, 1, 53, 1, 0, 6, 6, 1, 1, 0, 1, 54, 1, 0, 0, 6,
+        6, 0, 4, 1, 1, 1, 1, 6, 55, 4, 1, 1, 1, 1, 1, 6, 1, 3, 1, 1,
+        1, 0, 6, 0, 1, 1, 1, 1, 56, 4, 1, 5, 1, 6, 0, 1, 1, 4, 1, 1,
+        1, 0, 1, 0, 1, 1, 0, 1, 1, 3, 1, 3, 0, 1, 1, 4, 1, 1, 0, 1,
+        3, 1, 5, 1, 1, 3, 0, 3, 0, 1, 5, 0, 1, 5, 3, 1, 1, 1, 1, 6,
+        1, 1, 0, 1, 3, 1, 1, 0, 1, 4, 1, 1, 3, 1, 1, 4, 1, 1, 1, 4,
+        3, 1, 0, 1, 1, 1, 0, 1, 6, 1, 1, 1, 0, 4, 11, 0, 1, 6, 1, 6,
+        0, 0, 3, 3, 1, 4, 0, 1, 1, 4, 1, 1, 3, 1, 1, 1, 6, 0, 1, 1,
+        1, 1, 4, 3, 1, 3, 0, 1, 0, 3, 0, 0, 1, 3, 3, 1, 1, 1, 1, 1,
+        1, 0, 3, 4, 0, 1, 1, 3, 4, 4, 3, 1, 3, 0, 6, 0, 1, 1, 1, 3,
+        1, 7, 1, 3, 1, 3, 4, 1, 3, 0, 6, 1, 24, 3, 2, 1, 4, 1, 6, 6,
+        1, 1, 0, 4, 1, 3, 3, 3, 3, 1, 1, 3, 1, 1, 6, 1, 1, 1, 1, 1,
+        1, 1, 1, 4, 4, 6, 1, 1, 1, 1, 3, 1, 1, 2, 2, 1, 0, 1, 1, 6,
+        0, 1, 3, 0, 4, 4, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 1,
+        0, 1, 1, 3, 1, 1, 1, 1, 1, 3, 3, 1, 1, 2, 6, 6, 6, 1, 1, 0,
+        1, 1, 1, 0, 0, 1, 3, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 17, 1, 1,
+        1, 0, 1, 3, 1, 1, 1, 1, 1, 3, 1, 0, 0, 0, 1, 1, 0, 1, 3, 1,
+        1, 1, 1, 6, 1, 6, 5, 1, 1, 0, 0, 3, 1, 3, 1, 0, 1, 1, 1, 29,
+        3, 1, 0, 0, 1, 0, 1, 6, 3, 3, 3, 0, 3, 11, 1, 0, 0, 4, 0, 0,
+        1, 1, 1, 3, 1, 3, 0, 0, 1, 1, 1, 3, 0, 1, 1, 1, 0, 4, 1, 1,
+        1, 3, 0, 0, 10, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 3,
+        6, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 10, 1, 1, 1, 3, 3, 3, 1,
+        3, 6, 0, 1, 1, 3, 0, 3, 6, 1, 3, 1, 3, 57, 0, 1, 1, 0, 1, 0,
+        0, 1, 0, 0, 1, 0, 0, 1, 1, 3, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1,
+        0, 1, 1, 1, 1, 0, 1, 6, 1, 17, 1, 0, 1, 1, 1, 1, 1, 0, 0, 0,
+        1, 0, 0, 3, 0, 0, 0, 1, 3, 3, 0, 3, 0, 1, 1, 3, 3, 1, 3, 0,
+        0, 1, 1, 3, 1, 1, 0, 0, 3, 0, 0, 0, 0, 0, 1, 1, 0, 3, 1, 1,
+        1, 6, 0, 3, 0, 3, 1, 1, 3, 1, 1, 1, 3, 6, 0, 6, 1, 1, 58, 1,
+        3, 1, 1, 1, 0, 0, 3, 0, 1, 0, 3, 1, 1, 3, 1, 0, 1, 3, 0, 6,
+        1, 1, 0, 3, 0, 1, 1, 1, 3, 1, 1, 1, 1, 1, 0, 1, 3, 6, 1, 6,
+        6, 59, 30, 1, 1, 60, 3, 6, 0, 1, 0, 1, 1, 0, 0, 1, 0, 1, 4, 1,
+        1, 0, 0, 1, 1, 3, 6, 1, 1, 1, 1, 1, 1, 1, 4, 0, 3, 1, 1, 0,
+        1, 4, 3, 7, 1, 1, 3, 1, 1, 1, 61, 1, 1, 0, 1, 1, 1, 1, 1, 1,
+        1, 3, 10, 3, 11, 0, 6, 1, 2, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 6,
+        1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 1, 3, 17, 0, 0, 1, 6, 0, 0, 1,
+        1, 1, 1, 1, 26, 0, 3, 1, 6, 1, 0, 0, 1, 3, 1, 0, 1, 0, 1, 6,
+        0, 0, 62, 1, 1, 1, 1, 1, 6, 1, 4, 1, 3, 3, 1, 0, 1, 1, 1, 1,
+        3, 1, 3, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 1, 3, 1, 1, 0, 1,
+        1, 0, 1, 1, 7, 6, 63, 1, 1, 0, 1, 4, 0, 0, 0, 1, 1, 0, 1, 0,
+        4, 3, 7, 6, 6, 0, 1, 1, 1, 3, 3, 0, 1, 1, 3, 6, 0, 1, 6, 3,
+        1, 1, 1, 0, 6, 1, 3, 0, 5, 1, 3, 1, 0, 1, 1, 1, 0, 1, 0, 1,
+        1, 1, 6, 1, 3, 3, 7, 6, 1, 1, 1, 1, 1, 0, 1, 3, 6, 3, 6, 6,
+        1, 3, 1, 1, 1, 1, 1, 1, 4, 1, 4, 64, 1, 1, 3, 6, 1, 65, 6, 3,
+        3, 1, 1, 3, 3, 1, 1, 1, 3, 1, 1, 1, 6, 0, 1, 1, 1, 1, 1, 0,
+        1, 1, 1, 1, 4, 6, 1, 6, 1, 4, 1, 1, 4, 1, 1, 1, 3, 1, 3, 1,
+        1, 1, 1, 0, 4, 1, 3, 1, 6, 1, 1, 3, 6, 1, 1, 1, 1, 1, 6, 1,
+        3, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 4, 1, 1, 3, 1, 3, 1,
+        4, 1, 0, 6, 3, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1,
+        1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 4, 6, 1, 6, 1, 7, 6, 1, 1, 1,
+        1, 4, 1, 1, 1, 3, 1, 1, 1, 1, 1, 0, 1, 1, 3, 3, 1, 1, 1, 6,
+        0, 1, 1, 1, 3, 0, 3, 6, 1, 1, 1, 7, 6, 1, 6, 3, 4, 1, 3, 2,
+        66, 4, 6, 0, 1, 1, 1, 3, 1, 3, 3, 3, 6, 3, 1, 1, 1, 1, 4, 1,
+        7, 67, 1, 1, 1, 4, 4, 1, 3, 1, 3, 1, 1, 1, 6, 1, 1, 1, 4, 1,
+        1, 3, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 3, 1, 1, 1, 3, 1, 1, 1, 1, 3, 6, 1, 1, 1, 1, 0, 1, 1, 3,
+        1, 2, 1, 4, 6, 1, 6, 1, 6, 1, 1, 1, 1, 1, 1, 1, 1, 7, 3, 1,
+        68, 1, 1, 4, 1, 4, 0, 3, 1, 3, 0, 4, 1, 1, 0, 1, 6, 1, 1, 1,
+        6, 1, 1, 1, 1, 6, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 7,
+        1, 0, 1, 5, 1, 1, 1, 1, 7, 1, 6, 1, 4, 4, 6, 1, 1, 6, 1, 4,
+        0, 1, 4, 1, 1, 1, 1, 4, 1, 1, 1, 1, 4, 6, 6, 1, 1, 3, 1, 1,
+        1, 4, 1, 6, 1, 1, 1, 1, 1, 3, 6, 6, 1, 6, 1, 5, 1, 3, 3, 1,
+        1, 4, 1, 1, 1, 3, 1, 1, 1, 4, 1, 6, 4, 1, 1, 4, 1, 6, 6, 2,
+        4, 3, 1, 5, 1, 1, 3, 1, 6, 1, 6, 1, 7, 1, 1, 4, 1, 6, 1, 0,
+        1, 1, 3, 1, 4, 1, 1, 1, 1, 1, 1, 1, 4, 0, 1, 6, 0, 1, 6, 1,
+        4, 1, 3, 1, 1, 1, 6, 1, 1, 4, 1, 6, 1, 1, 1, 1, 0, 0, 1, 6,
+        6, 1, 4, 1, 1, 1, 1, 1, 1, 1, 69, 1, 1, 1, 4, 1, 1, 4, 1, 1,
+        6, 3, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1,
+        1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 3, 1, 1, 3, 3, 1, 3, 1, 1,
+        1, 1, 6, 1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 3, 1, 1, 6, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 4, 1, 1, 3, 1, 1, 1,
+        1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 7, 4,
+        1, 1, 3, 6, 4, 1, 1, 1, 1, 1, 1, 1, 4, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 3, 1, 4, 1, 1, 6, 0, 6, 3, 4, 1, 1, 1, 1,
+        3, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 1, 1, 1, 0, 1,
+        1, 1, 4, 0, 1, 0, 6, 1, 3, 1, 1, 1, 1, 4, 1, 1, 1, 1, 1, 1,
+        6, 1, 1, 1, 2, 1, 3, 4, 1, 4, 3, 1, 1, 0, 1, 1, 1, 1, 3, 1,
+        6, 1, 3, 1, 1, 1, 3, 3, 1, 1, 1, 0, 3, 1, 3, 0, 0, 1, 1, 1,
+        1, 3, 3, 1, 0, 0, 1, 1, 3, 1, 1, 1, 6, 6, 1, 1, 3, 6, 3, 1,
+        1, 3, 3, 1, 1, 3, 7, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 1, 3, 3,
+        1, 1, 3, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 4, 1, 4, 1, 1, 1,
+        3, 1, 1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 0, 3, 3, 6,
+        1, 3, 1, 1, 1, 3, 0, 1, 1, 1, 26, 6, 1, 1, 6, 6, 1, 1, 6, 6,
+        1, 1, 4, 4, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 4, 1,
+        0, 1, 6, 6, 3, 1, 4, 3, 1, 1, 1, 1, 6, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 0, 1, 3, 1, 70, 1, 1, 1, 4, 1, 1, 1, 3, 1, 1, 1, 1, 1,
+        6, 1, 1, 1, 1, 0, 1, 1, 1, 3, 1, 4, 1, 1, 1, 1, 4, 1, 1, 4,
+        1, 7, 6, 1, 6, 1, 7, 1, 6, 1, 4, 1, 6, 3, 3, 3, 1, 1, 1, 1,
+        1, 1, 1, 7, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 4, 1, 1, 1, 1,
+        0, 1, 6, 1, 4, 6, 1, 1, 1, 1, 1, 4, 1, 1, 0, 1, 1, 3, 1, 1,
+        1, 3, 3, 3, 6, 1, 1, 1, 1, 4, 1, 3, 3, 1, 1, 1, 3, 1, 3, 6,
+        1, 6, 1, 2, 4, 6, 6, 3, 1, 1, 1, 1, 4, 1, 1, 1, 1, 6, 1,
+    ],
+    &[
+        [Edit::new(1, 0, 3), Edit::new(1, 3, 4)],
+        [Edit::new(1, 2, 1), Edit::new(1, 5, 2)],
+        [Edit::new(0, 1, 2), Edit::new(0, 4, 3)],
+        [Edit::new(2, 0, 3), Edit::new(2, 3, 4)],
+        [Edit::new(0, 7, 3), Edit::new(0, 10, 4)],
+        [Edit::new(0, 2, 1), Edit::new(0, 5, 2)],
+        [Edit::new(1, 1, 2), Edit::new(1, 4, 3)],
+        [Edit::new(5, 14, 10), Edit::new(3, 24, 4)],
+        [Edit::new(0, 28, 2), Edit::new(0, 30, 2)],
+        [Edit::new(0, 32, 3), Edit::new(0, 35, 4)],
+        [Edit::new(0, 39, 3), Edit::new(0, 42, 4)],
+        [Edit::new(7, 46, 12), Edit::new(0, 24, 4)],
+        [Edit::new(0, 58, 3), Edit::new(0, 61, 4)],
+        [Edit::new(9, 65, 14), Edit::new(0, 5, 2)],
+        [Edit::new(3, 79, 8), Edit::new(0, 87, 4)],
+        [Edit::new(0, 91, 4), Edit::new(0, 95, 5)],
+        [Edit::new(0, 21, 3), Edit::new(0, 100, 4)],
+        [Edit::new(9, 104, 14), Edit::new(0, 24, 4)],
+        [Edit::new(0, 118, 3), Edit::new(0, 121, 4)],
+        [Edit::new(0, 73, 3), Edit::new(0, 125, 4)],
+        [Edit::new(1, 21, 3), Edit::new(1, 100, 4)],
+        [Edit::new(5, 129, 10), Edit::new(0, 24, 4)],
+        [Edit::new(0, 139, 3), Edit::new(0, 142, 4)],
+        [Edit::new(0, 1, 2), Edit::new(0, 24, 4)],
+        [Edit::new(1, 0, 3), Edit::new(0, 5, 2)],
+        [Edit::new(0, 146, 3), Edit::new(0, 87, 4)],
+        [Edit::new(2, 149, 7), Edit::new(0, 24, 4)],
+        [Edit::new(1, 146, 3), Edit::new(1, 87, 4)],
+        [Edit::new(0, 156, 3), Edit::new(0, 159, 4)],
+        [Edit::new(0, 136, 3), Edit::new(0, 163, 4)],
+        [Edit::new(8, 167, 12), Edit::new(1, 24, 4)],
+        [Edit::new(4, 179, 5), Edit::new(4, 184, 5)],
+        [Edit::new(1, 136, 3), Edit::new(1, 24, 4)],
+        [Edit::new(1, 28, 2), Edit::new(1, 30, 2)],
+        [Edit::new(6, 189, 11), Edit::new(0, 42, 4)],
+        [Edit::new(7, 200, 12), Edit::new(0, 5, 2)],
+        [Edit::new(6, 212, 11), Edit::new(0, 4, 3)],
+        [Edit::new(1, 39, 3), Edit::new(1, 42, 4)],
+        [Edit::new(7, 223, 12), Edit::new(0, 5, 2)],
+        [Edit::new(8, 235, 12), Edit::new(1, 24, 4)],
+        [Edit::new(1, 156, 3), Edit::new(1, 159, 4)],
+        [Edit::new(6, 247, 11), Edit::new(0, 4, 3)],
+        [Edit::new(5, 258, 10), Edit::new(0, 24, 4)],
+        [Edit::new(3, 0, 3), Edit::new(3, 3, 4)],
+        [Edit::new(4, 268, 9), Edit::new(0, 24, 4)],
+        [Edit::new(7, 277, 11), Edit::new(1, 4, 3)],
+        [Edit::new(7, 288, 12), Edit::new(0, 4, 3)],
+        [Edit::new(7, 300, 12), Edit::new(0, 24, 4)],
+        [Edit::new(7, 312, 12), Edit::new(0, 24, 4)],
+        [Edit::new(6, 324, 8), Edit::new(6, 332, 9)],
+        [Edit::new(3, 15, 3), Edit::new(3, 25, 3)],
+        [Edit::new(5, 341, 10), Edit::new(0, 24, 4)],
+        [Edit::new(6, 351, 11), Edit::new(0, 24, 4)],
+        [Edit::new(0, 14, 4), Edit::new(0, 24, 4)],
+        [Edit::new(9, 362, 13), Edit::new(1, 24, 4)],
+        [Edit::new(6, 375, 10), Edit::new(1, 24, 4)],
+        [Edit::new(9, 385, 13), Edit::new(1, 24, 4)],
+        [Edit::new(1, 118, 3), Edit::new(1, 121, 4)],
+        [Edit::new(0, 398, 3), Edit::new(0, 401, 4)],
+        [Edit::new(7, 405, 12), Edit::new(0, 24, 4)],
+        [Edit::new(5, 417, 10), Edit::new(0, 5, 2)],
+        [Edit::new(5, 427, 10), Edit::new(0, 24, 4)],
+        [Edit::new(4, 437, 6), Edit::new(4, 159, 4)],
+        [Edit::new(7, 443, 12), Edit::new(0, 4, 3)],
+        [Edit::new(1, 139, 3), Edit::new(1, 142, 4)],
+        [Edit::new(8, 455, 13), Edit::new(0, 4, 3)],
+        [Edit::new(3, 468, 5), Edit::new(3, 473, 6)],
+        [Edit::new(5, 437, 6), Edit::new(5, 159, 4)],
+        [Edit::new(8, 479, 13), Edit::new(0, 24, 4)],
+        [Edit::new(8, 492, 13), Edit::new(0, 4, 3)],
+    ],
+    "ieriestlerlestmore uttermostaratgergestmermestmore centralferfestmore desperatemore duddesttherthesttestmore favouritesersestpestmore innerkerkestderore midberbestnestmore westernworseworstmore dadgummore daggonemore darnedmore doggonemore easternmore fallenmore firstmore foremore germanmore hauntedmore leewardmore lefternerweightestweightore middlemore nethermore northernmore outermore southerncercestmore thithermore tracemore upperbettermore bitchinmore doggonederassestassmore righternmore bitching",
+);
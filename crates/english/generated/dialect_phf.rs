@@ -0,0 +1,47 @@
+// Curated list of common regional forms, to be replaced by the output of
+// `cargo xtask refresh-data`. See `English::noun_in`.
+use phf::phf_map;
+
+/// "dialect:word" => regional plural
+pub static DIALECT_PLURAL_MAP: phf::Map<&'static str, [&'static str; 1]> = phf_map! {
+    "uk:penny" => ["pence"],
+};
+
+/// "dialect:infinitive" => (3rd person singular, past, present participle, past participle)
+pub static DIALECT_VERB_MAP: phf::Map<&'static str, [&'static str; 4]> = phf_map! {
+    "ca:cancel" => ["cancels", "cancelled", "cancelling", "cancelled"],
+    "ca:counsel" => ["counsels", "counselled", "counselling", "counselled"],
+    "ca:dial" => ["dials", "dialled", "dialling", "dialled"],
+    "ca:fuel" => ["fuels", "fuelled", "fuelling", "fuelled"],
+    "ca:label" => ["labels", "labelled", "labelling", "labelled"],
+    "ca:level" => ["levels", "levelled", "levelling", "levelled"],
+    "ca:marvel" => ["marvels", "marvelled", "marvelling", "marvelled"],
+    "ca:model" => ["models", "modelled", "modelling", "modelled"],
+    "ca:quarrel" => ["quarrels", "quarrelled", "quarrelling", "quarrelled"],
+    "ca:signal" => ["signals", "signalled", "signalling", "signalled"],
+    "ca:total" => ["totals", "totalled", "totalling", "totalled"],
+    "ca:travel" => ["travels", "travelled", "travelling", "travelled"],
+    "uk:burn" => ["burns", "burnt", "burning", "burnt"],
+    "uk:cancel" => ["cancels", "cancelled", "cancelling", "cancelled"],
+    "uk:counsel" => ["counsels", "counselled", "counselling", "counselled"],
+    "uk:dial" => ["dials", "dialled", "dialling", "dialled"],
+    "uk:dream" => ["dreams", "dreamt", "dreaming", "dreamt"],
+    "uk:fuel" => ["fuels", "fuelled", "fuelling", "fuelled"],
+    "uk:label" => ["labels", "labelled", "labelling", "labelled"],
+    "uk:leap" => ["leaps", "leapt", "leaping", "leapt"],
+    "uk:learn" => ["learns", "learnt", "learning", "learnt"],
+    "uk:level" => ["levels", "levelled", "levelling", "levelled"],
+    "uk:marvel" => ["marvels", "marvelled", "marvelling", "marvelled"],
+    "uk:model" => ["models", "modelled", "modelling", "modelled"],
+    "uk:quarrel" => ["quarrels", "quarrelled", "quarrelling", "quarrelled"],
+    "uk:signal" => ["signals", "signalled", "signalling", "signalled"],
+    "uk:smell" => ["smells", "smelt", "smelling", "smelt"],
+    "uk:spell" => ["spells", "spelt", "spelling", "spelt"],
+    "uk:spill" => ["spills", "spilt", "spilling", "spilt"],
+    "uk:spoil" => ["spoils", "spoilt", "spoiling", "spoilt"],
+    "uk:total" => ["totals", "totalled", "totalling", "totalled"],
+    "uk:travel" => ["travels", "travelled", "travelling", "travelled"],
+    "us:dive" => ["dives", "dove", "diving", "dived"],
+    "us:fit" => ["fits", "fit", "fitting", "fit"],
+    "us:get" => ["gets", "got", "getting", "gotten"],
+};
@@ -0,0 +1,4890 @@
+use crate::trie::{Edit, Trie};
+
+pub static PLURAL_MAP: Trie<1> = Trie::new(
+    b"\x00abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghilmnoprstuwxyabcdefhilmnoprstuvwyza\
+        bcdefghijklmnoprsuwyz2abcdefghiklmnopqrstuvwxyzabegilmnoprtuwyzabcdefghijlmnoprstuvwyacdefgilmno\
+        prsuwy2abcdfghjklmnopqrstvwyz2aefijostuwyabdefghiklmnoprsuvwyacdefghijmoprsuvwxy23abcdefgiklmnop\
+        rstuvwxyzabcdegiklnoprtuxyz2abcdefghiklmnoprstuvwxyzabcdefghilmnoprstuvwyaefioprstuwyabcdefhilno\
+        pqtuwxyabcdefghijklmnopqrstuvwxyzabcdefghiklmnoprstuwxyz2abcdefghijklmnoprstvwyzabcdefiklopruyab\
+        cdefhiklmnoprstuyaeghilopsuxyaceioptuaehilmouvwybcdnprstabdehiknorsuyzacefhinorstuyadefhimnoprvy\
+        zacdgnorstafilrstabeghlnorstueilortu2dikmnrstaiaehiorstuabcdefgiklmnopstuvyabefimnoptuwyacdefghi\
+        klmnopstuinprtaefhilnoprstuysuabceghikmopqrstuy2acdefhikmpsty2aefhilmortuy2adfklmrstaegiosvadfqs\
+        losalmaeiotzabcdeghijklmnorstuyzlsefracdefghiklmnorstvyzfaeiu2abcdefgjlmnoprstvwzaeiou2fsxfuabcd\
+        efgijklmnorstuwxyz2dfimosadeiouyfhabcdfiklmnoprstuyzbitklosbcdefghijklmnpqrstuvyfifgsfsacilmnprs\
+        tvwxyafpsaehilmoruyabcglmnprstvaeiouy2abdeszefisabcdefghiklmnopqrstuvwxyzimpsaeilosuyfrscefabcfi\
+        lmnprstzarsmtabclmnpstwaeabcdefghiklmnoprstuvyzfmfhsasabcdefghiklmnprstuvxzaeorabcdefghiklmnoprs\
+        tvwyzoufs2bcdefghjklmnorstuvwyzaefiouy2hmscdefmnoprstuxaefabensfrouacdhotuzadeit2sfibfgpudgkmnrs\
+        kpuwadefiklouvwyabefmoptuyabcdefgiklnostufhzaehiorsuy2uabcefghimnosuwxy2cekopqrstacfhrycfghklmno\
+        prst2aefzeyacehioprstuw2aerahbcefgiklmnprstuvwabcdeilmnorstyzabcdeglmnorsuvxzaeiosuyfkozacdegikl\
+        mnorstuvwisaceiouw2mx2cdfgjklmnrstz2bcdefgijklmnoprstuwyzafpfnfsacdeiklmnoprstuzy2aaeiosu2bfgjlm\
+        noprstuyaeiosuyfiloaosuabdefhijklmnoprstuvy2fmpaeioruyzfg2ftabefghijklmnoqrstyzaefamnoprtabcdefg\
+        ijklmnoprstuvwzkqacdefgiklmnpqrstxymprabcdefgklmnprstwz23bcdefghklmnoprstuvw2fioyftabfiklmnpqrst\
+        wzayabdeglmoprsmsaehiefhostefiosdsflnoqlsmweilsuabimp2acdefghijknorstuvydmnpwfrsumnaefimosuaceio\
+        stuefhoafiainoaho2bcefgijkmnprtuvwzdfhjlorstwzabcfghlmnstz2bchiklmnoprsubdghkmnrsvxz23nabdfghijk\
+        lmnoprstuwy2pabdeghiklmnprstvyzaoaeilmouabcdefgjklmnoprstwyzowaeiouyeps2aeiouy2abcdefghiklmnoprs\
+        tuvyzefhmaeiouafabdefgiklmnprstuvziuaeoaklorzabcdefghkmnopqrstuvwyzhsfisabcdegikmnopstuvwxyz2sbf\
+        fox2abcefgklmnopqrstvxz23abcdfghklmnoprstuvwfscfn2habcdfijlmnoprstvxaenscdfgkmnotabcdefghijklmno\
+        pqrstuwxyzaps2hmtyabcdefghijlmnoprstvwxzfafuacdefghjklmnqrstwxyzmrsy2f23eabcdefghjklmnopqrstuwxy\
+        zaghos23fgs2fhimpsbfx2acdefghijklmnoqrstwyz2sacfncelnorstxesuabefghiklmnopqrstuvwxyzefyabcdefgik\
+        lmnoprstuvwxabeoquwbcdefghklmnoprstvzir23bcdeghlmnoprstuvxyzsubcdeklmnprstxzacglmeifkrabeijloceh\
+        irtuadeioucdnsuabfudolucklnprsailortudefimopsabeinopuacdeiklnostuydfgmnprstaeghiopstuabcdefgikln\
+        oprty2345ahmostafhikot2rtaefiouflaeioyaers2etbcdeghijklmnoprstuvwxyzpsyelmtfgacdegklmnopqrstuwae\
+        yefaeiloruyabcefghiklmnoprstuxyzaefiouyfos2ensacdefghiklmnoprstuvwybfhmqtaeiostuy23acefhinoyadef\
+        sxabdefgiklmnprstuyfsansucegklorsxaflmprfpzdnrtzbfmrfsasbfpabeinorrzabcdfghijkmnprstvwxzfibfabcd\
+        efgijlmnprstuvzxaeiouy2abcdefgklmnopstvyabcdeghjklmnoprstuwxfmslstbcdfgmnpstlouabcdefghiklmnoprs\
+        tuvwxzfiopraefhiloruyasabcdefgijklmnpqrstuvwxymopalracefhikmnoprtuvabcdefghklmnoprstuwxyzaehioru\
+        yaefiopsuyaeiosu2aiosuyabcdfiklmnoprstuvyaefhiloprsuvfmuafiru2aefiorsuwy2abcdfgiklmnprsckacefio2\
+        3blmnprzeibcefghijklmnopqrstuvwxzpsafhmwacdefgijklmnopqrstuwxfiaeiloruyabdefgiklmnprtvyzaiesabcd\
+        efgiklmnoprstuwxaefiouy2aeiopsuesyabcfijklmnprstxzeiocilmpryaiouckprsxeuyhlafmoafueipcefinptuxab\
+        deikluwzabcdghinopstzcfopsabcdehimnostuhntaceforsueudhnegcgjklmnpqrstuxfhcscdegilnrstabcdefglmno\
+        prstvz2asclmnprsxaoucnsvas2acdefghiklmnopqrstvxyznswabdeilmnrtfmaefinosuycfgilnprstzadfscfklmnop\
+        rtimeiu2fmnsafnvnrabcepuafklr2sresxlsbcdghjkmnoprstaceilmnorstwdfmr2bklmnortuorer2acegkmnoprstzb\
+        dghiklmnprsvzbcilmnprtueimouefgjlmnrz2345aceilnoqrtbcflnprtzaeieodhbcknsty2oucelnaeudegstilmnrvz\
+        aciscfnrtxeiuprt2aeioucnacdlmnueioiulmaiomsaeualnioabdenptvzioeyiontdueiaiaieotyatiorck2ailmpznr\
+        23423onrai23heg2rglmukqbcps2yiu2mbmnrtpt23lmblmnrsaeiuayfwx2aeaucefkmpyaeoyaeiotuyaesaieolmxoust\
+        klnrsuzaeioublnrcdgnsunsegmruaehludszglbcgklnprstehioyarycmnpseilmoruglmslylmpraaehilopruarsaicl\
+        prstilrxseilot2cdksacdlpstuxaeloruyioeieyds2flmrtbnpvwaouahounoeoaceklosvahafiymoraehoaehehiomsb\
+        eltnrtzailouaeiktansaoaehilaefioehrulnckmryfjei23uyaio2aioeoeglrwaeoafaeioblptaounzlrafszcybdr2a\
+        dpdltx2euychiktuckluyafilamtujklnotaoaefhkltuadeilostuwabf2cdgijknty2zabefgikmnoprstyaehikorstua\
+        cefhmtzalocklrtuackforscfgjouaovdrhkcilotabainstziuabegknsyikspzjnprtglslnsublreorefrstaefnabdel\
+        odfiouybcfgmpsteoydfmotceoptcektucdhnsacpwntbcotuwef2rtachaoyorufaegor2aklhkouaegilosabadegimstu\
+        fjkmrtabdegrstzbcejostahtblr2liuacfwozcdhikmnstvzadegbcdeglmstvacdhlmnos2cfjspzblocehkrgijoaeoak\
+        beloubp2cgknaefgiklmnrs2bcfhistcosty2oz2iyairhotuademucrstefiiulmntacdeilmpvxyzabeiopacdfginotuv\
+        zaefhiloprsuyabcdefgiknoprstuycehikostuacefhopstucdlaeiu2mu2n23aublelbefostehaceiouvtuer2bcdefgi\
+        klmnpqrstuvwzbcdefiklmnorstvwyrtabcefgklmnprstwyzaeoacegiklnprtuweioybcdfklmnprtultaoaeifibecego\
+        pcrcgpthryctcdfilnrstuvwafimpruvfmnptvafosuvwstpscdghltabeirachkou2aeflnxfonoeofmnbceilopstuyabe\
+        imnpruabcdefgijnopqstuvfkprtilpruabdefgimnoprtuvy23imstaehylnrstry23acfilpaiobcdfmnpstwyzademnps\
+        wcmnostaceimqrstwfmsxzaopmpeiolmctdelmptabdu2adeijtbforuafinrtptuilostzmteoounxabagohrsaiobcflmp\
+        stzaeehrtadou2fghouou2fgkmaiuamyafmos2adgiptwacgstueahloepraoyafyfleuaceikoruaeuksicntrsfiptaeim\
+        oadgistbemn2cehikmpsyiuesio2yamlncdglrstuzbladehkotyoruy2notzfaeieudtadeflouzacgkocthiloptydei2a\
+        bcehkpsthitzaeiyelhktuemor2kfefimortioacdilmopu2iuagkudfraceflmosy2hiahjkmeacfgikmnpsuwydmfnvgmn\
+        pswgkmsz2naektlrtbdkmdeudmalo2adirutuce2eru2i2swegtdfmthlyioiotmrsehor23nty23elcslseheu2wlyew23n\
+        scnpuvnv2cklnalorltahlouymnuayaehoryaiosalaoeickm23aaeoracrscdeioycdfgklmnprstzcoeiunskmn2anodgm\
+        stcvnstahaoiouermtaoehireinoryahleiaoahlioepnr23hnrlucdlnflacdnprstlwyauirmvdfaelrueistu2xiolnra\
+        ealsxiu23acdfgnoptaimrchiaehsnxaeursauiydsghtbfimu2einofreimrsuzsuiu2zmnsnruotadflaefilmosafgino\
+        stntaemscht2ai23zbgmostuvrxgzcoruwcfkmntx2bcfilopsflhudterotdfikldtdfltacefkmnstuzaulreicegimntu\
+        zaeijkmnscdgjknstzbgnststzkugi2siuaclmtabeiuadgi2bcfioyaeituaouzndgjoafjkrweiiu2abdegilabemdegij\
+        oknabdfinopuy2hmpstwes2cmrsatwaeozeiy2hoaelsaefmsadeikorstuemptdk2aemsest23aerzrtnstuos2anefgiyg\
+        knzalsamakx2bcdnsubnofostbefimstvotcpfoptwcfm2dffltabfloycimfadlyadefgijnodefnsinugshirtyad234ab\
+        cdfmnpsvwyabdegntxceflnqszstulrbfmps2lcilmnrbelrntadflpimbdnpwgjknsufnft23zisilprsstaojzpsaoulra\
+        eiehideijgmnritbfbdkraaehu2aefilotuaepuabdgijkuzlrhlptuabdeimnpqrtuvahistacfizcmnstaeuaikzazdlpr\
+        tvahtegylmafkmnrtehteilmouaeioscdgrtwatbcdeimnoprtpqaemaeotadaeiaorlru2aoikiouaeadoaeoamo2tcopcu\
+        dffpiklouadeilmoyeoubcegodfgklmilpalnostehopacefotwmnrstaefi23lnosl2acpiluabdehimsdktaioporabstw\
+        ountelaio23englpsaehinopcxiobi23234fwinotns23lmtlraoiuxaosaiucgnuaiordntoruaeilrailueiouyaeilruo\
+        uafhoiuctaoeiptuaeioreghikmaednio2ysikqvdstlsginqrmnaeikubcscdehklpqtaeugxakqueoaoaborginsafiv2a\
+        tpouiygnkratueflnof2ajm2cgjnruflksloiugregoaefluay2japcgtzcytlgxaelouadofieiubdjnrstziaeabegilpw\
+        yabeiopu2abdgjkortuwzeuabdelortuyhtuaehostmrczaiosaedrsiukrsyalp2m23dnacefmnsuy2atu2u2astflmnrst\
+        zin23eueikluklmrtisabeihkiouaouaiuwydiloaeo2acfgjnosyruw2apugimasacehkst2fkclmnrteprtwzckpsbkmno\
+        yrt232afcptwcfaf2lrsu2aoyzcmvefuaoyaelu2bntaeouabehiklabimnouabdegikostklrdeiubehikortuy2ehmosae\
+        hjlyhklmrbeu2imnenlnosbemnoprtuiuaufbhahiuailtaeiabdgokmnotuah2ezel23hciklpzerbtiydghybdeforuyae\
+        iotualytuaehimoaehoabdeipcdegijtxzsuwfilpsau2afinruvy2asteistudnrarnsbdopaoyzdfmtekhtyeudraciuot\
+        23awyamnoaijostirtho2cdk2a23i2i2ghzbtber2aebdnethnunuailyabeocdegkozaostuwaenuahotuaefhouioeuehk\
+        ouhifgiopsaoiowgtfgkmnsbsairu2sefiouikmstckkuorahuftabeimpagu2gihtez2a2eeoaogxhtmrsachikqruacdei\
+        prudgnszitaegimntuyabiopsuzdklnozloaiouaefiltuabeimpuz2abcdefghijnostuxyzhlmruauaquabcegiklmqrst\
+        uwyz23aceghjkstuaehrszcmsaklmsafoahzlntwhoairtunpr2aiesbdjostaeiotbeopaehiopqstuacegilopsuvwehko\
+        tuaehiorsulrsu2neuzimscehimrcdflrswamorv2afiklruioaceghiknostuyuvaeimrabceghiknoprsthinopstzoueo\
+        t2neorz2i2hkueioufawaemaoaeikoaeioyeimosz2aegikmopstycdfknrs2huabcefgiklnptuwaehloqshilostfjst2o\
+        ezdnp223oru2afhisffjlsaiehkalotaimadgiscnadertuacehkloqstuaestulshueoeiohpbcflmnsuimoaeouaoouaei\
+        opu2ntaflrzafioeiyabpucdnot23ahacirtwaehinortuacilmpstaeiaybemfrtabuekrtdlirfgsorct2ltcdfikprstz\
+        eirefoitfhsgmreoubfhsknreschkoufleghorukufgabipcejcimpehkqeio23hiiloaiaouaeioabcefghiklopswzbdkt\
+        himrefhtaehiotzmvaeu234aceehlizirlxbemactaces234gueihktah2aeoabfslylsx2jaioouiuallraeoylmacnwiou\
+        bgmouaootisdrfiwckmpghadghntswchou2begs23fghkmnstpgnackyabgsuvhpiourthiois2bgumnpseohsahshklnr2t\
+        aiahnsuornortiuah2aaibghsstchlmnpqcdhpstgkaiai2ueirkmemckmsaeoadgbplnraocltcmzainacnvclmrtbkszfl\
+        luhkoszadefornseiuauvlnsuwzaeioaeilmpsuylpuacdefgijnostzlpaehiopuyadefghikmnortuvachipstaeiortuw\
+        anqeifntiaeotycfrschkotaeiru2degaefilmtuvcdfgiknostuynreloptdtacefgimnoprsu2ahsaertulsfnceglnrse\
+        lnoalzeoybcelnrstuaeolmstzdnz2aceikot2drafaefrspsaceilosuaelmaefgknostuwxyefaioacinostacfhimtuya\
+        eicginstyabcdinostuxuvwfmnrst23ao23ahkaeiopskshr2noaeoeilotuyaefmopcdegikstyflmrailopciknoprtu2e\
+        histafhijotuwcfstcdegnswxacdefhilmnopstvzacemnsuvabcdefgklmnopstvwx2mnrtatklmpu2dlsagclioyiosfil\
+        aeu2ilpvems2acdejtafpudilvsaotzaloioaodgpsaeioryahlyaelmagtgs23dfilnprstbcenrtzdflnorvyzirtdsabh\
+        keiufisfgmlnoyehuiouacdgkquaehipfikptuafitozaflmsaeluitbdfgicdfefouaimnucfmnsaiefo2degimaeilrtui\
+        uyeir2bcmpdmnunpzdmnbmntaboekteoafueiisaoufgaiohoptaoraecdsaoafmo2daeeiemos2aemnudifimrsae2abcek\
+        tuahilotuflnrxbiabilodeyabegudeiegociahstfhtukt2hktmnaelor2abchkrilocnaeouaodflsakuaeimpstuvabei\
+        lmopsuacdgimpstuyflahipsvacdgiortuaehstacmnorsyckrtirybft2hbdlmnprtuzaeisaeilmnoprtuvwfnrwaebflo\
+        prtuaeioudfmnprtzioubcflpqrstuwasu2adehotuweioucdflrefidfnyiobefkloaeipu2abdeghioprstuahptuaefio\
+        prtuvotaouweietvgratbcdghiklmnorstvwy2adefgiklnqrtabdfgiklmnprstuz2ozcefghjkmnoprstuvwzaiouaeior\
+        abglmrstaifmahikudehkmvulmnbefiklopuvycipuacfgikotumpfhdn2tyhkouztzelpdflnprtloucfprbituvdeinvmn\
+        fmlrsglrdlrfkru2fcgikpcfpzfhoqwfqfwaeikmefty2baeu2adefiouvaeoagknortuyhrdfgirtuisthoflmnprsvknoc\
+        oucdegnrtwyzaceklorstaeiryacfknrstaeiofknortuaeiumntaimnbcdfhilmnprstuvacegilmnprwafcfglmnprabck\
+        lmnprtuvaeioucdfmnrfglrabcdefghilmnoprstuvchkcmfgagy2tkucflpu2aimoptux2afipryehpracfgiops2hoqtyg\
+        himnrtzcelrmnstzorhlvbpacdeghknostuint2eiluhonpfos23abelm2lnsyaekloaceilmopuwyabipacgiknorstuwzi\
+        ntehaefgioprstuw23htu2aestuosaeiniozpaecprshptdiuafimunsaeiuaehilnopuabeinpacdefgnostuaehipuaefm\
+        oprtuzlstaeorsiklnoruabceimorstacgmnogkklmrueioulmnmrgkacoflafolraklaelabenpuacefgstwosufoualmou\
+        eiinaffuaeuackoaklotuabceimoadfgknosuyflrtaehioaceginoprstuhiceotuacmrfnsao2bcdgimnptuvyaefghkmn\
+        pzabcdefghklmnopqrstuvbciklmopuwcefgmnsmpwdmrlst2eft2e2sbckmnrtykrtaefukuaopbtu2afginipuabcefikn\
+        ruckoersuelklgnyaobpehioaodpmtltuhj2cmei2lrseyir2beior2au2lrisuacrileucgpsxinerahitcemnoueheoaoa\
+        f2bcgns2saiai2r23hu2couiueiluvafeideiucetaefnrsuru23glnadlosuvadiotuabdfimrstuipstkot2lteruetu23\
+        fsvailepcdtyeoaegitu23ciaekrtyzs2aeacknosuvctatauaiozckaoyaflnrstafiadiklrtepadgtaipficdeghiluyh\
+        lpaceuhwagzizklptvbefknvmstai23eoauaclnprterfglmpstzfmchfgadldegnostflzfovabimxydstdfklntklmrsta\
+        pkw2titioaiononqioaoaouhu2auhnuou2aikotubcmnstadgkoydidiraichales2gprbis2h2n23biaoudtksfjzeikufk\
+        ntz2bah2qrtiolneoabdrbknrsouabopdftiodfm2fbiallsaemoimnno2aizbrcegacagoucdegnpxhzruaiofomu2ifigh\
+        232bcmnpduiuahistvwetauadl2iudtms2itaeaeoudiouadrvaouyenrinauauahitaodtacdmprstfn2muapionpaioups\
+        bcnptckimae232io2345hnfpbcf23htouelu2dl2amrsnqrtdrgngmrfsze23234hlniuasaloptnrfmgucdsoprs23h2hsp\
+        pr2cs2inhoweoionsfltxlsaououounuioyaouaeiopowioaiuoualoueoagthortcgaypselnrbgnsioioeoxaeabhlt2pc\
+        fhlnprseoruabcdhmnprstv2i23iteiiououpsryeyoyerlrcxiynseocghi2eacdefimowbsaeoeiaeodmnt23lcr2nriop\
+        soudloaecms23gkptynp2i234gisdsmrenzioao23nrcm2soucscrx2bceglmps23aao2lmnshyaibfoprsmsaohuiofyju2\
+        iorayfnblnutajsaeio23auei23aiahaefiaubvwaoeochiknst2aeinsiuflsudftzaeiootlcdjlnstert2aobkakfstae\
+        l2dgefalfo2jtot23aopsy23eyei2aeio2kckahvwdz23232iuhotao2epehiohobfgeouckaio23cditylsbdfocfgs2dff\
+        pclaoeufaleoeuktgsveieiopfn23ahflslmntxiyae2hch2bebpormreloeotuchitmvcktzisstaoudkmstegial2i23lt\
+        choctzdmei2chsouaiouioeooyouaem2eoaeioyoueiegraefiacibepkseiailm23ef234eiei2tuntzaeibdlmptaeiuen\
+        cdfgmnxiu232rcpxeiinsyioorsaiaefoulrerfweouenaehirszalctz2caueihi2eeoymsaionxubdeneiycl2aeioueou\
+        efoaodt2insefaioelorbflpswabaeio2aelyaeiodio2lafoprtmnauybmbs232eioruehostaoubmmu2mcxrualuhkmo2f\
+        rbt2nraikluabiopgyaetu2aegimorsthimstaeifraeuazhk23452ckstaidrsagioeorsuvfh2rscuahkceiraucdeiklu\
+        aipadefoteipsioaiopdsaraoht2aoaeidgmoystadeio2nsmsmnaohk2ecr23arapcnrtdo2nrdtnslxhillmhoueou23ou\
+        ouaefrkoiounrfdtamgoucsiuagioefeoiubfouchfmwouyailafsy2peioeoueiaomosbstaeiouybnrsiouglm2lntfior\
+        cdaiaiuaeouehoeouysyae2eioryduaipsteioptaiornseoeo2nlwmplnaeieyeioulnsuaeiouei2bdmpzmnpsaiuaclcs\
+        tatctcf2hk2sbp2iiuoycmteo2klsaeucetapteo2touao234alst2fsbattveoctchmozuxaeuoueintai2mscslooufhkl\
+        mpswhp2323taieopscmctmskpeuiyou2royioyserfs2ehaikndfhmrstamndmmrbmisqrvaiobghrudgnsrsbnabaicdaif\
+        paoeianiohoounuekcyptmnckauy2d23ckouefvouwzmtou2eiossteheieorseoymorfporairourslooraiy2iknco2am2\
+        3dnafcn2boxulpdsyaoao2p2shouftmnae2ahrekwcflmououflophkotf2fpw23cnaei2nop23ioy2rdnu2pcmtzbohpai2\
+        32ruafstaegrou2mauouru23ereoaioeiolmaemrcfcdmnprstiycdhdteolrmpszeoioblmouaehorueiloaioaeaeihlue\
+        tuahoyaolsgy23ioer2lp23lraiopsxau2yikgpuxehoouauehaioaehoou232gflmfjefgos23tiost23rznoewacoautor\
+        iseh23araoeierch2hiaiou2342c234aio2rlykrpv2ioeofiuckgncfnpbf2elwfiuepsbeimhkfhlulsdfraieo2ep2323\
+        csasfstbefmpei23bcfghlprsteiaiucxsntnsaoat2cgksdmzem2cucheo2g2tzazsthtno2t2s2ndtdgouaouaiouauaug\
+        s2sklneoinao2gnauanreiouaoioaoau2lu223gsacebfoszrsaeiouap2bleioctauartveotuzaimrsilsseuaeaoeiw23\
+        23abghnslyiklfhcfaoufwbms23tzeuiaeuaoaeiyio23f2efiydgqfocdfstbwoio234nsfmpsadnouehaeifldnsazfflg\
+        szcisznpsouaioaiuyai2naou22mitenepsdbmrspbd2ens23knei234oen23eao2ao2haumn2fm2n2ckphlpsbertafiou2\
+        aneucdnrnslsfmzabcfkosy2ifwaikm23houeo2akmrstiy2didgmaogx2ersikiuftbp23ae2hnr2acfklqswfhleych234\
+        cslsknpabcmouxaeosimlntbcdeghmnpstvpt2gioaoawrstaeoei2eioacdefhkloprstcmgckstltaoaokodj2ksaucgpg\
+        klslsioeufiyiobhptbflodeilnobwruioaofilswfphm2iewhifaugirmp2h23au2n2zascmnprstxziochensrxaedgklm\
+        prsteu23bmfgstfsosai2eoucpimvaio23eoaoiystiltrtuyeio2viubscfxcguvcnnuchos2u2emsclgnaraio234cdhmo\
+        rstvw23ai2sgio23hmpwcplrfkoseois2345iw2bfpbgzho2345eiis22aegkuotlyopainr23csftiyzghmrzaghnpwmnzc\
+        gptfjmnsaimpbdisthjeln2lokniy2ikiyeilyijnptionszauklzeohkns2cgkmnrsuio2iughn223hioioaegkdfab23ha\
+        iiktiostuiw2knslomsiu2newados2rmyujlwdeioabchlndtdf2ir2h2hhikmaoknsaeeseiilcsststdfpeicft232f22c\
+        2iinaeops2jisyszhodit2nszaiao2haoruhiiyisy232akvw2nabmaihn2aeioaoststzae2n2ehn2nrry23iiklbkst232\
+        323ljk2h23uy2lrwiubcalbenpit2aaiuadnsafoufrtadfgpstudloehudl2ngxoubrsxfgns2aeaublnt23eonoaofow2f\
+        lpwns2owcsiumsfviuiou2ncgiomscmnsdjmltacshiahcraei2crlsdtoyounrswas2cou23nbms2345623efcrioprulsa\
+        fo2ulms23fmsailsuwyfo232afaigmeruefiiufodnegmnscruilzabcefglnprstval2eaioatao2nioaeiujlroubrcuei\
+        fh2morsnr23gycwnsaeiumsiouaiordshtahoaijoruafaeowuwdfmnp2keieiorbsapcrfmeintae2cmiorcet2aegkseiu\
+        eiy2glprufieiueioucdgit2feiyraoauauilht23dsuwaiuioacfmuaelsabcdfghlmnprst23odkmndsdmpaebnhinuden\
+        sblmrao2achtasuehuewantpsmnoruabcefgnprstvnsz2bcfhlnpstzgnpaouycfscdpsaeio2rmzouiaoalaiiokq2s234\
+        5aeaeltfp2efir2nhocnabcghlmstu2omstaeocdeuehimer2ioaeiaruaocluveihlpeilrcoulsjwgl232haeciksfueye\
+        oryoulm2chmprsteirhimcfrs23fntxlnnosst232aaoayflvrszhsw2oesaeailn2h2342eiknawotaeinairu2oei23aou\
+        arehioeiauaeblkswstaosu23iyagdfpr2clmaloeiaeaeianrspseoacmsz2im23232aswsvghyouhiadfghprstvzioaoe\
+        uouakalathjrcgaoiyefk2gpaohydneio2m23ehhpiofoeiiuaioulr2oae23euaeuflaeahouy2t2kiyiniynplskspstah\
+        orlqsteouelrehpksmteloriubgstftcptaoulmnaeiougpfnmtvfpahe2uiustgq2saoaficdehksthmnpv2cmseicp2322\
+        aknasao2rsru2fpsost2doiogscltaieiouao234hoae2bdtruinoranck2kmrvwaocgprsio23iocmes23ekloeo2rahrcd\
+        egintuwzaosmsaiaefi2eu2dls23aehudncgmraeaeogntioucnaueiuprceoraebdglrfkpwlnsaualoubcdfghjlmnpqst\
+        eoglnrsouensdnt2ohrzhiabeieiocklrbnacptxaeicxlmdgraefsmreiuhlnerclopenrskoaeaoiuoucelnsaioyituae\
+        iorylm23noouciceintehiknoqzeoouabcdfhkmnopstuiuaiknsrteoekiuhrscrbglyiychn2eaunoaeletmoyemaoylot\
+        2zbgaeiaontegnueoioaiiaelocfnpt2iko2nceotalsi23aoio2finouaonstmskxaotoufoauaoufrtcingpszeiopefpl\
+        rtaeucnceiktumteityotiuouhsoraio23gtabuegpaeocnptfhkbptmymtmscostaeoueiacdghnopqrstdt2l2ldlaeaeh\
+        iiucf23fiuae2dfvaoiuotuwoueiaceimouykqrueuacefhilnpsstwtcmnpstaioueicper23euaiaououbhisaotnronpe\
+        odfsbgptctaimvaloehotiroueuglryeiaeoaiouyaegouefaehlorycekloptaehioeiou23haeio2234odopbmpstgle23\
+        mulnloaehtaeiaouy2finioailmntxdftcsiykq22tircleiyruktmsertfnceiilacdnoqtz2345tlpsafacfmoux23bwbd\
+        lnseou2eiolneiarbw234gsksfyfveuouaiafbfgsstdmoauizeg23vzbpeoaiacn2coueiaeio2ioeioeiouhuhofr23rai\
+        2lfgtrtrscftiyioiyeoofoer23lrlmnfhosist22el2ae223iy2ikiy23illrfthoufuaoubpbcfs232ikltzrsdfmcpcnv\
+        x2eaiafsuwdmaojnrau2szjsyaoafgailouzetioehiuaehioiseufpslmpouksaoenaeausypraybiadeoyipdshuaefuot\
+        fglmrrtzcetaeioceilodfeoltaeiaeoiyeioasueipospvbfptbfmeoilaeituz23szaralriouow2ntylnrrslqi2hu2ut\
+        wcnu2eaoir23iabcdfhkmnopstwaeiiruehio2aeifsaieioueifp2efmseiuaiucntrtlmnraynt23bi2ckoru23aiteouc\
+        habeimopu2dgbmikhteoktftfpt2dfl2agabeimoprdndoerikodiljkwdijklnortwzfwaitaoabntaeibfyaeiokuagptf\
+        hfseiaebfefopf2felfehaootnrae2nr2aaceoucq2vxaeey23aehukxao2eermrlsszeluefjsziufw23ctfgltltfyel2f\
+        kziocgnpteefm23bsnraioaecdf2mn2jlthtyiyfvahiogkmntbsagp22ckeiotwaiagkhuzzkrcitumomocegrxcxauefad\
+        eiouaiofhzenfdgfklnfkotuftntdmnrswdlrzcyaou23porekemnpdilzehbceforadmehiumpptcmlnbfsaelocgmswcei\
+        nhkf2teiluyeiufip23ckaipao2bfwyfneny234kptadmnptwaeilmptagknxbgmnpuas23fiafbioulprtaouahlorcpnre\
+        uaeumnaeioaiouprhoreocehpuaehorclaeuefauhptw23234ikmeoueouaeu2tacfghiklmnopqrstvwae2hikhtaftpteg\
+        ofkzfsitaoilhoepxehlercdnvahaniylsafs2irisy23uwaeananiuarsbnstiyiolrao2iabeikouyafioaeituaor2aif\
+        inxen2fqouauaoueuhoereofir2afdnanchal2t232mnuabcfginostiostoulu2eloeioeuweirsiofoikt2nalaoiybhps\
+        tbdkseeiaeafmneieiaiunrntaioaeilwagrtnrtaoabnlrtaimszelolubpeiouain2saenalpmwarouos2lftio22fwrsn\
+        siouen2ln2piyionnyiraicntcfesiokuvzaioae232ahsrstz2nnsdfhjiknau2eeorlsai2anaabnfsptaehkteiwehuae\
+        fmksio2saioiufaoefoscknrzeiruaehloreounrsloaegodlmbilenkobdmehtuaeaekotitmnaru2eilu2bfbiahygstaf\
+        epckdieioaeaognaeiu2addoah23tfrslslt23lu2f2flqio2nemoho23ltpsafcnckfn2lmpdi232eagkmau23ae23anaib\
+        cfhklprscs23suaimthy2horaiueiuaouiycoxiuaimrtstmscpfltaedrrulsaereiahipteiauiraiioaiocnaiuaigmai\
+        uao23lxae23blauauou232ei2nuops2fgns2fzcp2rmoaefu234aunpjwouaio2hrghzeiomsepbcflpswegtyfpbhdhauef\
+        csbpe2ryeizmyefaoaiopseufks2cisacyio2cglrwsw2psaieoydmplnfapspsmnsnsfpsan2indsmtmnt2tzaoes2ckbip\
+        2afpflst2hdminkthi2oeoai22h2yhqhio2nrdz2eohl2e234567892txetaelsou2emps2ioloiouimtuacflmsiormsao2\
+        touio2be234lmayciap2323iuaiuxahcneoemoptmzbnosaeist2ai2aecpbcefklms2gn2g2342342loiuaudulmrczircr\
+        loeueioaeosceiuoreost23iyctiu2eeinprucmpeu2t2f2r23ioeioaotbnps23ou23iooueiosoubcntaeem2rsauahrae\
+        et23ltudeiuarbdaosuotlnio2fglrns2tcniuaooseiai232ialiu223fsnrlmpqty2hsztyeuioaufman232kei2rfmw2o\
+        t2ualrtmnrs2aimyaoaoiw23fksfoioauiu2fo2t23fcpsry2e2sfk2auiougnrouaust2dgnisilnau2ilaounoeoananot\
+        2oauiumpctnost23aoufr2ghuetdtns2bhpcseiliourainsaoyvaehnpttczerao2sy2loaofpfhnzmthnmt2eirnrms2gk\
+        ndi2aahoruds23fr23aeosckau23etekeu23iauaesew232aiugfpkwaymn2ivweosz2323otcdpsouaoagopaosaeiolnns\
+        22ch2hfpcxaobrcnslrls23cslsetdstougxalbgmnsbsermshrbgdgneu23iueuben2riou2ac23stctntprt2aemrtcmrs\
+        t23hsnrclraerdiknaeoauflat23ctalatctai2p234lsae232laeerfmdour23cfrsbcpqrsthunsorcneodxarsouao232\
+        i23ckaefuvaiaohiou2iir2tcddn232dta2ubcmnsbn2cpstcmafdhprfoaiou23cnbfk2iu2iuaieiaenoueoraoounsiom\
+        nroueoaeeyeuhlakteh2piioaompcpeouaiolnreinrstbcgdrtuckaeoryaeobnaepraecek2pei23p23cdhsval2llsau2\
+        ireicmvabeglmstxeifntfchpwiocl232ioufpiouaoafoaimsei2plmaiop23t2ssgwdtcgnsbn2ntacdet223lnrtlthrg\
+        har23hmptnr232aiiulsiou2fnsfoiayio2cmolmfsdfdsefis2e23cfsrtnuaouptadu23awlntps23423234ahuz234hlr\
+        iuchcmchiuae2r2323dsau2e232auadiuhot2ibempsmzehlodlaeoctehmpdhkprst23amor2nnsaeiobcdptnschdmaiiy\
+        iogmpiooukrtltptoueiu23ioeofpsw23aeyeoltaoeoaobgimnoprstveaiaelaeihltchaorahr2kdhot23nuafiiuaesi\
+        vaueooufor23ei23eiaeaecghuirnt2s2ecuaiuy23io23chnror22asz2agk23iz2ghugadm232323afaj23s2au23cniub\
+        n22sz2ai2323423kn232322kouiukmiuouio2323iueuptauae2iei2ns23iy232salaou2fkpsau22t23agaoudesiootlr\
+        gmndlahloailuouepblcghaeflciai23ameou2ock2tlt2e2nrzbn2kns2g23lu2ns2uai2honzaciuou2ln23cdftcisorh\
+        oacox2h23h2o2n2h23fkptoumsao2failnrpsltenuloeoeithrsepaoirgxueiei23lwchctlmmpeoaeueihlaeactahiyo\
+        xaeoreuiwaeaehlorcetahiuao23nxaiopt2sotnp23h23abcefghjklmnprstvz23aiindghjlors2k2e23aoae2ouetyei\
+        glileiasinai234iu2323ao2botegfohrf23232f2df232bfgpvgrfvgvou2ltgxflchnasawas23nshrtuhr2eoiyntnrho\
+        ipbdmt2t23cr23423dtbpsz2lntabcefglmnprstv2hflhniou2jiuiueiaulmriupubcleriuisdmeh23andoadtbds2rld\
+        stlpacmnsaielrouciunu2iuoscqos2cfhlrsddsuai2lrkoehstarkncknmuioioaoilaleoreouioflalnriuaeilyeoae\
+        tuehlrstaehiptyctstdgmmr2icnzprahauaefntaueinseoeu23iu232rt2elnbcdhknprstckmtumsnpntknopsltahory\
+        ayeudeisalotoptyhoruyltae232aimouae22enraouckgxnzmnstxio23fymnrsadersrxbnsxnsmsae23lr23flrf223iu\
+        aiah234hichkobnctftaeksuaoafo23fpserbnsgt2ratahiaoabiobmnraoortuioctouloioeuefiomtiloualounreuao\
+        uoueieo23rxnxlsbp2ieiowdtst23rsorioaounrbcprcglsot23cnouardsaoehioycnueibdmpahymrsnuaeosubceghmn\
+        pstvzdn2r232oabcefghlmnprstvx23atiolsoufi2dsouaeiouaeiozairueinznveiaioclnsumsfsimsennpeo2euaiou\
+        mslscpshmcddouhiscf2couau2oulslsr23eugxeicrs2caeaoir2uam2afasainrcfht2dfifnioaolsaeizoelzaeaibcf\
+        p23nrmn23ot2su23iuiobgpcg2342ckhorulmarupuaennis232hmncmamlsehai23tps23nraohy23ahouou2234567nsae\
+        a2fiyfhesdu2fliunyffck23gs2kneyiumnaiytzkt2323423ae2e2342fraiflsoulrhorouauce2iiy2323iou23asaifi\
+        araou2aooulsflfsltftcioaeyptfisubfaeuekyaerz2faeobtbo23dsaoiuhrsz23asdhwor223aimrbfveou2aeofpu23\
+        lns2safip2ctir2opeioufpeibctef2auabgtaiughplsephtlreionrsxiomuisuar23dtmxrtxeyelblm234blsiynxaho\
+        ioeucnioaoueocsehrcehptaeoeioaicmsou2ycefglpsuaueoaueiiuotugxeorgx2mnaoaw2345hm2345stlrbu2uei2ps\
+        234auacet234ar23eo23rouionv223bcm2tnt2nr23423lr2rtcs23abcdeghklmprszbmeuiu23eoououcxnsoumsahipte\
+        i23423ioiuacflmi223dr2alaurydycouaceoyauoslseghmpstvwymsotciunprioionslraeioahpioyctaeircu2tlsce\
+        iraou2lp2iukqkqindotmaooueoftmyaeoukqktauhlerchipt2cyaeickeo2ciousximbxcgaihuktosai2giuaoaueifwa\
+        hty222sowor2npbfy2h2h2h234an2ouetogmei23pt2nounsai2raoounpdlauzbmptzaioay23aoair23eiayacaimnlrao\
+        uluaiaocu23ouctlnaitymsbccseuer23et22r2riuaoku2f2323aleifo23oucpta2notilmsnueieiioiu23aibnouchnt\
+        fumsaearcxeiiou23223ai2342sxruehu2323hrmn22bgmsmpouabcensu23afeiuy2iy2hoptcsaimsafioaiouiobcfkln\
+        pstyft23c23bceflpsthpaheoubl23euio2tiaiiudm2tczcklnpaytuotou2eoercnt2elropmrclmt2huaodteiayaiiu2\
+        gs2eanioougi23i222iouilptbempsbdmzhr2342342szptlnrtotcmno2323aocneousyciteoayaeaedhch23orordzgx2\
+        23222ncn2t2nch2pt2raogx2laehsmnscnougk2elr2snp223protaoscdmsiooueilrxlraecleoaurxio23eryauaeonpm\
+        z234523dltaiuahoiuailou2alryaeayiueouaehloraeoehioptwhriooy2paunritmsaufsnsckiraeir23eo2amohyioa\
+        ley2tstaeaoahlayappugnppuioiuaolmaecrgsrsmnoscstouirnu23456789ehaeeii2323horerottnsnsesoutupueio\
+        umroyeigxpstioaoeyeuu23koiyafrpt2lei23eo22os23dtcntoyioei23rxcuds22euauemnp2almseoelaueihscptyhr\
+        irruehoyaelauyioyaioyeuaeilnotuptuahy2gnrtcegpvrtmnaisuaubcpos23hncisuclmiuior234aoou23aio2rouau\
+        2323aiei2an2i2oh23h2232dhn234232imslpctauttuaioiu2cmchmptaostfoucvdr23cuhselptllmepaueilrntxnpue\
+        itao23ooueoao23423456h2323rucnhoerehdlmxaimrhortyioygneoaep23iotaiuailoaiaibdefp23eicmns23aeneia\
+        iiou2lsisaymtareoaooxoxux23npz2ens22edrce23aydn2tmtaopuiolruouercpt23fmp23oyouox2bm223al2323gxst\
+        ei2232totcevnsclaistcsnrcmnscucrlnrptcrteoirouaibl2eiraomsaoaoio23gx2n2ot2aae2t23ay232mr2232lp2i\
+        uehaeououpr2o2lsclntcxnriorarnsru2tot2bmsay2hn2homs2hliulmns2345lnckosapltio2msaycelesembu2tu2dp\
+        ougot2",
+    b"im2a2s22hajsa2rffsungar2ssfsysinkairfwyxps2faupasuyaluifimjilbang2ftfsfseffharea2nsfesssmoc2erty\
+        hywyofnj2fesrsofvsfzd2csfghur2i2rfatehssso2lw2sfw2ffosa2b2htotyijovkaeno2gfssccsi2s2manozzohazak\
+        i2otanumcco2akh2e22lnis2erson2enocortex2cid2iniumr2ajhar2erlifeada2adahorapanthi2u2a2naspiessss2\
+        2orusidoka2au2riniakuyu2usmaoakal2ionsarterperson2umdium22eoniumiomannfero2age2itsuuan2dosachs2t\
+        yousobranchoea2aqal2auaco2eggio2uebus2s2itasat2kamekwasanma2ap2itan2as2har2ocatol2iyyahhar2la2m2\
+        h2lu2aq2leamumridiniumec2rdmantl2igar222lliumforelifethawi22ael2tani2oon2nnium2ou2bo2irgaa222uy2\
+        2agilfulfc2fosffldingful2namano2leurumzachtihynnifebusus2eful2on2eful2uetio2rsscum2api2a2lonese2\
+        ffeobik2batta2areryivoss2ne2smisdaeworksuille2epu2onac2m22ff2fniusufrakfwd22litoyddrowitzchsanai\
+        2mon2tai2hnephorosibao2s2kji2eikowar2noaositumrthgiha2umambusethylhyundika2yo2da2eplumnstairslum\
+        as2ou2fmvir2lf2kbuk2ashisfysmenopolisema2hy2ma2echk2o2ws2noon2ak2satzgruppeteddfodele2elear2wife\
+        f2opsgium2is2ifetronriyji2f2sma2gma2ightenedmippus2oon2nixilon2lisllion2iums2u2ine2aiatz2ianimph\
+        aguiedum2hareottine2og2mon2aredronaryote2olpid2uchpisthobranchaxonngelarium2onerful2edra2adiusic\
+        catifes2irfe2fottiao2nsfoottherfoot2aslat2urabriarimanesies2l2inichnionbaonessografl2f2sytfanshi\
+        fozrahapon2lscoilauzik2fssss2ko2eochsko2imerahnbulji2rush2inkamarirnata2po2decca2oretna2rs2sl2ei\
+        i2eninairi2sywna222felhupfayna2ar2jatiein2tnahja2jfrsumenosat2refefeftus2kmanpi2b2o2ddeok2lraumv\
+        na2mada2vniassfou2inasi2ara2ridoma2rnaroma2aeosaurusatern2xacasusso2efu2rot2irhaat2s2ymorato2rti\
+        a2eritrix2on2uriaoltino2oantesa2c22miuqgmiutnarchola2hhmus2lmen2yphallusngotan2sgumi22mantekaya2\
+        ava2rilochkoffbfylful2maney2anese2melinfish2fish2zifadi2unlful2ito2aque2glypuffadi2bab2eio2znny2\
+        amachio2okuchi2adholaeki2ilusuenarium2tafo2moji2a2aniful2aeshionoo2a2auwee2ayitlder2taystdlbasa2\
+        u2ichoniomiilbash2ktuaenggwaritzhidelzhliarionakchuchsh2unaklle22idnoot2r2iheluna2umdere2aszttza\
+        ma2losgylbash2ri2sfu2ue2fd22ak2t2v2ng2hnfish2erleafivium2ardf2umentum2hoelife2susgino2vouai22oma\
+        22i2j2s2ion2eodiumoi2phniumkhara2iri2sf2st2enzoniumflis22hatoicafnga2ssiesflio2anerel2a2sionik2g\
+        e2ui2ree2ai22ngwen2s2scf2ng2ang2ful2aarnas2asca2koke2limu2hin2lohyoideusthe2ngu2n2svsuasarehar2a\
+        rgul2uka2dicky2umbiumatoehus2andige2ni2ika2ltrumee2fang2biumu2rkham2stmaai2kantal2au2awanaige2ri\
+        n2achalk2hffmagphmam2umkeectumlipmibunduea2itoriumumntomema2fnsoastichni2one22homageosoe2an2ya2a\
+        homannomiyakiua2riec2gun2eudsihalger2ol2osy2agata2en2oss2chitisachi2fucleuslam2peciesraaafssumop\
+        hagosimum2s2o2oiyot2eans2ihanariumachhox2togaritis2aznik2oboros2mplo2fish2aurterki22eon2ehss22fa\
+        sus2inphigusuocedanum2ful2xmrochoihoieniumte2iuzca2tyumoticutukawa2indah2hatan2nado22222f2srmiga\
+        n2frokamau2rpera2tskko22rc22dnonnon2isfizik2ikelbash2dush2eilbash2yzuzbanr2ipoc2oc2ba2guash2ghyz\
+        ylbash2anwin2iolobones22ms2onefiefnionese2erslto2fatonievo22o2kiurokubilschachy22s2yhnik2fletdek\
+        oicava2rro2rnik2s2inznphsno2mik2dahaninchellois2mbello2faffis2otzoozritzoney2eyuan2a2eslaw2anato\
+        r2iesumzoinionnkworksboda2hbootsrf22piumshka2s2s2t2mp2fys2aomanomanhmno2hiloma2ygium2kely2rif2qa\
+        nahgi2ssstotumchew2uesta2criuma2tiloma2finniytipsen2azquimilollingit2ma2dfish2acco2cata2dlekinss\
+        etsilok2hun2f2ia2edo2znsieshoplankton2ntu2ari22urmekh2ueleturt2n2ri2isubari2yousz2tis2a2an2tilla\
+        2ah22ak2hwethazunguelt2ulueliefeuova2btaeelieermenschokiountrykororoerclassperson2seoxatnanprach\
+        eext2ea22haripiaiculusuge2mitisla2ashinageineriagezd2oufbasiumoruero2dois22tispstakeseotcong2int\
+        iloncello2arium2ch2ic2o2kresnik22kauw2lja2ano2uselltichhyvanka2i2gwan2fmen2rani2eoffsf2gefish2rd\
+        o2digos2fofmn2endigoo2eoffeperson2eguyf2efish2erchildy2ckfish2ld2fderndoteante2eahouyuhcainonfle\
+        ng2oitzcuintlin2ftatim2manaa2hirorful2lamanbichai2asizhfbyos2nguutahertzgegrammenioisrbiumiumnar\
+        ie2ary2ik2drumzhuan2id2attruga2aha2rachino2tgedrumpolzgnqing2ud2gef2lijzea2colo2a2lus2otnik2ue2i\
+        2chinpa2urtblesrgspitzschenschachntomanblompi2wetganudaya2men2cedarius2d2znry2man2seusetaoshila2\
+        asugo2esignedzorokissa2ssusia2an2thhistosee2lerando2sed2halusmenesli2aulsma2il2ansmful2seusen2zo\
+        liumxyantinoma22x2ndum2ctor2gussthana2laasi2as2u2kra2oma2usasi22nistrrxitisamathanha2so2catusato\
+        n2ium2m2agusculagruschgma2nchyma2heticsmnetes22cionado2gato2esco2ionado2omanncesado2ospeciesdum2\
+        atummen2ar2ao22eehole22sa22rafttaffmneteson2ezekoatsubeahistosoerioneaagmossoa2um2tifoniumifeand\
+        ersjor2reacil2yclobacillusfreukelta2anylaluminxylyud2vium2lca2noa22lusssumarchtasat2iusti2r2iciu\
+        musiurumihperson2chi2erosa2bus2umreuxlopsisituhedron2llgo2go2dala2olapesistoronhema2orperson2llo\
+        stoma2ste2o2dotoncephalusimerontruslfish2oh2lar2ezlusifiniumhinaeful2losaurus2us2ectumianinianus\
+        2ersonum2mbrak2ofauna22tis2he2achee2sisejo2tron2osaurus2rohedron2la2dslsebiarismushaa2oraa2oseid\
+        iontrophusranionyomenosndiewifeggiatura2oof2um2s2rylachusoma2ozoan2auna2fanile2ium22aaipa2ak2kai\
+        2rtus2num2la2ntbba22ara2sara2lusso2taipa2rson2ullla22a2stook2a2wak2azcid2foot2akuni2rculuspexall\
+        piglotticusenoideuste2umruar2ukeristesepisnti2raheimerchego2rsragusraltumeniummblyperson2ish2cia\
+        hedronrma2r2um2deton2amanapa2anial2inganoss2ment2um2chacful2hertzria2rium2isabe2aufufuch2trissku\
+        a22rucheseexralopithecus2rchmguardista22ahtrix2andumado2elihi2a2arachiniumyestuffiumobacter2yski\
+        nshurlluseria2luhan2handsluslusttinoul2samasmanan2ri2reeing2cco2cco2iets2tiraava2un2uctumboschch\
+        adir2esu2amulengei2elousea2meerson2taffonella2mentful2o2etful2eliefinadoto2him2ish2an2iffgoula2h\
+        salmonduskafico2y2ul2on2oomfulhdropsummlf2ofarzane2a2oro2hildefochful22a22noisho2cker2le2amasco2\
+        oese2aglierelliumsiozyrka2a2opovetsoi2d2a2al22a2ti2y22tomatonle2iotheca2efpslano2iterionrroceniu\
+        mustum2htekseki22nusootumterian2o2ungsromansfxi2lestifful2ou2mencleusan2litzaciesas2hotolysis2nn\
+        isc2ridiniumcliffantula2hchildottctrix2neroxovanadiumrophaful2i2ersmen2kderful2aj2ketful2thharit\
+        szfish2kful2dto2bokfch2ful2tarcheworsftyr2yperson2facsrin2o2en2non2to2s2ia2ygarchgnh2no2sperson2\
+        o2toot2ebuck2s2umslang2anaful2orad2elia2sch2oi2ok2hnova2esman2zokuyroslik2geois2sh2l2u2hildife2e\
+        ttoburynchild2edrumaniletto2da2sema2ri2kworksfl22ladleaf2o2iigarchoniumella2fxhyllumoan2legik22l\
+        lariusanium2ero2no2u2ka2lrful22kkeumiriaschka2umo2ing2kumin2au2kkoundazanoffy2emeseul2nessperson\
+        2ineseo2herhudan2lffnana2llero2nonexia2ethesusmenver2o2nza2iumcum2ratorium2zilla2langang2iffariu\
+        m2ecac2ollia2onsrrista2leul2o2nusla2as2one2taul2arnaumin2icct2bus2inoo2oiafnculaaful2ne2lahpoint\
+        snish2blepaserson2o2lusus2tissanga2elia2m2a2idh2alo2ntouslinasalminoma2ira2rachimoniumf2ma2uan2u\
+        rek2erlitis2ongsam2trumala2enne2tumrumffemba2uvzummyuh2nnix2orirchildki2tal2smon2s2ftungus2nibyo\
+        urarium2rone2ish2llo2asso2rarium2ulum2llino2us2uo2uyerson2araful2saskamasoutisrschachlam2sperson\
+        2ssssulaful2sydra2cherfacca2ffetful2dful2erleaf2nfish2terzesusjutrix2ulum2uiltecan2ful2ltocenium\
+        lerfish2liefa2other2ma2rriculum2va2x2ish2ecutrix2cusnaln2nina2perium2treau2y2hicumtaffsfoot2umte\
+        nusadone2tus2ini2unctivivenceerson2uistador2ful2g2footaintifferola2opsis22i2um2usndumus2o2riusur\
+        nusis2hatta2eau22nyounghildish2stafferson2tissternum2fish2leful2tpie2pedoneful2ffish2atorium2ida\
+        person2oumphoroseritussus2sado2uise2alum2f2ado2lftilao2o2lusberfish2atec2latec2esac2umen2aispeci\
+        esnachl2umusculusful2oardful2ul2la2ammoniumla2a2umsey2isomassfish2ffahnim2ellahrrium2s2droma2rrh\
+        odionhaneddcephalusiela2denolastema2occusiliusemma2a2harynx2echoon2chch2a2yoma2ylyag22chertoothr\
+        2manr2ukuappeiroismakura2tenatica2na2iseau2elfish2id2o2atsushikiian2ei2fy2wa2fish2annechbus2anas\
+        hogarlarvahinois2chch2ul2anfish2n2ffani2ful2escendo2nsndum2shka2tniensoccusychus2herium2chegi22h\
+        inium2rchntia2y2ro2y2y2rescendo2rtful2i2atina2erado2tussor2rchmark2lfish2to22sanacane2ey2essaron\
+        2losouk2astularbeniumef2orion2lon2clohexylammoniumachmamcephalon2ffhylammoniumeugmenonstricussti\
+        tdron2ik2yoma2o2seed2phosaurus2viak2mmoniumadmiuma2a2enylntoerful2gusumctrix2gistbled2eliefquili\
+        brium2osoconch2yrambdaht2o22sioarmanon2mius2seriescan2ans22ish22a2n2ib2ooth22braetto2rumashinssb\
+        e2eypower2m2y2itorium2nicumobronchusirak2rak2ku2raki2inhobor2n2tsionful2hma2ikahgperson2stfish2k\
+        stuffebygo2german2pa2e2e2x2tone2us2logar2to2ek2uma2kopfreary2footmeo2tta2ndiusumfani2mn2hperson2\
+        rtegarchllesf2msdodecahedrontufforksmbryoma2erminoma2opiarumul2uffhperson2esiaclema2s2zoan2raeus\
+        eumoofpeciesndomycorrhiza2yma2opiumrix2a2ndi2uvium2upful2on2nlocustsiesnvresiaklema22ele2d2mobra\
+        nchtchhantchunium2s2is2atorium2remalyolon2smaysema2astrsathemmn2rpus2phalinusclopwa2i2lpion2achi\
+        hiotarchimada2mn2ris2ch2ephalonymaltesrraauna2orosrhema2ohphoronsleusthemumtesiteruurus2achchpho\
+        rumiumeenosema2agimumhaton2u2tisstomasrantido2esso2au2esjg2rgumeticsatorio2umcsog2lammoniumlyptu\
+        s2ologion2ataw2ee2mellibranchgitoriumoniumantulapus2bius2ermyertzthema2chusrsus2ra2igmoscephalus\
+        archscusod2dium2keleton2y2sspexbrella2ium2ropperful2ul2va2llaiauica2laesimile2lano2childne2leiti\
+        22lialusriot2ypantsango2e2uropita2ol2iago2i2q2yer2le2erkinso22sicula2loma2la22ee2ha2y2an2nr2stng\
+        2oman2cfmfultumnga2ata2ommling2la2chrift2loliumalissanesemacoiflaionkinleleafdful2fish2pinxumwor\
+        kslisfko2echo2uraantustifgenaellum2unatino2yliumfushtcraftzcunder2tusfe2key2eful2oniumyraft2ish2\
+        ngfish2eaf2ffersonpeck2footseratususy2umlona2any2erful22um2lifeiiculusfish2mepsful2ichungsstipen\
+        diumism2a2laah2llanum2ixeralflejon2eful2e2ioneko2korpsole2andgeful2oladellegefan2co2nitzfish2tol\
+        atum2ex2zs2o2chnionhien2rum2am2enoportulalefingersttolusmbulculusy2ourium2lli2ilugsma2nari2n2res\
+        utsi2etbahn2lt2iaigoan222ranr2eumka2fff2faoageperson2ish2et2ike2mapon2oulosehohinerirorksby2ho22\
+        s2geumto2ingsbo2onetta2e2e22a2n2t2ifemiuminschaft2ish2bok2arme2o22thicslankton2ech2nium2shumllsc\
+        haft2o22ld2ial2ao2toaqua2tfish2ighphtheightteeaushitaffomenonno2uak2ak2go22o2h2eng2uno2san2san2e\
+        lla2is2isscoma2agarry2cystidiumfsandoaosporastis2eful2nium2ocaltotheca2fua2haliumha2chi2fei2f2az\
+        o2fish2no2ul2etful222hild2umzo2umhedronola2faloniere2ylusaofy2ey2efk2ikzoedd2ute2ute2ocksan2mi2f\
+        ul2fen2ilisino2sumlix2tgrandchildo2a2oryzky22e2f2go2ua2to22l2fishfpypantspetto2h2lharo2acume2bia\
+        no2ani2insrnifnseywomaneafowl2arfish22en2ah2ful2ho2aiuyies2erson2orkses2ar2ha2ard2h2ul2ado2ul2lo\
+        22lo2lyik2usa2orium2i2a2o2um2s2scheibetusendado2theumyeo2esiarchzusish2unayfish2am2rei2r2tzerful\
+        2ok2erchiefie2itarai2i2izo2auebus2atin2estfish2nat2d2id2a2kikomihetfish2fotzrahhebu2supai2r2rahr\
+        a2yfish2aan2sfang2ful2then2tonicosachoron2sher2oreful2ful2raad2u2eganilichzmoi2shsher2esperson2s\
+        lytronhin2hperson2ecahedron2eyokaiy2aiifetulessperson2trix2ia2etarium2zeduiahtran2contahedronetr\
+        ahedroneguy2rnoma2ku2amanshinokamiakegaynon2ni2stion2hinaki2rchohiti2damaerson2o2mul2ish2lumu2o2\
+        ramspisoffbut2uschickhedronnculus2ashoho2ysweetslum2aarsumigan2an2riumickseorium22oitium2khikuly\
+        ootke2eok2ifeongidfish2ful22rcrafttzapai2aco2rani2tec2epuff2hol2il2jingo2balang2nuzzrumrusumliat\
+        rix2trumpapa2sa2keimenonahn2icano2yniyya2if2ingsife2ng22una2otuffnarium2lossusarynx2lastron2chni\
+        onumogriffotherium22a2myoma2dish2orksomagehyoostasiogusicon2on2uro2ramtiss22cushdopsisnoisquariu\
+        mmrgo2zo2ngiatrixniumamnompi2suralinium2roseptum2mum2uenza2ala2sh22ish2osi2si2ngsuluagmadius22lg\
+        liorma2nacondo2amiarikotiminnerma2iumdiumcraft22mbilanjawwa2isogionanej2uoisiiashigoma2emia2mia2\
+        oai2m2ongo2oco2oomonchoedrono2ato2achuinoliniumach2i2rl2ueszzi2ol2l22ffn2achtrix2ky2ul2yaey2ka22\
+        erson2ileitism2um2ori2offymunglum2rums2lfish2ish2atto2dor2ilbang2y2ana2ikmish2ifeo2m2offm2msnnhe\
+        tzead2rumul2ansm2ynah2oikunna2offo2ihen2locortex2don2oonaish2eeklatsch2n2li2na2unan2u2oseki2iaki\
+        2ukara2elia2njin2i2isar2oakuan2yso2i2anane2haroska2ar2ashi22iyaoffel2oa22yupimeronchy2atua2i2hib\
+        unaugaken22bchecois2cheefhond2llaha2ogi2etsu2hiodoshibit2ionfish2imi2elful2thief2ru2osgmagurileb\
+        tzagadila2aseen2i2tak2ar222r22khoehop2hraineef2or22nja2utzi2nago2sh22sh2ku2poo2mora2embeoeer2rit\
+        eno22hakuolk2iteahperson2ane2ma2aka2aneng2h2ara2une2ruit2iwi2kersath2sperson2stengeloterschuh2an\
+        g2mer2kitat2itat2mosasnan2p2ciliumz22zekoffpern22ledgklenutsfze2ib2oati2aun2zar2hi2aa2rn2l22aru2\
+        rabi2ana2al2apu2ma2shi2ch2asroga2o2el2oskiniosenosndor2ki2ini2akke2si2ani2hugoumimanoek2zanaan2u\
+        igum2ki2os2vorotka22tsu2ornosebi2etlai2oulionbarosoal2mshi2kon22a2sedel2l2glaphoros2ylospoek2esk\
+        y2pkakaovina2zektzn22litzupf22rai2k2chumurkampfkumi2i2imanwi2uanummakuosis2rigamataka2lbash2ilba\
+        sh2lha2iutl2hioqua2a2okun2ekwereaxm2aien2yzza2anumllum2ul2ratorium2rnumrinthitisndon2nta2ona2khi\
+        2eful2fish2rygon2ratoi2nmand2anful22ta2ssu2anlleful2sta2iaoonhito2ai2ul2as2usriussmanax2akitjma2\
+        ngna22icinoseddasa2ful2yee2fferson2r2boanheruchen2ith2m2fish22uffonella2men2myomotine2thnaiscus2\
+        nfishpe2s2sperson2idooechffo2oma2nskya2sranosn2estodofftskirtsumon2ngeni2m2turfnniumolnensis2y2s\
+        leumertorte2umoothidus2iltokwllayk2la2w2enetswczyk22san2tessh2lkfoot2llusa2culaiaonerch2eriesna2\
+        chum2apa2ar2y2worksg2eyranchteonriusca2yful2me2byul2phaguss2s2ouma2erophoroshildinsini22k2s2ador\
+        2mo22n2narium2i22flaitja2ai2rum2le2mbourgeoisum2podium2urium2rumaar2aiiesgno2erel2uariumomhilda2\
+        moiselle2ga2erson2mbiitro2umooso2ir2tama2sterium2a2fsarab2mahi2achud22or2o2person2sngkong2kkowie\
+        coufese2razziy2llaer2ote2riend2u222il2i2i2ao2i2richeefiritarlefish2hesin2orkrebsuis2ubiumfari2la\
+        2ulonucleused2id2id2remakuuauco2oleum2shien2leries2go2anjeebahful2nka2echa2le2schaumllahrrinud2u\
+        tsu2infauna2hi2ei22offrannto2raachi2hune2adachki2spissuyuce2erson2wineseilleuxifewaki2igaki2ubus\
+        hilaevi2ouw22k2x2i2ma2osukee2llaac2ingulumorcepsifehipperson2do2aoraion2ehn2eis2ambussa2aag2etar\
+        ee22huku2aqahelonnaiseh2poix2illo2orful2k2rygutsomanito2agedzuketei2agedchondriavak2k22ec2a2nkei\
+        2ahi22ado2ddumc2s2ai2and2ri22n2anro2tto2culmen2bdenumntji22er2rchfish2ea2plieshin2eeruthifowl2e2\
+        atos2us2eau2en2rumachingful2hoadella2la2ong2rwaravemanthouiterkinsopi2ik2hful22tta2sgwe2sso2s2is\
+        h2ifriyalumazah2ireachca2h2utti2kanykinsfiq2ocipalidadtahedron2iumbit2erhobo2x2i2addslman2um2ima\
+        h2u2uarriflad2hshahntoma2ncephalontiseer2achtbrieflastoma2asciitisucleusshuillo2blytesgoguss2mxa\
+        nthoendothelioma2swazaduri2al22ld2ful2uri2dashi2a2ge2do2snykootsy2i2oplankton2icoke2eailzukeshex\
+        2hal2lis2bi2aspharynx2ani2storium2hezonful2aeilus2sink2r2ar2ele2rbu22by2riichlssarolefish2ghborh\
+        oodfulseria2opolisalyxheca2eausortex2ymiuman2ntima2orosectumhalamusoan2sh2lim2id2s2dioblastoma2u\
+        l2roschen2xanthoendothelioma2salf2pa2asanrindjerilung2a2onagepallium2s22lo2roistrobunao2n2manige\
+        risin2lyumsiesaheesessing2uciwalli2uallniumliumi22lissimuseperson2rimbuloluca2chi2me2rchn2hedron\
+        2eliefish2minctus2in2ifeminoifeero22lefish2sack2armananidgewock2eratuiulbookful2orienoneau22m2ak\
+        e2culallusebranchhr22inusiusiusghe2hen2ongieryrafkasn2themeron2elharpa2u2au22uish2person2igato22\
+        eime2ngata2s2tt2nlusospeciesea2nnium2loxyuswaa2hi2goa2on2n2m2othritislossumanomusschchoe2phagus2\
+        ruca2o2ish2garchorkshoehoe2ai22shiashiaikiotoshiageiferanon22gummanumbolbosietsua2a2eorihene2um2\
+        aerson2a2tum2i2agerchusatherumhamblesmoropteryx2yoideushorion2tissamba2sudiumcytoma2da2iesiiri2m\
+        2adaga2ordumnusnium2oroma22xoneca2apaka2eye2akarixn2iicusthm22obrium2n2ionrix2culum2d2noni2oncus\
+        hogalumhypopharynx2arynx2o2asopharynx2en2erasox2edron2noea2tat2e2atekunarium2auananraniumonoko2i\
+        2alpingitis2r2mangami2oorsperson2o2o22ful2rasitionoccuso2estisarbeniummoniumaiummmoniumarbeniumi\
+        umanadiumarbeniumoron2hynchusone2un2ata2isheafumlum2y2ung2rero2ootuca2ula2ano2ful2nastukawa2ant2\
+        ful2tworkste2an2uri2wa2ha2ra2taffnotaxon2icorasiusori2oao2riopedilumdumrumo2eliful2a2aslisafacie\
+        slubong2kevilfika2alumisiello2ee2uachinses2ul2ee2utanffetanotumowl2lfish2eadillo2ka2ero2nculusee\
+        2se2d2iculado2is2ilfish2ulum2nifebscot22ia2rniumeronostreptococcus22eadiuslakozgraffernussnig2el\
+        le2seonion2chromocytoma2le2x2ctia2asmiphriongmnntisteriona2a2oina2za2dor2olo2uramontese2ogersuso\
+        nplumserch2taffhard222nto2ico2sish2n2lusorksyon2ruit2formisnka2q2achio22hul2osguara2an2osporumt2\
+        ut2iolo2cato2iumoffsurecraftiscitum2g2opuspiohruser2stafffderfish2i22sset2tecatlettusx2um2olkovn\
+        ik2takannik2sadenomshchik2tsperson2umenonnoket2nergeist2shka2ret2efy2o22a2i2fsi2dumnaiteusfish2h\
+        calyx2hyrogenitso2tul2os2guara2iekosmatoleful2s2orkshful2ing2ticum2hamanma2izaeodymiumn2is2bdome\
+        n2umanmagoperculum2eklionful2s2mifish2enello2igo2anik2anistesmoma2hismadiumossoshliumscoccygeusl\
+        larisiese2o2ko22itum2ilelo2lua2onorseum2ish2tan2oniumfieu2o2men2ollup2ngdium2pagusrus2ermranulom\
+        a2neumothorax2alpinx2olidinliumnka2kaunaaq2tzezuaq2har2hizh2ftaw2iecoishua2k2zal2al22fucohanockl\
+        y2erful2oughcohannock2fum2um2to2iiau2ladiiish2are2an2n2at2offaing2gandusosi2riumho2sumuel2nui2ll\
+        oentan2olnik2ador2l2l2ish2onale2lerfish2matazzl2ful2earchto2tiufftatiaitendumho2rendum2o2rmado2a\
+        um2lhskanzler2irodeer2hi2xraegado2a2culusinirperson2titorium2chnionum2tabilityduum2s2nch2odhphot\
+        hecahisariuma2iummsalisbcarb2chodaeumttuta2onfish2speciesettsia2us2au2ttocimento2gio22shha2portu\
+        laorzando2rium2eno2ffon2etto2retto2rdando2rnello2atto2rium2h2ful2tbeefta2ban2montado2eer2liklach\
+        2ngya2rdrumopsffnrtopfany2ichal2sha2n22nek2ful2bachterfish2ni2fish2rium2ifopflaviruseni2olo2eau2\
+        dan2oatful2erful2diumisffsby22y2lachlachumn2nococcusstaffu2ffhandaah2lkaneniumyl2an2ei2ain2ekiic\
+        h2mikiefish2rae2e2ertorte2k2ebum2ulum2ghwangpenummono22m22anos2son2chor2y2ietrinoonfish2rai2ttum\
+        2aisakuaia2dillo2ena2adillo22s2oinella2asss2oriusbobong2imi2rugng2helful2ar2ami2apy2umaimo2agrah\
+        i2e2ernesardoaard2ones2ish2ooth2en22p2on22ful2us2io2ritzoitztilla2rhus2ora2ffrfpful2tcraft2ffito\
+        enful2fgil2fstomasouseerch2uarium2uffe2rout22ifestophant2i2m2lotricumefsh2achioepemc2kamatchiqel\
+        der2iefish2rrakona2enusriup22ntnciariuseioffca2i2or2jextee2ai2yu2ratrix2uku2r2omanm2ithoe2fforks\
+        r2nnium2ir2liatellazato2oko2aila2iaante2nee2ful2taliaetzkh2el2el2ai2azukeu2take2ehr2oku2hun2et2e\
+        rehonlderful2elokufmpffbfuldlan2tebelfeimel2r2unkin22iken2wap2tzu2lio2laur2person2opaienseful22o\
+        sa2ergroschen2x2eworksykinshium2rus2llithiumha2llexlacrum2baffiput2oniful2pia2sitis2aiao2ul2uncu\
+        luson22a2logose2ka2ful2i2ffish2ful2tful2ionkumffrafthosmioniumseriffeghterperson2eyful2veful2ghf\
+        ul2ky2ovin2typantsma2d2syenoffey2rebrod2sekadiumgletooth2lfish2efish2ewifekfezfomish2ualmfualmie\
+        2ish2orksdor2rsidesusan2rioneworks2umiya2man2usm2usbody2ni2tina2yoai2vabitch2er2anino2humtesy2il\
+        egussskaosenuto2olaki2ful2dperson2laki2hozarkhozk2heeset2zehetton2narddosulatiusloffsumnagisnx2l\
+        lefish2satusnchnocraniumfofesperson2fin2tfish2ite2ce2oneky2um2ang2leful2iflhelm2irorotocherinoos\
+        aurus2nma2andriumbumn2maulus2gfish2ha2eseoineful2mcotperson2geon2ul2ofaunaylpyridiniumwarfoucleu\
+        sacuum2omimus2hienraniyarestum2acaseful2ahusa2h2ch2itan2i2umi2ish22erch2oofavadi2ryliuluseonfish\
+        2mono2zlus2tylusuehannock2entaculum2fulili2pful22i2i2omanlfish2foffs2chse2deta2anglionedrionaryo\
+        n2emaacrum2siais2elamanyeongsoneirlog2antesiatellacuffo2aki2noffanyaki2ing2umnasas2eg2sh2zdatchi\
+        k2an2hangya2wha2oki2uki2orksakuseachga2tum2ofaciesumn2fokizorimi2gyojiama2mnium2ug2ezzy22vicherv\
+        onetsupful2otful2offpoonful2anec2ibranchy2um2ray2lenla2hokunx2ahfaharmoniumum2uryashka2gu2rinosa\
+        r2e2culum2h2ndumootnec2huaildarium2codame22ibracha2la22ali22u2mae2f2f2aurus2anullwertklebgumet2c\
+        haaskantor2mbikinsderfish2eng2en2eifaislafish2nga2unalpia2amook2rau2ie2ancua2mouseturaa2ul22enst\
+        richeorksfftaffrano2ouse2ki2lusvaevain222ul2s2manse2chhuriperson2wa2ec2idiniumful2od2ful2n2ywand\
+        a2a2sillitiss2acho2pousulusador2och2edo2o2rak2hka2nkopfariava2reg2ouleur2ishrch2nful2eztoria2maf\
+        ul2sury2fnumantiri2fan2cheerionuetra2iairitzhka2osi2leyfelful2kful2fan2g2cumaanorhynchorkspoyina\
+        2haht2sntetelisith2hiatoriiaezoriuharaiana2eg2ul2humdi2u2aumlusul2ooseca2yerware2na2co2oman2enfu\
+        l22oon2shka2arora2fish2vka2loix2i2vesiesletoeseafh22n2rusma2al2deflo2notec2meszitkinexzilras2il2\
+        il2hefensche2fnik2i2ematum2avacuum2alang2lllicus2ut2ngu2achtigochsdaromanornfish2ravidaara2usniu\
+        miumrson2oofroka2ortslon2airshus2us2akiilussritisrimat2bo2rium22ul2oros2omphusiumteonbti2lflago2\
+        us2ur2r2iniumufumnsnokumta2diumul2tto2um2o2nsulful2usmansul2ik2ushka22o2to2lonella2stuffukoen2m2\
+        etthromboembolism2trumello2tasuca2montanum2cilloelful2oek22urful2xrnumoriatussexvir2ir22aco2naul\
+        um2exem2lrfish2ma2o2oplanktonuossustatrix22i2aazla2ruano2sraad2ado2st2ellta2a2gom22tisvaginitis2\
+        amaw2o2shiu2ta2aki2ful2ua2hengrenoan2uszn2era2to2ti2frobeful2userodashii2ga2fish2a2fish2hrsi2aw2\
+        orksng2ooser2fish2themeoc2hererfish2y2losoot2ful2digo2erfish2ananschauung2lestikoshiw22nau2ko2ef\
+        ish2au2ukleaboutsfga2kut2sey2staffkefpfatru2ita2ul2a2glassful2fish2ebago22tub2ish2ooffen2nnnnerc\
+        hildamon2iee2erfzdful2fish2ygutstful2eoffo2us2affee2th2hsmosfderma2bing2longbao2xue2rium2rchstlt\
+        a2v22obraoskin2a2ot2hung2ushiraw2ba2o2ere2tonmamo2uedaoong22aman2i2ri2hmanowe2ee2ee2erson2yeonga\
+        l2da2inia2ivasyno2zuna2t22oma2eihdshireperson2ba2fai2g2haoteci2in2a22jiuo2anozoan2ik2leen2aleen2\
+        ik2uga2atsu2ai2ichi2e2uuska2ska2nal2useoneiricohoxylumekanktec2ushti2a2ish2er2st2trix2ijyanka2tv\
+        o22a2taa2iaoghuzhngahworksfan2ak2onium2hedronla2hlorellaetritusa2l2anthellaecailon2neri2olo2lo22\
+        2cher2il2il2ranchactusa2a2scus22a2s2onsis2oixansor2m2nguki2musiken2bi2rient2eau2eau2sha22moeball\
+        a2s2iacusnarium2cecidium2sccatura2ensa2ulusbulum2xym2loniummenid2g2onropoietonium22m2cesspissa2u\
+        a2tumium22nium2archmaliniumermatitision2hagusticherion2an2mntado2hotaxontum2horontvian2an22us2ra\
+        ft2eflankton2ropolis2aansnisanthus2starchthetesomosfhonusinessperson2hnionoodstufft22rne2s2rson2\
+        anefulffionis22anchtreseualfello2ross2o2go2eoiso2num2i2iumummluminumulfoniumithiumxyrimethylammo\
+        niumtoumul2an2atorfish2m2ortex2ium2xyalladiumhodiumamariumerson2etl2humanumulustratusnosa2sa2ett\
+        to22gh2nomachia22sadrixish2amps2us2acado22ma2la2blastoma2srrhkaliummumlium2la22tto2no2so2hus22r2\
+        tislaidoma2mepsuthon2thon2mma2gon2s2eon2apodotonordotonotaspisychushyroszi2rmastrix22aalastoma2y\
+        nus2a2nefish2tisrfish2manbeeeles2otheriumzexelix2ope2nenoseptum2tomachopophagusrium2aoronucleusr\
+        hinumm2tifuranchon2tis2lussssistron2therionipnonterium2gia2tikionlesmaopaionto2a22ful2doketon2rr\
+        arium2rium2inoo2oma2tum2hiefioceselamen2agnifeus2diazoniumniumusno2iasentarium2i2ol2tornte2kreis\
+        2itis2horions2siazoniumithiumxydesion2occusatromaaruo2u2oriuazihardi2illtasorium2ese2into2eszoan\
+        2galocoanla2s2ulacalpium2ha22ncephalitisas2ycus2aizuri2una2ndum2e2ba2zeleia2ka2a2nt2usriefischff\
+        ackerseef2n2n2uffish2los22ri2ffyful2ri2ari2u2yuosaika2a2en2a2trinmarium2mus2ra2no2lan2au2ish2ttm\
+        arysa2a2a2r2rfish2ck2hoisui2ta2zai2ka2ingha2hrumrouxto2e2shl2ul2lo2mominiumul2zlfcwful2nium2one2\
+        iostaiomai2ful2ternum2pithelium2eliefho2nello2ue2ul2ubful2k2ecraft2eeroula2rful2chachish22olaiso\
+        nium2nia2afflowerson2niffful22ngren2niummidazolium2k22ah2etitzang2hongsh2l2ildmpendiumahedron2tr\
+        ix2tifrieseciescherapeutics2in2ma2la2hworks2denitistishikfish22zeaf2erum2yry2ish2ffurlsish2umful\
+        2ish2ul2erson2cio22lncinorfrdseborffrraja2us2archar2se2novik22lo2roofish2kaii2oul2ousetafffetza2\
+        gmusgmriusetto2h2ch2ok22ootsantsnese2yx2ochigo2ga2eful2utennaisi2ioolaeolai2his2ino2letto2tvo2ac\
+        hdo2ra22tful2hful2sperson2low2ow2andeering2letail2olli2hosaurus2eworksfish2staffffhetta2elsosem2\
+        napharynx2lakamashilztful22psos2tproofastiffrfishffe2222me2oisrafafk2tta2ish2kohtis2uck2lful2ers\
+        onnifernsky2mmoniummidazoliumithiumagnesiumtful2ul2usbranchmony2mony2us2ra2nio2a2chachachreseium\
+        uzooya2hus2umlus2fdarium2arium2aanco2ago22co2s2iulus222eu22eu2um2ja22aperson2ea2ador2ul2santo2s2\
+        iculusllusizansish2orksllonela2iro2go2trice2rbury2co2efdor2ola2ollo22ccitto2eaf2cum2gulumniumniu\
+        ma2arcoma2mum22l2eer2saufex2x2hletful2ncho2saussul2tid2po2ellibo2otful2ul2panca2sne2lleful2ato2a\
+        sionalcooba22y2r2sma2licolo2loe2x2cula2putamen2ronful2nema2ish2laerson2nto2in2teron2l2lastoma2a2\
+        ium2peciesstokes2mtronoglossusria2sony2maitis2umdnik2isdaiamchiuma2o2ish2iguezol2worksfishacoco2\
+        erful2curoeu2ndazau2erful2lineoffsmonium2lais2itsud2a2iushkanau2no2asia2nte2rrahn2ako2haful2ecer\
+        aectoma2egimen2ngo2yux2podiummiss2ybumonesbonetsul2airoscuro2mha2li2nxarron2hafftai2o2n2waotin2o\
+        ot2la2nla2humani2se2ul2ok2zlewcahua2ril2naia22sma2emaw2nginiumrakeage2itickful2ank2gusiditisyuus\
+        el2mographionospeciesprasusvararum2hi2umaahful2umrumethoraxey2ium2a2rinusbeiarchonidiniumnnusode\
+        susussphorus2bacteriosissbranchschachul2oomful2anie2p2lasachomastoideus2tis2y2yperson2terionchlo\
+        gsticksumsmen2fish2um2limnionusishllusrissoul2le2sused2roofish2ouse2orksbacillussirperson2efea2l\
+        orumish2footlorumnteron2arusnful2tusanballsrtaffacilluseumis2culusquium2virium2oma2umsndachrusso\
+        2us2cheula222etumrudo2otusando2nirtmentful2situmtusrdia2mnedloma2toga22n2riesus22essperson22ioma\
+        2m2tiscenteruncusgliere2eciesnuum2rnodrum2rslvulus2y2ff2eaf2orksa2henomenonnaucepsshperson2la2sc\
+        husnochnachgator2x2ant2orasbusha2plex2us2olis2muluslau2hino2ilperson2el2nto2hiefexhan2eacha2pers\
+        on2o2fishsh2l22pantshpuffrix2nza222ful2el2laendofish2rytenoideusharyngeushyroideusn2anmn2an22ech\
+        ata2oot2umdo2a2orium2olcano2x2ustahedron2emioctahedronedusa2ctahedron2oan2s2goto2rpersonngi2svoi\
+        2dero2culum2sulaefish22ss2orum2eronifeifeuseumymposium2ifeeium2ldiumllanautesediumarcinoma2a2erc\
+        usisa2eleton2eciesthraskinskinsr2r22n2uonoi2re2ufffh22an2ik2edron2illennium2chtichhlon2sed2nuill\
+        ennium2inoum2itusanesiesuiumium2eryperson2hedron2dium2ranchuyumaneliefrgex2eron2kin2kulo2kuej2li\
+        um2noma2ho2ere2s2ul2erson2nrtspoonful2atina2tina2chumconch2ylon2chusmenososticsita2theia2ylammon\
+        iumhliodoniumoraema2a2iumheeseopfmen2m2oma2ecahedron2hh2ssreno2riumsan2ak2ma2tante2n2m2sr22heriu\
+        m2seneiodoniumodoniumpaitroniumroteschan2imen2ul2anful2sitifoofffophasstiandsminiumrix2cosidodec\
+        ahedronltrimethylammoniumtsukoshi2hos2lumranakirium2ish2hniona2lawi2iang2s2eaf2erson2o2que2akis2\
+        psischchhoroshi2manculussperson2an2tsperson22rful2nifeorkshildifean2man2lusss2ul2eaf2fferful2ky2\
+        y2phila2dy2oot2owereed2um22x2cariusffmoma2chna2mhenomenonycorrhiza2hrixoourgeoislichnik2ellful2o\
+        onful2rchcon2srchish2inionherarchtorful2nseso2ass2a2s2nmium2sa2ma2euma2lworksrado2tiridionridion\
+        2ndroma2piium2edia2dia2teritishnionermis2hizashn2ontahedronecahedron2edron2akeake2ba2eba2echofau\
+        na2lastrontromaotisma2iserssde2rtexaniustisdymiottis2nusrynx2mnion2nikionosmon2lfoniumme2mpanumn\
+        2n2ibrium2etum2o2sum2ma2oderma2adoado2born2rchsoy2m2enngful2uyerson2trix2rvix2orion2rtex2ss2rthe\
+        x2miastaecananscansratorium2mum2oof2fftumm2roundsan2onel2nish2uyffaffsh2othiooth2iyaata2rfish2if\
+        e2ulus2atolusollesngstufftuff2h2ix22nucleusfan2niellollahioioi22cmhettotusllago2ee2toia2lumairid\
+        ash2otr2ffianagi2hiefish2ado2erson2orn2oot2rouseul2cuffllumerson2taffeau2n2ngo2agenituslightfish\
+        2ful2ead2andss2usgum2legium2rpotful2ffnfulco2ish2tuffechallfishul2uffaw2n2nifereliefoxaemur2affo\
+        of2ifeerson2eeftxdnscarium2la2atorium2ido2o2eau22la2s2usiumipani2enfish2askoisperson2iumerful2si\
+        s2an2ho2person2au22itelloata2do22fish2utsff2a2i2o2orium2ashiatorium22sshi2srium2llus2s2lan2ranao\
+        de2hikii2lus22nium22kujin2o2taminiumnumlusa2oryful2ambus2do22sh2wl2nati2mioela2eful2ia2orpsaldin\
+        o2una2hedroneau2ian2eaala2u2erson2o2le2ria22hria2aese22s2an2ospeciesheitronpecieslhomme2edium2se\
+        umn2lt2reyeh22annumaudaussertznumtacy2vo2ymusfe22hildtrix2lus2s2orkslastoma2a2arcoma2eratusfish2\
+        sh2ngioma2ruls2comoneusus2xx2on2soma2ishye2ish2lockslocksoblastoma2ua2ua2occusendron2tylushecayi\
+        feffsh2oteionaurus2sdumfels2ium2men2ishing2ygutsedo2er22risinohen2ondee2ssco2di22a2m2a222g22cksl\
+        aojiicsceum2droblastoma2cium2araiusifastigium2aadiusulaar22nik2bateeha22dado2reaumaki2ck2thdomi2\
+        rahrah2i222ahakreuzhaeno2umanifeenny2taffut2r2eniumul2g22x2r2ra2hiryad2tastotasusudaachi2umokute\
+        nagfulufferchief2fftperson2a2an2kiahanxeralfron2nema2aki2i2inhead2head2idiashiataanagennio2beest\
+        2wapexokiafahsh2l2rau2rum2oisas2222chheeseerchief2ouseuarterscarf2orksul2ful2eaf2nful22otylusedr\
+        on2hiumarumogioniumolis2nthemumus2an2psis2m2nodikasspawn22a2umgiooranchrachm2ktonanglioneaxillaa\
+        2ertebrarotozoanhorax2anatis2minorium2ifeoin2nvolk2ng2ra2rluminumoecameron2ooseuminumctahedron2t\
+        ychchtetrahedrontahedron2inoashirahi2usha2cus2o2go2sa2dencystoma2rchomori2toshianshi2ee2tomachst\
+        rumsampus2riffotamana2ana2ogiona2himanocytoma2ok2k2ul2a2umsranchedron2lankton2richoi2ul2ifeuplex\
+        2oteleuton2oogonculususionao2eibingarium2rarium2todromosmachng2to2ogium2itzedronaki2ifei2ng2umme\
+        2edweight2an2erson22ndo2llhookumg2iniumkosiumhyhiza2honiumoum2pteron2yamus2igithronthium2pist22m\
+        a2ermeimenonerontleguss2uonaciesenuspeciesaxonauna2aurusn2hedron2lon2s2occygeussoas2inatus2ium2w\
+        ayakinarium2xglio2zoliniumumliumpecieshrombusr2a2iensvium2smatur2vonatoriumsorum2ium2miduum2ta2t\
+        naostalisumanrdopchndo2tarium2berg2nuendo2mumuctrix22noma2maostalisictum2yoideussseustriarigo2al\
+        lum2eb2dos2a2umcyclitis2an2erson2orksavernosusaguson2texnifeffau22leung2a2y2ish2lanktoning2shii2\
+        u2e22ha2lyperson2i2a22uraefowl2o2erson2ciesosdha2udao2fifan2o2adacho2atsuhineriang2in2o2in2ono2a\
+        georihan2uya2avkion2ago2pel2kantzarosauchion2toko2o2tachiz2aukion2itza2avkion2shi2amo2ma222kinin\
+        le2ka2m2ishiteenapakh2e2vanets2ansuini2k22in2zin2nfeld2ziruhi22omahresisana2ana2ari2aibeta2sma2l\
+        ikos2na2oum22ang22ka2tis2nsma2eionrumul2hahna2fha2ura2th2umamanymar2hoi2ixan2ushka2l22ik2la2poo2\
+        rumffhawsumi2onu22a2eer2sefish2ffnnumaudal2ertzova2ashiaoshiomful2ish2shynch2izzaeshion2ffitsune\
+        2oowannful2hahki2anntailswake2ffam2h2ch2ydra2oerionasishe2k2k2hman2chman2ha22n222u2hnik2i2ip2oza\
+        i2nu2asukuikan2ubi2riononga2cortexiki2kr2aktnik2szaan22ka2ka2kakak2keai2ai2ak2antbosreineriagesk\
+        ossk2urirophosekagez22ineriagea2aaay2chi2ephalos2a2h22zo2ouraosm2s2eaf2tusorksalaacillusoccus2oo\
+        kolon2naausnaoi222branchla2o2ata2daring2kaish2sfish2mmanros2owl2rafersonag2manebeefaufsrnfish2an\
+        marium2l2222raopterygium22ondo2o2xsimusnummium2stinus2o2ava2ado2orium2ttone2estsrone2oot2fforkse\
+        af2ersonorkssweltn2e2a2ternium2onsa2arcoma22ltok2ngenons2so2uma2ariephalusperma2uma2toor2rchoi22\
+        mpa2ampa2cio22rtto2genaulusentrixa2trumnikaoet2222aikuorks2person2od222lastoma2a2arcoma2riaul2oi\
+        n2atumasgeul2ffmus2fachssimusehaysi2ul2ffsish2torium2toriumeschaeft2ensche2icalissnbourgeoisish2\
+        a2sh2l2ish2ma2engipithelioma2a2odusarcoma2o2oni2co2oketor2iota2mboluslomerulusepidopteran2henome\
+        noneliefhrombusihuitl2a22sah2nshira2h2d2r2strassed2torium2tarfico2m2ir2leh22ob2b2ohobohild2s2s2o\
+        no2toshiitare2uouchiakotobaali2ite2ifeois2eet2rd2n2m2la2er2usi2i2hi2oac2ildsusa2oiarchba2ish2rfu\
+        l22a2ajia2aoo2estoe2iit2rvant22au2umrium2ta2lieshn2222sh2hkawieck2ma2nale2afngroscheno2tful2rafa\
+        2person2illaispobranchpium2nriiachumee2abiyya2kuul2bahflarsrbator2alpa22u2amilias2shka2oshka2i22\
+        la2m2sh2y2ua2ecross2orkstzatanumpsisornuurcansh2selannum2audoccusarafausseoi2o2hahectesma2akaron\
+        o2oeia2llailedum2elongkabau2lion2enn2cus2ranchoginahchi22h2evik2ruum2la2des2ildrammoniumine2m2a2\
+        halluslankton2bryanthemumrerep2ronchusucrocodyle2astrium2ortex2o2o2eliefumanocephalon2imeron2noc\
+        occusselah2lal2hionichkaa2teon2teeumanatinto2zahyukingium2heperson2shfffeof22hi2u2se2rense2u2fis\
+        h2erson2nul2eisgag2piraft2werfer2lbumus2onjouocusari2mpi22n2nnhor2yllaneang2rfoofsriumssini2r2co\
+        2n22nhatlelmataah2nful2mus2imnion2lankton222lo2atrix2umlussir2k2an2i22h2ansus2ul2m22kinsr2machos\
+        eor2ia2olimnion2ephalusedron2inochistichrochigneur2culusartroisish2oofife2orium2ella2lo2a2oiari2\
+        co2peciesaxonrium2dnik22on2o2bus2intleco2eloana2euxrella2tta22tisia2sha2dd2annath2asto2to2t2omah\
+        22ngo2ucu2see2flfik2ogee2to2to2to2lman2efino2i2man2allim2rahedron2wanfish2sarifm2k2k2u2erma2lora\
+        2laytoma2ipoma2a2rdionus2mma2mmapoma22tritisrcoma2kitisxiarchoetics2ba2eba2mhondroma2a2arcoma2oa\
+        n2moaki22nkaka2ashiagecushothiri2diri2iri2ri2ntenna2rumocus2auss2eiorusortexooplankton2ssus2gans\
+        etwsed2ayka2rtium2chia2eag2ka2ius222o2oulaatrix2susum22rchief2ul2erchief2ranuloma2olisplumcalyxa\
+        u2e2ahtti2to2imi2ritoneum2arynx2amaneciesul2hedronrhosenim2lik2ke2la2ahendum2rn2perperson2rson2r\
+        2mrife22iastratuss22o22tatum22an222iumhenoxyusaima2hylaxheticsctusmpos2iterionect2uilibrium2to2r\
+        ousewifemanrson2oofamaneciesa2ack2ono2oumeaf2sxsranchenusaxoniahaetaleuronrochway2way2rno2la2vir\
+        2a2n2hs2ara2ish2uts2iut2ak2ish2erson2lopemeron2um2m2on2s2t2e2ato2uuslisfrontalisrium2ul2d2horon2\
+        ecahedron2teriseaidecahedrontichedron2inoariususir2h2ful2umring22eaoshiurirchon2on2jiul2aguscerc\
+        oma2ticon2opoeia2iarium2pluteusoneyulalaardful2llastrum2m2num2arynx2mxontrahedronoriashiaoshiidi\
+        ummtis2t2ia2riusato2ononinatomakina2s22hiefoniumliumbiumephalosaurus2eninxtful2taffki2efckful22n\
+        e2i22i2ikirisinta2ar2i2stra2ng2alazo2tra2bacchiusado2restto2ul2to2brasussletful2shpodkey2ea2iari\
+        onanttiumarummonium2ro2yriscephalitistone2sh2rte2l2umondms2ticon2ama2uffchyful2rottrottile2umo2a\
+        gi2azzul2nifeerson2orksgo2sadums22ss2udoka2uito2axonssus2menon2iusgon2thesia2n2m2stintin2thmiono\
+        sychia2itfish2eniumidge2ufchnionyfan2lh2luluma2rcho2aiareheukieri2en2n2ket2ent2ladior2ua2a2n2chu\
+        l2niffa2ino2n2nehda22ero2avium2nalpusse2ese2gonium2gian2eman2ps2sh2l22inariumilll2sula2rth22one2\
+        affmminoooftimatum2bra2oot2ncinos2ino2ephaluso2akan2e2nt22onnibranchnhivaniectusrala2la2oium2ocu\
+        selion2ysium2ranion2tohedroncho2itohky22uano2idnr2abzmum2lusertzdeerhiaero2olosarchuanchapschaps\
+        hahah22somenonchromocytoma2raron2asmaas2nolus2loxyips2dendron2rum2masiaenanas2melusixus2yi2x2mni\
+        umno22testis2ohorionungus2hotodermatitiselmassiforte2eheeseffoothlgainiumiemensro2sh2otpellistum\
+        2sfwful22fish22ionloma2blastoma2um22ecula2oot222la2do2pi2ongs2ha2lasma2hsna2ffantsrful2orkful2er\
+        son2la2ia2ta2rdeerlae2tustia2ton2laoglobulusnumerful2stomuspo2m2d2sedronaurus2ra2on2tishstaffoni\
+        onalasenusara2rchus2niumoccusiexyul2andkerchief2niferanchhyllumhecaonman2o2huk2eperson2myelitis2\
+        ck2xck2mse2umhermatomyositisonum2edron2euritismino2uaterniumadiculyndeton2eerriso2oro2o2no2lmous\
+        e2au2fsh2l2ish2sh2l2eumfetsdumdumnpinefish2nza2a2ssctusngerful2ulmanteau2canteau2lano2gueze2anka\
+        22onium2rassum2mbdomen2urcaumanche2arvaariscriptum2siumoatomi2inik2axillaomen2vasneuslla2kant2ho\
+        2isternum2rendum2oshptocephalusdium2um2rismen2cleusyteraeaf2dium2person2rsusrikinstorium2tebraea\
+        ik2us2fish2useieu2txrachravida2ara2ia2ledgnion2issiemascisoxassoconch2omnm2rensinsottisammamnasi\
+        umimenonmenongomenonchostochondrionntorium2shlennik2phros2horn2iamigorispomenon2euronietrix2lyna\
+        mbanomenosectus2sossnnuluspigraphmago2pomposiumn2pusranch2aleuronhorax2a2rfish2anslusuo22einello\
+        2rellallo2dumdummsfsytisnephritis2tisis2iahrumia2iniumormisohedron2umulome2umloxy22uan2h2222oniu\
+        mm2onium2omentum2peciesrnino2fish2etumcho2mansmafish2riusunx2ecat2liueibrachfish2la2agus2bonesul\
+        2lulich2uorksowfish2eer2use2ro22zvousstirionserua2culus22sfari2umonesingsymnasiumchule2ofo2nna22\
+        m2shsh22uck2ish2ium22ta2or2en2s2sionefiousvousi2i2sentamen2ofrium2larium2riuservix2solatizncepha\
+        lontisnasli2umncephalon2cs2ussmus2n2ish2care2aff22aul2it2ail2iusto2alteshefish2rixlly2sh2wl22au2\
+        letto2ffish2la2llum2o2craft2anda2ish2eelsffperson22hka2o2rfish2achlachlachk2k2ano2i2catioguts2aw\
+        la2toothish2ootharul2ng2coccyxm2itin2yraft2ish2iava2ottariukia2ka2o2anderfish2hilderson2an2a22ll\
+        a22nto2ishpoonful2s2orksador2tella22a2l2en2dated2a2ed2rium2rium2rachish2rouse2kuovese22icel2aset\
+        rinoil2o2kele2al2ku22i2nstanhhiemma2a2hagus22moli2fisng2nachshi2ama2anful2s2ardfish2nusppine2foo\
+        tllusicusoriusbaeus2deer2sma2erbrainsskopfhticoke2lumrhus2zhrezpps2ckeckzhnusnionlftlff2zstaffel\
+        rpunkt2tz2ma2na2xpale2la2aionfish2ma2person2torium2ano2icula2hula2um2fowl2ftto2i2llabranchlefm2o\
+        an2ss2eam2tchiffaft2sh2wl2l2nful2ine2dan2a2um2diie2e2ku2ori2ake2tperson2iumffcson2onutomatonrief\
+        warf2reddo2umanhahembranosusosisorksi2rvivumternum2fo2i2urakullarium2io2rdirabranchtur2fsa2ntari\
+        um2gola2baffla2egspoonful2rix2tiusto2aahsiesumchdo2o2hanachfda22n22hlesssen2jierss2ygaffingaitop\
+        us2la2iosh2h2an22ish2ful2fngo2ffro2effana2ifflaniu2llaampo2en2ngiuk2i2omiazagamiitaiansen2eaf2ec\
+        ock2biari2a2ul2orkstoriful2moumutsaht2tkubaioxful2ul22rt2irinin2um2offul2ish2h2irumul2rful2ul2ff\
+        erson2ish2l2nja2nii2kuzorifflecraft2tztze2ng2se2oiushno2usm2ula2ua2y2r222tta2aleseltus22t22rkins\
+        um2briuminchiuma22icksickston2mr2ul2ack2wishsh2l2ul2orkszumcraftofungus22ul22efritzish2umse2o2oa\
+        do2erson2routrsye2ul2an2iotoshihinbutsuium2do2erfish22tara2ggio2itrixerrumonar22ophylaxahaeoe2ki\
+        nskinsabitchabitch2l2al2no2akeomatausogasukizoriowlful2poonful2shtra2umlumraftinfx2zle2ifekopita\
+        2xisaniumsla2le2easy2hiaeslnaro2ilo2elusraphisnumter222la22letrixladenoma2ul2amefioblastoma2fish\
+        2ful2ful2ful2laf2gzristatumh2fish22la2ato22n2merson2footfishn2osedo2tsaiffo2yltish2sta2rchionons\
+        person2mossticksedusaegionegionheotokionystoma2a2bok2ul2trasse2hildrandchildumisifeoogma2e2ttoag\
+        aetto2saga2tipellisla2ry2aado22dobrancherstein2deum2uck2orks2fiumful2ello2knifea2ful2hrieftivari\
+        us2onium2geonium2mworkstful2mel2ma2toto2aeoffgilanoffhpach2trumish2ololebumongebra2crium2ilifftn\
+        ik2reau2aviusiterionho2dsnmlo2managomina2nguacusnimum2tifcosa2timum2do2oofgnum2ofhema2rieseriffe\
+        ciesxon2gulaalamusrusibusulaahdaneum2rfish2ishgotexlumitsorksnage2a2nretumnyliumniumretum2rful2l\
+        aori2ntenna2enusilonova2ueeroegnum2m2a2araiorijh2stakes2bymishootnifeish2person22i2bgismusotumep\
+        haron2sisthesia2tarionllusinorium2ton2umhia2rionymirchkklau2ul2poonful2icha22a22hahhahf2an2umhho\
+        2duse2aw2la2n2na2ao2lo22hul22muan2ul2a22nbaumn2uff2lumumi2e2utula2acumn2to2fa2n2mtflemetatarsuss\
+        morionfo2ine2rksriffifrsallso2farchul2ium22ohaum2on2giectasia2tomatonutomatonureau2onforum2ochry\
+        salis2cephalon2conch2sunami2umniumchcallichcalli2or22stariusrfn22asiswoman2ino2ynovitisesr2ul2ri\
+        um2sio2e2ahn2audertzh2orao2emezslla2hahciustto2na2tura2dous2temorion22ummr2ctrumeg2tofaunaka2cha\
+        eaurgusthroposchr2ma22u2e2ogbo2okionph2aca2os2oliumtful2ips2eful22acillusyryliumet2t2ssumfish2an\
+        dweight2dfisiesosmbuslcrafts2boff2s2usibula2arsusish2orksa2ha2oguyeries22m2lfishipsa2a2ita222umt\
+        heriumageoma2ama2chendilulaod2ishno22onan2efa2hi2wa2nese2tsura2au2choambou2ium22ja2ugua2umiaoshi\
+        sntfish2ni2ix22chba2ac2nco2us2eul2person2lasma2escperson2tor2ifelaphusetto22zzinoul2anumuhedron2\
+        cumil2s2is2sh2l22lando2rful2ul2nema2ontahedron2isguleniumoliumsperson2kosachs2lon2iterioncahedro\
+        n2miniumum2archtino2uoroacetoxy2minusnon2ron2ixitetartemorion2y2thon2thylasiumummenolonion2ino2w\
+        ifeenylphosphoniumsdiusgnum2gion2ctahedron2ysvir2gulin2gam2usea2s2us2ul2onno2liumhful2seau2n2au2\
+        etfish2usfivtsa2uchi2ut2222umookominagebitobaiere2toshira22ulabal2ul2culoma2tockuku2erful2ka2ish\
+        ish2ak22nado22man2alle2ffusni22enolitisononishosaurus2ese2aita2ik22geaakeusola2llo22ushedron2mvi\
+        r2ootumimono2ifeuffulus2cusm2tisa2horaxs2stitisiyeniomotare2tis2la2culam2copulalkkella2rum2tis2m\
+        22sta2en2ium2ura2schoimanharynxish2eafiotta2iumeperson2ri2a2scum2nariusemdungseffekttusok2culuse\
+        ciesa22lbulocerebellum2yperson22no2cr2knifeomesnaco2sluminmidazoliumo2a22nla222l2il2llaularium2m\
+        22lus2rium2sx2cella22rekeerson2taffashiraashi2hai2fan2ffchul2mnoag2mga2umahr2ort2n2mto2nger22ief\
+        aft2a2ourwifetsu2ffanhikomiserson2uck2raft2oeaf2heeporksgooseooseech2ish2erson2achwarfuman2hamac\
+        allit2isarrowful2ersonnpoofino22ng2owitzianusabeest2owl2papago2gowful2uffurfrful2ish2in2pitzhild\
+        festo2ervant2nhildnnnock2rouse2ouseorksorkserson2mmer2nd2lasma2liumocha2hora2fthemumslastron2ter\
+        num2agusa22ul2erson2person2n2obi22agei2kin2a2aa2ori22a2imoe2ee2ona2una2an2re2an2i2erson2iegshail\
+        22ashiodoshi2iaoshiman2ao2r2li222edron2uanuin2o2u2i2a2ea2orusankton22icillaretalumeron2h2us2rium\
+        2s22a2elvis2oma2niumeuronlaniumoniumis2mcanthoma2arcinoma2ibroma2ympharcoma2viarixix222nis2n2m2n\
+        ea2latt2n2uminiummoniumlladiumridiniumhorion2s2urus2eciesumm2s2arum2ceperson2a2ea2thoxyethoxyxyy\
+        ridiniumerminusa2oucleolusxus2arcausskosiushrum2ld2usoggin2hinx2lastoma2holecystitisndothelioma2\
+        ibroma2eratoma2usga2ao2ypeussta2xarca2laium2ltimatum2ion2icumaulusoan2rcraft2chchiusbarusinorium\
+        2mnsinsion2tifsto2rson2ychionrum2gma2perherorpedo2agusrtexrtexeptumarsussphhik2tisla22opteryxarc\
+        hfish2sortexaguse2siaul2chllo222kislisranch2od2ma2um2branchnsgo2n2cusslastoma2ytoma2esthesum2mbo\
+        lusa2ishn2s2nnon2atrix2ton2lonrum2rada2umaffa2iontissaria2ile2eer22tam2nffula2go22ishazi2lafish2\
+        co2sh2l2do22ma2usca2rsusbial2ul2sh2l2ishungsmotivskoniumidiniumyryliumuinoniumhiazoliumloxy2itsp\
+        antsrry2ck2sh2otme2mfa2denitisy22noul2akersorks2o2suxella2ium2ionann2ma2ca2orksruit2nifetuffastc\
+        upful2ffssul2ffor2ishavernosushyllumpongiosusum22h2nifenco2s2sssnettoeu22lus2m2o2ra2o2ums2rs2n2e\
+        2nolo2lum2o22tto22lkoxy2alfish2myois2lan2s2etacarpusodium2sful2ney2is2eris22m2feum2or2ltatumara2\
+        us222east2ead2erson2ulustto2ero2etto2tislaful2tis2ionalisimo2leheliozoan22ir2ematoma2matoma22hor\
+        aystisoan2llum2nny22l2semaul2erson22uszah2eau2z2oissa2mater2idi2fafularioseuxla2oixn2n2ffumo2o2l\
+        2l2nsillum2imulusnhakaee2s2n22s2saw2nlipsaw2r2ife2mo2ish2erson2weyan22an2acha2ozoan2skosan2an2ll\
+        a2ome2oan2carcinoma2isystitisocheritonitisteatoma2scentrum22trichiscopus2mbus2toma2nnyliumnemais\
+        2themum2h2abras2ul2erson2ula2222endibus2adiusumulusebula2tratuslana2a22rfish2echitis2israchrache\
+        usumlastula2astrula2achymathesia2ium2fish2en2centeizahizaoratrix2enoma2gium2chyma2sm2archsums2ll\
+        a2ellao2sh2otl2denocarcinoma2arcinoma2teeperson2atrix2asdium2itrixtutto2se2inium22tiere2tusva2st\
+        oytumsus2vatoriiesatiotium2ffllationfish2ictor2nerful2iongo2ligarchzione2sish2um2cho2ulaa2u22opi\
+        a2o2serson2e2simoeus2us22ryul2artialium2pm2chaeonountry2ish2taffranch2occusorticus2uizchispecies\
+        erfnionimbus2tratusrium2ix2hto2rson2liticsronylammoniumedia2openium2mrcoma2rusonrchful2ir2ir2lia\
+        2um22ma2edron2dss2us2umanchranium2iumiumiconum2iaurus2rula2acillusoccusaolustinuum2hhallustylusu\
+        lento2ndusish2odecahedron2eolis2chish2cracyum2sephalumcopedesornish2insh2l2sh2l2hedron2mvir2e22n\
+        ful2rson2droma2s2rvix2lpusaniumn2n2mszoon2adiculitisn2m2ba2eba2rdirvix2tricorrhiza2uriumclethalm\
+        itisasm2lpinxeleton2sta2erson2atis2n2n2etsashusionionss2s2n2yx2thusdium2irema2rema2drummis22isiu\
+        musomenoniumonronrium2ion2ionnomenonnema2agmallumstron2uronisctateshion2suslamium2on2oneciesia2i\
+        aeciesos2umantimulusaganza2kiakiaa2m2olo2ese2niumniumeniumanadiumlastoma2pithelioma2ranuloma2hec\
+        oma2anthoma2sailful2ipful2hio2ioerson2ifeferksaa2sh2ot2l2ul2erson2tisma2s2n2fful2machrsusbiao2si\
+        mo2seserson2um22lisersperson2clypeusersonrigeniaternumholass2ion2ion2ytoma2lioma2eriesrasful2oma\
+        2isdenocarcinoma2uodenumnterrichach2issimrix2liaconix2lossusyol2ix2enerson2hem2ium2tissh2l2ustis\
+        nephritiss2ynechialysisrcepst22llo22hildumffspringoma22ruit2hot2oul2a2timulusyesumttoraftish22to\
+        2iummrchn2m2hinx2emium2umla2a2umgiooporidian2h2as2ahkoxychaeon2umcleushhondroma2a2hsh2l2ffin2ial\
+        ftalf2222m2ish2l2rysumoglossa2lastoma2ndothelioma2a2ericytoma2arcoma2a2oanrebrum2oliniumitoris2r\
+        tex2ron2ix2resthesia2nsrotum2ich2orax2a2ancreas2ecahedron2edron2aidecahedronarallelohedronchtich\
+        hlon2rchachchix2dadeutics2ia2a2sranchliticonuplex2agusrichosistahedronichoron2horon2edron2ron2er\
+        onidecahedron2schlostisrammateushant2n2shi2ssnifeiola2echocuseleuton2totoneleuton2ssls2sh2l2sh2o\
+        t2rson2wer2ul2erson2taffife2ulusphalus2anicumdusa2duluslumon2lpinx2maca2rax2n2n2hetto2atrix2ctah\
+        edronadius2orusma2s2eum2um2ossusrynx2mma2mnioneronhemaachissis2ma2iarsusichinodecaexahedron2ctah\
+        edron2etraiummtor2um2gos2rio2umsatisats2na2rium2raishrium2yperson2ciesnatusa2xilladiusooukk2sh2u\
+        it2ipe2affha2seorpscorps2n22h2os22rhynch2a222i2a2n2in2ariirokashiierma2a222imbda2xnida2sh2l2ori2\
+        2riononudakeage2sssh2l2necht2ansummstissh2l2llalaterrich2m2ninumrmumra22emanticsusful22a2saciese\
+        lyphopedionpermum2saf2s2nksreperson2cksauilloistis2carcinoma2isandula2anuloma2uenga2osima2enoma2\
+        garcinoma2aetaitorisuna2lariaorangusva2cleustaeciesrissalushaletto22aciesiumhumis2sy2a2fowl2lus2\
+        skie2ki2i2laise22infish2i2ulaa2a2iesada222e2ucleuszouhquaissnm2onge2hion2fis2ytoma2ditisrium2ix2\
+        in22rchula2shka2222ostimulus2an2inerson2rix2ss22blastoma2ish2una2sh2oryaurus2lennium2ochondrionk\
+        koumcleusanktonlis2ctumn2riesemensrobiluserium2on2unami2dodiumu2umama2sm2e22aihephalon2eronachma\
+        d2ygdala2pendix2rebrum2una2murrcaphros2umahlpinxapulaichrsusbiampanum22rprcaria2murrcamnion2orks\
+        upliusphros2rapteronleteurondialeotaspishema2riesoma2rsbiaffn2iumymidazoliumxyyridiniumriphenylp\
+        hosphoniumpatheiais2olismbolnifeetazoan2ry2aum2iarcy2auss2eansky2uxnennium2oentgen2abau22rchlflo\
+        2lix2tifrieserimposium2aotteniefefing2e22uga2auga2eum2datium2no2sn2n2agsdium2erour2nor2ul2n2ilda\
+        ft2ish2eer2ishuyidusravida2ara2ibrissaumor2uado2ee2ado2dystrophyslunge2o2ee2man2ho2fizgi2rararif\
+        ktonma2iza2hiza2ism2edron2chbaeba2kkiknikry2tzenomenonlyhedronemensacecraftourgeoisolaredusaorpe\
+        do22hium2is2lastoma2yelitisoan2emoma2is2strocytoma2lastoma2pithelioma2a2etinitisrochasculitis2is\
+        h2ips22sh2wl2l2in22222fldintiffs2f2inoma2flful2m2eciesbspeciesranchonch2usst2avida2mmiutmmiuqa2r\
+        on2ioctahedronuchlon2lesea2ashitoshirthritisendroglirichranchomosoma2isisarium22is2ithiumeroxyrt\
+        hritis22rimano2rsonus2oofofffarf2gnedmiummsh2l2stra2hino2uychinkeerebellum2ish2umanaeliefium2um2\
+        ado2m2rium2na2mousesa2kritisritis2rumum2oekkoekulinful2icon2imusm2222o22alonchusrvix2sia2inoma2o\
+        cculusnglioossa2athusaphosppocampusmanrvaxiconpelohedron2zisecium2tellon2mahelionclegma2euronota\
+        spisis2eronlene2ahtofauna2ermigma2nthetonheseiso2gioezzo2byca2zhorusale2222laro2amilias2t22susbr\
+        anchculus2llarialusum2s2ula2lg2rachedron2aidecahedron2idiumchhlooostysento2om22od2sustosnulus2rt\
+        itistron2hronrdiondritispe2anium2therionryon2macleusontitiscuskostncreatitisseidionerosmaonoviti\
+        selioma2nichsphlitisle2eusus2usa2sstumum2nopsisisces2opoeia2os2thridiniuminiumeniumpecieshiazini\
+        umtisbranchul22otifiumpeciesntenna2ermatitislectrosythesiselieftimuluschranchiaoma2era2pecieso2s\
+        imo2shaw2shaw2haube2el2ushizash2l2ziniumdinyloxytingakichi2hki2ko2ta2lothesperson2ifful2rful2in2\
+        ladu2ul2saurus2ish2usma2nchyma2um2ranchto2aia22ischorax2archniumrchureau2ctdelolo2moniumeta2ron2\
+        dium2agmon2s2tisneuritishrahedronmo22k22llolusx2ento2s2s2vaypeusrnubituscephalitischildrson2bisv\
+        aneussitus2bissdium2xta2r2nua2illa2lexitus2isaumpspefforkso2dozent2ternum2arsussussiontisss2daca\
+        ium2tinoma2sus2vaulusomenonsisciss2or2leusciamiento2nm2sesagmaitn2nium2emautto2lexnemautrixcepha\
+        lon2cha2tariontarionranchion2a2otitisecalosoeba2suspisemaslrax2tonmranchscolexitochondrionertebr\
+        aoan2or2triculusiumncpipleuronungusaviceferendumulva2anthoma2urus2igmaum2ideusus2m2ootficationio\
+        n2nimbussnnium2miniummanrius2brachdecimanriusumino2orvir2mvir2iuminiumnir2stisneuritisesiumusebu\
+        la2trontiumlna2f222nik2ik2ariusolis2uxllol2tisla2yarcoma2la22erosyphaa2occusendron2odecahedron2s\
+        idodecahedron2boctahedron2edron2deraftul2hal2m22smycesmabiumium2tum2s22a2u2eh2hik2y2y2rium2rium2\
+        2ifmm2n22chd2id2eseraftul2tra2ra2o2ish2no2ish2trapeziuma22yfpoliticslfritzefritzt2t2us2hildul2er\
+        son2noma2tzaa2chyma2nhuslsh2l2gravida2rachravida2ara2ulaera2ididlon2oma2pium2e2a2rabola2oofpamnd\
+        inosusceful2elese22i2vir2ostaaxilla2gesima2atrum2ion2tisuserson2sius2ts2a2an2anffosh2shh2ss2ank2\
+        k2ish2fishhead2uck2ck2ul2ifesh2l2uuanfhhamihiuashi22eiyokuiyan2an2akie2ert2frchk2ish2eaf2ideschm\
+        fritzsildck2aft2mgo2hak2tz222sh2ot2l2rdo2h22ish2ng2asosm2coccuspolyhedronlaegshanksootlafaurus2l\
+        um2us2ibrillarichulusma2rthritisuslollenhecaish2fperson2laok22lla2la2toesonium2liumrcharchelfish\
+        2ul2razzorazzo2aumisch2yffiusescks2worksaft2222rumead2orksk2as2um2e2oma2lastula2ormula2illumbram\
+        2rion2rul2erson2mladium2do2ish2ngful2erson2hitis2sh2uit2ffado2ggotussul2etstzacillusoccusum2ndo2\
+        ul2sus2yla2iumlosluslossusyoideusus2xendix2ipelago2ometatarsuslusgo2frionitisjunctiva2taaneurium\
+        chula2m2us2s2dquartersoex2ima2ma2icon2ium2rix2inomenonlum2na2yhedron2stusaumliumiumoniumrgo2loss\
+        usciesrmula2manosahedrondex2nguacustifmbulava2tahedroneptum2rson2enomenonemiumhema2rieseroeciesi\
+        mulusxontrahedronrrtexrtuoso2lcano2iffeargo2axillapinatus2m2a2heeksrman2ish2umotatomsrium2s2nome\
+        nonniumrchm22culamma2ism2ma2tonrax2on2ochi2la2a22an2oa2eon2bon22en2ne2olis2haman22lesperson2opef\
+        ish2riesichrsusochful2n2alisot2l2tissusitisaginitislastoma2arcinoma2a2ula2us2arium2iumium2laaolo\
+        uloma2lkylammoniumratrammaton2eemma2ethylachtoliumolosinos2ums2person2numenonsss22h2sa2rytenoide\
+        usyoideusditisharyngeusropinoma2sllo2sh2l2charp2ish2eafark2a2o2ia2u2lin2sak2orios2olo2lo2laulma2\
+        medusa2trixs2ffperson2a2nna2uyumanedium2erson2eriesillaersospinalisomynouth222ctahedron2etrahedr\
+        onhussns2na2nium2psium2ium2ium22rummmoniumydraziniumhenylammoniumchteschtrix2umidecahedron2lopha\
+        ummaus2cusino22onion2riumset2erch2sh2l22222cha2ashitoshii2shinonephritissr2r2ish2an2fish2n2n2m22\
+        2eanailiffmrsonivilegedarf2eriffgned2eciesratum2ula2ful22tishoen2oen2n2n2e2culbronchusm22llaisml\
+        ingra22illuso2tism2nifeiuncula22salia2um2eful2ummlpararium2s2sesul2orksgiuful2e2eful2tl2tful2oof\
+        ui2uni2aish22222n22offfdgeuffna2ranuloma2ionum2ongium2romawl2l22h2nksnsi22siumhagusionis22moan2y\
+        charynx2richongitistisma22oma2n2n2lusum2achiomyoma2poma22rcoma2otoma2monstinuum2ionn2m2iticssriu\
+        sarius2nhik2ssephalon2eron2ago2dulum2tiserigmanovitis2oino2alcaneum2chrfum2ian2fhon2iusiussh2l22\
+        2sda2ishiso2au2esish2nium2saurus2m2ephalic2odiummema2i2lusis2yfish22a2slla2le2e22ss2umlastoma2uc\
+        leus22io2ususul2eful2a2itisematoma2matoma2gusothorax2opagus2sumtism22on2o22ungan2ngedron2cha2ema\
+        lastoma2lossusarcoma2a2oxsslastoma2arcinoma2pithelioma22mul2mbalo2therium2lambalummbalum2rusecie\
+        seum2upful2poonful2rium2um2to2ul2no22riuso2m2n2asso2tnik2agotto2tnik2to2tisciontimulusempsish2hu\
+        ffmbargoifeariffumanerson2gusaryngioma2fadsffoctahedronum2usstisicsa2rsusbiatismron2icosahedron2\
+        rix2occusoan2ardiumnifearynx2icshorus22elitisis2m2ma2islussum2malioma2ix2horax2nomenonlusynx2n2e\
+        xye2num2aiaon2orion2rriculum2nganeselybdenumenoma2heroma2iomyoma2poma22rcoma2tisnsl2lass2urorcin\
+        oma2emiustiscolitis2slossusdeusumssh2y2ersonufflastoma2ndothelioma2a2ericytoma2arcoma2ium2ochaao\
+        anilusotozoaneeteron2rema2visis2asartemorion2ctahedron2etrahedron2arcinoma2a2emon2nachton2la2umd\
+        ex2phroma2va2cleuseudopilusron2hema2mplex2imulusrtexlcano2rynx2sitisstron2uronlcaodecahedron2edr\
+        on2horon2edron2re2ice2reiceium2zo2num22avuanthoma2gioma2e2ikhaftaft2harynrachea2isrium2n2itisise\
+        diondion2ya22arcoma2cusch2cumulusso2a2seandum2a22a2sa2z2lisychosarcinoma2erma2aarcoma2n2m2seson2\
+        tex2aanxynx2urontumiefrl2spusaterionmionnomenonagmal2slamusrax22lankton2moniumoxyenoma2gaheroma2\
+        cillusota2s2aetasm2slariaorangusussomerulusanuloma2ruslo2nsky2fex2ktonva2cleusrson2enomenonicaly\
+        hedronolactinoma2ion2liefentgen2eepemensmainerombrtexon2ma2erson22m2ry2tzemensokes2lism2ul2worth\
+        u2u2fifrriffifse2is2ma2aetatoma2broramennglionimmmphoma2rix2ycheron2rcoma2ta2nrcinoma2ondrastoma\
+        22elitisrcoma2ermumeciesbspecieslossusarisharyneciesbspeciestis2cholangitistissma2lumacusmefish2\
+        22a2menonisium2eritislusslia2agmag2rolophus2phalonymquoddy2zzo2ria2ooron2ntahedron2oncahedron2ac\
+        hmalis2ychich2talitrontxisssism2hritisum2tisus2senonium2malium2um2tisitis2horusreme2tis2nniumsce\
+        ncess2si2k22rium2ul2iserson2uffemma2sma2um2onma2rhus2h2ik2ton2chf2s2s2nuauss2tomyonssmonium2oeia\
+        2mius2aench2nomeruluslo2manmaumenomenonaltisolexeciesomancellusecosis2ochnusomion2anchbo2lluylot\
+        horaxphellanglionnusioma2ikumanphafeculusmphoma2mmatosisllala2renchyma2lusankton2eumoperitoneumd\
+        2erygium2paecieslcusbesmpanumescheps2nnium2aium2staffto2vir2nalia2lia2um2o2s2m2lastoma2horoiditi\
+        shoraarcoma2eumonitisleroma2nusitis2sonchatusedulla2na2stis2pharyngeusia22phorossndo2no22chnm2sl\
+        dciesnalisriusium2m2n2ashinageineriagena222lahyllumulumrixhedronm2isrthritistisarthritissh2l2occ\
+        usa2yoideusastoideusleuron2hyroideusrionn2richul2ssolcano2o2son2usmaryngeusfdusex2islaum2rieseci\
+        es2s2fldix2o2rix2iado2edo2safpsuff2theca2nsful2ma22miya2ix2m2hcheniumcahedron2achmon2risylammoni\
+        umron2ix2ihexahedronahedron2idemoniumsoniumenyllisopylammoniumychlonchonlonoscephalontronpagusng\
+        iitismbolus2hlebitis2a2tiss2tisionstomalia2um2tops2onranchiaragma2pithelioma2ish2erson2son2hulus\
+        emapeciesoshi2fefssperson2fftisencephalitissst2s2eloma2andibularisemporaliscillusanchsma2n2n2lip\
+        oma2oma2n22m22oon2gersh22o2ortexncephalitis2xosarcoma2enoma2gioma2a2balumherium222ostonctusno22u\
+        ffiefffrmula2l2rson2oofoffeciesimulusibroolymyositisstoma2ma2m2ma2oma2lastoma2a2mus2usitisaon2os\
+        isisoccusncephalitisentriculitisktontronaioma2x2umicson2aaurus2nula2cinoma2cusedo2ch2mos2iesna2u\
+        scepsixry2tzillaria22faciesridian2mulusobilusembolussxrissalusumfif2ma2a2arcoma22ma22ma2tis2ma2e\
+        us2uslla2ex22lla2torion22iaryngitisalatinusonmritisritiseum2um2n2m2n2m2halonebrum2sitiscusumella\
+        2asifetomaoma2isigmamaomallusombuse2imaliaix2susrticychlastoma2a2arcoma2rynx2ma2edron2eca22ondro\
+        scapulariseidomastoideusrrusmuluslaum2on2ch2ahedron2ahedron2nionhexahedron2rsoniumhosphoniumankt\
+        on2eryx22a2arcoma22ositiscuslyitis2encephalitisa2eculama2ism2ium2teros2semma2nheca2",
+    &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 3, 3, 3, 3,
+        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8,
+        8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        8, 8, 14, 14, 14, 14, 15, 16, 16, 17, 17, 18, 18, 18, 18, 18, 18, 24, 24, 24,
+        24, 24, 24, 24, 24, 24, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25,
+        25, 25, 25, 25, 25, 26, 26, 27, 27, 27, 27, 28, 28, 28, 28, 28, 28, 29, 29, 30,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 36,
+        36, 36, 36, 37, 37, 37, 37, 39, 39, 40, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+        44, 44, 44, 44, 44, 44, 44, 45, 51, 51, 51, 51, 51, 51, 54, 54, 54, 54, 54, 54,
+        54, 54, 54, 54, 54, 56, 56, 66, 66, 67, 69, 69, 70, 70, 70, 70, 71, 71, 71, 71,
+        71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71, 71,
+        71, 71, 71, 72, 72, 72, 72, 72, 72, 74, 74, 74, 74, 74, 74, 74, 74, 74, 75, 75,
+        75, 75, 75, 79, 79, 79, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 81,
+        83, 83, 83, 83, 83, 83, 84, 86, 86, 87, 88, 88, 88, 89, 89, 89, 89, 89, 89, 89,
+        89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89,
+        89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89, 89,
+        89, 89, 89, 89, 89, 89, 89, 92, 92, 92, 92, 92, 96, 96, 96, 96, 97, 97, 97, 97,
+        97, 97, 98, 100, 100, 100, 102, 102, 102, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105, 105,
+        105, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 106, 107, 107, 108, 108, 108, 108, 108,
+        108, 110, 110, 110, 110, 110, 110, 112, 112, 112, 114, 114, 115, 115, 115, 115, 115, 115, 115, 115,
+        115, 115, 115, 115, 115, 115, 118, 120, 120, 121, 126, 128, 128, 128, 128, 128, 130, 130, 130, 130,
+        130, 130, 133, 133, 134, 134, 135, 135, 135, 135, 135, 135, 136, 136, 136, 139, 139, 140, 140, 140,
+        142, 142, 143, 143, 143, 143, 143, 144, 146, 146, 147, 147, 147, 147, 147, 148, 148, 148, 148, 148,
+        148, 152, 152, 154, 154, 154, 154, 154, 154, 154, 154, 155, 155, 155, 155, 155, 156, 156, 156, 156,
+        156, 156, 159, 165, 165, 165, 169, 169, 170, 172, 173, 176, 176, 178, 180, 180, 183, 183, 187, 187,
+        187, 187, 187, 190, 194, 194, 200, 200, 204, 204, 208, 208, 208, 208, 208, 208, 208, 210, 210, 210,
+        211, 211, 211, 212, 212, 212, 212, 212, 212, 212, 212, 212, 216, 222, 232, 232, 232, 232, 236, 237,
+        237, 237, 237, 241, 241, 241, 241, 243, 243, 243, 245, 245, 249, 255, 255, 259, 259, 263, 273, 275,
+        275, 275, 275, 275, 277, 279, 279, 279, 285, 285, 286, 288, 289, 289, 292, 293, 299, 300, 302, 302,
+        302, 302, 304, 304, 304, 306, 307, 312, 316, 317, 322, 329, 329, 329, 329, 329, 338, 338, 338, 338,
+        338, 338, 338, 338, 340, 345, 345, 346, 346, 346, 347, 347, 347, 347, 347, 347, 347, 352, 354, 354,
+        354, 358, 358, 359, 359, 359, 359, 359, 359, 359, 359, 363, 363, 363, 367, 371, 372, 372, 375, 375,
+        375, 375, 378, 381, 383, 384, 385, 385, 385, 385, 385, 385, 395, 399, 399, 399, 399, 399, 399, 399,
+        399, 404, 405, 405, 405, 405, 405, 405, 410, 410, 410, 410, 410, 416, 422, 422, 422, 422, 422, 422,
+        422, 422, 422, 424, 428, 428, 428, 428, 428, 431, 431, 431, 431, 431, 431, 431, 431, 431, 431, 437,
+        439, 441, 442, 442, 442, 444, 447, 447, 447, 449, 449, 452, 452, 452, 452, 452, 452, 452, 452, 455,
+        455, 455, 459, 464, 464, 466, 466, 471, 475, 478, 479, 480, 482, 485, 488, 494, 494, 502, 502, 505,
+        505, 510, 510, 510, 510, 511, 511, 511, 511, 511, 511, 511, 511, 511, 511, 511, 511, 511, 513, 513,
+        518, 519, 520, 525, 525, 526, 526, 526, 526, 526, 533, 533, 533, 533, 533, 533, 533, 533, 535, 535,
+        535, 539, 540, 541, 541, 541, 541, 544, 544, 549, 549, 549, 549, 549, 553, 559, 559, 559, 562, 562,
+        565, 565, 565, 565, 565, 565, 565, 569, 571, 571, 571, 571, 571, 571, 571, 571, 571, 572, 573, 573,
+        576, 576, 577, 577, 577, 577, 577, 577, 577, 580, 580, 580, 580, 580, 580, 580, 580, 580, 580, 584,
+        585, 585, 585, 585, 585, 585, 585, 585, 587, 587, 587, 588, 588, 588, 588, 588, 589, 589, 590, 591,
+        591, 591, 591, 592, 601, 601, 601, 601, 601, 608, 614, 614, 614, 614, 615, 615, 615, 618, 620, 623,
+        627, 627, 629, 633, 633, 633, 633, 633, 633, 638, 638, 638, 641, 646, 646, 646, 646, 646, 652, 652,
+        652, 652, 652, 652, 652, 652, 654, 654, 655, 655, 655, 655, 659, 659, 659, 659, 659, 659, 659, 659,
+        659, 659, 663, 665, 665, 672, 673, 673, 674, 674, 674, 674, 675, 675, 675, 680, 680, 680, 680, 680,
+        686, 686, 686, 688, 691, 691, 691, 691, 691, 691, 691, 692, 692, 692, 692, 692, 692, 692, 692, 694,
+        695, 697, 699, 700, 701, 704, 704, 706, 706, 706, 706, 706, 706, 706, 706, 706, 706, 706, 712, 712,
+        712, 712, 712, 712, 718, 718, 718, 718, 718, 719, 719, 719, 722, 726, 727, 728, 729, 730, 730, 730,
+        730, 731, 731, 731, 731, 731, 731, 732, 733, 734, 738, 738, 738, 738, 739, 740, 740, 740, 740, 740,
+        740, 740, 740, 740, 740, 743, 744, 744, 747, 748, 748, 748, 749, 750, 750, 750, 750, 751, 752, 755,
+        761, 763, 766, 769, 769, 769, 773, 773, 773, 773, 773, 773, 773, 773, 773, 777, 786, 786, 786, 786,
+        786, 786, 786, 791, 791, 793, 793, 793, 793, 796, 797, 797, 797, 797, 797, 797, 797, 801, 805, 807,
+        808, 808, 808, 808, 814, 814, 814, 815, 816, 816, 816, 817, 817, 822, 822, 824, 824, 824, 824, 824,
+        824, 824, 824, 824, 829, 829, 829, 834, 834, 834, 834, 834, 834, 834, 834, 836, 836, 836, 840, 843,
+        843, 843, 843, 843, 843, 843, 843, 843, 843, 846, 846, 849, 849, 849, 849, 849, 849, 849, 849, 849,
+        854, 861, 864, 864, 864, 865, 865, 865, 865, 865, 865, 865, 865, 866, 867, 867, 870, 870, 871, 871,
+        871, 871, 871, 871, 871, 871, 876, 876, 876, 879, 879, 880, 884, 884, 885, 885, 885, 885, 888, 889,
+        890, 890, 892, 892, 892, 892, 901, 905, 906, 908, 911, 911, 914, 914, 914, 914, 916, 918, 918, 918,
+        921, 922, 923, 923, 926, 929, 939, 939, 946, 946, 946, 950, 953, 953, 956, 956, 960, 961, 962, 965,
+        970, 970, 973, 976, 980, 980, 980, 982, 982, 983, 986, 986, 987, 988, 989, 989, 989, 989, 989, 992,
+        992, 992, 996, 996, 1004, 1004, 1004, 1004, 1004, 1005, 1005, 1011, 1015, 1015, 1016, 1016, 1016, 1016, 1018, 1023,
+        1026, 1032, 1032, 1032, 1032, 1035, 1036, 1036, 1036, 1037, 1039, 1039, 1043, 1045, 1045, 1048, 1048, 1049, 1052, 1052,
+        1052, 1052, 1052, 1054, 1058, 1058, 1060, 1060, 1064, 1064, 1065, 1076, 1076, 1076, 1079, 1083, 1083, 1083, 1085, 1090,
+        1097, 1097, 1103, 1106, 1118, 1118, 1118, 1118, 1122, 1122, 1132, 1132, 1132, 1135, 1140, 1140, 1140, 1140, 1140, 1145,
+        1145, 1145, 1145, 1150, 1155, 1155, 1155, 1158, 1158, 1160, 1160, 1162, 1165, 1165, 1165, 1165, 1165, 1166, 1169, 1169,
+        1169, 1169, 1169, 1169, 1173, 1173, 1173, 1173, 1173, 1173, 1179, 1188, 1188, 1188, 1189, 1189, 1190, 1192, 1192, 1192,
+        1192, 1192, 1193, 1193, 1194, 1194, 1194, 1194, 1194, 1194, 1194, 1197, 1197, 1201, 1201, 1203, 1203, 1203, 1206, 1211,
+        1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1212, 1214, 1214, 1214, 1214, 1223, 1223, 1223, 1225, 1227,
+        1227, 1229, 1229, 1229, 1229, 1230, 1235, 1235, 1235, 1237, 1238, 1239, 1239, 1240, 1240, 1240, 1240, 1240, 1241, 1241,
+        1243, 1243, 1243, 1243, 1248, 1249, 1249, 1250, 1250, 1250, 1250, 1250, 1250, 1250, 1250, 1251, 1251, 1253, 1259, 1259,
+        1265, 1265, 1268, 1268, 1268, 1268, 1268, 1268, 1268, 1268, 1271, 1271, 1271, 1271, 1271, 1272, 1272, 1272, 1273, 1273,
+        1274, 1274, 1275, 1275, 1275, 1277, 1280, 1282, 1284, 1285, 1288, 1288, 1288, 1288, 1288, 1289, 1289, 1289, 1289, 1290,
+        1294, 1294, 1294, 1297, 1297, 1297, 1301, 1301, 1301, 1306, 1306, 1306, 1306, 1306, 1310, 1310, 1314, 1314, 1320, 1323,
+        1323, 1323, 1323, 1329, 1333, 1333, 1333, 1333, 1333, 1333, 1333, 1333, 1333, 1336, 1338, 1339, 1339, 1339, 1340, 1340,
+        1340, 1340, 1340, 1342, 1342, 1344, 1344, 1346, 1350, 1350, 1352, 1352, 1352, 1352, 1352, 1352, 1352, 1353, 1355, 1355,
+        1355, 1355, 1355, 1356, 1356, 1356, 1356, 1356, 1356, 1356, 1356, 1361, 1361, 1362, 1362, 1362, 1363, 1363, 1363, 1363,
+        1364, 1370, 1375, 1375, 1378, 1380, 1380, 1380, 1380, 1383, 1386, 1386, 1387, 1387, 1387, 1387, 1387, 1387, 1387, 1387,
+        1389, 1389, 1389, 1389, 1389, 1393, 1393, 1393, 1393, 1393, 1393, 1393, 1393, 1393, 1394, 1394, 1394, 1394, 1394, 1394,
+        1394, 1394, 1394, 1394, 1394, 1394, 1394, 1394, 1395, 1397, 1397, 1397, 1397, 1397, 1397, 1403, 1403, 1403, 1403, 1403,
+        1403, 1403, 1406, 1406, 1406, 1406, 1406, 1406, 1409, 1411, 1413, 1417, 1417, 1421, 1421, 1421, 1421, 1421, 1421, 1421,
+        1421, 1421, 1422, 1422, 1422, 1422, 1424, 1426, 1426, 1426, 1428, 1428, 1428, 1434, 1434, 1434, 1439, 1439, 1439, 1439,
+        1439, 1439, 1439, 1439, 1439, 1439, 1439, 1439, 1439, 1439, 1439, 1443, 1448, 1452, 1452, 1453, 1453, 1454, 1455, 1455,
+        1458, 1458, 1458, 1458, 1458, 1460, 1460, 1460, 1460, 1464, 1468, 1468, 1468, 1468, 1475, 1476, 1478, 1483, 1492, 1492,
+        1492, 1492, 1493, 1496, 1496, 1496, 1498, 1499, 1502, 1503, 1503, 1503, 1503, 1503, 1503, 1505, 1506, 1506, 1507, 1507,
+        1508, 1510, 1510, 1510, 1512, 1512, 1516, 1520, 1523, 1524, 1524, 1525, 1525, 1525, 1525, 1525, 1526, 1526, 1526, 1526,
+        1526, 1526, 1526, 1533, 1533, 1533, 1538, 1538, 1538, 1546, 1549, 1553, 1553, 1553, 1553, 1553, 1553, 1553, 1553, 1560,
+        1560, 1561, 1565, 1565, 1566, 1567, 1567, 1568, 1570, 1571, 1575, 1580, 1580, 1585, 1585, 1585, 1586, 1586, 1586, 1589,
+        1589, 1590, 1590, 1590, 1590, 1590, 1595, 1595, 1600, 1600, 1608, 1611, 1615, 1615, 1616, 1621, 1622, 1622, 1627, 1632,
+        1636, 1638, 1638, 1646, 1646, 1647, 1649, 1649, 1654, 1657, 1660, 1660, 1660, 1666, 1666, 1666, 1667, 1676, 1681, 1682,
+        1683, 1684, 1688, 1690, 1691, 1691, 1691, 1691, 1695, 1695, 1695, 1695, 1699, 1704, 1704, 1711, 1715, 1719, 1719, 1719,
+        1721, 1723, 1724, 1724, 1724, 1724, 1728, 1728, 1734, 1734, 1736, 1742, 1743, 1749, 1749, 1753, 1753, 1757, 1757, 1757,
+        1757, 1758, 1758, 1758, 1758, 1758, 1766, 1768, 1768, 1768, 1768, 1768, 1769, 1769, 1769, 1769, 1771, 1771, 1771, 1771,
+        1771, 1771, 1771, 1771, 1776, 1776, 1776, 1776, 1776, 1776, 1777, 1777, 1777, 1777, 1778, 1778, 1781, 1781, 1785, 1785,
+        1785, 1790, 1790, 1793, 1793, 1795, 1795, 1797, 1797, 1797, 1803, 1807, 1808, 1808, 1808, 1812, 1814, 1817, 1817, 1817,
+        1817, 1817, 1817, 1817, 1818, 1824, 1826, 1826, 1831, 1831, 1831, 1831, 1831, 1831, 1831, 1831, 1831, 1831, 1832, 1835,
+        1842, 1845, 1854, 1854, 1854, 1854, 1854, 1856, 1856, 1856, 1857, 1857, 1857, 1861, 1862, 1862, 1862, 1862, 1862, 1862,
+        1862, 1862, 1870, 1871, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1877, 1880,
+        1880, 1884, 1888, 1888, 1888, 1889, 1889, 1889, 1889, 1889, 1896, 1898, 1898, 1899, 1899, 1899, 1900, 1900, 1902, 1902,
+        1902, 1902, 1902, 1902, 1906, 1906, 1906, 1908, 1913, 1916, 1916, 1917, 1920, 1920, 1920, 1923, 1923, 1926, 1926, 1926,
+        1927, 1934, 1937, 1937, 1937, 1937, 1938, 1939, 1939, 1941, 1941, 1941, 1941, 1941, 1941, 1943, 1943, 1943, 1943, 1943,
+        1943, 1943, 1943, 1943, 1943, 1944, 1944, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1945, 1947,
+        1947, 1948, 1948, 1948, 1948, 1948, 1948, 1951, 1951, 1951, 1951, 1953, 1955, 1955, 1955, 1955, 1955, 1958, 1958, 1959,
+        1959, 1959, 1959, 1959, 1959, 1959, 1959, 1965, 1965, 1965, 1965, 1965, 1965, 1971, 1977, 1981, 1981, 1981, 1982, 1982,
+        1982, 1982, 1982, 1982, 1982, 1984, 1984, 1990, 1990, 1990, 1992, 1992, 1992, 1992, 1992, 1993, 1998, 1998, 1998, 1999,
+        1999, 1999, 1999, 1999, 1999, 1999, 1999, 2001, 2001, 2006, 2008, 2012, 2012, 2012, 2013, 2017, 2017, 2017, 2017, 2017,
+        2017, 2018, 2020, 2022, 2024, 2024, 2028, 2029, 2034, 2037, 2039, 2039, 2043, 2043, 2043, 2049, 2049, 2049, 2049, 2049,
+        2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2049, 2050, 2050, 2050, 2053,
+        2054, 2055, 2055, 2055, 2056, 2057, 2059, 2059, 2067, 2067, 2067, 2067, 2068, 2068, 2068, 2068, 2072, 2072, 2072, 2072,
+        2073, 2077, 2077, 2077, 2077, 2077, 2077, 2080, 2080, 2081, 2085, 2085, 2086, 2086, 2086, 2086, 2090, 2091, 2095, 2100,
+        2102, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2103, 2103, 2104, 2105, 2105, 2105, 2105, 2105, 2105,
+        2105, 2108, 2108, 2111, 2111, 2111, 2114, 2114, 2114, 2114, 2114, 2114, 2114, 2114, 2114, 2114, 2114, 2117, 2117, 2117,
+        2117, 2117, 2121, 2121, 2124, 2124, 2124, 2125, 2125, 2129, 2129, 2129, 2129, 2129, 2131, 2131, 2131, 2131, 2131, 2132,
+        2133, 2134, 2135, 2136, 2136, 2137, 2137, 2140, 2140, 2140, 2144, 2144, 2148, 2149, 2149, 2149, 2149, 2149, 2149, 2149,
+        2149, 2155, 2155, 2155, 2155, 2156, 2160, 2160, 2160, 2165, 2170, 2174, 2174, 2174, 2174, 2175, 2184, 2184, 2184, 2184,
+        2185, 2186, 2186, 2188, 2188, 2192, 2194, 2195, 2196, 2197, 2197, 2199, 2199, 2199, 2199, 2199, 2199, 2200, 2200, 2204,
+        2204, 2204, 2204, 2204, 2204, 2207, 2209, 2209, 2213, 2213, 2213, 2217, 2217, 2217, 2217, 2223, 2223, 2223, 2223, 2223,
+        2223, 2229, 2232, 2233, 2233, 2234, 2234, 2234, 2234, 2234, 2234, 2234, 2237, 2237, 2241, 2244, 2247, 2251, 2256, 2259,
+        2259, 2259, 2259, 2259, 2260, 2260, 2264, 2264, 2264, 2264, 2264, 2268, 2268, 2270, 2270, 2271, 2273, 2276, 2277, 2277,
+        2277, 2277, 2277, 2278, 2278, 2280, 2283, 2286, 2287, 2287, 2287, 2287, 2290, 2290, 2290, 2290, 2290, 2290, 2290, 2293,
+        2298, 2298, 2298, 2298, 2298, 2298, 2298, 2302, 2302, 2302, 2302, 2306, 2306, 2309, 2309, 2313, 2314, 2316, 2316, 2318,
+        2319, 2321, 2323, 2324, 2325, 2327, 2327, 2329, 2329, 2329, 2329, 2334, 2334, 2335, 2337, 2337, 2337, 2344, 2347, 2347,
+        2347, 2347, 2354, 2354, 2356, 2359, 2360, 2360, 2364, 2364, 2364, 2365, 2366, 2367, 2367, 2367, 2374, 2377, 2381, 2382,
+        2388, 2390, 2390, 2391, 2393, 2396, 2396, 2396, 2399, 2405, 2413, 2414, 2416, 2418, 2418, 2418, 2418, 2418, 2421, 2425,
+        2426, 2426, 2426, 2429, 2429, 2429, 2430, 2430, 2433, 2433, 2437, 2437, 2440, 2440, 2440, 2442, 2444, 2450, 2450, 2453,
+        2455, 2457, 2463, 2468, 2468, 2468, 2469, 2475, 2475, 2479, 2485, 2485, 2485, 2487, 2490, 2490, 2490, 2494, 2494, 2501,
+        2506, 2508, 2508, 2508, 2508, 2510, 2510, 2510, 2510, 2510, 2516, 2521, 2522, 2522, 2530, 2533, 2535, 2537, 2537, 2537,
+        2537, 2537, 2537, 2537, 2537, 2543, 2543, 2543, 2543, 2543, 2543, 2547, 2553, 2553, 2553, 2553, 2560, 2560, 2561, 2561,
+        2561, 2562, 2562, 2565, 2565, 2570, 2570, 2570, 2571, 2571, 2571, 2571, 2571, 2573, 2576, 2576, 2579, 2580, 2580, 2580,
+        2580, 2580, 2580, 2580, 2580, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584, 2584,
+        2586, 2587, 2587, 2587, 2587, 2588, 2589, 2590, 2590, 2591, 2591, 2591, 2591, 2591, 2596, 2598, 2598, 2604, 2604, 2604,
+        2604, 2606, 2606, 2606, 2606, 2614, 2618, 2618, 2618, 2618, 2619, 2619, 2619, 2619, 2619, 2619, 2619, 2619, 2620, 2620,
+        2620, 2624, 2624, 2624, 2624, 2624, 2629, 2629, 2630, 2630, 2630, 2630, 2634, 2634, 2634, 2634, 2634, 2637, 2639, 2639,
+        2640, 2640, 2640, 2640, 2643, 2643, 2643, 2643, 2643, 2645, 2645, 2645, 2647, 2647, 2647, 2648, 2648, 2648, 2651, 2651,
+        2651, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2659, 2665, 2671, 2676, 2677, 2677, 2678, 2679,
+        2679, 2680, 2680, 2680, 2680, 2680, 2680, 2681, 2681, 2681, 2681, 2681, 2681, 2682, 2682, 2682, 2682, 2682, 2683, 2683,
+        2683, 2690, 2691, 2692, 2692, 2692, 2692, 2697, 2697, 2699, 2705, 2705, 2705, 2707, 2707, 2707, 2707, 2707, 2707, 2707,
+        2707, 2707, 2711, 2711, 2711, 2712, 2715, 2716, 2716, 2717, 2720, 2720, 2720, 2724, 2724, 2724, 2724, 2724, 2726, 2727,
+        2729, 2729, 2729, 2732, 2732, 2732, 2734, 2741, 2746, 2746, 2746, 2747, 2754, 2756, 2756, 2758, 2761, 2761, 2761, 2763,
+        2763, 2768, 2768, 2771, 2771, 2774, 2774, 2774, 2777, 2777, 2780, 2784, 2791, 2791, 2791, 2791, 2791, 2791, 2791, 2797,
+        2797, 2797, 2797, 2797, 2797, 2797, 2797, 2797, 2797, 2801, 2806, 2807, 2807, 2807, 2808, 2811, 2811, 2811, 2811, 2811,
+        2811, 2811, 2811, 2811, 2811, 2813, 2813, 2813, 2813, 2813, 2818, 2818, 2818, 2826, 2829, 2829, 2829, 2829, 2829, 2829,
+        2829, 2829, 2829, 2829, 2833, 2833, 2833, 2833, 2834, 2834, 2838, 2838, 2843, 2843, 2843, 2844, 2844, 2844, 2844, 2844,
+        2846, 2846, 2846, 2847, 2847, 2847, 2847, 2847, 2848, 2855, 2856, 2856, 2856, 2856, 2856, 2862, 2862, 2862, 2862, 2862,
+        2864, 2864, 2865, 2865, 2866, 2867, 2868, 2868, 2873, 2873, 2874, 2874, 2874, 2874, 2874, 2874, 2874, 2877, 2877, 2877,
+        2877, 2877, 2877, 2878, 2878, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2880, 2881,
+        2881, 2887, 2887, 2887, 2891, 2896, 2898, 2901, 2901, 2902, 2902, 2902, 2902, 2902, 2902, 2902, 2902, 2904, 2905, 2905,
+        2905, 2905, 2905, 2905, 2905, 2905, 2908, 2908, 2912, 2912, 2912, 2912, 2912, 2912, 2912, 2912, 2912, 2912, 2915, 2915,
+        2919, 2919, 2927, 2928, 2928, 2929, 2935, 2936, 2940, 2940, 2941, 2941, 2941, 2942, 2942, 2945, 2946, 2948, 2948, 2952,
+        2952, 2952, 2952, 2952, 2952, 2957, 2957, 2957, 2957, 2957, 2957, 2963, 2965, 2965, 2965, 2965, 2965, 2965, 2966, 2966,
+        2966, 2971, 2978, 2981, 2983, 2984, 2984, 2984, 2990, 2990, 2990, 2990, 2997, 2997, 2997, 2997, 2997, 2997, 3002, 3003,
+        3003, 3003, 3008, 3008, 3008, 3008, 3008, 3008, 3010, 3010, 3010, 3010, 3010, 3011, 3011, 3012, 3013, 3013, 3013, 3016,
+        3016, 3016, 3016, 3016, 3016, 3016, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021, 3021,
+        3021, 3022, 3022, 3023, 3023, 3025, 3026, 3027, 3027, 3027, 3027, 3030, 3031, 3031, 3031, 3031, 3031, 3031, 3031, 3031,
+        3031, 3031, 3031, 3032, 3032, 3032, 3034, 3034, 3034, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035, 3035,
+        3039, 3043, 3043, 3046, 3046, 3046, 3046, 3046, 3046, 3046, 3048, 3048, 3048, 3048, 3055, 3055, 3061, 3065, 3066, 3066,
+        3066, 3066, 3066, 3066, 3069, 3069, 3070, 3070, 3070, 3070, 3070, 3070, 3070, 3075, 3075, 3075, 3075, 3075, 3078, 3078,
+        3078, 3078, 3079, 3079, 3080, 3080, 3080, 3080, 3081, 3081, 3081, 3081, 3086, 3086, 3086, 3086, 3086, 3086, 3086, 3086,
+        3086, 3091, 3091, 3097, 3097, 3097, 3097, 3102, 3104, 3111, 3111, 3112, 3112, 3112, 3112, 3114, 3114, 3114, 3114, 3114,
+        3115, 3116, 3121, 3121, 3121, 3122, 3124, 3124, 3124, 3124, 3124, 3124, 3124, 3124, 3125, 3125, 3126, 3136, 3141, 3143,
+        3144, 3150, 3155, 3160, 3167, 3167, 3167, 3167, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169,
+        3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3169, 3173, 3173, 3173, 3173, 3176, 3176, 3176, 3176,
+        3176, 3180, 3181, 3182, 3185, 3185, 3185, 3185, 3185, 3185, 3185, 3185, 3185, 3189, 3190, 3190, 3191, 3195, 3206, 3206,
+        3206, 3206, 3206, 3206, 3207, 3207, 3207, 3207, 3209, 3210, 3214, 3215, 3216, 3216, 3216, 3217, 3219, 3222, 3222, 3227,
+        3227, 3227, 3231, 3233, 3236, 3236, 3243, 3244, 3246, 3246, 3248, 3250, 3250, 3254, 3256, 3259, 3259, 3259, 3259, 3265,
+        3265, 3268, 3269, 3272, 3278, 3278, 3283, 3287, 3290, 3290, 3295, 3295, 3296, 3297, 3297, 3297, 3301, 3304, 3304, 3309,
+        3317, 3320, 3326, 3326, 3332, 3346, 3346, 3346, 3347, 3348, 3351, 3352, 3352, 3353, 3355, 3355, 3355, 3361, 3365, 3365,
+        3365, 3368, 3368, 3369, 3373, 3373, 3373, 3376, 3382, 3386, 3387, 3391, 3394, 3402, 3407, 3410, 3413, 3413, 3413, 3413,
+        3413, 3416, 3416, 3422, 3422, 3424, 3429, 3429, 3429, 3429, 3433, 3434, 3434, 3434, 3434, 3435, 3438, 3438, 3445, 3445,
+        3445, 3445, 3445, 3445, 3445, 3445, 3445, 3445, 3445, 3447, 3453, 3453, 3457, 3457, 3457, 3457, 3466, 3466, 3466, 3466,
+        3466, 3472, 3472, 3472, 3474, 3475, 3475, 3475, 3476, 3478, 3480, 3480, 3488, 3489, 3491, 3494, 3498, 3502, 3504, 3507,
+        3507, 3511, 3519, 3519, 3519, 3519, 3521, 3526, 3527, 3527, 3531, 3531, 3531, 3531, 3531, 3531, 3536, 3536, 3536, 3536,
+        3536, 3536, 3540, 3540, 3540, 3540, 3542, 3543, 3543, 3543, 3543, 3550, 3550, 3554, 3554, 3554, 3558, 3558, 3558, 3558,
+        3559, 3559, 3559, 3560, 3560, 3561, 3563, 3565, 3565, 3566, 3566, 3567, 3567, 3574, 3574, 3574, 3578, 3586, 3590, 3590,
+        3590, 3591, 3592, 3592, 3592, 3592, 3592, 3598, 3598, 3598, 3598, 3598, 3605, 3605, 3606, 3607, 3607, 3614, 3614, 3617,
+        3617, 3618, 3618, 3621, 3621, 3625, 3626, 3626, 3631, 3631, 3631, 3631, 3632, 3633, 3638, 3639, 3642, 3645, 3646, 3651,
+        3662, 3664, 3664, 3664, 3665, 3667, 3667, 3667, 3667, 3668, 3671, 3671, 3671, 3671, 3671, 3674, 3674, 3675, 3675, 3675,
+        3677, 3677, 3681, 3681, 3686, 3686, 3691, 3698, 3698, 3700, 3700, 3700, 3700, 3700, 3701, 3703, 3703, 3704, 3706, 3709,
+        3709, 3709, 3709, 3712, 3713, 3713, 3713, 3713, 3720, 3720, 3730, 3733, 3738, 3741, 3741, 3742, 3742, 3742, 3747, 3748,
+        3748, 3748, 3751, 3754, 3754, 3758, 3759, 3760, 3760, 3760, 3764, 3767, 3767, 3767, 3769, 3769, 3769, 3769, 3769, 3775,
+        3777, 3779, 3781, 3787, 3790, 3790, 3790, 3794, 3797, 3797, 3798, 3799, 3800, 3805, 3808, 3808, 3808, 3810, 3811, 3812,
+        3812, 3815, 3815, 3815, 3815, 3816, 3816, 3816, 3816, 3816, 3819, 3824, 3826, 3830, 3836, 3836, 3836, 3839, 3841, 3841,
+        3841, 3845, 3845, 3845, 3845, 3848, 3848, 3849, 3849, 3855, 3862, 3873, 3879, 3879, 3883, 3886, 3886, 3886, 3889, 3892,
+        3892, 3892, 3898, 3898, 3902, 3903, 3911, 3913, 3913, 3913, 3914, 3918, 3921, 3922, 3923, 3926, 3933, 3933, 3933, 3936,
+        3939, 3942, 3942, 3949, 3951, 3954, 3959, 3960, 3963, 3966, 3969, 3971, 3971, 3977, 3980, 3988, 3988, 3992, 3997, 3997,
+        3997, 3997, 3997, 3997, 4002, 4005, 4006, 4008, 4008, 4012, 4014, 4015, 4015, 4017, 4017, 4019, 4023, 4023, 4023, 4023,
+        4024, 4027, 4030, 4036, 4036, 4038, 4047, 4048, 4050, 4055, 4060, 4063, 4063, 4063, 4070, 4072, 4072, 4076, 4079, 4081,
+        4085, 4089, 4091, 4095, 4096, 4101, 4102, 4107, 4117, 4120, 4125, 4130, 4130, 4134, 4136, 4140, 4144, 4148, 4148, 4150,
+        4154, 4161, 4161, 4167, 4173, 4174, 4175, 4183, 4188, 4193, 4200, 4204, 4204, 4212, 4212, 4212, 4220, 4220, 4220, 4224,
+        4228, 4228, 4228, 4228, 4228, 4228, 4232, 4232, 4233, 4233, 4233, 4233, 4233, 4233, 4235, 4238, 4238, 4239, 4240, 4246,
+        4247, 4249, 4250, 4251, 4251, 4255, 4255, 4259, 4259, 4265, 4267, 4268, 4268, 4270, 4273, 4277, 4279, 4280, 4286, 4287,
+        4292, 4292, 4292, 4293, 4293, 4294, 4299, 4302, 4303, 4305, 4306, 4306, 4306, 4306, 4306, 4309, 4314, 4314, 4317, 4323,
+        4323, 4327, 4329, 4329, 4334, 4346, 4347, 4347, 4353, 4353, 4356, 4357, 4357, 4366, 4368, 4369, 4369, 4370, 4370, 4370,
+        4373, 4378, 4379, 4379, 4379, 4379, 4382, 4384, 4384, 4384, 4386, 4386, 4387, 4390, 4391, 4394, 4398, 4398, 4401, 4404,
+        4404, 4407, 4410, 4410, 4410, 4411, 4411, 4412, 4412, 4412, 4412, 4412, 4417, 4419, 4420, 4425, 4425, 4433, 4436, 4437,
+        4443, 4444, 4447, 4449, 4449, 4453, 4453, 4459, 4459, 4469, 4471, 4471, 4474, 4477, 4482, 4482, 4482, 4484, 4490, 4495,
+        4495, 4495, 4495, 4495, 4495, 4500, 4500, 4509, 4510, 4510, 4518, 4522, 4523, 4524, 4529, 4529, 4537, 4543, 4547, 4553,
+        4553, 4553, 4556, 4560, 4562, 4565, 4567, 4572, 4572, 4576, 4581, 4590, 4593, 4593, 4593, 4598, 4598, 4598, 4598, 4598,
+        4598, 4600, 4609, 4614, 4617, 4621, 4623, 4630, 4630, 4631, 4635, 4638, 4644, 4647, 4651, 4656, 4664, 4673, 4675, 4675,
+        4676, 4678, 4679, 4680, 4684, 4690, 4692, 4692, 4692, 4694, 4696, 4699, 4699, 4699, 4699, 4699, 4707, 4714, 4714, 4720,
+        4727, 4727, 4730, 4735, 4744, 4747, 4747, 4748, 4750, 4750, 4751, 4753, 4757, 4761, 4765, 4771, 4771, 4776, 4777, 4783,
+        4787, 4787, 4788, 4788, 4789, 4794, 4797, 4801, 4802, 4806, 4810, 4810, 4810, 4810, 4810, 4813, 4815, 4819, 4820, 4824,
+        4825, 4829, 4832, 4835, 4836, 4841, 4843, 4846, 4846, 4848, 4852, 4853, 4855, 4861, 4863, 4867, 4867, 4868, 4869, 4873,
+        4878, 4884, 4884, 4885, 4890, 4890, 4890, 4893, 4896, 4907, 4907, 4915, 4918, 4920, 4925, 4928, 4931, 4934, 4937, 4937,
+        4938, 4942, 4945, 4945, 4946, 4949, 4952, 4956, 4956, 4957, 4958, 4963, 4964, 4969, 4969, 4974, 4974, 4985, 4989, 4989,
+        4998, 4999, 5002, 5002, 5004, 5007, 5013, 5017, 5021, 5023, 5024, 5027, 5027, 5028, 5028, 5034, 5034, 5034, 5035, 5036,
+        5041, 5044, 5047, 5047, 5047, 5052, 5057, 5061, 5066, 5068, 5072, 5075, 5077, 5080, 5081, 5085, 5086, 5090, 5090, 5091,
+        5091, 5091, 5091, 5095, 5098, 5100, 5113, 5116, 5116, 5117, 5127, 5127, 5128, 5130, 5135, 5135, 5135, 5140, 5144, 5146,
+        5147, 5150, 5152, 5152, 5152, 5157, 5162, 5169, 5172, 5180, 5181, 5181, 5181, 5182, 5186, 5187, 5189, 5193, 5193, 5198,
+        5200, 5204, 5205, 5209, 5212, 5215, 5220, 5223, 5224, 5228, 5231, 5233, 5234, 5235, 5237, 5240, 5240, 5244, 5248, 5248,
+        5248, 5252, 5252, 5252, 5257, 5259, 5261, 5265, 5268, 5268, 5268, 5273, 5276, 5276, 5276, 5278, 5278, 5278, 5280, 5283,
+        5285, 5285, 5285, 5285, 5285, 5285, 5285, 5285, 5285, 5287, 5287, 5289, 5297, 5297, 5299, 5299, 5299, 5299, 5299, 5300,
+        5300, 5305, 5307, 5307, 5309, 5315, 5315, 5319, 5326, 5326, 5326, 5334, 5336, 5336, 5342, 5342, 5347, 5347, 5352, 5355,
+        5355, 5359, 5359, 5363, 5363, 5366, 5366, 5366, 5366, 5369, 5375, 5382, 5382, 5382, 5383, 5385, 5385, 5386, 5392, 5394,
+        5397, 5400, 5406, 5406, 5412, 5412, 5414, 5414, 5414, 5415, 5418, 5420, 5423, 5427, 5429, 5431, 5433, 5437, 5439, 5439,
+        5442, 5446, 5447, 5449, 5450, 5454, 5454, 5455, 5457, 5457, 5458, 5458, 5459, 5462, 5465, 5472, 5473, 5477, 5484, 5489,
+        5491, 5498, 5500, 5507, 5508, 5510, 5512, 5514, 5517, 5518, 5519, 5520, 5523, 5525, 5525, 5526, 5533, 5536, 5544, 5546,
+        5548, 5553, 5560, 5569, 5569, 5575, 5580, 5584, 5585, 5588, 5591, 5593, 5600, 5602, 5611, 5612, 5613, 5616, 5623, 5626,
+        5629, 5632, 5637, 5640, 5644, 5644, 5649, 5652, 5652, 5662, 5662, 5662, 5666, 5668, 5676, 5681, 5681, 5683, 5688, 5694,
+        5697, 5703, 5704, 5717, 5722, 5722, 5726, 5728, 5731, 5735, 5736, 5743, 5746, 5753, 5754, 5754, 5755, 5758, 5761, 5761,
+        5762, 5767, 5772, 5773, 5776, 5779, 5779, 5779, 5780, 5780, 5780, 5780, 5780, 5780, 5780, 5782, 5783, 5787, 5792, 5797,
+        5798, 5798, 5802, 5810, 5810, 5811, 5814, 5814, 5818, 5820, 5820, 5820, 5823, 5827, 5830, 5832, 5832, 5835, 5835, 5841,
+        5843, 5844, 5845, 5845, 5848, 5856, 5856, 5858, 5860, 5863, 5863, 5869, 5871, 5871, 5873, 5873, 5879, 5882, 5886, 5886,
+        5888, 5888, 5891, 5891, 5896, 5896, 5900, 5903, 5906, 5907, 5912, 5918, 5922, 5923, 5923, 5923, 5926, 5926, 5930, 5930,
+        5935, 5935, 5936, 5939, 5941, 5943, 5947, 5947, 5950, 5951, 5955, 5955, 5959, 5959, 5966, 5971, 5973, 5973, 5973, 5973,
+        5973, 5980, 5980, 5983, 5985, 5988, 5991, 5997, 5997, 5998, 5998, 5999, 6000, 6000, 6001, 6003, 6009, 6009, 6011, 6012,
+        6018, 6023, 6023, 6023, 6023, 6023, 6028, 6029, 6030, 6030, 6036, 6040, 6043, 6046, 6047, 6047, 6054, 6054, 6054, 6060,
+        6064, 6067, 6069, 6072, 6073, 6078, 6079, 6082, 6085, 6085, 6089, 6089, 6093, 6094, 6096, 6096, 6096, 6098, 6098, 6098,
+        6102, 6108, 6111, 6111, 6111, 6114, 6119, 6119, 6121, 6124, 6124, 6124, 6124, 6126, 6131, 6134, 6134, 6145, 6150, 6152,
+        6155, 6156, 6161, 6161, 6162, 6162, 6164, 6164, 6164, 6166, 6169, 6175, 6176, 6178, 6183, 6188, 6190, 6193, 6197, 6199,
+        6203, 6206, 6207, 6210, 6210, 6210, 6212, 6219, 6219, 6220, 6220, 6225, 6226, 6228, 6233, 6236, 6236, 6236, 6242, 6242,
+        6242, 6242, 6247, 6251, 6252, 6253, 6253, 6255, 6255, 6255, 6255, 6256, 6263, 6263, 6263, 6263, 6265, 6268, 6270, 6273,
+        6273, 6273, 6273, 6276, 6279, 6283, 6285, 6285, 6288, 6294, 6294, 6297, 6297, 6297, 6300, 6300, 6301, 6302, 6306, 6306,
+        6309, 6309, 6309, 6309, 6311, 6311, 6313, 6313, 6313, 6313, 6313, 6315, 6315, 6320, 6320, 6321, 6325, 6325, 6325, 6325,
+        6328, 6328, 6328, 6330, 6330, 6337, 6338, 6342, 6342, 6348, 6354, 6356, 6356, 6359, 6362, 6362, 6362, 6362, 6362, 6365,
+        6366, 6366, 6368, 6371, 6371, 6371, 6371, 6371, 6376, 6378, 6380, 6384, 6384, 6384, 6388, 6391, 6391, 6391, 6393, 6393,
+        6398, 6400, 6400, 6400, 6400, 6400, 6400, 6407, 6408, 6408, 6411, 6415, 6422, 6422, 6422, 6422, 6422, 6422, 6423, 6424,
+        6424, 6427, 6427, 6427, 6427, 6427, 6428, 6428, 6428, 6428, 6428, 6428, 6431, 6431, 6436, 6436, 6438, 6438, 6438, 6443,
+        6444, 6444, 6445, 6445, 6451, 6451, 6452, 6455, 6457, 6459, 6464, 6467, 6470, 6470, 6470, 6470, 6471, 6472, 6472, 6472,
+        6472, 6472, 6472, 6472, 6472, 6472, 6472, 6477, 6479, 6482, 6484, 6487, 6487, 6488, 6488, 6492, 6495, 6501, 6501, 6501,
+        6501, 6501, 6501, 6504, 6504, 6508, 6508, 6513, 6513, 6514, 6515, 6515, 6515, 6516, 6516, 6519, 6519, 6523, 6523, 6523,
+        6523, 6529, 6529, 6531, 6536, 6536, 6536, 6541, 6541, 6545, 6549, 6554, 6554, 6554, 6561, 6566, 6572, 6575, 6576, 6576,
+        6578, 6580, 6586, 6586, 6589, 6589, 6593, 6594, 6596, 6601, 6601, 6606, 6613, 6617, 6625, 6626, 6627, 6628, 6632, 6632,
+        6636, 6636, 6636, 6636, 6636, 6642, 6642, 6644, 6646, 6647, 6649, 6649, 6649, 6649, 6649, 6652, 6653, 6654, 6660, 6665,
+        6672, 6678, 6681, 6682, 6683, 6686, 6686, 6693, 6698, 6707, 6707, 6711, 6720, 6728, 6732, 6734, 6740, 6743, 6743, 6743,
+        6743, 6743, 6752, 6752, 6755, 6757, 6761, 6761, 6761, 6769, 6769, 6772, 6772, 6775, 6777, 6782, 6782, 6789, 6795, 6797,
+        6802, 6802, 6802, 6802, 6802, 6802, 6806, 6812, 6812, 6814, 6814, 6814, 6814, 6815, 6815, 6819, 6819, 6822, 6825, 6829,
+        6833, 6833, 6833, 6833, 6833, 6833, 6833, 6839, 6845, 6845, 6851, 6860, 6860, 6860, 6860, 6860, 6860, 6860, 6864, 6866,
+        6870, 6870, 6877, 6879, 6880, 6883, 6883, 6883, 6883, 6889, 6890, 6892, 6895, 6897, 6897, 6897, 6897, 6897, 6897, 6901,
+        6904, 6904, 6904, 6904, 6904, 6905, 6906, 6906, 6910, 6910, 6915, 6915, 6918, 6918, 6918, 6924, 6928, 6928, 6929, 6929,
+        6929, 6935, 6939, 6943, 6943, 6948, 6954, 6954, 6957, 6965, 6970, 6970, 6976, 6977, 6977, 6977, 6981, 6986, 6991, 6992,
+        6997, 6997, 6997, 6997, 6997, 7005, 7005, 7008, 7008, 7015, 7016, 7016, 7018, 7024, 7024, 7027, 7028, 7030, 7034, 7039,
+        7039, 7042, 7044, 7044, 7049, 7049, 7050, 7050, 7050, 7051, 7055, 7057, 7057, 7058, 7060, 7063, 7065, 7065, 7065, 7068,
+        7076, 7081, 7087, 7092, 7092, 7094, 7097, 7098, 7106, 7107, 7107, 7110, 7111, 7111, 7112, 7114, 7116, 7121, 7121, 7125,
+        7133, 7136, 7139, 7147, 7150, 7150, 7150, 7152, 7154, 7154, 7158, 7160, 7161, 7162, 7162, 7170, 7172, 7178, 7178, 7178,
+        7182, 7183, 7184, 7189, 7189, 7191, 7197, 7197, 7197, 7197, 7205, 7211, 7219, 7219, 7220, 7224, 7228, 7228, 7228, 7236,
+        7241, 7246, 7251, 7253, 7260, 7260, 7263, 7267, 7269, 7271, 7272, 7274, 7279, 7281, 7285, 7285, 7286, 7288, 7288, 7288,
+        7295, 7295, 7295, 7297, 7300, 7302, 7302, 7305, 7310, 7310, 7310, 7314, 7321, 7324, 7324, 7330, 7330, 7331, 7331, 7333,
+        7339, 7346, 7346, 7349, 7349, 7351, 7351, 7360, 7364, 7367, 7368, 7370, 7373, 7373, 7378, 7380, 7384, 7388, 7390, 7393,
+        7396, 7402, 7408, 7409, 7411, 7413, 7414, 7414, 7417, 7419, 7419, 7424, 7426, 7428, 7428, 7432, 7432, 7432, 7436, 7436,
+        7444, 7444, 7446, 7451, 7456, 7456, 7457, 7462, 7467, 7473, 7480, 7482, 7486, 7487, 7487, 7494, 7494, 7497, 7502, 7502,
+        7504, 7504, 7504, 7506, 7506, 7507, 7507, 7507, 7509, 7509, 7509, 7511, 7511, 7520, 7526, 7528, 7534, 7534, 7534, 7540,
+        7540, 7540, 7543, 7547, 7548, 7555, 7561, 7564, 7564, 7565, 7570, 7572, 7574, 7577, 7577, 7577, 7577, 7577, 7577, 7577,
+        7585, 7588, 7589, 7592, 7598, 7605, 7607, 7608, 7614, 7614, 7618, 7618, 7634, 7634, 7639, 7639, 7640, 7640, 7649, 7651,
+        7662, 7670, 7670, 7677, 7680, 7681, 7686, 7686, 7689, 7694, 7694, 7696, 7696, 7696, 7701, 7711, 7713, 7716, 7723, 7729,
+        7729, 7731, 7731, 7731, 7733, 7737, 7740, 7740, 7740, 7746, 7746, 7749, 7751, 7757, 7761, 7761, 7766, 7771, 7771, 7782,
+        7782, 7784, 7784, 7791, 7791, 7796, 7801, 7803, 7803, 7804, 7804, 7807, 7808, 7812, 7815, 7820, 7820, 7820, 7826, 7827,
+        7830, 7830, 7833, 7833, 7834, 7834, 7835, 7839, 7840, 7842, 7844, 7847, 7852, 7852, 7853, 7856, 7861, 7864, 7864, 7864,
+        7865, 7866, 7869, 7870, 7870, 7870, 7871, 7874, 7874, 7882, 7883, 7884, 7884, 7884, 7884, 7884, 7886, 7886, 7886, 7894,
+        7899, 7908, 7908, 7908, 7913, 7917, 7920, 7925, 7927, 7933, 7933, 7935, 7938, 7938, 7938, 7939, 7944, 7946, 7948, 7950,
+        7951, 7952, 7953, 7953, 7960, 7961, 7961, 7967, 7973, 7976, 7976, 7976, 7979, 7979, 7979, 7979, 7986, 7989, 7989, 7991,
+        7993, 7993, 7995, 8000, 8000, 8000, 8003, 8005, 8009, 8012, 8015, 8017, 8019, 8023, 8029, 8033, 8034, 8035, 8037, 8041,
+        8041, 8046, 8046, 8046, 8048, 8049, 8053, 8054, 8054, 8056, 8064, 8072, 8072, 8076, 8078, 8079, 8079, 8092, 8092, 8096,
+        8100, 8108, 8117, 8121, 8124, 8127, 8130, 8138, 8138, 8142, 8148, 8150, 8155, 8155, 8155, 8158, 8163, 8166, 8172, 8186,
+        8190, 8190, 8195, 8195, 8195, 8195, 8199, 8201, 8201, 8201, 8205, 8211, 8217, 8217, 8220, 8220, 8226, 8231, 8232, 8233,
+        8233, 8234, 8238, 8244, 8245, 8249, 8249, 8249, 8251, 8259, 8260, 8262, 8266, 8266, 8266, 8270, 8274, 8274, 8276, 8277,
+        8279, 8287, 8288, 8291, 8292, 8294, 8298, 8298, 8301, 8307, 8311, 8311, 8313, 8313, 8313, 8317, 8318, 8319, 8320, 8325,
+        8329, 8329, 8329, 8333, 8337, 8337, 8337, 8337, 8337, 8340, 8340, 8342, 8348, 8349, 8352, 8353, 8354, 8360, 8360, 8360,
+        8366, 8367, 8367, 8367, 8367, 8369, 8373, 8375, 8376, 8383, 8385, 8385, 8390, 8391, 8393, 8393, 8393, 8398, 8398, 8402,
+        8402, 8402, 8402, 8402, 8408, 8408, 8408, 8409, 8410, 8416, 8417, 8417, 8421, 8427, 8430, 8435, 8440, 8443, 8443, 8445,
+        8445, 8451, 8454, 8455, 8459, 8459, 8463, 8464, 8464, 8465, 8469, 8470, 8470, 8475, 8477, 8477, 8480, 8485, 8486, 8494,
+        8499, 8502, 8504, 8505, 8507, 8511, 8516, 8523, 8525, 8525, 8527, 8527, 8527, 8530, 8539, 8546, 8554, 8559, 8560, 8562,
+        8573, 8575, 8581, 8586, 8592, 8596, 8596, 8601, 8604, 8604, 8605, 8605, 8605, 8609, 8615, 8617, 8619, 8624, 8624, 8627,
+        8632, 8640, 8640, 8644, 8648, 8648, 8648, 8648, 8651, 8656, 8664, 8664, 8664, 8665, 8666, 8666, 8667, 8671, 8671, 8678,
+        8682, 8692, 8695, 8698, 8701, 8704, 8708, 8710, 8710, 8712, 8718, 8718, 8720, 8720, 8720, 8723, 8723, 8728, 8731, 8731,
+        8737, 8737, 8737, 8737, 8737, 8738, 8741, 8744, 8744, 8744, 8749, 8755, 8760, 8760, 8760, 8762, 8770, 8773, 8774, 8774,
+        8774, 8774, 8778, 8779, 8780, 8782, 8786, 8789, 8795, 8797, 8797, 8797, 8798, 8798, 8799, 8805, 8805, 8810, 8813, 8814,
+        8817, 8817, 8817, 8820, 8820, 8820, 8822, 8825, 8826, 8826, 8828, 8828, 8830, 8833, 8838, 8839, 8840, 8847, 8849, 8851,
+        8855, 8855, 8863, 8866, 8866, 8873, 8879, 8883, 8884, 8884, 8889, 8891, 8891, 8893, 8895, 8895, 8897, 8897, 8903, 8903,
+        8909, 8909, 8914, 8914, 8919, 8919, 8923, 8925, 8930, 8930, 8930, 8933, 8933, 8933, 8934, 8934, 8934, 8937, 8940, 8942,
+        8945, 8945, 8945, 8947, 8948, 8950, 8950, 8950, 8950, 8953, 8957, 8957, 8957, 8957, 8963, 8963, 8963, 8966, 8966, 8966,
+        8971, 8976, 8977, 8979, 8986, 8987, 8989, 8989, 8989, 8994, 8994, 8997, 8998, 9000, 9000, 9004, 9009, 9014, 9014, 9015,
+        9020, 9024, 9031, 9035, 9037, 9042, 9047, 9047, 9051, 9051, 9052, 9058, 9060, 9062, 9064, 9069, 9073, 9079, 9080, 9083,
+        9087, 9088, 9088, 9094, 9094, 9094, 9099, 9099, 9100, 9103, 9103, 9103, 9107, 9107, 9108, 9124, 9126, 9128, 9129, 9130,
+        9132, 9135, 9135, 9135, 9138, 9138, 9142, 9149, 9155, 9160, 9160, 9161, 9162, 9166, 9169, 9169, 9174, 9176, 9178, 9178,
+        9178, 9178, 9181, 9187, 9187, 9189, 9195, 9201, 9204, 9204, 9204, 9208, 9213, 9213, 9213, 9217, 9221, 9224, 9225, 9225,
+        9227, 9229, 9235, 9235, 9237, 9240, 9240, 9240, 9244, 9247, 9249, 9257, 9257, 9266, 9269, 9269, 9272, 9276, 9276, 9276,
+        9281, 9281, 9283, 9283, 9283, 9283, 9283, 9285, 9290, 9290, 9294, 9299, 9302, 9307, 9309, 9312, 9312, 9315, 9317, 9324,
+        9324, 9325, 9327, 9327, 9328, 9328, 9332, 9335, 9336, 9337, 9337, 9340, 9340, 9340, 9340, 9342, 9342, 9343, 9345, 9345,
+        9348, 9348, 9349, 9349, 9349, 9350, 9350, 9351, 9352, 9353, 9355, 9355, 9355, 9365, 9365, 9369, 9369, 9371, 9372, 9376,
+        9377, 9377, 9377, 9382, 9387, 9388, 9396, 9397, 9401, 9401, 9404, 9404, 9407, 9407, 9408, 9410, 9414, 9417, 9421, 9424,
+        9424, 9426, 9431, 9433, 9435, 9436, 9436, 9438, 9440, 9441, 9442, 9444, 9449, 9449, 9458, 9462, 9462, 9466, 9466, 9471,
+        9471, 9471, 9471, 9471, 9473, 9474, 9474, 9474, 9479, 9479, 9487, 9490, 9491, 9491, 9491, 9496, 9498, 9498, 9500, 9509,
+        9511, 9511, 9511, 9511, 9512, 9515, 9519, 9519, 9522, 9524, 9529, 9535, 9536, 9552, 9555, 9559, 9559, 9559, 9565, 9568,
+        9568, 9569, 9572, 9575, 9575, 9578, 9579, 9581, 9581, 9581, 9583, 9587, 9588, 9591, 9595, 9599, 9599, 9604, 9607, 9608,
+        9609, 9610, 9615, 9616, 9622, 9631, 9632, 9632, 9637, 9637, 9637, 9638, 9638, 9644, 9644, 9645, 9649, 9654, 9659, 9659,
+        9663, 9671, 9672, 9675, 9681, 9682, 9684, 9688, 9689, 9690, 9690, 9692, 9693, 9694, 9698, 9703, 9704, 9706, 9709, 9715,
+        9716, 9717, 9721, 9722, 9724, 9724, 9727, 9727, 9729, 9735, 9735, 9739, 9740, 9749, 9753, 9753, 9754, 9756, 9756, 9756,
+        9758, 9758, 9761, 9763, 9765, 9767, 9768, 9769, 9773, 9777, 9781, 9785, 9788, 9788, 9791, 9795, 9795, 9795, 9795, 9796,
+        9799, 9803, 9807, 9807, 9807, 9807, 9807, 9810, 9810, 9814, 9814, 9825, 9827, 9829, 9829, 9832, 9832, 9836, 9837, 9839,
+        9841, 9841, 9841, 9844, 9847, 9847, 9847, 9847, 9850, 9850, 9851, 9853, 9857, 9858, 9865, 9871, 9873, 9873, 9874, 9879,
+        9883, 9885, 9891, 9891, 9895, 9898, 9901, 9902, 9911, 9918, 9925, 9926, 9929, 9929, 9932, 9936, 9939, 9939, 9941, 9943,
+        9947, 9953, 9957, 9960, 9963, 9966, 9970, 9972, 9972, 9975, 9979, 9982, 9982, 9982, 9982, 9982, 9985, 9986, 9989, 9991,
+        9994, 9995, 9998, 9998, 10004, 10004, 10004, 10006, 10008, 10010, 10013, 10013, 10013, 10015, 10015, 10022, 10022, 10022, 10025, 10025,
+        10025, 10032, 10032, 10032, 10034, 10034, 10037, 10037, 10041, 10048, 10049, 10049, 10051, 10055, 10057, 10058, 10058, 10064, 10064, 10064,
+        10064, 10067, 10071, 10071, 10071, 10073, 10073, 10073, 10073, 10073, 10073, 10075, 10075, 10075, 10081, 10081, 10081, 10084, 10084, 10084,
+        10084, 10084, 10091, 10091, 10092, 10094, 10095, 10099, 10099, 10101, 10101, 10101, 10101, 10103, 10103, 10103, 10103, 10103, 10107, 10108,
+        10114, 10119, 10119, 10119, 10127, 10131, 10131, 10133, 10136, 10138, 10144, 10152, 10152, 10153, 10159, 10160, 10160, 10164, 10164, 10164,
+        10170, 10172, 10175, 10178, 10179, 10184, 10185, 10185, 10188, 10188, 10194, 10198, 10199, 10203, 10203, 10218, 10223, 10224, 10225, 10230,
+        10230, 10234, 10239, 10241, 10241, 10246, 10250, 10251, 10253, 10255, 10257, 10262, 10263, 10263, 10263, 10263, 10271, 10271, 10272, 10272,
+        10278, 10278, 10278, 10282, 10290, 10300, 10306, 10308, 10310, 10313, 10314, 10314, 10314, 10318, 10326, 10326, 10331, 10331, 10334, 10334,
+        10342, 10342, 10343, 10345, 10350, 10350, 10351, 10354, 10354, 10365, 10365, 10375, 10375, 10380, 10380, 10386, 10386, 10386, 10389, 10389,
+        10393, 10393, 10393, 10393, 10396, 10402, 10402, 10405, 10412, 10415, 10416, 10421, 10421, 10425, 10425, 10428, 10431, 10431, 10431, 10431,
+        10432, 10432, 10432, 10432, 10437, 10441, 10447, 10449, 10450, 10450, 10453, 10457, 10460, 10460, 10462, 10464, 10466, 10467, 10471, 10474,
+        10474, 10478, 10485, 10485, 10485, 10491, 10491, 10491, 10498, 10502, 10505, 10512, 10512, 10512, 10515, 10516, 10520, 10522, 10527, 10529,
+        10530, 10530, 10530, 10534, 10538, 10538, 10538, 10539, 10545, 10546, 10547, 10547, 10553, 10554, 10558, 10560, 10563, 10566, 10570, 10573,
+        10577, 10583, 10587, 10587, 10587, 10588, 10594, 10594, 10596, 10596, 10596, 10601, 10605, 10610, 10614, 10619, 10619, 10620, 10624, 10627,
+        10631, 10633, 10640, 10641, 10644, 10647, 10650, 10652, 10660, 10660, 10664, 10664, 10669, 10670, 10670, 10672, 10680, 10682, 10684, 10690,
+        10697, 10697, 10700, 10704, 10708, 10711, 10711, 10712, 10713, 10716, 10721, 10721, 10721, 10721, 10721, 10721, 10722, 10728, 10734, 10740,
+        10748, 10748, 10748, 10748, 10748, 10754, 10756, 10756, 10762, 10771, 10772, 10774, 10780, 10780, 10781, 10781, 10781, 10781, 10781, 10781,
+        10785, 10789, 10789, 10790, 10794, 10797, 10803, 10803, 10803, 10803, 10807, 10807, 10812, 10815, 10816, 10819, 10822, 10825, 10827, 10828,
+        10831, 10831, 10832, 10838, 10842, 10842, 10849, 10849, 10849, 10850, 10854, 10857, 10860, 10860, 10860, 10860, 10865, 10869, 10871, 10873,
+        10873, 10873, 10877, 10877, 10877, 10877, 10881, 10881, 10881, 10881, 10881, 10887, 10887, 10888, 10888, 10897, 10901, 10907, 10908, 10911,
+        10911, 10914, 10915, 10919, 10923, 10926, 10929, 10929, 10932, 10936, 10940, 10941, 10941, 10941, 10942, 10942, 10943, 10947, 10948, 10951,
+        10955, 10955, 10959, 10962, 10967, 10968, 10968, 10971, 10972, 10973, 10978, 10981, 10985, 10991, 10991, 10992, 10992, 11000, 11001, 11002,
+        11004, 11004, 11004, 11006, 11011, 11011, 11014, 11016, 11016, 11020, 11021, 11026, 11031, 11036, 11040, 11041, 11044, 11046, 11051, 11055,
+        11055, 11056, 11060, 11063, 11068, 11070, 11074, 11077, 11087, 11090, 11091, 11093, 11094, 11094, 11094, 11096, 11096, 11099, 11103, 11106,
+        11108, 11109, 11111, 11112, 11112, 11113, 11116, 11116, 11121, 11121, 11124, 11124, 11127, 11129, 11132, 11132, 11135, 11136, 11142, 11149,
+        11151, 11154, 11154, 11157, 11158, 11161, 11169, 11170, 11173, 11175, 11181, 11185, 11190, 11190, 11190, 11190, 11190, 11194, 11201, 11203,
+        11203, 11205, 11205, 11207, 11207, 11209, 11210, 11214, 11217, 11219, 11221, 11223, 11224, 11226, 11227, 11228, 11229, 11235, 11235, 11235,
+        11238, 11241, 11244, 11247, 11249, 11254, 11255, 11255, 11259, 11262, 11265, 11265, 11265, 11267, 11268, 11272, 11272, 11272, 11281, 11281,
+        11281, 11281, 11285, 11289, 11289, 11289, 11289, 11293, 11293, 11303, 11303, 11305, 11308, 11311, 11313, 11316, 11318, 11318, 11320, 11324,
+        11329, 11332, 11335, 11335, 11335, 11335, 11340, 11345, 11347, 11347, 11347, 11349, 11352, 11352, 11352, 11353, 11353, 11353, 11353, 11356,
+        11356, 11356, 11356, 11359, 11361, 11363, 11365, 11368, 11371, 11371, 11376, 11379, 11382, 11387, 11387, 11388, 11388, 11388, 11391, 11391,
+        11391, 11391, 11391, 11397, 11397, 11398, 11400, 11401, 11403, 11403, 11410, 11410, 11412, 11412, 11414, 11419, 11421, 11427, 11432, 11432,
+        11434, 11435, 11435, 11439, 11443, 11444, 11449, 11454, 11458, 11460, 11464, 11469, 11471, 11477, 11481, 11484, 11489, 11489, 11493, 11493,
+        11493, 11493, 11499, 11500, 11505, 11508, 11508, 11508, 11510, 11510, 11513, 11513, 11513, 11517, 11519, 11520, 11520, 11523, 11523, 11527,
+        11527, 11530, 11530, 11530, 11536, 11536, 11536, 11538, 11542, 11545, 11546, 11547, 11547, 11547, 11549, 11550, 11554, 11554, 11555, 11558,
+        11558, 11563, 11566, 11566, 11567, 11570, 11571, 11571, 11575, 11578, 11580, 11585, 11585, 11585, 11588, 11589, 11592, 11594, 11595, 11595,
+        11599, 11604, 11604, 11608, 11609, 11613, 11613, 11613, 11613, 11617, 11617, 11620, 11620, 11621, 11625, 11629, 11629, 11632, 11634, 11634,
+        11641, 11643, 11645, 11646, 11648, 11648, 11648, 11649, 11652, 11652, 11652, 11654, 11658, 11658, 11658, 11659, 11660, 11664, 11664, 11668,
+        11668, 11668, 11673, 11677, 11681, 11681, 11685, 11693, 11693, 11693, 11700, 11700, 11701, 11710, 11714, 11718, 11724, 11729, 11729, 11732,
+        11735, 11738, 11740, 11746, 11746, 11747, 11748, 11749, 11749, 11749, 11749, 11750, 11751, 11755, 11760, 11761, 11765, 11772, 11773, 11774,
+        11776, 11776, 11779, 11780, 11784, 11786, 11786, 11788, 11788, 11789, 11792, 11795, 11796, 11798, 11799, 11799, 11801, 11803, 11804, 11808,
+        11808, 11808, 11813, 11817, 11817, 11820, 11820, 11824, 11827, 11831, 11831, 11831, 11834, 11836, 11841, 11842, 11843, 11843, 11846, 11846,
+        11852, 11852, 11854, 11858, 11863, 11863, 11866, 11870, 11872, 11875, 11878, 11878, 11882, 11882, 11886, 11886, 11888, 11892, 11892, 11896,
+        11896, 11896, 11897, 11899, 11899, 11903, 11903, 11904, 11904, 11908, 11909, 11909, 11909, 11909, 11912, 11912, 11915, 11923, 11924, 11928,
+        11928, 11933, 11937, 11939, 11940, 11943, 11949, 11949, 11954, 11955, 11958, 11963, 11967, 11967, 11968, 11970, 11970, 11972, 11976, 11978,
+        11981, 11987, 11988, 11992, 11997, 12002, 12002, 12002, 12007, 12007, 12013, 12016, 12016, 12018, 12020, 12021, 12021, 12025, 12028, 12029,
+        12029, 12030, 12034, 12036, 12038, 12040, 12047, 12047, 12047, 12052, 12052, 12054, 12058, 12061, 12061, 12062, 12064, 12066, 12070, 12075,
+        12075, 12081, 12086, 12086, 12092, 12099, 12100, 12100, 12100, 12100, 12100, 12103, 12103, 12108, 12115, 12117, 12120, 12122, 12128, 12129,
+        12129, 12130, 12132, 12134, 12137, 12139, 12140, 12142, 12146, 12151, 12154, 12163, 12163, 12167, 12176, 12181, 12181, 12182, 12185, 12186,
+        12189, 12193, 12198, 12203, 12209, 12211, 12215, 12216, 12219, 12221, 12223, 12223, 12223, 12227, 12228, 12231, 12235, 12235, 12237, 12239,
+        12239, 12239, 12240, 12240, 12244, 12244, 12248, 12251, 12251, 12251, 12253, 12255, 12258, 12261, 12264, 12264, 12266, 12267, 12269, 12273,
+        12274, 12274, 12277, 12277, 12277, 12280, 12285, 12286, 12288, 12289, 12289, 12293, 12293, 12294, 12294, 12294, 12294, 12299, 12300, 12300,
+        12305, 12305, 12305, 12307, 12311, 12311, 12312, 12315, 12317, 12323, 12324, 12325, 12327, 12327, 12327, 12327, 12329, 12332, 12332, 12338,
+        12338, 12339, 12341, 12342, 12343, 12344, 12349, 12350, 12353, 12360, 12364, 12367, 12371, 12371, 12371, 12374, 12374, 12376, 12377, 12378,
+        12384, 12389, 12392, 12393, 12394, 12395, 12402, 12403, 12404, 12405, 12406, 12409, 12411, 12411, 12412, 12413, 12417, 12417, 12417, 12417,
+        12417, 12421, 12421, 12421, 12421, 12421, 12421, 12421, 12423, 12424, 12426, 12426, 12426, 12426, 12429, 12431, 12436, 12436, 12436, 12436,
+        12439, 12446, 12448, 12448, 12451, 12451, 12457, 12459, 12459, 12463, 12464, 12464, 12464, 12468, 12477, 12479, 12479, 12479, 12480, 12484,
+        12492, 12492, 12492, 12494, 12498, 12503, 12509, 12509, 12512, 12515, 12518, 12520, 12525, 12531, 12531, 12532, 12533, 12533, 12536, 12536,
+        12539, 12539, 12542, 12544, 12549, 12549, 12553, 12553, 12553, 12553, 12553, 12555, 12559, 12559, 12561, 12563, 12563, 12568, 12573, 12576,
+        12578, 12580, 12581, 12585, 12585, 12588, 12588, 12590, 12590, 12595, 12597, 12599, 12604, 12608, 12612, 12615, 12620, 12621, 12621, 12623,
+        12625, 12628, 12628, 12634, 12636, 12638, 12641, 12643, 12644, 12652, 12656, 12659, 12663, 12664, 12666, 12666, 12668, 12673, 12676, 12676,
+        12676, 12677, 12677, 12679, 12686, 12688, 12688, 12689, 12690, 12692, 12692, 12693, 12697, 12700, 12704, 12704, 12704, 12704, 12707, 12707,
+        12717, 12720, 12727, 12727, 12727, 12732, 12733, 12736, 12737, 12739, 12740, 12742, 12742, 12745, 12745, 12749, 12754, 12760, 12761, 12761,
+        12762, 12766, 12768, 12777, 12780, 12786, 12786, 12789, 12790, 12790, 12790, 12794, 12797, 12801, 12804, 12809, 12809, 12809, 12817, 12819,
+        12819, 12820, 12821, 12826, 12826, 12826, 12831, 12831, 12834, 12837, 12838, 12841, 12841, 12843, 12850, 12851, 12858, 12858, 12858, 12861,
+        12861, 12861, 12865, 12865, 12865, 12865, 12868, 12868, 12868, 12872, 12872, 12877, 12877, 12879, 12882, 12882, 12882, 12882, 12885, 12885,
+        12885, 12889, 12889, 12889, 12889, 12895, 12895, 12895, 12895, 12897, 12898, 12898, 12898, 12898, 12898, 12899, 12899, 12902, 12904, 12906,
+        12909, 12911, 12913, 12913, 12916, 12916, 12918, 12924, 12924, 12931, 12934, 12934, 12934, 12934, 12934, 12937, 12944, 12948, 12953, 12953,
+        12953, 12954, 12958, 12958, 12958, 12958, 12958, 12961, 12971, 12974, 12977, 12977, 12980, 12980, 12980, 12980, 12980, 12980, 12981, 12986,
+        12986, 12987, 12987, 12992, 12992, 12998, 13001, 13004, 13005, 13005, 13010, 13011, 13011, 13014, 13014, 13018, 13022, 13025, 13025, 13025,
+        13026, 13026, 13027, 13030, 13030, 13030, 13033, 13033, 13035, 13038, 13044, 13044, 13044, 13048, 13052, 13055, 13060, 13060, 13062, 13068,
+        13071, 13074, 13074, 13075, 13075, 13075, 13078, 13081, 13085, 13087, 13092, 13092, 13097, 13099, 13099, 13099, 13099, 13102, 13102, 13102,
+        13105, 13109, 13109, 13110, 13112, 13112, 13116, 13116, 13122, 13126, 13127, 13134, 13137, 13137, 13137, 13142, 13142, 13143, 13148, 13148,
+        13148, 13148, 13148, 13148, 13148, 13154, 13156, 13160, 13164, 13165, 13167, 13167, 13168, 13168, 13168, 13168, 13169, 13171, 13174, 13181,
+        13184, 13184, 13184, 13187, 13187, 13194, 13194, 13200, 13203, 13203, 13213, 13213, 13216, 13217, 13217, 13224, 13226, 13226, 13227, 13228,
+        13228, 13228, 13228, 13231, 13232, 13237, 13240, 13240, 13240, 13240, 13241, 13244, 13244, 13244, 13251, 13252, 13257, 13261, 13261, 13261,
+        13269, 13272, 13272, 13276, 13277, 13282, 13288, 13290, 13290, 13290, 13296, 13297, 13297, 13300, 13304, 13308, 13312, 13312, 13312, 13312,
+        13312, 13312, 13316, 13320, 13327, 13328, 13328, 13328, 13330, 13332, 13334, 13335, 13335, 13338, 13338, 13340, 13344, 13345, 13349, 13349,
+        13349, 13350, 13350, 13350, 13354, 13358, 13358, 13358, 13360, 13360, 13360, 13362, 13365, 13365, 13365, 13369, 13372, 13372, 13373, 13375,
+        13375, 13377, 13380, 13384, 13387, 13391, 13391, 13397, 13399, 13402, 13402, 13403, 13403, 13406, 13406, 13409, 13409, 13409, 13409, 13414,
+        13415, 13415, 13417, 13417, 13417, 13422, 13426, 13429, 13429, 13433, 13433, 13438, 13440, 13440, 13443, 13445, 13445, 13448, 13452, 13452,
+        13452, 13455, 13455, 13458, 13461, 13468, 13470, 13477, 13480, 13484, 13487, 13488, 13490, 13494, 13497, 13500, 13500, 13506, 13506, 13510,
+        13510, 13510, 13510, 13510, 13513, 13513, 13518, 13518, 13519, 13523, 13523, 13524, 13528, 13529, 13529, 13529, 13529, 13530, 13532, 13534,
+        13534, 13534, 13538, 13540, 13544, 13547, 13547, 13552, 13554, 13558, 13561, 13562, 13562, 13563, 13563, 13567, 13567, 13567, 13568, 13568,
+        13569, 13571, 13576, 13580, 13580, 13581, 13590, 13590, 13599, 13602, 13606, 13613, 13615, 13617, 13620, 13621, 13626, 13626, 13629, 13629,
+        13629, 13634, 13636, 13637, 13637, 13637, 13637, 13637, 13637, 13642, 13642, 13642, 13646, 13651, 13651, 13652, 13657, 13657, 13666, 13669,
+        13669, 13673, 13682, 13690, 13690, 13698, 13698, 13698, 13704, 13704, 13707, 13707, 13712, 13718, 13723, 13723, 13723, 13725, 13726, 13726,
+        13745, 13746, 13746, 13750, 13751, 13751, 13755, 13758, 13758, 13759, 13760, 13762, 13762, 13766, 13766, 13770, 13776, 13778, 13781, 13784,
+        13784, 13784, 13788, 13788, 13788, 13791, 13792, 13794, 13796, 13806, 13806, 13812, 13812, 13812, 13814, 13816, 13820, 13820, 13821, 13821,
+        13825, 13829, 13832, 13833, 13833, 13836, 13838, 13846, 13850, 13850, 13851, 13858, 13861, 13867, 13868, 13868, 13868, 13869, 13874, 13874,
+        13879, 13879, 13881, 13884, 13888, 13889, 13892, 13893, 13895, 13896, 13898, 13901, 13902, 13906, 13906, 13907, 13907, 13914, 13915, 13915,
+        13915, 13915, 13926, 13932, 13932, 13938, 13942, 13947, 13947, 13950, 13951, 13957, 13962, 13965, 13971, 13975, 13975, 13980, 13980, 13987,
+        13991, 13994, 13998, 13998, 14001, 14001, 14003, 14015, 14015, 14018, 14018, 14018, 14026, 14026, 14026, 14026, 14045, 14046, 14046, 14046,
+        14050, 14051, 14053, 14057, 14065, 14070, 14070, 14072, 14077, 14077, 14085, 14087, 14088, 14091, 14095, 14095, 14096, 14102, 14104, 14106,
+        14106, 14109, 14109, 14109, 14114, 14114, 14118, 14120, 14122, 14126, 14127, 14132, 14138, 14140, 14140, 14140, 14141, 14147, 14151, 14155,
+        14159, 14159, 14159, 14159, 14161, 14162, 14170, 14178, 14180, 14185, 14190, 14194, 14197, 14197, 14200, 14202, 14202, 14202, 14209, 14214,
+        14214, 14214, 14218, 14221, 14221, 14226, 14229, 14232, 14236, 14236, 14236, 14239, 14243, 14244, 14251, 14256, 14256, 14261, 14261, 14263,
+        14272, 14272, 14277, 14278, 14278, 14280, 14288, 14289, 14289, 14289, 14289, 14291, 14295, 14299, 14300, 14300, 14302, 14302, 14302, 14302,
+        14302, 14306, 14310, 14314, 14314, 14315, 14321, 14322, 14324, 14325, 14326, 14326, 14326, 14327, 14329, 14329, 14332, 14335, 14339, 14343,
+        14347, 14350, 14350, 14350, 14350, 14353, 14356, 14358, 14358, 14367, 14375, 14375, 14377, 14380, 14380, 14380, 14380, 14381, 14381, 14382,
+        14386, 14393, 14399, 14400, 14400, 14401, 14401, 14401, 14405, 14405, 14405, 14411, 14411, 14413, 14414, 14416, 14417, 14420, 14428, 14431,
+        14431, 14437, 14437, 14441, 14443, 14443, 14447, 14450, 14452, 14454, 14457, 14459, 14461, 14469, 14475, 14476, 14481, 14482, 14484, 14489,
+        14496, 14498, 14499, 14499, 14501, 14503, 14507, 14512, 14516, 14519, 14523, 14524, 14526, 14527, 14527, 14530, 14534, 14535, 14544, 14547,
+        14547, 14547, 14550, 14556, 14557, 14560, 14561, 14561, 14565, 14568, 14568, 14571, 14571, 14571, 14575, 14578, 14580, 14581, 14584, 14589,
+        14592, 14593, 14599, 14601, 14605, 14607, 14607, 14607, 14610, 14613, 14615, 14622, 14629, 14630, 14640, 14647, 14654, 14657, 14657, 14658,
+        14663, 14665, 14665, 14669, 14676, 14679, 14682, 14682, 14683, 14687, 14689, 14690, 14695, 14700, 14700, 14703, 14708, 14714, 14715, 14718,
+        14721, 14721, 14722, 14728, 14732, 14734, 14735, 14735, 14738, 14739, 14739, 14740, 14741, 14745, 14747, 14749, 14750, 14757, 14757, 14759,
+        14762, 14766, 14766, 14771, 14772, 14772, 14772, 14774, 14775, 14777, 14779, 14779, 14781, 14784, 14784, 14791, 14792, 14803, 14809, 14811,
+        14822, 14822, 14825, 14829, 14832, 14838, 14843, 14847, 14847, 14849, 14852, 14854, 14854, 14855, 14855, 14855, 14861, 14861, 14861, 14862,
+        14863, 14865, 14865, 14867, 14873, 14879, 14881, 14892, 14892, 14894, 14897, 14902, 14913, 14913, 14915, 14917, 14918, 14922, 14922, 14924,
+        14930, 14935, 14937, 14942, 14950, 14956, 14956, 14956, 14957, 14960, 14967, 14975, 14978, 14985, 14993, 14993, 14998, 15005, 15009, 15012,
+        15015, 15016, 15016, 15019, 15022, 15024, 15028, 15029, 15029, 15030, 15030, 15030, 15034, 15034, 15039, 15042, 15046, 15046, 15050, 15054,
+        15058, 15060, 15061, 15061, 15068, 15072, 15076, 15082, 15082, 15085, 15088, 15092, 15095, 15098, 15098, 15101, 15101, 15101, 15101, 15101,
+        15101, 15101, 15105, 15105, 15113, 15116, 15116, 15116, 15116, 15117, 15117, 15117, 15117, 15122, 15122, 15122, 15122, 15122, 15126, 15126,
+        15126, 15127, 15130, 15130, 15131, 15140, 15140, 15143, 15143, 15143, 15144, 15144, 15144, 15144, 15146, 15148, 15151, 15151, 15155, 15157,
+        15159, 15159, 15159, 15159, 15162, 15169, 15176, 15176, 15181, 15186, 15190, 15190, 15190, 15190, 15190, 15190, 15192, 15193, 15197, 15197,
+        15197, 15198, 15201, 15207, 15209, 15211, 15214, 15217, 15218, 15218, 15218, 15221, 15223, 15223, 15227, 15231, 15235, 15241, 15242, 15249,
+        15252, 15252, 15252, 15252, 15252, 15252, 15252, 15256, 15262, 15262, 15265, 15268, 15268, 15268, 15270, 15270, 15270, 15275, 15275, 15275,
+        15278, 15281, 15288, 15293, 15293, 15293, 15293, 15297, 15297, 15303, 15303, 15303, 15303, 15303, 15304, 15307, 15308, 15308, 15312, 15316,
+        15330, 15331, 15332, 15332, 15332, 15332, 15332, 15332, 15332, 15332, 15332, 15332, 15333, 15338, 15338, 15340, 15340, 15341, 15341, 15341,
+        15341, 15341, 15342, 15342, 15342, 15347, 15347, 15354, 15358, 15361, 15361, 15363, 15363, 15363, 15363, 15363, 15365, 15371, 15371, 15384,
+        15387, 15387, 15387, 15387, 15389, 15391, 15394, 15394, 15394, 15396, 15396, 15398, 15400, 15400, 15404, 15406, 15407, 15417, 15417, 15419,
+        15421, 15422, 15422, 15426, 15426, 15429, 15429, 15433, 15437, 15437, 15437, 15437, 15437, 15440, 15440, 15448, 15450, 15450, 15452, 15455,
+        15461, 15461, 15462, 15462, 15467, 15471, 15471, 15476, 15476, 15477, 15477, 15477, 15477, 15477, 15478, 15482, 15486, 15487, 15487, 15487,
+        15491, 15491, 15491, 15491, 15493, 15493, 15493, 15496, 15500, 15501, 15504, 15504, 15509, 15509, 15515, 15515, 15519, 15519, 15520, 15520,
+        15521, 15521, 15527, 15528, 15529, 15532, 15534, 15540, 15543, 15550, 15552, 15555, 15560, 15560, 15565, 15565, 15568, 15568, 15568, 15568,
+        15568, 15571, 15580, 15588, 15588, 15590, 15594, 15594, 15595, 15597, 15598, 15599, 15601, 15601, 15604, 15609, 15610, 15610, 15618, 15619,
+        15620, 15620, 15620, 15620, 15621, 15621, 15621, 15621, 15626, 15632, 15634, 15637, 15639, 15642, 15642, 15651, 15660, 15661, 15661, 15665,
+        15674, 15674, 15683, 15689, 15695, 15695, 15695, 15695, 15695, 15695, 15696, 15704, 15709, 15709, 15709, 15709, 15713, 15714, 15714, 15714,
+        15714, 15715, 15717, 15719, 15720, 15722, 15722, 15723, 15723, 15724, 15725, 15726, 15728, 15731, 15733, 15738, 15738, 15738, 15738, 15738,
+        15743, 15744, 15750, 15759, 15759, 15759, 15760, 15760, 15760, 15762, 15763, 15763, 15763, 15763, 15766, 15769, 15775, 15780, 15784, 15790,
+        15792, 15796, 15801, 15801, 15801, 15805, 15811, 15816, 15816, 15819, 15822, 15830, 15832, 15835, 15842, 15842, 15842, 15842, 15842, 15846,
+        15850, 15850, 15850, 15850, 15859, 15859, 15859, 15859, 15860, 15860, 15860, 15862, 15862, 15863, 15863, 15869, 15871, 15871, 15871, 15871,
+        15871, 15871, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15873, 15878, 15880, 15880, 15880, 15885, 15889,
+        15890, 15894, 15898, 15898, 15901, 15906, 15911, 15912, 15912, 15915, 15917, 15917, 15918, 15920, 15921, 15925, 15925, 15925, 15925, 15926,
+        15935, 15935, 15935, 15941, 15946, 15948, 15951, 15952, 15952, 15952, 15952, 15957, 15958, 15959, 15963, 15963, 15963, 15967, 15967, 15971,
+        15973, 15975, 15975, 15976, 15980, 15980, 15984, 15989, 15990, 15994, 15996, 15996, 16000, 16000, 16001, 16001, 16006, 16006, 16008, 16013,
+        16018, 16018, 16022, 16025, 16034, 16046, 16053, 16053, 16053, 16053, 16053, 16059, 16063, 16067, 16069, 16075, 16075, 16077, 16079, 16080,
+        16083, 16084, 16084, 16087, 16090, 16092, 16092, 16092, 16092, 16093, 16093, 16093, 16094, 16097, 16097, 16098, 16098, 16103, 16107, 16107,
+        16109, 16109, 16113, 16116, 16117, 16118, 16118, 16118, 16127, 16130, 16136, 16150, 16150, 16151, 16154, 16157, 16157, 16157, 16160, 16161,
+        16162, 16162, 16165, 16165, 16167, 16167, 16169, 16170, 16174, 16178, 16181, 16181, 16181, 16183, 16186, 16189, 16194, 16197, 16200, 16204,
+        16204, 16208, 16209, 16209, 16211, 16214, 16218, 16218, 16221, 16222, 16222, 16225, 16227, 16227, 16231, 16237, 16242, 16242, 16244, 16246,
+        16250, 16256, 16258, 16264, 16270, 16272, 16272, 16272, 16276, 16281, 16284, 16286, 16289, 16289, 16293, 16293, 16296, 16299, 16299, 16301,
+        16301, 16304, 16304, 16304, 16311, 16313, 16319, 16319, 16319, 16319, 16320, 16323, 16324, 16334, 16334, 16337, 16342, 16345, 16345, 16345,
+        16346, 16346, 16348, 16348, 16349, 16354, 16356, 16361, 16363, 16372, 16381, 16387, 16390, 16390, 16390, 16398, 16403, 16405, 16408, 16409,
+        16409, 16410, 16410, 16411, 16412, 16420, 16423, 16428, 16430, 16433, 16434, 16434, 16439, 16439, 16439, 16440, 16441, 16445, 16445, 16453,
+        16453, 16459, 16466, 16473, 16473, 16480, 16483, 16486, 16489, 16497, 16497, 16501, 16501, 16502, 16504, 16507, 16507, 16514, 16514, 16522,
+        16522, 16527, 16531, 16533, 16536, 16536, 16541, 16547, 16554, 16561, 16566, 16571, 16571, 16573, 16577, 16582, 16585, 16585, 16586, 16586,
+        16589, 16597, 16597, 16601, 16604, 16608, 16609, 16614, 16619, 16622, 16624, 16624, 16624, 16625, 16630, 16634, 16640, 16644, 16644, 16646,
+        16647, 16651, 16655, 16659, 16667, 16670, 16675, 16675, 16680, 16682, 16682, 16682, 16685, 16685, 16687, 16692, 16693, 16695, 16696, 16696,
+        16700, 16700, 16700, 16704, 16705, 16708, 16708, 16715, 16715, 16715, 16721, 16725, 16727, 16729, 16729, 16733, 16734, 16734, 16736, 16740,
+        16740, 16744, 16746, 16748, 16756, 16761, 16763, 16765, 16770, 16773, 16776, 16777, 16777, 16777, 16777, 16782, 16783, 16785, 16788, 16791,
+        16791, 16792, 16795, 16798, 16798, 16802, 16806, 16806, 16812, 16814, 16815, 16815, 16816, 16818, 16818, 16826, 16826, 16826, 16828, 16832,
+        16833, 16838, 16844, 16849, 16854, 16855, 16857, 16857, 16858, 16860, 16860, 16860, 16860, 16860, 16863, 16863, 16863, 16864, 16864, 16866,
+        16866, 16867, 16867, 16867, 16867, 16867, 16867, 16869, 16869, 16872, 16874, 16874, 16881, 16888, 16892, 16893, 16897, 16897, 16897, 16897,
+        16899, 16899, 16899, 16899, 16900, 16903, 16905, 16907, 16913, 16917, 16917, 16924, 16925, 16927, 16927, 16928, 16935, 16938, 16938, 16940,
+        16940, 16945, 16949, 16949, 16952, 16956, 16956, 16959, 16962, 16969, 16972, 16976, 16976, 16980, 16987, 16994, 16995, 16996, 16996, 17001,
+        17005, 17006, 17010, 17015, 17019, 17024, 17024, 17027, 17027, 17028, 17028, 17028, 17028, 17028, 17028, 17028, 17030, 17033, 17034, 17038,
+        17041, 17041, 17041, 17041, 17041, 17042, 17044, 17044, 17044, 17048, 17049, 17049, 17049, 17052, 17052, 17052, 17058, 17063, 17067, 17069,
+        17070, 17070, 17071, 17071, 17076, 17076, 17076, 17076, 17083, 17088, 17094, 17094, 17094, 17094, 17094, 17095, 17095, 17098, 17099, 17100,
+        17100, 17100, 17105, 17105, 17106, 17106, 17106, 17106, 17110, 17115, 17122, 17127, 17127, 17132, 17133, 17136, 17145, 17147, 17149, 17149,
+        17157, 17159, 17162, 17165, 17165, 17167, 17167, 17173, 17173, 17173, 17175, 17176, 17180, 17182, 17188, 17190, 17195, 17196, 17196, 17198,
+        17203, 17203, 17205, 17206, 17206, 17207, 17209, 17209, 17209, 17212, 17212, 17213, 17215, 17215, 17215, 17215, 17219, 17219, 17223, 17225,
+        17228, 17231, 17233, 17236, 17243, 17243, 17246, 17249, 17249, 17249, 17249, 17256, 17256, 17260, 17260, 17260, 17260, 17262, 17262, 17262,
+        17262, 17262, 17262, 17266, 17268, 17269, 17272, 17273, 17274, 17276, 17276, 17280, 17280, 17282, 17288, 17288, 17291, 17299, 17301, 17304,
+        17305, 17305, 17308, 17308, 17310, 17310, 17313, 17313, 17315, 17315, 17315, 17315, 17315, 17315, 17315, 17320, 17324, 17324, 17324, 17324,
+        17328, 17328, 17333, 17336, 17339, 17342, 17342, 17342, 17345, 17345, 17346, 17348, 17353, 17353, 17355, 17355, 17360, 17360, 17360, 17361,
+        17361, 17361, 17361, 17362, 17362, 17364, 17367, 17367, 17368, 17368, 17369, 17369, 17369, 17372, 17375, 17375, 17375, 17377, 17377, 17377,
+        17377, 17378, 17381, 17381, 17389, 17391, 17391, 17394, 17395, 17397, 17399, 17403, 17408, 17409, 17413, 17414, 17420, 17422, 17428, 17428,
+        17429, 17429, 17434, 17438, 17438, 17438, 17440, 17442, 17442, 17442, 17442, 17444, 17446, 17448, 17451, 17458, 17458, 17462, 17465, 17470,
+        17471, 17473, 17475, 17475, 17486, 17488, 17489, 17489, 17494, 17499, 17499, 17504, 17508, 17508, 17516, 17519, 17520, 17523, 17530, 17534,
+        17539, 17542, 17542, 17542, 17546, 17550, 17550, 17556, 17558, 17561, 17564, 17571, 17574, 17574, 17574, 17574, 17574, 17575, 17577, 17582,
+        17582, 17582, 17584, 17587, 17587, 17587, 17591, 17593, 17594, 17599, 17603, 17603, 17608, 17608, 17611, 17614, 17616, 17620, 17620, 17623,
+        17630, 17635, 17636, 17637, 17649, 17651, 17655, 17661, 17667, 17670, 17673, 17674, 17675, 17675, 17675, 17675, 17682, 17682, 17685, 17685,
+        17687, 17689, 17690, 17694, 17694, 17697, 17704, 17706, 17706, 17706, 17712, 17721, 17727, 17733, 17738, 17739, 17740, 17741, 17742, 17743,
+        17749, 17749, 17753, 17753, 17754, 17761, 17765, 17769, 17773, 17779, 17781, 17781, 17784, 17785, 17789, 17794, 17795, 17795, 17797, 17801,
+        17805, 17805, 17805, 17805, 17805, 17807, 17807, 17807, 17807, 17809, 17811, 17811, 17816, 17819, 17824, 17824, 17826, 17826, 17826, 17829,
+        17829, 17837, 17837, 17840, 17842, 17846, 17846, 17849, 17852, 17854, 17860, 17861, 17864, 17866, 17872, 17873, 17873, 17878, 17882, 17882,
+        17890, 17890, 17890, 17890, 17895, 17898, 17904, 17906, 17911, 17913, 17914, 17915, 17915, 17915, 17920, 17920, 17920, 17920, 17922, 17926,
+        17926, 17926, 17926, 17929, 17936, 17940, 17941, 17941, 17941, 17943, 17943, 17943, 17944, 17948, 17951, 17953, 17953, 17953, 17959, 17959,
+        17959, 17964, 17964, 17976, 17976, 17977, 17979, 17979, 17988, 17988, 17988, 17988, 17992, 17998, 17998, 18002, 18002, 18005, 18008, 18011,
+        18014, 18014, 18014, 18016, 18018, 18024, 18024, 18025, 18026, 18032, 18032, 18032, 18032, 18032, 18032, 18032, 18033, 18033, 18035, 18037,
+        18039, 18046, 18046, 18054, 18055, 18055, 18058, 18065, 18065, 18065, 18068, 18070, 18070, 18070, 18070, 18072, 18072, 18077, 18083, 18083,
+        18083, 18083, 18086, 18086, 18088, 18088, 18088, 18089, 18090, 18090, 18092, 18092, 18097, 18097, 18097, 18097, 18097, 18098, 18100, 18100,
+        18100, 18100, 18108, 18113, 18116, 18122, 18122, 18134, 18134, 18134, 18134, 18138, 18138, 18139, 18139, 18139, 18139, 18139, 18139, 18145,
+        18145, 18145, 18145, 18145, 18145, 18145, 18151, 18151, 18158, 18158, 18160, 18162, 18163, 18168, 18169, 18175, 18176, 18176, 18184, 18186,
+        18186, 18188, 18188, 18190, 18191, 18192, 18192, 18195, 18195, 18195, 18195, 18200, 18201, 18202, 18203, 18203, 18206, 18210, 18211, 18216,
+        18219, 18225, 18226, 18227, 18229, 18229, 18229, 18233, 18233, 18241, 18246, 18247, 18251, 18256, 18256, 18256, 18256, 18266, 18276, 18276,
+        18279, 18283, 18283, 18288, 18289, 18289, 18291, 18293, 18297, 18297, 18303, 18304, 18307, 18307, 18309, 18311, 18311, 18314, 18315, 18317,
+        18317, 18319, 18319, 18319, 18319, 18319, 18319, 18319, 18326, 18332, 18338, 18341, 18341, 18347, 18347, 18350, 18350, 18350, 18352, 18352,
+        18352, 18353, 18354, 18356, 18356, 18359, 18359, 18366, 18366, 18368, 18370, 18370, 18370, 18374, 18376, 18379, 18385, 18386, 18386, 18390,
+        18392, 18392, 18396, 18397, 18400, 18402, 18402, 18407, 18411, 18413, 18413, 18413, 18413, 18413, 18416, 18417, 18419, 18422, 18425, 18425,
+        18425, 18434, 18437, 18437, 18444, 18444, 18448, 18448, 18448, 18452, 18453, 18453, 18453, 18456, 18460, 18463, 18468, 18471, 18471, 18475,
+        18482, 18482, 18482, 18482, 18484, 18484, 18486, 18486, 18486, 18486, 18486, 18487, 18487, 18487, 18487, 18487, 18488, 18494, 18494, 18497,
+        18502, 18506, 18506, 18507, 18512, 18515, 18515, 18517, 18518, 18520, 18520, 18520, 18520, 18521, 18526, 18533, 18539, 18545, 18548, 18556,
+        18556, 18561, 18568, 18570, 18573, 18577, 18577, 18577, 18578, 18580, 18582, 18583, 18587, 18589, 18590, 18592, 18595, 18595, 18595, 18603,
+        18606, 18608, 18615, 18615, 18615, 18618, 18620, 18620, 18623, 18626, 18628, 18628, 18634, 18636, 18636, 18640, 18643, 18643, 18643, 18643,
+        18643, 18643, 18643, 18647, 18650, 18652, 18659, 18659, 18659, 18659, 18659, 18659, 18659, 18666, 18667, 18667, 18673, 18673, 18673, 18675,
+        18675, 18675, 18675, 18678, 18678, 18678, 18678, 18679, 18683, 18683, 18683, 18684, 18686, 18688, 18688, 18690, 18690, 18692, 18694, 18694,
+        18694, 18694, 18700, 18709, 18709, 18710, 18712, 18713, 18716, 18716, 18719, 18719, 18722, 18722, 18731, 18731, 18731, 18731, 18731, 18731,
+        18732, 18732, 18738, 18746, 18746, 18746, 18746, 18750, 18750, 18752, 18752, 18753, 18754, 18758, 18758, 18759, 18761, 18766, 18770, 18772,
+        18774, 18778, 18778, 18784, 18788, 18789, 18789, 18791, 18793, 18797, 18802, 18806, 18808, 18811, 18812, 18812, 18821, 18825, 18827, 18831,
+        18831, 18831, 18831, 18833, 18834, 18836, 18841, 18844, 18847, 18850, 18853, 18855, 18856, 18857, 18860, 18860, 18861, 18862, 18866, 18868,
+        18870, 18871, 18874, 18874, 18874, 18874, 18881, 18884, 18887, 18894, 18894, 18898, 18901, 18905, 18907, 18908, 18908, 18914, 18914, 18916,
+        18916, 18916, 18916, 18917, 18924, 18926, 18926, 18926, 18929, 18931, 18931, 18931, 18933, 18935, 18935, 18935, 18935, 18939, 18944, 18947,
+        18947, 18947, 18948, 18952, 18952, 18954, 18954, 18954, 18954, 18958, 18962, 18967, 18967, 18970, 18974, 18978, 18985, 18985, 18985, 18988,
+        18988, 18988, 18992, 18992, 18992, 18992, 18992, 18992, 18992, 18997, 18997, 18997, 18999, 19005, 19007, 19007, 19011, 19016, 19017, 19017,
+        19020, 19027, 19031, 19031, 19033, 19033, 19033, 19033, 19033, 19033, 19033, 19033, 19033, 19033, 19034, 19039, 19039, 19039, 19039, 19039,
+        19039, 19045, 19045, 19045, 19045, 19045, 19046, 19052, 19053, 19056, 19060, 19063, 19063, 19064, 19064, 19064, 19070, 19075, 19079, 19079,
+        19081, 19081, 19081, 19084, 19085, 19094, 19098, 19098, 19101, 19101, 19105, 19110, 19111, 19111, 19112, 19118, 19122, 19122, 19123, 19123,
+        19123, 19123, 19125, 19125, 19130, 19130, 19131, 19137, 19143, 19147, 19150, 19150, 19150, 19150, 19153, 19153, 19156, 19156, 19159, 19161,
+        19162, 19162, 19164, 19167, 19167, 19167, 19170, 19174, 19177, 19178, 19178, 19185, 19188, 19191, 19191, 19196, 19202, 19203, 19204, 19204,
+        19204, 19207, 19212, 19218, 19223, 19227, 19229, 19232, 19234, 19234, 19234, 19240, 19246, 19249, 19251, 19252, 19254, 19257, 19260, 19263,
+        19263, 19266, 19268, 19268, 19269, 19270, 19270, 19270, 19275, 19278, 19281, 19286, 19289, 19293, 19296, 19299, 19299, 19302, 19307, 19309,
+        19309, 19309, 19310, 19314, 19316, 19316, 19317, 19323, 19331, 19331, 19337, 19339, 19345, 19345, 19345, 19345, 19348, 19352, 19352, 19359,
+        19361, 19361, 19361, 19362, 19363, 19363, 19363, 19363, 19364, 19366, 19369, 19377, 19383, 19387, 19389, 19389, 19393, 19396, 19401, 19404,
+        19409, 19413, 19417, 19421, 19425, 19428, 19431, 19432, 19435, 19436, 19441, 19442, 19447, 19450, 19455, 19455, 19456, 19459, 19459, 19464,
+        19464, 19470, 19473, 19477, 19477, 19477, 19481, 19481, 19483, 19488, 19488, 19491, 19491, 19491, 19494, 19496, 19496, 19501, 19503, 19505,
+        19506, 19507, 19510, 19512, 19512, 19515, 19515, 19519, 19522, 19526, 19529, 19531, 19532, 19532, 19533, 19535, 19539, 19541, 19544, 19545,
+        19548, 19551, 19557, 19557, 19558, 19560, 19561, 19561, 19561, 19563, 19571, 19576, 19576, 19576, 19577, 19577, 19582, 19584, 19586, 19586,
+        19586, 19586, 19602, 19602, 19602, 19606, 19606, 19611, 19611, 19614, 19614, 19618, 19618, 19618, 19627, 19628, 19632, 19638, 19638, 19642,
+        19643, 19645, 19645, 19649, 19649, 19650, 19650, 19654, 19654, 19661, 19662, 19662, 19662, 19662, 19668, 19671, 19672, 19676, 19676, 19677,
+        19678, 19683, 19685, 19688, 19689, 19695, 19698, 19698, 19700, 19700, 19709, 19712, 19713, 19713, 19713, 19715, 19722, 19723, 19723, 19725,
+        19725, 19726, 19728, 19728, 19731, 19731, 19731, 19733, 19733, 19737, 19743, 19747, 19750, 19753, 19756, 19756, 19758, 19758, 19760, 19760,
+        19760, 19762, 19763, 19766, 19776, 19776, 19781, 19783, 19786, 19786, 19788, 19791, 19791, 19795, 19799, 19802, 19809, 19809, 19809, 19809,
+        19809, 19809, 19810, 19813, 19813, 19813, 19815, 19816, 19818, 19818, 19818, 19820, 19822, 19822, 19825, 19828, 19828, 19828, 19829, 19829,
+        19837, 19837, 19839, 19842, 19847, 19849, 19852, 19857, 19857, 19859, 19864, 19864, 19865, 19866, 19869, 19872, 19876, 19879, 19883, 19883,
+        19885, 19885, 19890, 19892, 19898, 19901, 19908, 19910, 19913, 19917, 19921, 19926, 19933, 19935, 19935, 19946, 19948, 19957, 19957, 19957,
+        19957, 19957, 19958, 19959, 19963, 19966, 19972, 19975, 19977, 19977, 19977, 19978, 19985, 19986, 19989, 19993, 19997, 20002, 20004, 20004,
+        20004, 20005, 20007, 20012, 20016, 20019, 20021, 20021, 20021, 20021, 20021, 20030, 20035, 20041, 20042, 20046, 20046, 20050, 20053, 20053,
+        20053, 20054, 20057, 20058, 20058, 20059, 20059, 20060, 20061, 20068, 20070, 20073, 20073, 20073, 20077, 20077, 20080, 20081, 20081, 20086,
+        20091, 20096, 20101, 20105, 20107, 20110, 20110, 20114, 20114, 20116, 20119, 20120, 20124, 20124, 20124, 20125, 20131, 20136, 20144, 20145,
+        20148, 20148, 20148, 20153, 20153, 20156, 20156, 20157, 20158, 20162, 20162, 20164, 20165, 20165, 20167, 20169, 20175, 20175, 20175, 20175,
+        20175, 20177, 20180, 20185, 20185, 20189, 20193, 20193, 20196, 20196, 20198, 20198, 20202, 20202, 20205, 20210, 20215, 20219, 20220, 20220,
+        20220, 20225, 20227, 20230, 20231, 20231, 20234, 20236, 20238, 20241, 20241, 20244, 20250, 20255, 20258, 20261, 20266, 20266, 20269, 20269,
+        20272, 20272, 20272, 20274, 20274, 20274, 20279, 20280, 20281, 20282, 20286, 20288, 20288, 20288, 20289, 20290, 20290, 20302, 20305, 20306,
+        20309, 20311, 20311, 20312, 20312, 20314, 20317, 20319, 20319, 20322, 20322, 20324, 20325, 20327, 20330, 20338, 20339, 20341, 20346, 20352,
+        20355, 20359, 20364, 20367, 20372, 20374, 20375, 20380, 20384, 20385, 20388, 20391, 20394, 20394, 20399, 20406, 20411, 20415, 20421, 20421,
+        20423, 20423, 20427, 20430, 20433, 20438, 20440, 20446, 20450, 20450, 20451, 20453, 20453, 20455, 20457, 20460, 20463, 20465, 20465, 20467,
+        20467, 20467, 20467, 20469, 20474, 20478, 20479, 20481, 20486, 20487, 20487, 20493, 20496, 20504, 20511, 20513, 20514, 20515, 20515, 20523,
+        20526, 20528, 20531, 20535, 20535, 20536, 20536, 20540, 20543, 20543, 20544, 20544, 20545, 20545, 20550, 20553, 20556, 20556, 20556, 20556,
+        20561, 20566, 20567, 20568, 20568, 20568, 20570, 20575, 20575, 20576, 20578, 20580, 20583, 20586, 20589, 20590, 20592, 20595, 20597, 20600,
+        20603, 20608, 20611, 20611, 20614, 20620, 20624, 20627, 20628, 20628, 20628, 20632, 20632, 20633, 20633, 20638, 20642, 20642, 20644, 20649,
+        20656, 20665, 20666, 20674, 20677, 20679, 20683, 20689, 20691, 20693, 20699, 20705, 20707, 20709, 20718, 20722, 20723, 20725, 20728, 20729,
+        20735, 20738, 20741, 20745, 20746, 20751, 20751, 20755, 20757, 20763, 20772, 20776, 20776, 20781, 20785, 20791, 20794, 20794, 20794, 20794,
+        20795, 20801, 20808, 20808, 20808, 20812, 20817, 20818, 20821, 20823, 20823, 20826, 20827, 20830, 20835, 20837, 20845, 20853, 20853, 20857,
+        20860, 20861, 20867, 20867, 20867, 20867, 20867, 20873, 20879, 20879, 20879, 20879, 20879, 20879, 20883, 20884, 20887, 20901, 20906, 20914,
+        20914, 20914, 20916, 20917, 20921, 20923, 20928, 20934, 20936, 20936, 20939, 20942, 20942, 20943, 20945, 20947, 20949, 20952, 20956, 20961,
+        20966, 20968, 20971, 20975, 20977, 20977, 20977, 20980, 20980, 20980, 20980, 20981, 20981, 20981, 20981, 20981, 20981, 20981, 20983, 20986,
+        20988, 20989, 20996, 21004, 21004, 21010, 21012, 21012, 21028, 21030, 21032, 21035, 21038, 21047, 21049, 21055, 21059, 21059, 21061, 21069,
+        21075, 21082, 21082, 21082, 21082, 21082, 21082, 21082, 21088, 21092, 21097, 21097, 21103, 21109, 21109, 21110, 21110, 21114, 21117, 21119,
+        21123, 21124, 21127, 21136, 21137, 21143, 21147, 21152, 21155, 21155, 21156, 21161, 21162, 21165, 21168, 21177, 21178, 21181, 21184, 21187,
+        21188, 21190, 21190, 21194, 21195, 21195, 21198, 21199, 21203, 21206, 21209, 21212, 21213, 21214, 21214, 21215, 21216, 21219, 21221, 21227,
+        21228, 21228, 21231, 21237, 21242, 21246, 21250, 21252, 21256, 21264, 21266, 21271, 21278, 21283, 21284, 21288, 21291, 21294, 21295, 21300,
+        21301, 21302, 21302, 21303, 21311, 21315, 21316, 21316, 21318, 21325, 21328, 21328, 21334, 21337, 21337, 21337, 21337, 21339, 21340, 21345,
+        21353, 21354, 21354, 21356, 21356, 21356, 21361, 21365, 21366, 21368, 21376, 21382, 21382, 21382, 21391, 21396, 21396, 21397, 21397, 21401,
+        21401, 21401, 21407, 21407, 21413, 21413, 21413, 21413, 21413, 21415, 21415, 21415, 21418, 21421, 21424, 21427, 21431, 21431, 21434, 21435,
+        21436, 21439, 21444, 21444, 21451, 21456, 21463, 21467, 21473, 21478, 21484, 21487, 21488, 21488, 21489, 21490, 21490, 21494, 21502, 21510,
+        21515, 21518, 21520, 21521, 21524, 21524, 21528, 21528, 21532, 21538, 21538, 21544, 21544, 21546, 21547, 21550, 21553, 21553, 21553, 21562,
+        21566, 21568, 21571, 21571, 21573, 21574, 21574, 21583, 21585, 21587, 21587, 21587, 21588, 21591, 21595, 21595, 21601, 21602, 21606, 21606,
+        21612, 21612, 21614, 21615, 21623, 21629, 21631, 21634, 21638, 21643, 21644, 21649, 21649, 21649, 21652, 21654, 21656, 21659, 21660, 21663,
+        21668, 21668, 21669, 21669, 21669, 21669, 21672, 21675, 21681, 21681, 21681, 21685, 21686, 21690, 21690, 21692, 21692, 21697, 21700, 21700,
+        21700, 21700, 21700, 21701, 21703, 21705, 21705, 21705, 21708, 21710, 21713, 21721, 21722, 21724, 21725, 21725, 21725, 21725, 21736, 21739,
+        21744, 21744, 21751, 21751, 21751, 21751, 21755, 21759, 21760, 21760, 21762, 21765, 21769, 21772, 21775, 21777, 21780, 21782, 21786, 21790,
+        21792, 21798, 21801, 21801, 21802, 21802, 21804, 21806, 21807, 21807, 21809, 21809, 21813, 21816, 21817, 21818, 21818, 21821, 21823, 21828,
+        21831, 21835, 21837, 21839, 21841, 21846, 21846, 21848, 21849, 21851, 21853, 21854, 21854, 21857, 21857, 21857, 21857, 21857, 21864, 21865,
+        21867, 21868, 21868, 21868, 21871, 21874, 21875, 21878, 21878, 21881, 21885, 21887, 21891, 21892, 21894, 21896, 21898, 21900, 21900, 21906,
+        21909, 21913, 21916, 21919, 21923, 21926, 21932, 21936, 21936, 21940, 21940, 21942, 21943, 21945, 21947, 21949, 21952, 21955, 21955, 21956,
+        21963, 21966, 21967, 21967, 21967, 21969, 21970, 21975, 21980, 21984, 21985, 21989, 21993, 21995, 21995, 21999, 22006, 22006, 22016, 22021,
+        22024, 22024, 22029, 22029, 22029, 22030, 22031, 22033, 22036, 22036, 22042, 22044, 22044, 22051, 22051, 22054, 22059, 22064, 22064, 22066,
+        22069, 22073, 22074, 22074, 22079, 22085, 22089, 22092, 22092, 22092, 22094, 22095, 22101, 22105, 22109, 22109, 22109, 22109, 22110, 22115,
+        22116, 22116, 22116, 22120, 22130, 22130, 22130, 22131, 22133, 22134, 22134, 22137, 22139, 22142, 22146, 22150, 22150, 22150, 22150, 22150,
+        22150, 22153, 22155, 22158, 22166, 22174, 22179, 22182, 22182, 22186, 22191, 22193, 22203, 22204, 22204, 22204, 22207, 22207, 22207, 22207,
+        22210, 22210, 22213, 22219, 22220, 22227, 22230, 22230, 22230, 22230, 22233, 22238, 22239, 22240, 22240, 22244, 22249, 22250, 22253, 22257,
+        22259, 22263, 22267, 22269, 22269, 22269, 22273, 22277, 22280, 22286, 22290, 22291, 22292, 22297, 22297, 22298, 22299, 22302, 22305, 22306,
+        22308, 22309, 22310, 22314, 22317, 22321, 22321, 22324, 22327, 22329, 22333, 22334, 22337, 22341, 22345, 22348, 22348, 22348, 22350, 22351,
+        22354, 22358, 22362, 22363, 22366, 22367, 22367, 22368, 22372, 22374, 22378, 22378, 22378, 22383, 22385, 22388, 22388, 22391, 22392, 22396,
+        22400, 22405, 22408, 22412, 22415, 22418, 22423, 22423, 22426, 22431, 22432, 22433, 22435, 22435, 22438, 22442, 22442, 22444, 22446, 22447,
+        22448, 22452, 22458, 22458, 22462, 22465, 22468, 22468, 22471, 22471, 22471, 22472, 22477, 22482, 22490, 22490, 22490, 22494, 22497, 22497,
+        22503, 22503, 22503, 22507, 22514, 22516, 22516, 22519, 22520, 22528, 22534, 22539, 22544, 22545, 22546, 22546, 22552, 22552, 22555, 22558,
+        22559, 22560, 22562, 22570, 22572, 22579, 22581, 22586, 22587, 22587, 22590, 22590, 22591, 22592, 22598, 22604, 22604, 22609, 22611, 22611,
+        22613, 22613, 22614, 22615, 22616, 22617, 22619, 22619, 22622, 22625, 22627, 22629, 22633, 22637, 22639, 22639, 22640, 22644, 22648, 22653,
+        22658, 22662, 22662, 22663, 22668, 22675, 22685, 22691, 22699, 22699, 22699, 22704, 22707, 22707, 22707, 22707, 22709, 22715, 22720, 22725,
+        22725, 22728, 22731, 22735, 22737, 22737, 22739, 22742, 22745, 22749, 22752, 22754, 22754, 22759, 22760, 22760, 22763, 22765, 22769, 22770,
+        22777, 22777, 22783, 22783, 22784, 22789, 22793, 22794, 22797, 22799, 22799, 22799, 22799, 22800, 22804, 22804, 22805, 22806, 22807, 22810,
+        22810, 22811, 22814, 22817, 22820, 22821, 22829, 22830, 22830, 22832, 22832, 22837, 22840, 22846, 22848, 22854, 22854, 22858, 22863, 22867,
+        22871, 22876, 22876, 22879, 22883, 22886, 22892, 22898, 22898, 22901, 22903, 22903, 22903, 22907, 22907, 22911, 22911, 22911, 22911, 22916,
+        22917, 22920, 22924, 22924, 22928, 22932, 22932, 22937, 22941, 22941, 22945, 22947, 22954, 22957, 22957, 22957, 22958, 22959, 22961, 22961,
+        22965, 22966, 22966, 22968, 22972, 22974, 22975, 22975, 22975, 22977, 22982, 22987, 22987, 22988, 22990, 22990, 22991, 22992, 22995, 22995,
+        22999, 23000, 23002, 23006, 23006, 23009, 23010, 23010, 23015, 23018, 23024, 23025, 23028, 23030, 23035, 23035, 23039, 23044, 23048, 23049,
+        23049, 23049, 23052, 23053, 23055, 23057, 23057, 23061, 23065, 23065, 23068, 23070, 23071, 23072, 23072, 23074, 23079, 23087, 23094, 23094,
+        23098, 23098, 23098, 23099, 23103, 23105, 23111, 23112, 23115, 23118, 23124, 23124, 23125, 23126, 23134, 23136, 23140, 23146, 23146, 23146,
+        23146, 23146, 23152, 23153, 23153, 23154, 23158, 23158, 23158, 23158, 23166, 23170, 23170, 23171, 23171, 23175, 23175, 23176, 23176, 23177,
+        23181, 23182, 23184, 23184, 23189, 23191, 23194, 23196, 23203, 23204, 23205, 23205, 23209, 23214, 23217, 23217, 23217, 23222, 23222, 23222,
+        23222, 23226, 23227, 23227, 23232, 23238, 23242, 23242, 23243, 23243, 23245, 23249, 23252, 23258, 23261, 23261, 23261, 23261, 23265, 23267,
+        23273, 23277, 23278, 23278, 23278, 23282, 23284, 23286, 23287, 23292, 23293, 23296, 23299, 23299, 23304, 23308, 23309, 23312, 23314, 23314,
+        23318, 23318, 23318, 23318, 23318, 23318, 23318, 23320, 23324, 23325, 23325, 23325, 23329, 23336, 23343, 23343, 23347, 23349, 23351, 23357,
+        23362, 23362, 23366, 23370, 23371, 23376, 23379, 23382, 23382, 23389, 23390, 23393, 23396, 23398, 23404, 23404, 23408, 23412, 23412, 23414,
+        23416, 23418, 23423, 23423, 23423, 23423, 23427, 23427, 23430, 23431, 23434, 23439, 23439, 23441, 23444, 23447, 23450, 23451, 23451, 23452,
+        23454, 23454, 23460, 23462, 23464, 23464, 23465, 23466, 23469, 23470, 23474, 23474, 23474, 23474, 23476, 23479, 23479, 23479, 23479, 23482,
+        23482, 23486, 23487, 23490, 23493, 23494, 23495, 23503, 23507, 23510, 23510, 23510, 23510, 23516, 23516, 23519, 23519, 23523, 23533, 23533,
+        23541, 23541, 23547, 23551, 23554, 23554, 23555, 23558, 23560, 23560, 23560, 23560, 23561, 23561, 23562, 23563, 23567, 23569, 23572, 23573,
+        23579, 23579, 23582, 23586, 23588, 23590, 23593, 23595, 23600, 23600, 23609, 23613, 23617, 23619, 23619, 23621, 23621, 23622, 23622, 23629,
+        23629, 23629, 23629, 23640, 23641, 23647, 23648, 23650, 23653, 23656, 23663, 23668, 23670, 23670, 23672, 23672, 23672, 23676, 23676, 23687,
+        23688, 23692, 23694, 23702, 23708, 23710, 23714, 23719, 23721, 23722, 23726, 23731, 23734, 23741, 23743, 23746, 23750, 23752, 23753, 23754,
+        23755, 23758, 23761, 23762, 23767, 23771, 23775, 23780, 23784, 23792, 23793, 23793, 23802, 23804, 23807, 23812, 23816, 23820, 23825, 23825,
+        23825, 23832, 23833, 23833, 23833, 23833, 23836, 23836, 23841, 23844, 23844, 23851, 23852, 23856, 23863, 23866, 23869, 23869, 23869, 23874,
+        23880, 23882, 23887, 23891, 23891, 23893, 23894, 23899, 23902, 23903, 23905, 23907, 23907, 23907, 23908, 23911, 23915, 23915, 23916, 23917,
+        23921, 23926, 23927, 23930, 23931, 23935, 23935, 23937, 23947, 23947, 23952, 23955, 23955, 23955, 23960, 23964, 23964, 23964, 23969, 23974,
+        23975, 23977, 23977, 23977, 23981, 23984, 23985, 23995, 23996, 24001, 24002, 24003, 24006, 24006, 24011, 24017, 24017, 24024, 24024, 24029,
+        24029, 24029, 24034, 24037, 24037, 24042, 24042, 24044, 24051, 24053, 24055, 24055, 24056, 24060, 24064, 24066, 24075, 24075, 24075, 24076,
+        24078, 24078, 24078, 24082, 24082, 24091, 24091, 24091, 24094, 24094, 24094, 24095, 24097, 24099, 24103, 24107, 24113, 24115, 24115, 24115,
+        24119, 24123, 24126, 24127, 24129, 24134, 24137, 24142, 24142, 24142, 24147, 24149, 24151, 24156, 24156, 24165, 24168, 24168, 24172, 24172,
+        24176, 24178, 24182, 24183, 24183, 24186, 24188, 24188, 24195, 24197, 24201, 24201, 24201, 24204, 24206, 24207, 24212, 24213, 24217, 24221,
+        24225, 24226, 24226, 24227, 24231, 24232, 24232, 24234, 24236, 24240, 24240, 24245, 24256, 24265, 24274, 24275, 24276, 24276, 24277, 24278,
+        24279, 24281, 24284, 24284, 24284, 24284, 24285, 24288, 24288, 24292, 24292, 24296, 24296, 24298, 24301, 24303, 24309, 24316, 24318, 24318,
+        24318, 24320, 24329, 24342, 24348, 24358, 24362, 24364, 24364, 24369, 24376, 24380, 24380, 24381, 24385, 24385, 24390, 24390, 24390, 24396,
+        24396, 24397, 24397, 24400, 24400, 24406, 24407, 24408, 24410, 24415, 24418, 24422, 24425, 24430, 24430, 24439, 24442, 24443, 24443, 24443,
+        24443, 24443, 24443, 24447, 24448, 24452, 24455, 24461, 24466, 24466, 24475, 24477, 24482, 24484, 24486, 24486, 24493, 24498, 24502, 24503,
+        24507, 24511, 24513, 24515, 24515, 24516, 24518, 24519, 24520, 24521, 24522, 24524, 24527, 24530, 24531, 24533, 24533, 24534, 24537, 24538,
+        24539, 24540, 24540, 24540, 24540, 24546, 24556, 24558, 24562, 24567, 24571, 24571, 24572, 24573, 24583, 24586, 24589, 24593, 24595, 24597,
+        24600, 24604, 24608, 24618, 24625, 24630, 24630, 24635, 24637, 24641, 24646, 24648, 24651, 24656, 24659, 24660, 24660, 24664, 24664, 24665,
+        24667, 24670, 24675, 24680, 24683, 24687, 24687, 24689, 24692, 24698, 24699, 24699, 24710, 24716, 24721, 24723, 24725, 24731, 24736, 24736,
+        24740, 24745, 24751, 24755, 24761, 24771, 24772, 24781, 24784, 24788, 24790, 24793, 24798, 24801, 24805, 24807, 24811, 24811, 24821, 24821,
+        24821, 24822, 24824, 24825, 24826, 24829, 24829, 24831, 24835, 24836, 24839, 24842, 24845, 24851, 24853, 24855, 24855, 24856, 24858, 24859,
+        24859, 24859, 24866, 24868, 24868, 24878, 24885, 24885, 24885, 24887, 24895, 24897, 24900, 24902, 24905, 24905, 24910, 24913, 24919, 24924,
+        24927, 24929, 24929, 24933, 24934, 24935, 24937, 24937, 24941, 24947, 24948, 24951, 24951, 24967, 24985, 24985, 24988, 24990, 24994, 24998,
+        25001, 25001, 25002, 25004, 25004, 25004, 25007, 25007, 25012, 25016, 25021, 25021, 25021, 25023, 25028, 25033, 25035, 25039, 25045, 25047,
+        25051, 25054, 25056, 25060, 25062, 25064, 25064, 25069, 25069, 25072, 25074, 25074, 25080, 25080, 25088, 25091, 25091, 25091, 25091, 25091,
+        25091, 25100, 25101, 25106, 25110, 25114, 25118, 25118, 25121, 25124, 25128, 25131, 25132, 25134, 25137, 25141, 25143, 25149, 25152, 25154,
+        25160, 25163, 25163, 25163, 25167, 25171, 25175, 25175, 25175, 25175, 25178, 25179, 25181, 25187, 25187, 25187, 25189, 25189, 25189, 25189,
+        25189, 25189, 25194, 25194, 25196, 25199, 25199, 25200, 25200, 25209, 25209, 25219, 25223, 25224, 25232, 25240, 25247, 25254, 25257, 25261,
+        25261, 25261, 25261, 25261, 25262, 25265, 25269, 25274, 25281, 25288, 25292, 25294, 25296, 25296, 25298, 25298, 25300, 25300, 25302, 25303,
+        25304, 25308, 25311, 25311, 25311, 25314, 25319, 25325, 25329, 25330, 25331, 25331, 25338, 25345, 25352, 25354, 25358, 25363, 25367, 25367,
+        25374, 25379, 25379, 25379, 25385, 25385, 25385, 25385, 25389, 25389, 25389, 25391, 25393, 25403, 25413, 25419, 25422, 25426, 25429, 25433,
+        25436, 25436, 25443, 25450, 25455, 25456, 25456, 25456, 25459, 25462, 25462, 25464, 25466, 25467, 25468, 25468, 25471, 25471, 25475, 25480,
+        25483, 25483, 25487, 25493, 25496, 25501, 25507, 25507, 25513, 25513, 25515, 25515, 25515, 25515, 25515, 25515, 25519, 25519, 25526, 25526,
+        25526, 25529, 25535, 25537, 25539, 25546, 25551, 25553, 25554, 25554, 25554, 25556, 25557, 25560, 25567, 25567, 25570, 25574, 25574, 25574,
+        25578, 25579, 25582, 25582, 25583, 25584, 25584, 25586, 25586, 25588, 25588, 25590, 25596, 25596, 25598, 25604, 25609, 25609, 25614, 25620,
+        25625, 25626, 25628, 25634, 25637, 25640, 25643, 25647, 25651, 25660, 25660, 25664, 25668, 25670, 25673, 25675, 25675, 25675, 25675, 25681,
+        25684, 25684, 25686, 25689, 25689, 25690, 25694, 25696, 25698, 25698, 25698, 25698, 25701, 25704, 25706, 25708, 25713, 25713, 25713, 25716,
+        25716, 25720, 25720, 25726, 25729, 25730, 25734, 25734, 25735, 25735, 25738, 25741, 25746, 25753, 25757, 25757, 25758, 25758, 25758, 25760,
+        25763, 25764, 25771, 25775, 25781, 25784, 25784, 25787, 25789, 25789, 25791, 25792, 25792, 25793, 25793, 25794, 25794, 25794, 25794, 25794,
+        25799, 25799, 25799, 25799, 25802, 25805, 25805, 25805, 25805, 25808, 25811, 25813, 25816, 25820, 25825, 25825, 25828, 25830, 25831, 25832,
+        25832, 25833, 25834, 25834, 25835, 25838, 25841, 25845, 25849, 25853, 25859, 25863, 25867, 25867, 25868, 25872, 25872, 25875, 25879, 25879,
+        25883, 25889, 25893, 25897, 25899, 25903, 25911, 25921, 25925, 25925, 25929, 25933, 25935, 25937, 25937, 25940, 25940, 25940, 25941, 25948,
+        25956, 25958, 25958, 25959, 25960, 25960, 25960, 25960, 25960, 25962, 25962, 25965, 25965, 25969, 25973, 25976, 25983, 25983, 25986, 25989,
+        25992, 25994, 25999, 25999, 25999, 26004, 26007, 26012, 26015, 26019, 26022, 26028, 26031, 26032, 26032, 26033, 26034, 26036, 26043, 26046,
+        26054, 26054, 26055, 26058, 26060, 26064, 26064, 26065, 26068, 26070, 26072, 26072, 26075, 26081, 26088, 26094, 26101, 26104, 26110, 26114,
+        26117, 26117, 26118, 26118, 26120, 26127, 26130, 26131, 26131, 26137, 26137, 26141, 26141, 26144, 26144, 26144, 26144, 26145, 26150, 26153,
+        26155, 26155, 26156, 26156, 26158, 26158, 26160, 26162, 26168, 26172, 26180, 26181, 26182, 26182, 26186, 26187, 26192, 26196, 26197, 26199,
+        26199, 26201, 26203, 26204, 26207, 26211, 26211, 26215, 26217, 26220, 26220, 26221, 26222, 26226, 26227, 26228, 26234, 26234, 26236, 26244,
+        26247, 26250, 26250, 26252, 26253, 26259, 26264, 26264, 26265, 26268, 26269, 26272, 26275, 26275, 26279, 26280, 26281, 26281, 26283, 26287,
+        26287, 26287, 26287, 26292, 26295, 26295, 26299, 26299, 26306, 26310, 26316, 26316, 26316, 26320, 26324, 26326, 26327, 26327, 26327, 26330,
+        26332, 26338, 26338, 26340, 26343, 26347, 26347, 26348, 26349, 26352, 26353, 26353, 26353, 26354, 26354, 26358, 26359, 26359, 26359, 26361,
+        26362, 26364, 26364, 26372, 26379, 26385, 26392, 26393, 26393, 26393, 26398, 26402, 26402, 26404, 26407, 26408, 26413, 26414, 26415, 26415,
+        26415, 26415, 26415, 26419, 26422, 26426, 26430, 26433, 26438, 26438, 26438, 26439, 26441, 26445, 26448, 26449, 26453, 26453, 26458, 26462,
+        26464, 26464, 26468, 26476, 26478, 26485, 26485, 26485, 26491, 26491, 26496, 26496, 26499, 26506, 26509, 26510, 26511, 26516, 26519, 26522,
+        26523, 26525, 26528, 26533, 26533, 26533, 26536, 26536, 26539, 26543, 26548, 26553, 26563, 26566, 26569, 26569, 26569, 26574, 26581, 26581,
+        26586, 26590, 26591, 26594, 26596, 26599, 26601, 26601, 26601, 26601, 26605, 26611, 26611, 26611, 26612, 26612, 26613, 26613, 26615, 26620,
+        26620, 26620, 26624, 26628, 26628, 26628, 26631, 26635, 26640, 26640, 26641, 26644, 26647, 26647, 26647, 26648, 26651, 26654, 26654, 26658,
+        26660, 26660, 26660, 26661, 26662, 26664, 26665, 26666, 26669, 26671, 26672, 26673, 26675, 26677, 26679, 26680, 26681, 26681, 26683, 26684,
+        26687, 26689, 26689, 26689, 26692, 26692, 26695, 26700, 26712, 26717, 26720, 26720, 26723, 26734, 26735, 26740, 26740, 26746, 26747, 26751,
+        26751, 26756, 26756, 26759, 26759, 26759, 26760, 26765, 26769, 26774, 26774, 26774, 26774, 26777, 26779, 26781, 26781, 26781, 26784, 26784,
+        26784, 26784, 26787, 26790, 26790, 26791, 26792, 26793, 26794, 26795, 26795, 26797, 26798, 26798, 26803, 26803, 26805, 26805, 26809, 26813,
+        26816, 26821, 26825, 26828, 26830, 26835, 26835, 26835, 26838, 26840, 26841, 26843, 26843, 26843, 26843, 26845, 26848, 26850, 26855, 26858,
+        26860, 26863, 26863, 26865, 26865, 26868, 26873, 26876, 26881, 26886, 26889, 26889, 26897, 26899, 26900, 26907, 26907, 26907, 26907, 26909,
+        26909, 26912, 26916, 26918, 26918, 26918, 26918, 26924, 26924, 26928, 26933, 26933, 26933, 26933, 26937, 26939, 26939, 26941, 26946, 26951,
+        26951, 26952, 26954, 26958, 26961, 26966, 26967, 26969, 26970, 26971, 26971, 26977, 26979, 26982, 26985, 26989, 26992, 26994, 26998, 27002,
+        27005, 27007, 27007, 27007, 27008, 27009, 27009, 27010, 27011, 27011, 27011, 27013, 27018, 27018, 27026, 27030, 27037, 27042, 27046, 27049,
+        27053, 27057, 27062, 27063, 27069, 27075, 27075, 27075, 27075, 27079, 27083, 27088, 27088, 27088, 27091, 27096, 27103, 27106, 27106, 27109,
+        27114, 27115, 27116, 27123, 27124, 27124, 27129, 27130, 27132, 27134, 27137, 27138, 27143, 27143, 27149, 27153, 27160, 27161, 27167, 27169,
+        27169, 27169, 27169, 27176, 27184, 27190, 27190, 27193, 27197, 27197, 27197, 27201, 27206, 27209, 27210, 27211, 27211, 27211, 27211, 27213,
+        27219, 27222, 27222, 27225, 27226, 27226, 27233, 27234, 27237, 27243, 27246, 27247, 27247, 27253, 27263, 27267, 27269, 27270, 27280, 27289,
+        27292, 27298, 27301, 27306, 27310, 27312, 27315, 27318, 27321, 27329, 27332, 27332, 27338, 27343, 27349, 27349, 27349, 27352, 27358, 27363,
+        27364, 27370, 27374, 27378, 27382, 27386, 27391, 27391, 27393, 27398, 27406, 27406, 27409, 27411, 27414, 27414, 27416, 27418, 27419, 27424,
+        27430, 27438, 27442, 27445, 27448, 27451, 27451, 27457, 27467, 27468, 27472, 27477, 27482, 27485, 27491, 27497, 27504, 27512, 27516, 27518,
+        27519, 27519, 27522, 27528, 27528, 27531, 27531, 27531, 27536, 27539, 27539, 27540, 27540, 27543, 27545, 27548, 27548, 27550, 27551, 27552,
+        27553, 27562, 27565, 27571, 27571, 27572, 27572, 27572, 27576, 27584, 27584, 27586, 27586, 27591, 27591, 27594, 27594, 27594, 27597, 27599,
+        27604, 27604, 27605, 27610, 27611, 27614, 27621, 27627, 27630, 27635, 27641, 27646, 27646, 27647, 27647, 27650, 27653, 27653, 27660, 27660,
+        27664, 27664, 27664, 27665, 27665, 27670, 27672, 27672, 27672, 27673, 27675, 27680, 27684, 27690, 27694, 27699, 27704, 27705, 27706, 27713,
+        27713, 27713, 27716, 27717, 27719, 27727, 27732, 27732, 27739, 27743, 27749, 27756, 27756, 27756, 27756, 27757, 27762, 27766, 27770, 27772,
+        27776, 27782, 27789, 27791, 27793, 27797, 27797, 27797, 27802, 27802, 27803, 27809, 27810, 27810, 27811, 27819, 27820, 27820, 27820, 27824,
+        27824, 27825, 27829, 27829, 27830, 27836, 27837, 27839, 27840, 27842, 27849, 27853, 27856, 27857, 27857, 27859, 27859, 27862, 27863, 27863,
+        27870, 27875, 27876, 27882, 27885, 27892, 27893, 27898, 27900, 27900, 27900, 27900, 27907, 27913, 27920, 27920, 27925, 27925, 27929, 27934,
+        27940, 27943, 27943, 27943, 27947, 27947, 27947, 27947, 27949, 27951, 27959, 27959, 27959, 27960, 27963, 27969, 27973, 27982, 27986, 27989,
+        27992, 27992, 27996, 27998, 27998, 28001, 28002, 28003, 28008, 28008, 28008, 28008, 28008, 28008, 28010, 28010, 28012, 28016, 28023, 28027,
+        28030, 28032, 28033, 28034, 28034, 28034, 28034, 28034, 28036, 28037, 28037, 28040, 28040, 28040, 28041, 28049, 28051, 28053, 28053, 28054,
+        28057, 28063, 28064, 28065, 28071, 28071, 28075, 28077, 28078, 28078, 28078, 28081, 28081, 28084, 28086, 28087, 28089, 28092, 28094, 28101,
+        28105, 28105, 28105, 28111, 28115, 28115, 28118, 28120, 28123, 28127, 28130, 28133, 28134, 28137, 28141, 28141, 28141, 28141, 28148, 28152,
+        28156, 28156, 28165, 28173, 28174, 28178, 28180, 28185, 28185, 28186, 28187, 28187, 28187, 28194, 28199, 28199, 28206, 28210, 28214, 28214,
+        28214, 28214, 28214, 28217, 28217, 28218, 28219, 28221, 28224, 28227, 28230, 28232, 28236, 28240, 28246, 28247, 28248, 28254, 28255, 28260,
+        28260, 28263, 28265, 28266, 28269, 28273, 28279, 28280, 28285, 28286, 28287, 28290, 28290, 28296, 28300, 28304, 28308, 28309, 28312, 28312,
+        28315, 28319, 28325, 28325, 28328, 28329, 28331, 28332, 28333, 28333, 28333, 28333, 28337, 28338, 28341, 28345, 28345, 28346, 28347, 28350,
+        28354, 28357, 28360, 28361, 28363, 28363, 28363, 28363, 28365, 28366, 28367, 28370, 28374, 28377, 28379, 28383, 28384, 28385, 28385, 28388,
+        28388, 28392, 28394, 28397, 28403, 28404, 28404, 28404, 28405, 28406, 28406, 28409, 28410, 28412, 28416, 28419, 28421, 28425, 28425, 28429,
+        28431, 28433, 28433, 28433, 28434, 28436, 28440, 28442, 28447, 28449, 28453, 28456, 28459, 28463, 28463, 28467, 28471, 28476, 28482, 28486,
+        28486, 28487, 28492, 28492, 28493, 28493, 28493, 28495, 28496, 28501, 28504, 28504, 28506, 28512, 28513, 28515, 28518, 28523, 28529, 28531,
+        28537, 28542, 28544, 28547, 28549, 28552, 28557, 28558, 28558, 28563, 28565, 28568, 28570, 28572, 28574, 28579, 28579, 28585, 28585, 28585,
+        28585, 28585, 28585, 28585, 28588, 28588, 28589, 28590, 28591, 28592, 28593, 28593, 28595, 28600, 28600, 28601, 28602, 28603, 28603, 28605,
+        28605, 28606, 28607, 28610, 28613, 28619, 28619, 28623, 28627, 28627, 28631, 28633, 28636, 28642, 28642, 28646, 28647, 28649, 28656, 28658,
+        28662, 28663, 28666, 28669, 28671, 28671, 28673, 28673, 28673, 28673, 28675, 28677, 28680, 28683, 28683, 28684, 28686, 28689, 28692, 28694,
+        28694, 28699, 28702, 28702, 28702, 28702, 28706, 28707, 28709, 28712, 28712, 28718, 28723, 28724, 28724, 28724, 28724, 28724, 28725, 28725,
+        28726, 28731, 28734, 28734, 28734, 28736, 28737, 28741, 28741, 28741, 28741, 28745, 28752, 28753, 28755, 28757, 28758, 28759, 28759, 28759,
+        28759, 28759, 28759, 28759, 28761, 28762, 28765, 28765, 28767, 28767, 28767, 28769, 28771, 28771, 28775, 28778, 28782, 28783, 28785, 28792,
+        28797, 28798, 28801, 28806, 28808, 28809, 28811, 28813, 28816, 28816, 28817, 28818, 28824, 28826, 28827, 28829, 28830, 28833, 28837, 28837,
+        28840, 28842, 28846, 28847, 28852, 28856, 28860, 28864, 28867, 28872, 28872, 28875, 28878, 28883, 28886, 28887, 28894, 28896, 28897, 28903,
+        28904, 28904, 28905, 28905, 28906, 28907, 28908, 28910, 28921, 28921, 28922, 28927, 28929, 28930, 28935, 28935, 28938, 28942, 28943, 28950,
+        28952, 28956, 28960, 28966, 28966, 28966, 28969, 28972, 28974, 28976, 28981, 28981, 28985, 28987, 28991, 28991, 28991, 28995, 29000, 29004,
+        29009, 29009, 29009, 29011, 29013, 29015, 29023, 29025, 29026, 29028, 29035, 29035, 29036, 29041, 29045, 29047, 29049, 29050, 29050, 29050,
+        29050, 29052, 29054, 29054, 29056, 29059, 29066, 29066, 29066, 29067, 29072, 29074, 29076, 29078, 29081, 29084, 29085, 29085, 29087, 29088,
+        29092, 29097, 29101, 29102, 29103, 29107, 29111, 29111, 29115, 29121, 29123, 29127, 29130, 29131, 29135, 29136, 29137, 29137, 29138, 29142,
+        29142, 29146, 29147, 29154, 29154, 29157, 29158, 29158, 29158, 29158, 29159, 29167, 29169, 29176, 29176, 29176, 29176, 29176, 29176, 29176,
+        29176, 29179, 29182, 29186, 29188, 29190, 29190, 29190, 29191, 29192, 29192, 29192, 29194, 29197, 29199, 29199, 29200, 29202, 29203, 29203,
+        29203, 29203, 29204, 29207, 29207, 29207, 29213, 29214, 29215, 29215, 29218, 29220, 29223, 29225, 29225, 29225, 29225, 29226, 29230, 29237,
+        29243, 29252, 29257, 29259, 29265, 29266, 29276, 29280, 29282, 29285, 29287, 29291, 29294, 29296, 29298, 29309, 29309, 29311, 29315, 29322,
+        29324, 29328, 29331, 29331, 29335, 29338, 29338, 29343, 29343, 29349, 29349, 29358, 29370, 29370, 29379, 29384, 29384, 29391, 29392, 29398,
+        29400, 29400, 29401, 29405, 29405, 29406, 29412, 29414, 29416, 29418, 29418, 29425, 29427, 29434, 29434, 29437, 29442, 29444, 29447, 29451,
+        29452, 29455, 29457, 29462, 29462, 29466, 29467, 29469, 29470, 29470, 29471, 29471, 29475, 29480, 29486, 29487, 29492, 29499, 29499, 29499,
+        29499, 29503, 29507, 29510, 29514, 29518, 29521, 29521, 29523, 29524, 29524, 29525, 29528, 29528, 29528, 29528, 29528, 29531, 29535, 29537,
+        29540, 29544, 29544, 29544, 29547, 29550, 29550, 29550, 29552, 29553, 29558, 29558, 29561, 29565, 29565, 29570, 29571, 29573, 29578, 29579,
+        29582, 29586, 29588, 29589, 29589, 29589, 29592, 29598, 29599, 29602, 29602, 29602, 29604, 29609, 29609, 29612, 29616, 29617, 29619, 29619,
+        29620, 29621, 29622, 29623, 29624, 29625, 29625, 29625, 29634, 29637, 29641, 29642, 29644, 29644, 29653, 29654, 29654, 29655, 29660, 29663,
+        29665, 29672, 29678, 29686, 29691, 29691, 29692, 29694, 29694, 29698, 29700, 29703, 29710, 29712, 29715, 29715, 29718, 29719, 29721, 29723,
+        29723, 29723, 29727, 29727, 29730, 29735, 29736, 29738, 29738, 29746, 29751, 29751, 29757, 29759, 29759, 29759, 29760, 29762, 29763, 29765,
+        29768, 29770, 29773, 29775, 29775, 29775, 29778, 29778, 29780, 29784, 29784, 29787, 29790, 29792, 29796, 29796, 29800, 29804, 29805, 29805,
+        29808, 29810, 29811, 29812, 29817, 29820, 29825, 29829, 29829, 29833, 29834, 29835, 29836, 29836, 29836, 29836, 29836, 29836, 29836, 29836,
+        29837, 29839, 29842, 29842, 29842, 29847, 29850, 29850, 29858, 29863, 29863, 29866, 29869, 29873, 29875, 29877, 29884, 29889, 29891, 29893,
+        29893, 29897, 29902, 29904, 29906, 29908, 29912, 29912, 29912, 29913, 29915, 29920, 29925, 29925, 29925, 29928, 29932, 29935, 29944, 29944,
+        29948, 29950, 29952, 29958, 29966, 29966, 29977, 29977, 29980, 29983, 29983, 29983, 29990, 29990, 30001, 30001, 30009, 30009, 30009, 30009,
+        30009, 30009, 30015, 30017, 30019, 30019, 30019, 30024, 30024, 30024, 30028, 30028, 30028, 30028, 30028, 30028, 30029, 30029, 30038, 30045,
+        30053, 30053, 30059, 30060, 30063, 30063, 30063, 30067, 30072, 30074, 30078, 30079, 30081, 30081, 30081, 30081, 30084, 30086, 30087, 30093,
+        30096, 30100, 30106, 30106, 30107, 30115, 30117, 30119, 30121, 30123, 30124, 30125, 30125, 30128, 30128, 30128, 30128, 30128, 30130, 30133,
+        30139, 30141, 30142, 30142, 30146, 30152, 30152, 30153, 30156, 30156, 30159, 30159, 30160, 30163, 30165, 30170, 30177, 30181, 30184, 30189,
+        30189, 30189, 30193, 30193, 30193, 30197, 30198, 30198, 30201, 30201, 30202, 30202, 30204, 30205, 30206, 30207, 30210, 30210, 30211, 30217,
+        30217, 30220, 30220, 30222, 30225, 30225, 30226, 30230, 30236, 30238, 30241, 30241, 30241, 30243, 30244, 30245, 30248, 30251, 30255, 30258,
+        30263, 30267, 30267, 30274, 30282, 30283, 30284, 30287, 30293, 30295, 30298, 30299, 30299, 30299, 30302, 30304, 30307, 30307, 30307, 30307,
+        30307, 30309, 30309, 30310, 30311, 30312, 30314, 30318, 30321, 30323, 30324, 30328, 30330, 30334, 30334, 30334, 30337, 30340, 30343, 30352,
+        30352, 30359, 30365, 30368, 30368, 30372, 30376, 30380, 30387, 30387, 30387, 30387, 30392, 30399, 30399, 30403, 30406, 30408, 30408, 30408,
+        30410, 30410, 30416, 30421, 30424, 30426, 30429, 30432, 30435, 30441, 30445, 30445, 30445, 30445, 30445, 30450, 30450, 30455, 30456, 30456,
+        30459, 30461, 30465, 30465, 30473, 30476, 30476, 30479, 30479, 30480, 30480, 30483, 30489, 30493, 30494, 30497, 30500, 30503, 30503, 30504,
+        30504, 30504, 30504, 30504, 30505, 30506, 30507, 30508, 30514, 30516, 30516, 30519, 30522, 30524, 30524, 30529, 30530, 30534, 30538, 30538,
+        30538, 30540, 30542, 30543, 30543, 30543, 30543, 30543, 30544, 30545, 30548, 30548, 30553, 30556, 30556, 30559, 30562, 30562, 30567, 30567,
+        30573, 30575, 30579, 30585, 30585, 30585, 30585, 30594, 30596, 30602, 30607, 30609, 30609, 30609, 30611, 30612, 30613, 30615, 30620, 30625,
+        30627, 30627, 30627, 30633, 30639, 30641, 30644, 30649, 30653, 30656, 30661, 30662, 30668, 30674, 30676, 30679, 30679, 30680, 30685, 30685,
+        30691, 30692, 30695, 30699, 30700, 30709, 30711, 30718, 30722, 30722, 30724, 30728, 30728, 30728, 30728, 30728, 30728, 30729, 30730, 30732,
+        30732, 30735, 30739, 30742, 30746, 30753, 30758, 30762, 30765, 30772, 30775, 30780, 30785, 30786, 30786, 30787, 30787, 30791, 30796, 30807,
+        30812, 30812, 30818, 30820, 30823, 30824, 30828, 30834, 30839, 30843, 30846, 30850, 30851, 30852, 30854, 30855, 30855, 30858, 30863, 30864,
+        30867, 30870, 30871, 30878, 30881, 30889, 30889, 30898, 30902, 30906, 30911, 30914, 30916, 30916, 30918, 30922, 30924, 30925, 30925, 30929,
+        30929, 30929, 30938, 30944, 30948, 30953, 30953, 30953, 30956, 30962, 30967, 30971, 30975, 30978, 30978, 30978, 30978, 30981, 30983, 30989,
+        30989, 30992, 31002, 31007, 31007, 31007, 31009, 31010, 31010, 31011, 31011, 31011, 31015, 31016, 31017, 31017, 31018, 31025, 31027, 31028,
+        31028, 31030, 31030, 31031, 31036, 31037, 31038, 31041, 31042, 31043, 31046, 31052, 31054, 31054, 31056, 31059, 31064, 31064, 31070, 31074,
+        31074, 31079, 31086, 31090, 31100, 31100, 31103, 31105, 31112, 31115, 31120, 31120, 31123, 31123, 31127, 31132, 31134, 31138, 31142, 31143,
+        31145, 31149, 31150, 31150, 31151, 31152, 31157, 31161, 31161, 31165, 31165, 31167, 31172, 31178, 31182, 31186, 31190, 31194, 31197, 31201,
+        31203, 31203, 31205, 31206, 31206, 31208, 31208, 31212, 31216, 31220, 31224, 31227, 31227, 31231, 31237, 31240, 31247, 31250, 31250, 31252,
+        31255, 31257, 31259, 31261, 31265, 31268, 31268, 31268, 31271, 31280, 31285, 31288, 31290, 31296, 31306, 31311, 31312, 31324, 31328, 31328,
+        31334, 31337, 31342, 31344, 31347, 31347, 31348, 31348, 31349, 31353, 31353, 31355, 31360, 31360, 31360, 31360, 31360, 31361, 31362, 31363,
+        31367, 31370, 31373, 31373, 31373, 31376, 31376, 31379, 31381, 31381, 31384, 31388, 31388, 31396, 31402, 31409, 31409, 31416, 31423, 31425,
+        31425, 31427, 31427, 31430, 31432, 31433, 31439, 31439, 31442, 31448, 31448, 31450, 31453, 31454, 31454, 31454, 31454, 31460, 31461, 31464,
+        31473, 31473, 31476, 31480, 31485, 31490, 31491, 31495, 31497, 31497, 31500, 31504, 31508, 31510, 31512, 31512, 31514, 31519, 31522, 31522,
+        31524, 31526, 31527, 31531, 31532, 31532, 31532, 31532, 31536, 31538, 31538, 31538, 31540, 31544, 31557, 31562, 31567, 31571, 31571, 31571,
+        31572, 31574, 31576, 31582, 31583, 31586, 31586, 31588, 31589, 31591, 31596, 31602, 31605, 31605, 31607, 31612, 31612, 31612, 31615, 31618,
+        31621, 31621, 31621, 31625, 31633, 31637, 31637, 31637, 31640, 31644, 31647, 31650, 31650, 31653, 31654, 31654, 31654, 31656, 31656, 31656,
+        31656, 31657, 31657, 31664, 31666, 31669, 31673, 31676, 31682, 31684, 31685, 31689, 31690, 31693, 31700, 31703, 31707, 31717, 31722, 31725,
+        31729, 31731, 31733, 31734, 31736, 31737, 31737, 31737, 31739, 31745, 31749, 31752, 31754, 31754, 31759, 31763, 31767, 31771, 31773, 31775,
+        31779, 31782, 31785, 31789, 31795, 31799, 31799, 31802, 31804, 31807, 31809, 31810, 31810, 31811, 31813, 31813, 31813, 31813, 31813, 31813,
+        31813, 31819, 31819, 31819, 31819, 31819, 31824, 31824, 31828, 31832, 31833, 31839, 31842, 31842, 31846, 31853, 31855, 31857, 31858, 31865,
+        31871, 31873, 31879, 31880, 31880, 31886, 31891, 31896, 31898, 31898, 31904, 31904, 31905, 31905, 31909, 31910, 31910, 31910, 31912, 31912,
+        31913, 31913, 31917, 31919, 31922, 31924, 31931, 31934, 31935, 31938, 31941, 31943, 31947, 31951, 31952, 31955, 31958, 31961, 31963, 31965,
+        31967, 31970, 31974, 31976, 31980, 31982, 31983, 31983, 31985, 31985, 31988, 31989, 31993, 31993, 31999, 32000, 32005, 32008, 32012, 32019,
+        32024, 32025, 32027, 32027, 32028, 32029, 32032, 32035, 32037, 32038, 32045, 32048, 32048, 32050, 32054, 32055, 32055, 32055, 32059, 32060,
+        32064, 32067, 32068, 32068, 32068, 32068, 32072, 32075, 32075, 32083, 32087, 32087, 32087, 32091, 32096, 32098, 32098, 32101, 32102, 32109,
+        32110, 32111, 32116, 32118, 32121, 32122, 32124, 32124, 32132, 32133, 32133, 32140, 32144, 32146, 32149, 32152, 32152, 32153, 32153, 32157,
+        32161, 32167, 32167, 32173, 32173, 32173, 32173, 32173, 32173, 32180, 32180, 32188, 32190, 32192, 32195, 32195, 32199, 32200, 32205, 32207,
+        32208, 32210, 32213, 32217, 32220, 32224, 32228, 32228, 32231, 32235, 32239, 32243, 32243, 32246, 32251, 32256, 32259, 32261, 32262, 32262,
+        32263, 32263, 32264, 32270, 32283, 32283, 32285, 32285, 32289, 32293, 32294, 32296, 32297, 32301, 32302, 32302, 32302, 32306, 32310, 32318,
+        32322, 32322, 32322, 32322, 32327, 32330, 32332, 32333, 32334, 32339, 32341, 32344, 32344, 32347, 32349, 32350, 32354, 32354, 32357, 32357,
+        32358, 32361, 32364, 32364, 32365, 32366, 32366, 32372, 32377, 32378, 32392, 32396, 32396, 32399, 32399, 32405, 32405, 32406, 32411, 32411,
+        32413, 32417, 32420, 32425, 32431, 32431, 32431, 32431, 32434, 32434, 32437, 32439, 32439, 32439, 32445, 32449, 32449, 32450, 32451, 32456,
+        32457, 32457, 32462, 32463, 32463, 32466, 32466, 32471, 32480, 32483, 32484, 32490, 32494, 32495, 32496, 32499, 32501, 32502, 32505, 32505,
+        32505, 32509, 32509, 32510, 32513, 32513, 32519, 32519, 32520, 32521, 32524, 32526, 32530, 32535, 32542, 32548, 32551, 32554, 32557, 32562,
+        32562, 32564, 32566, 32566, 32569, 32569, 32573, 32577, 32577, 32579, 32579, 32588, 32588, 32591, 32597, 32597, 32603, 32603, 32606, 32608,
+        32610, 32611, 32616, 32622, 32625, 32627, 32628, 32631, 32631, 32631, 32631, 32637, 32642, 32642, 32643, 32645, 32646, 32650, 32654, 32657,
+        32660, 32664, 32669, 32670, 32673, 32673, 32676, 32688, 32692, 32697, 32703, 32707, 32709, 32713, 32715, 32715, 32715, 32719, 32727, 32735,
+        32736, 32736, 32739, 32740, 32743, 32744, 32747, 32749, 32749, 32750, 32764, 32769, 32775, 32782, 32787, 32787, 32796, 32802, 32810, 32811,
+        32816, 32816, 32816, 32816, 32816, 32818, 32822, 32824, 32827, 32833, 32834, 32837, 32837, 32838, 32841, 32843, 32843, 32843, 32843, 32843,
+        32847, 32850, 32852, 32853, 32853, 32853, 32855, 32856, 32859, 32862, 32865, 32866, 32875, 32876, 32877, 32879, 32881, 32882, 32883, 32887,
+        32895, 32895, 32897, 32905, 32906, 32913, 32918, 32924, 32929, 32930, 32930, 32936, 32938, 32943, 32944, 32951, 32951, 32951, 32955, 32959,
+        32963, 32967, 32971, 32971, 32979, 32983, 32984, 32990, 32995, 32995, 33001, 33006, 33006, 33006, 33006, 33008, 33009, 33009, 33013, 33017,
+        33022, 33025, 33034, 33041, 33044, 33044, 33044, 33055, 33060, 33060, 33063, 33066, 33070, 33075, 33075, 33075, 33080, 33084, 33089, 33096,
+        33100, 33101, 33106, 33113, 33118, 33120, 33123, 33126, 33131, 33134, 33138, 33138, 33140, 33144, 33151, 33155, 33158, 33158, 33158, 33158,
+        33162, 33167, 33169, 33171, 33174, 33178, 33178, 33179, 33181, 33181, 33190, 33192, 33193, 33195, 33199, 33199, 33202, 33207, 33211, 33218,
+        33224, 33229, 33229, 33236, 33236, 33240, 33251, 33259, 33259, 33268, 33268, 33274, 33279, 33281, 33281, 33283, 33283, 33285, 33296, 33296,
+        33301, 33301, 33308, 33309, 33309, 33309, 33311, 33322, 33322, 33328, 33328, 33328, 33328, 33328, 33328, 33328, 33328, 33330, 33331, 33332,
+        33332, 33332, 33332, 33338, 33345, 33350, 33350, 33350, 33350, 33350, 33350, 33356, 33359, 33361, 33364, 33370, 33371, 33377, 33377, 33383,
+        33383, 33385, 33391, 33391, 33391, 33393, 33394, 33397, 33398, 33400, 33401, 33402, 33402, 33409, 33409, 33414, 33417, 33418, 33421, 33424,
+        33426, 33426, 33426, 33427, 33428, 33429, 33432, 33441, 33442, 33445, 33448, 33449, 33450, 33454, 33457, 33462, 33467, 33475, 33479, 33483,
+        33485, 33489, 33490, 33491, 33491, 33491, 33494, 33495, 33495, 33497, 33498, 33498, 33499, 33500, 33505, 33507, 33513, 33513, 33521, 33527,
+        33529, 33533, 33533, 33533, 33538, 33542, 33546, 33549, 33552, 33557, 33561, 33565, 33567, 33570, 33572, 33574, 33580, 33580, 33580, 33580,
+        33580, 33580, 33585, 33588, 33592, 33593, 33598, 33601, 33602, 33604, 33604, 33607, 33607, 33607, 33608, 33608, 33608, 33609, 33613, 33620,
+        33624, 33626, 33628, 33628, 33631, 33632, 33632, 33632, 33637, 33644, 33648, 33650, 33656, 33657, 33658, 33663, 33665, 33669, 33673, 33673,
+        33673, 33673, 33681, 33687, 33688, 33688, 33689, 33689, 33689, 33691, 33695, 33695, 33696, 33698, 33700, 33703, 33704, 33704, 33708, 33712,
+        33716, 33716, 33717, 33720, 33723, 33726, 33726, 33726, 33728, 33729, 33733, 33734, 33735, 33735, 33735, 33738, 33742, 33744, 33746, 33755,
+        33757, 33762, 33769, 33773, 33773, 33773, 33773, 33779, 33779, 33780, 33783, 33783, 33786, 33795, 33798, 33798, 33798, 33800, 33800, 33804,
+        33806, 33806, 33816, 33817, 33817, 33819, 33821, 33822, 33826, 33828, 33832, 33837, 33837, 33837, 33840, 33841, 33842, 33842, 33843, 33846,
+        33849, 33853, 33856, 33859, 33859, 33864, 33867, 33871, 33874, 33878, 33878, 33881, 33884, 33884, 33885, 33888, 33894, 33896, 33900, 33903,
+        33908, 33908, 33908, 33908, 33910, 33916, 33916, 33918, 33921, 33925, 33929, 33931, 33938, 33939, 33943, 33945, 33945, 33945, 33951, 33954,
+        33954, 33958, 33962, 33962, 33962, 33962, 33964, 33966, 33966, 33970, 33972, 33977, 33981, 33981, 33981, 33982, 33984, 33987, 33992, 33996,
+        34000, 34002, 34002, 34002, 34005, 34008, 34008, 34014, 34016, 34017, 34021, 34022, 34027, 34031, 34031, 34031, 34031, 34031, 34031, 34032,
+        34036, 34042, 34045, 34047, 34050, 34052, 34052, 34062, 34066, 34072, 34072, 34075, 34077, 34078, 34078, 34082, 34083, 34084, 34087, 34090,
+        34098, 34100, 34104, 34109, 34109, 34114, 34115, 34116, 34116, 34118, 34120, 34120, 34120, 34123, 34126, 34129, 34131, 34134, 34139, 34144,
+        34148, 34152, 34158, 34160, 34166, 34166, 34167, 34172, 34173, 34174, 34174, 34180, 34183, 34183, 34184, 34185, 34187, 34188, 34190, 34193,
+        34193, 34196, 34197, 34199, 34201, 34204, 34204, 34205, 34207, 34212, 34214, 34220, 34221, 34222, 34226, 34226, 34227, 34229, 34232, 34232,
+        34232, 34236, 34236, 34237, 34240, 34244, 34250, 34250, 34251, 34252, 34260, 34263, 34263, 34263, 34263, 34269, 34273, 34277, 34277, 34281,
+        34286, 34286, 34286, 34292, 34296, 34297, 34298, 34301, 34309, 34314, 34322, 34325, 34325, 34325, 34330, 34331, 34331, 34332, 34334, 34335,
+        34339, 34342, 34344, 34345, 34346, 34349, 34353, 34353, 34354, 34354, 34355, 34357, 34359, 34360, 34368, 34368, 34375, 34377, 34378, 34378,
+        34381, 34383, 34384, 34385, 34385, 34390, 34390, 34390, 34393, 34393, 34394, 34402, 34405, 34412, 34419, 34423, 34425, 34429, 34434, 34437,
+        34442, 34443, 34443, 34446, 34446, 34447, 34449, 34449, 34452, 34458, 34461, 34463, 34463, 34463, 34463, 34463, 34467, 34470, 34474, 34477,
+        34480, 34484, 34487, 34490, 34492, 34497, 34501, 34501, 34502, 34505, 34507, 34510, 34512, 34513, 34513, 34513, 34515, 34515, 34517, 34517,
+        34517, 34519, 34519, 34520, 34524, 34528, 34536, 34539, 34539, 34539, 34539, 34539, 34541, 34543, 34546, 34546, 34546, 34548, 34556, 34560,
+        34560, 34565, 34571, 34575, 34578, 34588, 34589, 34592, 34592, 34592, 34592, 34596, 34598, 34604, 34610, 34610, 34611, 34614, 34616, 34621,
+        34624, 34629, 34629, 34630, 34630, 34630, 34632, 34632, 34635, 34637, 34637, 34643, 34643, 34643, 34643, 34647, 34648, 34648, 34648, 34649,
+        34649, 34651, 34651, 34659, 34661, 34664, 34664, 34668, 34671, 34672, 34682, 34686, 34690, 34693, 34694, 34696, 34700, 34702, 34702, 34704,
+        34707, 34709, 34709, 34709, 34709, 34709, 34709, 34712, 34712, 34712, 34715, 34716, 34716, 34716, 34719, 34720, 34720, 34721, 34722, 34723,
+        34724, 34724, 34724, 34727, 34728, 34732, 34732, 34734, 34737, 34739, 34739, 34744, 34744, 34747, 34749, 34749, 34755, 34756, 34758, 34759,
+        34763, 34765, 34768, 34769, 34769, 34773, 34773, 34777, 34777, 34777, 34777, 34778, 34778, 34782, 34782, 34784, 34787, 34789, 34790, 34792,
+        34792, 34794, 34797, 34800, 34801, 34802, 34803, 34806, 34811, 34811, 34814, 34814, 34814, 34817, 34820, 34822, 34825, 34828, 34828, 34832,
+        34836, 34842, 34846, 34852, 34853, 34854, 34854, 34858, 34860, 34863, 34867, 34867, 34871, 34875, 34877, 34877, 34877, 34879, 34882, 34884,
+        34886, 34887, 34892, 34898, 34901, 34902, 34903, 34904, 34905, 34905, 34908, 34911, 34912, 34914, 34915, 34918, 34921, 34921, 34925, 34926,
+        34926, 34927, 34928, 34929, 34931, 34934, 34939, 34942, 34944, 34950, 34950, 34950, 34954, 34956, 34956, 34956, 34960, 34962, 34964, 34970,
+        34972, 34980, 34982, 34986, 34989, 34992, 34992, 34993, 34996, 34996, 34997, 35000, 35002, 35002, 35002, 35004, 35008, 35011, 35011, 35011,
+        35012, 35013, 35014, 35015, 35016, 35016, 35016, 35017, 35021, 35026, 35031, 35032, 35034, 35034, 35034, 35034, 35035, 35040, 35043, 35048,
+        35055, 35057, 35058, 35062, 35066, 35070, 35070, 35071, 35073, 35076, 35080, 35084, 35084, 35087, 35089, 35092, 35096, 35096, 35096, 35096,
+        35097, 35097, 35099, 35104, 35105, 35112, 35113, 35116, 35117, 35117, 35123, 35123, 35127, 35130, 35130, 35132, 35134, 35134, 35135, 35139,
+        35145, 35149, 35151, 35154, 35157, 35160, 35160, 35160, 35167, 35175, 35175, 35179, 35182, 35189, 35190, 35195, 35200, 35205, 35205, 35210,
+        35210, 35215, 35215, 35215, 35216, 35216, 35223, 35224, 35228, 35230, 35234, 35238, 35244, 35251, 35253, 35256, 35259, 35259, 35262, 35267,
+        35270, 35271, 35280, 35287, 35295, 35301, 35303, 35306, 35306, 35306, 35310, 35312, 35313, 35315, 35315, 35319, 35322, 35329, 35329, 35332,
+        35337, 35338, 35338, 35341, 35344, 35344, 35349, 35349, 35352, 35354, 35354, 35355, 35355, 35360, 35364, 35364, 35368, 35374, 35377, 35377,
+        35377, 35381, 35382, 35383, 35386, 35387, 35387, 35387, 35389, 35389, 35393, 35393, 35395, 35402, 35402, 35402, 35405, 35408, 35408, 35409,
+        35409, 35409, 35409, 35420, 35420, 35425, 35429, 35433, 35433, 35437, 35437, 35437, 35439, 35440, 35441, 35442, 35443, 35448, 35448, 35451,
+        35451, 35453, 35458, 35459, 35462, 35462, 35462, 35466, 35467, 35467, 35469, 35470, 35470, 35470, 35475, 35476, 35480, 35484, 35484, 35486,
+        35486, 35488, 35492, 35492, 35495, 35498, 35500, 35500, 35502, 35502, 35502, 35503, 35507, 35510, 35511, 35514, 35517, 35519, 35527, 35530,
+        35536, 35536, 35541, 35546, 35551, 35560, 35567, 35569, 35569, 35570, 35573, 35576, 35582, 35582, 35583, 35583, 35583, 35587, 35596, 35598,
+        35600, 35603, 35604, 35605, 35609, 35609, 35609, 35609, 35609, 35609, 35611, 35614, 35617, 35622, 35623, 35627, 35627, 35635, 35638, 35641,
+        35642, 35642, 35642, 35642, 35646, 35646, 35647, 35655, 35663, 35663, 35668, 35668, 35672, 35672, 35676, 35677, 35678, 35681, 35685, 35690,
+        35695, 35695, 35695, 35695, 35697, 35701, 35706, 35707, 35715, 35721, 35729, 35729, 35729, 35729, 35735, 35740, 35744, 35744, 35747, 35749,
+        35752, 35752, 35753, 35757, 35758, 35758, 35760, 35765, 35765, 35765, 35766, 35770, 35771, 35775, 35775, 35775, 35779, 35782, 35782, 35782,
+        35787, 35789, 35789, 35789, 35795, 35795, 35796, 35801, 35806, 35811, 35816, 35816, 35816, 35821, 35821, 35828, 35831, 35831, 35833, 35833,
+        35834, 35835, 35838, 35838, 35841, 35844, 35844, 35849, 35849, 35853, 35856, 35856, 35862, 35865, 35870, 35876, 35879, 35879, 35879, 35882,
+        35887, 35889, 35894, 35898, 35903, 35908, 35908, 35912, 35916, 35922, 35925, 35929, 35932, 35933, 35934, 35941, 35941, 35947, 35950, 35952,
+        35955, 35955, 35958, 35961, 35965, 35965, 35965, 35965, 35965, 35965, 35969, 35970, 35972, 35973, 35973, 35978, 35984, 35988, 35993, 35994,
+        35999, 36001, 36001, 36005, 36012, 36012, 36012, 36016, 36016, 36016, 36024, 36024, 36024, 36024, 36024, 36024, 36029, 36035, 36035, 36035,
+        36035, 36035, 36035, 36036, 36036, 36037, 36037, 36039, 36043, 36046, 36047, 36047, 36049, 36055, 36055, 36056, 36058, 36062, 36065, 36069,
+        36069, 36073, 36080, 36081, 36083, 36084, 36090, 36094, 36102, 36102, 36104, 36112, 36113, 36118, 36122, 36122, 36130, 36134, 36136, 36140,
+        36144, 36146, 36147, 36147, 36147, 36150, 36150, 36151, 36152, 36154, 36154, 36156, 36159, 36167, 36167, 36167, 36170, 36170, 36172, 36173,
+        36173, 36173, 36173, 36175, 36175, 36176, 36177, 36179, 36180, 36182, 36183, 36183, 36183, 36184, 36184, 36187, 36189, 36189, 36190, 36190,
+        36193, 36194, 36197, 36198, 36201, 36204, 36206, 36206, 36209, 36209, 36212, 36215, 36216, 36220, 36221, 36222, 36225, 36226, 36229, 36231,
+        36231, 36232, 36237, 36239, 36242, 36243, 36246, 36246, 36248, 36248, 36250, 36252, 36252, 36253, 36258, 36262, 36264, 36267, 36267, 36268,
+        36270, 36272, 36273, 36274, 36275, 36277, 36287, 36288, 36294, 36297, 36301, 36304, 36308, 36310, 36316, 36318, 36318, 36319, 36320, 36320,
+        36323, 36326, 36329, 36330, 36331, 36332, 36333, 36333, 36333, 36334, 36334, 36337, 36337, 36337, 36337, 36340, 36350, 36357, 36365, 36371,
+        36374, 36379, 36379, 36390, 36399, 36405, 36405, 36412, 36414, 36418, 36418, 36418, 36425, 36433, 36436, 36437, 36437, 36444, 36444, 36444,
+        36446, 36447, 36447, 36448, 36449, 36450, 36452, 36459, 36463, 36471, 36473, 36475, 36478, 36483, 36484, 36485, 36487, 36487, 36489, 36489,
+        36491, 36492, 36492, 36493, 36493, 36496, 36500, 36502, 36502, 36503, 36505, 36507, 36510, 36510, 36511, 36512, 36512, 36512, 36516, 36517,
+        36517, 36519, 36523, 36527, 36530, 36530, 36535, 36535, 36535, 36537, 36540, 36549, 36550, 36552, 36552, 36552, 36552, 36553, 36555, 36560,
+        36560, 36563, 36570, 36573, 36578, 36583, 36590, 36592, 36593, 36594, 36597, 36598, 36600, 36602, 36604, 36607, 36612, 36615, 36619, 36622,
+        36622, 36627, 36632, 36635, 36636, 36641, 36642, 36649, 36656, 36659, 36661, 36662, 36663, 36663, 36665, 36670, 36670, 36680, 36683, 36687,
+        36689, 36693, 36699, 36701, 36702, 36705, 36706, 36706, 36706, 36708, 36708, 36710, 36710, 36710, 36716, 36721, 36721, 36721, 36721, 36721,
+        36725, 36729, 36731, 36734, 36738, 36743, 36744, 36745, 36747, 36752, 36755, 36755, 36757, 36759, 36759, 36763, 36764, 36765, 36767, 36774,
+        36777, 36781, 36784, 36785, 36785, 36787, 36792, 36795, 36795, 36795, 36798, 36801, 36801, 36801, 36804, 36805, 36810, 36812, 36814, 36817,
+        36820, 36825, 36828, 36831, 36834, 36834, 36834, 36834, 36834, 36834, 36834, 36836, 36836, 36837, 36837, 36837, 36843, 36847, 36848, 36851,
+        36854, 36854, 36856, 36856, 36859, 36864, 36864, 36865, 36865, 36872, 36872, 36875, 36878, 36878, 36879, 36881, 36881, 36881, 36884, 36887,
+        36888, 36891, 36894, 36895, 36898, 36905, 36911, 36913, 36914, 36914, 36914, 36921, 36921, 36921, 36925, 36928, 36934, 36939, 36939, 36940,
+        36945, 36948, 36950, 36952, 36952, 36953, 36960, 36960, 36963, 36966, 36968, 36971, 36974, 36976, 36976, 36977, 36983, 36988, 36991, 36996,
+        36996, 37007, 37009, 37017, 37017, 37022, 37030, 37033, 37036, 37038, 37038, 37038, 37038, 37038, 37042, 37049, 37058, 37064, 37067, 37067,
+        37071, 37076, 37087, 37087, 37088, 37093, 37093, 37097, 37101, 37103, 37118, 37120, 37125, 37125, 37129, 37134, 37136, 37139, 37143, 37147,
+        37151, 37155, 37170, 37170, 37171, 37175, 37180, 37185, 37185, 37185, 37195, 37195, 37195, 37197, 37201, 37207, 37207, 37209, 37211, 37211,
+        37213, 37213, 37216, 37218, 37218, 37221, 37224, 37226, 37229, 37229, 37229, 37233, 37238, 37243, 37243, 37245, 37245, 37248, 37255, 37257,
+        37258, 37259, 37259, 37260, 37264, 37269, 37272, 37273, 37274, 37275, 37278, 37281, 37287, 37291, 37291, 37294, 37294, 37294, 37298, 37298,
+        37303, 37306, 37307, 37307, 37307, 37314, 37317, 37324, 37328, 37328, 37328, 37328, 37332, 37332, 37332, 37338, 37341, 37341, 37344, 37344,
+        37348, 37351, 37352, 37357, 37357, 37357, 37358, 37358, 37358, 37362, 37367, 37369, 37369, 37369, 37371, 37374, 37375, 37376, 37377, 37377,
+        37383, 37383, 37385, 37387, 37390, 37398, 37398, 37402, 37403, 37407, 37408, 37410, 37410, 37410, 37410, 37411, 37413, 37414, 37417, 37420,
+        37420, 37422, 37422, 37422, 37423, 37427, 37427, 37427, 37428, 37430, 37437, 37442, 37442, 37442, 37445, 37447, 37453, 37456, 37456, 37459,
+        37459, 37463, 37464, 37464, 37464, 37467, 37468, 37469, 37472, 37474, 37479, 37481, 37487, 37487, 37490, 37492, 37499, 37500, 37504, 37507,
+        37507, 37511, 37513, 37513, 37519, 37520, 37521, 37522, 37527, 37531, 37531, 37535, 37537, 37538, 37542, 37545, 37549, 37553, 37553, 37557,
+        37557, 37557, 37561, 37567, 37571, 37574, 37576, 37576, 37580, 37583, 37591, 37591, 37594, 37595, 37596, 37601, 37607, 37620, 37623, 37623,
+        37623, 37626, 37631, 37636, 37638, 37638, 37638, 37639, 37640, 37655, 37663, 37664, 37667, 37668, 37670, 37670, 37670, 37675, 37679, 37680,
+        37680, 37684, 37685, 37690, 37700, 37702, 37704, 37705, 37706, 37709, 37710, 37711, 37711, 37711, 37712, 37713, 37716, 37719, 37719, 37719,
+        37727, 37727, 37727, 37729, 37730, 37734, 37739, 37740, 37740, 37740, 37740, 37742, 37748, 37748, 37748, 37749, 37753, 37759, 37763, 37763,
+        37763, 37769, 37769, 37769, 37769, 37769, 37774, 37775, 37778, 37778, 37779, 37782, 37784, 37786, 37786, 37789, 37790, 37790, 37790, 37790,
+        37795, 37796, 37799, 37801, 37805, 37809, 37811, 37812, 37815, 37820, 37821, 37821, 37824, 37828, 37830, 37832, 37832, 37837, 37841, 37843,
+        37845, 37851, 37852, 37858, 37862, 37867, 37868, 37872, 37872, 37876, 37880, 37885, 37889, 37892, 37893, 37897, 37903, 37906, 37906, 37910,
+        37914, 37914, 37914, 37914, 37915, 37926, 37928, 37937, 37942, 37947, 37947, 37951, 37952, 37952, 37955, 37955, 37965, 37966, 37972, 37976,
+        37978, 37980, 37983, 37985, 37990, 37993, 37996, 38001, 38001, 38005, 38008, 38012, 38012, 38016, 38022, 38029, 38030, 38034, 38035, 38036,
+        38037, 38037, 38041, 38047, 38051, 38051, 38055, 38055, 38059, 38059, 38065, 38065, 38070, 38073, 38079, 38079, 38083, 38086, 38088, 38093,
+        38094, 38100, 38100, 38101, 38109, 38116, 38120, 38120, 38122, 38122, 38122, 38123, 38123, 38126, 38132, 38139, 38141, 38145, 38145, 38146,
+        38149, 38151, 38155, 38157, 38158, 38159, 38160, 38164, 38164, 38165, 38167, 38168, 38170, 38172, 38175, 38175, 38179, 38183, 38186, 38189,
+        38192, 38192, 38194, 38194, 38200, 38201, 38204, 38205, 38209, 38209, 38210, 38214, 38220, 38221, 38221, 38222, 38227, 38231, 38233, 38233,
+        38234, 38236, 38239, 38240, 38241, 38241, 38241, 38247, 38247, 38250, 38251, 38253, 38253, 38253, 38254, 38254, 38256, 38256, 38258, 38260,
+        38260, 38262, 38265, 38269, 38276, 38276, 38277, 38277, 38277, 38277, 38277, 38277, 38277, 38277, 38277, 38277, 38279, 38285, 38290, 38294,
+        38295, 38297, 38299, 38300, 38305, 38307, 38307, 38308, 38310, 38316, 38320, 38320, 38324, 38324, 38329, 38329, 38329, 38329, 38331, 38335,
+        38340, 38343, 38344, 38353, 38362, 38369, 38373, 38373, 38380, 38383, 38386, 38389, 38390, 38391, 38394, 38395, 38395, 38395, 38397, 38399,
+        38402, 38403, 38403, 38408, 38410, 38410, 38410, 38410, 38417, 38423, 38430, 38438, 38445, 38447, 38452, 38457, 38457, 38457, 38457, 38457,
+        38459, 38460, 38460, 38461, 38463, 38463, 38463, 38464, 38468, 38477, 38479, 38482, 38487, 38493, 38495, 38504, 38511, 38513, 38514, 38514,
+        38522, 38526, 38530, 38530, 38532, 38536, 38539, 38543, 38543, 38544, 38546, 38546, 38547, 38549, 38555, 38560, 38568, 38580, 38592, 38599,
+        38607, 38607, 38607, 38607, 38607, 38609, 38612, 38612, 38615, 38615, 38615, 38615, 38615, 38615, 38615, 38615, 38620, 38624, 38626, 38629,
+        38630, 38632, 38636, 38645, 38645, 38649, 38653, 38658, 38662, 38669, 38671, 38676, 38681, 38689, 38689, 38689, 38690, 38693, 38699, 38702,
+        38706, 38711, 38711, 38714, 38717, 38721, 38725, 38732, 38738, 38742, 38746, 38750, 38755, 38760, 38760, 38760, 38761, 38763, 38767, 38767,
+        38770, 38773, 38773, 38774, 38774, 38781, 38781, 38785, 38790, 38791, 38796, 38800, 38801, 38802, 38803, 38803, 38805, 38805, 38808, 38808,
+        38810, 38810, 38810, 38814, 38815, 38816, 38818, 38819, 38819, 38820, 38822, 38828, 38828, 38831, 38831, 38831, 38831, 38834, 38834, 38834,
+        38834, 38837, 38843, 38844, 38845, 38848, 38848, 38850, 38853, 38853, 38854, 38862, 38868, 38874, 38874, 38874, 38877, 38883, 38885, 38885,
+        38887, 38887, 38890, 38892, 38897, 38897, 38903, 38907, 38910, 38914, 38919, 38919, 38919, 38921, 38924, 38924, 38926, 38929, 38932, 38933,
+        38933, 38938, 38942, 38945, 38946, 38947, 38949, 38951, 38953, 38956, 38958, 38961, 38962, 38962, 38962, 38965, 38969, 38971, 38976, 38976,
+        38976, 38976, 38976, 38976, 38979, 38982, 38984, 38987, 38988, 38991, 38991, 38993, 38996, 39000, 39003, 39003, 39003, 39003, 39005, 39006,
+        39008, 39011, 39013, 39013, 39013, 39013, 39016, 39016, 39025, 39026, 39032, 39039, 39046, 39054, 39063, 39067, 39067, 39068, 39068, 39068,
+        39071, 39076, 39080, 39083, 39086, 39088, 39091, 39092, 39093, 39095, 39102, 39102, 39102, 39102, 39102, 39104, 39105, 39107, 39107, 39107,
+        39110, 39115, 39119, 39119, 39120, 39122, 39122, 39123, 39125, 39125, 39125, 39130, 39130, 39134, 39137, 39137, 39137, 39137, 39137, 39139,
+        39139, 39140, 39141, 39141, 39144, 39147, 39151, 39151, 39151, 39156, 39160, 39164, 39174, 39176, 39176, 39176, 39176, 39177, 39177, 39178,
+        39178, 39181, 39183, 39183, 39186, 39186, 39186, 39189, 39198, 39204, 39213, 39215, 39216, 39216, 39217, 39217, 39219, 39219, 39223, 39223,
+        39227, 39229, 39230, 39231, 39232, 39237, 39239, 39240, 39241, 39244, 39245, 39247, 39249, 39252, 39252, 39254, 39254, 39254, 39256, 39256,
+        39257, 39257, 39258, 39259, 39261, 39261, 39263, 39265, 39267, 39270, 39274, 39276, 39277, 39281, 39282, 39287, 39287, 39288, 39295, 39298,
+        39300, 39300, 39300, 39301, 39303, 39305, 39307, 39316, 39322, 39322, 39323, 39327, 39331, 39334, 39339, 39339, 39339, 39339, 39340, 39342,
+        39343, 39347, 39347, 39347, 39350, 39353, 39356, 39357, 39360, 39363, 39364, 39365, 39365, 39370, 39374, 39380, 39384, 39384, 39388, 39392,
+        39397, 39400, 39400, 39402, 39406, 39406, 39410, 39417, 39421, 39423, 39433, 39434, 39437, 39445, 39452, 39452, 39453, 39453, 39457, 39457,
+        39462, 39466, 39471, 39471, 39472, 39475, 39475, 39475, 39475, 39475, 39476, 39478, 39478, 39482, 39485, 39491, 39491, 39491, 39492, 39492,
+        39494, 39497, 39497, 39498, 39502, 39503, 39503, 39504, 39504, 39504, 39507, 39508, 39510, 39510, 39510, 39512, 39515, 39516, 39517, 39519,
+        39519, 39520, 39521, 39523, 39530, 39533, 39535, 39536, 39536, 39536, 39536, 39536, 39539, 39539, 39539, 39541, 39543, 39545, 39547, 39549,
+        39551, 39553, 39555, 39555, 39555, 39563, 39569, 39574, 39577, 39577, 39579, 39579, 39579, 39581, 39582, 39584, 39588, 39593, 39596, 39598,
+        39601, 39601, 39601, 39601, 39601, 39602, 39603, 39605, 39609, 39615, 39617, 39621, 39622, 39625, 39630, 39636, 39636, 39640, 39643, 39646,
+        39650, 39650, 39654, 39658, 39658, 39658, 39658, 39658, 39668, 39670, 39677, 39680, 39690, 39698, 39698, 39699, 39707, 39707, 39708, 39708,
+        39713, 39721, 39726, 39726, 39731, 39732, 39738, 39738, 39742, 39745, 39752, 39752, 39754, 39754, 39754, 39759, 39760, 39760, 39760, 39763,
+        39769, 39773, 39774, 39775, 39776, 39784, 39789, 39795, 39801, 39807, 39809, 39810, 39811, 39812, 39812, 39812, 39812, 39812, 39814, 39815,
+        39821, 39824, 39828, 39829, 39831, 39835, 39839, 39842, 39842, 39842, 39844, 39852, 39860, 39861, 39866, 39873, 39877, 39877, 39877, 39882,
+        39885, 39890, 39893, 39897, 39905, 39911, 39912, 39916, 39922, 39923, 39925, 39925, 39925, 39929, 39930, 39932, 39934, 39934, 39938, 39942,
+        39942, 39944, 39947, 39949, 39951, 39951, 39965, 39974, 39974, 39974, 39974, 39984, 39990, 39992, 39997, 40002, 40004, 40005, 40007, 40007,
+        40008, 40011, 40017, 40018, 40024, 40027, 40027, 40030, 40031, 40033, 40033, 40033, 40037, 40037, 40041, 40047, 40050, 40054, 40059, 40061,
+        40073, 40079, 40079, 40086, 40089, 40092, 40092, 40092, 40099, 40105, 40106, 40110, 40113, 40115, 40117, 40117, 40117, 40120, 40122, 40123,
+        40124, 40125, 40125, 40130, 40132, 40132, 40133, 40133, 40139, 40139, 40139, 40139, 40141, 40145, 40149, 40152, 40152, 40152, 40152, 40153,
+        40153, 40153, 40154, 40155, 40158, 40164, 40168, 40169, 40171, 40177, 40177, 40177, 40184, 40188, 40188, 40192, 40198, 40203, 40211, 40214,
+        40218, 40224, 40227, 40231, 40231, 40237, 40243, 40248, 40251, 40252, 40255, 40260, 40266, 40269, 40279, 40284, 40291, 40291, 40292, 40293,
+        40299, 40302, 40302, 40302, 40302, 40304, 40304, 40304, 40304, 40304, 40307, 40311, 40311, 40314, 40317, 40321, 40324, 40325, 40328, 40334,
+        40335, 40336, 40338, 40338, 40341, 40343, 40347, 40347, 40347, 40354, 40357, 40360, 40364, 40364, 40364, 40364, 40367, 40369, 40374, 40374,
+        40379, 40386, 40391, 40392, 40392, 40392, 40396, 40403, 40403, 40404, 40410, 40415, 40415, 40417, 40422, 40426, 40430, 40442, 40443, 40448,
+        40450, 40454, 40459, 40459, 40461, 40462, 40463, 40463, 40463, 40470, 40477, 40480, 40484, 40486, 40486, 40489, 40491, 40491, 40494, 40496,
+        40503, 40508, 40510, 40511, 40516, 40521, 40527, 40527, 40529, 40534, 40538, 40543, 40545, 40547, 40547, 40548, 40548, 40548, 40549, 40553,
+        40553, 40554, 40564, 40566, 40568, 40571, 40575, 40578, 40583, 40586, 40595, 40600, 40603, 40612, 40616, 40621, 40628, 40628, 40632, 40632,
+        40632, 40638, 40639, 40643, 40643, 40645, 40647, 40650, 40652, 40655, 40658, 40661, 40662, 40663, 40664, 40666, 40668, 40671, 40675, 40680,
+        40686, 40691, 40695, 40699, 40700, 40702, 40705, 40707, 40713, 40716, 40718, 40721, 40726, 40730, 40733, 40733, 40740, 40745, 40749, 40753,
+        40759, 40763, 40763, 40765, 40768, 40771, 40771, 40776, 40779, 40786, 40786, 40788, 40789, 40791, 40791, 40796, 40799, 40801, 40801, 40801,
+        40806, 40806, 40806, 40806, 40809, 40813, 40820, 40827, 40829, 40832, 40832, 40833, 40834, 40834, 40835, 40837, 40837, 40841, 40845, 40849,
+        40853, 40858, 40858, 40865, 40865, 40873, 40884, 40893, 40893, 40893, 40893, 40900, 40908, 40908, 40909, 40909, 40916, 40922, 40922, 40926,
+        40928, 40934, 40937, 40939, 40942, 40943, 40945, 40945, 40945, 40945, 40948, 40951, 40953, 40956, 40962, 40965, 40968, 40969, 40970, 40972,
+        40974, 40977, 40981, 40985, 40988, 40990, 40995, 40996, 40998, 40998, 41004, 41007, 41008, 41011, 41021, 41028, 41033, 41033, 41033, 41040,
+        41040, 41046, 41048, 41053, 41053, 41057, 41061, 41067, 41073, 41073, 41078, 41081, 41085, 41085, 41089, 41091, 41105, 41105, 41112, 41116,
+        41120, 41120, 41120, 41120, 41121, 41121, 41123, 41124, 41129, 41133, 41139, 41142, 41148, 41150, 41152, 41155, 41155, 41155, 41155, 41157,
+        41163, 41167, 41167, 41171, 41171, 41171, 41171, 41171, 41174, 41177, 41179, 41181, 41181, 41184, 41193, 41194, 41194, 41194, 41195, 41207,
+        41207, 41212, 41213, 41214, 41215, 41219, 41220, 41220, 41224, 41226, 41234, 41238, 41239, 41244, 41248, 41249, 41252, 41254, 41261, 41264,
+        41264, 41266, 41266, 41266, 41269, 41269, 41269, 41273, 41277, 41277, 41278, 41281, 41284, 41285, 41288, 41290, 41292, 41297, 41303, 41303,
+        41305, 41307, 41307, 41307, 41308, 41310, 41312, 41315, 41316, 41316, 41325, 41325, 41325, 41325, 41325, 41325, 41325, 41325, 41325, 41327,
+        41328, 41330, 41332, 41332, 41332, 41332, 41332, 41336, 41343, 41345, 41350, 41351, 41351, 41360, 41362, 41363, 41363, 41366, 41368, 41370,
+        41373, 41373, 41374, 41374, 41374, 41374, 41374, 41377, 41381, 41382, 41383, 41383, 41384, 41384, 41384, 41384, 41385, 41386, 41387, 41388,
+        41391, 41393, 41398, 41398, 41405, 41405, 41406, 41414, 41426, 41428, 41438, 41445, 41447, 41450, 41457, 41464, 41471, 41476, 41480, 41483,
+        41493, 41493, 41495, 41501, 41505, 41505, 41510, 41512, 41520, 41530, 41536, 41548, 41562, 41564, 41568, 41573, 41573, 41573, 41576, 41579,
+        41581, 41584, 41584, 41587, 41593, 41593, 41594, 41597, 41599, 41600, 41605, 41612, 41618, 41622, 41630, 41638, 41646, 41652, 41658, 41662,
+        41666, 41678, 41679, 41681, 41684, 41687, 41687, 41696, 41696, 41701, 41703, 41707, 41708, 41709, 41713, 41718, 41721, 41725, 41733, 41738,
+        41746, 41747, 41748, 41751, 41754, 41756, 41756, 41756, 41759, 41762, 41767, 41771, 41774, 41780, 41784, 41788, 41788, 41788, 41792, 41799,
+        41805, 41810, 41815, 41818, 41821, 41827, 41829, 41832, 41836, 41838, 41840, 41840, 41846, 41846, 41852, 41852, 41861, 41861, 41867, 41867,
+        41871, 41871, 41874, 41874, 41876, 41880, 41883, 41888, 41893, 41897, 41902, 41902, 41902, 41906, 41910, 41915, 41919, 41922, 41924, 41928,
+        41928, 41931, 41931, 41933, 41938, 41948, 41958, 41962, 41962, 41962, 41965, 41966, 41970, 41970, 41973, 41975, 41977, 41981, 41981, 41983,
+        41986, 41990, 41990, 41992, 41994, 41995, 42000, 42002, 42005, 42010, 42018, 42018, 42018, 42022, 42027, 42027, 42027, 42027, 42029, 42034,
+        42034, 42038, 42038, 42039, 42040, 42042, 42044, 42047, 42051, 42055, 42058, 42058, 42061, 42061, 42063, 42067, 42072, 42073, 42075, 42075,
+        42075, 42075, 42075, 42075, 42076, 42076, 42076, 42078, 42080, 42081, 42082, 42089, 42091, 42091, 42091, 42092, 42093, 42093, 42093, 42093,
+        42095, 42097, 42097, 42097, 42099, 42102, 42102, 42105, 42108, 42113, 42114, 42114, 42119, 42121, 42121, 42121, 42121, 42121, 42121, 42121,
+        42122, 42122, 42123, 42124, 42124, 42124, 42124, 42124, 42129, 42130, 42130, 42130, 42134, 42135, 42138, 42140, 42143, 42144, 42144, 42144,
+        42145, 42145, 42145, 42149, 42151, 42151, 42151, 42151, 42151, 42151, 42151, 42151, 42156, 42159, 42159, 42159, 42159, 42159, 42159, 42159,
+        42160, 42161, 42162, 42165, 42167, 42173, 42176, 42178, 42179, 42179, 42180, 42183, 42183, 42183, 42183, 42183, 42183, 42186, 42188, 42191,
+        42193, 42196, 42200, 42201, 42203, 42203, 42206, 42206, 42208, 42212, 42215, 42215, 42216, 42224, 42224, 42224, 42226, 42230, 42230, 42230,
+        42230, 42231, 42231, 42233, 42233, 42233, 42234, 42234, 42239, 42251, 42251, 42257, 42258, 42259, 42259, 42259, 42260, 42260, 42260, 42262,
+        42264, 42267, 42276, 42279, 42280, 42287, 42287, 42291, 42301, 42301, 42303, 42310, 42318, 42324, 42326, 42330, 42336, 42338, 42346, 42350,
+        42356, 42356, 42360, 42365, 42368, 42372, 42375, 42380, 42382, 42387, 42392, 42395, 42396, 42402, 42402, 42403, 42404, 42409, 42412, 42413,
+        42415, 42417, 42418, 42418, 42418, 42421, 42423, 42428, 42432, 42433, 42433, 42437, 42440, 42442, 42442, 42442, 42444, 42446, 42446, 42448,
+        42448, 42449, 42456, 42458, 42461, 42461, 42461, 42463, 42463, 42463, 42463, 42465, 42465, 42465, 42468, 42468, 42468, 42471, 42471, 42471,
+        42472, 42473, 42474, 42474, 42476, 42476, 42482, 42484, 42485, 42486, 42491, 42492, 42493, 42495, 42495, 42495, 42500, 42500, 42500, 42501,
+        42505, 42506, 42509, 42515, 42520, 42520, 42525, 42528, 42531, 42532, 42535, 42539, 42544, 42545, 42545, 42546, 42546, 42546, 42546, 42546,
+        42547, 42547, 42556, 42556, 42557, 42557, 42557, 42560, 42562, 42568, 42572, 42573, 42574, 42575, 42575, 42576, 42585, 42585, 42589, 42593,
+        42596, 42597, 42599, 42599, 42599, 42605, 42613, 42623, 42626, 42628, 42633, 42639, 42643, 42647, 42649, 42653, 42658, 42665, 42671, 42674,
+        42680, 42681, 42686, 42688, 42688, 42690, 42694, 42694, 42694, 42695, 42697, 42697, 42699, 42700, 42700, 42700, 42700, 42702, 42703, 42703,
+        42711, 42715, 42718, 42722, 42729, 42736, 42743, 42743, 42747, 42750, 42753, 42759, 42761, 42762, 42762, 42763, 42763, 42768, 42773, 42776,
+        42780, 42780, 42783, 42790, 42791, 42791, 42793, 42800, 42800, 42800, 42803, 42806, 42812, 42816, 42822, 42828, 42836, 42839, 42839, 42844,
+        42849, 42856, 42861, 42865, 42869, 42871, 42871, 42874, 42876, 42878, 42878, 42878, 42878, 42881, 42882, 42882, 42892, 42894, 42903, 42922,
+        42922, 42922, 42929, 42931, 42932, 42936, 42936, 42936, 42936, 42936, 42936, 42936, 42936, 42936, 42940, 42940, 42940, 42940, 42940, 42944,
+        42944, 42952, 42952, 42952, 42952, 42952, 42952, 42952, 42952, 42952, 42952, 42955, 42956, 42959, 42960, 42965, 42970, 42971, 42977, 42979,
+        42987, 42995, 42995, 42995, 43000, 43000, 43001, 43001, 43001, 43004, 43006, 43009, 43013, 43013, 43016, 43016, 43020, 43023, 43031, 43031,
+        43032, 43037, 43040, 43042, 43046, 43048, 43049, 43053, 43058, 43058, 43058, 43062, 43069, 43069, 43069, 43069, 43072, 43073, 43073, 43073,
+        43073, 43073, 43075, 43077, 43077, 43080, 43085, 43088, 43091, 43095, 43098, 43100, 43103, 43107, 43107, 43107, 43107, 43107, 43107, 43107,
+        43111, 43111, 43115, 43118, 43118, 43118, 43118, 43120, 43124, 43131, 43135, 43142, 43147, 43148, 43148, 43152, 43155, 43159, 43168, 43169,
+        43169, 43175, 43177, 43180, 43180, 43180, 43184, 43187, 43190, 43193, 43194, 43194, 43194, 43195, 43196, 43200, 43200, 43200, 43204, 43207,
+        43211, 43216, 43218, 43220, 43226, 43228, 43228, 43230, 43234, 43235, 43236, 43238, 43241, 43244, 43246, 43254, 43262, 43267, 43275, 43283,
+        43287, 43292, 43299, 43299, 43300, 43300, 43300, 43301, 43301, 43301, 43301, 43301, 43301, 43301, 43305, 43308, 43316, 43323, 43323, 43327,
+        43327, 43329, 43333, 43336, 43347, 43355, 43355, 43366, 43366, 43366, 43366, 43367, 43368, 43368, 43376, 43376, 43380, 43389, 43389, 43390,
+        43394, 43397, 43398, 43399, 43402, 43405, 43407, 43407, 43410, 43411, 43412, 43413, 43414, 43415, 43417, 43423, 43425, 43427, 43433, 43434,
+        43439, 43441, 43446, 43454, 43459, 43464, 43466, 43469, 43475, 43480, 43485, 43487, 43487, 43491, 43502, 43505, 43509, 43509, 43509, 43513,
+        43515, 43519, 43524, 43532, 43540, 43544, 43549, 43553, 43557, 43557, 43559, 43561, 43567, 43568, 43571, 43577, 43582, 43591, 43591, 43591,
+        43592, 43592, 43596, 43599, 43603, 43606, 43609, 43611, 43613, 43617, 43621, 43622, 43625, 43626, 43626, 43629, 43631, 43632, 43636, 43641,
+        43643, 43643, 43649, 43649, 43659, 43663, 43667, 43668, 43673, 43673, 43677, 43680, 43684, 43686, 43691, 43694, 43694, 43699, 43700, 43702,
+        43703, 43708, 43713, 43714, 43714, 43717, 43720, 43723, 43727, 43729, 43735, 43740, 43744, 43745, 43746, 43746, 43747, 43747, 43748, 43749,
+        43751, 43751, 43752, 43753, 43753, 43754, 43760, 43765, 43765, 43769, 43769, 43769, 43775, 43782, 43787, 43792, 43797, 43802, 43811, 43814,
+        43817, 43822, 43823, 43833, 43836, 43836, 43842, 43849, 43851, 43857, 43860, 43864, 43864, 43869, 43876, 43879, 43883, 43883, 43888, 43890,
+        43898, 43901, 43906, 43913, 43913, 43917, 43917, 43919, 43921, 43924, 43924, 43929, 43931, 43931, 43931, 43932, 43934, 43935, 43940, 43944,
+        43945, 43946, 43947, 43949, 43949, 43952, 43960, 43962, 43963, 43963, 43964, 43966, 43972, 43977, 43977, 43977, 43978, 43984, 43987, 43987,
+        43987, 43990, 43991, 43991, 43991, 43991, 43992, 43996, 43996, 43996, 43997, 43999, 44003, 44003, 44003, 44009, 44022, 44027, 44027, 44029,
+        44029, 44032, 44033, 44038, 44043, 44044, 44045, 44046, 44047, 44047, 44050, 44051, 44053, 44056, 44062, 44068, 44068, 44073, 44073, 44077,
+        44080, 44088, 44091, 44097, 44104, 44109, 44111, 44111, 44116, 44123, 44126, 44129, 44130, 44140, 44140, 44147, 44151, 44153, 44154, 44154,
+        44161, 44168, 44169, 44172, 44173, 44180, 44182, 44183, 44186, 44188, 44188, 44188, 44188, 44189, 44191, 44193, 44194, 44195, 44198, 44201,
+        44201, 44201, 44207, 44207, 44209, 44211, 44211, 44213, 44220, 44222, 44222, 44223, 44233, 44238, 44240, 44243, 44249, 44258, 44258, 44261,
+        44267, 44267, 44267, 44270, 44271, 44275, 44278, 44278, 44284, 44291, 44300, 44314, 44319, 44326, 44328, 44328, 44335, 44335, 44339, 44343,
+        44343, 44349, 44351, 44356, 44361, 44366, 44366, 44366, 44372, 44375, 44375, 44375, 44375, 44377, 44381, 44381, 44381, 44381, 44381, 44384,
+        44386, 44392, 44400, 44405, 44409, 44409, 44409, 44409, 44410, 44411, 44415, 44415, 44415, 44416, 44418, 44421, 44434, 44437, 44440, 44440,
+        44440, 44445, 44448, 44448, 44450, 44450, 44451, 44453, 44456, 44463, 44463, 44463, 44467, 44469, 44472, 44479, 44482, 44487, 44487, 44488,
+        44490, 44491, 44491, 44494, 44495, 44497, 44499, 44504, 44505, 44508, 44512, 44515, 44521, 44522, 44526, 44530, 44536, 44536, 44536, 44540,
+        44544, 44549, 44555, 44555, 44557, 44560, 44568, 44569, 44577, 44577, 44578, 44580, 44581, 44583, 44584, 44587, 44590, 44591, 44591, 44592,
+        44592, 44597, 44597, 44599, 44599, 44599, 44601, 44603, 44608, 44611, 44616, 44626, 44631, 44636, 44639, 44641, 44641, 44645, 44651, 44654,
+        44655, 44660, 44664, 44666, 44666, 44666, 44668, 44669, 44670, 44675, 44678, 44683, 44685, 44686, 44686, 44688, 44690, 44692, 44694, 44698,
+        44700, 44707, 44714, 44719, 44719, 44719, 44719, 44722, 44723, 44726, 44729, 44729, 44729, 44730, 44732, 44734, 44736, 44740, 44747, 44751,
+        44753, 44757, 44763, 44766, 44769, 44771, 44774, 44774, 44774, 44778, 44788, 44789, 44791, 44794, 44798, 44798, 44800, 44800, 44802, 44807,
+        44810, 44815, 44818, 44822, 44827, 44836, 44840, 44846, 44852, 44857, 44861, 44863, 44864, 44869, 44872, 44875, 44880, 44883, 44886, 44889,
+        44890, 44891, 44891, 44895, 44898, 44899, 44904, 44904, 44910, 44910, 44910, 44922, 44922, 44922, 44922, 44922, 44929, 44933, 44936, 44944,
+        44944, 44947, 44947, 44947, 44947, 44948, 44949, 44949, 44949, 44958, 44963, 44963, 44963, 44963, 44963, 44967, 44967, 44976, 44976, 44976,
+        44981, 44989, 44989, 44989, 44994, 44998, 45001, 45006, 45008, 45008, 45009, 45011, 45014, 45022, 45022, 45026, 45026, 45032, 45033, 45033,
+        45039, 45039, 45045, 45048, 45053, 45053, 45058, 45065, 45065, 45069, 45071, 45075, 45081, 45081, 45086, 45089, 45094, 45095, 45098, 45098,
+        45099, 45099, 45102, 45110, 45115, 45117, 45123, 45131, 45134, 45134, 45135, 45136, 45137, 45138, 45139, 45139, 45143, 45146, 45151, 45156,
+        45158, 45158, 45158, 45161, 45162, 45163, 45166, 45166, 45169, 45170, 45177, 45180, 45181, 45185, 45185, 45185, 45189, 45191, 45196, 45203,
+        45215, 45230, 45242, 45248, 45250, 45250, 45254, 45257, 45257, 45261, 45261, 45263, 45264, 45265, 45265, 45270, 45271, 45276, 45276, 45276,
+        45276, 45280, 45280, 45280, 45284, 45285, 45285, 45286, 45286, 45287, 45289, 45291, 45294, 45294, 45298, 45300, 45302, 45307, 45312, 45313,
+        45315, 45315, 45316, 45318, 45318, 45318, 45320, 45320, 45321, 45321, 45323, 45325, 45328, 45331, 45335, 45338, 45342, 45345, 45345, 45347,
+        45351, 45354, 45358, 45358, 45358, 45358, 45358, 45367, 45369, 45369, 45370, 45371, 45372, 45372, 45380, 45386, 45392, 45392, 45394, 45396,
+        45396, 45396, 45399, 45403, 45406, 45412, 45412, 45412, 45417, 45420, 45422, 45428, 45428, 45428, 45429, 45432, 45432, 45432, 45433, 45433,
+        45433, 45436, 45438, 45445, 45446, 45450, 45457, 45461, 45464, 45468, 45468, 45468, 45468, 45468, 45470, 45472, 45472, 45476, 45480, 45485,
+        45487, 45489, 45496, 45499, 45501, 45501, 45502, 45502, 45510, 45516, 45522, 45523, 45525, 45525, 45525, 45525, 45525, 45529, 45529, 45533,
+        45540, 45546, 45547, 45548, 45553, 45556, 45556, 45557, 45560, 45560, 45560, 45562, 45568, 45568, 45568, 45568, 45569, 45573, 45574, 45576,
+        45578, 45579, 45579, 45579, 45581, 45583, 45584, 45585, 45589, 45591, 45591, 45591, 45591, 45591, 45591, 45591, 45591, 45591, 45593, 45594,
+        45596, 45598, 45598, 45598, 45600, 45602, 45606, 45610, 45610, 45615, 45619, 45622, 45622, 45625, 45628, 45631, 45633, 45634, 45637, 45637,
+        45638, 45639, 45640, 45640, 45640, 45643, 45645, 45645, 45646, 45647, 45650, 45651, 45652, 45652, 45654, 45658, 45659, 45663, 45666, 45669,
+        45669, 45671, 45672, 45672, 45672, 45672, 45672, 45672, 45672, 45672, 45673, 45674, 45674, 45675, 45675, 45675, 45675, 45675, 45676, 45676,
+        45679, 45681, 45685, 45689, 45693, 45695, 45696, 45701, 45702, 45705, 45708, 45711, 45712, 45713, 45716, 45716, 45720, 45722, 45722, 45723,
+        45724, 45725, 45725, 45725, 45725, 45725, 45728, 45731, 45733, 45733, 45733, 45737, 45739, 45740, 45740, 45744, 45747, 45747, 45747, 45749,
+        45751, 45753, 45753, 45753, 45759, 45769, 45771, 45774, 45779, 45782, 45784, 45785, 45791, 45795, 45798, 45805, 45809, 45813, 45813, 45816,
+        45824, 45826, 45827, 45832, 45836, 45840, 45841, 45848, 45850, 45853, 45853, 45854, 45858, 45861, 45865, 45871, 45875, 45878, 45882, 45889,
+        45889, 45889, 45892, 45897, 45902, 45903, 45906, 45911, 45912, 45914, 45914, 45914, 45917, 45917, 45917, 45917, 45919, 45923, 45928, 45932,
+        45933, 45933, 45933, 45933, 45934, 45935, 45938, 45942, 45946, 45948, 45950, 45950, 45950, 45950, 45951, 45954, 45956, 45960, 45968, 45975,
+        45980, 45983, 45983, 45985, 45985, 45990, 45991, 45991, 45994, 46000, 46001, 46001, 46001, 46003, 46008, 46008, 46008, 46008, 46011, 46015,
+        46021, 46027, 46027, 46028, 46033, 46036, 46040, 46040, 46042, 46046, 46047, 46052, 46052, 46053, 46056, 46057, 46059, 46061, 46068, 46073,
+        46076, 46080, 46083, 46083, 46083, 46084, 46084, 46087, 46091, 46094, 46097, 46097, 46100, 46106, 46113, 46113, 46115, 46116, 46117, 46123,
+        46131, 46142, 46145, 46148, 46149, 46149, 46157, 46166, 46166, 46168, 46169, 46176, 46178, 46182, 46184, 46187, 46187, 46189, 46198, 46199,
+        46199, 46199, 46202, 46206, 46209, 46214, 46218, 46222, 46223, 46230, 46234, 46237, 46245, 46246, 46249, 46249, 46249, 46249, 46249, 46249,
+        46250, 46250, 46252, 46256, 46259, 46264, 46268, 46268, 46274, 46278, 46284, 46284, 46287, 46296, 46300, 46304, 46307, 46307, 46310, 46311,
+        46315, 46318, 46326, 46332, 46337, 46345, 46350, 46355, 46359, 46362, 46367, 46373, 46376, 46385, 46386, 46390, 46397, 46403, 46405, 46407,
+        46412, 46418, 46425, 46426, 46428, 46428, 46430, 46435, 46440, 46444, 46446, 46446, 46451, 46451, 46453, 46458, 46460, 46467, 46471, 46474,
+        46476, 46477, 46480, 46481, 46482, 46485, 46487, 46489, 46492, 46492, 46495, 46499, 46501, 46501, 46502, 46502, 46506, 46506, 46507, 46509,
+        46510, 46512, 46512, 46512, 46512, 46512, 46513, 46516, 46516, 46516, 46516, 46516, 46516, 46516, 46519, 46520, 46523, 46527, 46527, 46528,
+        46531, 46534, 46534, 46539, 46544, 46544, 46544, 46544, 46545, 46546, 46556, 46564, 46568, 46571, 46571, 46571, 46571, 46575, 46578, 46578,
+        46578, 46582, 46584, 46584, 46584, 46588, 46591, 46593, 46596, 46599, 46603, 46611, 46611, 46611, 46611, 46619, 46628, 46630, 46630, 46634,
+        46637, 46643, 46643, 46646, 46650, 46650, 46650, 46650, 46650, 46650, 46652, 46653, 46653, 46656, 46656, 46662, 46662, 46662, 46662, 46674,
+        46676, 46676, 46676, 46677, 46686, 46687, 46687, 46692, 46698, 46698, 46700, 46701, 46706, 46710, 46713, 46713, 46715, 46717, 46719, 46719,
+        46719, 46726, 46727, 46733, 46734, 46735, 46736, 46736, 46737, 46738, 46740, 46740, 46741, 46743, 46743, 46754, 46761, 46766, 46775, 46784,
+        46784, 46785, 46785, 46785, 46785, 46785, 46785, 46789, 46789, 46792, 46794, 46796, 46800, 46804, 46807, 46811, 46813, 46813, 46813, 46813,
+        46815, 46818, 46818, 46820, 46823, 46824, 46825, 46828, 46831, 46833, 46834, 46838, 46841, 46843, 46843, 46845, 46845, 46848, 46855, 46859,
+        46861, 46863, 46870, 46872, 46872, 46876, 46878, 46882, 46888, 46894, 46899, 46903, 46915, 46919, 46924, 46925, 46926, 46936, 46946, 46947,
+        46949, 46950, 46951, 46953, 46953, 46956, 46956, 46961, 46963, 46967, 46967, 46971, 46975, 46976, 46977, 46979, 46979, 46986, 46996, 47009,
+        47011, 47014, 47016, 47021, 47023, 47035, 47036, 47036, 47040, 47042, 47043, 47043, 47044, 47047, 47050, 47054, 47055, 47057, 47061, 47065,
+        47065, 47066, 47066, 47069, 47074, 47074, 47077, 47079, 47080, 47081, 47082, 47083, 47085, 47087, 47091, 47096, 47096, 47098, 47101, 47103,
+        47112, 47113, 47115, 47117, 47120, 47121, 47124, 47129, 47131, 47133, 47133, 47135, 47136, 47137, 47138, 47141, 47146, 47147, 47147, 47147,
+        47151, 47159, 47163, 47168, 47173, 47178, 47184, 47184, 47188, 47192, 47192, 47193, 47196, 47201, 47205, 47207, 47209, 47209, 47211, 47214,
+        47222, 47224, 47224, 47225, 47230, 47235, 47238, 47239, 47244, 47246, 47246, 47249, 47249, 47251, 47255, 47263, 47264, 47266, 47270, 47273,
+        47273, 47278, 47280, 47281, 47282, 47286, 47291, 47293, 47295, 47296, 47299, 47303, 47305, 47306, 47310, 47312, 47312, 47312, 47312, 47317,
+        47318, 47320, 47325, 47328, 47328, 47331, 47335, 47336, 47340, 47341, 47341, 47342, 47342, 47343, 47343, 47344, 47344, 47344, 47344, 47346,
+        47347, 47347, 47349, 47351, 47354, 47357, 47360, 47369, 47369, 47372, 47375, 47375, 47375, 47382, 47386, 47386, 47386, 47389, 47391, 47392,
+        47394, 47397, 47399, 47399, 47400, 47401, 47402, 47402, 47403, 47406, 47411, 47414, 47417, 47418, 47419, 47423, 47423, 47425, 47432, 47436,
+        47437, 47443, 47446, 47449, 47450, 47454, 47454, 47454, 47456, 47458, 47461, 47464, 47467, 47467, 47467, 47475, 47480, 47481, 47481, 47487,
+        47493, 47493, 47493, 47497, 47504, 47505, 47506, 47507, 47509, 47511, 47516, 47517, 47521, 47526, 47527, 47528, 47532, 47533, 47533, 47533,
+        47533, 47534, 47542, 47547, 47551, 47557, 47557, 47560, 47566, 47573, 47574, 47574, 47577, 47579, 47588, 47588, 47590, 47592, 47595, 47599,
+        47599, 47599, 47600, 47604, 47604, 47604, 47607, 47607, 47610, 47613, 47615, 47616, 47617, 47618, 47621, 47621, 47624, 47624, 47624, 47624,
+        47624, 47626, 47628, 47631, 47633, 47637, 47642, 47642, 47642, 47649, 47651, 47659, 47664, 47668, 47669, 47669, 47671, 47674, 47677, 47677,
+        47683, 47684, 47684, 47686, 47687, 47691, 47694, 47694, 47696, 47697, 47697, 47698, 47700, 47700, 47700, 47700, 47702, 47710, 47716, 47717,
+        47718, 47721, 47721, 47723, 47725, 47728, 47733, 47735, 47739, 47747, 47754, 47757, 47765, 47771, 47772, 47773, 47775, 47778, 47778, 47780,
+        47780, 47780, 47780, 47780, 47780, 47781, 47784, 47784, 47784, 47784, 47784, 47784, 47784, 47786, 47787, 47790, 47793, 47795, 47795, 47795,
+        47795, 47795, 47801, 47803, 47805, 47808, 47808, 47808, 47816, 47822, 47822, 47829, 47831, 47833, 47834, 47835, 47835, 47843, 47852, 47863,
+        47864, 47864, 47865, 47865, 47865, 47865, 47868, 47874, 47882, 47884, 47884, 47884, 47890, 47896, 47896, 47897, 47900, 47905, 47909, 47915,
+        47923, 47928, 47930, 47930, 47931, 47934, 47937, 47940, 47941, 47945, 47946, 47947, 47949, 47950, 47951, 47956, 47961, 47968, 47973, 47976,
+        47983, 47983, 47990, 47994, 47994, 47998, 47998, 47998, 47998, 47999, 47999, 47999, 47999, 48002, 48008, 48008, 48011, 48011, 48011, 48016,
+        48020, 48026, 48029, 48039, 48040, 48043, 48045, 48055, 48058, 48058, 48058, 48060, 48061, 48064, 48064, 48064, 48067, 48069, 48073, 48076,
+        48079, 48080, 48084, 48096, 48096, 48100, 48105, 48109, 48115, 48119, 48125, 48128, 48134, 48135, 48141, 48144, 48146, 48149, 48151, 48154,
+        48155, 48155, 48158, 48160, 48166, 48168, 48168, 48169, 48175, 48175, 48182, 48185, 48189, 48191, 48194, 48196, 48200, 48201, 48202, 48203,
+        48206, 48206, 48212, 48221, 48228, 48236, 48242, 48249, 48257, 48262, 48263, 48263, 48269, 48272, 48274, 48276, 48276, 48281, 48281, 48281,
+        48284, 48292, 48297, 48300, 48307, 48308, 48309, 48315, 48319, 48319, 48319, 48321, 48321, 48321, 48321, 48321, 48321, 48322, 48325, 48327,
+        48332, 48335, 48343, 48355, 48357, 48367, 48374, 48378, 48382, 48383, 48386, 48390, 48397, 48397, 48397, 48397, 48397, 48397, 48397, 48397,
+        48398, 48399, 48405, 48410, 48413, 48416, 48418, 48429, 48439, 48450, 48459, 48461, 48466, 48470, 48470, 48470, 48470, 48470, 48474, 48477,
+        48479, 48483, 48490, 48493, 48498, 48507, 48511, 48516, 48522, 48528, 48532, 48538, 48543, 48548, 48554, 48558, 48559, 48561, 48573, 48579,
+        48585, 48591, 48591, 48591, 48594, 48598, 48600, 48603, 48607, 48610, 48614, 48615, 48615, 48615, 48615, 48615, 48615, 48615, 48618, 48626,
+        48632, 48632, 48634, 48634, 48636, 48640, 48644, 48644, 48649, 48656, 48656, 48658, 48663, 48665, 48669, 48669, 48669, 48671, 48676, 48681,
+        48682, 48682, 48684, 48685, 48692, 48695, 48698, 48705, 48706, 48708, 48710, 48710, 48712, 48718, 48718, 48718, 48720, 48721, 48723, 48723,
+        48723, 48723, 48724, 48726, 48726, 48727, 48728, 48731, 48736, 48745, 48750, 48751, 48758, 48758, 48758, 48760, 48762, 48764, 48765, 48768,
+        48772, 48773, 48776, 48780, 48780, 48784, 48787, 48790, 48790, 48791, 48793, 48794, 48797, 48798, 48804, 48808, 48808, 48808, 48815, 48819,
+        48819, 48821, 48822, 48827, 48827, 48831, 48832, 48840, 48846, 48849, 48849, 48849, 48849, 48849, 48849, 48849, 48855, 48857, 48864, 48870,
+        48874, 48876, 48876, 48880, 48880, 48883, 48884, 48884, 48889, 48892, 48892, 48896, 48896, 48899, 48907, 48915, 48918, 48921, 48921, 48926,
+        48926, 48928, 48930, 48934, 48937, 48942, 48942, 48947, 48955, 48958, 48966, 48977, 48981, 48985, 48992, 48992, 48995, 49000, 49002, 49002,
+        49002, 49005, 49009, 49009, 49009, 49013, 49016, 49019, 49019, 49025, 49026, 49028, 49031, 49033, 49038, 49042, 49043, 49043, 49046, 49048,
+        49051, 49056, 49056, 49056, 49058, 49060, 49061, 49063, 49064, 49067, 49068, 49070, 49070, 49070, 49073, 49075, 49075, 49076, 49076, 49079,
+        49083, 49088, 49091, 49096, 49102, 49103, 49105, 49112, 49112, 49116, 49119, 49124, 49130, 49132, 49133, 49134, 49142, 49146, 49153, 49154,
+        49160, 49166, 49171, 49176, 49184, 49190, 49194, 49199, 49204, 49212, 49216, 49227, 49230, 49231, 49231, 49234, 49237, 49238, 49238, 49238,
+        49241, 49241, 49242, 49248, 49249, 49250, 49252, 49252, 49257, 49259, 49263, 49264, 49269, 49272, 49273, 49277, 49281, 49283, 49292, 49292,
+        49292, 49292, 49292, 49292, 49292, 49292, 49292, 49298, 49300, 49307, 49311, 49315, 49316, 49316, 49316, 49316, 49316, 49316, 49321, 49331,
+        49333, 49342, 49347, 49351, 49354, 49357, 49358, 49358, 49366, 49367, 49368, 49370, 49371, 49372, 49374, 49376, 49382, 49382, 49385, 49388,
+        49391, 49392, 49396, 49400, 49402, 49407, 49410, 49413, 49417, 49418, 49423, 49423, 49428, 49431, 49431, 49432, 49433, 49437, 49437, 49444,
+        49446, 49447, 49447, 49447, 49449, 49450, 49451, 49452, 49457, 49460, 49462, 49468, 49471, 49471, 49476, 49480, 49483, 49483, 49483, 49485,
+        49487, 49487, 49487, 49488, 49488, 49488, 49488, 49489, 49493, 49495, 49498, 49498, 49502, 49504, 49505, 49506, 49508, 49510, 49510, 49510,
+        49512, 49513, 49515, 49517, 49517, 49521, 49521, 49521, 49523, 49524, 49524, 49525, 49525, 49531, 49532, 49537, 49537, 49539, 49542, 49543,
+        49544, 49544, 49548, 49549, 49557, 49560, 49563, 49565, 49567, 49575, 49580, 49584, 49589, 49592, 49599, 49606, 49609, 49612, 49618, 49622,
+        49625, 49628, 49637, 49637, 49643, 49649, 49652, 49657, 49660, 49663, 49666, 49668, 49673, 49680, 49683, 49688, 49688, 49688, 49691, 49694,
+        49703, 49703, 49706, 49713, 49727, 49729, 49737, 49739, 49744, 49744, 49748, 49751, 49751, 49757, 49757, 49758, 49759, 49761, 49761, 49765,
+        49771, 49772, 49772, 49776, 49781, 49784, 49788, 49794, 49798, 49801, 49803, 49805, 49805, 49807, 49815, 49825, 49829, 49829, 49836, 49836,
+        49836, 49836, 49845, 49852, 49860, 49860, 49861, 49865, 49869, 49876, 49879, 49880, 49884, 49894, 49897, 49897, 49897, 49897, 49898, 49904,
+        49904, 49905, 49905, 49905, 49909, 49912, 49913, 49913, 49915, 49916, 49918, 49918, 49919, 49919, 49919, 49919, 49919, 49919, 49921, 49925,
+        49930, 49930, 49934, 49938, 49938, 49940, 49940, 49942, 49942, 49942, 49942, 49942, 49942, 49942, 49942, 49942, 49950, 49955, 49958, 49958,
+        49958, 49958, 49958, 49958, 49958, 49961, 49962, 49963, 49965, 49971, 49975, 49978, 49984, 49986, 49986, 49988, 49988, 49996, 49999, 50008,
+        50011, 50013, 50013, 50018, 50020, 50020, 50027, 50036, 50043, 50052, 50056, 50058, 50058, 50062, 50065, 50066, 50067, 50067, 50074, 50076,
+        50080, 50080, 50080, 50082, 50083, 50091, 50091, 50092, 50093, 50095, 50098, 50100, 50102, 50105, 50109, 50114, 50115, 50117, 50118, 50120,
+        50123, 50124, 50125, 50129, 50130, 50134, 50138, 50139, 50141, 50143, 50146, 50147, 50153, 50153, 50153, 50154, 50154, 50155, 50159, 50162,
+        50163, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50168, 50171, 50173, 50174, 50176, 50181,
+        50181, 50190, 50197, 50200, 50210, 50214, 50217, 50228, 50236, 50239, 50239, 50245, 50251, 50255, 50258, 50270, 50273, 50276, 50278, 50280,
+        50283, 50285, 50293, 50297, 50302, 50309, 50316, 50324, 50324, 50324, 50325, 50325, 50326, 50327, 50330, 50331, 50332, 50335, 50338, 50343,
+        50347, 50350, 50355, 50357, 50364, 50370, 50381, 50385, 50391, 50391, 50391, 50392, 50395, 50396, 50400, 50403, 50409, 50409, 50409, 50413,
+        50413, 50414, 50415, 50417, 50426, 50428, 50431, 50443, 50444, 50444, 50445, 50445, 50447, 50448, 50449, 50455, 50466, 50475, 50481, 50485,
+        50486, 50489, 50491, 50493, 50500, 50504, 50506, 50507, 50508, 50509, 50509, 50509, 50510, 50510, 50513, 50513, 50514, 50518, 50520, 50520,
+        50520, 50521, 50523, 50523, 50528, 50539, 50539, 50539, 50540, 50550, 50556, 50562, 50562, 50564, 50569, 50576, 50577, 50578, 50582, 50587,
+        50590, 50591, 50594, 50597, 50599, 50605, 50607, 50612, 50615, 50618, 50623, 50629, 50629, 50629, 50633, 50633, 50644, 50644, 50644, 50644,
+        50650, 50653, 50655, 50658, 50662, 50662, 50670, 50672, 50672, 50672, 50672, 50676, 50678, 50678, 50678, 50678, 50678, 50682, 50682, 50683,
+        50685, 50685, 50686, 50686, 50686, 50686, 50686, 50690, 50690, 50692, 50697, 50708, 50720, 50724, 50728, 50729, 50734, 50736, 50738, 50741,
+        50744, 50745, 50751, 50756, 50763, 50766, 50770, 50773, 50777, 50780, 50783, 50785, 50789, 50791, 50794, 50796, 50800, 50804, 50805, 50806,
+        50807, 50811, 50818, 50823, 50829, 50836, 50837, 50837, 50838, 50843, 50846, 50846, 50848, 50849, 50851, 50852, 50855, 50857, 50864, 50865,
+        50868, 50869, 50872, 50876, 50879, 50879, 50879, 50882, 50883, 50883, 50885, 50885, 50889, 50892, 50892, 50892, 50893, 50897, 50897, 50899,
+        50899, 50899, 50903, 50904, 50904, 50904, 50904, 50905, 50906, 50906, 50906, 50906, 50906, 50915, 50923, 50923, 50925, 50926, 50931, 50936,
+        50940, 50943, 50945, 50947, 50949, 50951, 50956, 50962, 50962, 50963, 50963, 50967, 50967, 50970, 50977, 50979, 50981, 50986, 50990, 50992,
+        50993, 50996, 50998, 51001, 51005, 51010, 51012, 51018, 51021, 51022, 51022, 51024, 51028, 51031, 51031, 51039, 51041, 51048, 51053, 51056,
+        51056, 51062, 51062, 51062, 51066, 51066, 51067, 51067, 51067, 51082, 51096, 51100, 51105, 51107, 51109, 51110, 51110, 51110, 51110, 51110,
+        51110, 51113, 51116, 51124, 51132, 51136, 51147, 51154, 51164, 51171, 51176, 51176, 51177, 51177, 51179, 51186, 51187, 51193, 51196, 51198,
+        51202, 51203, 51204, 51215, 51217, 51222, 51225, 51225, 51227, 51229, 51229, 51233, 51238, 51239, 51240, 51245, 51246, 51251, 51251,
+    ],
+    &[
+        1, 27, 53, 74, 95, 117, 143, 159, 181, 198, 221, 233, 253, 272, 297, 315, 340, 361, 373, 391,
+        417, 440, 464, 478, 497, 509, 517, 528, 536, 550, 563, 577, 586, 593, 605, 612, 621, 623, 632, 651,
+        664, 681, 686, 700, 702, 719, 733, 746, 756, 763, 768, 771, 774, 780, 800, 802, 802, 805, 824, 825,
+        825, 829, 849, 854, 858, 860, 882, 889, 896, 898, 898, 916, 919, 919, 923, 944, 946, 949, 951, 965,
+        969, 979, 991, 997, 1004, 1008, 1033, 1037, 1045, 1048, 1051, 1064, 1067, 1069, 1079, 1081, 1103, 1105, 1108, 1110,
+        1131, 1131, 1131, 1135, 1157, 1159, 1159, 1159, 1161, 1161, 1183, 1183, 1190, 1194, 1207, 1210, 1215, 1215, 1215, 1217,
+        1219, 1227, 1232, 1234, 1236, 1241, 1241, 1248, 1252, 1264, 1274, 1289, 1292, 1301, 1303, 1319, 1329, 1335, 1348, 1353,
+        1355, 1367, 1371, 1373, 1390, 1390, 1405, 1405, 1421, 1428, 1432, 1432, 1449, 1451, 1458, 1461, 1475, 1475, 1475, 1475,
+        1497, 1500, 1502, 1504, 1520, 1521, 1523, 1529, 1544, 1544, 1551, 1555, 1559, 1579, 1583, 1591, 1593, 1596, 1615, 1615,
+        1618, 1625, 1647, 1647, 1649, 1667, 1670, 1670, 1687, 1687, 1687, 1687, 1708, 1710, 1713, 1715, 1730, 1732, 1743, 1743,
+        1745, 1749, 1755, 1760, 1762, 1767, 1769, 1771, 1771, 1776, 1781, 1800, 1805, 1809, 1811, 1819, 1827, 1831, 1834, 1836,
+        1838, 1841, 1841, 1859, 1870, 1870, 1882, 1882, 1896, 1896, 1896, 1908, 1908, 1911, 1931, 1933, 1933, 1951, 1951, 1953,
+        1960, 1980, 1982, 1988, 1991, 1998, 2021, 2025, 2030, 2032, 2050, 2052, 2055, 2061, 2083, 2085, 2088, 2108, 2110, 2112,
+        2115, 2135, 2137, 2137, 2156, 2158, 2161, 2163, 2180, 2180, 2184, 2184, 2193, 2193, 2193, 2218, 2221, 2226, 2226, 2248,
+        2249, 2252, 2272, 2272, 2276, 2278, 2281, 2305, 2310, 2315, 2322, 2325, 2347, 2349, 2352, 2353, 2362, 2365, 2388, 2388,
+        2388, 2391, 2412, 2419, 2437, 2439, 2439, 2439, 2460, 2460, 2460, 2462, 2476, 2476, 2481, 2483, 2483, 2486, 2493, 2500,
+        2506, 2511, 2515, 2517, 2519, 2526, 2533, 2541, 2549, 2562, 2571, 2581, 2597, 2607, 2614, 2617, 2623, 2625, 2630, 2634,
+        2637, 2660, 2663, 2667, 2669, 2686, 2689, 2691, 2699, 2720, 2727, 2730, 2734, 2755, 2761, 2769, 2780, 2786, 2803, 2805,
+        2809, 2818, 2824, 2827, 2827, 2832, 2836, 2836, 2838, 2840, 2843, 2850, 2850, 2852, 2872, 2874, 2874, 2876, 2895, 2896,
+        2902, 2920, 2920, 2920, 2940, 2943, 2943, 2946, 2956, 2956, 2956, 2959, 2982, 2987, 2997, 2999, 3022, 3025, 3028, 3043,
+        3065, 3065, 3073, 3082, 3088, 3095, 3113, 3125, 3128, 3131, 3133, 3144, 3159, 3161, 3167, 3169, 3176, 3178, 3201, 3203,
+        3208, 3208, 3229, 3229, 3231, 3239, 3256, 3256, 3258, 3260, 3260, 3280, 3280, 3287, 3295, 3298, 3314, 3317, 3317, 3324,
+        3328, 3328, 3334, 3337, 3339, 3343, 3343, 3343, 3346, 3346, 3346, 3346, 3349, 3358, 3368, 3381, 3381, 3386, 3399, 3402,
+        3410, 3412, 3415, 3417, 3417, 3431, 3431, 3433, 3435, 3445, 3445, 3462, 3463, 3465, 3473, 3473, 3476, 3480, 3482, 3505,
+        3505, 3508, 3508, 3518, 3520, 3529, 3540, 3540, 3540, 3544, 3544, 3554, 3556, 3559, 3559, 3559, 3564, 3566, 3568, 3570,
+        3570, 3570, 3576, 3576, 3581, 3583, 3584, 3585, 3587, 3589, 3603, 3603, 3615, 3619, 3629, 3631, 3633, 3647, 3661, 3671,
+        3676, 3685, 3689, 3689, 3699, 3708, 3708, 3711, 3713, 3713, 3713, 3713, 3715, 3715, 3715, 3715, 3715, 3722, 3722, 3725,
+        3729, 3732, 3737, 3737, 3737, 3744, 3744, 3747, 3747, 3748, 3748, 3754, 3757, 3760, 3760, 3766, 3768, 3768, 3775, 3778,
+        3778, 3780, 3782, 3785, 3787, 3790, 3793, 3793, 3795, 3804, 3806, 3808, 3808, 3808, 3808, 3810, 3812, 3812, 3812, 3814,
+        3816, 3818, 3820, 3820, 3822, 3824, 3826, 3826, 3829, 3831, 3832, 3834, 3834, 3834, 3838, 3838, 3840, 3843, 3843, 3843,
+        3846, 3848, 3850, 3850, 3850, 3853, 3855, 3857, 3857, 3861, 3861, 3861, 3861, 3861, 3861, 3861, 3861, 3863, 3863, 3867,
+        3869, 3871, 3871, 3873, 3878, 3878, 3878, 3878, 3878, 3880, 3880, 3882, 3884, 3890, 3894, 3896, 3896, 3899, 3902, 3904,
+        3911, 3915, 3922, 3925, 3925, 3925, 3927, 3927, 3929, 3932, 3934, 3936, 3943, 3948, 3952, 3952, 3958, 3958, 3960, 3965,
+        3970, 3970, 3973, 3973, 3975, 3985, 3990, 3993, 3998, 3998, 4005, 4005, 4009, 4011, 4011, 4011, 4013, 4015, 4015, 4016,
+        4025, 4028, 4028, 4028, 4028, 4030, 4030, 4036, 4040, 4041, 4046, 4051, 4051, 4051, 4060, 4067, 4069, 4071, 4073, 4075,
+        4075, 4075, 4081, 4086, 4089, 4093, 4095, 4097, 4097, 4105, 4107, 4111, 4114, 4114, 4114, 4118, 4121, 4125, 4127, 4131,
+        4131, 4135, 4140, 4140, 4140, 4140, 4145, 4148, 4150, 4150, 4155, 4160, 4164, 4166, 4166, 4171, 4173, 4173, 4175, 4175,
+        4177, 4177, 4179, 4182, 4186, 4186, 4186, 4186, 4186, 4188, 4193, 4193, 4196, 4198, 4202, 4206, 4209, 4211, 4213, 4213,
+        4217, 4219, 4219, 4219, 4222, 4222, 4222, 4222, 4222, 4222, 4224, 4224, 4224, 4224, 4224, 4224, 4226, 4226, 4230, 4230,
+        4230, 4231, 4234, 4240, 4243, 4245, 4249, 4253, 4259, 4261, 4269, 4279, 4282, 4292, 4294, 4309, 4319, 4327, 4327, 4330,
+        4330, 4330, 4330, 4330, 4330, 4330, 4336, 4339, 4343, 4347, 4347, 4349, 4352, 4354, 4356, 4361, 4363, 4369, 4371, 4379,
+        4382, 4382, 4382, 4382, 4384, 4384, 4389, 4389, 4392, 4392, 4392, 4396, 4399, 4402, 4402, 4402, 4407, 4411, 4411, 4417,
+        4417, 4423, 4431, 4434, 4439, 4444, 4449, 4449, 4449, 4449, 4454, 4458, 4460, 4466, 4468, 4468, 4468, 4468, 4468, 4468,
+        4468, 4471, 4471, 4474, 4477, 4480, 4481, 4486, 4490, 4490, 4494, 4501, 4503, 4512, 4518, 4527, 4534, 4537, 4540, 4542,
+        4544, 4548, 4550, 4550, 4550, 4550, 4550, 4550, 4550, 4550, 4561, 4561, 4565, 4575, 4584, 4589, 4591, 4591, 4591, 4591,
+        4594, 4599, 4603, 4606, 4606, 4608, 4613, 4615, 4620, 4620, 4620, 4631, 4639, 4644, 4645, 4646, 4647, 4648, 4648, 4648,
+        4648, 4650, 4650, 4650, 4653, 4657, 4662, 4666, 4669, 4669, 4671, 4675, 4675, 4675, 4687, 4693, 4705, 4717, 4717, 4734,
+        4743, 4753, 4756, 4760, 4763, 4763, 4763, 4763, 4763, 4765, 4765, 4767, 4767, 4769, 4771, 4773, 4776, 4779, 4781, 4788,
+        4790, 4792, 4792, 4792, 4792, 4792, 4792, 4792, 4792, 4792, 4813, 4830, 4832, 4849, 4852, 4852, 4865, 4869, 4881, 4883,
+        4883, 4885, 4888, 4890, 4890, 4892, 4895, 4897, 4899, 4903, 4906, 4908, 4920, 4928, 4934, 4941, 4943, 4945, 4945, 4945,
+        4945, 4945, 4945, 4945, 4945, 4945, 4945, 4945, 4945, 4951, 4956, 4962, 4966, 4969, 4971, 4973, 4975, 4978, 4978, 4989,
+        4998, 5015, 5020, 5025, 5025, 5041, 5047, 5051, 5056, 5058, 5066, 5069, 5069, 5069, 5069, 5069, 5069, 5069, 5081, 5089,
+        5095, 5095, 5105, 5105, 5110, 5113, 5113, 5113, 5113, 5113, 5113, 5113, 5115, 5118, 5120, 5120, 5122, 5128, 5132, 5139,
+        5144, 5150, 5153, 5158, 5159, 5159, 5159, 5159, 5159, 5159, 5161, 5163, 5165, 5167, 5169, 5172, 5175, 5178, 5187, 5187,
+        5187, 5187, 5189, 5189, 5193, 5197, 5197, 5199, 5203, 5205, 5210, 5213, 5216, 5221, 5229, 5229, 5229, 5235, 5236, 5240,
+        5243, 5246, 5249, 5249, 5249, 5249, 5249, 5249, 5249, 5249, 5249, 5251, 5253, 5261, 5264, 5266, 5267, 5267, 5267, 5270,
+        5272, 5276, 5281, 5287, 5287, 5291, 5301, 5303, 5305, 5307, 5309, 5309, 5311, 5311, 5313, 5313, 5322, 5324, 5332, 5336,
+        5341, 5342, 5345, 5347, 5347, 5349, 5357, 5359, 5362, 5364, 5371, 5374, 5384, 5388, 5391, 5391, 5392, 5392, 5392, 5392,
+        5392, 5392, 5392, 5394, 5398, 5402, 5404, 5405, 5412, 5412, 5414, 5414, 5423, 5426, 5430, 5433, 5442, 5445, 5447, 5450,
+        5450, 5450, 5450, 5451, 5464, 5466, 5466, 5469, 5475, 5480, 5482, 5482, 5482, 5482, 5482, 5486, 5486, 5489, 5489, 5493,
+        5496, 5498, 5501, 5507, 5509, 5511, 5511, 5513, 5515, 5515, 5515, 5517, 5517, 5520, 5520, 5523, 5523, 5527, 5527, 5527,
+        5527, 5530, 5532, 5535, 5538, 5542, 5544, 5544, 5544, 5544, 5544, 5547, 5547, 5547, 5549, 5551, 5551, 5551, 5551, 5553,
+        5553, 5553, 5555, 5557, 5557, 5557, 5557, 5559, 5561, 5563, 5565, 5565, 5567, 5569, 5569, 5574, 5574, 5574, 5574, 5574,
+        5574, 5576, 5576, 5576, 5576, 5581, 5585, 5587, 5587, 5587, 5587, 5593, 5593, 5593, 5593, 5596, 5598, 5604, 5608, 5608,
+        5608, 5610, 5610, 5612, 5612, 5614, 5617, 5620, 5624, 5624, 5625, 5625, 5625, 5628, 5630, 5634, 5647, 5649, 5649, 5649,
+        5649, 5649, 5649, 5652, 5654, 5654, 5654, 5657, 5658, 5658, 5658, 5661, 5661, 5661, 5666, 5666, 5668, 5668, 5668, 5671,
+        5671, 5673, 5673, 5675, 5678, 5680, 5682, 5684, 5684, 5688, 5688, 5688, 5688, 5694, 5694, 5694, 5697, 5697, 5699, 5699,
+        5699, 5701, 5701, 5701, 5701, 5704, 5706, 5708, 5708, 5708, 5708, 5710, 5710, 5712, 5712, 5712, 5715, 5717, 5721, 5721,
+        5723, 5731, 5734, 5734, 5736, 5738, 5740, 5740, 5740, 5740, 5742, 5742, 5742, 5743, 5747, 5752, 5754, 5754, 5756, 5759,
+        5761, 5765, 5767, 5778, 5778, 5782, 5785, 5789, 5791, 5794, 5794, 5794, 5796, 5798, 5800, 5802, 5802, 5805, 5810, 5815,
+        5817, 5824, 5826, 5828, 5828, 5830, 5833, 5836, 5838, 5840, 5842, 5842, 5850, 5850, 5858, 5860, 5864, 5867, 5867, 5867,
+        5870, 5873, 5881, 5883, 5885, 5890, 5890, 5897, 5906, 5906, 5906, 5906, 5906, 5908, 5910, 5910, 5912, 5914, 5914, 5916,
+        5921, 5921, 5923, 5927, 5938, 5940, 5940, 5942, 5944, 5944, 5944, 5944, 5953, 5953, 5961, 5970, 5975, 5978, 5978, 5978,
+        5978, 5978, 5978, 5980, 5980, 5980, 5982, 5984, 5986, 5991, 5996, 6000, 6007, 6012, 6016, 6017, 6017, 6017, 6017, 6021,
+        6021, 6023, 6023, 6027, 6029, 6031, 6039, 6043, 6049, 6051, 6051, 6061, 6068, 6070, 6075, 6075, 6078, 6082, 6082, 6082,
+        6082, 6082, 6082, 6082, 6082, 6082, 6082, 6082, 6085, 6088, 6088, 6092, 6097, 6107, 6111, 6113, 6118, 6121, 6125, 6125,
+        6125, 6125, 6125, 6125, 6127, 6129, 6129, 6133, 6133, 6133, 6133, 6135, 6135, 6138, 6138, 6143, 6143, 6147, 6147, 6147,
+        6150, 6152, 6155, 6155, 6156, 6162, 6165, 6169, 6177, 6177, 6179, 6181, 6181, 6181, 6181, 6181, 6186, 6189, 6189, 6192,
+        6195, 6201, 6204, 6204, 6205, 6205, 6205, 6205, 6205, 6209, 6209, 6218, 6223, 6226, 6228, 6230, 6233, 6235, 6235, 6238,
+        6238, 6238, 6238, 6238, 6250, 6258, 6266, 6269, 6271, 6276, 6278, 6278, 6278, 6278, 6278, 6278, 6278, 6284, 6286, 6288,
+        6288, 6288, 6288, 6290, 6290, 6290, 6295, 6297, 6302, 6302, 6302, 6308, 6310, 6312, 6312, 6315, 6317, 6319, 6319, 6322,
+        6324, 6326, 6328, 6330, 6333, 6333, 6335, 6338, 6341, 6345, 6349, 6351, 6353, 6357, 6358, 6362, 6371, 6375, 6384, 6386,
+        6391, 6404, 6409, 6414, 6419, 6422, 6426, 6428, 6428, 6428, 6434, 6437, 6440, 6442, 6442, 6442, 6449, 6452, 6458, 6463,
+        6469, 6471, 6471, 6482, 6484, 6487, 6491, 6493, 6493, 6493, 6493, 6493, 6496, 6496, 6499, 6501, 6502, 6505, 6507, 6510,
+        6512, 6515, 6518, 6521, 6523, 6526, 6526, 6526, 6526, 6526, 6526, 6528, 6530, 6530, 6530, 6532, 6532, 6537, 6545, 6548,
+        6553, 6559, 6562, 6568, 6572, 6579, 6584, 6586, 6588, 6588, 6588, 6588, 6588, 6590, 6590, 6590, 6594, 6594, 6595, 6599,
+        6599, 6602, 6610, 6613, 6617, 6617, 6619, 6624, 6626, 6626, 6626, 6628, 6628, 6630, 6630, 6633, 6635, 6635, 6635, 6637,
+        6641, 6648, 6650, 6652, 6654, 6654, 6654, 6656, 6656, 6659, 6661, 6661, 6665, 6667, 6667, 6669, 6669, 6669, 6672, 6674,
+        6674, 6674, 6674, 6674, 6674, 6676, 6676, 6676, 6676, 6676, 6676, 6676, 6679, 6682, 6685, 6685, 6685, 6689, 6693, 6696,
+        6699, 6704, 6704, 6704, 6708, 6713, 6713, 6719, 6721, 6721, 6721, 6721, 6725, 6727, 6729, 6731, 6736, 6741, 6747, 6747,
+        6749, 6751, 6751, 6753, 6753, 6755, 6756, 6756, 6756, 6756, 6756, 6756, 6760, 6760, 6760, 6763, 6765, 6770, 6770, 6770,
+        6772, 6775, 6777, 6780, 6789, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6792, 6794, 6794, 6794,
+        6794, 6794, 6794, 6794, 6798, 6800, 6800, 6802, 6802, 6802, 6802, 6806, 6810, 6810, 6814, 6817, 6817, 6817, 6817, 6818,
+        6818, 6818, 6818, 6820, 6822, 6824, 6826, 6829, 6829, 6832, 6832, 6834, 6834, 6834, 6835, 6836, 6836, 6836, 6839, 6845,
+        6845, 6845, 6846, 6846, 6848, 6850, 6850, 6850, 6850, 6852, 6852, 6852, 6852, 6852, 6854, 6854, 6856, 6856, 6859, 6864,
+        6866, 6868, 6870, 6874, 6876, 6877, 6877, 6878, 6878, 6878, 6878, 6880, 6880, 6885, 6888, 6890, 6890, 6893, 6901, 6902,
+        6904, 6913, 6920, 6933, 6933, 6935, 6945, 6948, 6954, 6956, 6958, 6961, 6961, 6962, 6962, 6964, 6964, 6967, 6967, 6969,
+        6973, 6973, 6976, 6978, 6982, 6982, 6991, 6991, 6995, 6997, 6997, 6997, 6999, 7001, 7009, 7009, 7011, 7013, 7015, 7020,
+        7025, 7027, 7031, 7033, 7036, 7036, 7036, 7039, 7039, 7044, 7048, 7051, 7061, 7064, 7068, 7071, 7073, 7080, 7083, 7083,
+        7083, 7083, 7083, 7089, 7095, 7099, 7105, 7105, 7105, 7107, 7107, 7107, 7107, 7109, 7110, 7112, 7116, 7118, 7120, 7120,
+        7125, 7130, 7130, 7133, 7133, 7136, 7139, 7143, 7147, 7151, 7158, 7165, 7175, 7178, 7182, 7192, 7198, 7204, 7209, 7209,
+        7212, 7213, 7213, 7213, 7213, 7213, 7216, 7218, 7222, 7231, 7231, 7231, 7231, 7231, 7233, 7235, 7235, 7236, 7238, 7240,
+        7242, 7246, 7249, 7254, 7254, 7260, 7262, 7262, 7262, 7263, 7265, 7267, 7270, 7276, 7278, 7278, 7280, 7280, 7282, 7284,
+        7286, 7286, 7286, 7294, 7300, 7303, 7305, 7305, 7311, 7311, 7315, 7321, 7330, 7333, 7338, 7340, 7349, 7353, 7358, 7361,
+        7363, 7365, 7369, 7373, 7373, 7373, 7373, 7373, 7373, 7377, 7379, 7382, 7384, 7386, 7390, 7392, 7397, 7401, 7407, 7407,
+        7410, 7410, 7412, 7416, 7418, 7421, 7423, 7423, 7427, 7427, 7427, 7427, 7427, 7427, 7429, 7429, 7429, 7429, 7432, 7435,
+        7438, 7440, 7443, 7445, 7449, 7453, 7460, 7460, 7464, 7466, 7470, 7475, 7481, 7481, 7481, 7483, 7483, 7483, 7483, 7485,
+        7490, 7492, 7493, 7498, 7500, 7500, 7503, 7503, 7505, 7511, 7513, 7517, 7519, 7524, 7529, 7531, 7531, 7531, 7531, 7531,
+        7531, 7531, 7531, 7531, 7533, 7535, 7538, 7538, 7540, 7540, 7540, 7540, 7546, 7549, 7549, 7549, 7552, 7554, 7556, 7558,
+        7560, 7560, 7560, 7560, 7560, 7562, 7562, 7562, 7562, 7562, 7564, 7566, 7566, 7568, 7571, 7571, 7579, 7587, 7592, 7594,
+        7603, 7611, 7617, 7619, 7623, 7630, 7638, 7657, 7662, 7664, 7667, 7684, 7696, 7702, 7705, 7707, 7710, 7713, 7716, 7716,
+        7716, 7716, 7716, 7716, 7716, 7716, 7716, 7720, 7720, 7722, 7727, 7730, 7730, 7733, 7735, 7741, 7741, 7746, 7750, 7760,
+        7760, 7760, 7772, 7778, 7786, 7788, 7790, 7792, 7795, 7795, 7795, 7795, 7795, 7798, 7804, 7811, 7811, 7811, 7811, 7811,
+        7811, 7816, 7824, 7826, 7839, 7841, 7846, 7860, 7868, 7870, 7873, 7875, 7879, 7879, 7879, 7879, 7879, 7879, 7879, 7879,
+        7879, 7879, 7881, 7881, 7885, 7889, 7889, 7890, 7892, 7895, 7897, 7902, 7907, 7913, 7925, 7932, 7935, 7935, 7949, 7956,
+        7962, 7966, 7966, 7968, 7968, 7970, 7972, 7972, 7973, 7973, 7974, 7974, 7974, 7974, 7974, 7976, 7976, 7976, 7976, 7976,
+        7976, 7976, 7976, 7976, 7976, 7976, 7976, 7976, 7979, 7985, 7985, 7986, 7986, 7988, 7990, 7992, 7995, 7999, 8002, 8007,
+        8009, 8009, 8015, 8026, 8031, 8033, 8033, 8035, 8035, 8035, 8035, 8035, 8035, 8035, 8037, 8040, 8040, 8042, 8050, 8053,
+        8055, 8057, 8059, 8059, 8059, 8059, 8059, 8059, 8061, 8061, 8067, 8070, 8075, 8079, 8082, 8086, 8091, 8093, 8095, 8095,
+        8101, 8110, 8114, 8118, 8121, 8121, 8121, 8123, 8123, 8126, 8126, 8126, 8129, 8132, 8136, 8136, 8138, 8138, 8140, 8143,
+        8145, 8145, 8147, 8150, 8160, 8163, 8166, 8168, 8171, 8174, 8177, 8181, 8181, 8184, 8184, 8184, 8184, 8184, 8184, 8184,
+        8186, 8189, 8191, 8193, 8193, 8199, 8199, 8201, 8203, 8207, 8210, 8210, 8214, 8214, 8218, 8221, 8223, 8223, 8225, 8225,
+        8225, 8225, 8228, 8230, 8233, 8233, 8233, 8233, 8233, 8237, 8252, 8256, 8256, 8260, 8264, 8271, 8273, 8276, 8280, 8280,
+        8280, 8280, 8280, 8282, 8285, 8287, 8289, 8289, 8291, 8294, 8297, 8297, 8301, 8304, 8306, 8306, 8308, 8308, 8311, 8311,
+        8313, 8317, 8317, 8319, 8319, 8319, 8321, 8321, 8323, 8326, 8328, 8328, 8331, 8333, 8335, 8337, 8339, 8339, 8339, 8343,
+        8345, 8349, 8349, 8352, 8352, 8355, 8355, 8357, 8357, 8359, 8361, 8361, 8361, 8361, 8363, 8365, 8365, 8365, 8365, 8365,
+        8365, 8365, 8368, 8370, 8370, 8370, 8370, 8372, 8372, 8372, 8372, 8374, 8374, 8380, 8382, 8386, 8387, 8391, 8391, 8391,
+        8393, 8393, 8401, 8402, 8404, 8408, 8414, 8416, 8419, 8421, 8421, 8424, 8424, 8426, 8430, 8430, 8430, 8430, 8434, 8434,
+        8434, 8434, 8434, 8434, 8434, 8436, 8436, 8436, 8438, 8438, 8438, 8441, 8446, 8448, 8448, 8450, 8455, 8455, 8457, 8457,
+        8457, 8457, 8461, 8463, 8465, 8465, 8467, 8467, 8469, 8473, 8473, 8473, 8475, 8482, 8482, 8482, 8488, 8488, 8488, 8488,
+        8488, 8488, 8490, 8492, 8494, 8494, 8496, 8499, 8501, 8501, 8503, 8503, 8503, 8507, 8510, 8510, 8510, 8513, 8515, 8518,
+        8518, 8520, 8523, 8523, 8523, 8523, 8526, 8529, 8529, 8533, 8538, 8540, 8542, 8542, 8544, 8544, 8544, 8544, 8546, 8551,
+        8557, 8559, 8562, 8565, 8571, 8571, 8575, 8584, 8587, 8600, 8602, 8610, 8625, 8632, 8640, 8643, 8645, 8648, 8649, 8654,
+        8654, 8654, 8654, 8654, 8654, 8654, 8654, 8654, 8654, 8654, 8658, 8663, 8668, 8670, 8670, 8672, 8681, 8681, 8693, 8695,
+        8700, 8702, 8715, 8719, 8724, 8724, 8724, 8726, 8728, 8728, 8728, 8728, 8735, 8739, 8742, 8745, 8754, 8757, 8757, 8762,
+        8765, 8765, 8773, 8776, 8778, 8781, 8781, 8783, 8785, 8793, 8797, 8810, 8810, 8812, 8815, 8822, 8829, 8829, 8829, 8831,
+        8834, 8841, 8852, 8852, 8852, 8855, 8861, 8863, 8863, 8863, 8863, 8863, 8865, 8867, 8867, 8867, 8870, 8876, 8878, 8878,
+        8880, 8880, 8883, 8886, 8893, 8899, 8908, 8912, 8917, 8926, 8932, 8941, 8945, 8945, 8945, 8945, 8945, 8945, 8945, 8945,
+        8945, 8945, 8953, 8969, 8977, 8995, 8997, 8997, 9000, 9002, 9002, 9002, 9005, 9005, 9007, 9008, 9008, 9011, 9011, 9013,
+        9015, 9015, 9015, 9018, 9018, 9018, 9018, 9018, 9021, 9021, 9021, 9022, 9024, 9024, 9027, 9032, 9035, 9042, 9046, 9050,
+        9051, 9055, 9055, 9056, 9056, 9056, 9056, 9056, 9056, 9056, 9056, 9058, 9060, 9060, 9062, 9066, 9072, 9074, 9074, 9074,
+        9074, 9076, 9078, 9078, 9080, 9080, 9080, 9080, 9080, 9083, 9085, 9085, 9085, 9085, 9085, 9085, 9087, 9087, 9087, 9087,
+        9087, 9087, 9087, 9087, 9096, 9096, 9103, 9112, 9112, 9115, 9115, 9115, 9115, 9117, 9119, 9121, 9124, 9127, 9130, 9130,
+        9134, 9134, 9137, 9140, 9147, 9152, 9154, 9158, 9162, 9162, 9162, 9162, 9164, 9164, 9164, 9164, 9164, 9169, 9173, 9175,
+        9180, 9183, 9187, 9192, 9197, 9197, 9199, 9202, 9208, 9215, 9215, 9218, 9221, 9221, 9221, 9222, 9222, 9226, 9230, 9233,
+        9236, 9238, 9240, 9240, 9240, 9243, 9246, 9248, 9248, 9251, 9253, 9255, 9255, 9258, 9260, 9260, 9263, 9267, 9270, 9272,
+        9272, 9275, 9277, 9279, 9281, 9283, 9285, 9287, 9287, 9287, 9291, 9297, 9299, 9304, 9306, 9306, 9314, 9321, 9326, 9328,
+        9328, 9328, 9328, 9328, 9328, 9328, 9328, 9333, 9333, 9336, 9336, 9341, 9344, 9347, 9349, 9353, 9357, 9357, 9359, 9363,
+        9365, 9370, 9377, 9380, 9382, 9382, 9386, 9388, 9392, 9395, 9404, 9414, 9425, 9427, 9433, 9442, 9447, 9455, 9459, 9462,
+        9465, 9465, 9467, 9467, 9467, 9467, 9467, 9467, 9477, 9480, 9481, 9494, 9498, 9500, 9508, 9513, 9521, 9524, 9524, 9524,
+        9535, 9538, 9547, 9551, 9556, 9559, 9559, 9563, 9563, 9565, 9571, 9576, 9590, 9594, 9595, 9605, 9607, 9611, 9611, 9613,
+        9613, 9616, 9616, 9616, 9618, 9618, 9618, 9618, 9620, 9637, 9637, 9650, 9650, 9650, 9666, 9666, 9668, 9669, 9687, 9687,
+        9691, 9696, 9704, 9706, 9708, 9708, 9713, 9716, 9719, 9720, 9723, 9723, 9723, 9734, 9738, 9747, 9749, 9751, 9753, 9756,
+        9761, 9761, 9761, 9761, 9761, 9761, 9763, 9766, 9766, 9773, 9775, 9776, 9776, 9780, 9785, 9788, 9788, 9790, 9790, 9790,
+        9792, 9794, 9794, 9797, 9800, 9803, 9807, 9809, 9809, 9809, 9814, 9818, 9823, 9823, 9825, 9825, 9827, 9829, 9832, 9832,
+        9836, 9838, 9841, 9850, 9853, 9862, 9862, 9864, 9871, 9874, 9876, 9884, 9887, 9890, 9900, 9909, 9909, 9914, 9922, 9926,
+        9933, 9933, 9937, 9937, 9940, 9940, 9940, 9940, 9942, 9944, 9944, 9944, 9944, 9944, 9944, 9959, 9970, 9972, 9980, 9992,
+        9997, 9997, 10003, 10003, 10007, 10007, 10007, 10026, 10029, 10031, 10033, 10036, 10038, 10040, 10045, 10054, 10061, 10065, 10073, 10079,
+        10079, 10079, 10087, 10087, 10091, 10091, 10096, 10098, 10098, 10098, 10098, 10101, 10103, 10115, 10115, 10118, 10118, 10119, 10119, 10123,
+        10125, 10127, 10130, 10137, 10137, 10142, 10142, 10147, 10158, 10162, 10175, 10178, 10180, 10180, 10192, 10197, 10203, 10205, 10205, 10209,
+        10211, 10212, 10212, 10213, 10213, 10213, 10215, 10215, 10215, 10219, 10222, 10225, 10225, 10228, 10230, 10232, 10233, 10236, 10245, 10251,
+        10262, 10262, 10268, 10268, 10278, 10281, 10286, 10286, 10286, 10286, 10286, 10286, 10293, 10303, 10309, 10311, 10316, 10320, 10323, 10325,
+        10327, 10330, 10330, 10332, 10335, 10337, 10337, 10340, 10343, 10349, 10357, 10360, 10363, 10368, 10370, 10372, 10372, 10372, 10372, 10372,
+        10372, 10372, 10372, 10372, 10372, 10374, 10376, 10379, 10381, 10383, 10389, 10396, 10406, 10410, 10415, 10427, 10429, 10434, 10438, 10441,
+        10443, 10456, 10466, 10466, 10486, 10496, 10503, 10506, 10506, 10509, 10512, 10516, 10518, 10520, 10520, 10528, 10528, 10528, 10528, 10531,
+        10535, 10535, 10535, 10535, 10535, 10537, 10540, 10543, 10549, 10552, 10562, 10565, 10569, 10569, 10569, 10571, 10573, 10573, 10573, 10576,
+        10578, 10580, 10584, 10586, 10586, 10588, 10590, 10593, 10595, 10595, 10595, 10595, 10595, 10596, 10596, 10598, 10598, 10598, 10600, 10600,
+        10604, 10604, 10604, 10604, 10604, 10604, 10604, 10604, 10606, 10606, 10606, 10606, 10606, 10606, 10606, 10606, 10608, 10610, 10610, 10610,
+        10614, 10614, 10614, 10614, 10615, 10617, 10618, 10618, 10618, 10620, 10620, 10623, 10626, 10628, 10630, 10635, 10635, 10637, 10639, 10639,
+        10639, 10639, 10639, 10639, 10639, 10639, 10643, 10647, 10647, 10649, 10649, 10651, 10653, 10655, 10655, 10657, 10663, 10663, 10663, 10665,
+        10667, 10667, 10669, 10669, 10669, 10671, 10671, 10673, 10673, 10673, 10673, 10673, 10673, 10673, 10673, 10673, 10673, 10675, 10679, 10681,
+        10681, 10681, 10686, 10686, 10688, 10690, 10690, 10694, 10697, 10704, 10704, 10704, 10704, 10704, 10704, 10704, 10704, 10706, 10706, 10709,
+        10711, 10718, 10724, 10734, 10738, 10741, 10744, 10747, 10750, 10752, 10752, 10753, 10755, 10758, 10760, 10764, 10764, 10766, 10772, 10776,
+        10783, 10783, 10784, 10784, 10785, 10785, 10787, 10795, 10797, 10797, 10797, 10799, 10799, 10799, 10799, 10799, 10799, 10799, 10799, 10801,
+        10804, 10804, 10804, 10804, 10805, 10807, 10807, 10807, 10807, 10810, 10810, 10817, 10820, 10827, 10829, 10833, 10833, 10836, 10838, 10847,
+        10850, 10854, 10854, 10856, 10859, 10861, 10861, 10861, 10861, 10866, 10869, 10869, 10872, 10872, 10875, 10877, 10879, 10881, 10883, 10883,
+        10883, 10890, 10892, 10892, 10900, 10900, 10900, 10900, 10902, 10902, 10904, 10904, 10906, 10906, 10909, 10916, 10916, 10916, 10916, 10918,
+        10919, 10919, 10919, 10919, 10919, 10919, 10919, 10919, 10922, 10928, 10931, 10937, 10937, 10941, 10941, 10941, 10941, 10941, 10943, 10943,
+        10943, 10943, 10945, 10947, 10947, 10949, 10949, 10951, 10951, 10953, 10956, 10958, 10958, 10960, 10960, 10962, 10962, 10962, 10962, 10962,
+        10962, 10962, 10962, 10962, 10962, 10962, 10962, 10962, 10964, 10967, 10967, 10969, 10972, 10975, 10977, 10977, 10984, 10990, 10996, 10998,
+        10998, 11001, 11001, 11003, 11003, 11005, 11005, 11005, 11007, 11009, 11011, 11013, 11016, 11018, 11020, 11020, 11022, 11022, 11022, 11022,
+        11022, 11024, 11027, 11027, 11029, 11031, 11034, 11038, 11038, 11043, 11043, 11043, 11043, 11043, 11043, 11045, 11047, 11049, 11049, 11051,
+        11053, 11055, 11055, 11055, 11057, 11057, 11057, 11057, 11059, 11061, 11061, 11061, 11066, 11068, 11068, 11072, 11075, 11077, 11080, 11080,
+        11080, 11080, 11082, 11082, 11084, 11086, 11088, 11088, 11090, 11092, 11092, 11094, 11095, 11095, 11095, 11097, 11101, 11101, 11101, 11101,
+        11101, 11101, 11103, 11106, 11108, 11108, 11108, 11108, 11108, 11108, 11108, 11108, 11108, 11108, 11108, 11112, 11119, 11119, 11119, 11121,
+        11123, 11126, 11128, 11130, 11132, 11132, 11134, 11136, 11138, 11138, 11139, 11139, 11139, 11144, 11144, 11144, 11146, 11148, 11150, 11150,
+        11150, 11155, 11155, 11155, 11155, 11157, 11157, 11157, 11159, 11162, 11162, 11162, 11162, 11162, 11163, 11163, 11163, 11165, 11167, 11167,
+        11169, 11169, 11172, 11172, 11172, 11174, 11174, 11174, 11174, 11174, 11174, 11178, 11182, 11182, 11182, 11182, 11184, 11184, 11184, 11186,
+        11190, 11190, 11193, 11195, 11195, 11195, 11195, 11197, 11199, 11199, 11199, 11199, 11201, 11201, 11203, 11205, 11205, 11207, 11215, 11217,
+        11218, 11218, 11218, 11218, 11220, 11220, 11220, 11220, 11220, 11220, 11220, 11220, 11222, 11224, 11224, 11224, 11226, 11226, 11226, 11226,
+        11226, 11226, 11226, 11226, 11226, 11228, 11228, 11228, 11228, 11228, 11228, 11228, 11230, 11230, 11230, 11230, 11230, 11230, 11232, 11232,
+        11232, 11232, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11237, 11239, 11241, 11241, 11243, 11245, 11245,
+        11245, 11245, 11245, 11245, 11245, 11245, 11245, 11248, 11254, 11256, 11259, 11259, 11259, 11261, 11261, 11261, 11261, 11261, 11261, 11261,
+        11261, 11263, 11263, 11263, 11265, 11265, 11268, 11268, 11268, 11268, 11268, 11268, 11268, 11269, 11269, 11269, 11269, 11269, 11269, 11271,
+        11271, 11271, 11271, 11271, 11273, 11276, 11276, 11276, 11276, 11276, 11276, 11280, 11282, 11284, 11287, 11288, 11288, 11288, 11288, 11288,
+        11288, 11288, 11290, 11291, 11291, 11291, 11291, 11291, 11291, 11293, 11293, 11297, 11299, 11301, 11303, 11308, 11310, 11312, 11314, 11317,
+        11317, 11317, 11321, 11324, 11327, 11328, 11328, 11328, 11330, 11333, 11333, 11336, 11336, 11336, 11336, 11336, 11336, 11339, 11339, 11339,
+        11341, 11341, 11341, 11343, 11345, 11347, 11349, 11349, 11351, 11354, 11356, 11358, 11358, 11358, 11360, 11360, 11363, 11363, 11363, 11363,
+        11363, 11366, 11366, 11366, 11371, 11371, 11373, 11373, 11375, 11375, 11378, 11378, 11378, 11378, 11378, 11380, 11382, 11384, 11384, 11384,
+        11386, 11389, 11393, 11395, 11397, 11397, 11399, 11399, 11401, 11403, 11403, 11403, 11407, 11407, 11407, 11409, 11409, 11409, 11409, 11409,
+        11411, 11414, 11414, 11414, 11414, 11414, 11414, 11414, 11416, 11418, 11418, 11418, 11418, 11418, 11420, 11420, 11421, 11423, 11431, 11435,
+        11447, 11447, 11447, 11447, 11447, 11447, 11447, 11447, 11449, 11449, 11449, 11449, 11449, 11451, 11451, 11451, 11451, 11451, 11451, 11453,
+        11453, 11453, 11455, 11455, 11455, 11455, 11455, 11455, 11458, 11458, 11460, 11460, 11462, 11464, 11466, 11468, 11468, 11468, 11470, 11470,
+        11470, 11472, 11474, 11474, 11474, 11474, 11476, 11476, 11476, 11478, 11478, 11478, 11478, 11478, 11478, 11478, 11478, 11478, 11478, 11478,
+        11478, 11480, 11480, 11482, 11484, 11484, 11484, 11484, 11486, 11486, 11486, 11495, 11495, 11497, 11500, 11500, 11502, 11502, 11502, 11502,
+        11502, 11502, 11502, 11502, 11505, 11505, 11505, 11505, 11509, 11512, 11512, 11512, 11512, 11512, 11512, 11512, 11514, 11514, 11515, 11515,
+        11515, 11515, 11517, 11519, 11519, 11521, 11521, 11521, 11523, 11523, 11526, 11526, 11526, 11526, 11526, 11526, 11526, 11526, 11528, 11531,
+        11531, 11531, 11533, 11538, 11540, 11540, 11540, 11540, 11540, 11542, 11542, 11545, 11548, 11548, 11550, 11550, 11552, 11552, 11552, 11555,
+        11555, 11557, 11557, 11559, 11559, 11559, 11559, 11559, 11559, 11559, 11561, 11561, 11561, 11561, 11563, 11563, 11563, 11563, 11563, 11563,
+        11563, 11563, 11563, 11563, 11565, 11565, 11565, 11565, 11565, 11566, 11566, 11566, 11566, 11567, 11567, 11567, 11567, 11567, 11567, 11567,
+        11569, 11571, 11574, 11574, 11575, 11575, 11575, 11575, 11583, 11583, 11583, 11583, 11583, 11585, 11585, 11586, 11588, 11588, 11588, 11590,
+        11590, 11590, 11590, 11590, 11590, 11590, 11590, 11590, 11590, 11590, 11590, 11590, 11593, 11595, 11595, 11597, 11597, 11597, 11603, 11603,
+        11605, 11605, 11607, 11607, 11607, 11607, 11607, 11607, 11609, 11609, 11609, 11609, 11609, 11609, 11609, 11609, 11611, 11611, 11611, 11613,
+        11615, 11615, 11617, 11619, 11619, 11621, 11621, 11621, 11621, 11623, 11627, 11627, 11628, 11631, 11635, 11637, 11639, 11641, 11643, 11643,
+        11643, 11645, 11645, 11645, 11647, 11651, 11653, 11656, 11658, 11658, 11660, 11660, 11660, 11660, 11660, 11667, 11671, 11673, 11675, 11677,
+        11679, 11679, 11679, 11681, 11683, 11683, 11687, 11687, 11687, 11689, 11690, 11690, 11690, 11697, 11697, 11700, 11700, 11703, 11703, 11703,
+        11705, 11705, 11707, 11707, 11710, 11710, 11713, 11714, 11716, 11716, 11716, 11716, 11718, 11720, 11722, 11722, 11725, 11725, 11725, 11725,
+        11725, 11725, 11725, 11727, 11727, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11730, 11734,
+        11736, 11736, 11736, 11736, 11736, 11736, 11738, 11740, 11740, 11745, 11747, 11749, 11751, 11751, 11751, 11751, 11753, 11753, 11753, 11753,
+        11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11753, 11755, 11755, 11755, 11755, 11755, 11755,
+        11757, 11757, 11757, 11757, 11757, 11757, 11757, 11757, 11757, 11757, 11759, 11759, 11759, 11759, 11760, 11760, 11762, 11762, 11762, 11762,
+        11762, 11762, 11762, 11762, 11762, 11765, 11765, 11765, 11767, 11767, 11770, 11772, 11772, 11772, 11772, 11772, 11772, 11772, 11772, 11772,
+        11774, 11774, 11774, 11774, 11774, 11776, 11776, 11776, 11776, 11776, 11779, 11779, 11779, 11779, 11782, 11784, 11784, 11787, 11787, 11794,
+        11794, 11794, 11794, 11796, 11796, 11796, 11800, 11804, 11804, 11804, 11807, 11809, 11811, 11813, 11815, 11815, 11815, 11815, 11815, 11815,
+        11815, 11816, 11816, 11816, 11818, 11818, 11818, 11818, 11818, 11818, 11820, 11822, 11822, 11822, 11822, 11822, 11824, 11824, 11827, 11827,
+        11829, 11831, 11831, 11833, 11833, 11833, 11835, 11835, 11837, 11837, 11839, 11839, 11839, 11839, 11839, 11842, 11842, 11842, 11842, 11847,
+        11849, 11851, 11851, 11853, 11853, 11855, 11855, 11855, 11855, 11858, 11858, 11858, 11858, 11860, 11862, 11864, 11864, 11867, 11867, 11870,
+        11870, 11870, 11870, 11870, 11870, 11870, 11870, 11871, 11871, 11871, 11871, 11875, 11875, 11877, 11877, 11877, 11877, 11881, 11883, 11885,
+        11888, 11888, 11893, 11893, 11893, 11893, 11893, 11893, 11896, 11896, 11898, 11900, 11900, 11904, 11904, 11904, 11904, 11907, 11907, 11907,
+        11907, 11907, 11910, 11912, 11914, 11914, 11914, 11914, 11915, 11918, 11918, 11918, 11920, 11920, 11920, 11924, 11924, 11926, 11928, 11928,
+        11928, 11928, 11928, 11928, 11930, 11930, 11930, 11930, 11930, 11932, 11932, 11935, 11936, 11936, 11936, 11938, 11938, 11938, 11943, 11945,
+        11945, 11945, 11945, 11947, 11950, 11950, 11950, 11952, 11952, 11952, 11954, 11957, 11957, 11957, 11957, 11957, 11960, 11960, 11960, 11960,
+        11962, 11962, 11962, 11964, 11968, 11968, 11968, 11968, 11968, 11970, 11970, 11970, 11972, 11972, 11972, 11972, 11975, 11975, 11975, 11975,
+        11975, 11975, 11977, 11977, 11979, 11982, 11982, 11982, 11982, 11982, 11985, 11985, 11988, 11988, 11988, 11988, 11994, 11998, 11998, 12000,
+        12007, 12009, 12009, 12011, 12013, 12013, 12016, 12018, 12022, 12024, 12027, 12029, 12029, 12034, 12036, 12038, 12040, 12040, 12040, 12040,
+        12043, 12045, 12050, 12050, 12050, 12050, 12052, 12054, 12054, 12054, 12057, 12057, 12059, 12061, 12063, 12065, 12067, 12067, 12067, 12070,
+        12072, 12075, 12077, 12078, 12078, 12082, 12082, 12085, 12087, 12093, 12096, 12096, 12099, 12099, 12101, 12103, 12103, 12105, 12107, 12109,
+        12109, 12112, 12116, 12116, 12122, 12122, 12124, 12124, 12128, 12129, 12129, 12129, 12133, 12133, 12133, 12137, 12140, 12142, 12148, 12148,
+        12148, 12148, 12148, 12148, 12148, 12148, 12148, 12148, 12148, 12148, 12148, 12148, 12150, 12153, 12153, 12155, 12155, 12157, 12159, 12165,
+        12165, 12168, 12170, 12173, 12175, 12177, 12179, 12179, 12181, 12183, 12183, 12183, 12183, 12183, 12186, 12190, 12193, 12195, 12195, 12195,
+        12198, 12198, 12203, 12208, 12210, 12214, 12214, 12224, 12229, 12232, 12234, 12237, 12237, 12239, 12239, 12241, 12245, 12250, 12250, 12250,
+        12250, 12252, 12255, 12259, 12259, 12265, 12267, 12268, 12268, 12268, 12268, 12268, 12268, 12270, 12272, 12275, 12275, 12276, 12279, 12281,
+        12288, 12291, 12297, 12301, 12303, 12303, 12307, 12307, 12307, 12307, 12309, 12309, 12311, 12313, 12315, 12315, 12315, 12315, 12318, 12321,
+        12326, 12328, 12333, 12333, 12336, 12336, 12338, 12338, 12340, 12342, 12342, 12344, 12346, 12346, 12348, 12350, 12352, 12352, 12354, 12356,
+        12358, 12358, 12360, 12361, 12361, 12363, 12365, 12365, 12367, 12367, 12367, 12367, 12369, 12372, 12372, 12372, 12372, 12372, 12374, 12377,
+        12377, 12377, 12377, 12380, 12382, 12384, 12384, 12384, 12384, 12384, 12386, 12386, 12386, 12386, 12386, 12388, 12390, 12392, 12392, 12395,
+        12395, 12397, 12398, 12400, 12402, 12402, 12405, 12405, 12407, 12407, 12409, 12413, 12415, 12417, 12419, 12419, 12419, 12419, 12419, 12419,
+        12419, 12419, 12421, 12421, 12423, 12423, 12425, 12425, 12425, 12425, 12428, 12428, 12428, 12428, 12428, 12428, 12428, 12428, 12431, 12434,
+        12438, 12440, 12440, 12440, 12440, 12440, 12440, 12443, 12446, 12446, 12448, 12448, 12450, 12450, 12450, 12450, 12450, 12450, 12450, 12450,
+        12450, 12453, 12456, 12462, 12466, 12469, 12469, 12469, 12472, 12472, 12476, 12480, 12482, 12484, 12487, 12487, 12491, 12491, 12491, 12491,
+        12491, 12494, 12498, 12500, 12502, 12508, 12510, 12512, 12512, 12515, 12515, 12515, 12520, 12524, 12526, 12528, 12528, 12530, 12530, 12530,
+        12530, 12532, 12532, 12534, 12536, 12536, 12538, 12541, 12543, 12543, 12543, 12543, 12543, 12543, 12547, 12549, 12551, 12556, 12558, 12558,
+        12558, 12564, 12564, 12564, 12566, 12568, 12568, 12571, 12571, 12573, 12573, 12574, 12574, 12577, 12579, 12579, 12579, 12581, 12581, 12581,
+        12581, 12581, 12581, 12581, 12583, 12584, 12584, 12584, 12584, 12584, 12584, 12586, 12586, 12588, 12590, 12592, 12592, 12592, 12592, 12594,
+        12594, 12596, 12599, 12601, 12605, 12605, 12608, 12608, 12611, 12611, 12614, 12616, 12616, 12616, 12618, 12620, 12622, 12625, 12625, 12625,
+        12627, 12627, 12627, 12629, 12629, 12632, 12632, 12633, 12635, 12635, 12635, 12635, 12637, 12639, 12639, 12639, 12639, 12641, 12646, 12646,
+        12646, 12646, 12646, 12646, 12648, 12648, 12648, 12648, 12648, 12649, 12651, 12651, 12653, 12653, 12653, 12653, 12653, 12653, 12655, 12655,
+        12655, 12655, 12655, 12655, 12655, 12657, 12657, 12657, 12657, 12659, 12659, 12660, 12662, 12662, 12664, 12664, 12664, 12664, 12666, 12666,
+        12666, 12668, 12676, 12676, 12678, 12678, 12678, 12680, 12683, 12685, 12685, 12685, 12685, 12687, 12689, 12689, 12691, 12693, 12695, 12695,
+        12695, 12695, 12695, 12695, 12695, 12697, 12697, 12697, 12697, 12697, 12697, 12697, 12697, 12699, 12699, 12701, 12701, 12701, 12701, 12701,
+        12701, 12703, 12705, 12705, 12705, 12705, 12705, 12705, 12705, 12707, 12710, 12710, 12710, 12710, 12711, 12711, 12711, 12711, 12711, 12711,
+        12711, 12711, 12711, 12711, 12713, 12713, 12715, 12715, 12715, 12715, 12715, 12715, 12715, 12718, 12718, 12718, 12718, 12718, 12720, 12720,
+        12720, 12720, 12720, 12720, 12720, 12720, 12720, 12722, 12722, 12722, 12722, 12722, 12722, 12722, 12729, 12729, 12732, 12734, 12734, 12736,
+        12736, 12738, 12738, 12738, 12738, 12740, 12740, 12740, 12740, 12740, 12740, 12740, 12740, 12740, 12743, 12743, 12746, 12746, 12746, 12751,
+        12751, 12755, 12757, 12759, 12761, 12761, 12761, 12763, 12763, 12765, 12767, 12767, 12767, 12767, 12767, 12767, 12767, 12769, 12771, 12771,
+        12773, 12775, 12775, 12775, 12777, 12777, 12777, 12777, 12777, 12777, 12777, 12777, 12777, 12777, 12779, 12781, 12783, 12785, 12787, 12789,
+        12789, 12789, 12791, 12791, 12791, 12791, 12791, 12791, 12791, 12793, 12793, 12796, 12796, 12798, 12800, 12802, 12804, 12804, 12804, 12804,
+        12804, 12804, 12805, 12805, 12807, 12809, 12809, 12809, 12809, 12809, 12811, 12811, 12813, 12813, 12813, 12813, 12815, 12815, 12815, 12815,
+        12820, 12820, 12822, 12824, 12824, 12826, 12826, 12828, 12830, 12830, 12833, 12833, 12833, 12833, 12833, 12833, 12833, 12833, 12836, 12836,
+        12838, 12838, 12840, 12840, 12843, 12845, 12845, 12845, 12845, 12845, 12847, 12847, 12847, 12847, 12847, 12847, 12849, 12851, 12851, 12854,
+        12854, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12856, 12858, 12858, 12858, 12858, 12858, 12860, 12863,
+        12867, 12867, 12869, 12869, 12869, 12871, 12871, 12871, 12875, 12875, 12875, 12875, 12876, 12878, 12881, 12883, 12883, 12883, 12885, 12885,
+        12885, 12885, 12887, 12887, 12887, 12887, 12887, 12887, 12889, 12889, 12889, 12889, 12889, 12892, 12894, 12896, 12896, 12898, 12898, 12898,
+        12898, 12900, 12902, 12905, 12905, 12905, 12908, 12908, 12908, 12908, 12911, 12913, 12913, 12917, 12921, 12921, 12921, 12921, 12922, 12922,
+        12922, 12922, 12922, 12922, 12922, 12926, 12926, 12928, 12928, 12928, 12928, 12928, 12928, 12928, 12928, 12928, 12928, 12930, 12930, 12930,
+        12933, 12933, 12933, 12935, 12935, 12935, 12935, 12935, 12937, 12937, 12937, 12937, 12937, 12937, 12937, 12937, 12937, 12937, 12937, 12937,
+        12937, 12937, 12937, 12939, 12939, 12939, 12939, 12939, 12942, 12944, 12944, 12944, 12944, 12947, 12949, 12949, 12949, 12949, 12949, 12949,
+        12949, 12953, 12953, 12953, 12953, 12955, 12955, 12955, 12955, 12955, 12955, 12955, 12955, 12957, 12957, 12957, 12957, 12957, 12959, 12959,
+        12959, 12959, 12961, 12961, 12961, 12961, 12961, 12961, 12961, 12961, 12963, 12964, 12966, 12968, 12970, 12970, 12970, 12970, 12970, 12970,
+        12970, 12970, 12972, 12972, 12974, 12976, 12976, 12978, 12978, 12978, 12980, 12982, 12984, 12986, 12988, 12989, 12989, 12989, 12989, 12989,
+        12991, 12994, 12996, 12996, 12998, 13000, 13002, 13010, 13010, 13010, 13012, 13012, 13012, 13015, 13015, 13015, 13017, 13017, 13017, 13017,
+        13017, 13019, 13021, 13025, 13027, 13027, 13027, 13027, 13027, 13027, 13029, 13032, 13032, 13034, 13034, 13040, 13042, 13042, 13044, 13044,
+        13047, 13049, 13052, 13055, 13055, 13058, 13062, 13064, 13064, 13064, 13064, 13066, 13066, 13066, 13066, 13066, 13066, 13066, 13066, 13066,
+        13068, 13068, 13068, 13068, 13068, 13068, 13068, 13068, 13070, 13072, 13072, 13074, 13077, 13077, 13077, 13079, 13079, 13079, 13079, 13079,
+        13079, 13079, 13079, 13079, 13079, 13079, 13079, 13079, 13079, 13081, 13081, 13084, 13087, 13087, 13087, 13087, 13087, 13087, 13087, 13087,
+        13087, 13089, 13089, 13089, 13089, 13089, 13091, 13091, 13091, 13093, 13095, 13095, 13095, 13095, 13095, 13095, 13095, 13095, 13097, 13097,
+        13097, 13097, 13097, 13097, 13097, 13100, 13102, 13104, 13104, 13104, 13104, 13106, 13109, 13109, 13109, 13109, 13109, 13109, 13111, 13111,
+        13111, 13111, 13111, 13111, 13111, 13111, 13111, 13111, 13113, 13113, 13113, 13115, 13115, 13115, 13115, 13115, 13117, 13117, 13117, 13119,
+        13119, 13122, 13124, 13129, 13132, 13132, 13132, 13132, 13132, 13132, 13132, 13132, 13132, 13134, 13136, 13136, 13136, 13136, 13138, 13140,
+        13142, 13144, 13144, 13147, 13147, 13147, 13147, 13147, 13147, 13147, 13149, 13150, 13150, 13152, 13152, 13152, 13152, 13152, 13152, 13152,
+        13152, 13154, 13156, 13156, 13158, 13160, 13160, 13160, 13162, 13164, 13164, 13164, 13166, 13166, 13166, 13168, 13168, 13169, 13171, 13171,
+        13171, 13175, 13175, 13175, 13175, 13175, 13175, 13175, 13178, 13178, 13178, 13178, 13180, 13183, 13183, 13186, 13188, 13188, 13188, 13190,
+        13190, 13190, 13190, 13192, 13192, 13192, 13192, 13192, 13192, 13194, 13195, 13195, 13197, 13199, 13200, 13202, 13204, 13204, 13204, 13204,
+        13204, 13206, 13210, 13210, 13212, 13212, 13216, 13219, 13219, 13219, 13219, 13219, 13219, 13219, 13219, 13222, 13226, 13226, 13228, 13232,
+        13232, 13232, 13232, 13232, 13232, 13232, 13234, 13237, 13239, 13239, 13241, 13242, 13242, 13242, 13244, 13244, 13244, 13244, 13244, 13244,
+        13244, 13244, 13244, 13244, 13244, 13244, 13244, 13244, 13244, 13244, 13246, 13246, 13248, 13248, 13248, 13248, 13248, 13248, 13248, 13248,
+        13248, 13250, 13252, 13252, 13255, 13255, 13255, 13260, 13262, 13264, 13274, 13276, 13276, 13279, 13281, 13281, 13282, 13282, 13284, 13284,
+        13284, 13284, 13286, 13288, 13288, 13290, 13290, 13290, 13290, 13290, 13295, 13295, 13295, 13295, 13295, 13298, 13298, 13298, 13298, 13300,
+        13303, 13305, 13307, 13307, 13309, 13309, 13310, 13310, 13310, 13312, 13314, 13314, 13314, 13316, 13318, 13318, 13318, 13318, 13318, 13320,
+        13320, 13320, 13320, 13320, 13322, 13322, 13324, 13326, 13326, 13326, 13326, 13326, 13328, 13328, 13328, 13330, 13330, 13332, 13335, 13339,
+        13339, 13339, 13339, 13341, 13343, 13345, 13347, 13347, 13347, 13350, 13350, 13350, 13350, 13350, 13350, 13350, 13350, 13350, 13350, 13350,
+        13352, 13353, 13353, 13353, 13354, 13356, 13356, 13356, 13356, 13356, 13356, 13356, 13356, 13359, 13361, 13361, 13364, 13364, 13364, 13368,
+        13368, 13370, 13372, 13374, 13376, 13377, 13379, 13379, 13379, 13380, 13380, 13382, 13384, 13384, 13387, 13387, 13389, 13392, 13392, 13392,
+        13392, 13392, 13392, 13392, 13392, 13394, 13394, 13399, 13399, 13401, 13401, 13401, 13401, 13401, 13401, 13401, 13402, 13402, 13402, 13402,
+        13404, 13404, 13404, 13404, 13404, 13404, 13404, 13404, 13404, 13407, 13407, 13407, 13407, 13409, 13409, 13409, 13411, 13411, 13411, 13411,
+        13415, 13420, 13422, 13425, 13425, 13425, 13428, 13429, 13429, 13431, 13431, 13431, 13431, 13431, 13431, 13431, 13431, 13433, 13433, 13433,
+        13433, 13435, 13435, 13435, 13435, 13435, 13435, 13438, 13438, 13440, 13440, 13440, 13442, 13442, 13442, 13442, 13442, 13448, 13448, 13448,
+        13448, 13450, 13450, 13450, 13453, 13453, 13453, 13453, 13455, 13457, 13457, 13457, 13457, 13457, 13457, 13457, 13457, 13457, 13457, 13460,
+        13460, 13462, 13462, 13462, 13462, 13462, 13462, 13465, 13467, 13469, 13471, 13471, 13472, 13472, 13475, 13477, 13477, 13477, 13477, 13479,
+        13481, 13481, 13481, 13481, 13481, 13483, 13483, 13483, 13483, 13486, 13486, 13486, 13486, 13486, 13486, 13486, 13486, 13486, 13486, 13486,
+        13486, 13486, 13486, 13486, 13486, 13486, 13486, 13491, 13491, 13491, 13494, 13494, 13494, 13494, 13494, 13496, 13496, 13496, 13501, 13503,
+        13503, 13504, 13504, 13506, 13506, 13509, 13509, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511, 13511,
+        13511, 13511, 13511, 13512, 13515, 13520, 13522, 13522, 13525, 13525, 13527, 13527, 13527, 13527, 13530, 13530, 13532, 13532, 13532, 13532,
+        13532, 13533, 13535, 13535, 13535, 13537, 13538, 13542, 13542, 13545, 13545, 13545, 13545, 13545, 13545, 13545, 13545, 13545, 13547, 13547,
+        13547, 13547, 13547, 13550, 13550, 13550, 13554, 13554, 13554, 13554, 13554, 13554, 13554, 13554, 13554, 13554, 13554, 13556, 13556, 13556,
+        13556, 13556, 13556, 13556, 13556, 13556, 13556, 13556, 13558, 13558, 13558, 13558, 13561, 13561, 13561, 13562, 13562, 13562, 13562, 13562,
+        13562, 13564, 13564, 13566, 13566, 13568, 13571, 13571, 13571, 13571, 13571, 13572, 13576, 13576, 13577, 13577, 13579, 13583, 13585, 13587,
+        13589, 13589, 13593, 13595, 13597, 13598, 13598, 13600, 13600, 13600, 13601, 13603, 13603, 13603, 13603, 13605, 13607, 13607, 13609, 13612,
+        13614, 13614, 13614, 13614, 13618, 13618, 13622, 13626, 13631, 13634, 13636, 13636, 13640, 13642, 13642, 13644, 13647, 13647, 13655, 13657,
+        13659, 13661, 13661, 13663, 13663, 13663, 13663, 13666, 13668, 13668, 13670, 13673, 13677, 13677, 13679, 13682, 13685, 13687, 13687, 13689,
+        13689, 13689, 13691, 13693, 13693, 13693, 13695, 13695, 13695, 13695, 13695, 13695, 13697, 13697, 13697, 13697, 13697, 13697, 13699, 13701,
+        13701, 13701, 13703, 13703, 13705, 13705, 13707, 13709, 13709, 13718, 13718, 13718, 13721, 13721, 13723, 13723, 13723, 13725, 13728, 13728,
+        13730, 13730, 13730, 13730, 13730, 13730, 13730, 13730, 13732, 13732, 13732, 13732, 13732, 13735, 13742, 13746, 13746, 13748, 13748, 13751,
+        13751, 13763, 13765, 13765, 13765, 13765, 13765, 13765, 13767, 13767, 13769, 13771, 13773, 13773, 13773, 13776, 13776, 13778, 13778, 13779,
+        13779, 13781, 13782, 13782, 13782, 13784, 13785, 13785, 13798, 13798, 13800, 13800, 13801, 13801, 13803, 13803, 13805, 13807, 13807, 13809,
+        13809, 13811, 13811, 13811, 13811, 13811, 13813, 13813, 13813, 13813, 13813, 13813, 13815, 13815, 13818, 13818, 13818, 13820, 13823, 13825,
+        13827, 13829, 13829, 13831, 13831, 13831, 13831, 13831, 13831, 13833, 13833, 13833, 13833, 13833, 13833, 13833, 13833, 13833, 13833, 13833,
+        13836, 13836, 13836, 13838, 13842, 13842, 13846, 13852, 13852, 13852, 13852, 13852, 13854, 13856, 13856, 13856, 13856, 13856, 13856, 13856,
+        13856, 13858, 13860, 13860, 13860, 13862, 13865, 13867, 13867, 13867, 13867, 13869, 13869, 13871, 13871, 13871, 13871, 13871, 13871, 13871,
+        13871, 13871, 13871, 13873, 13875, 13875, 13875, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876,
+        13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13876, 13878, 13878, 13881, 13881, 13883, 13885, 13885, 13885, 13887, 13887, 13887,
+        13887, 13889, 13889, 13889, 13889, 13889, 13891, 13891, 13891, 13891, 13891, 13893, 13895, 13904, 13904, 13904, 13906, 13906, 13906, 13906,
+        13906, 13908, 13911, 13913, 13915, 13915, 13915, 13924, 13924, 13924, 13924, 13924, 13924, 13926, 13928, 13928, 13928, 13928, 13928, 13928,
+        13928, 13928, 13930, 13934, 13934, 13936, 13938, 13940, 13940, 13940, 13940, 13942, 13942, 13942, 13942, 13942, 13944, 13944, 13944, 13944,
+        13944, 13946, 13946, 13946, 13946, 13949, 13949, 13952, 13952, 13952, 13952, 13952, 13954, 13956, 13958, 13960, 13960, 13960, 13960, 13962,
+        13965, 13967, 13967, 13969, 13972, 13974, 13974, 13976, 13978, 13981, 13985, 13985, 13987, 13987, 13989, 13989, 13989, 13989, 13993, 13993,
+        13995, 13995, 13995, 13995, 13995, 13995, 13995, 13997, 13999, 13999, 13999, 13999, 14001, 14003, 14003, 14005, 14008, 14011, 14021, 14021,
+        14023, 14025, 14025, 14025, 14025, 14025, 14027, 14027, 14028, 14028, 14028, 14028, 14028, 14028, 14028, 14028, 14028, 14028, 14028, 14028,
+        14028, 14030, 14033, 14033, 14033, 14033, 14033, 14033, 14036, 14036, 14036, 14036, 14036, 14036, 14036, 14038, 14038, 14038, 14038, 14038,
+        14038, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14040, 14044, 14044, 14044, 14044,
+        14044, 14044, 14044, 14044, 14046, 14046, 14046, 14048, 14048, 14052, 14052, 14054, 14054, 14055, 14055, 14055, 14055, 14055, 14055, 14055,
+        14055, 14055, 14058, 14058, 14058, 14058, 14058, 14058, 14058, 14058, 14058, 14058, 14058, 14060, 14061, 14061, 14061, 14061, 14061, 14061,
+        14061, 14061, 14063, 14063, 14069, 14071, 14071, 14071, 14071, 14071, 14071, 14072, 14073, 14073, 14073, 14073, 14075, 14075, 14075, 14077,
+        14077, 14077, 14077, 14077, 14077, 14077, 14078, 14078, 14078, 14078, 14078, 14080, 14082, 14082, 14082, 14082, 14084, 14086, 14086, 14086,
+        14086, 14088, 14088, 14090, 14092, 14094, 14097, 14097, 14100, 14100, 14102, 14102, 14102, 14102, 14102, 14102, 14102, 14104, 14104, 14104,
+        14104, 14104, 14104, 14108, 14111, 14115, 14115, 14115, 14115, 14120, 14122, 14122, 14122, 14124, 14129, 14131, 14134, 14137, 14139, 14139,
+        14141, 14141, 14144, 14144, 14144, 14144, 14144, 14146, 14146, 14148, 14148, 14148, 14148, 14148, 14150, 14150, 14155, 14157, 14157, 14160,
+        14162, 14165, 14167, 14167, 14169, 14171, 14171, 14171, 14171, 14180, 14180, 14182, 14183, 14185, 14185, 14185, 14185, 14185, 14185, 14188,
+        14188, 14188, 14188, 14189, 14189, 14189, 14189, 14189, 14191, 14191, 14191, 14191, 14191, 14191, 14191, 14191, 14191, 14191, 14191, 14191,
+        14191, 14194, 14194, 14196, 14196, 14196, 14196, 14198, 14198, 14198, 14198, 14200, 14200, 14200, 14202, 14204, 14204, 14207, 14207, 14207,
+        14209, 14209, 14209, 14212, 14212, 14217, 14219, 14219, 14219, 14219, 14219, 14219, 14219, 14219, 14219, 14219, 14219, 14223, 14225, 14225,
+        14227, 14227, 14227, 14227, 14227, 14227, 14227, 14229, 14229, 14231, 14231, 14231, 14233, 14237, 14237, 14237, 14237, 14237, 14237, 14239,
+        14239, 14239, 14241, 14241, 14242, 14242, 14245, 14249, 14255, 14255, 14257, 14257, 14257, 14257, 14257, 14257, 14259, 14259, 14260, 14262,
+        14262, 14262, 14262, 14262, 14262, 14262, 14264, 14266, 14266, 14268, 14270, 14270, 14270, 14272, 14275, 14277, 14277, 14277, 14279, 14279,
+        14281, 14281, 14281, 14281, 14281, 14283, 14283, 14283, 14283, 14285, 14285, 14287, 14289, 14289, 14289, 14289, 14289, 14289, 14292, 14292,
+        14294, 14294, 14294, 14294, 14297, 14299, 14299, 14299, 14299, 14299, 14299, 14300, 14301, 14301, 14301, 14301, 14301, 14301, 14301, 14302,
+        14302, 14302, 14302, 14302, 14302, 14302, 14302, 14302, 14304, 14304, 14304, 14304, 14304, 14304, 14304, 14304, 14304, 14304, 14304, 14304,
+        14306, 14308, 14308, 14308, 14308, 14308, 14311, 14311, 14311, 14311, 14313, 14315, 14315, 14318, 14318, 14320, 14320, 14322, 14322, 14325,
+        14325, 14327, 14327, 14327, 14327, 14329, 14329, 14329, 14331, 14331, 14331, 14333, 14333, 14335, 14335, 14339, 14339, 14339, 14341, 14341,
+        14343, 14346, 14346, 14348, 14348, 14348, 14350, 14350, 14353, 14353, 14353, 14355, 14358, 14358, 14358, 14360, 14360, 14360, 14360, 14360,
+        14362, 14362, 14362, 14364, 14367, 14367, 14367, 14369, 14369, 14371, 14371, 14371, 14371, 14371, 14371, 14371, 14374, 14374, 14374, 14374,
+        14374, 14374, 14374, 14374, 14374, 14374, 14376, 14378, 14378, 14380, 14380, 14380, 14382, 14382, 14382, 14382, 14382, 14382, 14382, 14382,
+        14382, 14382, 14382, 14382, 14383, 14383, 14383, 14385, 14387, 14387, 14390, 14390, 14390, 14390, 14392, 14393, 14393, 14393, 14393, 14393,
+        14397, 14397, 14397, 14397, 14397, 14397, 14399, 14401, 14401, 14401, 14403, 14403, 14404, 14404, 14404, 14404, 14404, 14404, 14404, 14406,
+        14408, 14410, 14410, 14410, 14410, 14410, 14410, 14410, 14410, 14410, 14410, 14410, 14412, 14412, 14413, 14413, 14416, 14418, 14418, 14420,
+        14420, 14420, 14420, 14420, 14420, 14420, 14420, 14422, 14422, 14422, 14422, 14426, 14428, 14428, 14428, 14428, 14428, 14430, 14430, 14433,
+        14437, 14441, 14444, 14452, 14452, 14455, 14455, 14455, 14458, 14458, 14458, 14458, 14458, 14458, 14458, 14460, 14460, 14460, 14460, 14460,
+        14460, 14460, 14460, 14460, 14462, 14462, 14462, 14462, 14462, 14464, 14464, 14464, 14466, 14466, 14470, 14474, 14475, 14475, 14475, 14477,
+        14477, 14479, 14483, 14483, 14483, 14485, 14485, 14485, 14485, 14485, 14487, 14487, 14489, 14491, 14494, 14496, 14496, 14499, 14501, 14501,
+        14504, 14506, 14508, 14508, 14508, 14508, 14508, 14508, 14508, 14508, 14508, 14510, 14512, 14512, 14512, 14512, 14514, 14517, 14517, 14519,
+        14519, 14519, 14519, 14519, 14519, 14519, 14519, 14521, 14523, 14525, 14529, 14529, 14529, 14529, 14532, 14532, 14532, 14532, 14534, 14534,
+        14534, 14534, 14534, 14537, 14537, 14539, 14539, 14539, 14539, 14541, 14541, 14543, 14546, 14546, 14546, 14546, 14546, 14546, 14548, 14549,
+        14549, 14549, 14549, 14551, 14551, 14553, 14553, 14553, 14555, 14555, 14555, 14557, 14561, 14561, 14561, 14561, 14563, 14567, 14567, 14567,
+        14567, 14570, 14573, 14573, 14573, 14573, 14573, 14578, 14578, 14578, 14578, 14578, 14580, 14580, 14582, 14582, 14582, 14584, 14584, 14587,
+        14587, 14589, 14589, 14589, 14589, 14591, 14591, 14592, 14594, 14596, 14599, 14599, 14599, 14599, 14601, 14601, 14604, 14604, 14604, 14604,
+        14604, 14604, 14604, 14604, 14611, 14611, 14611, 14611, 14613, 14613, 14613, 14613, 14613, 14613, 14613, 14613, 14613, 14613, 14613, 14613,
+        14613, 14613, 14615, 14616, 14616, 14616, 14617, 14617, 14618, 14618, 14618, 14618, 14618, 14618, 14618, 14620, 14620, 14620, 14620, 14620,
+        14622, 14622, 14625, 14625, 14625, 14625, 14628, 14628, 14630, 14630, 14630, 14630, 14630, 14630, 14630, 14632, 14632, 14632, 14632, 14632,
+        14632, 14632, 14632, 14634, 14639, 14639, 14639, 14639, 14639, 14639, 14639, 14639, 14642, 14642, 14645, 14645, 14645, 14645, 14658, 14660,
+        14662, 14662, 14662, 14662, 14662, 14662, 14665, 14665, 14665, 14667, 14669, 14669, 14669, 14669, 14669, 14669, 14671, 14673, 14673, 14673,
+        14677, 14677, 14677, 14677, 14680, 14682, 14682, 14684, 14684, 14684, 14684, 14684, 14686, 14686, 14686, 14686, 14686, 14686, 14688, 14688,
+        14690, 14695, 14695, 14697, 14701, 14703, 14703, 14705, 14709, 14709, 14711, 14711, 14714, 14714, 14714, 14716, 14718, 14720, 14720, 14720,
+        14722, 14722, 14725, 14731, 14733, 14733, 14737, 14739, 14744, 14744, 14744, 14746, 14748, 14752, 14754, 14754, 14756, 14756, 14756, 14756,
+        14756, 14756, 14756, 14758, 14758, 14760, 14760, 14762, 14764, 14764, 14766, 14769, 14772, 14775, 14781, 14781, 14781, 14781, 14781, 14784,
+        14787, 14787, 14787, 14787, 14788, 14788, 14788, 14788, 14788, 14788, 14788, 14793, 14793, 14795, 14798, 14802, 14802, 14807, 14809, 14809,
+        14812, 14813, 14815, 14815, 14815, 14815, 14815, 14815, 14817, 14819, 14819, 14819, 14821, 14821, 14823, 14823, 14825, 14825, 14827, 14829,
+        14829, 14832, 14834, 14834, 14839, 14841, 14841, 14843, 14843, 14843, 14843, 14843, 14857, 14859, 14859, 14859, 14859, 14860, 14860, 14860,
+        14860, 14860, 14864, 14864, 14866, 14869, 14869, 14871, 14871, 14873, 14873, 14877, 14877, 14879, 14881, 14885, 14887, 14888, 14892, 14895,
+        14895, 14895, 14898, 14900, 14900, 14903, 14903, 14905, 14905, 14905, 14905, 14905, 14905, 14907, 14910, 14910, 14922, 14925, 14925, 14936,
+        14939, 14943, 14946, 14950, 14954, 14954, 14954, 14954, 14954, 14954, 14954, 14954, 14954, 14956, 14958, 14960, 14960, 14960, 14960, 14960,
+        14960, 14961, 14961, 14961, 14963, 14963, 14965, 14965, 14965, 14967, 14967, 14969, 14969, 14971, 14973, 14973, 14977, 14977, 14979, 14979,
+        14983, 14985, 14990, 14990, 14990, 14990, 14990, 14992, 14994, 14996, 14996, 14996, 15006, 15008, 15008, 15008, 15008, 15008, 15011, 15014,
+        15014, 15014, 15016, 15016, 15016, 15016, 15016, 15016, 15018, 15020, 15020, 15020, 15022, 15022, 15022, 15022, 15022, 15024, 15024, 15027,
+        15030, 15033, 15033, 15033, 15035, 15037, 15039, 15041, 15042, 15042, 15042, 15042, 15044, 15044, 15044, 15044, 15044, 15044, 15044, 15044,
+        15044, 15044, 15044, 15046, 15046, 15046, 15048, 15051, 15051, 15053, 15053, 15053, 15053, 15055, 15057, 15057, 15057, 15059, 15059, 15059,
+        15061, 15061, 15061, 15061, 15063, 15063, 15067, 15067, 15069, 15069, 15071, 15071, 15072, 15072, 15072, 15073, 15075, 15077, 15079, 15079,
+        15079, 15087, 15087, 15090, 15093, 15093, 15093, 15093, 15093, 15093, 15097, 15097, 15097, 15097, 15097, 15097, 15103, 15103, 15103, 15105,
+        15105, 15105, 15108, 15108, 15108, 15108, 15110, 15110, 15110, 15110, 15110, 15112, 15114, 15114, 15114, 15116, 15118, 15118, 15121, 15121,
+        15124, 15127, 15129, 15129, 15129, 15131, 15131, 15131, 15131, 15131, 15133, 15133, 15133, 15133, 15133, 15133, 15133, 15135, 15135, 15135,
+        15135, 15137, 15137, 15137, 15139, 15139, 15139, 15139, 15142, 15143, 15143, 15145, 15147, 15147, 15149, 15149, 15151, 15153, 15153, 15155,
+        15155, 15155, 15155, 15155, 15159, 15161, 15161, 15163, 15163, 15163, 15163, 15163, 15163, 15163, 15165, 15165, 15165, 15168, 15168, 15170,
+        15174, 15174, 15174, 15176, 15178, 15180, 15182, 15185, 15187, 15187, 15189, 15191, 15191, 15193, 15195, 15197, 15197, 15201, 15201, 15202,
+        15205, 15205, 15205, 15205, 15208, 15208, 15210, 15212, 15212, 15215, 15215, 15217, 15217, 15217, 15217, 15219, 15221, 15221, 15223, 15228,
+        15228, 15228, 15231, 15231, 15231, 15233, 15233, 15233, 15233, 15233, 15235, 15235, 15235, 15235, 15237, 15237, 15237, 15237, 15237, 15237,
+        15239, 15241, 15241, 15244, 15246, 15248, 15248, 15248, 15248, 15248, 15259, 15259, 15259, 15259, 15259, 15259, 15259, 15261, 15261, 15263,
+        15263, 15263, 15263, 15263, 15265, 15265, 15265, 15265, 15265, 15267, 15267, 15267, 15267, 15267, 15267, 15269, 15271, 15273, 15273, 15276,
+        15276, 15278, 15278, 15278, 15278, 15278, 15278, 15278, 15278, 15278, 15278, 15278, 15280, 15282, 15285, 15288, 15290, 15290, 15292, 15294,
+        15297, 15297, 15297, 15297, 15299, 15301, 15301, 15301, 15301, 15301, 15301, 15301, 15301, 15301, 15301, 15301, 15303, 15303, 15305, 15305,
+        15305, 15305, 15305, 15307, 15307, 15307, 15307, 15307, 15309, 15309, 15311, 15313, 15313, 15314, 15317, 15319, 15319, 15319, 15321, 15321,
+        15321, 15323, 15323, 15325, 15325, 15325, 15325, 15325, 15325, 15327, 15327, 15327, 15327, 15327, 15327, 15330, 15332, 15332, 15332, 15332,
+        15334, 15334, 15334, 15336, 15336, 15339, 15339, 15339, 15339, 15339, 15341, 15341, 15341, 15343, 15345, 15347, 15347, 15347, 15349, 15349,
+        15349, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15351, 15353, 15353, 15353, 15355, 15358, 15358, 15358,
+        15362, 15366, 15366, 15366, 15369, 15369, 15369, 15369, 15369, 15372, 15375, 15375, 15375, 15375, 15375, 15375, 15377, 15377, 15379, 15379,
+        15379, 15381, 15381, 15381, 15383, 15385, 15385, 15389, 15391, 15394, 15397, 15397, 15397, 15397, 15397, 15400, 15400, 15400, 15400, 15400,
+        15400, 15400, 15400, 15400, 15403, 15405, 15405, 15405, 15405, 15405, 15407, 15407, 15409, 15409, 15409, 15412, 15412, 15412, 15412, 15412,
+        15412, 15414, 15414, 15414, 15414, 15414, 15414, 15414, 15414, 15414, 15414, 15416, 15416, 15416, 15416, 15417, 15419, 15419, 15419, 15419,
+        15419, 15419, 15419, 15419, 15421, 15421, 15421, 15421, 15421, 15423, 15425, 15425, 15427, 15427, 15429, 15429, 15432, 15432, 15432, 15432,
+        15439, 15439, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15444, 15446, 15446, 15446, 15446, 15446,
+        15446, 15448, 15450, 15452, 15452, 15452, 15452, 15452, 15452, 15454, 15454, 15455, 15455, 15455, 15457, 15457, 15457, 15459, 15459, 15459,
+        15461, 15461, 15461, 15461, 15461, 15461, 15461, 15461, 15461, 15461, 15463, 15464, 15464, 15464, 15464, 15464, 15464, 15464, 15464, 15464,
+        15464, 15464, 15464, 15464, 15464, 15464, 15466, 15468, 15468, 15468, 15470, 15470, 15470, 15470, 15470, 15470, 15470, 15470, 15472, 15472,
+        15472, 15472, 15473, 15473, 15473, 15473, 15473, 15473, 15473, 15473, 15473, 15475, 15475, 15475, 15475, 15475, 15475, 15475, 15475, 15475,
+        15475, 15475, 15475, 15475, 15475, 15476, 15476, 15476, 15476, 15476, 15478, 15478, 15478, 15478, 15480, 15480, 15480, 15480, 15482, 15482,
+        15482, 15482, 15485, 15485, 15485, 15487, 15487, 15487, 15491, 15491, 15491, 15493, 15493, 15493, 15493, 15493, 15496, 15496, 15496, 15496,
+        15496, 15498, 15498, 15498, 15498, 15498, 15498, 15498, 15500, 15500, 15500, 15500, 15504, 15504, 15506, 15506, 15506, 15510, 15512, 15514,
+        15516, 15516, 15518, 15518, 15518, 15518, 15518, 15518, 15520, 15520, 15520, 15520, 15520, 15522, 15522, 15522, 15522, 15522, 15527, 15527,
+        15527, 15527, 15527, 15527, 15527, 15527, 15529, 15531, 15531, 15531, 15531, 15531, 15531, 15531, 15531, 15533, 15533, 15533, 15533, 15533,
+        15533, 15533, 15533, 15533, 15533, 15533, 15533, 15535, 15537, 15537, 15539, 15541, 15541, 15543, 15543, 15543, 15543, 15545, 15545, 15545,
+        15545, 15545, 15545, 15547, 15547, 15547, 15547, 15547, 15548, 15548, 15548, 15548, 15548, 15548, 15550, 15550, 15560, 15563, 15565, 15567,
+        15571, 15574, 15575, 15578, 15578, 15580, 15584, 15586, 15590, 15592, 15595, 15598, 15601, 15601, 15603, 15605, 15608, 15610, 15610, 15614,
+        15616, 15616, 15616, 15621, 15625, 15625, 15628, 15628, 15630, 15632, 15634, 15648, 15650, 15655, 15655, 15655, 15657, 15660, 15660, 15660,
+        15660, 15663, 15665, 15668, 15668, 15668, 15668, 15670, 15670, 15670, 15670, 15674, 15677, 15679, 15681, 15683, 15683, 15685, 15685, 15688,
+        15691, 15691, 15691, 15691, 15691, 15691, 15691, 15691, 15693, 15695, 15698, 15698, 15698, 15700, 15700, 15700, 15700, 15700, 15700, 15700,
+        15700, 15702, 15704, 15707, 15710, 15712, 15716, 15716, 15716, 15716, 15716, 15716, 15718, 15720, 15720, 15720, 15722, 15722, 15724, 15726,
+        15726, 15726, 15726, 15726, 15728, 15730, 15735, 15735, 15739, 15739, 15742, 15748, 15750, 15752, 15752, 15752, 15754, 15756, 15756, 15758,
+        15758, 15758, 15758, 15763, 15765, 15771, 15773, 15775, 15789, 15791, 15793, 15796, 15798, 15798, 15800, 15800, 15800, 15800, 15802, 15804,
+        15807, 15809, 15813, 15815, 15818, 15818, 15819, 15820, 15820, 15822, 15824, 15824, 15827, 15829, 15832, 15834, 15834, 15834, 15837, 15837,
+        15837, 15840, 15842, 15844, 15844, 15847, 15847, 15849, 15851, 15851, 15853, 15855, 15857, 15859, 15859, 15861, 15862, 15862, 15866, 15866,
+        15866, 15871, 15872, 15872, 15875, 15875, 15875, 15875, 15875, 15877, 15877, 15881, 15884, 15884, 15884, 15884, 15885, 15887, 15889, 15889,
+        15889, 15891, 15891, 15891, 15891, 15892, 15893, 15893, 15897, 15897, 15899, 15902, 15902, 15904, 15904, 15904, 15904, 15904, 15906, 15909,
+        15909, 15911, 15913, 15915, 15915, 15915, 15918, 15918, 15918, 15918, 15918, 15921, 15921, 15924, 15924, 15928, 15928, 15930, 15930, 15930,
+        15930, 15932, 15932, 15932, 15935, 15935, 15935, 15935, 15935, 15935, 15935, 15935, 15935, 15938, 15938, 15941, 15941, 15943, 15949, 15951,
+        15955, 15955, 15955, 15955, 15957, 15957, 15957, 15959, 15961, 15963, 15965, 15968, 15970, 15972, 15972, 15972, 15972, 15975, 15975, 15978,
+        15978, 15981, 15981, 15981, 15981, 15985, 15985, 15985, 15985, 15985, 15988, 15988, 15988, 15988, 15991, 15991, 15991, 15991, 15991, 15991,
+        15991, 15991, 15991, 15991, 15991, 15993, 15995, 15997, 16001, 16005, 16007, 16007, 16007, 16019, 16021, 16023, 16023, 16025, 16027, 16029,
+        16033, 16035, 16035, 16035, 16035, 16035, 16035, 16037, 16040, 16040, 16042, 16044, 16044, 16044, 16044, 16044, 16048, 16050, 16052, 16054,
+        16054, 16056, 16056, 16058, 16060, 16068, 16068, 16068, 16070, 16070, 16072, 16074, 16084, 16087, 16087, 16087, 16087, 16087, 16087, 16087,
+        16087, 16087, 16087, 16087, 16088, 16088, 16088, 16088, 16094, 16094, 16094, 16094, 16094, 16094, 16094, 16098, 16100, 16102, 16104, 16104,
+        16106, 16108, 16110, 16113, 16113, 16115, 16119, 16122, 16124, 16125, 16127, 16129, 16132, 16136, 16138, 16138, 16138, 16140, 16142, 16145,
+        16149, 16151, 16153, 16155, 16159, 16161, 16164, 16169, 16174, 16176, 16183, 16190, 16195, 16197, 16197, 16199, 16199, 16199, 16199, 16199,
+        16202, 16202, 16202, 16202, 16202, 16202, 16202, 16206, 16206, 16206, 16206, 16207, 16210, 16210, 16211, 16211, 16214, 16219, 16221, 16221,
+        16221, 16221, 16222, 16222, 16222, 16222, 16222, 16222, 16222, 16224, 16226, 16226, 16228, 16230, 16230, 16232, 16232, 16232, 16234, 16234,
+        16234, 16234, 16237, 16237, 16237, 16239, 16239, 16239, 16239, 16239, 16239, 16241, 16241, 16244, 16245, 16245, 16245, 16247, 16247, 16247,
+        16247, 16247, 16247, 16249, 16249, 16249, 16249, 16251, 16254, 16257, 16259, 16261, 16261, 16261, 16261, 16261, 16261, 16261, 16261, 16263,
+        16263, 16264, 16266, 16266, 16266, 16266, 16266, 16266, 16268, 16268, 16270, 16273, 16275, 16275, 16277, 16279, 16282, 16282, 16282, 16284,
+        16284, 16287, 16287, 16287, 16287, 16287, 16289, 16296, 16296, 16296, 16296, 16296, 16297, 16297, 16297, 16297, 16297, 16297, 16297, 16302,
+        16305, 16307, 16307, 16314, 16314, 16314, 16316, 16316, 16316, 16316, 16316, 16318, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
+        16323, 16323, 16326, 16330, 16330, 16330, 16330, 16332, 16332, 16332, 16334, 16334, 16334, 16334, 16334, 16334, 16334, 16336, 16336, 16336,
+        16336, 16336, 16338, 16338, 16338, 16338, 16338, 16343, 16343, 16343, 16343, 16345, 16345, 16347, 16349, 16351, 16351, 16353, 16355, 16355,
+        16357, 16357, 16359, 16359, 16359, 16359, 16359, 16361, 16363, 16366, 16366, 16366, 16368, 16368, 16368, 16368, 16368, 16368, 16370, 16372,
+        16372, 16372, 16374, 16374, 16376, 16376, 16376, 16376, 16378, 16378, 16378, 16378, 16378, 16380, 16382, 16382, 16382, 16382, 16382, 16382,
+        16385, 16387, 16387, 16389, 16391, 16391, 16391, 16391, 16391, 16391, 16392, 16395, 16396, 16398, 16401, 16405, 16407, 16407, 16407, 16407,
+        16409, 16409, 16409, 16409, 16411, 16411, 16411, 16411, 16413, 16413, 16414, 16414, 16414, 16414, 16416, 16416, 16416, 16416, 16418, 16418,
+        16421, 16421, 16421, 16421, 16421, 16423, 16423, 16423, 16423, 16423, 16423, 16423, 16425, 16425, 16425, 16425, 16425, 16428, 16430, 16432,
+        16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16432, 16434, 16434, 16434, 16434, 16434, 16436, 16436,
+        16436, 16436, 16436, 16436, 16436, 16436, 16436, 16436, 16436, 16436, 16437, 16439, 16439, 16441, 16441, 16443, 16443, 16443, 16443, 16445,
+        16445, 16448, 16448, 16448, 16452, 16452, 16452, 16452, 16452, 16455, 16455, 16455, 16456, 16456, 16458, 16458, 16458, 16459, 16459, 16460,
+        16462, 16462, 16462, 16462, 16462, 16463, 16463, 16463, 16463, 16463, 16465, 16467, 16470, 16472, 16472, 16472, 16474, 16474, 16474, 16474,
+        16474, 16474, 16474, 16474, 16476, 16476, 16476, 16478, 16478, 16478, 16480, 16480, 16480, 16480, 16483, 16483, 16485, 16488, 16488, 16488,
+        16488, 16488, 16488, 16488, 16488, 16488, 16488, 16490, 16490, 16490, 16490, 16494, 16496, 16502, 16502, 16504, 16507, 16509, 16513, 16515,
+        16517, 16517, 16522, 16524, 16526, 16529, 16531, 16531, 16534, 16534, 16534, 16537, 16537, 16537, 16537, 16539, 16539, 16542, 16548, 16550,
+        16550, 16552, 16556, 16561, 16563, 16563, 16563, 16563, 16563, 16563, 16565, 16565, 16565, 16565, 16568, 16571, 16571, 16573, 16575, 16575,
+        16577, 16577, 16577, 16579, 16579, 16579, 16581, 16583, 16583, 16583, 16583, 16583, 16585, 16585, 16585, 16585, 16587, 16587, 16589, 16589,
+        16589, 16589, 16589, 16589, 16589, 16589, 16589, 16589, 16591, 16591, 16596, 16598, 16600, 16602, 16606, 16608, 16608, 16608, 16608, 16608,
+        16608, 16608, 16610, 16613, 16616, 16619, 16619, 16623, 16628, 16628, 16630, 16632, 16634, 16634, 16637, 16637, 16637, 16637, 16637, 16637,
+        16640, 16642, 16642, 16645, 16645, 16648, 16651, 16653, 16653, 16653, 16653, 16655, 16659, 16662, 16664, 16664, 16664, 16666, 16666, 16666,
+        16671, 16672, 16674, 16676, 16676, 16678, 16681, 16684, 16684, 16684, 16684, 16684, 16686, 16686, 16686, 16686, 16686, 16686, 16686, 16686,
+        16686, 16686, 16686, 16687, 16690, 16690, 16693, 16693, 16695, 16697, 16697, 16697, 16698, 16698, 16698, 16698, 16698, 16698, 16698, 16700,
+        16700, 16701, 16701, 16703, 16705, 16705, 16705, 16708, 16710, 16710, 16712, 16712, 16714, 16717, 16731, 16734, 16734, 16734, 16734, 16734,
+        16734, 16734, 16734, 16734, 16734, 16737, 16737, 16737, 16741, 16745, 16747, 16747, 16749, 16749, 16753, 16755, 16757, 16757, 16757, 16760,
+        16762, 16765, 16768, 16768, 16768, 16771, 16771, 16773, 16773, 16773, 16775, 16775, 16777, 16777, 16777, 16779, 16779, 16779, 16781, 16781,
+        16783, 16785, 16785, 16791, 16791, 16796, 16796, 16799, 16801, 16809, 16812, 16814, 16816, 16818, 16820, 16820, 16820, 16822, 16822, 16824,
+        16824, 16827, 16827, 16828, 16828, 16828, 16831, 16834, 16834, 16842, 16844, 16844, 16844, 16846, 16846, 16848, 16848, 16851, 16854, 16857,
+        16868, 16870, 16873, 16875, 16879, 16879, 16879, 16879, 16879, 16879, 16882, 16885, 16887, 16887, 16887, 16889, 16891, 16891, 16893, 16895,
+        16897, 16899, 16901, 16903, 16903, 16905, 16909, 16909, 16910, 16912, 16912, 16912, 16912, 16914, 16914, 16915, 16915, 16915, 16915, 16917,
+        16917, 16919, 16919, 16919, 16921, 16923, 16923, 16923, 16925, 16928, 16930, 16932, 16932, 16932, 16932, 16932, 16933, 16933, 16933, 16933,
+        16933, 16933, 16933, 16935, 16935, 16935, 16935, 16937, 16937, 16937, 16940, 16940, 16940, 16942, 16942, 16942, 16944, 16946, 16946, 16946,
+        16946, 16948, 16950, 16952, 16952, 16952, 16954, 16954, 16954, 16954, 16954, 16954, 16954, 16956, 16956, 16958, 16960, 16960, 16960, 16960,
+        16962, 16962, 16962, 16962, 16964, 16967, 16967, 16967, 16968, 16968, 16968, 16970, 16970, 16972, 16972, 16972, 16972, 16972, 16974, 16974,
+        16974, 16974, 16974, 16976, 16976, 16978, 16978, 16978, 16978, 16978, 16980, 16980, 16980, 16981, 16982, 16984, 16984, 16984, 16984, 16986,
+        16986, 16986, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16988, 16990, 16990, 16992, 16992, 16992,
+        16992, 16994, 16996, 17001, 17002, 17002, 17002, 17002, 17002, 17002, 17002, 17005, 17005, 17007, 17007, 17007, 17007, 17007, 17007, 17007,
+        17007, 17009, 17009, 17011, 17014, 17014, 17016, 17019, 17022, 17022, 17022, 17026, 17026, 17026, 17026, 17029, 17029, 17031, 17033, 17033,
+        17035, 17035, 17035, 17035, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17037, 17042, 17042, 17042, 17042,
+        17042, 17044, 17047, 17047, 17047, 17048, 17049, 17049, 17049, 17049, 17049, 17051, 17054, 17056, 17056, 17058, 17061, 17064, 17064, 17064,
+        17065, 17067, 17071, 17071, 17071, 17071, 17071, 17073, 17075, 17075, 17078, 17080, 17082, 17082, 17082, 17082, 17084, 17086, 17086, 17092,
+        17095, 17095, 17098, 17098, 17100, 17101, 17101, 17101, 17101, 17103, 17107, 17112, 17112, 17112, 17114, 17114, 17116, 17116, 17116, 17116,
+        17116, 17122, 17126, 17126, 17126, 17126, 17128, 17131, 17134, 17134, 17136, 17138, 17142, 17146, 17148, 17154, 17157, 17161, 17163, 17165,
+        17167, 17167, 17169, 17169, 17172, 17176, 17176, 17176, 17181, 17185, 17185, 17185, 17185, 17187, 17188, 17190, 17195, 17195, 17195, 17198,
+        17201, 17203, 17203, 17205, 17205, 17208, 17210, 17215, 17217, 17220, 17224, 17226, 17226, 17233, 17240, 17245, 17251, 17253, 17255, 17257,
+        17259, 17260, 17260, 17260, 17260, 17260, 17263, 17263, 17267, 17270, 17275, 17275, 17277, 17279, 17281, 17284, 17286, 17290, 17294, 17294,
+        17296, 17299, 17301, 17306, 17311, 17313, 17313, 17316, 17316, 17318, 17318, 17318, 17320, 17321, 17324, 17324, 17326, 17326, 17326, 17329,
+        17332, 17332, 17335, 17335, 17338, 17338, 17338, 17338, 17338, 17341, 17343, 17343, 17343, 17343, 17343, 17343, 17343, 17343, 17343, 17343,
+        17343, 17343, 17361, 17361, 17361, 17363, 17365, 17365, 17365, 17365, 17365, 17365, 17365, 17365, 17365, 17367, 17369, 17369, 17369, 17369,
+        17369, 17369, 17369, 17369, 17369, 17372, 17372, 17372, 17372, 17374, 17374, 17374, 17374, 17377, 17377, 17379, 17380, 17380, 17382, 17384,
+        17386, 17386, 17388, 17390, 17393, 17396, 17398, 17400, 17400, 17400, 17400, 17400, 17402, 17402, 17404, 17404, 17406, 17408, 17408, 17408,
+        17410, 17410, 17413, 17413, 17416, 17416, 17418, 17418, 17419, 17419, 17421, 17421, 17421, 17421, 17423, 17423, 17425, 17425, 17425, 17425,
+        17425, 17425, 17425, 17427, 17427, 17429, 17431, 17431, 17431, 17431, 17434, 17438, 17440, 17442, 17442, 17442, 17442, 17442, 17442, 17444,
+        17446, 17446, 17446, 17448, 17448, 17456, 17456, 17460, 17463, 17463, 17463, 17465, 17468, 17468, 17468, 17468, 17469, 17469, 17471, 17471,
+        17471, 17475, 17477, 17477, 17478, 17480, 17480, 17482, 17484, 17487, 17489, 17489, 17491, 17491, 17491, 17493, 17493, 17493, 17493, 17493,
+        17493, 17493, 17495, 17495, 17495, 17495, 17498, 17498, 17498, 17498, 17501, 17503, 17504, 17504, 17504, 17504, 17504, 17504, 17504, 17504,
+        17505, 17505, 17505, 17505, 17505, 17505, 17507, 17507, 17509, 17511, 17511, 17511, 17511, 17511, 17511, 17513, 17513, 17517, 17527, 17527,
+        17527, 17529, 17529, 17531, 17533, 17533, 17533, 17535, 17536, 17536, 17536, 17539, 17539, 17539, 17542, 17542, 17542, 17545, 17547, 17549,
+        17551, 17553, 17556, 17556, 17558, 17560, 17560, 17562, 17564, 17569, 17573, 17574, 17576, 17576, 17576, 17578, 17578, 17582, 17584, 17584,
+        17586, 17589, 17591, 17591, 17593, 17596, 17596, 17596, 17596, 17601, 17603, 17603, 17603, 17605, 17608, 17608, 17610, 17610, 17610, 17613,
+        17616, 17619, 17619, 17619, 17621, 17624, 17624, 17626, 17626, 17628, 17628, 17630, 17630, 17632, 17632, 17635, 17637, 17640, 17643, 17645,
+        17645, 17647, 17647, 17647, 17649, 17651, 17651, 17651, 17653, 17653, 17655, 17655, 17655, 17655, 17655, 17657, 17658, 17658, 17658, 17658,
+        17659, 17659, 17661, 17661, 17661, 17663, 17665, 17665, 17668, 17668, 17668, 17668, 17668, 17668, 17668, 17670, 17670, 17670, 17670, 17671,
+        17673, 17673, 17675, 17677, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679, 17679,
+        17679, 17679, 17680, 17682, 17684, 17686, 17686, 17686, 17686, 17686, 17689, 17689, 17689, 17689, 17689, 17691, 17693, 17693, 17695, 17695,
+        17699, 17702, 17704, 17704, 17706, 17706, 17708, 17708, 17708, 17708, 17710, 17714, 17714, 17714, 17716, 17718, 17722, 17722, 17722, 17722,
+        17725, 17727, 17727, 17727, 17729, 17729, 17732, 17734, 17736, 17736, 17736, 17736, 17739, 17739, 17739, 17739, 17739, 17740, 17743, 17743,
+        17745, 17745, 17745, 17747, 17747, 17749, 17752, 17755, 17758, 17758, 17762, 17764, 17766, 17766, 17768, 17771, 17771, 17771, 17773, 17774,
+        17774, 17774, 17774, 17776, 17778, 17780, 17780, 17785, 17789, 17795, 17798, 17801, 17803, 17807, 17810, 17810, 17813, 17815, 17817, 17820,
+        17824, 17824, 17826, 17831, 17833, 17835, 17838, 17839, 17843, 17843, 17843, 17846, 17848, 17848, 17851, 17854, 17854, 17854, 17854, 17856,
+        17856, 17858, 17860, 17860, 17860, 17860, 17860, 17862, 17862, 17865, 17865, 17865, 17867, 17867, 17867, 17867, 17867, 17867, 17867, 17867,
+        17867, 17869, 17871, 17875, 17875, 17878, 17880, 17880, 17880, 17880, 17880, 17882, 17885, 17888, 17888, 17890, 17890, 17892, 17892, 17892,
+        17894, 17896, 17896, 17896, 17896, 17898, 17898, 17898, 17898, 17900, 17902, 17902, 17902, 17902, 17904, 17904, 17904, 17904, 17906, 17909,
+        17911, 17911, 17911, 17911, 17911, 17911, 17913, 17913, 17913, 17915, 17917, 17917, 17917, 17917, 17917, 17917, 17917, 17917, 17917, 17917,
+        17919, 17919, 17921, 17923, 17923, 17925, 17929, 17931, 17931, 17931, 17933, 17933, 17935, 17935, 17935, 17935, 17935, 17935, 17935, 17935,
+        17939, 17939, 17939, 17939, 17939, 17939, 17939, 17939, 17939, 17939, 17939, 17941, 17941, 17943, 17945, 17945, 17945, 17945, 17945, 17947,
+        17947, 17949, 17949, 17949, 17949, 17951, 17960, 17962, 17962, 17962, 17962, 17962, 17962, 17962, 17962, 17964, 17964, 17964, 17964, 17964,
+        17964, 17964, 17964, 17964, 17964, 17964, 17964, 17966, 17966, 17966, 17966, 17968, 17968, 17968, 17968, 17970, 17970, 17970, 17970, 17970,
+        17972, 17972, 17972, 17972, 17972, 17974, 17974, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976, 17976,
+        17979, 17979, 17979, 17979, 17982, 17982, 17985, 17985, 17985, 17985, 17985, 17985, 17987, 17990, 17990, 17992, 17992, 17992, 17992, 17992,
+        17992, 17992, 17992, 17992, 17992, 17992, 17994, 17994, 17994, 17994, 17994, 17994, 17997, 17999, 18001, 18003, 18003, 18003, 18005, 18006,
+        18008, 18010, 18010, 18012, 18014, 18014, 18016, 18016, 18017, 18017, 18019, 18019, 18024, 18026, 18026, 18028, 18028, 18028, 18030, 18030,
+        18030, 18032, 18032, 18032, 18034, 18034, 18037, 18037, 18039, 18039, 18039, 18039, 18039, 18039, 18039, 18039, 18039, 18039, 18042, 18042,
+        18042, 18042, 18042, 18042, 18044, 18044, 18044, 18046, 18046, 18049, 18049, 18051, 18053, 18053, 18053, 18053, 18053, 18053, 18055, 18055,
+        18057, 18057, 18057, 18059, 18059, 18059, 18061, 18061, 18061, 18061, 18061, 18061, 18061, 18063, 18063, 18065, 18065, 18067, 18069, 18072,
+        18073, 18073, 18073, 18073, 18073, 18073, 18073, 18073, 18073, 18074, 18074, 18074, 18075, 18075, 18075, 18075, 18075, 18078, 18083, 18084,
+        18086, 18088, 18090, 18090, 18092, 18096, 18099, 18099, 18099, 18101, 18103, 18105, 18105, 18107, 18107, 18107, 18110, 18111, 18111, 18113,
+        18113, 18116, 18116, 18116, 18116, 18116, 18116, 18116, 18119, 18119, 18119, 18121, 18123, 18130, 18130, 18130, 18130, 18130, 18130, 18132,
+        18132, 18132, 18132, 18134, 18134, 18136, 18136, 18136, 18136, 18136, 18136, 18136, 18136, 18136, 18138, 18138, 18138, 18138, 18138, 18138,
+        18140, 18140, 18140, 18140, 18140, 18140, 18140, 18140, 18142, 18144, 18146, 18148, 18148, 18149, 18149, 18149, 18149, 18150, 18152, 18154,
+        18155, 18155, 18155, 18155, 18155, 18155, 18155, 18157, 18159, 18161, 18166, 18166, 18166, 18166, 18166, 18166, 18168, 18168, 18168, 18168,
+        18171, 18172, 18172, 18175, 18178, 18178, 18180, 18180, 18181, 18181, 18181, 18181, 18186, 18186, 18186, 18188, 18189, 18189, 18191, 18191,
+        18191, 18191, 18191, 18191, 18191, 18191, 18193, 18193, 18193, 18193, 18193, 18196, 18196, 18199, 18201, 18202, 18202, 18202, 18202, 18202,
+        18202, 18205, 18207, 18207, 18210, 18210, 18212, 18212, 18215, 18215, 18215, 18215, 18215, 18217, 18219, 18220, 18220, 18222, 18222, 18224,
+        18227, 18227, 18227, 18227, 18230, 18230, 18230, 18232, 18232, 18234, 18234, 18237, 18237, 18240, 18242, 18242, 18242, 18242, 18242, 18244,
+        18244, 18244, 18244, 18244, 18244, 18244, 18244, 18248, 18248, 18248, 18248, 18248, 18248, 18248, 18248, 18248, 18248, 18248, 18250, 18250,
+        18250, 18250, 18250, 18250, 18252, 18254, 18254, 18254, 18254, 18254, 18254, 18254, 18254, 18254, 18254, 18254, 18256, 18256, 18256, 18256,
+        18256, 18256, 18258, 18258, 18258, 18258, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260,
+        18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18260, 18262, 18262, 18262, 18264, 18264, 18264, 18264,
+        18264, 18264, 18264, 18264, 18264, 18264, 18264, 18264, 18264, 18265, 18265, 18265, 18267, 18267, 18267, 18267, 18267, 18267, 18267, 18269,
+        18271, 18272, 18274, 18275, 18275, 18275, 18277, 18277, 18277, 18277, 18279, 18279, 18279, 18279, 18280, 18280, 18282, 18282, 18284, 18284,
+        18284, 18286, 18286, 18286, 18286, 18286, 18286, 18286, 18286, 18294, 18294, 18294, 18294, 18294, 18294, 18294, 18294, 18294, 18294, 18294,
+        18294, 18294, 18297, 18297, 18299, 18301, 18301, 18301, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303,
+        18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18303, 18305, 18305, 18307, 18307, 18307, 18310, 18310, 18310,
+        18310, 18310, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311, 18311,
+        18311, 18311, 18311, 18311, 18311, 18311, 18313, 18313, 18313, 18313, 18313, 18313, 18315, 18315, 18315, 18315, 18315, 18318, 18318, 18320,
+        18320, 18320, 18320, 18322, 18328, 18328, 18328, 18331, 18331, 18331, 18333, 18333, 18333, 18333, 18333, 18333, 18333, 18333, 18335, 18335,
+        18335, 18335, 18335, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337,
+        18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18337, 18339, 18339, 18339, 18339,
+        18339, 18339, 18339, 18339, 18339, 18341, 18344, 18344, 18344, 18344, 18344, 18344, 18344, 18344, 18344, 18344, 18344, 18347, 18347, 18347,
+        18347, 18347, 18347, 18347, 18349, 18349, 18349, 18351, 18351, 18353, 18353, 18353, 18353, 18353, 18353, 18353, 18353, 18355, 18355, 18355,
+        18355, 18355, 18355, 18355, 18357, 18357, 18359, 18359, 18359, 18359, 18359, 18359, 18359, 18359, 18359, 18361, 18365, 18365, 18365, 18367,
+        18367, 18367, 18367, 18367, 18367, 18367, 18367, 18367, 18367, 18367, 18369, 18369, 18369, 18369, 18369, 18369, 18369, 18371, 18371, 18371,
+        18371, 18371, 18376, 18376, 18376, 18378, 18378, 18378, 18378, 18378, 18378, 18378, 18378, 18378, 18382, 18385, 18385, 18385, 18385, 18385,
+        18385, 18387, 18387, 18387, 18387, 18387, 18387, 18387, 18387, 18387, 18389, 18389, 18389, 18389, 18390, 18390, 18390, 18390, 18390, 18390,
+        18390, 18390, 18393, 18393, 18393, 18393, 18393, 18395, 18395, 18395, 18395, 18403, 18403, 18403, 18404, 18406, 18408, 18411, 18414, 18414,
+        18414, 18415, 18415, 18415, 18417, 18419, 18419, 18419, 18421, 18423, 18423, 18423, 18426, 18428, 18428, 18428, 18430, 18432, 18434, 18434,
+        18436, 18439, 18439, 18443, 18443, 18447, 18449, 18451, 18451, 18451, 18451, 18451, 18451, 18451, 18451, 18451, 18451, 18453, 18453, 18453,
+        18453, 18455, 18455, 18457, 18457, 18457, 18457, 18457, 18457, 18457, 18457, 18457, 18459, 18461, 18461, 18461, 18461, 18461, 18461, 18461,
+        18461, 18461, 18461, 18463, 18463, 18463, 18463, 18465, 18465, 18465, 18469, 18469, 18472, 18474, 18476, 18476, 18476, 18476, 18478, 18478,
+        18478, 18478, 18478, 18480, 18482, 18482, 18484, 18484, 18484, 18487, 18487, 18487, 18487, 18487, 18487, 18487, 18487, 18490, 18490, 18494,
+        18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18494, 18496,
+        18496, 18496, 18496, 18496, 18496, 18496, 18498, 18500, 18500, 18502, 18502, 18502, 18504, 18504, 18506, 18506, 18508, 18508, 18510, 18514,
+        18514, 18514, 18516, 18518, 18518, 18518, 18518, 18518, 18518, 18518, 18520, 18520, 18521, 18521, 18521, 18521, 18523, 18526, 18526, 18526,
+        18526, 18528, 18528, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18530, 18532,
+        18532, 18532, 18532, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534, 18534,
+        18534, 18534, 18534, 18535, 18535, 18535, 18539, 18539, 18539, 18539, 18539, 18541, 18543, 18545, 18547, 18549, 18549, 18549, 18549, 18549,
+        18549, 18549, 18549, 18549, 18549, 18549, 18549, 18549, 18551, 18551, 18551, 18553, 18553, 18553, 18553, 18553, 18553, 18553, 18555, 18555,
+        18555, 18555, 18555, 18555, 18555, 18555, 18555, 18555, 18555, 18555, 18558, 18560, 18560, 18560, 18560, 18560, 18560, 18560, 18562, 18562,
+        18562, 18562, 18562, 18562, 18564, 18566, 18568, 18568, 18568, 18568, 18570, 18570, 18570, 18570, 18572, 18572, 18572, 18574, 18574, 18574,
+        18574, 18574, 18574, 18576, 18578, 18578, 18578, 18578, 18578, 18578, 18578, 18578, 18580, 18580, 18580, 18580, 18580, 18580, 18582, 18582,
+        18582, 18582, 18582, 18582, 18582, 18582, 18582, 18583, 18583, 18583, 18585, 18587, 18587, 18587, 18587, 18587, 18587, 18589, 18589, 18589,
+        18589, 18589, 18591, 18591, 18591, 18595, 18595, 18595, 18595, 18595, 18597, 18597, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599,
+        18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18599, 18601, 18601, 18603, 18605, 18607,
+        18607, 18609, 18609, 18609, 18609, 18609, 18609, 18611, 18611, 18611, 18613, 18613, 18613, 18613, 18614, 18614, 18614, 18614, 18614, 18614,
+        18614, 18614, 18614, 18614, 18615, 18615, 18615, 18615, 18615, 18615, 18615, 18615, 18617, 18617, 18619, 18619, 18619, 18619, 18619, 18622,
+        18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622, 18622,
+        18622, 18622, 18623, 18623, 18623, 18623, 18625, 18625, 18625, 18627, 18627, 18631, 18631, 18631, 18631, 18631, 18631, 18631, 18631, 18631,
+        18631, 18631, 18631, 18631, 18631, 18631, 18631, 18633, 18635, 18635, 18635, 18635, 18635, 18640, 18640, 18640, 18642, 18642, 18644, 18644,
+        18644, 18644, 18644, 18646, 18646, 18646, 18646, 18648, 18648, 18651, 18653, 18653, 18653, 18653, 18653, 18653, 18653, 18653, 18653, 18653,
+        18653, 18653, 18653, 18653, 18653, 18655, 18657, 18657, 18659, 18659, 18659, 18659, 18660, 18660, 18660, 18660, 18660, 18662, 18662, 18662,
+        18662, 18662, 18664, 18664, 18664, 18664, 18664, 18664, 18664, 18667, 18667, 18670, 18670, 18670, 18670, 18670, 18670, 18670, 18670, 18672,
+        18672, 18674, 18674, 18674, 18674, 18674, 18674, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676, 18676,
+        18676, 18676, 18676, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678,
+        18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18678, 18679, 18679, 18679, 18681, 18681, 18684, 18687, 18687, 18687, 18687, 18687,
+        18687, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18689, 18691, 18691, 18693, 18693, 18693, 18693, 18693,
+        18693, 18693, 18693, 18693, 18693, 18693, 18693, 18693, 18695, 18695, 18697, 18697, 18697, 18697, 18697, 18697, 18699, 18699, 18699, 18699,
+        18699, 18699, 18702, 18702, 18702, 18702, 18702, 18702, 18704, 18704, 18705, 18705, 18705, 18705, 18707, 18707, 18710, 18710, 18710, 18710,
+        18710, 18712, 18712, 18714, 18714, 18714, 18714, 18714, 18714, 18716, 18716, 18716, 18716, 18716, 18720, 18720, 18720, 18720, 18720, 18722,
+        18722, 18722, 18724, 18724, 18724, 18724, 18724, 18726, 18726, 18726, 18726, 18726, 18728, 18728, 18728, 18730, 18730, 18730, 18730, 18732,
+        18732, 18734, 18734, 18734, 18734, 18734, 18736, 18736, 18736, 18736, 18736, 18736, 18736, 18739, 18739, 18741, 18741, 18741, 18741, 18743,
+        18745, 18747, 18747, 18747, 18747, 18747, 18749, 18749, 18751, 18751, 18751, 18752, 18755, 18755, 18755, 18755, 18755, 18755, 18755, 18757,
+        18757, 18757, 18760, 18760, 18760, 18760, 18760, 18763, 18763, 18763, 18763, 18764, 18766, 18766, 18766, 18766, 18766, 18766, 18766, 18766,
+        18766, 18768, 18768, 18768, 18771, 18772, 18772, 18774, 18778, 18779, 18779, 18779, 18781, 18781, 18783, 18783, 18785, 18785, 18785, 18785,
+        18786, 18786, 18786, 18788, 18788, 18788, 18788, 18789, 18789, 18791, 18791, 18791, 18791, 18791, 18791, 18793, 18793, 18793, 18795, 18799,
+        18801, 18801, 18803, 18805, 18805, 18805, 18805, 18809, 18811, 18813, 18813, 18814, 18814, 18814, 18814, 18814, 18817, 18819, 18819, 18821,
+        18821, 18821, 18821, 18823, 18826, 18826, 18826, 18826, 18828, 18830, 18830, 18830, 18830, 18832, 18832, 18832, 18832, 18834, 18834, 18836,
+        18836, 18838, 18838, 18838, 18838, 18838, 18838, 18840, 18840, 18840, 18842, 18844, 18844, 18844, 18844, 18846, 18846, 18846, 18846, 18846,
+        18846, 18848, 18848, 18850, 18853, 18853, 18853, 18853, 18853, 18853, 18855, 18855, 18855, 18855, 18855, 18858, 18858, 18858, 18858, 18858,
+        18858, 18858, 18858, 18860, 18862, 18864, 18864, 18866, 18866, 18867, 18867, 18867, 18869, 18869, 18869, 18869, 18869, 18869, 18871, 18871,
+        18873, 18873, 18873, 18873, 18873, 18875, 18877, 18879, 18879, 18879, 18879, 18881, 18881, 18883, 18885, 18885, 18885, 18885, 18887, 18889,
+        18893, 18893, 18895, 18895, 18895, 18895, 18895, 18895, 18895, 18895, 18897, 18901, 18904, 18904, 18904, 18904, 18904, 18904, 18904, 18908,
+        18908, 18910, 18910, 18910, 18910, 18910, 18910, 18910, 18910, 18910, 18910, 18912, 18913, 18916, 18918, 18920, 18920, 18920, 18920, 18920,
+        18920, 18920, 18920, 18920, 18920, 18922, 18922, 18924, 18924, 18924, 18924, 18924, 18924, 18924, 18926, 18926, 18929, 18929, 18931, 18931,
+        18933, 18933, 18933, 18933, 18935, 18935, 18935, 18935, 18935, 18935, 18935, 18935, 18935, 18937, 18937, 18939, 18941, 18941, 18941, 18941,
+        18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18941, 18944, 18944, 18944,
+        18946, 18946, 18946, 18946, 18946, 18946, 18946, 18946, 18946, 18946, 18946, 18948, 18948, 18948, 18948, 18948, 18948, 18948, 18948, 18948,
+        18950, 18950, 18950, 18953, 18955, 18957, 18957, 18959, 18959, 18959, 18961, 18961, 18963, 18963, 18963, 18963, 18963, 18965, 18968, 18968,
+        18968, 18970, 18970, 18970, 18974, 18974, 18976, 18976, 18976, 18976, 18976, 18976, 18979, 18979, 18979, 18981, 18981, 18984, 18984, 18984,
+        18984, 18984, 18987, 18989, 18989, 18989, 18991, 18993, 18993, 18995, 18995, 18995, 18998, 19001, 19001, 19001, 19004, 19006, 19006, 19006,
+        19006, 19006, 19009, 19009, 19009, 19009, 19009, 19009, 19009, 19009, 19009, 19009, 19011, 19013, 19013, 19013, 19015, 19015, 19018, 19018,
+        19021, 19025, 19025, 19025, 19028, 19028, 19028, 19030, 19030, 19030, 19030, 19030, 19030, 19030, 19030, 19030, 19030, 19032, 19034, 19036,
+        19036, 19038, 19040, 19040, 19042, 19042, 19042, 19044, 19044, 19046, 19048, 19051, 19051, 19051, 19051, 19051, 19051, 19051, 19053, 19053,
+        19053, 19053, 19053, 19055, 19055, 19055, 19055, 19055, 19057, 19059, 19059, 19059, 19059, 19059, 19061, 19061, 19061, 19063, 19063, 19065,
+        19065, 19065, 19065, 19065, 19065, 19065, 19065, 19065, 19065, 19065, 19065, 19065, 19068, 19068, 19068, 19068, 19068, 19068, 19068, 19068,
+        19068, 19068, 19068, 19068, 19068, 19068, 19069, 19069, 19069, 19071, 19071, 19071, 19071, 19071, 19071, 19071, 19071, 19071, 19071, 19071,
+        19071, 19071, 19071, 19071, 19071, 19071, 19071, 19071, 19075, 19075, 19075, 19075, 19075, 19075, 19075, 19075, 19075, 19075, 19075, 19082,
+        19084, 19084, 19084, 19084, 19084, 19084, 19084, 19084, 19084, 19084, 19084, 19084, 19086, 19086, 19086, 19086, 19086, 19088, 19090, 19090,
+        19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19090, 19092, 19092, 19092, 19094, 19097, 19097,
+        19097, 19097, 19097, 19097, 19099, 19099, 19099, 19099, 19099, 19099, 19099, 19099, 19099, 19099, 19099, 19101, 19101, 19101, 19101, 19101,
+        19101, 19101, 19101, 19101, 19101, 19101, 19101, 19101, 19103, 19105, 19107, 19107, 19107, 19107, 19107, 19107, 19107, 19107, 19107, 19109,
+        19109, 19109, 19109, 19111, 19112, 19112, 19112, 19112, 19112, 19112, 19112, 19114, 19116, 19118, 19118, 19118, 19118, 19118, 19118, 19118,
+        19118, 19118, 19118, 19118, 19118, 19118, 19120, 19120, 19120, 19120, 19120, 19122, 19122, 19122, 19122, 19122, 19124, 19124, 19126, 19126,
+        19126, 19126, 19126, 19126, 19126, 19126, 19128, 19128, 19128, 19128, 19128, 19130, 19130, 19130, 19130, 19130, 19130, 19130, 19130, 19132,
+        19132, 19132, 19132, 19132, 19132, 19132, 19132, 19132, 19134, 19134, 19134, 19134, 19134, 19134, 19134, 19134, 19134, 19134, 19134, 19134,
+        19134, 19135, 19135, 19135, 19135, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137, 19137,
+        19137, 19138, 19138, 19138, 19138, 19138, 19138, 19140, 19143, 19143, 19143, 19145, 19145, 19145, 19145, 19147, 19147, 19147, 19147, 19147,
+        19147, 19148, 19152, 19152, 19152, 19152, 19154, 19154, 19154, 19154, 19156, 19156, 19160, 19160, 19160, 19160, 19160, 19160, 19160, 19160,
+        19160, 19160, 19162, 19162, 19162, 19162, 19162, 19162, 19162, 19162, 19162, 19166, 19166, 19166, 19166, 19166, 19166, 19166, 19166, 19166,
+        19166, 19166, 19166, 19166, 19166, 19166, 19166, 19166, 19166, 19166, 19168, 19170, 19170, 19170, 19170, 19170, 19173, 19173, 19173, 19173,
+        19174, 19174, 19174, 19174, 19174, 19174, 19174, 19176, 19176, 19176, 19176, 19176, 19177, 19177, 19177, 19177, 19177, 19177, 19177, 19177,
+        19177, 19177, 19179, 19179, 19179, 19179, 19179, 19179, 19179, 19181, 19181, 19181, 19181, 19181, 19181, 19183, 19183, 19185, 19185, 19185,
+        19185, 19186, 19186, 19186, 19186, 19186, 19188, 19188, 19188, 19188, 19191, 19191, 19191, 19193, 19193, 19193, 19193, 19193, 19193, 19193,
+        19193, 19193, 19193, 19195, 19195, 19195, 19195, 19195, 19195, 19195, 19195, 19195, 19195, 19197, 19197, 19197, 19197, 19197, 19197, 19197,
+        19197, 19197, 19197, 19197, 19197, 19197, 19197, 19197, 19197, 19197, 19199, 19202, 19202, 19202, 19202, 19204, 19204, 19204, 19204, 19206,
+        19206, 19206, 19206, 19208, 19208, 19210, 19212, 19214, 19214, 19217, 19219, 19221, 19221, 19221, 19221, 19221, 19221, 19221, 19221, 19221,
+        19222, 19224, 19224, 19224, 19224, 19226, 19228, 19230, 19230, 19230, 19233, 19233, 19236, 19236, 19236, 19239, 19239, 19241, 19241, 19241,
+        19241, 19243, 19245, 19245, 19245, 19245, 19245, 19248, 19248, 19250, 19250, 19252, 19254, 19259, 19262, 19264, 19264, 19266, 19266, 19268,
+        19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270, 19270,
+        19270, 19270, 19270, 19273, 19275, 19277, 19280, 19280, 19280, 19280, 19280, 19280, 19280, 19282, 19282, 19282, 19282, 19282, 19282, 19282,
+        19282, 19282, 19282, 19282, 19282, 19282, 19282, 19282, 19282, 19282, 19287, 19287, 19287, 19287, 19287, 19287, 19287, 19287, 19287, 19287,
+        19287, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289, 19289,
+        19289, 19289, 19289, 19289, 19289, 19289, 19289, 19291, 19291, 19293, 19293, 19293, 19293, 19293, 19293, 19293, 19293, 19293, 19293, 19293,
+        19293, 19293, 19293, 19293, 19293, 19293, 19295, 19295, 19296, 19296, 19296, 19296, 19298, 19300, 19303, 19303, 19303, 19303, 19303, 19303,
+        19303, 19303, 19303, 19303, 19303, 19303, 19312, 19312, 19314, 19314, 19314, 19314, 19314, 19314, 19314, 19314, 19314, 19314, 19317, 19317,
+        19317, 19318, 19318, 19320, 19320, 19320, 19320, 19320, 19320, 19320, 19320, 19320, 19320, 19320, 19322, 19322, 19324, 19324, 19324, 19326,
+        19328, 19328, 19329, 19329, 19329, 19329, 19329, 19329, 19329, 19332, 19332, 19332, 19332, 19332, 19332, 19332, 19332, 19334, 19334, 19334,
+        19334, 19334, 19334, 19334, 19334, 19334, 19334, 19334, 19334, 19337, 19337, 19337, 19337, 19337, 19337, 19337, 19337, 19337, 19337, 19337,
+        19337, 19337, 19339, 19339, 19339, 19339, 19339, 19339, 19339, 19339, 19339, 19339, 19339, 19342, 19344, 19344, 19344, 19344, 19344, 19344,
+        19344, 19344, 19346, 19346, 19346, 19346, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348, 19348,
+        19348, 19348, 19350, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19352, 19356, 19356, 19356, 19356,
+        19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19356, 19359, 19359, 19359, 19359, 19359, 19359, 19359,
+        19361, 19361, 19361, 19361, 19361, 19361, 19361, 19361, 19361, 19361, 19362, 19362, 19362, 19362, 19362, 19362, 19362, 19362, 19362, 19362,
+        19362, 19362, 19362, 19362, 19362, 19362, 19362, 19364, 19364, 19364, 19364, 19364, 19364, 19366, 19366, 19366, 19366, 19366, 19370, 19370,
+        19370, 19370, 19370, 19370, 19370, 19370, 19370, 19372, 19372, 19372, 19372, 19372, 19373, 19373, 19373, 19373, 19373, 19375, 19380, 19380,
+        19380, 19380, 19380, 19380, 19380, 19380, 19380, 19380, 19381, 19383, 19383, 19385, 19387, 19389, 19391, 19391, 19391, 19393, 19395, 19395,
+        19398, 19398, 19398, 19398, 19398, 19398, 19398, 19401, 19401, 19401, 19401, 19401, 19403, 19403, 19403, 19403, 19403, 19403, 19405, 19405,
+        19405, 19405, 19405, 19405, 19405, 19405, 19406, 19406, 19406, 19406, 19406, 19408, 19408, 19408, 19408, 19408, 19408, 19408, 19408, 19408,
+        19408, 19410, 19410, 19410, 19410, 19410, 19410, 19410, 19410, 19410, 19410, 19412, 19412, 19412, 19415, 19415, 19415, 19415, 19415, 19415,
+        19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19415, 19417, 19419, 19419, 19419, 19421,
+        19421, 19421, 19421, 19421, 19421, 19421, 19421, 19421, 19421, 19421, 19421, 19421, 19422, 19424, 19424, 19424, 19426, 19430, 19430, 19430,
+        19432, 19434, 19434, 19434, 19436, 19438, 19438, 19438, 19438, 19442, 19442, 19442, 19442, 19442, 19442, 19442, 19442, 19442, 19442, 19442,
+        19442, 19444, 19446, 19449, 19451, 19451, 19451, 19451, 19451, 19453, 19453, 19453, 19453, 19453, 19453, 19453, 19453, 19453, 19453, 19453,
+        19453, 19453, 19453, 19453, 19453, 19456, 19456, 19456, 19456, 19456, 19456, 19458, 19458, 19458, 19458, 19458, 19458, 19458, 19458, 19458,
+        19458, 19458, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19461, 19463, 19465, 19465, 19465, 19465, 19468,
+        19470, 19470, 19473, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19475, 19478, 19480, 19480, 19480,
+        19480, 19480, 19480, 19480, 19480, 19480, 19482, 19486, 19486, 19486, 19486, 19486, 19486, 19488, 19490, 19490, 19490, 19490, 19490, 19492,
+        19494, 19496, 19496, 19496, 19496, 19496, 19496, 19496, 19496, 19496, 19496, 19498, 19498, 19498, 19500, 19500, 19500, 19502, 19502, 19502,
+        19502, 19502, 19504, 19504, 19504, 19504, 19506, 19506, 19508, 19508, 19508, 19508, 19508, 19510, 19510, 19510, 19510, 19512, 19512, 19512,
+        19512, 19514, 19515, 19515, 19515, 19515, 19515, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517,
+        19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19517, 19519, 19519, 19519, 19519, 19521, 19521, 19521, 19521, 19521, 19521, 19521,
+        19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19521, 19524, 19524,
+        19524, 19524, 19524, 19524, 19524, 19526, 19526, 19526, 19526, 19526, 19531, 19533, 19533, 19537, 19537, 19537, 19537, 19539, 19539, 19539,
+        19542, 19544, 19546, 19546, 19546, 19546, 19546, 19546, 19546, 19548, 19555, 19555, 19555, 19555, 19557, 19559, 19561, 19563, 19565, 19565,
+        19565, 19565, 19565, 19565, 19569, 19574, 19574, 19576, 19578, 19578, 19578, 19580, 19582, 19582, 19582, 19582, 19582, 19584, 19584, 19584,
+        19584, 19584, 19584, 19584, 19584, 19584, 19584, 19584, 19586, 19586, 19586, 19589, 19589, 19589, 19589, 19591, 19591, 19591, 19591, 19591,
+        19591, 19591, 19591, 19593, 19593, 19593, 19593, 19593, 19593, 19593, 19593, 19596, 19596, 19596, 19596, 19596, 19596, 19596, 19596, 19596,
+        19596, 19596, 19596, 19596, 19596, 19596, 19598, 19598, 19598, 19600, 19600, 19600, 19600, 19600, 19600, 19600, 19600, 19600, 19602, 19602,
+        19602, 19605, 19605, 19605, 19605, 19607, 19609, 19611, 19611, 19611, 19611, 19611, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615,
+        19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19615, 19617, 19617, 19620, 19622, 19622, 19624,
+        19624, 19626, 19628, 19628, 19630, 19630, 19630, 19630, 19630, 19630, 19630, 19630, 19641, 19641, 19642, 19642, 19644, 19647, 19647, 19650,
+        19650, 19653, 19655, 19658, 19661, 19661, 19661, 19661, 19661, 19663, 19663, 19663, 19663, 19663, 19663, 19663, 19663, 19663, 19663, 19663,
+        19667, 19667, 19667, 19667, 19667, 19667, 19667, 19667, 19667, 19667, 19667, 19667, 19669, 19669, 19669, 19669, 19669, 19671, 19671, 19671,
+        19671, 19671, 19671, 19671, 19671, 19671, 19674, 19676, 19676, 19676, 19679, 19679, 19681, 19681, 19681, 19681, 19681, 19683, 19683, 19683,
+        19683, 19683, 19683, 19685, 19685, 19685, 19687, 19687, 19690, 19692, 19692, 19692, 19692, 19694, 19694, 19694, 19694, 19696, 19696, 19696,
+        19696, 19696, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19698, 19700, 19700, 19702, 19702, 19702,
+        19702, 19702, 19702, 19702, 19702, 19704, 19704, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706, 19706,
+        19706, 19708, 19708, 19708, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710,
+        19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19710, 19712, 19712, 19712, 19712, 19714, 19714, 19714, 19714, 19714, 19714,
+        19714, 19714, 19714, 19714, 19714, 19716, 19716, 19716, 19718, 19718, 19718, 19718, 19720, 19720, 19720, 19720, 19720, 19720, 19720, 19720,
+        19720, 19722, 19722, 19722, 19722, 19724, 19724, 19724, 19724, 19724, 19724, 19724, 19726, 19726, 19726, 19728, 19728, 19728, 19728, 19728,
+        19728, 19730, 19730, 19730, 19732, 19732, 19732, 19732, 19732, 19733, 19733, 19735, 19737, 19737, 19737, 19737, 19737, 19737, 19737, 19737,
+        19737, 19737, 19737, 19737, 19739, 19739, 19739, 19739, 19739, 19739, 19739, 19739, 19739, 19739, 19739, 19741, 19741, 19743, 19743, 19743,
+        19743, 19743, 19743, 19743, 19743, 19743, 19743, 19745, 19745, 19745, 19745, 19745, 19745, 19745, 19745, 19745, 19745, 19747, 19747, 19749,
+        19749, 19749, 19749, 19749, 19749, 19750, 19750, 19750, 19750, 19750, 19750, 19750, 19750, 19750, 19750, 19750, 19753, 19753, 19753, 19753,
+        19753, 19753, 19753, 19755, 19757, 19757, 19757, 19759, 19759, 19759, 19761, 19761, 19761, 19761, 19761, 19761, 19761, 19761, 19763, 19763,
+        19763, 19763, 19763, 19763, 19763, 19765, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766,
+        19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19766, 19767, 19769, 19769, 19769, 19769, 19769, 19769,
+        19769, 19769, 19769, 19769, 19769, 19769, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771, 19771,
+        19771, 19771, 19771, 19771, 19771, 19771, 19771, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773, 19773,
+        19773, 19775, 19775, 19775, 19775, 19775, 19775, 19775, 19775, 19775, 19775, 19777, 19777, 19777, 19777, 19777, 19777, 19777, 19778, 19778,
+        19779, 19781, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19782, 19784, 19784, 19784, 19784,
+        19784, 19784, 19784, 19784, 19786, 19786, 19789, 19789, 19789, 19789, 19789, 19789, 19789, 19789, 19789, 19791, 19791, 19791, 19791, 19791,
+        19793, 19793, 19793, 19793, 19795, 19797, 19797, 19797, 19797, 19797, 19797, 19797, 19797, 19797, 19797, 19797, 19798, 19798, 19798, 19798,
+        19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798,
+        19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798,
+        19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19798, 19800, 19800, 19802, 19804,
+        19804, 19804, 19804, 19804, 19804, 19804, 19804, 19804, 19804, 19804, 19806, 19806, 19806, 19806, 19806, 19806, 19806, 19808, 19808, 19808,
+        19810, 19810, 19810, 19812, 19812, 19812, 19812, 19812, 19812, 19812, 19812, 19812, 19812, 19814, 19814, 19814, 19814, 19814, 19814, 19814,
+        19814, 19814, 19814, 19814, 19814, 19814, 19816, 19816, 19816, 19816, 19816, 19816, 19816, 19816, 19816, 19816, 19818, 19818, 19818, 19818,
+        19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818, 19818,
+        19820, 19820, 19820, 19822, 19822, 19824, 19824, 19826, 19828, 19830, 19830, 19830, 19830, 19830, 19830, 19831, 19833, 19835, 19835, 19835,
+        19835, 19835, 19835, 19835, 19837, 19837, 19837, 19837, 19837, 19837, 19837, 19837, 19839, 19839, 19839, 19839, 19839, 19841, 19841, 19841,
+        19841, 19841, 19841, 19841, 19841, 19841, 19841, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843, 19843,
+        19843, 19843, 19843, 19843, 19846, 19846, 19851, 19851, 19851, 19851, 19853, 19853, 19854, 19854, 19854, 19854, 19854, 19854, 19854, 19856,
+        19856, 19856, 19856, 19856, 19856, 19858, 19858, 19860, 19863, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866,
+        19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19866, 19868, 19870, 19870, 19872, 19872, 19872, 19872,
+        19872, 19872, 19872, 19875, 19875, 19875, 19877, 19877, 19881, 19881, 19885, 19885, 19885, 19887, 19887, 19887, 19889, 19889, 19891, 19891,
+        19891, 19891, 19891, 19891, 19894, 19896, 19896, 19896, 19896, 19896, 19896, 19896, 19896, 19896, 19898, 19900, 19900, 19900, 19900, 19900,
+        19900, 19900, 19900, 19900, 19902, 19902, 19902, 19902, 19904, 19904, 19904, 19904, 19904, 19904, 19904, 19904, 19904, 19904, 19904, 19904,
+        19906, 19906, 19906, 19906, 19906, 19906, 19906, 19909, 19909, 19909, 19909, 19909, 19909, 19911, 19913, 19915, 19917, 19917, 19917, 19917,
+        19917, 19917, 19919, 19919, 19920, 19920, 19923, 19925, 19925, 19925, 19925, 19925, 19925, 19925, 19929, 19929, 19929, 19929, 19929, 19931,
+        19931, 19933, 19933, 19933, 19935, 19935, 19935, 19935, 19935, 19935, 19938, 19940, 19940, 19940, 19940, 19940, 19940, 19940, 19940, 19940,
+        19940, 19940, 19940, 19940, 19942, 19942, 19943, 19943, 19943, 19943, 19943, 19943, 19943, 19945, 19945, 19947, 19947, 19947, 19947, 19947,
+        19947, 19947, 19947, 19947, 19947, 19949, 19951, 19953, 19953, 19954, 19954, 19954, 19954, 19954, 19954, 19956, 19958, 19958, 19958, 19958,
+        19962, 19965, 19967, 19969, 19969, 19969, 19969, 19969, 19969, 19969, 19969, 19973, 19973, 19973, 19975, 19978, 19978, 19978, 19978, 19978,
+        19978, 19978, 19978, 19980, 19980, 19980, 19980, 19980, 19980, 19980, 19982, 19984, 19984, 19984, 19984, 19990, 19990, 19990, 19992, 19994,
+        19994, 19994, 19996, 19998, 19998, 19998, 19998, 19998, 20001, 20001, 20003, 20005, 20007, 20010, 20012, 20014, 20017, 20020, 20020, 20020,
+        20020, 20020, 20020, 20022, 20024, 20024, 20024, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026, 20026,
+        20028, 20028, 20029, 20031, 20033, 20035, 20035, 20035, 20035, 20035, 20035, 20035, 20035, 20037, 20039, 20039, 20039, 20039, 20039, 20039,
+        20039, 20039, 20039, 20039, 20039, 20039, 20039, 20041, 20041, 20041, 20043, 20045, 20045, 20047, 20047, 20050, 20050, 20052, 20054, 20056,
+        20059, 20063, 20063, 20063, 20063, 20065, 20065, 20065, 20069, 20071, 20071, 20073, 20075, 20081, 20084, 20088, 20090, 20092, 20092, 20092,
+        20092, 20094, 20094, 20099, 20099, 20099, 20099, 20099, 20099, 20099, 20099, 20099, 20101, 20103, 20105, 20105, 20105, 20105, 20108, 20108,
+        20110, 20110, 20110, 20128, 20128, 20128, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130, 20130,
+        20130, 20130, 20130, 20132, 20132, 20132, 20132, 20134, 20134, 20142, 20142, 20142, 20144, 20144, 20146, 20146, 20146, 20146, 20146, 20148,
+        20150, 20152, 20152, 20155, 20158, 20158, 20158, 20158, 20158, 20158, 20158, 20160, 20160, 20160, 20160, 20160, 20160, 20162, 20162, 20162,
+        20164, 20164, 20166, 20166, 20166, 20166, 20168, 20168, 20168, 20168, 20168, 20170, 20172, 20172, 20172, 20172, 20172, 20172, 20172, 20172,
+        20172, 20172, 20175, 20175, 20175, 20175, 20175, 20175, 20175, 20175, 20175, 20175, 20177, 20177, 20177, 20177, 20177, 20179, 20179, 20179,
+        20179, 20179, 20179, 20179, 20179, 20179, 20181, 20181, 20181, 20181, 20181, 20181, 20181, 20181, 20183, 20185, 20185, 20185, 20185, 20185,
+        20185, 20185, 20185, 20185, 20187, 20187, 20187, 20187, 20187, 20189, 20189, 20191, 20191, 20191, 20193, 20193, 20193, 20194, 20194, 20194,
+        20194, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20196, 20198, 20198, 20200, 20200, 20200, 20200,
+        20200, 20200, 20200, 20200, 20200, 20200, 20200, 20200, 20200, 20200, 20203, 20203, 20203, 20203, 20203, 20203, 20203, 20203, 20203, 20203,
+        20203, 20203, 20203, 20203, 20203, 20205, 20205, 20205, 20205, 20205, 20205, 20205, 20205, 20205, 20211, 20211, 20211, 20211, 20211, 20213,
+        20213, 20213, 20213, 20214, 20214, 20214, 20215, 20217, 20219, 20220, 20220, 20222, 20222, 20222, 20224, 20224, 20224, 20226, 20226, 20229,
+        20229, 20229, 20229, 20229, 20231, 20233, 20235, 20235, 20235, 20235, 20235, 20235, 20235, 20235, 20235, 20237, 20237, 20237, 20237, 20237,
+        20239, 20241, 20241, 20241, 20241, 20241, 20243, 20243, 20243, 20243, 20243, 20243, 20243, 20243, 20243, 20243, 20245, 20245, 20245, 20246,
+        20246, 20246, 20246, 20246, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20248, 20250, 20250,
+        20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20252, 20254, 20256, 20256, 20258, 20258, 20258, 20258,
+        20258, 20262, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20264, 20266, 20268, 20268, 20268, 20268,
+        20268, 20268, 20268, 20268, 20268, 20268, 20268, 20268, 20271, 20271, 20271, 20271, 20271, 20271, 20271, 20271, 20273, 20273, 20273, 20273,
+        20273, 20273, 20273, 20273, 20273, 20273, 20275, 20279, 20279, 20279, 20280, 20280, 20280, 20280, 20280, 20283, 20297, 20297, 20297, 20297,
+        20297, 20297, 20297, 20297, 20297, 20297, 20297, 20297, 20299, 20301, 20303, 20306, 20306, 20306, 20308, 20310, 20312, 20312, 20312, 20312,
+        20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312, 20312,
+        20314, 20314, 20314, 20314, 20314, 20314, 20314, 20314, 20314, 20314, 20314, 20316, 20316, 20319, 20319, 20319, 20319, 20319, 20319, 20319,
+        20319, 20319, 20319, 20321, 20321, 20321, 20321, 20321, 20323, 20323, 20323, 20323, 20323, 20323, 20323, 20323, 20323, 20323, 20323, 20323,
+        20323, 20323, 20323, 20323, 20326, 20326, 20328, 20328, 20328, 20328, 20328, 20328, 20330, 20330, 20330, 20332, 20332, 20332, 20332, 20332,
+        20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20332, 20334, 20334, 20334, 20336,
+        20336, 20336, 20336, 20338, 20338, 20338, 20338, 20338, 20338, 20338, 20340, 20340, 20340, 20340, 20340, 20340, 20340, 20340, 20340, 20340,
+        20340, 20342, 20342, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345, 20345,
+        20348, 20348, 20348, 20350, 20350, 20351, 20351, 20354, 20354, 20354, 20356, 20356, 20356, 20356, 20356, 20356, 20356, 20356, 20356, 20356,
+        20356, 20356, 20356, 20356, 20356, 20356, 20356, 20356, 20356, 20361, 20361, 20361, 20361, 20361, 20361, 20361, 20361, 20361, 20361, 20363,
+        20363, 20363, 20363, 20363, 20363, 20366, 20368, 20371, 20371, 20373, 20373, 20373, 20373, 20373, 20373, 20373, 20373, 20374, 20374, 20374,
+        20374, 20374, 20376, 20376, 20376, 20376, 20376, 20376, 20376, 20376, 20376, 20376, 20376, 20378, 20378, 20378, 20380, 20382, 20382, 20382,
+        20382, 20383, 20389, 20389, 20389, 20389, 20390, 20393, 20393, 20393, 20393, 20393, 20393, 20393, 20393, 20393, 20395, 20395, 20396, 20396,
+        20396, 20396, 20398, 20398, 20400, 20400, 20400, 20400, 20400, 20400, 20402, 20402, 20404, 20404, 20404, 20404, 20404, 20404, 20404, 20404,
+        20404, 20404, 20404, 20404, 20406, 20406, 20408, 20410, 20410, 20410, 20410, 20410, 20411, 20413, 20413, 20415, 20417, 20417, 20417, 20417,
+        20417, 20419, 20419, 20419, 20419, 20419, 20421, 20421, 20421, 20421, 20421, 20421, 20423, 20423, 20423, 20426, 20429, 20431, 20433, 20437,
+        20439, 20439, 20444, 20446, 20450, 20456, 20456, 20463, 20463, 20463, 20463, 20463, 20463, 20465, 20465, 20465, 20465, 20465, 20465, 20465,
+        20465, 20465, 20465, 20467, 20467, 20467, 20467, 20467, 20467, 20467, 20467, 20470, 20470, 20472, 20472, 20474, 20477, 20479, 20479, 20481,
+        20483, 20486, 20486, 20486, 20486, 20486, 20486, 20486, 20488, 20488, 20488, 20488, 20488, 20488, 20490, 20490, 20490, 20490, 20490, 20490,
+        20490, 20490, 20490, 20490, 20490, 20490, 20492, 20494, 20494, 20496, 20496, 20496, 20496, 20498, 20498, 20498, 20498, 20498, 20498, 20498,
+        20498, 20500, 20500, 20500, 20500, 20500, 20500, 20500, 20500, 20500, 20500, 20502, 20504, 20504, 20506, 20506, 20507, 20511, 20511, 20511,
+        20511, 20511, 20511, 20521, 20523, 20526, 20526, 20526, 20526, 20526, 20526, 20526, 20526, 20526, 20526, 20526, 20528, 20528, 20528, 20528,
+        20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20530, 20532, 20532, 20532, 20537, 20539, 20544, 20544,
+        20544, 20544, 20546, 20546, 20548, 20552, 20556, 20560, 20565, 20565, 20567, 20567, 20567, 20567, 20567, 20569, 20569, 20569, 20569, 20571,
+        20573, 20573, 20573, 20573, 20573, 20573, 20573, 20577, 20577, 20577, 20577, 20577, 20579, 20579, 20579, 20579, 20580, 20581, 20581, 20581,
+        20581, 20581, 20581, 20581, 20581, 20584, 20584, 20587, 20587, 20587, 20589, 20589, 20591, 20591, 20591, 20593, 20598, 20598, 20598, 20598,
+        20598, 20598, 20598, 20600, 20600, 20600, 20600, 20600, 20600, 20600, 20602, 20602, 20604, 20604, 20604, 20604, 20604, 20608, 20608, 20613,
+        20613, 20613, 20613, 20615, 20615, 20619, 20621, 20621, 20621, 20621, 20621, 20621, 20621, 20623, 20625, 20625, 20625, 20627, 20627, 20629,
+        20629, 20629, 20629, 20629, 20629, 20631, 20631, 20631, 20631, 20631, 20631, 20631, 20631, 20632, 20632, 20632, 20634, 20634, 20635, 20635,
+        20635, 20635, 20635, 20635, 20635, 20635, 20636, 20636, 20636, 20636, 20636, 20636, 20636, 20636, 20636, 20636, 20638, 20638, 20638, 20640,
+        20642, 20642, 20644, 20644, 20644, 20649, 20649, 20651, 20653, 20653, 20653, 20653, 20653, 20653, 20653, 20653, 20653, 20653, 20653, 20653,
+        20655, 20655, 20655, 20657, 20657, 20659, 20659, 20659, 20664, 20664, 20666, 20666, 20669, 20669, 20669, 20674, 20674, 20676, 20676, 20676,
+        20676, 20676, 20676, 20676, 20676, 20676, 20676, 20676, 20679, 20679, 20679, 20679, 20679, 20679, 20681, 20683, 20683, 20683, 20683, 20683,
+        20683, 20683, 20683, 20685, 20685, 20687, 20687, 20687, 20687, 20688, 20688, 20688, 20688, 20688, 20688, 20688, 20690, 20690, 20690, 20690,
+        20690, 20694, 20694, 20694, 20694, 20694, 20694, 20694, 20698, 20700, 20700, 20700, 20700, 20700, 20700, 20704, 20704, 20706, 20706, 20708,
+        20708, 20710, 20710, 20712, 20712, 20712, 20712, 20712, 20712, 20712, 20712, 20712, 20714, 20714, 20714, 20714, 20716, 20718, 20718, 20718,
+        20718, 20718, 20718, 20718, 20718, 20718, 20718, 20718, 20718, 20718, 20718, 20720, 20720, 20720, 20720, 20720, 20720, 20720, 20720, 20720,
+        20720, 20722, 20724, 20724, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20726, 20730, 20732, 20732, 20732,
+        20732, 20732, 20732, 20734, 20734, 20734, 20734, 20734, 20734, 20737, 20737, 20737, 20739, 20741, 20743, 20743, 20745, 20747, 20747, 20747,
+        20747, 20747, 20747, 20747, 20747, 20747, 20747, 20747, 20747, 20749, 20749, 20749, 20749, 20749, 20751, 20753, 20753, 20753, 20753, 20753,
+        20753, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20755, 20757, 20759, 20761,
+        20761, 20761, 20761, 20763, 20763, 20765, 20767, 20769, 20769, 20771, 20771, 20774, 20774, 20774, 20774, 20776, 20776, 20776, 20776, 20776,
+        20776, 20776, 20780, 20780, 20782, 20782, 20782, 20782, 20784, 20784, 20786, 20786, 20786, 20788, 20790, 20792, 20792, 20792, 20792, 20794,
+        20794, 20796, 20796, 20798, 20803, 20806, 20808, 20808, 20812, 20812, 20815, 20818, 20820, 20823, 20825, 20837, 20839, 20841, 20841, 20841,
+        20841, 20841, 20843, 20843, 20845, 20845, 20861, 20861, 20861, 20861, 20861, 20861, 20861, 20861, 20861, 20861, 20863, 20863, 20865, 20865,
+        20867, 20867, 20867, 20867, 20867, 20867, 20867, 20867, 20869, 20869, 20869, 20869, 20869, 20869, 20871, 20871, 20871, 20871, 20871, 20871,
+        20871, 20873, 20873, 20873, 20873, 20874, 20874, 20874, 20874, 20874, 20874, 20876, 20876, 20876, 20876, 20876, 20876, 20876, 20878, 20878,
+        20878, 20878, 20878, 20878, 20878, 20878, 20878, 20878, 20883, 20883, 20883, 20883, 20883, 20883, 20883, 20883, 20883, 20888, 20888, 20888,
+        20890, 20890, 20892, 20892, 20892, 20892, 20892, 20892, 20892, 20892, 20892, 20892, 20894, 20894, 20896, 20898, 20898, 20898, 20898, 20898,
+        20898, 20898, 20898, 20898, 20898, 20898, 20898, 20898, 20900, 20903, 20903, 20903, 20908, 20910, 20910, 20910, 20910, 20912, 20912, 20912,
+        20912, 20912, 20912, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913,
+        20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20913, 20915, 20915, 20915, 20915, 20915, 20915, 20915, 20915, 20915,
+        20915, 20917, 20917, 20917, 20917, 20917, 20919, 20919, 20919, 20919, 20919, 20919, 20921, 20921, 20921, 20921, 20921, 20921, 20921, 20921,
+        20921, 20921, 20921, 20922, 20924, 20928, 20928, 20928, 20930, 20930, 20930, 20932, 20932, 20932, 20932, 20935, 20935, 20935, 20937, 20937,
+        20937, 20939, 20939, 20942, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945, 20945,
+        20945, 20945, 20945, 20945, 20947, 20947, 20947, 20947, 20947, 20947, 20947, 20947, 20947, 20949, 20949, 20949, 20949, 20949, 20949, 20949,
+        20949, 20951, 20951, 20951, 20951, 20951, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20953, 20954,
+        20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954, 20954,
+        20954, 20956, 20958, 20960, 20960, 20960, 20961, 20961, 20961, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963,
+        20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20963, 20965, 20965, 20965, 20965, 20965, 20965, 20965, 20967, 20967, 20967,
+        20967, 20967, 20967, 20967, 20969, 20969, 20969, 20969, 20969, 20969, 20969, 20972, 20972, 20972, 20974, 20974, 20974, 20974, 20974, 20974,
+        20974, 20974, 20974, 20974, 20974, 20976, 20976, 20976, 20976, 20976, 20978, 20978, 20978, 20980, 20980, 20980, 20980, 20982, 20982, 20982,
+        20984, 20984, 20984, 20984, 20985, 20985, 20987, 20987, 20987, 20987, 20987, 20987, 20987, 20987, 20987, 20989, 20989, 20989, 20989, 20991,
+        20993, 20993, 20993, 20993, 20993, 20993, 20993, 20995, 20997, 20997, 20997, 20997, 20999, 21001, 21003, 21003, 21003, 21003, 21005, 21005,
+        21005, 21007, 21009, 21009, 21009, 21009, 21009, 21009, 21009, 21009, 21009, 21009, 21011, 21013, 21013, 21013, 21014, 21014, 21014, 21014,
+        21014, 21014, 21016, 21017, 21019, 21019, 21019, 21021, 21025, 21025, 21025, 21027, 21027, 21027, 21027, 21029, 21029, 21029, 21029, 21031,
+        21031, 21031, 21031, 21033, 21035, 21035, 21035, 21035, 21035, 21037, 21037, 21037, 21037, 21037, 21037, 21037, 21038, 21038, 21038, 21038,
+        21038, 21038, 21038, 21038, 21040, 21040, 21040, 21040, 21040, 21040, 21042, 21042, 21042, 21042, 21042, 21042, 21042, 21042, 21042, 21042,
+        21042, 21042, 21042, 21042, 21042, 21042, 21042, 21042, 21044, 21044, 21044, 21044, 21047, 21049, 21052, 21052, 21052, 21052, 21052, 21052,
+        21053, 21053, 21053, 21053, 21053, 21053, 21053, 21053, 21055, 21055, 21055, 21055, 21055, 21055, 21055, 21055, 21055, 21055, 21055, 21055,
+        21059, 21059, 21059, 21059, 21059, 21059, 21061, 21061, 21064, 21066, 21068, 21068, 21068, 21068, 21068, 21068, 21068, 21068, 21068, 21068,
+        21068, 21068, 21068, 21068, 21069, 21070, 21070, 21072, 21074, 21076, 21078, 21078, 21080, 21082, 21084, 21084, 21084, 21086, 21086, 21086,
+        21086, 21086, 21088, 21088, 21090, 21090, 21091, 21091, 21091, 21093, 21093, 21093, 21093, 21093, 21093, 21095, 21095, 21095, 21097, 21097,
+        21097, 21097, 21097, 21097, 21099, 21101, 21101, 21103, 21105, 21107, 21107, 21107, 21107, 21107, 21107, 21107, 21109, 21109, 21109, 21109,
+        21110, 21116, 21118, 21118, 21118, 21118, 21120, 21120, 21120, 21120, 21120, 21120, 21121, 21121, 21121, 21123, 21123, 21125, 21125, 21125,
+        21125, 21125, 21125, 21125, 21127, 21127, 21129, 21129, 21129, 21131, 21134, 21136, 21136, 21136, 21138, 21138, 21138, 21138, 21139, 21139,
+        21140, 21140, 21140, 21140, 21140, 21142, 21142, 21144, 21144, 21146, 21146, 21149, 21149, 21149, 21149, 21149, 21149, 21149, 21151, 21151,
+        21151, 21151, 21151, 21151, 21153, 21155, 21158, 21158, 21158, 21158, 21158, 21160, 21160, 21160, 21160, 21160, 21162, 21162, 21162, 21162,
+        21162, 21164, 21164, 21164, 21164, 21164, 21167, 21169, 21169, 21169, 21169, 21169, 21169, 21169, 21169, 21169, 21169, 21171, 21171, 21171,
+        21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21173, 21176, 21176, 21176, 21176, 21176, 21176,
+        21176, 21176, 21176, 21176, 21176, 21176, 21176, 21176, 21176, 21176, 21177, 21177, 21177, 21177, 21179, 21179, 21179, 21179, 21179, 21181,
+        21181, 21181, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184, 21184,
+        21184, 21184, 21184, 21184, 21184, 21184, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186,
+        21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21186, 21188, 21188, 21188, 21188, 21188, 21188, 21188, 21188, 21191, 21191, 21191,
+        21191, 21191, 21191, 21191, 21191, 21191, 21191, 21191, 21191, 21191, 21193, 21193, 21193, 21193, 21193, 21193, 21193, 21193, 21195, 21195,
+        21195, 21195, 21197, 21197, 21197, 21199, 21199, 21201, 21201, 21201, 21201, 21201, 21203, 21203, 21203, 21203, 21203, 21205, 21205, 21205,
+        21205, 21205, 21205, 21205, 21205, 21205, 21205, 21205, 21205, 21205, 21205, 21205, 21208, 21208, 21210, 21210, 21210, 21210, 21212, 21212,
+        21212, 21212, 21214, 21214, 21214, 21214, 21214, 21216, 21216, 21216, 21218, 21221, 21224, 21224, 21224, 21224, 21224, 21224, 21224, 21226,
+        21226, 21226, 21226, 21226, 21226, 21226, 21226, 21226, 21228, 21230, 21230, 21232, 21232, 21232, 21234, 21236, 21236, 21236, 21236, 21236,
+        21239, 21239, 21242, 21242, 21242, 21242, 21242, 21242, 21242, 21242, 21244, 21248, 21248, 21248, 21248, 21250, 21250, 21250, 21253, 21253,
+        21256, 21256, 21256, 21256, 21256, 21259, 21260, 21260, 21260, 21260, 21260, 21262, 21262, 21262, 21262, 21262, 21262, 21262, 21265, 21265,
+        21267, 21267, 21267, 21269, 21271, 21271, 21271, 21273, 21275, 21277, 21279, 21281, 21281, 21283, 21283, 21283, 21283, 21283, 21283, 21283,
+        21283, 21285, 21285, 21285, 21285, 21285, 21285, 21285, 21288, 21290, 21290, 21290, 21291, 21293, 21293, 21295, 21295, 21295, 21295, 21295,
+        21295, 21295, 21297, 21300, 21300, 21303, 21307, 21310, 21310, 21310, 21310, 21312, 21312, 21312, 21312, 21312, 21315, 21315, 21315, 21315,
+        21315, 21317, 21317, 21317, 21317, 21321, 21321, 21321, 21321, 21324, 21324, 21324, 21324, 21326, 21326, 21326, 21326, 21326, 21326, 21326,
+        21326, 21326, 21326, 21326, 21326, 21326, 21326, 21327, 21327, 21327, 21327, 21329, 21333, 21335, 21335, 21335, 21335, 21337, 21337, 21339,
+        21339, 21340, 21340, 21340, 21342, 21343, 21345, 21345, 21347, 21349, 21352, 21352, 21352, 21352, 21352, 21352, 21352, 21352, 21352, 21352,
+        21352, 21352, 21355, 21357, 21357, 21359, 21361, 21361, 21361, 21361, 21361, 21363, 21366, 21366, 21370, 21370, 21370, 21372, 21372, 21374,
+        21377, 21377, 21377, 21379, 21379, 21381, 21383, 21383, 21385, 21385, 21385, 21388, 21388, 21388, 21388, 21388, 21388, 21390, 21392, 21392,
+        21392, 21392, 21392, 21392, 21392, 21392, 21395, 21395, 21395, 21395, 21395, 21395, 21395, 21395, 21398, 21398, 21401, 21401, 21401, 21401,
+        21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21401, 21403, 21405, 21405, 21405, 21405, 21405, 21405,
+        21405, 21405, 21405, 21405, 21405, 21408, 21410, 21410, 21412, 21414, 21414, 21416, 21419, 21421, 21423, 21426, 21426, 21426, 21431, 21434,
+        21437, 21439, 21442, 21444, 21444, 21444, 21446, 21446, 21446, 21446, 21446, 21446, 21446, 21446, 21454, 21454, 21454, 21454, 21454, 21454,
+        21454, 21454, 21454, 21454, 21454, 21456, 21456, 21456, 21456, 21458, 21460, 21460, 21462, 21462, 21462, 21462, 21462, 21462, 21462, 21462,
+        21462, 21464, 21467, 21469, 21472, 21472, 21474, 21474, 21474, 21474, 21474, 21474, 21474, 21474, 21474, 21477, 21477, 21479, 21479, 21479,
+        21479, 21479, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21481, 21485, 21485, 21485, 21487, 21491, 21493,
+        21493, 21493, 21493, 21493, 21493, 21493, 21493, 21493, 21493, 21495, 21495, 21495, 21495, 21495, 21495, 21495, 21495, 21495, 21495, 21495,
+        21497, 21497, 21497, 21497, 21497, 21497, 21497, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499, 21499,
+        21499, 21499, 21499, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21501, 21502, 21502, 21502,
+        21502, 21502, 21502, 21502, 21502, 21504, 21504, 21504, 21504, 21507, 21507, 21507, 21507, 21507, 21507, 21507, 21507, 21507, 21507, 21507,
+        21507, 21507, 21509, 21509, 21509, 21509, 21513, 21513, 21513, 21513, 21516, 21518, 21520, 21520, 21520, 21520, 21520, 21520, 21522, 21525,
+        21527, 21529, 21531, 21531, 21531, 21531, 21531, 21531, 21531, 21532, 21532, 21532, 21532, 21532, 21532, 21532, 21532, 21532, 21532, 21532,
+        21534, 21534, 21534, 21534, 21534, 21534, 21534, 21534, 21534, 21537, 21539, 21539, 21539, 21539, 21539, 21539, 21541, 21544, 21544, 21547,
+        21547, 21549, 21549, 21549, 21549, 21551, 21551, 21554, 21556, 21558, 21558, 21558, 21558, 21558, 21572, 21572, 21574, 21576, 21576, 21576,
+        21578, 21578, 21578, 21578, 21578, 21580, 21580, 21580, 21582, 21582, 21582, 21582, 21582, 21582, 21584, 21584, 21584, 21584, 21584, 21584,
+        21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21586, 21588, 21588, 21588, 21588, 21588, 21590, 21590,
+        21590, 21590, 21590, 21590, 21592, 21592, 21592, 21592, 21594, 21594, 21599, 21599, 21599, 21601, 21601, 21601, 21601, 21601, 21601, 21601,
+        21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601, 21601,
+        21601, 21601, 21601, 21601, 21604, 21606, 21606, 21606, 21606, 21608, 21608, 21608, 21608, 21608, 21608, 21608, 21608, 21610, 21610, 21610,
+        21610, 21610, 21610, 21610, 21610, 21610, 21610, 21610, 21610, 21615, 21615, 21616, 21617, 21617, 21617, 21617, 21617, 21617, 21617, 21617,
+        21617, 21619, 21619, 21619, 21619, 21619, 21621, 21621, 21621, 21621, 21624, 21624, 21624, 21626, 21626, 21626, 21628, 21628, 21628, 21628,
+        21628, 21628, 21628, 21630, 21630, 21630, 21630, 21630, 21633, 21635, 21638, 21638, 21640, 21642, 21642, 21642, 21642, 21642, 21644, 21644,
+        21644, 21644, 21644, 21644, 21654, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656, 21656,
+        21656, 21656, 21658, 21658, 21660, 21660, 21660, 21660, 21661, 21661, 21661, 21664, 21666, 21668, 21668, 21668, 21668, 21668, 21668, 21670,
+        21670, 21670, 21670, 21672, 21672, 21672, 21674, 21674, 21674, 21674, 21674, 21674, 21674, 21676, 21679, 21679, 21679, 21679, 21679, 21679,
+        21679, 21679, 21679, 21682, 21682, 21682, 21682, 21682, 21684, 21686, 21686, 21688, 21690, 21690, 21690, 21690, 21692, 21692, 21692, 21692,
+        21692, 21694, 21694, 21694, 21694, 21694, 21694, 21696, 21696, 21698, 21701, 21701, 21701, 21701, 21704, 21704, 21705, 21705, 21705, 21705,
+        21707, 21709, 21711, 21713, 21713, 21713, 21713, 21713, 21713, 21713, 21713, 21713, 21713, 21713, 21716, 21716, 21716, 21717, 21717, 21719,
+        21719, 21719, 21719, 21719, 21719, 21719, 21719, 21719, 21719, 21721, 21721, 21723, 21723, 21723, 21723, 21723, 21723, 21723, 21723, 21723,
+        21723, 21723, 21723, 21723, 21725, 21725, 21725, 21727, 21729, 21729, 21729, 21729, 21729, 21729, 21729, 21729, 21729, 21729, 21731, 21731,
+        21731, 21731, 21731, 21731, 21731, 21731, 21731, 21731, 21731, 21731, 21733, 21733, 21733, 21733, 21733, 21733, 21733, 21735, 21735, 21735,
+        21735, 21735, 21735, 21735, 21735, 21735, 21735, 21735, 21735, 21735, 21735, 21735, 21737, 21739, 21739, 21739, 21739, 21739, 21741, 21741,
+        21746, 21746, 21746, 21746, 21746, 21746, 21746, 21746, 21747, 21747, 21747, 21747, 21747, 21747, 21747, 21747, 21747, 21747, 21747, 21747,
+        21749, 21749, 21749, 21749, 21749, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21752, 21754, 21756,
+        21756, 21756, 21756, 21756, 21756, 21756, 21756, 21758, 21758, 21758, 21758, 21761, 21761, 21761, 21761, 21761, 21761, 21761, 21761, 21763,
+        21765, 21765, 21765, 21765, 21765, 21767, 21769, 21769, 21771, 21771, 21771, 21771, 21771, 21773, 21773, 21775, 21777, 21777, 21777, 21779,
+        21781, 21781, 21781, 21783, 21783, 21783, 21783, 21783, 21785, 21785, 21785, 21785, 21785, 21785, 21787, 21787, 21787, 21787, 21787, 21787,
+        21787, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21789, 21791, 21791, 21791, 21791, 21791, 21791,
+        21791, 21791, 21791, 21793, 21793, 21793, 21793, 21793, 21793, 21793, 21793, 21793, 21793, 21793, 21795, 21795, 21795, 21795, 21795, 21795,
+        21795, 21796, 21796, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21797, 21799, 21799, 21799, 21799, 21799,
+        21799, 21799, 21799, 21799, 21799, 21799, 21801, 21801, 21803, 21803, 21803, 21803, 21803, 21803, 21803, 21803, 21803, 21803, 21803, 21803,
+        21803, 21803, 21803, 21806, 21806, 21806, 21806, 21806, 21806, 21806, 21806, 21806, 21806, 21809, 21809, 21809, 21809, 21811, 21811, 21811,
+        21813, 21815, 21815, 21818, 21818, 21818, 21818, 21818, 21820, 21820, 21820, 21820, 21820, 21821, 21821, 21821, 21821, 21821, 21821, 21821,
+        21821, 21821, 21821, 21821, 21821, 21823, 21823, 21825, 21825, 21825, 21825, 21826, 21826, 21826, 21826, 21828, 21828, 21830, 21830, 21830,
+        21830, 21830, 21830, 21830, 21830, 21830, 21830, 21832, 21832, 21832, 21834, 21834, 21834, 21834, 21834, 21834, 21834, 21834, 21834, 21834,
+        21834, 21834, 21834, 21834, 21834, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836, 21836,
+        21836, 21836, 21836, 21838, 21838, 21840, 21840, 21842, 21842, 21842, 21842, 21842, 21842, 21842, 21842, 21844, 21844, 21844, 21844, 21844,
+        21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21844, 21846, 21846, 21846,
+        21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21846, 21848, 21848, 21848, 21848,
+        21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21848, 21850, 21850, 21852, 21855, 21860, 21860, 21860,
+        21860, 21860, 21860, 21860, 21860, 21860, 21863, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865,
+        21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865, 21865,
+        21865, 21865, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21867, 21869, 21872,
+        21872, 21872, 21872, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874, 21874,
+        21874, 21876, 21878, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880, 21880,
+        21880, 21880, 21880, 21880, 21882, 21882, 21882, 21882, 21882, 21882, 21882, 21882, 21882, 21882, 21884, 21886, 21887, 21889, 21889, 21889,
+        21889, 21889, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21891, 21893, 21895, 21895, 21897,
+        21899, 21899, 21899, 21899, 21901, 21901, 21903, 21903, 21903, 21903, 21903, 21903, 21905, 21905, 21905, 21905, 21905, 21905, 21905, 21905,
+        21905, 21905, 21905, 21905, 21905, 21905, 21905, 21905, 21905, 21907, 21907, 21907, 21907, 21907, 21909, 21909, 21909, 21909, 21911, 21911,
+        21911, 21911, 21911, 21911, 21911, 21911, 21913, 21913, 21913, 21915, 21915, 21915, 21915, 21915, 21917, 21919, 21919, 21919, 21919, 21919,
+        21919, 21921, 21921, 21921, 21921, 21923, 21923, 21923, 21923, 21923, 21923, 21923, 21923, 21923, 21924, 21924, 21924, 21924, 21924, 21924,
+        21926, 21926, 21926, 21926, 21926, 21926, 21928, 21928, 21930, 21930, 21930, 21930, 21930, 21930, 21930, 21930, 21930, 21930, 21930, 21930,
+        21930, 21930, 21932, 21934, 21934, 21934, 21934, 21934, 21934, 21934, 21934, 21935, 21935, 21935, 21935, 21935, 21935, 21935, 21935, 21935,
+        21935, 21935, 21935, 21935, 21935, 21935, 21935, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936, 21936,
+        21936, 21936, 21936, 21936, 21936, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938,
+        21938, 21938, 21938, 21938, 21938, 21938, 21938, 21938, 21940, 21942, 21944, 21944, 21946, 21946, 21946, 21946, 21946, 21948, 21950, 21950,
+        21953, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21954, 21956, 21956, 21956, 21956, 21958, 21958,
+        21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21958, 21960, 21960, 21960,
+        21960, 21960, 21960, 21960, 21960, 21960, 21962, 21962, 21962, 21962, 21962, 21962, 21962, 21962, 21962, 21962, 21964, 21966, 21966, 21968,
+        21968, 21970, 21970, 21972, 21972, 21972, 21972, 21972, 21974, 21974, 21974, 21974, 21974, 21974, 21974, 21974, 21976, 21976, 21976, 21978,
+        21978, 21978, 21980, 21980, 21980, 21980, 21980, 21980, 21980, 21982, 21982, 21982, 21982, 21982, 21982, 21982, 21982, 21984, 21984, 21984,
+        21984, 21984, 21984, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986, 21986,
+        21986, 21986, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21988, 21990, 21990, 21992, 21994, 21996,
+        21996, 21996, 21996, 21999, 21999, 21999, 21999, 22001, 22001, 22001, 22001, 22001, 22001, 22001, 22001, 22001, 22002, 22004, 22004, 22006,
+        22006, 22006, 22009, 22009, 22009, 22010, 22010, 22010, 22012, 22014, 22014, 22014, 22014, 22017, 22017, 22017, 22017, 22017, 22019, 22021,
+        22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021,
+        22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021, 22021,
+        22021, 22021, 22021, 22021, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023, 22023,
+        22023, 22025, 22025, 22025, 22025, 22025, 22025, 22025, 22025, 22025, 22026, 22026, 22027, 22027, 22031, 22031, 22031, 22033, 22033, 22035,
+        22035, 22035, 22035, 22040, 22040, 22040, 22040, 22042, 22042, 22042, 22042, 22042, 22042, 22042, 22042, 22042, 22042, 22042, 22044, 22044,
+        22044, 22044, 22044, 22044, 22044, 22044, 22044, 22044, 22044, 22044, 22044, 22046, 22046, 22046, 22046, 22046, 22050, 22053, 22053, 22053,
+        22053, 22053, 22053, 22053, 22053, 22053, 22053, 22053, 22053, 22055, 22055, 22055, 22055, 22055, 22055, 22055, 22055, 22058, 22060, 22060,
+        22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22060, 22062, 22062, 22062,
+        22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22062, 22064, 22068, 22068, 22068,
+        22068, 22068, 22068, 22068, 22068, 22068, 22068, 22068, 22070, 22070, 22070, 22070, 22070, 22070, 22070, 22072, 22072, 22072, 22072, 22072,
+        22072, 22072, 22074, 22074, 22074, 22074, 22082, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084, 22084,
+        22084, 22084, 22086, 22086, 22086, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089, 22089,
+        22089, 22091, 22099, 22101, 22101, 22101, 22101, 22103, 22103, 22103, 22103, 22103, 22103, 22103, 22106, 22106, 22106, 22106, 22106, 22106,
+        22106, 22106, 22106, 22106, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22108, 22110, 22110, 22110,
+        22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110, 22110,
+        22112, 22112, 22112, 22112, 22112, 22112, 22112, 22112, 22114, 22114, 22114, 22114, 22114, 22114, 22114, 22114, 22114, 22114, 22114, 22114,
+        22114, 22114, 22114, 22116, 22116, 22116, 22116, 22116, 22116, 22117, 22117, 22117, 22119, 22121, 22121, 22121, 22121, 22121, 22123, 22123,
+        22123, 22123, 22123, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125, 22125,
+        22125, 22125, 22125, 22125, 22125, 22125, 22125, 22127, 22127, 22127, 22127, 22127, 22127, 22127, 22132, 22132, 22132, 22132, 22132, 22132,
+        22134, 22134, 22134, 22134, 22134, 22134, 22134, 22136, 22136, 22138, 22138, 22138, 22140, 22141, 22141, 22141, 22141, 22143, 22143, 22145,
+        22145, 22145, 22145, 22145, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148, 22148,
+        22148, 22148, 22148, 22148, 22148, 22149, 22149, 22149, 22149, 22149, 22149, 22149, 22149, 22149, 22149, 22152, 22152, 22152, 22152, 22152,
+        22152, 22152, 22154, 22154, 22154, 22154, 22156, 22156, 22156, 22156, 22160, 22160, 22160, 22160, 22160, 22160, 22160, 22161, 22161, 22161,
+        22161, 22161, 22161, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163, 22163,
+        22163, 22163, 22165, 22165, 22167, 22167, 22167, 22167, 22169, 22171, 22173, 22173, 22173, 22173, 22173, 22175, 22175, 22175, 22175, 22175,
+        22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175, 22175,
+        22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176, 22176,
+        22176, 22176, 22176, 22176, 22178, 22178, 22178, 22178, 22178, 22180, 22180, 22180, 22180, 22180, 22180, 22180, 22180, 22182, 22182, 22184,
+        22184, 22184, 22184, 22184, 22184, 22184, 22184, 22184, 22186, 22186, 22186, 22186, 22186, 22188, 22188, 22188, 22188, 22188, 22190, 22190,
+        22190, 22190, 22191, 22191, 22191, 22191, 22191, 22191, 22192, 22192, 22192, 22192, 22192, 22192, 22193, 22193, 22193, 22193, 22193, 22193,
+        22193, 22194, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197, 22197,
+        22199, 22199, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201, 22201,
+        22201, 22201, 22201, 22201, 22201, 22201, 22201, 22206, 22210, 22212, 22212, 22212, 22212, 22212, 22212, 22212, 22212, 22212, 22212, 22212,
+        22215, 22215, 22215, 22215, 22215, 22215, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22218, 22219,
+        22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22219, 22221,
+        22221, 22221, 22221, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223, 22223,
+        22223, 22225, 22225, 22225, 22225, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227,
+        22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227, 22227,
+        22227, 22227, 22229, 22229, 22229, 22229, 22231, 22231, 22233, 22233, 22233, 22233, 22235, 22237, 22237, 22237, 22237, 22237, 22237, 22237,
+        22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237,
+        22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22237, 22239, 22239, 22241, 22241, 22244, 22244, 22246, 22246, 22249,
+        22249, 22251, 22251, 22251, 22251, 22251, 22251, 22251, 22251, 22251, 22251, 22253, 22255, 22255, 22255, 22255, 22255, 22255, 22255, 22255,
+        22257, 22257, 22257, 22257, 22259, 22259, 22259, 22261, 22261, 22261, 22261, 22261, 22261, 22261, 22261, 22263, 22263, 22263, 22263, 22263,
+        22265, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267, 22267,
+        22269, 22269, 22271, 22272, 22272, 22274, 22274, 22274, 22274, 22274, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275,
+        22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22275, 22276, 22276, 22278,
+        22278, 22278, 22278, 22278, 22278, 22278, 22278, 22278, 22278, 22278, 22278, 22280, 22280, 22280, 22280, 22280, 22280, 22280, 22280, 22280,
+        22280, 22280, 22280, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282, 22282,
+        22282, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284, 22284,
+        22284, 22284, 22284, 22284, 22284, 22284, 22286, 22286, 22286, 22286, 22286, 22287, 22289, 22289, 22289, 22289, 22289, 22289, 22289, 22289,
+        22291, 22293, 22293, 22293, 22293, 22293, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295, 22295,
+        22295, 22295, 22297, 22297, 22297, 22297, 22297, 22297, 22297, 22297, 22301, 22301, 22301, 22301, 22301, 22301, 22301, 22301, 22301, 22301,
+        22301, 22301, 22301, 22301, 22301, 22301, 22301, 22301, 22301, 22304, 22304, 22304, 22304, 22304, 22304, 22304, 22304, 22304, 22304, 22304,
+        22304, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306,
+        22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22306, 22308, 22308, 22308, 22308, 22308,
+        22308, 22308, 22308, 22308, 22308, 22310, 22310, 22310, 22312, 22312, 22312, 22312, 22312, 22312, 22312, 22314, 22314, 22314, 22316, 22316,
+        22316, 22316, 22316, 22316, 22316, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318, 22318,
+        22318, 22318, 22318, 22318, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22319, 22321, 22321,
+        22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321, 22321,
+        22321, 22323, 22323, 22325, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328, 22328,
+        22328, 22328, 22328, 22328, 22332, 22332, 22332, 22334, 22334, 22334, 22334, 22336, 22336, 22336, 22336, 22336, 22338, 22338, 22338, 22338,
+        22338, 22338, 22338, 22338, 22338, 22338, 22338, 22341, 22341, 22341, 22341, 22341, 22341, 22343, 22345, 22345, 22347, 22347, 22347, 22347,
+        22347, 22349, 22349, 22349, 22349, 22349, 22351, 22351, 22353, 22355, 22355, 22355, 22355, 22355, 22355, 22355, 22355, 22357, 22360, 22360,
+        22360, 22360, 22360, 22360, 22360, 22362, 22365, 22365, 22365, 22365, 22367, 22367, 22367, 22367, 22367, 22369, 22369, 22369, 22369, 22369,
+        22369, 22369, 22369, 22369, 22369, 22373, 22373, 22373, 22375, 22375, 22375, 22378, 22381, 22384, 22386, 22391, 22396, 22398, 22400, 22400,
+        22402, 22402, 22405, 22411, 22414, 22421, 22423, 22425, 22427, 22427, 22427, 22427, 22429, 22429, 22431, 22431, 22431, 22433, 22433, 22433,
+        22433, 22433, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22435, 22437, 22437, 22439, 22439, 22441,
+        22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441, 22441,
+        22441, 22441, 22441, 22441, 22441, 22441, 22441, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443,
+        22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22443, 22445, 22445, 22445, 22445, 22445, 22445, 22445,
+        22445, 22445, 22445, 22445, 22445, 22445, 22445, 22445, 22445, 22445, 22447, 22449, 22449, 22449, 22451, 22451, 22451, 22451, 22451, 22451,
+        22451, 22451, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453,
+        22453, 22453, 22453, 22453, 22453, 22453, 22453, 22453, 22455, 22455, 22455, 22455, 22455, 22455, 22455, 22455, 22455, 22455, 22456, 22456,
+        22456, 22459, 22459, 22459, 22459, 22459, 22461, 22461, 22463, 22465, 22467, 22469, 22469, 22471, 22471, 22473, 22473, 22473, 22473, 22473,
+        22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473,
+        22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473, 22473,
+        22473, 22473, 22473, 22473, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22475, 22478, 22480,
+        22480, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22482, 22484, 22484, 22484,
+        22484, 22487, 22487, 22487, 22487, 22487, 22487, 22487, 22487, 22489, 22489, 22489, 22489, 22489, 22489, 22489, 22489, 22489, 22489, 22489,
+        22489, 22489, 22489, 22489, 22491, 22491, 22491, 22491, 22491, 22493, 22493, 22493, 22493, 22493, 22493, 22493, 22493, 22493, 22493, 22493,
+        22493, 22495, 22495, 22495, 22497, 22499, 22499, 22499, 22501, 22501, 22503, 22505, 22505, 22505, 22507, 22507, 22507, 22507, 22507, 22507,
+        22507, 22507, 22509, 22509, 22509, 22512, 22512, 22512, 22512, 22512, 22514, 22514, 22516, 22516, 22516, 22516, 22516, 22518, 22518, 22526,
+        22526, 22526, 22526, 22526, 22528, 22528, 22528, 22528, 22528, 22528, 22530, 22530, 22530, 22530, 22530, 22532, 22532, 22533, 22533, 22533,
+        22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533,
+        22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22533, 22535, 22537, 22537, 22537, 22540, 22542, 22542, 22542, 22542, 22544, 22544,
+        22545, 22547, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22549, 22551, 22551, 22553, 22553,
+        22555, 22555, 22555, 22555, 22555, 22555, 22555, 22557, 22557, 22557, 22557, 22557, 22559, 22559, 22561, 22561, 22561, 22561, 22563, 22565,
+        22565, 22565, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567, 22567,
+        22567, 22567, 22567, 22569, 22569, 22569, 22572, 22572, 22572, 22572, 22574, 22574, 22574, 22574, 22576, 22576, 22576, 22576, 22578, 22578,
+        22578, 22578, 22578, 22578, 22578, 22578, 22578, 22580, 22580, 22580, 22580, 22580, 22580, 22580, 22581, 22581, 22581, 22581, 22581, 22581,
+        22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581,
+        22581, 22581, 22581, 22581, 22581, 22581, 22581, 22581, 22583, 22583, 22583, 22585, 22587, 22587, 22587, 22587, 22587, 22587, 22587, 22589,
+        22590, 22590, 22590, 22592, 22592, 22594, 22596, 22596, 22596, 22596, 22596, 22596, 22596, 22598, 22598, 22598, 22598, 22598, 22598, 22600,
+        22600, 22601, 22601, 22601, 22601, 22602, 22602, 22602, 22602, 22602, 22602, 22602, 22602, 22604, 22606, 22606, 22606, 22608, 22611, 22611,
+        22611, 22611, 22611, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613, 22613,
+        22613, 22613, 22615, 22615, 22617, 22619, 22619, 22619, 22619, 22619, 22619, 22619, 22619, 22619, 22619, 22619, 22621, 22621, 22621, 22621,
+        22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621, 22621,
+        22621, 22621, 22621, 22621, 22621, 22621, 22623, 22623, 22623, 22623, 22623, 22625, 22625, 22625, 22625, 22625, 22625, 22625, 22625, 22625,
+        22625, 22625, 22625, 22625, 22626, 22626, 22626, 22626, 22626, 22626, 22626, 22626, 22626, 22627, 22627, 22629, 22631, 22633, 22633, 22633,
+        22633, 22633, 22633, 22633, 22633, 22633, 22633, 22633, 22633, 22633, 22633, 22633, 22635, 22636, 22636, 22636, 22636, 22636, 22636, 22636,
+        22636, 22638, 22640, 22640, 22640, 22640, 22640, 22642, 22642, 22644, 22646, 22646, 22648, 22650, 22654, 22656, 22656, 22656, 22656, 22656,
+        22656, 22656, 22656, 22656, 22656, 22656, 22658, 22660, 22664, 22664, 22664, 22667, 22670, 22673, 22677, 22679, 22687, 22687, 22690, 22693,
+        22693, 22693, 22693, 22693, 22693, 22693, 22693, 22693, 22693, 22694, 22694, 22694, 22694, 22694, 22694, 22694, 22694, 22694, 22694, 22698,
+        22698, 22703, 22703, 22703, 22703, 22705, 22705, 22705, 22705, 22705, 22705, 22705, 22705, 22707, 22707, 22707, 22707, 22709, 22709, 22709,
+        22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709, 22709,
+        22709, 22711, 22711, 22711, 22713, 22713, 22713, 22716, 22716, 22718, 22718, 22718, 22718, 22720, 22722, 22724, 22724, 22724, 22724, 22724,
+        22724, 22724, 22724, 22724, 22726, 22726, 22726, 22726, 22726, 22726, 22729, 22729, 22729, 22729, 22729, 22729, 22729, 22729, 22729, 22731,
+        22731, 22731, 22731, 22731, 22731, 22731, 22733, 22733, 22734, 22734, 22734, 22734, 22734, 22734, 22734, 22734, 22734, 22734, 22734, 22734,
+        22734, 22734, 22734, 22734, 22737, 22737, 22737, 22737, 22737, 22739, 22739, 22739, 22739, 22739, 22739, 22739, 22739, 22739, 22739, 22739,
+        22739, 22739, 22739, 22741, 22743, 22743, 22743, 22743, 22743, 22743, 22743, 22743, 22743, 22746, 22746, 22746, 22746, 22746, 22746, 22746,
+        22748, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22750, 22752, 22752,
+        22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22752, 22754, 22756, 22758, 22758, 22758, 22758,
+        22758, 22758, 22758, 22758, 22758, 22760, 22760, 22760, 22760, 22760, 22760, 22760, 22760, 22760, 22760, 22760, 22761, 22761, 22763, 22763,
+        22763, 22763, 22763, 22763, 22763, 22763, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22765, 22767, 22767,
+        22767, 22768, 22768, 22768, 22768, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22770, 22771,
+        22771, 22772, 22772, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774, 22774,
+        22774, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775, 22775,
+        22775, 22775, 22778, 22778, 22778, 22778, 22778, 22778, 22778, 22778, 22781, 22783, 22783, 22783, 22783, 22783, 22783, 22783, 22783, 22785,
+        22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22785, 22787, 22787, 22787, 22787, 22787,
+        22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22787, 22789, 22789, 22789, 22791, 22793, 22793,
+        22793, 22793, 22793, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22794, 22796, 22796,
+        22796, 22796, 22799, 22799, 22799, 22799, 22799, 22799, 22799, 22799, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801,
+        22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22801, 22802, 22802, 22804, 22804, 22804, 22804, 22804,
+        22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804, 22804,
+        22804, 22804, 22809, 22809, 22809, 22809, 22811, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813, 22813,
+        22813, 22813, 22813, 22814, 22814, 22814, 22814, 22814, 22814, 22814, 22816, 22816, 22818, 22818, 22818, 22820, 22820, 22822, 22822, 22822,
+        22822, 22822, 22822, 22822, 22822, 22822, 22822, 22822, 22822, 22822, 22822, 22824, 22824, 22824, 22824, 22826, 22826, 22826, 22826, 22826,
+        22826, 22826, 22826, 22826, 22826, 22828, 22828, 22828, 22830, 22831, 22831, 22831, 22831, 22831, 22831, 22831, 22831, 22831, 22831, 22831,
+        22831, 22831, 22831, 22831, 22831, 22831, 22831, 22831, 22833, 22833, 22833, 22833, 22833, 22835, 22835, 22835, 22835, 22835, 22835, 22837,
+        22837, 22837, 22837, 22837, 22837, 22837, 22837, 22839, 22839, 22839, 22839, 22841, 22841, 22841, 22841, 22841, 22841, 22844, 22844, 22844,
+        22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22844, 22847, 22847, 22847, 22847, 22847, 22847,
+        22847, 22847, 22847, 22847, 22847, 22847, 22847, 22847, 22847, 22847, 22847, 22849, 22849, 22850, 22850, 22850, 22850, 22850, 22850, 22850,
+        22850, 22850, 22852, 22852, 22852, 22852, 22852, 22852, 22852, 22855, 22855, 22855, 22857, 22859, 22859, 22859, 22859, 22861, 22861, 22861,
+        22861, 22861, 22861, 22861, 22861, 22861, 22861, 22861, 22861, 22861, 22861, 22861, 22864, 22870, 22870, 22870, 22870, 22870, 22870, 22870,
+        22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870,
+        22870, 22870, 22870, 22870, 22870, 22870, 22870, 22870, 22872, 22872, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874,
+        22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22874, 22876, 22876, 22876, 22876, 22876, 22876,
+        22878, 22880, 22882, 22884, 22884, 22888, 22890, 22890, 22892, 22897, 22897, 22900, 22900, 22900, 22900, 22902, 22902, 22902, 22902, 22902,
+        22902, 22902, 22902, 22902, 22902, 22902, 22902, 22904, 22904, 22904, 22904, 22907, 22907, 22907, 22907, 22907, 22907, 22909, 22909, 22909,
+        22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909, 22909,
+        22909, 22909, 22909, 22909, 22911, 22911, 22911, 22911, 22911, 22911, 22911, 22911, 22911, 22911, 22912, 22915, 22919, 22919, 22919, 22919,
+        22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919, 22919,
+        22919, 22919, 22921, 22921, 22923, 22923, 22926, 22926, 22926, 22926, 22926, 22926, 22926, 22926, 22928, 22928, 22930, 22930, 22930, 22930,
+        22930, 22930, 22930, 22930, 22930, 22930, 22932, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934, 22934,
+        22936, 22936, 22936, 22936, 22936, 22936, 22936, 22936, 22936, 22936, 22936, 22936, 22938, 22938, 22938, 22938, 22940, 22940, 22940, 22940,
+        22940, 22940, 22940, 22940, 22940, 22940, 22940, 22940, 22940, 22940, 22941, 22941, 22941, 22941, 22941, 22941, 22941, 22941, 22943, 22945,
+        22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22945, 22948,
+        22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948, 22948,
+        22948, 22948, 22948, 22948, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951, 22951,
+        22951, 22951, 22951, 22951, 22951, 22951, 22951, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953,
+        22953, 22953, 22953, 22953, 22953, 22953, 22953, 22953, 22955, 22955, 22955, 22955, 22955, 22955, 22955, 22955, 22955, 22955, 22955, 22955,
+        22955, 22955, 22955, 22955, 22955, 22955, 22955, 22957, 22957, 22957, 22957, 22957, 22957, 22957, 22957, 22957, 22959, 22961, 22961, 22961,
+        22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22963, 22965, 22965,
+        22965, 22965, 22967, 22967, 22967, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22969, 22971, 22971, 22971,
+        22974, 22974, 22974, 22974, 22974, 22974, 22974, 22974, 22974, 22974, 22974, 22974, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976,
+        22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976,
+        22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22976, 22978,
+        22978, 22978, 22979, 22979, 22979, 22979, 22979, 22981, 22981, 22981, 22981, 22981, 22981, 22981, 22981, 22983, 22983, 22983, 22983, 22983,
+        22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22983, 22985, 22985,
+        22985, 22985, 22985, 22985, 22985, 22985, 22985, 22985, 22985, 22985, 22985, 22987, 22987, 22987, 22987, 22987, 22987, 22987, 22987, 22987,
+        22987, 22987, 22987, 22987, 22987, 22987, 22987, 22987, 22987, 22987, 22989, 22989, 22989, 22989, 22989, 22989, 22991, 22991, 22991, 22991,
+        22991, 22993, 22993, 22993, 22993, 22993, 22993, 22993, 22993, 22993, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995,
+        22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22995, 22997, 22997, 22997, 22997, 22997, 22997, 22997,
+        22997, 22999, 22999, 22999, 22999, 22999, 23001, 23001, 23001, 23001, 23001, 23001, 23004, 23004, 23004, 23006, 23006, 23008, 23011, 23011,
+        23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23011, 23013, 23016, 23016, 23016, 23016, 23016, 23016,
+        23016, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018, 23018,
+        23018, 23018, 23018, 23018, 23018, 23018, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23020, 23022, 23022,
+        23022, 23022, 23022, 23022, 23022, 23022, 23022, 23022, 23022, 23022, 23022, 23024, 23024, 23025, 23025, 23025, 23025, 23025, 23025, 23025,
+        23027, 23027, 23027, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028,
+        23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23028, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030,
+        23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030,
+        23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23030, 23032, 23032, 23032, 23032,
+        23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032, 23032,
+        23032, 23032, 23032, 23032, 23034, 23036, 23036, 23036, 23038, 23038, 23038, 23038, 23038, 23038, 23038, 23038, 23038, 23038, 23038, 23038,
+        23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23040, 23042, 23042, 23042, 23042, 23042, 23042,
+        23042, 23042, 23042, 23042, 23042, 23043, 23043, 23045, 23045, 23045, 23045, 23047, 23047, 23049, 23052, 23052, 23052, 23052, 23052, 23052,
+        23052, 23052, 23052, 23052, 23054, 23054, 23054, 23054, 23056, 23058, 23058, 23058, 23058, 23058, 23058, 23058, 23058, 23058, 23058, 23058,
+        23060, 23060, 23060, 23060, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062, 23062,
+        23062, 23062, 23064, 23064, 23068, 23068, 23068, 23070, 23070, 23070, 23072, 23072, 23072, 23072, 23072, 23072, 23072, 23072, 23075, 23075,
+        23075, 23075, 23075, 23075, 23075, 23075, 23077, 23077, 23077, 23077, 23077, 23077, 23077, 23077, 23077, 23080, 23080, 23080, 23080, 23082,
+        23084, 23084, 23086, 23088, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23090, 23092, 23092, 23092,
+        23092, 23092, 23092, 23092, 23092, 23092, 23092, 23092, 23094, 23094, 23094, 23094, 23094, 23094, 23094, 23094, 23094, 23094, 23096, 23096,
+        23096, 23096, 23098, 23098, 23098, 23100, 23102, 23102, 23102, 23102, 23102, 23102, 23102, 23102, 23102, 23102, 23102, 23104, 23104, 23104,
+        23106, 23106, 23106, 23106, 23106, 23106, 23106, 23108, 23108, 23108, 23108, 23108, 23108, 23108, 23108, 23108, 23108, 23108, 23110, 23111,
+        23111, 23113, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115,
+        23115, 23115, 23115, 23115, 23115, 23115, 23115, 23115, 23117, 23117, 23117, 23117, 23119, 23119, 23119, 23119, 23119, 23119, 23119, 23119,
+        23119, 23119, 23121, 23121, 23121, 23121, 23121, 23121, 23121, 23123, 23123, 23125, 23125, 23125, 23126, 23126, 23126, 23128, 23128, 23129,
+        23129, 23131, 23131, 23131, 23131, 23131, 23131, 23132, 23132, 23132, 23132, 23132, 23132, 23132, 23134, 23134, 23135, 23135, 23135, 23135,
+        23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135, 23135,
+        23135, 23135, 23135, 23135, 23135, 23135, 23135, 23137, 23137, 23137, 23137, 23139, 23139, 23139, 23139, 23139, 23139, 23139, 23139, 23139,
+        23139, 23139, 23139, 23139, 23141, 23141, 23141, 23141, 23141, 23141, 23141, 23141, 23143, 23143, 23143, 23143, 23143, 23145, 23145, 23145,
+        23145, 23147, 23147, 23149, 23149, 23149, 23149, 23149, 23149, 23149, 23149, 23150, 23150, 23150, 23150, 23150, 23150, 23150, 23150, 23150,
+        23150, 23152, 23152, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23154, 23156, 23158, 23158, 23158,
+        23158, 23160, 23160, 23160, 23160, 23160, 23160, 23160, 23160, 23163, 23163, 23163, 23165, 23165, 23165, 23167, 23167, 23167, 23169, 23169,
+        23169, 23171, 23173, 23173, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23174, 23177, 23177, 23177,
+        23177, 23179, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180, 23180,
+        23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23182, 23183, 23183, 23183,
+        23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183,
+        23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23183, 23185, 23185, 23187, 23187, 23188, 23188,
+        23188, 23188, 23188, 23188, 23188, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23190, 23192, 23192, 23192,
+        23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192, 23192,
+        23192, 23192, 23192, 23192, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23194, 23196, 23196, 23196, 23196,
+        23196, 23196, 23196, 23196, 23196, 23196, 23196, 23196, 23196, 23196, 23200, 23200, 23200, 23200, 23200, 23200, 23200, 23200, 23200, 23200,
+        23202, 23202, 23202, 23202, 23202, 23202, 23202, 23202, 23202, 23204, 23206, 23206, 23206, 23208, 23208, 23208, 23208, 23208, 23208, 23208,
+        23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208,
+        23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208,
+        23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208,
+        23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23208, 23210, 23210, 23210, 23210, 23210, 23210, 23210,
+        23210, 23210, 23210, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23212, 23213, 23213,
+        23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23213, 23215, 23217, 23217, 23217, 23217, 23220,
+        23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220, 23220,
+        23220, 23222, 23222, 23222, 23222, 23222, 23222, 23222, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224,
+        23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224, 23224,
+        23224, 23224, 23224, 23224, 23224, 23224, 23224, 23226, 23226, 23226, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227,
+        23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227,
+        23227, 23227, 23227, 23227, 23227, 23227, 23227, 23227, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229,
+        23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23229, 23230, 23230, 23230, 23230, 23230, 23230, 23230, 23230, 23230,
+        23232, 23232, 23232, 23232, 23232, 23232, 23232, 23232, 23232, 23232, 23232, 23232, 23234, 23234, 23234, 23234, 23234, 23234, 23234, 23234,
+        23234, 23234, 23234, 23234, 23234, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237,
+        23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237,
+        23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23237, 23238, 23238, 23238, 23238, 23238,
+    ],
+    &[
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 2,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 3, 1, 0, 0, 0, 0, 0, 1,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+        1, 4, 0, 0, 0, 5, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 2, 6, 0, 0,
+        0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 7, 0, 0, 0, 0, 0, 8, 0,
+        1, 0, 1, 0, 0, 0, 1, 0, 1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0,
+        0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 9, 0, 0, 1, 0, 2, 0, 1, 1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+        0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 1, 10, 11, 0, 0, 0, 1, 0, 1,
+        0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 1,
+        0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 5, 0, 0, 6, 0, 0, 0, 0,
+        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0,
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0,
+        0, 1, 1, 0, 0, 1, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+        1, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0,
+        6, 0, 0, 0, 0, 14, 1, 0, 1, 2, 2, 0, 0, 1, 0, 1, 0, 0, 1, 0,
+        0, 0, 0, 7, 0, 1, 0, 0, 0, 1, 0, 5, 0, 0, 2, 0, 0, 1, 0, 2,
+        0, 1, 0, 1, 0, 0, 1, 2, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 5,
+        2, 0, 2, 0, 0, 1, 5, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 14, 0, 0, 15, 0, 0, 1, 1, 1, 0, 2, 3, 1, 1, 0, 16, 0, 0,
+        0, 0, 5, 2, 0, 1, 0, 17, 1, 2, 0, 0, 0, 1, 0, 0, 18, 0, 0, 3,
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 19, 20, 21, 0, 0, 7, 22, 0, 0,
+        0, 0, 1, 0, 0, 0, 2, 1, 0, 5, 0, 2, 23, 0, 2, 0, 0, 2, 24, 0,
+        0, 0, 1, 2, 0, 0, 0, 13, 0, 1, 3, 2, 2, 5, 1, 2, 0, 2, 0, 0,
+        0, 5, 0, 5, 5, 5, 2, 25, 0, 26, 0, 0, 27, 0, 0, 20, 0, 28, 0, 0,
+        0, 0, 0, 1, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0,
+        29, 0, 1, 0, 0, 0, 0, 0, 1, 0, 6, 0, 0, 30, 5, 0, 0, 2, 1, 0,
+        0, 16, 1, 3, 0, 1, 0, 0, 1, 0, 0, 1, 31, 0, 0, 0, 0, 0, 0, 1,
+        26, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 32, 33, 0, 0, 0, 0, 0, 33,
+        0, 0, 3, 34, 1, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 1, 0, 5, 0,
+        1, 0, 0, 0, 2, 2, 2, 5, 0, 0, 2, 0, 1, 0, 0, 0, 0, 0, 33, 0,
+        0, 2, 16, 0, 35, 1, 5, 2, 31, 0, 6, 36, 37, 2, 1, 0, 1, 0, 2, 7,
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2,
+        2, 3, 1, 1, 1, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38,
+        2, 2, 5, 7, 1, 0, 2, 0, 2, 2, 0, 0, 0, 2, 3, 1, 0, 39, 0, 6,
+        0, 0, 0, 0, 1, 0, 27, 2, 40, 0, 0, 0, 0, 0, 2, 1, 3, 3, 1, 3,
+        0, 2, 0, 0, 0, 1, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 5, 1, 5, 5, 2, 5, 0, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0,
+        0, 0, 1, 41, 0, 0, 1, 0, 17, 1, 0, 0, 0, 5, 1, 40, 42, 5, 43, 23,
+        0, 5, 44, 0, 0, 0, 0, 1, 41, 0, 0, 6, 41, 0, 0, 0, 0, 2, 0, 0,
+        0, 0, 0, 0, 1, 1, 5, 1, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 2, 2, 1, 3, 5, 1, 5, 1, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 45,
+        0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 5, 1,
+        3, 5, 3, 5, 46, 1, 27, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0,
+        0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 47, 2, 5, 2, 2, 0, 0, 0,
+        1, 0, 1, 0, 0, 1, 1, 3, 1, 17, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+        0, 0, 0, 40, 48, 1, 1, 49, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 7,
+        1, 0, 2, 0, 0, 6, 8, 0, 0, 0, 0, 0, 0, 0, 2, 16, 0, 1, 0, 0,
+        0, 0, 2, 1, 3, 1, 1, 1, 5, 3, 0, 0, 0, 0, 0, 1, 5, 2, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 7, 0, 2, 0, 1, 0, 0, 0, 0, 0,
+        1, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 1, 1, 40, 52, 2, 1,
+        1, 53, 0, 0, 0, 0, 1, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        5, 1, 5, 0, 0, 1, 0, 0, 0, 0, 3, 1, 1, 3, 0, 2, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 16, 0, 0, 3, 1, 0, 54, 0, 27, 0, 1, 0, 5, 1, 1,
+        0, 0, 0, 0, 0, 0, 55, 56, 3, 55, 0, 1, 30, 0, 1, 2, 2, 1, 0, 3,
+        57, 0, 0, 6, 2, 58, 0, 0, 0, 0, 2, 0, 0, 2, 0, 23, 1, 2, 5, 3,
+        0, 17, 23, 59, 0, 0, 0, 1, 1, 2, 0, 1, 2, 46, 0, 0, 0, 0, 55, 1,
+        0, 55, 0, 5, 0, 0, 0, 0, 1, 1, 44, 3, 0, 0, 0, 0, 0, 5, 60, 46,
+        60, 61, 0, 0, 1, 1, 0, 1, 62, 2, 0, 2, 5, 0, 7, 0, 35, 5, 0, 33,
+        0, 1, 0, 0, 0, 0, 0, 1, 0, 2, 2, 1, 0, 54, 60, 0, 1, 0, 59, 63,
+        0, 22, 1, 1, 0, 0, 0, 59, 2, 3, 0, 1, 0, 41, 1, 0, 0, 0, 31, 0,
+        0, 5, 17, 0, 0, 0, 23, 64, 33, 0, 65, 2, 7, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 2, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 66, 0, 0, 0, 0,
+        0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 27, 0, 27, 0, 0, 0, 1, 1, 5, 0,
+        0, 0, 0, 0, 0, 1, 0, 0, 1, 2, 2, 5, 0, 0, 59, 0, 0, 31, 0, 0,
+        67, 0, 0, 0, 0, 1, 0, 0, 2, 1, 2, 0, 1, 0, 0, 0, 0, 1, 2, 1,
+        1, 2, 0, 5, 1, 0, 0, 0, 0, 1, 0, 0, 0, 68, 7, 2, 5, 2, 0, 66,
+        1, 7, 0, 7, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 1, 1, 5,
+        1, 1, 1, 1, 69, 6, 5, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 1, 56,
+        1, 2, 5, 0, 0, 2, 0, 1, 2, 2, 0, 1, 0, 5, 0, 5, 0, 45, 6, 0,
+        1, 0, 70, 56, 0, 0, 0, 0, 1, 0, 1, 1, 71, 5, 2, 0, 0, 1, 0, 0,
+        0, 0, 54, 1, 5, 8, 2, 5, 0, 5, 0, 0, 0, 0, 5, 0, 0, 2, 0, 2,
+        1, 5, 1, 0, 0, 0, 0, 0, 0, 0, 71, 1, 2, 72, 1, 2, 0, 0, 0, 1,
+        1, 2, 0, 2, 5, 0, 1, 0, 5, 2, 0, 0, 0, 1, 5, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 5, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 1, 16, 0, 0, 0, 0, 0,
+        0, 2, 0, 0, 0, 0, 0, 1, 1, 1, 2, 0, 1, 0, 0, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 2, 74, 75, 6, 6, 0, 0, 2, 1, 0, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 17, 71, 0, 1, 5, 0, 5, 1, 0, 2,
+        0, 1, 0, 0, 5, 0, 0, 0, 2, 2, 0, 1, 0, 55, 0, 0, 55, 17, 0, 0,
+        0, 0, 0, 0, 1, 2, 0, 76, 0, 0, 1, 1, 0, 1, 0, 5, 1, 0, 0, 3,
+        1, 1, 1, 24, 0, 2, 77, 2, 1, 5, 2, 0, 0, 0, 1, 78, 0, 0, 0, 0,
+        0, 2, 17, 0, 0, 31, 0, 0, 6, 60, 27, 0, 0, 0, 0, 1, 0, 0, 17, 0,
+        0, 5, 0, 5, 0, 1, 2, 2, 2, 72, 5, 0, 1, 1, 0, 0, 0, 1, 2, 0,
+        0, 0, 0, 0, 0, 44, 5, 2, 1, 17, 79, 2, 1, 5, 2, 2, 0, 5, 2, 2,
+        5, 2, 1, 0, 0, 1, 0, 41, 1, 80, 0, 0, 3, 0, 0, 1, 6, 6, 7, 5,
+        1, 81, 0, 0, 0, 0, 0, 41, 0, 7, 0, 2, 2, 1, 5, 81, 82, 0, 0, 5,
+        2, 7, 2, 0, 0, 3, 5, 5, 0, 2, 5, 83, 5, 0, 2, 0, 17, 0, 0, 0,
+        0, 0, 5, 0, 5, 3, 27, 84, 6, 3, 0, 1, 0, 0, 1, 2, 0, 0, 5, 0,
+        0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 5, 2, 0, 5, 0, 41, 0, 0,
+        5, 0, 0, 0, 6, 0, 2, 0, 0, 2, 85, 0, 0, 0, 54, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 71, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 2,
+        5, 5, 0, 0, 0, 0, 7, 1, 0, 5, 1, 31, 0, 7, 0, 0, 0, 0, 2, 0,
+        0, 59, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0,
+        86, 2, 1, 5, 2, 0, 0, 0, 0, 87, 88, 1, 5, 0, 0, 5, 1, 0, 0, 0,
+        0, 0, 0, 2, 0, 0, 1, 2, 5, 0, 0, 0, 0, 0, 2, 0, 16, 0, 0, 0,
+        2, 2, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 3, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+        3, 0, 0, 0, 0, 0, 2, 0, 89, 1, 2, 2, 1, 0, 1, 2, 2, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 38, 0, 1, 3, 0, 9, 90, 2, 0, 0,
+        0, 1, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 91, 1, 1, 1, 1,
+        27, 3, 1, 0, 0, 0, 17, 0, 17, 5, 2, 1, 0, 2, 55, 0, 0, 0, 0, 0,
+        2, 2, 2, 3, 0, 60, 23, 1, 2, 0, 0, 1, 0, 0, 92, 0, 0, 0, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 5,
+        5, 2, 1, 2, 1, 2, 0, 1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 2,
+        5, 0, 0, 0, 0, 0, 0, 0, 1, 93, 1, 1, 0, 0, 0, 5, 1, 59, 5, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 2, 1, 1, 1, 2, 1, 31, 16,
+        59, 0, 16, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 95, 0,
+        0, 2, 0, 2, 0, 0, 2, 5, 5, 5, 96, 2, 1, 2, 0, 3, 1, 1, 1, 2,
+        1, 5, 1, 1, 3, 2, 2, 0, 0, 2, 1, 41, 0, 0, 0, 0, 0, 1, 0, 0,
+        2, 0, 0, 0, 0, 2, 0, 2, 2, 97, 2, 1, 5, 0, 0, 17, 0, 0, 0, 0,
+        0, 0, 98, 1, 99, 2, 5, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0,
+        0, 0, 0, 0, 2, 5, 0, 2, 0, 1, 2, 0, 0, 0, 3, 0, 1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 5, 2, 2, 2, 1, 2, 0,
+        0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 1, 0, 2, 38, 0, 0, 101, 0, 5, 2,
+        6, 0, 0, 0, 25, 2, 5, 5, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 39, 5,
+        1, 5, 0, 0, 0, 0, 2, 0, 1, 0, 2, 0, 8, 0, 2, 0, 1, 0, 25, 0,
+        0, 5, 0, 3, 1, 0, 5, 0, 0, 0, 1, 0, 0, 0, 0, 0, 102, 31, 0, 0,
+        0, 1, 0, 1, 0, 1, 0, 55, 0, 0, 1, 8, 1, 0, 0, 1, 2, 2, 2, 5,
+        16, 0, 0, 2, 2, 5, 0, 2, 1, 5, 0, 2, 0, 0, 0, 5, 0, 2, 2, 0,
+        1, 0, 5, 0, 0, 0, 0, 0, 0, 103, 0, 2, 0, 0, 16, 3, 2, 5, 2, 5,
+        104, 46, 2, 1, 0, 1, 17, 0, 2, 5, 0, 0, 0, 1, 0, 0, 1, 0, 16, 3,
+        104, 0, 0, 0, 105, 0, 1, 0, 0, 106, 2, 0, 0, 1, 1, 0, 2, 107, 108, 3,
+        109, 0, 0, 0, 5, 0, 0, 0, 1, 0, 19, 16, 0, 0, 110, 17, 0, 0, 0, 1,
+        0, 0, 17, 1, 6, 0, 0, 27, 0, 0, 0, 0, 5, 0, 2, 2, 2, 0, 0, 0,
+        0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 5, 0, 5,
+        5, 1, 1, 3, 1, 2, 2, 1, 1, 0, 0, 0, 0, 44, 0, 0, 17, 0, 0, 0,
+        0, 0, 0, 0, 3, 41, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+        1, 0, 0, 1, 0, 5, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 5, 0, 0,
+        0, 0, 1, 31, 0, 0, 0, 1, 1, 1, 31, 0, 0, 1, 5, 0, 0, 0, 1, 0,
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 6, 2, 1, 2, 2, 5,
+        2, 0, 0, 0, 0, 0, 1, 0, 0, 2, 3, 0, 2, 0, 5, 1, 0, 1, 0, 0,
+        2, 1, 0, 1, 1, 1, 5, 0, 2, 31, 1, 0, 5, 0, 0, 0, 0, 0, 0, 111,
+        0, 112, 1, 1, 3, 2, 31, 1, 5, 59, 0, 0, 60, 0, 0, 0, 0, 46, 1, 7,
+        0, 0, 2, 0, 1, 5, 2, 54, 0, 0, 5, 2, 5, 1, 7, 0, 1, 1, 88, 1,
+        2, 1, 2, 0, 72, 0, 0, 5, 0, 2, 5, 2, 0, 0, 0, 0, 0, 0, 2, 5,
+        7, 0, 0, 0, 0, 0, 0, 0, 16, 5, 2, 0, 1, 2, 3, 1, 0, 0, 0, 0,
+        0, 1, 0, 0, 7, 0, 0, 0, 0, 1, 0, 0, 3, 5, 7, 1, 0, 0, 0, 0,
+        0, 0, 2, 6, 0, 0, 0, 1, 1, 0, 0, 17, 0, 5, 2, 0, 1, 0, 0, 2,
+        0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 3,
+        1, 2, 5, 1, 3, 1, 0, 16, 0, 1, 0, 1, 0, 0, 5, 0, 113, 0, 0, 0,
+        0, 0, 0, 0, 114, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        31, 7, 1, 17, 16, 3, 7, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
+        0, 0, 0, 0, 0, 17, 0, 16, 0, 0, 0, 0, 0, 115, 0, 0, 0, 56, 0, 5,
+        0, 5, 2, 0, 5, 17, 1, 0, 0, 1, 0, 1, 6, 0, 8, 0, 40, 0, 7, 0,
+        0, 0, 0, 0, 2, 0, 0, 0, 1, 0, 2, 2, 0, 0, 0, 0, 0, 0, 7, 0,
+        2, 83, 5, 8, 7, 0, 0, 59, 0, 0, 5, 5, 0, 0, 0, 1, 0, 71, 5, 0,
+        0, 5, 0, 0, 0, 0, 0, 1, 31, 0, 0, 0, 3, 0, 3, 0, 0, 0, 1, 1,
+        0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+        1, 0, 3, 0, 3, 5, 2, 0, 0, 1, 2, 1, 7, 116, 0, 0, 1, 0, 0, 0,
+        1, 0, 1, 0, 2, 33, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1,
+        1, 0, 5, 0, 1, 0, 0, 2, 3, 6, 0, 0, 0, 55, 0, 3, 5, 2, 0, 0,
+        0, 1, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 2, 0, 0,
+        5, 5, 5, 1, 5, 0, 5, 1, 0, 0, 0, 1, 1, 0, 0, 5, 0, 0, 0, 0,
+        2, 0, 2, 0, 0, 0, 1, 2, 55, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 5, 2, 25, 3,
+        6, 6, 45, 5, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+        0, 0, 1, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 2, 1, 1, 0, 0,
+        2, 1, 2, 2, 0, 0, 1, 0, 0, 0, 0, 0, 6, 7, 0, 0, 5, 2, 0, 0,
+        0, 0, 0, 5, 0, 0, 0, 0, 2, 2, 2, 5, 5, 5, 0, 5, 2, 0, 5, 0,
+        1, 2, 2, 2, 1, 5, 2, 0, 5, 117, 6, 1, 19, 31, 54, 0, 0, 1, 2, 0,
+        118, 2, 36, 119, 0, 120, 94, 121, 0, 1, 0, 0, 0, 0, 0, 31, 0, 0, 5, 13,
+        5, 122, 1, 5, 20, 0, 0, 6, 0, 27, 0, 0, 0, 1, 0, 0, 58, 31, 0, 0,
+        31, 0, 2, 5, 0, 1, 0, 17, 2, 123, 46, 31, 5, 5, 5, 14, 0, 0, 0, 7,
+        1, 0, 1, 0, 0, 6, 0, 124, 0, 5, 125, 1, 1, 5, 3, 126, 0, 5, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 17, 0, 0, 0, 0,
+        3, 7, 3, 5, 3, 0, 0, 0, 2, 2, 0, 16, 127, 6, 94, 45, 6, 117, 0, 0,
+        1, 71, 2, 5, 0, 6, 2, 1, 0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+        0, 1, 0, 0, 0, 1, 3, 5, 0, 0, 6, 0, 6, 0, 0, 0, 0, 0, 1, 1,
+        0, 0, 89, 0, 1, 1, 5, 0, 3, 0, 23, 0, 128, 0, 0, 1, 20, 1, 0, 40,
+        1, 2, 1, 1, 5, 1, 6, 0, 0, 5, 0, 129, 0, 1, 2, 5, 6, 0, 2, 2,
+        1, 0, 0, 38, 0, 23, 0, 2, 0, 0, 0, 5, 0, 5, 0, 5, 59, 1, 2, 5,
+        2, 2, 1, 1, 6, 1, 1, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2,
+        0, 5, 0, 41, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 1, 1, 2, 2,
+        0, 0, 5, 0, 0, 0, 0, 5, 0, 5, 5, 1, 1, 2, 0, 0, 0, 2, 0, 0,
+        0, 6, 2, 0, 1, 7, 7, 0, 0, 6, 130, 0, 0, 5, 0, 0, 0, 0, 17, 56,
+        2, 0, 17, 0, 0, 0, 1, 0, 0, 7, 0, 5, 2, 2, 0, 0, 5, 1, 2, 7,
+        7, 0, 0, 0, 7, 45, 2, 131, 3, 31, 17, 31, 44, 16, 0, 0, 2, 2, 0, 0,
+        0, 0, 0, 0, 45, 0, 0, 7, 5, 27, 1, 1, 0, 132, 2, 0, 0, 0, 5, 5,
+        0, 2, 2, 133, 0, 44, 2, 0, 0, 5, 3, 1, 2, 5, 17, 5, 0, 0, 31, 0,
+        2, 0, 5, 16, 0, 31, 46, 17, 31, 134, 0, 0, 16, 2, 6, 0, 2, 17, 0, 0,
+        3, 0, 0, 5, 2, 135, 0, 0, 55, 7, 2, 0, 1, 0, 0, 41, 0, 0, 0, 17,
+        17, 133, 1, 0, 1, 55, 3, 2, 3, 6, 17, 0, 0, 2, 5, 0, 2, 2, 39, 2,
+        55, 5, 2, 6, 0, 8, 46, 2, 6, 17, 60, 0, 3, 3, 17, 27, 17, 0, 1, 55,
+        31, 0, 5, 136, 2, 137, 17, 17, 6, 6, 1, 6, 6, 0, 0, 5, 0, 138, 3, 1,
+        2, 112, 139, 31, 16, 133, 0, 0, 0, 0, 56, 140, 0, 2, 2, 2, 2, 5, 2, 2,
+        0, 2, 2, 0, 5, 0, 1, 3, 136, 59, 2, 6, 138, 5, 5, 5, 5, 16, 0, 59,
+        2, 94, 5, 5, 0, 16, 141, 16, 1, 33, 0, 0, 0, 0, 1, 1, 1, 23, 5, 3,
+        6, 0, 27, 6, 17, 1, 1, 5, 0, 31, 0, 0, 0, 1, 0, 0, 0, 0, 0, 142,
+        3, 0, 0, 0, 5, 2, 16, 0, 0, 31, 143, 144, 17, 17, 1, 1, 0, 5, 14, 0,
+        17, 24, 0, 0, 0, 0, 145, 0, 0, 0, 0, 1, 17, 0, 5, 0, 20, 5, 60, 31,
+        0, 44, 1, 0, 5, 0, 7, 0, 60, 0, 8, 2, 2, 31, 0, 0, 0, 7, 59, 0,
+        0, 0, 0, 0, 55, 0, 20, 0, 0, 55, 2, 0, 3, 59, 0, 17, 59, 17, 6, 0,
+        0, 2, 6, 5, 17, 1, 1, 0, 0, 41, 44, 44, 1, 0, 1, 27, 0, 0, 0, 0,
+        27, 44, 146, 3, 27, 2, 31, 0, 31, 19, 2, 2, 0, 6, 60, 44, 60, 47, 0, 21,
+        1, 0, 46, 27, 17, 27, 2, 0, 2, 0, 2, 0, 0, 0, 0, 59, 17, 0, 59, 16,
+        0, 0, 23, 45, 1, 0, 3, 1, 0, 147, 19, 27, 17, 55, 32, 31, 31, 1, 148, 3,
+        0, 2, 0, 0, 2, 2, 2, 0, 44, 3, 0, 149, 0, 0, 31, 0, 2, 45, 2, 5,
+        2, 17, 17, 0, 2, 5, 34, 0, 0, 31, 3, 55, 2, 2, 2, 0, 27, 138, 22, 3,
+        2, 0, 0, 17, 0, 1, 150, 0, 17, 0, 17, 2, 1, 2, 5, 46, 5, 0, 0, 17,
+        2, 0, 0, 0, 5, 1, 151, 1, 0, 16, 0, 0, 1, 0, 1, 0, 20, 6, 0, 59,
+        0, 55, 0, 6, 3, 60, 1, 2, 1, 0, 2, 1, 5, 0, 16, 152, 33, 17, 2, 69,
+        3, 27, 2, 0, 41, 5, 2, 3, 5, 30, 1, 1, 1, 31, 46, 16, 2, 1, 31, 0,
+        0, 0, 1, 58, 150, 44, 1, 0, 1, 17, 7, 54, 153, 6, 1, 0, 1, 6, 0, 5,
+        2, 2, 154, 25, 1, 1, 1, 1, 2, 1, 3, 0, 0, 5, 0, 8, 17, 0, 31, 0,
+        54, 0, 5, 17, 17, 16, 41, 0, 46, 1, 1, 2, 8, 2, 5, 0, 2, 155, 0, 0,
+        155, 0, 0, 94, 0, 5, 26, 144, 0, 0, 1, 156, 0, 0, 0, 0, 0, 0, 7, 2,
+        0, 1, 16, 0, 0, 0, 0, 0, 5, 0, 2, 5, 2, 2, 0, 0, 0, 0, 0, 0,
+        157, 71, 0, 0, 20, 0, 5, 31, 0, 5, 41, 2, 0, 41, 0, 1, 0, 7, 2, 5,
+        2, 0, 6, 0, 3, 0, 13, 0, 1, 2, 5, 0, 0, 0, 17, 0, 5, 6, 3, 41,
+        2, 158, 0, 5, 0, 1, 7, 7, 1, 3, 1, 7, 2, 2, 8, 2, 129, 1, 0, 0,
+        41, 55, 2, 2, 5, 0, 0, 2, 5, 0, 0, 0, 2, 2, 155, 0, 3, 16, 1, 5,
+        17, 2, 159, 16, 69, 2, 45, 2, 2, 5, 2, 6, 3, 0, 33, 59, 2, 31, 1, 0,
+        2, 59, 1, 1, 3, 5, 2, 31, 117, 0, 1, 32, 6, 27, 5, 0, 2, 1, 41, 39,
+        160, 17, 54, 7, 0, 5, 33, 0, 6, 0, 0, 126, 2, 1, 1, 1, 1, 31, 129, 0,
+        6, 5, 1, 27, 0, 41, 2, 5, 133, 0, 41, 2, 41, 0, 0, 1, 0, 5, 0, 7,
+        6, 41, 0, 6, 5, 0, 0, 1, 2, 0, 0, 0, 0, 0, 1, 2, 161, 1, 5, 1,
+        1, 16, 20, 0, 1, 5, 83, 162, 71, 0, 0, 2, 2, 2, 2, 0, 162, 0, 1, 0,
+        0, 2, 0, 6, 20, 0, 6, 0, 163, 0, 2, 33, 1, 1, 0, 2, 5, 41, 0, 0,
+        0, 6, 0, 31, 0, 3, 2, 2, 0, 31, 3, 5, 0, 0, 0, 16, 0, 16, 0, 5,
+        41, 164, 6, 165, 39, 129, 1, 23, 2, 16, 0, 1, 0, 64, 1, 1, 0, 0, 0, 0,
+        6, 0, 166, 1, 55, 2, 5, 0, 1, 0, 0, 2, 0, 2, 5, 3, 0, 17, 5, 1,
+        1, 0, 0, 0, 2, 31, 1, 5, 0, 1, 32, 0, 16, 6, 0, 17, 0, 0, 3, 6,
+        17, 2, 2, 0, 41, 17, 5, 5, 0, 5, 0, 16, 2, 1, 0, 2, 6, 0, 0, 2,
+        2, 39, 0, 0, 5, 7, 0, 1, 1, 0, 0, 33, 3, 1, 165, 0, 20, 1, 6, 0,
+        5, 2, 0, 0, 14, 1, 7, 3, 0, 71, 6, 0, 7, 31, 2, 0, 160, 69, 2, 45,
+        1, 0, 3, 0, 0, 27, 3, 1, 7, 0, 2, 0, 1, 2, 1, 0, 0, 3, 0, 0,
+        0, 2, 0, 0, 127, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 41, 2, 17, 0,
+        0, 0, 31, 33, 17, 0, 0, 41, 1, 0, 2, 0, 0, 0, 0, 0, 3, 44, 0, 0,
+        0, 0, 1, 6, 0, 5, 0, 0, 0, 0, 1, 0, 27, 0, 0, 41, 0, 0, 0, 17,
+        0, 0, 27, 0, 5, 0, 6, 0, 31, 20, 6, 0, 17, 33, 0, 0, 0, 0, 126, 17,
+        6, 1, 2, 3, 27, 2, 3, 2, 3, 2, 167, 0, 0, 17, 0, 1, 0, 0, 0, 16,
+        0, 0, 0, 0, 0, 0, 55, 0, 0, 31, 1, 1, 2, 0, 0, 0, 0, 1, 54, 0,
+        2, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 2, 0, 16, 0, 0, 0, 1, 46, 2,
+        0, 0, 0, 2, 0, 0, 1, 168, 2, 2, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0,
+        0, 0, 0, 0, 5, 0, 2, 8, 1, 0, 2, 0, 0, 0, 127, 5, 129, 0, 0, 5,
+        0, 0, 2, 0, 2, 0, 60, 0, 0, 2, 0, 0, 1, 0, 0, 1, 44, 0, 0, 0,
+        5, 0, 27, 3, 0, 0, 17, 0, 6, 6, 17, 0, 0, 3, 44, 17, 44, 0, 0, 6,
+        1, 20, 0, 0, 0, 161, 5, 34, 5, 0, 5, 1, 2, 20, 0, 0, 0, 27, 0, 41,
+        0, 1, 0, 0, 31, 0, 1, 0, 1, 0, 0, 0, 0, 0, 31, 1, 1, 41, 41, 3,
+        6, 0, 7, 0, 17, 0, 6, 3, 2, 0, 41, 1, 6, 1, 2, 169, 2, 0, 0, 0,
+        0, 3, 2, 2, 21, 6, 0, 0, 6, 0, 17, 0, 7, 170, 2, 1, 3, 39, 3, 1,
+        0, 5, 0, 0, 0, 23, 3, 0, 1, 0, 0, 0, 0, 0, 17, 0, 7, 17, 2, 2,
+        0, 0, 0, 1, 0, 0, 0, 171, 0, 20, 6, 0, 0, 0, 0, 1, 0, 41, 2, 1,
+        0, 1, 0, 0, 31, 0, 0, 0, 6, 3, 6, 3, 17, 0, 0, 0, 0, 0, 1, 44,
+        0, 2, 33, 0, 0, 2, 0, 17, 0, 17, 1, 2, 0, 0, 2, 39, 0, 80, 172, 173,
+        5, 129, 6, 0, 1, 20, 27, 46, 3, 6, 0, 41, 0, 0, 0, 2, 59, 41, 0, 6,
+        0, 0, 0, 0, 3, 0, 27, 0, 20, 0, 0, 1, 16, 1, 0, 0, 0, 44, 6, 0,
+        5, 2, 0, 3, 0, 1, 1, 111, 127, 6, 1, 0, 0, 7, 2, 2, 0, 0, 17, 6,
+        2, 2, 174, 0, 1, 133, 27, 5, 1, 0, 1, 0, 2, 2, 1, 17, 17, 0, 41, 41,
+        41, 31, 1, 31, 0, 1, 31, 1, 0, 80, 126, 0, 1, 0, 6, 1, 2, 0, 7, 27,
+        0, 0, 3, 0, 44, 55, 0, 0, 0, 59, 175, 17, 0, 0, 31, 0, 0, 0, 55, 17,
+        59, 55, 55, 176, 0, 1, 3, 5, 5, 2, 17, 55, 0, 2, 0, 6, 1, 3, 1, 1,
+        0, 0, 2, 1, 2, 2, 5, 5, 0, 0, 5, 2, 5, 5, 31, 1, 1, 1, 2, 39,
+        6, 2, 6, 0, 2, 0, 5, 2, 2, 1, 3, 2, 177, 6, 5, 5, 33, 0, 8, 5,
+        27, 5, 2, 1, 1, 3, 0, 41, 8, 1, 6, 2, 1, 0, 2, 0, 0, 41, 0, 17,
+        0, 5, 3, 17, 0, 178, 179, 17, 44, 3, 1, 2, 3, 0, 3, 0, 1, 31, 0, 3,
+        0, 0, 0, 0, 2, 34, 0, 3, 0, 0, 3, 3, 17, 41, 2, 71, 0, 0, 6, 0,
+        0, 5, 6, 0, 2, 6, 6, 3, 3, 5, 1, 2, 80, 0, 0, 0, 0, 0, 0, 60,
+        16, 0, 54, 27, 1, 1, 2, 60, 0, 60, 0, 1, 0, 0, 0, 0, 180, 60, 1, 1,
+        59, 1, 17, 0, 0, 60, 5, 2, 55, 0, 6, 0, 1, 2, 44, 0, 2, 1, 1, 0,
+        2, 0, 0, 31, 0, 16, 0, 0, 41, 0, 17, 1, 6, 7, 33, 2, 1, 0, 3, 0,
+        16, 0, 3, 0, 0, 2, 6, 7, 45, 0, 181, 100, 1, 6, 44, 0, 0, 5, 0, 3,
+        0, 34, 2, 31, 1, 17, 6, 2, 55, 2, 2, 3, 0, 2, 27, 17, 1, 0, 0, 0,
+        182, 0, 17, 183, 0, 5, 2, 0, 2, 1, 83, 1, 0, 0, 0, 80, 1, 0, 3, 1,
+        17, 0, 3, 2, 2, 2, 2, 0, 184, 1, 2, 5, 0, 0, 0, 41, 0, 55, 47, 185,
+        0, 0, 0, 20, 5, 0, 6, 1, 1, 0, 0, 6, 0, 0, 5, 3, 2, 1, 30, 2,
+        3, 2, 17, 16, 0, 2, 0, 2, 6, 2, 1, 71, 1, 3, 1, 1, 0, 17, 45, 0,
+        17, 2, 0, 1, 1, 2, 1, 0, 2, 20, 1, 127, 5, 3, 1, 2, 59, 180, 1, 5,
+        55, 55, 0, 1, 41, 1, 20, 0, 0, 55, 6, 32, 0, 0, 16, 1, 1, 5, 31, 55,
+        0, 1, 186, 32, 0, 6, 2, 2, 3, 2, 3, 41, 0, 60, 16, 17, 5, 0, 187, 35,
+        188, 0, 55, 2, 2, 189, 2, 2, 1, 0, 1, 0, 0, 0, 5, 3, 2, 94, 5, 3,
+        3, 0, 25, 0, 0, 2, 0, 25, 55, 0, 0, 0, 0, 0, 124, 1, 190, 3, 44, 0,
+        0, 0, 17, 0, 0, 0, 0, 1, 2, 0, 2, 60, 0, 5, 1, 0, 1, 3, 191, 126,
+        0, 0, 0, 0, 134, 2, 1, 3, 59, 0, 0, 5, 0, 27, 0, 0, 31, 0, 192, 0,
+        0, 0, 0, 55, 0, 0, 0, 1, 59, 67, 0, 17, 1, 136, 5, 44, 1, 193, 1, 0,
+        1, 1, 5, 16, 34, 55, 27, 0, 0, 1, 0, 0, 60, 2, 0, 46, 25, 17, 9, 17,
+        39, 5, 7, 54, 1, 5, 59, 1, 0, 5, 0, 0, 54, 1, 44, 60, 2, 5, 2, 1,
+        0, 1, 1, 27, 44, 0, 33, 5, 0, 0, 17, 194, 5, 55, 1, 5, 2, 0, 31, 16,
+        17, 1, 1, 17, 0, 0, 0, 69, 3, 60, 0, 0, 1, 3, 27, 17, 150, 0, 31, 3,
+        41, 41, 2, 27, 195, 31, 27, 0, 5, 148, 0, 27, 2, 138, 17, 0, 129, 17, 0, 2,
+        0, 0, 0, 0, 127, 27, 17, 2, 134, 6, 5, 6, 0, 0, 196, 126, 2, 0, 0, 0,
+        0, 6, 0, 2, 197, 2, 2, 5, 6, 0, 138, 2, 0, 17, 31, 1, 55, 31, 2, 30,
+        0, 0, 2, 0, 0, 3, 134, 0, 0, 198, 2, 0, 69, 3, 0, 1, 199, 0, 31, 45,
+        0, 31, 31, 7, 94, 1, 126, 0, 200, 1, 31, 0, 0, 1, 0, 0, 7, 5, 0, 1,
+        1, 41, 0, 6, 1, 1, 1, 5, 0, 5, 69, 0, 0, 1, 0, 0, 2, 1, 17, 31,
+        0, 0, 1, 0, 17, 0, 0, 3, 1, 27, 6, 200, 7, 3, 0, 0, 6, 0, 0, 17,
+        1, 1, 5, 5, 7, 0, 0, 0, 2, 0, 17, 1, 2, 0, 80, 41, 1, 3, 1, 2,
+        6, 6, 3, 1, 146, 2, 1, 1, 1, 0, 17, 0, 80, 1, 2, 2, 41, 3, 3, 23,
+        0, 0, 17, 0, 1, 6, 0, 0, 0, 0, 0, 41, 0, 0, 190, 126, 3, 0, 31, 27,
+        2, 0, 0, 27, 0, 3, 1, 6, 41, 0, 16, 94, 16, 6, 0, 5, 7, 30, 0, 0,
+        0, 0, 41, 0, 7, 58, 134, 6, 0, 40, 7, 6, 0, 0, 31, 3, 21, 5, 0, 44,
+        6, 59, 2, 0, 2, 0, 0, 3, 2, 160, 27, 0, 5, 16, 0, 17, 0, 0, 0, 17,
+        2, 3, 0, 0, 0, 0, 5, 3, 0, 2, 5, 2, 2, 2, 5, 7, 5, 2, 94, 0,
+        201, 2, 1, 5, 0, 5, 2, 2, 2, 5, 5, 2, 0, 0, 108, 0, 5, 1, 0, 2,
+        0, 0, 0, 0, 1, 0, 1, 6, 1, 5, 0, 0, 20, 0, 6, 0, 0, 2, 2, 1,
+        1, 33, 2, 16, 0, 5, 0, 5, 0, 3, 31, 6, 1, 2, 2, 5, 6, 5, 6, 0,
+        1, 45, 2, 2, 2, 202, 2, 60, 0, 2, 1, 1, 0, 94, 6, 0, 2, 27, 203, 0,
+        0, 0, 0, 2, 204, 0, 124, 5, 0, 2, 1, 22, 205, 54, 3, 206, 0, 1, 94, 6,
+        0, 207, 208, 2, 2, 2, 0, 6, 0, 2, 6, 0, 195, 5, 1, 2, 0, 59, 2, 1,
+        0, 2, 2, 0, 6, 2, 6, 0, 0, 2, 2, 5, 6, 2, 2, 209, 31, 2, 0, 46,
+        0, 55, 27, 3, 1, 1, 0, 0, 0, 0, 27, 1, 27, 0, 0, 19, 41, 3, 0, 0,
+        31, 1, 2, 1, 0, 2, 2, 1, 0, 2, 2, 1, 3, 6, 6, 5, 6, 41, 41, 6,
+        2, 129, 210, 1, 1, 6, 0, 1, 59, 0, 45, 1, 17, 17, 0, 5, 1, 0, 0, 80,
+        1, 80, 0, 2, 0, 7, 0, 35, 2, 2, 5, 2, 1, 6, 41, 211, 54, 212, 1, 2,
+        126, 17, 1, 0, 0, 0, 1, 0, 2, 0, 129, 2, 2, 0, 1, 0, 3, 176, 30, 3,
+        1, 0, 6, 2, 0, 40, 0, 6, 0, 83, 83, 7, 1, 5, 17, 54, 3, 0, 6, 1,
+        2, 2, 0, 6, 5, 0, 1, 213, 2, 6, 31, 2, 1, 2, 41, 2, 0, 5, 1, 2,
+        20, 5, 2, 2, 2, 2, 2, 0, 41, 6, 41, 0, 6, 200, 5, 2, 3, 2, 1, 2,
+        0, 17, 0, 3, 0, 0, 2, 2, 6, 3, 27, 0, 44, 214, 58, 0, 0, 0, 0, 0,
+        6, 215, 0, 0, 7, 5, 0, 2, 1, 7, 0, 38, 6, 5, 0, 0, 6, 0, 0, 0,
+        54, 2, 216, 0, 217, 0, 0, 0, 0, 0, 8, 0, 0, 41, 0, 0, 2, 0, 0, 0,
+        0, 1, 0, 5, 2, 5, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 6, 0, 33,
+        2, 0, 0, 6, 2, 0, 54, 54, 31, 5, 6, 0, 1, 56, 1, 1, 2, 0, 0, 2,
+        54, 0, 2, 0, 6, 0, 0, 54, 0, 2, 41, 0, 2, 0, 60, 54, 0, 0, 41, 0,
+        41, 218, 2, 1, 5, 1, 7, 0, 2, 8, 54, 219, 0, 0, 0, 20, 0, 17, 0, 59,
+        0, 0, 2, 20, 60, 5, 3, 0, 23, 0, 0, 0, 5, 20, 0, 6, 0, 31, 7, 3,
+        13, 5, 8, 5, 0, 8, 3, 0, 59, 0, 59, 5, 3, 0, 55, 0, 0, 2, 0, 1,
+        0, 5, 2, 5, 5, 5, 5, 2, 11, 17, 60, 0, 5, 0, 2, 1, 0, 0, 0, 0,
+        0, 3, 0, 2, 5, 20, 6, 1, 0, 41, 6, 1, 5, 2, 2, 5, 1, 46, 1, 0,
+        2, 220, 0, 0, 59, 0, 0, 44, 5, 6, 5, 0, 0, 1, 3, 5, 1, 2, 1, 2,
+        0, 0, 1, 5, 0, 0, 0, 3, 30, 5, 0, 3, 0, 5, 1, 1, 2, 2, 23, 5,
+        6, 41, 0, 0, 2, 5, 1, 7, 221, 32, 2, 6, 2, 2, 1, 2, 2, 6, 6, 5,
+        6, 2, 1, 7, 1, 17, 1, 6, 0, 1, 0, 2, 0, 0, 2, 59, 0, 2, 6, 72,
+        0, 3, 5, 91, 2, 0, 2, 5, 31, 1, 0, 0, 0, 2, 31, 0, 3, 17, 176, 60,
+        0, 0, 0, 0, 59, 1, 0, 1, 3, 127, 31, 55, 0, 0, 2, 21, 222, 19, 2, 6,
+        5, 0, 0, 5, 0, 0, 0, 2, 33, 17, 0, 60, 60, 5, 6, 0, 46, 44, 21, 17,
+        0, 5, 7, 5, 0, 1, 0, 1, 1, 6, 223, 0, 0, 0, 0, 7, 1, 124, 0, 0,
+        0, 2, 0, 0, 0, 27, 0, 0, 0, 0, 1, 0, 3, 0, 3, 3, 45, 0, 31, 0,
+        2, 2, 6, 224, 225, 5, 0, 0, 25, 17, 2, 0, 0, 2, 0, 0, 0, 0, 31, 0,
+        0, 6, 5, 5, 5, 0, 5, 8, 226, 55, 1, 1, 2, 2, 2, 1, 5, 5, 5, 2,
+        0, 0, 46, 59, 16, 5, 6, 0, 5, 5, 5, 227, 31, 31, 0, 2, 3, 228, 229, 7,
+        0, 59, 7, 59, 2, 6, 1, 1, 1, 2, 2, 8, 7, 230, 6, 0, 5, 6, 2, 2,
+        2, 1, 1, 0, 2, 1, 0, 6, 0, 3, 0, 41, 231, 80, 2, 2, 2, 20, 126, 3,
+        3, 0, 5, 2, 1, 1, 2, 1, 33, 6, 6, 6, 0, 5, 1, 3, 6, 2, 3, 2,
+        5, 0, 2, 0, 0, 1, 6, 23, 6, 2, 5, 5, 2, 1, 5, 0, 58, 7, 0, 2,
+        1, 41, 5, 3, 2, 5, 2, 5, 2, 1, 0, 0, 6, 5, 2, 0, 0, 21, 7, 230,
+        0, 2, 0, 0, 0, 0, 54, 0, 94, 0, 232, 2, 2, 5, 2, 2, 0, 16, 2, 2,
+        5, 31, 0, 0, 0, 2, 2, 2, 0, 0, 46, 2, 0, 5, 0, 0, 0, 0, 5, 0,
+        2, 0, 2, 6, 2, 2, 0, 30, 0, 16, 2, 2, 2, 0, 2, 0, 0, 5, 0, 0,
+        0, 0, 30, 0, 0, 2, 94, 5, 0, 59, 0, 5, 0, 3, 2, 2, 5, 5, 0, 2,
+        6, 5, 5, 5, 83, 1, 94, 0, 2, 2, 2, 5, 5, 2, 59, 6, 2, 2, 2, 54,
+        0, 41, 0, 3, 2, 1, 0, 16, 1, 25, 0, 2, 5, 0, 0, 2, 0, 2, 5, 0,
+        233, 1, 0, 2, 0, 0, 2, 2, 2, 2, 2, 16, 14, 2, 2, 5, 0, 0, 14, 0,
+        5, 1, 1, 2, 69, 2, 0, 234, 7, 2, 2, 0, 0, 54, 6, 54, 5, 2, 0, 2,
+        71, 0, 5, 5, 3, 0, 0, 0, 5, 0, 2, 2, 2, 5, 2, 0, 5, 56, 0, 20,
+        8, 2, 1, 2, 2, 0, 0, 2, 0, 0, 1, 2, 0, 0, 0, 2, 16, 0, 2, 0,
+        2, 2, 2, 5, 0, 2, 20, 5, 0, 5, 0, 0, 31, 2, 235, 2, 2, 0, 16, 0,
+        30, 94, 1, 0, 13, 2, 236, 237, 238, 16, 7, 23, 1, 2, 2, 7, 5, 1, 7, 91,
+        2, 2, 5, 2, 5, 2, 2, 0, 5, 16, 2, 5, 2, 239, 1, 2, 2, 6, 2, 0,
+        0, 6, 2, 2, 2, 0, 2, 240, 2, 0, 0, 16, 0, 2, 0, 2, 0, 54, 0, 5,
+        0, 16, 16, 241, 0, 242, 2, 0, 2, 2, 0, 2, 0, 5, 0, 1, 1, 0, 31, 0,
+        0, 7, 0, 219, 2, 0, 5, 0, 1, 2, 0, 0, 2, 2, 0, 17, 17, 2, 2, 0,
+        16, 2, 0, 0, 2, 59, 0, 16, 0, 2, 2, 2, 243, 69, 2, 0, 1, 16, 2, 5,
+        16, 33, 16, 2, 3, 0, 0, 244, 0, 2, 245, 0, 7, 2, 2, 1, 7, 1, 2, 5,
+        2, 2, 16, 47, 1, 1, 0, 0, 2, 0, 2, 1, 246, 0, 5, 1, 1, 5, 6, 0,
+        5, 2, 241, 2, 2, 0, 0, 56, 140, 0, 2, 5, 2, 2, 2, 5, 83, 247, 0, 0,
+        0, 14, 5, 2, 5, 5, 2, 1, 3, 41, 3, 0, 1, 126, 2, 0, 0, 2, 0, 31,
+        2, 41, 6, 6, 27, 2, 0, 1, 22, 1, 0, 0, 41, 2, 2, 2, 0, 8, 0, 0,
+        0, 0, 0, 41, 0, 17, 5, 0, 138, 7, 5, 59, 2, 41, 0, 5, 2, 5, 17, 17,
+        6, 1, 1, 0, 248, 5, 1, 31, 0, 3, 45, 0, 94, 0, 0, 16, 16, 117, 0, 5,
+        0, 0, 31, 41, 0, 1, 83, 1, 20, 0, 6, 0, 0, 0, 0, 1, 0, 0, 2, 0,
+        0, 0, 2, 1, 249, 6, 2, 1, 31, 133, 0, 0, 2, 217, 136, 0, 0, 1, 0, 44,
+        7, 2, 126, 6, 16, 20, 0, 0, 0, 0, 1, 1, 17, 0, 6, 55, 69, 0, 250, 251,
+        1, 176, 0, 200, 0, 7, 7, 2, 0, 31, 0, 0, 3, 16, 2, 27, 252, 0, 23, 1,
+        5, 1, 0, 17, 0, 253, 2, 0, 1, 1, 0, 0, 1, 6, 3, 0, 0, 5, 1, 30,
+        0, 0, 1, 1, 44, 5, 0, 27, 14, 31, 2, 5, 254, 0, 2, 16, 0, 134, 0, 5,
+        0, 6, 5, 3, 0, 17, 1, 0, 0, 0, 2, 27, 1, 0, 59, 0, 2, 5, 2, 1,
+        1, 2, 255, 0, 2, 1, 3, 0, 5, 2, 256, 1, 59, 17, 31, 161, 1, 3, 11, 7,
+        41, 0, 126, 44, 2, 1, 2, 157, 16, 129, 5, 2, 2, 257, 0, 3, 6, 145, 1, 0,
+        2, 0, 133, 3, 2, 0, 2, 0, 27, 2, 7, 2, 2, 3, 0, 7, 83, 2, 2, 5,
+        3, 3, 0, 0, 1, 2, 1, 27, 8, 31, 5, 0, 5, 1, 17, 2, 1, 0, 0, 0,
+        129, 2, 258, 2, 20, 0, 7, 5, 0, 0, 17, 5, 17, 54, 2, 0, 0, 3, 55, 0,
+        1, 17, 2, 0, 0, 2, 0, 1, 2, 2, 54, 0, 2, 20, 5, 2, 7, 0, 5, 0,
+        0, 259, 0, 0, 0, 1, 0, 0, 3, 0, 5, 0, 6, 27, 0, 0, 0, 54, 3, 0,
+        30, 0, 0, 0, 260, 0, 0, 0, 2, 94, 0, 0, 0, 0, 2, 0, 261, 2, 2, 2,
+        2, 5, 0, 5, 0, 1, 0, 0, 6, 0, 0, 0, 0, 0, 2, 5, 2, 1, 0, 0,
+        1, 2, 1, 252, 2, 33, 45, 17, 2, 262, 0, 263, 0, 0, 0, 5, 0, 0, 5, 0,
+        5, 0, 6, 1, 3, 5, 2, 0, 0, 5, 2, 0, 2, 0, 5, 0, 158, 0, 0, 6,
+        1, 0, 2, 0, 0, 1, 0, 2, 2, 1, 2, 0, 0, 5, 2, 2, 27, 5, 31, 2,
+        2, 0, 2, 0, 0, 1, 0, 6, 0, 2, 0, 2, 0, 0, 0, 0, 264, 0, 0, 2,
+        46, 0, 0, 1, 0, 2, 0, 20, 5, 17, 5, 23, 1, 0, 2, 0, 0, 2, 0, 0,
+        0, 0, 0, 0, 5, 27, 2, 94, 2, 2, 2, 1, 0, 0, 0, 2, 2, 55, 2, 27,
+        1, 5, 2, 0, 17, 0, 5, 23, 0, 20, 0, 2, 0, 0, 14, 0, 2, 0, 3, 0,
+        0, 0, 5, 2, 17, 31, 0, 0, 0, 5, 54, 0, 0, 2, 2, 2, 265, 0, 0, 5,
+        139, 0, 5, 2, 145, 41, 2, 0, 0, 5, 7, 0, 1, 2, 266, 5, 0, 267, 0, 0,
+        0, 2, 266, 0, 0, 0, 0, 268, 2, 2, 2, 0, 2, 2, 2, 5, 2, 11, 7, 2,
+        2, 2, 0, 6, 1, 0, 0, 2, 0, 1, 33, 2, 0, 0, 2, 2, 0, 2, 2, 0,
+        5, 269, 6, 0, 133, 0, 1, 0, 2, 0, 2, 5, 54, 2, 1, 0, 0, 0, 6, 5,
+        0, 2, 34, 0, 5, 2, 1, 1, 5, 0, 2, 2, 2, 270, 33, 0, 44, 39, 0, 1,
+        2, 0, 1, 1, 41, 0, 45, 31, 2, 271, 0, 0, 1, 272, 0, 0, 5, 0, 2, 0,
+        0, 0, 1, 16, 0, 41, 94, 2, 45, 0, 5, 2, 5, 3, 273, 274, 0, 145, 44, 275,
+        0, 6, 1, 0, 1, 1, 36, 0, 1, 2, 0, 0, 2, 0, 5, 0, 0, 17, 0, 276,
+        5, 5, 197, 0, 0, 7, 0, 60, 1, 277, 6, 21, 6, 0, 17, 3, 0, 3, 0, 0,
+        36, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 197, 0, 0, 0, 55, 0, 59, 126, 0,
+        94, 1, 55, 0, 126, 0, 0, 17, 0, 5, 0, 145, 136, 17, 0, 0, 44, 0, 0, 55,
+        17, 0, 5, 8, 0, 2, 6, 2, 2, 0, 6, 1, 41, 0, 2, 2, 2, 2, 2, 0,
+        0, 278, 0, 0, 0, 279, 3, 2, 2, 0, 2, 17, 280, 27, 281, 5, 0, 126, 0, 21,
+        2, 126, 2, 0, 282, 5, 176, 283, 0, 17, 3, 5, 41, 268, 0, 0, 0, 44, 0, 2,
+        0, 2, 2, 2, 1, 2, 3, 1, 2, 5, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0,
+        1, 158, 31, 0, 0, 138, 31, 2, 5, 5, 21, 1, 134, 31, 16, 0, 1, 0, 17, 32,
+        284, 2, 0, 6, 1, 17, 55, 0, 41, 0, 0, 2, 1, 0, 0, 55, 17, 0, 1, 3,
+        0, 285, 0, 5, 94, 5, 83, 5, 0, 3, 44, 3, 17, 5, 0, 0, 5, 6, 54, 0,
+        5, 1, 0, 5, 0, 2, 1, 1, 5, 0, 1, 2, 0, 0, 0, 5, 2, 0, 1, 1,
+        0, 2, 16, 286, 287, 17, 20, 5, 7, 31, 2, 2, 0, 1, 133, 0, 0, 60, 1, 0,
+        0, 6, 5, 0, 2, 2, 23, 7, 0, 0, 23, 6, 83, 6, 2, 0, 1, 0, 1, 2,
+        0, 5, 5, 0, 0, 41, 0, 0, 0, 0, 288, 59, 39, 31, 0, 3, 127, 289, 2, 27,
+        83, 27, 17, 0, 0, 1, 7, 2, 2, 0, 1, 0, 160, 17, 0, 17, 17, 17, 2, 5,
+        0, 53, 3, 290, 1, 5, 2, 5, 60, 291, 0, 2, 2, 22, 27, 0, 6, 5, 5, 6,
+        20, 17, 3, 0, 1, 150, 2, 2, 0, 0, 31, 0, 44, 0, 292, 0, 17, 5, 31, 0,
+        3, 0, 1, 17, 1, 2, 2, 5, 2, 60, 60, 3, 126, 1, 0, 17, 17, 1, 18, 44,
+        0, 0, 0, 2, 2, 6, 1, 5, 0, 136, 5, 2, 69, 0, 5, 5, 0, 5, 5, 0,
+        5, 23, 60, 293, 1, 1, 3, 1, 294, 0, 16, 18, 136, 13, 2, 2, 5, 5, 295, 3,
+        5, 20, 296, 3, 2, 0, 0, 5, 1, 0, 1, 5, 1, 176, 17, 60, 126, 0, 17, 297,
+        5, 1, 1, 55, 2, 5, 2, 5, 2, 3, 0, 2, 1, 16, 117, 3, 55, 2, 59, 27,
+        1, 2, 2, 2, 5, 5, 45, 7, 8, 0, 2, 5, 17, 0, 3, 6, 3, 0, 2, 59,
+        6, 0, 1, 2, 0, 2, 6, 0, 59, 2, 0, 5, 17, 298, 1, 0, 176, 176, 2, 176,
+        0, 2, 55, 2, 60, 31, 6, 0, 2, 5, 5, 0, 1, 0, 3, 3, 0, 0, 0, 0,
+        1, 0, 1, 1, 2, 2, 19, 0, 2, 1, 2, 20, 0, 2, 2, 54, 41, 0, 27, 5,
+        17, 17, 126, 1, 1, 0, 0, 47, 1, 1, 1, 1, 1, 1, 0, 60, 17, 59, 2, 5,
+        33, 7, 7, 1, 1, 3, 1, 0, 6, 0, 0, 2, 0, 6, 1, 2, 0, 31, 17, 41,
+        27, 17, 0, 2, 2, 41, 5, 1, 2, 2, 2, 2, 2, 0, 45, 27, 0, 0, 0, 0,
+        0, 1, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 2, 0, 0,
+        16, 2, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 6, 0, 0, 41, 31, 299,
+        0, 0, 0, 46, 5, 2, 0, 8, 2, 1, 0, 0, 0, 0, 0, 5, 0, 7, 0, 0,
+        2, 2, 5, 1, 33, 41, 2, 0, 0, 0, 8, 1, 0, 8, 1, 2, 6, 58, 6, 17,
+        0, 0, 0, 0, 0, 0, 6, 17, 2, 2, 3, 0, 0, 2, 1, 0, 27, 0, 0, 6,
+        222, 6, 3, 0, 0, 0, 23, 0, 2, 0, 0, 0, 0, 6, 2, 0, 0, 1, 0, 17,
+        2, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 17, 0, 27, 19, 8, 0, 0, 0,
+        0, 0, 0, 0, 1, 5, 27, 31, 0, 0, 2, 0, 0, 0, 0, 1, 60, 0, 55, 136,
+        0, 7, 0, 2, 0, 31, 0, 0, 46, 0, 0, 0, 0, 59, 0, 7, 59, 0, 55, 31,
+        0, 7, 45, 0, 45, 300, 6, 17, 0, 5, 0, 0, 27, 2, 3, 47, 0, 0, 17, 1,
+        0, 1, 1, 2, 1, 27, 2, 0, 2, 0, 0, 5, 0, 2, 6, 6, 5, 0, 0, 6,
+        0, 0, 0, 6, 5, 0, 17, 5, 1, 6, 0, 2, 0, 126, 0, 17, 0, 24, 27, 2,
+        0, 6, 126, 0, 41, 16, 2, 3, 1, 54, 54, 17, 0, 17, 0, 1, 0, 0, 0, 0,
+        1, 5, 3, 0, 6, 301, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 1, 6, 0, 33,
+        0, 2, 3, 55, 0, 31, 16, 2, 32, 0, 17, 21, 3, 0, 16, 16, 5, 5, 5, 16,
+        2, 20, 59, 2, 0, 0, 0, 0, 0, 0, 302, 17, 1, 0, 0, 2, 0, 0, 0, 0,
+        0, 80, 6, 6, 2, 34, 0, 0, 3, 0, 0, 6, 1, 27, 17, 0, 0, 0, 0, 6,
+        0, 127, 0, 0, 0, 17, 3, 0, 6, 0, 0, 0, 0, 41, 2, 2, 5, 7, 41, 3,
+        5, 165, 1, 19, 2, 3, 1, 0, 55, 27, 1, 2, 6, 133, 0, 0, 0, 1, 1, 0,
+        0, 0, 0, 3, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 41, 44, 0, 0, 0, 0,
+        0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 3, 5, 6, 6, 0,
+        16, 303, 304, 18, 55, 25, 0, 3, 1, 16, 7, 0, 16, 1, 1, 0, 0, 0, 126, 17,
+        5, 1, 126, 17, 2, 2, 2, 2, 0, 0, 3, 0, 0, 6, 0, 2, 2, 0, 7, 1,
+        1, 0, 31, 6, 0, 2, 1, 1, 39, 6, 0, 133, 0, 16, 7, 2, 0, 5, 3, 17,
+        27, 44, 0, 55, 305, 176, 0, 0, 0, 0, 0, 1, 71, 16, 36, 1, 7, 7, 0, 36,
+        56, 0, 306, 5, 2, 268, 307, 0, 1, 0, 0, 0, 2, 0, 0, 0, 5, 2, 0, 2,
+        0, 6, 6, 2, 1, 0, 0, 5, 3, 41, 2, 40, 1, 3, 3, 308, 1, 6, 0, 0,
+        0, 39, 0, 27, 1, 0, 5, 6, 2, 3, 0, 0, 14, 2, 1, 2, 5, 17, 2, 0,
+        1, 0, 0, 6, 1, 2, 0, 5, 2, 0, 16, 5, 1, 2, 16, 6, 0, 309, 310, 6,
+        148, 0, 6, 7, 311, 1, 0, 41, 5, 6, 0, 1, 0, 0, 0, 5, 0, 0, 1, 0,
+        6, 0, 1, 3, 6, 6, 0, 0, 0, 25, 3, 0, 2, 1, 5, 3, 2, 0, 0, 312,
+        1, 0, 6, 0, 6, 2, 17, 0, 20, 3, 59, 3, 0, 0, 1, 3, 33, 1, 148, 0,
+        0, 200, 0, 0, 27, 46, 1, 55, 1, 1, 0, 126, 0, 0, 0, 0, 2, 1, 1, 0,
+        45, 6, 5, 0, 31, 2, 39, 0, 17, 1, 32, 16, 2, 0, 2, 27, 27, 0, 17, 0,
+        3, 17, 1, 54, 0, 17, 17, 17, 17, 6, 3, 0, 2, 41, 1, 2, 0, 0, 0, 3,
+        6, 2, 3, 47, 5, 2, 2, 1, 5, 1, 5, 69, 1, 1, 2, 2, 2, 0, 2, 3,
+        313, 41, 1, 6, 6, 6, 3, 3, 1, 314, 0, 1, 0, 27, 0, 5, 5, 315, 0, 17,
+        0, 1, 39, 0, 2, 5, 41, 5, 0, 41, 1, 2, 1, 0, 3, 3, 27, 3, 5, 0,
+        5, 1, 133, 17, 1, 2, 1, 5, 2, 0, 0, 0, 0, 1, 1, 0, 2, 2, 2, 2,
+        5, 2, 5, 0, 2, 5, 0, 6, 8, 0, 2, 31, 144, 0, 30, 0, 0, 54, 1, 6,
+        3, 5, 1, 2, 6, 3, 0, 2, 5, 1, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 0, 1, 0, 2, 3, 0, 16, 6, 2, 0, 3, 0, 0, 0, 5,
+        0, 0, 0, 0, 5, 5, 2, 6, 31, 0, 6, 3, 3, 0, 0, 2, 0, 0, 17, 0,
+        17, 5, 0, 2, 2, 0, 0, 2, 41, 2, 2, 0, 3, 2, 2, 0, 2, 0, 5, 16,
+        8, 16, 2, 6, 3, 200, 16, 0, 2, 0, 0, 0, 0, 0, 0, 2, 34, 31, 41, 44,
+        5, 0, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 7, 0, 1, 31, 44, 2, 1, 0,
+        0, 1, 0, 41, 0, 0, 0, 2, 16, 41, 0, 0, 0, 0, 1, 1, 0, 2, 1, 0,
+        7, 0, 0, 17, 0, 0, 0, 157, 2, 3, 2, 0, 2, 39, 23, 6, 316, 3, 2, 1,
+        1, 2, 8, 0, 181, 0, 2, 0, 0, 5, 1, 220, 5, 2, 5, 6, 317, 1, 268, 73,
+        1, 5, 0, 0, 16, 5, 0, 0, 2, 0, 59, 0, 0, 0, 0, 27, 2, 17, 5, 1,
+        2, 2, 6, 2, 0, 2, 2, 0, 0, 0, 6, 0, 2, 0, 0, 0, 2, 1, 0, 0,
+        0, 0, 1, 3, 0, 5, 0, 31, 1, 0, 5, 0, 16, 3, 0, 16, 31, 0, 17, 0,
+        0, 6, 0, 5, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 2, 0, 41,
+        0, 5, 7, 318, 319, 0, 0, 319, 0, 0, 2, 5, 0, 2, 0, 2, 0, 0, 0, 0,
+        0, 0, 0, 0, 54, 5, 6, 30, 7, 0, 0, 0, 2, 54, 0, 0, 239, 0, 0, 0,
+        0, 0, 0, 41, 0, 0, 5, 1, 0, 1, 158, 54, 0, 320, 1, 321, 2, 2, 0, 30,
+        0, 2, 2, 0, 0, 7, 2, 0, 0, 0, 0, 6, 27, 54, 20, 5, 5, 5, 41, 2,
+        16, 55, 0, 2, 21, 23, 0, 5, 5, 0, 3, 44, 0, 1, 56, 0, 0, 3, 1, 292,
+        0, 0, 0, 41, 45, 0, 19, 5, 2, 41, 17, 6, 0, 33, 0, 0, 2, 2, 16, 0,
+        7, 2, 17, 0, 0, 41, 2, 1, 6, 41, 0, 41, 0, 59, 1, 1, 5, 1, 16, 1,
+        1, 1, 0, 20, 0, 41, 41, 41, 3, 0, 5, 31, 1, 1, 0, 5, 1, 55, 0, 14,
+        1, 0, 1, 1, 80, 2, 1, 3, 1, 1, 3, 6, 6, 23, 0, 1, 0, 7, 1, 2,
+        0, 0, 0, 1, 2, 6, 5, 6, 5, 17, 0, 3, 0, 16, 5, 3, 2, 1, 2, 3,
+        0, 6, 0, 0, 17, 0, 0, 0, 3, 17, 0, 3, 2, 45, 0, 5, 0, 0, 2, 0,
+        322, 1, 54, 0, 17, 1, 1, 5, 3, 17, 17, 16, 16, 17, 5, 0, 69, 41, 1, 20,
+        0, 0, 5, 69, 7, 7, 257, 1, 2, 7, 0, 0, 0, 16, 0, 0, 0, 2, 323, 40,
+        0, 0, 5, 5, 1, 16, 0, 0, 1, 0, 0, 0, 46, 2, 5, 0, 0, 6, 0, 0,
+        17, 0, 1, 0, 1, 1, 1, 20, 0, 0, 0, 2, 6, 0, 30, 0, 2, 16, 3, 3,
+        0, 0, 1, 2, 41, 0, 0, 5, 31, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0,
+        16, 0, 44, 0, 0, 55, 1, 0, 0, 1, 134, 1, 0, 1, 0, 0, 44, 6, 0, 0,
+        0, 2, 0, 5, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 1, 0, 0, 1,
+        1, 20, 2, 161, 5, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        0, 0, 0, 0, 0, 3, 0, 2, 0, 5, 160, 0, 268, 0, 3, 0, 2, 41, 0, 0,
+        17, 0, 324, 0, 2, 14, 3, 5, 0, 0, 2, 5, 2, 5, 2, 2, 6, 2, 2, 1,
+        2, 0, 1, 5, 0, 0, 17, 1, 6, 2, 7, 33, 17, 3, 0, 0, 2, 3, 1, 158,
+        2, 5, 41, 2, 0, 2, 2, 1, 0, 6, 1, 1, 0, 2, 0, 40, 325, 0, 0, 0,
+        31, 0, 0, 0, 0, 0, 0, 59, 59, 60, 25, 0, 3, 0, 27, 0, 0, 126, 3, 0,
+        2, 0, 27, 0, 1, 0, 5, 1, 16, 0, 54, 212, 2, 0, 5, 0, 25, 2, 1, 2,
+        5, 2, 0, 1, 0, 0, 2, 2, 1, 0, 0, 0, 0, 5, 27, 44, 31, 2, 25, 0,
+        16, 2, 0, 2, 0, 2, 0, 0, 2, 5, 0, 0, 2, 5, 5, 1, 2, 0, 3, 5,
+        0, 0, 1, 8, 0, 2, 0, 0, 0, 0, 1, 0, 3, 33, 0, 16, 5, 2, 2, 5,
+        2, 0, 1, 3, 2, 0, 7, 7, 3, 0, 0, 7, 2, 5, 159, 41, 41, 1, 41, 1,
+        2, 1, 3, 3, 2, 0, 1, 0, 0, 27, 2, 5, 1, 2, 0, 56, 0, 0, 1, 3,
+        0, 17, 0, 0, 2, 5, 0, 45, 2, 2, 0, 3, 2, 0, 1, 1, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 3, 0, 0, 0, 0, 1, 0, 2, 94, 0, 1, 0, 0, 2, 0,
+        0, 0, 2, 0, 0, 5, 2, 2, 0, 0, 2, 2, 0, 0, 2, 0, 3, 3, 0, 0,
+        0, 44, 27, 0, 0, 5, 0, 1, 0, 2, 0, 5, 0, 94, 0, 0, 0, 0, 0, 1,
+        0, 5, 6, 0, 0, 5, 2, 0, 105, 0, 3, 5, 5, 1, 0, 27, 6, 2, 8, 326,
+        2, 0, 2, 2, 0, 0, 2, 0, 2, 1, 27, 31, 41, 2, 0, 5, 5, 1, 1, 0,
+        1, 0, 0, 0, 16, 3, 2, 17, 5, 5, 2, 2, 2, 41, 1, 17, 31, 5, 2, 1,
+        1, 5, 0, 0, 0, 20, 2, 2, 1, 0, 41, 2, 41, 6, 0, 0, 2, 0, 2, 0,
+        0, 0, 16, 0, 44, 0, 327, 1, 2, 0, 0, 7, 17, 0, 0, 0, 17, 6, 1, 0,
+        0, 2, 6, 0, 17, 0, 0, 0, 2, 17, 1, 0, 5, 2, 2, 2, 1, 0, 7, 0,
+        1, 3, 0, 2, 0, 0, 0, 0, 41, 0, 0, 0, 45, 0, 0, 41, 3, 0, 1, 1,
+        69, 134, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0,
+        31, 0, 0, 0, 0, 0, 5, 0, 17, 2, 0, 0, 1, 0, 0, 41, 41, 134, 0, 2,
+        0, 0, 5, 25, 1, 5, 0, 5, 0, 71, 2, 0, 2, 5, 124, 328, 2, 2, 2, 1,
+        0, 0, 0, 5, 0, 0, 5, 5, 2, 2, 0, 0, 0, 41, 0, 1, 0, 2, 2, 0,
+        0, 1, 17, 144, 0, 41, 1, 31, 0, 0, 2, 2, 6, 0, 3, 41, 3, 0, 0, 0,
+        6, 2, 2, 6, 17, 0, 6, 2, 0, 0, 5, 5, 1, 2, 2, 2, 17, 55, 6, 0,
+        1, 0, 0, 6, 0, 0, 0, 2, 5, 268, 5, 0, 19, 329, 2, 3, 1, 13, 2, 0,
+        2, 1, 16, 2, 5, 5, 3, 3, 24, 2, 0, 44, 0, 0, 330, 31, 331, 332, 0, 1,
+        0, 5, 17, 5, 0, 0, 0, 27, 7, 1, 6, 27, 31, 17, 0, 1, 1, 20, 1, 2,
+        5, 60, 5, 44, 44, 5, 0, 17, 17, 46, 0, 94, 2, 3, 0, 31, 41, 2, 17, 0,
+        17, 1, 59, 2, 0, 217, 0, 1, 6, 2, 44, 2, 31, 2, 2, 2, 1, 1, 1, 0,
+        17, 333, 1, 0, 45, 0, 1, 41, 17, 3, 6, 0, 0, 17, 0, 41, 17, 1, 5, 41,
+        16, 17, 1, 2, 6, 0, 2, 6, 6, 31, 1, 0, 0, 0, 0, 31, 3, 0, 27, 0,
+        0, 334, 0, 0, 1, 0, 17, 1, 34, 0, 31, 0, 0, 3, 0, 7, 41, 0, 2, 6,
+        0, 31, 41, 0, 150, 0, 1, 0, 17, 17, 335, 6, 1, 16, 16, 45, 6, 0, 160, 27,
+        3, 150, 183, 0, 6, 55, 0, 6, 0, 5, 0, 0, 336, 33, 117, 6, 31, 0, 210, 0,
+        27, 7, 13, 337, 3, 0, 16, 27, 6, 218, 6, 16, 0, 31, 0, 31, 0, 0, 0, 27,
+        3, 31, 46, 19, 7, 2, 6, 5, 5, 2, 2, 1, 2, 41, 2, 5, 0, 0, 338, 0,
+        0, 0, 339, 0, 0, 0, 7, 2, 0, 0, 0, 2, 0, 6, 2, 0, 340, 1, 0, 41,
+        0, 17, 7, 5, 2, 2, 6, 0, 2, 6, 0, 0, 0, 2, 2, 5, 2, 1, 0, 2,
+        7, 6, 0, 2, 0, 6, 3, 5, 3, 41, 6, 6, 1, 5, 94, 5, 5, 2, 6, 0,
+        5, 2, 2, 2, 6, 6, 2, 0, 0, 0, 0, 5, 1, 83, 2, 80, 1, 0, 0, 40,
+        1, 1, 2, 2, 41, 83, 0, 0, 0, 0, 165, 6, 6, 6, 31, 0, 6, 1, 7, 0,
+        5, 2, 0, 0, 341, 0, 5, 5, 129, 5, 2, 0, 1, 116, 0, 1, 7, 0, 41, 6,
+        5, 41, 1, 2, 44, 0, 58, 2, 2, 2, 0, 16, 0, 0, 1, 55, 2, 2, 1, 2,
+        0, 0, 3, 0, 1, 0, 17, 0, 2, 54, 2, 2, 0, 0, 5, 2, 0, 2, 0, 0,
+        45, 2, 2, 0, 5, 2, 0, 2, 0, 2, 0, 2, 0, 0, 2, 5, 2, 2, 0, 5,
+        3, 2, 2, 1, 1, 1, 0, 2, 2, 2, 342, 20, 5, 6, 56, 31, 33, 0, 2, 278,
+        275, 30, 6, 0, 0, 2, 5, 2, 2, 2, 5, 1, 7, 7, 266, 0, 20, 2, 1, 2,
+        2, 0, 7, 2, 5, 0, 2, 2, 2, 5, 2, 2, 5, 2, 32, 5, 2, 2, 2, 54,
+        45, 2, 2, 2, 2, 5, 17, 17, 1, 2, 1, 16, 0, 16, 1, 0, 2, 2, 1, 2,
+        2, 6, 2, 302, 6, 7, 17, 59, 31, 17, 2, 0, 27, 55, 5, 5, 7, 2, 0, 0,
+        7, 0, 343, 5, 134, 0, 2, 1, 31, 0, 59, 31, 27, 59, 31, 0, 3, 0, 27, 27,
+        0, 344, 5, 31, 2, 16, 17, 17, 345, 2, 2, 2, 1, 2, 2, 2, 54, 346, 6, 1,
+        17, 0, 60, 0, 0, 2, 44, 0, 2, 2, 2, 5, 2, 5, 5, 34, 6, 2, 5, 2,
+        13, 2, 204, 2, 94, 39, 195, 3, 17, 2, 2, 0, 2, 0, 27, 31, 0, 44, 17, 3,
+        3, 17, 45, 179, 31, 17, 3, 1, 3, 1, 22, 2, 8, 59, 3, 31, 3, 5, 17, 2,
+        2, 1, 3, 55, 3, 0, 1, 25, 1, 46, 60, 0, 17, 1, 60, 134, 0, 2, 0, 1,
+        6, 59, 0, 0, 3, 59, 0, 5, 60, 0, 6, 19, 33, 2, 1, 2, 32, 0, 5, 5,
+        25, 44, 22, 2, 6, 6, 1, 136, 31, 16, 56, 140, 56, 16, 1, 59, 20, 59, 1, 112,
+        139, 2, 2, 2, 33, 20, 158, 1, 6, 59, 186, 3, 2, 1, 1, 0, 1, 1, 17, 2,
+        6, 155, 5, 6, 0, 0, 1, 1, 5, 138, 2, 347, 348, 153, 56, 140, 0, 6, 1, 1,
+        1, 1, 1, 0, 1, 1, 0, 1, 0, 1, 41, 3, 6, 3, 21, 3, 0, 1, 1, 1,
+        1, 2, 349, 0, 349, 0, 5, 20, 350, 1, 17, 17, 17, 3, 0, 0, 2, 2, 0, 17,
+        17, 351, 31, 352, 138, 6, 2, 44, 138, 0, 6, 31, 55, 31, 55, 17, 0, 353, 1, 1,
+        1, 0, 1, 31, 0, 31, 54, 17, 17, 17, 17, 2, 2, 0, 0, 31, 46, 27, 55, 1,
+        0, 5, 60, 60, 55, 60, 33, 60, 59, 0, 59, 46, 17, 1, 16, 2, 25, 354, 6, 148,
+        27, 0, 27, 55, 17, 6, 0, 31, 6, 46, 0, 6, 1, 5, 0, 0, 0, 0, 2, 1,
+        27, 31, 150, 0, 0, 127, 2, 0, 0, 3, 1, 0, 0, 17, 3, 0, 0, 0, 59, 0,
+        0, 17, 0, 1, 0, 0, 0, 44, 2, 19, 6, 1, 27, 1, 60, 19, 0, 17, 192, 192,
+        0, 60, 0, 59, 59, 3, 31, 59, 25, 59, 17, 0, 0, 127, 6, 1, 41, 60, 3, 3,
+        16, 6, 0, 2, 6, 3, 0, 1, 1, 219, 355, 0, 0, 0, 23, 33, 5, 0, 1, 1,
+        17, 6, 0, 0, 16, 0, 3, 6, 0, 41, 356, 164, 7, 2, 6, 31, 0, 19, 0, 59,
+        16, 17, 17, 1, 1, 1, 5, 60, 17, 25, 25, 293, 112, 5, 2, 2, 5, 5, 0, 288,
+        2, 2, 44, 2, 33, 0, 0, 3, 0, 46, 3, 0, 6, 0, 5, 0, 32, 0, 0, 2,
+        6, 0, 0, 149, 1, 31, 5, 31, 44, 0, 3, 1, 31, 94, 3, 0, 0, 126, 33, 44,
+        0, 2, 0, 112, 139, 31, 1, 6, 2, 2, 2, 5, 31, 17, 44, 6, 17, 1, 0, 1,
+        5, 1, 0, 3, 17, 54, 54, 5, 5, 1, 16, 6, 16, 2, 2, 2, 2, 1, 161, 2,
+        2, 2, 5, 156, 17, 0, 2, 357, 2, 2, 0, 0, 0, 0, 0, 2, 3, 3, 1, 17,
+        2, 2, 3, 2, 17, 7, 2, 0, 39, 6, 0, 5, 5, 2, 2, 2, 2, 0, 6, 2,
+        5, 2, 56, 2, 17, 2, 1, 83, 5, 0, 0, 2, 17, 7, 41, 41, 17, 0, 1, 1,
+        41, 7, 7, 0, 0, 0, 41, 3, 59, 0, 27, 25, 2, 0, 41, 3, 0, 3, 1, 2,
+        2, 16, 0, 0, 2, 195, 2, 41, 5, 41, 2, 0, 2, 6, 1, 2, 41, 0, 1, 1,
+        6, 6, 39, 5, 3, 31, 1, 2, 3, 0, 0, 20, 1, 161, 2, 3, 0, 3, 5, 2,
+        6, 0, 1, 1, 0, 2, 2, 2, 2, 0, 139, 0, 5, 2, 5, 2, 358, 2, 3, 3,
+        6, 41, 129, 1, 60, 6, 1, 17, 5, 5, 1, 5, 2, 16, 0, 2, 0, 0, 0, 55,
+        0, 31, 5, 2, 126, 46, 0, 16, 14, 0, 6, 2, 7, 5, 3, 3, 1, 3, 6, 1,
+        5, 6, 1, 5, 2, 41, 6, 41, 20, 2, 359, 0, 16, 0, 8, 1, 5, 7, 0, 1,
+        8, 8, 2, 44, 1, 46, 6, 3, 16, 16, 6, 6, 1, 6, 5, 2, 6, 2, 5, 41,
+        157, 1, 1, 7, 5, 0, 127, 17, 0, 17, 0, 2, 17, 3, 3, 2, 2, 2, 5, 5,
+        3, 127, 5, 6, 70, 41, 0, 0, 5, 5, 2, 69, 0, 31, 27, 0, 2, 0, 5, 2,
+        17, 6, 0, 59, 1, 6, 0, 45, 0, 0, 2, 41, 41, 20, 7, 5, 2, 2, 2, 1,
+        33, 360, 2, 2, 0, 1, 2, 0, 44, 5, 6, 1, 1, 1, 3, 45, 0, 5, 1, 1,
+        31, 0, 176, 27, 5, 7, 41, 6, 0, 5, 0, 17, 31, 1, 1, 71, 7, 1, 0, 2,
+        0, 6, 2, 6, 1, 2, 0, 5, 1, 1, 16, 45, 6, 5, 27, 13, 19, 2, 41, 146,
+        23, 5, 0, 3, 1, 1, 1, 1, 17, 71, 41, 41, 44, 2, 33, 17, 1, 3, 3, 3,
+        44, 31, 2, 2, 7, 1, 1, 1, 1, 1, 7, 0, 2, 0, 0, 44, 1, 44, 1, 3,
+        0, 3, 1, 27, 6, 2, 127, 6, 44, 2, 277, 31, 0, 17, 0, 127, 17, 6, 39, 1,
+        2, 39, 3, 2, 6, 20, 1, 0, 31, 0, 6, 41, 361, 33, 17, 0, 17, 5, 6, 5,
+        16, 0, 31, 2, 6, 17, 3, 0, 6, 47, 138, 138, 6, 0, 17, 0, 7, 17, 17, 31,
+        0, 17, 0, 3, 3, 292, 18, 1, 0, 1, 55, 55, 1, 0, 362, 2, 21, 41, 0, 3,
+        5, 0, 195, 21, 127, 1, 0, 2, 363, 41, 6, 0, 17, 195, 0, 17, 5, 41, 0, 6,
+        0, 6, 5, 2, 2, 0, 7, 41, 41, 364, 7, 17, 0, 41, 0, 17, 59, 7, 5, 0,
+        0, 2, 31, 3, 16, 0, 55, 0, 365, 6, 5, 0, 3, 27, 21, 31, 133, 41, 0, 25,
+        31, 0, 31, 6, 27, 20, 0, 17, 6, 60, 27, 0, 3, 55, 55, 3, 5, 2, 33, 2,
+        0, 5, 145, 0, 1, 59, 0, 0, 55, 17, 31, 0, 17, 0, 3, 0, 1, 6, 25, 126,
+        127, 1, 0, 54, 5, 5, 8, 2, 0, 6, 2, 6, 7, 2, 0, 11, 5, 0, 0, 0,
+        7, 0, 0, 2, 41, 5, 0, 0, 0, 2, 5, 39, 41, 7, 2, 0, 0, 1, 0, 3,
+        5, 2, 0, 0, 5, 54, 55, 0, 0, 8, 39, 2, 1, 2, 2, 8, 0, 54, 0, 6,
+        0, 366, 367, 368, 54, 2, 0, 41, 1, 0, 0, 27, 55, 133, 0, 6, 5, 127, 1, 2,
+        0, 1, 0, 0, 159, 41, 5, 369, 17, 0, 2, 6, 1, 6, 0, 1, 2, 5, 6, 2,
+        5, 2, 129, 0, 0, 2, 0, 2, 1, 2, 1, 0, 2, 3, 41, 2, 7, 0, 7, 0,
+        5, 2, 5, 2, 0, 0, 0, 31, 19, 55, 0, 27, 0, 0, 2, 370, 5, 0, 0, 0,
+        1, 0, 5, 5, 2, 5, 41, 2, 17, 0, 0, 0, 46, 39, 5, 126, 237, 59, 0, 5,
+        0, 17, 5, 1, 2, 2, 2, 1, 6, 144, 0, 53, 0, 0, 0, 41, 1, 1, 64, 138,
+        16, 2, 3, 31, 0, 17, 0, 1, 150, 1, 17, 5, 17, 0, 17, 0, 17, 0, 44, 0,
+        31, 16, 371, 0, 1, 17, 1, 1, 165, 41, 2, 2, 0, 27, 0, 0, 1, 1, 17, 167,
+        2, 2, 20, 59, 1, 5, 5, 1, 17, 133, 6, 3, 59, 17, 7, 17, 0, 126, 17, 0,
+        41, 372, 17, 2, 1, 6, 3, 5, 17, 17, 0, 20, 1, 31, 1, 6, 1, 1, 6, 0,
+        60, 27, 0, 0, 0, 17, 0, 41, 17, 0, 5, 0, 23, 17, 1, 19, 0, 0, 17, 3,
+        0, 3, 55, 0, 1, 0, 1, 17, 17, 2, 17, 1, 31, 6, 0, 31, 0, 6, 6, 1,
+        2, 0, 0, 5, 6, 0, 0, 41, 0, 1, 17, 0, 0, 2, 5, 0, 0, 55, 2, 80,
+        134, 0, 2, 5, 44, 6, 20, 3, 55, 1, 31, 0, 0, 16, 17, 0, 17, 0, 5, 0,
+        0, 3, 16, 0, 3, 17, 0, 44, 80, 1, 2, 6, 3, 5, 55, 59, 0, 0, 0, 195,
+        0, 0, 5, 0, 20, 16, 124, 31, 0, 0, 0, 1, 17, 1, 1, 6, 21, 0, 3, 6,
+        5, 17, 0, 31, 21, 2, 186, 0, 0, 17, 27, 39, 17, 0, 20, 2, 0, 6, 0, 1,
+        150, 2, 23, 91, 1, 83, 2, 20, 6, 7, 2, 0, 6, 41, 6, 5, 1, 1, 6, 45,
+        6, 2, 2, 41, 1, 27, 373, 27, 0, 1, 6, 17, 17, 17, 160, 6, 33, 27, 59, 1,
+        2, 2, 44, 2, 33, 45, 1, 0, 45, 2, 3, 5, 1, 6, 31, 3, 6, 21, 0, 0,
+        17, 60, 59, 31, 60, 32, 44, 150, 2, 146, 2, 0, 17, 2, 5, 6, 0, 0, 3, 19,
+        5, 46, 27, 126, 6, 6, 16, 44, 3, 7, 23, 23, 1, 0, 3, 23, 0, 0, 127, 248,
+        31, 55, 3, 0, 1, 27, 5, 1, 46, 55, 55, 17, 46, 55, 0, 60, 5, 27, 17, 5,
+        5, 2, 2, 6, 2, 2, 5, 0, 0, 0, 2, 2, 1, 1, 2, 374, 2, 54, 0, 5,
+        2, 8, 0, 1, 60, 3, 1, 1, 60, 2, 0, 0, 0, 3, 7, 3, 5, 1, 5, 5,
+        1, 3, 20, 60, 3, 0, 1, 1, 1, 1, 0, 21, 34, 5, 17, 0, 375, 0, 60, 5,
+        2, 3, 55, 6, 2, 0, 19, 41, 20, 1, 0, 41, 71, 71, 1, 1, 3, 60, 0, 17,
+        16, 5, 2, 2, 1, 1, 1, 0, 55, 55, 1, 1, 1, 133, 3, 55, 6, 60, 31, 16,
+        1, 14, 16, 17, 0, 1, 17, 1, 17, 2, 2, 55, 17, 60, 3, 2, 5, 2, 6, 5,
+        16, 3, 1, 1, 1, 1, 0, 0, 27, 1, 0, 376, 1, 3, 0, 133, 165, 41, 1, 1,
+        1, 0, 27, 16, 17, 0, 5, 5, 1, 0, 6, 0, 59, 1, 6, 5, 5, 2, 17, 1,
+        83, 0, 1, 46, 298, 124, 1, 3, 6, 59, 0, 2, 2, 377, 2, 44, 3, 20, 6, 2,
+        5, 33, 7, 1, 1, 378, 16, 2, 2, 0, 0, 17, 1, 20, 3, 0, 47, 219, 54, 53,
+        20, 210, 41, 23, 5, 129, 0, 23, 3, 3, 17, 17, 44, 41, 3, 1, 41, 3, 3, 31,
+        3, 0, 2, 3, 5, 2, 17, 6, 0, 3, 31, 21, 17, 31, 0, 1, 0, 2, 31, 5,
+        7, 55, 31, 1, 31, 0, 1, 6, 59, 0, 31, 138, 0, 5, 16, 41, 41, 1, 60, 31,
+        16, 2, 0, 17, 1, 6, 59, 1, 41, 5, 6, 5, 0, 44, 145, 55, 27, 44, 59, 1,
+        3, 31, 2, 31, 55, 55, 5, 7, 55, 0, 0, 59, 60, 55, 0, 3, 31, 31, 0, 46,
+        59, 1, 0, 19, 0, 0, 0, 27, 0, 0, 0, 142, 59, 60, 60, 5, 2, 31, 31, 1,
+        0, 31, 59, 25, 0, 0, 0, 126, 55, 0, 46, 0, 16, 17, 0, 148, 0, 150, 17, 126,
+        0, 0, 19, 17, 176, 60, 0, 59, 0, 16, 0, 0, 0, 0, 0, 60, 0, 1, 0, 0,
+        136, 1, 32, 3, 3, 3, 6, 6, 54, 212, 1, 6, 55, 55, 31, 7, 6, 2, 6, 5,
+        2, 1, 0, 0, 0, 0, 3, 27, 3, 2, 5, 41, 0, 1, 20, 6, 1, 127, 60, 21,
+        16, 44, 21, 5, 27, 27, 179, 179, 3, 0, 3, 3, 1, 1, 3, 17, 6, 2, 5, 134,
+        0, 5, 2, 7, 5, 6, 1, 1, 5, 2, 379, 1, 6, 1, 5, 3, 380, 2, 32, 7,
+        45, 5, 6, 23, 31, 17, 0, 31, 0, 7, 17, 5, 1, 1, 1, 2, 381, 212, 2, 6,
+        2, 17, 134, 16, 27, 0, 2, 2, 3, 6, 2, 0, 0, 0, 1, 44, 382, 2, 383, 16,
+        155, 17, 6, 17, 27, 0, 31, 0, 6, 30, 69, 31, 27, 27, 379, 6, 1, 0, 2, 1,
+        1, 1, 0, 5, 5, 2, 1, 6, 6, 20, 2, 3, 6, 0, 157, 0, 41, 1, 0, 0,
+        20, 1, 39, 355, 6, 17, 6, 41, 0, 2, 5, 2, 17, 17, 1, 1, 0, 31, 3, 41,
+        1, 2, 160, 1, 44, 33, 44, 2, 0, 126, 155, 5, 6, 1, 1, 7, 384, 385, 1, 386,
+        133, 0, 2, 387, 1, 27, 198, 1, 3, 23, 20, 1, 0, 0, 150, 388, 179, 3, 31, 3,
+        138, 0, 17, 17, 39, 0, 31, 31, 2, 17, 2, 1, 2, 6, 5, 20, 1, 41, 6, 3,
+        83, 0, 0, 6, 20, 39, 6, 6, 16, 389, 45, 7, 6, 7, 34, 0, 2, 6, 5, 1,
+        2, 2, 2, 2, 2, 2, 6, 3, 5, 3, 6, 17, 0, 2, 17, 3, 17, 6, 44, 5,
+        27, 134, 7, 5, 2, 2, 5, 2, 17, 5, 2, 45, 1, 2, 2, 2, 2, 6, 1, 1,
+        17, 27, 31, 16, 161, 17, 0, 2, 6, 6, 6, 2, 0, 5, 2, 1, 390, 0, 2, 390,
+        54, 391, 41, 2, 47, 5, 0, 17, 392, 59, 5, 5, 39, 2, 5, 5, 0, 0, 31, 39,
+        20, 3, 6, 2, 45, 5, 17, 8, 0, 31, 0, 0, 0, 0, 3, 17, 0, 0, 44, 0,
+        2, 5, 5, 59, 5, 393, 0, 5, 117, 3, 1, 0, 133, 94, 7, 394, 6, 0, 17, 6,
+        2, 6, 1, 5, 5, 5, 1, 3, 8, 1, 0, 6, 17, 2, 2, 129, 1, 6, 44, 44,
+        0, 5, 55, 55, 55, 17, 6, 17, 7, 6, 0, 6, 55, 0, 117, 31, 59, 17, 44, 138,
+        127, 60, 55, 18, 136, 7, 2, 2, 6, 5, 5, 55, 2, 2, 1, 0, 17, 60, 1, 17,
+        27, 1, 23, 1, 6, 1, 395, 16, 396, 59, 44, 1, 0, 5, 5, 0, 0, 1, 2, 0,
+        0, 3, 133, 0, 0, 7, 2, 5, 0, 1, 6, 2, 1, 1, 83, 5, 16, 7, 2, 59,
+        0, 5, 0, 0, 2, 16, 17, 6, 0, 6, 17, 32, 3, 44, 55, 6, 2, 2, 31, 5,
+        27, 6, 2, 5, 0, 5, 3, 55, 3, 27, 0, 17, 3, 25, 17, 5, 2, 2, 54, 2,
+        7, 0, 2, 44, 2, 397, 6, 195, 2, 398, 399, 400, 2, 0, 0, 401, 2, 2, 0, 0,
+        402, 0, 0, 2, 2, 5, 2, 2, 403, 2, 56, 5, 2, 7, 2, 0, 0, 6, 1, 23,
+        259, 1, 2, 404, 1, 0, 0, 41, 94, 6, 127, 6, 0, 0, 6, 71, 5, 6, 0, 0,
+        0, 5, 17, 17, 5, 2, 5, 5, 158, 1, 0, 3, 1, 0, 20, 405, 0, 1, 2, 5,
+        2, 0, 1, 56, 140, 0, 1, 0, 60, 55, 2, 5, 0, 2, 2, 5, 0, 2, 2, 2,
+        0, 5, 5, 5, 5, 1, 0, 181, 127, 2, 2, 5, 150, 5, 56, 6, 41, 94, 406, 5,
+        5, 112, 139, 2, 2, 72, 36, 54, 0, 2, 1, 1, 0, 3, 157, 5, 3, 5, 41, 41,
+        3, 41, 3, 17, 60, 366, 368, 54, 1, 1, 59, 16, 407, 1, 186, 1, 33, 0, 3, 6,
+        1, 127, 16, 5, 0, 2, 2, 55, 1, 0, 0, 1, 0, 22, 59, 59, 0, 27, 31, 0,
+        0, 0, 27, 2, 127, 2, 5, 19, 0, 0, 7, 3, 23, 0, 0, 0, 27, 0, 60, 302,
+        2, 6, 31, 0, 0, 1, 0, 0, 60, 5, 0, 0, 1, 60, 1, 1, 0, 59, 60, 7,
+        5, 2, 2, 44, 2, 6, 2, 0, 55, 1, 0, 2, 5, 2, 0, 2, 2, 1, 1, 408,
+        44, 1, 0, 2, 2, 59, 16, 2, 1, 55, 0, 2, 2, 41, 5, 2, 1, 16, 1, 60,
+        2, 1, 83, 41, 23, 0, 21, 60, 0, 59, 17, 59, 2, 5, 3, 3, 16, 0, 5, 6,
+        0, 6, 3, 16, 0, 0, 0, 59, 5, 2, 2, 0, 23, 2, 2, 1, 1, 1, 5, 6,
+        2, 3, 20, 2, 112, 112, 139, 6, 1, 2, 2, 31, 1, 0, 136, 0, 0, 1, 0, 31,
+        0, 0, 1, 0, 3, 60, 44, 5, 59, 3, 219, 0, 21, 27, 55, 0, 0, 59, 0, 59,
+        0, 0, 0, 0, 17, 44, 22, 219, 0, 5, 5, 117, 5, 59, 31, 17, 59, 186, 60, 0,
+        154, 59, 6, 44, 17, 17, 288, 44, 3, 5, 3, 0, 409, 410, 150, 32, 0, 1, 1, 1,
+        5, 17, 2, 2, 5, 0, 0, 3, 1, 0, 411, 0, 2, 16, 1, 5, 0, 46, 1, 1,
+        21, 3, 0, 1, 3, 0, 412, 0, 0, 126, 1, 362, 0, 6, 5, 2, 0, 3, 17, 3,
+        31, 0, 6, 1, 6, 31, 55, 25, 32, 17, 6, 126, 3, 17, 0, 17, 0, 27, 105, 3,
+        83, 17, 6, 2, 0, 413, 0, 414, 1, 46, 19, 2, 2, 3, 20, 5, 17, 17, 60, 150,
+        0, 23, 1, 0, 39, 6, 5, 2, 415, 416, 417, 418, 5, 2, 419, 3, 6, 5, 2, 5,
+        2, 5, 6, 420, 2, 421, 422, 334, 32, 0, 2, 3, 3, 0, 20, 2, 2, 5, 6, 27,
+        2, 5, 2, 20, 0, 5, 278, 0, 2, 72, 2, 0, 5, 2, 1, 1, 2, 2, 2, 5,
+        0, 7, 5, 2, 0, 2, 2, 2, 2, 5, 5, 0, 2, 2, 0, 1, 7, 60, 2, 2,
+        0, 16, 60, 0, 2, 2, 5, 5, 7, 2, 0, 0, 60, 45, 2, 60, 2, 2, 5, 5,
+        5, 2, 2, 0, 2, 2, 5, 5, 30, 17, 2, 5, 5, 2, 0, 2, 5, 2, 5, 7,
+        2, 2, 2, 302, 16, 302, 0, 5, 2, 60, 25, 2, 423, 2, 2, 2, 0, 5, 0, 2,
+        55, 17, 3, 11, 5, 1, 2, 2, 55, 112, 139, 2, 2, 2, 19, 0, 1, 5, 55, 59,
+        1, 41, 0, 0, 56, 140, 0, 424, 425, 1, 2, 71, 2, 1, 1, 5, 1, 0, 2, 2,
+        2, 426, 2, 71, 0, 1, 126, 2, 2, 54, 54, 320, 2, 2, 1, 1, 5, 2, 2, 5,
+        2, 5, 7, 427, 2, 3, 1, 6, 1, 1, 5, 2, 5, 0, 31, 5, 5, 41, 6, 139,
+        5, 1, 56, 112, 56, 0, 5, 5, 5, 2, 5, 1, 5, 2, 5, 5, 41, 2, 5, 5,
+        2, 1, 2, 3, 3, 31, 0, 16, 59, 38, 7, 30, 428, 14, 3, 1, 3, 6, 30, 16,
+        0, 16, 2, 2, 2, 2, 170, 170, 16, 136, 0, 2, 16, 2, 5, 2, 5, 5, 14, 7,
+        0, 7, 2, 2, 5, 2, 2, 2, 7, 59, 59, 2, 150, 2, 2, 0, 31, 16, 7, 2,
+        6, 17, 17, 0, 46, 0, 268, 307, 2, 2, 5, 2, 2, 2, 0, 2, 7, 16, 5, 0,
+        5, 5, 14, 0, 0, 16, 16, 16, 124, 16, 16, 429, 7, 7, 244, 58, 2, 16, 2, 2,
+        5, 5, 3, 1, 2, 5, 2, 2, 45, 5, 2, 16, 16, 2, 2, 2, 7, 430, 431, 430,
+        431, 112, 139, 2, 5, 5, 2, 16, 127, 248, 3, 126, 19, 3, 17, 5, 27, 27, 17, 17,
+        31, 5, 60, 27, 27, 301, 47, 2, 64, 2, 31, 1, 27, 31, 432, 0, 2, 0, 0, 2,
+        16, 6, 5, 6, 1, 433, 2, 1, 146, 0, 31, 1, 1, 1, 5, 6, 0, 1, 3, 0,
+        2, 32, 0, 176, 6, 6, 27, 3, 5, 21, 17, 6, 0, 17, 3, 1, 1, 31, 44, 6,
+        2, 6, 3, 2, 3, 0, 17, 5, 5, 17, 16, 3, 1, 5, 3, 0, 3, 146, 5, 13,
+        34, 3, 2, 2, 31, 3, 59, 17, 55, 55, 1, 31, 2, 5, 59, 17, 17, 55, 1, 0,
+        105, 1, 0, 55, 0, 17, 0, 0, 0, 55, 1, 55, 7, 6, 434, 0, 0, 2, 2, 2,
+        2, 32, 103, 0, 17, 27, 6, 17, 7, 31, 1, 0, 435, 2, 3, 6, 0, 133, 5, 67,
+        5, 31, 20, 2, 2, 6, 0, 2, 45, 2, 55, 55, 55, 2, 31, 45, 56, 140, 436, 437,
+        5, 41, 2, 0, 1, 0, 438, 27, 17, 0, 17, 16, 41, 1, 6, 1, 17, 3, 0, 162,
+        45, 1, 1, 16, 0, 17, 0, 0, 0, 5, 2, 41, 1, 2, 439, 44, 46, 6, 3, 1,
+        2, 13, 2, 126, 17, 5, 6, 2, 6, 41, 6, 55, 0, 0, 55, 0, 55, 17, 55, 6,
+        6, 6, 0, 56, 54, 0, 2, 0, 17, 0, 17, 32, 0, 59, 1, 0, 17, 0, 350, 31,
+        440, 441, 442, 0, 0, 2, 31, 6, 35, 17, 58, 54, 3, 0, 0, 6, 3, 2, 443, 2,
+        2, 2, 5, 0, 129, 2, 2, 0, 1, 2, 2, 2, 5, 2, 5, 5, 5, 2, 31, 0,
+        2, 2, 23, 2, 2, 2, 0, 6, 1, 1, 44, 2, 0, 0, 0, 0, 2, 2, 2, 2,
+        2, 0, 27, 129, 17, 0, 0, 2, 5, 1, 5, 2, 6, 0, 41, 6, 2, 2, 0, 128,
+        0, 2, 5, 0, 6, 2, 444, 2, 39, 5, 0, 1, 3, 5, 32, 5, 1, 2, 1, 232,
+        2, 2, 5, 0, 45, 1, 148, 2, 45, 363, 105, 1, 0, 5, 0, 2, 2, 41, 1, 69,
+        20, 5, 1, 3, 0, 0, 0, 6, 1, 1, 2, 2, 5, 41, 0, 0, 1, 27, 5, 0,
+        0, 0, 0, 6, 2, 2, 2, 445, 446, 17, 0, 17, 2, 0, 0, 31, 27, 31, 3, 6,
+        2, 2, 0, 112, 139, 16, 5, 2, 5, 0, 0, 8, 1, 7, 0, 124, 27, 0, 0, 54,
+        1, 0, 0, 3, 5, 17, 1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 112, 139, 2,
+        6, 56, 0, 0, 5, 55, 0, 59, 31, 27, 27, 363, 3, 8, 59, 2, 60, 5, 2, 0,
+        44, 1, 0, 0, 0, 2, 5, 3, 31, 94, 16, 3, 0, 0, 31, 2, 129, 1, 1, 2,
+        2, 2, 17, 2, 16, 1, 0, 8, 2, 0, 0, 17, 0, 148, 0, 3, 0, 0, 0, 0,
+        0, 21, 6, 6, 7, 31, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 60, 60, 17,
+        0, 45, 0, 408, 1, 5, 59, 16, 31, 59, 31, 0, 0, 0, 46, 1, 8, 0, 6, 0,
+        5, 3, 0, 153, 20, 8, 1, 23, 1, 2, 2, 2, 2, 56, 447, 112, 139, 2, 3, 148,
+        39, 1, 0, 6, 20, 2, 0, 41, 0, 5, 31, 0, 54, 0, 2, 2, 1, 33, 0, 0,
+        0, 17, 0, 0, 2, 1, 2, 2, 1, 33, 0, 54, 448, 8, 1, 6, 0, 1, 5, 0,
+        2, 0, 1, 1, 449, 7, 1, 2, 450, 6, 0, 0, 2, 2, 59, 56, 8, 5, 139, 41,
+        44, 0, 60, 2, 2, 2, 17, 6, 1, 17, 17, 0, 17, 2, 2, 2, 0, 2, 6, 2,
+        2, 27, 18, 1, 451, 0, 44, 41, 3, 2, 5, 54, 1, 0, 0, 1, 2, 31, 60, 2,
+        17, 60, 7, 0, 46, 1, 1, 452, 0, 453, 0, 17, 5, 0, 6, 1, 1, 31, 16, 454,
+        0, 3, 31, 6, 455, 5, 2, 6, 5, 59, 54, 456, 54, 0, 3, 0, 54, 3, 1, 2,
+        17, 33, 5, 2, 457, 5, 458, 2, 6, 0, 5, 45, 45, 31, 46, 2, 5, 72, 36, 2,
+        381, 197, 459, 460, 0, 16, 5, 197, 5, 5, 6, 6, 2, 0, 2, 2, 6, 2, 0, 6,
+        1, 2, 1, 381, 212, 27, 0, 0, 461, 197, 0, 2, 6, 0, 6, 6, 0, 3, 0, 6,
+        2, 3, 197, 0, 0, 0, 60, 381, 6, 1, 3, 232, 462, 16, 0, 2, 463, 55, 31, 0,
+        0, 19, 55, 55, 55, 0, 44, 55, 25, 55, 55, 464, 55, 5, 46, 0, 230, 1, 18, 5,
+        17, 31, 31, 0, 55, 55, 55, 32, 2, 5, 2, 2, 6, 334, 2, 2, 2, 0, 16, 0,
+        17, 5, 5, 5, 2, 2, 2, 2, 31, 1, 44, 2, 0, 0, 5, 0, 17, 150, 2, 44,
+        0, 0, 5, 465, 5, 17, 3, 31, 2, 2, 44, 31, 2, 6, 0, 0, 27, 7, 31, 17,
+        3, 3, 3, 41, 3, 6, 55, 0, 1, 138, 2, 2, 8, 140, 2, 0, 6, 2, 2, 186,
+        222, 3, 176, 1, 5, 0, 0, 41, 59, 5, 2, 2, 2, 27, 0, 0, 31, 56, 3, 83,
+        2, 20, 20, 2, 16, 2, 1, 0, 0, 0, 0, 91, 83, 0, 0, 0, 17, 44, 3, 2,
+        2, 2, 2, 1, 3, 2, 2, 2, 2, 1, 1, 17, 17, 5, 55, 426, 16, 5, 17, 0,
+        2, 59, 2, 3, 5, 17, 7, 23, 1, 20, 0, 1, 0, 1, 5, 2, 2, 2, 5, 1,
+        3, 16, 0, 138, 17, 1, 117, 0, 59, 1, 27, 27, 59, 1, 2, 2, 17, 45, 16, 31,
+        27, 2, 5, 0, 44, 0, 31, 6, 2, 2, 302, 0, 6, 20, 0, 60, 3, 18, 3, 60,
+        44, 16, 17, 17, 17, 2, 16, 126, 126, 3, 41, 6, 60, 60, 46, 0, 59, 1, 0, 60,
+        7, 17, 0, 16, 55, 1, 1, 44, 41, 0, 1, 2, 18, 136, 466, 14, 71, 5, 5, 5,
+        0, 1, 0, 2, 60, 5, 60, 5, 33, 165, 17, 16, 55, 60, 31, 467, 3, 17, 59, 0,
+        256, 34, 0, 27, 27, 41, 0, 27, 3, 0, 3, 1, 105, 2, 16, 468, 176, 5, 59, 59,
+        2, 5, 5, 5, 1, 1, 19, 69, 0, 45, 17, 17, 59, 59, 2, 5, 25, 5, 0, 2,
+        33, 6, 1, 7, 0, 0, 0, 1, 0, 2, 31, 1, 1, 44, 230, 41, 1, 2, 3, 333,
+        2, 0, 41, 17, 17, 2, 2, 45, 2, 5, 2, 6, 0, 2, 16, 0, 0, 2, 5, 17,
+        27, 0, 31, 17, 6, 27, 0, 5, 6, 41, 6, 16, 27, 17, 91, 0, 17, 17, 2, 6,
+        5, 0, 41, 0, 5, 2, 31, 59, 1, 0, 1, 0, 1, 3, 17, 303, 46, 17, 6, 17,
+        41, 1, 16, 0, 1, 0, 0, 44, 60, 55, 1, 1, 0, 161, 0, 0, 17, 1, 2, 2,
+        0, 41, 23, 20, 5, 0, 6, 27, 1, 44, 2, 0, 16, 44, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0, 0, 6, 0, 59, 5, 6, 60, 17, 0, 60, 31, 60, 3, 34, 2, 59,
+        16, 31, 0, 2, 6, 1, 2, 8, 1, 59, 0, 1, 0, 134, 0, 0, 0, 54, 0, 0,
+        0, 1, 31, 1, 6, 5, 5, 0, 6, 2, 2, 2, 2, 0, 124, 6, 2, 2, 54, 1,
+        41, 1, 55, 17, 355, 0, 0, 5, 0, 2, 2, 6, 0, 3, 59, 17, 3, 3, 3, 334,
+        0, 25, 25, 47, 31, 2, 6, 41, 6, 1, 0, 0, 142, 0, 222, 27, 0, 2, 259, 17,
+        1, 1, 0, 0, 0, 7, 1, 5, 3, 31, 180, 116, 87, 16, 17, 0, 16, 2, 17, 0,
+        2, 2, 18, 2, 2, 59, 469, 1, 59, 470, 471, 17, 0, 45, 31, 0, 0, 0, 3, 17,
+        60, 0, 3, 0, 0, 0, 0, 0, 60, 0, 59, 1, 145, 7, 0, 2, 69, 6, 0, 0,
+        368, 5, 3, 17, 5, 1, 0, 27, 6, 16, 1, 0, 5, 5, 5, 56, 56, 2, 3, 2,
+        27, 17, 59, 55, 0, 27, 0, 60, 25, 0, 17, 0, 17, 69, 0, 0, 1, 2, 60, 3,
+        200, 7, 0, 27, 27, 27, 46, 31, 17, 0, 44, 0, 2, 176, 1, 1, 0, 17, 0, 55,
+        5, 46, 0, 18, 0, 0, 59, 17, 60, 46, 25, 200, 0, 0, 17, 2, 0, 1, 0, 1,
+        1, 27, 1, 5, 0, 2, 31, 17, 16, 6, 1, 91, 1, 126, 3, 0, 17, 1, 41, 6,
+        16, 6, 3, 17, 59, 138, 55, 55, 3, 6, 31, 3, 6, 31, 31, 0, 6, 2, 0, 0,
+        5, 0, 2, 2, 0, 55, 0, 0, 5, 31, 1, 5, 41, 41, 20, 45, 45, 45, 1, 0,
+        27, 2, 0, 17, 0, 31, 2, 0, 27, 0, 17, 0, 1, 41, 0, 17, 0, 6, 3, 69,
+        301, 59, 44, 31, 59, 31, 46, 0, 44, 2, 1, 59, 27, 0, 0, 5, 117, 31, 1, 44,
+        1, 17, 0, 1, 0, 41, 3, 23, 1, 1, 27, 5, 3, 2, 5, 0, 16, 20, 46, 186,
+        0, 2, 150, 2, 1, 3, 1, 0, 0, 46, 3, 60, 46, 6, 0, 1, 0, 60, 0, 5,
+        0, 251, 0, 251, 6, 17, 6, 6, 1, 2, 39, 0, 1, 6, 41, 0, 0, 6, 3, 6,
+        6, 41, 5, 5, 2, 1, 1, 159, 1, 1, 0, 6, 59, 472, 2, 2, 16, 17, 17, 41,
+        27, 0, 39, 0, 39, 17, 2, 17, 473, 474, 3, 27, 3, 1, 133, 0, 0, 27, 1, 2,
+        27, 126, 0, 3, 1, 7, 2, 16, 0, 27, 133, 0, 0, 0, 27, 0, 0, 17, 45, 31,
+        6, 3, 3, 5, 105, 6, 17, 3, 0, 3, 126, 133, 17, 0, 0, 27, 3, 3, 20, 17,
+        0, 5, 3, 27, 27, 16, 44, 6, 157, 39, 1, 1, 1, 31, 31, 31, 0, 0, 0, 7,
+        60, 126, 0, 25, 0, 0, 0, 27, 0, 3, 0, 59, 3, 34, 0, 5, 46, 25, 1, 59,
+        59, 0, 59, 0, 16, 59, 3, 0, 16, 0, 17, 2, 0, 0, 0, 1, 362, 60, 0, 59,
+        0, 6, 0, 0, 0, 0, 16, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 56,
+        140, 0, 17, 0, 6, 0, 3, 45, 17, 2, 16, 1, 60, 17, 3, 0, 59, 0, 127, 0,
+        31, 6, 2, 337, 1, 17, 17, 0, 6, 6, 5, 5, 6, 0, 31, 16, 2, 1, 1, 5,
+        0, 2, 1, 5, 475, 46, 46, 55, 126, 19, 0, 27, 1, 31, 1, 126, 60, 0, 59, 1,
+        1, 2, 2, 139, 2, 5, 2, 0, 3, 2, 363, 6, 3, 1, 3, 3, 0, 3, 5, 0,
+        17, 0, 1, 6, 1, 2, 1, 25, 6, 17, 127, 0, 2, 0, 0, 1, 7, 476, 477, 448,
+        478, 6, 31, 17, 19, 5, 41, 0, 0, 1, 1, 0, 0, 127, 59, 479, 0, 5, 6, 3,
+        17, 2, 17, 6, 6, 5, 2, 5, 59, 5, 31, 44, 18, 46, 2, 1, 5, 5, 408, 6,
+        480, 1, 30, 69, 126, 1, 1, 1, 6, 31, 0, 6, 3, 5, 6, 31, 1, 2, 6, 3,
+        0, 33, 45, 6, 133, 181, 27, 44, 5, 16, 1, 0, 2, 31, 5, 5, 2, 2, 133, 1,
+        3, 3, 32, 0, 0, 6, 127, 0, 17, 27, 0, 7, 59, 46, 0, 2, 27, 0, 2, 1,
+        0, 60, 0, 0, 44, 17, 17, 44, 60, 6, 17, 17, 6, 1, 2, 2, 2, 27, 41, 2,
+        2, 17, 17, 0, 5, 1, 6, 7, 3, 1, 6, 2, 0, 2, 39, 17, 1, 6, 31, 3,
+        0, 44, 382, 17, 2, 0, 1, 31, 6, 5, 1, 20, 481, 17, 17, 482, 2, 6, 5, 3,
+        5, 5, 91, 17, 71, 16, 14, 2, 6, 6, 181, 5, 483, 3, 2, 5, 31, 1, 6, 1,
+        0, 0, 0, 41, 94, 27, 230, 3, 0, 2, 1, 2, 6, 5, 484, 2, 2, 5, 5, 2,
+        5, 5, 2, 17, 6, 1, 6, 129, 20, 0, 2, 31, 127, 2, 31, 31, 0, 17, 7, 41,
+        2, 5, 6, 0, 27, 6, 6, 1, 31, 2, 0, 2, 2, 0, 2, 45, 2, 3, 3, 1,
+        6, 2, 5, 3, 0, 6, 408, 5, 5, 0, 16, 2, 0, 5, 2, 5, 0, 2, 2, 0,
+        2, 2, 2, 5, 5, 1, 485, 5, 55, 55, 44, 6, 1, 2, 0, 1, 5, 2, 0, 0,
+        1, 16, 17, 2, 2, 41, 0, 0, 2, 6, 17, 0, 0, 0, 17, 1, 17, 0, 5, 17,
+        0, 0, 44, 1, 3, 17, 55, 5, 1, 2, 1, 0, 0, 44, 7, 7, 1, 58, 7, 2,
+        58, 0, 40, 0, 17, 59, 0, 0, 5, 1, 0, 1, 30, 58, 0, 31, 7, 31, 0, 55,
+        27, 3, 0, 0, 18, 27, 5, 31, 0, 27, 6, 55, 20, 3, 6, 5, 31, 31, 3, 2,
+        1, 1, 16, 0, 2, 2, 27, 27, 1, 0, 3, 7, 31, 55, 16, 32, 2, 2, 220, 1,
+        2, 6, 2, 41, 41, 2, 3, 0, 2, 2, 3, 0, 0, 0, 5, 148, 5, 2, 140, 56,
+        5, 2, 2, 2, 2, 20, 1, 0, 3, 23, 27, 1, 5, 60, 378, 2, 59, 59, 1, 0,
+        3, 17, 1, 56, 17, 0, 7, 0, 0, 0, 5, 2, 1, 1, 5, 6, 2, 2, 5, 27,
+        3, 16, 5, 486, 5, 2, 0, 0, 268, 0, 1, 0, 0, 0, 487, 1, 0, 2, 5, 1,
+        55, 0, 3, 0, 31, 27, 1, 31, 0, 41, 6, 17, 17, 27, 0, 5, 1, 0, 1, 17,
+        17, 17, 6, 0, 0, 2, 0, 0, 0, 1, 1, 488, 14, 72, 2, 0, 5, 6, 2, 1,
+        0, 0, 5, 8, 2, 0, 5, 5, 44, 2, 1, 5, 5, 5, 0, 44, 0, 2, 5, 2,
+        318, 489, 3, 0, 6, 5, 41, 490, 0, 0, 0, 2, 6, 0, 1, 2, 1, 1, 1, 7,
+        2, 1, 8, 5, 0, 2, 0, 6, 0, 2, 0, 129, 5, 2, 2, 5, 5, 0, 5, 5,
+        2, 3, 2, 0, 0, 0, 2, 2, 41, 5, 0, 5, 41, 5, 5, 0, 1, 5, 491, 54,
+        0, 5, 165, 41, 256, 0, 0, 2, 5, 5, 5, 2, 1, 492, 1, 41, 0, 6, 493, 0,
+        2, 5, 8, 1, 41, 41, 41, 1, 20, 1, 2, 6, 430, 0, 1, 2, 5, 2, 5, 1,
+        2, 7, 2, 2, 3, 31, 16, 17, 3, 153, 45, 17, 5, 0, 3, 31, 31, 6, 0, 1,
+        31, 0, 148, 3, 21, 494, 45, 45, 495, 2, 2, 2, 16, 16, 6, 59, 5, 3, 1, 1,
+        31, 2, 5, 5, 60, 0, 1, 2, 41, 2, 5, 7, 6, 161, 41, 5, 2, 80, 7, 7,
+        1, 1, 5, 16, 44, 2, 41, 80, 0, 7, 2, 6, 5, 7, 2, 2, 0, 7, 6, 20,
+        5, 5, 2, 41, 3, 54, 456, 5, 5, 0, 3, 6, 6, 17, 45, 32, 7, 0, 1, 17,
+        16, 0, 55, 6, 0, 426, 0, 5, 2, 5, 5, 0, 496, 2, 2, 17, 0, 5, 5, 5,
+        5, 5, 41, 41, 2, 1, 1, 14, 14, 5, 5, 0, 127, 0, 2, 23, 126, 0, 7, 1,
+        5, 0, 31, 2, 7, 3, 0, 31, 5, 0, 0, 0, 6, 9, 375, 17, 46, 1, 0, 230,
+        6, 176, 31, 31, 91, 1, 27, 0, 0, 497, 0, 27, 55, 0, 0, 41, 219, 7, 1, 0,
+        1, 0, 55, 1, 6, 41, 41, 1, 41, 0, 0, 27, 1, 2, 0, 7, 16, 0, 1, 0,
+        2, 6, 2, 31, 0, 40, 17, 3, 5, 60, 0, 3, 91, 146, 59, 1, 7, 0, 133, 0,
+        16, 6, 0, 0, 1, 6, 0, 0, 0, 0, 0, 6, 0, 69, 1, 59, 59, 20, 16, 5,
+        0, 27, 59, 59, 59, 55, 55, 0, 0, 2, 41, 5, 0, 136, 0, 375, 129, 129, 1, 7,
+        23, 0, 0, 55, 0, 0, 0, 1, 55, 59, 0, 16, 6, 17, 17, 0, 126, 31, 3, 27,
+        0, 31, 16, 6, 0, 31, 1, 31, 0, 3, 2, 2, 0, 5, 2, 1, 1, 41, 17, 23,
+        69, 16, 404, 17, 41, 1, 27, 44, 3, 3, 0, 0, 0, 5, 41, 321, 0, 55, 0, 17,
+        27, 27, 1, 0, 230, 0, 1, 0, 0, 0, 1, 31, 1, 155, 6, 6, 16, 27, 1, 1,
+        59, 0, 0, 31, 0, 0, 3, 1, 16, 39, 0, 0, 17, 0, 59, 6, 0, 5, 0, 0,
+        1, 6, 0, 1, 0, 0, 31, 0, 27, 17, 0, 3, 1, 31, 3, 105, 0, 0, 1, 3,
+        1, 55, 5, 1, 5, 0, 60, 27, 17, 17, 5, 27, 27, 0, 3, 0, 6, 7, 362, 150,
+        56, 1, 5, 5, 56, 140, 447, 112, 139, 5, 2, 2, 0, 0, 1, 1, 1, 1, 31, 41,
+        27, 2, 2, 31, 0, 0, 117, 0, 0, 31, 0, 0, 0, 0, 0, 7, 3, 0, 0, 0,
+        0, 0, 0, 498, 499, 0, 17, 5, 5, 7, 1, 6, 5, 0, 2, 1, 5, 1, 23, 7,
+        6, 20, 2, 2, 0, 17, 1, 60, 0, 0, 31, 0, 59, 17, 1, 3, 60, 1, 31, 59,
+        0, 0, 0, 0, 1, 0, 47, 278, 27, 5, 39, 41, 41, 3, 0, 8, 0, 2, 3, 500,
+        2, 0, 2, 31, 2, 56, 56, 56, 56, 1, 5, 27, 2, 501, 2, 1, 0, 0, 0, 6,
+        8, 1, 6, 2, 502, 2, 54, 2, 0, 2, 6, 2, 2, 6, 1, 5, 2, 41, 2, 0,
+        54, 1, 54, 1, 2, 1, 0, 1, 503, 2, 2, 1, 5, 31, 1, 2, 2, 3, 1, 504,
+        2, 1, 0, 1, 363, 17, 17, 59, 17, 2, 5, 1, 1, 5, 6, 1, 5, 5, 6, 1,
+        41, 1, 60, 45, 0, 8, 8, 53, 212, 133, 3, 112, 139, 31, 6, 31, 59, 59, 39, 1,
+        3, 0, 19, 60, 3, 0, 2, 1, 1, 0, 0, 0, 2, 6, 2, 17, 17, 0, 0, 0,
+        0, 0, 2, 2, 429, 5, 3, 17, 126, 5, 6, 41, 3, 126, 5, 17, 2, 2, 268, 505,
+        5, 5, 6, 1, 5, 5, 54, 55, 0, 0, 6, 5, 34, 5, 67, 0, 0, 31, 0, 1,
+        0, 17, 17, 45, 0, 45, 0, 0, 0, 44, 60, 2, 1, 0, 5, 0, 0, 2, 1, 0,
+        31, 27, 2, 5, 0, 16, 1, 0, 31, 55, 55, 2, 2, 0, 6, 59, 54, 31, 17, 0,
+        1, 41, 5, 6, 41, 2, 17, 1, 2, 2, 16, 17, 0, 1, 6, 2, 2, 0, 5, 16,
+        17, 5, 2, 0, 1, 73, 137, 0, 44, 0, 17, 31, 0, 31, 17, 5, 16, 45, 54, 6,
+        5, 2, 2, 1, 5, 2, 17, 3, 7, 5, 91, 31, 2, 6, 126, 2, 2, 1, 1, 5,
+        2, 5, 2, 0, 47, 47, 27, 1, 0, 2, 7, 5, 16, 17, 17, 2, 0, 2, 2, 2,
+        3, 5, 45, 39, 222, 19, 3, 5, 0, 5, 1, 5, 7, 7, 2, 2, 3, 17, 2, 5,
+        0, 2, 5, 5, 5, 0, 17, 2, 6, 0, 2, 6, 0, 44, 1, 0, 2, 2, 2, 1,
+        6, 33, 0, 41, 20, 55, 136, 0, 0, 1, 20, 0, 0, 6, 23, 17, 17, 0, 31, 16,
+        41, 1, 1, 0, 0, 60, 55, 5, 506, 222, 2, 6, 41, 1, 55, 17, 41, 165, 55, 5,
+        60, 0, 1, 0, 1, 20, 16, 1, 2, 27, 0, 1, 0, 60, 59, 60, 1, 3, 0, 1,
+        17, 1, 0, 31, 17, 0, 60, 60, 138, 60, 3, 60, 0, 0, 1, 1, 160, 59, 60, 6,
+        23, 1, 0, 301, 17, 3, 60, 0, 0, 60, 0, 0, 6, 16, 60, 0, 27, 3, 6, 17,
+        0, 31, 44, 80, 33, 41, 0, 6, 0, 0, 1, 41, 39, 0, 134, 5, 39, 6, 17, 0,
+        0, 0, 0, 71, 2, 2, 2, 2, 2, 5, 5, 5, 5, 0, 5, 2, 5, 2, 0, 5,
+        2, 69, 2, 31, 2, 41, 55, 5, 0, 17, 0, 2, 2, 31, 41, 2, 2, 7, 2, 6,
+        36, 413, 6, 0, 145, 6, 0, 0, 3, 30, 1, 2, 6, 17, 2, 507, 1, 0, 27, 46,
+        59, 59, 59, 7, 44, 6, 3, 25, 126, 0, 54, 116, 508, 2, 2, 5, 0, 5, 5, 27,
+        27, 6, 145, 31, 6, 2, 6, 31, 17, 60, 16, 0, 0, 1, 1, 2, 23, 0, 1, 0,
+        17, 222, 127, 94, 17, 1, 31, 126, 55, 138, 94, 46, 44, 5, 5, 5, 31, 19, 31, 0,
+        27, 3, 27, 27, 0, 47, 278, 31, 3, 138, 19, 3, 45, 17, 133, 3, 45, 0, 0, 1,
+        117, 1, 230, 6, 1, 181, 0, 45, 1, 20, 0, 2, 27, 509, 3, 17, 27, 17, 0, 0,
+        257, 17, 5, 71, 0, 0, 31, 0, 3, 20, 45, 17, 0, 6, 0, 0, 23, 67, 0, 0,
+        6, 17, 0, 1, 6, 2, 31, 0, 31, 45, 17, 44, 382, 0, 21, 506, 27, 6, 30, 3,
+        0, 27, 3, 31, 44, 3, 59, 105, 6, 510, 21, 31, 17, 0, 2, 5, 20, 1, 2, 1,
+        5, 5, 0, 3, 1, 2, 1, 2, 6, 1, 3, 1, 1, 0, 41, 1, 382, 2, 33, 2,
+        5, 2, 5, 31, 31, 2, 1, 6, 2, 2, 3, 1, 2, 2, 0, 5, 23, 2, 1, 1,
+        5, 5, 20, 2, 2, 0, 3, 0, 5, 5, 1, 1, 1, 3, 6, 20, 1, 5, 1, 1,
+        5, 5, 0, 3, 511, 7, 41, 146, 1, 91, 6, 80, 0, 2, 7, 17, 0, 2, 2, 0,
+        0, 6, 0, 41, 1, 1, 41, 0, 6, 2, 7, 3, 129, 6, 444, 190, 129, 5, 512, 513,
+        514, 2, 2, 157, 0, 5, 0, 5, 193, 20, 5, 30, 302, 55, 0, 1, 0, 55, 31, 1,
+        1, 1, 5, 60, 3, 17, 0, 55, 59, 0, 60, 16, 41, 20, 20, 2, 2, 2, 2, 5,
+        32, 2, 2, 5, 0, 2, 2, 2, 2, 2, 5, 5, 2, 2, 2, 2, 2, 2, 2, 2,
+        2, 2, 0, 20, 0, 5, 0, 2, 56, 139, 5, 5, 2, 5, 0, 5, 2, 5, 2, 2,
+        2, 2, 17, 2, 17, 6, 60, 2, 5, 5, 5, 7, 5, 6, 515, 17, 0, 2, 2, 2,
+        31, 31, 17, 2, 2, 2, 516, 517, 518, 519, 520, 521, 522, 523, 55, 0, 363, 1, 59, 2,
+        2, 17, 44, 3, 44, 2, 2, 55, 222, 55, 133, 1, 0, 59, 0, 0, 0, 27, 1, 1,
+        19, 1, 55, 55, 55, 0, 0, 55, 5, 138, 6, 3, 2, 5, 6, 136, 524, 60, 3, 27,
+        6, 3, 302, 133, 447, 112, 139, 1, 1, 1, 1, 60, 19, 44, 5, 2, 525, 2, 525, 1,
+        1, 0, 2, 44, 5, 5, 5, 3, 20, 31, 31, 1, 1, 1, 1, 17, 55, 0, 0, 17,
+        44, 27, 0, 408, 16, 17, 1, 69, 2, 129, 6, 45, 17, 2, 176, 55, 126, 55, 55, 55,
+        0, 0, 0, 3, 17, 2, 2, 2, 2, 128, 337, 2, 128, 337, 6, 17, 31, 27, 27, 31,
+        27, 3, 3, 0, 60, 1, 17, 32, 2, 1, 17, 17, 3, 0, 0, 1, 0, 60, 1, 17,
+        20, 0, 1, 59, 3, 55, 7, 6, 17, 150, 150, 1, 17, 19, 6, 0, 0, 16, 0, 0,
+        31, 2, 2, 0, 230, 0, 1, 6, 17, 150, 17, 0, 17, 17, 34, 6, 31, 41, 31, 1,
+        31, 16, 6, 6, 17, 124, 16, 27, 0, 46, 3, 59, 22, 0, 2, 11, 55, 0, 2, 204,
+        3, 1, 59, 17, 6, 0, 60, 17, 0, 17, 55, 55, 5, 0, 0, 3, 17, 55, 31, 126,
+        0, 94, 33, 2, 0, 6, 60, 59, 3, 45, 2, 31, 1, 1, 5, 2, 59, 46, 17, 0,
+        31, 17, 1, 45, 17, 0, 3, 0, 158, 2, 6, 2, 16, 6, 7, 2, 27, 6, 16, 16,
+        2, 0, 0, 6, 6, 41, 6, 6, 55, 126, 17, 31, 17, 27, 17, 6, 144, 144, 195, 6,
+        6, 41, 91, 2, 6, 7, 5, 27, 5, 1, 1, 1, 1, 1, 1, 140, 139, 5, 5, 5,
+        5, 3, 2, 6, 0, 2, 1, 1, 55, 126, 59, 16, 14, 3, 3, 17, 16, 2, 8, 41,
+        2, 5, 91, 6, 6, 16, 17, 0, 0, 0, 31, 0, 3, 59, 136, 3, 0, 0, 1, 1,
+        1, 6, 27, 55, 31, 5, 17, 6, 2, 23, 1, 41, 1, 0, 16, 53, 17, 0, 17, 91,
+        165, 1, 45, 6, 2, 6, 7, 17, 1, 17, 1, 2, 2, 2, 2, 6, 0, 23, 17, 6,
+        44, 46, 16, 17, 16, 0, 363, 6, 17, 31, 3, 6, 31, 31, 6, 0, 0, 1, 0, 5,
+        0, 2, 0, 44, 3, 2, 30, 0, 17, 3, 17, 32, 17, 32, 1, 0, 31, 6, 0, 46,
+        2, 0, 31, 27, 302, 44, 17, 3, 0, 5, 41, 80, 19, 19, 17, 6, 0, 17, 3, 1,
+        3, 6, 0, 69, 27, 1, 27, 31, 44, 60, 17, 6, 526, 2, 20, 17, 126, 17, 6, 17,
+        46, 0, 27, 41, 27, 19, 126, 17, 363, 32, 3, 16, 55, 55, 0, 60, 0, 0, 0, 126,
+        32, 3, 0, 1, 3, 19, 0, 1, 527, 6, 6, 27, 25, 41, 20, 1, 0, 2, 0, 17,
+        56, 0, 2, 39, 7, 2, 2, 0, 0, 5, 5, 2, 0, 6, 25, 7, 2, 0, 0, 154,
+        55, 1, 1, 16, 5, 27, 54, 5, 17, 71, 1, 5, 112, 139, 54, 54, 1, 1, 6, 6,
+        2, 2, 31, 45, 3, 17, 5, 2, 1, 44, 54, 528, 2, 55, 44, 2, 5, 529, 166, 23,
+        530, 531, 6, 0, 6, 1, 6, 6, 20, 5, 2, 2, 2, 2, 32, 6, 16, 2, 2, 31,
+        0, 59, 32, 2, 527, 2, 6, 55, 46, 126, 1, 126, 55, 0, 17, 3, 0, 31, 0, 1,
+        44, 44, 0, 55, 1, 1, 0, 25, 19, 3, 1, 2, 56, 56, 5, 112, 56, 0, 165, 20,
+        31, 6, 45, 17, 44, 17, 17, 31, 17, 27, 0, 27, 44, 44, 2, 0, 0, 31, 44, 6,
+        1, 46, 19, 46, 1, 1, 17, 230, 0, 1, 31, 31, 25, 25, 222, 3, 0, 0, 6, 133,
+        16, 27, 27, 6, 55, 7, 3, 55, 5, 3, 60, 44, 1, 17, 1, 44, 0, 31, 27, 2,
+        6, 6, 1, 41, 145, 55, 55, 44, 2, 5, 20, 6, 34, 3, 532, 0, 0, 16, 3, 31,
+        3, 3, 2, 17, 17, 1, 31, 0, 16, 222, 19, 1, 1, 2, 0, 5, 181, 3, 5, 6,
+        6, 0, 41, 59, 6, 0, 0, 1, 17, 17, 6, 3, 1, 6, 268, 307, 27, 2, 195, 6,
+        31, 0, 31, 6, 0, 46, 2, 20, 44, 198, 33, 17, 16, 44, 44, 17, 2, 31, 6, 27,
+        0, 0, 0, 41, 533, 3, 0, 3, 59, 17, 6, 3, 6, 0, 1, 3, 17, 44, 40, 46,
+        5, 1, 59, 0, 44, 17, 3, 6, 1, 6, 20, 5, 59, 1, 31, 1, 0, 6, 1, 55,
+        17, 2, 6, 6, 59, 2, 6, 5, 5, 1, 41, 1, 16, 16, 2, 3, 31, 55, 60, 0,
+        16, 44, 16, 44, 1, 1, 55, 0, 3, 1, 1, 59, 31, 16, 14, 3, 27, 44, 18, 31,
+        17, 17, 0, 59, 16, 17, 3, 91, 1, 17, 17, 0, 0, 17, 17, 6, 60, 0, 32, 1,
+        6, 1, 0, 1, 6, 17, 31, 16, 1, 5, 5, 6, 5, 56, 6, 41, 17, 6, 41, 60,
+        16, 2, 2, 41, 20, 55, 0, 44, 127, 17, 1, 32, 3, 0, 1, 59, 16, 46, 1, 0,
+        60, 534, 60, 3, 31, 31, 0, 127, 0, 31, 1, 0, 126, 69, 230, 60, 0, 31, 0, 2,
+        20, 31, 19, 0, 32, 3, 5, 5, 17, 59, 59, 16, 17, 31, 19, 60, 127, 17, 3, 55,
+        55, 1, 19, 19, 126, 1, 17, 59, 1, 59, 59, 3, 60, 59, 0, 59, 55, 25, 1, 60,
+        59, 0, 126, 27, 136, 0, 535, 17, 3, 0, 59, 2, 59, 162, 5, 31, 27, 162, 536, 5,
+        2, 16, 0, 2, 1, 17, 45, 124, 124, 32, 27, 27, 17, 31, 3, 17, 17, 3, 1, 1,
+        1, 0, 1, 0, 55, 55, 55, 0, 0, 0, 55, 55, 363, 126, 0, 41, 41, 1, 32, 2,
+        20, 23, 23, 5, 27, 31, 406, 3, 91, 6, 3, 41, 41, 20, 46, 55, 17, 2, 60, 1,
+        41, 1, 17, 27, 17, 17, 5, 5, 2, 20, 3, 3, 126, 20, 17, 146, 31, 6, 27, 31,
+        1, 5, 2, 0, 60, 60, 55, 55, 0, 5, 5, 41, 6, 55, 46, 55, 0, 1, 0, 1,
+        3, 56, 140, 27, 190, 1, 2, 0, 6, 59, 6, 17, 0, 363, 6, 56, 140, 0, 5, 20,
+        2, 1, 3, 537, 72, 6, 27, 126, 6, 165, 17, 17, 46, 126, 17, 390, 1, 60, 7, 1,
+        5, 0, 55, 3, 6, 6, 2, 129, 1, 5, 55, 3, 2, 2, 362, 41, 31, 17, 5, 0,
+        1, 0, 2, 16, 31, 14, 5, 6, 5, 2, 17, 1, 1, 1, 60, 3, 176, 3, 27, 1,
+        27, 538, 2, 2, 2, 1, 0, 0, 0, 32, 447, 112, 139, 112, 139, 153, 56, 140, 139, 0,
+        2, 56, 153, 56, 140, 0, 1, 60, 1, 17, 13, 13, 55, 55, 13, 13, 6, 41, 1, 2,
+        2, 5, 112, 139, 112, 139, 1, 1, 2, 2, 2, 2, 2, 11, 11, 16, 1, 2, 0, 6,
+        41, 1, 0, 27, 6, 2, 55, 55, 55, 55, 55, 55, 2, 3, 1, 19, 21, 60, 127, 31,
+        0, 34, 3, 3, 0, 127, 55, 539, 60, 60, 59, 59, 1, 1, 60, 2, 540, 1, 1, 1,
+        6, 16, 7, 5, 18, 16, 16, 31, 16, 1, 59, 21, 17, 7, 59, 60, 60, 60, 60, 59,
+        60, 0, 1, 16, 46, 0, 17, 0, 6, 2, 2, 0, 0, 23, 31, 1, 17, 60, 59, 60,
+        16, 17, 2, 6, 41, 2, 6, 6, 3, 20, 2, 41, 20, 5, 91, 31, 16, 17, 44, 1,
+        31, 17, 1, 60, 176, 25, 31, 127, 32, 3, 0, 6, 0, 6, 0, 59, 0, 44, 0, 17,
+        0, 55, 25, 222, 3, 3, 17, 176, 55, 59, 0, 0, 59, 25, 46, 186, 55, 27, 17, 0,
+        1, 2, 5, 0, 60, 60, 0, 105, 6, 1, 1, 6, 1, 3, 0, 2, 32, 1, 1, 0,
+        0, 27, 44, 27, 6, 3, 27, 7, 3, 20, 2, 31, 5, 17, 408, 6, 363, 2, 27, 0,
+        17, 0, 149, 7, 0, 413, 6, 2, 2, 1, 2, 2, 359, 1, 5, 5, 505, 2, 5, 5,
+        197, 541, 5, 2, 16, 3, 16, 16, 2, 2, 3, 31, 54, 2, 2, 2, 5, 2, 0, 2,
+        2, 436, 542, 16, 16, 2, 5, 5, 5, 5, 0, 55, 55, 112, 139, 112, 139, 112, 139, 2,
+        1, 2, 0, 543, 544, 5, 6, 2, 5, 112, 139, 27, 60, 6, 545, 5, 60, 3, 14, 14,
+        7, 14, 59, 59, 71, 546, 2, 71, 546, 32, 71, 5, 5, 17, 71, 18, 136, 389, 31, 2,
+        16, 17, 6, 41, 31, 0, 1, 1, 16, 17, 126, 0, 2, 3, 17, 6, 6, 547, 27, 0,
+        0, 1, 31, 3, 25, 0, 60, 1, 1, 31, 3, 60, 5, 186, 6, 17, 161, 16, 54, 2,
+        44, 0, 31, 27, 16, 17, 6, 5, 59, 0, 1, 31, 17, 194, 44, 27, 31, 45, 3, 2,
+        5, 20, 5, 5, 5, 17, 19, 55, 0, 46, 31, 55, 2, 16, 2, 55, 27, 55, 27, 46,
+        0, 31, 27, 27, 17, 31, 17, 27, 5, 27, 17, 8, 16, 8, 2, 2, 5, 1, 1, 1,
+        5, 2, 548, 2, 2, 55, 2, 44, 17, 6, 2, 2, 2, 31, 0, 27, 46, 2, 2, 549,
+        2, 6, 2, 27, 0, 2, 2, 0, 126, 2, 2, 17, 6, 5, 0, 6, 6, 0, 6, 2,
+        2, 2, 17, 2, 0, 17, 16, 195, 1, 5, 17, 59, 3, 550, 8, 2, 112, 139, 1, 60,
+        1, 19, 55, 126, 16, 3, 6, 6, 2, 1, 31, 17, 6, 56, 112, 72, 551, 0, 2, 2,
+        2, 17, 56, 112, 2, 31, 134, 5, 20, 6, 16, 126, 3, 17, 31, 55, 31, 6, 2, 6,
+        0, 1, 0, 0, 44, 3, 59, 5, 1, 17, 5, 186, 1, 60, 5, 5, 17, 3, 83, 2,
+        266, 1, 2, 0, 1, 55, 0, 230, 17, 69, 0, 2, 2, 112, 139, 0, 5, 13, 13, 60,
+        59, 8, 54, 31, 127, 3, 0, 31, 552, 27, 17, 1, 0, 0, 56, 0, 230, 27, 1, 17,
+        0, 27, 3, 6, 1, 0, 31, 0, 0, 552, 27, 60, 5, 17, 17, 59, 0, 0, 59, 363,
+        46, 55, 5, 55, 0, 0, 27, 1, 32, 0, 553, 54, 1, 1, 0, 1, 1, 1, 1, 18,
+        136, 47, 46, 60, 0, 56, 140, 0, 112, 139, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
+        32, 0, 0, 0, 0, 0, 0, 0, 268, 307, 2, 0, 3, 0, 3, 2, 0, 3, 5, 3,
+        2, 0, 2, 2, 2, 2, 2, 39, 1, 1, 6, 127, 16, 1, 0, 5, 0, 3, 0, 27,
+        5, 1, 1, 31, 2, 2, 2, 2, 5, 5, 3, 3, 447, 112, 139, 17, 17, 319, 54, 31,
+        14, 32, 60, 80, 5, 3, 0, 554, 16, 41, 2, 129, 2, 1, 555, 2, 56, 140, 6, 6,
+        3, 3, 7, 381, 212, 6, 1, 17, 31, 31, 27, 2, 0, 1, 6, 2, 6, 1, 17, 3,
+        2, 6, 2, 13, 8, 3, 6, 7, 2, 0, 0, 381, 81, 0, 1, 16, 14, 5, 55, 31,
+        31, 0, 3, 60, 1, 556, 27, 31, 47, 278, 47, 47, 3, 5, 59, 59, 5, 5, 5, 7,
+        27, 6, 2, 2, 2, 6, 1, 0, 186, 222, 32, 2, 6, 3, 19, 55, 46, 16, 32, 30,
+        0, 55, 19, 55, 55, 0, 55, 0, 0, 0, 0, 60, 0, 46, 0, 1, 46, 83, 83, 6,
+        5, 83, 54, 6, 2, 41, 2, 2, 2, 2, 2, 2, 1, 129, 1, 33, 3, 55, 1, 41,
+        3, 5, 5, 1, 3, 17, 6, 31, 5, 72, 31, 6, 60, 59, 1, 60, 44, 382, 1, 31,
+        5, 5, 46, 0, 1, 1, 16, 55, 31, 126, 126, 3, 19, 19, 1, 1, 19, 0, 0, 60,
+        0, 31, 17, 146, 33, 1, 1, 1, 3, 5, 1, 1, 1, 91, 6, 41, 0, 31, 6, 1,
+        0, 58, 116, 3, 6, 1, 27, 1, 0, 3, 3, 6, 3, 3, 31, 19, 1, 5, 71, 245,
+        46, 46, 539, 0, 1, 3, 13, 13, 0, 41, 60, 17, 1, 2, 16, 2, 16, 45, 17, 145,
+        0, 31, 27, 0, 0, 17, 127, 0, 222, 0, 0, 55, 17, 0, 31, 17, 16, 17, 1, 27,
+        59, 0, 60, 126, 0, 3, 55, 25, 59, 0, 55, 0, 59, 46, 19, 59, 0, 18, 0, 2,
+        5, 55, 59, 0, 1, 46, 46, 6, 2, 0, 17, 557, 6, 124, 0, 45, 47, 17, 17, 2,
+        54, 31, 27, 27, 6, 446, 2, 2, 27, 17, 17, 1, 17, 552, 363, 6, 27, 17, 18, 136,
+        3, 17, 259, 558, 31, 6, 31, 69, 0, 0, 108, 1, 0, 0, 60, 60, 1, 0, 1, 5,
+        0, 0, 256, 17, 16, 1, 3, 44, 59, 69, 34, 17, 16, 44, 46, 0, 60, 0, 59, 0,
+        46, 18, 3, 59, 60, 25, 0, 17, 46, 17, 16, 0, 46, 0, 59, 16, 25, 0, 0, 46,
+        55, 0, 1, 17, 46, 5, 2, 17, 17, 22, 6, 20, 31, 17, 55, 16, 17, 1, 3, 139,
+        139, 7, 0, 5, 44, 0, 44, 31, 16, 0, 176, 1, 1, 0, 1, 5, 1, 0, 46, 1,
+        127, 2, 41, 80, 1, 1, 0, 5, 31, 46, 7, 1, 17, 1, 426, 27, 59, 55, 31, 59,
+        5, 17, 17, 2, 2, 204, 559, 30, 2, 2, 6, 1, 17, 27, 1, 248, 2, 6, 6, 41,
+        1, 1, 5, 1, 94, 16, 30, 0, 6, 2, 0, 0, 333, 6, 31, 20, 1, 41, 0, 27,
+        41, 2, 0, 27, 0, 0, 39, 41, 44, 2, 3, 6, 0, 55, 55, 3, 1, 59, 0, 6,
+        5, 31, 31, 31, 126, 1, 127, 27, 1, 1, 1, 39, 0, 0, 6, 1, 0, 0, 31, 60,
+        3, 60, 0, 44, 46, 46, 1, 59, 7, 1, 6, 6, 16, 6, 16, 17, 150, 1, 2, 16,
+        17, 0, 33, 0, 0, 2, 27, 17, 124, 17, 46, 129, 20, 126, 27, 0, 17, 44, 126, 147,
+        3, 31, 6, 222, 2, 124, 27, 3, 31, 150, 44, 126, 27, 6, 1, 560, 16, 1, 5, 6,
+        94, 3, 17, 222, 19, 0, 5, 17, 59, 46, 0, 25, 16, 44, 27, 27, 3, 55, 44, 27,
+        17, 59, 46, 126, 17, 6, 31, 16, 17, 6, 59, 3, 251, 25, 0, 0, 0, 60, 3, 25,
+        17, 150, 25, 7, 60, 31, 59, 59, 1, 60, 55, 0, 126, 27, 16, 31, 17, 46, 25, 17,
+        0, 0, 127, 59, 1, 1, 0, 138, 0, 0, 59, 0, 0, 0, 0, 27, 32, 6, 17, 6,
+        1, 112, 139, 69, 59, 0, 0, 0, 59, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 31,
+        55, 55, 45, 44, 25, 3, 17, 17, 18, 6, 3, 1, 561, 3, 60, 46, 17, 17, 27, 3,
+        0, 1, 1, 44, 0, 1, 1, 7, 17, 1, 17, 16, 0, 16, 1, 1, 0, 16, 363, 126,
+        27, 46, 46, 1, 17, 31, 1, 27, 1, 44, 1, 33, 2, 3, 17, 16, 16, 17, 32, 5,
+        0, 44, 16, 0, 31, 46, 0, 31, 0, 55, 31, 19, 0, 0, 0, 27, 55, 17, 60, 60,
+        60, 60, 60, 0, 16, 5, 41, 2, 2, 0, 3, 31, 17, 3, 5, 1, 1, 16, 0, 17,
+        3, 59, 562, 3, 17, 0, 176, 16, 2, 31, 2, 2, 14, 16, 6, 6, 3, 3, 2, 1,
+        2, 1, 2, 0, 204, 14, 1, 31, 0, 1, 22, 22, 16, 5, 41, 2, 2, 31, 6, 6,
+        17, 6, 0, 0, 16, 27, 1, 31, 17, 55, 256, 1, 0, 5, 7, 7, 7, 54, 54, 0,
+        0, 33, 129, 41, 20, 430, 431, 55, 5, 55, 55, 127, 21, 59, 17, 2, 5, 0, 17, 6,
+        6, 41, 27, 17, 1, 31, 31, 27, 31, 368, 563, 564, 139, 0, 0, 0, 60, 45, 3, 2,
+        55, 31, 1, 27, 0, 1, 27, 17, 41, 3, 2, 2, 565, 11, 140, 56, 16, 0, 27, 31,
+        27, 3, 27, 3, 59, 0, 31, 126, 105, 6, 17, 20, 5, 112, 139, 34, 44, 0, 54, 2,
+        231, 54, 566, 54, 0, 1, 1, 2, 5, 567, 568, 569, 570, 571, 572, 573, 1, 54, 8, 574,
+        575, 2, 576, 16, 16, 6, 7, 5, 2, 2, 2, 3, 547, 23, 6, 41, 5, 5, 1, 1,
+        8, 8, 2, 31, 5, 5, 2, 5, 5, 5, 3, 2, 5, 5, 5, 5, 2, 2, 5, 7,
+        2, 0, 54, 577, 268, 307, 578, 54, 577, 0, 0, 2, 2, 430, 431, 579, 112, 1, 0, 1,
+        16, 6, 3, 5, 1, 1, 7, 5, 129, 2, 5, 2, 1, 6, 0, 16, 7, 2, 2, 2,
+        2, 580, 581, 17, 6, 6, 3, 41, 6, 145, 6, 2, 31, 0, 6, 2, 0, 0, 5, 5,
+        3, 2, 25, 17, 59, 27, 5, 5, 1, 27, 1, 44, 3, 2, 27, 1, 17, 0, 55, 126,
+        17, 0, 5, 27, 6, 1, 20, 27, 2, 0, 31, 31, 31, 5, 3, 1, 1, 1, 6, 7,
+        3, 41, 16, 16, 6, 1, 3, 1, 1, 17, 71, 17, 46, 27, 0, 5, 2, 5, 2, 437,
+        7, 17, 71, 31, 2, 1, 2, 5, 2, 5, 30, 31, 126, 31, 3, 378, 55, 31, 31, 17,
+        27, 0, 3, 3, 60, 0, 0, 41, 20, 1, 17, 6, 27, 3, 67, 6, 6, 6, 6, 41,
+        20, 55, 1, 19, 6, 2, 94, 1, 6, 0, 17, 0, 17, 41, 0, 159, 5, 17, 17, 3,
+        17, 41, 6, 27, 17, 25, 44, 31, 1, 16, 0, 17, 17, 17, 0, 17, 44, 150, 127, 6,
+        17, 17, 6, 1, 0, 126, 31, 0, 27, 27, 1, 1, 31, 3, 44, 34, 104, 5, 7, 105,
+        6, 21, 31, 55, 60, 3, 6, 0, 59, 3, 31, 60, 0, 17, 1, 447, 112, 139, 0, 27,
+        17, 1, 1, 1, 1, 6, 0, 17, 5, 31, 0, 1, 59, 21, 27, 17, 0, 1, 1, 27,
+        31, 59, 3, 20, 59, 1, 55, 5, 7, 5, 17, 59, 59, 0, 150, 17, 6, 1, 23, 6,
+        27, 17, 6, 3, 2, 2, 5, 3, 6, 1, 0, 7, 5, 5, 3, 44, 59, 1, 1, 3,
+        136, 0, 27, 1, 55, 46, 3, 55, 0, 59, 127, 0, 0, 176, 2, 5, 0, 31, 27, 2,
+        60, 582, 2, 54, 583, 2, 3, 6, 54, 268, 307, 0, 0, 2, 5, 2, 2, 2, 2, 2,
+        2, 3, 186, 1, 153, 56, 140, 17, 2, 20, 6, 5, 1, 162, 536, 17, 17, 1, 2, 277,
+        41, 60, 17, 6, 126, 3, 41, 0, 17, 0, 46, 19, 112, 139, 55, 55, 55, 31, 31, 44,
+        3, 31, 1, 3, 447, 112, 139, 112, 139, 27, 27, 31, 16, 0, 55, 126, 105, 6, 1, 0,
+        0, 0, 0, 60, 0, 0, 55, 0, 0, 1, 0, 1, 16, 7, 0, 44, 1, 44, 44, 33,
+        20, 59, 59, 17, 16, 17, 0, 127, 2, 6, 0, 17, 55, 16, 17, 17, 0, 17, 55, 363,
+        126, 584, 585, 586, 3, 14, 6, 7, 6, 41, 1, 2, 6, 1, 587, 16, 33, 112, 139, 6,
+        31, 30, 39, 0, 31, 17, 2, 5, 5, 80, 17, 17, 27, 27, 0, 0, 55, 31, 588, 44,
+        1, 20, 31, 17, 45, 1, 1, 3, 20, 5, 27, 126, 5, 2, 3, 44, 60, 59, 1, 17,
+        17, 0, 2, 0, 31, 0, 3, 5, 3, 34, 3, 3, 83, 0, 1, 0, 1, 1, 1, 1,
+        589, 1, 6, 1, 60, 0, 0, 27, 1, 1, 2, 5, 44, 17, 17, 17, 59, 60, 1, 0,
+        17, 2, 2, 2, 3, 6, 41, 54, 54, 54, 54, 0, 71, 5, 5, 0, 2, 5, 5, 126,
+        17, 2, 2, 7, 2, 3, 6, 3, 2, 16, 3, 54, 54, 5, 5, 1, 1, 0, 0, 146,
+        5, 3, 1, 2, 5, 3, 19, 31, 41, 27, 18, 46, 31, 31, 2, 2, 2, 2, 0, 17,
+        3, 126, 127, 5, 27, 31, 21, 17, 6, 27, 46, 1, 3, 23, 31, 145, 27, 2, 3, 31,
+        41, 1, 1, 0, 27, 3, 44, 1, 126, 41, 5, 5, 5, 41, 2, 3, 3, 2, 41, 1,
+        2, 41, 1, 2, 2, 2, 0, 6, 80, 72, 36, 2, 2, 2, 2, 2, 6, 128, 590, 2,
+        549, 1, 1, 7, 1, 31, 55, 0, 59, 3, 162, 17, 3, 25, 2, 1, 2, 41, 2, 6,
+        5, 5, 2, 5, 45, 17, 0, 17, 1, 17, 59, 186, 31, 1, 32, 0, 0, 176, 1, 0,
+        46, 126, 55, 55, 55, 31, 17, 32, 3, 17, 3, 1, 31, 16, 55, 55, 55, 0, 55, 55,
+        27, 0, 5, 3, 0, 7, 1, 60, 3, 5, 19, 17, 17, 2, 59, 16, 0, 222, 19, 0,
+        17, 60, 60, 6, 3, 27, 126, 25, 126, 3, 17, 0, 6, 3, 16, 1, 1, 3, 32, 2,
+        94, 1, 6, 91, 31, 17, 31, 17, 6, 41, 17, 17, 5, 2, 480, 7, 2, 3, 2, 3,
+        5, 6, 39, 5, 6, 3, 153, 56, 44, 3, 32, 1, 25, 2, 2, 2, 17, 19, 0, 6,
+        3, 59, 45, 17, 31, 17, 0, 2, 2, 16, 46, 44, 17, 6, 0, 1, 55, 17, 2, 2,
+        32, 1, 17, 17, 41, 41, 55, 46, 55, 55, 17, 127, 17, 127, 18, 1, 46, 0, 3, 44,
+        2, 591, 112, 139, 31, 60, 447, 112, 139, 54, 0, 5, 2, 2, 5, 2, 5, 2, 11, 2,
+        11, 60, 1, 55, 25, 19, 19, 55, 17, 0, 55, 55, 5, 16, 17, 0, 55, 55, 55, 60,
+        0, 1, 112, 139, 27, 41, 17, 3, 27, 0, 222, 1, 1, 2, 2, 17, 5, 3, 41, 41,
+        3, 1, 1, 44, 17, 41, 17, 17, 17, 181, 32, 3, 0, 17, 17, 16, 17, 16, 17, 59,
+        0, 17, 5, 256, 6, 0, 31, 45, 1, 18, 136, 0, 1, 7, 0, 23, 0, 0, 1, 1,
+        20, 17, 55, 1, 5, 1, 59, 3, 126, 2, 17, 17, 0, 0, 55, 5, 31, 17, 27, 126,
+        0, 60, 60, 31, 6, 17, 32, 1, 23, 176, 5, 44, 55, 46, 19, 3, 55, 46, 17, 17,
+        19, 3, 25, 55, 138, 0, 60, 127, 19, 59, 17, 176, 60, 1, 136, 3, 27, 0, 25, 60,
+        16, 60, 3, 1, 1, 55, 55, 55, 55, 55, 0, 55, 126, 5, 41, 91, 2, 5, 60, 0,
+        55, 17, 126, 46, 17, 59, 17, 17, 112, 139, 1, 19, 19, 387, 5, 16, 17, 6, 2, 146,
+        1, 55, 55, 55, 55, 55, 3, 27, 0, 2, 17, 2, 153, 56, 140, 447, 112, 139, 91, 5,
+        5, 60, 55, 126, 222, 55, 60, 60, 60, 55, 55, 6, 1, 17, 2, 44, 2, 60, 31, 1,
+        21, 55, 31, 17, 17, 60, 55, 21, 17, 150, 6, 176, 46, 60, 59, 0, 27, 60, 60, 60,
+        60, 105, 6, 17, 17, 16, 16, 3, 17, 3, 176, 6, 413, 592, 91, 6, 2, 5, 55, 55,
+        593, 594, 14, 0, 0, 30, 19, 0, 31, 31, 5, 3, 60, 46, 230, 31, 126, 59, 60, 0,
+        5, 55, 60, 55, 17, 3, 17, 17, 6, 2, 2, 1, 3, 0, 222, 31, 6, 2, 19, 116,
+        36, 5, 2, 7, 5, 31, 0, 16, 55, 55, 0, 55, 0, 0, 60, 3, 1, 126, 60, 21,
+        27, 230, 176, 0, 59, 1, 1, 0, 0, 363, 17, 552, 27, 59, 59, 595, 596, 59, 25, 0,
+        363, 17, 17, 0, 127, 3, 2, 1, 1, 186, 222, 32, 597, 112, 139, 55, 27, 55, 17, 2,
+        33, 0, 27, 0, 334, 17, 0, 27, 27, 0, 17, 2, 5, 17, 55, 17, 6, 0, 3, 5,
+        23, 2, 5, 31, 17, 0, 20, 59, 27, 59, 55, 14, 1, 2, 0, 5, 5, 25, 0, 0,
+        5, 0, 0, 0, 150, 3, 55, 31, 20, 2, 3, 3, 5, 5, 5, 3, 0, 126, 3, 41,
+        5, 59, 16, 2, 2, 1, 1, 0, 1, 1, 1, 222, 19, 3, 5, 2, 55, 0, 55, 27,
+        55, 0, 160, 59, 0, 0, 55, 55, 6, 1, 60, 55, 27, 2, 59, 55, 0, 55, 55, 0,
+        55, 1, 5, 5, 17, 126, 0, 5, 5, 19, 46, 46, 17, 27, 55, 1, 25, 0, 22, 17,
+        0, 0, 6, 6, 2, 55, 59, 59, 598, 3, 1, 46, 17, 17, 31, 25, 2, 44, 599, 600,
+        601, 602, 603, 112, 139, 604, 59, 0, 2, 17, 45, 0, 5, 605, 606, 607, 142, 60, 60, 59,
+        60, 0, 186, 1, 1, 60, 16, 59, 0, 150, 0, 16, 17, 126, 3, 46, 0, 3, 126, 44,
+        0, 59, 3, 25, 3, 3, 126, 46, 176, 17, 17, 59, 46, 0, 31, 59, 1, 1, 608, 44,
+        17, 2, 6, 2, 333, 2, 2, 3, 41, 126, 20, 1, 55, 55, 55, 3, 44, 2, 59, 25,
+        31, 19, 6, 5, 16, 14, 0, 44, 3, 16, 0, 60, 1, 1, 17, 33, 33, 17, 6, 124,
+        27, 17, 44, 0, 1, 19, 2, 59, 17, 27, 17, 0, 1, 60, 31, 19, 0, 44, 25, 1,
+        0, 3, 0, 17, 6, 1, 25, 1, 59, 136, 138, 5, 25, 17, 6, 1, 17, 60, 1, 6,
+        0, 138, 0, 27, 59, 117, 55, 5, 1, 27, 23, 17, 55, 27, 7, 0, 0, 27, 31, 55,
+        0, 17, 2, 1, 363, 3, 27, 5, 0, 17, 5, 0, 1, 44, 0, 34, 1, 0, 2, 3,
+        0, 0, 3, 1, 17, 16, 2, 2, 3, 17, 2, 27, 3, 55, 126, 27, 0, 55, 2, 33,
+        0, 126, 55, 19, 5, 17, 1, 117, 31, 31, 17, 19, 17, 45, 232, 16, 217, 31, 16, 0,
+        17, 2, 6, 17, 17, 17, 31, 1, 59, 3, 27, 17, 22, 6, 22, 6, 268, 129, 5, 126,
+        139, 17, 3, 54, 2, 268, 54, 72, 54, 566, 8, 609, 3, 610, 3, 5, 5, 5, 268, 307,
+        578, 54, 577, 16, 45, 3, 44, 27, 1, 1, 7, 59, 3, 0, 126, 0, 46, 126, 46, 6,
+        41, 2, 17, 55, 0, 17, 17, 60, 17, 59, 60, 16, 1, 41, 16, 17, 0, 6, 6, 20,
+        6, 16, 17, 1, 17, 5, 1, 129, 552, 21, 126, 27, 3, 5, 5, 31, 44, 1, 129, 127,
+        7, 3, 6, 0, 6, 6, 17, 1, 5, 1, 31, 31, 127, 21, 59, 0, 17, 41, 55, 31,
+        2, 611, 54, 11, 612, 613, 54, 268, 307, 0, 222, 19, 222, 19, 6, 3, 1, 1, 1, 0,
+        60, 60, 46, 1, 60, 127, 59, 60, 0, 0, 1, 1, 0, 303, 1, 1, 59, 1, 59, 59,
+        16, 59, 59, 17, 46, 44, 46, 222, 19, 2, 16, 27, 31, 126, 17, 31, 46, 59, 25, 2,
+        3, 2, 59, 27, 55, 55, 6, 20, 17, 6, 5, 60, 1, 17, 25, 5, 16, 3, 5, 5,
+        60, 1, 1, 20, 1, 46, 126, 17, 2, 5, 5, 2, 5, 55, 55, 126, 126, 17, 1, 5,
+        55, 32, 3, 55, 55, 60, 127, 1, 127, 2, 33, 60, 0, 1, 2, 2, 5, 6, 44, 2,
+        2, 6, 0, 150, 46, 2, 300, 55, 55, 55, 55, 2, 31, 1, 3, 45, 17, 16, 17, 17,
+        17, 1, 1, 1, 31, 41, 20, 1, 1, 5, 17, 222, 19, 0, 0, 126, 44, 3, 0, 55,
+        55, 3, 55, 55, 19, 55, 55, 19, 31, 55, 44, 17, 16, 14, 94, 30, 46, 230, 27, 59,
+        0, 2, 6, 186, 32, 55, 7, 0, 46, 17, 46, 46, 5, 59, 27, 55, 127, 1, 5, 60,
+        27, 44, 31, 55, 17, 145, 3, 17, 5, 31, 17, 5, 138, 3, 5, 27, 2, 2, 1, 27,
+        5, 32, 17, 17, 17, 17, 0, 497, 27, 18, 44, 1, 1, 1, 55, 55, 55, 55, 2, 55,
+        55, 55, 19, 55, 222, 19, 17, 176, 31, 17, 136, 31, 21, 154, 25, 55, 31, 32, 16, 31,
+        16, 59, 3, 19, 6, 19, 3, 0, 19, 44, 614, 19, 126, 17, 2, 59, 1, 46, 46, 3,
+        3, 60, 3, 60, 3, 59, 3, 2, 16, 21, 534, 17, 17, 31, 46, 1, 25, 55, 126, 0,
+        25, 25, 25, 17, 17, 18, 5, 6, 17, 31, 17, 0, 1, 91, 55, 55, 55, 176, 55, 19,
+        60, 59, 140, 31, 0, 3, 17, 19, 126, 17, 17, 17, 27, 1, 60, 16, 6, 54, 268, 307,
+        60, 3, 60, 60, 59, 60, 1, 1, 2, 176, 5, 17, 2, 55, 55, 55, 46, 17, 363, 46,
+        2, 1, 126, 55, 27, 55, 44, 46, 3, 222, 3, 16, 6, 17, 31, 59, 31, 2,
+    ],
+    &[
+        [Edit::new(0, 0, 1)],
+        [Edit::new(1, 0, 0)],
+        [Edit::new(1, 0, 1)],
+        [Edit::new(3, 1, 3)],
+        [Edit::new(0, 0, 0)],
+        [Edit::new(1, 2, 2)],
+        [Edit::new(0, 2, 2)],
+        [Edit::new(0, 4, 2)],
+        [Edit::new(1, 1, 1)],
+        [Edit::new(1, 6, 5)],
+        [Edit::new(1, 5, 1)],
+        [Edit::new(0, 11, 2)],
+        [Edit::new(0, 13, 2)],
+        [Edit::new(1, 15, 1)],
+        [Edit::new(2, 16, 2)],
+        [Edit::new(1, 4, 1)],
+        [Edit::new(2, 4, 1)],
+        [Edit::new(2, 18, 2)],
+        [Edit::new(2, 20, 3)],
+        [Edit::new(5, 23, 4)],
+        [Edit::new(3, 27, 4)],
+        [Edit::new(1, 18, 2)],
+        [Edit::new(2, 31, 3)],
+        [Edit::new(1, 34, 2)],
+        [Edit::new(0, 36, 2)],
+        [Edit::new(1, 38, 3)],
+        [Edit::new(0, 2, 1)],
+        [Edit::new(0, 39, 2)],
+        [Edit::new(3, 41, 3)],
+        [Edit::new(1, 14, 1)],
+        [Edit::new(1, 2, 1)],
+        [Edit::new(2, 0, 0)],
+        [Edit::new(1, 44, 3)],
+        [Edit::new(1, 47, 3)],
+        [Edit::new(1, 50, 2)],
+        [Edit::new(2, 35, 1)],
+        [Edit::new(1, 52, 4)],
+        [Edit::new(0, 44, 3)],
+        [Edit::new(1, 56, 1)],
+        [Edit::new(0, 57, 3)],
+        [Edit::new(4, 60, 4)],
+        [Edit::new(0, 64, 2)],
+        [Edit::new(5, 66, 8)],
+        [Edit::new(3, 4, 1)],
+        [Edit::new(2, 2, 1)],
+        [Edit::new(1, 20, 3)],
+        [Edit::new(0, 4, 1)],
+        [Edit::new(2, 74, 2)],
+        [Edit::new(5, 76, 6)],
+        [Edit::new(0, 50, 2)],
+        [Edit::new(0, 82, 2)],
+        [Edit::new(2, 84, 3)],
+        [Edit::new(1, 87, 2)],
+        [Edit::new(1, 4, 2)],
+        [Edit::new(1, 36, 2)],
+        [Edit::new(2, 89, 2)],
+        [Edit::new(0, 91, 3)],
+        [Edit::new(0, 14, 1)],
+        [Edit::new(2, 17, 1)],
+        [Edit::new(3, 17, 1)],
+        [Edit::new(1, 94, 1)],
+        [Edit::new(4, 95, 4)],
+        [Edit::new(4, 17, 1)],
+        [Edit::new(1, 40, 3)],
+        [Edit::new(5, 99, 5)],
+        [Edit::new(0, 104, 5)],
+        [Edit::new(2, 109, 4)],
+        [Edit::new(2, 113, 3)],
+        [Edit::new(1, 17, 1)],
+        [Edit::new(2, 116, 2)],
+        [Edit::new(2, 15, 1)],
+        [Edit::new(1, 35, 1)],
+        [Edit::new(2, 100, 2)],
+        [Edit::new(5, 118, 5)],
+        [Edit::new(2, 123, 4)],
+        [Edit::new(6, 127, 8)],
+        [Edit::new(7, 135, 9)],
+        [Edit::new(2, 144, 4)],
+        [Edit::new(6, 148, 8)],
+        [Edit::new(3, 156, 3)],
+        [Edit::new(2, 159, 3)],
+        [Edit::new(6, 162, 6)],
+        [Edit::new(1, 57, 1)],
+        [Edit::new(3, 168, 4)],
+        [Edit::new(4, 172, 5)],
+        [Edit::new(1, 74, 2)],
+        [Edit::new(3, 177, 3)],
+        [Edit::new(3, 180, 3)],
+        [Edit::new(2, 183, 2)],
+        [Edit::new(2, 185, 3)],
+        [Edit::new(3, 31, 3)],
+        [Edit::new(6, 188, 5)],
+        [Edit::new(7, 193, 7)],
+        [Edit::new(1, 13, 2)],
+        [Edit::new(1, 200, 3)],
+        [Edit::new(2, 6, 5)],
+        [Edit::new(8, 203, 6)],
+        [Edit::new(5, 209, 5)],
+        [Edit::new(7, 214, 7)],
+        [Edit::new(2, 150, 2)],
+        [Edit::new(1, 221, 2)],
+        [Edit::new(9, 223, 8)],
+        [Edit::new(3, 231, 2)],
+        [Edit::new(3, 233, 3)],
+        [Edit::new(2, 236, 4)],
+        [Edit::new(4, 240, 2)],
+        [Edit::new(2, 101, 2)],
+        [Edit::new(1, 39, 2)],
+        [Edit::new(1, 49, 2)],
+        [Edit::new(1, 242, 3)],
+        [Edit::new(1, 101, 2)],
+        [Edit::new(3, 89, 2)],
+        [Edit::new(0, 245, 3)],
+        [Edit::new(4, 248, 5)],
+        [Edit::new(0, 94, 1)],
+        [Edit::new(2, 0, 1)],
+        [Edit::new(2, 233, 3)],
+        [Edit::new(4, 253, 3)],
+        [Edit::new(9, 256, 10)],
+        [Edit::new(8, 266, 8)],
+        [Edit::new(6, 274, 7)],
+        [Edit::new(9, 281, 7)],
+        [Edit::new(4, 288, 4)],
+        [Edit::new(0, 17, 1)],
+        [Edit::new(1, 292, 3)],
+        [Edit::new(2, 2, 2)],
+        [Edit::new(2, 28, 3)],
+        [Edit::new(1, 91, 1)],
+        [Edit::new(0, 40, 3)],
+        [Edit::new(5, 295, 5)],
+        [Edit::new(1, 300, 2)],
+        [Edit::new(0, 5, 2)],
+        [Edit::new(3, 302, 3)],
+        [Edit::new(3, 13, 2)],
+        [Edit::new(5, 305, 6)],
+        [Edit::new(2, 108, 2)],
+        [Edit::new(1, 82, 2)],
+        [Edit::new(1, 28, 3)],
+        [Edit::new(3, 311, 3)],
+        [Edit::new(2, 311, 3)],
+        [Edit::new(3, 314, 3)],
+        [Edit::new(1, 317, 3)],
+        [Edit::new(0, 320, 2)],
+        [Edit::new(1, 322, 2)],
+        [Edit::new(1, 237, 3)],
+        [Edit::new(4, 23, 4)],
+        [Edit::new(2, 324, 4)],
+        [Edit::new(2, 328, 2)],
+        [Edit::new(1, 190, 2)],
+        [Edit::new(2, 27, 4)],
+        [Edit::new(6, 330, 8)],
+        [Edit::new(2, 338, 4)],
+        [Edit::new(2, 87, 2)],
+        [Edit::new(1, 288, 3)],
+        [Edit::new(2, 190, 2)],
+        [Edit::new(2, 342, 4)],
+        [Edit::new(4, 27, 3)],
+        [Edit::new(3, 60, 4)],
+        [Edit::new(3, 346, 3)],
+        [Edit::new(2, 302, 3)],
+        [Edit::new(5, 349, 6)],
+        [Edit::new(3, 2, 1)],
+        [Edit::new(4, 355, 5)],
+        [Edit::new(5, 360, 5)],
+        [Edit::new(4, 350, 5)],
+        [Edit::new(3, 40, 3)],
+        [Edit::new(1, 104, 3)],
+        [Edit::new(1, 125, 2)],
+        [Edit::new(6, 365, 6)],
+        [Edit::new(3, 371, 4)],
+        [Edit::new(1, 156, 3)],
+        [Edit::new(3, 375, 5)],
+        [Edit::new(4, 380, 4)],
+        [Edit::new(6, 384, 6)],
+        [Edit::new(6, 390, 8)],
+        [Edit::new(2, 398, 3)],
+        [Edit::new(3, 401, 5)],
+        [Edit::new(3, 406, 4)],
+        [Edit::new(1, 410, 3)],
+        [Edit::new(2, 28, 2)],
+        [Edit::new(0, 237, 3)],
+        [Edit::new(0, 44, 2)],
+        [Edit::new(2, 43, 2)],
+        [Edit::new(1, 346, 3)],
+        [Edit::new(1, 100, 2)],
+        [Edit::new(3, 413, 3)],
+        [Edit::new(0, 236, 2)],
+        [Edit::new(1, 416, 2)],
+        [Edit::new(7, 418, 8)],
+        [Edit::new(2, 13, 2)],
+        [Edit::new(2, 410, 3)],
+        [Edit::new(2, 3, 3)],
+        [Edit::new(2, 426, 3)],
+        [Edit::new(2, 155, 2)],
+        [Edit::new(0, 56, 1)],
+        [Edit::new(5, 429, 4)],
+        [Edit::new(1, 53, 2)],
+        [Edit::new(3, 433, 3)],
+        [Edit::new(0, 329, 2)],
+        [Edit::new(1, 57, 3)],
+        [Edit::new(4, 436, 6)],
+        [Edit::new(3, 442, 3)],
+        [Edit::new(6, 445, 7)],
+        [Edit::new(2, 47, 3)],
+        [Edit::new(5, 452, 5)],
+        [Edit::new(3, 457, 6)],
+        [Edit::new(3, 463, 5)],
+        [Edit::new(3, 468, 5)],
+        [Edit::new(5, 473, 4)],
+        [Edit::new(2, 39, 2)],
+        [Edit::new(1, 477, 2)],
+        [Edit::new(1, 236, 2)],
+        [Edit::new(5, 479, 6)],
+        [Edit::new(4, 485, 6)],
+        [Edit::new(1, 491, 3)],
+        [Edit::new(4, 494, 5)],
+        [Edit::new(1, 51, 1)],
+        [Edit::new(3, 499, 3)],
+        [Edit::new(1, 108, 2)],
+        [Edit::new(0, 12, 2)],
+        [Edit::new(4, 13, 2)],
+        [Edit::new(3, 2, 2)],
+        [Edit::new(6, 502, 7)],
+        [Edit::new(8, 509, 8)],
+        [Edit::new(7, 509, 8)],
+        [Edit::new(2, 517, 4)],
+        [Edit::new(8, 521, 7)],
+        [Edit::new(8, 528, 7)],
+        [Edit::new(7, 535, 6)],
+        [Edit::new(1, 398, 3)],
+        [Edit::new(0, 35, 1)],
+        [Edit::new(1, 150, 2)],
+        [Edit::new(7, 541, 8)],
+        [Edit::new(8, 549, 7)],
+        [Edit::new(3, 556, 4)],
+        [Edit::new(1, 560, 3)],
+        [Edit::new(3, 563, 4)],
+        [Edit::new(3, 567, 4)],
+        [Edit::new(2, 557, 3)],
+        [Edit::new(5, 571, 4)],
+        [Edit::new(1, 575, 2)],
+        [Edit::new(4, 577, 3)],
+        [Edit::new(2, 580, 3)],
+        [Edit::new(1, 433, 2)],
+        [Edit::new(2, 583, 2)],
+        [Edit::new(7, 585, 8)],
+        [Edit::new(10, 593, 13)],
+        [Edit::new(2, 606, 3)],
+        [Edit::new(3, 20, 2)],
+        [Edit::new(2, 609, 3)],
+        [Edit::new(1, 414, 2)],
+        [Edit::new(0, 233, 2)],
+        [Edit::new(10, 612, 10)],
+        [Edit::new(2, 622, 2)],
+        [Edit::new(8, 624, 7)],
+        [Edit::new(2, 156, 3)],
+        [Edit::new(3, 380, 2)],
+        [Edit::new(12, 631, 13)],
+        [Edit::new(3, 28, 2)],
+        [Edit::new(9, 644, 9)],
+        [Edit::new(4, 653, 4)],
+        [Edit::new(4, 657, 4)],
+        [Edit::new(4, 661, 4)],
+        [Edit::new(4, 665, 5)],
+        [Edit::new(7, 670, 6)],
+        [Edit::new(2, 676, 3)],
+        [Edit::new(4, 679, 5)],
+        [Edit::new(1, 89, 2)],
+        [Edit::new(6, 684, 5)],
+        [Edit::new(6, 689, 6)],
+        [Edit::new(7, 695, 7)],
+        [Edit::new(7, 702, 7)],
+        [Edit::new(3, 709, 7)],
+        [Edit::new(3, 5, 3)],
+        [Edit::new(1, 499, 3)],
+        [Edit::new(0, 562, 2)],
+        [Edit::new(3, 716, 3)],
+        [Edit::new(0, 15, 1)],
+        [Edit::new(3, 719, 5)],
+        [Edit::new(1, 155, 2)],
+        [Edit::new(5, 724, 7)],
+        [Edit::new(5, 731, 5)],
+        [Edit::new(5, 736, 3)],
+        [Edit::new(4, 739, 6)],
+        [Edit::new(6, 745, 7)],
+        [Edit::new(7, 752, 8)],
+        [Edit::new(6, 760, 7)],
+        [Edit::new(0, 5, 1)],
+        [Edit::new(2, 767, 4)],
+        [Edit::new(4, 771, 5)],
+        [Edit::new(2, 433, 2)],
+        [Edit::new(3, 776, 3)],
+        [Edit::new(3, 4, 2)],
+        [Edit::new(0, 779, 3)],
+        [Edit::new(9, 782, 8)],
+        [Edit::new(1, 38, 2)],
+        [Edit::new(7, 790, 6)],
+        [Edit::new(1, 796, 4)],
+        [Edit::new(0, 3, 3)],
+        [Edit::new(2, 56, 1)],
+        [Edit::new(2, 800, 4)],
+        [Edit::new(1, 233, 2)],
+        [Edit::new(2, 413, 3)],
+        [Edit::new(3, 804, 5)],
+        [Edit::new(7, 809, 7)],
+        [Edit::new(4, 816, 5)],
+        [Edit::new(1, 311, 3)],
+        [Edit::new(2, 499, 3)],
+        [Edit::new(5, 821, 4)],
+        [Edit::new(6, 825, 5)],
+        [Edit::new(3, 779, 3)],
+        [Edit::new(2, 830, 4)],
+        [Edit::new(1, 241, 2)],
+        [Edit::new(0, 253, 3)],
+        [Edit::new(5, 834, 5)],
+        [Edit::new(5, 839, 5)],
+        [Edit::new(2, 844, 4)],
+        [Edit::new(5, 848, 6)],
+        [Edit::new(3, 204, 4)],
+        [Edit::new(2, 563, 4)],
+        [Edit::new(3, 854, 4)],
+        [Edit::new(9, 858, 10)],
+        [Edit::new(3, 868, 5)],
+        [Edit::new(2, 873, 2)],
+        [Edit::new(5, 875, 4)],
+        [Edit::new(3, 879, 4)],
+        [Edit::new(3, 883, 3)],
+        [Edit::new(2, 886, 4)],
+        [Edit::new(4, 890, 3)],
+        [Edit::new(10, 893, 9)],
+        [Edit::new(8, 902, 8)],
+        [Edit::new(9, 267, 7)],
+        [Edit::new(2, 380, 2)],
+        [Edit::new(3, 0, 0)],
+        [Edit::new(2, 910, 3)],
+        [Edit::new(2, 200, 3)],
+        [Edit::new(1, 52, 1)],
+        [Edit::new(4, 913, 5)],
+        [Edit::new(6, 918, 4)],
+        [Edit::new(4, 922, 5)],
+        [Edit::new(4, 759, 4)],
+        [Edit::new(1, 44, 2)],
+        [Edit::new(5, 927, 6)],
+        [Edit::new(1, 12, 2)],
+        [Edit::new(0, 38, 3)],
+        [Edit::new(1, 502, 3)],
+        [Edit::new(5, 933, 5)],
+        [Edit::new(5, 938, 5)],
+        [Edit::new(4, 203, 5)],
+        [Edit::new(3, 943, 3)],
+        [Edit::new(8, 946, 9)],
+        [Edit::new(2, 49, 2)],
+        [Edit::new(8, 955, 10)],
+        [Edit::new(3, 965, 6)],
+        [Edit::new(3, 236, 4)],
+        [Edit::new(4, 351, 5)],
+        [Edit::new(4, 342, 4)],
+        [Edit::new(4, 965, 6)],
+        [Edit::new(2, 125, 2)],
+        [Edit::new(2, 971, 3)],
+        [Edit::new(7, 974, 6)],
+        [Edit::new(1, 236, 4)],
+        [Edit::new(1, 328, 2)],
+        [Edit::new(7, 980, 7)],
+        [Edit::new(3, 987, 4)],
+        [Edit::new(5, 991, 6)],
+        [Edit::new(5, 997, 6)],
+        [Edit::new(3, 844, 4)],
+        [Edit::new(6, 1003, 6)],
+        [Edit::new(3, 1009, 4)],
+        [Edit::new(5, 1013, 4)],
+        [Edit::new(5, 1017, 4)],
+        [Edit::new(2, 124, 3)],
+        [Edit::new(4, 1021, 4)],
+        [Edit::new(0, 1, 1)],
+        [Edit::new(8, 1025, 5)],
+        [Edit::new(9, 1030, 6)],
+        [Edit::new(2, 288, 3)],
+        [Edit::new(4, 1036, 4)],
+        [Edit::new(3, 1040, 3)],
+        [Edit::new(1, 159, 3)],
+        [Edit::new(3, 316, 2)],
+        [Edit::new(1, 54, 2)],
+        [Edit::new(4, 1043, 4)],
+        [Edit::new(7, 1047, 6)],
+        [Edit::new(7, 1053, 7)],
+        [Edit::new(4, 1060, 4)],
+        [Edit::new(2, 1064, 3)],
+        [Edit::new(1, 9, 1)],
+        [Edit::new(2, 223, 3)],
+        [Edit::new(4, 1067, 5)],
+        [Edit::new(5, 1072, 4)],
+        [Edit::new(6, 1076, 7)],
+        [Edit::new(7, 209, 5)],
+        [Edit::new(4, 844, 3)],
+        [Edit::new(4, 251, 3)],
+        [Edit::new(3, 583, 2)],
+        [Edit::new(2, 85, 2)],
+        [Edit::new(4, 1083, 5)],
+        [Edit::new(4, 1088, 4)],
+        [Edit::new(7, 1092, 7)],
+        [Edit::new(5, 1099, 5)],
+        [Edit::new(1, 873, 2)],
+        [Edit::new(1, 560, 2)],
+        [Edit::new(1, 1104, 4)],
+        [Edit::new(3, 18, 2)],
+        [Edit::new(4, 1108, 6)],
+        [Edit::new(2, 414, 2)],
+        [Edit::new(7, 1114, 7)],
+        [Edit::new(7, 1121, 8)],
+        [Edit::new(4, 1129, 4)],
+        [Edit::new(6, 1133, 7)],
+        [Edit::new(2, 34, 2)],
+        [Edit::new(7, 1140, 8)],
+        [Edit::new(3, 1148, 3)],
+        [Edit::new(4, 1151, 3)],
+        [Edit::new(4, 1154, 5)],
+        [Edit::new(4, 1159, 5)],
+        [Edit::new(5, 1164, 4)],
+        [Edit::new(4, 1147, 4)],
+        [Edit::new(1, 118, 2)],
+        [Edit::new(1, 105, 2)],
+        [Edit::new(8, 1168, 8)],
+        [Edit::new(7, 1176, 9)],
+        [Edit::new(6, 1185, 7)],
+        [Edit::new(1, 606, 3)],
+        [Edit::new(1, 306, 3)],
+        [Edit::new(3, 582, 3)],
+        [Edit::new(0, 9, 1)],
+        [Edit::new(1, 564, 3)],
+        [Edit::new(1, 855, 3)],
+        [Edit::new(3, 122, 2)],
+        [Edit::new(1, 47, 2)],
+        [Edit::new(0, 155, 2)],
+        [Edit::new(6, 1192, 6)],
+        [Edit::new(4, 580, 3)],
+        [Edit::new(4, 346, 3)],
+        [Edit::new(0, 67, 2)],
+        [Edit::new(5, 1198, 6)],
+        [Edit::new(5, 709, 7)],
+        [Edit::new(6, 709, 7)],
+        [Edit::new(6, 1204, 4)],
+        [Edit::new(3, 150, 2)],
+        [Edit::new(10, 1208, 10)],
+        [Edit::new(3, 200, 3)],
+        [Edit::new(11, 1218, 11)],
+        [Edit::new(3, 87, 2)],
+        [Edit::new(2, 1068, 4)],
+        [Edit::new(4, 640, 4)],
+        [Edit::new(6, 1229, 8)],
+        [Edit::new(6, 1237, 5)],
+        [Edit::new(11, 1242, 11)],
+        [Edit::new(4, 6, 5)],
+        [Edit::new(6, 1253, 5)],
+        [Edit::new(6, 1258, 5)],
+        [Edit::new(3, 557, 3)],
+        [Edit::new(7, 1263, 7)],
+        [Edit::new(8, 1270, 8)],
+        [Edit::new(5, 1278, 3)],
+        [Edit::new(0, 159, 3)],
+        [Edit::new(5, 1281, 6)],
+        [Edit::new(11, 1287, 8)],
+        [Edit::new(8, 214, 7)],
+        [Edit::new(11, 1295, 14)],
+        [Edit::new(8, 1309, 8)],
+        [Edit::new(2, 249, 2)],
+        [Edit::new(3, 74, 2)],
+        [Edit::new(1, 1317, 3)],
+        [Edit::new(2, 1110, 4)],
+        [Edit::new(2, 1320, 4)],
+        [Edit::new(1, 16, 2)],
+        [Edit::new(5, 1324, 6)],
+        [Edit::new(3, 1010, 3)],
+        [Edit::new(2, 1321, 3)],
+        [Edit::new(0, 87, 2)],
+        [Edit::new(2, 371, 4)],
+        [Edit::new(2, 1149, 4)],
+        [Edit::new(2, 1330, 4)],
+        [Edit::new(5, 1334, 5)],
+        [Edit::new(3, 414, 2)],
+        [Edit::new(1, 1339, 3)],
+        [Edit::new(3, 51, 1)],
+        [Edit::new(2, 875, 3)],
+        [Edit::new(3, 1342, 4)],
+        [Edit::new(5, 1346, 7)],
+        [Edit::new(5, 1353, 7)],
+        [Edit::new(5, 1360, 5)],
+        [Edit::new(1, 1365, 2)],
+        [Edit::new(4, 1367, 4)],
+        [Edit::new(6, 1371, 6)],
+        [Edit::new(2, 4, 2)],
+        [Edit::new(4, 1377, 3)],
+        [Edit::new(3, 1380, 5)],
+        [Edit::new(3, 1385, 4)],
+        [Edit::new(10, 1389, 9)],
+        [Edit::new(9, 1398, 10)],
+        [Edit::new(1, 864, 4)],
+        [Edit::new(3, 1164, 3)],
+        [Edit::new(4, 1164, 3)],
+        [Edit::new(4, 1408, 4)],
+        [Edit::new(2, 1412, 5)],
+        [Edit::new(8, 1417, 9)],
+        [Edit::new(5, 950, 3)],
+        [Edit::new(6, 1426, 4)],
+        [Edit::new(2, 236, 2)],
+        [Edit::new(3, 125, 2)],
+        [Edit::new(1, 25, 2)],
+        [Edit::new(1, 68, 1)],
+        [Edit::new(3, 1430, 4)],
+        [Edit::new(4, 1434, 7)],
+        [Edit::new(3, 477, 2)],
+        [Edit::new(5, 1441, 5)],
+        [Edit::new(3, 69, 2)],
+        [Edit::new(5, 1446, 4)],
+        [Edit::new(6, 1450, 7)],
+        [Edit::new(1, 1457, 5)],
+        [Edit::new(1, 1462, 4)],
+        [Edit::new(1, 1466, 5)],
+        [Edit::new(1, 1471, 6)],
+        [Edit::new(1, 1477, 7)],
+        [Edit::new(1, 1462, 5)],
+        [Edit::new(1, 1484, 6)],
+        [Edit::new(1, 1458, 4)],
+        [Edit::new(0, 18, 2)],
+        [Edit::new(5, 203, 5)],
+        [Edit::new(6, 0, 0)],
+        [Edit::new(3, 1490, 4)],
+        [Edit::new(1, 1494, 3)],
+        [Edit::new(4, 1497, 4)],
+        [Edit::new(2, 905, 3)],
+        [Edit::new(3, 1501, 4)],
+        [Edit::new(5, 1505, 5)],
+        [Edit::new(7, 1510, 8)],
+        [Edit::new(3, 20, 3)],
+        [Edit::new(4, 155, 2)],
+        [Edit::new(3, 104, 2)],
+        [Edit::new(3, 1518, 4)],
+        [Edit::new(8, 1522, 8)],
+        [Edit::new(2, 36, 2)],
+        [Edit::new(7, 1530, 9)],
+        [Edit::new(5, 1539, 5)],
+        [Edit::new(4, 1544, 4)],
+        [Edit::new(6, 1548, 5)],
+        [Edit::new(3, 1551, 2)],
+        [Edit::new(6, 1553, 6)],
+        [Edit::new(3, 575, 2)],
+        [Edit::new(5, 1553, 6)],
+        [Edit::new(4, 1559, 4)],
+        [Edit::new(8, 1563, 10)],
+        [Edit::new(4, 1439, 4)],
+        [Edit::new(1, 67, 2)],
+        [Edit::new(2, 433, 3)],
+        [Edit::new(3, 1573, 4)],
+        [Edit::new(8, 1229, 8)],
+        [Edit::new(6, 1577, 7)],
+        [Edit::new(3, 108, 2)],
+        [Edit::new(2, 1584, 3)],
+        [Edit::new(3, 0, 1)],
+        [Edit::new(2, 1587, 4)],
+        [Edit::new(3, 1060, 4)],
+        [Edit::new(9, 1591, 9)],
+        [Edit::new(2, 118, 2)],
+        [Edit::new(5, 1600, 6)],
+        [Edit::new(5, 1606, 7)],
+        [Edit::new(3, 1613, 4)],
+        [Edit::new(3, 1068, 4)],
+        [Edit::new(7, 1617, 6)],
+        [Edit::new(6, 1623, 7)],
+        [Edit::new(6, 1630, 6)],
+        [Edit::new(6, 1624, 6)],
+        [Edit::new(6, 1631, 5)],
+        [Edit::new(6, 1617, 6)],
+        [Edit::new(6, 1636, 5)],
+        [Edit::new(3, 1189, 4)],
+        [Edit::new(2, 320, 2)],
+        [Edit::new(6, 1641, 6)],
+        [Edit::new(3, 174, 3)],
+        [Edit::new(3, 1453, 4)],
+        [Edit::new(1, 1647, 3)],
+        [Edit::new(9, 1650, 9)],
+        [Edit::new(9, 1659, 10)],
+        [Edit::new(2, 1413, 4)],
+        [Edit::new(2, 1669, 4)],
+        [Edit::new(1, 187, 2)],
+        [Edit::new(1, 1673, 3)],
+        [Edit::new(1, 662, 2)],
+        [Edit::new(9, 1676, 9)],
+        [Edit::new(10, 1685, 9)],
+        [Edit::new(9, 1025, 5)],
+        [Edit::new(3, 14, 1)],
+        [Edit::new(3, 1694, 4)],
+        [Edit::new(4, 1698, 3)],
+        [Edit::new(5, 1701, 5)],
+        [Edit::new(7, 1701, 5)],
+        [Edit::new(5, 1200, 3)],
+        [Edit::new(5, 1706, 4)],
+        [Edit::new(2, 57, 3)],
+        [Edit::new(5, 1710, 6)],
+        [Edit::new(3, 1716, 3)],
+        [Edit::new(3, 1719, 4)],
+        [Edit::new(3, 1723, 4)],
+        [Edit::new(3, 1727, 4)],
+        [Edit::new(3, 1731, 5)],
+        [Edit::new(6, 1736, 6)],
+        [Edit::new(4, 28, 2)],
+        [Edit::new(4, 0, 1)],
+        [Edit::new(5, 1742, 6)],
+        [Edit::new(3, 44, 3)],
+        [Edit::new(3, 43, 2)],
+        [Edit::new(5, 1748, 5)],
+        [Edit::new(3, 1753, 5)],
+        [Edit::new(3, 1412, 5)],
+        [Edit::new(3, 1758, 5)],
+        [Edit::new(3, 155, 2)],
+    ],
+    "sjesimessrsbienyyaaedesopleicesvesittalarennsestesaukunaxzessfulodythynnodciymydauiroudosotgunqaregaurareannaiteseetheahzabeauxamabhacaamagqirhaluimamatongoieseenalabibajzaeytimtenourvsljjakhirwagangareswalimuwazeewazunguutvimbundurierainesikenstysfekotadaabakhwethaabazunguamazulufurtheratamxesuhhadchinawochuaothosiionniobidesiasinegoesntesatimtsyiesfulsmotssfullethrenanimattlekinesdesacaneddiongesuroossunttiaeisyrbipkwelevenenneoraedolimasasdarmesqepikshayimittenittimurotemswomenahwaaloodahwaldenzimbizoamakhosionemzangomazibongozicocodikgotlabibanjaorimovilachlochcamaokeketsikymakungwiamakwerekwerekesoisemalangenicymalwapaesdemoisellesenfriendsazilagidajidaqishanatiqdimamsarekoroephatobasarwabasothoesdamestinwamisephilimwasibaraashothdingakaminkishiminkisicteschaotitalenamanheneirambathesodesiskeihoracesdarkausulirtaligesartalbassaarimuyookhlechsuvbitchesnardseheesealileefyothjilabakwethaabelungumusawkafelshawqafemirotulamaulemamehimazighenimenukalenipitestotisantiepancheonesadarimadorimhertumukimosesolaeurusnounsanaglauluspoleeteeetfuleetpawsipesedaeonimnagushommesudoodududahadithuffazhangalakimimbongizimbonginturzindunazinyangawaninninnahinnanwariechresesdikgosanadikgosimakutamalotiarisenservantsresfamiliasessieursbaloiesseigneurserafearenaetshelobatswanaichasahiftawashihendometritidesanasheedonsaqimritzimoninwafidyatesoneraphimlisenteunturivatinkotzimeepashimiciacingalesesovbitchesasiraisimmatambalauruqeresertlachimminymynemirothsunuzumuzumuzaumuzdaumuzdanumuzunomimimsasawrrenricessmartialawatahadeethovahererouffartsivakhlyvesfulalisenchildrenagimilmoransbytinificationsidarimiddarimadimluchimelichimelihimluhimashrafekhsabitchessofbitchesysimjeveethmarksfautricesefimuitwarijlotheipsesiotiothiyosiyotiyothshiothillionurafaeisimeysim",
+);
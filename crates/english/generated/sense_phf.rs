@@ -0,0 +1,23 @@
+// Hand-written sample labels, to be replaced by the output of
+// `cargo xtask refresh-data`, which labels every sense key with a gloss.
+use phf::phf_map;
+
+/// sense key => label naming it after a `#`
+pub static NOUN_SENSE_LABEL_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "die" => "device",
+    "die2" => "polyhedron",
+    "fish2" => "species",
+    "index2" => "mathematics",
+    "octopus" => "marine",
+    "octopus2" => "hypercorrect",
+    "penny2" => "uk",
+    "person" => "formal",
+};
+
+/// sense key => label naming it after a `#`
+pub static VERB_SENSE_LABEL_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "lie" => "rest",
+    "lie2" => "false",
+    "shine" => "polish",
+    "shine2" => "emit",
+};
@@ -0,0 +1,23 @@
+// Hand-written sample notes, to be replaced by the output of
+// `cargo xtask refresh-data`, which notes every sense key.
+use phf::phf_map;
+
+/// sense key => usage tags or gloss
+pub static NOUN_NOTE_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "die" => "A device for cutting or shaping material, such as a stamp or mould.",
+    "die2" => "A polyhedron, usually a cube, with numbers or symbols on each side, used in games of chance.",
+    "fish2" => "of several species",
+    "index2" => "mathematics technical",
+    "octopus" => "Any of the marine molluscs of the order Octopoda, with eight arms.",
+    "octopus2" => "hypercorrect",
+    "penny2" => "UK",
+    "person" => "formal",
+};
+
+/// sense key => usage tags or gloss
+pub static VERB_NOTE_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "lie" => "To rest in a horizontal position on a surface.",
+    "lie2" => "To give false information intentionally.",
+    "shine" => "To polish; to make something shine.",
+    "shine2" => "To emit light.",
+};
@@ -1,4262 +1,4258 @@
 use phf::phf_map;
 
 /// (3rd person singular, past, present participle, past participle)
-pub static VERB_MAP: phf::Map<&'static str, (&'static str, &'static str, &'static str, &'static str)> = phf_map! {
-    "ab" => ("abs", "abbed", "abbing", "abbed"),
-    "abear" => ("abears", "abore", "abearing", "aborn"),
-    "abegge" => ("abegges", "abought", "abegging", "abought"),
-    "abet" => ("abets", "abetted", "abetting", "abetted"),
-    "abey" => ("abeys", "abeyed", "abeying", "abeyed"),
-    "abhor" => ("abhors", "abhorred", "abhorring", "abhorred"),
-    "abide" => ("abides", "abode", "abiding", "abode"),
-    "abite" => ("abites", "abit", "abiting", "abitten"),
-    "abray" => ("abrays", "abrayed", "abraying", "abrayed"),
-    "absorp" => ("absorps", "absorped", "absorping", "absorped"),
-    "abut" => ("abuts", "abutted", "abutting", "abutted"),
-    "accloy" => ("accloys", "accloyed", "accloying", "accloyed"),
-    "accoy" => ("accoys", "accoyed", "accoying", "accoyed"),
-    "acknow" => ("acknows", "acknew", "acknowing", "acknown"),
-    "acknowledg" => ("acknowledges", "acknowledged", "acknowledging", "acknowledged"),
-    "acoy" => ("acoys", "acoyed", "acoying", "acoyed"),
-    "acquit" => ("acquits", "acquitted", "acquitting", "acquitted"),
-    "adfreeze" => ("adfreezes", "adfroze", "adfreezing", "adfrozen"),
-    "adjudg" => ("adjudges", "adjudged", "adjudging", "adjudged"),
-    "adlib" => ("adlibs", "adlibbed", "adlibbing", "adlibbed"),
-    "admit" => ("admits", "admitted", "admitting", "admitted"),
-    "adraw" => ("adraws", "adrew", "adrawing", "adrawn"),
-    "adree" => ("adrees", "adreed", "adreeing", "adreed"),
-    "adsorp" => ("adsorps", "adsorped", "adsorping", "adsorped"),
-    "affray" => ("affrays", "affrayed", "affraying", "affrayed"),
-    "aftcast" => ("aftcasts", "aftcast", "aftcasting", "aftcast"),
-    "aftersee" => ("aftersees", "aftersaw", "afterseeing", "afterseen"),
-    "afterthink" => ("afterthinks", "afterthought", "afterthinking", "afterthought"),
-    "againsay" => ("againsays", "againsaid", "againsaying", "againsaid"),
-    "againstand" => ("againstands", "againstood", "againstanding", "againstood"),
-    "agg" => ("aggs", "agged", "agging", "agged"),
-    "aggroup" => ("aggroups", "aggrouped", "aggrouping", "aggrouped"),
-    "agree" => ("agrees", "agreed", "agreeing", "agreed"),
-    "ahem" => ("ahems", "ahemmed", "ahemming", "ahemmed"),
-    "ahoy" => ("ahoys", "ahoyed", "ahoying", "ahoyed"),
-    "aimbot" => ("aimbots", "aimbotted", "aimbotting", "aimbotted"),
-    "airstrike" => ("airstrikes", "airstruck", "airstriking", "airstruck"),
-    "ajar" => ("ajars", "ajarred", "ajarring", "ajarred"),
-    "aken" => ("akens", "akenned", "akenning", "akenned"),
-    "akin" => ("akins", "akinned", "akinning", "akinned"),
-    "aknow" => ("aknows", "aknew", "aknowing", "aknown"),
-    "aks" => ("aks", "aksed", "aksing", "aksed"),
-    "alay" => ("alays", "alayed", "alaying", "alayed"),
-    "alight2" => ("alights", "alit", "alighting", "alit"),
-    "allay" => ("allays", "allayed", "allaying", "allayed"),
-    "alledg" => ("alledges", "alledged", "alledging", "alledged"),
-    "alleycat" => ("alleycats", "alleycatted", "alleycatting", "alleycatted"),
-    "alleyoop" => ("alleyoops", "alleyooped", "alleyooping", "alleyooped"),
-    "allot" => ("allots", "allotted", "allotting", "allotted"),
-    "alloy" => ("alloys", "alloyed", "alloying", "alloyed"),
-    "amay" => ("amays", "amayed", "amaying", "amayed"),
-    "amit" => ("amits", "amitted", "amitting", "amitted"),
-    "amp" => ("amps", "amped", "amping", "amped"),
-    "amscray" => ("amscrays", "amscrayed", "amscraying", "amscrayed"),
-    "anagram" => ("anagrams", "anagrammed", "anagramming", "anagrammed"),
-    "annoy" => ("annoys", "annoyed", "annoying", "annoyed"),
-    "annul" => ("annuls", "annulled", "annulling", "annulled"),
-    "ante" => ("antes", "anted", "anteing", "anted"),
-    "antic" => ("antics", "anticked", "anticking", "anticked"),
-    "apay" => ("apays", "apayed", "apaying", "apayed"),
-    "appal" => ("appals", "appalled", "appalling", "appalled"),
-    "appay" => ("appays", "appayed", "appaying", "appayed"),
-    "appel" => ("appels", "appelled", "appelling", "appelled"),
-    "applique" => ("appliques", "appliqued", "appliqueing", "appliqued"),
-    "applot" => ("applots", "applotted", "applotting", "applotted"),
-    "appuy" => ("appuys", "appuyed", "appuying", "appuyed"),
-    "aread" => ("areads", "ared", "areading", "ared"),
-    "arede" => ("aredes", "aredd", "areding", "aredd"),
-    "areed" => ("areeds", "ared", "areeds", "ared"),
-    "aret" => ("arets", "aretted", "aretting", "aretted"),
-    "arise" => ("arises", "arose", "arising", "arisen"),
-    "arize" => ("arizes", "aroze", "arizing", "arizen"),
-    "armbar" => ("armbars", "armbarred", "armbarring", "armbarred"),
-    "array" => ("arrays", "arrayed", "arraying", "arrayed"),
-    "arread" => ("arreads", "arread", "arreading", "arread"),
-    "arreede" => ("arreedes", "arred", "arreeding", "arred"),
-    "ashcan" => ("ashcans", "ashcanned", "ashcanning", "ashcanned"),
-    "ashtray" => ("ashtrays", "ashtrayed", "ashtraying", "ashtrayed"),
-    "assagay" => ("assagays", "assagayed", "assagaying", "assagayed"),
-    "assay" => ("assays", "assayed", "assaying", "assayed"),
-    "assoilzie" => ("assoilzies", "assoilzied", "assoilzieing", "assoilzied"),
-    "assrun" => ("assruns", "assran", "assrunning", "assrun"),
-    "astand" => ("astands", "astood", "astanding", "astood"),
-    "astun" => ("astuns", "astunned", "astunning", "astunned"),
-    "at" => ("ats", "atted", "atting", "atted"),
-    "atgo" => ("atgoes", "atwent", "atgoing", "atgone"),
-    "atray" => ("atrays", "atrayed", "atraying", "atrayed"),
-    "atrede" => ("atredes", "atred", "atreding", "atred"),
-    "atren" => ("atrens", "atran", "atrenning", "atrun"),
-    "atride" => ("atrides", "atrode", "atriding", "atridden"),
-    "atrin" => ("atrins", "atran", "atrinning", "atrun"),
-    "atsake" => ("atsakes", "atsook", "atsaking", "atsaken"),
-    "atshake" => ("atshakes", "atshook", "atshaking", "atshaken"),
-    "atshoot" => ("atshoots", "atshot", "atshooting", "atshot"),
-    "atsit" => ("atsits", "atsat", "atsitting", "atsat"),
-    "atspring" => ("atsprings", "atsprang", "atspringing", "atsprung"),
-    "atstand" => ("atstands", "atstood", "atstanding", "atstood"),
-    "atstut" => ("atstuts", "atstutted", "atstutting", "atstutted"),
-    "attorney" => ("attorneys", "attorneyed", "attorneying", "attorneyed"),
-    "atwind" => ("atwinds", "atwound", "atwinding", "atwound"),
-    "auntie" => ("aunties", "auntied", "auntying", "auntied"),
-    "autoban" => ("autobans", "autobanned", "autobanning", "autobanned"),
-    "autocamp" => ("autocamps", "autocamped", "autocamping", "autocamped"),
-    "autocommit" => ("autocommits", "autocommitted", "autocommitting", "autocommitted"),
-    "autofit" => ("autofits", "autofitted", "autofitting", "autofitted"),
-    "autoformat" => ("autoformats", "autoformatted", "autoformatting", "autoformatted"),
-    "autohide" => ("autohides", "autohid", "autohiding", "autohidden"),
-    "autopen" => ("autopens", "autopenned", "autopenning", "autopenned"),
-    "autoplay" => ("autoplays", "autoplayed", "autoplaying", "autoplayed"),
-    "autorun" => ("autoruns", "autoran", "autorunning", "autorun"),
-    "autowind" => ("autowinds", "autowound", "autowinding", "autowound"),
-    "avel" => ("avels", "avelled", "avelling", "avelled"),
-    "aver" => ("avers", "averred", "averring", "averred"),
-    "awake" => ("awakes", "awoke", "awaking", "awoken"),
-    "away" => ("aways", "awayed", "awaying", "awayed"),
-    "awestrike" => ("awestrikes", "awestruck", "awestriking", "awestruck"),
-    "awreak" => ("awreaks", "awroke", "awreaking", "awroke"),
-    "aye" => ("ayes", "ayed", "ayeing", "ayed"),
-    "bab" => ("babs", "babbed", "babbing", "babbed"),
-    "babysit" => ("babysits", "babysat", "babysitting", "babysat"),
-    "babywear" => ("babywears", "babywore", "babywearing", "babyworn"),
-    "backbite" => ("backbites", "backbit", "backbiting", "backbitten"),
-    "backcast" => ("backcasts", "backcast", "backcasting", "backcast"),
-    "backchat" => ("backchats", "backchatted", "backchatting", "backchatted"),
-    "backfit" => ("backfits", "backfitted", "backfitting", "backfitted"),
-    "backgrind" => ("backgrinds", "background", "backgrinding", "background"),
-    "backhoe" => ("backhoes", "backhoed", "backhoeing", "backhoed"),
-    "backpat" => ("backpats", "backpatted", "backpatting", "backpatted"),
-    "backread" => ("backreads", "backread", "backreading", "backread"),
-    "backride" => ("backrides", "backrode", "backriding", "backridden"),
-    "backscan" => ("backscans", "backscanned", "backscanning", "backscanned"),
-    "backsell" => ("backsells", "backsold", "backselling", "backsold"),
-    "backset" => ("backsets", "backset", "backsetting", "backset"),
-    "backshine" => ("backshines", "backshone", "backshining", "backshone"),
-    "backshoot" => ("backshoots", "backshot", "backshooting", "backshot"),
-    "backshot" => ("backshots", "backshotted", "backshotting", "backshotted"),
-    "backslide" => ("backslides", "backslid", "backsliding", "backslidden"),
-    "backspin" => ("backspins", "backspun", "backspinning", "backspun"),
-    "backstab" => ("backstabs", "backstabbed", "backstabbing", "backstabbed"),
-    "backstamp" => ("backstamps", "backstamped", "backstamping", "backstamped"),
-    "backswing" => ("backswings", "backswung", "backswinging", "backswung"),
-    "backtab" => ("backtabs", "backtabbed", "backtabbing", "backtabbed"),
-    "backtransfer" => ("backtransfers", "backtransferred", "backtransferring", "backtransferred"),
-    "backwind2" => ("backwinds", "backwound", "backwinding", "backwound"),
-    "bad" => ("bads", "badded", "badding", "badded"),
-    "bae" => ("baes", "baed", "baeing", "baed"),
-    "bafflegab" => ("bafflegabs", "bafflegabbed", "bafflegabbing", "bafflegabbed"),
-    "bam" => ("bams", "bammed", "bamming", "bammed"),
-    "ban" => ("bans", "banned", "banning", "banned"),
-    "bandsaw" => ("bandsaws", "bandsawed", "bandsawing", "bandsawn"),
-    "bar" => ("bars", "barred", "barring", "barred"),
-    "barkeep" => ("barkeeps", "barkeeped", "barkeeping", "barkeeped"),
-    "barney" => ("barneys", "barneyed", "barneying", "barneyed"),
-    "barricado" => ("barricadoes", "barricadoed", "barricadoing", "barricadoed"),
-    "bastinado" => ("bastinadoes", "bastinadoed", "bastinadoing", "bastinadoed"),
-    "bat" => ("bats", "batted", "batting", "batted"),
-    "batman" => ("batmans", "batmanned", "batmanning", "batmanned"),
-    "battel2" => ("battels", "battelled", "battelling", "battelled"),
-    "bauf" => ("baufs", "bauffed", "bauffing", "bauffed"),
-    "bay" => ("bays", "bayed", "baying", "bayed"),
-    "bear2" => ("bears", "bore", "bearing", "borne"),
-    "beat" => ("beats", "beat", "beating", "beaten"),
-    "bebar" => ("bebars", "bebarred", "bebarring", "bebarred"),
-    "bebay" => ("bebays", "bebayed", "bebaying", "bebayed"),
-    "bebleed" => ("bebleeds", "bebled", "bebleeding", "bebled"),
-    "beblot" => ("beblots", "beblotted", "beblotting", "beblotted"),
-    "beblow" => ("beblows", "beblew", "beblowing", "beblown"),
-    "beblur" => ("beblurs", "beblurred", "beblurring", "beblurred"),
-    "bebreak" => ("bebreaks", "bebroke", "bebreaking", "bebroken"),
-    "bebump" => ("bebumps", "bebumped", "bebumping", "bebumped"),
-    "becast" => ("becasts", "becast", "becasting", "becast"),
-    "becatch" => ("becatches", "becaught", "becatching", "becaught"),
-    "bechat" => ("bechats", "bechatted", "bechatting", "bechatted"),
-    "bechide" => ("bechides", "bechid", "bechiding", "bechid"),
-    "beclam" => ("beclams", "beclammed", "beclamming", "beclammed"),
-    "beclasp" => ("beclasps", "beclasped", "beclasping", "beclasped"),
-    "beclepe" => ("beclepes", "beclept", "becleping", "beclept"),
-    "become" => ("becomes", "became", "becoming", "become"),
-    "becreep" => ("becreeps", "becrept", "becreeping", "becrept"),
-    "becut" => ("becuts", "becut", "becutting", "becut"),
-    "bed" => ("beds", "bedded", "bedding", "bedded"),
-    "bedamp" => ("bedamps", "bedamped", "bedamping", "bedamped"),
-    "bede" => ("bedes", "bade", "beding", "bode"),
-    "bedeal" => ("bedeals", "bedealt", "bedealing", "bedealt"),
-    "bedight" => ("bedights", "bedight", "bedighting", "bedight"),
-    "bedim" => ("bedims", "bedimmed", "bedimming", "bedimmed"),
-    "bedo" => ("bedoes", "bedid", "bedoing", "bedone"),
-    "bedraw" => ("bedraws", "bedrew", "bedrawing", "bedrawn"),
-    "bedream" => ("bedreams", "bedreamt", "bedreaming", "bedreamt"),
-    "bedrink" => ("bedrinks", "bedrank", "bedrinking", "bedrunk"),
-    "bedrite" => ("bedrites", "bedrote", "bedriting", "bedritten"),
-    "bedrive" => ("bedrives", "bedrove", "bedriving", "bedriven"),
-    "bedrum" => ("bedrums", "bedrummed", "bedrumming", "bedrummed"),
-    "bedwell" => ("bedwells", "bedwelt", "bedwelling", "bedwelt"),
-    "bedwet" => ("bedwets", "bedwet", "bedwetting", "bedwet"),
-    "bedye" => ("bedyes", "bedyed", "bedyeing", "bedyed"),
-    "beep" => ("beeps", "beeped", "beeping", "beeped"),
-    "befal" => ("befals", "befel", "befaling", "befalen"),
-    "befall" => ("befalls", "befell", "befalling", "befallen"),
-    "befie" => ("befies", "befied", "befying", "befied"),
-    "befind" => ("befinds", "befound", "befinding", "befound"),
-    "befit" => ("befits", "befitted", "befitting", "befitted"),
-    "beflay" => ("beflays", "beflayed", "beflaying", "beflayed"),
-    "beflee" => ("beflees", "befled", "befleeing", "befled"),
-    "befly" => ("beflies", "beflew", "beflying", "beflown"),
-    "befoe" => ("befoes", "befoed", "befoeing", "befoed"),
-    "befree" => ("befrees", "befreed", "befreeing", "befreed"),
-    "befreeze" => ("befreezes", "befroze", "befreezing", "befrozen"),
-    "befret" => ("befrets", "befretted", "befretting", "befretted"),
-    "befur" => ("befurs", "befurred", "befurring", "befurred"),
-    "begab" => ("begabs", "begabbed", "begabbing", "begabbed"),
-    "begat" => ("begats", "begatted", "begatting", "begatted"),
-    "begay" => ("begays", "begayed", "begaying", "begayed"),
-    "begem" => ("begems", "begemmed", "begemming", "begemmed"),
-    "beget" => ("begets", "begot", "begetting", "begotten"),
-    "begin" => ("begins", "began", "beginning", "begun"),
-    "begird" => ("begirds", "begirt", "begirding", "begirt"),
-    "begive" => ("begives", "begave", "begiving", "begiven"),
-    "bego" => ("begoes", "bewent", "begoing", "begone"),
-    "begod" => ("begods", "begodded", "begodding", "begodded"),
-    "begrasp" => ("begrasps", "begrasped", "begrasping", "begrasped"),
-    "begum" => ("begums", "begummed", "begumming", "begummed"),
-    "behang" => ("behangs", "behung", "behanging", "behung"),
-    "behat" => ("behats", "behatted", "behatting", "behatted"),
-    "behear" => ("behears", "beheard", "behearing", "beheard"),
-    "behelp" => ("behelps", "behelped", "behelping", "behelped"),
-    "behew" => ("behews", "behewed", "behewing", "behewn"),
-    "behight" => ("behights", "behight", "behighting", "behight"),
-    "behold" => ("beholds", "beheld", "beholding", "beheld"),
-    "beholde" => ("beholdes", "behelde", "beholding", "behelde"),
-    "behoney" => ("behoneys", "behoneyed", "behoneying", "behoneyed"),
-    "beken" => ("bekens", "bekenned", "bekenning", "bekenned"),
-    "beknit" => ("beknits", "beknit", "beknitting", "beknit"),
-    "beknow" => ("beknows", "beknew", "beknowing", "beknown"),
-    "belam" => ("belams", "belammed", "belamming", "belammed"),
-    "belay" => ("belays", "belayed", "belaying", "belayed"),
-    "belead" => ("beleads", "beled", "beleading", "beled"),
-    "beleap" => ("beleaps", "beleapt", "beleaping", "beleapt"),
-    "beleave" => ("beleaves", "beleft", "beleaving", "beleft"),
-    "belie" => ("belies", "belay", "belying", "belain"),
-    "belie2" => ("belies", "belied", "belying", "belied"),
-    "belive" => ("belives", "belove", "beliving", "beliven"),
-    "bemad" => ("bemads", "bemadded", "bemadding", "bemadded"),
-    "beman" => ("bemans", "bemanned", "bemanning", "bemanned"),
-    "bemar" => ("bemars", "bemarred", "bemarring", "bemarred"),
-    "bemean2" => ("bemeans", "bemeant", "bemeaning", "bemeant"),
-    "bemeet" => ("bemeets", "bemet", "bemeeting", "bemet"),
-    "bemud" => ("bemuds", "bemudded", "bemudding", "bemudded"),
-    "bend" => ("bends", "bent", "bending", "bent"),
-    "benday" => ("bendays", "bendayed", "bendaying", "bendayed"),
-    "benegro" => ("benegroes", "benegroed", "benegroing", "benegroed"),
-    "benet" => ("benets", "benetted", "benetting", "benetted"),
-    "benim" => ("benims", "benam", "benimming", "benomen"),
-    "bepat" => ("bepats", "bepatted", "bepatting", "bepatted"),
-    "beray" => ("berays", "berayed", "beraying", "berayed"),
-    "beread" => ("bereads", "beread", "bereading", "beread"),
-    "berede" => ("beredes", "bered", "bereding", "bered"),
-    "berend" => ("berends", "berent", "berending", "berent"),
-    "beride" => ("berides", "berode", "beriding", "beridden"),
-    "berisp" => ("berisps", "berisped", "berisping", "berisped"),
-    "berob" => ("berobs", "berobbed", "berobbing", "berobbed"),
-    "besay" => ("besays", "besaid", "besaying", "besaid"),
-    "bescrub" => ("bescrubs", "bescrubbed", "bescrubbing", "bescrubbed"),
-    "besee" => ("besees", "besaw", "beseeing", "beseen"),
-    "beseek" => ("beseeks", "besought", "beseeking", "besought"),
-    "beseeke" => ("beseekes", "besoughte", "beseeking", "besoughte"),
-    "besend" => ("besends", "besent", "besending", "besent"),
-    "beset" => ("besets", "beset", "besetting", "beset"),
-    "besew" => ("besews", "besewed", "besewing", "besewn"),
-    "beshake" => ("beshakes", "beshook", "beshaking", "beshaken"),
-    "besharp" => ("besharps", "besharped", "besharping", "besharped"),
-    "beshear" => ("beshears", "beshore", "beshearing", "beshorn"),
-    "beshine" => ("beshines", "beshone", "beshining", "beshone"),
-    "beshit" => ("beshits", "beshit", "beshitting", "beshit"),
-    "beshite" => ("beshites", "beshit", "beshiting", "beshitten"),
-    "beshoe" => ("beshoes", "beshoed", "beshoeing", "beshoed"),
-    "beshut" => ("beshuts", "beshut", "beshutting", "beshut"),
-    "besing" => ("besings", "besang", "besinging", "besung"),
-    "besinge" => ("besinges", "besinged", "besingeing", "besinged"),
-    "besit" => ("besits", "besat", "besitting", "besat"),
-    "besleep" => ("besleeps", "beslept", "besleeping", "beslept"),
-    "besmut" => ("besmuts", "besmutted", "besmutting", "besmutted"),
-    "besot" => ("besots", "besotted", "besotting", "besotted"),
-    "besow" => ("besows", "besowed", "besowing", "besown"),
-    "bespeak" => ("bespeaks", "bespoke", "bespeaking", "bespoken"),
-    "bespend" => ("bespends", "bespent", "bespending", "bespent"),
-    "bespin" => ("bespins", "bespun", "bespinning", "bespun"),
-    "bespit" => ("bespits", "bespat", "bespitting", "bespitten"),
-    "bespot" => ("bespots", "bespotted", "bespotting", "bespotted"),
-    "bespray" => ("besprays", "besprayed", "bespraying", "besprayed"),
-    "bespread" => ("bespreads", "bespread", "bespreading", "bespread"),
-    "besprenge" => ("besprenges", "besprent", "besprenging", "besprent"),
-    "bestand" => ("bestands", "bestood", "bestanding", "bestood"),
-    "bestar" => ("bestars", "bestarred", "bestarring", "bestarred"),
-    "bestay" => ("bestays", "bestayed", "bestaying", "bestayed"),
-    "bestead2" => ("besteads", "besteaded", "besteading", "bestead"),
-    "besteal" => ("besteals", "bestole", "bestealing", "bestolen"),
-    "bestem" => ("bestems", "bestemmed", "bestemming", "bestemmed"),
-    "bestick" => ("besticks", "bestuck", "besticking", "bestuck"),
-    "bestir" => ("bestirs", "bestirred", "bestirring", "bestirred"),
-    "bestrew" => ("bestrews", "bestrewed", "bestrewing", "bestrewn"),
-    "bestride" => ("bestrides", "bestrode", "bestriding", "bestrode"),
-    "bestrow" => ("bestrows", "bestrowed", "bestrowing", "bestrown"),
-    "bestrut" => ("bestruts", "bestrutted", "bestrutting", "bestrutted"),
-    "bestud" => ("bestuds", "bestudded", "bestudding", "bestudded"),
-    "beswear" => ("beswears", "beswore", "beswearing", "besworn"),
-    "beswinge" => ("beswinges", "beswinged", "beswingeing", "beswinged"),
-    "beswink" => ("beswinks", "beswank", "beswinking", "beswunk"),
-    "bet" => ("bets", "bet", "betting", "bet"),
-    "betake" => ("betakes", "betook", "betaking", "betaken"),
-    "beteach" => ("beteaches", "betaught", "beteaching", "betaught"),
-    "betear2" => ("betears", "betore", "betearing", "betorn"),
-    "betell" => ("betells", "betold", "betelling", "betold"),
-    "bethink" => ("bethinks", "bethought", "bethinking", "bethought"),
-    "bethrow" => ("bethrows", "bethrew", "bethrowing", "bethrown"),
-    "bethump" => ("bethumps", "bethumped", "bethumping", "bethumped"),
-    "betide" => ("betides", "betid", "betiding", "betid"),
-    "betie" => ("beties", "betied", "betying", "betied"),
-    "betray" => ("betrays", "betrayed", "betraying", "betrayed"),
-    "betread" => ("betreads", "betrod", "betreading", "betrodden"),
-    "betrim" => ("betrims", "betrimmed", "betrimming", "betrimmed"),
-    "betrump" => ("betrumps", "betrumped", "betrumping", "betrumped"),
-    "betwynde" => ("betwyndes", "betwound", "betwynding", "betwound"),
-    "bewed" => ("beweds", "bewed", "bewedding", "bewed"),
-    "beweep" => ("beweeps", "bewept", "beweeping", "bewept"),
-    "bewend" => ("bewends", "bewent", "bewending", "bewent"),
-    "bewet" => ("bewets", "bewet", "bewetting", "bewet"),
-    "bewin" => ("bewins", "bewon", "bewinning", "bewon"),
-    "bewind" => ("bewinds", "bewound", "bewinding", "bewound"),
-    "bewit" => ("bewits", "bewitted", "bewitting", "bewitted"),
-    "bewray" => ("bewrays", "bewrayed", "bewraying", "bewrayed"),
-    "bewrite" => ("bewrites", "bewrote", "bewriting", "bewritten"),
-    "beyelp" => ("beyelps", "beyelped", "beyelping", "beyelped"),
-    "bib" => ("bibs", "bibbed", "bibbing", "bibbed"),
-    "bid" => ("bids", "bid", "bidding", "bid"),
-    "bide" => ("bides", "bode", "biding", "bided"),
-    "bigg" => ("biggs", "bigged", "bigging", "bigged"),
-    "bikeshed" => ("bikesheds", "bikeshedded", "bikeshedding", "bikeshedded"),
-    "bilerp" => ("bilerps", "bilerped", "bilerping", "bilerped"),
-    "bin" => ("bins", "binned", "binning", "binned"),
-    "bind" => ("binds", "bound", "binding", "bound"),
-    "binge" => ("binges", "binged", "bingeing", "binged"),
-    "bioassay" => ("bioassays", "bioassayed", "bioassaying", "bioassayed"),
-    "biocontrol" => ("biocontrols", "biocontrolled", "biocontrolling", "biocontrolled"),
-    "biodecay" => ("biodecays", "biodecayed", "biodecaying", "biodecayed"),
-    "biospin" => ("biospins", "biospun", "biospinning", "biospun"),
-    "birdie" => ("birdies", "birdied", "birdieing", "birdied"),
-    "birdiesit" => ("birdiesits", "birdiesat", "birdiesitting", "birdiesat"),
-    "birdsit" => ("birdsits", "birdsat", "birdsitting", "birdsat"),
-    "birdysit" => ("birdysits", "birdysat", "birdysitting", "birdysat"),
-    "birg" => ("birgs", "birged", "birging", "birged"),
-    "birthday" => ("birthdays", "birthdayed", "birthdaying", "birthdayed"),
-    "bishop" => ("bishops", "bishoped", "bishoping", "bishoped"),
-    "bit" => ("bits", "bitted", "bitting", "bitted"),
-    "bite" => ("bites", "bit", "biting", "bitten"),
-    "bitrot" => ("bitrots", "bitrotted", "bitrotting", "bitrotted"),
-    "bivouac" => ("bivouacs", "bivouacked", "bivouacing", "bivouacked"),
-    "blab" => ("blabs", "blabbed", "blabbing", "blabbed"),
-    "blam" => ("blams", "blammed", "blamming", "blammed"),
-    "blarney" => ("blarneys", "blarneyed", "blarneying", "blarneyed"),
-    "blat" => ("blats", "blatted", "blatting", "blatted"),
-    "blawg" => ("blawgs", "blawged", "blawging", "blawged"),
-    "bleb" => ("blebs", "blebbed", "blebbing", "blebbed"),
-    "bleed" => ("bleeds", "bled", "bleeding", "bled"),
-    "bleep" => ("bleeps", "bleeped", "bleeping", "bleeped"),
-    "blem" => ("blems", "blemmed", "blemming", "blemmed"),
-    "bless" => ("blesses", "blest", "blessing", "blest"),
-    "blet" => ("blets", "bletted", "bletting", "bletted"),
-    "blevey" => ("bleveys", "bleveyed", "bleveying", "bleveyed"),
-    "blimp" => ("blimps", "blimped", "blimping", "blimped"),
-    "blin" => ("blins", "blinned", "blinning", "blinned"),
-    "blit" => ("blits", "blitted", "blitting", "blitted"),
-    "blive" => ("blives", "blove", "bliving", "bliven"),
-    "blob" => ("blobs", "blobbed", "blobbing", "blobbed"),
-    "bloodfeed" => ("bloodfeeds", "bloodfed", "bloodfeeding", "bloodfed"),
-    "bloodlet" => ("bloodlets", "bloodlet", "bloodletting", "bloodlet"),
-    "bloop" => ("bloops", "blooped", "blooping", "blooped"),
-    "blorp" => ("blorps", "blorped", "blorping", "blorped"),
-    "blot" => ("blots", "blotted", "blotting", "blotted"),
-    "blow" => ("blows", "blew", "blowing", "blown"),
-    "blowe" => ("blowes", "blewe", "blowing", "blowne"),
-    "blox" => ("bloxxes", "bloxxed", "bloxxing", "bloxxed"),
-    "blub" => ("blubs", "blubbed", "blubbing", "blubbed"),
-    "blue" => ("blues", "blued", "blueing", "blued"),
-    "blur" => ("blurs", "blurred", "blurring", "blurred"),
-    "bmx" => ("bmxs", "bmxed", "bmxing", "bmxed"),
-    "bob" => ("bobs", "bobbed", "bobbing", "bobbed"),
-    "bobsled" => ("bobsleds", "bobsledded", "bobsledding", "bobsledded"),
-    "bodybuild" => ("bodybuilds", "bodybuilt", "bodybuilding", "bodybuilt"),
-    "bodyslam" => ("bodyslams", "bodyslammed", "bodyslamming", "bodyslammed"),
-    "bogan" => ("bogans", "boganned", "boganning", "boganned"),
-    "bogey" => ("bogeys", "bogeyed", "bogeying", "bogeyed"),
-    "bok" => ("boks", "bokked", "bokking", "bokked"),
-    "boobfeed" => ("boobfeeds", "boobfed", "boobfeeding", "boobfed"),
-    "boog" => ("boogs", "booged", "booging", "booged"),
-    "boogey" => ("boogeys", "boogeyed", "boogeying", "boogeyed"),
-    "boogie" => ("boogies", "boogied", "boogying", "boogied"),
-    "bookkeep" => ("bookkeeps", "bookkept", "bookkeeping", "bookkept"),
-    "boop" => ("boops", "booped", "booping", "booped"),
-    "bopeep" => ("bopeeps", "bopeeped", "bopeeping", "bopeeped"),
-    "borg" => ("borgs", "borged", "borging", "borged"),
-    "born" => ("borns", "bornt", "bornin", "bornt"),
-    "bot" => ("bots", "botted", "botting", "botted"),
-    "bowcat" => ("bowcats", "bowcatted", "bowcatting", "bowcatted"),
-    "bowstring" => ("bowstrings", "bowstrung", "bowstringing", "bowstrung"),
-    "boxcar" => ("boxcars", "boxcarred", "boxcarring", "boxcarred"),
-    "boy" => ("boys", "boyed", "boying", "boyed"),
-    "braai" => ("braais", "braaied", "braaing", "braaied"),
-    "brad" => ("brads", "bradded", "bradding", "bradded"),
-    "bran" => ("brans", "branned", "branning", "branned"),
-    "brast" => ("brasts", "brast", "brasting", "brast"),
-    "brat" => ("brats", "bratted", "bratting", "bratted"),
-    "bray" => ("brays", "brayed", "braying", "brayed"),
-    "break2" => ("breaks", "broke", "breaking", "broken"),
-    "breake" => ("breakes", "broke", "breaking", "broken"),
-    "breastfeed" => ("breastfeeds", "breastfed", "breastfeeding", "breastfed"),
-    "breastsleep" => ("breastsleeps", "breastslept", "breastsleeping", "breastslept"),
-    "breathhold" => ("breathholds", "breathheld", "breathholding", "breathheld"),
-    "breed" => ("breeds", "bred", "breeding", "bred"),
-    "bren" => ("brens", "brenned", "brenning", "brenned"),
-    "brevet" => ("brevets", "brevetted", "brevetting", "brevetted"),
-    "brey" => ("breys", "breyed", "breying", "breyed"),
-    "brickbat" => ("brickbats", "brickbatted", "brickbatting", "brickbatted"),
-    "bricklay" => ("bricklays", "bricklaid", "bricklaying", "bricklaid"),
-    "brim" => ("brims", "brimmed", "brimming", "brimmed"),
-    "bring" => ("brings", "brought", "bringing", "brought"),
-    "bringe" => ("bringes", "broughte", "bringing", "broughte"),
-    "briquet" => ("briquets", "briquetted", "briquetting", "briquetted"),
-    "brit" => ("brits", "britted", "britting", "britted"),
-    "broadcast" => ("broadcasts", "broadcast", "broadcasting", "broadcast"),
-    "brob" => ("brobs", "brobbed", "brobbing", "brobbed"),
-    "brod" => ("brods", "brodded", "brodding", "brodded"),
-    "bronch" => ("bronchs", "bronched", "bronching", "bronched"),
-    "browbeat" => ("browbeats", "browbeat", "browbeating", "browbeaten"),
-    "brust" => ("brusts", "brust", "brusting", "brust"),
-    "bub" => ("bubs", "bubbed", "bubbing", "bubbed"),
-    "buckjump" => ("buckjumps", "buckjumped", "buckjumping", "buckjumped"),
-    "bud" => ("buds", "budded", "budding", "budded"),
-    "budg" => ("budges", "budged", "budging", "budged"),
-    "buffalo" => ("buffaloes", "buffaloed", "buffaloing", "buffaloed"),
-    "buie" => ("buies", "bought", "buying", "bought"),
-    "build" => ("builds", "built", "building", "built"),
-    "bukkake" => ("bukkakes", "bukkaked", "bukkakeing", "bukkaked"),
-    "bullshit" => ("bullshits", "bullshitted", "bullshitting", "bullshitted"),
-    "bullspeak" => ("bullspeaks", "bullspoke", "bullspeaking", "bullspoken"),
-    "bum" => ("bums", "bummed", "bumming", "bummed"),
-    "bump" => ("bumps", "bumped", "bumping", "bumped"),
-    "bun" => ("buns", "bunned", "bunning", "bunned"),
-    "bungee" => ("bungees", "bungeed", "bungeeing", "bungeed"),
-    "buoy" => ("buoys", "buoyed", "buoying", "buoyed"),
-    "burie" => ("buries", "buried", "burying", "buried"),
-    "burp" => ("burps", "burped", "burping", "burped"),
-    "burst" => ("bursts", "burst", "bursting", "burst"),
-    "burye" => ("buryes", "buryed", "buryeing", "buryed"),
-    "bus" => ("buss", "bused", "busing", "bused"),
-    "but" => ("buts", "butted", "butting", "butted"),
-    "buz" => ("buzzes", "buzzed", "buzzing", "buzzed"),
-    "bycatch" => ("bycatches", "bycaught", "bycatching", "bycaught"),
-    "cab" => ("cabs", "cabbed", "cabbing", "cabbed"),
-    "cablecast" => ("cablecasts", "cablecast", "cablecasting", "cablecast"),
-    "cablegram" => ("cablegrams", "cablegrammed", "cablegramming", "cablegrammed"),
-    "cabob" => ("cabobs", "cabobbed", "cabobbing", "cabobbed"),
-    "caddie" => ("caddies", "caddied", "caddying", "caddied"),
-    "cajol" => ("cajols", "cajolled", "cajolling", "cajolled"),
-    "calefie" => ("calefies", "calefied", "calefying", "calefied"),
-    "cam" => ("cams", "cammed", "camming", "cammed"),
-    "cameltoe" => ("cameltoes", "cameltoed", "cameltoeing", "cameltoed"),
-    "camp" => ("camps", "camped", "camping", "camped"),
-    "campervan" => ("campervans", "campervanned", "campervanning", "campervanned"),
-    "campshed" => ("campsheds", "campshedded", "campshedding", "campshedded"),
-    "can" => ("can", "could", "able", "could"),
-    "can2" => ("cans", "canned", "canning", "canned"),
-    "canal" => ("canals", "canalled", "canalling", "canalled"),
-    "cancan" => ("cancans", "cancanned", "cancanning", "cancanned"),
-    "canoe" => ("canoes", "canoed", "canoeing", "canoed"),
-    "capot" => ("capots", "capotted", "capotting", "capotted"),
-    "caracol" => ("caracols", "caracolled", "caracolling", "caracolled"),
-    "caravan" => ("caravans", "caravanned", "caravanning", "caravanned"),
-    "carboy" => ("carboys", "carboyed", "carboying", "carboyed"),
-    "caregive" => ("caregives", "caregave", "caregiving", "caregiven"),
-    "caretake" => ("caretakes", "caretook", "caretaking", "caretaken"),
-    "carney" => ("carneys", "carneyed", "carneying", "carneyed"),
-    "carp" => ("carps", "carped", "carping", "carped"),
-    "carrie" => ("carries", "carried", "carrying", "carried"),
-    "cashapp" => ("cashapps", "cashapped", "cashapping", "cashapped"),
-    "cast" => ("casts", "cast", "casting", "cast"),
-    "cat" => ("cats", "catted", "catting", "catted"),
-    "catalog" => ("catalogs", "cataloged", "cataloging", "cataloged"),
-    "catch" => ("catches", "caught", "catching", "caught"),
-    "catsit" => ("catsits", "catsat", "catsitting", "catsat"),
-    "causeway" => ("causeways", "causewayed", "causewaying", "causewayed"),
-    "caut" => ("cauteth", "cauted", "cauting", "cauted"),
-    "cham" => ("chams", "chammed", "chamming", "chammed"),
-    "champ" => ("champs", "champed", "champing", "champed"),
-    "char2" => ("chars", "charred", "charring", "charred"),
-    "charpit" => ("charpits", "charpitted", "charpitting", "charpitted"),
-    "chasse" => ("chasses", "chassed", "chasseing", "chassed"),
-    "chat" => ("chats", "chatted", "chatting", "chatted"),
-    "chauf" => ("chaufs", "chauffed", "chauffing", "chauffed"),
-    "chavel" => ("chavels", "chavelled", "chavelling", "chavelled"),
-    "cheap" => ("cheaps", "cheaped", "cheaping", "cheaped"),
-    "checksum" => ("checksums", "checksummed", "checksumming", "checksummed"),
-    "cheep" => ("cheeps", "cheeped", "cheeping", "cheeped"),
-    "cheerlead" => ("cheerleads", "cheerled", "cheerleading", "cheerled"),
-    "chef" => ("chefs", "cheffed", "cheffing", "cheffed"),
-    "chelp" => ("chelps", "chelped", "chelping", "chelped"),
-    "chemic" => ("chemics", "chemicked", "chemicking", "chemicked"),
-    "chemostat" => ("chemostats", "chemostatted", "chemostatting", "chemostatted"),
-    "cherup" => ("cherups", "cheruped", "cheruping", "cheruped"),
-    "chestfeed" => ("chestfeeds", "chestfed", "chestfeeding", "chestfed"),
-    "chib" => ("chibs", "chibbed", "chibbing", "chibbed"),
-    "chide" => ("chides", "chid", "chiding", "chid"),
-    "childsit" => ("childsits", "childsat", "childsitting", "childsat"),
-    "chimney" => ("chimneys", "chimneyed", "chimneying", "chimneyed"),
-    "chimp" => ("chimps", "chimped", "chimping", "chimped"),
-    "chin" => ("chins", "chinned", "chinning", "chinned"),
-    "chirp" => ("chirps", "chirped", "chirping", "chirped"),
-    "chirrup" => ("chirrups", "chirruped", "chirruping", "chirruped"),
-    "chit" => ("chits", "chitted", "chitting", "chitted"),
-    "chitchat" => ("chitchats", "chitchatted", "chitchatting", "chitchatted"),
-    "chiv" => ("chivs", "chivved", "chivving", "chivved"),
-    "chivaree" => ("chivarees", "chivareed", "chivareeing", "chivareed"),
-    "chivari" => ("chivaries", "chivaried", "chivariing", "chivaried"),
-    "chivey" => ("chiveys", "chiveyed", "chiveying", "chiveyed"),
-    "chokehold" => ("chokeholds", "chokeheld", "chokeholding", "chokeheld"),
-    "chokeslam" => ("chokeslams", "chokeslammed", "chokeslamming", "chokeslammed"),
-    "chomp" => ("chomps", "chomped", "chomping", "chomped"),
-    "choose" => ("chooses", "chose", "choosing", "chosen"),
-    "chor" => ("chors", "chorred", "chorrin", "chorred"),
-    "chromakey" => ("chromakeys", "chromakeyed", "chromakeying", "chromakeyed"),
-    "chub" => ("chubs", "chubbed", "chubbing", "chubbed"),
-    "chud" => ("chuds", "chudded", "chudding", "chudded"),
-    "chum" => ("chums", "chummed", "chumming", "chummed"),
-    "chump" => ("chumps", "chumped", "chumping", "chumped"),
-    "chunkay" => ("chunkays", "chunkayed", "chunkaying", "chunkayed"),
-    "chuse" => ("chuses", "chose", "chusing", "chosen"),
-    "chut" => ("chuts", "chutted", "chutting", "chutted"),
-    "cinemacast" => ("cinemacasts", "cinemacast", "cinemacasting", "cinemacast"),
-    "clad" => ("clads", "clad", "cladding", "clad"),
-    "clam" => ("clams", "clammed", "clamming", "clammed"),
-    "clamp" => ("clamps", "clamped", "clamping", "clamped"),
-    "clasp" => ("clasps", "clasped", "clasping", "clasped"),
-    "clat" => ("clats", "clatted", "clatting", "clatted"),
-    "clay" => ("clays", "clayed", "claying", "clayed"),
-    "cleave2" => ("cleaves", "cleft", "cleaving", "cleft"),
-    "clem" => ("clems", "clemmed", "clemming", "clemmed"),
-    "clep2" => ("cleps", "clept", "clepping", "clept"),
-    "cliche" => ("cliches", "cliched", "clicheing", "cliched"),
-    "cliffhang" => ("cliffhangs", "cliffhung", "cliffhanging", "cliffhung"),
-    "cling2" => ("clings", "clung", "clinging", "clung"),
-    "clip2" => ("clips", "clipt", "clipping", "clipt"),
-    "clit" => ("clits", "clitted", "clitting", "clitted"),
-    "clod" => ("clods", "clodded", "clodding", "clodded"),
-    "clomp" => ("clomps", "clomped", "clomping", "clomped"),
-    "cloop" => ("cloops", "clooped", "clooping", "clooped"),
-    "clot" => ("clots", "clotted", "clotting", "clotted"),
-    "clothespin" => ("clothespins", "clothespinned", "clothespinning", "clothespinned"),
-    "cloy" => ("cloys", "cloyed", "cloying", "cloyed"),
-    "club" => ("clubs", "clubbed", "clubbing", "clubbed"),
-    "clump" => ("clumps", "clumped", "clumping", "clumped"),
-    "coadmit" => ("coadmits", "coadmitted", "coadmitting", "coadmitted"),
-    "cob" => ("cobs", "cobbed", "cobbing", "cobbed"),
-    "cobuild" => ("cobuilds", "cobuilt", "cobuilding", "cobuilt"),
-    "cockey" => ("cockeys", "cockeyed", "cockeying", "cockeyed"),
-    "cod" => ("cods", "codded", "codding", "codded"),
-    "codevelop" => ("codevelops", "codeveloped", "codeveloping", "codeveloped"),
-    "codisplay" => ("codisplays", "codisplayed", "codisplaying", "codisplayed"),
-    "codistil" => ("codistils", "codistilled", "codistilling", "codistilled"),
-    "codrive" => ("codrives", "codrove", "codriving", "codriven"),
-    "cofeed" => ("cofeeds", "cofed", "cofeeding", "cofed"),
-    "coffee" => ("coffees", "coffeed", "coffeeing", "coffeed"),
-    "coif" => ("coifs", "coiffed", "coiffing", "coiffed"),
-    "cointer" => ("cointers", "cointerred", "cointerring", "cointerred"),
-    "coldsleep" => ("coldsleeps", "coldslept", "coldsleeping", "coldslept"),
-    "colead" => ("coleads", "coled", "coleading", "coled"),
-    "collop" => ("collops", "colloped", "colloping", "colloped"),
-    "colorbreed" => ("colorbreeds", "colorbred", "colorbreeding", "colorbred"),
-    "colorcast" => ("colorcasts", "colorcast", "colorcasting", "colorcast"),
-    "colourbreed" => ("colourbreeds", "colourbred", "colourbreeding", "colourbred"),
-    "colourcast" => ("colourcasts", "colourcast", "colourcasting", "colourcast"),
-    "come" => ("comes", "came", "coming", "come"),
-    "commit" => ("commits", "committed", "committing", "committed"),
-    "comp" => ("comps", "comped", "comping", "comped"),
-    "compel" => ("compels", "compelled", "compelling", "compelled"),
-    "complot" => ("complots", "complotted", "complotting", "complotted"),
-    "compromit" => ("compromits", "compromitted", "compromitting", "compromitted"),
-    "comptrol" => ("comptrols", "comptrolled", "comptrolling", "comptrolled"),
-    "con" => ("cons", "conned", "conning", "conned"),
-    "concat" => ("concats", "concatted", "concatting", "concatted"),
-    "concur" => ("concurs", "concurred", "concurring", "concurred"),
-    "condem" => ("condems", "condemmed", "condemming", "condemmed"),
-    "confab" => ("confabs", "confabbed", "confabbing", "confabbed"),
-    "confer" => ("confers", "conferred", "conferring", "conferred"),
-    "config" => ("configs", "configed", "configing", "configed"),
-    "conge" => ("conges", "conged", "congeing", "conged"),
-    "congee" => ("congees", "congeed", "congeeing", "congeed"),
-    "congree" => ("congrees", "congreed", "congreeing", "congreed"),
-    "control" => ("controls", "controlled", "controlling", "controlled"),
-    "convel" => ("convels", "convelled", "convelling", "convelled"),
-    "convey" => ("conveys", "conveyed", "conveying", "conveyed"),
-    "convoy" => ("convoys", "convoyed", "convoying", "convoyed"),
-    "cooccur" => ("cooccurs", "cooccurred", "cooccurring", "cooccurred"),
-    "cooee" => ("cooees", "cooeed", "cooeeing", "cooeed"),
-    "cooey" => ("cooeys", "cooeyed", "cooeying", "cooeyed"),
-    "cookie" => ("cookies", "cookied", "cookieing", "cookied"),
-    "coom" => ("cooms", "came", "cooming", "came"),
-    "coom2" => ("cooms", "came", "cooming", "coom"),
-    "coop" => ("coops", "cooped", "cooping", "cooped"),
-    "coorie" => ("coories", "cooried", "coorieing", "cooried"),
-    "copay" => ("copays", "copaid", "copaying", "copaid"),
-    "copen" => ("copens", "copenned", "copenning", "copenned"),
-    "coplot" => ("coplots", "coplotted", "coplotting", "coplotted"),
-    "copycat" => ("copycats", "copycatted", "copycatting", "copycatted"),
-    "copyread" => ("copyreads", "copyread", "copyreading", "copyread"),
-    "copywrite" => ("copywrites", "copywrote", "copywriting", "copywritten"),
-    "coquet" => ("coquets", "coquetted", "coquetting", "coquetted"),
-    "coquit" => ("coquits", "coquit", "coquitting", "coquit"),
-    "corduroy" => ("corduroys", "corduroyed", "corduroying", "corduroyed"),
-    "corefer" => ("corefers", "coreferred", "coreferring", "coreferred"),
-    "corncob" => ("corncobs", "corncobbed", "corncobbing", "corncobbed"),
-    "corrump" => ("corrumps", "corrumped", "corrumping", "corrumped"),
-    "cosleep" => ("cosleeps", "coslept", "cosleeping", "coslept"),
-    "cosplay" => ("cosplays", "cosplayed", "cosplaying", "cosplayed"),
-    "cost" => ("costs", "cost", "costing", "cost"),
-    "costar" => ("costars", "costarred", "costarring", "costarred"),
-    "cotch2" => ("cotches", "caught", "cotching", "caught"),
-    "coteach" => ("coteaches", "cotaught", "coteaching", "cotaught"),
-    "cotransfer" => ("cotransfers", "cotransferred", "cotransferring", "cotransferred"),
-    "cotransmit" => ("cotransmits", "cotransmitted", "cotransmitting", "cotransmitted"),
-    "counterbid" => ("counterbids", "counterbid", "counterbidding", "counterbid"),
-    "counterbleed" => ("counterbleeds", "counterbled", "counterbleeding", "counterbled"),
-    "counterdraw" => ("counterdraws", "counterdrew", "counterdrawing", "counterdrawn"),
-    "counterdye" => ("counterdyes", "counterdyed", "counterdyeing", "counterdyed"),
-    "counterfeed" => ("counterfeeds", "counterfed", "counterfeeding", "counterfed"),
-    "countermeet" => ("countermeets", "countermet", "countermeeting", "countermet"),
-    "counterplan" => ("counterplans", "counterplanned", "counterplanning", "counterplanned"),
-    "counterplay" => ("counterplays", "counterplayed", "counterplaying", "counterplayed"),
-    "counterplot" => ("counterplots", "counterplotted", "counterplotting", "counterplotted"),
-    "counterprogram" => ("counterprograms", "counterprogrammed", "counterprogramming", "counterprogrammed"),
-    "counterread" => ("counterreads", "counterread", "counterreading", "counterread"),
-    "counterrevolt" => ("counterrevolt", "counterrevolted", "counterrevolting", "counterrevolted"),
-    "countersing" => ("countersings", "countersang", "countersinging", "countersung"),
-    "countersink" => ("countersinks", "countersank", "countersinking", "countersunk"),
-    "counterstamp" => ("counterstamps", "counterstamped", "counterstamping", "counterstamped"),
-    "counterstrike" => ("counterstrikes", "counterstruck", "counterstriking", "counterstruck"),
-    "countersway" => ("countersways", "counterswayed", "counterswaying", "counterswayed"),
-    "counterswear" => ("counterswears", "counterswore", "counterswearing", "countersworn"),
-    "counterswing" => ("counterswings", "counterswung", "counterswinging", "counterswung"),
-    "counterthrow" => ("counterthrows", "counterthrew", "counterthrowing", "counterthrown"),
-    "countrol" => ("countrols", "countrolled", "countrolling", "countrolled"),
-    "coup" => ("coups", "couped", "couping", "couped"),
-    "covey" => ("coveys", "coveyed", "coveying", "coveyed"),
-    "covie" => ("covies", "covied", "covying", "covied"),
-    "cowboy" => ("cowboys", "cowboyed", "cowboying", "cowboyed"),
-    "cowp" => ("cowps", "cowped", "cowping", "cowped"),
-    "cowrite" => ("cowrites", "cowrote", "cowriting", "cowritten"),
-    "coy" => ("coys", "coyed", "coying", "coyed"),
-    "coyote" => ("coyotes", "coyoted", "coyoteing", "coyoted"),
-    "crab" => ("crabs", "crabbed", "crabbing", "crabbed"),
-    "cram" => ("crams", "crammed", "cramming", "crammed"),
-    "cramp" => ("cramps", "cramped", "cramping", "cramped"),
-    "cratedig" => ("cratedigs", "cratedug", "cratedigging", "cratedug"),
-    "cravat" => ("cravats", "cravatted", "cravatting", "cravatted"),
-    "creach" => ("creachs", "creached", "creaching", "creached"),
-    "creampie" => ("creampies", "creampied", "creampieing", "creampied"),
-    "creep" => ("creeps", "crept", "creeping", "crept"),
-    "creepshot" => ("creepshots", "creepshotted", "creepshotting", "creepshotted"),
-    "crescendo" => ("crescendoes", "crescendoed", "crescendoing", "crescendoed"),
-    "crib" => ("cribs", "cribbed", "cribbing", "cribbed"),
-    "crie" => ("cries", "cried", "crying", "cried"),
-    "crimethink" => ("crimethinks", "crimethought", "crimethinking", "crimethought"),
-    "crimp" => ("crimps", "crimped", "crimping", "crimped"),
-    "crisp" => ("crisps", "crisped", "crisping", "crisped"),
-    "crit" => ("crits", "crit", "critting", "crit"),
-    "critic" => ("critics", "criticked", "criticking", "criticked"),
-    "crockpot" => ("crockpots", "crockpotted", "crockpotting", "crockpotted"),
-    "crossbar" => ("crossbars", "crossbarred", "crossbarring", "crossbarred"),
-    "crossbite" => ("crossbites", "crossbit", "crossbiting", "crossbitten"),
-    "crossbreed" => ("crossbreeds", "crossbred", "crossbreeding", "crossbred"),
-    "crossclamp" => ("crossclamps", "crossclamped", "crossclamping", "crossclamped"),
-    "crosscut" => ("crosscuts", "crosscut", "crosscutting", "crosscut"),
-    "crossfeed" => ("crossfeeds", "crossfed", "crossfeeding", "crossfed"),
-    "crossfit" => ("crossfits", "crossfitted", "crossfitting", "crossfitted"),
-    "crossplay" => ("crossplays", "crossplayed", "crossplaying", "crossplayed"),
-    "crossref" => ("crossrefs", "crossreffed", "crossreffing", "crossreffed"),
-    "croup" => ("croups", "crouped", "crouping", "crouped"),
-    "crowbar" => ("crowbars", "crowbarred", "crowbarring", "crowbarred"),
-    "crud" => ("cruds", "crudded", "crudding", "crudded"),
-    "crump" => ("crumps", "crumped", "crumping", "crumped"),
-    "crut" => ("cruts", "crutted", "crutting", "crutted"),
-    "cryoclamp" => ("cryoclamps", "cryoclamped", "cryoclamping", "cryoclamped"),
-    "cryoembed" => ("cryoembeds", "cryoembedded", "cryoembedding", "cryoembedded"),
-    "cryogrind" => ("cryogrinds", "cryoground", "cryogrinding", "cryoground"),
-    "cryopump" => ("cryopumps", "cryopumped", "cryopumping", "cryopumped"),
-    "cub" => ("cubs", "cubbed", "cubbing", "cubbed"),
-    "cud" => ("cuds", "cudded", "cudding", "cudded"),
-    "cue" => ("cues", "cued", "cueing", "cued"),
-    "cum" => ("cums", "came", "cumming", "come"),
-    "cun" => ("cuns", "cunned", "cunning", "cunned"),
-    "curbstomp" => ("curbstomps", "curbstomped", "curbstomping", "curbstomped"),
-    "curmur" => ("curmurs", "curmurred", "curmurring", "curmurred"),
-    "curtsey" => ("curtseys", "curtseyed", "curtseying", "curtseyed"),
-    "curvet" => ("curvets", "curvetted", "curvetting", "curvetted"),
-    "cusp" => ("cusps", "cusped", "cusping", "cusped"),
-    "cut" => ("cuts", "cut", "cutting", "cut"),
-    "cvt" => ("cvts", "cvt", "cvtting", "cvt"),
-    "cybercast" => ("cybercasts", "cybercast", "cybercasting", "cybercast"),
-    "cybersquat" => ("cybersquats", "cybersquatted", "cybersquatting", "cybersquatted"),
-    "cyborg" => ("cyborgs", "cyborged", "cyborging", "cyborged"),
-    "dab" => ("dabs", "dabbed", "dabbing", "dabbed"),
-    "dad" => ("dads", "dadded", "dadding", "dadded"),
-    "dado" => ("dadoes", "dadoed", "dadoing", "dadoed"),
-    "dagnab" => ("dagnabs", "dagnabbed", "dagnabbing", "dagnabbed"),
-    "dak" => ("daks", "dakked", "dakking", "dakked"),
-    "dam" => ("dams", "dammed", "damming", "dammed"),
-    "damp" => ("damps", "damped", "damping", "damped"),
-    "daresay" => ("daresays", "daresayed", "daresaying", "daresayed"),
-    "darf" => ("darf", "durft", "durving", "durft"),
-    "databend" => ("databends", "databent", "databending", "databent"),
-    "datagram" => ("datagrams", "datagrammed", "datagramming", "datagrammed"),
-    "datestamp" => ("datestamps", "datestamped", "datestamping", "datestamped"),
-    "day" => ("days", "dayed", "daying", "dayed"),
-    "daydream" => ("daydreams", "daydreamt", "daydreaming", "daydreamt"),
-    "de" => ("diz", "did", "dein", "dyun"),
-    "deadpan" => ("deadpans", "deadpanned", "deadpanning", "deadpanned"),
-    "deal" => ("deals", "dealt", "dealing", "dealt"),
-    "debar" => ("debars", "debarred", "debarring", "debarred"),
-    "debel" => ("debels", "debelled", "debelling", "debelled"),
-    "debend" => ("debends", "debent", "debending", "debent"),
-    "debind" => ("debinds", "debound", "debinding", "debound"),
-    "deblur" => ("deblurs", "deblurred", "deblurring", "deblurred"),
-    "debran" => ("debrans", "debranned", "debranning", "debranned"),
-    "debud" => ("debuds", "debudded", "debudding", "debudded"),
-    "debur" => ("deburs", "deburred", "deburring", "deburred"),
-    "debus" => ("debusses", "debussed", "debussing", "debussed"),
-    "decamp" => ("decamps", "decamped", "decamping", "decamped"),
-    "decan" => ("decans", "decanned", "decanning", "decanned"),
-    "decar" => ("decars", "decarred", "decarring", "decarred"),
-    "decay" => ("decays", "decayed", "decaying", "decayed"),
-    "decerp" => ("decerps", "decerped", "decerping", "decerped"),
-    "dechirp" => ("dechirps", "dechirped", "dechirping", "dechirped"),
-    "declamp" => ("declamps", "declamped", "declamping", "declamped"),
-    "declump" => ("declumps", "declumped", "declumping", "declumped"),
-    "decommit" => ("decommits", "decommitted", "decommitting", "decommitted"),
-    "decomp" => ("decomps", "decomped", "decomping", "decomped"),
-    "decon" => ("decons", "deconned", "deconning", "deconned"),
-    "decontrol" => ("decontrols", "decontrolled", "decontrolling", "decontrolled"),
-    "decoy" => ("decoys", "decoyed", "decoying", "decoyed"),
-    "decrab" => ("decrabs", "decrabbed", "decrabbing", "decrabbed"),
-    "decree" => ("decrees", "decreed", "decreeing", "decreed"),
-    "decrud" => ("decruds", "decrudded", "decrudding", "decrudded"),
-    "dee" => ("diz", "dyun", "deein", "dyun"),
-    "deejay" => ("deejays", "deejayed", "deejaying", "deejayed"),
-    "deek" => ("deeks", "deeked", "deekin", "deeked"),
-    "deeky" => ("deekies", "deekied", "deekyin", "deekied"),
-    "deembed" => ("deembeds", "deembedded", "deembedding", "deembedded"),
-    "deep" => ("deeps", "deeped", "deeping", "deeped"),
-    "deepdraw" => ("deepdraws", "deepdrew", "deepdrawing", "deepdrawn"),
-    "defat" => ("defats", "defatted", "defatting", "defatted"),
-    "defed" => ("defeds", "defedded", "defedding", "defedded"),
-    "defer" => ("defers", "deferred", "deferring", "deferred"),
-    "defib" => ("defibs", "defibbed", "defibbing", "defibbed"),
-    "defie" => ("defies", "defied", "defying", "defied"),
-    "defin" => ("defins", "definned", "definning", "definned"),
-    "deformat" => ("deformats", "deformatted", "deformatting", "deformatted"),
-    "defray" => ("defrays", "defrayed", "defraying", "defrayed"),
-    "defreeze" => ("defreezes", "defroze", "defreezing", "defrozen"),
-    "defur" => ("defurs", "defurred", "defurring", "defurred"),
-    "degas" => ("degases", "degassed", "degassing", "degassed"),
-    "degel" => ("degels", "degelled", "degelling", "degelled"),
-    "degod" => ("degods", "degodded", "degodding", "degodded"),
-    "degrit" => ("degrits", "degritted", "degritting", "degritted"),
-    "degroup" => ("degroups", "degrouped", "degrouping", "degrouped"),
-    "degrow" => ("degrows", "degrew", "degrowing", "degrown"),
-    "degum" => ("degums", "degummed", "degumming", "degummed"),
-    "degut" => ("deguts", "degutted", "degutting", "degutted"),
-    "dehub" => ("dehubs", "dehubbed", "dehubbing", "dehubbed"),
-    "dehydrofreeze" => ("dehydrofreeze", "dehydrofreezing", "dehydrofreezes", "dehydrofrozen"),
-    "dekek" => ("dekeks", "dekekked", "dekekking", "dekekked"),
-    "delam" => ("delams", "delammed", "delamming", "delammed"),
-    "delay" => ("delays", "delayed", "delaying", "delayed"),
-    "dele" => ("deles", "deled", "deleing", "deled"),
-    "delid" => ("delids", "delidded", "delidding", "delidded"),
-    "delie" => ("delies", "delied", "delying", "delied"),
-    "demagog" => ("demagogs", "demagoged", "demagoging", "demagoged"),
-    "demake" => ("demakes", "demade", "demaking", "demade"),
-    "deman" => ("demans", "demanned", "demanning", "demanned"),
-    "demat" => ("demats", "dematted", "dematting", "dematted"),
-    "demit" => ("demits", "demitted", "demitting", "demitted"),
-    "demob" => ("demobs", "demobbed", "demobbing", "demobbed"),
-    "demod" => ("demods", "demodded", "demodding", "demodded"),
-    "demosaic" => ("demosaics", "demosaicked", "demosaicking", "demosaicked"),
-    "demur" => ("demurs", "demurred", "demurring", "demurred"),
-    "den" => ("dens", "denned", "denning", "denned"),
-    "denay" => ("denays", "denayed", "denaying", "denayed"),
-    "denet" => ("denets", "denetted", "denetting", "denetted"),
-    "denib" => ("denibs", "denibbed", "denibbing", "denibbed"),
-    "depin" => ("depins", "depinned", "depinning", "depinned"),
-    "deploy" => ("deploys", "deployed", "deploying", "deployed"),
-    "deprogram" => ("deprograms", "deprogrammed", "deprogramming", "deprogrammed"),
-    "deramp" => ("deramps", "deramped", "deramping", "deramped"),
-    "derat" => ("derats", "deratted", "deratting", "deratted"),
-    "deray" => ("derays", "derayed", "deraying", "derayed"),
-    "derp" => ("derps", "derped", "derping", "derped"),
-    "desat" => ("desats", "desatted", "desatting", "desatted"),
-    "descan" => ("descans", "descanned", "descanning", "descanned"),
-    "descum" => ("descums", "descummed", "descumming", "descummed"),
-    "deskin" => ("deskins", "deskinned", "deskinning", "deskinned"),
-    "deslot" => ("deslots", "deslotted", "deslotting", "deslotted"),
-    "despam" => ("despams", "despammed", "despamming", "despammed"),
-    "despeed" => ("despeeds", "desped", "despeeding", "desped"),
-    "despin" => ("despins", "despun", "despinning", "despun"),
-    "despread2" => ("despreads", "despread", "despreading", "despread"),
-    "destat" => ("destats", "destatted", "destatting", "destatted"),
-    "destem" => ("destems", "destemmed", "destemming", "destemmed"),
-    "destim" => ("destims", "destimmed", "destimming", "destimmed"),
-    "destroy" => ("destroys", "destroyed", "destroying", "destroyed"),
-    "desynch" => ("desynchs", "desynched", "desynching", "desynched"),
-    "detelecine" => ("detelecines", "detelecined", "detelecineing", "detelecined"),
-    "deter" => ("deters", "deterred", "deterring", "deterred"),
-    "detin" => ("detins", "detinned", "detinning", "detinned"),
-    "detwin" => ("detwins", "detwinned", "detwinning", "detwinned"),
-    "devan" => ("devans", "devanned", "devanning", "devanned"),
-    "devel" => ("devels", "develled", "develling", "develled"),
-    "develop" => ("develops", "developed", "developing", "developed"),
-    "devvel" => ("devvels", "devvelled", "devvelling", "devvelled"),
-    "dewet" => ("dewets", "dewetted", "dewetting", "dewetted"),
-    "dewret" => ("dewrets", "dewretted", "dewretting", "dewretted"),
-    "diadem" => ("diadems", "diademmed", "diademming", "diademmed"),
-    "diagram" => ("diagrams", "diagrammed", "diagramming", "diagrammed"),
-    "dialog" => ("dialogs", "dialoged", "dialoging", "dialoged"),
-    "diaperhump" => ("diaperhumps", "diaperhumped", "diaperhumping", "diaperhumped"),
-    "dib" => ("dibs", "dibbed", "dibbing", "dibbed"),
-    "dickride" => ("dickrides", "dickrode", "dickriding", "dickridden"),
-    "die" => ("dies", "died", "dying", "died"),
-    "diecast" => ("diecasts", "diecast", "diecasting", "diecast"),
-    "dig" => ("digs", "dug", "digging", "dug"),
-    "dight" => ("dights", "dight", "dighting", "dight"),
-    "digibin" => ("digibins", "digibinned", "digibinning", "digibinned"),
-    "dildo" => ("dildoes", "dildoed", "dildoing", "dildoed"),
-    "dim" => ("dims", "dimmed", "dimming", "dimmed"),
-    "dimit" => ("dimits", "dimitted", "dimitting", "dimitted"),
-    "din" => ("dins", "dinned", "dinning", "dinned"),
-    "dinge" => ("dinges", "dinged", "dingeing", "dinged"),
-    "dingo" => ("dingoes", "dingoed", "dingoing", "dingoed"),
-    "dingolay" => ("dingolays", "dingolayed", "dingolaying", "dingolayed"),
-    "dipnet" => ("dipnets", "dipnetted", "dipnetting", "dipnetted"),
-    "dis" => ("disses", "dissed", "dissing", "dissed"),
-    "disagree" => ("disagrees", "disagreed", "disagreeing", "disagreed"),
-    "disannul" => ("disannuls", "disannulled", "disannulling", "disannulled"),
-    "disarray" => ("disarrays", "disarrayed", "disarraying", "disarrayed"),
-    "disbar" => ("disbars", "disbarred", "disbarring", "disbarred"),
-    "disbecome" => ("disbecomes", "disbecame", "disbecoming", "disbecome"),
-    "disbend" => ("disbends", "disbent", "disbending", "disbent"),
-    "disbind" => ("disbinds", "disbound", "disbinding", "disbound"),
-    "disbud" => ("disbuds", "disbudded", "disbudding", "disbudded"),
-    "discamp" => ("discamps", "discamped", "discamping", "discamped"),
-    "discerp" => ("discerps", "discerped", "discerping", "discerped"),
-    "disconcur" => ("disconcurs", "disconcurred", "disconcurring", "disconcurred"),
-    "disculp" => ("disculps", "disculped", "disculping", "disculped"),
-    "disembed" => ("disembeds", "disembedded", "disembedding", "disembedded"),
-    "disemploy" => ("disemploys", "disemployed", "disemploying", "disemployed"),
-    "disenjoy" => ("disenjoys", "disenjoyed", "disenjoying", "disenjoyed"),
-    "disenrol" => ("disenrols", "disenrolled", "disenrolling", "disenrolled"),
-    "disenter" => ("disenters", "disenterred", "disenterring", "disenterred"),
-    "disenthral" => ("disenthrals", "disenthralled", "disenthralling", "disenthralled"),
-    "disenvelop" => ("disenvelops", "disenveloped", "disenveloping", "disenveloped"),
-    "disinter" => ("disinters", "disinterred", "disinterring", "disinterred"),
-    "disinthral" => ("disinthrals", "disinthralled", "disinthralling", "disinthralled"),
-    "disman" => ("dismans", "dismanned", "dismanning", "dismanned"),
-    "dismay" => ("dismays", "dismayed", "dismaying", "dismayed"),
-    "disobey" => ("disobeys", "disobeyed", "disobeying", "disobeyed"),
-    "dispel" => ("dispels", "dispelled", "dispelling", "dispelled"),
-    "display" => ("displays", "displayed", "displaying", "displayed"),
-    "disprefer" => ("disprefers", "dispreferred", "dispreferring", "dispreferred"),
-    "dispurvey" => ("dispurveys", "dispurveyed", "dispurveying", "dispurveyed"),
-    "disray" => ("disrays", "disrayed", "disraying", "disrayed"),
-    "dister" => ("disters", "disterred", "disterring", "disterred"),
-    "distil" => ("distils", "distilled", "distilling", "distilled"),
-    "disvelop" => ("disvelops", "disveloped", "disveloping", "disveloped"),
-    "disway" => ("disways", "diswayed", "diswaying", "diswayed"),
-    "disworship" => ("disworships", "disworshiped", "disworshiping", "disworshiped"),
-    "dit" => ("dits", "ditted", "ditting", "ditted"),
-    "divast" => ("divasts", "divast", "divasting", "divast"),
-    "divel" => ("divels", "divelled", "divelling", "divelled"),
-    "diy" => ("diys", "diyed", "diying", "diyed"),
-    "diz" => ("dizzes", "dizzed", "dizzing", "dizzed"),
-    "do" => ("does", "did", "doing", "done"),
-    "dob" => ("dobs", "dobbed", "dobbing", "dobbed"),
-    "dod" => ("dods", "dodded", "dodding", "dodded"),
-    "doe" => ("does", "did", "doing", "done"),
-    "doge" => ("doges", "doged", "dogeing", "doged"),
-    "dogsit" => ("dogsits", "dogsat", "dogsitting", "dogsat"),
-    "dogsled" => ("dogsleds", "dogsledded", "dogsledding", "dogsledded"),
-    "dogtrot" => ("dogtrots", "dogtrotted", "dogtrotting", "dogtrotted"),
-    "dollop" => ("dollops", "dolloped", "dolloping", "dolloped"),
-    "dom" => ("doms", "dommed", "domming", "dommed"),
-    "domino" => ("dominoes", "dominoed", "dominoing", "dominoed"),
-    "don" => ("dons", "donned", "donning", "donned"),
-    "doodie" => ("doodies", "doodied", "doodying", "doodied"),
-    "doomsay" => ("doomsays", "doomsaid", "doomsaying", "doomsaid"),
-    "doorknob" => ("doorknobs", "doorknobbed", "doorknobbing", "doorknobbed"),
-    "dorcas" => ("dorcasses", "dorcassed", "dorcassing", "dorcassed"),
-    "dot" => ("dots", "dotted", "dotting", "dotted"),
-    "dotcom" => ("dotcoms", "dotcommed", "dotcomming", "dotcommed"),
-    "doublethink" => ("doublethinks", "doublethought", "doublethinking", "doublethought"),
-    "doughnut" => ("doughnuts", "doughnutted", "doughnutting", "doughnutted"),
-    "dougie" => ("dougies", "dougied", "dougying", "dougied"),
-    "downarg" => ("downargs", "downarged", "downarging", "downarged"),
-    "downbear" => ("downbears", "downbore", "downbearing", "downborne"),
-    "downbet" => ("downbets", "downbet", "downbetting", "downbet"),
-    "downcast" => ("downcasts", "downcast", "downcasting", "downcast"),
-    "downcome" => ("downcomes", "downcame", "downcoming", "downcome"),
-    "downcut" => ("downcuts", "downcut", "downcutting", "downcut"),
-    "downdraw" => ("downdraws", "downdrew", "downdrawing", "downdrawn"),
-    "downfall" => ("downfalls", "downfell", "downfalling", "downfallen"),
-    "downplay" => ("downplays", "downplayed", "downplaying", "downplayed"),
-    "downspin" => ("downspins", "downspun", "downspinning", "downspun"),
-    "downstrike" => ("downstrikes", "downstruck", "downstriking", "downstricken"),
-    "downstroy" => ("downstroys", "downstroyed", "downstroying", "downstroyed"),
-    "downsweep" => ("downsweeps", "downswept", "downsweeping", "downswept"),
-    "downtread" => ("downtreads", "downtrod", "downtreading", "downtrodden"),
-    "downtrod" => ("downtrods", "downtrod", "downtrodding", "downtrodden"),
-    "dp" => ("dps", "dped", "dping", "dped"),
-    "drab" => ("drabs", "drabbed", "drabbing", "drabbed"),
-    "dragnet" => ("dragnets", "dragnetted", "dragnetting", "dragnetted"),
-    "dram" => ("drams", "drammed", "dramming", "drammed"),
-    "drat" => ("drats", "dratted", "dratting", "dratted"),
-    "draw" => ("draws", "drew", "drawing", "drawn"),
-    "drawe" => ("drawes", "drewe", "drawing", "drawen"),
-    "dray" => ("drays", "drayed", "draying", "drayed"),
-    "dreap" => ("dreaps", "dreaped", "dreaping", "dreaped"),
-    "dreave" => ("dreaves", "dreft", "dreaving", "dreft"),
-    "dree" => ("drees", "dreed", "dreeing", "dreed"),
-    "dreep" => ("dreeps", "dreeped", "dreeping", "dreeped"),
-    "dressmake" => ("dressmakes", "dressmade", "dressmaking", "dressmade"),
-    "drib" => ("dribs", "dribbed", "dribbing", "dribbed"),
-    "driftnet" => ("driftnets", "driftnetted", "driftnetting", "driftnetted"),
-    "drinck" => ("drincks", "dranck", "drincking", "drunck"),
-    "drink" => ("drinks", "drank", "drinking", "drunk"),
-    "drite" => ("drites", "drote", "driting", "dritten"),
-    "driue" => ("driues", "droue", "driuing", "driuen"),
-    "drive" => ("drives", "drove", "driving", "driven"),
-    "droop" => ("droops", "drooped", "drooping", "drooped"),
-    "dropcast" => ("dropcasts", "dropcast", "dropcasting", "dropcast"),
-    "dropset" => ("dropsets", "dropsetted", "dropsetting", "dropsetted"),
-    "drub" => ("drubs", "drubbed", "drubbing", "drubbed"),
-    "drum" => ("drums", "drummed", "drumming", "drummed"),
-    "dry2" => ("drys", "dryed", "drying", "dryed"),
-    "dub" => ("dubs", "dubbed", "dubbing", "dubbed"),
-    "dude" => ("dudes", "duded", "dudeing", "duded"),
-    "duet" => ("duets", "duetted", "duetting", "duetted"),
-    "dump" => ("dumps", "dumped", "dumping", "dumped"),
-    "dun" => ("duns", "dunned", "dunning", "dunned"),
-    "dunsh" => ("dunshes", "dunshed", "dunshin", "dunshed"),
-    "dwell" => ("dwells", "dwelt", "dwelling", "dwelt"),
-    "dx" => ("dxs", "dxed", "dxing", "dxed"),
-    "dyb" => ("dybs", "dybbed", "dybbing", "dybbed"),
-    "dye" => ("dyes", "dyed", "dyeing", "dyed"),
-    "eat" => ("eats", "ate", "eating", "eaten"),
-    "eate" => ("eates", "ate", "eating", "eaten"),
-    "eavesread" => ("eavesreads", "eavesread", "eavesreading", "eavesread"),
-    "ebay" => ("ebays", "ebayed", "ebaying", "ebayed"),
-    "echo" => ("echoes", "echoed", "echoing", "echoed"),
-    "eep" => ("eeps", "eeped", "eeping", "eeped"),
-    "effray" => ("effrays", "effrayed", "effraying", "effrayed"),
-    "egg" => ("eggs", "egged", "egging", "egged"),
-    "egocast" => ("egocasts", "egocast", "egocasting", "egocast"),
-    "egoscan" => ("egoscans", "egoscanned", "egoscanning", "egoscanned"),
-    "electroblot" => ("electroblots", "electroblotted", "electroblotting", "electroblotted"),
-    "electrospray" => ("electrosprays", "electrosprayed", "electrospraying", "electrosprayed"),
-    "electrostun" => ("electrostuns", "electrostunned", "electrostunning", "electrostunned"),
-    "electrotin" => ("electrotins", "electrotinned", "electrotinning", "electrotinned"),
-    "electrotransfer" => ("electrotransfers", "electrotransferred", "electrotransferring", "electrotransferred"),
-    "electrowin" => ("electrowins", "electrowon", "electrowinning", "electrowon"),
-    "embar" => ("embars", "embarred", "embarring", "embarred"),
-    "embargo" => ("embargoes", "embargoed", "embargoing", "embargoed"),
-    "embay" => ("embays", "embayed", "embaying", "embayed"),
-    "embed" => ("embeds", "embedded", "embedding", "embedded"),
-    "embus" => ("embusses", "embussed", "embussing", "embussed"),
-    "emcee" => ("emcees", "emceed", "emceeing", "emceed"),
-    "emit" => ("emits", "emitted", "emitting", "emitted"),
-    "emplot" => ("emplots", "emplotted", "emplotting", "emplotted"),
-    "employ" => ("employs", "employed", "employing", "employed"),
-    "encamp" => ("encamps", "encamped", "encamping", "encamped"),
-    "enclasp" => ("enclasps", "enclasped", "enclasping", "enclasped"),
-    "encoop" => ("encoops", "encooped", "encooping", "encooped"),
-    "encur" => ("encurs", "encurred", "encurring", "encurred"),
-    "endplay" => ("endplays", "endplayed", "endplaying", "endplayed"),
-    "endree" => ("endrees", "endreed", "endreeing", "endreed"),
-    "enfree" => ("enfrees", "enfreed", "enfreeing", "enfreed"),
-    "enfreeze" => ("enfreezes", "enfroze", "enfreezing", "enfrozen"),
-    "englut" => ("engluts", "englutted", "englutting", "englutted"),
-    "engrasp" => ("engrasps", "engrasped", "engrasping", "engrasped"),
-    "enhoney" => ("enhoneys", "enhoneyed", "enhoneying", "enhoneyed"),
-    "enioy" => ("enioys", "enioyed", "enioying", "enioyed"),
-    "enjoy" => ("enjoys", "enjoyed", "enjoying", "enjoyed"),
-    "enknit" => ("enknits", "enknitted", "enknitting", "enknitted"),
-    "enlay" => ("enlays", "enlaid", "enlaying", "enlaid"),
-    "ennui" => ("ennuis", "ennuied", "ennuying", "ennuied"),
-    "enrol" => ("enrols", "enrolled", "enrolling", "enrolled"),
-    "enskin" => ("enskins", "enskinned", "enskinning", "enskinned"),
-    "enstamp" => ("enstamps", "enstamped", "enstamping", "enstamped"),
-    "enstar" => ("enstars", "enstarred", "enstarring", "enstarred"),
-    "ensteep" => ("ensteeps", "ensteeped", "ensteeping", "ensteeped"),
-    "ensweep" => ("ensweeps", "enswept", "ensweeping", "enswept"),
-    "entertake" => ("entertakes", "entertook", "entertaking", "entertaken"),
-    "enthral" => ("enthrals", "enthralled", "enthralling", "enthralled"),
-    "enturban" => ("enturbans", "enturbanned", "enturbanning", "enturbanned"),
-    "entwin" => ("entwins", "entwinned", "entwinning", "entwinned"),
-    "envelop" => ("envelops", "enveloped", "enveloping", "enveloped"),
-    "envie" => ("envies", "envied", "envying", "envied"),
-    "enweave" => ("enweaves", "enwove", "enweaving", "enwoven"),
-    "enwind" => ("enwinds", "enwound", "enwinding", "enwound"),
-    "enwrite" => ("enwrites", "enwrote", "enwriting", "enwritten"),
-    "epoch" => ("epochs", "epoched", "epoching", "epoched"),
-    "er" => ("ers", "erred", "erring", "erred"),
-    "erg" => ("ergs", "erged", "erging", "erged"),
-    "erp" => ("erps", "erped", "erping", "erped"),
-    "escallop" => ("escallops", "escalloped", "escalloping", "escalloped"),
-    "escalop" => ("escalops", "escaloped", "escaloping", "escaloped"),
-    "escarp" => ("escarps", "escarped", "escarping", "escarped"),
-    "escortcel" => ("escortcels", "escortcelled", "escortcelling", "escortcelled"),
-    "essay" => ("essays", "essayed", "essaying", "essayed"),
-    "estray" => ("estrays", "estrayed", "estraying", "estrayed"),
-    "eunuch" => ("eunuchs", "eunuched", "eunuching", "eunuched"),
-    "ew" => ("ews", "ewwed", "ewwing", "ewwed"),
-    "excambie" => ("excambies", "excambied", "excambying", "excambied"),
-    "excel" => ("excels", "excelled", "excelling", "excelled"),
-    "excerp" => ("excerps", "excerped", "excerping", "excerped"),
-    "excur" => ("excurs", "excurred", "excurring", "excurred"),
-    "exemplifie" => ("exemplifies", "exemplified", "exemplifying", "exemplified"),
-    "exeunt" => ("exit", "exeunted", "exeunting", "exeunted"),
-    "expel" => ("expels", "expelled", "expelling", "expelled"),
-    "extirp" => ("extirps", "extirped", "extirping", "extirped"),
-    "extol" => ("extols", "extolled", "extolling", "extolled"),
-    "eye" => ("eyes", "eyed", "eyeing", "eyed"),
-    "eyelet" => ("eyelets", "eyeletted", "eyeletting", "eyeletted"),
-    "fab" => ("fabs", "fabbed", "fabbing", "fabbed"),
-    "fabric" => ("fabrics", "fabricked", "fabricking", "fabricked"),
-    "facesit" => ("facesits", "facesat", "facesitting", "facesat"),
-    "facsimile" => ("facsimiles", "facsimiled", "facsimileing", "facsimiled"),
-    "fakie" => ("fakies", "fakied", "fakieing", "fakied"),
-    "fall" => ("falls", "fell", "falling", "fallen"),
-    "fan" => ("fans", "fanned", "fanning", "fanned"),
-    "fanboy" => ("fanboys", "fanboyed", "fanboying", "fanboyed"),
-    "fancast" => ("fancasts", "fancast", "fancasting", "fancast"),
-    "fandub" => ("fandubs", "fandubbed", "fandubbing", "fandubbed"),
-    "fanfic" => ("fanfics", "fanficced", "fanficcing", "fanficced"),
-    "fansie" => ("fansies", "fansied", "fansying", "fansied"),
-    "fansub" => ("fansubs", "fansubbed", "fansubbing", "fansubbed"),
-    "far" => ("fars", "farred", "farring", "farred"),
-    "farsee" => ("farsees", "farsaw", "farseeing", "farseen"),
-    "farspeak" => ("farspeaks", "farspoke", "farspeaking", "farspoken"),
-    "fasel" => ("fasels", "faselled", "faselling", "faselled"),
-    "fat" => ("fats", "fatted", "fatting", "fatted"),
-    "faultfind" => ("faultfinds", "faultfound", "faultfinding", "faultfound"),
-    "fay" => ("fays", "fayed", "faying", "fayed"),
-    "feal2" => ("feals", "fale", "fealing", "folen"),
-    "featherbed" => ("featherbeds", "featherbedded", "featherbedding", "featherbedded"),
-    "fed" => ("feds", "fedded", "fedding", "fedded"),
-    "fee" => ("fees", "feed", "feeing", "feed"),
-    "feed" => ("feeds", "fed", "feeding", "fed"),
-    "feel" => ("feels", "felt", "feeling", "felt"),
-    "feeze2" => ("feezes", "foze", "feezing", "fozen"),
-    "fellowfeel" => ("fellowfeels", "fellowfelt", "fellowfeeling", "fellowfelt"),
-    "fet" => ("fets", "fet", "fetting", "fet"),
-    "fey" => ("feys", "feyed", "feying", "feyed"),
-    "fib" => ("fibs", "fibbed", "fibbing", "fibbed"),
-    "fid" => ("fids", "fidded", "fidding", "fidded"),
-    "filagree" => ("filagrees", "filagreed", "filagreeing", "filagreed"),
-    "filigree" => ("filigrees", "filigreed", "filigreeing", "filigreed"),
-    "fillip" => ("fillips", "filliped", "filliping", "filliped"),
-    "filmset" => ("filmsets", "filmset", "filmsetting", "filmset"),
-    "fin" => ("fins", "finned", "finning", "finned"),
-    "find" => ("finds", "found", "finding", "found"),
-    "finedraw" => ("finedraws", "finedrew", "finedrawing", "finedrawn"),
-    "fink2" => ("finks", "fought", "finking", "fought"),
-    "fisheye" => ("fisheyes", "fisheyed", "fisheyeing", "fisheyed"),
-    "fistbump" => ("fistbumps", "fistbumped", "fistbumping", "fistbumped"),
-    "fit" => ("fits", "fitted", "fitting", "fitted"),
-    "fix" => ("fixes", "fixt", "fixing", "fixt"),
-    "fiz" => ("fizzes", "fizzed", "fizzing", "fizzed"),
-    "flam" => ("flams", "flammed", "flamming", "flammed"),
-    "flambe" => ("flambes", "flambeed", "flambeing", "flambeed"),
-    "flambee" => ("flambees", "flambeed", "flambeeing", "flambeed"),
-    "flan" => ("flans", "flanned", "flanning", "flanned"),
-    "flashlight" => ("flashlights", "flashlit", "flashlighting", "flashlit"),
-    "flat" => ("flats", "flatted", "flatting", "flatted"),
-    "flatbed" => ("flatbeds", "flatbedded", "flatbedding", "flatbedded"),
-    "flatplan" => ("flatplans", "flatplanned", "flatplanning", "flatplanned"),
-    "flay" => ("flays", "flayed", "flaying", "flayed"),
-    "flea2" => ("fleas", "flead", "fleaing", "flead"),
-    "flee" => ("flees", "fled", "fleeing", "fled"),
-    "fley" => ("fleys", "fleyed", "fleying", "fleyed"),
-    "flimflam" => ("flimflams", "flimflammed", "flimflamming", "flimflammed"),
-    "flimp" => ("flimps", "flimped", "flimping", "flimped"),
-    "fling" => ("flings", "flung", "flinging", "flung"),
-    "flit" => ("flits", "flitted", "flitting", "flitted"),
-    "flob" => ("flobs", "flobbed", "flobbing", "flobbed"),
-    "floc" => ("flocs", "flocced", "floccing", "flocced"),
-    "floodlight" => ("floodlights", "floodlit", "floodlighting", "floodlit"),
-    "floop" => ("floops", "flooped", "flooping", "flooped"),
-    "flub" => ("flubs", "flubbed", "flubbing", "flubbed"),
-    "flump" => ("flumps", "flumped", "flumping", "flumped"),
-    "fly2" => ("flies", "flew", "flying", "flown"),
-    "flyblow" => ("flyblows", "flyblew", "flyblowing", "flyblown"),
-    "flycatch" => ("flycatches", "flycaught", "flycatching", "flycaught"),
-    "fob" => ("fobs", "fobbed", "fobbing", "fobbed"),
-    "foind" => ("foinds", "found", "foinding", "found"),
-    "foodstagram" => ("foodstagrams", "foodstagrammed", "foodstagramming", "foodstagrammed"),
-    "footgun" => ("footguns", "footgunned", "footgunning", "footgunned"),
-    "footpad" => ("footpads", "footpadded", "footpadding", "footpadded"),
-    "foray" => ("forays", "forayed", "foraying", "forayed"),
-    "forban" => ("forbans", "forbanned", "forbanning", "forbanned"),
-    "forbar" => ("forbars", "forbarred", "forbarring", "forbarred"),
-    "forbear" => ("forbears", "forbore", "forbearing", "forborne"),
-    "forbeat" => ("forbeats", "forbeat", "forbeating", "forbeaten"),
-    "forbid" => ("forbids", "forbade", "forbidding", "forbidden"),
-    "forbite" => ("forbites", "forbit", "forbiting", "forbitten"),
-    "forbleed" => ("forbleeds", "forbled", "forbleeding", "forbled"),
-    "forbreak" => ("forbreaks", "forbroke", "forbreaking", "forbroken"),
-    "forburst" => ("forbursts", "forburst", "forbursting", "forburst"),
-    "forcarve" => ("forcarves", "forcorve", "forcarving", "forcorven"),
-    "forcast" => ("forcasts", "forcast", "forcasting", "forcast"),
-    "forcefeed" => ("forcefeeds", "forcefed", "forcefeeding", "forcefed"),
-    "forcleave" => ("forcleaves", "forclove", "forcleaving", "forcloven"),
-    "forcut" => ("forcuts", "forcut", "forcutting", "forcut"),
-    "fordim" => ("fordims", "fordimmed", "fordimming", "fordimmed"),
-    "fordo" => ("fordoes", "fordid", "fordoing", "fordone"),
-    "fordrive" => ("fordrives", "fordrove", "fordriving", "fordriven"),
-    "forebar" => ("forebars", "forebarred", "forebarring", "forebarred"),
-    "forebear" => ("forebears", "forebore", "forebearing", "foreborne"),
-    "forebite" => ("forebites", "forebit", "forebiting", "forebitten"),
-    "forebring" => ("forebrings", "forebrought", "forebringing", "forebrought"),
-    "forecaddie" => ("forecaddies", "forecaddied", "forecaddying", "forecaddied"),
-    "forecast" => ("forecasts", "forecast", "forecasting", "forecast"),
-    "forecatch" => ("forecatches", "forecaught", "forecatching", "forecaught"),
-    "forechoose" => ("forechooses", "forechose", "forechoosing", "forechosen"),
-    "forecome" => ("forecomes", "forecame", "forecoming", "forecome"),
-    "forecut" => ("forecuts", "forecut", "forecutting", "forecut"),
-    "foredecree" => ("foredecrees", "foredecreed", "foredecreeing", "foredecreed"),
-    "foredo" => ("foredoes", "foredid", "foredoing", "foredone"),
-    "foredraw" => ("foredraws", "foredrew", "foredrawing", "foredrawn"),
-    "forefeel" => ("forefeels", "forefelt", "forefeeling", "forefelt"),
-    "foregive" => ("foregives", "foregave", "foregiving", "foregiven"),
-    "forego" => ("foregoes", "forewent", "foregoing", "foregone"),
-    "foregrasp" => ("foregrasps", "foregrasped", "foregrasping", "foregrasped"),
-    "forehear" => ("forehears", "foreheard", "forehearing", "foreheard"),
-    "forehew" => ("forehews", "forehewed", "forehewing", "forehewn"),
-    "forehold" => ("foreholds", "foreheld", "foreholding", "foreheld"),
-    "foreken" => ("forekens", "forekenned", "forekenning", "forekenned"),
-    "foreknow" => ("foreknows", "foreknew", "foreknowing", "foreknown"),
-    "forelay" => ("forelays", "forelaid", "forelaying", "forelaid"),
-    "forelead" => ("foreleads", "foreled", "foreleading", "foreled"),
-    "forelearn" => ("forelearns", "forelearnt", "forelearning", "forelearnt"),
-    "forelend" => ("forelends", "forelent", "forelending", "forelent"),
-    "forelet" => ("forelets", "forelet", "foreletting", "forelet"),
-    "forelie" => ("forelies", "forelay", "forelying", "forelain"),
-    "foremake" => ("foremakes", "foremade", "foremaking", "foremade"),
-    "forepay" => ("forepays", "forepaid", "forepaying", "forepaid"),
-    "foreplan" => ("foreplans", "foreplanned", "foreplanning", "foreplanned"),
-    "foreplay" => ("foreplays", "foreplayed", "foreplaying", "foreplayed"),
-    "foreread" => ("forereads", "foreread", "forereading", "foreread"),
-    "forerun" => ("foreruns", "foreran", "forerunning", "forerun"),
-    "foresay" => ("foresays", "foresaid", "foresaying", "foresaid"),
-    "forescan" => ("forescans", "forescanned", "forescanning", "forescanned"),
-    "foresee" => ("foresees", "foresaw", "foreseeing", "foreseen"),
-    "foreseek" => ("foreseeks", "foresought", "foreseeking", "foresought"),
-    "foresend" => ("foresends", "foresent", "foresending", "foresent"),
-    "foreset" => ("foresets", "foreset", "foresetting", "foreset"),
-    "foreshew" => ("foreshews", "foreshewed", "foreshewing", "foreshewn"),
-    "foreshine" => ("foreshines", "foreshone", "foreshining", "foreshone"),
-    "foreshoot" => ("foreshoots", "foreshot", "foreshooting", "foreshot"),
-    "foreshow" => ("foreshows", "foreshowed", "foreshowing", "foreshown"),
-    "foresing" => ("foresings", "foresang", "foresinging", "foresung"),
-    "foresit" => ("foresits", "foresat", "foresitting", "foresat"),
-    "foreskin" => ("foreskins", "foreskinned", "foreskinning", "foreskinned"),
-    "foreslay" => ("foreslays", "foreslew", "foreslaying", "foreslain"),
-    "foresmell" => ("foresmells", "foresmelt", "foresmelling", "foresmelt"),
-    "forespeak" => ("forespeaks", "forespoke", "forespeaking", "forespoken"),
-    "forespeed" => ("forespeeds", "foresped", "forespeeding", "foresped"),
-    "forespend" => ("forespends", "forespent", "forespending", "forespent"),
-    "forespread" => ("forespreads", "forespread", "forespreading", "forespread"),
-    "forestal" => ("forestals", "forestalled", "forestalling", "forestalled"),
-    "forestand" => ("forestands", "forestood", "forestanding", "forestood"),
-    "forestay" => ("forestays", "forestayed", "forestaying", "forestayed"),
-    "foreswear" => ("foreswears", "foreswore", "foreswearing", "foresworn"),
-    "foretake" => ("foretakes", "foretook", "foretaking", "foretaken"),
-    "foreteach" => ("foreteaches", "foretaught", "foreteaching", "foretaught"),
-    "foretel" => ("foretels", "foretold", "foretelling", "foretold"),
-    "foretell" => ("foretells", "foretold", "foretelling", "foretold"),
-    "forethink" => ("forethinks", "forethought", "forethinking", "forethought"),
-    "foreweep" => ("foreweeps", "forewept", "foreweeping", "forewept"),
-    "forewit" => ("forewot", "forewist", "forewitting", "forewist"),
-    "forewrite" => ("forewrites", "forewrote", "forewriting", "forewritten"),
-    "forgab" => ("forgabs", "forgabbed", "forgabbing", "forgabbed"),
-    "forget" => ("forgets", "forgot", "forgetting", "forgotten"),
-    "forgive" => ("forgives", "forgave", "forgiving", "forgiven"),
-    "forgo" => ("forgoes", "forwent", "forgoing", "forgone"),
-    "forgrow" => ("forgrows", "forgrew", "forgrowing", "forgrown"),
-    "forhele" => ("forheles", "forhole", "forheling", "forholen"),
-    "forhold" => ("forholds", "forheld", "forholding", "forheld"),
-    "forlat" => ("forlats", "forleet", "forlatting", "forlatten"),
-    "forlay" => ("forlays", "forlaid", "forlaying", "forlaid"),
-    "forlead" => ("forleads", "forled", "forleading", "forled"),
-    "forleave" => ("forleaves", "forleft", "forleaving", "forleft"),
-    "forleet" => ("forleets", "forleet", "forleeting", "forleet"),
-    "forlend" => ("forlends", "forlent", "forlending", "forlent"),
-    "forlese" => ("forleses", "forlore", "forlesing", "forlorn"),
-    "forlet" => ("forlets", "forlet", "forletting", "forlet"),
-    "forlie" => ("forlies", "forlay", "forlying", "forlain"),
-    "format" => ("formats", "formatted", "formatting", "formatted"),
-    "formay" => ("formays", "formayed", "formaying", "formayed"),
-    "forncast" => ("forncasts", "forncast", "forncasting", "forncast"),
-    "forray" => ("forrays", "forrayed", "forraying", "forrayed"),
-    "forsake" => ("forsakes", "forsook", "forsaking", "forsaken"),
-    "forsay" => ("forsays", "forsaid", "forsaying", "forsaid"),
-    "forsee" => ("forsees", "forsaw", "forseeing", "forseen"),
-    "forseek" => ("forseeks", "forsought", "forseeking", "forsought"),
-    "forsend" => ("forsends", "forsent", "forsending", "forsent"),
-    "forset" => ("forsets", "forset", "forsetting", "forset"),
-    "forshake" => ("forshakes", "forshook", "forshaking", "forshaken"),
-    "forshut" => ("forshuts", "forshut", "forshutting", "forshut"),
-    "forsing" => ("forsings", "forsang", "forsinging", "forsung"),
-    "forsleep" => ("forsleeps", "forslept", "forsleeping", "forslept"),
-    "forsling" => ("forslings", "forslung", "forslinging", "forslung"),
-    "forsmite" => ("forsmites", "forsmote", "forsmiting", "forsmitten"),
-    "forspan" => ("forspans", "forspanned", "forspanning", "forspanned"),
-    "forspeak" => ("forspeaks", "forspoke", "forspeaking", "forspoken"),
-    "forspend" => ("forspends", "forspent", "forspending", "forspent"),
-    "forspread" => ("forspreads", "forspread", "forspreading", "forspread"),
-    "forstand" => ("forstands", "forstood", "forstanding", "forstood"),
-    "forsteal" => ("forsteals", "forstole", "forstealing", "forstolen"),
-    "forswart" => ("forswarts", "forswart", "forswarting", "forswart"),
-    "forswear" => ("forswears", "forswore", "forswearing", "forsworn"),
-    "forswing" => ("forswings", "forswang", "forswinging", "forswungen"),
-    "forswink" => ("forswinks", "forswank", "forswinking", "forswunken"),
-    "fortake" => ("fortakes", "fortook", "fortaking", "fortaken"),
-    "forteach" => ("forteaches", "fortaught", "forteaching", "fortaught"),
-    "fortear" => ("fortears", "fortore", "fortearing", "fortorn"),
-    "forthbear" => ("forthbears", "forthbore", "forthbearing", "forthborne"),
-    "forthbring" => ("forthbrings", "forthbrought", "forthbringing", "forthbrought"),
-    "forthcast" => ("forthcasts", "forthcast", "forthcasting", "forthcast"),
-    "forthcome" => ("forthcomes", "forthcame", "forthcoming", "forthcome"),
-    "forthcut" => ("forthcuts", "forthcut", "forthcutting", "forthcut"),
-    "forthdraw" => ("forthdraws", "forthdrew", "forthdrawing", "forthdrawn"),
-    "forthgive" => ("forthgives", "forthgave", "forthgiving", "forthgiven"),
-    "forthgo" => ("forthgoes", "forthwent", "forthgoing", "forthgone"),
-    "forthink" => ("forthinks", "forthought", "forthinking", "forthought"),
-    "forthlead" => ("forthleads", "forthled", "forthleading", "forthled"),
-    "forthleap" => ("forthleaps", "forthleapt", "forthleaping", "forthleapt"),
-    "forthput" => ("forthputs", "forthput", "forthputting", "forthput"),
-    "forthrow" => ("forthrows", "forthrew", "forthrowing", "forthrown"),
-    "forthsend" => ("forthsends", "forthsent", "forthsending", "forthsent"),
-    "forthset" => ("forthsets", "forthset", "forthsetting", "forthset"),
-    "forthshow" => ("forthshows", "forthshowed", "forthshowing", "forthshown"),
-    "forthspeak" => ("forthspeaks", "forthspoke", "forthspeaking", "forthspoken"),
-    "forthtell" => ("forthtells", "forthtold", "forthtelling", "forthtold"),
-    "fortread" => ("fortreads", "fortrod", "fortreading", "fortrodden"),
-    "forway" => ("forways", "forwayed", "forwaying", "forwayed"),
-    "forwear" => ("forwears", "forwore", "forwearing", "forworn"),
-    "forweep" => ("forweeps", "forwept", "forweeping", "forwept"),
-    "forworship" => ("forworships", "forworshiped", "forworshiping", "forworshiped"),
-    "foxtrot" => ("foxtrots", "foxtrotted", "foxtrotting", "foxtrotted"),
-    "frab" => ("frabs", "frabbed", "frabbing", "frabbed"),
-    "frac" => ("fracs", "fracced", "fraccing", "fracced"),
-    "frak" => ("fraks", "frakked", "frakking", "frakked"),
-    "fraught" => ("fraughts", "fraught", "fraughting", "fraught"),
-    "fray" => ("frays", "frayed", "fraying", "frayed"),
-    "free" => ("frees", "freed", "freeing", "freed"),
-    "freefall" => ("freefalls", "freefell", "freefalling", "freefallen"),
-    "freefeed" => ("freefeeds", "freefed", "freefeeding", "freefed"),
-    "freep" => ("freeps", "freeped", "freeping", "freeped"),
-    "freeride" => ("freerides", "freerode", "freeriding", "freeridden"),
-    "freerun" => ("freeruns", "freeran", "freerunning", "freerun"),
-    "freewrite" => ("freewrites", "freewrote", "freewriting", "freewritten"),
-    "freeze" => ("freezes", "froze", "freezing", "frozen"),
-    "fresco" => ("frescoes", "frescoed", "frescoing", "frescoed"),
-    "fret" => ("frets", "fretted", "fretting", "fretted"),
-    "fricassee" => ("fricassees", "fricasseed", "fricasseeing", "fricasseed"),
-    "frisbee" => ("frisbees", "frisbeed", "frisbeeing", "frisbeed"),
-    "frit" => ("frits", "fritted", "fritting", "fritted"),
-    "friz" => ("frizzes", "frizzed", "frizzing", "frizzed"),
-    "frob" => ("frobs", "frobbed", "frobbing", "frobbed"),
-    "frogg" => ("froggs", "frogged", "frogging", "frogged"),
-    "frogtie" => ("frogties", "frogtied", "frogtying", "frogtied"),
-    "frolic" => ("frolics", "frolicked", "frolicking", "frolicked"),
-    "frontrun" => ("frontruns", "frontran", "frontrunning", "frontrun"),
-    "frostbite" => ("frostbites", "frostbit", "frostbiting", "frostbitten"),
-    "frot" => ("frots", "frotted", "frotting", "frotted"),
-    "frump" => ("frumps", "frumped", "frumping", "frumped"),
-    "ftp" => ("ftps", "ftped", "ftping", "ftped"),
-    "fub" => ("fubs", "fubbed", "fubbing", "fubbed"),
-    "fugg" => ("fuggs", "fugged", "fugging", "fugged"),
-    "fulfil" => ("fulfils", "fulfilled", "fulfilling", "fulfilled"),
-    "fullbring" => ("fullbrings", "fullbrought", "fullbringing", "fullbrought"),
-    "fulldo" => ("fulldoes", "fulldid", "fulldoing", "fulldone"),
-    "fullfeed" => ("fullfeeds", "fullfed", "fullfeeding", "fullfed"),
-    "fum" => ("fums", "fummed", "fumming", "fummed"),
-    "fun" => ("funs", "funned", "funning", "funned"),
-    "fuq" => ("fuqs", "fuqqed", "fuqqing", "fuqqed"),
-    "fur" => ("furs", "furred", "furring", "furred"),
-    "fusspot" => ("fusspots", "fusspotted", "fusspotting", "fusspotted"),
-    "gab" => ("gabs", "gabbed", "gabbing", "gabbed"),
-    "gad" => ("gads", "gadded", "gadding", "gadded"),
-    "gaf" => ("gaf", "gaf", "gaf", "gaf"),
-    "gainsay" => ("gainsays", "gainsaid", "gainsaying", "gainsaid"),
-    "gainset" => ("gainsets", "gainset", "gainsetting", "gainset"),
-    "gainstand" => ("gainstands", "gainstood", "gainstanding", "gainstood"),
-    "gainstay" => ("gainstays", "gainstayed", "gainstaying", "gainstayed"),
-    "gallop" => ("gallops", "galloped", "galloping", "galloped"),
-    "galop" => ("galops", "galoped", "galoping", "galoped"),
-    "galp" => ("galps", "galped", "galping", "galped"),
-    "gam" => ("gams", "gammed", "gamming", "gammed"),
-    "gammet" => ("gammets", "gammetted", "gammetting", "gammetted"),
-    "gan" => ("gans", "went", "gawn", "gane"),
-    "gangway" => ("gangways", "gangwayed", "gangwaying", "gangwayed"),
-    "gar" => ("gars", "gart", "garring", "gart"),
-    "garlic" => ("garlics", "garlicked", "garlicking", "garlicked"),
-    "garnishee" => ("garnishees", "garnisheed", "garnisheeing", "garnisheed"),
-    "gas" => ("gases", "gassed", "gassing", "gassed"),
-    "gasp" => ("gasps", "gasped", "gasping", "gasped"),
-    "gat" => ("gats", "gatted", "gatting", "gatted"),
-    "gatekeep" => ("gatekeeps", "gatekept", "gatekeeping", "gatekept"),
-    "gateway" => ("gateways", "gatewayed", "gatewaying", "gatewayed"),
-    "gaup" => ("gaups", "gauped", "gauping", "gauped"),
-    "gawp" => ("gawps", "gawped", "gawping", "gawped"),
-    "gay" => ("gays", "gayed", "gaying", "gayed"),
-    "gazump" => ("gazumps", "gazumped", "gazumping", "gazumped"),
-    "gee" => ("gees", "geed", "geeing", "geed"),
-    "gegg" => ("geggs", "gegged", "gegging", "gegged"),
-    "gel" => ("gels", "gelled", "gelling", "gelled"),
-    "gem" => ("gems", "gemmed", "gemming", "gemmed"),
-    "gen" => ("gens", "genned", "genning", "genned"),
-    "genderbend" => ("genderbends", "genderbent", "genderbending", "genderbent"),
-    "genogroup" => ("genogroups", "genogrouped", "genogrouping", "genogrouped"),
-    "geosurvey" => ("geosurveys", "geosurveyed", "geosurveying", "geosurveyed"),
-    "get" => ("gets", "got", "getting", "got"),
-    "ghetto" => ("ghettoes", "ghettoed", "ghettoing", "ghettoed"),
-    "ghillie" => ("ghillies", "ghillied", "ghillying", "ghillied"),
-    "ghostban" => ("ghostbans", "ghostbanned", "ghostbanning", "ghostbanned"),
-    "ghostwrite" => ("ghostwrites", "ghostwrote", "ghostwriting", "ghostwritten"),
-    "gib" => ("gibs", "gibbed", "gibbing", "gibbed"),
-    "giddyap" => ("giddyaps", "giddyaped", "giddyaping", "giddyaped"),
-    "giddyup" => ("giddyups", "giddyuped", "giddyuping", "giddyuped"),
-    "giftsub" => ("giftsubs", "giftsubbed", "giftsubbing", "giftsubbed"),
-    "gigacast" => ("gigacasts", "gigacast", "gigacasting", "gigacast"),
-    "gillie" => ("gillies", "gillied", "gillying", "gillied"),
-    "gillnet" => ("gillnets", "gillnetted", "gillnetting", "gillnetted"),
-    "gimp" => ("gimps", "gimped", "gimping", "gimped"),
-    "gin" => ("gins", "gan", "ginning", "gun"),
-    "gin2" => ("gins", "ginned", "ginning", "ginned"),
-    "gipsey" => ("gipseys", "gipseyed", "gipseying", "gipseyed"),
-    "git" => ("gits", "got", "gitting", "gotten"),
-    "giue" => ("giues", "gaue", "giuing", "giuen"),
-    "give" => ("gives", "gave", "giving", "given"),
-    "glad" => ("glads", "gladded", "gladding", "gladded"),
-    "glam" => ("glams", "glammed", "glamming", "glammed"),
-    "glamp" => ("glamps", "glamped", "glamping", "glamped"),
-    "glee" => ("glees", "gleed", "gleeing", "gleed"),
-    "gley" => ("gleys", "gleyed", "gleying", "gleyed"),
-    "glib" => ("glibs", "glibbed", "glibbing", "glibbed"),
-    "glim" => ("glims", "glimmed", "glimming", "glimmed"),
-    "glob" => ("globs", "globbed", "globbing", "globbed"),
-    "globetrot" => ("globetrots", "globetrotted", "globetrotting", "globetrotted"),
-    "glom" => ("gloms", "glommed", "glomming", "glommed"),
-    "glomp" => ("glomps", "glomped", "glomping", "glomped"),
-    "gloop" => ("gloops", "glooped", "glooping", "glooped"),
-    "glub" => ("glubs", "glubbed", "glubbing", "glubbed"),
-    "glum" => ("glums", "glummed", "glumming", "glummed"),
-    "glump" => ("glumps", "glumped", "glumping", "glumped"),
-    "glut" => ("gluts", "glutted", "glutting", "glutted"),
-    "gnar" => ("gnars", "gnarred", "gnarring", "gnarred"),
-    "go" => ("goes", "went", "going", "gone"),
-    "goalie" => ("goalies", "goalied", "goalieing", "goalied"),
-    "goalkeep" => ("goalkeeps", "goalkept", "goalkeeping", "goalkept"),
-    "goatse" => ("goatses", "goatsed", "goatseing", "goatsed"),
-    "gob" => ("gobs", "gobbed", "gobbing", "gobbed"),
-    "god" => ("gods", "godded", "godding", "godded"),
-    "godcast" => ("godcasts", "godcast", "godcasting", "godcast"),
-    "gollop" => ("gollops", "golloped", "golloping", "golloped"),
-    "goop" => ("goops", "gooped", "gooping", "gooped"),
-    "gossip" => ("gossips", "gossiped", "gossiping", "gossiped"),
-    "got" => ("got", "had", "goting", "had"),
-    "gotsta" => ("gotsta", "gotstaed", "gotstaing", "gotstaed"),
-    "gotta" => ("gotta", "gottaed", "gottaing", "gottaed"),
-    "grab" => ("grabs", "grabbed", "grabbing", "grabbed"),
-    "gralloch" => ("grallochs", "gralloched", "grallochin", "gralloched"),
-    "gralloch2" => ("grallochs", "gralloched", "gralloching", "gralloched"),
-    "gram" => ("grams", "grammed", "gramming", "grammed"),
-    "grasp" => ("grasps", "grasped", "grasping", "grasped"),
-    "gratinee" => ("gratinees", "gratineed", "gratineeing", "gratineed"),
-    "graverob" => ("graverobs", "graverobbed", "graverobbing", "graverobbed"),
-    "gravestand" => ("gravestands", "gravestood", "gravestanding", "gravestood"),
-    "gray" => ("grays", "grayed", "graying", "grayed"),
-    "gree" => ("grees", "greed", "greeing", "greed"),
-    "gren" => ("grens", "grenned", "grenning", "grenned"),
-    "grey" => ("greys", "greyed", "greying", "greyed"),
-    "grid" => ("grids", "gridded", "gridding", "gridded"),
-    "gril" => ("grils", "grilled", "grilling", "grilled"),
-    "grim" => ("grims", "grimmed", "grimming", "grimmed"),
-    "grin" => ("grins", "grinned", "grinning", "grinned"),
-    "grind" => ("grinds", "ground", "grinding", "ground"),
-    "gristbite" => ("gristbites", "gristbit", "gristbiting", "gristbitten"),
-    "grit" => ("grits", "gritted", "gritting", "gritted"),
-    "grizbite" => ("grizbites", "grizbit", "grizbiting", "grizbitten"),
-    "grob" => ("grobs", "grobbed", "grobbing", "grobbed"),
-    "grok" => ("groks", "grokked", "grokking", "grokked"),
-    "groop" => ("groops", "grooped", "grooping", "grooped"),
-    "group" => ("groups", "grouped", "grouping", "grouped"),
-    "grow" => ("grows", "grew", "growing", "grown"),
-    "growe" => ("growes", "grewe", "growing", "growne"),
-    "grub" => ("grubs", "grubbed", "grubbing", "grubbed"),
-    "grump" => ("grumps", "grumped", "grumping", "grumped"),
-    "guarantee" => ("guarantees", "guaranteed", "guaranteeing", "guaranteed"),
-    "gub" => ("gubs", "gubbed", "gubbing", "gubbed"),
-    "guimp" => ("guimps", "guimped", "guimping", "guimped"),
-    "gulag" => ("gulags", "gulaged", "gulaging", "gulaged"),
-    "gulley" => ("gulleys", "gulleyed", "gulleying", "gulleyed"),
-    "gulp" => ("gulps", "gulped", "gulping", "gulped"),
-    "gum" => ("gums", "gummed", "gumming", "gummed"),
-    "gumshoe" => ("gumshoes", "gumshoed", "gumshoeing", "gumshoed"),
-    "gun" => ("guns", "gunned", "gunning", "gunned"),
-    "gurks" => ("gurks", "gurksed", "gurksing", "gurksed"),
-    "gurp" => ("gurps", "gurped", "gurping", "gurped"),
-    "gut" => ("guts", "gutted", "gutting", "gutted"),
-    "gutshoot" => ("gutshoots", "gutshot", "gutshooting", "gutshot"),
-    "guy" => ("guys", "guyed", "guying", "guyed"),
-    "gweep" => ("gweeps", "gweeped", "gweeping", "gweeped"),
-    "gymcel" => ("gymcels", "gymcelled", "gymcelling", "gymcelled"),
-    "gymp" => ("gymps", "gymped", "gymping", "gymped"),
-    "hackney" => ("hackneys", "hackneyed", "hackneying", "hackneyed"),
-    "hafta" => ("hasta", "hadda", "haftaing", "hadda"),
-    "hagride" => ("hagrides", "hagrode", "hagriding", "hagridden"),
-    "haircut" => ("haircuts", "haircutted", "haircutting", "haircutted"),
-    "hairsplit" => ("hairsplits", "hairsplit", "hairsplitting", "hairsplit"),
-    "hairspray" => ("hairsprays", "hairsprayed", "hairspraying", "hairsprayed"),
-    "halal" => ("halals", "halalled", "halalling", "halalled"),
-    "half" => ("halves", "halved", "halving", "halved"),
-    "hallal" => ("hallals", "hallalled", "hallalling", "hallalled"),
-    "halo" => ("haloes", "haloed", "haloing", "haloed"),
-    "halp" => ("halps", "halped", "halping", "halped"),
-    "halterbreak" => ("halterbreaks", "halterbroke", "halterbreaking", "halterbroken"),
-    "ham" => ("hams", "hammed", "hamming", "hammed"),
-    "hamburg" => ("hamburgs", "hamburged", "hamburging", "hamburged"),
-    "hamstring" => ("hamstrings", "hamstrung", "hamstringing", "hamstrung"),
-    "handfeed" => ("handfeeds", "handfed", "handfeeding", "handfed"),
-    "handhold" => ("handholds", "handheld", "handholding", "handheld"),
-    "handknit" => ("handknits", "handknitted", "handknitting", "handknitted"),
-    "handmake" => ("handmakes", "handmade", "handmaking", "handmade"),
-    "handset" => ("handsets", "handset", "handsetting", "handset"),
-    "handstamp" => ("handstamps", "handstamped", "handstamping", "handstamped"),
-    "handstand" => ("handstands", "handstood", "handstanding", "handstood"),
-    "handweave" => ("handweaves", "handwove", "handweaving", "handwoven"),
-    "handwrite" => ("handwrites", "handwrote", "handwriting", "handwritten"),
-    "handyman" => ("handymans", "handymanned", "handymanning", "handymanned"),
-    "hang" => ("hangs", "hung", "hanging", "hung"),
-    "hardsub" => ("hardsubs", "hardsubbed", "hardsubbing", "hardsubbed"),
-    "harp" => ("harps", "harped", "harping", "harped"),
-    "hasp" => ("hasps", "hasped", "hasping", "hasped"),
-    "hat" => ("hats", "hatted", "hatting", "hatted"),
-    "haue" => ("has", "had", "hauing", "had"),
-    "hav" => ("has", "had", "having", "had"),
-    "have" => ("has", "had", "having", "had"),
-    "havoc" => ("havocs", "havocked", "havocking", "havocked"),
-    "hay" => ("hays", "hayed", "haying", "hayed"),
-    "haz" => ("haz", "hazed", "hazing", "hazed"),
-    "headbump" => ("headbumps", "headbumped", "headbumping", "headbumped"),
-    "headshot" => ("headshots", "headshotted", "headshotting", "headshotted"),
-    "headspring" => ("headsprings", "headsprang", "headspringing", "headsprung"),
-    "headstamp" => ("headstamps", "headstamped", "headstamping", "headstamped"),
-    "heap" => ("heaps", "heaped", "heaping", "heaped"),
-    "hear" => ("hears", "heard", "hearing", "heard"),
-    "heimlich" => ("heimlichs", "heimliched", "heimliching", "heimliched"),
-    "hellban" => ("hellbans", "hellbanned", "hellbanning", "hellbanned"),
-    "help" => ("helps", "helped", "helping", "helped"),
-    "hem" => ("hems", "hemmed", "hemming", "hemmed"),
-    "hemingway" => ("hemingways", "hemingwayed", "hemingwaying", "hemingwayed"),
-    "hen" => ("hens", "henned", "henning", "henned"),
-    "hero" => ("heroes", "heroed", "heroing", "heroed"),
-    "herye" => ("heryeth", "heryed", "herying", "heryed"),
-    "het" => ("hets", "hetted", "hetting", "hetted"),
-    "hev" => ("hes", "hed", "heving", "hed"),
-    "hew" => ("hews", "hewed", "hewing", "hewn"),
-    "hey" => ("heys", "heyed", "heying", "heyed"),
-    "hiccup" => ("hiccups", "hiccuped", "hiccuping", "hiccuped"),
-    "hickup" => ("hickups", "hickuped", "hickuping", "hickuped"),
-    "hide2" => ("hides", "hid", "hiding", "hidden"),
-    "hie" => ("hies", "hied", "hying", "hied"),
-    "hight" => ("hights", "hight", "highting", "hight"),
-    "highway" => ("highways", "highwayed", "highwaying", "highwayed"),
-    "hindcast" => ("hindcasts", "hindcast", "hindcasting", "hindcast"),
-    "histoblot" => ("histoblots", "histoblotted", "histoblotting", "histoblotted"),
-    "histogram" => ("histograms", "histogrammed", "histogramming", "histogrammed"),
-    "hit" => ("hits", "hit", "hitting", "hit"),
-    "ho" => ("hoes", "hoed", "hoeing", "hoed"),
-    "hob" => ("hobs", "hobbed", "hobbing", "hobbed"),
-    "hobandnob" => ("hobandnobs", "hobandnobbed", "hobandnobbing", "hobandnobbed"),
-    "hobanob" => ("hobanobs", "hobanobbed", "hobanobbing", "hobanobbed"),
-    "hobday" => ("hobdays", "hobdayed", "hobdaying", "hobdayed"),
-    "hobjob" => ("hobjobs", "hobjobbed", "hobjobbing", "hobjobbed"),
-    "hobnob" => ("hobnobs", "hobnobbed", "hobnobbing", "hobnobbed"),
-    "hockle2" => ("hockles", "hockled", "hocklin", "hockled"),
-    "hod" => ("hods", "hodded", "hodding", "hodded"),
-    "hoe" => ("hoes", "hoed", "hoeing", "hoed"),
-    "hogtie" => ("hogties", "hogtied", "hogtying", "hogtied"),
-    "hoit2" => ("hoits", "hoit", "hoiting", "hoit"),
-    "hold" => ("holds", "held", "holding", "held"),
-    "holiday" => ("holidays", "holidayed", "holidaying", "holidayed"),
-    "hollo" => ("holloes", "holloed", "holloing", "holloed"),
-    "holup" => ("holups", "holuped", "holuping", "holuped"),
-    "homemake" => ("homemakes", "homemade", "homemaking", "homemade"),
-    "homesit" => ("homesits", "homesat", "homesitting", "homesat"),
-    "honey" => ("honeys", "honeyed", "honeying", "honeyed"),
-    "hoop" => ("hoops", "hooped", "hooping", "hooped"),
-    "hooray" => ("hoorays", "hoorayed", "hooraying", "hoorayed"),
-    "horseplay" => ("horseplays", "horseplayed", "horseplaying", "horseplayed"),
-    "horseshoe" => ("horseshoes", "horseshoed", "horseshoeing", "horseshoed"),
-    "horsesit" => ("horsesits", "horsesat", "horsesitting", "horsesat"),
-    "hosey" => ("hoseys", "hoseyed", "hoseying", "hoseyed"),
-    "hot" => ("hots", "hotted", "hotting", "hotted"),
-    "hotkey" => ("hotkeys", "hotkeyed", "hotkeying", "hotkeyed"),
-    "hotshot" => ("hotshots", "hotshotted", "hotshotting", "hotshotted"),
-    "hould" => ("houlds", "held", "houlding", "held"),
-    "housebreak" => ("housebreaks", "housebroke", "housebreaking", "housebroken"),
-    "housekeep" => ("housekeeps", "housekept", "housekeeping", "housekept"),
-    "housesit" => ("housesits", "housesat", "housesitting", "housesat"),
-    "hoy" => ("hoys", "hoyed", "hoying", "hoyed"),
-    "hubbub" => ("hubbubs", "hubbubbed", "hubbubbing", "hubbubbed"),
-    "hum" => ("hums", "hummed", "humming", "hummed"),
-    "humjob" => ("humjobs", "humjobbed", "humjobbing", "humjobbed"),
-    "hump" => ("humps", "humped", "humping", "humped"),
-    "hurray" => ("hurrays", "hurrayed", "hurraying", "hurrayed"),
-    "hurt" => ("hurts", "hurt", "hurting", "hurt"),
-    "hut" => ("huts", "hutted", "hutting", "hutted"),
-    "hx" => ("hxs", "hxed", "hxing", "hxed"),
-    "hyde" => ("hydes", "hyd", "hyding", "hydden"),
-    "hydraulic" => ("hydraulics", "hydraulicked", "hydraulicking", "hydraulicked"),
-    "hydrofrac" => ("hydrofracs", "hydrofracked", "hydrofracking", "hydrofracked"),
-    "hydrojet" => ("hydrojets", "hydrojetted", "hydrojetting", "hydrojetted"),
-    "hyperbleb" => ("hyperblebs", "hyperblebbed", "hyperblebbing", "hyperblebbed"),
-    "hyperdom" => ("hyperdoms", "hyperdommed", "hyperdomming", "hyperdommed"),
-    "hyperwet" => ("hyperwets", "hyperwet", "hyperwetting", "hyperwet"),
-    "ice2" => ("ices", "iced", "iceing", "iced"),
-    "igg" => ("iggs", "igged", "igging", "igged"),
-    "imbar" => ("imbars", "imbarred", "imbarring", "imbarred"),
-    "imbargo" => ("imbargoes", "imbargoed", "imbargoing", "imbargoed"),
-    "imbay" => ("imbays", "imbayed", "imbaying", "imbayed"),
-    "imbed" => ("imbeds", "imbedded", "imbedding", "imbedded"),
-    "imbreed" => ("imbreeds", "imbred", "imbreeding", "imbred"),
-    "imbring" => ("imbrings", "imbrought", "imbringing", "imbrought"),
-    "immit" => ("immits", "immitted", "immitting", "immitted"),
-    "immunoassay" => ("immunoassays", "immunoassayed", "immunoassaying", "immunoassayed"),
-    "immunoblot" => ("immunoblots", "immunoblotted", "immunoblotting", "immunoblotted"),
-    "imp" => ("imps", "imped", "imping", "imped"),
-    "impasto" => ("impastoes", "impastoed", "impastoing", "impastoed"),
-    "impel" => ("impels", "impelled", "impelling", "impelled"),
-    "impen" => ("impens", "impenned", "impenning", "impenned"),
-    "imploy" => ("imploys", "imployed", "imploying", "imployed"),
-    "improv" => ("improvs", "improvved", "improvving", "improvved"),
-    "in" => ("ins", "inned", "inning", "inned"),
-    "inbear" => ("inbears", "inbore", "inbearing", "inborne"),
-    "inbeat" => ("inbeats", "inbeat", "inbeating", "inbeaten"),
-    "inbind" => ("inbinds", "inbound", "inbinding", "inbound"),
-    "inblow" => ("inblows", "inblew", "inblowing", "inblown"),
-    "inbreak" => ("inbreaks", "inbroke", "inbreaking", "inbroken"),
-    "inbreed" => ("inbreeds", "inbred", "inbreeding", "inbred"),
-    "inbring" => ("inbrings", "inbrought", "inbringing", "inbrought"),
-    "inbuild" => ("inbuilds", "inbuilt", "inbuilding", "inbuilt"),
-    "incamp" => ("incamps", "incamped", "incamping", "incamped"),
-    "inclasp" => ("inclasps", "inclasped", "inclasping", "inclasped"),
-    "incoop" => ("incoops", "incooped", "incooping", "incooped"),
-    "increep" => ("increeps", "incrept", "increeping", "incrept"),
-    "incur" => ("incurs", "incurred", "incurring", "incurred"),
-    "indef" => ("indefs", "indeffed", "indeffing", "indeffed"),
-    "indraw" => ("indraws", "indrew", "indrawing", "indrawn"),
-    "ineye" => ("ineyes", "ineyed", "ineyeing", "ineyed"),
-    "infall" => ("infalls", "infell", "infalling", "infallen"),
-    "infer" => ("infers", "inferred", "inferring", "inferred"),
-    "infodump" => ("infodumps", "infodumped", "infodumping", "infodumped"),
-    "inglut" => ("ingluts", "inglutted", "inglutting", "inglutted"),
-    "ingroup" => ("ingroups", "ingrouped", "ingrouping", "ingrouped"),
-    "ingrow" => ("ingrows", "ingrew", "ingrowing", "ingrown"),
-    "inhold" => ("inholds", "inheld", "inholding", "inheld"),
-    "inhoop" => ("inhoops", "inhooped", "inhooping", "inhooped"),
-    "injoy" => ("injoys", "injoyed", "injoying", "injoyed"),
-    "inknit" => ("inknits", "inknitted", "inknitting", "inknitted"),
-    "inknot" => ("inknots", "inknotted", "inknotting", "inknotted"),
-    "inlay" => ("inlays", "inlaid", "inlaying", "inlaid"),
-    "inlead" => ("inleads", "inled", "inleading", "inled"),
-    "inlet" => ("inlets", "inlet", "inletting", "inlet"),
-    "input" => ("inputs", "input", "inputting", "input"),
-    "insculp" => ("insculps", "insculped", "insculping", "insculped"),
-    "insee" => ("insees", "insaw", "inseeing", "inseen"),
-    "insend" => ("insends", "insent", "insending", "insent"),
-    "inset" => ("insets", "inset", "insetting", "inset"),
-    "inspan" => ("inspans", "inspanned", "inspanning", "inspanned"),
-    "inspeak" => ("inspeaks", "inspoke", "inspeaking", "inspoken"),
-    "instaban" => ("instabans", "instabanned", "instabanning", "instabanned"),
-    "instagib" => ("instagibs", "instagibbed", "instagibbing", "instagibbed"),
-    "instagram" => ("instagrams", "instagrammed", "instagramming", "instagrammed"),
-    "instal" => ("instals", "installed", "installing", "installed"),
-    "instamp" => ("instamps", "instamped", "instamping", "instamped"),
-    "instar" => ("instars", "instarred", "instarring", "instarred"),
-    "instasend" => ("instasends", "instasent", "instasending", "instasent"),
-    "insteep" => ("insteeps", "insteeped", "insteeping", "insteeped"),
-    "instil" => ("instils", "instilled", "instilling", "instilled"),
-    "instrew" => ("instrews", "instrewed", "instrewing", "instrewn"),
-    "intake" => ("intakes", "intook", "intaking", "intaken"),
-    "inter" => ("inters", "interred", "interring", "interred"),
-    "interbed" => ("interbeds", "interbedded", "interbedding", "interbedded"),
-    "interbreed" => ("interbreeds", "interbred", "interbreeding", "interbred"),
-    "interbring" => ("interbrings", "interbrought", "interbringing", "interbrought"),
-    "intercom" => ("intercoms", "intercommed", "intercomming", "intercommed"),
-    "intercome" => ("intercomes", "intercame", "intercoming", "intercome"),
-    "intercur" => ("intercurs", "intercurred", "intercurring", "intercurred"),
-    "intercut" => ("intercuts", "intercut", "intercutting", "intercut"),
-    "interdeal" => ("interdeals", "interdealt", "interdealing", "interdealt"),
-    "interdot" => ("interdots", "interdotted", "interdotting", "interdotted"),
-    "intergrind" => ("intergrinds", "interground", "intergrinding", "interground"),
-    "intergrow" => ("intergrows", "intergrew", "intergrowing", "intergrown"),
-    "interknit" => ("interknits", "interknitted", "interknitting", "interknitted"),
-    "interknot" => ("interknots", "interknotted", "interknotting", "interknotted"),
-    "interknow" => ("interknows", "interknew", "interknowing", "interknown"),
-    "interlay" => ("interlays", "interlaid", "interlaying", "interlaid"),
-    "interlend" => ("interlends", "interlent", "interlending", "interlent"),
-    "interlie" => ("interlies", "interlay", "interlying", "interlain"),
-    "intermat" => ("intermats", "intermatted", "intermatting", "intermatted"),
-    "intermit" => ("intermits", "intermitted", "intermitting", "intermitted"),
-    "internet" => ("internets", "internetted", "internetting", "internetted"),
-    "interpel" => ("interpels", "interpelled", "interpelling", "interpelled"),
-    "interplay" => ("interplays", "interplayed", "interplaying", "interplayed"),
-    "interset" => ("intersets", "interset", "intersetting", "interset"),
-    "intershine" => ("intershines", "intershone", "intershining", "intershone"),
-    "intersow" => ("intersows", "intersowed", "intersowing", "intersown"),
-    "interthink" => ("interthinks", "interthought", "interthinking", "interthought"),
-    "interweave" => ("interweaves", "interwove", "interweaving", "interwoven"),
-    "interwed" => ("interweds", "interwedded", "interwedding", "interwedded"),
-    "interwind" => ("interwinds", "interwound", "interwinding", "interwound"),
-    "inthral" => ("inthrals", "inthralled", "inthralling", "inthralled"),
-    "inthrow" => ("inthrows", "inthrew", "inthrowing", "inthrown"),
-    "inthrust" => ("inthrusts", "inthrust", "inthrusting", "inthrust"),
-    "intromit" => ("intromits", "intromitted", "intromitting", "intromitted"),
-    "inweave" => ("inweaves", "inwove", "inweaving", "inwoven"),
-    "inwind" => ("inwinds", "inwound", "inwinding", "inwound"),
-    "inwork" => ("inworks", "inwrought", "inworking", "inwrought"),
-    "inwrite" => ("inwrites", "inwrote", "inwriting", "inwritten"),
-    "ixnay" => ("ixnays", "ixnayed", "ixnaying", "ixnayed"),
-    "jab" => ("jabs", "jabbed", "jabbing", "jabbed"),
-    "jackpot" => ("jackpots", "jackpotted", "jackpotting", "jackpotted"),
-    "jailbreak" => ("jailbreaks", "jailbroke", "jailbreaking", "jailbroken"),
-    "jam" => ("jams", "jammed", "jamming", "jammed"),
-    "jambok" => ("jamboks", "jambokked", "jambokking", "jambokked"),
-    "jamboree" => ("jamborees", "jamboreed", "jamboreeing", "jamboreed"),
-    "janney" => ("janneys", "janneyed", "janneying", "janneyed"),
-    "japan" => ("japans", "japanned", "japanning", "japanned"),
-    "jar" => ("jars", "jarred", "jarring", "jarred"),
-    "jarp" => ("jarps", "jarped", "jarping", "jarped"),
-    "jaup" => ("jaups", "jauped", "jauping", "jauped"),
-    "jayrun" => ("jayruns", "jayran", "jayrunning", "jayrun"),
-    "jee" => ("jees", "jeed", "jeeing", "jeed"),
-    "jeep" => ("jeeps", "jeeped", "jeeping", "jeeped"),
-    "jerrybuild" => ("jerrybuilds", "jerrybuilt", "jerrybuilding", "jerrybuilt"),
-    "jet" => ("jets", "jetted", "jetting", "jetted"),
-    "jetset" => ("jetsets", "jetsetted", "jetsetting", "jetsetted"),
-    "jib" => ("jibs", "jibbed", "jibbing", "jibbed"),
-    "jim" => ("jims", "jimmed", "jimming", "jimmed"),
-    "jimjam" => ("jimjams", "jimjammed", "jimjamming", "jimjammed"),
-    "jit2" => ("jits", "jitted", "jitting", "jitted"),
-    "job" => ("jobs", "jobbed", "jobbing", "jobbed"),
-    "jockey" => ("jockeys", "jockeyed", "jockeying", "jockeyed"),
-    "jogtrot" => ("jogtrots", "jogtrotted", "jogtrotting", "jogtrotted"),
-    "jol" => ("jols", "jolled", "jolling", "jolled"),
-    "jolley" => ("jolleys", "jolleyed", "jolleying", "jolleyed"),
-    "jooj" => ("joojes", "joojed", "joojing", "joojed"),
-    "joozh" => ("joozhes", "joozhed", "joozhing", "joozhed"),
-    "jot" => ("jots", "jotted", "jotting", "jotted"),
-    "journey" => ("journeys", "journeyed", "journeying", "journeyed"),
-    "joy" => ("joys", "joyed", "joying", "joyed"),
-    "joyride" => ("joyrides", "joyrode", "joyriding", "joyridden"),
-    "jpeg2" => ("jpegs", "jpeged", "jpeging", "jpeged"),
-    "judg" => ("judges", "judged", "judging", "judged"),
-    "jugg" => ("juggs", "jugged", "jugging", "jugged"),
-    "juj" => ("jujes", "jujed", "jujing", "jujed"),
-    "jump" => ("jumps", "jumped", "jumping", "jumped"),
-    "jumpcut" => ("jumpcuts", "jumpcut", "jumpcutting", "jumpcut"),
-    "justifie" => ("justifies", "justified", "justifying", "justified"),
-    "jut" => ("juts", "jutted", "jutting", "jutted"),
-    "juzh" => ("juzhes", "juzhed", "juzhing", "juzhed"),
-    "kabob" => ("kabobs", "kabobbed", "kabobbing", "kabobbed"),
-    "kak" => ("kaks", "kakked", "kakking", "kakked"),
-    "kamikaze" => ("kamikazes", "kamikazed", "kamikazeing", "kamikazed"),
-    "kanye" => ("kanyes", "kanyed", "kanyeing", "kanyed"),
-    "kape" => ("kapes", "kapt", "kaping", "kapt"),
-    "karaoke" => ("karaokes", "karaoked", "karaokeing", "karaoked"),
-    "karate" => ("karates", "karated", "karateing", "karated"),
-    "keb" => ("kebs", "kebbed", "kebbing", "kebbed"),
-    "kebab" => ("kebabs", "kebabbed", "kebabbing", "kebabbed"),
-    "kebob" => ("kebobs", "kebobbed", "kebobbing", "kebobbed"),
-    "keep" => ("keeps", "kept", "keeping", "kept"),
-    "kek" => ("keks", "kekked", "kekking", "kekked"),
-    "keke" => ("kekes", "keked", "kekeing", "keked"),
-    "kelp" => ("kelps", "kelped", "kelping", "kelped"),
-    "kemp" => ("kemps", "kemped", "kemping", "kemped"),
-    "ken" => ("kens", "kenned", "kenning", "kenned"),
-    "kerblam" => ("kerblams", "kerblammed", "kerblamming", "kerblammed"),
-    "kest" => ("kests", "kest", "kesting", "kest"),
-    "key" => ("keys", "keyed", "keying", "keyed"),
-    "kickban" => ("kickbans", "kickbanned", "kickbanning", "kickbanned"),
-    "kicksled" => ("kicksleds", "kicksledded", "kicksledding", "kicksledded"),
-    "kid" => ("kids", "kidded", "kidding", "kidded"),
-    "kin" => ("kins", "kinned", "kinning", "kinned"),
-    "kingmake" => ("kingmakes", "kingmade", "kingmaking", "kingmade"),
-    "kit" => ("kits", "kitted", "kitting", "kitted"),
-    "klomp" => ("klomps", "klomped", "klomping", "klomped"),
-    "knab" => ("knabs", "knabbed", "knabbing", "knabbed"),
-    "knaw2" => ("knaws", "knawed", "knawing", "knawn"),
-    "knee" => ("knees", "kneed", "kneeing", "kneed"),
-    "kneel" => ("kneels", "knelt", "kneeling", "knelt"),
-    "knit" => ("knits", "knit", "knitting", "knit"),
-    "knob" => ("knobs", "knobbed", "knobbing", "knobbed"),
-    "knot" => ("knots", "knotted", "knotting", "knotted"),
-    "know" => ("knows", "knew", "knowing", "known"),
-    "kris" => ("kriss", "krised", "krising", "krised"),
-    "ks" => ("kss", "ksed", "ksing", "ksed"),
-    "kurvey" => ("kurveys", "kurveyed", "kurveying", "kurveyed"),
-    "lackey" => ("lackeys", "lackeyed", "lackeying", "lackeyed"),
-    "lacquey" => ("lacqueys", "lacqueyed", "lacqueying", "lacqueyed"),
-    "lade" => ("lades", "laded", "lading", "laden"),
-    "lam" => ("lams", "lammed", "lamming", "lammed"),
-    "lamp" => ("lamps", "lamped", "lamping", "lamped"),
-    "landgrab" => ("landgrabs", "landgrabbed", "landgrabbing", "landgrabbed"),
-    "landslide" => ("landslides", "landslid", "landsliding", "landslid"),
-    "larn" => ("larns", "larned", "larnin", "larned"),
-    "larp" => ("larps", "larped", "larping", "larped"),
-    "larrup" => ("larrups", "larruped", "larruping", "larruped"),
-    "lasertypeset" => ("lasertypesets", "lasertypeset", "lasertypesetting", "lasertypeset"),
-    "lasso" => ("lassoes", "lassoed", "lassoing", "lassoed"),
-    "lasthit" => ("lasthits", "lasthit", "lasthitting", "lasthit"),
-    "latchkey" => ("latchkeys", "latchkeyed", "latchkeying", "latchkeyed"),
-    "laup" => ("laups", "lauped", "lauping", "lauped"),
-    "lavacast" => ("lavacasts", "lavacast", "lavacasting", "lavacast"),
-    "lawmake" => ("lawmakes", "lawmade", "lawmaking", "lawmade"),
-    "lay" => ("lays", "laid", "laying", "laid"),
-    "layaway" => ("layaways", "layawayed", "layawaying", "layawayed"),
-    "lead2" => ("leads", "led", "leading", "led"),
-    "leap" => ("leaps", "leaped", "leaping", "leaped"),
-    "leaue" => ("leaues", "left", "leauing", "left"),
-    "leave2" => ("leaves", "left", "leaving", "left"),
-    "leeroy" => ("leeroys", "leeroyed", "leeroying", "leeroyed"),
-    "leese2" => ("leeses", "lore", "leesing", "lorn"),
-    "lek" => ("leks", "lekked", "lekking", "lekked"),
-    "lend" => ("lends", "lent", "lending", "lent"),
-    "lepay" => ("lepays", "lepayed", "lepaying", "lepayed"),
-    "lern" => ("lerns", "lernt", "lerning", "lernt"),
-    "lerp" => ("lerps", "lerped", "lerping", "lerped"),
-    "lese" => ("leses", "lore", "lesing", "lorn"),
-    "let" => ("lets", "let", "letting", "let"),
-    "let2" => ("lets", "letted", "letting", "let"),
-    "levee" => ("levees", "leveed", "leveeing", "leveed"),
-    "lewinsky" => ("lewinskys", "lewinskyed", "lewinskying", "lewinskyed"),
-    "lib" => ("libs", "libbed", "libbing", "libbed"),
-    "lid" => ("lids", "lidded", "lidding", "lidded"),
-    "lie" => ("lies", "lay", "lying", "lain"),
-    "lie2" => ("lies", "lied", "lying", "lied"),
-    "light2" => ("lights", "lit", "lighting", "lit"),
-    "limbec" => ("limbecs", "limbecked", "limbecking", "limbecked"),
-    "limp" => ("limps", "lamp", "limping", "lump"),
-    "limp2" => ("limps", "limped", "limping", "limped"),
-    "lin" => ("lins", "linned", "linning", "linned"),
-    "linchpin" => ("linchpins", "linchpinned", "linchpinning", "linchpinned"),
-    "linebreed" => ("linebreeds", "linebred", "linebreeding", "linebred"),
-    "lipread" => ("lipreads", "lipread", "lipreading", "lipread"),
-    "lipsynch" => ("lipsynchs", "lipsynched", "lipsynching", "lipsynched"),
-    "lisp" => ("lisps", "lisped", "lisping", "lisped"),
-    "list2" => ("lists", "list", "listing", "list"),
-    "lit" => ("lits", "litted", "litting", "litted"),
-    "livecast" => ("livecasts", "livecast", "livecasting", "livecast"),
-    "livechat" => ("livechats", "livechatted", "livechatting", "livechatted"),
-    "livescan" => ("livescans", "livescanned", "livescanning", "livescanned"),
-    "lob" => ("lobs", "lobbed", "lobbing", "lobbed"),
-    "lobbycon" => ("lobbycons", "lobbyconned", "lobbyconning", "lobbyconned"),
-    "loc2" => ("locs", "locced", "loccing", "locced"),
-    "logic" => ("logics", "logicked", "logicking", "logicked"),
-    "logjam" => ("logjams", "logjammed", "logjamming", "logjammed"),
-    "lollop" => ("lollops", "lolloped", "lolloping", "lolloped"),
-    "lollpoop" => ("lollpoops", "lollpooped", "lollpooping", "lollpooped"),
-    "lolmao" => ("lolmaoes", "lolmaoed", "lolmaoing", "lolmaoed"),
-    "longe" => ("longes", "longed", "longeing", "longed"),
-    "looksee" => ("looksees", "looksaw", "lookseeing", "lookseen"),
-    "loop" => ("loops", "looped", "looping", "looped"),
-    "lose" => ("loses", "lost", "losing", "lost"),
-    "lot" => ("lots", "lotted", "lotting", "lotted"),
-    "lowp" => ("lowps", "lowpt", "lowping", "lowpt"),
-    "luigi2" => ("luigis", "luigied", "luiging", "luigied"),
-    "lump" => ("lumps", "lumped", "lumping", "lumped"),
-    "luv" => ("luvz", "luvd", "luving", "luvd"),
-    "lye" => ("lyes", "lay", "lying", "lain"),
-    "lye2" => ("lyes", "lyed", "lyeing", "lyed"),
-    "lynchpin" => ("lynchpins", "lynchpinned", "lynchpinning", "lynchpinned"),
-    "mab" => ("mabs", "mabbed", "mabbing", "mabbed"),
-    "machete" => ("machetes", "macheted", "macheteing", "macheted"),
-    "machinegun" => ("machineguns", "machinegunned", "machinegunning", "machinegunned"),
-    "macrame" => ("macrames", "macrameed", "macrameing", "macrameed"),
-    "mad" => ("mads", "madded", "madding", "madded"),
-    "magdump" => ("magdumps", "magdumped", "magdumping", "magdumped"),
-    "magic" => ("magics", "magicked", "magicking", "magicked"),
-    "magpie" => ("magpies", "magpied", "magpieing", "magpied"),
-    "mailgram" => ("mailgrams", "mailgrammed", "mailgramming", "mailgrammed"),
-    "mailshot" => ("mailshots", "mailshotted", "mailshotting", "mailshotted"),
-    "mainswear" => ("mainswears", "mainswore", "mainswearing", "mainsworn"),
-    "mak" => ("maks", "makked", "makkin", "makked"),
-    "make" => ("makes", "made", "making", "made"),
-    "makeup" => ("makeups", "makeuped", "makeuping", "makeuped"),
-    "malemploy" => ("malemploys", "malemployed", "malemploying", "malemployed"),
-    "mamaguy" => ("mamaguys", "mamaguyed", "mamaguying", "mamaguyed"),
-    "man" => ("mans", "manned", "manning", "manned"),
-    "mandril" => ("mandrils", "mandrilled", "mandrilling", "mandrilled"),
-    "mango" => ("mangoes", "mangoed", "mangoing", "mangoed"),
-    "mankeep" => ("mankeeps", "mankept", "mankeeping", "mankept"),
-    "mansplay" => ("mansplays", "mansplayed", "mansplaying", "mansplayed"),
-    "manspread" => ("manspreads", "manspread", "manspreading", "manspread"),
-    "manswear" => ("manswears", "manswore", "manswearing", "mansworn"),
-    "manumit" => ("manumits", "manumitted", "manumitting", "manumitted"),
-    "mar" => ("mars", "marred", "marring", "marred"),
-    "marcel" => ("marcels", "marcelled", "marcelling", "marcelled"),
-    "marcot" => ("marcots", "marcotted", "marcotting", "marcotted"),
-    "marquee" => ("marquees", "marqueed", "marqueeing", "marqueed"),
-    "marzipan" => ("marzipans", "marzipanned", "marzipanning", "marzipanned"),
-    "massacree" => ("massacrees", "massacreed", "massacreeing", "massacreed"),
-    "masterplan" => ("masterplans", "masterplanned", "masterplanning", "masterplanned"),
-    "mat" => ("mats", "matted", "matting", "matted"),
-    "matchmake" => ("matchmakes", "matchmade", "matchmaking", "matchmade"),
-    "matinee" => ("matinees", "matineed", "matineeing", "matineed"),
-    "may" => ("may", "might", "maying", "might"),
-    "may2" => ("mays", "mayed", "maying", "mayed"),
-    "mean" => ("means", "meant", "meaning", "meant"),
-    "meatpuppet" => ("meatpuppets", "meatpuppetted", "meatpuppetting", "meatpuppetted"),
-    "meatride" => ("meatrides", "meatrode", "meatriding", "meatridden"),
-    "med" => ("meds", "medded", "medding", "medded"),
-    "medley" => ("medleys", "medleyed", "medleying", "medleyed"),
-    "meean" => ("meeans", "meeant", "meeaning", "meeant"),
-    "meep" => ("meeps", "meeped", "meeping", "meeped"),
-    "meet" => ("meets", "met", "meeting", "met"),
-    "megacast" => ("megacasts", "megacast", "megacasting", "megacast"),
-    "melee" => ("melees", "meleed", "meleeing", "meleed"),
-    "meme" => ("memes", "memed", "memeing", "memed"),
-    "merrimake" => ("merrimakes", "merrimade", "merrimaking", "merrimade"),
-    "merrymake" => ("merrymakes", "merrymade", "merrymaking", "merrymade"),
-    "metric" => ("metrics", "metricked", "metricking", "metricked"),
-    "mickey" => ("mickeys", "mickeyed", "mickeying", "mickeyed"),
-    "microalloy" => ("microalloys", "microalloyed", "microalloying", "microalloyed"),
-    "microassay" => ("microassays", "microassayed", "microassaying", "microassayed"),
-    "microdot" => ("microdots", "microdotted", "microdotting", "microdotted"),
-    "microprogram" => ("microprograms", "microprogrammed", "microprogramming", "microprogrammed"),
-    "microteach" => ("microteaches", "microtaught", "microteaching", "microtaught"),
-    "microtrepan" => ("microtrepans", "microtrepanned", "microtrepanning", "microtrepanned"),
-    "middleman" => ("middlemans", "middlemanned", "middlemanning", "middlemanned"),
-    "might" => ("might", "might", "mighting", "might"),
-    "mimic" => ("mimics", "mimicked", "mimicking", "mimicked"),
-    "mimp" => ("mimps", "mimped", "mimping", "mimped"),
-    "min" => ("mins", "minned", "minning", "minned"),
-    "minac" => ("minacs", "minacked", "minacking", "minacked"),
-    "mindread" => ("mindreads", "mindread", "mindreading", "mindread"),
-    "minicab" => ("minicabs", "minicabbed", "minicabbing", "minicabbed"),
-    "minimod" => ("minimods", "minimodded", "minimodding", "minimodded"),
-    "minivan" => ("minivans", "minivanned", "minivanning", "minivanned"),
-    "misagree" => ("misagrees", "misagreed", "misagreeing", "misagreed"),
-    "misallot" => ("misallots", "misallotted", "misallotting", "misallotted"),
-    "misbear" => ("misbears", "misbore", "misbearing", "misborne"),
-    "misbeat" => ("misbeats", "misbeat", "misbeating", "misbeaten"),
-    "misbecome" => ("misbecomes", "misbecame", "misbecoming", "misbecome"),
-    "misbefall" => ("misbefalls", "misbefell", "misbefalling", "misbefallen"),
-    "misbeget" => ("misbegets", "misbegot", "misbegetting", "misbegotten"),
-    "misbid" => ("misbids", "misbid", "misbidding", "misbidden"),
-    "misbind" => ("misbinds", "misbound", "misbinding", "misbound"),
-    "misblow" => ("misblows", "misblew", "misblowing", "misblown"),
-    "misbreed" => ("misbreeds", "misbred", "misbreeding", "misbred"),
-    "misbuild" => ("misbuilds", "misbuilt", "misbuilding", "misbuilt"),
-    "miscast" => ("miscasts", "miscast", "miscasting", "miscast"),
-    "miscatalog" => ("miscatalogs", "miscataloged", "miscataloging", "miscataloged"),
-    "miscatch" => ("miscatches", "miscaught", "miscatching", "miscaught"),
-    "mischoose" => ("mischooses", "mischose", "mischoosing", "mischosen"),
-    "miscleave" => ("miscleaves", "miscleft", "miscleaving", "miscleft"),
-    "miscome" => ("miscomes", "miscame", "miscoming", "miscome"),
-    "miscommit" => ("miscommits", "miscommitted", "miscommitting", "miscommitted"),
-    "miscontrol" => ("miscontrols", "miscontrolled", "miscontrolling", "miscontrolled"),
-    "misconvey" => ("misconveys", "misconveyed", "misconveying", "misconveyed"),
-    "miscut" => ("miscuts", "miscut", "miscutting", "miscut"),
-    "misdeal" => ("misdeals", "misdealt", "misdealing", "misdealt"),
-    "misdevelop" => ("misdevelops", "misdeveloped", "misdeveloping", "misdeveloped"),
-    "misdiagram" => ("misdiagrams", "misdiagrammed", "misdiagramming", "misdiagrammed"),
-    "misdig" => ("misdigs", "misdug", "misdigging", "misdug"),
-    "misdisplay" => ("misdisplays", "misdisplayed", "misdisplaying", "misdisplayed"),
-    "misdo" => ("misdoes", "misdid", "misdoing", "misdone"),
-    "misdraw" => ("misdraws", "misdrew", "misdrawing", "misdrawn"),
-    "misdrive" => ("misdrives", "misdrove", "misdriving", "misdriven"),
-    "misdub" => ("misdubs", "misdubbed", "misdubbing", "misdubbed"),
-    "misdump" => ("misdumps", "misdumped", "misdumping", "misdumped"),
-    "miseat" => ("miseats", "misate", "miseating", "miseaten"),
-    "misemploy" => ("misemploys", "misemployed", "misemploying", "misemployed"),
-    "misenrol" => ("misenrols", "misenrolled", "misenrolling", "misenrolled"),
-    "misfall" => ("misfalls", "misfell", "misfalling", "misfallen"),
-    "misfeed" => ("misfeeds", "misfed", "misfeeding", "misfed"),
-    "misfeel" => ("misfeels", "misfelt", "misfeeling", "misfelt"),
-    "misfit" => ("misfits", "misfitted", "misfitting", "misfitted"),
-    "misforecast" => ("misforecasts", "misforecast", "misforecasting", "misforecast"),
-    "misforgive" => ("misforgives", "misforgave", "misforgiving", "misforgiven"),
-    "misformat" => ("misformats", "misformatted", "misformatting", "misformatted"),
-    "misget" => ("misgets", "misgot", "misgetting", "misgotten"),
-    "misgive" => ("misgives", "misgave", "misgiving", "misgiven"),
-    "misgo" => ("misgoes", "miswent", "misgoing", "misgone"),
-    "misgrab" => ("misgrabs", "misgrabbed", "misgrabbing", "misgrabbed"),
-    "misgrasp" => ("misgrasps", "misgrasped", "misgrasping", "misgrasped"),
-    "misgrind" => ("misgrinds", "misground", "misgrinding", "misground"),
-    "misgroup" => ("misgroups", "misgrouped", "misgrouping", "misgrouped"),
-    "misgrow" => ("misgrows", "misgrew", "misgrowing", "misgrown"),
-    "mishang" => ("mishangs", "mishung", "mishanging", "mishung"),
-    "mishear" => ("mishears", "misheard", "mishearing", "misheard"),
-    "mishit" => ("mishits", "mishit", "mishitting", "mishit"),
-    "mishold" => ("misholds", "misheld", "misholding", "misheld"),
-    "misinfer" => ("misinfers", "misinferred", "misinferring", "misinferred"),
-    "misinput" => ("misinputs", "misinputted", "misinputting", "misinputted"),
-    "miskeep" => ("miskeeps", "miskept", "miskeeping", "miskept"),
-    "misken" => ("miskens", "miskenned", "miskenning", "miskenned"),
-    "miskey" => ("miskeys", "miskeyed", "miskeying", "miskeyed"),
-    "misknit" => ("misknits", "misknit", "misknitting", "misknit"),
-    "misknot" => ("misknots", "misknotted", "misknotting", "misknotted"),
-    "misknow" => ("misknows", "misknew", "misknowing", "misknown"),
-    "mislay" => ("mislays", "mislaid", "mislaying", "mislaid"),
-    "mislead" => ("misleads", "misled", "misleading", "misled"),
-    "mislie" => ("mislies", "mislay", "mislying", "mislain"),
-    "mismake" => ("mismakes", "mismade", "mismaking", "mismade"),
-    "mismean" => ("mismeans", "mismeant", "mismeaning", "mismeant"),
-    "mismeet" => ("mismeets", "mismet", "mismeeting", "mismet"),
-    "misobey" => ("misobeys", "misobeyed", "misobeying", "misobeyed"),
-    "mispay" => ("mispays", "mispaid", "mispaying", "mispaid"),
-    "mispen" => ("mispens", "mispenned", "mispenning", "mispenned"),
-    "mispin" => ("mispins", "mispinned", "mispinning", "mispinned"),
-    "misplan" => ("misplans", "misplanned", "misplanning", "misplanned"),
-    "misplay" => ("misplays", "misplayed", "misplaying", "misplayed"),
-    "misplead" => ("mispleads", "mispled", "mispleading", "mispled"),
-    "misplot" => ("misplots", "misplotted", "misplotting", "misplotted"),
-    "misportray" => ("misportrays", "misportrayed", "misportraying", "misportrayed"),
-    "misprogram" => ("misprograms", "misprogrammed", "misprogramming", "misprogrammed"),
-    "mispump" => ("mispumps", "mispumped", "mispumping", "mispumped"),
-    "misput" => ("misputs", "misput", "misputting", "misput"),
-    "misread" => ("misreads", "misread", "misreading", "misread"),
-    "misrefer" => ("misrefers", "misreferred", "misreferring", "misreferred"),
-    "misrelay" => ("misrelays", "misrelayed", "misrelaying", "misrelayed"),
-    "misride" => ("misrides", "misrode", "misriding", "misridden"),
-    "misring" => ("misrings", "misrang", "misringing", "misrung"),
-    "misrun" => ("misruns", "misran", "misrunning", "misrun"),
-    "missay" => ("missays", "missaid", "missaying", "missaid"),
-    "misscan" => ("misscans", "misscanned", "misscanning", "misscanned"),
-    "missee" => ("missees", "missaw", "misseeing", "misseen"),
-    "misseek" => ("misseeks", "missought", "misseeking", "missought"),
-    "missell" => ("missells", "missold", "misselling", "missold"),
-    "missend" => ("missends", "missent", "missending", "missent"),
-    "misset" => ("missets", "misset", "missetting", "misset"),
-    "missew" => ("missews", "missewed", "missewing", "missewn"),
-    "misshoe" => ("misshoes", "misshoed", "misshoeing", "misshoed"),
-    "misshoot" => ("misshoots", "misshot", "misshooting", "misshot"),
-    "misspeak" => ("misspeaks", "misspoke", "misspeaking", "misspoken"),
-    "misspeed" => ("misspeeds", "missped", "misspeeding", "missped"),
-    "misspell" => ("misspells", "misspelt", "misspelling", "misspelt"),
-    "misspend" => ("misspends", "misspent", "misspending", "misspent"),
-    "misspin" => ("misspins", "misspun", "misspinning", "misspun"),
-    "misspit" => ("misspits", "misspat", "misspitting", "misspat"),
-    "missplit" => ("missplits", "missplit", "missplitting", "missplit"),
-    "misspot" => ("misspots", "misspotted", "misspotting", "misspotted"),
-    "misspray" => ("missprays", "missprayed", "misspraying", "missprayed"),
-    "misspread" => ("misspreads", "misspread", "misspreading", "misspread"),
-    "misstamp" => ("misstamps", "misstamped", "misstamping", "misstamped"),
-    "misstay" => ("misstays", "misstayed", "misstaying", "misstayed"),
-    "misstrike" => ("misstrikes", "misstruck", "misstriking", "misstruck"),
-    "misstring" => ("misstrings", "misstrung", "misstringing", "misstrung"),
-    "missubmit" => ("missubmits", "missubmitted", "missubmitting", "missubmitted"),
-    "missurvey" => ("missurveys", "missurveyed", "missurveying", "missurveyed"),
-    "missway" => ("missways", "misswayed", "misswaying", "misswayed"),
-    "misswear" => ("misswears", "misswore", "misswearing", "missworn"),
-    "misswing" => ("misswings", "misswung", "misswinging", "misswung"),
-    "mistake" => ("mistakes", "mistook", "mistaking", "mistaken"),
-    "misteach" => ("misteaches", "mistaught", "misteaching", "mistaught"),
-    "mistell" => ("mistells", "mistold", "mistelling", "mistold"),
-    "misthink" => ("misthinks", "misthought", "misthinking", "misthought"),
-    "misthrow" => ("misthrows", "misthrew", "misthrowing", "misthrown"),
-    "mistie" => ("misties", "mistied", "mistying", "mistied"),
-    "mistransfer" => ("mistransfers", "mistransferred", "mistransferring", "mistransferred"),
-    "mistransmit" => ("mistransmits", "mistransmitted", "mistransmitting", "mistransmitted"),
-    "mistrim" => ("mistrims", "mistrimmed", "mistrimming", "mistrimmed"),
-    "misunderstand" => ("misunderstands", "misunderstood", "misunderstanding", "misunderstood"),
-    "miswear" => ("miswears", "miswore", "miswearing", "misworn"),
-    "misweave" => ("misweaves", "miswove", "misweaving", "miswoven"),
-    "miswed" => ("misweds", "miswed", "miswedding", "miswed"),
-    "miswend" => ("miswends", "miswent", "miswending", "miswent"),
-    "miswind" => ("miswinds", "miswound", "miswinding", "miswound"),
-    "misworship" => ("misworships", "misworshiped", "misworshiping", "misworshiped"),
-    "miswrite" => ("miswrites", "miswrote", "miswriting", "miswritten"),
-    "mob" => ("mobs", "mobbed", "mobbing", "mobbed"),
-    "mobcast" => ("mobcasts", "mobcast", "mobcasting", "mobcast"),
-    "mod" => ("mods", "modded", "modding", "modded"),
-    "moleskin" => ("moleskins", "moleskinned", "moleskinning", "moleskinned"),
-    "mollifie" => ("mollifies", "mollified", "mollifying", "mollified"),
-    "mom" => ("moms", "mommed", "momming", "mommed"),
-    "monkey" => ("monkeys", "monkeyed", "monkeying", "monkeyed"),
-    "monocrop" => ("monocrops", "monocroped", "monocroping", "monocroped"),
-    "monogram" => ("monograms", "monogrammed", "monogramming", "monogrammed"),
-    "monolog" => ("monologs", "monologed", "monologing", "monologed"),
-    "moonrun" => ("moonruns", "moonran", "moonrunning", "moonran"),
-    "moop" => ("moops", "mooped", "mooping", "mooped"),
-    "mosaic" => ("mosaics", "mosaicked", "mosaicking", "mosaicked"),
-    "mosey" => ("moseys", "moseyed", "moseying", "moseyed"),
-    "mote" => ("mote", "must", "moting", "must"),
-    "motel" => ("motels", "motelled", "motelling", "motelled"),
-    "motley" => ("motleys", "motleyed", "motleying", "motleyed"),
-    "mouthsoap" => ("mouthsoaps", "mouthsoaped", "mouthsoaping", "mouthsoaped"),
-    "mow2" => ("mows", "mowed", "mowing", "mown"),
-    "msg" => ("msgs", "msged", "msging", "msged"),
-    "mud" => ("muds", "mudded", "mudding", "mudded"),
-    "mudsling" => ("mudslings", "mudslung", "mudslinging", "mudslung"),
-    "mugwump" => ("mugwumps", "mugwumped", "mugwumping", "mugwumped"),
-    "mum" => ("mums", "mummed", "mumming", "mummed"),
-    "mump" => ("mumps", "mumped", "mumping", "mumped"),
-    "music" => ("musics", "musicked", "musicking", "musicked"),
-    "must2" => ("must", "must", "musting", "must"),
-    "myek" => ("myeks", "made", "myekin", "made"),
-    "mythmake" => ("mythmakes", "mythmade", "mythmaking", "mythmade"),
-    "nab" => ("nabs", "nabbed", "nabbing", "nabbed"),
-    "namaste" => ("namastes", "namasted", "namasteing", "namasted"),
-    "nannybot" => ("nannybots", "nannybotted", "nannybotting", "nannybotted"),
-    "nanoalloy" => ("nanoalloys", "nanoalloyed", "nanoalloying", "nanoalloyed"),
-    "narcan" => ("narcans", "narcanned", "narcanning", "narcanned"),
-    "narrowcast" => ("narrowcasts", "narrowcast", "narrowcasting", "narrowcast"),
-    "naumkeag" => ("naumkeags", "naumkeaged", "naumkeaging", "naumkeaged"),
-    "nav" => ("navs", "navved", "navving", "navved"),
-    "nay" => ("nays", "nayed", "naying", "nayed"),
-    "naysay" => ("naysays", "naysaid", "naysaying", "naysaid"),
-    "neap" => ("neaps", "neaped", "neaping", "neaped"),
-    "necro" => ("necroes", "necroed", "necroing", "necroed"),
-    "necrobump" => ("necrobumps", "necrobumped", "necrobumping", "necrobumped"),
-    "needa" => ("needa", "needaed", "needaing", "needaed"),
-    "net" => ("nets", "netted", "netting", "netted"),
-    "netsplit" => ("netsplits", "netsplitted", "netsplitting", "netsplitted"),
-    "neurotransmit" => ("neurotransmits", "neurotransmitted", "neurotransmitting", "neurotransmitted"),
-    "newcast" => ("newcasts", "newcast", "newcasting", "newcast"),
-    "newgroup" => ("newgroups", "newgrouped", "newgrouping", "newgrouped"),
-    "newmake" => ("newmakes", "newmade", "newmaking", "newmade"),
-    "newscast" => ("newscasts", "newscast", "newscasting", "newscast"),
-    "newset" => ("newsets", "newset", "newsetting", "newset"),
-    "newvamp" => ("newvamps", "newvamped", "newvamping", "newvamped"),
-    "nfa" => ("nfas", "nfad", "nfaing", "nfad"),
-    "nib" => ("nibs", "nibbed", "nibbing", "nibbed"),
-    "nightclub" => ("nightclubs", "nightclubbed", "nightclubbing", "nightclubbed"),
-    "nim" => ("nims", "nimmed", "nimming", "nimmed"),
-    "nit" => ("nits", "nitted", "nitting", "nitted"),
-    "no" => ("noes", "noed", "noing", "noed"),
-    "nob" => ("nobs", "nobbed", "nobbing", "nobbed"),
-    "nod" => ("nods", "nodded", "nodding", "nodded"),
-    "noie" => ("noies", "noied", "noying", "noied"),
-    "nollepros" => ("nolleprosses", "nolleprossed", "nolleprossing", "nolleprossed"),
-    "nom" => ("noms", "nommed", "nomming", "nommed"),
-    "nomogram" => ("nomograms", "nomogrammed", "nomogramming", "nomogrammed"),
-    "nonconcur" => ("nonconcurs", "nonconcurred", "nonconcurring", "nonconcurred"),
-    "nonplus" => ("nonplusses", "nonplussed", "nonplussing", "nonplussed"),
-    "nonpros" => ("nonprosses", "nonprossed", "nonprossing", "nonprossed"),
-    "noogie" => ("noogies", "noogied", "noogying", "noogied"),
-    "nop2" => ("nops", "noped", "noping", "noped"),
-    "nosel" => ("nosels", "noselled", "noselling", "noselled"),
-    "noseride" => ("noserides", "noserode", "noseriding", "noseridden"),
-    "noserub" => ("noserubs", "noserubbed", "noserubbing", "noserubbed"),
-    "nosey" => ("noseys", "noseyed", "noseying", "noseyed"),
-    "notetake" => ("notetakes", "notetook", "notetaking", "notetaken"),
-    "nowcast" => ("nowcasts", "nowcast", "nowcasting", "nowcast"),
-    "noy" => ("noys", "noyed", "noying", "noyed"),
-    "nub" => ("nubs", "nubbed", "nubbing", "nubbed"),
-    "nudzh" => ("nudzhes", "nudzhed", "nudzhing", "nudzhed"),
-    "nut" => ("nuts", "nutted", "nutting", "nutted"),
-    "nutshot" => ("nutshots", "nutshot", "nutshotting", "nutshot"),
-    "nyam" => ("nyams", "nyammed", "nyamming", "nyammed"),
-    "obey" => ("obeys", "obeyed", "obeying", "obeyed"),
-    "occur" => ("occurs", "occurred", "occurring", "occurred"),
-    "octopus" => ("octopusses", "octopussed", "octopussing", "octopussed"),
-    "octroy" => ("octroys", "octroyed", "octroying", "octroyed"),
-    "oddjob" => ("oddjobs", "oddjobbed", "oddjobbing", "oddjobbed"),
-    "oe" => ("oes", "oeed", "oeing", "oeed"),
-    "offbear" => ("offbears", "offbore", "offbearing", "offborne"),
-    "offcast" => ("offcasts", "offcast", "offcasting", "offcast"),
-    "offcut" => ("offcuts", "offcut", "offcutting", "offcut"),
-    "offgas" => ("offgases", "offgassed", "offgassing", "offgassed"),
-    "offhold" => ("offholds", "offheld", "offholding", "offheld"),
-    "offlay" => ("offlays", "offlaid", "offlaying", "offlaid"),
-    "offlead" => ("offleads", "offled", "offleading", "offled"),
-    "offsend" => ("offsends", "offsent", "offsending", "offsent"),
-    "offset" => ("offsets", "offset", "offsetting", "offset"),
-    "offsmite" => ("offsmites", "offsmote", "offsmiting", "offsmitten"),
-    "offsplit" => ("offsplits", "offsplit", "offsplitting", "offsplit"),
-    "offstand" => ("offstands", "offstood", "offstanding", "offstood"),
-    "offtake" => ("offtakes", "offtook", "offtaking", "offtaken"),
-    "offthrow" => ("offthrows", "offthrew", "offthrowing", "offthrown"),
-    "okay" => ("okays", "okayed", "okaying", "okayed"),
-    "ollie" => ("ollies", "ollied", "ollieing", "ollied"),
-    "om" => ("oms", "ommed", "omming", "ommed"),
-    "omit" => ("omits", "omitted", "omitting", "omitted"),
-    "oncome" => ("oncomes", "oncame", "oncoming", "oncome"),
-    "oneshot" => ("oneshots", "oneshotted", "oneshotting", "oneshotted"),
-    "onfall" => ("onfalls", "onfell", "onfalling", "onfallen"),
-    "ongo" => ("ongoes", "onwent", "ongoing", "ongone"),
-    "onhold" => ("onholds", "onheld", "onholding", "onheld"),
-    "onlay" => ("onlays", "onlaid", "onlaying", "onlaid"),
-    "onlead" => ("onleads", "onled", "onleading", "onled"),
-    "onlend" => ("onlends", "onlent", "onlending", "onlent"),
-    "onsell" => ("onsells", "onsold", "onselling", "onsold"),
-    "onsend" => ("onsends", "onsent", "onsending", "onsent"),
-    "onset" => ("onsets", "onset", "onsetting", "onset"),
-    "ontake" => ("ontakes", "ontook", "ontaking", "ontaken"),
-    "oop" => ("oops", "ooped", "ooping", "ooped"),
-    "oopsie" => ("oopsies", "oopsied", "oopsieing", "oopsied"),
-    "orp" => ("orps", "orped", "orping", "orped"),
-    "outbar" => ("outbars", "outbarred", "outbarring", "outbarred"),
-    "outbear" => ("outbears", "outbore", "outbearing", "outborne"),
-    "outbeat" => ("outbeats", "outbeat", "outbeating", "outbeaten"),
-    "outbet" => ("outbets", "outbetted", "outbetting", "outbetted"),
-    "outbid" => ("outbids", "outbid", "outbidding", "outbid"),
-    "outbleed" => ("outbleeds", "outbled", "outbleeding", "outbled"),
-    "outblot" => ("outblots", "outblotted", "outblotting", "outblotted"),
-    "outblow" => ("outblows", "outblew", "outblowing", "outblown"),
-    "outbray" => ("outbrays", "outbrayed", "outbraying", "outbrayed"),
-    "outbreak" => ("outbreaks", "outbroke", "outbreaking", "outbroken"),
-    "outbreed" => ("outbreeds", "outbred", "outbreeding", "outbred"),
-    "outbring" => ("outbrings", "outbrought", "outbringing", "outbrought"),
-    "outbud" => ("outbuds", "outbudded", "outbudding", "outbudded"),
-    "outbuild" => ("outbuilds", "outbuilt", "outbuilding", "outbuilt"),
-    "outbullshit" => ("outbullshits", "outbullshitted", "outbullshitting", "outbullshitted"),
-    "outburp" => ("outburps", "outburped", "outburping", "outburped"),
-    "outburst" => ("outbursts", "outburst", "outbursting", "outburst"),
-    "outcast" => ("outcasts", "outcast", "outcasting", "outcast"),
-    "outcatch" => ("outcatches", "outcaught", "outcatching", "outcaught"),
-    "outcon" => ("outcons", "outconned", "outconning", "outconned"),
-    "outcreep" => ("outcreeps", "outcreeped", "outcreeping", "outcreeped"),
-    "outcreep2" => ("outcreeps", "outcrept", "outcreeping", "outcrept"),
-    "outcut" => ("outcuts", "outcut", "outcutting", "outcut"),
-    "outdeploy" => ("outdeploys", "outdeployed", "outdeploying", "outdeployed"),
-    "outdig" => ("outdigs", "outdug", "outdigging", "outdug"),
-    "outdin" => ("outdins", "outdinned", "outdinning", "outdinned"),
-    "outdo" => ("outdoes", "outdid", "outdoing", "outdone"),
-    "outdraw" => ("outdraws", "outdrew", "outdrawing", "outdrawn"),
-    "outdrink" => ("outdrinks", "outdrank", "outdrinking", "outdrunk"),
-    "outdrive" => ("outdrives", "outdrove", "outdriving", "outdriven"),
-    "outdwell" => ("outdwells", "outdwelt", "outdwelling", "outdwelt"),
-    "outeat" => ("outeats", "outate", "outeating", "outeaten"),
-    "outecho" => ("outechoes", "outechoed", "outechoing", "outechoed"),
-    "outfall" => ("outfalls", "outfell", "outfalling", "outfallen"),
-    "outfeel" => ("outfeels", "outfelt", "outfeeling", "outfelt"),
-    "outfib" => ("outfibs", "outfibbed", "outfibbing", "outfibbed"),
-    "outfind" => ("outfinds", "outfound", "outfinding", "outfound"),
-    "outfit" => ("outfits", "outfitted", "outfitting", "outfitted"),
-    "outflee" => ("outflees", "outfled", "outfleeing", "outfled"),
-    "outfling" => ("outflings", "outflung", "outflinging", "outflung"),
-    "outfly" => ("outflies", "outflew", "outflying", "outflown"),
-    "outfreeze" => ("outfreezes", "outfroze", "outfreezing", "outfrozen"),
-    "outgallop" => ("outgallops", "outgalloped", "outgalloping", "outgalloped"),
-    "outgas" => ("outgases", "outgassed", "outgassing", "outgassed"),
-    "outgay" => ("outgays", "outgayed", "outgaying", "outgayed"),
-    "outgive" => ("outgives", "outgave", "outgiving", "outgiven"),
-    "outgo" => ("outgoes", "outwent", "outgoing", "outgone"),
-    "outgrin" => ("outgrins", "outgrinned", "outgrinning", "outgrinned"),
-    "outgroup" => ("outgroups", "outgrouped", "outgrouping", "outgrouped"),
-    "outgrow" => ("outgrows", "outgrew", "outgrowing", "outgrown"),
-    "outgun" => ("outguns", "outgunned", "outgunning", "outgunned"),
-    "outhear" => ("outhears", "outheard", "outhearing", "outheard"),
-    "outhit" => ("outhits", "outhit", "outhitting", "outhit"),
-    "outhold" => ("outholds", "outheld", "outholding", "outheld"),
-    "outjockey" => ("outjockeys", "outjockeyed", "outjockeying", "outjockeyed"),
-    "outjourney" => ("outjourneys", "outjourneyed", "outjourneying", "outjourneyed"),
-    "outjump" => ("outjumps", "outjumped", "outjumping", "outjumped"),
-    "outjut" => ("outjuts", "outjutted", "outjutting", "outjutted"),
-    "outkeep" => ("outkeeps", "outkept", "outkeeping", "outkept"),
-    "outken" => ("outkens", "outkenned", "outkenning", "outkenned"),
-    "outknit" => ("outknits", "outknitted", "outknitting", "outknitted"),
-    "outlay" => ("outlays", "outlaid", "outlaying", "outlaid"),
-    "outlead" => ("outleads", "outled", "outleading", "outled"),
-    "outlean" => ("outleans", "outleant", "outleaning", "outleant"),
-    "outleap" => ("outleaps", "outleapt", "outleaping", "outleapt"),
-    "outlie" => ("outlies", "outlay", "outlying", "outlain"),
-    "outlie2" => ("outlies", "outlied", "outlying", "outlied"),
-    "outmagic" => ("outmagics", "outmagicked", "outmagicking", "outmagicked"),
-    "outman" => ("outmans", "outmanned", "outmanning", "outmanned"),
-    "outpay" => ("outpays", "outpaid", "outpaying", "outpaid"),
-    "outpeep" => ("outpeeps", "outpeeped", "outpeeping", "outpeeped"),
-    "outplan" => ("outplans", "outplanned", "outplanning", "outplanned"),
-    "outplay" => ("outplays", "outplayed", "outplaying", "outplayed"),
-    "outplod" => ("outplods", "outplodded", "outplodding", "outplodded"),
-    "outplot" => ("outplots", "outplotted", "outplotting", "outplotted"),
-    "outpomp" => ("outpomps", "outpomped", "outpomping", "outpomped"),
-    "outpray" => ("outprays", "outprayed", "outpraying", "outprayed"),
-    "outprogram" => ("outprograms", "outprogrammed", "outprogramming", "outprogrammed"),
-    "outpsych" => ("outpsychs", "outpsyched", "outpsyching", "outpsyched"),
-    "output" => ("outputs", "output", "outputting", "output"),
-    "outray" => ("outrays", "outrayed", "outraying", "outrayed"),
-    "outread" => ("outreads", "outread", "outreading", "outread"),
-    "outredd" => ("outredds", "outredd", "outredding", "outredd"),
-    "outride" => ("outrides", "outrode", "outriding", "outridden"),
-    "outring" => ("outrings", "outrang", "outringing", "outrung"),
-    "outrive" => ("outrives", "outrove", "outriving", "outriven"),
-    "outrun" => ("outruns", "outran", "outrunning", "outrun"),
-    "outscam" => ("outscams", "outscammed", "outscamming", "outscammed"),
-    "outscoop" => ("outscoops", "outscooped", "outscooping", "outscooped"),
-    "outsee" => ("outsees", "outsaw", "outseeing", "outseen"),
-    "outseek" => ("outseeks", "outsought", "outseeking", "outsought"),
-    "outsell" => ("outsells", "outsold", "outselling", "outsold"),
-    "outsend" => ("outsends", "outsent", "outsending", "outsent"),
-    "outset" => ("outsets", "outset", "outsetting", "outset"),
-    "outshake" => ("outshakes", "outshook", "outshaking", "outshaken"),
-    "outsharp" => ("outsharps", "outsharped", "outsharping", "outsharped"),
-    "outshine" => ("outshines", "outshone", "outshining", "outshone"),
-    "outshoot" => ("outshoots", "outshot", "outshooting", "outshot"),
-    "outshow" => ("outshows", "outshowed", "outshowing", "outshown"),
-    "outshut" => ("outshuts", "outshut", "outshutting", "outshut"),
-    "outsimp" => ("outsimps", "outsimped", "outsimping", "outsimped"),
-    "outsin" => ("outsins", "outsinned", "outsinning", "outsinned"),
-    "outsing" => ("outsings", "outsang", "outsinging", "outsung"),
-    "outsit" => ("outsits", "outsat", "outsitting", "outsat"),
-    "outskin" => ("outskins", "outskinned", "outskinning", "outskinned"),
-    "outsleep" => ("outsleeps", "outslept", "outsleeping", "outslept"),
-    "outslide" => ("outslides", "outslid", "outsliding", "outslid"),
-    "outsling" => ("outslings", "outslung", "outslinging", "outslung"),
-    "outslink" => ("outslinks", "outslunk", "outslinking", "outslunk"),
-    "outslut" => ("outsluts", "outslutted", "outslutting", "outslutted"),
-    "outsnob" => ("outsnobs", "outsnobbed", "outsnobbing", "outsnobbed"),
-    "outspan" => ("outspans", "outspanned", "outspanning", "outspanned"),
-    "outspeak" => ("outspeaks", "outspoke", "outspeaking", "outspoken"),
-    "outspeed" => ("outspeeds", "outsped", "outspeeding", "outsped"),
-    "outspend" => ("outspends", "outspent", "outspending", "outspent"),
-    "outspin" => ("outspins", "outspun", "outspinning", "outspun"),
-    "outspit" => ("outspits", "outspat", "outspitting", "outspat"),
-    "outspread" => ("outspreads", "outspread", "outspreading", "outspread"),
-    "outspring" => ("outsprings", "outsprang", "outspringing", "outsprung"),
-    "outsquat" => ("outsquats", "outsquatted", "outsquatting", "outsquatted"),
-    "outstand" => ("outstands", "outstood", "outstanding", "outstood"),
-    "outstat" => ("outstats", "outstatted", "outstatting", "outstatted"),
-    "outstatistic" => ("outstatistics", "outstatisticked", "outstatisticking", "outstatisticked"),
-    "outstay" => ("outstays", "outstayed", "outstaying", "outstayed"),
-    "outsteal" => ("outsteals", "outstole", "outstealing", "outstolen"),
-    "outsting" => ("outstings", "outstung", "outstinging", "outstung"),
-    "outstink" => ("outstinks", "outstank", "outstinking", "outstunk"),
-    "outstride" => ("outstrides", "outstrode", "outstriding", "outstridden"),
-    "outstrike" => ("outstrikes", "outstruck", "outstriking", "outstruck"),
-    "outstrut" => ("outstruts", "outstrutted", "outstrutting", "outstrutted"),
-    "outsum" => ("outsums", "outsummed", "outsumming", "outsummed"),
-    "outswear" => ("outswears", "outswore", "outswearing", "outsworn"),
-    "outsweat" => ("outsweats", "outsweat", "outsweating", "outsweat"),
-    "outsweep" => ("outsweeps", "outswept", "outsweeping", "outswept"),
-    "outswim" => ("outswims", "outswam", "outswimming", "outswum"),
-    "outswing" => ("outswings", "outswung", "outswinging", "outswung"),
-    "outtake" => ("outtakes", "outtook", "outtaking", "outtaken"),
-    "outtell" => ("outtells", "outtold", "outtelling", "outtold"),
-    "outthink" => ("outthinks", "outthought", "outthinking", "outthought"),
-    "outthrob" => ("outthrobs", "outthrobbed", "outthrobbing", "outthrobbed"),
-    "outthrow" => ("outthrows", "outthrew", "outthrowing", "outthrown"),
-    "outthrust" => ("outthrusts", "outthrust", "outthrusting", "outthrust"),
-    "outtrot" => ("outtrots", "outtrotted", "outtrotting", "outtrotted"),
-    "outtrump" => ("outtrumps", "outtrumped", "outtrumping", "outtrumped"),
-    "outvie" => ("outvies", "outvied", "outvying", "outvied"),
-    "outwake" => ("outwakes", "outwoke", "outwaking", "outwoken"),
-    "outwar" => ("outwars", "outwarred", "outwarring", "outwarred"),
-    "outwear" => ("outwears", "outwore", "outwearing", "outworn"),
-    "outweave" => ("outweaves", "outwove", "outweaving", "outwoven"),
-    "outweep" => ("outweeps", "outwept", "outweeping", "outwept"),
-    "outwin" => ("outwins", "outwon", "outwinning", "outwon"),
-    "outwind2" => ("outwinds", "outwound", "outwinding", "outwound"),
-    "outwit" => ("outwits", "outwitted", "outwitting", "outwitted"),
-    "outwring" => ("outwrings", "outwrung", "outwringing", "outwrung"),
-    "outwrite" => ("outwrites", "outwrote", "outwriting", "outwritten"),
-    "outyelp" => ("outyelps", "outyelped", "outyelping", "outyelped"),
-    "overamp" => ("overamps", "overamped", "overamping", "overamped"),
-    "overbar" => ("overbars", "overbarred", "overbarring", "overbarred"),
-    "overbear" => ("overbears", "overbore", "overbearing", "overborne"),
-    "overbeat" => ("overbeats", "overbeat", "overbeating", "overbeaten"),
-    "overbend" => ("overbends", "overbent", "overbending", "overbent"),
-    "overbet" => ("overbets", "overbet", "overbetting", "overbet"),
-    "overbid" => ("overbids", "overbid", "overbidding", "overbid"),
-    "overbide" => ("overbides", "overbode", "overbiding", "overbidden"),
-    "overbind" => ("overbinds", "overbound", "overbinding", "overbound"),
-    "overbite" => ("overbites", "overbit", "overbiting", "overbitten"),
-    "overbleed" => ("overbleeds", "overbled", "overbleeding", "overbled"),
-    "overblow" => ("overblows", "overblew", "overblowing", "overblown"),
-    "overbreak" => ("overbreaks", "overbroke", "overbreaking", "overbroken"),
-    "overbreed" => ("overbreeds", "overbred", "overbreeding", "overbred"),
-    "overbrim" => ("overbrims", "overbrimmed", "overbrimming", "overbrimmed"),
-    "overbuild" => ("overbuilds", "overbuilt", "overbuilding", "overbuilt"),
-    "overcast" => ("overcasts", "overcast", "overcasting", "overcast"),
-    "overcatch" => ("overcatches", "overcaught", "overcatching", "overcaught"),
-    "overclad" => ("overclads", "overclad", "overcladding", "overclad"),
-    "overclasp" => ("overclasps", "overclasped", "overclasping", "overclasped"),
-    "overcloy" => ("overcloys", "overcloyed", "overcloying", "overcloyed"),
-    "overclub" => ("overclubs", "overclubbed", "overclubbing", "overclubbed"),
-    "overcome" => ("overcomes", "overcame", "overcoming", "overcome"),
-    "overcommit" => ("overcommits", "overcommitted", "overcommitting", "overcommitted"),
-    "overcontrol" => ("overcontrols", "overcontrolled", "overcontrolling", "overcontrolled"),
-    "overcram" => ("overcrams", "overcrammed", "overcramming", "overcrammed"),
-    "overcreep" => ("overcreeps", "overcrept", "overcreeping", "overcrept"),
-    "overcut" => ("overcuts", "overcut", "overcutting", "overcut"),
-    "overdamp" => ("overdamps", "overdamped", "overdamping", "overdamped"),
-    "overdeal" => ("overdeals", "overdealt", "overdealing", "overdealt"),
-    "overdefer" => ("overdefers", "overdeferred", "overdeferring", "overdeferred"),
-    "overdevelop" => ("overdevelops", "overdeveloped", "overdeveloping", "overdeveloped"),
-    "overdo" => ("overdoes", "overdid", "overdoing", "overdone"),
-    "overdot" => ("overdots", "overdotted", "overdotting", "overdotted"),
-    "overdraw" => ("overdraws", "overdrew", "overdrawing", "overdrawn"),
-    "overdreep" => ("overdreeps", "overdreeped", "overdreeping", "overdreeped"),
-    "overdrink" => ("overdrinks", "overdrank", "overdrinking", "overdrunk"),
-    "overdrive" => ("overdrives", "overdrove", "overdriving", "overdriven"),
-    "overdub" => ("overdubs", "overdubbed", "overdubbing", "overdubbed"),
-    "overdye" => ("overdyes", "overdyed", "overdyeing", "overdyed"),
-    "overeat" => ("overeats", "overate", "overeating", "overeaten"),
-    "overegg" => ("overeggs", "overegged", "overegging", "overegged"),
-    "overemploy" => ("overemploys", "overemployed", "overemploying", "overemployed"),
-    "overenjoy" => ("overenjoys", "overenjoyed", "overenjoying", "overenjoyed"),
-    "overexcel" => ("overexcels", "overexcelled", "overexcelling", "overexcelled"),
-    "overeye" => ("overeyes", "overeyed", "overeyeing", "overeyed"),
-    "overfall" => ("overfalls", "overfell", "overfalling", "overfallen"),
-    "overfeed" => ("overfeeds", "overfed", "overfeeding", "overfed"),
-    "overfeel" => ("overfeels", "overfelt", "overfeeling", "overfelt"),
-    "overfit" => ("overfits", "overfitted", "overfitting", "overfitted"),
-    "overfly" => ("overflies", "overflew", "overflying", "overflown"),
-    "overformat" => ("overformats", "overformatted", "overformatting", "overformatted"),
-    "overfreeze" => ("overfreezes", "overfroze", "overfreezing", "overfrozen"),
-    "overfreight" => ("overfreights", "overfreighted", "overfreighting", "overfraught"),
-    "overfret" => ("overfrets", "overfretted", "overfretting", "overfretted"),
-    "overfulfil" => ("overfulfils", "overfulfilled", "overfulfilling", "overfulfilled"),
-    "overget" => ("overgets", "overgot", "overgetting", "overgot"),
-    "overgive" => ("overgives", "overgave", "overgiving", "overgiven"),
-    "overglut" => ("overgluts", "overglutted", "overglutting", "overglutted"),
-    "overgo" => ("overgoes", "overwent", "overgoing", "overgone"),
-    "overgrind" => ("overgrinds", "overground", "overgrinding", "overground"),
-    "overgrow" => ("overgrows", "overgrew", "overgrowing", "overgrown"),
-    "overguarantee" => ("overguarantees", "overguaranteed", "overguaranteeing", "overguaranteed"),
-    "overhang" => ("overhangs", "overhung", "overhanging", "overhung"),
-    "overheal2" => ("overheals", "overhole", "overhealing", "overholen"),
-    "overheap" => ("overheaps", "overheaped", "overheaping", "overheaped"),
-    "overhear" => ("overhears", "overheard", "overhearing", "overheard"),
-    "overheave" => ("overheaves", "overhove", "overheaving", "overhoven"),
-    "overhelp" => ("overhelps", "overhelped", "overhelping", "overhelped"),
-    "overhend" => ("overhends", "overhent", "overhending", "overhent"),
-    "overhent" => ("overhents", "overhent", "overhenting", "overhent"),
-    "overhie" => ("overhies", "overhied", "overhying", "overhied"),
-    "overhit" => ("overhits", "overhit", "overhitting", "overhit"),
-    "overhold" => ("overholds", "overheld", "overholding", "overheld"),
-    "overjoy" => ("overjoys", "overjoyed", "overjoying", "overjoyed"),
-    "overjump" => ("overjumps", "overjumped", "overjumping", "overjumped"),
-    "overjut" => ("overjuts", "overjutted", "overjutting", "overjutted"),
-    "overkeep" => ("overkeeps", "overkept", "overkeeping", "overkept"),
-    "overlay" => ("overlays", "overlaid", "overlaying", "overlaid"),
-    "overlead" => ("overleads", "overled", "overleading", "overled"),
-    "overlean" => ("overleans", "overleant", "overleaning", "overleant"),
-    "overleap" => ("overleaps", "overleaped", "overleaping", "overleaped"),
-    "overleave" => ("overleaves", "overleft", "overleaving", "overleft"),
-    "overlend" => ("overlends", "overlent", "overlending", "overlent"),
-    "overlie" => ("overlies", "overlay", "overlying", "overlain"),
-    "overlight" => ("overlights", "overlit", "overlighting", "overlit"),
-    "overloop" => ("overloops", "overlooped", "overlooping", "overlooped"),
-    "overmake" => ("overmakes", "overmade", "overmaking", "overmade"),
-    "overman" => ("overmans", "overmanned", "overmanning", "overmanned"),
-    "overnet" => ("overnets", "overnetted", "overnetting", "overnetted"),
-    "overnim" => ("overnims", "overnam", "overnimming", "overnum"),
-    "overpad" => ("overpads", "overpadded", "overpadding", "overpadded"),
-    "overpay" => ("overpays", "overpaid", "overpaying", "overpaid"),
-    "overpet" => ("overpets", "overpetted", "overpetting", "overpetted"),
-    "overplan" => ("overplans", "overplanned", "overplanning", "overplanned"),
-    "overplay" => ("overplays", "overplayed", "overplaying", "overplayed"),
-    "overplot" => ("overplots", "overplotted", "overplotting", "overplotted"),
-    "overpot" => ("overpots", "overpotted", "overpotting", "overpotted"),
-    "overprogram" => ("overprograms", "overprogrammed", "overprogramming", "overprogrammed"),
-    "overpump" => ("overpumps", "overpumped", "overpumping", "overpumped"),
-    "overput" => ("overputs", "overput", "overputting", "overput"),
-    "overram" => ("overrams", "overrammed", "overramming", "overrammed"),
-    "overread" => ("overreads", "overread", "overreading", "overread"),
-    "overren" => ("overrens", "overran", "overrenning", "overrun"),
-    "overrev" => ("overrevs", "overrevved", "overrevving", "overrevved"),
-    "override" => ("overrides", "overrode", "overriding", "overridden"),
-    "overring" => ("overrings", "overrang", "overringing", "overrung"),
-    "overrise" => ("overrises", "overrose", "overrising", "overrisen"),
-    "overrun" => ("overruns", "overran", "overrunning", "overrun"),
-    "oversay" => ("oversays", "oversaid", "oversaying", "oversaid"),
-    "overscrub" => ("overscrubs", "overscrubbed", "overscrubbing", "overscrubbed"),
-    "oversee" => ("oversees", "oversaw", "overseeing", "overseen"),
-    "oversell" => ("oversells", "oversold", "overselling", "oversold"),
-    "oversend" => ("oversends", "oversent", "oversending", "oversent"),
-    "overset" => ("oversets", "overset", "oversetting", "overset"),
-    "oversew" => ("oversews", "oversewed", "oversewing", "oversewn"),
-    "overshake" => ("overshakes", "overshook", "overshaking", "overshaken"),
-    "overshine" => ("overshines", "overshone", "overshining", "overshone"),
-    "overshoot" => ("overshoots", "overshot", "overshooting", "overshot"),
-    "overshow" => ("overshows", "overshowed", "overshowing", "overshown"),
-    "overshrink" => ("overshrinks", "overshrank", "overshrinking", "overshrunk"),
-    "oversing" => ("oversings", "oversang", "oversinging", "oversung"),
-    "oversit" => ("oversits", "oversat", "oversitting", "oversat"),
-    "overskim" => ("overskims", "overskimmed", "overskimming", "overskimmed"),
-    "oversleep" => ("oversleeps", "overslept", "oversleeping", "overslept"),
-    "overslide" => ("overslides", "overslid", "oversliding", "overslid"),
-    "oversow" => ("oversows", "oversowed", "oversowing", "oversown"),
-    "overspan" => ("overspans", "overspanned", "overspanning", "overspanned"),
-    "overspeak" => ("overspeaks", "overspoke", "overspeaking", "overspoken"),
-    "overspeed" => ("overspeeds", "oversped", "overspeeding", "oversped"),
-    "overspend" => ("overspends", "overspent", "overspending", "overspent"),
-    "overspin" => ("overspins", "overspun", "overspinning", "overspun"),
-    "oversplit" => ("oversplits", "oversplit", "oversplitting", "oversplit"),
-    "overspray" => ("oversprays", "oversprayed", "overspraying", "oversprayed"),
-    "overspread" => ("overspreads", "overspread", "overspreading", "overspread"),
-    "overspring" => ("oversprings", "oversprang", "overspringing", "oversprung"),
-    "overstamp" => ("overstamps", "overstamped", "overstamping", "overstamped"),
-    "overstand" => ("overstands", "overstood", "overstanding", "overstood"),
-    "overstay" => ("overstays", "overstayed", "overstaying", "overstayed"),
-    "oversteep" => ("oversteeps", "oversteeped", "oversteeping", "oversteeped"),
-    "overstir" => ("overstirs", "overstirred", "overstirring", "overstirred"),
-    "overstraw" => ("overstraws", "overstrawed", "overstrawing", "overstrawn"),
-    "overstrew" => ("overstrews", "overstrewed", "overstrewing", "overstrewn"),
-    "overstride" => ("overstrides", "overstrode", "overstriding", "overstrode"),
-    "overstrike" => ("overstrikes", "overstruck", "overstriking", "overstruck"),
-    "overstrive" => ("overstrives", "overstrove", "overstriving", "overstriven"),
-    "overstrow" => ("overstrows", "overstrowed", "overstrowing", "overstrown"),
-    "oversum" => ("oversums", "oversummed", "oversumming", "oversummed"),
-    "oversway" => ("oversways", "overswayed", "overswaying", "overswayed"),
-    "overswear" => ("overswears", "overswore", "overswearing", "oversworn"),
-    "oversweep" => ("oversweeps", "overswept", "oversweeping", "overswept"),
-    "overswim" => ("overswims", "overswam", "overswimming", "overswum"),
-    "overswing" => ("overswings", "overswung", "overswinging", "overswung"),
-    "overtake" => ("overtakes", "overtook", "overtaking", "overtaken"),
-    "overtan" => ("overtans", "overtanned", "overtanning", "overtanned"),
-    "overteach" => ("overteaches", "overtaught", "overteaching", "overtaught"),
-    "overtell" => ("overtells", "overtold", "overtelling", "overtold"),
-    "overthin" => ("overthins", "overthinned", "overthinning", "overthinned"),
-    "overthink" => ("overthinks", "overthought", "overthinking", "overthought"),
-    "overthrow" => ("overthrows", "overthrew", "overthrowing", "overthrown"),
-    "overtransmit" => ("overtransmits", "overtransmitted", "overtransmitting", "overtransmitted"),
-    "overtread" => ("overtreads", "overtrod", "overtreading", "overtrodden"),
-    "overtrim" => ("overtrims", "overtrimmed", "overtrimming", "overtrimmed"),
-    "overtrump" => ("overtrumps", "overtrumped", "overtrumping", "overtrumped"),
-    "overwear" => ("overwears", "overwore", "overwearing", "overworn"),
-    "overweep" => ("overweeps", "overwept", "overweeping", "overwept"),
-    "overwet" => ("overwets", "overwet", "overwetting", "overwet"),
-    "overwin" => ("overwins", "overwon", "overwinning", "overwon"),
-    "overwind" => ("overwinds", "overwound", "overwinding", "overwound"),
-    "overwit" => ("overwits", "overwitted", "overwitting", "overwitted"),
-    "overwithhold" => ("overwithholds", "overwithheld", "overwithholding", "overwithheld"),
-    "overworship" => ("overworships", "overworshiped", "overworshiping", "overworshiped"),
-    "overwrite" => ("overwrites", "overwrote", "overwriting", "overwritten"),
-    "packrat" => ("packrats", "packratted", "packratting", "packratted"),
-    "pacquet" => ("pacquets", "pacquetted", "pacquetting", "pacquetted"),
-    "pad" => ("pads", "padded", "padding", "padded"),
-    "pal" => ("pals", "palled", "palling", "palled"),
-    "palisado" => ("palisadoes", "palisadoed", "palisadoing", "palisadoed"),
-    "palp" => ("palps", "palped", "palping", "palped"),
-    "pam" => ("pams", "pammed", "pamming", "pammed"),
-    "pamp" => ("pamps", "pamped", "pamping", "pamped"),
-    "pan" => ("pans", "panned", "panning", "panned"),
-    "panic" => ("panics", "panicked", "panicking", "panicked"),
-    "panyar" => ("panyars", "panyarred", "panyarring", "panyarred"),
-    "par" => ("pars", "parred", "parring", "parred"),
-    "paratroop" => ("paratroops", "paratrooped", "paratrooping", "paratrooped"),
-    "parkrun" => ("parkruns", "parkran", "parkrunning", "parkrun"),
-    "parlay" => ("parlays", "parlayed", "parlaying", "parlayed"),
-    "parley" => ("parleys", "parleyed", "parleying", "parleyed"),
-    "parp" => ("parps", "parped", "parping", "parped"),
-    "parsley" => ("parsleys", "parsleyed", "parsleying", "parsleyed"),
-    "partake" => ("partakes", "partook", "partaking", "partaken"),
-    "partay" => ("partays", "partayed", "partaying", "partayed"),
-    "partial" => ("partials", "partialled", "partialing", "partialled"),
-    "pat" => ("pats", "patted", "patting", "patted"),
-    "pathfind" => ("pathfinds", "pathfound", "pathfinding", "pathfound"),
-    "patrol" => ("patrols", "patrolled", "patrolling", "patrolled"),
-    "pay" => ("pays", "paid", "paying", "paid"),
-    "pay2" => ("pays", "payed", "paying", "payed"),
-    "peanut" => ("peanuts", "peanutted", "peanutting", "peanutted"),
-    "pech" => ("pechs", "peched", "peching", "peched"),
-    "pedagog" => ("pedagogs", "pedagoged", "pedagoging", "pedagoged"),
-    "pedigree" => ("pedigrees", "pedigreed", "pedigreeing", "pedigreed"),
-    "pee" => ("pees", "peed", "peeing", "peed"),
-    "peep" => ("peeps", "peeped", "peeping", "peeped"),
-    "peercast" => ("peercasts", "peercast", "peercasting", "peercast"),
-    "pen" => ("pens", "penned", "penning", "penned"),
-    "perbreak" => ("perbreaks", "perbroke", "perbreaking", "perbroken"),
-    "permaban" => ("permabans", "permabanned", "permabanning", "permabanned"),
-    "permadie" => ("permadies", "permadied", "permadying", "permadied"),
-    "permastun" => ("permastuns", "permastunned", "permastunning", "permastunned"),
-    "permban" => ("permbans", "permbanned", "permbanning", "permbanned"),
-    "permit" => ("permits", "permitted", "permitting", "permitted"),
-    "pertake" => ("pertakes", "pertook", "pertaking", "pertaken"),
-    "pet" => ("pets", "pet", "petting", "petted"),
-    "pet2" => ("pets", "petted", "petting", "petted"),
-    "petsit" => ("petsits", "petsat", "petsitting", "petsat"),
-    "phantasie" => ("phantasies", "phantasied", "phantasying", "phantasied"),
-    "phoney" => ("phoneys", "phoneyed", "phoneying", "phoneyed"),
-    "phot" => ("phots", "photted", "photting", "photted"),
-    "photoemit" => ("photoemits", "photoemitted", "photoemitting", "photoemitted"),
-    "photoset" => ("photosets", "photoset", "photosetting", "photoset"),
-    "photosurvey" => ("photosurveys", "photosurveyed", "photosurveying", "photosurveyed"),
-    "phototypeset" => ("phototypesets", "phototypeset", "phototypesetting", "phototypeset"),
-    "phub" => ("phubs", "phubbed", "phubbing", "phubbed"),
-    "phut" => ("phuts", "phutted", "phutting", "phutted"),
-    "physic" => ("physics", "physicked", "physicking", "physicked"),
-    "pi" => ("pies", "pied", "piing", "pied"),
-    "picnic" => ("picnics", "picnicked", "picnicking", "picnicked"),
-    "pie" => ("pies", "pied", "pieing", "pied"),
-    "pigjump" => ("pigjumps", "pigjumped", "pigjumping", "pigjumped"),
-    "pigsit" => ("pigsits", "pigsat", "pigsitting", "pigsat"),
-    "pikey" => ("pikeys", "pikeyed", "pikeying", "pikeyed"),
-    "piledrive" => ("piledrives", "piledrove", "piledriving", "piledriven"),
-    "pimp" => ("pimps", "pimped", "pimping", "pimped"),
-    "pin" => ("pins", "pinned", "pinning", "pinned"),
-    "pinspot" => ("pinspots", "pinspotted", "pinspotting", "pinspotted"),
-    "pipeclay" => ("pipeclays", "pipeclayed", "pipeclaying", "pipeclayed"),
-    "pipet" => ("pipets", "pipetted", "pipetting", "pipetted"),
-    "pisstake" => ("pisstakes", "pisstook", "pisstaking", "pisstaken"),
-    "pit" => ("pits", "pitted", "pitting", "pitted"),
-    "pitapat" => ("pitapats", "pitapatted", "pitapatting", "pitapatted"),
-    "plan" => ("plans", "planned", "planning", "planned"),
-    "planespot" => ("planespots", "planespotted", "planespotting", "planespotted"),
-    "planogram" => ("planograms", "planogrammed", "planogramming", "planogrammed"),
-    "plantsit" => ("plantsits", "plantsat", "plantsitting", "plantsat"),
-    "plat" => ("plats", "platted", "platting", "platted"),
-    "play" => ("plays", "played", "playing", "played"),
-    "pledg" => ("pledges", "pledged", "pledging", "pledged"),
-    "plim" => ("plims", "plimmed", "plimming", "plimmed"),
-    "plod" => ("plods", "plodded", "plodding", "plodded"),
-    "plot" => ("plots", "plotted", "plotting", "plotted"),
-    "ploy" => ("ploys", "ployed", "ploying", "ployed"),
-    "plum" => ("plums", "plummed", "plumming", "plummed"),
-    "plump" => ("plumps", "plumped", "plumping", "plumped"),
-    "plus" => ("pluss", "plused", "plusing", "plused"),
-    "pod" => ("pods", "podded", "podding", "podded"),
-    "podcast" => ("podcasts", "podcast", "podcasting", "podcast"),
-    "poe" => ("poes", "poed", "poeing", "poed"),
-    "pointcast" => ("pointcasts", "pointcast", "pointcasting", "pointcast"),
-    "politic" => ("politics", "politicked", "politicking", "politicked"),
-    "polkadot" => ("polkadots", "polkadotted", "polkadotting", "polkadotted"),
-    "poly" => ("polys", "polyed", "polying", "polyed"),
-    "pomp" => ("pomps", "pomped", "pomping", "pomped"),
-    "pon" => ("pons", "ponned", "ponning", "ponned"),
-    "poop" => ("poops", "pooped", "pooping", "pooped"),
-    "portray" => ("portrays", "portrayed", "portraying", "portrayed"),
-    "portscan" => ("portscans", "portscanned", "portscanning", "portscanned"),
-    "postsynch" => ("postsynchs", "postsynched", "postsynching", "postsynched"),
-    "pot" => ("pots", "potted", "potting", "potted"),
-    "potato" => ("potatoes", "potatoed", "potatoing", "potatoed"),
-    "potshoot" => ("potshoots", "potshot", "potshooting", "potshot"),
-    "potshot" => ("potshots", "potshotted", "potshotting", "potshotted"),
-    "pourtray" => ("pourtrays", "pourtrayed", "pourtraying", "pourtrayed"),
-    "powerslam" => ("powerslams", "powerslammed", "powerslamming", "powerslammed"),
-    "poz" => ("pozzes", "pozzed", "pozzing", "pozzed"),
-    "praetermit" => ("praetermits", "praetermitted", "praetermitting", "praetermitted"),
-    "pratfall" => ("pratfalls", "pratfell", "pratfalling", "pratfallen"),
-    "pray" => ("prays", "prayed", "praying", "prayed"),
-    "pre" => ("pres", "pred", "preing", "pred"),
-    "preadmit" => ("preadmits", "preadmitted", "preadmitting", "preadmitted"),
-    "preallot" => ("preallots", "preallotted", "preallotting", "preallotted"),
-    "preamp" => ("preamps", "preamped", "preamping", "preamped"),
-    "prebend" => ("prebends", "prebent", "prebending", "prebent"),
-    "prebind" => ("prebinds", "prebound", "prebinding", "prebound"),
-    "preblow" => ("preblows", "preblew", "preblowing", "preblown"),
-    "prebreak" => ("prebreaks", "prebroke", "prebreaking", "prebroken"),
-    "prebuild" => ("prebuilds", "prebuilt", "prebuilding", "prebuilt"),
-    "prebut" => ("prebuts", "prebutted", "prebutting", "prebutted"),
-    "precast" => ("precasts", "precast", "precasting", "precast"),
-    "precel" => ("precels", "precelled", "precelling", "precelled"),
-    "prechoose" => ("prechooses", "prechose", "prechoosing", "prechosen"),
-    "precommit" => ("precommits", "precommitted", "precommitting", "precommitted"),
-    "preconvey" => ("preconveys", "preconveyed", "preconveying", "preconveyed"),
-    "precue" => ("precues", "precued", "precueing", "precued"),
-    "precum" => ("precums", "precame", "precumming", "precum"),
-    "precut" => ("precuts", "precut", "precutting", "precut"),
-    "predevelop" => ("predevelops", "predeveloped", "predeveloping", "predeveloped"),
-    "predub" => ("predubs", "predubbed", "predubbing", "predubbed"),
-    "predye" => ("predyes", "predyed", "predyeing", "predyed"),
-    "pree" => ("prees", "preed", "preeing", "preed"),
-    "preemploy" => ("preemploys", "preemployed", "preemploying", "preemployed"),
-    "prefab" => ("prefabs", "prefabbed", "prefabbing", "prefabbed"),
-    "prefeed" => ("prefeeds", "prefed", "prefeeding", "prefed"),
-    "prefer" => ("prefers", "preferred", "preferring", "preferred"),
-    "prefit" => ("prefits", "prefitted", "prefitting", "prefitted"),
-    "preformat" => ("preformats", "preformatted", "preformatting", "preformatted"),
-    "prefreeze" => ("prefreezes", "prefroze", "prefreezing", "prefrozen"),
-    "pregrab" => ("pregrabs", "pregrabbed", "pregrabbing", "pregrabbed"),
-    "pregrind" => ("pregrinds", "preground", "pregrinding", "preground"),
-    "pregroup" => ("pregroups", "pregrouped", "pregrouping", "pregrouped"),
-    "prelay" => ("prelays", "prelaid", "prelaying", "prelaid"),
-    "prelight" => ("prelights", "prelit", "prelighting", "prelit"),
-    "premake" => ("premakes", "premade", "premaking", "premade"),
-    "premit" => ("premits", "premitted", "premitting", "premitted"),
-    "preoutfit" => ("preoutfits", "preoutfitted", "preoutfitting", "preoutfitted"),
-    "prepay" => ("prepays", "prepaid", "prepaying", "prepaid"),
-    "preplan" => ("preplans", "preplanned", "preplanning", "preplanned"),
-    "preplay" => ("preplays", "preplayed", "preplaying", "preplayed"),
-    "preprogram" => ("preprograms", "preprogrammed", "preprogramming", "preprogrammed"),
-    "preread" => ("prereads", "preread", "prereading", "preread"),
-    "prerun" => ("preruns", "preran", "prerunning", "prerun"),
-    "prescan" => ("prescans", "prescanned", "prescanning", "prescanned"),
-    "presell" => ("presells", "presold", "preselling", "presold"),
-    "presend" => ("presends", "present", "presending", "present"),
-    "preset" => ("presets", "preset", "presetting", "preset"),
-    "presew" => ("presews", "presewed", "presewing", "presewn"),
-    "preshow" => ("preshows", "preshowed", "preshowing", "preshown"),
-    "preshrink" => ("preshrinks", "preshrank", "preshrinking", "preshrunk"),
-    "presplit" => ("presplits", "presplit", "presplitting", "presplit"),
-    "prestamp" => ("prestamps", "prestamped", "prestamping", "prestamped"),
-    "presubmit" => ("presubmits", "presubmitted", "presubmitting", "presubmitted"),
-    "preswab" => ("preswabs", "preswabbed", "preswabbing", "preswabbed"),
-    "pretan" => ("pretans", "pretanned", "pretanning", "pretanned"),
-    "pretell" => ("pretells", "pretold", "pretelling", "pretold"),
-    "pretermit" => ("pretermits", "pretermitted", "pretermitting", "pretermitted"),
-    "prethin" => ("prethins", "prethinned", "prethinning", "prethinned"),
-    "pretin" => ("pretins", "pretinned", "pretinning", "pretinned"),
-    "pretransmit" => ("pretransmits", "pretransmitted", "pretransmitting", "pretransmitted"),
-    "pretrim" => ("pretrims", "pretrimmed", "pretrimming", "pretrimmed"),
-    "prevet" => ("prevets", "prevetted", "prevetting", "prevetted"),
-    "prewet" => ("prewets", "prewet", "prewetting", "prewet"),
-    "prewrite" => ("prewrites", "prewrote", "prewriting", "prewritten"),
-    "prey" => ("preys", "preyed", "preying", "preyed"),
-    "prie" => ("pries", "pried", "prying", "pried"),
-    "prim" => ("prims", "primmed", "primming", "primmed"),
-    "primp" => ("primps", "primped", "primping", "primped"),
-    "prin" => ("prins", "prinned", "prinning", "prinned"),
-    "proc" => ("procs", "procced", "proccing", "procced"),
-    "prod" => ("prods", "prodded", "prodding", "prodded"),
-    "profer" => ("profers", "proferred", "proferring", "proferred"),
-    "program" => ("programs", "programmed", "programming", "programmed"),
-    "prolog" => ("prologs", "prologed", "prologing", "prologed"),
-    "prom" => ("proms", "prommed", "promming", "prommed"),
-    "proofread" => ("proofreads", "proofread", "proofreading", "proofread"),
-    "propel" => ("propels", "propelled", "propelling", "propelled"),
-    "psych" => ("psychs", "psyched", "psyching", "psyched"),
-    "pub" => ("pubs", "pubbed", "pubbing", "pubbed"),
-    "public" => ("publics", "publicked", "publicking", "publicked"),
-    "pubstomp" => ("pubstomps", "pubstomped", "pubstomping", "pubstomped"),
-    "pug2" => ("pugs", "puged", "puging", "puged"),
-    "pulley" => ("pulleys", "pulleyed", "pulleying", "pulleyed"),
-    "pulp" => ("pulps", "pulped", "pulping", "pulped"),
-    "pump" => ("pumps", "pumped", "pumping", "pumped"),
-    "pun" => ("puns", "punned", "punning", "punned"),
-    "puppysit" => ("puppysits", "puppysat", "puppysitting", "puppysat"),
-    "pur" => ("purs", "purred", "purring", "purred"),
-    "puree" => ("purees", "pureed", "pureeing", "pureed"),
-    "purvey" => ("purveys", "purveyed", "purveying", "purveyed"),
-    "pus" => ("pusses", "pussed", "pussing", "pussed"),
-    "pushpin" => ("pushpins", "pushpinned", "pushpinning", "pushpinned"),
-    "put" => ("puts", "put", "putting", "put"),
-    "putt2" => ("putts", "putt", "putting", "putt"),
-    "pvp" => ("pvps", "pvped", "pvping", "pvped"),
-    "qrp" => ("qrps", "qrped", "qrping", "qrped"),
-    "quab" => ("quabs", "quabbed", "quabbing", "quabbed"),
-    "quad" => ("quads", "quadded", "quadding", "quadded"),
-    "quadrat" => ("quadrats", "quadratted", "quadratting", "quadratted"),
-    "quaere" => ("quaeres", "quaered", "quaereing", "quaered"),
-    "quahaug" => ("quahaugs", "quahauged", "quahauging", "quahauged"),
-    "quar" => ("quars", "quarred", "quarring", "quarred"),
-    "quartersaw" => ("quartersaws", "quartersawed", "quartersawing", "quartersawn"),
-    "quat" => ("quats", "quatted", "quatting", "quatted"),
-    "quay" => ("quays", "quayed", "quaying", "quayed"),
-    "queep" => ("queeps", "queeped", "queeping", "queeped"),
-    "queue" => ("queues", "queued", "queueing", "queued"),
-    "quid" => ("quids", "quidded", "quidding", "quidded"),
-    "quit" => ("quits", "quit", "quitting", "quit"),
-    "quiz" => ("quizzes", "quizzed", "quizzing", "quizzed"),
-    "quob" => ("quobs", "quobbed", "quobbing", "quobbed"),
-    "quod" => ("quods", "quodded", "quodding", "quodded"),
-    "rabat" => ("rabats", "rabatted", "rabatting", "rabatted"),
-    "racebend" => ("racebends", "racebent", "racebending", "racebent"),
-    "radge" => ("radges", "radged", "radgin", "radged"),
-    "radioassay" => ("radioassays", "radioassayed", "radioassaying", "radioassayed"),
-    "radiobroadcast" => ("radiobroadcasts", "radiobroadcast", "radiobroadcasting", "radiobroadcast"),
-    "radiocast" => ("radiocasts", "radiocast", "radiocasting", "radiocast"),
-    "radioimmunoassay" => ("radioimmunoassays", "radioimmunoassayed", "radioimmunoassaying", "radioimmunoassayed"),
-    "radioscan" => ("radioscans", "radioscanned", "radioscanning", "radioscanned"),
-    "ragequit" => ("ragequits", "ragequit", "ragequitting", "ragequit"),
-    "ram" => ("rams", "rammed", "ramming", "rammed"),
-    "ramp" => ("ramps", "ramped", "ramping", "ramped"),
-    "ramrod" => ("ramrods", "ramrodded", "ramrodding", "ramrodded"),
-    "ramset" => ("ramsets", "ramsetted", "ramsetting", "ramsetted"),
-    "rappel" => ("rappels", "rappelled", "rappelling", "rappelled"),
-    "rasp" => ("rasps", "rasped", "rasping", "rasped"),
-    "rat" => ("rats", "ratted", "ratting", "ratted"),
-    "rataplan" => ("rataplans", "rataplanned", "rataplanning", "rataplanned"),
-    "ray" => ("rays", "rayed", "raying", "rayed"),
-    "razee" => ("razees", "razeed", "razeeing", "razeed"),
-    "reaccession" => ("reaccession", "reaccessioned", "reaccessioning", "reaccessioned"),
-    "reacquit" => ("reacquits", "reacquitted", "reacquitting", "reacquitted"),
-    "read" => ("reads", "read", "reading", "read"),
-    "readmit" => ("readmits", "readmitted", "readmitting", "readmitted"),
-    "reagree" => ("reagrees", "reagreed", "reagreeing", "reagreed"),
-    "reallot" => ("reallots", "reallotted", "reallotting", "reallotted"),
-    "realloy" => ("realloys", "realloyed", "realloying", "realloyed"),
-    "reap" => ("reaps", "reaped", "reaping", "reaped"),
-    "rearise" => ("rearises", "rearose", "rearising", "rearisen"),
-    "rearray" => ("rearrays", "rearrayed", "rearraying", "rearrayed"),
-    "reassay" => ("reassays", "reassayed", "reassaying", "reassayed"),
-    "reave2" => ("reaves", "reft", "reaving", "reft"),
-    "reawake" => ("reawakes", "reawoke", "reawaking", "reawoken"),
-    "reban" => ("rebans", "rebanned", "rebanning", "rebanned"),
-    "rebar" => ("rebars", "rebarred", "rebarring", "rebarred"),
-    "rebat" => ("rebats", "rebatted", "rebatting", "rebatted"),
-    "rebear" => ("rebears", "rebore", "rebearing", "reborne"),
-    "rebeat" => ("rebeats", "rebeat", "rebeating", "rebeaten"),
-    "rebecome" => ("rebecomes", "rebecame", "rebecoming", "rebecome"),
-    "rebed" => ("rebeds", "rebedded", "rebedding", "rebedded"),
-    "rebeget" => ("rebegets", "rebegot", "rebegetting", "rebegotten"),
-    "rebegin" => ("rebegins", "rebegan", "rebeginning", "rebegun"),
-    "rebehold" => ("rebeholds", "rebeheld", "rebeholding", "rebeheld"),
-    "rebel" => ("rebels", "rebelled", "rebelling", "rebelled"),
-    "rebend" => ("rebends", "rebent", "rebending", "rebent"),
-    "rebetray" => ("rebetrays", "rebetrayed", "rebetraying", "rebetrayed"),
-    "rebid" => ("rebids", "rebid", "rebidding", "rebid"),
-    "rebin" => ("rebins", "rebinned", "rebinning", "rebinned"),
-    "rebind" => ("rebinds", "rebound", "rebinding", "rebound"),
-    "rebite" => ("rebites", "rebit", "rebiting", "rebitten"),
-    "rebleed" => ("rebleeds", "rebled", "rebleeding", "rebled"),
-    "reblit" => ("reblits", "reblitted", "reblitting", "reblitted"),
-    "reblot" => ("reblots", "reblotted", "reblotting", "reblotted"),
-    "reblow" => ("reblows", "reblew", "reblowing", "reblown"),
-    "reblue" => ("reblues", "reblued", "reblueing", "reblued"),
-    "rebreak" => ("rebreaks", "rebroke", "rebreaking", "rebroken"),
-    "rebreed" => ("rebreeds", "rebred", "rebreeding", "rebred"),
-    "rebring" => ("rebrings", "rebrought", "rebringing", "rebrought"),
-    "rebroadcast" => ("rebroadcasts", "rebroadcast", "rebroadcasting", "rebroadcast"),
-    "rebud" => ("rebuds", "rebudded", "rebudding", "rebudded"),
-    "rebuild" => ("rebuilds", "rebuilt", "rebuilding", "rebuilt"),
-    "rebump" => ("rebumps", "rebumped", "rebumping", "rebumped"),
-    "reburst" => ("rebursts", "reburst", "rebursting", "reburst"),
-    "rebut" => ("rebuts", "rebutted", "rebutting", "rebutted"),
-    "rec" => ("recs", "recced", "reccing", "recced"),
-    "recal" => ("recals", "recalled", "recalling", "recalled"),
-    "recam" => ("recams", "recammed", "recamming", "recammed"),
-    "recan" => ("recans", "recanned", "recanning", "recanned"),
-    "recast" => ("recasts", "recast", "recasting", "recast"),
-    "recatalog" => ("recatalogs", "recataloged", "recataloging", "recataloged"),
-    "recatch" => ("recatches", "recaught", "recatching", "recaught"),
-    "recce" => ("recces", "recced", "recceing", "recced"),
-    "rechoose" => ("rechooses", "rechose", "rechoosing", "rechosen"),
-    "reclad" => ("reclads", "recladded", "recladding", "recladded"),
-    "reclamp" => ("reclamps", "reclamped", "reclamping", "reclamped"),
-    "reclasp" => ("reclasps", "reclasped", "reclasping", "reclasped"),
-    "recommit" => ("recommits", "recommitted", "recommitting", "recommitted"),
-    "recomp" => ("recomps", "recomped", "recomping", "recomped"),
-    "recompel" => ("recompels", "recompelled", "recompelling", "recompelled"),
-    "recon" => ("recons", "reconned", "reconning", "reconned"),
-    "reconfer" => ("reconfers", "reconferred", "reconferring", "reconferred"),
-    "recontrol" => ("recontrols", "recontrolled", "recontrolling", "recontrolled"),
-    "reconvey" => ("reconveys", "reconveyed", "reconveying", "reconveyed"),
-    "recoup" => ("recoups", "recouped", "recouping", "recouped"),
-    "recramp" => ("recramps", "recramped", "recramping", "recramped"),
-    "recur" => ("recurs", "recurred", "recurring", "recurred"),
-    "recut" => ("recuts", "recut", "recutting", "recut"),
-    "red" => ("reds", "redded", "redding", "redded"),
-    "redam" => ("redams", "redammed", "redamming", "redammed"),
-    "redd2" => ("redds", "redd", "redding", "redd"),
-    "rede" => ("redes", "red", "reding", "red"),
-    "redeal" => ("redeals", "redealt", "redealing", "redealt"),
-    "redecree" => ("redecrees", "redecreed", "redecreeing", "redecreed"),
-    "redefer" => ("redefers", "redeferred", "redeferring", "redeferred"),
-    "redelay" => ("redelays", "redelayed", "redelaying", "redelayed"),
-    "redeploy" => ("redeploys", "redeployed", "redeploying", "redeployed"),
-    "redestroy" => ("redestroys", "redestroyed", "redestroying", "redestroyed"),
-    "redevelop" => ("redevelops", "redeveloped", "redeveloping", "redeveloped"),
-    "rediagram" => ("rediagrams", "rediagrammed", "rediagramming", "rediagrammed"),
-    "redie" => ("redies", "redied", "redying", "redied"),
-    "redig" => ("redigs", "redug", "redigging", "redug"),
-    "redispel" => ("redispels", "redispelled", "redispelling", "redispelled"),
-    "redisplay" => ("redisplays", "redisplayed", "redisplaying", "redisplayed"),
-    "redistil" => ("redistils", "redistilled", "redistilling", "redistilled"),
-    "redlight" => ("redlights", "redlit", "redlighting", "redlit"),
-    "redo" => ("redoes", "redid", "redoing", "redone"),
-    "redon" => ("redons", "redonned", "redonning", "redonned"),
-    "redraw" => ("redraws", "redrew", "redrawing", "redrawn"),
-    "redrink" => ("redrinks", "redrank", "redrinking", "redrunk"),
-    "redrive" => ("redrives", "redrove", "redriving", "redriven"),
-    "redrum" => ("redrums", "redrummed", "redrumming", "redrummed"),
-    "redub" => ("redubs", "redubbed", "redubbing", "redubbed"),
-    "redump" => ("redumps", "redumped", "redumping", "redumped"),
-    "redye" => ("redyes", "redyed", "redyeing", "redyed"),
-    "ree" => ("rees", "reed", "reeing", "reed"),
-    "reeat" => ("reeats", "reate", "reeating", "reeaten"),
-    "reembed" => ("reembeds", "reembedded", "reembedding", "reembedded"),
-    "reemit" => ("reemits", "reemitted", "reemitting", "reemitted"),
-    "reemploy" => ("reemploys", "reemployed", "reemploying", "reemployed"),
-    "reenjoy" => ("reenjoys", "reenjoyed", "reenjoying", "reenjoyed"),
-    "reenrol" => ("reenrols", "reenrolled", "reenrolling", "reenrolled"),
-    "reenvelop" => ("reenvelops", "reenveloped", "reenveloping", "reenveloped"),
-    "reexpel" => ("reexpels", "reexpelled", "reexpelling", "reexpelled"),
-    "ref" => ("refs", "reffed", "reffing", "reffed"),
-    "refall" => ("refalls", "refell", "refalling", "refallen"),
-    "refan" => ("refans", "refanned", "refanning", "refanned"),
-    "refeed" => ("refeeds", "refed", "refeeding", "refed"),
-    "refeel" => ("refeels", "refelt", "refeeling", "refelt"),
-    "refel" => ("refels", "refelled", "refelling", "refelled"),
-    "refer" => ("refers", "referred", "referring", "referred"),
-    "referee" => ("referees", "refereed", "refereeing", "refereed"),
-    "refind" => ("refinds", "refound", "refinding", "refound"),
-    "refit" => ("refits", "refitted", "refitting", "refitted"),
-    "refly" => ("reflies", "reflew", "reflying", "reflown"),
-    "reforbid" => ("reforbids", "reforbade", "reforbidding", "reforbidden"),
-    "reforecast" => ("reforecasts", "reforecast", "reforecasting", "reforecast"),
-    "reforget" => ("reforgets", "reforgot", "reforgetting", "reforgotten"),
-    "reforgive" => ("reforgives", "reforgave", "reforgiving", "reforgiven"),
-    "reformat" => ("reformats", "reformatted", "reformatting", "reformatted"),
-    "refreeze" => ("refreezes", "refroze", "refreezing", "refrozen"),
-    "refret" => ("refrets", "refretted", "refretting", "refretted"),
-    "refugee" => ("refugees", "refugeed", "refugeeing", "refugeed"),
-    "regas" => ("regases", "regassed", "regassing", "regassed"),
-    "regen" => ("regens", "regenned", "regenning", "regenned"),
-    "reget" => ("regets", "regot", "regetting", "regot"),
-    "regive" => ("regives", "regave", "regiving", "regiven"),
-    "regrab" => ("regrabs", "regrabbed", "regrabbing", "regrabbed"),
-    "regram" => ("regrams", "regrammed", "regramming", "regrammed"),
-    "regrasp" => ("regrasps", "regrasped", "regrasping", "regrasped"),
-    "regret" => ("regrets", "regretted", "regretting", "regretted"),
-    "regrind" => ("regrinds", "reground", "regrinding", "reground"),
-    "regrit" => ("regrits", "regritted", "regritting", "regritted"),
-    "regroup" => ("regroups", "regrouped", "regrouping", "regrouped"),
-    "regrow" => ("regrows", "regrew", "regrowing", "regrown"),
-    "rehab" => ("rehabs", "rehabbed", "rehabbing", "rehabbed"),
-    "rehat" => ("rehats", "rehatted", "rehatting", "rehatted"),
-    "rehave" => ("rehas", "rehad", "rehaving", "rehad"),
-    "reheap" => ("reheaps", "reheaped", "reheaping", "reheaped"),
-    "rehear" => ("rehears", "reheard", "rehearing", "reheard"),
-    "rehem" => ("rehems", "rehemmed", "rehemming", "rehemmed"),
-    "rehew" => ("rehews", "rehewed", "rehewing", "rehewn"),
-    "rehide" => ("rehides", "rehid", "rehiding", "rehidden"),
-    "rehit" => ("rehits", "rehit", "rehitting", "rehit"),
-    "rehoe" => ("rehoes", "rehoed", "rehoeing", "rehoed"),
-    "rehoop" => ("rehoops", "rehooped", "rehooping", "rehooped"),
-    "reimpel" => ("reimpels", "reimpelled", "reimpelling", "reimpelled"),
-    "reincur" => ("reincurs", "reincurred", "reincurring", "reincurred"),
-    "reinfer" => ("reinfers", "reinferred", "reinferring", "reinferred"),
-    "reinput" => ("reinputs", "reinput", "reinputting", "reinput"),
-    "reinstil" => ("reinstils", "reinstilled", "reinstilling", "reinstilled"),
-    "reinter" => ("reinters", "reinterred", "reinterring", "reinterred"),
-    "rejapan" => ("rejapans", "rejapanned", "rejapanning", "rejapanned"),
-    "rejar" => ("rejars", "rejarred", "rejarring", "rejarred"),
-    "rejet" => ("rejets", "rejetted", "rejetting", "rejetted"),
-    "rejourney" => ("rejourneys", "rejourneyed", "rejourneying", "rejourneyed"),
-    "rejoy" => ("rejoys", "rejoyed", "rejoying", "rejoyed"),
-    "rejump" => ("rejumps", "rejumped", "rejumping", "rejumped"),
-    "rekey" => ("rekeys", "rekeyed", "rekeying", "rekeyed"),
-    "rekit" => ("rekits", "rekitted", "rekitting", "rekitted"),
-    "reknit" => ("reknits", "reknit", "reknitting", "reknit"),
-    "reknot" => ("reknots", "reknotted", "reknotting", "reknotted"),
-    "reknow" => ("reknows", "reknew", "reknowing", "reknown"),
-    "relamp" => ("relamps", "relamped", "relamping", "relamped"),
-    "relay" => ("relays", "relaid", "relaying", "relaid"),
-    "relay2" => ("relays", "relayed", "relaying", "relayed"),
-    "relead2" => ("releads", "reled", "releading", "reled"),
-    "releap" => ("releaps", "releaped", "releaping", "releaped"),
-    "relend" => ("relends", "relent", "relending", "relent"),
-    "relet" => ("relets", "relet", "reletting", "relet"),
-    "reloop" => ("reloops", "relooped", "relooping", "relooped"),
-    "relose" => ("reloses", "relost", "relosing", "relost"),
-    "remake" => ("remakes", "remade", "remaking", "remade"),
-    "reman" => ("remans", "remanned", "remanning", "remanned"),
-    "remeet" => ("remeets", "remet", "remeeting", "remet"),
-    "remit" => ("remits", "remitted", "remitting", "remitted"),
-    "renay" => ("renays", "renayed", "renaying", "renayed"),
-    "rend" => ("rends", "rent", "rending", "rent"),
-    "rendevous" => ("rendevous", "rendevoused", "rendevousing", "rendevoused"),
-    "reneg" => ("renegs", "reneged", "reneging", "reneged"),
-    "reney" => ("reneys", "reneyed", "reneying", "reneyed"),
-    "renib" => ("renibs", "renibbed", "renibbing", "renibbed"),
-    "renod" => ("renods", "renodded", "renodding", "renodded"),
-    "renvoy" => ("renvoys", "renvoyed", "renvoying", "renvoyed"),
-    "reoccur" => ("reoccurs", "reoccurred", "reoccurring", "reoccurred"),
-    "reorg" => ("reorgs", "reorged", "reorging", "reorged"),
-    "reoutfit" => ("reoutfits", "reoutfitted", "reoutfitting", "reoutfitted"),
-    "reoutput" => ("reoutputs", "reoutput", "reoutputting", "reoutput"),
-    "reovertake" => ("reovertakes", "reovertook", "reovertaking", "reovertaken"),
-    "repad" => ("repads", "repadded", "repadding", "repadded"),
-    "repartake" => ("repartakes", "repartook", "repartaking", "repartaken"),
-    "repartee" => ("repartees", "reparteed", "reparteeing", "reparteed"),
-    "repatrol" => ("repatrols", "repatrolled", "repatrolling", "repatrolled"),
-    "repay" => ("repays", "repaid", "repaying", "repaid"),
-    "repay2" => ("repays", "repayed", "repaying", "repayed"),
-    "repel" => ("repels", "repelled", "repelling", "repelled"),
-    "repen" => ("repens", "repenned", "repenning", "repenned"),
-    "repermit" => ("repermits", "repermitted", "repermitting", "repermitted"),
-    "repin" => ("repins", "repinned", "repinning", "repinned"),
-    "replan" => ("replans", "replanned", "replanning", "replanned"),
-    "replat" => ("replats", "replatted", "replatting", "replatted"),
-    "replay" => ("replays", "replayed", "replaying", "replayed"),
-    "replead" => ("repleads", "repled", "repleading", "repled"),
-    "replot" => ("replots", "replotted", "replotting", "replotted"),
-    "repod" => ("repods", "repodded", "repodding", "repodded"),
-    "repot" => ("repots", "repotted", "repotting", "repotted"),
-    "repray" => ("reprays", "reprayed", "repraying", "reprayed"),
-    "reprogram" => ("reprograms", "reprogrammed", "reprogramming", "reprogrammed"),
-    "repropel" => ("repropels", "repropelled", "repropelling", "repropelled"),
-    "repulp" => ("repulps", "repulped", "repulping", "repulped"),
-    "repump" => ("repumps", "repumped", "repumping", "repumped"),
-    "reput" => ("reputs", "reput", "reputting", "reput"),
-    "requit" => ("requits", "requitted", "requitting", "requitted"),
-    "requiz" => ("requizzes", "requizzed", "requizzing", "requizzed"),
-    "reread" => ("rereads", "reread", "rereading", "reread"),
-    "rerefer" => ("rerefers", "rereferred", "rereferring", "rereferred"),
-    "reremake" => ("reremakes", "reremade", "reremaking", "reremade"),
-    "rerise" => ("rerises", "rerose", "rerising", "rerisen"),
-    "rerub" => ("rerubs", "rerubbed", "rerubbing", "rerubbed"),
-    "rerun" => ("reruns", "reran", "rerunning", "rerun"),
-    "res" => ("resses", "ressed", "ressing", "ressed"),
-    "resaw" => ("resaws", "resawed", "resawing", "resawn"),
-    "resay" => ("resays", "resaid", "resaying", "resaid"),
-    "rescan" => ("rescans", "rescanned", "rescanning", "rescanned"),
-    "rescoop" => ("rescoops", "rescooped", "rescooping", "rescooped"),
-    "rescrub" => ("rescrubs", "rescrubbed", "rescrubbing", "rescrubbed"),
-    "resee" => ("resees", "resaw", "reseeing", "reseen"),
-    "reseek" => ("reseeks", "resought", "reseeking", "resought"),
-    "resell" => ("resells", "resold", "reselling", "resold"),
-    "resend" => ("resends", "resent", "resending", "resent"),
-    "reset" => ("resets", "reset", "resetting", "reset"),
-    "reset2" => ("resets", "resetted", "resetting", "resetted"),
-    "reshake" => ("reshakes", "reshook", "reshaking", "reshaken"),
-    "reshear" => ("reshears", "resheared", "reshearing", "reshorn"),
-    "reshim" => ("reshims", "reshimmed", "reshimming", "reshimmed"),
-    "reshoe" => ("reshoes", "reshoed", "reshoeing", "reshoed"),
-    "reshoot" => ("reshoots", "reshot", "reshooting", "reshot"),
-    "reshow" => ("reshows", "reshowed", "reshowing", "reshown"),
-    "reshrink" => ("reshrinks", "reshrank", "reshrinking", "reshrunk"),
-    "reshut" => ("reshuts", "reshut", "reshutting", "reshut"),
-    "resing" => ("resings", "resang", "resinging", "resung"),
-    "resink" => ("resinks", "resank", "resinking", "resunk"),
-    "resit" => ("resits", "resat", "resitting", "resat"),
-    "reskim" => ("reskims", "reskimmed", "reskimming", "reskimmed"),
-    "reskin" => ("reskins", "reskinned", "reskinning", "reskinned"),
-    "reslay" => ("reslays", "reslew", "reslaying", "reslain"),
-    "reslide" => ("reslides", "reslid", "resliding", "reslid"),
-    "resling" => ("reslings", "reslung", "reslinging", "reslung"),
-    "reslot" => ("reslots", "reslotted", "reslotting", "reslotted"),
-    "resnub" => ("resnubs", "resnubbed", "resnubbing", "resnubbed"),
-    "resoap" => ("resoaps", "resoaped", "resoaping", "resoaped"),
-    "resod" => ("resods", "resodded", "resodding", "resodded"),
-    "resow" => ("resows", "resowed", "resowing", "resown"),
-    "resp" => ("resps", "resped", "resping", "resped"),
-    "respeak" => ("respeaks", "respoke", "respeaking", "respoken"),
-    "respec" => ("respecs", "respecced", "respeccing", "respecced"),
-    "respend" => ("respends", "respent", "respending", "respent"),
-    "respin" => ("respins", "respun", "respinning", "respun"),
-    "resplit" => ("resplits", "resplit", "resplitting", "resplit"),
-    "respot" => ("respots", "respotted", "respotting", "respotted"),
-    "respray" => ("resprays", "resprayed", "respraying", "resprayed"),
-    "respread" => ("respreads", "respread", "respreading", "respread"),
-    "respring" => ("resprings", "resprang", "respringing", "resprung"),
-    "restamp" => ("restamps", "restamped", "restamping", "restamped"),
-    "restand" => ("restands", "restood", "restanding", "restood"),
-    "resteal" => ("resteals", "restole", "restealing", "restolen"),
-    "resteep" => ("resteeps", "resteeped", "resteeping", "resteeped"),
-    "restem" => ("restems", "restemmed", "restemming", "restemmed"),
-    "restick" => ("resticks", "restuck", "resticking", "restuck"),
-    "resting" => ("restings", "restung", "restinging", "restung"),
-    "restir" => ("restirs", "restirred", "restirring", "restirred"),
-    "restrike" => ("restrikes", "restruck", "restriking", "restruck"),
-    "restring" => ("restrings", "restrung", "restringing", "restrung"),
-    "restrive" => ("restrives", "restrove", "restriving", "restriven"),
-    "restucco" => ("restuccoes", "restuccoed", "restuccoing", "restuccoed"),
-    "restump" => ("restumps", "restumped", "restumping", "restumped"),
-    "resub" => ("resubs", "resubbed", "resubbing", "resubbed"),
-    "resubmit" => ("resubmits", "resubmitted", "resubmitting", "resubmitted"),
-    "resum" => ("resums", "resummed", "resumming", "resummed"),
-    "resurveil" => ("resurveils", "resurveilled", "resurveilling", "resurveilled"),
-    "resurvey" => ("resurveys", "resurveyed", "resurveying", "resurveyed"),
-    "reswear" => ("reswears", "reswore", "reswearing", "resworn"),
-    "resweep" => ("resweeps", "reswept", "resweeping", "reswept"),
-    "reswell" => ("reswells", "reswelled", "reswelling", "reswollen"),
-    "reswim" => ("reswims", "reswam", "reswimming", "reswum"),
-    "resynch" => ("resynchs", "resynched", "resynching", "resynched"),
-    "ret" => ("rets", "retted", "retting", "retted"),
-    "retake" => ("retakes", "retook", "retaking", "retaken"),
-    "retan" => ("retans", "retanned", "retanning", "retanned"),
-    "retar" => ("retars", "retarred", "retarring", "retarred"),
-    "retarmac" => ("retarmacs", "retarmacked", "retarmacking", "retarmacked"),
-    "retcon" => ("retcons", "retconned", "retconning", "retconned"),
-    "reteach" => ("reteaches", "retaught", "reteaching", "retaught"),
-    "retear" => ("retears", "retore", "retearing", "retorn"),
-    "retee" => ("retees", "reteed", "reteeing", "reteed"),
-    "retelecast" => ("retelecasts", "retelecast", "retelecasting", "retelecast"),
-    "retell" => ("retells", "retold", "retelling", "retold"),
-    "rethink" => ("rethinks", "rethought", "rethinking", "rethought"),
-    "rethrow" => ("rethrows", "rethrew", "rethrowing", "rethrown"),
-    "rethrust" => ("rethrusts", "rethrust", "rethrusting", "rethrust"),
-    "retie" => ("reties", "retied", "retying", "retied"),
-    "retin" => ("retins", "retinned", "retinning", "retinned"),
-    "retramp" => ("retramps", "retramped", "retramping", "retramped"),
-    "retransfer" => ("retransfers", "retransferred", "retransferring", "retransferred"),
-    "retransmit" => ("retransmits", "retransmitted", "retransmitting", "retransmitted"),
-    "retread2" => ("retreads", "retrod", "retreading", "retrodden"),
-    "retrim" => ("retrims", "retrimmed", "retrimming", "retrimmed"),
-    "retrocue" => ("retrocues", "retrocued", "retrocueing", "retrocued"),
-    "retrofit" => ("retrofits", "retrofitted", "retrofitting", "retrofitted"),
-    "retromod" => ("retromods", "retromodded", "retromodding", "retromodded"),
-    "retrotransfer" => ("retrotransfers", "retrotransferred", "retrotransferring", "retrotransferred"),
-    "retrue" => ("retrues", "retrued", "retrueing", "retrued"),
-    "reundercut" => ("reundercuts", "reundercut", "reundercutting", "reundercut"),
-    "reundergo" => ("reundergoes", "reunderwent", "reundergoing", "reundergone"),
-    "reunderstand" => ("reunderstands", "reunderstood", "reunderstanding", "reunderstood"),
-    "reundertake" => ("reundertakes", "reundertook", "reundertaking", "reundertaken"),
-    "reuptake" => ("reuptakes", "reuptook", "reuptaking", "reuptaken"),
-    "reusurp" => ("reusurps", "reusurped", "reusurping", "reusurped"),
-    "rev" => ("revs", "revved", "revving", "revved"),
-    "revamp" => ("revamps", "revamped", "revamping", "revamped"),
-    "revel2" => ("revels", "revelled", "revelling", "revelled"),
-    "reverie" => ("reveries", "reveried", "reverying", "reveried"),
-    "revet" => ("revets", "revetted", "revetting", "revetted"),
-    "revie" => ("revies", "revied", "revying", "revied"),
-    "rewake" => ("rewakes", "rewoke", "rewaking", "rewoken"),
-    "rewear" => ("rewears", "rewore", "rewearing", "reworn"),
-    "reweave" => ("reweaves", "rewove", "reweaving", "rewoven"),
-    "rewed" => ("reweds", "rewed", "rewedding", "rewed"),
-    "rewet" => ("rewets", "rewet", "rewetting", "rewet"),
-    "rewin" => ("rewins", "rewon", "rewinning", "rewon"),
-    "rewind" => ("rewinds", "rewound", "rewinding", "rewound"),
-    "rewithdraw" => ("rewithdraws", "rewithdrew", "rewithdrawing", "rewithdrawn"),
-    "rewring" => ("rewrings", "rewrung", "rewringing", "rewrung"),
-    "rewrite" => ("rewrites", "rewrote", "rewriting", "rewritten"),
-    "rez" => ("rezs", "rezed", "rezing", "rezed"),
-    "rib" => ("ribs", "ribbed", "ribbing", "ribbed"),
-    "rid" => ("rids", "rid", "ridding", "rid"),
-    "ride" => ("rides", "rode", "riding", "ridden"),
-    "rif2" => ("rifs", "riffed", "riffing", "riffed"),
-    "rim" => ("rims", "rimmed", "rimming", "rimmed"),
-    "rin" => ("rins", "ran", "rinning", "run"),
-    "ring2" => ("rings", "rang", "ringing", "rung"),
-    "ringlead" => ("ringleads", "ringled", "ringleading", "ringled"),
-    "rise" => ("rises", "rose", "rising", "risen"),
-    "risp" => ("risps", "risped", "risping", "risped"),
-    "rit" => ("rits", "ritted", "ritting", "ritted"),
-    "rize" => ("rizes", "roze", "rizing", "rizen"),
-    "rmgroup" => ("rmgroups", "rmgrouped", "rmgrouping", "rmgrouped"),
-    "roadie" => ("roadies", "roadied", "roadying", "roadied"),
-    "roadshow" => ("roadshows", "roadshowed", "roadshowing", "roadshown"),
-    "roap" => ("roaps", "roaped", "roaping", "roaped"),
-    "rob" => ("robs", "robbed", "robbing", "robbed"),
-    "robocast" => ("robocasts", "robocast", "robocasting", "robocast"),
-    "rockabye" => ("rockabyes", "rockabyed", "rockabyeing", "rockabyed"),
-    "rod" => ("rods", "rodded", "rodding", "rodded"),
-    "roentgenogram" => ("roentgenograms", "roentgenogrammed", "roentgenogramming", "roentgenogrammed"),
-    "roflstomp" => ("roflstomps", "roflstomped", "roflstomping", "roflstomped"),
-    "roleplay" => ("roleplays", "roleplayed", "roleplaying", "roleplayed"),
-    "romp" => ("romps", "romped", "romping", "romped"),
-    "ronne" => ("ronnes", "ronne", "ronning", "ronne"),
-    "roofie" => ("roofies", "roofied", "roofieing", "roofied"),
-    "rookie" => ("rookies", "rookied", "rookying", "rookied"),
-    "roop" => ("roops", "rooped", "rooping", "rooped"),
-    "rootkit" => ("rootkits", "rootkitted", "rootkitting", "rootkitted"),
-    "rot" => ("rots", "rotted", "rotting", "rotted"),
-    "rotisserie" => ("rotisseries", "rotisseried", "rotisserieing", "rotisseried"),
-    "roughcast" => ("roughcasts", "roughcast", "roughcasting", "roughcast"),
-    "roughdraw" => ("roughdraws", "roughdrew", "roughdrawing", "roughdrawn"),
-    "roup" => ("roups", "rouped", "rouping", "rouped"),
-    "route" => ("routes", "routed", "routeing", "routed"),
-    "rp" => ("rps", "rped", "rping", "rped"),
-    "rpg" => ("rpgs", "rpged", "rpging", "rpged"),
-    "rsvp" => ("rsvps", "rsvped", "rsvping", "rsvped"),
-    "rts" => ("rtss", "rtsed", "rtsing", "rtsed"),
-    "rub" => ("rubs", "rubbed", "rubbing", "rubbed"),
-    "rubberstamp" => ("rubberstamps", "rubberstamped", "rubberstamping", "rubberstamped"),
-    "rubric" => ("rubrics", "rubricked", "rubricking", "rubricked"),
-    "rud" => ("ruds", "rudded", "rudding", "rudded"),
-    "rufie" => ("rufies", "rufied", "rufieing", "rufied"),
-    "rump" => ("rumps", "rumped", "rumping", "rumped"),
-    "run" => ("runs", "ran", "running", "run"),
-    "rut" => ("ruts", "rutted", "rutting", "rutted"),
-    "sab" => ("sabs", "sabbed", "sabbing", "sabbed"),
-    "sac" => ("sacs", "sacked", "sacking", "sacked"),
-    "sad" => ("sads", "sadded", "sadding", "sadded"),
-    "safekeep" => ("safekeeps", "safekept", "safekeeping", "safekept"),
-    "sam" => ("sams", "sammed", "samming", "sammed"),
-    "sandcast" => ("sandcasts", "sandcast", "sandcasting", "sandcast"),
-    "sangaree" => ("sangarees", "sangareed", "sangareeing", "sangareed"),
-    "sashay" => ("sashays", "sashayed", "sashaying", "sashayed"),
-    "satcast" => ("satcasts", "satcast", "satcasting", "satcast"),
-    "saturday" => ("saturdays", "saturdayed", "saturdaying", "saturdayed"),
-    "saucepan" => ("saucepans", "saucepanned", "saucepanning", "saucepanned"),
-    "saute" => ("sautes", "sauteed", "sauteing", "sauteed"),
-    "sautee" => ("sautees", "sauteed", "sauteeing", "sauteed"),
-    "savescum" => ("savescums", "savescummed", "savescumming", "savescummed"),
-    "savvey" => ("savveys", "savveyed", "savveying", "savveyed"),
-    "say" => ("says", "said", "saying", "said"),
-    "say2" => ("says", "sayed", "saying", "sayed"),
-    "saye" => ("sayes", "saide", "sayeing", "saide"),
-    "scab" => ("scabs", "scabbed", "scabbing", "scabbed"),
-    "scallop" => ("scallops", "scalloped", "scalloping", "scalloped"),
-    "scalp" => ("scalps", "scalped", "scalping", "scalped"),
-    "scam" => ("scams", "scammed", "scamming", "scammed"),
-    "scamp" => ("scamps", "scamped", "scamping", "scamped"),
-    "scan" => ("scans", "scanned", "scanning", "scanned"),
-    "scar" => ("scars", "scarred", "scarring", "scarred"),
-    "scarp" => ("scarps", "scarped", "scarping", "scarped"),
-    "scat" => ("scats", "scatted", "scatting", "scatted"),
-    "scatterplot" => ("scatterplots", "scatterplotted", "scatterplotting", "scatterplotted"),
-    "schiz" => ("schizs", "schized", "schizing", "schized"),
-    "schlepp" => ("schlepps", "schlepped", "schlepping", "schlepped"),
-    "schloop" => ("schloops", "schlooped", "schlooping", "schlooped"),
-    "schlump" => ("schlumps", "schlumped", "schlumping", "schlumped"),
-    "schlurp" => ("schlurps", "schlurped", "schlurping", "schlurped"),
-    "scholarship" => ("scholarships", "scholarshiped", "scholarshiping", "scholarshiped"),
-    "schtoop" => ("schtoops", "schtooped", "schtooping", "schtooped"),
-    "scintiscan" => ("scintiscans", "scintiscanned", "scintiscanning", "scintiscanned"),
-    "scob" => ("scobs", "scobbed", "scobbing", "scobbed"),
-    "scollop" => ("scollops", "scolloped", "scolloping", "scolloped"),
-    "scomfit" => ("scomfits", "scomfit", "scomfiting", "scomfit"),
-    "scon" => ("scons", "sconned", "sconning", "sconned"),
-    "scoog" => ("scoogs", "scooged", "scooging", "scooged"),
-    "scoop" => ("scoops", "scooped", "scooping", "scooped"),
-    "scoug" => ("scougs", "scouged", "scouging", "scouged"),
-    "scrab" => ("scrabs", "scrabbed", "scrabbing", "scrabbed"),
-    "scram2" => ("scrams", "scrammed", "scramming", "scrammed"),
-    "scramp" => ("scramps", "scramped", "scramping", "scramped"),
-    "scran" => ("scrans", "scranned", "scranning", "scranned"),
-    "scrat" => ("scrats", "scratted", "scratting", "scratted"),
-    "scree" => ("screes", "screed", "screeing", "screed"),
-    "screengrab" => ("screengrabs", "screengrabbed", "screengrabbing", "screengrabbed"),
-    "screenplay" => ("screenplays", "screenplayed", "screenplaying", "screenplayed"),
-    "screenshoot" => ("screenshoots", "screenshot", "screenshooting", "screenshot"),
-    "screenshot" => ("screenshots", "screenshotted", "screenshotting", "screenshotted"),
-    "screenwrite" => ("screenwrites", "screenwrote", "screenwriting", "screenwritten"),
-    "screwdrive" => ("screwdrives", "screwdrove", "screwdriving", "screwdriven"),
-    "scrim" => ("scrims", "scrimmed", "scrimming", "scrimmed"),
-    "scrimp" => ("scrimps", "scrimped", "scrimping", "scrimped"),
-    "scrob" => ("scrobs", "scrobbed", "scrobbing", "scrobbed"),
-    "scroop" => ("scroops", "scrooped", "scrooping", "scrooped"),
-    "scrub" => ("scrubs", "scrubbed", "scrubbing", "scrubbed"),
-    "scrum" => ("scrums", "scrummed", "scrumming", "scrummed"),
-    "scrump" => ("scrumps", "scrumped", "scrumping", "scrumped"),
-    "scud" => ("scuds", "scudded", "scudding", "scudded"),
-    "sculp" => ("sculps", "sculped", "sculping", "sculped"),
-    "scum" => ("scums", "scummed", "scumming", "scummed"),
-    "scun" => ("scuns", "scunned", "scunning", "scunned"),
-    "scur" => ("scurs", "scurred", "scurring", "scurred"),
-    "scut" => ("scuts", "scut", "scutting", "scut"),
-    "sed" => ("seds", "sedded", "sedding", "sedded"),
-    "see" => ("sees", "saw", "seeing", "seen"),
-    "seech" => ("seeches", "sought", "seeching", "sought"),
-    "seek" => ("seeks", "sought", "seeking", "sought"),
-    "seep" => ("seeps", "seeped", "seeping", "seeped"),
-    "segue" => ("segues", "segued", "segueing", "segued"),
-    "segway" => ("segways", "segwayed", "segwaying", "segwayed"),
-    "selfie" => ("selfies", "selfied", "selfying", "selfied"),
-    "selfpub" => ("selfpubs", "selfpubbed", "selfpubbing", "selfpubbed"),
-    "sell" => ("sells", "sold", "selling", "sold"),
-    "send" => ("sends", "sent", "sending", "sent"),
-    "serogroup" => ("serogroups", "serogrouped", "serogrouping", "serogrouped"),
-    "set" => ("sets", "set", "setting", "set"),
-    "set2" => ("sets", "setted", "setting", "setted"),
-    "setnet" => ("setnets", "setnetted", "setnetting", "setnetted"),
-    "sew2" => ("sews", "sewed", "sewing", "sewn"),
-    "shab" => ("shabs", "shabbed", "shabbing", "shabbed"),
-    "shadowban" => ("shadowbans", "shadowbanned", "shadowbanning", "shadowbanned"),
-    "shadowcast" => ("shadowcasts", "shadowcast", "shadowcasting", "shadowcast"),
-    "shake" => ("shakes", "shook", "shaking", "shaken"),
-    "shall" => ("shall", "should", "shalling", "should"),
-    "sham" => ("shams", "shammed", "shamming", "shammed"),
-    "shambok" => ("shamboks", "shambokked", "shambokking", "shambokked"),
-    "shamoy" => ("shamoys", "shamoyed", "shamoying", "shamoyed"),
-    "shanghae" => ("shanghaes", "shanghaed", "shanghaeing", "shanghaed"),
-    "sharp" => ("sharps", "sharped", "sharping", "sharped"),
-    "sharpshoot" => ("sharpshoots", "sharpshot", "sharpshooting", "sharpshot"),
-    "shazam" => ("shazams", "shazammed", "shazamming", "shazammed"),
-    "shear" => ("shears", "sheared", "shearing", "shorn"),
-    "shed" => ("sheds", "shed", "shedding", "shed"),
-    "shed2" => ("sheds", "shedded", "shedding", "shedded"),
-    "shellac" => ("shellacs", "shellacked", "shellacking", "shellacked"),
-    "shend" => ("shends", "shent", "shending", "shent"),
-    "shende" => ("shendes", "shent", "shending", "shent"),
-    "shet" => ("shets", "shetted", "shetting", "shetted"),
-    "shew2" => ("shews", "shewed", "shewing", "shewn"),
-    "shh" => ("shhes", "shhed", "shhing", "shhed"),
-    "shid" => ("shids", "shid", "shidding", "shid"),
-    "shie" => ("shies", "shied", "shieing", "shied"),
-    "shikar" => ("shikars", "shikarred", "shikarring", "shikarred"),
-    "shim" => ("shims", "shimmed", "shimming", "shimmed"),
-    "shimmey" => ("shimmeys", "shimmeyed", "shimmeying", "shimmeyed"),
-    "shin" => ("shins", "shinned", "shinning", "shinned"),
-    "shine2" => ("shines", "shone", "shining", "shone"),
-    "shir" => ("shirs", "shirred", "shirring", "shirred"),
-    "shit" => ("shits", "shat", "shitting", "shat"),
-    "shitcan" => ("shitcans", "shitcanned", "shitcanning", "shitcanned"),
-    "shiv" => ("shivs", "shivved", "shivving", "shivved"),
-    "shivaree" => ("shivarees", "shivareed", "shivareeing", "shivareed"),
-    "shivari" => ("shivaries", "shivaried", "shivariing", "shivaried"),
-    "shlepp" => ("shlepps", "shlepped", "shlepping", "shlepped"),
-    "shlump" => ("shlumps", "shlumped", "shlumping", "shlumped"),
-    "shlurp" => ("shlurps", "shlurped", "shlurping", "shlurped"),
-    "shnor" => ("shnors", "shnorred", "shnorring", "shnorred"),
-    "shoe" => ("shoes", "shod", "shoeing", "shod"),
-    "shoop" => ("shoops", "shooped", "shooping", "shooped"),
-    "shoot" => ("shoots", "shot", "shooting", "shot"),
-    "shopsteal" => ("shopsteals", "shopstole", "shopstealing", "shopstolen"),
-    "shortcut" => ("shortcuts", "shortcutted", "shortcutting", "shortcutted"),
-    "shot" => ("shots", "shotted", "shotting", "shotted"),
-    "shotgun" => ("shotguns", "shotgunned", "shotgunning", "shotgunned"),
-    "should2" => ("should", "shoulded", "shoulding", "shoulded"),
-    "shovelbum" => ("shovelbums", "shovelbummed", "shovelbumming", "shovelbummed"),
-    "show" => ("shows", "showed", "showing", "shown"),
-    "showjump" => ("showjumps", "showjumped", "showjumping", "showjumped"),
-    "showrun" => ("showruns", "showran", "showrunning", "showrun"),
-    "shralp" => ("shralps", "shralped", "shralping", "shralped"),
-    "shram" => ("shrams", "shrammed", "shramming", "shrammed"),
-    "shred" => ("shreds", "shredded", "shredding", "shredded"),
-    "shrim" => ("shrims", "shrimmed", "shrimming", "shrimmed"),
-    "shrimp" => ("shrimps", "shrimped", "shrimping", "shrimped"),
-    "shrink" => ("shrinks", "shrank", "shrinking", "shrunk"),
-    "shrinke" => ("shrinkes", "shrank", "shrinking", "shrunke"),
-    "shrive" => ("shrives", "shrove", "shriving", "shriven"),
-    "shrub" => ("shrubs", "shrubbed", "shrubbing", "shrubbed"),
-    "shrump" => ("shrumps", "shrumped", "shrumping", "shrumped"),
-    "shun" => ("shuns", "shunned", "shunning", "shunned"),
-    "shut" => ("shuts", "shut", "shutting", "shut"),
-    "shxt" => ("shxts", "shxt", "shxtting", "shxt"),
-    "sib" => ("sibs", "sibbed", "sibbing", "sibbed"),
-    "sic" => ("sics", "sicced", "siccing", "sicced"),
-    "sidebar" => ("sidebars", "sidebarred", "sidebarring", "sidebarred"),
-    "sidecast" => ("sidecasts", "sidecast", "sidecasting", "sidecast"),
-    "sie" => ("sies", "sied", "sying", "sied"),
-    "sightread" => ("sightreads", "sightread", "sightreading", "sightread"),
-    "sightsee" => ("sightsees", "sightsaw", "sightseeing", "sightseen"),
-    "silo" => ("siloes", "siloed", "siloing", "siloed"),
-    "sim" => ("sims", "simmed", "simming", "simmed"),
-    "simp" => ("simps", "simped", "simping", "simped"),
-    "simulcast" => ("simulcasts", "simulcast", "simulcasting", "simulcast"),
-    "simulpub" => ("simulpubs", "simulpubbed", "simulpubbing", "simulpubbed"),
-    "sin" => ("sins", "sinned", "sinning", "sinned"),
-    "sing" => ("sings", "sang", "singing", "sung"),
-    "singe" => ("singes", "sange", "singing", "sunge"),
-    "singe2" => ("singes", "singed", "singeing", "singed"),
-    "sink" => ("sinks", "sank", "sinking", "sunk"),
-    "sir" => ("sirs", "sirred", "sirring", "sirred"),
-    "sirup" => ("sirups", "siruped", "siruping", "siruped"),
-    "sit" => ("sits", "sat", "sitting", "sat"),
-    "siteban" => ("sitebans", "sitebanned", "sitebanning", "sitebanned"),
-    "sjambok" => ("sjamboks", "sjambokked", "sjambokking", "sjambokked"),
-    "sk" => ("sks", "sked", "skg", "sked"),
-    "sked" => ("skeds", "skedded", "skedding", "skedded"),
-    "skee" => ("skees", "skeed", "skeeing", "skeed"),
-    "skelloch" => ("skellochs", "skelloched", "skelloching", "skelloched"),
-    "skelp" => ("skelps", "skelped", "skelping", "skelped"),
-    "sken" => ("skens", "skenned", "skenning", "skenned"),
-    "skibidi" => ("skibidis", "skibidied", "skibiding", "skibidied"),
-    "skibob" => ("skibobs", "skibobbed", "skibobbing", "skibobbed"),
-    "skid" => ("skids", "skidded", "skidding", "skidded"),
-    "skim" => ("skims", "skimmed", "skimming", "skimmed"),
-    "skimp" => ("skimps", "skimped", "skimping", "skimped"),
-    "skin" => ("skins", "skinned", "skinning", "skinned"),
-    "skit" => ("skits", "skitted", "skitting", "skitted"),
-    "skol" => ("skols", "skolled", "skolling", "skolled"),
-    "skreich" => ("skreichs", "skreiched", "skreiching", "skreiched"),
-    "skrimp" => ("skrimps", "skrimped", "skrimping", "skrimped"),
-    "skud" => ("skuds", "skudded", "skudding", "skudded"),
-    "skum" => ("skums", "skummed", "skumming", "skummed"),
-    "skywrite" => ("skywrites", "skywrote", "skywriting", "skywritten"),
-    "slab" => ("slabs", "slabbed", "slabbing", "slabbed"),
-    "slam" => ("slams", "slammed", "slamming", "slammed"),
-    "slashdot" => ("slashdots", "slashdotted", "slashdotting", "slashdotted"),
-    "slat" => ("slats", "slatted", "slatting", "slatted"),
-    "slay" => ("slays", "slew", "slaying", "slain"),
-    "sled" => ("sleds", "sledded", "sledding", "sledded"),
-    "sleep" => ("sleeps", "slept", "sleeping", "slept"),
-    "sleepe" => ("sleepes", "slepte", "sleeping", "slepte"),
-    "sleepeat" => ("sleepeats", "sleepate", "sleepeating", "sleepeaten"),
-    "sleepwrite" => ("sleepwrites", "sleepwrote", "sleepwriting", "sleepwritten"),
-    "slerp" => ("slerps", "slerped", "slerping", "slerped"),
-    "sley" => ("sleys", "sleyed", "sleying", "sleyed"),
-    "slg" => ("slgs", "slged", "slging", "slged"),
-    "slide" => ("slides", "slid", "sliding", "slid"),
-    "slim" => ("slims", "slimmed", "slimming", "slimmed"),
-    "sling" => ("slings", "slung", "slinging", "slung"),
-    "slingshoot" => ("slingshoots", "slingshot", "slingshooting", "slingshotten"),
-    "slingshot" => ("slingshots", "slingshotted", "slingshotting", "slingshotted"),
-    "slink" => ("slinks", "slunk", "slinking", "slunk"),
-    "slit" => ("slits", "slit", "slitting", "slit"),
-    "slite" => ("slites", "slit", "sliting", "slit"),
-    "slive2" => ("slives", "slove", "sliving", "sliven"),
-    "slivercast" => ("slivercasts", "slivercast", "slivercasting", "slivercast"),
-    "slob" => ("slobs", "slobbed", "slobbing", "slobbed"),
-    "slorp" => ("slorps", "slorped", "slorping", "slorped"),
-    "slot" => ("slots", "slotted", "slotting", "slotted"),
-    "slowplay" => ("slowplays", "slowplayed", "slowplaying", "slowplayed"),
-    "slub" => ("slubs", "slubbed", "slubbing", "slubbed"),
-    "slum" => ("slums", "slummed", "slumming", "slummed"),
-    "slump" => ("slumps", "slumped", "slumping", "slumped"),
-    "slur" => ("slurs", "slurred", "slurring", "slurred"),
-    "slurp" => ("slurps", "slurped", "slurping", "slurped"),
-    "slut" => ("sluts", "slutted", "slutting", "slutted"),
-    "smeb" => ("smebs", "smebbed", "smebbing", "smebbed"),
-    "smh" => ("smhes", "smhed", "smhing", "smhed"),
-    "smight" => ("smights", "smote", "smighting", "smoten"),
-    "smit" => ("smits", "smitted", "smitting", "smitted"),
-    "smite" => ("smites", "smote", "smiting", "smitten"),
-    "smof" => ("smofs", "smoffed", "smoffing", "smoffed"),
-    "smur" => ("smurs", "smurred", "smurring", "smurred"),
-    "smut" => ("smuts", "smutted", "smutting", "smutted"),
-    "snam" => ("snams", "snammed", "snamming", "snammed"),
-    "snapchat" => ("snapchats", "snapchatted", "snapchatting", "snapchatted"),
-    "snapshot" => ("snapshots", "snapshotted", "snapshotting", "snapshotted"),
-    "snar" => ("snars", "snarred", "snarring", "snarred"),
-    "sneap" => ("sneaps", "sneaped", "sneaping", "sneaped"),
-    "sneb" => ("snebs", "snebbed", "snebbing", "snebbed"),
-    "sned" => ("sneds", "snedded", "snedding", "snedded"),
-    "snee" => ("snees", "sneed", "sneeing", "sneed"),
-    "snet" => ("snets", "snetted", "snetting", "snetted"),
-    "snib" => ("snibs", "snibbed", "snibbing", "snibbed"),
-    "snie" => ("snies", "snied", "snying", "snied"),
-    "snoop" => ("snoops", "snooped", "snooping", "snooped"),
-    "snot" => ("snots", "snotted", "snotting", "snotted"),
-    "snowgo" => ("snowgoes", "snowgoed", "snowgoing", "snowgoed"),
-    "snowshoe" => ("snowshoes", "snowshoed", "snowshoeing", "snowshoed"),
-    "snub" => ("snubs", "snubbed", "snubbing", "snubbed"),
-    "soap" => ("soaps", "soaped", "soaping", "soaped"),
-    "sob" => ("sobs", "sobbed", "sobbing", "sobbed"),
-    "sod" => ("sods", "sodded", "sodding", "sodded"),
-    "sodcast" => ("sodcasts", "sodcast", "sodcasting", "sodcast"),
-    "softmod" => ("softmods", "softmodded", "softmodding", "softmodded"),
-    "softsoap" => ("softsoaps", "softsoaped", "softsoaping", "softsoaped"),
-    "softsub" => ("softsubs", "softsubbed", "softsubbing", "softsubbed"),
-    "sojourney" => ("sojourneys", "sojourneyed", "sojourneying", "sojourneyed"),
-    "somerset" => ("somersets", "somersetted", "somersetting", "somersetted"),
-    "son" => ("sons", "sonned", "sonning", "sonned"),
-    "songwrite" => ("songwrites", "songwrote", "songwriting", "songwritten"),
-    "sonogram" => ("sonograms", "sonogrammed", "sonogramming", "sonogrammed"),
-    "soogee" => ("soogees", "soogeed", "soogeeing", "soogeed"),
-    "soogie" => ("soogies", "soogied", "soogieing", "soogied"),
-    "soothsay" => ("soothsays", "soothsaid", "soothsaying", "soothsaid"),
-    "sortie" => ("sorties", "sortied", "sortieing", "sortied"),
-    "sot" => ("sots", "sotted", "sotting", "sotted"),
-    "souffle" => ("souffles", "souffled", "souffleing", "souffled"),
-    "soup" => ("soups", "souped", "souping", "souped"),
-    "southsay" => ("southsays", "southsaid", "southsaying", "southsaid"),
-    "sow" => ("sows", "sowed", "sowing", "sown"),
-    "soy" => ("soys", "soyed", "soying", "soyed"),
-    "spacegram" => ("spacegrams", "spacegrammed", "spacegramming", "spacegrammed"),
-    "spae" => ("spaes", "spaed", "spaeing", "spaed"),
-    "spaie" => ("spaies", "spaied", "spaying", "spaied"),
-    "spaik" => ("spaiks", "spoke", "spaiking", "spoke"),
-    "spam" => ("spams", "spammed", "spamming", "spammed"),
-    "span" => ("spans", "spanned", "spanning", "spanned"),
-    "spar" => ("spars", "sparred", "sparring", "sparred"),
-    "spat" => ("spats", "spatted", "spatting", "spatted"),
-    "spay" => ("spays", "spayed", "spaying", "spayed"),
-    "spaye" => ("spayes", "spayed", "spayeing", "spayed"),
-    "spaz" => ("spazzes", "spazzed", "spazzing", "spazzed"),
-    "speak" => ("speaks", "spoke", "speaking", "spoken"),
-    "speake" => ("speakes", "spoke", "speaking", "spoke"),
-    "speechread" => ("speechreads", "speechread", "speechreading", "speechread"),
-    "speed" => ("speeds", "sped", "speeding", "sped"),
-    "speedread" => ("speedreads", "speedread", "speedreading", "speedread"),
-    "speedrun" => ("speedruns", "speedran", "speedrunning", "speedrun"),
-    "speld" => ("spelds", "speld", "spelding", "speld"),
-    "spellbind" => ("spellbinds", "spellbound", "spellbinding", "spellbound"),
-    "spellcast" => ("spellcasts", "spellcast", "spellcasting", "spellcast"),
-    "spend" => ("spends", "spent", "spending", "spent"),
-    "sperg" => ("spergs", "sperged", "sperging", "sperged"),
-    "spet" => ("spets", "spetted", "spetting", "spetted"),
-    "spey" => ("speys", "speyed", "speying", "speyed"),
-    "spiderweb" => ("spiderwebs", "spiderwebbed", "spiderwebbing", "spiderwebbed"),
-    "spie" => ("spies", "spied", "spying", "spied"),
-    "spin" => ("spins", "spun", "spinning", "spun"),
-    "spincast" => ("spincasts", "spincast", "spincasting", "spincast"),
-    "spit" => ("spits", "spat", "spitting", "spat"),
-    "spit2" => ("spits", "spitted", "spitting", "spitted"),
-    "splat" => ("splats", "splatted", "splatting", "splatted"),
-    "splay" => ("splays", "splayed", "splaying", "splayed"),
-    "splet" => ("splets", "spletted", "spletting", "spletted"),
-    "split" => ("splits", "split", "splitting", "split"),
-    "spod" => ("spods", "spodded", "spodding", "spodded"),
-    "spoonfeed" => ("spoonfeeds", "spoonfed", "spoonfeeding", "spoonfed"),
-    "spot" => ("spots", "spotted", "spotting", "spotted"),
-    "sprat" => ("sprats", "spratted", "spratting", "spratted"),
-    "spray" => ("sprays", "sprayed", "spraying", "sprayed"),
-    "spread" => ("spreads", "spread", "spreading", "spread"),
-    "spred" => ("spreds", "spred", "spredding", "spred"),
-    "spree" => ("sprees", "spreed", "spreeing", "spreed"),
-    "spring2" => ("springs", "sprang", "springing", "sprung"),
-    "sprit" => ("sprits", "spritted", "spritting", "spritted"),
-    "sprue" => ("sprues", "sprued", "sprueing", "sprued"),
-    "spud" => ("spuds", "spudded", "spudding", "spudded"),
-    "spuilzie" => ("spuilzies", "spuilzied", "spuilzieing", "spuilzied"),
-    "spulye" => ("spulyes", "spulyed", "spulyeing", "spulyed"),
-    "spulyie" => ("spulyies", "spulyied", "spulyieing", "spulyied"),
-    "spulzie" => ("spulzies", "spulzied", "spulzieing", "spulzied"),
-    "spur" => ("spurs", "spurred", "spurring", "spurred"),
-    "spye" => ("spyes", "spyed", "spyeing", "spyed"),
-    "squab" => ("squabs", "squabbed", "squabbing", "squabbed"),
-    "squad" => ("squads", "squadded", "squadding", "squadded"),
-    "squat" => ("squats", "squatted", "squatting", "squatted"),
-    "squee" => ("squees", "squeed", "squeeing", "squeed"),
-    "squeegee" => ("squeegees", "squeegeed", "squeegeeing", "squeegeed"),
-    "squib" => ("squibs", "squibbed", "squibbing", "squibbed"),
-    "squid" => ("squids", "squidded", "squidding", "squidded"),
-    "squilgee" => ("squilgees", "squilgeed", "squilgeeing", "squilgeed"),
-    "squillgee" => ("squillgees", "squillgeed", "squillgeeing", "squillgeed"),
-    "squir" => ("squirs", "squirred", "squirring", "squirred"),
-    "squit" => ("squits", "squitted", "squitting", "squitted"),
-    "squiz" => ("squizzes", "squizzed", "squizzing", "squizzed"),
-    "stab" => ("stabs", "stabbed", "stabbing", "stabbed"),
-    "stallfeed" => ("stallfeeds", "stallfed", "stallfeeding", "stallfed"),
-    "stam" => ("stams", "stammed", "stamming", "stammed"),
-    "stamp" => ("stamps", "stamped", "stamping", "stamped"),
-    "stan" => ("stans", "stanned", "stanning", "stanned"),
-    "stand" => ("stands", "stood", "standing", "stood"),
-    "standby" => ("standbys", "standbyed", "standbying", "standbyed"),
-    "star" => ("stars", "starred", "starring", "starred"),
-    "startscum" => ("startscums", "startscummed", "startscumming", "startscummed"),
-    "stat" => ("stats", "statted", "statting", "statted"),
-    "statistic" => ("statistics", "statisticked", "statisticking", "statisticked"),
-    "stay" => ("stays", "stayed", "staying", "stayed"),
-    "steal" => ("steals", "stole", "stealing", "stolen"),
-    "steale" => ("steales", "stole", "stealing", "stolen"),
-    "stealthban" => ("stealthbans", "stealthbanned", "stealthbanning", "stealthbanned"),
-    "steelman" => ("steelmans", "steelmanned", "steelmanning", "steelmanned"),
-    "steep" => ("steeps", "steeped", "steeping", "steeped"),
-    "stem" => ("stems", "stemmed", "stemming", "stemmed"),
-    "stet" => ("stets", "stetted", "stetting", "stetted"),
-    "stick2" => ("sticks", "stuck", "sticking", "stuck"),
-    "sticke" => ("stickes", "stuck", "sticking", "stuck"),
-    "stilp" => ("stilps", "stilped", "stilping", "stilped"),
-    "stim" => ("stims", "stimmed", "stimming", "stimmed"),
-    "sting" => ("stings", "stung", "stinging", "stung"),
-    "stink" => ("stinks", "stank", "stinking", "stunk"),
-    "stir" => ("stirs", "stirred", "stirring", "stirred"),
-    "stob" => ("stobs", "stobbed", "stobbing", "stobbed"),
-    "stomach" => ("stomachs", "stomached", "stomaching", "stomached"),
-    "stomp" => ("stomps", "stomped", "stomping", "stomped"),
-    "stoop" => ("stoops", "stooped", "stooping", "stooped"),
-    "stot" => ("stots", "stotted", "stotting", "stotted"),
-    "stott" => ("stotts", "stotted", "stottin", "stotted"),
-    "stoup" => ("stoups", "stouped", "stouping", "stouped"),
-    "stram" => ("strams", "strammed", "stramming", "strammed"),
-    "stranglehold" => ("strangleholds", "strangleheld", "strangleholding", "strangleheld"),
-    "straphang" => ("straphangs", "straphung", "straphanging", "straphung"),
-    "stravaig" => ("stravaigs", "stravaiged", "stravaiging", "stravaiged"),
-    "strawman" => ("strawmans", "strawmanned", "strawmanning", "strawmanned"),
-    "strawpedo" => ("strawpedoes", "strawpedoed", "strawpedoing", "strawpedoed"),
-    "stray" => ("strays", "strayed", "straying", "strayed"),
-    "strew" => ("strews", "strewed", "strewing", "strewn"),
-    "stride" => ("strides", "strode", "striding", "stridden"),
-    "strike" => ("strikes", "struck", "striking", "struck"),
-    "strikebreak" => ("strikebreaks", "strikebroke", "strikebreaking", "strikebroken"),
-    "strikethrough" => ("strikesthrough", "struckthrough", "strikethroughing", "struckthrough"),
-    "strim" => ("strims", "strimmed", "strimming", "strimmed"),
-    "string" => ("strings", "strung", "stringing", "strung"),
-    "strive" => ("strives", "strove", "striving", "striven"),
-    "strow" => ("strows", "strowed", "strowing", "strown"),
-    "stroy" => ("stroys", "stroyed", "stroying", "stroyed"),
-    "strub" => ("strubs", "strubbed", "strubbing", "strubbed"),
-    "strum" => ("strums", "strummed", "strumming", "strummed"),
-    "strut" => ("struts", "strutted", "strutting", "strutted"),
-    "stryde" => ("strydes", "strode", "stryding", "strode"),
-    "stub" => ("stubs", "stubbed", "stubbing", "stubbed"),
-    "stud" => ("studs", "studded", "studding", "studded"),
-    "studie" => ("studies", "studied", "studying", "studied"),
-    "stuf" => ("stufs", "stuffed", "stuffing", "stuffed"),
-    "stum" => ("stums", "stummed", "stumming", "stummed"),
-    "stump" => ("stumps", "stumped", "stumping", "stumped"),
-    "stun" => ("stuns", "stunned", "stunning", "stunned"),
-    "stur" => ("sturs", "sturred", "sturring", "sturred"),
-    "stut" => ("stuts", "stutted", "stutting", "stutted"),
-    "stymie" => ("stymies", "stymied", "stymieing", "stymied"),
-    "sub" => ("subs", "subbed", "subbing", "subbed"),
-    "subgroup" => ("subgroups", "subgrouped", "subgrouping", "subgrouped"),
-    "subinfer" => ("subinfers", "subinferred", "subinferring", "subinferred"),
-    "sublet" => ("sublets", "sublet", "subletting", "sublet"),
-    "submit" => ("submits", "submitted", "submitting", "submitted"),
-    "subnet" => ("subnets", "subnetted", "subnetting", "subnetted"),
-    "subplot" => ("subplots", "subplotted", "subplotting", "subplotted"),
-    "subset" => ("subsets", "subsetted", "subsetting", "subsetted"),
-    "subsublet" => ("subsublets", "subsublet", "subsubletting", "subsublet"),
-    "subunderlet" => ("subunderlets", "subunderlet", "subunderletting", "subunderlet"),
-    "subway" => ("subways", "subwayed", "subwaying", "subwayed"),
-    "sum" => ("sums", "summed", "summing", "summed"),
-    "summerset" => ("summersets", "summersetted", "summersetting", "summersetted"),
-    "sump" => ("sumps", "sumped", "sumping", "sumped"),
-    "sun" => ("suns", "sunned", "sunning", "sunned"),
-    "sunday" => ("sundays", "sundayed", "sundaying", "sundayed"),
-    "sunset" => ("sunsets", "sunsetted", "sunsetting", "sunsetted"),
-    "suntan" => ("suntans", "suntanned", "suntanning", "suntanned"),
-    "superchat" => ("superchats", "superchatted", "superchatting", "superchatted"),
-    "superexcel" => ("superexcels", "superexcelled", "superexcelling", "superexcelled"),
-    "superfat" => ("superfats", "superfatted", "superfatting", "superfatted"),
-    "superlie" => ("superlies", "superlay", "superlying", "superlain"),
-    "supersell" => ("supersells", "supersold", "superselling", "supersold"),
-    "superset" => ("supersets", "supersetted", "supersetting", "supersetted"),
-    "supershed" => ("supersheds", "supershed", "supershedding", "supershed"),
-    "superspread" => ("superspreads", "superspread", "superspreading", "superspread"),
-    "surbeat" => ("surbeats", "surbeated", "surbeating", "surbeaten"),
-    "surbed" => ("surbeds", "surbedded", "surbedding", "surbedded"),
-    "surcloy" => ("surcloys", "surcloyed", "surcloying", "surcloyed"),
-    "surfcast" => ("surfcasts", "surfcast", "surfcasting", "surfcast"),
-    "surfel" => ("surfels", "surfelled", "surfelling", "surfelled"),
-    "surmit" => ("surmits", "surmitted", "surmitting", "surmitted"),
-    "surpay" => ("surpays", "surpaid", "surpaying", "surpaid"),
-    "surrebut" => ("surrebuts", "surrebutted", "surrebutting", "surrebutted"),
-    "survay" => ("survays", "survayed", "survaying", "survayed"),
-    "surveil" => ("surveils", "surveilled", "surveilling", "surveilled"),
-    "survey" => ("surveys", "surveyed", "surveying", "surveyed"),
-    "sus" => ("susses", "sussed", "sussing", "sussed"),
-    "swab" => ("swabs", "swabbed", "swabbing", "swabbed"),
-    "swaip" => ("swaips", "swaiped", "swaiping", "swaiped"),
-    "swallie" => ("swallies", "swallied", "swallying", "swallied"),
-    "swally" => ("swallys", "swallied", "swallying", "swallied"),
-    "swamp" => ("swamps", "swamped", "swamping", "swamped"),
-    "swan" => ("swans", "swanned", "swanning", "swanned"),
-    "swat" => ("swats", "swatted", "swatting", "swatted"),
-    "sway" => ("sways", "swayed", "swaying", "swayed"),
-    "swear2" => ("swears", "swore", "swearing", "sworn"),
-    "sweare" => ("sweares", "swore", "swearing", "sworne"),
-    "sweb" => ("swebs", "swebbed", "swebbing", "swebbed"),
-    "sweep" => ("sweeps", "swept", "sweeping", "swept"),
-    "swell" => ("swells", "swelled", "swelling", "swollen"),
-    "swike" => ("swikes", "swoke", "swiking", "swicken"),
-    "swim" => ("swims", "swam", "swimming", "swum"),
-    "swing" => ("swings", "swung", "swinging", "swung"),
-    "swink" => ("swinks", "swank", "swinking", "swunk"),
-    "swive" => ("swives", "swove", "swiving", "swiven"),
-    "swob" => ("swobs", "swobbed", "swobbing", "swobbed"),
-    "swoop" => ("swoops", "swooped", "swooping", "swooped"),
-    "swot" => ("swots", "swotted", "swotting", "swotted"),
-    "synch" => ("synchs", "synched", "synching", "synched"),
-    "syrup" => ("syrups", "syruped", "syruping", "syruped"),
-    "tab" => ("tabs", "tabbed", "tabbing", "tabbed"),
-    "tailride" => ("tailrides", "tailrode", "tailriding", "tailridden"),
-    "tailspin" => ("tailspins", "tailspun", "tailspinning", "tailspun"),
-    "tak" => ("taks", "teuk", "takkin", "takken"),
-    "take" => ("takes", "took", "taking", "taken"),
-    "talebear" => ("talebears", "talebore", "talebearing", "taleborne"),
-    "tamp" => ("tamps", "tamped", "tamping", "tamped"),
-    "tan" => ("tans", "tanned", "tanning", "tanned"),
-    "tango" => ("tangoes", "tangoed", "tangoing", "tangoed"),
-    "tannoy" => ("tannoys", "tannoyed", "tannoying", "tannoyed"),
-    "taphold" => ("tapholds", "tapheld", "tapholding", "tapheld"),
-    "tar" => ("tars", "tarred", "tarring", "tarred"),
-    "tarmac" => ("tarmacs", "tarmacked", "tarmacking", "tarmacked"),
-    "tarp" => ("tarps", "tarped", "tarping", "tarped"),
-    "tarpit" => ("tarpits", "tarpitted", "tarpitting", "tarpitted"),
-    "tat" => ("tats", "tatted", "tatting", "tatted"),
-    "taxicab" => ("taxicabs", "taxicabbed", "taxicabbing", "taxicabbed"),
-    "taxpay" => ("taxpays", "taxpaid", "taxpaying", "taxpaid"),
-    "teach" => ("teaches", "taught", "teaching", "taught"),
-    "tear2" => ("tears", "tore", "tearing", "torn"),
-    "teargas" => ("teargases", "teargassed", "teargassing", "teargassed"),
-    "tech" => ("techs", "teched", "teching", "teched"),
-    "ted" => ("teds", "tedded", "tedding", "tedded"),
-    "tee" => ("tees", "teed", "teeing", "teed"),
-    "teehee" => ("teehees", "teeheed", "teeheeing", "teeheed"),
-    "teensit" => ("teensits", "teensat", "teensitting", "teensat"),
-    "teep" => ("teeps", "teeped", "teeping", "teeped"),
-    "teepee" => ("teepees", "teepeed", "teepeeing", "teepeed"),
-    "teetee" => ("teetees", "teeteed", "teeteeing", "teeteed"),
-    "tehee" => ("tehees", "teheed", "teheeing", "teheed"),
-    "tek" => ("teks", "took", "teking", "tekken"),
-    "telebroadcast" => ("telebroadcasts", "telebroadcast", "telebroadcasting", "telebroadcast"),
-    "telecast" => ("telecasts", "telecast", "telecasting", "telecast"),
-    "telecine" => ("telecines", "telecined", "telecineing", "telecined"),
-    "telecontrol" => ("telecontrols", "telecontrolled", "telecontrolling", "telecontrolled"),
-    "telegram" => ("telegrams", "telegrammed", "telegramming", "telegrammed"),
-    "teletransmit" => ("teletransmits", "teletransmitted", "teletransmitting", "teletransmitted"),
-    "tell" => ("tells", "told", "telling", "told"),
-    "telnet" => ("telnets", "telnetted", "telnetting", "telnetted"),
-    "temp" => ("temps", "temped", "temping", "temped"),
-    "tempban" => ("tempbans", "tempbanned", "tempbanning", "tempbanned"),
-    "terp" => ("terps", "terped", "terping", "terped"),
-    "testbed" => ("testbeds", "testbedded", "testbedding", "testbedded"),
-    "testilie" => ("testilies", "testilied", "testilying", "testilied"),
-    "thanksgive" => ("thanksgives", "thanksgave", "thanksgiving", "thanksgiven"),
-    "thee" => ("thees", "theed", "theeing", "theed"),
-    "thesp" => ("thesps", "thesped", "thesping", "thesped"),
-    "they" => ("theys", "theyed", "theying", "theyed"),
-    "thin" => ("thins", "thinned", "thinning", "thinned"),
-    "thinck" => ("thincks", "thought", "thincking", "thought"),
-    "think" => ("thinks", "thought", "thinking", "thought"),
-    "thinke" => ("thinkes", "thought", "thinking", "thought"),
-    "thoroughgo" => ("thoroughgoes", "thoroughwent", "thoroughgoing", "thoroughgone"),
-    "thoughtcast" => ("thoughtcasts", "thoughtcast", "thoughtcasting", "thoughtcast"),
-    "threap" => ("threaps", "threaped", "threaping", "threaped"),
-    "thrid" => ("thrids", "thridded", "thridding", "thridded"),
-    "thring" => ("thrings", "thrang", "thringing", "thrung"),
-    "thrive" => ("thrives", "throve", "thriving", "thriven"),
-    "throb" => ("throbs", "throbbed", "throbbing", "throbbed"),
-    "throe" => ("throes", "throed", "throeing", "throed"),
-    "throw" => ("throws", "threw", "throwing", "thrown"),
-    "thrum" => ("thrums", "thrummed", "thrumming", "thrummed"),
-    "thrump" => ("thrumps", "thrumped", "thrumping", "thrumped"),
-    "thrust" => ("thrusts", "thrust", "thrusting", "thrust"),
-    "thud" => ("thuds", "thudded", "thudding", "thudded"),
-    "thumbshot" => ("thumbshots", "thumbshotted", "thumbshotting", "thumbshotted"),
-    "thump" => ("thumps", "thumped", "thumping", "thumped"),
-    "thunderstrike" => ("thunderstrikes", "thunderstruck", "thunderstriking", "thunderstricken"),
-    "tic" => ("tics", "ticced", "ticcing", "ticced"),
-    "tie" => ("ties", "tied", "tying", "tied"),
-    "tiebreak" => ("tiebreaks", "tiebroke", "tiebreaking", "tiebroken"),
-    "timekeep" => ("timekeeps", "timekept", "timekeeping", "timekept"),
-    "timeslot" => ("timeslots", "timeslotted", "timeslotting", "timeslotted"),
-    "timestamp" => ("timestamps", "timestamped", "timestamping", "timestamped"),
-    "timestomp" => ("timestomps", "timestomped", "timestomping", "timestomped"),
-    "tin" => ("tins", "tinned", "tinning", "tinned"),
-    "tinsel2" => ("tinsels", "tinselled", "tinselling", "tinselled"),
-    "tippytoe" => ("tippytoes", "tippytoed", "tippytoeing", "tippytoed"),
-    "tiptoe" => ("tiptoes", "tiptoed", "tiptoeing", "tiptoed"),
-    "tit" => ("tits", "titted", "titting", "titted"),
-    "titup" => ("titups", "tituped", "titupping", "tituped"),
-    "toadeat" => ("toadeats", "toadate", "toadeating", "toadeaten"),
-    "tobeat" => ("tobeats", "tobeat", "tobeating", "tobeaten"),
-    "tobreak" => ("tobreaks", "tobroke", "tobreaking", "tobroken"),
-    "tocleave" => ("tocleaves", "toclove", "tocleaving", "tocloven"),
-    "tocut" => ("tocuts", "tocut", "tocutting", "tocut"),
-    "tod" => ("tods", "todded", "todding", "todded"),
-    "todraw" => ("todraws", "todrew", "todrawing", "todrawn"),
-    "todrive" => ("todrives", "todrove", "todriving", "todriven"),
-    "toe" => ("toes", "toed", "toeing", "toed"),
-    "toffee" => ("toffees", "toffeed", "toffeeing", "toffeed"),
-    "togo" => ("togoes", "towent", "togoing", "togone"),
-    "togrind" => ("togrinds", "toground", "togrinding", "toground"),
-    "tohew" => ("tohews", "tohewed", "tohewing", "tohewn"),
-    "tom" => ("toms", "tommed", "tomming", "tommed"),
-    "tomcat" => ("tomcats", "tomcatted", "tomcatting", "tomcatted"),
-    "tomtom" => ("tomtoms", "tomtommed", "tomtomming", "tomtommed"),
-    "tonic" => ("tonics", "tonicked", "tonicking", "tonicked"),
-    "topspin" => ("topspins", "topspun", "topspinning", "topspun"),
-    "toput" => ("toputs", "toput", "toputting", "toput"),
-    "torat" => ("torats", "toratted", "toratting", "toratted"),
-    "toreave" => ("toreaves", "toreft", "toreaving", "toreft"),
-    "torend" => ("torends", "torent", "torending", "torent"),
-    "torfel" => ("torfels", "torfelled", "torfelling", "torfelled"),
-    "torit" => ("torits", "torit", "toritting", "torit"),
-    "torive" => ("torives", "torift", "toriving", "torift"),
-    "torob" => ("torobs", "torobbed", "torobbing", "torobbed"),
-    "torpedo" => ("torpedoes", "torpedoed", "torpedoing", "torpedoed"),
-    "toshake" => ("toshakes", "toshook", "toshaking", "toshaken"),
-    "toshear" => ("toshears", "toshore", "toshearing", "toshorn"),
-    "toshend" => ("toshends", "toshent", "toshending", "toshent"),
-    "toswink" => ("toswinks", "toswank", "toswinking", "toswunk"),
-    "tot" => ("tots", "totted", "totting", "totted"),
-    "totear" => ("totears", "totore", "totearing", "totorn"),
-    "totread" => ("totreads", "totrod", "totreading", "totrodden"),
-    "tourney" => ("tourneys", "tourneyed", "tourneying", "tourneyed"),
-    "towind" => ("towinds", "towound", "towinding", "towound"),
-    "toy" => ("toys", "toyed", "toying", "toyed"),
-    "tp" => ("tps", "tped", "tping", "tped"),
-    "tradlarp" => ("tradlarps", "tradlarped", "tradlarping", "tradlarped"),
-    "tradpub" => ("tradpubs", "tradpubbed", "tradpubbing", "tradpubbed"),
-    "traffic" => ("traffics", "trafficked", "trafficking", "trafficked"),
-    "trailbreak" => ("trailbreaks", "trailbroke", "trailbreaking", "trailbroke"),
-    "trainspot" => ("trainspots", "trainspotted", "trainspotting", "trainspotted"),
-    "tram" => ("trams", "trammed", "tramming", "trammed"),
-    "tramp" => ("tramps", "tramped", "tramping", "tramped"),
-    "transblot" => ("transblots", "transblotted", "transblotting", "transblotted"),
-    "transcur" => ("transcurs", "transcurred", "transcurring", "transcurred"),
-    "transfer" => ("transfers", "transferred", "transferring", "transferred"),
-    "transfur" => ("transfurs", "transfurred", "transfurring", "transfurred"),
-    "transmat" => ("transmats", "transmatted", "transmatting", "transmatted"),
-    "transmit" => ("transmits", "transmitted", "transmitting", "transmitted"),
-    "trapan" => ("trapans", "trapanned", "trapanning", "trapanned"),
-    "tray" => ("trays", "trayed", "traying", "trayed"),
-    "tread" => ("treads", "trod", "treading", "trodden"),
-    "tree" => ("trees", "treed", "treeing", "treed"),
-    "trek" => ("treks", "trekked", "trekking", "trekked"),
-    "tremp" => ("tremps", "tremped", "tremping", "tremped"),
-    "trendelenburg" => ("trendelenburgs", "trendelenburged", "trendelenburging", "trendelenburged"),
-    "trepan" => ("trepans", "trepanned", "trepanning", "trepanned"),
-    "trib" => ("tribs", "tribbed", "tribbing", "tribbed"),
-    "trilerp" => ("trilerps", "trilerped", "trilerping", "trilerped"),
-    "trim" => ("trims", "trimmed", "trimming", "trimmed"),
-    "trod" => ("trods", "trodded", "trodding", "trodded"),
-    "trolley" => ("trolleys", "trolleyed", "trolleying", "trolleyed"),
-    "trollop" => ("trollops", "trolloped", "trolloping", "trolloped"),
-    "tromp" => ("tromps", "tromped", "tromping", "tromped"),
-    "troop" => ("troops", "trooped", "trooping", "trooped"),
-    "trot" => ("trots", "trotted", "trotting", "trotted"),
-    "troubleshoot" => ("troubleshoots", "troubleshot", "troubleshooting", "troubleshot"),
-    "true" => ("trues", "trued", "trueing", "trued"),
-    "trump" => ("trumps", "trumped", "trumping", "trumped"),
-    "trustee" => ("trustees", "trusteed", "trusteeing", "trusteed"),
-    "tszuj" => ("tszujes", "tszujed", "tszujing", "tszujed"),
-    "tub" => ("tubs", "tubbed", "tubbing", "tubbed"),
-    "tubthump" => ("tubthumps", "tubthumped", "tubthumping", "tubthumped"),
-    "tumbleset" => ("tumblesets", "tumblesetted", "tumblesetting", "tumblesetted"),
-    "tump" => ("tumps", "tumped", "tumping", "tumped"),
-    "tun" => ("tuns", "tunned", "tunning", "tunned"),
-    "tupe" => ("tupes", "tuped", "tupeing", "tuped"),
-    "turney" => ("turneys", "turneyed", "turneying", "turneyed"),
-    "turnip" => ("turnips", "turniped", "turniping", "turniped"),
-    "turnkey" => ("turnkeys", "turnkeyed", "turnkeying", "turnkeyed"),
-    "tut" => ("tuts", "tutted", "tutting", "tutted"),
-    "tutay" => ("tutays", "tutayed", "tutaying", "tutayed"),
-    "tutoie" => ("tutoies", "tutoied", "tutoieing", "tutoied"),
-    "tutoy" => ("tutoys", "tutoyed", "tutoying", "tutoyed"),
-    "twat" => ("twats", "twatted", "twatting", "twatted"),
-    "tweag" => ("tweags", "tweaged", "tweaging", "tweaged"),
-    "tweep" => ("tweeps", "tweeped", "tweeping", "tweeped"),
-    "twight" => ("twights", "twight", "twighting", "twight"),
-    "twilight" => ("twilights", "twilit", "twilighting", "twilit"),
-    "twin" => ("twins", "twinned", "twinning", "twinned"),
-    "twinge" => ("twinges", "twinged", "twingeing", "twinged"),
-    "twit" => ("twits", "twitted", "twitting", "twitted"),
-    "twoc" => ("twocs", "twocced", "twoccing", "twocced"),
-    "twos" => ("twoss", "twosed", "twosing", "twosed"),
-    "tye" => ("tyes", "tyed", "tyeing", "tyed"),
-    "tyek" => ("tyeks", "teuk", "tyekin", "tyeken"),
-    "typecast" => ("typecasts", "typecast", "typecasting", "typecast"),
-    "typeset" => ("typesets", "typeset", "typesetting", "typeset"),
-    "typewrite" => ("typewrites", "typewrote", "typewriting", "typewritten"),
-    "typosquat" => ("typosquats", "typosquatted", "typosquatting", "typosquatted"),
-    "tyran" => ("tyrans", "tyranned", "tyranning", "tyranned"),
-    "ugg" => ("uggs", "ugged", "ugging", "ugged"),
-    "ultrascan" => ("ultrascans", "ultrascanned", "ultrascanning", "ultrascanned"),
-    "um" => ("ums", "ummed", "umming", "ummed"),
-    "umbecast" => ("umbecasts", "umbecast", "umbecasting", "umbecast"),
-    "umbedraw" => ("umbedraws", "umbedrew", "umbedrawing", "umbedrawn"),
-    "umbego" => ("umbegoes", "umbewent", "umbegoing", "umbegone"),
-    "umbeset" => ("umbesets", "umbeset", "umbesetting", "umbeset"),
-    "umbethink" => ("umbethinks", "umbethought", "umbethinking", "umbethought"),
-    "umcast" => ("umcasts", "umcast", "umcasting", "umcast"),
-    "ump" => ("umps", "umped", "umping", "umped"),
-    "unallot" => ("unallots", "unallotted", "unallotting", "unallotted"),
-    "unarray" => ("unarrays", "unarrayed", "unarraying", "unarrayed"),
-    "unban" => ("unbans", "unbanned", "unbanning", "unbanned"),
-    "unbar" => ("unbars", "unbarred", "unbarring", "unbarred"),
-    "unbear" => ("unbears", "unbore", "unbearing", "unborne"),
-    "unbecome" => ("unbecomes", "unbecame", "unbecoming", "unbecome"),
-    "unbed" => ("unbeds", "unbedded", "unbedding", "unbedded"),
-    "unbefit" => ("unbefits", "unbefitted", "unbefitting", "unbefitted"),
-    "unbeget" => ("unbegets", "unbegot", "unbegetting", "unbegotten"),
-    "unbend" => ("unbends", "unbent", "unbending", "unbent"),
-    "unbespeak" => ("unbespeaks", "unbespoke", "unbespeaking", "unbespoken"),
-    "unbethink" => ("unbethinks", "unbethought", "unbethinking", "unbethought"),
-    "unbetray" => ("unbetrays", "unbetrayed", "unbetraying", "unbetrayed"),
-    "unbid" => ("unbids", "unbid", "unbidding", "unbid"),
-    "unbin" => ("unbins", "unbinned", "unbinning", "unbinned"),
-    "unbind" => ("unbinds", "unbound", "unbinding", "unbound"),
-    "unbishop" => ("unbishops", "unbishoped", "unbishoping", "unbishoped"),
-    "unbit" => ("unbits", "unbitted", "unbitting", "unbitted"),
-    "unbite" => ("unbites", "unbit", "unbiting", "unbitten"),
-    "unblue" => ("unblues", "unblued", "unblueing", "unblued"),
-    "unblur" => ("unblurs", "unblurred", "unblurring", "unblurred"),
-    "unboy" => ("unboys", "unboyed", "unboying", "unboyed"),
-    "unbran" => ("unbrans", "unbranned", "unbranning", "unbranned"),
-    "unbreak" => ("unbreaks", "unbroke", "unbreaking", "unbroken"),
-    "unbreed" => ("unbreeds", "unbred", "unbreeding", "unbred"),
-    "unbuild" => ("unbuilds", "unbuilt", "unbuilding", "unbuilt"),
-    "unbump" => ("unbumps", "unbumped", "unbumping", "unbumped"),
-    "uncamp" => ("uncamps", "uncamped", "uncamping", "uncamped"),
-    "uncan" => ("uncans", "uncanned", "uncanning", "uncanned"),
-    "uncast" => ("uncasts", "uncast", "uncasting", "uncast"),
-    "uncatch" => ("uncatches", "uncaught", "uncatching", "uncaught"),
-    "unchoose" => ("unchooses", "unchose", "unchoosing", "unchosen"),
-    "unclamp" => ("unclamps", "unclamped", "unclamping", "unclamped"),
-    "unclasp" => ("unclasps", "unclasped", "unclasping", "unclasped"),
-    "uncling" => ("unclings", "unclung", "unclinging", "unclung"),
-    "unclump" => ("unclumps", "unclumped", "unclumping", "unclumped"),
-    "uncoif" => ("uncoifs", "uncoiffed", "uncoiffing", "uncoiffed"),
-    "uncommit" => ("uncommits", "uncommitted", "uncommitting", "uncommitted"),
-    "uncoop" => ("uncoops", "uncooped", "uncooping", "uncooped"),
-    "uncram" => ("uncrams", "uncrammed", "uncramming", "uncrammed"),
-    "uncramp" => ("uncramps", "uncramped", "uncramping", "uncramped"),
-    "uncrib" => ("uncribs", "uncribbed", "uncribbing", "uncribbed"),
-    "uncrimp" => ("uncrimps", "uncrimped", "uncrimping", "uncrimped"),
-    "uncrisp" => ("uncrisps", "uncrisped", "uncrisping", "uncrisped"),
-    "undam" => ("undams", "undammed", "undamming", "undammed"),
-    "undecree" => ("undecrees", "undecreed", "undecreeing", "undecreed"),
-    "undeploy" => ("undeploys", "undeployed", "undeploying", "undeployed"),
-    "underbar" => ("underbars", "underbarred", "underbarring", "underbarred"),
-    "underbear" => ("underbears", "underbore", "underbearing", "underborne"),
-    "underbeat" => ("underbeats", "underbeat", "underbeating", "underbeaten"),
-    "underbet" => ("underbets", "underbet", "underbetting", "underbet"),
-    "underbid" => ("underbids", "underbid", "underbidding", "underbid"),
-    "underbind" => ("underbinds", "underbound", "underbinding", "underbound"),
-    "underbite" => ("underbites", "underbit", "underbiting", "underbitten"),
-    "underblow" => ("underblows", "underblew", "underblowing", "underblown"),
-    "underbreed" => ("underbreeds", "underbred", "underbreeding", "underbred"),
-    "underbring" => ("underbrings", "underbrought", "underbringing", "underbrought"),
-    "underbuild" => ("underbuilds", "underbuilt", "underbuilding", "underbuilt"),
-    "undercast" => ("undercasts", "undercast", "undercasting", "undercast"),
-    "underclub" => ("underclubs", "underclubbed", "underclubbing", "underclubbed"),
-    "undercome" => ("undercomes", "undercame", "undercoming", "undercome"),
-    "undercommit" => ("undercommits", "undercommitted", "undercommitting", "undercommitted"),
-    "undercreep" => ("undercreeps", "undercrept", "undercreeping", "undercrept"),
-    "undercut" => ("undercuts", "undercut", "undercutting", "undercut"),
-    "underdamp" => ("underdamps", "underdamped", "underdamping", "underdamped"),
-    "underdeal" => ("underdeals", "underdealt", "underdealing", "underdealt"),
-    "underdevelop" => ("underdevelops", "underdeveloped", "underdeveloping", "underdeveloped"),
-    "underdig" => ("underdigs", "underdug", "underdigging", "underdug"),
-    "underdo" => ("underdoes", "underdid", "underdoing", "underdone"),
-    "underdraw" => ("underdraws", "underdrew", "underdrawing", "underdrawn"),
-    "underdrive" => ("underdrives", "underdrove", "underdriving", "underdriven"),
-    "undereat" => ("undereats", "underate", "undereating", "undereaten"),
-    "underfeed" => ("underfeeds", "underfed", "underfeeding", "underfed"),
-    "underfeel" => ("underfeels", "underfelt", "underfeeling", "underfelt"),
-    "underfit" => ("underfits", "underfitted", "underfitting", "underfitted"),
-    "underfulfil" => ("underfulfils", "underfulfilled", "underfulfilling", "underfulfilled"),
-    "underget" => ("undergets", "undergot", "undergetting", "undergot"),
-    "undergo" => ("undergoes", "underwent", "undergoing", "undergone"),
-    "undergoe" => ("undergoes", "underwent", "undergoing", "undergone"),
-    "undergrind" => ("undergrinds", "underground", "undergrinding", "underground"),
-    "undergrow" => ("undergrows", "undergrew", "undergrowing", "undergrown"),
-    "underhang" => ("underhangs", "underhung", "underhanging", "underhung"),
-    "underhit" => ("underhits", "underhit", "underhitting", "underhit"),
-    "underhold" => ("underholds", "underheld", "underholding", "underheld"),
-    "underkeep" => ("underkeeps", "underkept", "underkeeping", "underkept"),
-    "underlay" => ("underlays", "underlaid", "underlaying", "underlaid"),
-    "underlead" => ("underleads", "underled", "underleading", "underled"),
-    "underlet" => ("underlets", "underlet", "underletting", "underlet"),
-    "underlie" => ("underlies", "underlay", "underlying", "underlain"),
-    "underly" => ("underlies", "underlay", "underlying", "underlain"),
-    "underlye" => ("underlyes", "underlay", "underlying", "underlain"),
-    "underman" => ("undermans", "undermanned", "undermanning", "undermanned"),
-    "underpay" => ("underpays", "underpaid", "underpaying", "underpaid"),
-    "underpeep" => ("underpeeps", "underpeeped", "underpeeping", "underpeeped"),
-    "underpin" => ("underpins", "underpinned", "underpinning", "underpinned"),
-    "underplan" => ("underplans", "underplanned", "underplanning", "underplanned"),
-    "underplay" => ("underplays", "underplayed", "underplaying", "underplayed"),
-    "underplot" => ("underplots", "underplotted", "underplotting", "underplotted"),
-    "underpot" => ("underpots", "underpotted", "underpotting", "underpotted"),
-    "underput" => ("underputs", "underput", "underputting", "underput"),
-    "underread" => ("underreads", "underread", "underreading", "underread"),
-    "underride" => ("underrides", "underrode", "underriding", "underridden"),
-    "underring" => ("underrings", "underrang", "underringing", "underrung"),
-    "underrun" => ("underruns", "underran", "underrunning", "underrun"),
-    "undersay" => ("undersays", "undersaid", "undersaying", "undersaid"),
-    "undersee" => ("undersees", "undersaw", "underseeing", "underseen"),
-    "underseek" => ("underseeks", "undersought", "underseeking", "undersought"),
-    "undersell" => ("undersells", "undersold", "underselling", "undersold"),
-    "undersend" => ("undersends", "undersent", "undersending", "undersent"),
-    "underset" => ("undersets", "underset", "undersetting", "underset"),
-    "undershoot" => ("undershoots", "undershot", "undershooting", "undershot"),
-    "undersing" => ("undersings", "undersang", "undersinging", "undersung"),
-    "undersleep" => ("undersleeps", "underslept", "undersleeping", "underslept"),
-    "undersling" => ("underslings", "underslung", "underslinging", "underslung"),
-    "undersow" => ("undersows", "undersowed", "undersowing", "undersown"),
-    "underspeak" => ("underspeaks", "underspoke", "underspeaking", "underspoken"),
-    "underspend" => ("underspends", "underspent", "underspending", "underspent"),
-    "understand" => ("understands", "understood", "understanding", "understood"),
-    "understir" => ("understirs", "understirred", "understirring", "understirred"),
-    "understride" => ("understrides", "understrode", "understriding", "understrode"),
-    "undertake" => ("undertakes", "undertook", "undertaking", "undertaken"),
-    "underteach" => ("underteaches", "undertaught", "underteaching", "undertaught"),
-    "underthink" => ("underthinks", "underthought", "underthinking", "underthought"),
-    "underthrow" => ("underthrows", "underthrew", "underthrowing", "underthrown"),
-    "undertrump" => ("undertrumps", "undertrumped", "undertrumping", "undertrumped"),
-    "underwind" => ("underwinds", "underwound", "underwinding", "underwound"),
-    "underwithhold" => ("underwithholds", "underwithheld", "underwithholding", "underwithheld"),
-    "underwrite" => ("underwrites", "underwrote", "underwriting", "underwritten"),
-    "undestroy" => ("undestroys", "undestroyed", "undestroying", "undestroyed"),
-    "undiadem" => ("undiadems", "undiademmed", "undiademming", "undiademmed"),
-    "undie" => ("undies", "undied", "undying", "undied"),
-    "undig" => ("undigs", "undug", "undigging", "undug"),
-    "undim" => ("undims", "undimmed", "undimming", "undimmed"),
-    "undisplay" => ("undisplays", "undisplayed", "undisplaying", "undisplayed"),
-    "undo" => ("undoes", "undid", "undoing", "undone"),
-    "undraw" => ("undraws", "undrew", "undrawing", "undrawn"),
-    "undrink" => ("undrinks", "undrank", "undrinking", "undrunk"),
-    "undub" => ("undubs", "undubbed", "undubbing", "undubbed"),
-    "undump" => ("undumps", "undumped", "undumping", "undumped"),
-    "undye" => ("undyes", "undyed", "undyeing", "undyed"),
-    "uneat" => ("uneats", "unate", "uneating", "uneaten"),
-    "unembed" => ("unembeds", "unembedded", "unembedding", "unembedded"),
-    "unemploy" => ("unemploys", "unemployed", "unemploying", "unemployed"),
-    "unenrol" => ("unenrols", "unenrolled", "unenrolling", "unenrolled"),
-    "unfeel" => ("unfeels", "unfelt", "unfeeling", "unfelt"),
-    "unfit" => ("unfits", "unfitted", "unfitting", "unfitted"),
-    "unforget" => ("unforgets", "unforgot", "unforgetting", "unforgotten"),
-    "unforgive" => ("unforgives", "unforgave", "unforgiving", "unforgiven"),
-    "unformat" => ("unformats", "unformatted", "unformatting", "unformatted"),
-    "unfreeze" => ("unfreezes", "unfroze", "unfreezing", "unfrozen"),
-    "unfret" => ("unfrets", "unfretted", "unfretting", "unfretted"),
-    "unfulfil" => ("unfulfils", "unfulfilled", "unfulfilling", "unfulfilled"),
-    "unfur" => ("unfurs", "unfurred", "unfurring", "unfurred"),
-    "ungay" => ("ungays", "ungayed", "ungaying", "ungayed"),
-    "unget" => ("ungets", "ungot", "ungetting", "ungot"),
-    "ungive" => ("ungives", "ungave", "ungiving", "ungiven"),
-    "ungod" => ("ungods", "ungodded", "ungodding", "ungodded"),
-    "ungrab" => ("ungrabs", "ungrabbed", "ungrabbing", "ungrabbed"),
-    "ungrasp" => ("ungrasps", "ungrasped", "ungrasping", "ungrasped"),
-    "ungroup" => ("ungroups", "ungrouped", "ungrouping", "ungrouped"),
-    "ungrow" => ("ungrows", "ungrew", "ungrowing", "ungrown"),
-    "ungum" => ("ungums", "ungummed", "ungumming", "ungummed"),
-    "ungut" => ("unguts", "ungutted", "ungutting", "ungutted"),
-    "unhang2" => ("unhangs", "unhung", "unhanging", "unhung"),
-    "unhasp" => ("unhasps", "unhasped", "unhasping", "unhasped"),
-    "unhat" => ("unhats", "unhatted", "unhatting", "unhatted"),
-    "unhear" => ("unhears", "unheard", "unhearing", "unheard"),
-    "unhelp" => ("unhelps", "unhelped", "unhelping", "unhelped"),
-    "unhem" => ("unhems", "unhemmed", "unhemming", "unhemmed"),
-    "unhide" => ("unhides", "unhid", "unhiding", "unhidden"),
-    "unhit" => ("unhits", "unhit", "unhitting", "unhit"),
-    "unhold" => ("unholds", "unheld", "unholding", "unheld"),
-    "unhoop" => ("unhoops", "unhooped", "unhooping", "unhooped"),
-    "unicast" => ("unicasts", "unicast", "unicasting", "unicast"),
-    "unjailbreak" => ("unjailbreaks", "unjailbroke", "unjailbreaking", "unjailbroken"),
-    "unjam" => ("unjams", "unjammed", "unjamming", "unjammed"),
-    "unjar" => ("unjars", "unjarred", "unjarring", "unjarred"),
-    "unken" => ("unkens", "unkenned", "unkenning", "unkenned"),
-    "unkey" => ("unkeys", "unkeyed", "unkeying", "unkeyed"),
-    "unknit" => ("unknits", "unknit", "unknitting", "unknit"),
-    "unknot" => ("unknots", "unknotted", "unknotting", "unknotted"),
-    "unknow" => ("unknows", "unknew", "unknowing", "unknown"),
-    "unlade" => ("unlades", "unladed", "unlading", "unladen"),
-    "unlay" => ("unlays", "unlaid", "unlaying", "unlaid"),
-    "unlid" => ("unlids", "unlidded", "unlidding", "unlidded"),
-    "unloop" => ("unloops", "unlooped", "unlooping", "unlooped"),
-    "unlose" => ("unloses", "unlost", "unlosing", "unlost"),
-    "unmagic" => ("unmagics", "unmagicked", "unmagicking", "unmagicked"),
-    "unmake" => ("unmakes", "unmade", "unmaking", "unmade"),
-    "unman" => ("unmans", "unmanned", "unmanning", "unmanned"),
-    "unmat" => ("unmats", "unmatted", "unmatting", "unmatted"),
-    "unmean" => ("unmeans", "unmeant", "unmeaning", "unmeant"),
-    "unmeet" => ("unmeets", "unmet", "unmeeting", "unmet"),
-    "unmonarch" => ("unmonarchs", "unmonarched", "unmonarching", "unmonarched"),
-    "unnerstand" => ("unnerstands", "unnerstood", "unnerstanding", "unnerstood"),
-    "unnun" => ("unnuns", "unnunned", "unnunning", "unnunned"),
-    "unpay" => ("unpays", "unpaid", "unpaying", "unpaid"),
-    "unpen" => ("unpens", "unpenned", "unpenning", "unpenned"),
-    "unpin" => ("unpins", "unpinned", "unpinning", "unpinned"),
-    "unplan" => ("unplans", "unplanned", "unplanning", "unplanned"),
-    "unplat" => ("unplats", "unplatted", "unplatting", "unplatted"),
-    "unplay" => ("unplays", "unplayed", "unplaying", "unplayed"),
-    "unplot" => ("unplots", "unplotted", "unplotting", "unplotted"),
-    "unpot" => ("unpots", "unpotted", "unpotting", "unpotted"),
-    "unpray" => ("unprays", "unprayed", "unpraying", "unprayed"),
-    "unquit" => ("unquits", "unquit", "unquitting", "unquit"),
-    "unram" => ("unrams", "unrammed", "unramming", "unrammed"),
-    "unread" => ("unreads", "unread", "unreading", "unread"),
-    "unring2" => ("unrings", "unrang", "unringing", "unrung"),
-    "unsake" => ("unsakes", "unsook", "unsaking", "unsaken"),
-    "unsay" => ("unsays", "unsaid", "unsaying", "unsaid"),
-    "unsee" => ("unsees", "unsaw", "unseeing", "unseen"),
-    "unsell" => ("unsells", "unsold", "unselling", "unsold"),
-    "unsend" => ("unsends", "unsent", "unsending", "unsent"),
-    "unset" => ("unsets", "unset", "unsetting", "unset"),
-    "unsew" => ("unsews", "unsewed", "unsewing", "unsewn"),
-    "unshadowban" => ("unshadowbans", "unshadowbanned", "unshadowbanning", "unshadowbanned"),
-    "unshake" => ("unshakes", "unshook", "unshaking", "unshaken"),
-    "unshar" => ("unshars", "unsharred", "unsharring", "unsharred"),
-    "unsharp" => ("unsharps", "unsharped", "unsharping", "unsharped"),
-    "unshit" => ("unshits", "unshit", "unshitting", "unshit"),
-    "unshoe" => ("unshoes", "unshoed", "unshoeing", "unshoed"),
-    "unshoot" => ("unshoots", "unshot", "unshooting", "unshot"),
-    "unshot" => ("unshots", "unshotted", "unshotting", "unshotted"),
-    "unshrink" => ("unshrinks", "unshrank", "unshrinking", "unshrunk"),
-    "unshut" => ("unshuts", "unshut", "unshutting", "unshut"),
-    "unsin" => ("unsins", "unsinned", "unsinning", "unsinned"),
-    "unsing" => ("unsings", "unsang", "unsinging", "unsung"),
-    "unskin" => ("unskins", "unskinned", "unskinning", "unskinned"),
-    "unsleep" => ("unsleeps", "unslept", "unsleeping", "unslept"),
-    "unslide" => ("unslides", "unslid", "unsliding", "unslid"),
-    "unsling" => ("unslings", "unslung", "unslinging", "unslung"),
-    "unslot" => ("unslots", "unslotted", "unslotting", "unslotted"),
-    "unsnib" => ("unsnibs", "unsnibbed", "unsnibbing", "unsnibbed"),
-    "unspam" => ("unspams", "unspammed", "unspamming", "unspammed"),
-    "unspar" => ("unspars", "unsparred", "unsparring", "unsparred"),
-    "unspeak" => ("unspeaks", "unspoke", "unspeaking", "unspoken"),
-    "unspend" => ("unspends", "unspent", "unspending", "unspent"),
-    "unspin" => ("unspins", "unspun", "unspinning", "unspun"),
-    "unspit" => ("unspits", "unspitted", "unspitting", "unspitted"),
-    "unsplay" => ("unsplays", "unsplayed", "unsplaying", "unsplayed"),
-    "unsplit" => ("unsplits", "unsplit", "unsplitting", "unsplit"),
-    "unspot" => ("unspots", "unspotted", "unspotting", "unspotted"),
-    "unspread" => ("unspreads", "unspread", "unspreading", "unspread"),
-    "unspring" => ("unsprings", "unsprang", "unspringing", "unsprung"),
-    "unstamp" => ("unstamps", "unstamped", "unstamping", "unstamped"),
-    "unstan" => ("unstans", "unstanned", "unstanning", "unstanned"),
-    "unstar" => ("unstars", "unstarred", "unstarring", "unstarred"),
-    "unstay" => ("unstays", "unstayed", "unstaying", "unstayed"),
-    "unsteal" => ("unsteals", "unstole", "unstealing", "unstolen"),
-    "unstick" => ("unsticks", "unstuck", "unsticking", "unstuck"),
-    "unstir" => ("unstirs", "unstirred", "unstirring", "unstirred"),
-    "unstrike" => ("unstrikes", "unstruck", "unstriking", "unstruck"),
-    "unstring" => ("unstrings", "unstrung", "unstringing", "unstrung"),
-    "unsub" => ("unsubs", "unsubbed", "unsubbing", "unsubbed"),
-    "unsubmit" => ("unsubmits", "unsubmitted", "unsubmitting", "unsubmitted"),
-    "unswear" => ("unswears", "unswore", "unswearing", "unsworn"),
-    "unswell" => ("unswells", "unswelled", "unswelling", "unswollen"),
-    "unswing" => ("unswings", "unswung", "unswinging", "unswung"),
-    "untar" => ("untars", "untarred", "untarring", "untarred"),
-    "unteach" => ("unteaches", "untaught", "unteaching", "untaught"),
-    "untell" => ("untells", "untold", "untelling", "untold"),
-    "unthink" => ("unthinks", "unthought", "unthinking", "unthought"),
-    "unthrow" => ("unthrows", "unthrew", "unthrowing", "unthrew"),
-    "untie" => ("unties", "untied", "untying", "untied"),
-    "untin" => ("untins", "untinned", "untinning", "untinned"),
-    "untread" => ("untreads", "untrod", "untreading", "untrod"),
-    "untree" => ("untrees", "untreed", "untreeing", "untreed"),
-    "untrim" => ("untrims", "untrimmed", "untrimming", "untrimmed"),
-    "unty" => ("untyes", "untyed", "untying", "untyed"),
-    "unvat" => ("unvats", "unvatted", "unvatting", "unvatted"),
-    "unwad" => ("unwads", "unwadded", "unwadding", "unwadded"),
-    "unwarp" => ("unwarps", "unwarped", "unwarping", "unwarped"),
-    "unweave" => ("unweaves", "unwove", "unweaving", "unwoven"),
-    "unweb" => ("unwebs", "unwebbed", "unwebbing", "unwebbed"),
-    "unwed" => ("unweds", "unwed", "unwedding", "unwed"),
-    "unwet" => ("unwets", "unwet", "unwetting", "unwet"),
-    "unwind" => ("unwinds", "unwound", "unwinding", "unwound"),
-    "unwit" => ("unwits", "unwitted", "unwitting", "unwitted"),
-    "unwrite" => ("unwrites", "unwrote", "unwriting", "unwritten"),
-    "upbar" => ("upbars", "upbarred", "upbarring", "upbarred"),
-    "upbear" => ("upbears", "upbore", "upbearing", "upborne"),
-    "upbend" => ("upbends", "upbent", "upbending", "upbent"),
-    "upbid" => ("upbids", "upbid", "upbidding", "upbid"),
-    "upbind" => ("upbinds", "upbound", "upbinding", "upbound"),
-    "upblow" => ("upblows", "upblew", "upblowing", "upblown"),
-    "upbray" => ("upbrays", "upbrayed", "upbraying", "upbrayed"),
-    "upbreak" => ("upbreaks", "upbroke", "upbreaking", "upbroken"),
-    "upbreed" => ("upbreeds", "upbred", "upbreeding", "upbred"),
-    "upbrim" => ("upbrims", "upbrimmed", "upbrimming", "upbrimmed"),
-    "upbring" => ("upbrings", "upbrought", "upbringing", "upbrought"),
-    "upbuild" => ("upbuilds", "upbuilt", "upbuilding", "upbuilt"),
-    "upbuoy" => ("upbuoys", "upbuoyed", "upbuoying", "upbuoyed"),
-    "upcast" => ("upcasts", "upcast", "upcasting", "upcast"),
-    "upcome" => ("upcomes", "upcame", "upcoming", "upcome"),
-    "upcreep" => ("upcreeps", "upcrept", "upcreeping", "upcrept"),
-    "upcut" => ("upcuts", "upcut", "upcutting", "upcut"),
-    "updraw" => ("updraws", "updrew", "updrawing", "updrawn"),
-    "upfit" => ("upfits", "upfitted", "upfitting", "upfitted"),
-    "upfling" => ("upflings", "upflung", "upflinging", "upflung"),
-    "upfly" => ("upflies", "upflew", "upflying", "upflown"),
-    "upgive" => ("upgives", "upgave", "upgiving", "upgiven"),
-    "upgo" => ("upgoes", "upwent", "upgoing", "upgone"),
-    "upgrow" => ("upgrows", "upgrew", "upgrowing", "upgrown"),
-    "upgun" => ("upguns", "upgunned", "upgunning", "upgunned"),
-    "uphang" => ("uphangs", "uphung", "uphanging", "uphung"),
-    "upheap" => ("upheaps", "upheaped", "upheaping", "upheaped"),
-    "uphold" => ("upholds", "upheld", "upholding", "upheld"),
-    "upjet" => ("upjets", "upjetted", "upjetting", "upjetted"),
-    "upkeep" => ("upkeeps", "upkept", "upkeeping", "upkept"),
-    "upknit" => ("upknits", "upknitted", "upknitting", "upknitted"),
-    "uplay" => ("uplays", "uplaid", "uplaying", "uplaid"),
-    "uplead" => ("upleads", "upled", "upleading", "upled"),
-    "uplean" => ("upleans", "upleant", "upleaning", "upleant"),
-    "upleap" => ("upleaps", "upleaped", "upleaping", "upleaped"),
-    "upmake" => ("upmakes", "upmade", "upmaking", "upmade"),
-    "uppercut" => ("uppercuts", "uppercut", "uppercutting", "uppercut"),
-    "uppluck" => ("upplucks", "upplight", "upplucking", "upplight"),
-    "upput" => ("upputs", "upput", "upputting", "upput"),
-    "uprend" => ("uprends", "uprent", "uprending", "uprent"),
-    "upride" => ("uprides", "uprode", "upriding", "upridden"),
-    "uprise" => ("uprises", "uprose", "uprising", "uprisen"),
-    "uprun" => ("upruns", "upran", "uprunning", "uprun"),
-    "upseek" => ("upseeks", "upsought", "upseeking", "upsought"),
-    "upsell" => ("upsells", "upsold", "upselling", "upsold"),
-    "upsend" => ("upsends", "upsent", "upsending", "upsent"),
-    "upset" => ("upsets", "upset", "upsetting", "upset"),
-    "upshoot" => ("upshoots", "upshot", "upshooting", "upshot"),
-    "upshut" => ("upshuts", "upshut", "upshutting", "upshut"),
-    "upspeak" => ("upspeaks", "upspoke", "upspeaking", "upspoken"),
-    "upspeed" => ("upspeeds", "upsped", "upspeeding", "upsped"),
-    "upspread" => ("upspreads", "upspread", "upspreading", "upspread"),
-    "upspring" => ("upsprings", "upsprang", "upspringing", "upsprung"),
-    "upstand" => ("upstands", "upstood", "upstanding", "upstood"),
-    "upstay" => ("upstays", "upstayed", "upstaying", "upstayed"),
-    "upsteal" => ("upsteals", "upstole", "upstealing", "upstolen"),
-    "upstir" => ("upstirs", "upstirred", "upstirring", "upstirred"),
-    "upstrive" => ("upstrives", "upstrove", "upstriving", "upstriven"),
-    "upsway" => ("upsways", "upswayed", "upswaying", "upswayed"),
-    "upsweep" => ("upsweeps", "upswept", "upsweeping", "upswept"),
-    "upswim" => ("upswims", "upswam", "upswimming", "upswum"),
-    "upswing" => ("upswings", "upswung", "upswinging", "upswung"),
-    "uptake" => ("uptakes", "uptook", "uptaking", "uptaken"),
-    "uptear" => ("uptears", "uptore", "uptearing", "uptorn"),
-    "upthrow" => ("upthrows", "upthrew", "upthrowing", "upthrown"),
-    "uptie" => ("upties", "uptied", "uptying", "uptied"),
-    "upwind" => ("upwinds", "upwound", "upwinding", "upwound"),
-    "urp" => ("urps", "urped", "urping", "urped"),
-    "usurp" => ("usurps", "usurped", "usurping", "usurped"),
-    "vab" => ("vabs", "vabbed", "vabbing", "vabbed"),
-    "vac" => ("vacs", "vacced", "vaccing", "vacced"),
-    "vacay" => ("vacays", "vacayed", "vacaying", "vacayed"),
-    "valley" => ("valleys", "valleyed", "valleying", "valleyed"),
-    "vamp" => ("vamps", "vamped", "vamping", "vamped"),
-    "van" => ("vans", "vanned", "vanning", "vanned"),
-    "vat" => ("vats", "vatted", "vatting", "vatted"),
-    "vax" => ("vaxxes", "vaxxed", "vaxxing", "vaxxed"),
-    "vee" => ("vees", "veed", "veeing", "veed"),
-    "veejay" => ("veejays", "veejayed", "veejaying", "veejayed"),
-    "verticut" => ("verticuts", "verticut", "verticutting", "verticut"),
-    "vet" => ("vets", "vetted", "vetting", "vetted"),
-    "veto" => ("vetoes", "vetoed", "vetoing", "vetoed"),
-    "vfx" => ("vfxs", "vfxed", "vfxing", "vfxed"),
-    "vidcast" => ("vidcasts", "vidcast", "vidcasting", "vidcast"),
-    "vidchat" => ("vidchats", "vidchatted", "vidchatting", "vidchatted"),
-    "video" => ("videoes", "videoed", "videoing", "videoed"),
-    "videocast" => ("videocasts", "videocast", "videocasting", "videocast"),
-    "videochat" => ("videochats", "videochatted", "videochatting", "videochatted"),
-    "vie" => ("vies", "vied", "vying", "vied"),
-    "viewbot" => ("viewbots", "viewbotted", "viewbotting", "viewbotted"),
-    "vis" => ("viss", "vised", "vising", "vised"),
-    "vise2" => ("vises", "vised", "viseing", "vised"),
-    "viz" => ("vizs", "vized", "vizing", "vized"),
-    "vodcast" => ("vodcasts", "vodcast", "vodcasting", "vodcast"),
-    "volley" => ("volleys", "volleyed", "volleying", "volleyed"),
-    "voluntell" => ("voluntells", "voluntold", "voluntelling", "voluntold"),
-    "vom" => ("voms", "vommed", "vomming", "vommed"),
-    "voop" => ("voops", "vooped", "vooping", "vooped"),
-    "voteban" => ("votebans", "votebanned", "votebanning", "votebanned"),
-    "vousvoie" => ("vousvoies", "vousvoied", "vousvoying", "vousvoied"),
-    "vouvoie" => ("vouvoies", "vouvoied", "vouvoying", "vouvoied"),
-    "vouvoy" => ("vouvoys", "vouvoyed", "vouvoying", "vouvoyed"),
-    "vum" => ("vums", "vummed", "vumming", "vummed"),
-    "vurp" => ("vurps", "vurped", "vurping", "vurped"),
-    "wad" => ("wads", "wadded", "wadding", "wadded"),
-    "wadset" => ("wadsets", "wadsetted", "wadsetting", "wadsetted"),
-    "wake" => ("wakes", "woke", "waking", "woken"),
-    "wallop" => ("wallops", "walloped", "walloping", "walloped"),
-    "wallride" => ("wallrides", "wallrode", "wallriding", "wallridden"),
-    "wallslam" => ("wallslams", "wallslammed", "wallslamming", "wallslammed"),
-    "war" => ("wars", "warred", "warring", "warred"),
-    "wardrive" => ("wardrives", "wardrove", "wardriving", "wardriven"),
-    "warg" => ("wargs", "warged", "warging", "warged"),
-    "warp" => ("warps", "warped", "warping", "warped"),
-    "warray" => ("warrays", "warrayed", "warraying", "warrayed"),
-    "warrey" => ("warreys", "warreyed", "warreying", "warreyed"),
-    "wasp" => ("wasps", "wasped", "wasping", "wasped"),
-    "way" => ("ways", "wayed", "waying", "wayed"),
-    "wayfare" => ("wayfares", "wayfore", "wayfaring", "wayfaren"),
-    "waylay" => ("waylays", "waylaid", "waylaying", "waylaid"),
-    "wear2" => ("wears", "wore", "wearing", "worn"),
-    "weare" => ("weares", "wore", "wearing", "worne"),
-    "weave2" => ("weaves", "wove", "weaving", "woven"),
-    "web" => ("webs", "webbed", "webbing", "webbed"),
-    "webcam" => ("webcams", "webcammed", "webcamming", "webcammed"),
-    "webcast" => ("webcasts", "webcast", "webcasting", "webcast"),
-    "webchat" => ("webchats", "webchatted", "webchatting", "webchatted"),
-    "wechat" => ("wechats", "wechatted", "wechatting", "wechatted"),
-    "wed" => ("weds", "wed", "wedding", "wed"),
-    "wedgie" => ("wedgies", "wedgied", "wedgying", "wedgied"),
-    "wedset" => ("wedsets", "wedsetted", "wedsetting", "wedsetted"),
-    "wee" => ("wees", "weed", "weeing", "weed"),
-    "weep" => ("weeps", "wept", "weeping", "wept"),
-    "weewee" => ("weewees", "weeweed", "weeweeing", "weeweed"),
-    "weightbear" => ("weightbears", "weightbore", "weightbearing", "weightborne"),
-    "wet" => ("wets", "wet", "wetting", "wet"),
-    "wfh" => ("wfh", "wfh", "wfh", "wfh"),
-    "wham" => ("whams", "whammed", "whamming", "whammed"),
-    "whatsapp" => ("whatsapps", "whatsapped", "whatsapping", "whatsapped"),
-    "whee" => ("whees", "wheed", "wheeing", "wheed"),
-    "wheech" => ("wheechs", "wheeched", "wheeching", "wheeched"),
-    "wheelie" => ("wheelies", "wheelied", "wheelieing", "wheelied"),
-    "wheelspin" => ("wheelspins", "wheelspun", "wheelspinning", "wheelspun"),
-    "whelp" => ("whelps", "whelped", "whelping", "whelped"),
-    "whet" => ("whets", "whetted", "whetting", "whetted"),
-    "whid" => ("whids", "whidded", "whidding", "whidded"),
-    "whim" => ("whims", "whimmed", "whimming", "whimmed"),
-    "whinge" => ("whinges", "whinged", "whingeing", "whinged"),
-    "whipsaw" => ("whipsaws", "whipsawed", "whipsawing", "whipsawn"),
-    "whir" => ("whirs", "whirred", "whirring", "whirred"),
-    "whistleblow" => ("whistleblows", "whistleblew", "whistleblowing", "whistleblown"),
-    "whiz" => ("whizzes", "whizzed", "whizzing", "whizzed"),
-    "whomp" => ("whomps", "whomped", "whomping", "whomped"),
-    "whoomp" => ("whoomps", "whoomped", "whoomping", "whoomped"),
-    "whoop" => ("whoops", "whooped", "whooping", "whooped"),
-    "whoopee" => ("whoopees", "whoopeed", "whoopeeing", "whoopeed"),
-    "whud" => ("whuds", "whudded", "whudding", "whudded"),
-    "whump" => ("whumps", "whumped", "whumping", "whumped"),
-    "whur" => ("whurs", "whurred", "whurring", "whurred"),
-    "why" => ("whys", "whyed", "whying", "whyed"),
-    "wicketkeep" => ("wicketkeeps", "wicketkept", "wicketkeeping", "wicketkept"),
-    "wigwam" => ("wigwams", "wigwammed", "wigwamming", "wigwammed"),
-    "wildcat" => ("wildcats", "wildcatted", "wildcatting", "wildcatted"),
-    "wilderpee" => ("wilderpees", "wilderpeed", "wilderpeeing", "wilderpeed"),
-    "wildfeed" => ("wildfeeds", "wildfed", "wildfeeding", "wildfed"),
-    "will2" => ("will", "would", "willing", "would"),
-    "willey" => ("willeys", "willeyed", "willeying", "willeyed"),
-    "wimp" => ("wimps", "wimped", "wimping", "wimped"),
-    "win" => ("wins", "won", "winning", "won"),
-    "wind2" => ("winds", "wound", "winding", "wound"),
-    "windjam" => ("windjams", "windjammed", "windjamming", "windjammed"),
-    "winge" => ("winges", "winged", "wingeing", "winged"),
-    "wingman" => ("wingmans", "wingmanned", "wingmanning", "wingmanned"),
-    "winterfeed" => ("winterfeeds", "winterfed", "winterfeeding", "winterfed"),
-    "wiredraw" => ("wiredraws", "wiredrew", "wiredrawing", "wiredrawn"),
-    "wis" => ("wis", "wised", "wising", "wist"),
-    "wishcast" => ("wishcasts", "wishcast", "wishcasting", "wishcast"),
-    "wisp" => ("wisps", "wisped", "wisping", "wisped"),
-    "wit" => ("wot", "wist", "witting", "wist"),
-    "withbear" => ("withbears", "withbore", "withbearing", "withborne"),
-    "withdraw" => ("withdraws", "withdrew", "withdrawing", "withdrawn"),
-    "withdrive" => ("withdrives", "withdrove", "withdriving", "withdriven"),
-    "withgo" => ("withgoes", "withwent", "withgoing", "withgone"),
-    "withhold" => ("withholds", "withheld", "withholding", "withheld"),
-    "withold" => ("witholds", "witheld", "witholding", "witheld"),
-    "withsake" => ("withsakes", "withsook", "withsaking", "withsaken"),
-    "withsay" => ("withsays", "withsaid", "withsaying", "withsaid"),
-    "withset" => ("withsets", "withset", "withsetting", "withset"),
-    "withsit" => ("withsits", "withsat", "withsitting", "withsat"),
-    "withspeak" => ("withspeaks", "withspoke", "withspeaking", "withspoken"),
-    "withstand" => ("withstands", "withstood", "withstanding", "withstood"),
-    "withstay" => ("withstays", "withstayed", "withstaying", "withstayed"),
-    "withtake" => ("withtakes", "withtook", "withtaking", "withtaken"),
-    "withthrow" => ("withthrows", "withthrew", "withthrowing", "withthrown"),
-    "wiz" => ("wizzes", "wizzed", "wizzing", "wizzed"),
-    "wollop" => ("wollops", "wolloped", "wolloping", "wolloped"),
-    "womp" => ("womps", "womped", "womping", "womped"),
-    "won" => ("wons", "wonned", "wonning", "wonned"),
-    "woodshed" => ("woodsheds", "woodshedded", "woodshedding", "woodshedded"),
-    "worship" => ("worships", "worshiped", "worshiping", "worshiped"),
-    "worth" => ("worths", "worth", "worthing", "worth"),
-    "wot" => ("wots", "wotted", "wotting", "wotted"),
-    "wramp" => ("wramps", "wramped", "wramping", "wramped"),
-    "wright" => ("wrights", "wrighten", "wrought", "wrighten"),
-    "wring" => ("wrings", "wrung", "wringing", "wrung"),
-    "write" => ("writes", "wrote", "writing", "written"),
-    "wrongdo" => ("wrongdoes", "wrongdid", "wrongdoing", "wrongdone"),
-    "wrongtake" => ("wrongtakes", "wrongtook", "wrongtaking", "wrongtaken"),
-    "wrongthink" => ("wrongthinks", "wrongthought", "wrongthinking", "wrongthought"),
-    "wub" => ("wubs", "wubbed", "wubbing", "wubbed"),
-    "wuk" => ("wuks", "wukked", "wuking", "wukked"),
-    "wuv" => ("wuvz", "wuvd", "wuving", "wuvd"),
-    "x" => ("xs", "xed", "xing", "xed"),
-    "xbreed" => ("xbreeds", "xbred", "xbreeding", "xbred"),
-    "xenotransmit" => ("xenotransmits", "xenotransmitted", "xenotransmitting", "xenotransmitted"),
-    "xfeed" => ("xfeeds", "xfed", "xfeeding", "xfed"),
-    "xfer" => ("xfers", "xferred", "xferring", "xferred"),
-    "xit" => ("xits", "xitted", "xitting", "xitted"),
-    "xmas" => ("xmass", "xmased", "xmasing", "xmased"),
-    "xmit" => ("xmits", "xmitted", "xmitting", "xmitted"),
-    "yab" => ("yabs", "yabbed", "yabbing", "yabbed"),
-    "yak" => ("yaks", "yakked", "yakking", "yakked"),
-    "yam" => ("yams", "yammed", "yamming", "yammed"),
-    "yankee" => ("yankees", "yankeed", "yankeeing", "yankeed"),
-    "yar" => ("yars", "yarred", "yarring", "yarred"),
-    "yaup" => ("yaups", "yauped", "yauping", "yauped"),
-    "yawp" => ("yawps", "yawped", "yawping", "yawped"),
-    "yeasay" => ("yeasays", "yeasaid", "yeasaying", "yeasaid"),
-    "yed" => ("yeds", "yedded", "yedding", "yedded"),
-    "yegg" => ("yeggs", "yegged", "yegging", "yegged"),
-    "yel" => ("yels", "yelled", "yelling", "yelled"),
-    "yelloch" => ("yellochs", "yelloched", "yelloching", "yelloched"),
-    "yelp" => ("yelps", "yelped", "yelping", "yelped"),
-    "yen" => ("yens", "yenned", "yenning", "yenned"),
-    "yes" => ("yeses", "yessed", "yessing", "yessed"),
-    "yet" => ("yets", "yet", "yetting", "yet"),
-    "yet2" => ("yets", "yot", "yetting", "yotten"),
-    "yewtree" => ("yewtrees", "yewtreed", "yewtreeing", "yewtreed"),
-    "yive" => ("yives", "yave", "yiving", "yiven"),
-    "yok" => ("yoks", "yokked", "yokking", "yokked"),
-    "yomp" => ("yomps", "yomped", "yomping", "yomped"),
-    "yot" => ("yots", "yotted", "yotting", "yotted"),
-    "yuk" => ("yuks", "yukked", "yukking", "yukked"),
-    "yume" => ("yumes", "yumed", "yumeing", "yumed"),
-    "yump" => ("yumps", "yumped", "yumping", "yumped"),
-    "zed" => ("zeds", "zedded", "zedding", "zedded"),
-    "zee" => ("zees", "zeed", "zeeing", "zeed"),
-    "zerg" => ("zergs", "zerged", "zerging", "zerged"),
-    "zero" => ("zeroes", "zeroed", "zeroing", "zeroed"),
-    "zhoozh" => ("zhoozhes", "zhoozhed", "zhoozhing", "zhoozhed"),
-    "zhuzh" => ("zhuzhes", "zhuzhed", "zhuzhing", "zhuzhed"),
-    "ziptie" => ("zipties", "ziptied", "ziptying", "ziptied"),
-    "zot" => ("zots", "zotted", "zotting", "zotted"),
-    "zuckerberg" => ("zuckerbergs", "zuckerberged", "zuckerberging", "zuckerberged"),
-    "zump" => ("zumps", "zumped", "zumping", "zumped"),
-    "zzz" => ("zzzs", "zzzed", "zzzing", "zzzed"),
+pub static VERB_MAP: phf::Map<&'static str, [&'static str; 4]> = phf_map! {
+    "ab" => ["abs", "abbed", "abbing", "abbed"],
+    "abear" => ["abears", "abore", "abearing", "aborn"],
+    "abegge" => ["abegges", "abought", "abegging", "abought"],
+    "abet" => ["abets", "abetted", "abetting", "abetted"],
+    "abey" => ["abeys", "abeyed", "abeying", "abeyed"],
+    "abhor" => ["abhors", "abhorred", "abhorring", "abhorred"],
+    "abide" => ["abides", "abode", "abiding", "abode"],
+    "abite" => ["abites", "abit", "abiting", "abitten"],
+    "abray" => ["abrays", "abrayed", "abraying", "abrayed"],
+    "absorp" => ["absorps", "absorped", "absorping", "absorped"],
+    "abut" => ["abuts", "abutted", "abutting", "abutted"],
+    "accloy" => ["accloys", "accloyed", "accloying", "accloyed"],
+    "accoy" => ["accoys", "accoyed", "accoying", "accoyed"],
+    "acknow" => ["acknows", "acknew", "acknowing", "acknown"],
+    "acknowledg" => ["acknowledges", "acknowledged", "acknowledging", "acknowledged"],
+    "acoy" => ["acoys", "acoyed", "acoying", "acoyed"],
+    "acquit" => ["acquits", "acquitted", "acquitting", "acquitted"],
+    "adfreeze" => ["adfreezes", "adfroze", "adfreezing", "adfrozen"],
+    "adjudg" => ["adjudges", "adjudged", "adjudging", "adjudged"],
+    "adlib" => ["adlibs", "adlibbed", "adlibbing", "adlibbed"],
+    "admit" => ["admits", "admitted", "admitting", "admitted"],
+    "adraw" => ["adraws", "adrew", "adrawing", "adrawn"],
+    "adree" => ["adrees", "adreed", "adreeing", "adreed"],
+    "adsorp" => ["adsorps", "adsorped", "adsorping", "adsorped"],
+    "affray" => ["affrays", "affrayed", "affraying", "affrayed"],
+    "aftcast" => ["aftcasts", "aftcast", "aftcasting", "aftcast"],
+    "aftersee" => ["aftersees", "aftersaw", "afterseeing", "afterseen"],
+    "afterthink" => ["afterthinks", "afterthought", "afterthinking", "afterthought"],
+    "againsay" => ["againsays", "againsaid", "againsaying", "againsaid"],
+    "againstand" => ["againstands", "againstood", "againstanding", "againstood"],
+    "agg" => ["aggs", "agged", "agging", "agged"],
+    "aggroup" => ["aggroups", "aggrouped", "aggrouping", "aggrouped"],
+    "agree" => ["agrees", "agreed", "agreeing", "agreed"],
+    "ahem" => ["ahems", "ahemmed", "ahemming", "ahemmed"],
+    "ahoy" => ["ahoys", "ahoyed", "ahoying", "ahoyed"],
+    "aimbot" => ["aimbots", "aimbotted", "aimbotting", "aimbotted"],
+    "airstrike" => ["airstrikes", "airstruck", "airstriking", "airstruck"],
+    "ajar" => ["ajars", "ajarred", "ajarring", "ajarred"],
+    "aken" => ["akens", "akenned", "akenning", "akenned"],
+    "akin" => ["akins", "akinned", "akinning", "akinned"],
+    "aknow" => ["aknows", "aknew", "aknowing", "aknown"],
+    "aks" => ["aks", "aksed", "aksing", "aksed"],
+    "alay" => ["alays", "alayed", "alaying", "alayed"],
+    "alight2" => ["alights", "alit", "alighting", "alit"],
+    "allay" => ["allays", "allayed", "allaying", "allayed"],
+    "alledg" => ["alledges", "alledged", "alledging", "alledged"],
+    "alleycat" => ["alleycats", "alleycatted", "alleycatting", "alleycatted"],
+    "alleyoop" => ["alleyoops", "alleyooped", "alleyooping", "alleyooped"],
+    "allot" => ["allots", "allotted", "allotting", "allotted"],
+    "alloy" => ["alloys", "alloyed", "alloying", "alloyed"],
+    "amay" => ["amays", "amayed", "amaying", "amayed"],
+    "amit" => ["amits", "amitted", "amitting", "amitted"],
+    "amp" => ["amps", "amped", "amping", "amped"],
+    "amscray" => ["amscrays", "amscrayed", "amscraying", "amscrayed"],
+    "anagram" => ["anagrams", "anagrammed", "anagramming", "anagrammed"],
+    "annoy" => ["annoys", "annoyed", "annoying", "annoyed"],
+    "annul" => ["annuls", "annulled", "annulling", "annulled"],
+    "ante" => ["antes", "anted", "anteing", "anted"],
+    "antic" => ["antics", "anticked", "anticking", "anticked"],
+    "apay" => ["apays", "apayed", "apaying", "apayed"],
+    "appal" => ["appals", "appalled", "appalling", "appalled"],
+    "appay" => ["appays", "appayed", "appaying", "appayed"],
+    "appel" => ["appels", "appelled", "appelling", "appelled"],
+    "applique" => ["appliques", "appliqued", "appliqueing", "appliqued"],
+    "applot" => ["applots", "applotted", "applotting", "applotted"],
+    "appuy" => ["appuys", "appuyed", "appuying", "appuyed"],
+    "aread" => ["areads", "ared", "areading", "ared"],
+    "arede" => ["aredes", "aredd", "areding", "aredd"],
+    "areed" => ["areeds", "ared", "areeds", "ared"],
+    "aret" => ["arets", "aretted", "aretting", "aretted"],
+    "arise" => ["arises", "arose", "arising", "arisen"],
+    "arize" => ["arizes", "aroze", "arizing", "arizen"],
+    "armbar" => ["armbars", "armbarred", "armbarring", "armbarred"],
+    "array" => ["arrays", "arrayed", "arraying", "arrayed"],
+    "arread" => ["arreads", "arread", "arreading", "arread"],
+    "arreede" => ["arreedes", "arred", "arreeding", "arred"],
+    "ashcan" => ["ashcans", "ashcanned", "ashcanning", "ashcanned"],
+    "ashtray" => ["ashtrays", "ashtrayed", "ashtraying", "ashtrayed"],
+    "assagay" => ["assagays", "assagayed", "assagaying", "assagayed"],
+    "assay" => ["assays", "assayed", "assaying", "assayed"],
+    "assoilzie" => ["assoilzies", "assoilzied", "assoilzieing", "assoilzied"],
+    "assrun" => ["assruns", "assran", "assrunning", "assrun"],
+    "astand" => ["astands", "astood", "astanding", "astood"],
+    "astun" => ["astuns", "astunned", "astunning", "astunned"],
+    "at" => ["ats", "atted", "atting", "atted"],
+    "atgo" => ["atgoes", "atwent", "atgoing", "atgone"],
+    "atray" => ["atrays", "atrayed", "atraying", "atrayed"],
+    "atrede" => ["atredes", "atred", "atreding", "atred"],
+    "atren" => ["atrens", "atran", "atrenning", "atrun"],
+    "atride" => ["atrides", "atrode", "atriding", "atridden"],
+    "atrin" => ["atrins", "atran", "atrinning", "atrun"],
+    "atsake" => ["atsakes", "atsook", "atsaking", "atsaken"],
+    "atshake" => ["atshakes", "atshook", "atshaking", "atshaken"],
+    "atshoot" => ["atshoots", "atshot", "atshooting", "atshot"],
+    "atsit" => ["atsits", "atsat", "atsitting", "atsat"],
+    "atspring" => ["atsprings", "atsprang", "atspringing", "atsprung"],
+    "atstand" => ["atstands", "atstood", "atstanding", "atstood"],
+    "atstut" => ["atstuts", "atstutted", "atstutting", "atstutted"],
+    "attorney" => ["attorneys", "attorneyed", "attorneying", "attorneyed"],
+    "atwind" => ["atwinds", "atwound", "atwinding", "atwound"],
+    "auntie" => ["aunties", "auntied", "auntying", "auntied"],
+    "autoban" => ["autobans", "autobanned", "autobanning", "autobanned"],
+    "autocamp" => ["autocamps", "autocamped", "autocamping", "autocamped"],
+    "autocommit" => ["autocommits", "autocommitted", "autocommitting", "autocommitted"],
+    "autofit" => ["autofits", "autofitted", "autofitting", "autofitted"],
+    "autoformat" => ["autoformats", "autoformatted", "autoformatting", "autoformatted"],
+    "autohide" => ["autohides", "autohid", "autohiding", "autohidden"],
+    "autopen" => ["autopens", "autopenned", "autopenning", "autopenned"],
+    "autoplay" => ["autoplays", "autoplayed", "autoplaying", "autoplayed"],
+    "autorun" => ["autoruns", "autoran", "autorunning", "autorun"],
+    "autowind" => ["autowinds", "autowound", "autowinding", "autowound"],
+    "avel" => ["avels", "avelled", "avelling", "avelled"],
+    "aver" => ["avers", "averred", "averring", "averred"],
+    "awake" => ["awakes", "awoke", "awaking", "awoken"],
+    "away" => ["aways", "awayed", "awaying", "awayed"],
+    "awestrike" => ["awestrikes", "awestruck", "awestriking", "awestruck"],
+    "awreak" => ["awreaks", "awroke", "awreaking", "awroke"],
+    "aye" => ["ayes", "ayed", "ayeing", "ayed"],
+    "bab" => ["babs", "babbed", "babbing", "babbed"],
+    "babysit" => ["babysits", "babysat", "babysitting", "babysat"],
+    "babywear" => ["babywears", "babywore", "babywearing", "babyworn"],
+    "backbite" => ["backbites", "backbit", "backbiting", "backbitten"],
+    "backcast" => ["backcasts", "backcast", "backcasting", "backcast"],
+    "backchat" => ["backchats", "backchatted", "backchatting", "backchatted"],
+    "backfit" => ["backfits", "backfitted", "backfitting", "backfitted"],
+    "backgrind" => ["backgrinds", "background", "backgrinding", "background"],
+    "backhoe" => ["backhoes", "backhoed", "backhoeing", "backhoed"],
+    "backpat" => ["backpats", "backpatted", "backpatting", "backpatted"],
+    "backread" => ["backreads", "backread", "backreading", "backread"],
+    "backride" => ["backrides", "backrode", "backriding", "backridden"],
+    "backscan" => ["backscans", "backscanned", "backscanning", "backscanned"],
+    "backsell" => ["backsells", "backsold", "backselling", "backsold"],
+    "backset" => ["backsets", "backset", "backsetting", "backset"],
+    "backshine" => ["backshines", "backshone", "backshining", "backshone"],
+    "backshoot" => ["backshoots", "backshot", "backshooting", "backshot"],
+    "backshot" => ["backshots", "backshotted", "backshotting", "backshotted"],
+    "backslide" => ["backslides", "backslid", "backsliding", "backslidden"],
+    "backspin" => ["backspins", "backspun", "backspinning", "backspun"],
+    "backstab" => ["backstabs", "backstabbed", "backstabbing", "backstabbed"],
+    "backstamp" => ["backstamps", "backstamped", "backstamping", "backstamped"],
+    "backswing" => ["backswings", "backswung", "backswinging", "backswung"],
+    "backtab" => ["backtabs", "backtabbed", "backtabbing", "backtabbed"],
+    "backtransfer" => ["backtransfers", "backtransferred", "backtransferring", "backtransferred"],
+    "backwind2" => ["backwinds", "backwound", "backwinding", "backwound"],
+    "bad" => ["bads", "badded", "badding", "badded"],
+    "bae" => ["baes", "baed", "baeing", "baed"],
+    "bafflegab" => ["bafflegabs", "bafflegabbed", "bafflegabbing", "bafflegabbed"],
+    "bam" => ["bams", "bammed", "bamming", "bammed"],
+    "ban" => ["bans", "banned", "banning", "banned"],
+    "bandsaw" => ["bandsaws", "bandsawed", "bandsawing", "bandsawn"],
+    "bar" => ["bars", "barred", "barring", "barred"],
+    "barkeep" => ["barkeeps", "barkeeped", "barkeeping", "barkeeped"],
+    "barney" => ["barneys", "barneyed", "barneying", "barneyed"],
+    "barricado" => ["barricadoes", "barricadoed", "barricadoing", "barricadoed"],
+    "bastinado" => ["bastinadoes", "bastinadoed", "bastinadoing", "bastinadoed"],
+    "bat" => ["bats", "batted", "batting", "batted"],
+    "batman" => ["batmans", "batmanned", "batmanning", "batmanned"],
+    "battel2" => ["battels", "battelled", "battelling", "battelled"],
+    "bauf" => ["baufs", "bauffed", "bauffing", "bauffed"],
+    "bay" => ["bays", "bayed", "baying", "bayed"],
+    "bear2" => ["bears", "bore", "bearing", "borne"],
+    "beat" => ["beats", "beat", "beating", "beaten"],
+    "bebar" => ["bebars", "bebarred", "bebarring", "bebarred"],
+    "bebay" => ["bebays", "bebayed", "bebaying", "bebayed"],
+    "bebleed" => ["bebleeds", "bebled", "bebleeding", "bebled"],
+    "beblot" => ["beblots", "beblotted", "beblotting", "beblotted"],
+    "beblow" => ["beblows", "beblew", "beblowing", "beblown"],
+    "beblur" => ["beblurs", "beblurred", "beblurring", "beblurred"],
+    "bebreak" => ["bebreaks", "bebroke", "bebreaking", "bebroken"],
+    "bebump" => ["bebumps", "bebumped", "bebumping", "bebumped"],
+    "becast" => ["becasts", "becast", "becasting", "becast"],
+    "becatch" => ["becatches", "becaught", "becatching", "becaught"],
+    "bechat" => ["bechats", "bechatted", "bechatting", "bechatted"],
+    "bechide" => ["bechides", "bechid", "bechiding", "bechid"],
+    "beclam" => ["beclams", "beclammed", "beclamming", "beclammed"],
+    "beclasp" => ["beclasps", "beclasped", "beclasping", "beclasped"],
+    "beclepe" => ["beclepes", "beclept", "becleping", "beclept"],
+    "become" => ["becomes", "became", "becoming", "become"],
+    "becreep" => ["becreeps", "becrept", "becreeping", "becrept"],
+    "becut" => ["becuts", "becut", "becutting", "becut"],
+    "bed" => ["beds", "bedded", "bedding", "bedded"],
+    "bedamp" => ["bedamps", "bedamped", "bedamping", "bedamped"],
+    "bede" => ["bedes", "bade", "beding", "bode"],
+    "bedeal" => ["bedeals", "bedealt", "bedealing", "bedealt"],
+    "bedight" => ["bedights", "bedight", "bedighting", "bedight"],
+    "bedim" => ["bedims", "bedimmed", "bedimming", "bedimmed"],
+    "bedo" => ["bedoes", "bedid", "bedoing", "bedone"],
+    "bedraw" => ["bedraws", "bedrew", "bedrawing", "bedrawn"],
+    "bedream" => ["bedreams", "bedreamt", "bedreaming", "bedreamt"],
+    "bedrink" => ["bedrinks", "bedrank", "bedrinking", "bedrunk"],
+    "bedrite" => ["bedrites", "bedrote", "bedriting", "bedritten"],
+    "bedrive" => ["bedrives", "bedrove", "bedriving", "bedriven"],
+    "bedrum" => ["bedrums", "bedrummed", "bedrumming", "bedrummed"],
+    "bedwell" => ["bedwells", "bedwelt", "bedwelling", "bedwelt"],
+    "bedwet" => ["bedwets", "bedwet", "bedwetting", "bedwet"],
+    "bedye" => ["bedyes", "bedyed", "bedyeing", "bedyed"],
+    "beep" => ["beeps", "beeped", "beeping", "beeped"],
+    "befal" => ["befals", "befel", "befaling", "befalen"],
+    "befall" => ["befalls", "befell", "befalling", "befallen"],
+    "befie" => ["befies", "befied", "befying", "befied"],
+    "befind" => ["befinds", "befound", "befinding", "befound"],
+    "befit" => ["befits", "befitted", "befitting", "befitted"],
+    "beflay" => ["beflays", "beflayed", "beflaying", "beflayed"],
+    "beflee" => ["beflees", "befled", "befleeing", "befled"],
+    "befly" => ["beflies", "beflew", "beflying", "beflown"],
+    "befoe" => ["befoes", "befoed", "befoeing", "befoed"],
+    "befree" => ["befrees", "befreed", "befreeing", "befreed"],
+    "befreeze" => ["befreezes", "befroze", "befreezing", "befrozen"],
+    "befret" => ["befrets", "befretted", "befretting", "befretted"],
+    "befur" => ["befurs", "befurred", "befurring", "befurred"],
+    "begab" => ["begabs", "begabbed", "begabbing", "begabbed"],
+    "begat" => ["begats", "begatted", "begatting", "begatted"],
+    "begay" => ["begays", "begayed", "begaying", "begayed"],
+    "begem" => ["begems", "begemmed", "begemming", "begemmed"],
+    "beget" => ["begets", "begot", "begetting", "begotten"],
+    "begin" => ["begins", "began", "beginning", "begun"],
+    "begird" => ["begirds", "begirt", "begirding", "begirt"],
+    "begive" => ["begives", "begave", "begiving", "begiven"],
+    "bego" => ["begoes", "bewent", "begoing", "begone"],
+    "begod" => ["begods", "begodded", "begodding", "begodded"],
+    "begrasp" => ["begrasps", "begrasped", "begrasping", "begrasped"],
+    "begum" => ["begums", "begummed", "begumming", "begummed"],
+    "behang" => ["behangs", "behung", "behanging", "behung"],
+    "behat" => ["behats", "behatted", "behatting", "behatted"],
+    "behear" => ["behears", "beheard", "behearing", "beheard"],
+    "behelp" => ["behelps", "behelped", "behelping", "behelped"],
+    "behew" => ["behews", "behewed", "behewing", "behewn"],
+    "behight" => ["behights", "behight", "behighting", "behight"],
+    "behold" => ["beholds", "beheld", "beholding", "beheld"],
+    "beholde" => ["beholdes", "behelde", "beholding", "behelde"],
+    "behoney" => ["behoneys", "behoneyed", "behoneying", "behoneyed"],
+    "beken" => ["bekens", "bekenned", "bekenning", "bekenned"],
+    "beknit" => ["beknits", "beknit", "beknitting", "beknit"],
+    "beknow" => ["beknows", "beknew", "beknowing", "beknown"],
+    "belam" => ["belams", "belammed", "belamming", "belammed"],
+    "belay" => ["belays", "belayed", "belaying", "belayed"],
+    "belead" => ["beleads", "beled", "beleading", "beled"],
+    "beleap" => ["beleaps", "beleapt", "beleaping", "beleapt"],
+    "beleave" => ["beleaves", "beleft", "beleaving", "beleft"],
+    "belie" => ["belies", "belay", "belying", "belain"],
+    "belie2" => ["belies", "belied", "belying", "belied"],
+    "belive" => ["belives", "belove", "beliving", "beliven"],
+    "bemad" => ["bemads", "bemadded", "bemadding", "bemadded"],
+    "beman" => ["bemans", "bemanned", "bemanning", "bemanned"],
+    "bemar" => ["bemars", "bemarred", "bemarring", "bemarred"],
+    "bemean2" => ["bemeans", "bemeant", "bemeaning", "bemeant"],
+    "bemeet" => ["bemeets", "bemet", "bemeeting", "bemet"],
+    "bemud" => ["bemuds", "bemudded", "bemudding", "bemudded"],
+    "bend" => ["bends", "bent", "bending", "bent"],
+    "benday" => ["bendays", "bendayed", "bendaying", "bendayed"],
+    "benegro" => ["benegroes", "benegroed", "benegroing", "benegroed"],
+    "benet" => ["benets", "benetted", "benetting", "benetted"],
+    "benim" => ["benims", "benam", "benimming", "benomen"],
+    "bepat" => ["bepats", "bepatted", "bepatting", "bepatted"],
+    "beray" => ["berays", "berayed", "beraying", "berayed"],
+    "beread" => ["bereads", "beread", "bereading", "beread"],
+    "berede" => ["beredes", "bered", "bereding", "bered"],
+    "berend" => ["berends", "berent", "berending", "berent"],
+    "beride" => ["berides", "berode", "beriding", "beridden"],
+    "berisp" => ["berisps", "berisped", "berisping", "berisped"],
+    "berob" => ["berobs", "berobbed", "berobbing", "berobbed"],
+    "besay" => ["besays", "besaid", "besaying", "besaid"],
+    "bescrub" => ["bescrubs", "bescrubbed", "bescrubbing", "bescrubbed"],
+    "besee" => ["besees", "besaw", "beseeing", "beseen"],
+    "beseek" => ["beseeks", "besought", "beseeking", "besought"],
+    "beseeke" => ["beseekes", "besoughte", "beseeking", "besoughte"],
+    "besend" => ["besends", "besent", "besending", "besent"],
+    "beset" => ["besets", "beset", "besetting", "beset"],
+    "besew" => ["besews", "besewed", "besewing", "besewn"],
+    "beshake" => ["beshakes", "beshook", "beshaking", "beshaken"],
+    "besharp" => ["besharps", "besharped", "besharping", "besharped"],
+    "beshear" => ["beshears", "beshore", "beshearing", "beshorn"],
+    "beshine" => ["beshines", "beshone", "beshining", "beshone"],
+    "beshit" => ["beshits", "beshit", "beshitting", "beshit"],
+    "beshite" => ["beshites", "beshit", "beshiting", "beshitten"],
+    "beshoe" => ["beshoes", "beshoed", "beshoeing", "beshoed"],
+    "beshut" => ["beshuts", "beshut", "beshutting", "beshut"],
+    "besing" => ["besings", "besang", "besinging", "besung"],
+    "besinge" => ["besinges", "besinged", "besingeing", "besinged"],
+    "besit" => ["besits", "besat", "besitting", "besat"],
+    "besleep" => ["besleeps", "beslept", "besleeping", "beslept"],
+    "besmut" => ["besmuts", "besmutted", "besmutting", "besmutted"],
+    "besot" => ["besots", "besotted", "besotting", "besotted"],
+    "besow" => ["besows", "besowed", "besowing", "besown"],
+    "bespeak" => ["bespeaks", "bespoke", "bespeaking", "bespoken"],
+    "bespend" => ["bespends", "bespent", "bespending", "bespent"],
+    "bespin" => ["bespins", "bespun", "bespinning", "bespun"],
+    "bespit" => ["bespits", "bespat", "bespitting", "bespitten"],
+    "bespot" => ["bespots", "bespotted", "bespotting", "bespotted"],
+    "bespray" => ["besprays", "besprayed", "bespraying", "besprayed"],
+    "bespread" => ["bespreads", "bespread", "bespreading", "bespread"],
+    "besprenge" => ["besprenges", "besprent", "besprenging", "besprent"],
+    "bestand" => ["bestands", "bestood", "bestanding", "bestood"],
+    "bestar" => ["bestars", "bestarred", "bestarring", "bestarred"],
+    "bestay" => ["bestays", "bestayed", "bestaying", "bestayed"],
+    "bestead2" => ["besteads", "besteaded", "besteading", "bestead"],
+    "besteal" => ["besteals", "bestole", "bestealing", "bestolen"],
+    "bestem" => ["bestems", "bestemmed", "bestemming", "bestemmed"],
+    "bestick" => ["besticks", "bestuck", "besticking", "bestuck"],
+    "bestir" => ["bestirs", "bestirred", "bestirring", "bestirred"],
+    "bestrew" => ["bestrews", "bestrewed", "bestrewing", "bestrewn"],
+    "bestride" => ["bestrides", "bestrode", "bestriding", "bestrode"],
+    "bestrow" => ["bestrows", "bestrowed", "bestrowing", "bestrown"],
+    "bestrut" => ["bestruts", "bestrutted", "bestrutting", "bestrutted"],
+    "bestud" => ["bestuds", "bestudded", "bestudding", "bestudded"],
+    "beswear" => ["beswears", "beswore", "beswearing", "besworn"],
+    "beswinge" => ["beswinges", "beswinged", "beswingeing", "beswinged"],
+    "beswink" => ["beswinks", "beswank", "beswinking", "beswunk"],
+    "bet" => ["bets", "bet", "betting", "bet"],
+    "betake" => ["betakes", "betook", "betaking", "betaken"],
+    "beteach" => ["beteaches", "betaught", "beteaching", "betaught"],
+    "betear2" => ["betears", "betore", "betearing", "betorn"],
+    "betell" => ["betells", "betold", "betelling", "betold"],
+    "bethink" => ["bethinks", "bethought", "bethinking", "bethought"],
+    "bethrow" => ["bethrows", "bethrew", "bethrowing", "bethrown"],
+    "bethump" => ["bethumps", "bethumped", "bethumping", "bethumped"],
+    "betide" => ["betides", "betid", "betiding", "betid"],
+    "betie" => ["beties", "betied", "betying", "betied"],
+    "betray" => ["betrays", "betrayed", "betraying", "betrayed"],
+    "betread" => ["betreads", "betrod", "betreading", "betrodden"],
+    "betrim" => ["betrims", "betrimmed", "betrimming", "betrimmed"],
+    "betrump" => ["betrumps", "betrumped", "betrumping", "betrumped"],
+    "betwynde" => ["betwyndes", "betwound", "betwynding", "betwound"],
+    "bewed" => ["beweds", "bewed", "bewedding", "bewed"],
+    "beweep" => ["beweeps", "bewept", "beweeping", "bewept"],
+    "bewend" => ["bewends", "bewent", "bewending", "bewent"],
+    "bewet" => ["bewets", "bewet", "bewetting", "bewet"],
+    "bewin" => ["bewins", "bewon", "bewinning", "bewon"],
+    "bewind" => ["bewinds", "bewound", "bewinding", "bewound"],
+    "bewit" => ["bewits", "bewitted", "bewitting", "bewitted"],
+    "bewray" => ["bewrays", "bewrayed", "bewraying", "bewrayed"],
+    "bewrite" => ["bewrites", "bewrote", "bewriting", "bewritten"],
+    "beyelp" => ["beyelps", "beyelped", "beyelping", "beyelped"],
+    "bib" => ["bibs", "bibbed", "bibbing", "bibbed"],
+    "bid" => ["bids", "bid", "bidding", "bid"],
+    "bide" => ["bides", "bode", "biding", "bided"],
+    "bigg" => ["biggs", "bigged", "bigging", "bigged"],
+    "bikeshed" => ["bikesheds", "bikeshedded", "bikeshedding", "bikeshedded"],
+    "bilerp" => ["bilerps", "bilerped", "bilerping", "bilerped"],
+    "bin" => ["bins", "binned", "binning", "binned"],
+    "bind" => ["binds", "bound", "binding", "bound"],
+    "binge" => ["binges", "binged", "bingeing", "binged"],
+    "bioassay" => ["bioassays", "bioassayed", "bioassaying", "bioassayed"],
+    "biocontrol" => ["biocontrols", "biocontrolled", "biocontrolling", "biocontrolled"],
+    "biodecay" => ["biodecays", "biodecayed", "biodecaying", "biodecayed"],
+    "biospin" => ["biospins", "biospun", "biospinning", "biospun"],
+    "birdie" => ["birdies", "birdied", "birdieing", "birdied"],
+    "birdiesit" => ["birdiesits", "birdiesat", "birdiesitting", "birdiesat"],
+    "birdsit" => ["birdsits", "birdsat", "birdsitting", "birdsat"],
+    "birdysit" => ["birdysits", "birdysat", "birdysitting", "birdysat"],
+    "birg" => ["birgs", "birged", "birging", "birged"],
+    "birthday" => ["birthdays", "birthdayed", "birthdaying", "birthdayed"],
+    "bishop" => ["bishops", "bishoped", "bishoping", "bishoped"],
+    "bit" => ["bits", "bitted", "bitting", "bitted"],
+    "bite" => ["bites", "bit", "biting", "bitten"],
+    "bitrot" => ["bitrots", "bitrotted", "bitrotting", "bitrotted"],
+    "bivouac" => ["bivouacs", "bivouacked", "bivouacing", "bivouacked"],
+    "blab" => ["blabs", "blabbed", "blabbing", "blabbed"],
+    "blam" => ["blams", "blammed", "blamming", "blammed"],
+    "blarney" => ["blarneys", "blarneyed", "blarneying", "blarneyed"],
+    "blat" => ["blats", "blatted", "blatting", "blatted"],
+    "blawg" => ["blawgs", "blawged", "blawging", "blawged"],
+    "bleb" => ["blebs", "blebbed", "blebbing", "blebbed"],
+    "bleed" => ["bleeds", "bled", "bleeding", "bled"],
+    "bleep" => ["bleeps", "bleeped", "bleeping", "bleeped"],
+    "blem" => ["blems", "blemmed", "blemming", "blemmed"],
+    "bless" => ["blesses", "blest", "blessing", "blest"],
+    "blet" => ["blets", "bletted", "bletting", "bletted"],
+    "blevey" => ["bleveys", "bleveyed", "bleveying", "bleveyed"],
+    "blimp" => ["blimps", "blimped", "blimping", "blimped"],
+    "blin" => ["blins", "blinned", "blinning", "blinned"],
+    "blit" => ["blits", "blitted", "blitting", "blitted"],
+    "blive" => ["blives", "blove", "bliving", "bliven"],
+    "blob" => ["blobs", "blobbed", "blobbing", "blobbed"],
+    "bloodfeed" => ["bloodfeeds", "bloodfed", "bloodfeeding", "bloodfed"],
+    "bloodlet" => ["bloodlets", "bloodlet", "bloodletting", "bloodlet"],
+    "bloop" => ["bloops", "blooped", "blooping", "blooped"],
+    "blorp" => ["blorps", "blorped", "blorping", "blorped"],
+    "blot" => ["blots", "blotted", "blotting", "blotted"],
+    "blow" => ["blows", "blew", "blowing", "blown"],
+    "blowe" => ["blowes", "blewe", "blowing", "blowne"],
+    "blox" => ["bloxxes", "bloxxed", "bloxxing", "bloxxed"],
+    "blub" => ["blubs", "blubbed", "blubbing", "blubbed"],
+    "blue" => ["blues", "blued", "blueing", "blued"],
+    "blur" => ["blurs", "blurred", "blurring", "blurred"],
+    "bmx" => ["bmxs", "bmxed", "bmxing", "bmxed"],
+    "bob" => ["bobs", "bobbed", "bobbing", "bobbed"],
+    "bobsled" => ["bobsleds", "bobsledded", "bobsledding", "bobsledded"],
+    "bodybuild" => ["bodybuilds", "bodybuilt", "bodybuilding", "bodybuilt"],
+    "bodyslam" => ["bodyslams", "bodyslammed", "bodyslamming", "bodyslammed"],
+    "bogan" => ["bogans", "boganned", "boganning", "boganned"],
+    "bogey" => ["bogeys", "bogeyed", "bogeying", "bogeyed"],
+    "bok" => ["boks", "bokked", "bokking", "bokked"],
+    "boobfeed" => ["boobfeeds", "boobfed", "boobfeeding", "boobfed"],
+    "boog" => ["boogs", "booged", "booging", "booged"],
+    "boogey" => ["boogeys", "boogeyed", "boogeying", "boogeyed"],
+    "boogie" => ["boogies", "boogied", "boogying", "boogied"],
+    "bookkeep" => ["bookkeeps", "bookkept", "bookkeeping", "bookkept"],
+    "boop" => ["boops", "booped", "booping", "booped"],
+    "bopeep" => ["bopeeps", "bopeeped", "bopeeping", "bopeeped"],
+    "borg" => ["borgs", "borged", "borging", "borged"],
+    "born" => ["borns", "bornt", "bornin", "bornt"],
+    "bot" => ["bots", "botted", "botting", "botted"],
+    "bowcat" => ["bowcats", "bowcatted", "bowcatting", "bowcatted"],
+    "bowstring" => ["bowstrings", "bowstrung", "bowstringing", "bowstrung"],
+    "boxcar" => ["boxcars", "boxcarred", "boxcarring", "boxcarred"],
+    "boy" => ["boys", "boyed", "boying", "boyed"],
+    "braai" => ["braais", "braaied", "braaing", "braaied"],
+    "brad" => ["brads", "bradded", "bradding", "bradded"],
+    "bran" => ["brans", "branned", "branning", "branned"],
+    "brast" => ["brasts", "brast", "brasting", "brast"],
+    "brat" => ["brats", "bratted", "bratting", "bratted"],
+    "bray" => ["brays", "brayed", "braying", "brayed"],
+    "break2" => ["breaks", "broke", "breaking", "broken"],
+    "breake" => ["breakes", "broke", "breaking", "broken"],
+    "breastfeed" => ["breastfeeds", "breastfed", "breastfeeding", "breastfed"],
+    "breastsleep" => ["breastsleeps", "breastslept", "breastsleeping", "breastslept"],
+    "breathhold" => ["breathholds", "breathheld", "breathholding", "breathheld"],
+    "breed" => ["breeds", "bred", "breeding", "bred"],
+    "bren" => ["brens", "brenned", "brenning", "brenned"],
+    "brevet" => ["brevets", "brevetted", "brevetting", "brevetted"],
+    "brey" => ["breys", "breyed", "breying", "breyed"],
+    "brickbat" => ["brickbats", "brickbatted", "brickbatting", "brickbatted"],
+    "bricklay" => ["bricklays", "bricklaid", "bricklaying", "bricklaid"],
+    "brim" => ["brims", "brimmed", "brimming", "brimmed"],
+    "bring" => ["brings", "brought", "bringing", "brought"],
+    "bringe" => ["bringes", "broughte", "bringing", "broughte"],
+    "briquet" => ["briquets", "briquetted", "briquetting", "briquetted"],
+    "brit" => ["brits", "britted", "britting", "britted"],
+    "broadcast" => ["broadcasts", "broadcast", "broadcasting", "broadcast"],
+    "brob" => ["brobs", "brobbed", "brobbing", "brobbed"],
+    "brod" => ["brods", "brodded", "brodding", "brodded"],
+    "bronch" => ["bronchs", "bronched", "bronching", "bronched"],
+    "browbeat" => ["browbeats", "browbeat", "browbeating", "browbeaten"],
+    "brust" => ["brusts", "brust", "brusting", "brust"],
+    "bub" => ["bubs", "bubbed", "bubbing", "bubbed"],
+    "buckjump" => ["buckjumps", "buckjumped", "buckjumping", "buckjumped"],
+    "bud" => ["buds", "budded", "budding", "budded"],
+    "budg" => ["budges", "budged", "budging", "budged"],
+    "buffalo" => ["buffaloes", "buffaloed", "buffaloing", "buffaloed"],
+    "buie" => ["buies", "bought", "buying", "bought"],
+    "build" => ["builds", "built", "building", "built"],
+    "bukkake" => ["bukkakes", "bukkaked", "bukkakeing", "bukkaked"],
+    "bullshit" => ["bullshits", "bullshitted", "bullshitting", "bullshitted"],
+    "bullspeak" => ["bullspeaks", "bullspoke", "bullspeaking", "bullspoken"],
+    "bum" => ["bums", "bummed", "bumming", "bummed"],
+    "bump" => ["bumps", "bumped", "bumping", "bumped"],
+    "bun" => ["buns", "bunned", "bunning", "bunned"],
+    "bungee" => ["bungees", "bungeed", "bungeeing", "bungeed"],
+    "buoy" => ["buoys", "buoyed", "buoying", "buoyed"],
+    "burie" => ["buries", "buried", "burying", "buried"],
+    "burp" => ["burps", "burped", "burping", "burped"],
+    "burst" => ["bursts", "burst", "bursting", "burst"],
+    "burye" => ["buryes", "buryed", "buryeing", "buryed"],
+    "bus" => ["buss", "bused", "busing", "bused"],
+    "but" => ["buts", "butted", "butting", "butted"],
+    "buz" => ["buzzes", "buzzed", "buzzing", "buzzed"],
+    "bycatch" => ["bycatches", "bycaught", "bycatching", "bycaught"],
+    "cab" => ["cabs", "cabbed", "cabbing", "cabbed"],
+    "cablecast" => ["cablecasts", "cablecast", "cablecasting", "cablecast"],
+    "cablegram" => ["cablegrams", "cablegrammed", "cablegramming", "cablegrammed"],
+    "cabob" => ["cabobs", "cabobbed", "cabobbing", "cabobbed"],
+    "caddie" => ["caddies", "caddied", "caddying", "caddied"],
+    "cajol" => ["cajols", "cajolled", "cajolling", "cajolled"],
+    "calefie" => ["calefies", "calefied", "calefying", "calefied"],
+    "cam" => ["cams", "cammed", "camming", "cammed"],
+    "cameltoe" => ["cameltoes", "cameltoed", "cameltoeing", "cameltoed"],
+    "camp" => ["camps", "camped", "camping", "camped"],
+    "campervan" => ["campervans", "campervanned", "campervanning", "campervanned"],
+    "campshed" => ["campsheds", "campshedded", "campshedding", "campshedded"],
+    "can" => ["can", "could", "able", "could"],
+    "can2" => ["cans", "canned", "canning", "canned"],
+    "canal" => ["canals", "canalled", "canalling", "canalled"],
+    "cancan" => ["cancans", "cancanned", "cancanning", "cancanned"],
+    "canoe" => ["canoes", "canoed", "canoeing", "canoed"],
+    "capot" => ["capots", "capotted", "capotting", "capotted"],
+    "caracol" => ["caracols", "caracolled", "caracolling", "caracolled"],
+    "caravan" => ["caravans", "caravanned", "caravanning", "caravanned"],
+    "carboy" => ["carboys", "carboyed", "carboying", "carboyed"],
+    "caregive" => ["caregives", "caregave", "caregiving", "caregiven"],
+    "caretake" => ["caretakes", "caretook", "caretaking", "caretaken"],
+    "carney" => ["carneys", "carneyed", "carneying", "carneyed"],
+    "carp" => ["carps", "carped", "carping", "carped"],
+    "carrie" => ["carries", "carried", "carrying", "carried"],
+    "cashapp" => ["cashapps", "cashapped", "cashapping", "cashapped"],
+    "cast" => ["casts", "cast", "casting", "cast"],
+    "cat" => ["cats", "catted", "catting", "catted"],
+    "catalog" => ["catalogs", "cataloged", "cataloging", "cataloged"],
+    "catch" => ["catches", "caught", "catching", "caught"],
+    "catsit" => ["catsits", "catsat", "catsitting", "catsat"],
+    "causeway" => ["causeways", "causewayed", "causewaying", "causewayed"],
+    "caut" => ["cauteth", "cauted", "cauting", "cauted"],
+    "cham" => ["chams", "chammed", "chamming", "chammed"],
+    "champ" => ["champs", "champed", "champing", "champed"],
+    "char2" => ["chars", "charred", "charring", "charred"],
+    "charpit" => ["charpits", "charpitted", "charpitting", "charpitted"],
+    "chasse" => ["chasses", "chassed", "chasseing", "chassed"],
+    "chat" => ["chats", "chatted", "chatting", "chatted"],
+    "chauf" => ["chaufs", "chauffed", "chauffing", "chauffed"],
+    "chavel" => ["chavels", "chavelled", "chavelling", "chavelled"],
+    "cheap" => ["cheaps", "cheaped", "cheaping", "cheaped"],
+    "checksum" => ["checksums", "checksummed", "checksumming", "checksummed"],
+    "cheep" => ["cheeps", "cheeped", "cheeping", "cheeped"],
+    "cheerlead" => ["cheerleads", "cheerled", "cheerleading", "cheerled"],
+    "chef" => ["chefs", "cheffed", "cheffing", "cheffed"],
+    "chelp" => ["chelps", "chelped", "chelping", "chelped"],
+    "chemic" => ["chemics", "chemicked", "chemicking", "chemicked"],
+    "chemostat" => ["chemostats", "chemostatted", "chemostatting", "chemostatted"],
+    "cherup" => ["cherups", "cheruped", "cheruping", "cheruped"],
+    "chestfeed" => ["chestfeeds", "chestfed", "chestfeeding", "chestfed"],
+    "chib" => ["chibs", "chibbed", "chibbing", "chibbed"],
+    "chide" => ["chides", "chid", "chiding", "chid"],
+    "childsit" => ["childsits", "childsat", "childsitting", "childsat"],
+    "chimney" => ["chimneys", "chimneyed", "chimneying", "chimneyed"],
+    "chimp" => ["chimps", "chimped", "chimping", "chimped"],
+    "chin" => ["chins", "chinned", "chinning", "chinned"],
+    "chirp" => ["chirps", "chirped", "chirping", "chirped"],
+    "chirrup" => ["chirrups", "chirruped", "chirruping", "chirruped"],
+    "chit" => ["chits", "chitted", "chitting", "chitted"],
+    "chitchat" => ["chitchats", "chitchatted", "chitchatting", "chitchatted"],
+    "chiv" => ["chivs", "chivved", "chivving", "chivved"],
+    "chivaree" => ["chivarees", "chivareed", "chivareeing", "chivareed"],
+    "chivari" => ["chivaries", "chivaried", "chivariing", "chivaried"],
+    "chivey" => ["chiveys", "chiveyed", "chiveying", "chiveyed"],
+    "chokehold" => ["chokeholds", "chokeheld", "chokeholding", "chokeheld"],
+    "chokeslam" => ["chokeslams", "chokeslammed", "chokeslamming", "chokeslammed"],
+    "chomp" => ["chomps", "chomped", "chomping", "chomped"],
+    "choose" => ["chooses", "chose", "choosing", "chosen"],
+    "chor" => ["chors", "chorred", "chorrin", "chorred"],
+    "chromakey" => ["chromakeys", "chromakeyed", "chromakeying", "chromakeyed"],
+    "chub" => ["chubs", "chubbed", "chubbing", "chubbed"],
+    "chud" => ["chuds", "chudded", "chudding", "chudded"],
+    "chum" => ["chums", "chummed", "chumming", "chummed"],
+    "chump" => ["chumps", "chumped", "chumping", "chumped"],
+    "chunkay" => ["chunkays", "chunkayed", "chunkaying", "chunkayed"],
+    "chuse" => ["chuses", "chose", "chusing", "chosen"],
+    "chut" => ["chuts", "chutted", "chutting", "chutted"],
+    "cinemacast" => ["cinemacasts", "cinemacast", "cinemacasting", "cinemacast"],
+    "clad" => ["clads", "clad", "cladding", "clad"],
+    "clam" => ["clams", "clammed", "clamming", "clammed"],
+    "clamp" => ["clamps", "clamped", "clamping", "clamped"],
+    "clasp" => ["clasps", "clasped", "clasping", "clasped"],
+    "clat" => ["clats", "clatted", "clatting", "clatted"],
+    "clay" => ["clays", "clayed", "claying", "clayed"],
+    "cleave2" => ["cleaves", "cleft", "cleaving", "cleft"],
+    "clem" => ["clems", "clemmed", "clemming", "clemmed"],
+    "clep2" => ["cleps", "clept", "clepping", "clept"],
+    "cliche" => ["cliches", "cliched", "clicheing", "cliched"],
+    "cliffhang" => ["cliffhangs", "cliffhung", "cliffhanging", "cliffhung"],
+    "cling2" => ["clings", "clung", "clinging", "clung"],
+    "clip2" => ["clips", "clipt", "clipping", "clipt"],
+    "clit" => ["clits", "clitted", "clitting", "clitted"],
+    "clod" => ["clods", "clodded", "clodding", "clodded"],
+    "clomp" => ["clomps", "clomped", "clomping", "clomped"],
+    "cloop" => ["cloops", "clooped", "clooping", "clooped"],
+    "clot" => ["clots", "clotted", "clotting", "clotted"],
+    "clothespin" => ["clothespins", "clothespinned", "clothespinning", "clothespinned"],
+    "cloy" => ["cloys", "cloyed", "cloying", "cloyed"],
+    "club" => ["clubs", "clubbed", "clubbing", "clubbed"],
+    "clump" => ["clumps", "clumped", "clumping", "clumped"],
+    "coadmit" => ["coadmits", "coadmitted", "coadmitting", "coadmitted"],
+    "cob" => ["cobs", "cobbed", "cobbing", "cobbed"],
+    "cobuild" => ["cobuilds", "cobuilt", "cobuilding", "cobuilt"],
+    "cockey" => ["cockeys", "cockeyed", "cockeying", "cockeyed"],
+    "cod" => ["cods", "codded", "codding", "codded"],
+    "codevelop" => ["codevelops", "codeveloped", "codeveloping", "codeveloped"],
+    "codisplay" => ["codisplays", "codisplayed", "codisplaying", "codisplayed"],
+    "codistil" => ["codistils", "codistilled", "codistilling", "codistilled"],
+    "codrive" => ["codrives", "codrove", "codriving", "codriven"],
+    "cofeed" => ["cofeeds", "cofed", "cofeeding", "cofed"],
+    "coffee" => ["coffees", "coffeed", "coffeeing", "coffeed"],
+    "coif" => ["coifs", "coiffed", "coiffing", "coiffed"],
+    "cointer" => ["cointers", "cointerred", "cointerring", "cointerred"],
+    "coldsleep" => ["coldsleeps", "coldslept", "coldsleeping", "coldslept"],
+    "colead" => ["coleads", "coled", "coleading", "coled"],
+    "collop" => ["collops", "colloped", "colloping", "colloped"],
+    "colorbreed" => ["colorbreeds", "colorbred", "colorbreeding", "colorbred"],
+    "colorcast" => ["colorcasts", "colorcast", "colorcasting", "colorcast"],
+    "colourbreed" => ["colourbreeds", "colourbred", "colourbreeding", "colourbred"],
+    "colourcast" => ["colourcasts", "colourcast", "colourcasting", "colourcast"],
+    "come" => ["comes", "came", "coming", "come"],
+    "commit" => ["commits", "committed", "committing", "committed"],
+    "comp" => ["comps", "comped", "comping", "comped"],
+    "compel" => ["compels", "compelled", "compelling", "compelled"],
+    "complot" => ["complots", "complotted", "complotting", "complotted"],
+    "compromit" => ["compromits", "compromitted", "compromitting", "compromitted"],
+    "comptrol" => ["comptrols", "comptrolled", "comptrolling", "comptrolled"],
+    "con" => ["cons", "conned", "conning", "conned"],
+    "concat" => ["concats", "concatted", "concatting", "concatted"],
+    "concur" => ["concurs", "concurred", "concurring", "concurred"],
+    "condem" => ["condems", "condemmed", "condemming", "condemmed"],
+    "confab" => ["confabs", "confabbed", "confabbing", "confabbed"],
+    "confer" => ["confers", "conferred", "conferring", "conferred"],
+    "config" => ["configs", "configed", "configing", "configed"],
+    "conge" => ["conges", "conged", "congeing", "conged"],
+    "congee" => ["congees", "congeed", "congeeing", "congeed"],
+    "congree" => ["congrees", "congreed", "congreeing", "congreed"],
+    "control" => ["controls", "controlled", "controlling", "controlled"],
+    "convel" => ["convels", "convelled", "convelling", "convelled"],
+    "convey" => ["conveys", "conveyed", "conveying", "conveyed"],
+    "convoy" => ["convoys", "convoyed", "convoying", "convoyed"],
+    "cooccur" => ["cooccurs", "cooccurred", "cooccurring", "cooccurred"],
+    "cooee" => ["cooees", "cooeed", "cooeeing", "cooeed"],
+    "cooey" => ["cooeys", "cooeyed", "cooeying", "cooeyed"],
+    "cookie" => ["cookies", "cookied", "cookieing", "cookied"],
+    "coom" => ["cooms", "came", "cooming", "came"],
+    "coom2" => ["cooms", "came", "cooming", "coom"],
+    "coop" => ["coops", "cooped", "cooping", "cooped"],
+    "coorie" => ["coories", "cooried", "coorieing", "cooried"],
+    "copay" => ["copays", "copaid", "copaying", "copaid"],
+    "copen" => ["copens", "copenned", "copenning", "copenned"],
+    "coplot" => ["coplots", "coplotted", "coplotting", "coplotted"],
+    "copycat" => ["copycats", "copycatted", "copycatting", "copycatted"],
+    "copyread" => ["copyreads", "copyread", "copyreading", "copyread"],
+    "copywrite" => ["copywrites", "copywrote", "copywriting", "copywritten"],
+    "coquet" => ["coquets", "coquetted", "coquetting", "coquetted"],
+    "coquit" => ["coquits", "coquit", "coquitting", "coquit"],
+    "corduroy" => ["corduroys", "corduroyed", "corduroying", "corduroyed"],
+    "corefer" => ["corefers", "coreferred", "coreferring", "coreferred"],
+    "corncob" => ["corncobs", "corncobbed", "corncobbing", "corncobbed"],
+    "corrump" => ["corrumps", "corrumped", "corrumping", "corrumped"],
+    "cosleep" => ["cosleeps", "coslept", "cosleeping", "coslept"],
+    "cosplay" => ["cosplays", "cosplayed", "cosplaying", "cosplayed"],
+    "cost" => ["costs", "cost", "costing", "cost"],
+    "costar" => ["costars", "costarred", "costarring", "costarred"],
+    "cotch2" => ["cotches", "caught", "cotching", "caught"],
+    "coteach" => ["coteaches", "cotaught", "coteaching", "cotaught"],
+    "cotransfer" => ["cotransfers", "cotransferred", "cotransferring", "cotransferred"],
+    "cotransmit" => ["cotransmits", "cotransmitted", "cotransmitting", "cotransmitted"],
+    "counterbid" => ["counterbids", "counterbid", "counterbidding", "counterbid"],
+    "counterbleed" => ["counterbleeds", "counterbled", "counterbleeding", "counterbled"],
+    "counterdraw" => ["counterdraws", "counterdrew", "counterdrawing", "counterdrawn"],
+    "counterdye" => ["counterdyes", "counterdyed", "counterdyeing", "counterdyed"],
+    "counterfeed" => ["counterfeeds", "counterfed", "counterfeeding", "counterfed"],
+    "countermeet" => ["countermeets", "countermet", "countermeeting", "countermet"],
+    "counterplan" => ["counterplans", "counterplanned", "counterplanning", "counterplanned"],
+    "counterplay" => ["counterplays", "counterplayed", "counterplaying", "counterplayed"],
+    "counterplot" => ["counterplots", "counterplotted", "counterplotting", "counterplotted"],
+    "counterprogram" => ["counterprograms", "counterprogrammed", "counterprogramming", "counterprogrammed"],
+    "counterread" => ["counterreads", "counterread", "counterreading", "counterread"],
+    "counterrevolt" => ["counterrevolt", "counterrevolted", "counterrevolting", "counterrevolted"],
+    "countersing" => ["countersings", "countersang", "countersinging", "countersung"],
+    "countersink" => ["countersinks", "countersank", "countersinking", "countersunk"],
+    "counterstamp" => ["counterstamps", "counterstamped", "counterstamping", "counterstamped"],
+    "counterstrike" => ["counterstrikes", "counterstruck", "counterstriking", "counterstruck"],
+    "countersway" => ["countersways", "counterswayed", "counterswaying", "counterswayed"],
+    "counterswear" => ["counterswears", "counterswore", "counterswearing", "countersworn"],
+    "counterswing" => ["counterswings", "counterswung", "counterswinging", "counterswung"],
+    "counterthrow" => ["counterthrows", "counterthrew", "counterthrowing", "counterthrown"],
+    "countrol" => ["countrols", "countrolled", "countrolling", "countrolled"],
+    "coup" => ["coups", "couped", "couping", "couped"],
+    "covey" => ["coveys", "coveyed", "coveying", "coveyed"],
+    "covie" => ["covies", "covied", "covying", "covied"],
+    "cowboy" => ["cowboys", "cowboyed", "cowboying", "cowboyed"],
+    "cowp" => ["cowps", "cowped", "cowping", "cowped"],
+    "cowrite" => ["cowrites", "cowrote", "cowriting", "cowritten"],
+    "coy" => ["coys", "coyed", "coying", "coyed"],
+    "coyote" => ["coyotes", "coyoted", "coyoteing", "coyoted"],
+    "crab" => ["crabs", "crabbed", "crabbing", "crabbed"],
+    "cram" => ["crams", "crammed", "cramming", "crammed"],
+    "cramp" => ["cramps", "cramped", "cramping", "cramped"],
+    "cratedig" => ["cratedigs", "cratedug", "cratedigging", "cratedug"],
+    "cravat" => ["cravats", "cravatted", "cravatting", "cravatted"],
+    "creach" => ["creachs", "creached", "creaching", "creached"],
+    "creampie" => ["creampies", "creampied", "creampieing", "creampied"],
+    "creep" => ["creeps", "crept", "creeping", "crept"],
+    "creepshot" => ["creepshots", "creepshotted", "creepshotting", "creepshotted"],
+    "crescendo" => ["crescendoes", "crescendoed", "crescendoing", "crescendoed"],
+    "crib" => ["cribs", "cribbed", "cribbing", "cribbed"],
+    "crie" => ["cries", "cried", "crying", "cried"],
+    "crimethink" => ["crimethinks", "crimethought", "crimethinking", "crimethought"],
+    "crimp" => ["crimps", "crimped", "crimping", "crimped"],
+    "crisp" => ["crisps", "crisped", "crisping", "crisped"],
+    "crit" => ["crits", "crit", "critting", "crit"],
+    "critic" => ["critics", "criticked", "criticking", "criticked"],
+    "crockpot" => ["crockpots", "crockpotted", "crockpotting", "crockpotted"],
+    "crossbar" => ["crossbars", "crossbarred", "crossbarring", "crossbarred"],
+    "crossbite" => ["crossbites", "crossbit", "crossbiting", "crossbitten"],
+    "crossbreed" => ["crossbreeds", "crossbred", "crossbreeding", "crossbred"],
+    "crossclamp" => ["crossclamps", "crossclamped", "crossclamping", "crossclamped"],
+    "crosscut" => ["crosscuts", "crosscut", "crosscutting", "crosscut"],
+    "crossfeed" => ["crossfeeds", "crossfed", "crossfeeding", "crossfed"],
+    "crossfit" => ["crossfits", "crossfitted", "crossfitting", "crossfitted"],
+    "crossplay" => ["crossplays", "crossplayed", "crossplaying", "crossplayed"],
+    "crossref" => ["crossrefs", "crossreffed", "crossreffing", "crossreffed"],
+    "croup" => ["croups", "crouped", "crouping", "crouped"],
+    "crowbar" => ["crowbars", "crowbarred", "crowbarring", "crowbarred"],
+    "crud" => ["cruds", "crudded", "crudding", "crudded"],
+    "crump" => ["crumps", "crumped", "crumping", "crumped"],
+    "crut" => ["cruts", "crutted", "crutting", "crutted"],
+    "cryoclamp" => ["cryoclamps", "cryoclamped", "cryoclamping", "cryoclamped"],
+    "cryoembed" => ["cryoembeds", "cryoembedded", "cryoembedding", "cryoembedded"],
+    "cryogrind" => ["cryogrinds", "cryoground", "cryogrinding", "cryoground"],
+    "cryopump" => ["cryopumps", "cryopumped", "cryopumping", "cryopumped"],
+    "cub" => ["cubs", "cubbed", "cubbing", "cubbed"],
+    "cud" => ["cuds", "cudded", "cudding", "cudded"],
+    "cue" => ["cues", "cued", "cueing", "cued"],
+    "cum" => ["cums", "came", "cumming", "come"],
+    "cun" => ["cuns", "cunned", "cunning", "cunned"],
+    "curbstomp" => ["curbstomps", "curbstomped", "curbstomping", "curbstomped"],
+    "curmur" => ["curmurs", "curmurred", "curmurring", "curmurred"],
+    "curtsey" => ["curtseys", "curtseyed", "curtseying", "curtseyed"],
+    "curvet" => ["curvets", "curvetted", "curvetting", "curvetted"],
+    "cusp" => ["cusps", "cusped", "cusping", "cusped"],
+    "cut" => ["cuts", "cut", "cutting", "cut"],
+    "cvt" => ["cvts", "cvt", "cvtting", "cvt"],
+    "cybercast" => ["cybercasts", "cybercast", "cybercasting", "cybercast"],
+    "cybersquat" => ["cybersquats", "cybersquatted", "cybersquatting", "cybersquatted"],
+    "cyborg" => ["cyborgs", "cyborged", "cyborging", "cyborged"],
+    "dab" => ["dabs", "dabbed", "dabbing", "dabbed"],
+    "dad" => ["dads", "dadded", "dadding", "dadded"],
+    "dado" => ["dadoes", "dadoed", "dadoing", "dadoed"],
+    "dagnab" => ["dagnabs", "dagnabbed", "dagnabbing", "dagnabbed"],
+    "dak" => ["daks", "dakked", "dakking", "dakked"],
+    "dam" => ["dams", "dammed", "damming", "dammed"],
+    "damp" => ["damps", "damped", "damping", "damped"],
+    "daresay" => ["daresays", "daresayed", "daresaying", "daresayed"],
+    "darf" => ["darf", "durft", "durving", "durft"],
+    "databend" => ["databends", "databent", "databending", "databent"],
+    "datagram" => ["datagrams", "datagrammed", "datagramming", "datagrammed"],
+    "datestamp" => ["datestamps", "datestamped", "datestamping", "datestamped"],
+    "day" => ["days", "dayed", "daying", "dayed"],
+    "daydream" => ["daydreams", "daydreamt", "daydreaming", "daydreamt"],
+    "de" => ["diz", "did", "dein", "dyun"],
+    "deadpan" => ["deadpans", "deadpanned", "deadpanning", "deadpanned"],
+    "deal" => ["deals", "dealt", "dealing", "dealt"],
+    "debar" => ["debars", "debarred", "debarring", "debarred"],
+    "debel" => ["debels", "debelled", "debelling", "debelled"],
+    "debend" => ["debends", "debent", "debending", "debent"],
+    "debind" => ["debinds", "debound", "debinding", "debound"],
+    "deblur" => ["deblurs", "deblurred", "deblurring", "deblurred"],
+    "debran" => ["debrans", "debranned", "debranning", "debranned"],
+    "debud" => ["debuds", "debudded", "debudding", "debudded"],
+    "debur" => ["deburs", "deburred", "deburring", "deburred"],
+    "debus" => ["debusses", "debussed", "debussing", "debussed"],
+    "decamp" => ["decamps", "decamped", "decamping", "decamped"],
+    "decan" => ["decans", "decanned", "decanning", "decanned"],
+    "decar" => ["decars", "decarred", "decarring", "decarred"],
+    "decay" => ["decays", "decayed", "decaying", "decayed"],
+    "decerp" => ["decerps", "decerped", "decerping", "decerped"],
+    "dechirp" => ["dechirps", "dechirped", "dechirping", "dechirped"],
+    "declamp" => ["declamps", "declamped", "declamping", "declamped"],
+    "declump" => ["declumps", "declumped", "declumping", "declumped"],
+    "decommit" => ["decommits", "decommitted", "decommitting", "decommitted"],
+    "decomp" => ["decomps", "decomped", "decomping", "decomped"],
+    "decon" => ["decons", "deconned", "deconning", "deconned"],
+    "decontrol" => ["decontrols", "decontrolled", "decontrolling", "decontrolled"],
+    "decoy" => ["decoys", "decoyed", "decoying", "decoyed"],
+    "decrab" => ["decrabs", "decrabbed", "decrabbing", "decrabbed"],
+    "decree" => ["decrees", "decreed", "decreeing", "decreed"],
+    "decrud" => ["decruds", "decrudded", "decrudding", "decrudded"],
+    "dee" => ["diz", "dyun", "deein", "dyun"],
+    "deejay" => ["deejays", "deejayed", "deejaying", "deejayed"],
+    "deek" => ["deeks", "deeked", "deekin", "deeked"],
+    "deeky" => ["deekies", "deekied", "deekyin", "deekied"],
+    "deembed" => ["deembeds", "deembedded", "deembedding", "deembedded"],
+    "deep" => ["deeps", "deeped", "deeping", "deeped"],
+    "deepdraw" => ["deepdraws", "deepdrew", "deepdrawing", "deepdrawn"],
+    "defat" => ["defats", "defatted", "defatting", "defatted"],
+    "defed" => ["defeds", "defedded", "defedding", "defedded"],
+    "defer" => ["defers", "deferred", "deferring", "deferred"],
+    "defib" => ["defibs", "defibbed", "defibbing", "defibbed"],
+    "defie" => ["defies", "defied", "defying", "defied"],
+    "defin" => ["defins", "definned", "definning", "definned"],
+    "deformat" => ["deformats", "deformatted", "deformatting", "deformatted"],
+    "defray" => ["defrays", "defrayed", "defraying", "defrayed"],
+    "defreeze" => ["defreezes", "defroze", "defreezing", "defrozen"],
+    "defur" => ["defurs", "defurred", "defurring", "defurred"],
+    "degas" => ["degases", "degassed", "degassing", "degassed"],
+    "degel" => ["degels", "degelled", "degelling", "degelled"],
+    "degod" => ["degods", "degodded", "degodding", "degodded"],
+    "degrit" => ["degrits", "degritted", "degritting", "degritted"],
+    "degroup" => ["degroups", "degrouped", "degrouping", "degrouped"],
+    "degrow" => ["degrows", "degrew", "degrowing", "degrown"],
+    "degum" => ["degums", "degummed", "degumming", "degummed"],
+    "degut" => ["deguts", "degutted", "degutting", "degutted"],
+    "dehub" => ["dehubs", "dehubbed", "dehubbing", "dehubbed"],
+    "dehydrofreeze" => ["dehydrofreeze", "dehydrofreezing", "dehydrofreezes", "dehydrofrozen"],
+    "dekek" => ["dekeks", "dekekked", "dekekking", "dekekked"],
+    "delam" => ["delams", "delammed", "delamming", "delammed"],
+    "delay" => ["delays", "delayed", "delaying", "delayed"],
+    "dele" => ["deles", "deled", "deleing", "deled"],
+    "delid" => ["delids", "delidded", "delidding", "delidded"],
+    "delie" => ["delies", "delied", "delying", "delied"],
+    "demagog" => ["demagogs", "demagoged", "demagoging", "demagoged"],
+    "demake" => ["demakes", "demade", "demaking", "demade"],
+    "deman" => ["demans", "demanned", "demanning", "demanned"],
+    "demat" => ["demats", "dematted", "dematting", "dematted"],
+    "demit" => ["demits", "demitted", "demitting", "demitted"],
+    "demob" => ["demobs", "demobbed", "demobbing", "demobbed"],
+    "demod" => ["demods", "demodded", "demodding", "demodded"],
+    "demosaic" => ["demosaics", "demosaicked", "demosaicking", "demosaicked"],
+    "demur" => ["demurs", "demurred", "demurring", "demurred"],
+    "den" => ["dens", "denned", "denning", "denned"],
+    "denay" => ["denays", "denayed", "denaying", "denayed"],
+    "denet" => ["denets", "denetted", "denetting", "denetted"],
+    "denib" => ["denibs", "denibbed", "denibbing", "denibbed"],
+    "depin" => ["depins", "depinned", "depinning", "depinned"],
+    "deploy" => ["deploys", "deployed", "deploying", "deployed"],
+    "deprogram" => ["deprograms", "deprogrammed", "deprogramming", "deprogrammed"],
+    "deramp" => ["deramps", "deramped", "deramping", "deramped"],
+    "derat" => ["derats", "deratted", "deratting", "deratted"],
+    "deray" => ["derays", "derayed", "deraying", "derayed"],
+    "derp" => ["derps", "derped", "derping", "derped"],
+    "desat" => ["desats", "desatted", "desatting", "desatted"],
+    "descan" => ["descans", "descanned", "descanning", "descanned"],
+    "descum" => ["descums", "descummed", "descumming", "descummed"],
+    "deskin" => ["deskins", "deskinned", "deskinning", "deskinned"],
+    "deslot" => ["deslots", "deslotted", "deslotting", "deslotted"],
+    "despam" => ["despams", "despammed", "despamming", "despammed"],
+    "despeed" => ["despeeds", "desped", "despeeding", "desped"],
+    "despin" => ["despins", "despun", "despinning", "despun"],
+    "despread2" => ["despreads", "despread", "despreading", "despread"],
+    "destat" => ["destats", "destatted", "destatting", "destatted"],
+    "destem" => ["destems", "destemmed", "destemming", "destemmed"],
+    "destim" => ["destims", "destimmed", "destimming", "destimmed"],
+    "destroy" => ["destroys", "destroyed", "destroying", "destroyed"],
+    "desynch" => ["desynchs", "desynched", "desynching", "desynched"],
+    "detelecine" => ["detelecines", "detelecined", "detelecineing", "detelecined"],
+    "deter" => ["deters", "deterred", "deterring", "deterred"],
+    "detin" => ["detins", "detinned", "detinning", "detinned"],
+    "detwin" => ["detwins", "detwinned", "detwinning", "detwinned"],
+    "devan" => ["devans", "devanned", "devanning", "devanned"],
+    "devel" => ["devels", "develled", "develling", "develled"],
+    "develop" => ["develops", "developed", "developing", "developed"],
+    "devvel" => ["devvels", "devvelled", "devvelling", "devvelled"],
+    "dewet" => ["dewets", "dewetted", "dewetting", "dewetted"],
+    "dewret" => ["dewrets", "dewretted", "dewretting", "dewretted"],
+    "diadem" => ["diadems", "diademmed", "diademming", "diademmed"],
+    "diagram" => ["diagrams", "diagrammed", "diagramming", "diagrammed"],
+    "dialog" => ["dialogs", "dialoged", "dialoging", "dialoged"],
+    "diaperhump" => ["diaperhumps", "diaperhumped", "diaperhumping", "diaperhumped"],
+    "dib" => ["dibs", "dibbed", "dibbing", "dibbed"],
+    "dickride" => ["dickrides", "dickrode", "dickriding", "dickridden"],
+    "die" => ["dies", "died", "dying", "died"],
+    "diecast" => ["diecasts", "diecast", "diecasting", "diecast"],
+    "dig" => ["digs", "dug", "digging", "dug"],
+    "dight" => ["dights", "dight", "dighting", "dight"],
+    "digibin" => ["digibins", "digibinned", "digibinning", "digibinned"],
+    "dildo" => ["dildoes", "dildoed", "dildoing", "dildoed"],
+    "dim" => ["dims", "dimmed", "dimming", "dimmed"],
+    "dimit" => ["dimits", "dimitted", "dimitting", "dimitted"],
+    "din" => ["dins", "dinned", "dinning", "dinned"],
+    "dinge" => ["dinges", "dinged", "dingeing", "dinged"],
+    "dingo" => ["dingoes", "dingoed", "dingoing", "dingoed"],
+    "dingolay" => ["dingolays", "dingolayed", "dingolaying", "dingolayed"],
+    "dipnet" => ["dipnets", "dipnetted", "dipnetting", "dipnetted"],
+    "dis" => ["disses", "dissed", "dissing", "dissed"],
+    "disagree" => ["disagrees", "disagreed", "disagreeing", "disagreed"],
+    "disannul" => ["disannuls", "disannulled", "disannulling", "disannulled"],
+    "disarray" => ["disarrays", "disarrayed", "disarraying", "disarrayed"],
+    "disbar" => ["disbars", "disbarred", "disbarring", "disbarred"],
+    "disbecome" => ["disbecomes", "disbecame", "disbecoming", "disbecome"],
+    "disbend" => ["disbends", "disbent", "disbending", "disbent"],
+    "disbind" => ["disbinds", "disbound", "disbinding", "disbound"],
+    "disbud" => ["disbuds", "disbudded", "disbudding", "disbudded"],
+    "discamp" => ["discamps", "discamped", "discamping", "discamped"],
+    "discerp" => ["discerps", "discerped", "discerping", "discerped"],
+    "disconcur" => ["disconcurs", "disconcurred", "disconcurring", "disconcurred"],
+    "disculp" => ["disculps", "disculped", "disculping", "disculped"],
+    "disembed" => ["disembeds", "disembedded", "disembedding", "disembedded"],
+    "disemploy" => ["disemploys", "disemployed", "disemploying", "disemployed"],
+    "disenjoy" => ["disenjoys", "disenjoyed", "disenjoying", "disenjoyed"],
+    "disenrol" => ["disenrols", "disenrolled", "disenrolling", "disenrolled"],
+    "disenter" => ["disenters", "disenterred", "disenterring", "disenterred"],
+    "disenthral" => ["disenthrals", "disenthralled", "disenthralling", "disenthralled"],
+    "disenvelop" => ["disenvelops", "disenveloped", "disenveloping", "disenveloped"],
+    "disinter" => ["disinters", "disinterred", "disinterring", "disinterred"],
+    "disinthral" => ["disinthrals", "disinthralled", "disinthralling", "disinthralled"],
+    "disman" => ["dismans", "dismanned", "dismanning", "dismanned"],
+    "dismay" => ["dismays", "dismayed", "dismaying", "dismayed"],
+    "disobey" => ["disobeys", "disobeyed", "disobeying", "disobeyed"],
+    "dispel" => ["dispels", "dispelled", "dispelling", "dispelled"],
+    "display" => ["displays", "displayed", "displaying", "displayed"],
+    "disprefer" => ["disprefers", "dispreferred", "dispreferring", "dispreferred"],
+    "dispurvey" => ["dispurveys", "dispurveyed", "dispurveying", "dispurveyed"],
+    "disray" => ["disrays", "disrayed", "disraying", "disrayed"],
+    "dister" => ["disters", "disterred", "disterring", "disterred"],
+    "distil" => ["distils", "distilled", "distilling", "distilled"],
+    "disvelop" => ["disvelops", "disveloped", "disveloping", "disveloped"],
+    "disway" => ["disways", "diswayed", "diswaying", "diswayed"],
+    "disworship" => ["disworships", "disworshiped", "disworshiping", "disworshiped"],
+    "dit" => ["dits", "ditted", "ditting", "ditted"],
+    "divast" => ["divasts", "divast", "divasting", "divast"],
+    "divel" => ["divels", "divelled", "divelling", "divelled"],
+    "diy" => ["diys", "diyed", "diying", "diyed"],
+    "diz" => ["dizzes", "dizzed", "dizzing", "dizzed"],
+    "do" => ["does", "did", "doing", "done"],
+    "dob" => ["dobs", "dobbed", "dobbing", "dobbed"],
+    "dod" => ["dods", "dodded", "dodding", "dodded"],
+    "doe" => ["does", "did", "doing", "done"],
+    "doge" => ["doges", "doged", "dogeing", "doged"],
+    "dogsit" => ["dogsits", "dogsat", "dogsitting", "dogsat"],
+    "dogsled" => ["dogsleds", "dogsledded", "dogsledding", "dogsledded"],
+    "dogtrot" => ["dogtrots", "dogtrotted", "dogtrotting", "dogtrotted"],
+    "dollop" => ["dollops", "dolloped", "dolloping", "dolloped"],
+    "dom" => ["doms", "dommed", "domming", "dommed"],
+    "domino" => ["dominoes", "dominoed", "dominoing", "dominoed"],
+    "don" => ["dons", "donned", "donning", "donned"],
+    "doodie" => ["doodies", "doodied", "doodying", "doodied"],
+    "doomsay" => ["doomsays", "doomsaid", "doomsaying", "doomsaid"],
+    "doorknob" => ["doorknobs", "doorknobbed", "doorknobbing", "doorknobbed"],
+    "dorcas" => ["dorcasses", "dorcassed", "dorcassing", "dorcassed"],
+    "dot" => ["dots", "dotted", "dotting", "dotted"],
+    "dotcom" => ["dotcoms", "dotcommed", "dotcomming", "dotcommed"],
+    "doublethink" => ["doublethinks", "doublethought", "doublethinking", "doublethought"],
+    "doughnut" => ["doughnuts", "doughnutted", "doughnutting", "doughnutted"],
+    "dougie" => ["dougies", "dougied", "dougying", "dougied"],
+    "downarg" => ["downargs", "downarged", "downarging", "downarged"],
+    "downbear" => ["downbears", "downbore", "downbearing", "downborne"],
+    "downbet" => ["downbets", "downbet", "downbetting", "downbet"],
+    "downcast" => ["downcasts", "downcast", "downcasting", "downcast"],
+    "downcome" => ["downcomes", "downcame", "downcoming", "downcome"],
+    "downcut" => ["downcuts", "downcut", "downcutting", "downcut"],
+    "downdraw" => ["downdraws", "downdrew", "downdrawing", "downdrawn"],
+    "downfall" => ["downfalls", "downfell", "downfalling", "downfallen"],
+    "downplay" => ["downplays", "downplayed", "downplaying", "downplayed"],
+    "downspin" => ["downspins", "downspun", "downspinning", "downspun"],
+    "downstrike" => ["downstrikes", "downstruck", "downstriking", "downstricken"],
+    "downstroy" => ["downstroys", "downstroyed", "downstroying", "downstroyed"],
+    "downsweep" => ["downsweeps", "downswept", "downsweeping", "downswept"],
+    "downtread" => ["downtreads", "downtrod", "downtreading", "downtrodden"],
+    "downtrod" => ["downtrods", "downtrod", "downtrodding", "downtrodden"],
+    "dp" => ["dps", "dped", "dping", "dped"],
+    "drab" => ["drabs", "drabbed", "drabbing", "drabbed"],
+    "dragnet" => ["dragnets", "dragnetted", "dragnetting", "dragnetted"],
+    "dram" => ["drams", "drammed", "dramming", "drammed"],
+    "drat" => ["drats", "dratted", "dratting", "dratted"],
+    "draw" => ["draws", "drew", "drawing", "drawn"],
+    "drawe" => ["drawes", "drewe", "drawing", "drawen"],
+    "dray" => ["drays", "drayed", "draying", "drayed"],
+    "dreap" => ["dreaps", "dreaped", "dreaping", "dreaped"],
+    "dreave" => ["dreaves", "dreft", "dreaving", "dreft"],
+    "dree" => ["drees", "dreed", "dreeing", "dreed"],
+    "dreep" => ["dreeps", "dreeped", "dreeping", "dreeped"],
+    "dressmake" => ["dressmakes", "dressmade", "dressmaking", "dressmade"],
+    "drib" => ["dribs", "dribbed", "dribbing", "dribbed"],
+    "driftnet" => ["driftnets", "driftnetted", "driftnetting", "driftnetted"],
+    "drinck" => ["drincks", "dranck", "drincking", "drunck"],
+    "drink" => ["drinks", "drank", "drinking", "drunk"],
+    "drite" => ["drites", "drote", "driting", "dritten"],
+    "driue" => ["driues", "droue", "driuing", "driuen"],
+    "drive" => ["drives", "drove", "driving", "driven"],
+    "droop" => ["droops", "drooped", "drooping", "drooped"],
+    "dropcast" => ["dropcasts", "dropcast", "dropcasting", "dropcast"],
+    "dropset" => ["dropsets", "dropsetted", "dropsetting", "dropsetted"],
+    "drub" => ["drubs", "drubbed", "drubbing", "drubbed"],
+    "drum" => ["drums", "drummed", "drumming", "drummed"],
+    "dry2" => ["drys", "dryed", "drying", "dryed"],
+    "dub" => ["dubs", "dubbed", "dubbing", "dubbed"],
+    "dude" => ["dudes", "duded", "dudeing", "duded"],
+    "duet" => ["duets", "duetted", "duetting", "duetted"],
+    "dump" => ["dumps", "dumped", "dumping", "dumped"],
+    "dun" => ["duns", "dunned", "dunning", "dunned"],
+    "dunsh" => ["dunshes", "dunshed", "dunshin", "dunshed"],
+    "dwell" => ["dwells", "dwelt", "dwelling", "dwelt"],
+    "dx" => ["dxs", "dxed", "dxing", "dxed"],
+    "dyb" => ["dybs", "dybbed", "dybbing", "dybbed"],
+    "dye" => ["dyes", "dyed", "dyeing", "dyed"],
+    "eat" => ["eats", "ate", "eating", "eaten"],
+    "eate" => ["eates", "ate", "eating", "eaten"],
+    "eavesread" => ["eavesreads", "eavesread", "eavesreading", "eavesread"],
+    "ebay" => ["ebays", "ebayed", "ebaying", "ebayed"],
+    "echo" => ["echoes", "echoed", "echoing", "echoed"],
+    "eep" => ["eeps", "eeped", "eeping", "eeped"],
+    "effray" => ["effrays", "effrayed", "effraying", "effrayed"],
+    "egg" => ["eggs", "egged", "egging", "egged"],
+    "egocast" => ["egocasts", "egocast", "egocasting", "egocast"],
+    "egoscan" => ["egoscans", "egoscanned", "egoscanning", "egoscanned"],
+    "electroblot" => ["electroblots", "electroblotted", "electroblotting", "electroblotted"],
+    "electrospray" => ["electrosprays", "electrosprayed", "electrospraying", "electrosprayed"],
+    "electrostun" => ["electrostuns", "electrostunned", "electrostunning", "electrostunned"],
+    "electrotin" => ["electrotins", "electrotinned", "electrotinning", "electrotinned"],
+    "electrotransfer" => ["electrotransfers", "electrotransferred", "electrotransferring", "electrotransferred"],
+    "electrowin" => ["electrowins", "electrowon", "electrowinning", "electrowon"],
+    "embar" => ["embars", "embarred", "embarring", "embarred"],
+    "embargo" => ["embargoes", "embargoed", "embargoing", "embargoed"],
+    "embay" => ["embays", "embayed", "embaying", "embayed"],
+    "embed" => ["embeds", "embedded", "embedding", "embedded"],
+    "embus" => ["embusses", "embussed", "embussing", "embussed"],
+    "emcee" => ["emcees", "emceed", "emceeing", "emceed"],
+    "emit" => ["emits", "emitted", "emitting", "emitted"],
+    "emplot" => ["emplots", "emplotted", "emplotting", "emplotted"],
+    "employ" => ["employs", "employed", "employing", "employed"],
+    "encamp" => ["encamps", "encamped", "encamping", "encamped"],
+    "enclasp" => ["enclasps", "enclasped", "enclasping", "enclasped"],
+    "encoop" => ["encoops", "encooped", "encooping", "encooped"],
+    "encur" => ["encurs", "encurred", "encurring", "encurred"],
+    "endplay" => ["endplays", "endplayed", "endplaying", "endplayed"],
+    "endree" => ["endrees", "endreed", "endreeing", "endreed"],
+    "enfree" => ["enfrees", "enfreed", "enfreeing", "enfreed"],
+    "enfreeze" => ["enfreezes", "enfroze", "enfreezing", "enfrozen"],
+    "englut" => ["engluts", "englutted", "englutting", "englutted"],
+    "engrasp" => ["engrasps", "engrasped", "engrasping", "engrasped"],
+    "enhoney" => ["enhoneys", "enhoneyed", "enhoneying", "enhoneyed"],
+    "enioy" => ["enioys", "enioyed", "enioying", "enioyed"],
+    "enjoy" => ["enjoys", "enjoyed", "enjoying", "enjoyed"],
+    "enknit" => ["enknits", "enknitted", "enknitting", "enknitted"],
+    "enlay" => ["enlays", "enlaid", "enlaying", "enlaid"],
+    "ennui" => ["ennuis", "ennuied", "ennuying", "ennuied"],
+    "enrol" => ["enrols", "enrolled", "enrolling", "enrolled"],
+    "enskin" => ["enskins", "enskinned", "enskinning", "enskinned"],
+    "enstamp" => ["enstamps", "enstamped", "enstamping", "enstamped"],
+    "enstar" => ["enstars", "enstarred", "enstarring", "enstarred"],
+    "ensteep" => ["ensteeps", "ensteeped", "ensteeping", "ensteeped"],
+    "ensweep" => ["ensweeps", "enswept", "ensweeping", "enswept"],
+    "entertake" => ["entertakes", "entertook", "entertaking", "entertaken"],
+    "enthral" => ["enthrals", "enthralled", "enthralling", "enthralled"],
+    "enturban" => ["enturbans", "enturbanned", "enturbanning", "enturbanned"],
+    "entwin" => ["entwins", "entwinned", "entwinning", "entwinned"],
+    "envelop" => ["envelops", "enveloped", "enveloping", "enveloped"],
+    "envie" => ["envies", "envied", "envying", "envied"],
+    "enweave" => ["enweaves", "enwove", "enweaving", "enwoven"],
+    "enwind" => ["enwinds", "enwound", "enwinding", "enwound"],
+    "enwrite" => ["enwrites", "enwrote", "enwriting", "enwritten"],
+    "epoch" => ["epochs", "epoched", "epoching", "epoched"],
+    "er" => ["ers", "erred", "erring", "erred"],
+    "erg" => ["ergs", "erged", "erging", "erged"],
+    "erp" => ["erps", "erped", "erping", "erped"],
+    "escallop" => ["escallops", "escalloped", "escalloping", "escalloped"],
+    "escalop" => ["escalops", "escaloped", "escaloping", "escaloped"],
+    "escarp" => ["escarps", "escarped", "escarping", "escarped"],
+    "escortcel" => ["escortcels", "escortcelled", "escortcelling", "escortcelled"],
+    "essay" => ["essays", "essayed", "essaying", "essayed"],
+    "estray" => ["estrays", "estrayed", "estraying", "estrayed"],
+    "eunuch" => ["eunuchs", "eunuched", "eunuching", "eunuched"],
+    "ew" => ["ews", "ewwed", "ewwing", "ewwed"],
+    "excambie" => ["excambies", "excambied", "excambying", "excambied"],
+    "excel" => ["excels", "excelled", "excelling", "excelled"],
+    "excerp" => ["excerps", "excerped", "excerping", "excerped"],
+    "excur" => ["excurs", "excurred", "excurring", "excurred"],
+    "exemplifie" => ["exemplifies", "exemplified", "exemplifying", "exemplified"],
+    "exeunt" => ["exit", "exeunted", "exeunting", "exeunted"],
+    "expel" => ["expels", "expelled", "expelling", "expelled"],
+    "extirp" => ["extirps", "extirped", "extirping", "extirped"],
+    "extol" => ["extols", "extolled", "extolling", "extolled"],
+    "eye" => ["eyes", "eyed", "eyeing", "eyed"],
+    "eyelet" => ["eyelets", "eyeletted", "eyeletting", "eyeletted"],
+    "fab" => ["fabs", "fabbed", "fabbing", "fabbed"],
+    "fabric" => ["fabrics", "fabricked", "fabricking", "fabricked"],
+    "facesit" => ["facesits", "facesat", "facesitting", "facesat"],
+    "facsimile" => ["facsimiles", "facsimiled", "facsimileing", "facsimiled"],
+    "fakie" => ["fakies", "fakied", "fakieing", "fakied"],
+    "fall" => ["falls", "fell", "falling", "fallen"],
+    "fan" => ["fans", "fanned", "fanning", "fanned"],
+    "fanboy" => ["fanboys", "fanboyed", "fanboying", "fanboyed"],
+    "fancast" => ["fancasts", "fancast", "fancasting", "fancast"],
+    "fandub" => ["fandubs", "fandubbed", "fandubbing", "fandubbed"],
+    "fanfic" => ["fanfics", "fanficced", "fanficcing", "fanficced"],
+    "fansie" => ["fansies", "fansied", "fansying", "fansied"],
+    "fansub" => ["fansubs", "fansubbed", "fansubbing", "fansubbed"],
+    "far" => ["fars", "farred", "farring", "farred"],
+    "farsee" => ["farsees", "farsaw", "farseeing", "farseen"],
+    "farspeak" => ["farspeaks", "farspoke", "farspeaking", "farspoken"],
+    "fasel" => ["fasels", "faselled", "faselling", "faselled"],
+    "fat" => ["fats", "fatted", "fatting", "fatted"],
+    "faultfind" => ["faultfinds", "faultfound", "faultfinding", "faultfound"],
+    "fay" => ["fays", "fayed", "faying", "fayed"],
+    "feal2" => ["feals", "fale", "fealing", "folen"],
+    "featherbed" => ["featherbeds", "featherbedded", "featherbedding", "featherbedded"],
+    "fed" => ["feds", "fedded", "fedding", "fedded"],
+    "fee" => ["fees", "feed", "feeing", "feed"],
+    "feed" => ["feeds", "fed", "feeding", "fed"],
+    "feel" => ["feels", "felt", "feeling", "felt"],
+    "feeze2" => ["feezes", "foze", "feezing", "fozen"],
+    "fellowfeel" => ["fellowfeels", "fellowfelt", "fellowfeeling", "fellowfelt"],
+    "fet" => ["fets", "fet", "fetting", "fet"],
+    "fey" => ["feys", "feyed", "feying", "feyed"],
+    "fib" => ["fibs", "fibbed", "fibbing", "fibbed"],
+    "fid" => ["fids", "fidded", "fidding", "fidded"],
+    "filagree" => ["filagrees", "filagreed", "filagreeing", "filagreed"],
+    "filigree" => ["filigrees", "filigreed", "filigreeing", "filigreed"],
+    "fillip" => ["fillips", "filliped", "filliping", "filliped"],
+    "filmset" => ["filmsets", "filmset", "filmsetting", "filmset"],
+    "fin" => ["fins", "finned", "finning", "finned"],
+    "find" => ["finds", "found", "finding", "found"],
+    "finedraw" => ["finedraws", "finedrew", "finedrawing", "finedrawn"],
+    "fink2" => ["finks", "fought", "finking", "fought"],
+    "fisheye" => ["fisheyes", "fisheyed", "fisheyeing", "fisheyed"],
+    "fistbump" => ["fistbumps", "fistbumped", "fistbumping", "fistbumped"],
+    "fit" => ["fits", "fitted", "fitting", "fitted"],
+    "fix" => ["fixes", "fixt", "fixing", "fixt"],
+    "fiz" => ["fizzes", "fizzed", "fizzing", "fizzed"],
+    "flam" => ["flams", "flammed", "flamming", "flammed"],
+    "flambe" => ["flambes", "flambeed", "flambeing", "flambeed"],
+    "flambee" => ["flambees", "flambeed", "flambeeing", "flambeed"],
+    "flan" => ["flans", "flanned", "flanning", "flanned"],
+    "flashlight" => ["flashlights", "flashlit", "flashlighting", "flashlit"],
+    "flat" => ["flats", "flatted", "flatting", "flatted"],
+    "flatbed" => ["flatbeds", "flatbedded", "flatbedding", "flatbedded"],
+    "flatplan" => ["flatplans", "flatplanned", "flatplanning", "flatplanned"],
+    "flay" => ["flays", "flayed", "flaying", "flayed"],
+    "flea2" => ["fleas", "flead", "fleaing", "flead"],
+    "flee" => ["flees", "fled", "fleeing", "fled"],
+    "fley" => ["fleys", "fleyed", "fleying", "fleyed"],
+    "flimflam" => ["flimflams", "flimflammed", "flimflamming", "flimflammed"],
+    "flimp" => ["flimps", "flimped", "flimping", "flimped"],
+    "fling" => ["flings", "flung", "flinging", "flung"],
+    "flit" => ["flits", "flitted", "flitting", "flitted"],
+    "flob" => ["flobs", "flobbed", "flobbing", "flobbed"],
+    "floc" => ["flocs", "flocced", "floccing", "flocced"],
+    "floodlight" => ["floodlights", "floodlit", "floodlighting", "floodlit"],
+    "floop" => ["floops", "flooped", "flooping", "flooped"],
+    "flub" => ["flubs", "flubbed", "flubbing", "flubbed"],
+    "flump" => ["flumps", "flumped", "flumping", "flumped"],
+    "fly2" => ["flies", "flew", "flying", "flown"],
+    "flyblow" => ["flyblows", "flyblew", "flyblowing", "flyblown"],
+    "flycatch" => ["flycatches", "flycaught", "flycatching", "flycaught"],
+    "fob" => ["fobs", "fobbed", "fobbing", "fobbed"],
+    "foind" => ["foinds", "found", "foinding", "found"],
+    "foodstagram" => ["foodstagrams", "foodstagrammed", "foodstagramming", "foodstagrammed"],
+    "footgun" => ["footguns", "footgunned", "footgunning", "footgunned"],
+    "footpad" => ["footpads", "footpadded", "footpadding", "footpadded"],
+    "foray" => ["forays", "forayed", "foraying", "forayed"],
+    "forban" => ["forbans", "forbanned", "forbanning", "forbanned"],
+    "forbar" => ["forbars", "forbarred", "forbarring", "forbarred"],
+    "forbear" => ["forbears", "forbore", "forbearing", "forborne"],
+    "forbeat" => ["forbeats", "forbeat", "forbeating", "forbeaten"],
+    "forbid" => ["forbids", "forbade", "forbidding", "forbidden"],
+    "forbite" => ["forbites", "forbit", "forbiting", "forbitten"],
+    "forbleed" => ["forbleeds", "forbled", "forbleeding", "forbled"],
+    "forbreak" => ["forbreaks", "forbroke", "forbreaking", "forbroken"],
+    "forburst" => ["forbursts", "forburst", "forbursting", "forburst"],
+    "forcarve" => ["forcarves", "forcorve", "forcarving", "forcorven"],
+    "forcast" => ["forcasts", "forcast", "forcasting", "forcast"],
+    "forcefeed" => ["forcefeeds", "forcefed", "forcefeeding", "forcefed"],
+    "forcleave" => ["forcleaves", "forclove", "forcleaving", "forcloven"],
+    "forcut" => ["forcuts", "forcut", "forcutting", "forcut"],
+    "fordim" => ["fordims", "fordimmed", "fordimming", "fordimmed"],
+    "fordo" => ["fordoes", "fordid", "fordoing", "fordone"],
+    "fordrive" => ["fordrives", "fordrove", "fordriving", "fordriven"],
+    "forebar" => ["forebars", "forebarred", "forebarring", "forebarred"],
+    "forebear" => ["forebears", "forebore", "forebearing", "foreborne"],
+    "forebite" => ["forebites", "forebit", "forebiting", "forebitten"],
+    "forebring" => ["forebrings", "forebrought", "forebringing", "forebrought"],
+    "forecaddie" => ["forecaddies", "forecaddied", "forecaddying", "forecaddied"],
+    "forecast" => ["forecasts", "forecast", "forecasting", "forecast"],
+    "forecatch" => ["forecatches", "forecaught", "forecatching", "forecaught"],
+    "forechoose" => ["forechooses", "forechose", "forechoosing", "forechosen"],
+    "forecome" => ["forecomes", "forecame", "forecoming", "forecome"],
+    "forecut" => ["forecuts", "forecut", "forecutting", "forecut"],
+    "foredecree" => ["foredecrees", "foredecreed", "foredecreeing", "foredecreed"],
+    "foredo" => ["foredoes", "foredid", "foredoing", "foredone"],
+    "foredraw" => ["foredraws", "foredrew", "foredrawing", "foredrawn"],
+    "forefeel" => ["forefeels", "forefelt", "forefeeling", "forefelt"],
+    "foregive" => ["foregives", "foregave", "foregiving", "foregiven"],
+    "forego" => ["foregoes", "forewent", "foregoing", "foregone"],
+    "foregrasp" => ["foregrasps", "foregrasped", "foregrasping", "foregrasped"],
+    "forehear" => ["forehears", "foreheard", "forehearing", "foreheard"],
+    "forehew" => ["forehews", "forehewed", "forehewing", "forehewn"],
+    "forehold" => ["foreholds", "foreheld", "foreholding", "foreheld"],
+    "foreken" => ["forekens", "forekenned", "forekenning", "forekenned"],
+    "foreknow" => ["foreknows", "foreknew", "foreknowing", "foreknown"],
+    "forelay" => ["forelays", "forelaid", "forelaying", "forelaid"],
+    "forelead" => ["foreleads", "foreled", "foreleading", "foreled"],
+    "forelearn" => ["forelearns", "forelearnt", "forelearning", "forelearnt"],
+    "forelend" => ["forelends", "forelent", "forelending", "forelent"],
+    "forelet" => ["forelets", "forelet", "foreletting", "forelet"],
+    "forelie" => ["forelies", "forelay", "forelying", "forelain"],
+    "foremake" => ["foremakes", "foremade", "foremaking", "foremade"],
+    "forepay" => ["forepays", "forepaid", "forepaying", "forepaid"],
+    "foreplan" => ["foreplans", "foreplanned", "foreplanning", "foreplanned"],
+    "foreplay" => ["foreplays", "foreplayed", "foreplaying", "foreplayed"],
+    "foreread" => ["forereads", "foreread", "forereading", "foreread"],
+    "forerun" => ["foreruns", "foreran", "forerunning", "forerun"],
+    "foresay" => ["foresays", "foresaid", "foresaying", "foresaid"],
+    "forescan" => ["forescans", "forescanned", "forescanning", "forescanned"],
+    "foresee" => ["foresees", "foresaw", "foreseeing", "foreseen"],
+    "foreseek" => ["foreseeks", "foresought", "foreseeking", "foresought"],
+    "foresend" => ["foresends", "foresent", "foresending", "foresent"],
+    "foreset" => ["foresets", "foreset", "foresetting", "foreset"],
+    "foreshew" => ["foreshews", "foreshewed", "foreshewing", "foreshewn"],
+    "foreshine" => ["foreshines", "foreshone", "foreshining", "foreshone"],
+    "foreshoot" => ["foreshoots", "foreshot", "foreshooting", "foreshot"],
+    "foreshow" => ["foreshows", "foreshowed", "foreshowing", "foreshown"],
+    "foresing" => ["foresings", "foresang", "foresinging", "foresung"],
+    "foresit" => ["foresits", "foresat", "foresitting", "foresat"],
+    "foreskin" => ["foreskins", "foreskinned", "foreskinning", "foreskinned"],
+    "foreslay" => ["foreslays", "foreslew", "foreslaying", "foreslain"],
+    "foresmell" => ["foresmells", "foresmelt", "foresmelling", "foresmelt"],
+    "forespeak" => ["forespeaks", "forespoke", "forespeaking", "forespoken"],
+    "forespeed" => ["forespeeds", "foresped", "forespeeding", "foresped"],
+    "forespend" => ["forespends", "forespent", "forespending", "forespent"],
+    "forespread" => ["forespreads", "forespread", "forespreading", "forespread"],
+    "forestal" => ["forestals", "forestalled", "forestalling", "forestalled"],
+    "forestand" => ["forestands", "forestood", "forestanding", "forestood"],
+    "forestay" => ["forestays", "forestayed", "forestaying", "forestayed"],
+    "foreswear" => ["foreswears", "foreswore", "foreswearing", "foresworn"],
+    "foretake" => ["foretakes", "foretook", "foretaking", "foretaken"],
+    "foreteach" => ["foreteaches", "foretaught", "foreteaching", "foretaught"],
+    "foretel" => ["foretels", "foretold", "foretelling", "foretold"],
+    "foretell" => ["foretells", "foretold", "foretelling", "foretold"],
+    "forethink" => ["forethinks", "forethought", "forethinking", "forethought"],
+    "foreweep" => ["foreweeps", "forewept", "foreweeping", "forewept"],
+    "forewit" => ["forewot", "forewist", "forewitting", "forewist"],
+    "forewrite" => ["forewrites", "forewrote", "forewriting", "forewritten"],
+    "forgab" => ["forgabs", "forgabbed", "forgabbing", "forgabbed"],
+    "forget" => ["forgets", "forgot", "forgetting", "forgotten"],
+    "forgive" => ["forgives", "forgave", "forgiving", "forgiven"],
+    "forgo" => ["forgoes", "forwent", "forgoing", "forgone"],
+    "forgrow" => ["forgrows", "forgrew", "forgrowing", "forgrown"],
+    "forhele" => ["forheles", "forhole", "forheling", "forholen"],
+    "forhold" => ["forholds", "forheld", "forholding", "forheld"],
+    "forlat" => ["forlats", "forleet", "forlatting", "forlatten"],
+    "forlay" => ["forlays", "forlaid", "forlaying", "forlaid"],
+    "forlead" => ["forleads", "forled", "forleading", "forled"],
+    "forleave" => ["forleaves", "forleft", "forleaving", "forleft"],
+    "forleet" => ["forleets", "forleet", "forleeting", "forleet"],
+    "forlend" => ["forlends", "forlent", "forlending", "forlent"],
+    "forlese" => ["forleses", "forlore", "forlesing", "forlorn"],
+    "forlet" => ["forlets", "forlet", "forletting", "forlet"],
+    "forlie" => ["forlies", "forlay", "forlying", "forlain"],
+    "format" => ["formats", "formatted", "formatting", "formatted"],
+    "formay" => ["formays", "formayed", "formaying", "formayed"],
+    "forncast" => ["forncasts", "forncast", "forncasting", "forncast"],
+    "forray" => ["forrays", "forrayed", "forraying", "forrayed"],
+    "forsake" => ["forsakes", "forsook", "forsaking", "forsaken"],
+    "forsay" => ["forsays", "forsaid", "forsaying", "forsaid"],
+    "forsee" => ["forsees", "forsaw", "forseeing", "forseen"],
+    "forseek" => ["forseeks", "forsought", "forseeking", "forsought"],
+    "forsend" => ["forsends", "forsent", "forsending", "forsent"],
+    "forset" => ["forsets", "forset", "forsetting", "forset"],
+    "forshake" => ["forshakes", "forshook", "forshaking", "forshaken"],
+    "forshut" => ["forshuts", "forshut", "forshutting", "forshut"],
+    "forsing" => ["forsings", "forsang", "forsinging", "forsung"],
+    "forsleep" => ["forsleeps", "forslept", "forsleeping", "forslept"],
+    "forsling" => ["forslings", "forslung", "forslinging", "forslung"],
+    "forsmite" => ["forsmites", "forsmote", "forsmiting", "forsmitten"],
+    "forspan" => ["forspans", "forspanned", "forspanning", "forspanned"],
+    "forspeak" => ["forspeaks", "forspoke", "forspeaking", "forspoken"],
+    "forspend" => ["forspends", "forspent", "forspending", "forspent"],
+    "forspread" => ["forspreads", "forspread", "forspreading", "forspread"],
+    "forstand" => ["forstands", "forstood", "forstanding", "forstood"],
+    "forsteal" => ["forsteals", "forstole", "forstealing", "forstolen"],
+    "forswart" => ["forswarts", "forswart", "forswarting", "forswart"],
+    "forswear" => ["forswears", "forswore", "forswearing", "forsworn"],
+    "forswing" => ["forswings", "forswang", "forswinging", "forswungen"],
+    "forswink" => ["forswinks", "forswank", "forswinking", "forswunken"],
+    "fortake" => ["fortakes", "fortook", "fortaking", "fortaken"],
+    "forteach" => ["forteaches", "fortaught", "forteaching", "fortaught"],
+    "fortear" => ["fortears", "fortore", "fortearing", "fortorn"],
+    "forthbear" => ["forthbears", "forthbore", "forthbearing", "forthborne"],
+    "forthbring" => ["forthbrings", "forthbrought", "forthbringing", "forthbrought"],
+    "forthcast" => ["forthcasts", "forthcast", "forthcasting", "forthcast"],
+    "forthcome" => ["forthcomes", "forthcame", "forthcoming", "forthcome"],
+    "forthcut" => ["forthcuts", "forthcut", "forthcutting", "forthcut"],
+    "forthdraw" => ["forthdraws", "forthdrew", "forthdrawing", "forthdrawn"],
+    "forthgive" => ["forthgives", "forthgave", "forthgiving", "forthgiven"],
+    "forthgo" => ["forthgoes", "forthwent", "forthgoing", "forthgone"],
+    "forthink" => ["forthinks", "forthought", "forthinking", "forthought"],
+    "forthlead" => ["forthleads", "forthled", "forthleading", "forthled"],
+    "forthleap" => ["forthleaps", "forthleapt", "forthleaping", "forthleapt"],
+    "forthput" => ["forthputs", "forthput", "forthputting", "forthput"],
+    "forthrow" => ["forthrows", "forthrew", "forthrowing", "forthrown"],
+    "forthsend" => ["forthsends", "forthsent", "forthsending", "forthsent"],
+    "forthset" => ["forthsets", "forthset", "forthsetting", "forthset"],
+    "forthshow" => ["forthshows", "forthshowed", "forthshowing", "forthshown"],
+    "forthspeak" => ["forthspeaks", "forthspoke", "forthspeaking", "forthspoken"],
+    "forthtell" => ["forthtells", "forthtold", "forthtelling", "forthtold"],
+    "fortread" => ["fortreads", "fortrod", "fortreading", "fortrodden"],
+    "forway" => ["forways", "forwayed", "forwaying", "forwayed"],
+    "forwear" => ["forwears", "forwore", "forwearing", "forworn"],
+    "forweep" => ["forweeps", "forwept", "forweeping", "forwept"],
+    "forworship" => ["forworships", "forworshiped", "forworshiping", "forworshiped"],
+    "foxtrot" => ["foxtrots", "foxtrotted", "foxtrotting", "foxtrotted"],
+    "frab" => ["frabs", "frabbed", "frabbing", "frabbed"],
+    "frac" => ["fracs", "fracced", "fraccing", "fracced"],
+    "frak" => ["fraks", "frakked", "frakking", "frakked"],
+    "fraught" => ["fraughts", "fraught", "fraughting", "fraught"],
+    "fray" => ["frays", "frayed", "fraying", "frayed"],
+    "free" => ["frees", "freed", "freeing", "freed"],
+    "freefall" => ["freefalls", "freefell", "freefalling", "freefallen"],
+    "freefeed" => ["freefeeds", "freefed", "freefeeding", "freefed"],
+    "freep" => ["freeps", "freeped", "freeping", "freeped"],
+    "freeride" => ["freerides", "freerode", "freeriding", "freeridden"],
+    "freerun" => ["freeruns", "freeran", "freerunning", "freerun"],
+    "freewrite" => ["freewrites", "freewrote", "freewriting", "freewritten"],
+    "freeze" => ["freezes", "froze", "freezing", "frozen"],
+    "fresco" => ["frescoes", "frescoed", "frescoing", "frescoed"],
+    "fret" => ["frets", "fretted", "fretting", "fretted"],
+    "fricassee" => ["fricassees", "fricasseed", "fricasseeing", "fricasseed"],
+    "frisbee" => ["frisbees", "frisbeed", "frisbeeing", "frisbeed"],
+    "frit" => ["frits", "fritted", "fritting", "fritted"],
+    "friz" => ["frizzes", "frizzed", "frizzing", "frizzed"],
+    "frob" => ["frobs", "frobbed", "frobbing", "frobbed"],
+    "frogg" => ["froggs", "frogged", "frogging", "frogged"],
+    "frogtie" => ["frogties", "frogtied", "frogtying", "frogtied"],
+    "frolic" => ["frolics", "frolicked", "frolicking", "frolicked"],
+    "frontrun" => ["frontruns", "frontran", "frontrunning", "frontrun"],
+    "frostbite" => ["frostbites", "frostbit", "frostbiting", "frostbitten"],
+    "frot" => ["frots", "frotted", "frotting", "frotted"],
+    "frump" => ["frumps", "frumped", "frumping", "frumped"],
+    "ftp" => ["ftps", "ftped", "ftping", "ftped"],
+    "fub" => ["fubs", "fubbed", "fubbing", "fubbed"],
+    "fugg" => ["fuggs", "fugged", "fugging", "fugged"],
+    "fulfil" => ["fulfils", "fulfilled", "fulfilling", "fulfilled"],
+    "fullbring" => ["fullbrings", "fullbrought", "fullbringing", "fullbrought"],
+    "fulldo" => ["fulldoes", "fulldid", "fulldoing", "fulldone"],
+    "fullfeed" => ["fullfeeds", "fullfed", "fullfeeding", "fullfed"],
+    "fum" => ["fums", "fummed", "fumming", "fummed"],
+    "fun" => ["funs", "funned", "funning", "funned"],
+    "fuq" => ["fuqs", "fuqqed", "fuqqing", "fuqqed"],
+    "fur" => ["furs", "furred", "furring", "furred"],
+    "fusspot" => ["fusspots", "fusspotted", "fusspotting", "fusspotted"],
+    "gab" => ["gabs", "gabbed", "gabbing", "gabbed"],
+    "gad" => ["gads", "gadded", "gadding", "gadded"],
+    "gaf" => ["gaf", "gaf", "gaf", "gaf"],
+    "gainsay" => ["gainsays", "gainsaid", "gainsaying", "gainsaid"],
+    "gainset" => ["gainsets", "gainset", "gainsetting", "gainset"],
+    "gainstand" => ["gainstands", "gainstood", "gainstanding", "gainstood"],
+    "gainstay" => ["gainstays", "gainstayed", "gainstaying", "gainstayed"],
+    "gallop" => ["gallops", "galloped", "galloping", "galloped"],
+    "galop" => ["galops", "galoped", "galoping", "galoped"],
+    "galp" => ["galps", "galped", "galping", "galped"],
+    "gam" => ["gams", "gammed", "gamming", "gammed"],
+    "gammet" => ["gammets", "gammetted", "gammetting", "gammetted"],
+    "gan" => ["gans", "went", "gawn", "gane"],
+    "gangway" => ["gangways", "gangwayed", "gangwaying", "gangwayed"],
+    "gar" => ["gars", "gart", "garring", "gart"],
+    "garlic" => ["garlics", "garlicked", "garlicking", "garlicked"],
+    "garnishee" => ["garnishees", "garnisheed", "garnisheeing", "garnisheed"],
+    "gas" => ["gases", "gassed", "gassing", "gassed"],
+    "gasp" => ["gasps", "gasped", "gasping", "gasped"],
+    "gat" => ["gats", "gatted", "gatting", "gatted"],
+    "gatekeep" => ["gatekeeps", "gatekept", "gatekeeping", "gatekept"],
+    "gateway" => ["gateways", "gatewayed", "gatewaying", "gatewayed"],
+    "gaup" => ["gaups", "gauped", "gauping", "gauped"],
+    "gawp" => ["gawps", "gawped", "gawping", "gawped"],
+    "gay" => ["gays", "gayed", "gaying", "gayed"],
+    "gazump" => ["gazumps", "gazumped", "gazumping", "gazumped"],
+    "gee" => ["gees", "geed", "geeing", "geed"],
+    "gegg" => ["geggs", "gegged", "gegging", "gegged"],
+    "gel" => ["gels", "gelled", "gelling", "gelled"],
+    "gem" => ["gems", "gemmed", "gemming", "gemmed"],
+    "gen" => ["gens", "genned", "genning", "genned"],
+    "genderbend" => ["genderbends", "genderbent", "genderbending", "genderbent"],
+    "genogroup" => ["genogroups", "genogrouped", "genogrouping", "genogrouped"],
+    "geosurvey" => ["geosurveys", "geosurveyed", "geosurveying", "geosurveyed"],
+    "get" => ["gets", "got", "getting", "got"],
+    "ghetto" => ["ghettoes", "ghettoed", "ghettoing", "ghettoed"],
+    "ghillie" => ["ghillies", "ghillied", "ghillying", "ghillied"],
+    "ghostban" => ["ghostbans", "ghostbanned", "ghostbanning", "ghostbanned"],
+    "ghostwrite" => ["ghostwrites", "ghostwrote", "ghostwriting", "ghostwritten"],
+    "gib" => ["gibs", "gibbed", "gibbing", "gibbed"],
+    "giddyap" => ["giddyaps", "giddyaped", "giddyaping", "giddyaped"],
+    "giddyup" => ["giddyups", "giddyuped", "giddyuping", "giddyuped"],
+    "giftsub" => ["giftsubs", "giftsubbed", "giftsubbing", "giftsubbed"],
+    "gigacast" => ["gigacasts", "gigacast", "gigacasting", "gigacast"],
+    "gillie" => ["gillies", "gillied", "gillying", "gillied"],
+    "gillnet" => ["gillnets", "gillnetted", "gillnetting", "gillnetted"],
+    "gimp" => ["gimps", "gimped", "gimping", "gimped"],
+    "gin" => ["gins", "gan", "ginning", "gun"],
+    "gin2" => ["gins", "ginned", "ginning", "ginned"],
+    "gipsey" => ["gipseys", "gipseyed", "gipseying", "gipseyed"],
+    "git" => ["gits", "got", "gitting", "gotten"],
+    "giue" => ["giues", "gaue", "giuing", "giuen"],
+    "give" => ["gives", "gave", "giving", "given"],
+    "glad" => ["glads", "gladded", "gladding", "gladded"],
+    "glam" => ["glams", "glammed", "glamming", "glammed"],
+    "glamp" => ["glamps", "glamped", "glamping", "glamped"],
+    "glee" => ["glees", "gleed", "gleeing", "gleed"],
+    "gley" => ["gleys", "gleyed", "gleying", "gleyed"],
+    "glib" => ["glibs", "glibbed", "glibbing", "glibbed"],
+    "glim" => ["glims", "glimmed", "glimming", "glimmed"],
+    "glob" => ["globs", "globbed", "globbing", "globbed"],
+    "globetrot" => ["globetrots", "globetrotted", "globetrotting", "globetrotted"],
+    "glom" => ["gloms", "glommed", "glomming", "glommed"],
+    "glomp" => ["glomps", "glomped", "glomping", "glomped"],
+    "gloop" => ["gloops", "glooped", "glooping", "glooped"],
+    "glub" => ["glubs", "glubbed", "glubbing", "glubbed"],
+    "glum" => ["glums", "glummed", "glumming", "glummed"],
+    "glump" => ["glumps", "glumped", "glumping", "glumped"],
+    "glut" => ["gluts", "glutted", "glutting", "glutted"],
+    "gnar" => ["gnars", "gnarred", "gnarring", "gnarred"],
+    "go" => ["goes", "went", "going", "gone"],
+    "goalie" => ["goalies", "goalied", "goalieing", "goalied"],
+    "goalkeep" => ["goalkeeps", "goalkept", "goalkeeping", "goalkept"],
+    "goatse" => ["goatses", "goatsed", "goatseing", "goatsed"],
+    "gob" => ["gobs", "gobbed", "gobbing", "gobbed"],
+    "god" => ["gods", "godded", "godding", "godded"],
+    "godcast" => ["godcasts", "godcast", "godcasting", "godcast"],
+    "gollop" => ["gollops", "golloped", "golloping", "golloped"],
+    "goop" => ["goops", "gooped", "gooping", "gooped"],
+    "gossip" => ["gossips", "gossiped", "gossiping", "gossiped"],
+    "got" => ["got", "had", "goting", "had"],
+    "gotsta" => ["gotsta", "gotstaed", "gotstaing", "gotstaed"],
+    "gotta" => ["gotta", "gottaed", "gottaing", "gottaed"],
+    "grab" => ["grabs", "grabbed", "grabbing", "grabbed"],
+    "gralloch" => ["grallochs", "gralloched", "grallochin", "gralloched"],
+    "gralloch2" => ["grallochs", "gralloched", "gralloching", "gralloched"],
+    "gram" => ["grams", "grammed", "gramming", "grammed"],
+    "grasp" => ["grasps", "grasped", "grasping", "grasped"],
+    "gratinee" => ["gratinees", "gratineed", "gratineeing", "gratineed"],
+    "graverob" => ["graverobs", "graverobbed", "graverobbing", "graverobbed"],
+    "gravestand" => ["gravestands", "gravestood", "gravestanding", "gravestood"],
+    "gray" => ["grays", "grayed", "graying", "grayed"],
+    "gree" => ["grees", "greed", "greeing", "greed"],
+    "gren" => ["grens", "grenned", "grenning", "grenned"],
+    "grey" => ["greys", "greyed", "greying", "greyed"],
+    "grid" => ["grids", "gridded", "gridding", "gridded"],
+    "gril" => ["grils", "grilled", "grilling", "grilled"],
+    "grim" => ["grims", "grimmed", "grimming", "grimmed"],
+    "grin" => ["grins", "grinned", "grinning", "grinned"],
+    "grind" => ["grinds", "ground", "grinding", "ground"],
+    "gristbite" => ["gristbites", "gristbit", "gristbiting", "gristbitten"],
+    "grit" => ["grits", "gritted", "gritting", "gritted"],
+    "grizbite" => ["grizbites", "grizbit", "grizbiting", "grizbitten"],
+    "grob" => ["grobs", "grobbed", "grobbing", "grobbed"],
+    "grok" => ["groks", "grokked", "grokking", "grokked"],
+    "groop" => ["groops", "grooped", "grooping", "grooped"],
+    "group" => ["groups", "grouped", "grouping", "grouped"],
+    "grow" => ["grows", "grew", "growing", "grown"],
+    "growe" => ["growes", "grewe", "growing", "growne"],
+    "grub" => ["grubs", "grubbed", "grubbing", "grubbed"],
+    "grump" => ["grumps", "grumped", "grumping", "grumped"],
+    "guarantee" => ["guarantees", "guaranteed", "guaranteeing", "guaranteed"],
+    "gub" => ["gubs", "gubbed", "gubbing", "gubbed"],
+    "guimp" => ["guimps", "guimped", "guimping", "guimped"],
+    "gulag" => ["gulags", "gulaged", "gulaging", "gulaged"],
+    "gulley" => ["gulleys", "gulleyed", "gulleying", "gulleyed"],
+    "gulp" => ["gulps", "gulped", "gulping", "gulped"],
+    "gum" => ["gums", "gummed", "gumming", "gummed"],
+    "gumshoe" => ["gumshoes", "gumshoed", "gumshoeing", "gumshoed"],
+    "gun" => ["guns", "gunned", "gunning", "gunned"],
+    "gurks" => ["gurks", "gurksed", "gurksing", "gurksed"],
+    "gurp" => ["gurps", "gurped", "gurping", "gurped"],
+    "gut" => ["guts", "gutted", "gutting", "gutted"],
+    "gutshoot" => ["gutshoots", "gutshot", "gutshooting", "gutshot"],
+    "guy" => ["guys", "guyed", "guying", "guyed"],
+    "gweep" => ["gweeps", "gweeped", "gweeping", "gweeped"],
+    "gymcel" => ["gymcels", "gymcelled", "gymcelling", "gymcelled"],
+    "gymp" => ["gymps", "gymped", "gymping", "gymped"],
+    "hackney" => ["hackneys", "hackneyed", "hackneying", "hackneyed"],
+    "hafta" => ["hasta", "hadda", "haftaing", "hadda"],
+    "hagride" => ["hagrides", "hagrode", "hagriding", "hagridden"],
+    "haircut" => ["haircuts", "haircutted", "haircutting", "haircutted"],
+    "hairsplit" => ["hairsplits", "hairsplit", "hairsplitting", "hairsplit"],
+    "hairspray" => ["hairsprays", "hairsprayed", "hairspraying", "hairsprayed"],
+    "halal" => ["halals", "halalled", "halalling", "halalled"],
+    "half" => ["halves", "halved", "halving", "halved"],
+    "hallal" => ["hallals", "hallalled", "hallalling", "hallalled"],
+    "halo" => ["haloes", "haloed", "haloing", "haloed"],
+    "halp" => ["halps", "halped", "halping", "halped"],
+    "halterbreak" => ["halterbreaks", "halterbroke", "halterbreaking", "halterbroken"],
+    "ham" => ["hams", "hammed", "hamming", "hammed"],
+    "hamburg" => ["hamburgs", "hamburged", "hamburging", "hamburged"],
+    "hamstring" => ["hamstrings", "hamstrung", "hamstringing", "hamstrung"],
+    "handfeed" => ["handfeeds", "handfed", "handfeeding", "handfed"],
+    "handhold" => ["handholds", "handheld", "handholding", "handheld"],
+    "handknit" => ["handknits", "handknitted", "handknitting", "handknitted"],
+    "handmake" => ["handmakes", "handmade", "handmaking", "handmade"],
+    "handset" => ["handsets", "handset", "handsetting", "handset"],
+    "handstamp" => ["handstamps", "handstamped", "handstamping", "handstamped"],
+    "handstand" => ["handstands", "handstood", "handstanding", "handstood"],
+    "handweave" => ["handweaves", "handwove", "handweaving", "handwoven"],
+    "handwrite" => ["handwrites", "handwrote", "handwriting", "handwritten"],
+    "handyman" => ["handymans", "handymanned", "handymanning", "handymanned"],
+    "hang" => ["hangs", "hung", "hanging", "hung"],
+    "hardsub" => ["hardsubs", "hardsubbed", "hardsubbing", "hardsubbed"],
+    "harp" => ["harps", "harped", "harping", "harped"],
+    "hasp" => ["hasps", "hasped", "hasping", "hasped"],
+    "hat" => ["hats", "hatted", "hatting", "hatted"],
+    "haue" => ["has", "had", "hauing", "had"],
+    "hav" => ["has", "had", "having", "had"],
+    "have" => ["has", "had", "having", "had"],
+    "havoc" => ["havocs", "havocked", "havocking", "havocked"],
+    "hay" => ["hays", "hayed", "haying", "hayed"],
+    "haz" => ["haz", "hazed", "hazing", "hazed"],
+    "headbump" => ["headbumps", "headbumped", "headbumping", "headbumped"],
+    "headshot" => ["headshots", "headshotted", "headshotting", "headshotted"],
+    "headspring" => ["headsprings", "headsprang", "headspringing", "headsprung"],
+    "headstamp" => ["headstamps", "headstamped", "headstamping", "headstamped"],
+    "heap" => ["heaps", "heaped", "heaping", "heaped"],
+    "hear" => ["hears", "heard", "hearing", "heard"],
+    "heimlich" => ["heimlichs", "heimliched", "heimliching", "heimliched"],
+    "hellban" => ["hellbans", "hellbanned", "hellbanning", "hellbanned"],
+    "help" => ["helps", "helped", "helping", "helped"],
+    "hem" => ["hems", "hemmed", "hemming", "hemmed"],
+    "hemingway" => ["hemingways", "hemingwayed", "hemingwaying", "hemingwayed"],
+    "hen" => ["hens", "henned", "henning", "henned"],
+    "hero" => ["heroes", "heroed", "heroing", "heroed"],
+    "herye" => ["heryeth", "heryed", "herying", "heryed"],
+    "het" => ["hets", "hetted", "hetting", "hetted"],
+    "hev" => ["hes", "hed", "heving", "hed"],
+    "hew" => ["hews", "hewed", "hewing", "hewn"],
+    "hey" => ["heys", "heyed", "heying", "heyed"],
+    "hiccup" => ["hiccups", "hiccuped", "hiccuping", "hiccuped"],
+    "hickup" => ["hickups", "hickuped", "hickuping", "hickuped"],
+    "hide2" => ["hides", "hid", "hiding", "hidden"],
+    "hie" => ["hies", "hied", "hying", "hied"],
+    "hight" => ["hights", "hight", "highting", "hight"],
+    "highway" => ["highways", "highwayed", "highwaying", "highwayed"],
+    "hindcast" => ["hindcasts", "hindcast", "hindcasting", "hindcast"],
+    "histoblot" => ["histoblots", "histoblotted", "histoblotting", "histoblotted"],
+    "histogram" => ["histograms", "histogrammed", "histogramming", "histogrammed"],
+    "hit" => ["hits", "hit", "hitting", "hit"],
+    "ho" => ["hoes", "hoed", "hoeing", "hoed"],
+    "hob" => ["hobs", "hobbed", "hobbing", "hobbed"],
+    "hobandnob" => ["hobandnobs", "hobandnobbed", "hobandnobbing", "hobandnobbed"],
+    "hobanob" => ["hobanobs", "hobanobbed", "hobanobbing", "hobanobbed"],
+    "hobday" => ["hobdays", "hobdayed", "hobdaying", "hobdayed"],
+    "hobjob" => ["hobjobs", "hobjobbed", "hobjobbing", "hobjobbed"],
+    "hobnob" => ["hobnobs", "hobnobbed", "hobnobbing", "hobnobbed"],
+    "hockle2" => ["hockles", "hockled", "hocklin", "hockled"],
+    "hod" => ["hods", "hodded", "hodding", "hodded"],
+    "hoe" => ["hoes", "hoed", "hoeing", "hoed"],
+    "hogtie" => ["hogties", "hogtied", "hogtying", "hogtied"],
+    "hoit2" => ["hoits", "hoit", "hoiting", "hoit"],
+    "hold" => ["holds", "held", "holding", "held"],
+    "holiday" => ["holidays", "holidayed", "holidaying", "holidayed"],
+    "hollo" => ["holloes", "holloed", "holloing", "holloed"],
+    "holup" => ["holups", "holuped", "holuping", "holuped"],
+    "homemake" => ["homemakes", "homemade", "homemaking", "homemade"],
+    "homesit" => ["homesits", "homesat", "homesitting", "homesat"],
+    "honey" => ["honeys", "honeyed", "honeying", "honeyed"],
+    "hoop" => ["hoops", "hooped", "hooping", "hooped"],
+    "hooray" => ["hoorays", "hoorayed", "hooraying", "hoorayed"],
+    "horseplay" => ["horseplays", "horseplayed", "horseplaying", "horseplayed"],
+    "horseshoe" => ["horseshoes", "horseshoed", "horseshoeing", "horseshoed"],
+    "horsesit" => ["horsesits", "horsesat", "horsesitting", "horsesat"],
+    "hosey" => ["hoseys", "hoseyed", "hoseying", "hoseyed"],
+    "hot" => ["hots", "hotted", "hotting", "hotted"],
+    "hotkey" => ["hotkeys", "hotkeyed", "hotkeying", "hotkeyed"],
+    "hotshot" => ["hotshots", "hotshotted", "hotshotting", "hotshotted"],
+    "hould" => ["houlds", "held", "houlding", "held"],
+    "housebreak" => ["housebreaks", "housebroke", "housebreaking", "housebroken"],
+    "housekeep" => ["housekeeps", "housekept", "housekeeping", "housekept"],
+    "housesit" => ["housesits", "housesat", "housesitting", "housesat"],
+    "hoy" => ["hoys", "hoyed", "hoying", "hoyed"],
+    "hubbub" => ["hubbubs", "hubbubbed", "hubbubbing", "hubbubbed"],
+    "hum" => ["hums", "hummed", "humming", "hummed"],
+    "humjob" => ["humjobs", "humjobbed", "humjobbing", "humjobbed"],
+    "hump" => ["humps", "humped", "humping", "humped"],
+    "hurray" => ["hurrays", "hurrayed", "hurraying", "hurrayed"],
+    "hurt" => ["hurts", "hurt", "hurting", "hurt"],
+    "hut" => ["huts", "hutted", "hutting", "hutted"],
+    "hx" => ["hxs", "hxed", "hxing", "hxed"],
+    "hyde" => ["hydes", "hyd", "hyding", "hydden"],
+    "hydraulic" => ["hydraulics", "hydraulicked", "hydraulicking", "hydraulicked"],
+    "hydrofrac" => ["hydrofracs", "hydrofracked", "hydrofracking", "hydrofracked"],
+    "hydrojet" => ["hydrojets", "hydrojetted", "hydrojetting", "hydrojetted"],
+    "hyperbleb" => ["hyperblebs", "hyperblebbed", "hyperblebbing", "hyperblebbed"],
+    "hyperdom" => ["hyperdoms", "hyperdommed", "hyperdomming", "hyperdommed"],
+    "hyperwet" => ["hyperwets", "hyperwet", "hyperwetting", "hyperwet"],
+    "ice2" => ["ices", "iced", "iceing", "iced"],
+    "igg" => ["iggs", "igged", "igging", "igged"],
+    "imbar" => ["imbars", "imbarred", "imbarring", "imbarred"],
+    "imbargo" => ["imbargoes", "imbargoed", "imbargoing", "imbargoed"],
+    "imbay" => ["imbays", "imbayed", "imbaying", "imbayed"],
+    "imbed" => ["imbeds", "imbedded", "imbedding", "imbedded"],
+    "imbreed" => ["imbreeds", "imbred", "imbreeding", "imbred"],
+    "imbring" => ["imbrings", "imbrought", "imbringing", "imbrought"],
+    "immit" => ["immits", "immitted", "immitting", "immitted"],
+    "immunoassay" => ["immunoassays", "immunoassayed", "immunoassaying", "immunoassayed"],
+    "immunoblot" => ["immunoblots", "immunoblotted", "immunoblotting", "immunoblotted"],
+    "imp" => ["imps", "imped", "imping", "imped"],
+    "impasto" => ["impastoes", "impastoed", "impastoing", "impastoed"],
+    "impel" => ["impels", "impelled", "impelling", "impelled"],
+    "impen" => ["impens", "impenned", "impenning", "impenned"],
+    "imploy" => ["imploys", "imployed", "imploying", "imployed"],
+    "improv" => ["improvs", "improvved", "improvving", "improvved"],
+    "in" => ["ins", "inned", "inning", "inned"],
+    "inbear" => ["inbears", "inbore", "inbearing", "inborne"],
+    "inbeat" => ["inbeats", "inbeat", "inbeating", "inbeaten"],
+    "inbind" => ["inbinds", "inbound", "inbinding", "inbound"],
+    "inblow" => ["inblows", "inblew", "inblowing", "inblown"],
+    "inbreak" => ["inbreaks", "inbroke", "inbreaking", "inbroken"],
+    "inbreed" => ["inbreeds", "inbred", "inbreeding", "inbred"],
+    "inbring" => ["inbrings", "inbrought", "inbringing", "inbrought"],
+    "inbuild" => ["inbuilds", "inbuilt", "inbuilding", "inbuilt"],
+    "incamp" => ["incamps", "incamped", "incamping", "incamped"],
+    "inclasp" => ["inclasps", "inclasped", "inclasping", "inclasped"],
+    "incoop" => ["incoops", "incooped", "incooping", "incooped"],
+    "increep" => ["increeps", "incrept", "increeping", "incrept"],
+    "incur" => ["incurs", "incurred", "incurring", "incurred"],
+    "indef" => ["indefs", "indeffed", "indeffing", "indeffed"],
+    "indraw" => ["indraws", "indrew", "indrawing", "indrawn"],
+    "ineye" => ["ineyes", "ineyed", "ineyeing", "ineyed"],
+    "infall" => ["infalls", "infell", "infalling", "infallen"],
+    "infer" => ["infers", "inferred", "inferring", "inferred"],
+    "infodump" => ["infodumps", "infodumped", "infodumping", "infodumped"],
+    "inglut" => ["ingluts", "inglutted", "inglutting", "inglutted"],
+    "ingroup" => ["ingroups", "ingrouped", "ingrouping", "ingrouped"],
+    "ingrow" => ["ingrows", "ingrew", "ingrowing", "ingrown"],
+    "inhold" => ["inholds", "inheld", "inholding", "inheld"],
+    "inhoop" => ["inhoops", "inhooped", "inhooping", "inhooped"],
+    "injoy" => ["injoys", "injoyed", "injoying", "injoyed"],
+    "inknit" => ["inknits", "inknitted", "inknitting", "inknitted"],
+    "inknot" => ["inknots", "inknotted", "inknotting", "inknotted"],
+    "inlay" => ["inlays", "inlaid", "inlaying", "inlaid"],
+    "inlead" => ["inleads", "inled", "inleading", "inled"],
+    "inlet" => ["inlets", "inlet", "inletting", "inlet"],
+    "input" => ["inputs", "input", "inputting", "input"],
+    "insculp" => ["insculps", "insculped", "insculping", "insculped"],
+    "insee" => ["insees", "insaw", "inseeing", "inseen"],
+    "insend" => ["insends", "insent", "insending", "insent"],
+    "inset" => ["insets", "inset", "insetting", "inset"],
+    "inspan" => ["inspans", "inspanned", "inspanning", "inspanned"],
+    "inspeak" => ["inspeaks", "inspoke", "inspeaking", "inspoken"],
+    "instaban" => ["instabans", "instabanned", "instabanning", "instabanned"],
+    "instagib" => ["instagibs", "instagibbed", "instagibbing", "instagibbed"],
+    "instagram" => ["instagrams", "instagrammed", "instagramming", "instagrammed"],
+    "instal" => ["instals", "installed", "installing", "installed"],
+    "instamp" => ["instamps", "instamped", "instamping", "instamped"],
+    "instar" => ["instars", "instarred", "instarring", "instarred"],
+    "instasend" => ["instasends", "instasent", "instasending", "instasent"],
+    "insteep" => ["insteeps", "insteeped", "insteeping", "insteeped"],
+    "instil" => ["instils", "instilled", "instilling", "instilled"],
+    "instrew" => ["instrews", "instrewed", "instrewing", "instrewn"],
+    "intake" => ["intakes", "intook", "intaking", "intaken"],
+    "inter" => ["inters", "interred", "interring", "interred"],
+    "interbed" => ["interbeds", "interbedded", "interbedding", "interbedded"],
+    "interbreed" => ["interbreeds", "interbred", "interbreeding", "interbred"],
+    "interbring" => ["interbrings", "interbrought", "interbringing", "interbrought"],
+    "intercom" => ["intercoms", "intercommed", "intercomming", "intercommed"],
+    "intercome" => ["intercomes", "intercame", "intercoming", "intercome"],
+    "intercur" => ["intercurs", "intercurred", "intercurring", "intercurred"],
+    "intercut" => ["intercuts", "intercut", "intercutting", "intercut"],
+    "interdeal" => ["interdeals", "interdealt", "interdealing", "interdealt"],
+    "interdot" => ["interdots", "interdotted", "interdotting", "interdotted"],
+    "intergrind" => ["intergrinds", "interground", "intergrinding", "interground"],
+    "intergrow" => ["intergrows", "intergrew", "intergrowing", "intergrown"],
+    "interknit" => ["interknits", "interknitted", "interknitting", "interknitted"],
+    "interknot" => ["interknots", "interknotted", "interknotting", "interknotted"],
+    "interknow" => ["interknows", "interknew", "interknowing", "interknown"],
+    "interlay" => ["interlays", "interlaid", "interlaying", "interlaid"],
+    "interlend" => ["interlends", "interlent", "interlending", "interlent"],
+    "interlie" => ["interlies", "interlay", "interlying", "interlain"],
+    "intermat" => ["intermats", "intermatted", "intermatting", "intermatted"],
+    "intermit" => ["intermits", "intermitted", "intermitting", "intermitted"],
+    "internet" => ["internets", "internetted", "internetting", "internetted"],
+    "interpel" => ["interpels", "interpelled", "interpelling", "interpelled"],
+    "interplay" => ["interplays", "interplayed", "interplaying", "interplayed"],
+    "interset" => ["intersets", "interset", "intersetting", "interset"],
+    "intershine" => ["intershines", "intershone", "intershining", "intershone"],
+    "intersow" => ["intersows", "intersowed", "intersowing", "intersown"],
+    "interthink" => ["interthinks", "interthought", "interthinking", "interthought"],
+    "interweave" => ["interweaves", "interwove", "interweaving", "interwoven"],
+    "interwed" => ["interweds", "interwedded", "interwedding", "interwedded"],
+    "interwind" => ["interwinds", "interwound", "interwinding", "interwound"],
+    "inthral" => ["inthrals", "inthralled", "inthralling", "inthralled"],
+    "inthrow" => ["inthrows", "inthrew", "inthrowing", "inthrown"],
+    "inthrust" => ["inthrusts", "inthrust", "inthrusting", "inthrust"],
+    "intromit" => ["intromits", "intromitted", "intromitting", "intromitted"],
+    "inweave" => ["inweaves", "inwove", "inweaving", "inwoven"],
+    "inwind" => ["inwinds", "inwound", "inwinding", "inwound"],
+    "inwork" => ["inworks", "inwrought", "inworking", "inwrought"],
+    "inwrite" => ["inwrites", "inwrote", "inwriting", "inwritten"],
+    "ixnay" => ["ixnays", "ixnayed", "ixnaying", "ixnayed"],
+    "jab" => ["jabs", "jabbed", "jabbing", "jabbed"],
+    "jackpot" => ["jackpots", "jackpotted", "jackpotting", "jackpotted"],
+    "jailbreak" => ["jailbreaks", "jailbroke", "jailbreaking", "jailbroken"],
+    "jam" => ["jams", "jammed", "jamming", "jammed"],
+    "jambok" => ["jamboks", "jambokked", "jambokking", "jambokked"],
+    "jamboree" => ["jamborees", "jamboreed", "jamboreeing", "jamboreed"],
+    "janney" => ["janneys", "janneyed", "janneying", "janneyed"],
+    "japan" => ["japans", "japanned", "japanning", "japanned"],
+    "jar" => ["jars", "jarred", "jarring", "jarred"],
+    "jarp" => ["jarps", "jarped", "jarping", "jarped"],
+    "jaup" => ["jaups", "jauped", "jauping", "jauped"],
+    "jayrun" => ["jayruns", "jayran", "jayrunning", "jayrun"],
+    "jee" => ["jees", "jeed", "jeeing", "jeed"],
+    "jeep" => ["jeeps", "jeeped", "jeeping", "jeeped"],
+    "jerrybuild" => ["jerrybuilds", "jerrybuilt", "jerrybuilding", "jerrybuilt"],
+    "jet" => ["jets", "jetted", "jetting", "jetted"],
+    "jetset" => ["jetsets", "jetsetted", "jetsetting", "jetsetted"],
+    "jib" => ["jibs", "jibbed", "jibbing", "jibbed"],
+    "jim" => ["jims", "jimmed", "jimming", "jimmed"],
+    "jimjam" => ["jimjams", "jimjammed", "jimjamming", "jimjammed"],
+    "jit2" => ["jits", "jitted", "jitting", "jitted"],
+    "job" => ["jobs", "jobbed", "jobbing", "jobbed"],
+    "jockey" => ["jockeys", "jockeyed", "jockeying", "jockeyed"],
+    "jogtrot" => ["jogtrots", "jogtrotted", "jogtrotting", "jogtrotted"],
+    "jol" => ["jols", "jolled", "jolling", "jolled"],
+    "jolley" => ["jolleys", "jolleyed", "jolleying", "jolleyed"],
+    "jooj" => ["joojes", "joojed", "joojing", "joojed"],
+    "joozh" => ["joozhes", "joozhed", "joozhing", "joozhed"],
+    "jot" => ["jots", "jotted", "jotting", "jotted"],
+    "journey" => ["journeys", "journeyed", "journeying", "journeyed"],
+    "joy" => ["joys", "joyed", "joying", "joyed"],
+    "joyride" => ["joyrides", "joyrode", "joyriding", "joyridden"],
+    "jpeg2" => ["jpegs", "jpeged", "jpeging", "jpeged"],
+    "judg" => ["judges", "judged", "judging", "judged"],
+    "jugg" => ["juggs", "jugged", "jugging", "jugged"],
+    "juj" => ["jujes", "jujed", "jujing", "jujed"],
+    "jump" => ["jumps", "jumped", "jumping", "jumped"],
+    "jumpcut" => ["jumpcuts", "jumpcut", "jumpcutting", "jumpcut"],
+    "justifie" => ["justifies", "justified", "justifying", "justified"],
+    "jut" => ["juts", "jutted", "jutting", "jutted"],
+    "juzh" => ["juzhes", "juzhed", "juzhing", "juzhed"],
+    "kabob" => ["kabobs", "kabobbed", "kabobbing", "kabobbed"],
+    "kak" => ["kaks", "kakked", "kakking", "kakked"],
+    "kamikaze" => ["kamikazes", "kamikazed", "kamikazeing", "kamikazed"],
+    "kanye" => ["kanyes", "kanyed", "kanyeing", "kanyed"],
+    "kape" => ["kapes", "kapt", "kaping", "kapt"],
+    "karaoke" => ["karaokes", "karaoked", "karaokeing", "karaoked"],
+    "karate" => ["karates", "karated", "karateing", "karated"],
+    "keb" => ["kebs", "kebbed", "kebbing", "kebbed"],
+    "kebab" => ["kebabs", "kebabbed", "kebabbing", "kebabbed"],
+    "kebob" => ["kebobs", "kebobbed", "kebobbing", "kebobbed"],
+    "keep" => ["keeps", "kept", "keeping", "kept"],
+    "kek" => ["keks", "kekked", "kekking", "kekked"],
+    "keke" => ["kekes", "keked", "kekeing", "keked"],
+    "kelp" => ["kelps", "kelped", "kelping", "kelped"],
+    "kemp" => ["kemps", "kemped", "kemping", "kemped"],
+    "ken" => ["kens", "kenned", "kenning", "kenned"],
+    "kerblam" => ["kerblams", "kerblammed", "kerblamming", "kerblammed"],
+    "kest" => ["kests", "kest", "kesting", "kest"],
+    "key" => ["keys", "keyed", "keying", "keyed"],
+    "kickban" => ["kickbans", "kickbanned", "kickbanning", "kickbanned"],
+    "kicksled" => ["kicksleds", "kicksledded", "kicksledding", "kicksledded"],
+    "kid" => ["kids", "kidded", "kidding", "kidded"],
+    "kin" => ["kins", "kinned", "kinning", "kinned"],
+    "kingmake" => ["kingmakes", "kingmade", "kingmaking", "kingmade"],
+    "kit" => ["kits", "kitted", "kitting", "kitted"],
+    "klomp" => ["klomps", "klomped", "klomping", "klomped"],
+    "knab" => ["knabs", "knabbed", "knabbing", "knabbed"],
+    "knaw2" => ["knaws", "knawed", "knawing", "knawn"],
+    "knee" => ["knees", "kneed", "kneeing", "kneed"],
+    "kneel" => ["kneels", "knelt", "kneeling", "knelt"],
+    "knit" => ["knits", "knit", "knitting", "knit"],
+    "knob" => ["knobs", "knobbed", "knobbing", "knobbed"],
+    "knot" => ["knots", "knotted", "knotting", "knotted"],
+    "know" => ["knows", "knew", "knowing", "known"],
+    "kris" => ["kriss", "krised", "krising", "krised"],
+    "ks" => ["kss", "ksed", "ksing", "ksed"],
+    "kurvey" => ["kurveys", "kurveyed", "kurveying", "kurveyed"],
+    "lackey" => ["lackeys", "lackeyed", "lackeying", "lackeyed"],
+    "lacquey" => ["lacqueys", "lacqueyed", "lacqueying", "lacqueyed"],
+    "lade" => ["lades", "laded", "lading", "laden"],
+    "lam" => ["lams", "lammed", "lamming", "lammed"],
+    "lamp" => ["lamps", "lamped", "lamping", "lamped"],
+    "landgrab" => ["landgrabs", "landgrabbed", "landgrabbing", "landgrabbed"],
+    "landslide" => ["landslides", "landslid", "landsliding", "landslid"],
+    "larn" => ["larns", "larned", "larnin", "larned"],
+    "larp" => ["larps", "larped", "larping", "larped"],
+    "larrup" => ["larrups", "larruped", "larruping", "larruped"],
+    "lasertypeset" => ["lasertypesets", "lasertypeset", "lasertypesetting", "lasertypeset"],
+    "lasso" => ["lassoes", "lassoed", "lassoing", "lassoed"],
+    "lasthit" => ["lasthits", "lasthit", "lasthitting", "lasthit"],
+    "latchkey" => ["latchkeys", "latchkeyed", "latchkeying", "latchkeyed"],
+    "laup" => ["laups", "lauped", "lauping", "lauped"],
+    "lavacast" => ["lavacasts", "lavacast", "lavacasting", "lavacast"],
+    "lawmake" => ["lawmakes", "lawmade", "lawmaking", "lawmade"],
+    "lay" => ["lays", "laid", "laying", "laid"],
+    "layaway" => ["layaways", "layawayed", "layawaying", "layawayed"],
+    "lead2" => ["leads", "led", "leading", "led"],
+    "leap" => ["leaps", "leaped", "leaping", "leaped"],
+    "leaue" => ["leaues", "left", "leauing", "left"],
+    "leave2" => ["leaves", "left", "leaving", "left"],
+    "leeroy" => ["leeroys", "leeroyed", "leeroying", "leeroyed"],
+    "leese2" => ["leeses", "lore", "leesing", "lorn"],
+    "lek" => ["leks", "lekked", "lekking", "lekked"],
+    "lend" => ["lends", "lent", "lending", "lent"],
+    "lepay" => ["lepays", "lepayed", "lepaying", "lepayed"],
+    "lern" => ["lerns", "lernt", "lerning", "lernt"],
+    "lerp" => ["lerps", "lerped", "lerping", "lerped"],
+    "lese" => ["leses", "lore", "lesing", "lorn"],
+    "let" => ["lets", "let", "letting", "let"],
+    "let2" => ["lets", "letted", "letting", "let"],
+    "levee" => ["levees", "leveed", "leveeing", "leveed"],
+    "lewinsky" => ["lewinskys", "lewinskyed", "lewinskying", "lewinskyed"],
+    "lib" => ["libs", "libbed", "libbing", "libbed"],
+    "lid" => ["lids", "lidded", "lidding", "lidded"],
+    "lie" => ["lies", "lay", "lying", "lain"],
+    "lie2" => ["lies", "lied", "lying", "lied"],
+    "light2" => ["lights", "lit", "lighting", "lit"],
+    "limbec" => ["limbecs", "limbecked", "limbecking", "limbecked"],
+    "limp" => ["limps", "lamp", "limping", "lump"],
+    "limp2" => ["limps", "limped", "limping", "limped"],
+    "lin" => ["lins", "linned", "linning", "linned"],
+    "linchpin" => ["linchpins", "linchpinned", "linchpinning", "linchpinned"],
+    "linebreed" => ["linebreeds", "linebred", "linebreeding", "linebred"],
+    "lipread" => ["lipreads", "lipread", "lipreading", "lipread"],
+    "lipsynch" => ["lipsynchs", "lipsynched", "lipsynching", "lipsynched"],
+    "lisp" => ["lisps", "lisped", "lisping", "lisped"],
+    "list2" => ["lists", "list", "listing", "list"],
+    "lit" => ["lits", "litted", "litting", "litted"],
+    "livecast" => ["livecasts", "livecast", "livecasting", "livecast"],
+    "livechat" => ["livechats", "livechatted", "livechatting", "livechatted"],
+    "livescan" => ["livescans", "livescanned", "livescanning", "livescanned"],
+    "lob" => ["lobs", "lobbed", "lobbing", "lobbed"],
+    "lobbycon" => ["lobbycons", "lobbyconned", "lobbyconning", "lobbyconned"],
+    "loc2" => ["locs", "locced", "loccing", "locced"],
+    "logic" => ["logics", "logicked", "logicking", "logicked"],
+    "logjam" => ["logjams", "logjammed", "logjamming", "logjammed"],
+    "lollop" => ["lollops", "lolloped", "lolloping", "lolloped"],
+    "lollpoop" => ["lollpoops", "lollpooped", "lollpooping", "lollpooped"],
+    "lolmao" => ["lolmaoes", "lolmaoed", "lolmaoing", "lolmaoed"],
+    "longe" => ["longes", "longed", "longeing", "longed"],
+    "looksee" => ["looksees", "looksaw", "lookseeing", "lookseen"],
+    "loop" => ["loops", "looped", "looping", "looped"],
+    "lose" => ["loses", "lost", "losing", "lost"],
+    "lot" => ["lots", "lotted", "lotting", "lotted"],
+    "lowp" => ["lowps", "lowpt", "lowping", "lowpt"],
+    "luigi2" => ["luigis", "luigied", "luiging", "luigied"],
+    "lump" => ["lumps", "lumped", "lumping", "lumped"],
+    "luv" => ["luvz", "luvd", "luving", "luvd"],
+    "lye" => ["lyes", "lay", "lying", "lain"],
+    "lye2" => ["lyes", "lyed", "lyeing", "lyed"],
+    "lynchpin" => ["lynchpins", "lynchpinned", "lynchpinning", "lynchpinned"],
+    "mab" => ["mabs", "mabbed", "mabbing", "mabbed"],
+    "machete" => ["machetes", "macheted", "macheteing", "macheted"],
+    "machinegun" => ["machineguns", "machinegunned", "machinegunning", "machinegunned"],
+    "macrame" => ["macrames", "macrameed", "macrameing", "macrameed"],
+    "mad" => ["mads", "madded", "madding", "madded"],
+    "magdump" => ["magdumps", "magdumped", "magdumping", "magdumped"],
+    "magic" => ["magics", "magicked", "magicking", "magicked"],
+    "magpie" => ["magpies", "magpied", "magpieing", "magpied"],
+    "mailgram" => ["mailgrams", "mailgrammed", "mailgramming", "mailgrammed"],
+    "mailshot" => ["mailshots", "mailshotted", "mailshotting", "mailshotted"],
+    "mainswear" => ["mainswears", "mainswore", "mainswearing", "mainsworn"],
+    "mak" => ["maks", "makked", "makkin", "makked"],
+    "make" => ["makes", "made", "making", "made"],
+    "makeup" => ["makeups", "makeuped", "makeuping", "makeuped"],
+    "malemploy" => ["malemploys", "malemployed", "malemploying", "malemployed"],
+    "mamaguy" => ["mamaguys", "mamaguyed", "mamaguying", "mamaguyed"],
+    "man" => ["mans", "manned", "manning", "manned"],
+    "mandril" => ["mandrils", "mandrilled", "mandrilling", "mandrilled"],
+    "mango" => ["mangoes", "mangoed", "mangoing", "mangoed"],
+    "mankeep" => ["mankeeps", "mankept", "mankeeping", "mankept"],
+    "mansplay" => ["mansplays", "mansplayed", "mansplaying", "mansplayed"],
+    "manspread" => ["manspreads", "manspread", "manspreading", "manspread"],
+    "manswear" => ["manswears", "manswore", "manswearing", "mansworn"],
+    "manumit" => ["manumits", "manumitted", "manumitting", "manumitted"],
+    "mar" => ["mars", "marred", "marring", "marred"],
+    "marcel" => ["marcels", "marcelled", "marcelling", "marcelled"],
+    "marcot" => ["marcots", "marcotted", "marcotting", "marcotted"],
+    "marquee" => ["marquees", "marqueed", "marqueeing", "marqueed"],
+    "marzipan" => ["marzipans", "marzipanned", "marzipanning", "marzipanned"],
+    "massacree" => ["massacrees", "massacreed", "massacreeing", "massacreed"],
+    "masterplan" => ["masterplans", "masterplanned", "masterplanning", "masterplanned"],
+    "mat" => ["mats", "matted", "matting", "matted"],
+    "matchmake" => ["matchmakes", "matchmade", "matchmaking", "matchmade"],
+    "matinee" => ["matinees", "matineed", "matineeing", "matineed"],
+    "may" => ["may", "might", "maying", "might"],
+    "may2" => ["mays", "mayed", "maying", "mayed"],
+    "mean" => ["means", "meant", "meaning", "meant"],
+    "meatpuppet" => ["meatpuppets", "meatpuppetted", "meatpuppetting", "meatpuppetted"],
+    "meatride" => ["meatrides", "meatrode", "meatriding", "meatridden"],
+    "med" => ["meds", "medded", "medding", "medded"],
+    "medley" => ["medleys", "medleyed", "medleying", "medleyed"],
+    "meean" => ["meeans", "meeant", "meeaning", "meeant"],
+    "meep" => ["meeps", "meeped", "meeping", "meeped"],
+    "meet" => ["meets", "met", "meeting", "met"],
+    "megacast" => ["megacasts", "megacast", "megacasting", "megacast"],
+    "melee" => ["melees", "meleed", "meleeing", "meleed"],
+    "meme" => ["memes", "memed", "memeing", "memed"],
+    "merrimake" => ["merrimakes", "merrimade", "merrimaking", "merrimade"],
+    "merrymake" => ["merrymakes", "merrymade", "merrymaking", "merrymade"],
+    "metric" => ["metrics", "metricked", "metricking", "metricked"],
+    "mickey" => ["mickeys", "mickeyed", "mickeying", "mickeyed"],
+    "microalloy" => ["microalloys", "microalloyed", "microalloying", "microalloyed"],
+    "microassay" => ["microassays", "microassayed", "microassaying", "microassayed"],
+    "microdot" => ["microdots", "microdotted", "microdotting", "microdotted"],
+    "microprogram" => ["microprograms", "microprogrammed", "microprogramming", "microprogrammed"],
+    "microteach" => ["microteaches", "microtaught", "microteaching", "microtaught"],
+    "microtrepan" => ["microtrepans", "microtrepanned", "microtrepanning", "microtrepanned"],
+    "middleman" => ["middlemans", "middlemanned", "middlemanning", "middlemanned"],
+    "might" => ["might", "might", "mighting", "might"],
+    "mimic" => ["mimics", "mimicked", "mimicking", "mimicked"],
+    "mimp" => ["mimps", "mimped", "mimping", "mimped"],
+    "min" => ["mins", "minned", "minning", "minned"],
+    "minac" => ["minacs", "minacked", "minacking", "minacked"],
+    "mindread" => ["mindreads", "mindread", "mindreading", "mindread"],
+    "minicab" => ["minicabs", "minicabbed", "minicabbing", "minicabbed"],
+    "minimod" => ["minimods", "minimodded", "minimodding", "minimodded"],
+    "minivan" => ["minivans", "minivanned", "minivanning", "minivanned"],
+    "misagree" => ["misagrees", "misagreed", "misagreeing", "misagreed"],
+    "misallot" => ["misallots", "misallotted", "misallotting", "misallotted"],
+    "misbear" => ["misbears", "misbore", "misbearing", "misborne"],
+    "misbeat" => ["misbeats", "misbeat", "misbeating", "misbeaten"],
+    "misbecome" => ["misbecomes", "misbecame", "misbecoming", "misbecome"],
+    "misbefall" => ["misbefalls", "misbefell", "misbefalling", "misbefallen"],
+    "misbeget" => ["misbegets", "misbegot", "misbegetting", "misbegotten"],
+    "misbid" => ["misbids", "misbid", "misbidding", "misbidden"],
+    "misbind" => ["misbinds", "misbound", "misbinding", "misbound"],
+    "misblow" => ["misblows", "misblew", "misblowing", "misblown"],
+    "misbreed" => ["misbreeds", "misbred", "misbreeding", "misbred"],
+    "misbuild" => ["misbuilds", "misbuilt", "misbuilding", "misbuilt"],
+    "miscast" => ["miscasts", "miscast", "miscasting", "miscast"],
+    "miscatalog" => ["miscatalogs", "miscataloged", "miscataloging", "miscataloged"],
+    "miscatch" => ["miscatches", "miscaught", "miscatching", "miscaught"],
+    "mischoose" => ["mischooses", "mischose", "mischoosing", "mischosen"],
+    "miscleave" => ["miscleaves", "miscleft", "miscleaving", "miscleft"],
+    "miscome" => ["miscomes", "miscame", "miscoming", "miscome"],
+    "miscommit" => ["miscommits", "miscommitted", "miscommitting", "miscommitted"],
+    "miscontrol" => ["miscontrols", "miscontrolled", "miscontrolling", "miscontrolled"],
+    "misconvey" => ["misconveys", "misconveyed", "misconveying", "misconveyed"],
+    "miscut" => ["miscuts", "miscut", "miscutting", "miscut"],
+    "misdeal" => ["misdeals", "misdealt", "misdealing", "misdealt"],
+    "misdevelop" => ["misdevelops", "misdeveloped", "misdeveloping", "misdeveloped"],
+    "misdiagram" => ["misdiagrams", "misdiagrammed", "misdiagramming", "misdiagrammed"],
+    "misdig" => ["misdigs", "misdug", "misdigging", "misdug"],
+    "misdisplay" => ["misdisplays", "misdisplayed", "misdisplaying", "misdisplayed"],
+    "misdo" => ["misdoes", "misdid", "misdoing", "misdone"],
+    "misdraw" => ["misdraws", "misdrew", "misdrawing", "misdrawn"],
+    "misdrive" => ["misdrives", "misdrove", "misdriving", "misdriven"],
+    "misdub" => ["misdubs", "misdubbed", "misdubbing", "misdubbed"],
+    "misdump" => ["misdumps", "misdumped", "misdumping", "misdumped"],
+    "miseat" => ["miseats", "misate", "miseating", "miseaten"],
+    "misemploy" => ["misemploys", "misemployed", "misemploying", "misemployed"],
+    "misenrol" => ["misenrols", "misenrolled", "misenrolling", "misenrolled"],
+    "misfall" => ["misfalls", "misfell", "misfalling", "misfallen"],
+    "misfeed" => ["misfeeds", "misfed", "misfeeding", "misfed"],
+    "misfeel" => ["misfeels", "misfelt", "misfeeling", "misfelt"],
+    "misfit" => ["misfits", "misfitted", "misfitting", "misfitted"],
+    "misforecast" => ["misforecasts", "misforecast", "misforecasting", "misforecast"],
+    "misforgive" => ["misforgives", "misforgave", "misforgiving", "misforgiven"],
+    "misformat" => ["misformats", "misformatted", "misformatting", "misformatted"],
+    "misget" => ["misgets", "misgot", "misgetting", "misgotten"],
+    "misgive" => ["misgives", "misgave", "misgiving", "misgiven"],
+    "misgo" => ["misgoes", "miswent", "misgoing", "misgone"],
+    "misgrab" => ["misgrabs", "misgrabbed", "misgrabbing", "misgrabbed"],
+    "misgrasp" => ["misgrasps", "misgrasped", "misgrasping", "misgrasped"],
+    "misgrind" => ["misgrinds", "misground", "misgrinding", "misground"],
+    "misgroup" => ["misgroups", "misgrouped", "misgrouping", "misgrouped"],
+    "misgrow" => ["misgrows", "misgrew", "misgrowing", "misgrown"],
+    "mishang" => ["mishangs", "mishung", "mishanging", "mishung"],
+    "mishear" => ["mishears", "misheard", "mishearing", "misheard"],
+    "mishit" => ["mishits", "mishit", "mishitting", "mishit"],
+    "mishold" => ["misholds", "misheld", "misholding", "misheld"],
+    "misinfer" => ["misinfers", "misinferred", "misinferring", "misinferred"],
+    "misinput" => ["misinputs", "misinputted", "misinputting", "misinputted"],
+    "miskeep" => ["miskeeps", "miskept", "miskeeping", "miskept"],
+    "misken" => ["miskens", "miskenned", "miskenning", "miskenned"],
+    "miskey" => ["miskeys", "miskeyed", "miskeying", "miskeyed"],
+    "misknit" => ["misknits", "misknit", "misknitting", "misknit"],
+    "misknot" => ["misknots", "misknotted", "misknotting", "misknotted"],
+    "misknow" => ["misknows", "misknew", "misknowing", "misknown"],
+    "mislay" => ["mislays", "mislaid", "mislaying", "mislaid"],
+    "mislead" => ["misleads", "misled", "misleading", "misled"],
+    "mislie" => ["mislies", "mislay", "mislying", "mislain"],
+    "mismake" => ["mismakes", "mismade", "mismaking", "mismade"],
+    "mismean" => ["mismeans", "mismeant", "mismeaning", "mismeant"],
+    "mismeet" => ["mismeets", "mismet", "mismeeting", "mismet"],
+    "misobey" => ["misobeys", "misobeyed", "misobeying", "misobeyed"],
+    "mispay" => ["mispays", "mispaid", "mispaying", "mispaid"],
+    "mispen" => ["mispens", "mispenned", "mispenning", "mispenned"],
+    "mispin" => ["mispins", "mispinned", "mispinning", "mispinned"],
+    "misplan" => ["misplans", "misplanned", "misplanning", "misplanned"],
+    "misplay" => ["misplays", "misplayed", "misplaying", "misplayed"],
+    "misplead" => ["mispleads", "mispled", "mispleading", "mispled"],
+    "misplot" => ["misplots", "misplotted", "misplotting", "misplotted"],
+    "misportray" => ["misportrays", "misportrayed", "misportraying", "misportrayed"],
+    "misprogram" => ["misprograms", "misprogrammed", "misprogramming", "misprogrammed"],
+    "mispump" => ["mispumps", "mispumped", "mispumping", "mispumped"],
+    "misput" => ["misputs", "misput", "misputting", "misput"],
+    "misread" => ["misreads", "misread", "misreading", "misread"],
+    "misrefer" => ["misrefers", "misreferred", "misreferring", "misreferred"],
+    "misrelay" => ["misrelays", "misrelayed", "misrelaying", "misrelayed"],
+    "misride" => ["misrides", "misrode", "misriding", "misridden"],
+    "misring" => ["misrings", "misrang", "misringing", "misrung"],
+    "misrun" => ["misruns", "misran", "misrunning", "misrun"],
+    "missay" => ["missays", "missaid", "missaying", "missaid"],
+    "misscan" => ["misscans", "misscanned", "misscanning", "misscanned"],
+    "missee" => ["missees", "missaw", "misseeing", "misseen"],
+    "misseek" => ["misseeks", "missought", "misseeking", "missought"],
+    "missell" => ["missells", "missold", "misselling", "missold"],
+    "missend" => ["missends", "missent", "missending", "missent"],
+    "misset" => ["missets", "misset", "missetting", "misset"],
+    "missew" => ["missews", "missewed", "missewing", "missewn"],
+    "misshoe" => ["misshoes", "misshoed", "misshoeing", "misshoed"],
+    "misshoot" => ["misshoots", "misshot", "misshooting", "misshot"],
+    "misspeak" => ["misspeaks", "misspoke", "misspeaking", "misspoken"],
+    "misspeed" => ["misspeeds", "missped", "misspeeding", "missped"],
+    "misspell" => ["misspells", "misspelt", "misspelling", "misspelt"],
+    "misspend" => ["misspends", "misspent", "misspending", "misspent"],
+    "misspin" => ["misspins", "misspun", "misspinning", "misspun"],
+    "misspit" => ["misspits", "misspat", "misspitting", "misspat"],
+    "missplit" => ["missplits", "missplit", "missplitting", "missplit"],
+    "misspot" => ["misspots", "misspotted", "misspotting", "misspotted"],
+    "misspray" => ["missprays", "missprayed", "misspraying", "missprayed"],
+    "misspread" => ["misspreads", "misspread", "misspreading", "misspread"],
+    "misstamp" => ["misstamps", "misstamped", "misstamping", "misstamped"],
+    "misstay" => ["misstays", "misstayed", "misstaying", "misstayed"],
+    "misstrike" => ["misstrikes", "misstruck", "misstriking", "misstruck"],
+    "misstring" => ["misstrings", "misstrung", "misstringing", "misstrung"],
+    "missubmit" => ["missubmits", "missubmitted", "missubmitting", "missubmitted"],
+    "missurvey" => ["missurveys", "missurveyed", "missurveying", "missurveyed"],
+    "missway" => ["missways", "misswayed", "misswaying", "misswayed"],
+    "misswear" => ["misswears", "misswore", "misswearing", "missworn"],
+    "misswing" => ["misswings", "misswung", "misswinging", "misswung"],
+    "mistake" => ["mistakes", "mistook", "mistaking", "mistaken"],
+    "misteach" => ["misteaches", "mistaught", "misteaching", "mistaught"],
+    "mistell" => ["mistells", "mistold", "mistelling", "mistold"],
+    "misthink" => ["misthinks", "misthought", "misthinking", "misthought"],
+    "misthrow" => ["misthrows", "misthrew", "misthrowing", "misthrown"],
+    "mistie" => ["misties", "mistied", "mistying", "mistied"],
+    "mistransfer" => ["mistransfers", "mistransferred", "mistransferring", "mistransferred"],
+    "mistransmit" => ["mistransmits", "mistransmitted", "mistransmitting", "mistransmitted"],
+    "mistrim" => ["mistrims", "mistrimmed", "mistrimming", "mistrimmed"],
+    "misunderstand" => ["misunderstands", "misunderstood", "misunderstanding", "misunderstood"],
+    "miswear" => ["miswears", "miswore", "miswearing", "misworn"],
+    "misweave" => ["misweaves", "miswove", "misweaving", "miswoven"],
+    "miswed" => ["misweds", "miswed", "miswedding", "miswed"],
+    "miswend" => ["miswends", "miswent", "miswending", "miswent"],
+    "miswind" => ["miswinds", "miswound", "miswinding", "miswound"],
+    "misworship" => ["misworships", "misworshiped", "misworshiping", "misworshiped"],
+    "miswrite" => ["miswrites", "miswrote", "miswriting", "miswritten"],
+    "mob" => ["mobs", "mobbed", "mobbing", "mobbed"],
+    "mobcast" => ["mobcasts", "mobcast", "mobcasting", "mobcast"],
+    "mod" => ["mods", "modded", "modding", "modded"],
+    "moleskin" => ["moleskins", "moleskinned", "moleskinning", "moleskinned"],
+    "mollifie" => ["mollifies", "mollified", "mollifying", "mollified"],
+    "mom" => ["moms", "mommed", "momming", "mommed"],
+    "monkey" => ["monkeys", "monkeyed", "monkeying", "monkeyed"],
+    "monocrop" => ["monocrops", "monocroped", "monocroping", "monocroped"],
+    "monogram" => ["monograms", "monogrammed", "monogramming", "monogrammed"],
+    "monolog" => ["monologs", "monologed", "monologing", "monologed"],
+    "moonrun" => ["moonruns", "moonran", "moonrunning", "moonran"],
+    "moop" => ["moops", "mooped", "mooping", "mooped"],
+    "mosaic" => ["mosaics", "mosaicked", "mosaicking", "mosaicked"],
+    "mosey" => ["moseys", "moseyed", "moseying", "moseyed"],
+    "mote" => ["mote", "must", "moting", "must"],
+    "motel" => ["motels", "motelled", "motelling", "motelled"],
+    "motley" => ["motleys", "motleyed", "motleying", "motleyed"],
+    "mouthsoap" => ["mouthsoaps", "mouthsoaped", "mouthsoaping", "mouthsoaped"],
+    "mow2" => ["mows", "mowed", "mowing", "mown"],
+    "msg" => ["msgs", "msged", "msging", "msged"],
+    "mud" => ["muds", "mudded", "mudding", "mudded"],
+    "mudsling" => ["mudslings", "mudslung", "mudslinging", "mudslung"],
+    "mugwump" => ["mugwumps", "mugwumped", "mugwumping", "mugwumped"],
+    "mum" => ["mums", "mummed", "mumming", "mummed"],
+    "mump" => ["mumps", "mumped", "mumping", "mumped"],
+    "music" => ["musics", "musicked", "musicking", "musicked"],
+    "must2" => ["must", "must", "musting", "must"],
+    "myek" => ["myeks", "made", "myekin", "made"],
+    "mythmake" => ["mythmakes", "mythmade", "mythmaking", "mythmade"],
+    "nab" => ["nabs", "nabbed", "nabbing", "nabbed"],
+    "namaste" => ["namastes", "namasted", "namasteing", "namasted"],
+    "nannybot" => ["nannybots", "nannybotted", "nannybotting", "nannybotted"],
+    "nanoalloy" => ["nanoalloys", "nanoalloyed", "nanoalloying", "nanoalloyed"],
+    "narcan" => ["narcans", "narcanned", "narcanning", "narcanned"],
+    "narrowcast" => ["narrowcasts", "narrowcast", "narrowcasting", "narrowcast"],
+    "naumkeag" => ["naumkeags", "naumkeaged", "naumkeaging", "naumkeaged"],
+    "nav" => ["navs", "navved", "navving", "navved"],
+    "nay" => ["nays", "nayed", "naying", "nayed"],
+    "naysay" => ["naysays", "naysaid", "naysaying", "naysaid"],
+    "neap" => ["neaps", "neaped", "neaping", "neaped"],
+    "necro" => ["necroes", "necroed", "necroing", "necroed"],
+    "necrobump" => ["necrobumps", "necrobumped", "necrobumping", "necrobumped"],
+    "needa" => ["needa", "needaed", "needaing", "needaed"],
+    "net" => ["nets", "netted", "netting", "netted"],
+    "netsplit" => ["netsplits", "netsplitted", "netsplitting", "netsplitted"],
+    "neurotransmit" => ["neurotransmits", "neurotransmitted", "neurotransmitting", "neurotransmitted"],
+    "newcast" => ["newcasts", "newcast", "newcasting", "newcast"],
+    "newgroup" => ["newgroups", "newgrouped", "newgrouping", "newgrouped"],
+    "newmake" => ["newmakes", "newmade", "newmaking", "newmade"],
+    "newscast" => ["newscasts", "newscast", "newscasting", "newscast"],
+    "newset" => ["newsets", "newset", "newsetting", "newset"],
+    "newvamp" => ["newvamps", "newvamped", "newvamping", "newvamped"],
+    "nfa" => ["nfas", "nfad", "nfaing", "nfad"],
+    "nib" => ["nibs", "nibbed", "nibbing", "nibbed"],
+    "nightclub" => ["nightclubs", "nightclubbed", "nightclubbing", "nightclubbed"],
+    "nim" => ["nims", "nimmed", "nimming", "nimmed"],
+    "nit" => ["nits", "nitted", "nitting", "nitted"],
+    "no" => ["noes", "noed", "noing", "noed"],
+    "nob" => ["nobs", "nobbed", "nobbing", "nobbed"],
+    "nod" => ["nods", "nodded", "nodding", "nodded"],
+    "noie" => ["noies", "noied", "noying", "noied"],
+    "nollepros" => ["nolleprosses", "nolleprossed", "nolleprossing", "nolleprossed"],
+    "nom" => ["noms", "nommed", "nomming", "nommed"],
+    "nomogram" => ["nomograms", "nomogrammed", "nomogramming", "nomogrammed"],
+    "nonconcur" => ["nonconcurs", "nonconcurred", "nonconcurring", "nonconcurred"],
+    "nonplus" => ["nonplusses", "nonplussed", "nonplussing", "nonplussed"],
+    "nonpros" => ["nonprosses", "nonprossed", "nonprossing", "nonprossed"],
+    "noogie" => ["noogies", "noogied", "noogying", "noogied"],
+    "nop2" => ["nops", "noped", "noping", "noped"],
+    "nosel" => ["nosels", "noselled", "noselling", "noselled"],
+    "noseride" => ["noserides", "noserode", "noseriding", "noseridden"],
+    "noserub" => ["noserubs", "noserubbed", "noserubbing", "noserubbed"],
+    "nosey" => ["noseys", "noseyed", "noseying", "noseyed"],
+    "notetake" => ["notetakes", "notetook", "notetaking", "notetaken"],
+    "nowcast" => ["nowcasts", "nowcast", "nowcasting", "nowcast"],
+    "noy" => ["noys", "noyed", "noying", "noyed"],
+    "nub" => ["nubs", "nubbed", "nubbing", "nubbed"],
+    "nudzh" => ["nudzhes", "nudzhed", "nudzhing", "nudzhed"],
+    "nut" => ["nuts", "nutted", "nutting", "nutted"],
+    "nutshot" => ["nutshots", "nutshot", "nutshotting", "nutshot"],
+    "nyam" => ["nyams", "nyammed", "nyamming", "nyammed"],
+    "obey" => ["obeys", "obeyed", "obeying", "obeyed"],
+    "occur" => ["occurs", "occurred", "occurring", "occurred"],
+    "octopus" => ["octopusses", "octopussed", "octopussing", "octopussed"],
+    "octroy" => ["octroys", "octroyed", "octroying", "octroyed"],
+    "oddjob" => ["oddjobs", "oddjobbed", "oddjobbing", "oddjobbed"],
+    "oe" => ["oes", "oeed", "oeing", "oeed"],
+    "offbear" => ["offbears", "offbore", "offbearing", "offborne"],
+    "offcast" => ["offcasts", "offcast", "offcasting", "offcast"],
+    "offcut" => ["offcuts", "offcut", "offcutting", "offcut"],
+    "offgas" => ["offgases", "offgassed", "offgassing", "offgassed"],
+    "offhold" => ["offholds", "offheld", "offholding", "offheld"],
+    "offlay" => ["offlays", "offlaid", "offlaying", "offlaid"],
+    "offlead" => ["offleads", "offled", "offleading", "offled"],
+    "offsend" => ["offsends", "offsent", "offsending", "offsent"],
+    "offset" => ["offsets", "offset", "offsetting", "offset"],
+    "offsmite" => ["offsmites", "offsmote", "offsmiting", "offsmitten"],
+    "offsplit" => ["offsplits", "offsplit", "offsplitting", "offsplit"],
+    "offstand" => ["offstands", "offstood", "offstanding", "offstood"],
+    "offtake" => ["offtakes", "offtook", "offtaking", "offtaken"],
+    "offthrow" => ["offthrows", "offthrew", "offthrowing", "offthrown"],
+    "okay" => ["okays", "okayed", "okaying", "okayed"],
+    "ollie" => ["ollies", "ollied", "ollieing", "ollied"],
+    "om" => ["oms", "ommed", "omming", "ommed"],
+    "omit" => ["omits", "omitted", "omitting", "omitted"],
+    "oncome" => ["oncomes", "oncame", "oncoming", "oncome"],
+    "oneshot" => ["oneshots", "oneshotted", "oneshotting", "oneshotted"],
+    "onfall" => ["onfalls", "onfell", "onfalling", "onfallen"],
+    "ongo" => ["ongoes", "onwent", "ongoing", "ongone"],
+    "onhold" => ["onholds", "onheld", "onholding", "onheld"],
+    "onlay" => ["onlays", "onlaid", "onlaying", "onlaid"],
+    "onlead" => ["onleads", "onled", "onleading", "onled"],
+    "onlend" => ["onlends", "onlent", "onlending", "onlent"],
+    "onsell" => ["onsells", "onsold", "onselling", "onsold"],
+    "onsend" => ["onsends", "onsent", "onsending", "onsent"],
+    "onset" => ["onsets", "onset", "onsetting", "onset"],
+    "ontake" => ["ontakes", "ontook", "ontaking", "ontaken"],
+    "oop" => ["oops", "ooped", "ooping", "ooped"],
+    "oopsie" => ["oopsies", "oopsied", "oopsieing", "oopsied"],
+    "orp" => ["orps", "orped", "orping", "orped"],
+    "outbar" => ["outbars", "outbarred", "outbarring", "outbarred"],
+    "outbear" => ["outbears", "outbore", "outbearing", "outborne"],
+    "outbeat" => ["outbeats", "outbeat", "outbeating", "outbeaten"],
+    "outbet" => ["outbets", "outbetted", "outbetting", "outbetted"],
+    "outbid" => ["outbids", "outbid", "outbidding", "outbid"],
+    "outbleed" => ["outbleeds", "outbled", "outbleeding", "outbled"],
+    "outblot" => ["outblots", "outblotted", "outblotting", "outblotted"],
+    "outblow" => ["outblows", "outblew", "outblowing", "outblown"],
+    "outbray" => ["outbrays", "outbrayed", "outbraying", "outbrayed"],
+    "outbreak" => ["outbreaks", "outbroke", "outbreaking", "outbroken"],
+    "outbreed" => ["outbreeds", "outbred", "outbreeding", "outbred"],
+    "outbring" => ["outbrings", "outbrought", "outbringing", "outbrought"],
+    "outbud" => ["outbuds", "outbudded", "outbudding", "outbudded"],
+    "outbuild" => ["outbuilds", "outbuilt", "outbuilding", "outbuilt"],
+    "outbullshit" => ["outbullshits", "outbullshitted", "outbullshitting", "outbullshitted"],
+    "outburp" => ["outburps", "outburped", "outburping", "outburped"],
+    "outburst" => ["outbursts", "outburst", "outbursting", "outburst"],
+    "outcast" => ["outcasts", "outcast", "outcasting", "outcast"],
+    "outcatch" => ["outcatches", "outcaught", "outcatching", "outcaught"],
+    "outcon" => ["outcons", "outconned", "outconning", "outconned"],
+    "outcreep" => ["outcreeps", "outcreeped", "outcreeping", "outcreeped"],
+    "outcreep2" => ["outcreeps", "outcrept", "outcreeping", "outcrept"],
+    "outcut" => ["outcuts", "outcut", "outcutting", "outcut"],
+    "outdeploy" => ["outdeploys", "outdeployed", "outdeploying", "outdeployed"],
+    "outdig" => ["outdigs", "outdug", "outdigging", "outdug"],
+    "outdin" => ["outdins", "outdinned", "outdinning", "outdinned"],
+    "outdo" => ["outdoes", "outdid", "outdoing", "outdone"],
+    "outdraw" => ["outdraws", "outdrew", "outdrawing", "outdrawn"],
+    "outdrink" => ["outdrinks", "outdrank", "outdrinking", "outdrunk"],
+    "outdrive" => ["outdrives", "outdrove", "outdriving", "outdriven"],
+    "outdwell" => ["outdwells", "outdwelt", "outdwelling", "outdwelt"],
+    "outeat" => ["outeats", "outate", "outeating", "outeaten"],
+    "outecho" => ["outechoes", "outechoed", "outechoing", "outechoed"],
+    "outfall" => ["outfalls", "outfell", "outfalling", "outfallen"],
+    "outfeel" => ["outfeels", "outfelt", "outfeeling", "outfelt"],
+    "outfib" => ["outfibs", "outfibbed", "outfibbing", "outfibbed"],
+    "outfind" => ["outfinds", "outfound", "outfinding", "outfound"],
+    "outfit" => ["outfits", "outfitted", "outfitting", "outfitted"],
+    "outflee" => ["outflees", "outfled", "outfleeing", "outfled"],
+    "outfling" => ["outflings", "outflung", "outflinging", "outflung"],
+    "outfly" => ["outflies", "outflew", "outflying", "outflown"],
+    "outfreeze" => ["outfreezes", "outfroze", "outfreezing", "outfrozen"],
+    "outgallop" => ["outgallops", "outgalloped", "outgalloping", "outgalloped"],
+    "outgas" => ["outgases", "outgassed", "outgassing", "outgassed"],
+    "outgay" => ["outgays", "outgayed", "outgaying", "outgayed"],
+    "outgive" => ["outgives", "outgave", "outgiving", "outgiven"],
+    "outgo" => ["outgoes", "outwent", "outgoing", "outgone"],
+    "outgrin" => ["outgrins", "outgrinned", "outgrinning", "outgrinned"],
+    "outgroup" => ["outgroups", "outgrouped", "outgrouping", "outgrouped"],
+    "outgrow" => ["outgrows", "outgrew", "outgrowing", "outgrown"],
+    "outgun" => ["outguns", "outgunned", "outgunning", "outgunned"],
+    "outhear" => ["outhears", "outheard", "outhearing", "outheard"],
+    "outhit" => ["outhits", "outhit", "outhitting", "outhit"],
+    "outhold" => ["outholds", "outheld", "outholding", "outheld"],
+    "outjockey" => ["outjockeys", "outjockeyed", "outjockeying", "outjockeyed"],
+    "outjourney" => ["outjourneys", "outjourneyed", "outjourneying", "outjourneyed"],
+    "outjump" => ["outjumps", "outjumped", "outjumping", "outjumped"],
+    "outjut" => ["outjuts", "outjutted", "outjutting", "outjutted"],
+    "outkeep" => ["outkeeps", "outkept", "outkeeping", "outkept"],
+    "outken" => ["outkens", "outkenned", "outkenning", "outkenned"],
+    "outknit" => ["outknits", "outknitted", "outknitting", "outknitted"],
+    "outlay" => ["outlays", "outlaid", "outlaying", "outlaid"],
+    "outlead" => ["outleads", "outled", "outleading", "outled"],
+    "outlean" => ["outleans", "outleant", "outleaning", "outleant"],
+    "outleap" => ["outleaps", "outleapt", "outleaping", "outleapt"],
+    "outlie" => ["outlies", "outlay", "outlying", "outlain"],
+    "outlie2" => ["outlies", "outlied", "outlying", "outlied"],
+    "outmagic" => ["outmagics", "outmagicked", "outmagicking", "outmagicked"],
+    "outman" => ["outmans", "outmanned", "outmanning", "outmanned"],
+    "outpay" => ["outpays", "outpaid", "outpaying", "outpaid"],
+    "outpeep" => ["outpeeps", "outpeeped", "outpeeping", "outpeeped"],
+    "outplan" => ["outplans", "outplanned", "outplanning", "outplanned"],
+    "outplay" => ["outplays", "outplayed", "outplaying", "outplayed"],
+    "outplod" => ["outplods", "outplodded", "outplodding", "outplodded"],
+    "outplot" => ["outplots", "outplotted", "outplotting", "outplotted"],
+    "outpomp" => ["outpomps", "outpomped", "outpomping", "outpomped"],
+    "outpray" => ["outprays", "outprayed", "outpraying", "outprayed"],
+    "outprogram" => ["outprograms", "outprogrammed", "outprogramming", "outprogrammed"],
+    "outpsych" => ["outpsychs", "outpsyched", "outpsyching", "outpsyched"],
+    "output" => ["outputs", "output", "outputting", "output"],
+    "outray" => ["outrays", "outrayed", "outraying", "outrayed"],
+    "outread" => ["outreads", "outread", "outreading", "outread"],
+    "outredd" => ["outredds", "outredd", "outredding", "outredd"],
+    "outride" => ["outrides", "outrode", "outriding", "outridden"],
+    "outring" => ["outrings", "outrang", "outringing", "outrung"],
+    "outrive" => ["outrives", "outrove", "outriving", "outriven"],
+    "outrun" => ["outruns", "outran", "outrunning", "outrun"],
+    "outscam" => ["outscams", "outscammed", "outscamming", "outscammed"],
+    "outscoop" => ["outscoops", "outscooped", "outscooping", "outscooped"],
+    "outsee" => ["outsees", "outsaw", "outseeing", "outseen"],
+    "outseek" => ["outseeks", "outsought", "outseeking", "outsought"],
+    "outsell" => ["outsells", "outsold", "outselling", "outsold"],
+    "outsend" => ["outsends", "outsent", "outsending", "outsent"],
+    "outset" => ["outsets", "outset", "outsetting", "outset"],
+    "outshake" => ["outshakes", "outshook", "outshaking", "outshaken"],
+    "outsharp" => ["outsharps", "outsharped", "outsharping", "outsharped"],
+    "outshine" => ["outshines", "outshone", "outshining", "outshone"],
+    "outshoot" => ["outshoots", "outshot", "outshooting", "outshot"],
+    "outshow" => ["outshows", "outshowed", "outshowing", "outshown"],
+    "outshut" => ["outshuts", "outshut", "outshutting", "outshut"],
+    "outsimp" => ["outsimps", "outsimped", "outsimping", "outsimped"],
+    "outsin" => ["outsins", "outsinned", "outsinning", "outsinned"],
+    "outsing" => ["outsings", "outsang", "outsinging", "outsung"],
+    "outsit" => ["outsits", "outsat", "outsitting", "outsat"],
+    "outskin" => ["outskins", "outskinned", "outskinning", "outskinned"],
+    "outsleep" => ["outsleeps", "outslept", "outsleeping", "outslept"],
+    "outslide" => ["outslides", "outslid", "outsliding", "outslid"],
+    "outsling" => ["outslings", "outslung", "outslinging", "outslung"],
+    "outslink" => ["outslinks", "outslunk", "outslinking", "outslunk"],
+    "outslut" => ["outsluts", "outslutted", "outslutting", "outslutted"],
+    "outsnob" => ["outsnobs", "outsnobbed", "outsnobbing", "outsnobbed"],
+    "outspan" => ["outspans", "outspanned", "outspanning", "outspanned"],
+    "outspeak" => ["outspeaks", "outspoke", "outspeaking", "outspoken"],
+    "outspeed" => ["outspeeds", "outsped", "outspeeding", "outsped"],
+    "outspend" => ["outspends", "outspent", "outspending", "outspent"],
+    "outspin" => ["outspins", "outspun", "outspinning", "outspun"],
+    "outspit" => ["outspits", "outspat", "outspitting", "outspat"],
+    "outspread" => ["outspreads", "outspread", "outspreading", "outspread"],
+    "outspring" => ["outsprings", "outsprang", "outspringing", "outsprung"],
+    "outsquat" => ["outsquats", "outsquatted", "outsquatting", "outsquatted"],
+    "outstand" => ["outstands", "outstood", "outstanding", "outstood"],
+    "outstat" => ["outstats", "outstatted", "outstatting", "outstatted"],
+    "outstatistic" => ["outstatistics", "outstatisticked", "outstatisticking", "outstatisticked"],
+    "outstay" => ["outstays", "outstayed", "outstaying", "outstayed"],
+    "outsteal" => ["outsteals", "outstole", "outstealing", "outstolen"],
+    "outsting" => ["outstings", "outstung", "outstinging", "outstung"],
+    "outstink" => ["outstinks", "outstank", "outstinking", "outstunk"],
+    "outstride" => ["outstrides", "outstrode", "outstriding", "outstridden"],
+    "outstrike" => ["outstrikes", "outstruck", "outstriking", "outstruck"],
+    "outstrut" => ["outstruts", "outstrutted", "outstrutting", "outstrutted"],
+    "outsum" => ["outsums", "outsummed", "outsumming", "outsummed"],
+    "outswear" => ["outswears", "outswore", "outswearing", "outsworn"],
+    "outsweat" => ["outsweats", "outsweat", "outsweating", "outsweat"],
+    "outsweep" => ["outsweeps", "outswept", "outsweeping", "outswept"],
+    "outswim" => ["outswims", "outswam", "outswimming", "outswum"],
+    "outswing" => ["outswings", "outswung", "outswinging", "outswung"],
+    "outtake" => ["outtakes", "outtook", "outtaking", "outtaken"],
+    "outtell" => ["outtells", "outtold", "outtelling", "outtold"],
+    "outthink" => ["outthinks", "outthought", "outthinking", "outthought"],
+    "outthrob" => ["outthrobs", "outthrobbed", "outthrobbing", "outthrobbed"],
+    "outthrow" => ["outthrows", "outthrew", "outthrowing", "outthrown"],
+    "outthrust" => ["outthrusts", "outthrust", "outthrusting", "outthrust"],
+    "outtrot" => ["outtrots", "outtrotted", "outtrotting", "outtrotted"],
+    "outtrump" => ["outtrumps", "outtrumped", "outtrumping", "outtrumped"],
+    "outvie" => ["outvies", "outvied", "outvying", "outvied"],
+    "outwake" => ["outwakes", "outwoke", "outwaking", "outwoken"],
+    "outwar" => ["outwars", "outwarred", "outwarring", "outwarred"],
+    "outwear" => ["outwears", "outwore", "outwearing", "outworn"],
+    "outweave" => ["outweaves", "outwove", "outweaving", "outwoven"],
+    "outweep" => ["outweeps", "outwept", "outweeping", "outwept"],
+    "outwin" => ["outwins", "outwon", "outwinning", "outwon"],
+    "outwind2" => ["outwinds", "outwound", "outwinding", "outwound"],
+    "outwit" => ["outwits", "outwitted", "outwitting", "outwitted"],
+    "outwring" => ["outwrings", "outwrung", "outwringing", "outwrung"],
+    "outwrite" => ["outwrites", "outwrote", "outwriting", "outwritten"],
+    "outyelp" => ["outyelps", "outyelped", "outyelping", "outyelped"],
+    "overamp" => ["overamps", "overamped", "overamping", "overamped"],
+    "overbar" => ["overbars", "overbarred", "overbarring", "overbarred"],
+    "overbear" => ["overbears", "overbore", "overbearing", "overborne"],
+    "overbeat" => ["overbeats", "overbeat", "overbeating", "overbeaten"],
+    "overbend" => ["overbends", "overbent", "overbending", "overbent"],
+    "overbet" => ["overbets", "overbet", "overbetting", "overbet"],
+    "overbid" => ["overbids", "overbid", "overbidding", "overbid"],
+    "overbide" => ["overbides", "overbode", "overbiding", "overbidden"],
+    "overbind" => ["overbinds", "overbound", "overbinding", "overbound"],
+    "overbite" => ["overbites", "overbit", "overbiting", "overbitten"],
+    "overbleed" => ["overbleeds", "overbled", "overbleeding", "overbled"],
+    "overblow" => ["overblows", "overblew", "overblowing", "overblown"],
+    "overbreak" => ["overbreaks", "overbroke", "overbreaking", "overbroken"],
+    "overbreed" => ["overbreeds", "overbred", "overbreeding", "overbred"],
+    "overbrim" => ["overbrims", "overbrimmed", "overbrimming", "overbrimmed"],
+    "overbuild" => ["overbuilds", "overbuilt", "overbuilding", "overbuilt"],
+    "overcast" => ["overcasts", "overcast", "overcasting", "overcast"],
+    "overcatch" => ["overcatches", "overcaught", "overcatching", "overcaught"],
+    "overclad" => ["overclads", "overclad", "overcladding", "overclad"],
+    "overclasp" => ["overclasps", "overclasped", "overclasping", "overclasped"],
+    "overcloy" => ["overcloys", "overcloyed", "overcloying", "overcloyed"],
+    "overclub" => ["overclubs", "overclubbed", "overclubbing", "overclubbed"],
+    "overcome" => ["overcomes", "overcame", "overcoming", "overcome"],
+    "overcommit" => ["overcommits", "overcommitted", "overcommitting", "overcommitted"],
+    "overcontrol" => ["overcontrols", "overcontrolled", "overcontrolling", "overcontrolled"],
+    "overcram" => ["overcrams", "overcrammed", "overcramming", "overcrammed"],
+    "overcreep" => ["overcreeps", "overcrept", "overcreeping", "overcrept"],
+    "overcut" => ["overcuts", "overcut", "overcutting", "overcut"],
+    "overdamp" => ["overdamps", "overdamped", "overdamping", "overdamped"],
+    "overdeal" => ["overdeals", "overdealt", "overdealing", "overdealt"],
+    "overdefer" => ["overdefers", "overdeferred", "overdeferring", "overdeferred"],
+    "overdevelop" => ["overdevelops", "overdeveloped", "overdeveloping", "overdeveloped"],
+    "overdo" => ["overdoes", "overdid", "overdoing", "overdone"],
+    "overdot" => ["overdots", "overdotted", "overdotting", "overdotted"],
+    "overdraw" => ["overdraws", "overdrew", "overdrawing", "overdrawn"],
+    "overdreep" => ["overdreeps", "overdreeped", "overdreeping", "overdreeped"],
+    "overdrink" => ["overdrinks", "overdrank", "overdrinking", "overdrunk"],
+    "overdrive" => ["overdrives", "overdrove", "overdriving", "overdriven"],
+    "overdub" => ["overdubs", "overdubbed", "overdubbing", "overdubbed"],
+    "overdye" => ["overdyes", "overdyed", "overdyeing", "overdyed"],
+    "overeat" => ["overeats", "overate", "overeating", "overeaten"],
+    "overegg" => ["overeggs", "overegged", "overegging", "overegged"],
+    "overemploy" => ["overemploys", "overemployed", "overemploying", "overemployed"],
+    "overenjoy" => ["overenjoys", "overenjoyed", "overenjoying", "overenjoyed"],
+    "overexcel" => ["overexcels", "overexcelled", "overexcelling", "overexcelled"],
+    "overeye" => ["overeyes", "overeyed", "overeyeing", "overeyed"],
+    "overfall" => ["overfalls", "overfell", "overfalling", "overfallen"],
+    "overfeed" => ["overfeeds", "overfed", "overfeeding", "overfed"],
+    "overfeel" => ["overfeels", "overfelt", "overfeeling", "overfelt"],
+    "overfit" => ["overfits", "overfitted", "overfitting", "overfitted"],
+    "overfly" => ["overflies", "overflew", "overflying", "overflown"],
+    "overformat" => ["overformats", "overformatted", "overformatting", "overformatted"],
+    "overfreeze" => ["overfreezes", "overfroze", "overfreezing", "overfrozen"],
+    "overfreight" => ["overfreights", "overfreighted", "overfreighting", "overfraught"],
+    "overfret" => ["overfrets", "overfretted", "overfretting", "overfretted"],
+    "overfulfil" => ["overfulfils", "overfulfilled", "overfulfilling", "overfulfilled"],
+    "overget" => ["overgets", "overgot", "overgetting", "overgot"],
+    "overgive" => ["overgives", "overgave", "overgiving", "overgiven"],
+    "overglut" => ["overgluts", "overglutted", "overglutting", "overglutted"],
+    "overgo" => ["overgoes", "overwent", "overgoing", "overgone"],
+    "overgrind" => ["overgrinds", "overground", "overgrinding", "overground"],
+    "overgrow" => ["overgrows", "overgrew", "overgrowing", "overgrown"],
+    "overguarantee" => ["overguarantees", "overguaranteed", "overguaranteeing", "overguaranteed"],
+    "overhang" => ["overhangs", "overhung", "overhanging", "overhung"],
+    "overheal2" => ["overheals", "overhole", "overhealing", "overholen"],
+    "overheap" => ["overheaps", "overheaped", "overheaping", "overheaped"],
+    "overhear" => ["overhears", "overheard", "overhearing", "overheard"],
+    "overheave" => ["overheaves", "overhove", "overheaving", "overhoven"],
+    "overhelp" => ["overhelps", "overhelped", "overhelping", "overhelped"],
+    "overhend" => ["overhends", "overhent", "overhending", "overhent"],
+    "overhent" => ["overhents", "overhent", "overhenting", "overhent"],
+    "overhie" => ["overhies", "overhied", "overhying", "overhied"],
+    "overhit" => ["overhits", "overhit", "overhitting", "overhit"],
+    "overhold" => ["overholds", "overheld", "overholding", "overheld"],
+    "overjoy" => ["overjoys", "overjoyed", "overjoying", "overjoyed"],
+    "overjump" => ["overjumps", "overjumped", "overjumping", "overjumped"],
+    "overjut" => ["overjuts", "overjutted", "overjutting", "overjutted"],
+    "overkeep" => ["overkeeps", "overkept", "overkeeping", "overkept"],
+    "overlay" => ["overlays", "overlaid", "overlaying", "overlaid"],
+    "overlead" => ["overleads", "overled", "overleading", "overled"],
+    "overlean" => ["overleans", "overleant", "overleaning", "overleant"],
+    "overleap" => ["overleaps", "overleaped", "overleaping", "overleaped"],
+    "overleave" => ["overleaves", "overleft", "overleaving", "overleft"],
+    "overlend" => ["overlends", "overlent", "overlending", "overlent"],
+    "overlie" => ["overlies", "overlay", "overlying", "overlain"],
+    "overlight" => ["overlights", "overlit", "overlighting", "overlit"],
+    "overloop" => ["overloops", "overlooped", "overlooping", "overlooped"],
+    "overmake" => ["overmakes", "overmade", "overmaking", "overmade"],
+    "overman" => ["overmans", "overmanned", "overmanning", "overmanned"],
+    "overnet" => ["overnets", "overnetted", "overnetting", "overnetted"],
+    "overnim" => ["overnims", "overnam", "overnimming", "overnum"],
+    "overpad" => ["overpads", "overpadded", "overpadding", "overpadded"],
+    "overpay" => ["overpays", "overpaid", "overpaying", "overpaid"],
+    "overpet" => ["overpets", "overpetted", "overpetting", "overpetted"],
+    "overplan" => ["overplans", "overplanned", "overplanning", "overplanned"],
+    "overplay" => ["overplays", "overplayed", "overplaying", "overplayed"],
+    "overplot" => ["overplots", "overplotted", "overplotting", "overplotted"],
+    "overpot" => ["overpots", "overpotted", "overpotting", "overpotted"],
+    "overprogram" => ["overprograms", "overprogrammed", "overprogramming", "overprogrammed"],
+    "overpump" => ["overpumps", "overpumped", "overpumping", "overpumped"],
+    "overput" => ["overputs", "overput", "overputting", "overput"],
+    "overram" => ["overrams", "overrammed", "overramming", "overrammed"],
+    "overread" => ["overreads", "overread", "overreading", "overread"],
+    "overren" => ["overrens", "overran", "overrenning", "overrun"],
+    "overrev" => ["overrevs", "overrevved", "overrevving", "overrevved"],
+    "override" => ["overrides", "overrode", "overriding", "overridden"],
+    "overring" => ["overrings", "overrang", "overringing", "overrung"],
+    "overrise" => ["overrises", "overrose", "overrising", "overrisen"],
+    "overrun" => ["overruns", "overran", "overrunning", "overrun"],
+    "oversay" => ["oversays", "oversaid", "oversaying", "oversaid"],
+    "overscrub" => ["overscrubs", "overscrubbed", "overscrubbing", "overscrubbed"],
+    "oversee" => ["oversees", "oversaw", "overseeing", "overseen"],
+    "oversell" => ["oversells", "oversold", "overselling", "oversold"],
+    "oversend" => ["oversends", "oversent", "oversending", "oversent"],
+    "overset" => ["oversets", "overset", "oversetting", "overset"],
+    "oversew" => ["oversews", "oversewed", "oversewing", "oversewn"],
+    "overshake" => ["overshakes", "overshook", "overshaking", "overshaken"],
+    "overshine" => ["overshines", "overshone", "overshining", "overshone"],
+    "overshoot" => ["overshoots", "overshot", "overshooting", "overshot"],
+    "overshow" => ["overshows", "overshowed", "overshowing", "overshown"],
+    "overshrink" => ["overshrinks", "overshrank", "overshrinking", "overshrunk"],
+    "oversing" => ["oversings", "oversang", "oversinging", "oversung"],
+    "oversit" => ["oversits", "oversat", "oversitting", "oversat"],
+    "overskim" => ["overskims", "overskimmed", "overskimming", "overskimmed"],
+    "oversleep" => ["oversleeps", "overslept", "oversleeping", "overslept"],
+    "overslide" => ["overslides", "overslid", "oversliding", "overslid"],
+    "oversow" => ["oversows", "oversowed", "oversowing", "oversown"],
+    "overspan" => ["overspans", "overspanned", "overspanning", "overspanned"],
+    "overspeak" => ["overspeaks", "overspoke", "overspeaking", "overspoken"],
+    "overspeed" => ["overspeeds", "oversped", "overspeeding", "oversped"],
+    "overspend" => ["overspends", "overspent", "overspending", "overspent"],
+    "overspin" => ["overspins", "overspun", "overspinning", "overspun"],
+    "oversplit" => ["oversplits", "oversplit", "oversplitting", "oversplit"],
+    "overspray" => ["oversprays", "oversprayed", "overspraying", "oversprayed"],
+    "overspread" => ["overspreads", "overspread", "overspreading", "overspread"],
+    "overspring" => ["oversprings", "oversprang", "overspringing", "oversprung"],
+    "overstamp" => ["overstamps", "overstamped", "overstamping", "overstamped"],
+    "overstand" => ["overstands", "overstood", "overstanding", "overstood"],
+    "overstay" => ["overstays", "overstayed", "overstaying", "overstayed"],
+    "oversteep" => ["oversteeps", "oversteeped", "oversteeping", "oversteeped"],
+    "overstir" => ["overstirs", "overstirred", "overstirring", "overstirred"],
+    "overstraw" => ["overstraws", "overstrawed", "overstrawing", "overstrawn"],
+    "overstrew" => ["overstrews", "overstrewed", "overstrewing", "overstrewn"],
+    "overstride" => ["overstrides", "overstrode", "overstriding", "overstrode"],
+    "overstrike" => ["overstrikes", "overstruck", "overstriking", "overstruck"],
+    "overstrive" => ["overstrives", "overstrove", "overstriving", "overstriven"],
+    "overstrow" => ["overstrows", "overstrowed", "overstrowing", "overstrown"],
+    "oversum" => ["oversums", "oversummed", "oversumming", "oversummed"],
+    "oversway" => ["oversways", "overswayed", "overswaying", "overswayed"],
+    "overswear" => ["overswears", "overswore", "overswearing", "oversworn"],
+    "oversweep" => ["oversweeps", "overswept", "oversweeping", "overswept"],
+    "overswim" => ["overswims", "overswam", "overswimming", "overswum"],
+    "overswing" => ["overswings", "overswung", "overswinging", "overswung"],
+    "overtake" => ["overtakes", "overtook", "overtaking", "overtaken"],
+    "overtan" => ["overtans", "overtanned", "overtanning", "overtanned"],
+    "overteach" => ["overteaches", "overtaught", "overteaching", "overtaught"],
+    "overtell" => ["overtells", "overtold", "overtelling", "overtold"],
+    "overthin" => ["overthins", "overthinned", "overthinning", "overthinned"],
+    "overthink" => ["overthinks", "overthought", "overthinking", "overthought"],
+    "overthrow" => ["overthrows", "overthrew", "overthrowing", "overthrown"],
+    "overtransmit" => ["overtransmits", "overtransmitted", "overtransmitting", "overtransmitted"],
+    "overtread" => ["overtreads", "overtrod", "overtreading", "overtrodden"],
+    "overtrim" => ["overtrims", "overtrimmed", "overtrimming", "overtrimmed"],
+    "overtrump" => ["overtrumps", "overtrumped", "overtrumping", "overtrumped"],
+    "overwear" => ["overwears", "overwore", "overwearing", "overworn"],
+    "overweep" => ["overweeps", "overwept", "overweeping", "overwept"],
+    "overwet" => ["overwets", "overwet", "overwetting", "overwet"],
+    "overwin" => ["overwins", "overwon", "overwinning", "overwon"],
+    "overwind" => ["overwinds", "overwound", "overwinding", "overwound"],
+    "overwit" => ["overwits", "overwitted", "overwitting", "overwitted"],
+    "overwithhold" => ["overwithholds", "overwithheld", "overwithholding", "overwithheld"],
+    "overworship" => ["overworships", "overworshiped", "overworshiping", "overworshiped"],
+    "overwrite" => ["overwrites", "overwrote", "overwriting", "overwritten"],
+    "packrat" => ["packrats", "packratted", "packratting", "packratted"],
+    "pacquet" => ["pacquets", "pacquetted", "pacquetting", "pacquetted"],
+    "pad" => ["pads", "padded", "padding", "padded"],
+    "pal" => ["pals", "palled", "palling", "palled"],
+    "palisado" => ["palisadoes", "palisadoed", "palisadoing", "palisadoed"],
+    "palp" => ["palps", "palped", "palping", "palped"],
+    "pam" => ["pams", "pammed", "pamming", "pammed"],
+    "pamp" => ["pamps", "pamped", "pamping", "pamped"],
+    "pan" => ["pans", "panned", "panning", "panned"],
+    "panic" => ["panics", "panicked", "panicking", "panicked"],
+    "panyar" => ["panyars", "panyarred", "panyarring", "panyarred"],
+    "par" => ["pars", "parred", "parring", "parred"],
+    "paratroop" => ["paratroops", "paratrooped", "paratrooping", "paratrooped"],
+    "parkrun" => ["parkruns", "parkran", "parkrunning", "parkrun"],
+    "parlay" => ["parlays", "parlayed", "parlaying", "parlayed"],
+    "parley" => ["parleys", "parleyed", "parleying", "parleyed"],
+    "parp" => ["parps", "parped", "parping", "parped"],
+    "parsley" => ["parsleys", "parsleyed", "parsleying", "parsleyed"],
+    "partake" => ["partakes", "partook", "partaking", "partaken"],
+    "partay" => ["partays", "partayed", "partaying", "partayed"],
+    "partial" => ["partials", "partialled", "partialing", "partialled"],
+    "pat" => ["pats", "patted", "patting", "patted"],
+    "pathfind" => ["pathfinds", "pathfound", "pathfinding", "pathfound"],
+    "patrol" => ["patrols", "patrolled", "patrolling", "patrolled"],
+    "pay" => ["pays", "paid", "paying", "paid"],
+    "pay2" => ["pays", "payed", "paying", "payed"],
+    "peanut" => ["peanuts", "peanutted", "peanutting", "peanutted"],
+    "pech" => ["pechs", "peched", "peching", "peched"],
+    "pedagog" => ["pedagogs", "pedagoged", "pedagoging", "pedagoged"],
+    "pedigree" => ["pedigrees", "pedigreed", "pedigreeing", "pedigreed"],
+    "pee" => ["pees", "peed", "peeing", "peed"],
+    "peep" => ["peeps", "peeped", "peeping", "peeped"],
+    "peercast" => ["peercasts", "peercast", "peercasting", "peercast"],
+    "pen" => ["pens", "penned", "penning", "penned"],
+    "perbreak" => ["perbreaks", "perbroke", "perbreaking", "perbroken"],
+    "permaban" => ["permabans", "permabanned", "permabanning", "permabanned"],
+    "permadie" => ["permadies", "permadied", "permadying", "permadied"],
+    "permastun" => ["permastuns", "permastunned", "permastunning", "permastunned"],
+    "permban" => ["permbans", "permbanned", "permbanning", "permbanned"],
+    "permit" => ["permits", "permitted", "permitting", "permitted"],
+    "pertake" => ["pertakes", "pertook", "pertaking", "pertaken"],
+    "pet" => ["pets", "pet", "petting", "petted"],
+    "pet2" => ["pets", "petted", "petting", "petted"],
+    "petsit" => ["petsits", "petsat", "petsitting", "petsat"],
+    "phantasie" => ["phantasies", "phantasied", "phantasying", "phantasied"],
+    "phoney" => ["phoneys", "phoneyed", "phoneying", "phoneyed"],
+    "phot" => ["phots", "photted", "photting", "photted"],
+    "photoemit" => ["photoemits", "photoemitted", "photoemitting", "photoemitted"],
+    "photoset" => ["photosets", "photoset", "photosetting", "photoset"],
+    "photosurvey" => ["photosurveys", "photosurveyed", "photosurveying", "photosurveyed"],
+    "phototypeset" => ["phototypesets", "phototypeset", "phototypesetting", "phototypeset"],
+    "phub" => ["phubs", "phubbed", "phubbing", "phubbed"],
+    "phut" => ["phuts", "phutted", "phutting", "phutted"],
+    "physic" => ["physics", "physicked", "physicking", "physicked"],
+    "pi" => ["pies", "pied", "piing", "pied"],
+    "picnic" => ["picnics", "picnicked", "picnicking", "picnicked"],
+    "pie" => ["pies", "pied", "pieing", "pied"],
+    "pigjump" => ["pigjumps", "pigjumped", "pigjumping", "pigjumped"],
+    "pigsit" => ["pigsits", "pigsat", "pigsitting", "pigsat"],
+    "pikey" => ["pikeys", "pikeyed", "pikeying", "pikeyed"],
+    "piledrive" => ["piledrives", "piledrove", "piledriving", "piledriven"],
+    "pimp" => ["pimps", "pimped", "pimping", "pimped"],
+    "pin" => ["pins", "pinned", "pinning", "pinned"],
+    "pinspot" => ["pinspots", "pinspotted", "pinspotting", "pinspotted"],
+    "pipeclay" => ["pipeclays", "pipeclayed", "pipeclaying", "pipeclayed"],
+    "pipet" => ["pipets", "pipetted", "pipetting", "pipetted"],
+    "pisstake" => ["pisstakes", "pisstook", "pisstaking", "pisstaken"],
+    "pit" => ["pits", "pitted", "pitting", "pitted"],
+    "pitapat" => ["pitapats", "pitapatted", "pitapatting", "pitapatted"],
+    "plan" => ["plans", "planned", "planning", "planned"],
+    "planespot" => ["planespots", "planespotted", "planespotting", "planespotted"],
+    "planogram" => ["planograms", "planogrammed", "planogramming", "planogrammed"],
+    "plantsit" => ["plantsits", "plantsat", "plantsitting", "plantsat"],
+    "plat" => ["plats", "platted", "platting", "platted"],
+    "play" => ["plays", "played", "playing", "played"],
+    "pledg" => ["pledges", "pledged", "pledging", "pledged"],
+    "plim" => ["plims", "plimmed", "plimming", "plimmed"],
+    "plod" => ["plods", "plodded", "plodding", "plodded"],
+    "plot" => ["plots", "plotted", "plotting", "plotted"],
+    "ploy" => ["ploys", "ployed", "ploying", "ployed"],
+    "plum" => ["plums", "plummed", "plumming", "plummed"],
+    "plump" => ["plumps", "plumped", "plumping", "plumped"],
+    "plus" => ["pluss", "plused", "plusing", "plused"],
+    "pod" => ["pods", "podded", "podding", "podded"],
+    "podcast" => ["podcasts", "podcast", "podcasting", "podcast"],
+    "poe" => ["poes", "poed", "poeing", "poed"],
+    "pointcast" => ["pointcasts", "pointcast", "pointcasting", "pointcast"],
+    "politic" => ["politics", "politicked", "politicking", "politicked"],
+    "polkadot" => ["polkadots", "polkadotted", "polkadotting", "polkadotted"],
+    "poly" => ["polys", "polyed", "polying", "polyed"],
+    "pomp" => ["pomps", "pomped", "pomping", "pomped"],
+    "pon" => ["pons", "ponned", "ponning", "ponned"],
+    "poop" => ["poops", "pooped", "pooping", "pooped"],
+    "portray" => ["portrays", "portrayed", "portraying", "portrayed"],
+    "portscan" => ["portscans", "portscanned", "portscanning", "portscanned"],
+    "postsynch" => ["postsynchs", "postsynched", "postsynching", "postsynched"],
+    "pot" => ["pots", "potted", "potting", "potted"],
+    "potato" => ["potatoes", "potatoed", "potatoing", "potatoed"],
+    "potshoot" => ["potshoots", "potshot", "potshooting", "potshot"],
+    "potshot" => ["potshots", "potshotted", "potshotting", "potshotted"],
+    "pourtray" => ["pourtrays", "pourtrayed", "pourtraying", "pourtrayed"],
+    "powerslam" => ["powerslams", "powerslammed", "powerslamming", "powerslammed"],
+    "poz" => ["pozzes", "pozzed", "pozzing", "pozzed"],
+    "praetermit" => ["praetermits", "praetermitted", "praetermitting", "praetermitted"],
+    "pratfall" => ["pratfalls", "pratfell", "pratfalling", "pratfallen"],
+    "pray" => ["prays", "prayed", "praying", "prayed"],
+    "pre" => ["pres", "pred", "preing", "pred"],
+    "preadmit" => ["preadmits", "preadmitted", "preadmitting", "preadmitted"],
+    "preallot" => ["preallots", "preallotted", "preallotting", "preallotted"],
+    "preamp" => ["preamps", "preamped", "preamping", "preamped"],
+    "prebend" => ["prebends", "prebent", "prebending", "prebent"],
+    "prebind" => ["prebinds", "prebound", "prebinding", "prebound"],
+    "preblow" => ["preblows", "preblew", "preblowing", "preblown"],
+    "prebreak" => ["prebreaks", "prebroke", "prebreaking", "prebroken"],
+    "prebuild" => ["prebuilds", "prebuilt", "prebuilding", "prebuilt"],
+    "prebut" => ["prebuts", "prebutted", "prebutting", "prebutted"],
+    "precast" => ["precasts", "precast", "precasting", "precast"],
+    "precel" => ["precels", "precelled", "precelling", "precelled"],
+    "prechoose" => ["prechooses", "prechose", "prechoosing", "prechosen"],
+    "precommit" => ["precommits", "precommitted", "precommitting", "precommitted"],
+    "preconvey" => ["preconveys", "preconveyed", "preconveying", "preconveyed"],
+    "precue" => ["precues", "precued", "precueing", "precued"],
+    "precum" => ["precums", "precame", "precumming", "precum"],
+    "precut" => ["precuts", "precut", "precutting", "precut"],
+    "predevelop" => ["predevelops", "predeveloped", "predeveloping", "predeveloped"],
+    "predub" => ["predubs", "predubbed", "predubbing", "predubbed"],
+    "predye" => ["predyes", "predyed", "predyeing", "predyed"],
+    "pree" => ["prees", "preed", "preeing", "preed"],
+    "preemploy" => ["preemploys", "preemployed", "preemploying", "preemployed"],
+    "prefab" => ["prefabs", "prefabbed", "prefabbing", "prefabbed"],
+    "prefeed" => ["prefeeds", "prefed", "prefeeding", "prefed"],
+    "prefer" => ["prefers", "preferred", "preferring", "preferred"],
+    "prefit" => ["prefits", "prefitted", "prefitting", "prefitted"],
+    "preformat" => ["preformats", "preformatted", "preformatting", "preformatted"],
+    "prefreeze" => ["prefreezes", "prefroze", "prefreezing", "prefrozen"],
+    "pregrab" => ["pregrabs", "pregrabbed", "pregrabbing", "pregrabbed"],
+    "pregrind" => ["pregrinds", "preground", "pregrinding", "preground"],
+    "pregroup" => ["pregroups", "pregrouped", "pregrouping", "pregrouped"],
+    "prelay" => ["prelays", "prelaid", "prelaying", "prelaid"],
+    "prelight" => ["prelights", "prelit", "prelighting", "prelit"],
+    "premake" => ["premakes", "premade", "premaking", "premade"],
+    "premit" => ["premits", "premitted", "premitting", "premitted"],
+    "preoutfit" => ["preoutfits", "preoutfitted", "preoutfitting", "preoutfitted"],
+    "prepay" => ["prepays", "prepaid", "prepaying", "prepaid"],
+    "preplan" => ["preplans", "preplanned", "preplanning", "preplanned"],
+    "preplay" => ["preplays", "preplayed", "preplaying", "preplayed"],
+    "preprogram" => ["preprograms", "preprogrammed", "preprogramming", "preprogrammed"],
+    "preread" => ["prereads", "preread", "prereading", "preread"],
+    "prerun" => ["preruns", "preran", "prerunning", "prerun"],
+    "prescan" => ["prescans", "prescanned", "prescanning", "prescanned"],
+    "presell" => ["presells", "presold", "preselling", "presold"],
+    "presend" => ["presends", "present", "presending", "present"],
+    "preset" => ["presets", "preset", "presetting", "preset"],
+    "presew" => ["presews", "presewed", "presewing", "presewn"],
+    "preshow" => ["preshows", "preshowed", "preshowing", "preshown"],
+    "preshrink" => ["preshrinks", "preshrank", "preshrinking", "preshrunk"],
+    "presplit" => ["presplits", "presplit", "presplitting", "presplit"],
+    "prestamp" => ["prestamps", "prestamped", "prestamping", "prestamped"],
+    "presubmit" => ["presubmits", "presubmitted", "presubmitting", "presubmitted"],
+    "preswab" => ["preswabs", "preswabbed", "preswabbing", "preswabbed"],
+    "pretan" => ["pretans", "pretanned", "pretanning", "pretanned"],
+    "pretell" => ["pretells", "pretold", "pretelling", "pretold"],
+    "pretermit" => ["pretermits", "pretermitted", "pretermitting", "pretermitted"],
+    "prethin" => ["prethins", "prethinned", "prethinning", "prethinned"],
+    "pretin" => ["pretins", "pretinned", "pretinning", "pretinned"],
+    "pretransmit" => ["pretransmits", "pretransmitted", "pretransmitting", "pretransmitted"],
+    "pretrim" => ["pretrims", "pretrimmed", "pretrimming", "pretrimmed"],
+    "prevet" => ["prevets", "prevetted", "prevetting", "prevetted"],
+    "prewet" => ["prewets", "prewet", "prewetting", "prewet"],
+    "prewrite" => ["prewrites", "prewrote", "prewriting", "prewritten"],
+    "prey" => ["preys", "preyed", "preying", "preyed"],
+    "prie" => ["pries", "pried", "prying", "pried"],
+    "prim" => ["prims", "primmed", "primming", "primmed"],
+    "primp" => ["primps", "primped", "primping", "primped"],
+    "prin" => ["prins", "prinned", "prinning", "prinned"],
+    "proc" => ["procs", "procced", "proccing", "procced"],
+    "prod" => ["prods", "prodded", "prodding", "prodded"],
+    "profer" => ["profers", "proferred", "proferring", "proferred"],
+    "program" => ["programs", "programmed", "programming", "programmed"],
+    "prolog" => ["prologs", "prologed", "prologing", "prologed"],
+    "prom" => ["proms", "prommed", "promming", "prommed"],
+    "proofread" => ["proofreads", "proofread", "proofreading", "proofread"],
+    "propel" => ["propels", "propelled", "propelling", "propelled"],
+    "psych" => ["psychs", "psyched", "psyching", "psyched"],
+    "pub" => ["pubs", "pubbed", "pubbing", "pubbed"],
+    "public" => ["publics", "publicked", "publicking", "publicked"],
+    "pubstomp" => ["pubstomps", "pubstomped", "pubstomping", "pubstomped"],
+    "pug2" => ["pugs", "puged", "puging", "puged"],
+    "pulley" => ["pulleys", "pulleyed", "pulleying", "pulleyed"],
+    "pulp" => ["pulps", "pulped", "pulping", "pulped"],
+    "pump" => ["pumps", "pumped", "pumping", "pumped"],
+    "pun" => ["puns", "punned", "punning", "punned"],
+    "puppysit" => ["puppysits", "puppysat", "puppysitting", "puppysat"],
+    "pur" => ["purs", "purred", "purring", "purred"],
+    "puree" => ["purees", "pureed", "pureeing", "pureed"],
+    "purvey" => ["purveys", "purveyed", "purveying", "purveyed"],
+    "pus" => ["pusses", "pussed", "pussing", "pussed"],
+    "pushpin" => ["pushpins", "pushpinned", "pushpinning", "pushpinned"],
+    "put" => ["puts", "put", "putting", "put"],
+    "putt2" => ["putts", "putt", "putting", "putt"],
+    "pvp" => ["pvps", "pvped", "pvping", "pvped"],
+    "qrp" => ["qrps", "qrped", "qrping", "qrped"],
+    "quab" => ["quabs", "quabbed", "quabbing", "quabbed"],
+    "quad" => ["quads", "quadded", "quadding", "quadded"],
+    "quadrat" => ["quadrats", "quadratted", "quadratting", "quadratted"],
+    "quaere" => ["quaeres", "quaered", "quaereing", "quaered"],
+    "quahaug" => ["quahaugs", "quahauged", "quahauging", "quahauged"],
+    "quar" => ["quars", "quarred", "quarring", "quarred"],
+    "quartersaw" => ["quartersaws", "quartersawed", "quartersawing", "quartersawn"],
+    "quat" => ["quats", "quatted", "quatting", "quatted"],
+    "quay" => ["quays", "quayed", "quaying", "quayed"],
+    "queep" => ["queeps", "queeped", "queeping", "queeped"],
+    "queue" => ["queues", "queued", "queueing", "queued"],
+    "quid" => ["quids", "quidded", "quidding", "quidded"],
+    "quit" => ["quits", "quit", "quitting", "quit"],
+    "quiz" => ["quizzes", "quizzed", "quizzing", "quizzed"],
+    "quob" => ["quobs", "quobbed", "quobbing", "quobbed"],
+    "quod" => ["quods", "quodded", "quodding", "quodded"],
+    "rabat" => ["rabats", "rabatted", "rabatting", "rabatted"],
+    "racebend" => ["racebends", "racebent", "racebending", "racebent"],
+    "radge" => ["radges", "radged", "radgin", "radged"],
+    "radioassay" => ["radioassays", "radioassayed", "radioassaying", "radioassayed"],
+    "radiobroadcast" => ["radiobroadcasts", "radiobroadcast", "radiobroadcasting", "radiobroadcast"],
+    "radiocast" => ["radiocasts", "radiocast", "radiocasting", "radiocast"],
+    "radioimmunoassay" => ["radioimmunoassays", "radioimmunoassayed", "radioimmunoassaying", "radioimmunoassayed"],
+    "radioscan" => ["radioscans", "radioscanned", "radioscanning", "radioscanned"],
+    "ragequit" => ["ragequits", "ragequit", "ragequitting", "ragequit"],
+    "ram" => ["rams", "rammed", "ramming", "rammed"],
+    "ramp" => ["ramps", "ramped", "ramping", "ramped"],
+    "ramrod" => ["ramrods", "ramrodded", "ramrodding", "ramrodded"],
+    "ramset" => ["ramsets", "ramsetted", "ramsetting", "ramsetted"],
+    "rappel" => ["rappels", "rappelled", "rappelling", "rappelled"],
+    "rasp" => ["rasps", "rasped", "rasping", "rasped"],
+    "rat" => ["rats", "ratted", "ratting", "ratted"],
+    "rataplan" => ["rataplans", "rataplanned", "rataplanning", "rataplanned"],
+    "ray" => ["rays", "rayed", "raying", "rayed"],
+    "razee" => ["razees", "razeed", "razeeing", "razeed"],
+    "reaccession" => ["reaccession", "reaccessioned", "reaccessioning", "reaccessioned"],
+    "reacquit" => ["reacquits", "reacquitted", "reacquitting", "reacquitted"],
+    "read" => ["reads", "read", "reading", "read"],
+    "readmit" => ["readmits", "readmitted", "readmitting", "readmitted"],
+    "reagree" => ["reagrees", "reagreed", "reagreeing", "reagreed"],
+    "reallot" => ["reallots", "reallotted", "reallotting", "reallotted"],
+    "realloy" => ["realloys", "realloyed", "realloying", "realloyed"],
+    "reap" => ["reaps", "reaped", "reaping", "reaped"],
+    "rearise" => ["rearises", "rearose", "rearising", "rearisen"],
+    "rearray" => ["rearrays", "rearrayed", "rearraying", "rearrayed"],
+    "reassay" => ["reassays", "reassayed", "reassaying", "reassayed"],
+    "reave2" => ["reaves", "reft", "reaving", "reft"],
+    "reawake" => ["reawakes", "reawoke", "reawaking", "reawoken"],
+    "reban" => ["rebans", "rebanned", "rebanning", "rebanned"],
+    "rebar" => ["rebars", "rebarred", "rebarring", "rebarred"],
+    "rebat" => ["rebats", "rebatted", "rebatting", "rebatted"],
+    "rebear" => ["rebears", "rebore", "rebearing", "reborne"],
+    "rebeat" => ["rebeats", "rebeat", "rebeating", "rebeaten"],
+    "rebecome" => ["rebecomes", "rebecame", "rebecoming", "rebecome"],
+    "rebed" => ["rebeds", "rebedded", "rebedding", "rebedded"],
+    "rebeget" => ["rebegets", "rebegot", "rebegetting", "rebegotten"],
+    "rebegin" => ["rebegins", "rebegan", "rebeginning", "rebegun"],
+    "rebehold" => ["rebeholds", "rebeheld", "rebeholding", "rebeheld"],
+    "rebel" => ["rebels", "rebelled", "rebelling", "rebelled"],
+    "rebend" => ["rebends", "rebent", "rebending", "rebent"],
+    "rebetray" => ["rebetrays", "rebetrayed", "rebetraying", "rebetrayed"],
+    "rebid" => ["rebids", "rebid", "rebidding", "rebid"],
+    "rebin" => ["rebins", "rebinned", "rebinning", "rebinned"],
+    "rebind" => ["rebinds", "rebound", "rebinding", "rebound"],
+    "rebite" => ["rebites", "rebit", "rebiting", "rebitten"],
+    "rebleed" => ["rebleeds", "rebled", "rebleeding", "rebled"],
+    "reblit" => ["reblits", "reblitted", "reblitting", "reblitted"],
+    "reblot" => ["reblots", "reblotted", "reblotting", "reblotted"],
+    "reblow" => ["reblows", "reblew", "reblowing", "reblown"],
+    "reblue" => ["reblues", "reblued", "reblueing", "reblued"],
+    "rebreak" => ["rebreaks", "rebroke", "rebreaking", "rebroken"],
+    "rebreed" => ["rebreeds", "rebred", "rebreeding", "rebred"],
+    "rebring" => ["rebrings", "rebrought", "rebringing", "rebrought"],
+    "rebroadcast" => ["rebroadcasts", "rebroadcast", "rebroadcasting", "rebroadcast"],
+    "rebud" => ["rebuds", "rebudded", "rebudding", "rebudded"],
+    "rebuild" => ["rebuilds", "rebuilt", "rebuilding", "rebuilt"],
+    "rebump" => ["rebumps", "rebumped", "rebumping", "rebumped"],
+    "reburst" => ["rebursts", "reburst", "rebursting", "reburst"],
+    "rebut" => ["rebuts", "rebutted", "rebutting", "rebutted"],
+    "rec" => ["recs", "recced", "reccing", "recced"],
+    "recal" => ["recals", "recalled", "recalling", "recalled"],
+    "recam" => ["recams", "recammed", "recamming", "recammed"],
+    "recan" => ["recans", "recanned", "recanning", "recanned"],
+    "recast" => ["recasts", "recast", "recasting", "recast"],
+    "recatalog" => ["recatalogs", "recataloged", "recataloging", "recataloged"],
+    "recatch" => ["recatches", "recaught", "recatching", "recaught"],
+    "recce" => ["recces", "recced", "recceing", "recced"],
+    "rechoose" => ["rechooses", "rechose", "rechoosing", "rechosen"],
+    "reclad" => ["reclads", "recladded", "recladding", "recladded"],
+    "reclamp" => ["reclamps", "reclamped", "reclamping", "reclamped"],
+    "reclasp" => ["reclasps", "reclasped", "reclasping", "reclasped"],
+    "recommit" => ["recommits", "recommitted", "recommitting", "recommitted"],
+    "recomp" => ["recomps", "recomped", "recomping", "recomped"],
+    "recompel" => ["recompels", "recompelled", "recompelling", "recompelled"],
+    "recon" => ["recons", "reconned", "reconning", "reconned"],
+    "reconfer" => ["reconfers", "reconferred", "reconferring", "reconferred"],
+    "recontrol" => ["recontrols", "recontrolled", "recontrolling", "recontrolled"],
+    "reconvey" => ["reconveys", "reconveyed", "reconveying", "reconveyed"],
+    "recoup" => ["recoups", "recouped", "recouping", "recouped"],
+    "recramp" => ["recramps", "recramped", "recramping", "recramped"],
+    "recur" => ["recurs", "recurred", "recurring", "recurred"],
+    "recut" => ["recuts", "recut", "recutting", "recut"],
+    "red" => ["reds", "redded", "redding", "redded"],
+    "redam" => ["redams", "redammed", "redamming", "redammed"],
+    "redd2" => ["redds", "redd", "redding", "redd"],
+    "rede" => ["redes", "red", "reding", "red"],
+    "redeal" => ["redeals", "redealt", "redealing", "redealt"],
+    "redecree" => ["redecrees", "redecreed", "redecreeing", "redecreed"],
+    "redefer" => ["redefers", "redeferred", "redeferring", "redeferred"],
+    "redelay" => ["redelays", "redelayed", "redelaying", "redelayed"],
+    "redeploy" => ["redeploys", "redeployed", "redeploying", "redeployed"],
+    "redestroy" => ["redestroys", "redestroyed", "redestroying", "redestroyed"],
+    "redevelop" => ["redevelops", "redeveloped", "redeveloping", "redeveloped"],
+    "rediagram" => ["rediagrams", "rediagrammed", "rediagramming", "rediagrammed"],
+    "redie" => ["redies", "redied", "redying", "redied"],
+    "redig" => ["redigs", "redug", "redigging", "redug"],
+    "redispel" => ["redispels", "redispelled", "redispelling", "redispelled"],
+    "redisplay" => ["redisplays", "redisplayed", "redisplaying", "redisplayed"],
+    "redistil" => ["redistils", "redistilled", "redistilling", "redistilled"],
+    "redlight" => ["redlights", "redlit", "redlighting", "redlit"],
+    "redo" => ["redoes", "redid", "redoing", "redone"],
+    "redon" => ["redons", "redonned", "redonning", "redonned"],
+    "redraw" => ["redraws", "redrew", "redrawing", "redrawn"],
+    "redrink" => ["redrinks", "redrank", "redrinking", "redrunk"],
+    "redrive" => ["redrives", "redrove", "redriving", "redriven"],
+    "redrum" => ["redrums", "redrummed", "redrumming", "redrummed"],
+    "redub" => ["redubs", "redubbed", "redubbing", "redubbed"],
+    "redump" => ["redumps", "redumped", "redumping", "redumped"],
+    "redye" => ["redyes", "redyed", "redyeing", "redyed"],
+    "ree" => ["rees", "reed", "reeing", "reed"],
+    "reeat" => ["reeats", "reate", "reeating", "reeaten"],
+    "reembed" => ["reembeds", "reembedded", "reembedding", "reembedded"],
+    "reemit" => ["reemits", "reemitted", "reemitting", "reemitted"],
+    "reemploy" => ["reemploys", "reemployed", "reemploying", "reemployed"],
+    "reenjoy" => ["reenjoys", "reenjoyed", "reenjoying", "reenjoyed"],
+    "reenrol" => ["reenrols", "reenrolled", "reenrolling", "reenrolled"],
+    "reenvelop" => ["reenvelops", "reenveloped", "reenveloping", "reenveloped"],
+    "reexpel" => ["reexpels", "reexpelled", "reexpelling", "reexpelled"],
+    "ref" => ["refs", "reffed", "reffing", "reffed"],
+    "refall" => ["refalls", "refell", "refalling", "refallen"],
+    "refan" => ["refans", "refanned", "refanning", "refanned"],
+    "refeed" => ["refeeds", "refed", "refeeding", "refed"],
+    "refeel" => ["refeels", "refelt", "refeeling", "refelt"],
+    "refel" => ["refels", "refelled", "refelling", "refelled"],
+    "refer" => ["refers", "referred", "referring", "referred"],
+    "referee" => ["referees", "refereed", "refereeing", "refereed"],
+    "refind" => ["refinds", "refound", "refinding", "refound"],
+    "refit" => ["refits", "refitted", "refitting", "refitted"],
+    "refly" => ["reflies", "reflew", "reflying", "reflown"],
+    "reforbid" => ["reforbids", "reforbade", "reforbidding", "reforbidden"],
+    "reforecast" => ["reforecasts", "reforecast", "reforecasting", "reforecast"],
+    "reforget" => ["reforgets", "reforgot", "reforgetting", "reforgotten"],
+    "reforgive" => ["reforgives", "reforgave", "reforgiving", "reforgiven"],
+    "reformat" => ["reformats", "reformatted", "reformatting", "reformatted"],
+    "refreeze" => ["refreezes", "refroze", "refreezing", "refrozen"],
+    "refret" => ["refrets", "refretted", "refretting", "refretted"],
+    "refugee" => ["refugees", "refugeed", "refugeeing", "refugeed"],
+    "regas" => ["regases", "regassed", "regassing", "regassed"],
+    "regen" => ["regens", "regenned", "regenning", "regenned"],
+    "reget" => ["regets", "regot", "regetting", "regot"],
+    "regive" => ["regives", "regave", "regiving", "regiven"],
+    "regrab" => ["regrabs", "regrabbed", "regrabbing", "regrabbed"],
+    "regram" => ["regrams", "regrammed", "regramming", "regrammed"],
+    "regrasp" => ["regrasps", "regrasped", "regrasping", "regrasped"],
+    "regret" => ["regrets", "regretted", "regretting", "regretted"],
+    "regrind" => ["regrinds", "reground", "regrinding", "reground"],
+    "regrit" => ["regrits", "regritted", "regritting", "regritted"],
+    "regroup" => ["regroups", "regrouped", "regrouping", "regrouped"],
+    "regrow" => ["regrows", "regrew", "regrowing", "regrown"],
+    "rehab" => ["rehabs", "rehabbed", "rehabbing", "rehabbed"],
+    "rehat" => ["rehats", "rehatted", "rehatting", "rehatted"],
+    "rehave" => ["rehas", "rehad", "rehaving", "rehad"],
+    "reheap" => ["reheaps", "reheaped", "reheaping", "reheaped"],
+    "rehear" => ["rehears", "reheard", "rehearing", "reheard"],
+    "rehem" => ["rehems", "rehemmed", "rehemming", "rehemmed"],
+    "rehew" => ["rehews", "rehewed", "rehewing", "rehewn"],
+    "rehide" => ["rehides", "rehid", "rehiding", "rehidden"],
+    "rehit" => ["rehits", "rehit", "rehitting", "rehit"],
+    "rehoe" => ["rehoes", "rehoed", "rehoeing", "rehoed"],
+    "rehoop" => ["rehoops", "rehooped", "rehooping", "rehooped"],
+    "reimpel" => ["reimpels", "reimpelled", "reimpelling", "reimpelled"],
+    "reincur" => ["reincurs", "reincurred", "reincurring", "reincurred"],
+    "reinfer" => ["reinfers", "reinferred", "reinferring", "reinferred"],
+    "reinput" => ["reinputs", "reinput", "reinputting", "reinput"],
+    "reinstil" => ["reinstils", "reinstilled", "reinstilling", "reinstilled"],
+    "reinter" => ["reinters", "reinterred", "reinterring", "reinterred"],
+    "rejapan" => ["rejapans", "rejapanned", "rejapanning", "rejapanned"],
+    "rejar" => ["rejars", "rejarred", "rejarring", "rejarred"],
+    "rejet" => ["rejets", "rejetted", "rejetting", "rejetted"],
+    "rejourney" => ["rejourneys", "rejourneyed", "rejourneying", "rejourneyed"],
+    "rejoy" => ["rejoys", "rejoyed", "rejoying", "rejoyed"],
+    "rejump" => ["rejumps", "rejumped", "rejumping", "rejumped"],
+    "rekey" => ["rekeys", "rekeyed", "rekeying", "rekeyed"],
+    "rekit" => ["rekits", "rekitted", "rekitting", "rekitted"],
+    "reknit" => ["reknits", "reknit", "reknitting", "reknit"],
+    "reknot" => ["reknots", "reknotted", "reknotting", "reknotted"],
+    "reknow" => ["reknows", "reknew", "reknowing", "reknown"],
+    "relamp" => ["relamps", "relamped", "relamping", "relamped"],
+    "relay" => ["relays", "relaid", "relaying", "relaid"],
+    "relay2" => ["relays", "relayed", "relaying", "relayed"],
+    "relead2" => ["releads", "reled", "releading", "reled"],
+    "releap" => ["releaps", "releaped", "releaping", "releaped"],
+    "relend" => ["relends", "relent", "relending", "relent"],
+    "relet" => ["relets", "relet", "reletting", "relet"],
+    "reloop" => ["reloops", "relooped", "relooping", "relooped"],
+    "relose" => ["reloses", "relost", "relosing", "relost"],
+    "remake" => ["remakes", "remade", "remaking", "remade"],
+    "reman" => ["remans", "remanned", "remanning", "remanned"],
+    "remeet" => ["remeets", "remet", "remeeting", "remet"],
+    "remit" => ["remits", "remitted", "remitting", "remitted"],
+    "renay" => ["renays", "renayed", "renaying", "renayed"],
+    "rend" => ["rends", "rent", "rending", "rent"],
+    "rendevous" => ["rendevous", "rendevoused", "rendevousing", "rendevoused"],
+    "reneg" => ["renegs", "reneged", "reneging", "reneged"],
+    "reney" => ["reneys", "reneyed", "reneying", "reneyed"],
+    "renib" => ["renibs", "renibbed", "renibbing", "renibbed"],
+    "renod" => ["renods", "renodded", "renodding", "renodded"],
+    "renvoy" => ["renvoys", "renvoyed", "renvoying", "renvoyed"],
+    "reoccur" => ["reoccurs", "reoccurred", "reoccurring", "reoccurred"],
+    "reorg" => ["reorgs", "reorged", "reorging", "reorged"],
+    "reoutfit" => ["reoutfits", "reoutfitted", "reoutfitting", "reoutfitted"],
+    "reoutput" => ["reoutputs", "reoutput", "reoutputting", "reoutput"],
+    "reovertake" => ["reovertakes", "reovertook", "reovertaking", "reovertaken"],
+    "repad" => ["repads", "repadded", "repadding", "repadded"],
+    "repartake" => ["repartakes", "repartook", "repartaking", "repartaken"],
+    "repartee" => ["repartees", "reparteed", "reparteeing", "reparteed"],
+    "repatrol" => ["repatrols", "repatrolled", "repatrolling", "repatrolled"],
+    "repay" => ["repays", "repaid", "repaying", "repaid"],
+    "repay2" => ["repays", "repayed", "repaying", "repayed"],
+    "repel" => ["repels", "repelled", "repelling", "repelled"],
+    "repen" => ["repens", "repenned", "repenning", "repenned"],
+    "repermit" => ["repermits", "repermitted", "repermitting", "repermitted"],
+    "repin" => ["repins", "repinned", "repinning", "repinned"],
+    "replan" => ["replans", "replanned", "replanning", "replanned"],
+    "replat" => ["replats", "replatted", "replatting", "replatted"],
+    "replay" => ["replays", "replayed", "replaying", "replayed"],
+    "replead" => ["repleads", "repled", "repleading", "repled"],
+    "replot" => ["replots", "replotted", "replotting", "replotted"],
+    "repod" => ["repods", "repodded", "repodding", "repodded"],
+    "repot" => ["repots", "repotted", "repotting", "repotted"],
+    "repray" => ["reprays", "reprayed", "repraying", "reprayed"],
+    "reprogram" => ["reprograms", "reprogrammed", "reprogramming", "reprogrammed"],
+    "repropel" => ["repropels", "repropelled", "repropelling", "repropelled"],
+    "repulp" => ["repulps", "repulped", "repulping", "repulped"],
+    "repump" => ["repumps", "repumped", "repumping", "repumped"],
+    "reput" => ["reputs", "reput", "reputting", "reput"],
+    "requit" => ["requits", "requitted", "requitting", "requitted"],
+    "requiz" => ["requizzes", "requizzed", "requizzing", "requizzed"],
+    "reread" => ["rereads", "reread", "rereading", "reread"],
+    "rerefer" => ["rerefers", "rereferred", "rereferring", "rereferred"],
+    "reremake" => ["reremakes", "reremade", "reremaking", "reremade"],
+    "rerise" => ["rerises", "rerose", "rerising", "rerisen"],
+    "rerub" => ["rerubs", "rerubbed", "rerubbing", "rerubbed"],
+    "rerun" => ["reruns", "reran", "rerunning", "rerun"],
+    "res" => ["resses", "ressed", "ressing", "ressed"],
+    "resaw" => ["resaws", "resawed", "resawing", "resawn"],
+    "resay" => ["resays", "resaid", "resaying", "resaid"],
+    "rescan" => ["rescans", "rescanned", "rescanning", "rescanned"],
+    "rescoop" => ["rescoops", "rescooped", "rescooping", "rescooped"],
+    "rescrub" => ["rescrubs", "rescrubbed", "rescrubbing", "rescrubbed"],
+    "resee" => ["resees", "resaw", "reseeing", "reseen"],
+    "reseek" => ["reseeks", "resought", "reseeking", "resought"],
+    "resell" => ["resells", "resold", "reselling", "resold"],
+    "resend" => ["resends", "resent", "resending", "resent"],
+    "reset" => ["resets", "reset", "resetting", "reset"],
+    "reset2" => ["resets", "resetted", "resetting", "resetted"],
+    "reshake" => ["reshakes", "reshook", "reshaking", "reshaken"],
+    "reshear" => ["reshears", "resheared", "reshearing", "reshorn"],
+    "reshim" => ["reshims", "reshimmed", "reshimming", "reshimmed"],
+    "reshoe" => ["reshoes", "reshoed", "reshoeing", "reshoed"],
+    "reshoot" => ["reshoots", "reshot", "reshooting", "reshot"],
+    "reshow" => ["reshows", "reshowed", "reshowing", "reshown"],
+    "reshrink" => ["reshrinks", "reshrank", "reshrinking", "reshrunk"],
+    "reshut" => ["reshuts", "reshut", "reshutting", "reshut"],
+    "resing" => ["resings", "resang", "resinging", "resung"],
+    "resink" => ["resinks", "resank", "resinking", "resunk"],
+    "resit" => ["resits", "resat", "resitting", "resat"],
+    "reskim" => ["reskims", "reskimmed", "reskimming", "reskimmed"],
+    "reskin" => ["reskins", "reskinned", "reskinning", "reskinned"],
+    "reslay" => ["reslays", "reslew", "reslaying", "reslain"],
+    "reslide" => ["reslides", "reslid", "resliding", "reslid"],
+    "resling" => ["reslings", "reslung", "reslinging", "reslung"],
+    "reslot" => ["reslots", "reslotted", "reslotting", "reslotted"],
+    "resnub" => ["resnubs", "resnubbed", "resnubbing", "resnubbed"],
+    "resoap" => ["resoaps", "resoaped", "resoaping", "resoaped"],
+    "resod" => ["resods", "resodded", "resodding", "resodded"],
+    "resow" => ["resows", "resowed", "resowing", "resown"],
+    "resp" => ["resps", "resped", "resping", "resped"],
+    "respeak" => ["respeaks", "respoke", "respeaking", "respoken"],
+    "respec" => ["respecs", "respecced", "respeccing", "respecced"],
+    "respend" => ["respends", "respent", "respending", "respent"],
+    "respin" => ["respins", "respun", "respinning", "respun"],
+    "resplit" => ["resplits", "resplit", "resplitting", "resplit"],
+    "respot" => ["respots", "respotted", "respotting", "respotted"],
+    "respray" => ["resprays", "resprayed", "respraying", "resprayed"],
+    "respread" => ["respreads", "respread", "respreading", "respread"],
+    "respring" => ["resprings", "resprang", "respringing", "resprung"],
+    "restamp" => ["restamps", "restamped", "restamping", "restamped"],
+    "restand" => ["restands", "restood", "restanding", "restood"],
+    "resteal" => ["resteals", "restole", "restealing", "restolen"],
+    "resteep" => ["resteeps", "resteeped", "resteeping", "resteeped"],
+    "restem" => ["restems", "restemmed", "restemming", "restemmed"],
+    "restick" => ["resticks", "restuck", "resticking", "restuck"],
+    "resting" => ["restings", "restung", "restinging", "restung"],
+    "restir" => ["restirs", "restirred", "restirring", "restirred"],
+    "restrike" => ["restrikes", "restruck", "restriking", "restruck"],
+    "restring" => ["restrings", "restrung", "restringing", "restrung"],
+    "restrive" => ["restrives", "restrove", "restriving", "restriven"],
+    "restucco" => ["restuccoes", "restuccoed", "restuccoing", "restuccoed"],
+    "restump" => ["restumps", "restumped", "restumping", "restumped"],
+    "resub" => ["resubs", "resubbed", "resubbing", "resubbed"],
+    "resubmit" => ["resubmits", "resubmitted", "resubmitting", "resubmitted"],
+    "resum" => ["resums", "resummed", "resumming", "resummed"],
+    "resurveil" => ["resurveils", "resurveilled", "resurveilling", "resurveilled"],
+    "resurvey" => ["resurveys", "resurveyed", "resurveying", "resurveyed"],
+    "reswear" => ["reswears", "reswore", "reswearing", "resworn"],
+    "resweep" => ["resweeps", "reswept", "resweeping", "reswept"],
+    "reswell" => ["reswells", "reswelled", "reswelling", "reswollen"],
+    "reswim" => ["reswims", "reswam", "reswimming", "reswum"],
+    "resynch" => ["resynchs", "resynched", "resynching", "resynched"],
+    "ret" => ["rets", "retted", "retting", "retted"],
+    "retake" => ["retakes", "retook", "retaking", "retaken"],
+    "retan" => ["retans", "retanned", "retanning", "retanned"],
+    "retar" => ["retars", "retarred", "retarring", "retarred"],
+    "retarmac" => ["retarmacs", "retarmacked", "retarmacking", "retarmacked"],
+    "retcon" => ["retcons", "retconned", "retconning", "retconned"],
+    "reteach" => ["reteaches", "retaught", "reteaching", "retaught"],
+    "retear" => ["retears", "retore", "retearing", "retorn"],
+    "retee" => ["retees", "reteed", "reteeing", "reteed"],
+    "retelecast" => ["retelecasts", "retelecast", "retelecasting", "retelecast"],
+    "retell" => ["retells", "retold", "retelling", "retold"],
+    "rethink" => ["rethinks", "rethought", "rethinking", "rethought"],
+    "rethrow" => ["rethrows", "rethrew", "rethrowing", "rethrown"],
+    "rethrust" => ["rethrusts", "rethrust", "rethrusting", "rethrust"],
+    "retie" => ["reties", "retied", "retying", "retied"],
+    "retin" => ["retins", "retinned", "retinning", "retinned"],
+    "retramp" => ["retramps", "retramped", "retramping", "retramped"],
+    "retransfer" => ["retransfers", "retransferred", "retransferring", "retransferred"],
+    "retransmit" => ["retransmits", "retransmitted", "retransmitting", "retransmitted"],
+    "retread2" => ["retreads", "retrod", "retreading", "retrodden"],
+    "retrim" => ["retrims", "retrimmed", "retrimming", "retrimmed"],
+    "retrocue" => ["retrocues", "retrocued", "retrocueing", "retrocued"],
+    "retrofit" => ["retrofits", "retrofitted", "retrofitting", "retrofitted"],
+    "retromod" => ["retromods", "retromodded", "retromodding", "retromodded"],
+    "retrotransfer" => ["retrotransfers", "retrotransferred", "retrotransferring", "retrotransferred"],
+    "retrue" => ["retrues", "retrued", "retrueing", "retrued"],
+    "reundercut" => ["reundercuts", "reundercut", "reundercutting", "reundercut"],
+    "reundergo" => ["reundergoes", "reunderwent", "reundergoing", "reundergone"],
+    "reunderstand" => ["reunderstands", "reunderstood", "reunderstanding", "reunderstood"],
+    "reundertake" => ["reundertakes", "reundertook", "reundertaking", "reundertaken"],
+    "reuptake" => ["reuptakes", "reuptook", "reuptaking", "reuptaken"],
+    "reusurp" => ["reusurps", "reusurped", "reusurping", "reusurped"],
+    "rev" => ["revs", "revved", "revving", "revved"],
+    "revamp" => ["revamps", "revamped", "revamping", "revamped"],
+    "revel2" => ["revels", "revelled", "revelling", "revelled"],
+    "reverie" => ["reveries", "reveried", "reverying", "reveried"],
+    "revet" => ["revets", "revetted", "revetting", "revetted"],
+    "revie" => ["revies", "revied", "revying", "revied"],
+    "rewake" => ["rewakes", "rewoke", "rewaking", "rewoken"],
+    "rewear" => ["rewears", "rewore", "rewearing", "reworn"],
+    "reweave" => ["reweaves", "rewove", "reweaving", "rewoven"],
+    "rewed" => ["reweds", "rewed", "rewedding", "rewed"],
+    "rewet" => ["rewets", "rewet", "rewetting", "rewet"],
+    "rewin" => ["rewins", "rewon", "rewinning", "rewon"],
+    "rewind" => ["rewinds", "rewound", "rewinding", "rewound"],
+    "rewithdraw" => ["rewithdraws", "rewithdrew", "rewithdrawing", "rewithdrawn"],
+    "rewring" => ["rewrings", "rewrung", "rewringing", "rewrung"],
+    "rewrite" => ["rewrites", "rewrote", "rewriting", "rewritten"],
+    "rez" => ["rezs", "rezed", "rezing", "rezed"],
+    "rib" => ["ribs", "ribbed", "ribbing", "ribbed"],
+    "rid" => ["rids", "rid", "ridding", "rid"],
+    "ride" => ["rides", "rode", "riding", "ridden"],
+    "rif2" => ["rifs", "riffed", "riffing", "riffed"],
+    "rim" => ["rims", "rimmed", "rimming", "rimmed"],
+    "rin" => ["rins", "ran", "rinning", "run"],
+    "ring2" => ["rings", "rang", "ringing", "rung"],
+    "ringlead" => ["ringleads", "ringled", "ringleading", "ringled"],
+    "rise" => ["rises", "rose", "rising", "risen"],
+    "risp" => ["risps", "risped", "risping", "risped"],
+    "rit" => ["rits", "ritted", "ritting", "ritted"],
+    "rize" => ["rizes", "roze", "rizing", "rizen"],
+    "rmgroup" => ["rmgroups", "rmgrouped", "rmgrouping", "rmgrouped"],
+    "roadie" => ["roadies", "roadied", "roadying", "roadied"],
+    "roadshow" => ["roadshows", "roadshowed", "roadshowing", "roadshown"],
+    "roap" => ["roaps", "roaped", "roaping", "roaped"],
+    "rob" => ["robs", "robbed", "robbing", "robbed"],
+    "robocast" => ["robocasts", "robocast", "robocasting", "robocast"],
+    "rockabye" => ["rockabyes", "rockabyed", "rockabyeing", "rockabyed"],
+    "rod" => ["rods", "rodded", "rodding", "rodded"],
+    "roentgenogram" => ["roentgenograms", "roentgenogrammed", "roentgenogramming", "roentgenogrammed"],
+    "roflstomp" => ["roflstomps", "roflstomped", "roflstomping", "roflstomped"],
+    "roleplay" => ["roleplays", "roleplayed", "roleplaying", "roleplayed"],
+    "romp" => ["romps", "romped", "romping", "romped"],
+    "ronne" => ["ronnes", "ronne", "ronning", "ronne"],
+    "roofie" => ["roofies", "roofied", "roofieing", "roofied"],
+    "rookie" => ["rookies", "rookied", "rookying", "rookied"],
+    "roop" => ["roops", "rooped", "rooping", "rooped"],
+    "rootkit" => ["rootkits", "rootkitted", "rootkitting", "rootkitted"],
+    "rot" => ["rots", "rotted", "rotting", "rotted"],
+    "rotisserie" => ["rotisseries", "rotisseried", "rotisserieing", "rotisseried"],
+    "roughcast" => ["roughcasts", "roughcast", "roughcasting", "roughcast"],
+    "roughdraw" => ["roughdraws", "roughdrew", "roughdrawing", "roughdrawn"],
+    "roup" => ["roups", "rouped", "rouping", "rouped"],
+    "route" => ["routes", "routed", "routeing", "routed"],
+    "rp" => ["rps", "rped", "rping", "rped"],
+    "rpg" => ["rpgs", "rpged", "rpging", "rpged"],
+    "rsvp" => ["rsvps", "rsvped", "rsvping", "rsvped"],
+    "rts" => ["rtss", "rtsed", "rtsing", "rtsed"],
+    "rub" => ["rubs", "rubbed", "rubbing", "rubbed"],
+    "rubberstamp" => ["rubberstamps", "rubberstamped", "rubberstamping", "rubberstamped"],
+    "rubric" => ["rubrics", "rubricked", "rubricking", "rubricked"],
+    "rud" => ["ruds", "rudded", "rudding", "rudded"],
+    "rufie" => ["rufies", "rufied", "rufieing", "rufied"],
+    "rump" => ["rumps", "rumped", "rumping", "rumped"],
+    "run" => ["runs", "ran", "running", "run"],
+    "rut" => ["ruts", "rutted", "rutting", "rutted"],
+    "sab" => ["sabs", "sabbed", "sabbing", "sabbed"],
+    "sac" => ["sacs", "sacked", "sacking", "sacked"],
+    "sad" => ["sads", "sadded", "sadding", "sadded"],
+    "safekeep" => ["safekeeps", "safekept", "safekeeping", "safekept"],
+    "sam" => ["sams", "sammed", "samming", "sammed"],
+    "sandcast" => ["sandcasts", "sandcast", "sandcasting", "sandcast"],
+    "sangaree" => ["sangarees", "sangareed", "sangareeing", "sangareed"],
+    "sashay" => ["sashays", "sashayed", "sashaying", "sashayed"],
+    "satcast" => ["satcasts", "satcast", "satcasting", "satcast"],
+    "saturday" => ["saturdays", "saturdayed", "saturdaying", "saturdayed"],
+    "saucepan" => ["saucepans", "saucepanned", "saucepanning", "saucepanned"],
+    "saute" => ["sautes", "sauteed", "sauteing", "sauteed"],
+    "sautee" => ["sautees", "sauteed", "sauteeing", "sauteed"],
+    "savescum" => ["savescums", "savescummed", "savescumming", "savescummed"],
+    "savvey" => ["savveys", "savveyed", "savveying", "savveyed"],
+    "say" => ["says", "said", "saying", "said"],
+    "say2" => ["says", "sayed", "saying", "sayed"],
+    "saye" => ["sayes", "saide", "sayeing", "saide"],
+    "scab" => ["scabs", "scabbed", "scabbing", "scabbed"],
+    "scallop" => ["scallops", "scalloped", "scalloping", "scalloped"],
+    "scalp" => ["scalps", "scalped", "scalping", "scalped"],
+    "scam" => ["scams", "scammed", "scamming", "scammed"],
+    "scamp" => ["scamps", "scamped", "scamping", "scamped"],
+    "scan" => ["scans", "scanned", "scanning", "scanned"],
+    "scar" => ["scars", "scarred", "scarring", "scarred"],
+    "scarp" => ["scarps", "scarped", "scarping", "scarped"],
+    "scat" => ["scats", "scatted", "scatting", "scatted"],
+    "scatterplot" => ["scatterplots", "scatterplotted", "scatterplotting", "scatterplotted"],
+    "schiz" => ["schizs", "schized", "schizing", "schized"],
+    "schlepp" => ["schlepps", "schlepped", "schlepping", "schlepped"],
+    "schloop" => ["schloops", "schlooped", "schlooping", "schlooped"],
+    "schlump" => ["schlumps", "schlumped", "schlumping", "schlumped"],
+    "schlurp" => ["schlurps", "schlurped", "schlurping", "schlurped"],
+    "scholarship" => ["scholarships", "scholarshiped", "scholarshiping", "scholarshiped"],
+    "schtoop" => ["schtoops", "schtooped", "schtooping", "schtooped"],
+    "scintiscan" => ["scintiscans", "scintiscanned", "scintiscanning", "scintiscanned"],
+    "scob" => ["scobs", "scobbed", "scobbing", "scobbed"],
+    "scollop" => ["scollops", "scolloped", "scolloping", "scolloped"],
+    "scomfit" => ["scomfits", "scomfit", "scomfiting", "scomfit"],
+    "scon" => ["scons", "sconned", "sconning", "sconned"],
+    "scoog" => ["scoogs", "scooged", "scooging", "scooged"],
+    "scoop" => ["scoops", "scooped", "scooping", "scooped"],
+    "scoug" => ["scougs", "scouged", "scouging", "scouged"],
+    "scrab" => ["scrabs", "scrabbed", "scrabbing", "scrabbed"],
+    "scram2" => ["scrams", "scrammed", "scramming", "scrammed"],
+    "scramp" => ["scramps", "scramped", "scramping", "scramped"],
+    "scran" => ["scrans", "scranned", "scranning", "scranned"],
+    "scrat" => ["scrats", "scratted", "scratting", "scratted"],
+    "scree" => ["screes", "screed", "screeing", "screed"],
+    "screengrab" => ["screengrabs", "screengrabbed", "screengrabbing", "screengrabbed"],
+    "screenplay" => ["screenplays", "screenplayed", "screenplaying", "screenplayed"],
+    "screenshoot" => ["screenshoots", "screenshot", "screenshooting", "screenshot"],
+    "screenshot" => ["screenshots", "screenshotted", "screenshotting", "screenshotted"],
+    "screenwrite" => ["screenwrites", "screenwrote", "screenwriting", "screenwritten"],
+    "screwdrive" => ["screwdrives", "screwdrove", "screwdriving", "screwdriven"],
+    "scrim" => ["scrims", "scrimmed", "scrimming", "scrimmed"],
+    "scrimp" => ["scrimps", "scrimped", "scrimping", "scrimped"],
+    "scrob" => ["scrobs", "scrobbed", "scrobbing", "scrobbed"],
+    "scroop" => ["scroops", "scrooped", "scrooping", "scrooped"],
+    "scrub" => ["scrubs", "scrubbed", "scrubbing", "scrubbed"],
+    "scrum" => ["scrums", "scrummed", "scrumming", "scrummed"],
+    "scrump" => ["scrumps", "scrumped", "scrumping", "scrumped"],
+    "scud" => ["scuds", "scudded", "scudding", "scudded"],
+    "sculp" => ["sculps", "sculped", "sculping", "sculped"],
+    "scum" => ["scums", "scummed", "scumming", "scummed"],
+    "scun" => ["scuns", "scunned", "scunning", "scunned"],
+    "scur" => ["scurs", "scurred", "scurring", "scurred"],
+    "scut" => ["scuts", "scut", "scutting", "scut"],
+    "sed" => ["seds", "sedded", "sedding", "sedded"],
+    "see" => ["sees", "saw", "seeing", "seen"],
+    "seech" => ["seeches", "sought", "seeching", "sought"],
+    "seek" => ["seeks", "sought", "seeking", "sought"],
+    "seep" => ["seeps", "seeped", "seeping", "seeped"],
+    "segue" => ["segues", "segued", "segueing", "segued"],
+    "segway" => ["segways", "segwayed", "segwaying", "segwayed"],
+    "selfie" => ["selfies", "selfied", "selfying", "selfied"],
+    "selfpub" => ["selfpubs", "selfpubbed", "selfpubbing", "selfpubbed"],
+    "sell" => ["sells", "sold", "selling", "sold"],
+    "send" => ["sends", "sent", "sending", "sent"],
+    "serogroup" => ["serogroups", "serogrouped", "serogrouping", "serogrouped"],
+    "set" => ["sets", "set", "setting", "set"],
+    "set2" => ["sets", "setted", "setting", "setted"],
+    "setnet" => ["setnets", "setnetted", "setnetting", "setnetted"],
+    "sew2" => ["sews", "sewed", "sewing", "sewn"],
+    "shab" => ["shabs", "shabbed", "shabbing", "shabbed"],
+    "shadowban" => ["shadowbans", "shadowbanned", "shadowbanning", "shadowbanned"],
+    "shadowcast" => ["shadowcasts", "shadowcast", "shadowcasting", "shadowcast"],
+    "shake" => ["shakes", "shook", "shaking", "shaken"],
+    "shall" => ["shall", "should", "shalling", "should"],
+    "sham" => ["shams", "shammed", "shamming", "shammed"],
+    "shambok" => ["shamboks", "shambokked", "shambokking", "shambokked"],
+    "shamoy" => ["shamoys", "shamoyed", "shamoying", "shamoyed"],
+    "shanghae" => ["shanghaes", "shanghaed", "shanghaeing", "shanghaed"],
+    "sharp" => ["sharps", "sharped", "sharping", "sharped"],
+    "sharpshoot" => ["sharpshoots", "sharpshot", "sharpshooting", "sharpshot"],
+    "shazam" => ["shazams", "shazammed", "shazamming", "shazammed"],
+    "shear" => ["shears", "sheared", "shearing", "shorn"],
+    "shed" => ["sheds", "shed", "shedding", "shed"],
+    "shed2" => ["sheds", "shedded", "shedding", "shedded"],
+    "shellac" => ["shellacs", "shellacked", "shellacking", "shellacked"],
+    "shend" => ["shends", "shent", "shending", "shent"],
+    "shende" => ["shendes", "shent", "shending", "shent"],
+    "shet" => ["shets", "shetted", "shetting", "shetted"],
+    "shew2" => ["shews", "shewed", "shewing", "shewn"],
+    "shh" => ["shhes", "shhed", "shhing", "shhed"],
+    "shid" => ["shids", "shid", "shidding", "shid"],
+    "shie" => ["shies", "shied", "shieing", "shied"],
+    "shikar" => ["shikars", "shikarred", "shikarring", "shikarred"],
+    "shim" => ["shims", "shimmed", "shimming", "shimmed"],
+    "shimmey" => ["shimmeys", "shimmeyed", "shimmeying", "shimmeyed"],
+    "shin" => ["shins", "shinned", "shinning", "shinned"],
+    "shine2" => ["shines", "shone", "shining", "shone"],
+    "shir" => ["shirs", "shirred", "shirring", "shirred"],
+    "shit" => ["shits", "shat", "shitting", "shat"],
+    "shitcan" => ["shitcans", "shitcanned", "shitcanning", "shitcanned"],
+    "shiv" => ["shivs", "shivved", "shivving", "shivved"],
+    "shivaree" => ["shivarees", "shivareed", "shivareeing", "shivareed"],
+    "shivari" => ["shivaries", "shivaried", "shivariing", "shivaried"],
+    "shlepp" => ["shlepps", "shlepped", "shlepping", "shlepped"],
+    "shlump" => ["shlumps", "shlumped", "shlumping", "shlumped"],
+    "shlurp" => ["shlurps", "shlurped", "shlurping", "shlurped"],
+    "shnor" => ["shnors", "shnorred", "shnorring", "shnorred"],
+    "shoe" => ["shoes", "shod", "shoeing", "shod"],
+    "shoop" => ["shoops", "shooped", "shooping", "shooped"],
+    "shoot" => ["shoots", "shot", "shooting", "shot"],
+    "shopsteal" => ["shopsteals", "shopstole", "shopstealing", "shopstolen"],
+    "shortcut" => ["shortcuts", "shortcutted", "shortcutting", "shortcutted"],
+    "shot" => ["shots", "shotted", "shotting", "shotted"],
+    "shotgun" => ["shotguns", "shotgunned", "shotgunning", "shotgunned"],
+    "should2" => ["should", "shoulded", "shoulding", "shoulded"],
+    "shovelbum" => ["shovelbums", "shovelbummed", "shovelbumming", "shovelbummed"],
+    "show" => ["shows", "showed", "showing", "shown"],
+    "showjump" => ["showjumps", "showjumped", "showjumping", "showjumped"],
+    "showrun" => ["showruns", "showran", "showrunning", "showrun"],
+    "shralp" => ["shralps", "shralped", "shralping", "shralped"],
+    "shram" => ["shrams", "shrammed", "shramming", "shrammed"],
+    "shred" => ["shreds", "shredded", "shredding", "shredded"],
+    "shrim" => ["shrims", "shrimmed", "shrimming", "shrimmed"],
+    "shrimp" => ["shrimps", "shrimped", "shrimping", "shrimped"],
+    "shrink" => ["shrinks", "shrank", "shrinking", "shrunk"],
+    "shrinke" => ["shrinkes", "shrank", "shrinking", "shrunke"],
+    "shrive" => ["shrives", "shrove", "shriving", "shriven"],
+    "shrub" => ["shrubs", "shrubbed", "shrubbing", "shrubbed"],
+    "shrump" => ["shrumps", "shrumped", "shrumping", "shrumped"],
+    "shun" => ["shuns", "shunned", "shunning", "shunned"],
+    "shut" => ["shuts", "shut", "shutting", "shut"],
+    "shxt" => ["shxts", "shxt", "shxtting", "shxt"],
+    "sib" => ["sibs", "sibbed", "sibbing", "sibbed"],
+    "sic" => ["sics", "sicced", "siccing", "sicced"],
+    "sidebar" => ["sidebars", "sidebarred", "sidebarring", "sidebarred"],
+    "sidecast" => ["sidecasts", "sidecast", "sidecasting", "sidecast"],
+    "sie" => ["sies", "sied", "sying", "sied"],
+    "sightread" => ["sightreads", "sightread", "sightreading", "sightread"],
+    "sightsee" => ["sightsees", "sightsaw", "sightseeing", "sightseen"],
+    "silo" => ["siloes", "siloed", "siloing", "siloed"],
+    "sim" => ["sims", "simmed", "simming", "simmed"],
+    "simp" => ["simps", "simped", "simping", "simped"],
+    "simulcast" => ["simulcasts", "simulcast", "simulcasting", "simulcast"],
+    "simulpub" => ["simulpubs", "simulpubbed", "simulpubbing", "simulpubbed"],
+    "sin" => ["sins", "sinned", "sinning", "sinned"],
+    "sing" => ["sings", "sang", "singing", "sung"],
+    "singe" => ["singes", "sange", "singing", "sunge"],
+    "singe2" => ["singes", "singed", "singeing", "singed"],
+    "sink" => ["sinks", "sank", "sinking", "sunk"],
+    "sir" => ["sirs", "sirred", "sirring", "sirred"],
+    "sirup" => ["sirups", "siruped", "siruping", "siruped"],
+    "sit" => ["sits", "sat", "sitting", "sat"],
+    "siteban" => ["sitebans", "sitebanned", "sitebanning", "sitebanned"],
+    "sjambok" => ["sjamboks", "sjambokked", "sjambokking", "sjambokked"],
+    "sk" => ["sks", "sked", "skg", "sked"],
+    "sked" => ["skeds", "skedded", "skedding", "skedded"],
+    "skee" => ["skees", "skeed", "skeeing", "skeed"],
+    "skelloch" => ["skellochs", "skelloched", "skelloching", "skelloched"],
+    "skelp" => ["skelps", "skelped", "skelping", "skelped"],
+    "sken" => ["skens", "skenned", "skenning", "skenned"],
+    "skibidi" => ["skibidis", "skibidied", "skibiding", "skibidied"],
+    "skibob" => ["skibobs", "skibobbed", "skibobbing", "skibobbed"],
+    "skid" => ["skids", "skidded", "skidding", "skidded"],
+    "skim" => ["skims", "skimmed", "skimming", "skimmed"],
+    "skimp" => ["skimps", "skimped", "skimping", "skimped"],
+    "skin" => ["skins", "skinned", "skinning", "skinned"],
+    "skit" => ["skits", "skitted", "skitting", "skitted"],
+    "skol" => ["skols", "skolled", "skolling", "skolled"],
+    "skreich" => ["skreichs", "skreiched", "skreiching", "skreiched"],
+    "skrimp" => ["skrimps", "skrimped", "skrimping", "skrimped"],
+    "skud" => ["skuds", "skudded", "skudding", "skudded"],
+    "skum" => ["skums", "skummed", "skumming", "skummed"],
+    "skywrite" => ["skywrites", "skywrote", "skywriting", "skywritten"],
+    "slab" => ["slabs", "slabbed", "slabbing", "slabbed"],
+    "slam" => ["slams", "slammed", "slamming", "slammed"],
+    "slashdot" => ["slashdots", "slashdotted", "slashdotting", "slashdotted"],
+    "slat" => ["slats", "slatted", "slatting", "slatted"],
+    "slay" => ["slays", "slew", "slaying", "slain"],
+    "sled" => ["sleds", "sledded", "sledding", "sledded"],
+    "sleep" => ["sleeps", "slept", "sleeping", "slept"],
+    "sleepe" => ["sleepes", "slepte", "sleeping", "slepte"],
+    "sleepeat" => ["sleepeats", "sleepate", "sleepeating", "sleepeaten"],
+    "sleepwrite" => ["sleepwrites", "sleepwrote", "sleepwriting", "sleepwritten"],
+    "slerp" => ["slerps", "slerped", "slerping", "slerped"],
+    "sley" => ["sleys", "sleyed", "sleying", "sleyed"],
+    "slg" => ["slgs", "slged", "slging", "slged"],
+    "slide" => ["slides", "slid", "sliding", "slid"],
+    "slim" => ["slims", "slimmed", "slimming", "slimmed"],
+    "sling" => ["slings", "slung", "slinging", "slung"],
+    "slingshoot" => ["slingshoots", "slingshot", "slingshooting", "slingshotten"],
+    "slingshot" => ["slingshots", "slingshotted", "slingshotting", "slingshotted"],
+    "slink" => ["slinks", "slunk", "slinking", "slunk"],
+    "slit" => ["slits", "slit", "slitting", "slit"],
+    "slite" => ["slites", "slit", "sliting", "slit"],
+    "slive2" => ["slives", "slove", "sliving", "sliven"],
+    "slivercast" => ["slivercasts", "slivercast", "slivercasting", "slivercast"],
+    "slob" => ["slobs", "slobbed", "slobbing", "slobbed"],
+    "slorp" => ["slorps", "slorped", "slorping", "slorped"],
+    "slot" => ["slots", "slotted", "slotting", "slotted"],
+    "slowplay" => ["slowplays", "slowplayed", "slowplaying", "slowplayed"],
+    "slub" => ["slubs", "slubbed", "slubbing", "slubbed"],
+    "slum" => ["slums", "slummed", "slumming", "slummed"],
+    "slump" => ["slumps", "slumped", "slumping", "slumped"],
+    "slur" => ["slurs", "slurred", "slurring", "slurred"],
+    "slurp" => ["slurps", "slurped", "slurping", "slurped"],
+    "slut" => ["sluts", "slutted", "slutting", "slutted"],
+    "smeb" => ["smebs", "smebbed", "smebbing", "smebbed"],
+    "smh" => ["smhes", "smhed", "smhing", "smhed"],
+    "smight" => ["smights", "smote", "smighting", "smoten"],
+    "smit" => ["smits", "smitted", "smitting", "smitted"],
+    "smite" => ["smites", "smote", "smiting", "smitten"],
+    "smof" => ["smofs", "smoffed", "smoffing", "smoffed"],
+    "smur" => ["smurs", "smurred", "smurring", "smurred"],
+    "smut" => ["smuts", "smutted", "smutting", "smutted"],
+    "snam" => ["snams", "snammed", "snamming", "snammed"],
+    "snapchat" => ["snapchats", "snapchatted", "snapchatting", "snapchatted"],
+    "snapshot" => ["snapshots", "snapshotted", "snapshotting", "snapshotted"],
+    "snar" => ["snars", "snarred", "snarring", "snarred"],
+    "sneap" => ["sneaps", "sneaped", "sneaping", "sneaped"],
+    "sneb" => ["snebs", "snebbed", "snebbing", "snebbed"],
+    "sned" => ["sneds", "snedded", "snedding", "snedded"],
+    "snee" => ["snees", "sneed", "sneeing", "sneed"],
+    "snet" => ["snets", "snetted", "snetting", "snetted"],
+    "snib" => ["snibs", "snibbed", "snibbing", "snibbed"],
+    "snie" => ["snies", "snied", "snying", "snied"],
+    "snoop" => ["snoops", "snooped", "snooping", "snooped"],
+    "snot" => ["snots", "snotted", "snotting", "snotted"],
+    "snowgo" => ["snowgoes", "snowgoed", "snowgoing", "snowgoed"],
+    "snowshoe" => ["snowshoes", "snowshoed", "snowshoeing", "snowshoed"],
+    "snub" => ["snubs", "snubbed", "snubbing", "snubbed"],
+    "soap" => ["soaps", "soaped", "soaping", "soaped"],
+    "sob" => ["sobs", "sobbed", "sobbing", "sobbed"],
+    "sod" => ["sods", "sodded", "sodding", "sodded"],
+    "sodcast" => ["sodcasts", "sodcast", "sodcasting", "sodcast"],
+    "softmod" => ["softmods", "softmodded", "softmodding", "softmodded"],
+    "softsoap" => ["softsoaps", "softsoaped", "softsoaping", "softsoaped"],
+    "softsub" => ["softsubs", "softsubbed", "softsubbing", "softsubbed"],
+    "sojourney" => ["sojourneys", "sojourneyed", "sojourneying", "sojourneyed"],
+    "somerset" => ["somersets", "somersetted", "somersetting", "somersetted"],
+    "son" => ["sons", "sonned", "sonning", "sonned"],
+    "songwrite" => ["songwrites", "songwrote", "songwriting", "songwritten"],
+    "sonogram" => ["sonograms", "sonogrammed", "sonogramming", "sonogrammed"],
+    "soogee" => ["soogees", "soogeed", "soogeeing", "soogeed"],
+    "soogie" => ["soogies", "soogied", "soogieing", "soogied"],
+    "soothsay" => ["soothsays", "soothsaid", "soothsaying", "soothsaid"],
+    "sortie" => ["sorties", "sortied", "sortieing", "sortied"],
+    "sot" => ["sots", "sotted", "sotting", "sotted"],
+    "souffle" => ["souffles", "souffled", "souffleing", "souffled"],
+    "soup" => ["soups", "souped", "souping", "souped"],
+    "southsay" => ["southsays", "southsaid", "southsaying", "southsaid"],
+    "sow" => ["sows", "sowed", "sowing", "sown"],
+    "soy" => ["soys", "soyed", "soying", "soyed"],
+    "spacegram" => ["spacegrams", "spacegrammed", "spacegramming", "spacegrammed"],
+    "spae" => ["spaes", "spaed", "spaeing", "spaed"],
+    "spaie" => ["spaies", "spaied", "spaying", "spaied"],
+    "spaik" => ["spaiks", "spoke", "spaiking", "spoke"],
+    "spam" => ["spams", "spammed", "spamming", "spammed"],
+    "span" => ["spans", "spanned", "spanning", "spanned"],
+    "spar" => ["spars", "sparred", "sparring", "sparred"],
+    "spat" => ["spats", "spatted", "spatting", "spatted"],
+    "spay" => ["spays", "spayed", "spaying", "spayed"],
+    "spaye" => ["spayes", "spayed", "spayeing", "spayed"],
+    "spaz" => ["spazzes", "spazzed", "spazzing", "spazzed"],
+    "speak" => ["speaks", "spoke", "speaking", "spoken"],
+    "speake" => ["speakes", "spoke", "speaking", "spoke"],
+    "speechread" => ["speechreads", "speechread", "speechreading", "speechread"],
+    "speed" => ["speeds", "sped", "speeding", "sped"],
+    "speedread" => ["speedreads", "speedread", "speedreading", "speedread"],
+    "speedrun" => ["speedruns", "speedran", "speedrunning", "speedrun"],
+    "speld" => ["spelds", "speld", "spelding", "speld"],
+    "spellbind" => ["spellbinds", "spellbound", "spellbinding", "spellbound"],
+    "spellcast" => ["spellcasts", "spellcast", "spellcasting", "spellcast"],
+    "spend" => ["spends", "spent", "spending", "spent"],
+    "sperg" => ["spergs", "sperged", "sperging", "sperged"],
+    "spet" => ["spets", "spetted", "spetting", "spetted"],
+    "spey" => ["speys", "speyed", "speying", "speyed"],
+    "spiderweb" => ["spiderwebs", "spiderwebbed", "spiderwebbing", "spiderwebbed"],
+    "spie" => ["spies", "spied", "spying", "spied"],
+    "spin" => ["spins", "spun", "spinning", "spun"],
+    "spincast" => ["spincasts", "spincast", "spincasting", "spincast"],
+    "spit" => ["spits", "spat", "spitting", "spat"],
+    "spit2" => ["spits", "spitted", "spitting", "spitted"],
+    "splat" => ["splats", "splatted", "splatting", "splatted"],
+    "splay" => ["splays", "splayed", "splaying", "splayed"],
+    "splet" => ["splets", "spletted", "spletting", "spletted"],
+    "split" => ["splits", "split", "splitting", "split"],
+    "spod" => ["spods", "spodded", "spodding", "spodded"],
+    "spoonfeed" => ["spoonfeeds", "spoonfed", "spoonfeeding", "spoonfed"],
+    "spot" => ["spots", "spotted", "spotting", "spotted"],
+    "sprat" => ["sprats", "spratted", "spratting", "spratted"],
+    "spray" => ["sprays", "sprayed", "spraying", "sprayed"],
+    "spread" => ["spreads", "spread", "spreading", "spread"],
+    "spred" => ["spreds", "spred", "spredding", "spred"],
+    "spree" => ["sprees", "spreed", "spreeing", "spreed"],
+    "spring2" => ["springs", "sprang", "springing", "sprung"],
+    "sprit" => ["sprits", "spritted", "spritting", "spritted"],
+    "sprue" => ["sprues", "sprued", "sprueing", "sprued"],
+    "spud" => ["spuds", "spudded", "spudding", "spudded"],
+    "spuilzie" => ["spuilzies", "spuilzied", "spuilzieing", "spuilzied"],
+    "spulye" => ["spulyes", "spulyed", "spulyeing", "spulyed"],
+    "spulyie" => ["spulyies", "spulyied", "spulyieing", "spulyied"],
+    "spulzie" => ["spulzies", "spulzied", "spulzieing", "spulzied"],
+    "spur" => ["spurs", "spurred", "spurring", "spurred"],
+    "spye" => ["spyes", "spyed", "spyeing", "spyed"],
+    "squab" => ["squabs", "squabbed", "squabbing", "squabbed"],
+    "squad" => ["squads", "squadded", "squadding", "squadded"],
+    "squat" => ["squats", "squatted", "squatting", "squatted"],
+    "squee" => ["squees", "squeed", "squeeing", "squeed"],
+    "squeegee" => ["squeegees", "squeegeed", "squeegeeing", "squeegeed"],
+    "squib" => ["squibs", "squibbed", "squibbing", "squibbed"],
+    "squid" => ["squids", "squidded", "squidding", "squidded"],
+    "squilgee" => ["squilgees", "squilgeed", "squilgeeing", "squilgeed"],
+    "squillgee" => ["squillgees", "squillgeed", "squillgeeing", "squillgeed"],
+    "squir" => ["squirs", "squirred", "squirring", "squirred"],
+    "squit" => ["squits", "squitted", "squitting", "squitted"],
+    "squiz" => ["squizzes", "squizzed", "squizzing", "squizzed"],
+    "stab" => ["stabs", "stabbed", "stabbing", "stabbed"],
+    "stallfeed" => ["stallfeeds", "stallfed", "stallfeeding", "stallfed"],
+    "stam" => ["stams", "stammed", "stamming", "stammed"],
+    "stamp" => ["stamps", "stamped", "stamping", "stamped"],
+    "stan" => ["stans", "stanned", "stanning", "stanned"],
+    "stand" => ["stands", "stood", "standing", "stood"],
+    "standby" => ["standbys", "standbyed", "standbying", "standbyed"],
+    "star" => ["stars", "starred", "starring", "starred"],
+    "startscum" => ["startscums", "startscummed", "startscumming", "startscummed"],
+    "stat" => ["stats", "statted", "statting", "statted"],
+    "statistic" => ["statistics", "statisticked", "statisticking", "statisticked"],
+    "stay" => ["stays", "stayed", "staying", "stayed"],
+    "steal" => ["steals", "stole", "stealing", "stolen"],
+    "steale" => ["steales", "stole", "stealing", "stolen"],
+    "stealthban" => ["stealthbans", "stealthbanned", "stealthbanning", "stealthbanned"],
+    "steelman" => ["steelmans", "steelmanned", "steelmanning", "steelmanned"],
+    "steep" => ["steeps", "steeped", "steeping", "steeped"],
+    "stem" => ["stems", "stemmed", "stemming", "stemmed"],
+    "stet" => ["stets", "stetted", "stetting", "stetted"],
+    "stick2" => ["sticks", "stuck", "sticking", "stuck"],
+    "sticke" => ["stickes", "stuck", "sticking", "stuck"],
+    "stilp" => ["stilps", "stilped", "stilping", "stilped"],
+    "stim" => ["stims", "stimmed", "stimming", "stimmed"],
+    "sting" => ["stings", "stung", "stinging", "stung"],
+    "stink" => ["stinks", "stank", "stinking", "stunk"],
+    "stir" => ["stirs", "stirred", "stirring", "stirred"],
+    "stob" => ["stobs", "stobbed", "stobbing", "stobbed"],
+    "stomach" => ["stomachs", "stomached", "stomaching", "stomached"],
+    "stomp" => ["stomps", "stomped", "stomping", "stomped"],
+    "stoop" => ["stoops", "stooped", "stooping", "stooped"],
+    "stot" => ["stots", "stotted", "stotting", "stotted"],
+    "stott" => ["stotts", "stotted", "stottin", "stotted"],
+    "stoup" => ["stoups", "stouped", "stouping", "stouped"],
+    "stram" => ["strams", "strammed", "stramming", "strammed"],
+    "stranglehold" => ["strangleholds", "strangleheld", "strangleholding", "strangleheld"],
+    "straphang" => ["straphangs", "straphung", "straphanging", "straphung"],
+    "stravaig" => ["stravaigs", "stravaiged", "stravaiging", "stravaiged"],
+    "strawman" => ["strawmans", "strawmanned", "strawmanning", "strawmanned"],
+    "strawpedo" => ["strawpedoes", "strawpedoed", "strawpedoing", "strawpedoed"],
+    "stray" => ["strays", "strayed", "straying", "strayed"],
+    "strew" => ["strews", "strewed", "strewing", "strewn"],
+    "stride" => ["strides", "strode", "striding", "stridden"],
+    "strike" => ["strikes", "struck", "striking", "struck"],
+    "strikebreak" => ["strikebreaks", "strikebroke", "strikebreaking", "strikebroken"],
+    "strikethrough" => ["strikesthrough", "struckthrough", "strikethroughing", "struckthrough"],
+    "strim" => ["strims", "strimmed", "strimming", "strimmed"],
+    "string" => ["strings", "strung", "stringing", "strung"],
+    "strive" => ["strives", "strove", "striving", "striven"],
+    "strow" => ["strows", "strowed", "strowing", "strown"],
+    "stroy" => ["stroys", "stroyed", "stroying", "stroyed"],
+    "strub" => ["strubs", "strubbed", "strubbing", "strubbed"],
+    "strum" => ["strums", "strummed", "strumming", "strummed"],
+    "strut" => ["struts", "strutted", "strutting", "strutted"],
+    "stryde" => ["strydes", "strode", "stryding", "strode"],
+    "stub" => ["stubs", "stubbed", "stubbing", "stubbed"],
+    "stud" => ["studs", "studded", "studding", "studded"],
+    "studie" => ["studies", "studied", "studying", "studied"],
+    "stuf" => ["stufs", "stuffed", "stuffing", "stuffed"],
+    "stum" => ["stums", "stummed", "stumming", "stummed"],
+    "stump" => ["stumps", "stumped", "stumping", "stumped"],
+    "stun" => ["stuns", "stunned", "stunning", "stunned"],
+    "stur" => ["sturs", "sturred", "sturring", "sturred"],
+    "stut" => ["stuts", "stutted", "stutting", "stutted"],
+    "stymie" => ["stymies", "stymied", "stymieing", "stymied"],
+    "sub" => ["subs", "subbed", "subbing", "subbed"],
+    "subgroup" => ["subgroups", "subgrouped", "subgrouping", "subgrouped"],
+    "subinfer" => ["subinfers", "subinferred", "subinferring", "subinferred"],
+    "sublet" => ["sublets", "sublet", "subletting", "sublet"],
+    "submit" => ["submits", "submitted", "submitting", "submitted"],
+    "subnet" => ["subnets", "subnetted", "subnetting", "subnetted"],
+    "subplot" => ["subplots", "subplotted", "subplotting", "subplotted"],
+    "subset" => ["subsets", "subsetted", "subsetting", "subsetted"],
+    "subsublet" => ["subsublets", "subsublet", "subsubletting", "subsublet"],
+    "subunderlet" => ["subunderlets", "subunderlet", "subunderletting", "subunderlet"],
+    "subway" => ["subways", "subwayed", "subwaying", "subwayed"],
+    "sum" => ["sums", "summed", "summing", "summed"],
+    "summerset" => ["summersets", "summersetted", "summersetting", "summersetted"],
+    "sump" => ["sumps", "sumped", "sumping", "sumped"],
+    "sun" => ["suns", "sunned", "sunning", "sunned"],
+    "sunday" => ["sundays", "sundayed", "sundaying", "sundayed"],
+    "sunset" => ["sunsets", "sunsetted", "sunsetting", "sunsetted"],
+    "suntan" => ["suntans", "suntanned", "suntanning", "suntanned"],
+    "superchat" => ["superchats", "superchatted", "superchatting", "superchatted"],
+    "superexcel" => ["superexcels", "superexcelled", "superexcelling", "superexcelled"],
+    "superfat" => ["superfats", "superfatted", "superfatting", "superfatted"],
+    "superlie" => ["superlies", "superlay", "superlying", "superlain"],
+    "supersell" => ["supersells", "supersold", "superselling", "supersold"],
+    "superset" => ["supersets", "supersetted", "supersetting", "supersetted"],
+    "supershed" => ["supersheds", "supershed", "supershedding", "supershed"],
+    "superspread" => ["superspreads", "superspread", "superspreading", "superspread"],
+    "surbeat" => ["surbeats", "surbeated", "surbeating", "surbeaten"],
+    "surbed" => ["surbeds", "surbedded", "surbedding", "surbedded"],
+    "surcloy" => ["surcloys", "surcloyed", "surcloying", "surcloyed"],
+    "surfcast" => ["surfcasts", "surfcast", "surfcasting", "surfcast"],
+    "surfel" => ["surfels", "surfelled", "surfelling", "surfelled"],
+    "surmit" => ["surmits", "surmitted", "surmitting", "surmitted"],
+    "surpay" => ["surpays", "surpaid", "surpaying", "surpaid"],
+    "surrebut" => ["surrebuts", "surrebutted", "surrebutting", "surrebutted"],
+    "survay" => ["survays", "survayed", "survaying", "survayed"],
+    "surveil" => ["surveils", "surveilled", "surveilling", "surveilled"],
+    "survey" => ["surveys", "surveyed", "surveying", "surveyed"],
+    "sus" => ["susses", "sussed", "sussing", "sussed"],
+    "swab" => ["swabs", "swabbed", "swabbing", "swabbed"],
+    "swaip" => ["swaips", "swaiped", "swaiping", "swaiped"],
+    "swallie" => ["swallies", "swallied", "swallying", "swallied"],
+    "swally" => ["swallys", "swallied", "swallying", "swallied"],
+    "swamp" => ["swamps", "swamped", "swamping", "swamped"],
+    "swan" => ["swans", "swanned", "swanning", "swanned"],
+    "swat" => ["swats", "swatted", "swatting", "swatted"],
+    "sway" => ["sways", "swayed", "swaying", "swayed"],
+    "swear2" => ["swears", "swore", "swearing", "sworn"],
+    "sweare" => ["sweares", "swore", "swearing", "sworne"],
+    "sweb" => ["swebs", "swebbed", "swebbing", "swebbed"],
+    "sweep" => ["sweeps", "swept", "sweeping", "swept"],
+    "swell" => ["swells", "swelled", "swelling", "swollen"],
+    "swike" => ["swikes", "swoke", "swiking", "swicken"],
+    "swim" => ["swims", "swam", "swimming", "swum"],
+    "swing" => ["swings", "swung", "swinging", "swung"],
+    "swink" => ["swinks", "swank", "swinking", "swunk"],
+    "swive" => ["swives", "swove", "swiving", "swiven"],
+    "swob" => ["swobs", "swobbed", "swobbing", "swobbed"],
+    "swoop" => ["swoops", "swooped", "swooping", "swooped"],
+    "swot" => ["swots", "swotted", "swotting", "swotted"],
+    "synch" => ["synchs", "synched", "synching", "synched"],
+    "syrup" => ["syrups", "syruped", "syruping", "syruped"],
+    "tab" => ["tabs", "tabbed", "tabbing", "tabbed"],
+    "tailride" => ["tailrides", "tailrode", "tailriding", "tailridden"],
+    "tailspin" => ["tailspins", "tailspun", "tailspinning", "tailspun"],
+    "tak" => ["taks", "teuk", "takkin", "takken"],
+    "take" => ["takes", "took", "taking", "taken"],
+    "talebear" => ["talebears", "talebore", "talebearing", "taleborne"],
+    "tamp" => ["tamps", "tamped", "tamping", "tamped"],
+    "tan" => ["tans", "tanned", "tanning", "tanned"],
+    "tango" => ["tangoes", "tangoed", "tangoing", "tangoed"],
+    "tannoy" => ["tannoys", "tannoyed", "tannoying", "tannoyed"],
+    "taphold" => ["tapholds", "tapheld", "tapholding", "tapheld"],
+    "tar" => ["tars", "tarred", "tarring", "tarred"],
+    "tarmac" => ["tarmacs", "tarmacked", "tarmacking", "tarmacked"],
+    "tarp" => ["tarps", "tarped", "tarping", "tarped"],
+    "tarpit" => ["tarpits", "tarpitted", "tarpitting", "tarpitted"],
+    "tat" => ["tats", "tatted", "tatting", "tatted"],
+    "taxicab" => ["taxicabs", "taxicabbed", "taxicabbing", "taxicabbed"],
+    "taxpay" => ["taxpays", "taxpaid", "taxpaying", "taxpaid"],
+    "teach" => ["teaches", "taught", "teaching", "taught"],
+    "tear2" => ["tears", "tore", "tearing", "torn"],
+    "teargas" => ["teargases", "teargassed", "teargassing", "teargassed"],
+    "tech" => ["techs", "teched", "teching", "teched"],
+    "ted" => ["teds", "tedded", "tedding", "tedded"],
+    "tee" => ["tees", "teed", "teeing", "teed"],
+    "teehee" => ["teehees", "teeheed", "teeheeing", "teeheed"],
+    "teensit" => ["teensits", "teensat", "teensitting", "teensat"],
+    "teep" => ["teeps", "teeped", "teeping", "teeped"],
+    "teepee" => ["teepees", "teepeed", "teepeeing", "teepeed"],
+    "teetee" => ["teetees", "teeteed", "teeteeing", "teeteed"],
+    "tehee" => ["tehees", "teheed", "teheeing", "teheed"],
+    "tek" => ["teks", "took", "teking", "tekken"],
+    "telebroadcast" => ["telebroadcasts", "telebroadcast", "telebroadcasting", "telebroadcast"],
+    "telecast" => ["telecasts", "telecast", "telecasting", "telecast"],
+    "telecine" => ["telecines", "telecined", "telecineing", "telecined"],
+    "telecontrol" => ["telecontrols", "telecontrolled", "telecontrolling", "telecontrolled"],
+    "telegram" => ["telegrams", "telegrammed", "telegramming", "telegrammed"],
+    "teletransmit" => ["teletransmits", "teletransmitted", "teletransmitting", "teletransmitted"],
+    "tell" => ["tells", "told", "telling", "told"],
+    "telnet" => ["telnets", "telnetted", "telnetting", "telnetted"],
+    "temp" => ["temps", "temped", "temping", "temped"],
+    "tempban" => ["tempbans", "tempbanned", "tempbanning", "tempbanned"],
+    "terp" => ["terps", "terped", "terping", "terped"],
+    "testbed" => ["testbeds", "testbedded", "testbedding", "testbedded"],
+    "testilie" => ["testilies", "testilied", "testilying", "testilied"],
+    "thanksgive" => ["thanksgives", "thanksgave", "thanksgiving", "thanksgiven"],
+    "thee" => ["thees", "theed", "theeing", "theed"],
+    "thesp" => ["thesps", "thesped", "thesping", "thesped"],
+    "they" => ["theys", "theyed", "theying", "theyed"],
+    "thin" => ["thins", "thinned", "thinning", "thinned"],
+    "thinck" => ["thincks", "thought", "thincking", "thought"],
+    "think" => ["thinks", "thought", "thinking", "thought"],
+    "thinke" => ["thinkes", "thought", "thinking", "thought"],
+    "thoroughgo" => ["thoroughgoes", "thoroughwent", "thoroughgoing", "thoroughgone"],
+    "thoughtcast" => ["thoughtcasts", "thoughtcast", "thoughtcasting", "thoughtcast"],
+    "threap" => ["threaps", "threaped", "threaping", "threaped"],
+    "thrid" => ["thrids", "thridded", "thridding", "thridded"],
+    "thring" => ["thrings", "thrang", "thringing", "thrung"],
+    "thrive" => ["thrives", "throve", "thriving", "thriven"],
+    "throb" => ["throbs", "throbbed", "throbbing", "throbbed"],
+    "throe" => ["throes", "throed", "throeing", "throed"],
+    "throw" => ["throws", "threw", "throwing", "thrown"],
+    "thrum" => ["thrums", "thrummed", "thrumming", "thrummed"],
+    "thrump" => ["thrumps", "thrumped", "thrumping", "thrumped"],
+    "thrust" => ["thrusts", "thrust", "thrusting", "thrust"],
+    "thud" => ["thuds", "thudded", "thudding", "thudded"],
+    "thumbshot" => ["thumbshots", "thumbshotted", "thumbshotting", "thumbshotted"],
+    "thump" => ["thumps", "thumped", "thumping", "thumped"],
+    "thunderstrike" => ["thunderstrikes", "thunderstruck", "thunderstriking", "thunderstricken"],
+    "tic" => ["tics", "ticced", "ticcing", "ticced"],
+    "tie" => ["ties", "tied", "tying", "tied"],
+    "tiebreak" => ["tiebreaks", "tiebroke", "tiebreaking", "tiebroken"],
+    "timekeep" => ["timekeeps", "timekept", "timekeeping", "timekept"],
+    "timeslot" => ["timeslots", "timeslotted", "timeslotting", "timeslotted"],
+    "timestamp" => ["timestamps", "timestamped", "timestamping", "timestamped"],
+    "timestomp" => ["timestomps", "timestomped", "timestomping", "timestomped"],
+    "tin" => ["tins", "tinned", "tinning", "tinned"],
+    "tinsel2" => ["tinsels", "tinselled", "tinselling", "tinselled"],
+    "tippytoe" => ["tippytoes", "tippytoed", "tippytoeing", "tippytoed"],
+    "tiptoe" => ["tiptoes", "tiptoed", "tiptoeing", "tiptoed"],
+    "tit" => ["tits", "titted", "titting", "titted"],
+    "titup" => ["titups", "tituped", "titupping", "tituped"],
+    "toadeat" => ["toadeats", "toadate", "toadeating", "toadeaten"],
+    "tobeat" => ["tobeats", "tobeat", "tobeating", "tobeaten"],
+    "tobreak" => ["tobreaks", "tobroke", "tobreaking", "tobroken"],
+    "tocleave" => ["tocleaves", "toclove", "tocleaving", "tocloven"],
+    "tocut" => ["tocuts", "tocut", "tocutting", "tocut"],
+    "tod" => ["tods", "todded", "todding", "todded"],
+    "todraw" => ["todraws", "todrew", "todrawing", "todrawn"],
+    "todrive" => ["todrives", "todrove", "todriving", "todriven"],
+    "toe" => ["toes", "toed", "toeing", "toed"],
+    "toffee" => ["toffees", "toffeed", "toffeeing", "toffeed"],
+    "togo" => ["togoes", "towent", "togoing", "togone"],
+    "togrind" => ["togrinds", "toground", "togrinding", "toground"],
+    "tohew" => ["tohews", "tohewed", "tohewing", "tohewn"],
+    "tom" => ["toms", "tommed", "tomming", "tommed"],
+    "tomcat" => ["tomcats", "tomcatted", "tomcatting", "tomcatted"],
+    "tomtom" => ["tomtoms", "tomtommed", "tomtomming", "tomtommed"],
+    "tonic" => ["tonics", "tonicked", "tonicking", "tonicked"],
+    "topspin" => ["topspins", "topspun", "topspinning", "topspun"],
+    "toput" => ["toputs", "toput", "toputting", "toput"],
+    "torat" => ["torats", "toratted", "toratting", "toratted"],
+    "toreave" => ["toreaves", "toreft", "toreaving", "toreft"],
+    "torend" => ["torends", "torent", "torending", "torent"],
+    "torfel" => ["torfels", "torfelled", "torfelling", "torfelled"],
+    "torit" => ["torits", "torit", "toritting", "torit"],
+    "torive" => ["torives", "torift", "toriving", "torift"],
+    "torob" => ["torobs", "torobbed", "torobbing", "torobbed"],
+    "torpedo" => ["torpedoes", "torpedoed", "torpedoing", "torpedoed"],
+    "toshake" => ["toshakes", "toshook", "toshaking", "toshaken"],
+    "toshear" => ["toshears", "toshore", "toshearing", "toshorn"],
+    "toshend" => ["toshends", "toshent", "toshending", "toshent"],
+    "toswink" => ["toswinks", "toswank", "toswinking", "toswunk"],
+    "tot" => ["tots", "totted", "totting", "totted"],
+    "totear" => ["totears", "totore", "totearing", "totorn"],
+    "totread" => ["totreads", "totrod", "totreading", "totrodden"],
+    "tourney" => ["tourneys", "tourneyed", "tourneying", "tourneyed"],
+    "towind" => ["towinds", "towound", "towinding", "towound"],
+    "toy" => ["toys", "toyed", "toying", "toyed"],
+    "tp" => ["tps", "tped", "tping", "tped"],
+    "tradlarp" => ["tradlarps", "tradlarped", "tradlarping", "tradlarped"],
+    "tradpub" => ["tradpubs", "tradpubbed", "tradpubbing", "tradpubbed"],
+    "traffic" => ["traffics", "trafficked", "trafficking", "trafficked"],
+    "trailbreak" => ["trailbreaks", "trailbroke", "trailbreaking", "trailbroke"],
+    "trainspot" => ["trainspots", "trainspotted", "trainspotting", "trainspotted"],
+    "tram" => ["trams", "trammed", "tramming", "trammed"],
+    "tramp" => ["tramps", "tramped", "tramping", "tramped"],
+    "transblot" => ["transblots", "transblotted", "transblotting", "transblotted"],
+    "transcur" => ["transcurs", "transcurred", "transcurring", "transcurred"],
+    "transfer" => ["transfers", "transferred", "transferring", "transferred"],
+    "transfur" => ["transfurs", "transfurred", "transfurring", "transfurred"],
+    "transmat" => ["transmats", "transmatted", "transmatting", "transmatted"],
+    "transmit" => ["transmits", "transmitted", "transmitting", "transmitted"],
+    "trapan" => ["trapans", "trapanned", "trapanning", "trapanned"],
+    "tray" => ["trays", "trayed", "traying", "trayed"],
+    "tread" => ["treads", "trod", "treading", "trodden"],
+    "tree" => ["trees", "treed", "treeing", "treed"],
+    "trek" => ["treks", "trekked", "trekking", "trekked"],
+    "tremp" => ["tremps", "tremped", "tremping", "tremped"],
+    "trendelenburg" => ["trendelenburgs", "trendelenburged", "trendelenburging", "trendelenburged"],
+    "trepan" => ["trepans", "trepanned", "trepanning", "trepanned"],
+    "trib" => ["tribs", "tribbed", "tribbing", "tribbed"],
+    "trilerp" => ["trilerps", "trilerped", "trilerping", "trilerped"],
+    "trim" => ["trims", "trimmed", "trimming", "trimmed"],
+    "trod" => ["trods", "trodded", "trodding", "trodded"],
+    "trolley" => ["trolleys", "trolleyed", "trolleying", "trolleyed"],
+    "trollop" => ["trollops", "trolloped", "trolloping", "trolloped"],
+    "tromp" => ["tromps", "tromped", "tromping", "tromped"],
+    "troop" => ["troops", "trooped", "trooping", "trooped"],
+    "trot" => ["trots", "trotted", "trotting", "trotted"],
+    "troubleshoot" => ["troubleshoots", "troubleshot", "troubleshooting", "troubleshot"],
+    "true" => ["trues", "trued", "trueing", "trued"],
+    "trump" => ["trumps", "trumped", "trumping", "trumped"],
+    "trustee" => ["trustees", "trusteed", "trusteeing", "trusteed"],
+    "tszuj" => ["tszujes", "tszujed", "tszujing", "tszujed"],
+    "tub" => ["tubs", "tubbed", "tubbing", "tubbed"],
+    "tubthump" => ["tubthumps", "tubthumped", "tubthumping", "tubthumped"],
+    "tumbleset" => ["tumblesets", "tumblesetted", "tumblesetting", "tumblesetted"],
+    "tump" => ["tumps", "tumped", "tumping", "tumped"],
+    "tun" => ["tuns", "tunned", "tunning", "tunned"],
+    "tupe" => ["tupes", "tuped", "tupeing", "tuped"],
+    "turney" => ["turneys", "turneyed", "turneying", "turneyed"],
+    "turnip" => ["turnips", "turniped", "turniping", "turniped"],
+    "turnkey" => ["turnkeys", "turnkeyed", "turnkeying", "turnkeyed"],
+    "tut" => ["tuts", "tutted", "tutting", "tutted"],
+    "tutay" => ["tutays", "tutayed", "tutaying", "tutayed"],
+    "tutoie" => ["tutoies", "tutoied", "tutoieing", "tutoied"],
+    "tutoy" => ["tutoys", "tutoyed", "tutoying", "tutoyed"],
+    "twat" => ["twats", "twatted", "twatting", "twatted"],
+    "tweag" => ["tweags", "tweaged", "tweaging", "tweaged"],
+    "tweep" => ["tweeps", "tweeped", "tweeping", "tweeped"],
+    "twight" => ["twights", "twight", "twighting", "twight"],
+    "twilight" => ["twilights", "twilit", "twilighting", "twilit"],
+    "twin" => ["twins", "twinned", "twinning", "twinned"],
+    "twinge" => ["twinges", "twinged", "twingeing", "twinged"],
+    "twit" => ["twits", "twitted", "twitting", "twitted"],
+    "twoc" => ["twocs", "twocced", "twoccing", "twocced"],
+    "twos" => ["twoss", "twosed", "twosing", "twosed"],
+    "tye" => ["tyes", "tyed", "tyeing", "tyed"],
+    "tyek" => ["tyeks", "teuk", "tyekin", "tyeken"],
+    "typecast" => ["typecasts", "typecast", "typecasting", "typecast"],
+    "typeset" => ["typesets", "typeset", "typesetting", "typeset"],
+    "typewrite" => ["typewrites", "typewrote", "typewriting", "typewritten"],
+    "typosquat" => ["typosquats", "typosquatted", "typosquatting", "typosquatted"],
+    "tyran" => ["tyrans", "tyranned", "tyranning", "tyranned"],
+    "ugg" => ["uggs", "ugged", "ugging", "ugged"],
+    "ultrascan" => ["ultrascans", "ultrascanned", "ultrascanning", "ultrascanned"],
+    "um" => ["ums", "ummed", "umming", "ummed"],
+    "umbecast" => ["umbecasts", "umbecast", "umbecasting", "umbecast"],
+    "umbedraw" => ["umbedraws", "umbedrew", "umbedrawing", "umbedrawn"],
+    "umbego" => ["umbegoes", "umbewent", "umbegoing", "umbegone"],
+    "umbeset" => ["umbesets", "umbeset", "umbesetting", "umbeset"],
+    "umbethink" => ["umbethinks", "umbethought", "umbethinking", "umbethought"],
+    "umcast" => ["umcasts", "umcast", "umcasting", "umcast"],
+    "ump" => ["umps", "umped", "umping", "umped"],
+    "unallot" => ["unallots", "unallotted", "unallotting", "unallotted"],
+    "unarray" => ["unarrays", "unarrayed", "unarraying", "unarrayed"],
+    "unban" => ["unbans", "unbanned", "unbanning", "unbanned"],
+    "unbar" => ["unbars", "unbarred", "unbarring", "unbarred"],
+    "unbear" => ["unbears", "unbore", "unbearing", "unborne"],
+    "unbecome" => ["unbecomes", "unbecame", "unbecoming", "unbecome"],
+    "unbed" => ["unbeds", "unbedded", "unbedding", "unbedded"],
+    "unbefit" => ["unbefits", "unbefitted", "unbefitting", "unbefitted"],
+    "unbeget" => ["unbegets", "unbegot", "unbegetting", "unbegotten"],
+    "unbend" => ["unbends", "unbent", "unbending", "unbent"],
+    "unbespeak" => ["unbespeaks", "unbespoke", "unbespeaking", "unbespoken"],
+    "unbethink" => ["unbethinks", "unbethought", "unbethinking", "unbethought"],
+    "unbetray" => ["unbetrays", "unbetrayed", "unbetraying", "unbetrayed"],
+    "unbid" => ["unbids", "unbid", "unbidding", "unbid"],
+    "unbin" => ["unbins", "unbinned", "unbinning", "unbinned"],
+    "unbind" => ["unbinds", "unbound", "unbinding", "unbound"],
+    "unbishop" => ["unbishops", "unbishoped", "unbishoping", "unbishoped"],
+    "unbit" => ["unbits", "unbitted", "unbitting", "unbitted"],
+    "unbite" => ["unbites", "unbit", "unbiting", "unbitten"],
+    "unblue" => ["unblues", "unblued", "unblueing", "unblued"],
+    "unblur" => ["unblurs", "unblurred", "unblurring", "unblurred"],
+    "unboy" => ["unboys", "unboyed", "unboying", "unboyed"],
+    "unbran" => ["unbrans", "unbranned", "unbranning", "unbranned"],
+    "unbreak" => ["unbreaks", "unbroke", "unbreaking", "unbroken"],
+    "unbreed" => ["unbreeds", "unbred", "unbreeding", "unbred"],
+    "unbuild" => ["unbuilds", "unbuilt", "unbuilding", "unbuilt"],
+    "unbump" => ["unbumps", "unbumped", "unbumping", "unbumped"],
+    "uncamp" => ["uncamps", "uncamped", "uncamping", "uncamped"],
+    "uncan" => ["uncans", "uncanned", "uncanning", "uncanned"],
+    "uncast" => ["uncasts", "uncast", "uncasting", "uncast"],
+    "uncatch" => ["uncatches", "uncaught", "uncatching", "uncaught"],
+    "unchoose" => ["unchooses", "unchose", "unchoosing", "unchosen"],
+    "unclamp" => ["unclamps", "unclamped", "unclamping", "unclamped"],
+    "unclasp" => ["unclasps", "unclasped", "unclasping", "unclasped"],
+    "uncling" => ["unclings", "unclung", "unclinging", "unclung"],
+    "unclump" => ["unclumps", "unclumped", "unclumping", "unclumped"],
+    "uncoif" => ["uncoifs", "uncoiffed", "uncoiffing", "uncoiffed"],
+    "uncommit" => ["uncommits", "uncommitted", "uncommitting", "uncommitted"],
+    "uncoop" => ["uncoops", "uncooped", "uncooping", "uncooped"],
+    "uncram" => ["uncrams", "uncrammed", "uncramming", "uncrammed"],
+    "uncramp" => ["uncramps", "uncramped", "uncramping", "uncramped"],
+    "uncrib" => ["uncribs", "uncribbed", "uncribbing", "uncribbed"],
+    "uncrimp" => ["uncrimps", "uncrimped", "uncrimping", "uncrimped"],
+    "uncrisp" => ["uncrisps", "uncrisped", "uncrisping", "uncrisped"],
+    "undam" => ["undams", "undammed", "undamming", "undammed"],
+    "undecree" => ["undecrees", "undecreed", "undecreeing", "undecreed"],
+    "undeploy" => ["undeploys", "undeployed", "undeploying", "undeployed"],
+    "underbar" => ["underbars", "underbarred", "underbarring", "underbarred"],
+    "underbear" => ["underbears", "underbore", "underbearing", "underborne"],
+    "underbeat" => ["underbeats", "underbeat", "underbeating", "underbeaten"],
+    "underbet" => ["underbets", "underbet", "underbetting", "underbet"],
+    "underbid" => ["underbids", "underbid", "underbidding", "underbid"],
+    "underbind" => ["underbinds", "underbound", "underbinding", "underbound"],
+    "underbite" => ["underbites", "underbit", "underbiting", "underbitten"],
+    "underblow" => ["underblows", "underblew", "underblowing", "underblown"],
+    "underbreed" => ["underbreeds", "underbred", "underbreeding", "underbred"],
+    "underbring" => ["underbrings", "underbrought", "underbringing", "underbrought"],
+    "underbuild" => ["underbuilds", "underbuilt", "underbuilding", "underbuilt"],
+    "undercast" => ["undercasts", "undercast", "undercasting", "undercast"],
+    "underclub" => ["underclubs", "underclubbed", "underclubbing", "underclubbed"],
+    "undercome" => ["undercomes", "undercame", "undercoming", "undercome"],
+    "undercommit" => ["undercommits", "undercommitted", "undercommitting", "undercommitted"],
+    "undercreep" => ["undercreeps", "undercrept", "undercreeping", "undercrept"],
+    "undercut" => ["undercuts", "undercut", "undercutting", "undercut"],
+    "underdamp" => ["underdamps", "underdamped", "underdamping", "underdamped"],
+    "underdeal" => ["underdeals", "underdealt", "underdealing", "underdealt"],
+    "underdevelop" => ["underdevelops", "underdeveloped", "underdeveloping", "underdeveloped"],
+    "underdig" => ["underdigs", "underdug", "underdigging", "underdug"],
+    "underdo" => ["underdoes", "underdid", "underdoing", "underdone"],
+    "underdraw" => ["underdraws", "underdrew", "underdrawing", "underdrawn"],
+    "underdrive" => ["underdrives", "underdrove", "underdriving", "underdriven"],
+    "undereat" => ["undereats", "underate", "undereating", "undereaten"],
+    "underfeed" => ["underfeeds", "underfed", "underfeeding", "underfed"],
+    "underfeel" => ["underfeels", "underfelt", "underfeeling", "underfelt"],
+    "underfit" => ["underfits", "underfitted", "underfitting", "underfitted"],
+    "underfulfil" => ["underfulfils", "underfulfilled", "underfulfilling", "underfulfilled"],
+    "underget" => ["undergets", "undergot", "undergetting", "undergot"],
+    "undergo" => ["undergoes", "underwent", "undergoing", "undergone"],
+    "undergoe" => ["undergoes", "underwent", "undergoing", "undergone"],
+    "undergrind" => ["undergrinds", "underground", "undergrinding", "underground"],
+    "undergrow" => ["undergrows", "undergrew", "undergrowing", "undergrown"],
+    "underhang" => ["underhangs", "underhung", "underhanging", "underhung"],
+    "underhit" => ["underhits", "underhit", "underhitting", "underhit"],
+    "underhold" => ["underholds", "underheld", "underholding", "underheld"],
+    "underkeep" => ["underkeeps", "underkept", "underkeeping", "underkept"],
+    "underlay" => ["underlays", "underlaid", "underlaying", "underlaid"],
+    "underlead" => ["underleads", "underled", "underleading", "underled"],
+    "underlet" => ["underlets", "underlet", "underletting", "underlet"],
+    "underlie" => ["underlies", "underlay", "underlying", "underlain"],
+    "underly" => ["underlies", "underlay", "underlying", "underlain"],
+    "underlye" => ["underlyes", "underlay", "underlying", "underlain"],
+    "underman" => ["undermans", "undermanned", "undermanning", "undermanned"],
+    "underpay" => ["underpays", "underpaid", "underpaying", "underpaid"],
+    "underpeep" => ["underpeeps", "underpeeped", "underpeeping", "underpeeped"],
+    "underpin" => ["underpins", "underpinned", "underpinning", "underpinned"],
+    "underplan" => ["underplans", "underplanned", "underplanning", "underplanned"],
+    "underplay" => ["underplays", "underplayed", "underplaying", "underplayed"],
+    "underplot" => ["underplots", "underplotted", "underplotting", "underplotted"],
+    "underpot" => ["underpots", "underpotted", "underpotting", "underpotted"],
+    "underput" => ["underputs", "underput", "underputting", "underput"],
+    "underread" => ["underreads", "underread", "underreading", "underread"],
+    "underride" => ["underrides", "underrode", "underriding", "underridden"],
+    "underring" => ["underrings", "underrang", "underringing", "underrung"],
+    "underrun" => ["underruns", "underran", "underrunning", "underrun"],
+    "undersay" => ["undersays", "undersaid", "undersaying", "undersaid"],
+    "undersee" => ["undersees", "undersaw", "underseeing", "underseen"],
+    "underseek" => ["underseeks", "undersought", "underseeking", "undersought"],
+    "undersell" => ["undersells", "undersold", "underselling", "undersold"],
+    "undersend" => ["undersends", "undersent", "undersending", "undersent"],
+    "underset" => ["undersets", "underset", "undersetting", "underset"],
+    "undershoot" => ["undershoots", "undershot", "undershooting", "undershot"],
+    "undersing" => ["undersings", "undersang", "undersinging", "undersung"],
+    "undersleep" => ["undersleeps", "underslept", "undersleeping", "underslept"],
+    "undersling" => ["underslings", "underslung", "underslinging", "underslung"],
+    "undersow" => ["undersows", "undersowed", "undersowing", "undersown"],
+    "underspeak" => ["underspeaks", "underspoke", "underspeaking", "underspoken"],
+    "underspend" => ["underspends", "underspent", "underspending", "underspent"],
+    "understand" => ["understands", "understood", "understanding", "understood"],
+    "understir" => ["understirs", "understirred", "understirring", "understirred"],
+    "understride" => ["understrides", "understrode", "understriding", "understrode"],
+    "undertake" => ["undertakes", "undertook", "undertaking", "undertaken"],
+    "underteach" => ["underteaches", "undertaught", "underteaching", "undertaught"],
+    "underthink" => ["underthinks", "underthought", "underthinking", "underthought"],
+    "underthrow" => ["underthrows", "underthrew", "underthrowing", "underthrown"],
+    "undertrump" => ["undertrumps", "undertrumped", "undertrumping", "undertrumped"],
+    "underwind" => ["underwinds", "underwound", "underwinding", "underwound"],
+    "underwithhold" => ["underwithholds", "underwithheld", "underwithholding", "underwithheld"],
+    "underwrite" => ["underwrites", "underwrote", "underwriting", "underwritten"],
+    "undestroy" => ["undestroys", "undestroyed", "undestroying", "undestroyed"],
+    "undiadem" => ["undiadems", "undiademmed", "undiademming", "undiademmed"],
+    "undie" => ["undies", "undied", "undying", "undied"],
+    "undig" => ["undigs", "undug", "undigging", "undug"],
+    "undim" => ["undims", "undimmed", "undimming", "undimmed"],
+    "undisplay" => ["undisplays", "undisplayed", "undisplaying", "undisplayed"],
+    "undo" => ["undoes", "undid", "undoing", "undone"],
+    "undraw" => ["undraws", "undrew", "undrawing", "undrawn"],
+    "undrink" => ["undrinks", "undrank", "undrinking", "undrunk"],
+    "undub" => ["undubs", "undubbed", "undubbing", "undubbed"],
+    "undump" => ["undumps", "undumped", "undumping", "undumped"],
+    "undye" => ["undyes", "undyed", "undyeing", "undyed"],
+    "uneat" => ["uneats", "unate", "uneating", "uneaten"],
+    "unembed" => ["unembeds", "unembedded", "unembedding", "unembedded"],
+    "unemploy" => ["unemploys", "unemployed", "unemploying", "unemployed"],
+    "unenrol" => ["unenrols", "unenrolled", "unenrolling", "unenrolled"],
+    "unfeel" => ["unfeels", "unfelt", "unfeeling", "unfelt"],
+    "unfit" => ["unfits", "unfitted", "unfitting", "unfitted"],
+    "unforget" => ["unforgets", "unforgot", "unforgetting", "unforgotten"],
+    "unforgive" => ["unforgives", "unforgave", "unforgiving", "unforgiven"],
+    "unformat" => ["unformats", "unformatted", "unformatting", "unformatted"],
+    "unfreeze" => ["unfreezes", "unfroze", "unfreezing", "unfrozen"],
+    "unfret" => ["unfrets", "unfretted", "unfretting", "unfretted"],
+    "unfulfil" => ["unfulfils", "unfulfilled", "unfulfilling", "unfulfilled"],
+    "unfur" => ["unfurs", "unfurred", "unfurring", "unfurred"],
+    "ungay" => ["ungays", "ungayed", "ungaying", "ungayed"],
+    "unget" => ["ungets", "ungot", "ungetting", "ungot"],
+    "ungive" => ["ungives", "ungave", "ungiving", "ungiven"],
+    "ungod" => ["ungods", "ungodded", "ungodding", "ungodded"],
+    "ungrab" => ["ungrabs", "ungrabbed", "ungrabbing", "ungrabbed"],
+    "ungrasp" => ["ungrasps", "ungrasped", "ungrasping", "ungrasped"],
+    "ungroup" => ["ungroups", "ungrouped", "ungrouping", "ungrouped"],
+    "ungrow" => ["ungrows", "ungrew", "ungrowing", "ungrown"],
+    "ungum" => ["ungums", "ungummed", "ungumming", "ungummed"],
+    "ungut" => ["unguts", "ungutted", "ungutting", "ungutted"],
+    "unhang2" => ["unhangs", "unhung", "unhanging", "unhung"],
+    "unhasp" => ["unhasps", "unhasped", "unhasping", "unhasped"],
+    "unhat" => ["unhats", "unhatted", "unhatting", "unhatted"],
+    "unhear" => ["unhears", "unheard", "unhearing", "unheard"],
+    "unhelp" => ["unhelps", "unhelped", "unhelping", "unhelped"],
+    "unhem" => ["unhems", "unhemmed", "unhemming", "unhemmed"],
+    "unhide" => ["unhides", "unhid", "unhiding", "unhidden"],
+    "unhit" => ["unhits", "unhit", "unhitting", "unhit"],
+    "unhold" => ["unholds", "unheld", "unholding", "unheld"],
+    "unhoop" => ["unhoops", "unhooped", "unhooping", "unhooped"],
+    "unicast" => ["unicasts", "unicast", "unicasting", "unicast"],
+    "unjailbreak" => ["unjailbreaks", "unjailbroke", "unjailbreaking", "unjailbroken"],
+    "unjam" => ["unjams", "unjammed", "unjamming", "unjammed"],
+    "unjar" => ["unjars", "unjarred", "unjarring", "unjarred"],
+    "unken" => ["unkens", "unkenned", "unkenning", "unkenned"],
+    "unkey" => ["unkeys", "unkeyed", "unkeying", "unkeyed"],
+    "unknit" => ["unknits", "unknit", "unknitting", "unknit"],
+    "unknot" => ["unknots", "unknotted", "unknotting", "unknotted"],
+    "unknow" => ["unknows", "unknew", "unknowing", "unknown"],
+    "unlade" => ["unlades", "unladed", "unlading", "unladen"],
+    "unlay" => ["unlays", "unlaid", "unlaying", "unlaid"],
+    "unlid" => ["unlids", "unlidded", "unlidding", "unlidded"],
+    "unloop" => ["unloops", "unlooped", "unlooping", "unlooped"],
+    "unlose" => ["unloses", "unlost", "unlosing", "unlost"],
+    "unmagic" => ["unmagics", "unmagicked", "unmagicking", "unmagicked"],
+    "unmake" => ["unmakes", "unmade", "unmaking", "unmade"],
+    "unman" => ["unmans", "unmanned", "unmanning", "unmanned"],
+    "unmat" => ["unmats", "unmatted", "unmatting", "unmatted"],
+    "unmean" => ["unmeans", "unmeant", "unmeaning", "unmeant"],
+    "unmeet" => ["unmeets", "unmet", "unmeeting", "unmet"],
+    "unmonarch" => ["unmonarchs", "unmonarched", "unmonarching", "unmonarched"],
+    "unnerstand" => ["unnerstands", "unnerstood", "unnerstanding", "unnerstood"],
+    "unnun" => ["unnuns", "unnunned", "unnunning", "unnunned"],
+    "unpay" => ["unpays", "unpaid", "unpaying", "unpaid"],
+    "unpen" => ["unpens", "unpenned", "unpenning", "unpenned"],
+    "unpin" => ["unpins", "unpinned", "unpinning", "unpinned"],
+    "unplan" => ["unplans", "unplanned", "unplanning", "unplanned"],
+    "unplat" => ["unplats", "unplatted", "unplatting", "unplatted"],
+    "unplay" => ["unplays", "unplayed", "unplaying", "unplayed"],
+    "unplot" => ["unplots", "unplotted", "unplotting", "unplotted"],
+    "unpot" => ["unpots", "unpotted", "unpotting", "unpotted"],
+    "unpray" => ["unprays", "unprayed", "unpraying", "unprayed"],
+    "unquit" => ["unquits", "unquit", "unquitting", "unquit"],
+    "unram" => ["unrams", "unrammed", "unramming", "unrammed"],
+    "unread" => ["unreads", "unread", "unreading", "unread"],
+    "unring2" => ["unrings", "unrang", "unringing", "unrung"],
+    "unsake" => ["unsakes", "unsook", "unsaking", "unsaken"],
+    "unsay" => ["unsays", "unsaid", "unsaying", "unsaid"],
+    "unsee" => ["unsees", "unsaw", "unseeing", "unseen"],
+    "unsell" => ["unsells", "unsold", "unselling", "unsold"],
+    "unsend" => ["unsends", "unsent", "unsending", "unsent"],
+    "unset" => ["unsets", "unset", "unsetting", "unset"],
+    "unsew" => ["unsews", "unsewed", "unsewing", "unsewn"],
+    "unshadowban" => ["unshadowbans", "unshadowbanned", "unshadowbanning", "unshadowbanned"],
+    "unshake" => ["unshakes", "unshook", "unshaking", "unshaken"],
+    "unshar" => ["unshars", "unsharred", "unsharring", "unsharred"],
+    "unsharp" => ["unsharps", "unsharped", "unsharping", "unsharped"],
+    "unshit" => ["unshits", "unshit", "unshitting", "unshit"],
+    "unshoe" => ["unshoes", "unshoed", "unshoeing", "unshoed"],
+    "unshoot" => ["unshoots", "unshot", "unshooting", "unshot"],
+    "unshot" => ["unshots", "unshotted", "unshotting", "unshotted"],
+    "unshrink" => ["unshrinks", "unshrank", "unshrinking", "unshrunk"],
+    "unshut" => ["unshuts", "unshut", "unshutting", "unshut"],
+    "unsin" => ["unsins", "unsinned", "unsinning", "unsinned"],
+    "unsing" => ["unsings", "unsang", "unsinging", "unsung"],
+    "unskin" => ["unskins", "unskinned", "unskinning", "unskinned"],
+    "unsleep" => ["unsleeps", "unslept", "unsleeping", "unslept"],
+    "unslide" => ["unslides", "unslid", "unsliding", "unslid"],
+    "unsling" => ["unslings", "unslung", "unslinging", "unslung"],
+    "unslot" => ["unslots", "unslotted", "unslotting", "unslotted"],
+    "unsnib" => ["unsnibs", "unsnibbed", "unsnibbing", "unsnibbed"],
+    "unspam" => ["unspams", "unspammed", "unspamming", "unspammed"],
+    "unspar" => ["unspars", "unsparred", "unsparring", "unsparred"],
+    "unspeak" => ["unspeaks", "unspoke", "unspeaking", "unspoken"],
+    "unspend" => ["unspends", "unspent", "unspending", "unspent"],
+    "unspin" => ["unspins", "unspun", "unspinning", "unspun"],
+    "unspit" => ["unspits", "unspitted", "unspitting", "unspitted"],
+    "unsplay" => ["unsplays", "unsplayed", "unsplaying", "unsplayed"],
+    "unsplit" => ["unsplits", "unsplit", "unsplitting", "unsplit"],
+    "unspot" => ["unspots", "unspotted", "unspotting", "unspotted"],
+    "unspread" => ["unspreads", "unspread", "unspreading", "unspread"],
+    "unspring" => ["unsprings", "unsprang", "unspringing", "unsprung"],
+    "unstamp" => ["unstamps", "unstamped", "unstamping", "unstamped"],
+    "unstan" => ["unstans", "unstanned", "unstanning", "unstanned"],
+    "unstar" => ["unstars", "unstarred", "unstarring", "unstarred"],
+    "unstay" => ["unstays", "unstayed", "unstaying", "unstayed"],
+    "unsteal" => ["unsteals", "unstole", "unstealing", "unstolen"],
+    "unstick" => ["unsticks", "unstuck", "unsticking", "unstuck"],
+    "unstir" => ["unstirs", "unstirred", "unstirring", "unstirred"],
+    "unstrike" => ["unstrikes", "unstruck", "unstriking", "unstruck"],
+    "unstring" => ["unstrings", "unstrung", "unstringing", "unstrung"],
+    "unsub" => ["unsubs", "unsubbed", "unsubbing", "unsubbed"],
+    "unsubmit" => ["unsubmits", "unsubmitted", "unsubmitting", "unsubmitted"],
+    "unswear" => ["unswears", "unswore", "unswearing", "unsworn"],
+    "unswell" => ["unswells", "unswelled", "unswelling", "unswollen"],
+    "unswing" => ["unswings", "unswung", "unswinging", "unswung"],
+    "untar" => ["untars", "untarred", "untarring", "untarred"],
+    "unteach" => ["unteaches", "untaught", "unteaching", "untaught"],
+    "untell" => ["untells", "untold", "untelling", "untold"],
+    "unthink" => ["unthinks", "unthought", "unthinking", "unthought"],
+    "unthrow" => ["unthrows", "unthrew", "unthrowing", "unthrew"],
+    "untie" => ["unties", "untied", "untying", "untied"],
+    "untin" => ["untins", "untinned", "untinning", "untinned"],
+    "untread" => ["untreads", "untrod", "untreading", "untrod"],
+    "untree" => ["untrees", "untreed", "untreeing", "untreed"],
+    "untrim" => ["untrims", "untrimmed", "untrimming", "untrimmed"],
+    "unty" => ["untyes", "untyed", "untying", "untyed"],
+    "unvat" => ["unvats", "unvatted", "unvatting", "unvatted"],
+    "unwad" => ["unwads", "unwadded", "unwadding", "unwadded"],
+    "unwarp" => ["unwarps", "unwarped", "unwarping", "unwarped"],
+    "unweave" => ["unweaves", "unwove", "unweaving", "unwoven"],
+    "unweb" => ["unwebs", "unwebbed", "unwebbing", "unwebbed"],
+    "unwed" => ["unweds", "unwed", "unwedding", "unwed"],
+    "unwet" => ["unwets", "unwet", "unwetting", "unwet"],
+    "unwind" => ["unwinds", "unwound", "unwinding", "unwound"],
+    "unwit" => ["unwits", "unwitted", "unwitting", "unwitted"],
+    "unwrite" => ["unwrites", "unwrote", "unwriting", "unwritten"],
+    "upbar" => ["upbars", "upbarred", "upbarring", "upbarred"],
+    "upbear" => ["upbears", "upbore", "upbearing", "upborne"],
+    "upbend" => ["upbends", "upbent", "upbending", "upbent"],
+    "upbid" => ["upbids", "upbid", "upbidding", "upbid"],
+    "upbind" => ["upbinds", "upbound", "upbinding", "upbound"],
+    "upblow" => ["upblows", "upblew", "upblowing", "upblown"],
+    "upbray" => ["upbrays", "upbrayed", "upbraying", "upbrayed"],
+    "upbreak" => ["upbreaks", "upbroke", "upbreaking", "upbroken"],
+    "upbreed" => ["upbreeds", "upbred", "upbreeding", "upbred"],
+    "upbrim" => ["upbrims", "upbrimmed", "upbrimming", "upbrimmed"],
+    "upbring" => ["upbrings", "upbrought", "upbringing", "upbrought"],
+    "upbuild" => ["upbuilds", "upbuilt", "upbuilding", "upbuilt"],
+    "upbuoy" => ["upbuoys", "upbuoyed", "upbuoying", "upbuoyed"],
+    "upcast" => ["upcasts", "upcast", "upcasting", "upcast"],
+    "upcome" => ["upcomes", "upcame", "upcoming", "upcome"],
+    "upcreep" => ["upcreeps", "upcrept", "upcreeping", "upcrept"],
+    "upcut" => ["upcuts", "upcut", "upcutting", "upcut"],
+    "updraw" => ["updraws", "updrew", "updrawing", "updrawn"],
+    "upfit" => ["upfits", "upfitted", "upfitting", "upfitted"],
+    "upfling" => ["upflings", "upflung", "upflinging", "upflung"],
+    "upfly" => ["upflies", "upflew", "upflying", "upflown"],
+    "upgive" => ["upgives", "upgave", "upgiving", "upgiven"],
+    "upgo" => ["upgoes", "upwent", "upgoing", "upgone"],
+    "upgrow" => ["upgrows", "upgrew", "upgrowing", "upgrown"],
+    "upgun" => ["upguns", "upgunned", "upgunning", "upgunned"],
+    "uphang" => ["uphangs", "uphung", "uphanging", "uphung"],
+    "upheap" => ["upheaps", "upheaped", "upheaping", "upheaped"],
+    "uphold" => ["upholds", "upheld", "upholding", "upheld"],
+    "upjet" => ["upjets", "upjetted", "upjetting", "upjetted"],
+    "upkeep" => ["upkeeps", "upkept", "upkeeping", "upkept"],
+    "upknit" => ["upknits", "upknitted", "upknitting", "upknitted"],
+    "uplay" => ["uplays", "uplaid", "uplaying", "uplaid"],
+    "uplead" => ["upleads", "upled", "upleading", "upled"],
+    "uplean" => ["upleans", "upleant", "upleaning", "upleant"],
+    "upleap" => ["upleaps", "upleaped", "upleaping", "upleaped"],
+    "upmake" => ["upmakes", "upmade", "upmaking", "upmade"],
+    "uppercut" => ["uppercuts", "uppercut", "uppercutting", "uppercut"],
+    "uppluck" => ["upplucks", "upplight", "upplucking", "upplight"],
+    "upput" => ["upputs", "upput", "upputting", "upput"],
+    "uprend" => ["uprends", "uprent", "uprending", "uprent"],
+    "upride" => ["uprides", "uprode", "upriding", "upridden"],
+    "uprise" => ["uprises", "uprose", "uprising", "uprisen"],
+    "uprun" => ["upruns", "upran", "uprunning", "uprun"],
+    "upseek" => ["upseeks", "upsought", "upseeking", "upsought"],
+    "upsell" => ["upsells", "upsold", "upselling", "upsold"],
+    "upsend" => ["upsends", "upsent", "upsending", "upsent"],
+    "upset" => ["upsets", "upset", "upsetting", "upset"],
+    "upshoot" => ["upshoots", "upshot", "upshooting", "upshot"],
+    "upshut" => ["upshuts", "upshut", "upshutting", "upshut"],
+    "upspeak" => ["upspeaks", "upspoke", "upspeaking", "upspoken"],
+    "upspeed" => ["upspeeds", "upsped", "upspeeding", "upsped"],
+    "upspread" => ["upspreads", "upspread", "upspreading", "upspread"],
+    "upspring" => ["upsprings", "upsprang", "upspringing", "upsprung"],
+    "upstand" => ["upstands", "upstood", "upstanding", "upstood"],
+    "upstay" => ["upstays", "upstayed", "upstaying", "upstayed"],
+    "upsteal" => ["upsteals", "upstole", "upstealing", "upstolen"],
+    "upstir" => ["upstirs", "upstirred", "upstirring", "upstirred"],
+    "upstrive" => ["upstrives", "upstrove", "upstriving", "upstriven"],
+    "upsway" => ["upsways", "upswayed", "upswaying", "upswayed"],
+    "upsweep" => ["upsweeps", "upswept", "upsweeping", "upswept"],
+    "upswim" => ["upswims", "upswam", "upswimming", "upswum"],
+    "upswing" => ["upswings", "upswung", "upswinging", "upswung"],
+    "uptake" => ["uptakes", "uptook", "uptaking", "uptaken"],
+    "uptear" => ["uptears", "uptore", "uptearing", "uptorn"],
+    "upthrow" => ["upthrows", "upthrew", "upthrowing", "upthrown"],
+    "uptie" => ["upties", "uptied", "uptying", "uptied"],
+    "upwind" => ["upwinds", "upwound", "upwinding", "upwound"],
+    "urp" => ["urps", "urped", "urping", "urped"],
+    "usurp" => ["usurps", "usurped", "usurping", "usurped"],
+    "vab" => ["vabs", "vabbed", "vabbing", "vabbed"],
+    "vac" => ["vacs", "vacced", "vaccing", "vacced"],
+    "vacay" => ["vacays", "vacayed", "vacaying", "vacayed"],
+    "valley" => ["valleys", "valleyed", "valleying", "valleyed"],
+    "vamp" => ["vamps", "vamped", "vamping", "vamped"],
+    "van" => ["vans", "vanned", "vanning", "vanned"],
+    "vat" => ["vats", "vatted", "vatting", "vatted"],
+    "vax" => ["vaxxes", "vaxxed", "vaxxing", "vaxxed"],
+    "vee" => ["vees", "veed", "veeing", "veed"],
+    "veejay" => ["veejays", "veejayed", "veejaying", "veejayed"],
+    "verticut" => ["verticuts", "verticut", "verticutting", "verticut"],
+    "vet" => ["vets", "vetted", "vetting", "vetted"],
+    "veto" => ["vetoes", "vetoed", "vetoing", "vetoed"],
+    "vfx" => ["vfxs", "vfxed", "vfxing", "vfxed"],
+    "vidcast" => ["vidcasts", "vidcast", "vidcasting", "vidcast"],
+    "vidchat" => ["vidchats", "vidchatted", "vidchatting", "vidchatted"],
+    "video" => ["videoes", "videoed", "videoing", "videoed"],
+    "videocast" => ["videocasts", "videocast", "videocasting", "videocast"],
+    "videochat" => ["videochats", "videochatted", "videochatting", "videochatted"],
+    "vie" => ["vies", "vied", "vying", "vied"],
+    "viewbot" => ["viewbots", "viewbotted", "viewbotting", "viewbotted"],
+    "vis" => ["viss", "vised", "vising", "vised"],
+    "vise2" => ["vises", "vised", "viseing", "vised"],
+    "viz" => ["vizs", "vized", "vizing", "vized"],
+    "vodcast" => ["vodcasts", "vodcast", "vodcasting", "vodcast"],
+    "volley" => ["volleys", "volleyed", "volleying", "volleyed"],
+    "voluntell" => ["voluntells", "voluntold", "voluntelling", "voluntold"],
+    "vom" => ["voms", "vommed", "vomming", "vommed"],
+    "voop" => ["voops", "vooped", "vooping", "vooped"],
+    "voteban" => ["votebans", "votebanned", "votebanning", "votebanned"],
+    "vousvoie" => ["vousvoies", "vousvoied", "vousvoying", "vousvoied"],
+    "vouvoie" => ["vouvoies", "vouvoied", "vouvoying", "vouvoied"],
+    "vouvoy" => ["vouvoys", "vouvoyed", "vouvoying", "vouvoyed"],
+    "vum" => ["vums", "vummed", "vumming", "vummed"],
+    "vurp" => ["vurps", "vurped", "vurping", "vurped"],
+    "wad" => ["wads", "wadded", "wadding", "wadded"],
+    "wadset" => ["wadsets", "wadsetted", "wadsetting", "wadsetted"],
+    "wake" => ["wakes", "woke", "waking", "woken"],
+    "wallop" => ["wallops", "walloped", "walloping", "walloped"],
+    "wallride" => ["wallrides", "wallrode", "wallriding", "wallridden"],
+    "wallslam" => ["wallslams", "wallslammed", "wallslamming", "wallslammed"],
+    "war" => ["wars", "warred", "warring", "warred"],
+    "wardrive" => ["wardrives", "wardrove", "wardriving", "wardriven"],
+    "warg" => ["wargs", "warged", "warging", "warged"],
+    "warp" => ["warps", "warped", "warping", "warped"],
+    "warray" => ["warrays", "warrayed", "warraying", "warrayed"],
+    "warrey" => ["warreys", "warreyed", "warreying", "warreyed"],
+    "wasp" => ["wasps", "wasped", "wasping", "wasped"],
+    "way" => ["ways", "wayed", "waying", "wayed"],
+    "wayfare" => ["wayfares", "wayfore", "wayfaring", "wayfaren"],
+    "waylay" => ["waylays", "waylaid", "waylaying", "waylaid"],
+    "wear2" => ["wears", "wore", "wearing", "worn"],
+    "weare" => ["weares", "wore", "wearing", "worne"],
+    "weave2" => ["weaves", "wove", "weaving", "woven"],
+    "web" => ["webs", "webbed", "webbing", "webbed"],
+    "webcam" => ["webcams", "webcammed", "webcamming", "webcammed"],
+    "webcast" => ["webcasts", "webcast", "webcasting", "webcast"],
+    "webchat" => ["webchats", "webchatted", "webchatting", "webchatted"],
+    "wechat" => ["wechats", "wechatted", "wechatting", "wechatted"],
+    "wed" => ["weds", "wed", "wedding", "wed"],
+    "wedgie" => ["wedgies", "wedgied", "wedgying", "wedgied"],
+    "wedset" => ["wedsets", "wedsetted", "wedsetting", "wedsetted"],
+    "wee" => ["wees", "weed", "weeing", "weed"],
+    "weep" => ["weeps", "wept", "weeping", "wept"],
+    "weewee" => ["weewees", "weeweed", "weeweeing", "weeweed"],
+    "weightbear" => ["weightbears", "weightbore", "weightbearing", "weightborne"],
+    "wet" => ["wets", "wet", "wetting", "wet"],
+    "wfh" => ["wfh", "wfh", "wfh", "wfh"],
+    "wham" => ["whams", "whammed", "whamming", "whammed"],
+    "whatsapp" => ["whatsapps", "whatsapped", "whatsapping", "whatsapped"],
+    "whee" => ["whees", "wheed", "wheeing", "wheed"],
+    "wheech" => ["wheechs", "wheeched", "wheeching", "wheeched"],
+    "wheelie" => ["wheelies", "wheelied", "wheelieing", "wheelied"],
+    "wheelspin" => ["wheelspins", "wheelspun", "wheelspinning", "wheelspun"],
+    "whelp" => ["whelps", "whelped", "whelping", "whelped"],
+    "whet" => ["whets", "whetted", "whetting", "whetted"],
+    "whid" => ["whids", "whidded", "whidding", "whidded"],
+    "whim" => ["whims", "whimmed", "whimming", "whimmed"],
+    "whinge" => ["whinges", "whinged", "whingeing", "whinged"],
+    "whipsaw" => ["whipsaws", "whipsawed", "whipsawing", "whipsawn"],
+    "whir" => ["whirs", "whirred", "whirring", "whirred"],
+    "whistleblow" => ["whistleblows", "whistleblew", "whistleblowing", "whistleblown"],
+    "whiz" => ["whizzes", "whizzed", "whizzing", "whizzed"],
+    "whomp" => ["whomps", "whomped", "whomping", "whomped"],
+    "whoomp" => ["whoomps", "whoomped", "whoomping", "whoomped"],
+    "whoop" => ["whoops", "whooped", "whooping", "whooped"],
+    "whoopee" => ["whoopees", "whoopeed", "whoopeeing", "whoopeed"],
+    "whud" => ["whuds", "whudded", "whudding", "whudded"],
+    "whump" => ["whumps", "whumped", "whumping", "whumped"],
+    "whur" => ["whurs", "whurred", "whurring", "whurred"],
+    "why" => ["whys", "whyed", "whying", "whyed"],
+    "wicketkeep" => ["wicketkeeps", "wicketkept", "wicketkeeping", "wicketkept"],
+    "wigwam" => ["wigwams", "wigwammed", "wigwamming", "wigwammed"],
+    "wildcat" => ["wildcats", "wildcatted", "wildcatting", "wildcatted"],
+    "wilderpee" => ["wilderpees", "wilderpeed", "wilderpeeing", "wilderpeed"],
+    "wildfeed" => ["wildfeeds", "wildfed", "wildfeeding", "wildfed"],
+    "will2" => ["will", "would", "willing", "would"],
+    "willey" => ["willeys", "willeyed", "willeying", "willeyed"],
+    "wimp" => ["wimps", "wimped", "wimping", "wimped"],
+    "win" => ["wins", "won", "winning", "won"],
+    "wind2" => ["winds", "wound", "winding", "wound"],
+    "windjam" => ["windjams", "windjammed", "windjamming", "windjammed"],
+    "winge" => ["winges", "winged", "wingeing", "winged"],
+    "wingman" => ["wingmans", "wingmanned", "wingmanning", "wingmanned"],
+    "winterfeed" => ["winterfeeds", "winterfed", "winterfeeding", "winterfed"],
+    "wiredraw" => ["wiredraws", "wiredrew", "wiredrawing", "wiredrawn"],
+    "wis" => ["wis", "wised", "wising", "wist"],
+    "wishcast" => ["wishcasts", "wishcast", "wishcasting", "wishcast"],
+    "wisp" => ["wisps", "wisped", "wisping", "wisped"],
+    "wit" => ["wot", "wist", "witting", "wist"],
+    "withbear" => ["withbears", "withbore", "withbearing", "withborne"],
+    "withdraw" => ["withdraws", "withdrew", "withdrawing", "withdrawn"],
+    "withdrive" => ["withdrives", "withdrove", "withdriving", "withdriven"],
+    "withgo" => ["withgoes", "withwent", "withgoing", "withgone"],
+    "withhold" => ["withholds", "withheld", "withholding", "withheld"],
+    "withold" => ["witholds", "witheld", "witholding", "witheld"],
+    "withsake" => ["withsakes", "withsook", "withsaking", "withsaken"],
+    "withsay" => ["withsays", "withsaid", "withsaying", "withsaid"],
+    "withset" => ["withsets", "withset", "withsetting", "withset"],
+    "withsit" => ["withsits", "withsat", "withsitting", "withsat"],
+    "withspeak" => ["withspeaks", "withspoke", "withspeaking", "withspoken"],
+    "withstand" => ["withstands", "withstood", "withstanding", "withstood"],
+    "withstay" => ["withstays", "withstayed", "withstaying", "withstayed"],
+    "withtake" => ["withtakes", "withtook", "withtaking", "withtaken"],
+    "withthrow" => ["withthrows", "withthrew", "withthrowing", "withthrown"],
+    "wiz" => ["wizzes", "wizzed", "wizzing", "wizzed"],
+    "wollop" => ["wollops", "wolloped", "wolloping", "wolloped"],
+    "womp" => ["womps", "womped", "womping", "womped"],
+    "won" => ["wons", "wonned", "wonning", "wonned"],
+    "woodshed" => ["woodsheds", "woodshedded", "woodshedding", "woodshedded"],
+    "worship" => ["worships", "worshiped", "worshiping", "worshiped"],
+    "worth" => ["worths", "worth", "worthing", "worth"],
+    "wot" => ["wots", "wotted", "wotting", "wotted"],
+    "wramp" => ["wramps", "wramped", "wramping", "wramped"],
+    "wright" => ["wrights", "wrighten", "wrought", "wrighten"],
+    "wring" => ["wrings", "wrung", "wringing", "wrung"],
+    "write" => ["writes", "wrote", "writing", "written"],
+    "wrongdo" => ["wrongdoes", "wrongdid", "wrongdoing", "wrongdone"],
+    "wrongtake" => ["wrongtakes", "wrongtook", "wrongtaking", "wrongtaken"],
+    "wrongthink" => ["wrongthinks", "wrongthought", "wrongthinking", "wrongthought"],
+    "wub" => ["wubs", "wubbed", "wubbing", "wubbed"],
+    "wuk" => ["wuks", "wukked", "wuking", "wukked"],
+    "wuv" => ["wuvz", "wuvd", "wuving", "wuvd"],
+    "x" => ["xs", "xed", "xing", "xed"],
+    "xbreed" => ["xbreeds", "xbred", "xbreeding", "xbred"],
+    "xenotransmit" => ["xenotransmits", "xenotransmitted", "xenotransmitting", "xenotransmitted"],
+    "xfeed" => ["xfeeds", "xfed", "xfeeding", "xfed"],
+    "xfer" => ["xfers", "xferred", "xferring", "xferred"],
+    "xit" => ["xits", "xitted", "xitting", "xitted"],
+    "xmas" => ["xmass", "xmased", "xmasing", "xmased"],
+    "xmit" => ["xmits", "xmitted", "xmitting", "xmitted"],
+    "yab" => ["yabs", "yabbed", "yabbing", "yabbed"],
+    "yak" => ["yaks", "yakked", "yakking", "yakked"],
+    "yam" => ["yams", "yammed", "yamming", "yammed"],
+    "yankee" => ["yankees", "yankeed", "yankeeing", "yankeed"],
+    "yar" => ["yars", "yarred", "yarring", "yarred"],
+    "yaup" => ["yaups", "yauped", "yauping", "yauped"],
+    "yawp" => ["yawps", "yawped", "yawping", "yawped"],
+    "yeasay" => ["yeasays", "yeasaid", "yeasaying", "yeasaid"],
+    "yed" => ["yeds", "yedded", "yedding", "yedded"],
+    "yegg" => ["yeggs", "yegged", "yegging", "yegged"],
+    "yel" => ["yels", "yelled", "yelling", "yelled"],
+    "yelloch" => ["yellochs", "yelloched", "yelloching", "yelloched"],
+    "yelp" => ["yelps", "yelped", "yelping", "yelped"],
+    "yen" => ["yens", "yenned", "yenning", "yenned"],
+    "yes" => ["yeses", "yessed", "yessing", "yessed"],
+    "yet" => ["yets", "yet", "yetting", "yet"],
+    "yet2" => ["yets", "yot", "yetting", "yotten"],
+    "yewtree" => ["yewtrees", "yewtreed", "yewtreeing", "yewtreed"],
+    "yive" => ["yives", "yave", "yiving", "yiven"],
+    "yok" => ["yoks", "yokked", "yokking", "yokked"],
+    "yomp" => ["yomps", "yomped", "yomping", "yomped"],
+    "yot" => ["yots", "yotted", "yotting", "yotted"],
+    "yuk" => ["yuks", "yukked", "yukking", "yukked"],
+    "yume" => ["yumes", "yumed", "yumeing", "yumed"],
+    "yump" => ["yumps", "yumped", "yumping", "yumped"],
+    "zed" => ["zeds", "zedded", "zedding", "zedded"],
+    "zee" => ["zees", "zeed", "zeeing", "zeed"],
+    "zerg" => ["zergs", "zerged", "zerging", "zerged"],
+    "zero" => ["zeroes", "zeroed", "zeroing", "zeroed"],
+    "zhoozh" => ["zhoozhes", "zhoozhed", "zhoozhing", "zhoozhed"],
+    "zhuzh" => ["zhuzhes", "zhuzhed", "zhuzhing", "zhuzhed"],
+    "ziptie" => ["zipties", "ziptied", "ziptying", "ziptied"],
+    "zot" => ["zots", "zotted", "zotting", "zotted"],
+    "zuckerberg" => ["zuckerbergs", "zuckerberged", "zuckerberging", "zuckerberged"],
+    "zump" => ["zumps", "zumped", "zumping", "zumped"],
+    "zzz" => ["zzzs", "zzzed", "zzzing", "zzzed"],
 };
-
-pub fn get_verb_forms(infinitive: &str) -> Option<(&'static str, &'static str, &'static str, &'static str)> {
-    VERB_MAP.get(infinitive).copied()
-}
@@ -0,0 +1,1256 @@
+use crate::trie::{Edit, Trie};
+
+/// (3rd person singular, past, present participle, past participle)
+pub static VERB_MAP: Trie<4> = Trie::new(
+    b"\x00abcdefghijklmnopqrstuvwxyzbcdfghijklmnprstuvwyaeilmoruyahiloruvyaeiopruwxyabcefglmnprsuwxyaeilo\
+        rtuaehilnoruwyaeiouxycgmnxaeiopuaeilnrsuaeiouyaeiosuyaefiouybcdefklmnoruvaehilorsuvruaeimopstuac\
+        ehijklmnopqtuwyaehioprsuwyglmnprsaefiouaefhiorubefimaeiouehiouzehirsuckoqfjlmrsftagreomreinsaila\
+        ipsantapeimrhstgrstwntlraerbcdefmnrstuyabcdefghklmnprstwybdgklnorstvaeioubdgkoprtwxyaeioubcdfikl\
+        mnorstzbdjlmnprstuaeioruaeiouabcdfilmnopqrstuvwyaeiouybdemnrsteobdgkmrtyabcefghklmnprstvwabceglm\
+        npstvyzbdeglmnortuwaeiouybdemnbetvgobstwbcipcdfghijklnrstvwgpcstceptlbcklnrstuyadeltybdlnstxzaei\
+        ouybiorxaeioubglmnqrsbdfilmnrstuwyzeglmnoteiobdfglmnptuvaeiouabdlostaeiouabilmnrtycpcfgilmnrstuv\
+        yzailmnrtvwycdegnstbcdegilmnorstuybmrtdpbmpbcdefghjklpstwbcimnpruyertbmtbcglotuydgjmstzbkmnprbek\
+        lmnrsycdntaeiocdmnrstuvwyaeknprstvwbdegmnpstvbcglnostwimvenbcdgiklmnrstyadeglmrtcdgmnsbdlmnostuw\
+        dgmsetbmnruvyacetuwbgmtbdilmnopstwybdtctbcghlsticefghlstsbcdefghjklmprstvwyabcdefghjklmnprstwcdl\
+        mnrtyacdenrtaouycegklmnpstaeioudeilmnorstuwzaeiobglmnprstaeiobcdgmpstyzabcdefghijklmnopqrstuvwzb\
+        dfmnstzabcdeflmnotugbdfmntbcdfmnstuvyahiorudeglnrtwaehilnoruxbcdeglmnrteioruyaegiouehiouaeiouabd\
+        fjmnortuwyaeiloruyaeiaeioruybmnprsaeionrbiklmnprtxacdehklmrsaeiorucemnptabcdefghmnprstuwyaeioubm\
+        nprtaeioeprbcpabcdefghijklmnpqrstvwbcdfghjklmprstwbclmntxertdeszdlmotumrdklrsyabcdeitaeiouycglmn\
+        rstzlmnortaiobkveraibkmnruwadglnstwkmtkmderoucmagtydtlolaeceatraeooueiaeluadetszaectaorauaeiahip\
+        tbcfhprwkyybcfghprstwdknrmtrtalruahloruaeiorwyailoruaeioruaeioenaeiaeudeiaeioacehilmoptwaehirwei\
+        redgacdsdgterbmrtwbemstvmntvbortwxbersbsaebgkpgncsadnstyaenvycmnqtabdnwgelhppgipsyloep2acoabenpr\
+        htacsstmrstuvaceflmrsbdlmnrtvkmorbdmnstdmstyampcfnptdmotybmueireffndeloempcdfgtvcekmpraelyeidenr\
+        lptcernpeibprobmtvaesbemstcsuwdmtcegpbmtvcsopefaeddlaeilruaehlorjkmpaeioruaeoruuyaeiaiouaeiilrap\
+        acklptyeiwaeverdglpchiigabceimoprtvwaeestidmrcbgabcdfpstbgmtwyaesbfntuvopbmsecsptiraeutyalouprzl\
+        rktwehuweieiraoaeumuioresbcdfssltdlzailmdekhtmnstyaeymntbcobm2bcdtabcdeghlmnrstwbckuyestcstzbgln\
+        stflaetlopmglnpedobwauin2dmeybmbmobmtltcstblmstvyenydlmnstzbkouwbmalpskpscsafloptbsdgdpeodprlpio\
+        ycktwbgadjndilumsprpskslsjprterbdwaeriuaelreilrualorueraeolrloioaeceptaehreiorbppsjljzrcotaoebsg\
+        bwlbtwkqpgsnprestadpuvrsnp22bpcersptcsbijlmkp2hrdiplnedgksucqzstci2ntlaptiykripadiabcdefghiklmop\
+        rstuwcelkonpaeelspitnocrsbscgmsvocplrysorauaeemptahaelntaeilruaorueiorwacaeilraiorueioouenaeigna\
+        elorsuaeiucehiklnpqtuwaehraeiraeilrualoruaeoruyagmnxyaeilorueiloruaeioouaeiokneiaeloruaeiuacehik\
+        loptuwaehreiorkqippiyaklpsthr2aiprbmt2sntbtjssctantydtymscikyrsasetyabcdefglmoprstvwyemncdfglmop\
+        lslpevhtbdehrtyeudtzbdgiprsacdglprsvwaeilruachloruadeiloruyamnxaeiloruaeiraeiomnaeoueinaeoaeiade\
+        iovcruvaeilorutzeiuacehiklnoptuwyacehirnpsaeiaeiregepdpofkptigptbrdgcuctev2eblmnrtilotblmnouaeio\
+        udlmnrtckpuwfl2nbdklmnrzadlntwdekmnrtveueoprtuvwaeiuntbcrspugkuedelnbdmnteidmbmstyderydmntvbrtwb\
+        mrtgtrtmprabdetbeotwcmsgogtfptceimnrtyzaelnrtydentaeidotaeiudilrbdtgbdlrtzblmnrtyaemtclmnrbmotua\
+        eiouybdfmnrtgilmnpsuwmpdstceflsbcfmprvbilmntyabelkmnvbotrsegnmpipcrhnptelnbbiesyckrueioudmnbkssp\
+        tuerlurorctsuaefiophwerdfimnpyaekmnpblmdlmotuemstbpeikaoaeglntcskeocdgstlraeiloruahloraeioruyamn\
+        eioruaeioruaeioimrenaioaeoeuaeiloraeiaehiklnptuwaehiryaeiraeilruaoruilioruaeoenaeelueiuehptwaehi\
+        ajocewelusvsorsdgprflrvcgspwmteltdmnprszmodmrdldgthphstgntdtlp2epgostdytylyioaegydndnaoauaoioels\
+        waheicehlptwarryeoustaiaeagmaeiultlentaeyetbtymtnrvdntalwlniomydpvevdnraeagtadndsentwaeiounttwei\
+        oraeirueialirudeaeiudentntaiisydpdpeeikstblecgescvgtp2pprionpprcaehspphptruelrtauaeiereoey2crwaf\
+        merpcmsepibcfprbglndrsmnryaumnyaeuyddrbenaeiomtmydegkntbdsmtyauaeiraeirlroeognraeiuaeoumnehnyelr\
+        ueiaoilhiataouptweoepvpckcsryaelrlriuepbbpfpdpgpaeilruaeluiorbcdfghklmprstwaeioreoaeityaehilmptw\
+        aehraeofprwzgtbdfnykwpeppik2rsdelrfhkmswybsdohibksaoryeiablnrteiuwatentaeaeirbcdgklmnpstwaoukr2a\
+        hopeigsupweopradptcmvgleilruahloueioruamnaeioeioraeiofpenaeiaeaeilorueiuacehptuwaehireiorcgllcel\
+        riuntanateoaeidilrst2gnaibnteiylsyiocumtendnp2aoaoaddnvaoelntaioumnteiuaeiraeireiiroukraentntant\
+        dnteoeistaoumnaeafvtaeidleitioalnetmtanegdyaomtanvdnselntwaiornteiaeilraeiraeiknaliraeiuaetntaea\
+        iabioeotpotdlmeilruaehoueuymaeioraioaiaialreucehptuwaehirerprtabciscqmtyirnrtacdghlntdnteioueiod\
+        imrtlmnstdmsmnurtacflpsvaegsnaiubmbipjrvlnelrntbegmetntaeiobtvamwdteocfpstpruyiomyantosknegyfpdr\
+        tylnraeodtaolmtafmbnwyaorelntaeioruntmnaioadweiloraeirubmreiknraelirenaeioucgstlrtadtntnt2liscde\
+        lpppteougpbmntewpbobmipbcbos2emecamrptgjrlmmnvbmcpelpiopewgke2rppecsgsoueiekeecddlc2tytyadentyzg\
+        lpdtietlp2egkaptmnpvwydkmnvwybmtipeuehpadceaeiy2egki2gebcgtednvbewmsbpltaiantvaelplnpbcfmeoiygcs\
+        wnracdfgnstdnsteraeimmnstaiuimoaicprsaegmsaibmbpgmetlraomtnstalmdtlonyiodyosgkntaeaokyelntwaioru\
+        geioaeiloraeirmeialireneidrabdtntandnaeiioiydnpdselntoueraeiraeiahciyae2eahclmpcef2e2jembdghosta\
+        hcoltiobmtwmsntvze2kkrnteanntaenryamcreiougkcrdsfl2efseibcbcelybdfmprstairlueimptuwlcnbpjrtviogm\
+        rloenrrtdtrsaeirahoueorioreoenaeialeuacehiklmptwaeheirtyaenstkyentaueiaeraeaeicrbcdgilprstaeaeiu\
+        eterhptfjgaebglmrseroueoioioaeiaielehoeilrlseracfgdnstmnavagsaibmdlegmaioenyioaeaeomtafldnelntwe\
+        oeilorarbraeialiraiadnrttwaepsnvuwnydtkkrowgdnaennteintygkiuaemnourvdrteaedsemnvlprvdtdnpvnyowae\
+        naeimnyaeioaemnkdhkybdsestitmnemternylntworlrairteidtwaeacmpftvptnvdleeibmsntuwprtw2dpae2ntygpk2\
+        eowgkdnacnaeimnaemcnrknvcmmiyaelmcreloumncfmtrvdmr2pnpeipe2asrbceibmpbtltiyaiopaoaneuaihrdeaemsp\
+        bmsbcdefghklmprstweibsuwtwntydkreotdnmranntaieimnrycrknalaeaemnaeeinymnahmsiseaiaeiptahoteeandli\
+        lrylravitwehdstawantnyenteionterlnyaldvkangkeraouiodpehpmnireierttwadrtacemtvbsuwtwnykaelnntaemy\
+        knfmrvgkidkrtbwdkveueelfmgpswoteuaeilrualoruaeioreiueoraioaeiyaeiloueiuaehiloptaehrirpkynyaogkar\
+        aeidrkowaenlntotatdnteiemavaidleioateaodnelnteianairirntnyrtnyk",
+    b"areexcatchnemacasttbellayhopfrayectroochnuchepareepme2gnayeg2ompisrveygaameydjobfaylieppterychpp\
+        groupvpsambokuzujgtrascanpurpxhreednotransmitetveptietzorayorptnowyuitreezeudgibitorprayinseemyb\
+        otstrikennowyght2ytcraygramybaroorneyindtieostrikeeakkflegabtinadofpatelpgeshederphopouacyeepcar\
+        stkjumpfalokakelsydieolefieotomakeydmitkeyuoprrgnabekkridedonetlopcasn2etpesreadlotineetloreeone\
+        yoyoynitayuiolayrayeletielelltfindlowfeelndtrotmpgspotnsppumpgsurveyttolliestdytsubacastlpseyeel\
+        oppsipranteempelkneytariderpemliche2hdcasttokle2tiet2eeyseeybuberyeooynutkpotlbreakneyanprunrybu\
+        ild2keytrotrneyggptifiehobikazeyeeapppblamtketedchkeypacastmakedayeeeinskyht2e2geepgi2pchpinempl\
+        oyaguyacasteeerricdlemanhtthsoap2wumpkhmakeastemkeagprodarotransmithtclubeleprosgie2eetakecastzh\
+        urearasoldtomeshotallooldeakeieaieelpmpeepanuthntasiesicniceyedrivepestakedgmntcastppttsynchrtra\
+        yerslam2ppysitatebendequitpelpeeui2ekabyentgenogramlstompeplaypneiepekeephayntiscandogroup2orteh\
+        tolwritebfbptourneyersettieeemieerchuplebearpholdheepptnksgivenedeatfeeewicrneyindentaneastcasta\
+        uitatrawetakeindleypticutcastpebanpelphatghtbearketkeepwampedrawloppdshedmpngdstkeeppsaygtreepoz\
+        hzhkerbergprgeeeoyyedgweastryandoupyylclydedeeyanrayilzieunndnyketringanideunindeiteitrindoeatin\
+        d2saweepeyicadoanel22eakmpmeeeptmpeeleeraspmghtnadmybyrubeeputkeyndeessayontrolecaypinhdayotneyg\
+        seypepleduildlamnyfeedkeepattringitdetkguetdcastchbeatditeakeeeteebltoelaneoyeyieapploghitewayse\
+        felpksumpuptfeededsitepsekayepve22hefhangg22pppildvelopsiveedeetersleepadopitemrolcurieieynotttu\
+        royfercobumpeeplayh2achanstyeoyiteteedigatpcendopkpotspbarplampmbedrindumpstompurseyetastquatsay\
+        stampreampanrnduranrpirpaybedtrmatrsldbdrofreezetrytbnoyogramtinotnchninnleltetemramogerhumpastt\
+        bintntabeyayelopstlrotnoiesayknobomlethinkrgerawalllayrnetsmaketneteeephastcanrayunnansferdsmpas\
+        poprlayeeeutaspineeprtakeralrbaninlopeavenditertcelmbierplifientrplicsitimileoyastubic2herbede2g\
+        reegreeipsetdraw2eyebumphlight2gplowatchstagramyacastayghtcoasseebeeictruntbiteilytaoppetwayicis\
+        heeerbendgroupanriteppeetpseasttaalochpineeetbitebitepppgeyhoeshootutplalerbreakurgtringsubcbann\
+        gwayeupupaylotramnayobobdayopakeitaylayeyhotdeobaylebometdtnostooyovandowildmpaspopeeprfawllrdum\
+        putodpttyulpkerromitavendrkiteoetameyhideutkeebbanledmake2eyueyrablideuprtypesetohitway2ee2oye2e\
+        chpinbreedeadynch2canyconcamaoseeameumpcieswearriloeepmitueeipanacreeerplanhmakeneeeynmakemakeey\
+        occreadnbeynderstandastskinifieeyrunicyeylingc2ybotalloyanowcastayumpplitastroupakeampgramoncurh\
+        otpusoysttyaditelitandkerowyldrdneeptployelltholleleezevenartldityaeicyepmpychtyninobuatmkelliri\
+        ldtmpbetgployjoycelelletyrmatelfiltveutaranteengldyyopetmttogrammnyrubimwmrshipiteratuetsadocart\
+        rooprunleyfindolgoggreecastreakakeiteyumpitpotlaypatastticadotaycantohotermitfallrutfiteterramog\
+        freadelictompeyeeypin2reaugpeeoodetplanreelosaye2akeeooseaampm2ightetpelyregeesvepeltmpytettybdo\
+        ycurgtertakenseiubnchondertakeurpmpekeicastieiekitsserieheerstampiccastareeastrdayepanescumeyzla\
+        rshipooplopfitgmpheayetowelghaepamrlacd2arppstealtcutld2elbumdarasteadeelpbanichmphdotppeepplayh\
+        tppastodwritegramhsayflehsayegramkdgerwebttnfeedelzieeelfeedlkpppwderoupnferetitetlotnderletayer\
+        setayetanhatxcelatieeloyitayebutplprpleepidepinooyaccabayheesiteeetanedliepkoughgoghtcastapderst\
+        rikereakeepel2ytoeoepateakeavetindatompinttelbedoinkareadficsandpdelenburganerplppbleshootpteehu\
+        mplesetypeyygphtightsquatastrawoethinklotrayuyoosemetroleltreytvedlbreakdnarchrstandnynntaymadng\
+        2ninibbriterowstmeeepttveownngapldepityarcutucktndnkearroweyayobot2eyntellvoieoetpidelamrivearea\
+        ye2ieeteesapppgesawtleblowpperfeedcasthiphhtgeoocheehinkgquetddeayeekeotndtmpmmittrmatnayitearst\
+        ataddeanideinaingbansferedrtchtdepelhtwammldyedegrpdeytween2tyrodedepdaretgtdarnlnkowmpeyadmmppd\
+        yteeitsityethholdatayastramrvanhedolaniveakeitleadcstateyuphatryoldlamespinlayilrlotomitroltrbrg\
+        eeyateadritereritrolhpiehotthinkclayefendramdpmpmpbedrawyezetogeaicpnmmednead2tmmoyecinepreenulr\
+        ayrnddmprpncurlprrallayeferrveyrlyrshiptednuterstmetinreepaddekastetepppebeeakeedlanlamlightunad\
+        aeedeakrstfeedeavetmiveeelakebtveowleldengitekeaeadyrshipriteeieringoeeddeepaytroteeepobtanditay\
+        eedoldnitakemanumpnobboetreakeepitulicedngssaylotngpddnakeplewrnethinklwsteestatpooptenegunramho\
+        tpearltuppetideotrogramabodanreelotoweedildooseeavettployrollletrtvengartlderutyadekeynnrtrayogr\
+        amnyanokeendrshipiteropramogasttusosdebdddedyngldlshittchwdenglopnkeyrneyppygramddegemopldnetpep\
+        tnalnnktmpepgeddeedwmtchybtrolmeplerelopwepzeghtndwppdhttpdegeldkeneinkgepdennitepryechlnownsmit\
+        admmprpyyalantspotgramsittmitlotpndndoweakstloosevelopbeploybtrmateezebndupyghtketyaogramadnanli\
+        tampbmitabninntiteatersawssayroadcastastmmunoassaycanessionuititseayomeolddrayeedtengadcastldpst\
+        tppplreeerayloytroyelopgramwmpedtloyoyolelopldidcastatzeteepureruttileranrneytpdpeevousituttrolm\
+        itadtyppderakenopubldkearminktytpnittivemenkad2meutotandake2iehdrawgeeadehowastrawoperplotppopdr\
+        ivepeubanastokyhootey2anrppunumpunpkepastubochdibritecasthathotohoeapbeehreadastdg2ieeegeescumst\
+        ichbanmanchgleholdhangaigeegeetbletedreadtastlyetaseroadcastramransmitgetshototwvevedekearpubbre\
+        akspotloturypeeastetriteromeitetdpeakhopnldpptchngmpfmitpmreeloytroydemplaywnkpedloyatzefilg2prp\
+        edptepeicentteldtinktepttaliitngchlnkowmpvedrddyldyngeekldottalriveyepstateyyathpatrpeeeedyamane\
+        arrooldldkeinkatoplneppkeeeepekdydkwdewteh2iteedeteedleepereedastreedasteedeetehrowrteeedamptedt\
+        itrolpayomededloyoyolelopkeyopoppevetrarteingoosemetcreeawveaspldnowyeyaadnyaninayellaearkeinkep\
+        titetdeedketepngneadndalrtarnhrawnkeautowellrplleddentaaveiteotringampracetokdanpenddmaltndwtynd\
+        ettlaytinewndayeadloysayachepanomealletdtlelopgramplaywvepcastiveatndptntadtpderayegldtittimitve\
+        yyarngchlnkownsmdkdtkepeptekddadngdtpgste2kdpitke2eetkddyadngpdwwwrpgholdeanietunmitypesetldmitv\
+        eydldwinklmitnsmitmtnkdloghiterroleyilkeetvepdp2keerameltegkdyadngpdlpkgegecopitlrplachcastwstps\
+        ueitodransfereppethoindasteyanedoreakhroughllstnentrolmpmprdrrttinkaykdppppateepantveppowbaneteg\
+        kdytadngpkegrldkdgkdadngdgstattepinewvetteakakerowtdge2deedaweogramdoltnrraldietchrddwnegeaddche\
+        kdaringstmetveoweakpdbamedngveloghitroleyppkdldyadpegeritesticeeeeetrveyllayeritrablayhoritetadn\
+        rowildstubmeeptmpgetlfiltngtldyeyepnttadnyootngwekeachumpiteedtmpikeyarngntkdddtdeedngitlelopwve\
+        ndwdtegldepngkdndridenkowdhhold",
+    &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 2, 2, 2,
+        2, 2, 2, 2, 2, 3, 3, 4, 4, 4, 4, 4, 5, 5, 5, 5, 10, 10, 10, 18,
+        18, 18, 18, 18, 19, 20, 20, 20, 20, 20, 20, 20, 20, 23, 23, 23, 23, 25, 27, 28,
+        32, 32, 37, 37, 37, 40, 40, 40, 44, 44, 44, 45, 45, 45, 45, 45, 45, 45, 46, 46,
+        46, 46, 46, 46, 46, 48, 48, 48, 48, 51, 52, 52, 52, 52, 52, 52, 52, 52, 54, 55,
+        55, 55, 58, 58, 58, 58, 58, 61, 61, 61, 61, 61, 64, 64, 66, 66, 70, 70, 70, 70,
+        70, 70, 70, 70, 70, 70, 70, 71, 71, 71, 71, 71, 72, 72, 72, 72, 74, 76, 76, 80,
+        80, 81, 83, 86, 86, 86, 87, 88, 89, 91, 91, 91, 91, 91, 91, 91, 91, 94, 94, 95,
+        96, 96, 96, 96, 96, 101, 101, 101, 103, 104, 104, 104, 104, 104, 104, 104, 109, 109, 109, 109,
+        109, 109, 109, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 113, 113, 113, 113, 114,
+        121, 121, 121, 121, 122, 125, 125, 125, 126, 126, 126, 126, 126, 126, 127, 127, 127, 127, 127, 127,
+        131, 141, 142, 143, 143, 143, 143, 145, 145, 145, 145, 145, 149, 150, 150, 151, 151, 153, 153, 155,
+        158, 159, 159, 162, 163, 166, 171, 174, 176, 178, 178, 181, 184, 184, 187, 187, 189, 190, 191, 194,
+        200, 201, 202, 204, 204, 205, 209, 209, 210, 211, 211, 215, 219, 219, 219, 220, 220, 220, 220, 223,
+        223, 223, 223, 223, 224, 224, 224, 229, 232, 235, 236, 236, 236, 236, 242, 245, 245, 246, 246, 246,
+        252, 252, 252, 252, 258, 258, 259, 259, 259, 259, 259, 259, 260, 260, 260, 260, 260, 260, 260, 260,
+        262, 262, 262, 262, 262, 265, 265, 265, 266, 271, 274, 274, 274, 274, 277, 277, 281, 281, 281, 281,
+        281, 281, 281, 282, 282, 282, 282, 285, 285, 285, 285, 288, 288, 288, 288, 288, 288, 290, 290, 295,
+        295, 299, 299, 303, 305, 305, 305, 306, 306, 306, 306, 306, 306, 309, 311, 315, 315, 315, 317, 317,
+        317, 317, 317, 317, 317, 317, 317, 323, 323, 323, 323, 323, 323, 323, 327, 327, 330, 330, 330, 330,
+        330, 330, 330, 330, 330, 331, 331, 331, 331, 331, 331, 331, 331, 331, 331, 331, 331, 331, 332, 332,
+        332, 332, 332, 332, 332, 333, 333, 334, 336, 336, 336, 339, 339, 339, 339, 339, 339, 339, 339, 339,
+        339, 339, 339, 339, 341, 341, 341, 341, 341, 341, 341, 341, 341, 341, 341, 341, 346, 346, 346, 348,
+        348, 348, 351, 351, 351, 351, 351, 351, 351, 351, 351, 351, 354, 354, 354, 354, 357, 357, 357, 358,
+        358, 358, 358, 358, 358, 359, 359, 360, 361, 362, 362, 362, 362, 362, 368, 368, 368, 371, 371, 371,
+        373, 373, 375, 376, 378, 378, 378, 381, 381, 385, 387, 389, 392, 394, 396, 398, 398, 398, 398, 398,
+        398, 398, 398, 400, 403, 403, 403, 405, 405, 407, 407, 407, 409, 410, 410, 410, 412, 412, 418, 418,
+        418, 418, 418, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425, 425,
+        425, 427, 427, 427, 431, 431, 431, 431, 431, 433, 433, 434, 434, 434, 434, 434, 434, 438, 438, 438,
+        438, 440, 440, 440, 440, 440, 440, 440, 441, 442, 442, 445, 445, 446, 446, 446, 446, 452, 452, 455,
+        459, 461, 461, 463, 467, 472, 473, 474, 474, 477, 477, 478, 479, 479, 479, 479, 479, 479, 479, 479,
+        479, 482, 483, 486, 486, 486, 486, 486, 486, 486, 492, 492, 494, 494, 494, 494, 494, 494, 494, 496,
+        496, 500, 502, 506, 507, 507, 507, 507, 507, 508, 508, 509, 509, 509, 509, 509, 514, 514, 514, 514,
+        514, 514, 514, 514, 514, 514, 516, 516, 517, 522, 524, 524, 524, 528, 528, 528, 531, 533, 533, 534,
+        536, 536, 538, 540, 540, 540, 540, 543, 543, 543, 543, 543, 545, 545, 545, 545, 545, 545, 545, 547,
+        547, 547, 548, 550, 551, 551, 553, 553, 553, 553, 553, 557, 563, 563, 566, 568, 568, 569, 572, 572,
+        579, 579, 579, 579, 580, 580, 583, 587, 587, 587, 587, 591, 591, 592, 593, 593, 594, 599, 599, 600,
+        602, 602, 607, 609, 610, 611, 611, 612, 612, 613, 614, 614, 618, 619, 619, 620, 620, 620, 620, 620,
+        621, 622, 622, 622, 623, 623, 624, 624, 624, 629, 630, 635, 639, 639, 639, 639, 639, 640, 642, 642,
+        643, 643, 645, 650, 650, 650, 650, 653, 653, 653, 653, 653, 653, 654, 654, 655, 655, 655, 657, 657,
+        658, 658, 659, 662, 663, 663, 663, 668, 668, 668, 668, 668, 668, 668, 674, 678, 678, 678, 678, 678,
+        678, 678, 678, 678, 683, 685, 686, 687, 690, 690, 696, 698, 698, 698, 698, 698, 698, 698, 698, 698,
+        698, 698, 698, 704, 705, 705, 709, 709, 709, 710, 715, 715, 719, 719, 719, 724, 724, 724, 725, 727,
+        729, 729, 739, 739, 739, 745, 745, 745, 745, 745, 746, 752, 752, 752, 755, 756, 757, 762, 766, 766,
+        766, 768, 768, 770, 770, 773, 773, 775, 778, 778, 778, 778, 779, 782, 786, 789, 790, 793, 793, 794,
+        797, 799, 799, 799, 799, 799, 799, 799, 799, 799, 799, 799, 800, 800, 800, 800, 800, 802, 802, 805,
+        807, 807, 807, 807, 807, 807, 807, 807, 807, 810, 810, 811, 811, 811, 811, 811, 811, 811, 811, 811,
+        811, 811, 811, 811, 811, 811, 814, 815, 815, 815, 815, 815, 815, 821, 821, 821, 824, 827, 827, 827,
+        829, 835, 836, 836, 837, 842, 842, 842, 844, 845, 845, 845, 845, 845, 851, 851, 852, 852, 853, 854,
+        860, 860, 865, 871, 871, 871, 871, 871, 871, 871, 872, 872, 873, 873, 878, 878, 878, 878, 878, 878,
+        878, 878, 880, 885, 885, 890, 890, 893, 894, 894, 894, 896, 896, 896, 896, 896, 896, 896, 896, 896,
+        896, 896, 896, 896, 896, 896, 896, 896, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 899, 899,
+        899, 899, 899, 900, 900, 900, 905, 905, 915, 921, 926, 927, 929, 929, 929, 929, 929, 929, 929, 931,
+        932, 932, 932, 932, 932, 932, 937, 937, 937, 940, 940, 940, 940, 940, 940, 940, 947, 947, 947, 947,
+        947, 947, 947, 947, 948, 954, 954, 955, 955, 955, 955, 955, 955, 957, 957, 957, 957, 958, 958, 958,
+        959, 959, 961, 962, 962, 962, 962, 962, 962, 962, 963, 963, 963, 968, 968, 968, 968, 968, 968, 968,
+        969, 969, 969, 970, 970, 970, 970, 970, 970, 971, 972, 972, 972, 973, 979, 984, 984, 984, 987, 987,
+        987, 987, 987, 987, 987, 987, 987, 987, 987, 987, 988, 988, 989, 989, 989, 989, 989, 989, 989, 989,
+        992, 992, 992, 992, 994, 994, 994, 994, 994, 994, 994, 996, 998, 998, 999, 999, 1004, 1005, 1005, 1009,
+        1009, 1009, 1009, 1009, 1010, 1010, 1010, 1012, 1012, 1012, 1013, 1014, 1015, 1022, 1022, 1023, 1023, 1023, 1023, 1023,
+        1023, 1024, 1024, 1024, 1024, 1028, 1028, 1028, 1028, 1028, 1031, 1031, 1033, 1033, 1035, 1035, 1035, 1035, 1035, 1039,
+        1042, 1042, 1042, 1042, 1042, 1042, 1042, 1042, 1042, 1042, 1043, 1044, 1044, 1045, 1045, 1045, 1045, 1045, 1045, 1047,
+        1048, 1051, 1051, 1051, 1051, 1051, 1051, 1051, 1051, 1051, 1051, 1055, 1056, 1056, 1056, 1056, 1056, 1056, 1059, 1059,
+        1059, 1059, 1061, 1061, 1061, 1061, 1064, 1064, 1064, 1064, 1066, 1066, 1066, 1069, 1069, 1069, 1069, 1069, 1072, 1072,
+        1072, 1075, 1076, 1076, 1076, 1076, 1076, 1081, 1081, 1081, 1081, 1081, 1081, 1085, 1085, 1085, 1086, 1090, 1090, 1090,
+        1091, 1091, 1092, 1093, 1093, 1094, 1094, 1094, 1094, 1097, 1097, 1097, 1104, 1104, 1104, 1104, 1104, 1104, 1104, 1104,
+        1111, 1114, 1114, 1115, 1115, 1120, 1120, 1120, 1120, 1123, 1124, 1124, 1129, 1129, 1129, 1131, 1131, 1133, 1133, 1133,
+        1133, 1134, 1134, 1135, 1136, 1136, 1136, 1136, 1139, 1139, 1140, 1141, 1144, 1144, 1145, 1145, 1145, 1145, 1145, 1149,
+        1149, 1150, 1150, 1150, 1150, 1150, 1150, 1150, 1153, 1155, 1162, 1163, 1164, 1166, 1166, 1166, 1167, 1168, 1170, 1171,
+        1174, 1175, 1176, 1179, 1180, 1181, 1184, 1187, 1188, 1188, 1188, 1189, 1190, 1190, 1191, 1191, 1192, 1192, 1193, 1194,
+        1195, 1196, 1196, 1197, 1198, 1199, 1199, 1201, 1204, 1204, 1209, 1211, 1213, 1214, 1215, 1215, 1215, 1217, 1217, 1218,
+        1222, 1222, 1224, 1224, 1224, 1227, 1227, 1229, 1232, 1233, 1233, 1233, 1236, 1236, 1238, 1242, 1244, 1246, 1246, 1246,
+        1246, 1250, 1253, 1256, 1258, 1263, 1265, 1268, 1269, 1269, 1269, 1269, 1272, 1274, 1274, 1274, 1274, 1276, 1279, 1280,
+        1282, 1282, 1282, 1282, 1282, 1283, 1284, 1285, 1285, 1285, 1286, 1287, 1288, 1288, 1288, 1288, 1288, 1291, 1292, 1292,
+        1292, 1295, 1295, 1296, 1296, 1296, 1297, 1297, 1297, 1297, 1298, 1298, 1298, 1299, 1300, 1300, 1300, 1301, 1302, 1305,
+        1305, 1305, 1305, 1308, 1310, 1310, 1310, 1310, 1310, 1312, 1312, 1312, 1312, 1312, 1316, 1316, 1316, 1316, 1316, 1316,
+        1317, 1321, 1327, 1331, 1334, 1334, 1334, 1338, 1338, 1340, 1340, 1340, 1343, 1343, 1344, 1344, 1344, 1344, 1345, 1345,
+        1347, 1348, 1348, 1348, 1349, 1349, 1349, 1350, 1350, 1350, 1350, 1350, 1350, 1350, 1353, 1357, 1360, 1361, 1362, 1366,
+        1366, 1370, 1370, 1370, 1370, 1372, 1377, 1378, 1378, 1378, 1379, 1379, 1379, 1379, 1380, 1380, 1382, 1382, 1383, 1383,
+        1384, 1387, 1387, 1392, 1392, 1392, 1394, 1398, 1398, 1398, 1399, 1401, 1404, 1404, 1406, 1407, 1407, 1408, 1409, 1410,
+        1411, 1415, 1415, 1415, 1416, 1418, 1419, 1419, 1421, 1421, 1423, 1423, 1425, 1428, 1428, 1431, 1432, 1434, 1438, 1438,
+        1438, 1438, 1440, 1440, 1441, 1443, 1444, 1448, 1448, 1448, 1449, 1449, 1451, 1456, 1456, 1457, 1461, 1461, 1461, 1461,
+        1461, 1461, 1462, 1463, 1465, 1465, 1465, 1465, 1465, 1468, 1469, 1469, 1469, 1469, 1470, 1470, 1470, 1473, 1473, 1474,
+        1476, 1481, 1483, 1484, 1484, 1484, 1485, 1486, 1486, 1486, 1486, 1487, 1490, 1495, 1496, 1499, 1501, 1503, 1503, 1506,
+        1511, 1513, 1515, 1515, 1515, 1517, 1517, 1517, 1519, 1519, 1519, 1522, 1522, 1525, 1525, 1527, 1527, 1527, 1529, 1530,
+        1531, 1533, 1533, 1534, 1535, 1539, 1542, 1545, 1548, 1551, 1554, 1554, 1556, 1559, 1562, 1563, 1563, 1564, 1565, 1567,
+        1567, 1570, 1572, 1572, 1572, 1576, 1578, 1578, 1579, 1584, 1584, 1584, 1584, 1585, 1585, 1589, 1590, 1591, 1594, 1594,
+        1595, 1595, 1599, 1603, 1607, 1610, 1615, 1617, 1620, 1622, 1625, 1629, 1629, 1629, 1632, 1632, 1632, 1637, 1641, 1644,
+        1644, 1645, 1645, 1647, 1649, 1651, 1651, 1651, 1653, 1656, 1656, 1656, 1656, 1658, 1658, 1661, 1661, 1662, 1662, 1662,
+        1666, 1666, 1667, 1668, 1669, 1670, 1670, 1670, 1671, 1680, 1680, 1680, 1680, 1680, 1681, 1681, 1682, 1683, 1684, 1685,
+        1686, 1688, 1693, 1693, 1693, 1694, 1694, 1696, 1698, 1698, 1698, 1701, 1701, 1702, 1704, 1705, 1706, 1708, 1709, 1711,
+        1713, 1716, 1718, 1724, 1727, 1728, 1731, 1732, 1732, 1732, 1732, 1732, 1732, 1734, 1735, 1738, 1738, 1740, 1740, 1744,
+        1744, 1746, 1747, 1747, 1747, 1750, 1752, 1754, 1757, 1761, 1763, 1770, 1770, 1772, 1773, 1773, 1776, 1779, 1782, 1782,
+        1783, 1783, 1786, 1786, 1786, 1786, 1786, 1786, 1786, 1791, 1791, 1795, 1795, 1796, 1797, 1798, 1799, 1799, 1799, 1799,
+        1800, 1800, 1803, 1806, 1809, 1811, 1812, 1818, 1818, 1819, 1820, 1820, 1820, 1822, 1825, 1827, 1828, 1831, 1833, 1834,
+        1836, 1839, 1841, 1841, 1844, 1849, 1852, 1856, 1858, 1861, 1862, 1865, 1867, 1870, 1870, 1875, 1879, 1879, 1880, 1886,
+        1888, 1890, 1891, 1893, 1896, 1901, 1903, 1906, 1908, 1910, 1910, 1910, 1911, 1917, 1917, 1917, 1919, 1923, 1927, 1929,
+        1932, 1932, 1936, 1937, 1940, 1944, 1944, 1944, 1950, 1950, 1950, 1951, 1951, 1951, 1951, 1952, 1952, 1952, 1952, 1952,
+        1952, 1953, 1953, 1956, 1960, 1967, 1967, 1968, 1968, 1968, 1968, 1968, 1968, 1968, 1968, 1969, 1973, 1975, 1975, 1975,
+        1975, 1975, 1975, 1975, 1978, 1978, 1978, 1980, 1980, 1985, 1988, 1988, 1988, 1988, 1988, 1990, 1994, 1999, 1999, 2001,
+        2001, 2002, 2003, 2004, 2006, 2007, 2007, 2009, 2012, 2014, 2019, 2019, 2019, 2025, 2030, 2032, 2036, 2037, 2038, 2039,
+        2041, 2041, 2041, 2041, 2041, 2041, 2041, 2041, 2041, 2041, 2042, 2042, 2042, 2042, 2042, 2044, 2047, 2049, 2050, 2050,
+        2054, 2054, 2055, 2059, 2060, 2060, 2060, 2060, 2060, 2060, 2060, 2060, 2060, 2065, 2065, 2069, 2069, 2069, 2070, 2071,
+        2071, 2071, 2072, 2073, 2075, 2075, 2078, 2079, 2079, 2083, 2085, 2086, 2087, 2087, 2089, 2089, 2089, 2096, 2099, 2104,
+        2104, 2104, 2107, 2107, 2107, 2108, 2108, 2108, 2108, 2111, 2111, 2116, 2116, 2117, 2119, 2121, 2121, 2123, 2126, 2129,
+        2130, 2132, 2134, 2136, 2136, 2139, 2140, 2141, 2144, 2146, 2146, 2148, 2151, 2151, 2153, 2156, 2157, 2158, 2160, 2160,
+        2162, 2162, 2162, 2162, 2165, 2167, 2169, 2169, 2170, 2170, 2171, 2173, 2176, 2176, 2178, 2180, 2181, 2183, 2185, 2185,
+        2188, 2190, 2193, 2195, 2198, 2199, 2200, 2202, 2204, 2205, 2209, 2211, 2212, 2213, 2214, 2215, 2216, 2217, 2217, 2220,
+        2220, 2220, 2220, 2222, 2223, 2224, 2228, 2231, 2233, 2235, 2238, 2239, 2239, 2239, 2241, 2243, 2245, 2245, 2246, 2249,
+        2251, 2253, 2254, 2255, 2256, 2256, 2258, 2261, 2265, 2265, 2266, 2266, 2266, 2266, 2266, 2268, 2271, 2271, 2274, 2278,
+        2278, 2278, 2280, 2288, 2289, 2292, 2295, 2296, 2296, 2297, 2299, 2301, 2303, 2303, 2303, 2303, 2303, 2305, 2305, 2309,
+        2314, 2317, 2321, 2321, 2322, 2322, 2325, 2329, 2330, 2332, 2332, 2334, 2337, 2337, 2337, 2337, 2340, 2343, 2344, 2346,
+        2346, 2351, 2351, 2354, 2355, 2358, 2358, 2361, 2361, 2364, 2368, 2373, 2379, 2384, 2387, 2387, 2387, 2387, 2389, 2390,
+        2390, 2390, 2394, 2398, 2400, 2401, 2402, 2402, 2403, 2407, 2407, 2407, 2407, 2407, 2407, 2407, 2407, 2407, 2407, 2408,
+        2408, 2408, 2408, 2411, 2411, 2411, 2411, 2411, 2420, 2420, 2423, 2427, 2431, 2433, 2433, 2436, 2436, 2438, 2439, 2439,
+        2441, 2445, 2445, 2446, 2447, 2451, 2456, 2458, 2464, 2466, 2469, 2473, 2476, 2480, 2483, 2483, 2486, 2490, 2495, 2495,
+        2495, 2495, 2495, 2498, 2501, 2503, 2505, 2506, 2507, 2509, 2509, 2512, 2515, 2518, 2520, 2523, 2524, 2524, 2525, 2526,
+        2526, 2527, 2527, 2528, 2528, 2528, 2528, 2528, 2529, 2532, 2533, 2537, 2537, 2537, 2537, 2540, 2541, 2543, 2545, 2547,
+        2547, 2547, 2551, 2551, 2553, 2553, 2553, 2554, 2556, 2557, 2559, 2559, 2559, 2559, 2561, 2562, 2563, 2564, 2566, 2566,
+        2567, 2569, 2569, 2571, 2571, 2574, 2575, 2576, 2576, 2576, 2577, 2577, 2577, 2577, 2577, 2579, 2579, 2581, 2581, 2584,
+        2584, 2585, 2585, 2587, 2589, 2589, 2589, 2589, 2589, 2589, 2590, 2591, 2591, 2591, 2591, 2591, 2594, 2594, 2594, 2594,
+        2594, 2595, 2597, 2597, 2597, 2597, 2598, 2599, 2600, 2601, 2605, 2608, 2611, 2612, 2614, 2615, 2616, 2617, 2621, 2622,
+        2626, 2627, 2629, 2631, 2631, 2631, 2638, 2640, 2640, 2640, 2642, 2643, 2643, 2644, 2644, 2644, 2646, 2647, 2647, 2648,
+        2649, 2649, 2650, 2650, 2651, 2656, 2656, 2657, 2657, 2657, 2658, 2659, 2662, 2662, 2662, 2662, 2664, 2664, 2665, 2665,
+        2665, 2666, 2666, 2666, 2666, 2666, 2666, 2666, 2666, 2671, 2674, 2677, 2680, 2684, 2684, 2684, 2685, 2687, 2692, 2695,
+        2695, 2695, 2698, 2698, 2702, 2704, 2704, 2707, 2711, 2711, 2715, 2719, 2719, 2722, 2722, 2724, 2726, 2726, 2726, 2726,
+        2729, 2731, 2734, 2737, 2737, 2740, 2740, 2740, 2740, 2740, 2740, 2740, 2740, 2740, 2743, 2746, 2750, 2750, 2752, 2755,
+        2757, 2759, 2765, 2769, 2769, 2769, 2769, 2769, 2769, 2769, 2769, 2770, 2770, 2770, 2775, 2775, 2775, 2775, 2775, 2777,
+        2777, 2777, 2777, 2777, 2777, 2777, 2777, 2779, 2782, 2784, 2784, 2789, 2791, 2793, 2797, 2799, 2799, 2800, 2802, 2805,
+        2806, 2806, 2806, 2808, 2811, 2811, 2811, 2811, 2812, 2813, 2813, 2813, 2813, 2813, 2813, 2814, 2815, 2815, 2817, 2819,
+        2823, 2823, 2823, 2826, 2828, 2828, 2828, 2831, 2833, 2836, 2836, 2836, 2836, 2836, 2836, 2836, 2836, 2837, 2841, 2842,
+        2842, 2845, 2845, 2846, 2847, 2847, 2847, 2851, 2851, 2851, 2851, 2852, 2853, 2853, 2853, 2856, 2856, 2856, 2856, 2857,
+        2858, 2859, 2862, 2863, 2863, 2865, 2865, 2865, 2865, 2865, 2865, 2868, 2868, 2868, 2869, 2869, 2871, 2872, 2873, 2873,
+        2873, 2873, 2873, 2873, 2875, 2876, 2877, 2877, 2877, 2878, 2879, 2881, 2884, 2885, 2886, 2892, 2892, 2892, 2893, 2893,
+        2893, 2893, 2893, 2893, 2893, 2893, 2895, 2895, 2895, 2895, 2895, 2895, 2895, 2896, 2896, 2898, 2898, 2898, 2898, 2898,
+        2898, 2901, 2901, 2903, 2903, 2903, 2903, 2903, 2906, 2910, 2913, 2915, 2915, 2916, 2918, 2918, 2918, 2919, 2919, 2919,
+        2919, 2919, 2919, 2919, 2923, 2925, 2927, 2927, 2930, 2936, 2937, 2937, 2938, 2945, 2947, 2951, 2955, 2958, 2962, 2966,
+        2967, 2971, 2973, 2973, 2973, 2973, 2973, 2973, 2973, 2973, 2973, 2974, 2974, 2981, 2984, 2984, 2987, 2990, 2990, 2990,
+        2991, 2991, 2991, 2992, 2992, 2992, 2992, 2993, 2993, 2993, 2993, 2993, 2994, 2994, 2994, 2995, 2997, 2997, 2997, 2997,
+        2999, 2999, 3001, 3002, 3003, 3003, 3007, 3008, 3010, 3011, 3011, 3014, 3015, 3015, 3016, 3016, 3016, 3018, 3018, 3018,
+        3018, 3018, 3018, 3020, 3020, 3020, 3020, 3025, 3029, 3029, 3032, 3037, 3037, 3037, 3038, 3038, 3038, 3038, 3038, 3040,
+        3043, 3046, 3048, 3048, 3049, 3049, 3049, 3050, 3053, 3053, 3053, 3053, 3053, 3053, 3053, 3053, 3053, 3053, 3056, 3058,
+        3058, 3058, 3058, 3058, 3062, 3062, 3062, 3062, 3063, 3064, 3064, 3065, 3065, 3065, 3065, 3066, 3066, 3067, 3067, 3071,
+        3071, 3071, 3071, 3071, 3073, 3073, 3073, 3073, 3073, 3073, 3073, 3074, 3074, 3074, 3074, 3074, 3074, 3074, 3075, 3075,
+        3075, 3078, 3080, 3080, 3085, 3089, 3089, 3093, 3096, 3096, 3100, 3105, 3105, 3105, 3105, 3105, 3105, 3105, 3105, 3105,
+        3106, 3106, 3106, 3107, 3108, 3108, 3108, 3113, 3113, 3113, 3113, 3113, 3114, 3115, 3115, 3120, 3120, 3120, 3120, 3120,
+        3121, 3121, 3125, 3125, 3125, 3125, 3125, 3125, 3127, 3127, 3127, 3127, 3127, 3127, 3127, 3127, 3132, 3132, 3132, 3132,
+        3132, 3132, 3133, 3133, 3133, 3133, 3134, 3135, 3135, 3135, 3135, 3135, 3135, 3136, 3136, 3137, 3137, 3138, 3138, 3138,
+        3138, 3140, 3140, 3140, 3140, 3140, 3140, 3140, 3140, 3144, 3148, 3150, 3152, 3154, 3157, 3157, 3164, 3166, 3171, 3171,
+        3173, 3175, 3177, 3180, 3184, 3186, 3188, 3188, 3189, 3192, 3192, 3194, 3196, 3200, 3200, 3200, 3201, 3202, 3203, 3203,
+        3203, 3203, 3204, 3204, 3205, 3206, 3207, 3207, 3207, 3208, 3208, 3209, 3209, 3212, 3215, 3215, 3216, 3218, 3220, 3220,
+        3223, 3225, 3226, 3226, 3228, 3231, 3231, 3233, 3233, 3233, 3235, 3237, 3239, 3242, 3242, 3243, 3243, 3244, 3244, 3250,
+        3257, 3259, 3259, 3259, 3259, 3259, 3259, 3268, 3272, 3275, 3275, 3278, 3282, 3284, 3285, 3287, 3290, 3294, 3295, 3295,
+        3295, 3298, 3300, 3302, 3305, 3306, 3307, 3307, 3309, 3309, 3310, 3313, 3313, 3316, 3318, 3321, 3321, 3324, 3324, 3324,
+        3325, 3327, 3327, 3328, 3328, 3328, 3329, 3338, 3340, 3340, 3343, 3343, 3343, 3344, 3345, 3346, 3346, 3354, 3354, 3355,
+        3358, 3362, 3367, 3367, 3368, 3369, 3371, 3372, 3372, 3373, 3374, 3376, 3380, 3380, 3380, 3380, 3380, 3380, 3380, 3385,
+        3388, 3391, 3392, 3394, 3398, 3401, 3404, 3404, 3404, 3404, 3405, 3406, 3406, 3406, 3406, 3410, 3410, 3410, 3410, 3411,
+        3411, 3411, 3411, 3411, 3411, 3412, 3413, 3413, 3416, 3418, 3419, 3420, 3421, 3421, 3422, 3423, 3425, 3426, 3426, 3426,
+        3426, 3426, 3426, 3426, 3432, 3432, 3432, 3432, 3432, 3432, 3433, 3433, 3433, 3433, 3438, 3444, 3445, 3446, 3447, 3448,
+        3448, 3449, 3451, 3452, 3454, 3457, 3457, 3457, 3457, 3458, 3460, 3460, 3462, 3462, 3462, 3463, 3463, 3464, 3464, 3464,
+        3464, 3464, 3464, 3464, 3464, 3464, 3467, 3468, 3468, 3468, 3470, 3470, 3470, 3472, 3474, 3477, 3478, 3479, 3479, 3481,
+        3481, 3483, 3484, 3486, 3488, 3490, 3492, 3494, 3495, 3496, 3500, 3503, 3504, 3506, 3506, 3507, 3507, 3507, 3507, 3507,
+        3507, 3509, 3511, 3514, 3515, 3516, 3518, 3518, 3518, 3519, 3522, 3523, 3525, 3530, 3534, 3535, 3537, 3538, 3541, 3544,
+        3548, 3548, 3548, 3548, 3551, 3553, 3553, 3555, 3555, 3557, 3559, 3559, 3561, 3561, 3565, 3565, 3566, 3566, 3566, 3566,
+        3568, 3571, 3571, 3578, 3578, 3579, 3579, 3579, 3580, 3580, 3580, 3580, 3580, 3580, 3586, 3590, 3590, 3590, 3593, 3594,
+        3596, 3597, 3598, 3599, 3599, 3602, 3602, 3602, 3602, 3602, 3602, 3602, 3604, 3608, 3609, 3609, 3609, 3609, 3609, 3609,
+        3612, 3613, 3614, 3616, 3618, 3618, 3619, 3619, 3620, 3620, 3622, 3624, 3626, 3627, 3629, 3633, 3634, 3638, 3639, 3641,
+        3643, 3646, 3648, 3650, 3652, 3654, 3656, 3656, 3656, 3659, 3661, 3662, 3665, 3666, 3672, 3672, 3672, 3674, 3674, 3675,
+        3676, 3678, 3679, 3681, 3681, 3683, 3684, 3686, 3686, 3687, 3689, 3689, 3690, 3691, 3691, 3691, 3691, 3692, 3692, 3693,
+        3694, 3694, 3694, 3694, 3694, 3694, 3694, 3694, 3694, 3694, 3695, 3696, 3696, 3697, 3697, 3698, 3699, 3699, 3700, 3702,
+        3703, 3704, 3704, 3704, 3704, 3704, 3705, 3705, 3706, 3706, 3706, 3706, 3708, 3709, 3710, 3712, 3712, 3713, 3714, 3715,
+        3716, 3717, 3717, 3718, 3718, 3718, 3718, 3720, 3720, 3721, 3722, 3723, 3723, 3723, 3723, 3723, 3723, 3724, 3724, 3724,
+        3724, 3724, 3724, 3725, 3727, 3728, 3728, 3729, 3731, 3733, 3735, 3736, 3736, 3737, 3739, 3740, 3742, 3742, 3743, 3744,
+        3744, 3744, 3744, 3745, 3747, 3748, 3750, 3753, 3753, 3753, 3753, 3753, 3753, 3754, 3755, 3755, 3756, 3761, 3763, 3765,
+        3765, 3768, 3771, 3775, 3778, 3780, 3782, 3785, 3788, 3788, 3788, 3790, 3790, 3794, 3795, 3799, 3801, 3801, 3801, 3803,
+        3806, 3807, 3808, 3811, 3814, 3814, 3814, 3819, 3822, 3824, 3824, 3825, 3826, 3828, 3832, 3835, 3836, 3837, 3838, 3839,
+        3840, 3840, 3842, 3842, 3843, 3843, 3843, 3843, 3845, 3848, 3852, 3853, 3855, 3857, 3858, 3860, 3860, 3861, 3864, 3867,
+        3872, 3872, 3873, 3873, 3873, 3873, 3876, 3878, 3881, 3884, 3884, 3885, 3885, 3885, 3885, 3886, 3886, 3886, 3886, 3888,
+        3890, 3890, 3890, 3890, 3891, 3892, 3893, 3893, 3896, 3896, 3896, 3896, 3896, 3896, 3897, 3900, 3901, 3901, 3901, 3901,
+        3901, 3901, 3901, 3901, 3903, 3904, 3904, 3904, 3904, 3904, 3907, 3908, 3908, 3908, 3909, 3910, 3911, 3913, 3914, 3918,
+        3919, 3920, 3921, 3923, 3928, 3928, 3929, 3929, 3929, 3932, 3935, 3938, 3939, 3939, 3941, 3942, 3944, 3946, 3950, 3952,
+        3952, 3952, 3953, 3956, 3956, 3956, 3957, 3959, 3963, 3967, 3968, 3969, 3970, 3975, 3976, 3978, 3981, 3982, 3983, 3983,
+        3985, 3987, 3988, 3990, 3991, 3994, 3996, 3997, 3997, 3997, 3998, 3998, 3999, 3999, 4002, 4004, 4004, 4004, 4004, 4006,
+        4006, 4007, 4007, 4008, 4009, 4010, 4011, 4014, 4015, 4017, 4020, 4023, 4023, 4028, 4028, 4030, 4032, 4032, 4033, 4033,
+        4036, 4039, 4042, 4042, 4046, 4050, 4051, 4052, 4052, 4055, 4055, 4055, 4055, 4058, 4058, 4058, 4058, 4058, 4061, 4061,
+        4061, 4061, 4061, 4061, 4062, 4063, 4065, 4065, 4067, 4069, 4071, 4071, 4071, 4072, 4072, 4072, 4072, 4072, 4072, 4074,
+        4074, 4077, 4077, 4077, 4077, 4079, 4080, 4080, 4083, 4084, 4084, 4089, 4089, 4089, 4089, 4093, 4094, 4094, 4096, 4100,
+        4101, 4104, 4105, 4105, 4108, 4110, 4110, 4114, 4114, 4114, 4115, 4118, 4118, 4120, 4124, 4124, 4124, 4126, 4128, 4131,
+        4134, 4137, 4140, 4140, 4140, 4143, 4146, 4146, 4149, 4150, 4152, 4153, 4157, 4160, 4162, 4166, 4166, 4166, 4166, 4168,
+        4170, 4174, 4177, 4177, 4177, 4177, 4177, 4177, 4179, 4180, 4180, 4181, 4181, 4181, 4182, 4182, 4183, 4185, 4185, 4187,
+        4188, 4190, 4190, 4190, 4190, 4191, 4192, 4192, 4192, 4194, 4194, 4194, 4198, 4198, 4199, 4200, 4202, 4202, 4204, 4204,
+        4206, 4208, 4209, 4212, 4214, 4219, 4222, 4225, 4226, 4226, 4229, 4230, 4231, 4236, 4239, 4239, 4241, 4247, 4247, 4249,
+        4251, 4253, 4256, 4259, 4259, 4259, 4261, 4264, 4267, 4267, 4271, 4275, 4275, 4276, 4276, 4276, 4276, 4276, 4276, 4277,
+        4281, 4284, 4286, 4287, 4288, 4289, 4290, 4292, 4292, 4292, 4294, 4296, 4297, 4299, 4301, 4303, 4303, 4303, 4304, 4306,
+        4307, 4309, 4309, 4310, 4311, 4312, 4312, 4317, 4322, 4322, 4322, 4322, 4323, 4324, 4326, 4326, 4327, 4327, 4327, 4327,
+        4327, 4329, 4329, 4329, 4330, 4330, 4330, 4332, 4337, 4340, 4343, 4346, 4348, 4348, 4351, 4352, 4354, 4356, 4358, 4359,
+        4360, 4360, 4361, 4362, 4362, 4362, 4364, 4364, 4365, 4365, 4367, 4367, 4369, 4374, 4374, 4375, 4377, 4377, 4377, 4377,
+        4378, 4378, 4378, 4379, 4379, 4380, 4382, 4382, 4385, 4385, 4385, 4386, 4386, 4389, 4393, 4394, 4394, 4395, 4395, 4395,
+        4395, 4395, 4395, 4395, 4395, 4396, 4400, 4401, 4402, 4403, 4404, 4405, 4406, 4408, 4408, 4409, 4410, 4410, 4410, 4412,
+        4412, 4413, 4414, 4414, 4414, 4416, 4416, 4417, 4418, 4418, 4418, 4418, 4418, 4420, 4421, 4421, 4421, 4421, 4423, 4423,
+        4424, 4426, 4427, 4427, 4427, 4428, 4428, 4428, 4429, 4430, 4430, 4431, 4431, 4431, 4432, 4433, 4435, 4436, 4436, 4437,
+        4438, 4440, 4440, 4441, 4442, 4442, 4446, 4447, 4449, 4450, 4452, 4456, 4456, 4457, 4459, 4459, 4459, 4459, 4461, 4464,
+        4464, 4466, 4467, 4467, 4468, 4468, 4468, 4468, 4469, 4469, 4469, 4470, 4470, 4472, 4472, 4472, 4472, 4473, 4474, 4474,
+        4475, 4475, 4475, 4476, 4477, 4478, 4478, 4479, 4480, 4480, 4480, 4482, 4484, 4484, 4487, 4488, 4488, 4490, 4492, 4493,
+        4493, 4494, 4496, 4496, 4496, 4498, 4499, 4499, 4500, 4500, 4500, 4501, 4501, 4503, 4504, 4505, 4507, 4512, 4514, 4515,
+        4517, 4518, 4519, 4519, 4519, 4519, 4520, 4521, 4521, 4523, 4523, 4525, 4526, 4526, 4530, 4534, 4537, 4537, 4538, 4538,
+        4541, 4544, 4545, 4547, 4549, 4551, 4554, 4554, 4556, 4557, 4561, 4561, 4561, 4566, 4567, 4568, 4572, 4573, 4573, 4574,
+        4578, 4582, 4583, 4585, 4587, 4588, 4591, 4593, 4594, 4595, 4596, 4601, 4603, 4604, 4606, 4606, 4606, 4609, 4612, 4616,
+        4618, 4619, 4619, 4621, 4622, 4622, 4623, 4626, 4626, 4628, 4633, 4637, 4645, 4648, 4658, 4661, 4667, 4670, 4672, 4672,
+        4672, 4674, 4676, 4676, 4676, 4676, 4676, 4679, 4679, 4679, 4682, 4682, 4683, 4686, 4686, 4686, 4687, 4689, 4690, 4690,
+        4691, 4691, 4693, 4699, 4699, 4701, 4702, 4704, 4704, 4704, 4704, 4704, 4705, 4705, 4705, 4706, 4707, 4707, 4707, 4708,
+        4708, 4708, 4709, 4712, 4714, 4716, 4719, 4723, 4727, 4731, 4731, 4731, 4731, 4731, 4732, 4732, 4733, 4733, 4734, 4736,
+        4737, 4740, 4742, 4744, 4748, 4749, 4749, 4749, 4749, 4749, 4750, 4750, 4752, 4756, 4756, 4758, 4760, 4760, 4760, 4760,
+        4760, 4761, 4761, 4761, 4761, 4761, 4762, 4762, 4762, 4762, 4763, 4763, 4763, 4764, 4766, 4768, 4770, 4773, 4775, 4777,
+        4777, 4781, 4781, 4782, 4782, 4783, 4783, 4783, 4784, 4784, 4785, 4786, 4787, 4787, 4791, 4791, 4791, 4793, 4795, 4795,
+        4796, 4799, 4799, 4799, 4799, 4802, 4802, 4804, 4805, 4805, 4805, 4806, 4806, 4807, 4808, 4808, 4809, 4811, 4814, 4814,
+        4814, 4814, 4814, 4815, 4817, 4819, 4819, 4820, 4821, 4821, 4823, 4825, 4826, 4826, 4829, 4830, 4830, 4830, 4830, 4830,
+        4831, 4831, 4832, 4833, 4833, 4833, 4833, 4834, 4836, 4837, 4837, 4837, 4837, 4837, 4838, 4838, 4838, 4838, 4840, 4840,
+        4841, 4842, 4842, 4842, 4842, 4842, 4842, 4844, 4844, 4844, 4844, 4844, 4847, 4848, 4848, 4849, 4851, 4852, 4856, 4859,
+        4860, 4862, 4862, 4862, 4862, 4862, 4862, 4867, 4868, 4869, 4869, 4872, 4873, 4876, 4879, 4882, 4882, 4884, 4884, 4884,
+        4884, 4890, 4892, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4899, 4899, 4899, 4900, 4900, 4900, 4901, 4903,
+        4905, 4908, 4910, 4911, 4915, 4915, 4915, 4917, 4918, 4920, 4921, 4922, 4923, 4923, 4923, 4925, 4928, 4930, 4931, 4931,
+        4931, 4932, 4933, 4933, 4934, 4937, 4939, 4939, 4942, 4942, 4944, 4945, 4945, 4945, 4949, 4949, 4949, 4949, 4949, 4953,
+        4953, 4953, 4953, 4956, 4959, 4960, 4963, 4965, 4966, 4967, 4968, 4968, 4968, 4968, 4968, 4973, 4973, 4973, 4973, 4976,
+        4976, 4976, 4976, 4976, 4976, 4977, 4977, 4977, 4979, 4979, 4979, 4981, 4983, 4986, 4986, 4986, 4990, 4994, 4994, 4998,
+        5001, 5001, 5001, 5001, 5001, 5001, 5003, 5003, 5003, 5003, 5010, 5014, 5017, 5017, 5017, 5018, 5019, 5019, 5020, 5021,
+        5021, 5021, 5021, 5021, 5021, 5022, 5022, 5023, 5027, 5027, 5029, 5033, 5034, 5034, 5037, 5038, 5039, 5039, 5040, 5040,
+        5040, 5040, 5040, 5040, 5041, 5041, 5043, 5044, 5052, 5052, 5055, 5062, 5062, 5062, 5063, 5064, 5064, 5064, 5064, 5064,
+        5065, 5069, 5069, 5071, 5071, 5072, 5074, 5076, 5077, 5077, 5078, 5080, 5080, 5083, 5085, 5090, 5094, 5094, 5097, 5099,
+        5099, 5099, 5100, 5101, 5102, 5102, 5103, 5106, 5108, 5112, 5112, 5112, 5113, 5116, 5116, 5118, 5120, 5121, 5125, 5125,
+        5125, 5125, 5128, 5128, 5128, 5128, 5129, 5129, 5131, 5132, 5133, 5133, 5134, 5136, 5136, 5138, 5140, 5141, 5144, 5145,
+        5146, 5146, 5149, 5152, 5152, 5156, 5159, 5159, 5159, 5159, 5163, 5164, 5166, 5166, 5167, 5169, 5172, 5172, 5174, 5176,
+        5176, 5179, 5179, 5179, 5179, 5179, 5179, 5181, 5182, 5182, 5183, 5184, 5184, 5185, 5185, 5186, 5187, 5187, 5187, 5188,
+        5188, 5189, 5189, 5190, 5191, 5193, 5194, 5194, 5194, 5195, 5196, 5196, 5197, 5198, 5198, 5198, 5199, 5200, 5200, 5200,
+        5200, 5201, 5201, 5204, 5205, 5205, 5207, 5207, 5208, 5208, 5208, 5208, 5208, 5209, 5209, 5209, 5211, 5211, 5212, 5214,
+        5214, 5216, 5218, 5219, 5221, 5223, 5223, 5223, 5223, 5224, 5224, 5225, 5227, 5227, 5227, 5227, 5228, 5228, 5229, 5230,
+        5230, 5231, 5232, 5232, 5232, 5234, 5235, 5237, 5237, 5237, 5237, 5237, 5238, 5239, 5240, 5241, 5242, 5242, 5244, 5245,
+        5245, 5245, 5245, 5247, 5248, 5251, 5252, 5254, 5254, 5256, 5258, 5258, 5259, 5259, 5260, 5261, 5261, 5261, 5261, 5263,
+        5264, 5264, 5265, 5265, 5267, 5271, 5274, 5274, 5275, 5275, 5277, 5277, 5279, 5282, 5283, 5284, 5287, 5289, 5289, 5289,
+        5291, 5294, 5296, 5298, 5299, 5299, 5301, 5301, 5301, 5302, 5302, 5302, 5302, 5303, 5304, 5305, 5306, 5307, 5307, 5307,
+        5307, 5308, 5309, 5310, 5310, 5310, 5311, 5312, 5312, 5312, 5313, 5313, 5314, 5314, 5314, 5314, 5314, 5315, 5315, 5316,
+        5318, 5319, 5320, 5321, 5321, 5322, 5323, 5323, 5325, 5328, 5330, 5330, 5330, 5333, 5337, 5338, 5338, 5342, 5345, 5349,
+        5352, 5352, 5352, 5352, 5352, 5352, 5355, 5358, 5358, 5359, 5359, 5363, 5364, 5366, 5369, 5372, 5373, 5375, 5376, 5378,
+        5378, 5381, 5382, 5382, 5384, 5387, 5388, 5390, 5393, 5395, 5397, 5397, 5401, 5403, 5404, 5405, 5406, 5406, 5408, 5409,
+        5409, 5409, 5409, 5409, 5409, 5409, 5410, 5412, 5413, 5414, 5416, 5418, 5421, 5421, 5425, 5427, 5428, 5432, 5432, 5434,
+        5436, 5436, 5439, 5439, 5441, 5442, 5444, 5445, 5445, 5446, 5447, 5448, 5450, 5451, 5452, 5454, 5454, 5454, 5454, 5456,
+        5458, 5461, 5461, 5462, 5465, 5467, 5467, 5470, 5472, 5473, 5476, 5476, 5476, 5476, 5477, 5478, 5479, 5479, 5480, 5480,
+        5480, 5481, 5481, 5483, 5484, 5486, 5488, 5489, 5489, 5492, 5494, 5496, 5498, 5500, 5501, 5502, 5502, 5502, 5502, 5505,
+        5505, 5507, 5509, 5511, 5513, 5513, 5516, 5517, 5518, 5520, 5522, 5524, 5525, 5526, 5527, 5530, 5533, 5535, 5539, 5542,
+        5545, 5547, 5548, 5549, 5550, 5552, 5552, 5552, 5553, 5553, 5556, 5557, 5557, 5558, 5558, 5560, 5561, 5563, 5564, 5565,
+        5565, 5566, 5568, 5569, 5570, 5571, 5572, 5574, 5575, 5578, 5579, 5579, 5581, 5583, 5586, 5589, 5592, 5595, 5599, 5599,
+        5602, 5605, 5607, 5607, 5608, 5609, 5609, 5609, 5609, 5610, 5614, 5618, 5618, 5622, 5623, 5625, 5625, 5626, 5626, 5626,
+        5630, 5633, 5635, 5635, 5637, 5637, 5638, 5638, 5638, 5639, 5639, 5640, 5641, 5641, 5643, 5644, 5645, 5645, 5646, 5648,
+        5650, 5651, 5652, 5652, 5653, 5654, 5654, 5654, 5654, 5655, 5655, 5655, 5657, 5658, 5658, 5658, 5659, 5662, 5665, 5666,
+        5668, 5670, 5672, 5673, 5675, 5677, 5679, 5680, 5680, 5680, 5681, 5681, 5681, 5681, 5681, 5682, 5683, 5683, 5684, 5685,
+        5686, 5687, 5687, 5687, 5687, 5687, 5687, 5687, 5688, 5689, 5690, 5690, 5690, 5691, 5691, 5692, 5693, 5694, 5694, 5694,
+        5696, 5698, 5699, 5699, 5699, 5699, 5699, 5699, 5700, 5700, 5701, 5701, 5702, 5702, 5704, 5704, 5705, 5706, 5706, 5706,
+        5706, 5707, 5708, 5708, 5709, 5709, 5711, 5712, 5713, 5714, 5714, 5714, 5715, 5715, 5715, 5715, 5715, 5715, 5716, 5716,
+        5716, 5717, 5717, 5718, 5719, 5720, 5721, 5723, 5725, 5726, 5727, 5727, 5728, 5729, 5729, 5730, 5731, 5732, 5732, 5733,
+        5733, 5733, 5737, 5738, 5738, 5740, 5742, 5745, 5748, 5748, 5754, 5756, 5756, 5759, 5762, 5762, 5762, 5762, 5763, 5763,
+        5763, 5763, 5764, 5765, 5765, 5765, 5766, 5769, 5770, 5773, 5778, 5779, 5779, 5779, 5780, 5781, 5781, 5781, 5781, 5782,
+        5783, 5786, 5787, 5789, 5789, 5791, 5794, 5796, 5796, 5798, 5799, 5800, 5800, 5800, 5801, 5802, 5804, 5804, 5804, 5805,
+        5806, 5806, 5807, 5807, 5807, 5807, 5807, 5807, 5807, 5808, 5808, 5810, 5811, 5811, 5811, 5811, 5811, 5814, 5816, 5816,
+        5816, 5816, 5817, 5817, 5817, 5817, 5818, 5819, 5820, 5820, 5821, 5822, 5824, 5826, 5827, 5828, 5829, 5830, 5830, 5831,
+        5832, 5832, 5833, 5834, 5835, 5837, 5838, 5840, 5841, 5841, 5842, 5843, 5844, 5846, 5847, 5847, 5851, 5851, 5852, 5854,
+        5855, 5856, 5858, 5860, 5862, 5869, 5869, 5870, 5870, 5871, 5872, 5872, 5872, 5872, 5872, 5873, 5873, 5873, 5873, 5873,
+        5874, 5876, 5876, 5879, 5882, 5882, 5883, 5884, 5886, 5889, 5893, 5899, 5900, 5900, 5901, 5901, 5903, 5905, 5910, 5910,
+        5912, 5914, 5915, 5916, 5917, 5918, 5919, 5920, 5923, 5925, 5925, 5925, 5926, 5927, 5928, 5929, 5929, 5929, 5930, 5931,
+        5931, 5931, 5931, 5933, 5933, 5933, 5933, 5936, 5936, 5938, 5938, 5938, 5938, 5938, 5938, 5939, 5941, 5941, 5942, 5943,
+        5943, 5943, 5943, 5943, 5943, 5943, 5948, 5949, 5949, 5949, 5950, 5950, 5951, 5952, 5952, 5952, 5953, 5954, 5954, 5954,
+        5955, 5956, 5958, 5960, 5961, 5961, 5961, 5961, 5962, 5962, 5963, 5964, 5965, 5966, 5967, 5967, 5968, 5969, 5969, 5970,
+        5971, 5972, 5974, 5976, 5977, 5977, 5977, 5978, 5980, 5982, 5982, 5983, 5984, 5987, 5988, 5989, 5991, 5991, 5992, 5993,
+        5996, 5997, 5999, 6002, 6003, 6003, 6003, 6003, 6004, 6006, 6007, 6007, 6008, 6011, 6013, 6014, 6014, 6019, 6020, 6023,
+        6024, 6024, 6024, 6025, 6028, 6031, 6032, 6034, 6035, 6035, 6035, 6036, 6036, 6036, 6036, 6036, 6037, 6037, 6038, 6040,
+        6040, 6041, 6041, 6041, 6044, 6044, 6045, 6045, 6047, 6047, 6047, 6048, 6048, 6049, 6050, 6050, 6050, 6052, 6055, 6057,
+        6059, 6060, 6062, 6062, 6062, 6062, 6062, 6064, 6067, 6068, 6069, 6070, 6072, 6074, 6076, 6076, 6076, 6076, 6076, 6076,
+        6078, 6078, 6078, 6078, 6081, 6082, 6082, 6084, 6087, 6089, 6089, 6090, 6091, 6091, 6091, 6091, 6091, 6091, 6091, 6092,
+        6093, 6094, 6095, 6095, 6095, 6096, 6098, 6099, 6099, 6100, 6101, 6103, 6105, 6105, 6106, 6106, 6106, 6110, 6111, 6112,
+        6112, 6112, 6112, 6112, 6113, 6114, 6115, 6116, 6120, 6121, 6122, 6124, 6126, 6128, 6131, 6134, 6136, 6140, 6141, 6141,
+        6143, 6144, 6145, 6145, 6145, 6147, 6147, 6150, 6152, 6154, 6155, 6158, 6159, 6161, 6161, 6162, 6162, 6162, 6163, 6164,
+        6168, 6169, 6169, 6169, 6171, 6172, 6174, 6175, 6175, 6176, 6176, 6177, 6179, 6180, 6180, 6181, 6182, 6184, 6184, 6185,
+        6186, 6186, 6189, 6191, 6191, 6192, 6193, 6193, 6195, 6198, 6198, 6201, 6201, 6204, 6204, 6205, 6205, 6206, 6206, 6206,
+        6207, 6207, 6207, 6209, 6212, 6213, 6215, 6217, 6217, 6218, 6218, 6219, 6219, 6220, 6221, 6222, 6222, 6223, 6223, 6224,
+        6224, 6224, 6224, 6224, 6225, 6226, 6228, 6230, 6230, 6232, 6233, 6237, 6238, 6240, 6240, 6240, 6240, 6242, 6243, 6244,
+        6244, 6244, 6244, 6245, 6246, 6247, 6247, 6248, 6249, 6249, 6251, 6253, 6254, 6255, 6257, 6258, 6261, 6263, 6265, 6266,
+        6271, 6271, 6271, 6271, 6271, 6271, 6271, 6271,
+    ],
+    &[
+        1, 27, 47, 56, 65, 75, 91, 99, 110, 117, 122, 128, 136, 142, 149, 156, 169, 179, 181, 190,
+        207, 218, 225, 231, 239, 244, 249, 255, 261, 265, 271, 273, 276, 278, 280, 280, 284, 287, 291, 294,
+        296, 300, 303, 308, 310, 312, 315, 315, 327, 345, 356, 361, 361, 372, 377, 391, 391, 402, 408, 408,
+        413, 432, 438, 446, 446, 448, 456, 473, 487, 499, 499, 505, 510, 510, 510, 512, 514, 514, 514, 514,
+        514, 516, 520, 524, 539, 539, 541, 544, 544, 544, 548, 549, 559, 565, 573, 579, 584, 589, 589, 597,
+        611, 618, 621, 632, 637, 637, 644, 649, 658, 658, 660, 674, 684, 691, 706, 710, 710, 712, 712, 712,
+        715, 729, 729, 738, 741, 744, 752, 752, 759, 765, 774, 778, 778, 782, 782, 782, 782, 793, 803, 813,
+        822, 825, 827, 840, 848, 854, 864, 864, 868, 870, 877, 883, 883, 887, 899, 902, 902, 902, 904, 904,
+        904, 911, 911, 911, 912, 920, 921, 921, 939, 957, 965, 972, 976, 986, 991, 1004, 1008, 1008, 1017, 1017,
+        1017, 1021, 1031, 1055, 1063, 1063, 1075, 1076, 1076, 1076, 1082, 1093, 1099, 1107, 1117, 1127, 1127, 1133, 1139, 1144,
+        1149, 1162, 1170, 1173, 1180, 1186, 1190, 1192, 1202, 1212, 1218, 1224, 1241, 1241, 1246, 1246, 1252, 1256, 1259, 1259,
+        1259, 1262, 1283, 1298, 1298, 1298, 1305, 1308, 1308, 1312, 1318, 1320, 1326, 1333, 1333, 1339, 1348, 1354, 1357, 1360,
+        1360, 1360, 1362, 1362, 1364, 1371, 1379, 1379, 1382, 1384, 1387, 1389, 1389, 1389, 1391, 1391, 1395, 1395, 1397, 1397,
+        1397, 1397, 1399, 1400, 1400, 1400, 1400, 1400, 1400, 1400, 1402, 1402, 1402, 1404, 1406, 1407, 1407, 1407, 1407, 1407,
+        1407, 1407, 1407, 1407, 1407, 1407, 1407, 1410, 1410, 1410, 1410, 1410, 1410, 1412, 1414, 1414, 1418, 1422, 1424, 1424,
+        1426, 1428, 1431, 1433, 1433, 1436, 1441, 1441, 1441, 1441, 1448, 1448, 1448, 1450, 1450, 1450, 1451, 1461, 1461, 1461,
+        1461, 1461, 1462, 1465, 1465, 1467, 1467, 1467, 1469, 1473, 1479, 1486, 1486, 1492, 1498, 1502, 1504, 1507, 1510, 1513,
+        1513, 1517, 1528, 1534, 1537, 1537, 1537, 1538, 1538, 1538, 1538, 1540, 1544, 1547, 1547, 1549, 1549, 1554, 1560, 1564,
+        1570, 1573, 1574, 1576, 1578, 1578, 1582, 1582, 1584, 1584, 1586, 1586, 1586, 1592, 1597, 1602, 1607, 1607, 1607, 1607,
+        1608, 1608, 1610, 1610, 1612, 1613, 1614, 1614, 1618, 1618, 1618, 1618, 1620, 1620, 1620, 1620, 1622, 1626, 1626, 1632,
+        1634, 1637, 1639, 1645, 1653, 1661, 1665, 1665, 1671, 1676, 1679, 1684, 1689, 1691, 1691, 1692, 1692, 1695, 1697, 1699,
+        1703, 1706, 1712, 1718, 1722, 1724, 1728, 1731, 1734, 1736, 1738, 1741, 1742, 1746, 1749, 1754, 1758, 1761, 1765, 1765,
+        1765, 1765, 1765, 1765, 1769, 1769, 1769, 1771, 1771, 1771, 1772, 1772, 1772, 1773, 1775, 1777, 1778, 1780, 1786, 1792,
+        1796, 1802, 1807, 1809, 1809, 1812, 1816, 1819, 1822, 1824, 1831, 1834, 1837, 1839, 1843, 1843, 1843, 1844, 1846, 1846,
+        1847, 1848, 1848, 1860, 1860, 1862, 1862, 1862, 1862, 1862, 1862, 1865, 1865, 1866, 1866, 1869, 1869, 1870, 1872, 1880,
+        1886, 1889, 1895, 1897, 1899, 1899, 1899, 1899, 1899, 1899, 1900, 1900, 1900, 1901, 1901, 1901, 1903, 1903, 1905, 1907,
+        1907, 1910, 1910, 1910, 1912, 1916, 1918, 1919, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1924, 1928, 1930, 1933,
+        1933, 1933, 1935, 1935, 1935, 1938, 1940, 1940, 1942, 1942, 1943, 1945, 1945, 1945, 1950, 1951, 1951, 1951, 1951, 1951,
+        1953, 1953, 1956, 1956, 1956, 1956, 1956, 1956, 1960, 1963, 1965, 1965, 1965, 1965, 1970, 1973, 1976, 1979, 1981, 1984,
+        1984, 1984, 1986, 2000, 2000, 2005, 2008, 2012, 2018, 2018, 2018, 2018, 2020, 2020, 2020, 2020, 2020, 2020, 2020, 2020,
+        2020, 2023, 2026, 2027, 2028, 2030, 2031, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2034, 2034, 2034, 2034,
+        2034, 2036, 2036, 2038, 2038, 2038, 2040, 2040, 2041, 2041, 2041, 2041, 2041, 2043, 2045, 2047, 2050, 2053, 2055, 2055,
+        2056, 2056, 2056, 2056, 2058, 2065, 2068, 2075, 2080, 2082, 2082, 2082, 2082, 2085, 2086, 2086, 2088, 2089, 2089, 2089,
+        2089, 2089, 2089, 2089, 2091, 2097, 2099, 2101, 2103, 2103, 2103, 2103, 2105, 2105, 2105, 2108, 2108, 2110, 2111, 2111,
+        2113, 2113, 2113, 2113, 2113, 2115, 2115, 2115, 2117, 2117, 2119, 2119, 2123, 2123, 2123, 2123, 2123, 2123, 2127, 2129,
+        2129, 2131, 2133, 2133, 2135, 2137, 2137, 2137, 2139, 2141, 2141, 2143, 2146, 2149, 2151, 2155, 2160, 2165, 2167, 2167,
+        2170, 2172, 2174, 2174, 2176, 2178, 2178, 2182, 2186, 2190, 2190, 2190, 2190, 2191, 2191, 2191, 2192, 2192, 2192, 2193,
+        2193, 2194, 2194, 2195, 2195, 2195, 2195, 2195, 2196, 2198, 2198, 2198, 2199, 2199, 2199, 2199, 2200, 2200, 2200, 2200,
+        2200, 2200, 2200, 2200, 2200, 2202, 2204, 2204, 2205, 2205, 2205, 2205, 2205, 2205, 2205, 2207, 2207, 2208, 2208, 2210,
+        2211, 2211, 2214, 2216, 2216, 2217, 2219, 2222, 2225, 2225, 2225, 2225, 2225, 2226, 2230, 2232, 2232, 2232, 2232, 2234,
+        2234, 2235, 2235, 2235, 2235, 2235, 2236, 2236, 2238, 2240, 2242, 2244, 2244, 2246, 2247, 2247, 2249, 2251, 2251, 2253,
+        2253, 2253, 2253, 2253, 2253, 2253, 2254, 2254, 2254, 2256, 2256, 2259, 2261, 2262, 2262, 2262, 2267, 2270, 2272, 2274,
+        2275, 2277, 2278, 2281, 2281, 2281, 2281, 2283, 2283, 2285, 2285, 2285, 2287, 2290, 2309, 2310, 2310, 2312, 2312, 2314,
+        2316, 2318, 2320, 2320, 2320, 2321, 2321, 2322, 2324, 2324, 2324, 2324, 2324, 2326, 2328, 2328, 2328, 2329, 2329, 2330,
+        2330, 2331, 2331, 2336, 2336, 2336, 2336, 2336, 2336, 2336, 2336, 2336, 2337, 2339, 2339, 2339, 2342, 2342, 2342, 2342,
+        2342, 2342, 2343, 2343, 2345, 2345, 2347, 2347, 2347, 2349, 2353, 2355, 2355, 2355, 2355, 2355, 2355, 2355, 2357, 2360,
+        2360, 2360, 2366, 2370, 2375, 2377, 2382, 2387, 2390, 2392, 2394, 2397, 2399, 2406, 2410, 2422, 2426, 2426, 2430, 2430,
+        2430, 2436, 2441, 2447, 2453, 2460, 2466, 2470, 2472, 2472, 2476, 2478, 2480, 2486, 2490, 2502, 2506, 2510, 2512, 2512,
+        2514, 2515, 2517, 2523, 2525, 2526, 2526, 2526, 2528, 2530, 2530, 2533, 2535, 2535, 2537, 2539, 2539, 2539, 2539, 2541,
+        2541, 2541, 2541, 2542, 2544, 2544, 2545, 2548, 2548, 2548, 2551, 2553, 2554, 2554, 2554, 2557, 2557, 2557, 2557, 2559,
+        2559, 2561, 2561, 2561, 2561, 2564, 2581, 2584, 2592, 2594, 2594, 2596, 2596, 2596, 2596, 2598, 2599, 2600, 2607, 2609,
+        2612, 2614, 2614, 2614, 2616, 2616, 2619, 2619, 2619, 2620, 2620, 2620, 2629, 2635, 2642, 2651, 2655, 2662, 2666, 2670,
+        2672, 2676, 2679, 2682, 2685, 2691, 2695, 2702, 2704, 2707, 2721, 2727, 2730, 2733, 2737, 2737, 2737, 2738, 2738, 2738,
+        2739, 2741, 2741, 2741, 2743, 2744, 2744, 2744, 2744, 2744, 2744, 2744, 2744, 2748, 2749, 2752, 2752, 2754, 2754, 2754,
+        2754, 2754, 2754, 2754, 2754, 2754, 2754, 2754, 2756, 2756, 2758, 2760, 2762, 2764, 2770, 2774, 2774, 2780, 2785, 2791,
+        2791, 2794, 2796, 2798, 2798, 2798, 2800, 2800, 2808, 2814, 2814, 2822, 2824, 2824, 2832, 2836, 2838, 2838, 2838, 2838,
+        2840, 2840, 2842, 2842, 2844, 2846, 2847, 2848, 2852, 2857, 2857, 2859, 2861, 2861, 2866, 2870, 2870, 2875, 2879, 2883,
+        2883, 2883, 2885, 2885, 2887, 2890, 2895, 2897, 2900, 2900, 2900, 2900, 2901, 2903, 2903, 2903, 2905, 2907, 2907, 2907,
+        2910, 2910, 2910, 2919, 2926, 2930, 2933, 2936, 2940, 2944, 2944, 2947, 2948, 2954, 2961, 2965, 2970, 2975, 2981, 2988,
+        2988, 2997, 2999, 3002, 3007, 3014, 3014, 3021, 3025, 3029, 3032, 3032, 3032, 3032, 3034, 3035, 3035, 3035, 3037, 3037,
+        3039, 3039, 3041, 3043, 3043, 3043, 3047, 3047, 3047, 3050, 3051, 3051, 3053, 3053, 3056, 3058, 3060, 3064, 3067, 3067,
+        3068, 3070, 3071, 3073, 3074, 3074, 3076, 3078, 3079, 3079, 3079, 3081, 3081, 3083, 3083, 3085, 3091, 3093, 3095, 3095,
+        3095, 3095, 3102, 3108, 3111, 3117, 3120, 3121, 3123, 3123, 3123, 3126, 3128, 3128, 3130, 3134, 3136, 3137, 3139, 3139,
+        3144, 3144, 3144, 3146, 3153, 3158, 3165, 3168, 3173, 3179, 3183, 3183, 3186, 3188, 3191, 3194, 3196, 3202, 3202, 3205,
+        3216, 3222, 3222, 3226, 3232, 3236, 3236, 3238, 3242, 3245, 3245, 3247, 3249, 3249, 3252, 3255, 3260, 3264, 3264, 3264,
+        3265, 3265, 3265, 3265, 3265, 3265, 3266, 3266, 3267, 3269, 3270, 3271, 3271, 3271, 3273, 3273, 3273, 3273, 3275, 3275,
+        3275, 3276, 3276, 3279, 3283, 3283, 3285, 3287, 3288, 3288, 3290, 3292, 3292, 3292, 3294, 3297, 3304, 3306, 3309, 3309,
+        3309, 3309, 3311, 3311, 3314, 3314, 3316, 3317, 3317, 3317, 3317, 3317, 3317, 3319, 3319, 3319, 3322, 3324, 3324, 3324,
+        3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3324, 3326, 3326, 3326, 3327, 3327,
+        3327, 3327, 3327, 3327, 3329, 3329, 3329, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331, 3331,
+        3331, 3331, 3331, 3331, 3333, 3333, 3333, 3333, 3333, 3335, 3337, 3337, 3337, 3337, 3337, 3339, 3339, 3341, 3341, 3341,
+        3341, 3341, 3341, 3341, 3341, 3341, 3343, 3343, 3343, 3345, 3345, 3345, 3345, 3345, 3345, 3347, 3349, 3349, 3351, 3351,
+        3351, 3353, 3353, 3355, 3357, 3357, 3359, 3359, 3359, 3359, 3359, 3361, 3361, 3363, 3363, 3363, 3363, 3363, 3365, 3372,
+        3374, 3374, 3374, 3374, 3374, 3374, 3374, 3374, 3374, 3374, 3376, 3379, 3379, 3379, 3381, 3383, 3385, 3385, 3385, 3385,
+        3385, 3386, 3388, 3388, 3392, 3394, 3394, 3395, 3398, 3401, 3401, 3403, 3403, 3406, 3408, 3411, 3412, 3412, 3412, 3414,
+        3417, 3417, 3419, 3419, 3421, 3423, 3426, 3428, 3431, 3433, 3433, 3434, 3436, 3436, 3436, 3439, 3441, 3441, 3441, 3441,
+        3445, 3450, 3452, 3452, 3452, 3454, 3458, 3463, 3465, 3465, 3467, 3470, 3472, 3476, 3476, 3480, 3482, 3484, 3484, 3484,
+        3484, 3484, 3484, 3484, 3484, 3487, 3487, 3487, 3487, 3487, 3487, 3487, 3487, 3487, 3487, 3487, 3489, 3489, 3489, 3489,
+        3489, 3489, 3489, 3489, 3489, 3489, 3491, 3491, 3491, 3492, 3492, 3492, 3492, 3492, 3492, 3492, 3492, 3492, 3492, 3492,
+        3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3494, 3497, 3497, 3497, 3497, 3497, 3499, 3499,
+        3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3500, 3502,
+        3502, 3502, 3504, 3504, 3504, 3504, 3504, 3506, 3506, 3508, 3508, 3508, 3508, 3508, 3508, 3508, 3508, 3508, 3508, 3508,
+        3509, 3511, 3511, 3511, 3511, 3511, 3511, 3511, 3513, 3513, 3513, 3515, 3515, 3515, 3515, 3515, 3515, 3517, 3517, 3519,
+        3520, 3522, 3524, 3524, 3524, 3524, 3524, 3524, 3525, 3525, 3525, 3525, 3525, 3526, 3526, 3526, 3526, 3526, 3526, 3526,
+        3526, 3526, 3526, 3526, 3526, 3526, 3526, 3526, 3527, 3527, 3527, 3527, 3527, 3527, 3529, 3529, 3529, 3529, 3529, 3529,
+        3529, 3529, 3529, 3531, 3531, 3531, 3535, 3537, 3537, 3540, 3542, 3542, 3544, 3544, 3546, 3546, 3547, 3547, 3547, 3547,
+        3547, 3547, 3550, 3550, 3550, 3550, 3550, 3550, 3550, 3550, 3550, 3551, 3551, 3551, 3553, 3555, 3555, 3555, 3555, 3555,
+        3555, 3555, 3555, 3555, 3556, 3556, 3556, 3558, 3559, 3559, 3559, 3559, 3561, 3561, 3562, 3562, 3567, 3567, 3567, 3567,
+        3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3567, 3569, 3569, 3569, 3569,
+        3569, 3569, 3571, 3571, 3571, 3571, 3574, 3578, 3578, 3578, 3580, 3583, 3586, 3586, 3587, 3587, 3588, 3588, 3590, 3593,
+        3593, 3595, 3595, 3595, 3595, 3595, 3597, 3599, 3599, 3599, 3601, 3601, 3603, 3607, 3607, 3610, 3610, 3610, 3610, 3610,
+        3610, 3610, 3610, 3613, 3613, 3613, 3615, 3615, 3615, 3619, 3623, 3623, 3625, 3625, 3625, 3625, 3626, 3626, 3626, 3626,
+        3626, 3626, 3626, 3626, 3626, 3626, 3626, 3626, 3628, 3631, 3635, 3639, 3641, 3643, 3645, 3645, 3649, 3649, 3651, 3651,
+        3653, 3653, 3653, 3653, 3655, 3655, 3655, 3655, 3655, 3655, 3655, 3655, 3657, 3657, 3659, 3662, 3662, 3662, 3662, 3665,
+        3667, 3667, 3667, 3667, 3667, 3668, 3668, 3670, 3671, 3671, 3671, 3671, 3673, 3673, 3673, 3673, 3673, 3675, 3675, 3675,
+        3675, 3675, 3675, 3675, 3675, 3675, 3675, 3675, 3677, 3677, 3677, 3677, 3677, 3677, 3677, 3677, 3677, 3677, 3677, 3677,
+        3677, 3677, 3677, 3679, 3679, 3679, 3679, 3679, 3679, 3679, 3679, 3679, 3679, 3679, 3681, 3681, 3681, 3683, 3683, 3683,
+        3683, 3683, 3683, 3683, 3683, 3683, 3683, 3683, 3683, 3683, 3685, 3687, 3687, 3687, 3687, 3687, 3687, 3687, 3687, 3687,
+        3687, 3687, 3687, 3687, 3687, 3687, 3688, 3688, 3688, 3690, 3690, 3690, 3690, 3690, 3692, 3692, 3692, 3692, 3692, 3694,
+        3694, 3694, 3694, 3694, 3694, 3694, 3696, 3696, 3702, 3706, 3709, 3723, 3728, 3730, 3733, 3735, 3735, 3735, 3744, 3748,
+        3751, 3751, 3751, 3751, 3751, 3751, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3756, 3758, 3758, 3758, 3758, 3758, 3758,
+        3761, 3761, 3761, 3763, 3763, 3763, 3763, 3763, 3763, 3763, 3763, 3763, 3765, 3765, 3765, 3765, 3765, 3765, 3765, 3765,
+        3765, 3765, 3765, 3766, 3766, 3766, 3766, 3766, 3767, 3768, 3768, 3768, 3769, 3769, 3771, 3771, 3771, 3771, 3771, 3771,
+        3772, 3772, 3772, 3772, 3774, 3774, 3774, 3774, 3774, 3774, 3774, 3774, 3775, 3775, 3775, 3775, 3775, 3775, 3775, 3775,
+        3776, 3776, 3776, 3776, 3776, 3776, 3776, 3776, 3776, 3776, 3776, 3778, 3778, 3778, 3778, 3778, 3778, 3778, 3778, 3778,
+        3785, 3785, 3785, 3785, 3785, 3785, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787, 3787,
+        3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3789, 3791, 3791, 3791, 3791, 3794, 3794, 3794,
+        3794, 3794, 3794, 3796, 3796, 3796, 3796, 3798, 3798, 3800, 3800, 3802, 3802, 3804, 3804, 3804, 3806, 3806, 3806, 3808,
+        3808, 3808, 3808, 3808, 3808, 3808, 3808, 3808, 3808, 3808, 3808, 3808, 3810, 3810, 3810, 3810, 3810, 3810, 3812, 3812,
+        3815, 3817, 3821, 3821, 3833, 3836, 3836, 3836, 3836, 3836, 3836, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838,
+        3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838,
+        3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3838, 3839, 3839,
+        3839, 3839, 3839, 3839, 3839, 3841, 3841, 3841, 3841, 3841, 3843, 3843, 3843, 3843, 3843, 3845, 3845, 3845, 3845, 3845,
+        3847, 3847, 3848, 3848, 3848, 3848, 3850, 3850, 3852, 3852, 3852, 3852, 3852, 3852, 3852, 3852, 3852, 3854, 3854, 3854,
+        3854, 3854, 3854, 3854, 3854, 3858, 3858, 3858, 3858, 3858, 3861, 3863, 3868, 3873, 3878, 3881, 3885, 3889, 3893, 3895,
+        3897, 3900, 3902, 3902, 3909, 3912, 3920, 3925, 3925, 3929, 3929, 3929, 3929, 3929, 3932, 3932, 3932, 3932, 3932, 3933,
+        3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3933, 3935, 3935, 3935, 3935, 3937,
+        3937, 3939, 3939, 3939, 3939, 3939, 3939, 3939, 3939, 3939, 3941, 3941, 3941, 3941, 3941, 3941, 3941, 3943, 3943, 3943,
+        3943, 3943, 3945, 3945, 3947, 3950, 3954, 3956, 3956, 3957, 3957, 3957, 3959, 3959, 3961, 3961, 3961, 3961, 3961, 3961,
+        3964, 3967, 3967, 3970, 3970, 3970, 3972, 3972, 3972, 3972, 3972, 3974, 3976, 3978, 3978, 3978, 3981, 3982, 3982, 3982,
+        3982, 3982, 3984, 3984, 3986, 3986, 3986, 3986, 3988, 3991, 3991, 3993, 3997, 4001, 4004, 4004, 4007, 4007, 4011, 4011,
+        4015, 4015, 4017, 4017, 4017, 4019, 4021, 4023, 4025, 4027, 4029, 4029, 4032, 4035, 4037, 4039, 4039, 4041, 4044, 4046,
+        4048, 4048, 4048, 4051, 4052, 4055, 4055, 4055, 4055, 4055, 4055, 4055, 4055, 4055, 4055, 4057, 4057, 4057, 4057, 4060,
+        4060, 4060, 4060, 4060, 4060, 4062, 4062, 4062, 4065, 4067, 4067, 4067, 4069, 4069, 4071, 4073, 4073, 4073, 4073, 4073,
+        4073, 4075, 4075, 4077, 4077, 4077, 4079, 4079, 4082, 4085, 4085, 4085, 4085, 4090, 4094, 4096, 4096, 4098, 4098, 4103,
+        4107, 4107, 4110, 4112, 4114, 4116, 4120, 4123, 4125, 4125, 4125, 4125, 4125, 4125, 4125, 4125, 4125, 4125, 4125, 4125,
+        4127, 4127, 4127, 4129, 4129, 4129, 4129, 4129, 4129, 4129, 4129, 4129, 4132, 4132, 4132, 4132, 4132, 4133, 4133, 4133,
+        4133, 4133, 4133, 4133, 4133, 4133, 4136, 4136, 4136, 4136, 4136, 4136, 4137, 4137, 4137, 4137, 4137, 4137, 4137, 4137,
+        4137, 4139, 4139, 4139, 4139, 4142, 4147, 4152, 4155, 4156, 4161, 4164, 4166, 4168, 4168, 4171, 4173, 4180, 4185, 4185,
+        4187, 4187, 4187, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188, 4188,
+        4188, 4188, 4189, 4189, 4189, 4190, 4190, 4190, 4190, 4190, 4190, 4190, 4190, 4190, 4190, 4190, 4195, 4195, 4195, 4195,
+        4195, 4197, 4198, 4198, 4200, 4200, 4202, 4202, 4202, 4202, 4205, 4213, 4216, 4220, 4223, 4228, 4233, 4233, 4233, 4236,
+        4239, 4239, 4241, 4241, 4241, 4248, 4252, 4252, 4253, 4256, 4258, 4258, 4258, 4261, 4264, 4264, 4266, 4269, 4271, 4271,
+        4275, 4277, 4277, 4277, 4279, 4279, 4283, 4286, 4289, 4291, 4293, 4293, 4298, 4300, 4300, 4302, 4302, 4302, 4302, 4304,
+        4306, 4309, 4311, 4313, 4313, 4313, 4313, 4314, 4316, 4316, 4316, 4316, 4316, 4316, 4318, 4318, 4322, 4325, 4325, 4328,
+        4330, 4332, 4335, 4335, 4335, 4338, 4338, 4340, 4342, 4345, 4349, 4355, 4357, 4359, 4362, 4362, 4365, 4370, 4375, 4378,
+        4380, 4380, 4383, 4383, 4386, 4388, 4390, 4395, 4399, 4399, 4399, 4399, 4402, 4402, 4402, 4405, 4407, 4409, 4409, 4411,
+        4411, 4411, 4413, 4413, 4413, 4413, 4413, 4413, 4413, 4413, 4415, 4415, 4415, 4415, 4415, 4415, 4415, 4415, 4415, 4415,
+        4416, 4416, 4416, 4416, 4416, 4416, 4418, 4419, 4419, 4420, 4421, 4421, 4424, 4424, 4424, 4424, 4424, 4424, 4424, 4426,
+        4426, 4430, 4432, 4433, 4435, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4437, 4439, 4439, 4439,
+        4439, 4439, 4441, 4441, 4441, 4443, 4443, 4444, 4444, 4444, 4445, 4445, 4446, 4446, 4446, 4446, 4446, 4446, 4447, 4448,
+        4448, 4449, 4450, 4450, 4452, 4452, 4454, 4454, 4454, 4455, 4455, 4455, 4457, 4459, 4459, 4462, 4464, 4464, 4464, 4464,
+        4464, 4464, 4464, 4464, 4466, 4467, 4467, 4467, 4467, 4467, 4467, 4469, 4469, 4471, 4471, 4472, 4472, 4472, 4472, 4472,
+        4472, 4472, 4472, 4472, 4472, 4472, 4472, 4472, 4474, 4474, 4474, 4474, 4474, 4476, 4477, 4479, 4479, 4479, 4479, 4479,
+        4479, 4480, 4481, 4481, 4481, 4482, 4482, 4482, 4482, 4484, 4484, 4484, 4484, 4484, 4484, 4484, 4484, 4484, 4484, 4484,
+        4486, 4486, 4486, 4488, 4488, 4488, 4490, 4490, 4490, 4490, 4490, 4490, 4490, 4492, 4492, 4492, 4492, 4492, 4493, 4493,
+        4494, 4496, 4498, 4498, 4498, 4498, 4498, 4498, 4498, 4499, 4500, 4502, 4502, 4502, 4502, 4502, 4502, 4504, 4507, 4509,
+        4509, 4509, 4509, 4511, 4511, 4511, 4511, 4511, 4511, 4511, 4511, 4513, 4513, 4513, 4513, 4513, 4513, 4514, 4515, 4516,
+        4517, 4517, 4519, 4521, 4521, 4521, 4523, 4523, 4523, 4525, 4525, 4525, 4527, 4527, 4528, 4528, 4534, 4534, 4539, 4541,
+        4544, 4544, 4544, 4545, 4545, 4546, 4546, 4546, 4546, 4546, 4546, 4546, 4546, 4546, 4546, 4548, 4548, 4548, 4548, 4548,
+        4548, 4548, 4548, 4548, 4548, 4548, 4548, 4551, 4553, 4553, 4555, 4555, 4555, 4555, 4557, 4557, 4557, 4559, 4559, 4559,
+        4559, 4559, 4561, 4561, 4561, 4561, 4561, 4561, 4563, 4563, 4563, 4563, 4563, 4563, 4563, 4563, 4563, 4563, 4563, 4564,
+        4564, 4564, 4564, 4566, 4566, 4566, 4567, 4567, 4571, 4571, 4571, 4571, 4571, 4571, 4571, 4571, 4571, 4571, 4572, 4572,
+        4572, 4572, 4575, 4578, 4580, 4580, 4582, 4582, 4582, 4582, 4584, 4584, 4584, 4584, 4584, 4584, 4584, 4584, 4584, 4586,
+        4586, 4586, 4586, 4586, 4586, 4586, 4586, 4588, 4588, 4590, 4590, 4590, 4592, 4592, 4592, 4592, 4594, 4594, 4596, 4597,
+        4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4601, 4603, 4603, 4603, 4603, 4603, 4603, 4603,
+        4603, 4603, 4603, 4603, 4603, 4603, 4603, 4603, 4605, 4605, 4605, 4605, 4605, 4606, 4606, 4606, 4606, 4606, 4609, 4609,
+        4609, 4609, 4609, 4609, 4609, 4609, 4609, 4611, 4619, 4623, 4625, 4625, 4627, 4629, 4633, 4633, 4636, 4639, 4641, 4641,
+        4645, 4650, 4650, 4652, 4654, 4654, 4654, 4656, 4656, 4656, 4656, 4658, 4660, 4662, 4662, 4662, 4662, 4662, 4664, 4666,
+        4669, 4672, 4674, 4676, 4676, 4676, 4676, 4678, 4680, 4682, 4682, 4684, 4688, 4690, 4690, 4690, 4690, 4690, 4690, 4690,
+        4692, 4692, 4692, 4692, 4692, 4692, 4694, 4699, 4704, 4705, 4705, 4708, 4708, 4714, 4718, 4719, 4721, 4721, 4723, 4725,
+        4727, 4729, 4729, 4731, 4735, 4737, 4737, 4737, 4739, 4741, 4741, 4744, 4746, 4746, 4746, 4746, 4746, 4746, 4748, 4748,
+        4748, 4748, 4748, 4748, 4748, 4748, 4748, 4748, 4748, 4751, 4751, 4751, 4751, 4751, 4753, 4753, 4757, 4759, 4761, 4765,
+        4768, 4768, 4768, 4768, 4768, 4768, 4768, 4768, 4770, 4771, 4771, 4771, 4771, 4771, 4771, 4773, 4773, 4773, 4773, 4773,
+        4773, 4773, 4773, 4775, 4775, 4775, 4777, 4777, 4779, 4779, 4779, 4779, 4779, 4779, 4779, 4781, 4781, 4781, 4781, 4781,
+        4781, 4781, 4781, 4781, 4781, 4781, 4783, 4783, 4783, 4783, 4786, 4788, 4790, 4792, 4792, 4792, 4792, 4799, 4799, 4799,
+        4799, 4799, 4799, 4799, 4801, 4801, 4801, 4801, 4801, 4801, 4801, 4801, 4801, 4801, 4801, 4803, 4803, 4803, 4803, 4803,
+        4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803, 4803,
+        4803, 4803, 4803, 4803, 4803, 4803, 4803, 4805, 4807, 4807, 4807, 4809, 4809, 4809, 4809, 4809, 4809, 4809, 4811, 4811,
+        4811, 4811, 4811, 4811, 4813, 4813, 4813, 4813, 4813, 4813, 4813, 4816, 4816, 4816, 4816, 4816, 4816, 4816, 4816, 4816,
+        4816, 4816, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4817, 4818, 4818,
+        4818, 4818, 4818, 4818, 4818, 4818, 4818, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819, 4819,
+        4819, 4819, 4820, 4820, 4820, 4820, 4822, 4822, 4824, 4824, 4824, 4825, 4825, 4825, 4825, 4827, 4829, 4829, 4831, 4834,
+        4836, 4838, 4842, 4842, 4842, 4844, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846, 4846,
+        4846, 4847, 4847, 4847, 4847, 4848, 4848, 4848, 4848, 4848, 4850, 4850, 4850, 4850, 4850, 4852, 4854, 4854, 4854, 4854,
+        4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854, 4854,
+        4854, 4856, 4856, 4856, 4856, 4856, 4856, 4856, 4856, 4856, 4858, 4860, 4860, 4860, 4860, 4860, 4860, 4860, 4860, 4860,
+        4860, 4861, 4861, 4863, 4863, 4863, 4863, 4863, 4863, 4863, 4863, 4863, 4863, 4863, 4871, 4871, 4871, 4871, 4871, 4871,
+        4871, 4871, 4871, 4874, 4876, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878, 4878,
+        4878, 4880, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4881, 4883, 4883, 4883,
+        4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883,
+        4883, 4883, 4883, 4883, 4883, 4883, 4883, 4883, 4884, 4884, 4884, 4884, 4884, 4886, 4886, 4886, 4886, 4886, 4886, 4886,
+        4888, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892, 4892,
+        4892, 4892, 4892, 4892, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4894, 4895, 4895, 4897, 4897,
+        4899, 4899, 4899, 4899, 4899, 4899, 4899, 4899, 4900, 4900, 4900, 4900, 4900, 4900, 4900, 4900, 4900, 4902, 4904, 4906,
+        4906, 4906, 4906, 4908, 4908, 4908, 4908, 4908, 4908, 4908, 4912, 4916, 4919, 4919, 4922, 4924, 4926, 4929, 4929, 4931,
+        4933, 4944, 4947, 4950, 4950, 4950, 4950, 4950, 4950, 4950, 4950, 4952, 4957, 4957, 4957, 4957, 4959, 4962, 4964, 4964,
+        4966, 4966, 4969, 4971, 4974, 4974, 4976, 4986, 4986, 4986, 4988, 4988, 4990, 4990, 4992, 4992, 4992, 4992, 4992, 4992,
+        4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992, 4992,
+        4992, 4992, 4992, 4994, 4996, 4996, 4996, 4999, 4999, 4999, 4999, 4999, 4999, 4999, 4999, 4999, 5001, 5002, 5002, 5002,
+        5002, 5002, 5002, 5002, 5002, 5002, 5002, 5004, 5004, 5004, 5004, 5004, 5004, 5004, 5004, 5004, 5004, 5004, 5010, 5010,
+        5010, 5010, 5012, 5014, 5016, 5018, 5020, 5023, 5025, 5025, 5027, 5030, 5030, 5032, 5032, 5032, 5032, 5032, 5032, 5032,
+        5032, 5032, 5032, 5032, 5032, 5032, 5032, 5032, 5032, 5034, 5034, 5034, 5034, 5034, 5034, 5036, 5036, 5036, 5038, 5038,
+        5038, 5038, 5038, 5038, 5042, 5044, 5044, 5044, 5044, 5046, 5046, 5046, 5048, 5048, 5050, 5053, 5053, 5055, 5057, 5057,
+        5057, 5057, 5057, 5059, 5059, 5062, 5062, 5062, 5062, 5065, 5065, 5065, 5065, 5065, 5065, 5065, 5068, 5070, 5070, 5070,
+        5070, 5070, 5072, 5072, 5072, 5072, 5075, 5075, 5075, 5077, 5080, 5082, 5082, 5082, 5082, 5087, 5089, 5094, 5096, 5098,
+        5101, 5101, 5103, 5105, 5105, 5107, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110, 5110,
+        5110, 5110, 5110, 5110, 5112, 5112, 5112, 5114, 5114, 5116, 5116, 5116, 5116, 5116, 5118, 5118, 5118, 5118, 5118, 5118,
+        5118, 5120, 5120, 5120, 5120, 5120, 5120, 5120, 5120, 5120, 5120, 5120, 5122, 5122, 5122, 5122, 5122, 5122, 5122, 5122,
+        5122, 5122, 5122, 5124, 5126, 5126, 5126, 5126, 5126, 5126, 5126, 5126, 5126, 5126, 5127, 5127, 5127, 5127, 5129, 5129,
+        5131, 5131, 5131, 5132, 5132, 5132, 5134, 5134, 5134, 5137, 5139, 5141, 5144, 5144, 5146, 5148, 5150, 5152, 5152, 5154,
+        5154, 5154, 5154, 5154, 5156, 5156, 5157, 5157, 5157, 5157, 5159, 5159, 5159, 5160, 5160, 5160, 5160, 5160, 5162, 5162,
+        5162, 5162, 5164, 5164, 5164, 5166, 5166, 5166, 5166, 5166, 5166, 5166, 5166, 5166, 5166, 5168, 5168, 5168, 5168, 5168,
+        5168, 5168, 5168, 5172, 5172, 5174, 5174, 5174, 5174, 5174, 5178, 5178, 5178, 5178, 5178, 5178, 5180, 5180, 5180, 5180,
+        5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5180, 5182, 5182, 5182, 5182, 5182, 5182, 5182,
+        5185, 5185, 5185, 5188, 5191, 5191, 5191, 5195, 5195, 5197, 5199, 5199, 5199, 5199, 5199, 5200, 5200, 5200, 5200, 5200,
+        5200, 5200, 5200, 5200, 5201, 5202, 5202, 5202, 5204, 5204, 5207, 5207, 5207, 5210, 5210, 5210, 5210, 5210, 5210, 5210,
+        5210, 5210, 5210, 5210, 5210, 5210, 5210, 5212, 5212, 5212, 5212, 5214, 5217, 5217, 5217, 5217, 5217, 5217, 5219, 5219,
+        5219, 5219, 5219, 5219, 5219, 5219, 5219, 5219, 5219, 5219, 5221, 5221, 5221, 5221, 5221, 5225, 5227, 5227, 5227, 5227,
+        5227, 5227, 5229, 5229, 5229, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231, 5231,
+        5231, 5231, 5231, 5231, 5231, 5231, 5233, 5233, 5233, 5235, 5235, 5235, 5235, 5235, 5235, 5236, 5236, 5236, 5236, 5238,
+        5238, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5240, 5242, 5242, 5242, 5242, 5244, 5247, 5247,
+        5247, 5247, 5247, 5247, 5247, 5247, 5247, 5247, 5247, 5247, 5247, 5247, 5249, 5249, 5249, 5251, 5251, 5251, 5251, 5251,
+        5251, 5251, 5251, 5251, 5251, 5251, 5251, 5253, 5253, 5254, 5254, 5254, 5254, 5254, 5256, 5256, 5256, 5256, 5256, 5256,
+        5259, 5259, 5261, 5263, 5263, 5263, 5263, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265, 5265,
+        5265, 5265, 5265, 5265, 5267, 5267, 5268, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270, 5270,
+        5272, 5272, 5273, 5273, 5273, 5273, 5276, 5276, 5276, 5276, 5276, 5276, 5278, 5278, 5278, 5278, 5278, 5278, 5278, 5278,
+        5278, 5278, 5278, 5278, 5278, 5278, 5279, 5279, 5279, 5280, 5280, 5280, 5280, 5283, 5283, 5283, 5285, 5285, 5285, 5285,
+        5285, 5287, 5287, 5287, 5287, 5287, 5290, 5290, 5290, 5290, 5293, 5295, 5298, 5301, 5304, 5306, 5307, 5307, 5309, 5312,
+        5312, 5312, 5312, 5313, 5315, 5315, 5317, 5317, 5319, 5319, 5319, 5321, 5321, 5321, 5325, 5325, 5325, 5325, 5325, 5325,
+        5325, 5325, 5325, 5327, 5327, 5327, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328, 5328,
+        5328, 5328, 5328, 5328, 5330, 5330, 5330, 5330, 5332, 5332, 5332, 5333, 5333, 5333, 5333, 5333, 5333, 5334, 5334, 5334,
+        5334, 5334, 5334, 5334, 5334, 5334, 5334, 5334, 5334, 5334, 5336, 5336, 5336, 5336, 5336, 5336, 5336, 5336, 5336, 5336,
+        5337, 5338, 5338, 5338, 5338, 5338, 5338, 5339, 5339, 5339, 5339, 5339, 5339, 5340, 5340, 5341, 5341, 5341, 5341, 5341,
+        5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5341, 5342, 5342, 5344, 5344,
+        5344, 5344, 5344, 5344, 5344, 5344, 5344, 5344, 5344, 5344, 5346, 5346, 5346, 5346, 5346, 5347, 5347, 5347, 5347, 5347,
+        5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5347, 5349, 5349, 5349, 5351, 5351, 5351, 5351,
+        5351, 5351, 5351, 5351, 5351, 5351, 5351, 5351, 5351, 5353, 5353, 5353, 5353, 5353, 5353, 5353, 5353, 5355, 5355, 5355,
+        5355, 5355, 5355, 5355, 5355, 5355, 5355, 5355, 5355, 5358, 5358, 5358, 5358, 5358, 5358, 5358, 5358, 5358, 5358, 5359,
+        5359, 5359, 5359, 5359, 5361, 5361, 5361, 5361, 5361, 5361, 5361, 5361, 5363, 5363, 5363, 5363, 5363, 5363, 5363, 5363,
+        5365, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5367, 5369,
+        5369, 5370, 5370, 5371, 5371, 5371, 5371, 5373, 5373, 5373, 5373, 5373, 5373, 5373, 5375, 5375, 5375, 5375, 5375, 5375,
+        5376, 5379, 5379, 5379, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5394, 5396, 5396, 5396,
+        5396, 5396, 5396, 5398, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400, 5400,
+        5402, 5402, 5402, 5402, 5402, 5402, 5402, 5402, 5402, 5402, 5402, 5405, 5405, 5405, 5405, 5405, 5405, 5405, 5405, 5405,
+        5408, 5408, 5411, 5411, 5411, 5411, 5411, 5413, 5413, 5415, 5417, 5419, 5421, 5421, 5423, 5427, 5427, 5429, 5431, 5431,
+        5433, 5433, 5433, 5433, 5433, 5433, 5433, 5433, 5435, 5435, 5435, 5435, 5435, 5435, 5435, 5435, 5435, 5435, 5435, 5435,
+        5435, 5435, 5435, 5437, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439, 5439,
+        5441, 5443, 5445, 5445, 5445, 5445, 5445, 5445, 5447, 5447, 5447, 5449, 5449, 5449, 5449, 5449, 5449, 5449, 5451, 5451,
+        5451, 5453, 5453, 5454, 5454, 5454, 5454, 5454, 5454, 5454, 5454, 5454, 5454, 5454, 5456, 5456, 5456, 5456, 5461, 5463,
+        5463, 5463, 5463, 5463, 5463, 5463, 5463, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465, 5465,
+        5466, 5466, 5466, 5466, 5467, 5467, 5467, 5467, 5467, 5467, 5467, 5469, 5469, 5469, 5469, 5471, 5471, 5471, 5471, 5471,
+        5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471, 5471,
+        5471, 5471, 5471, 5471, 5473, 5475, 5475, 5475, 5477, 5479, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482,
+        5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5482, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484,
+        5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5484, 5487, 5487, 5487, 5487, 5487, 5487, 5487,
+        5487, 5487, 5487, 5489, 5489, 5489, 5489, 5489, 5492, 5492, 5492, 5494, 5494, 5494, 5494, 5494, 5497, 5500, 5502, 5502,
+        5502, 5502, 5504, 5507, 5507, 5507, 5509, 5509, 5509, 5509, 5509, 5509, 5509, 5511, 5511, 5511, 5511, 5511, 5511, 5511,
+        5512, 5512, 5512, 5512, 5512, 5512, 5512, 5512, 5514, 5514, 5514, 5514, 5514, 5514, 5516, 5516, 5516, 5518, 5521, 5521,
+        5523, 5523, 5525, 5525, 5525, 5528, 5528, 5528, 5528, 5528, 5528, 5528, 5528, 5528, 5530, 5530, 5530, 5530, 5530, 5530,
+        5530, 5530, 5530, 5530, 5530, 5530, 5532, 5532, 5532, 5532, 5532, 5532, 5534, 5535, 5537, 5537, 5537, 5537, 5537, 5537,
+        5539, 5539, 5539, 5539, 5539, 5539, 5539, 5539, 5539, 5539, 5539, 5541, 5541, 5541, 5541, 5541, 5541, 5541, 5541, 5543,
+        5543, 5543, 5543, 5543, 5543, 5543, 5545, 5547, 5549, 5549, 5549, 5549, 5549, 5549, 5549, 5549, 5549, 5549, 5549, 5549,
+        5549, 5549, 5549, 5551, 5551, 5553, 5553, 5553, 5553, 5553, 5555, 5555, 5555, 5557, 5557, 5557, 5557, 5557, 5557, 5557,
+        5557, 5557, 5557, 5558, 5558, 5558, 5558, 5558, 5558, 5558, 5562, 5564, 5564, 5564, 5566, 5568, 5570, 5570, 5570, 5570,
+        5570, 5570, 5570, 5570, 5570, 5570, 5572, 5572, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574,
+        5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5574, 5576, 5576, 5576, 5576, 5576, 5576,
+        5576, 5576, 5576, 5577, 5577, 5577, 5577, 5579, 5579, 5581, 5581, 5581, 5581, 5583, 5583, 5583, 5583, 5583, 5583, 5583,
+        5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583,
+        5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5583, 5586, 5586, 5586, 5586, 5586, 5586,
+        5586, 5586, 5586, 5586, 5586, 5586, 5586, 5586, 5586, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588,
+        5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588, 5588,
+        5588, 5588, 5588, 5588, 5589, 5589, 5589, 5589, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591,
+        5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591,
+        5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591,
+        5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591, 5591,
+        5591, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5593, 5597, 5597, 5597, 5597, 5597, 5597, 5597,
+        5597, 5599, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601,
+        5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601, 5601,
+        5607, 5612, 5617, 5617, 5620, 5623, 5626, 5626, 5630, 5630, 5636, 5639, 5647, 5651, 5653, 5653, 5653, 5653, 5653, 5653,
+        5653, 5653, 5653, 5653, 5653, 5653, 5653, 5653, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654,
+        5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654,
+        5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5654, 5656, 5656, 5656,
+        5656, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5658, 5660, 5660, 5660, 5660,
+        5662, 5664, 5667, 5667, 5667, 5667, 5667, 5667, 5667, 5667, 5669, 5669, 5669, 5669, 5669, 5670, 5670, 5670, 5670, 5670,
+        5672, 5672, 5672, 5675, 5675, 5675, 5675, 5675, 5675, 5676, 5676, 5676, 5676, 5676, 5676, 5676, 5676, 5676, 5676, 5676,
+        5676, 5676, 5676, 5676, 5676, 5676, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678,
+        5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678,
+        5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678,
+        5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5678, 5680, 5680, 5680, 5680,
+        5680, 5680, 5680, 5682, 5685, 5685, 5687, 5687, 5687, 5687, 5689, 5689, 5689, 5689, 5691, 5691, 5691, 5693, 5695, 5695,
+        5695, 5695, 5696, 5698, 5698, 5698, 5698, 5698, 5700, 5700, 5701, 5701, 5701, 5701, 5703, 5703, 5703, 5703, 5705, 5705,
+        5705, 5709, 5709, 5709, 5711, 5711, 5713, 5716, 5716, 5716, 5718, 5718, 5720, 5720, 5720, 5720, 5720, 5720, 5720, 5722,
+        5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722, 5722,
+        5722, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724, 5724,
+        5724, 5724, 5726, 5726, 5726, 5726, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727,
+        5727, 5727, 5727, 5727, 5727, 5727, 5727, 5727,
+    ],
+    &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0,
+        0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 7, 0,
+        0, 0, 0, 8, 0, 0, 9, 0, 10, 1, 0, 0, 11, 1, 0, 0, 1, 12, 1, 1,
+        0, 0, 0, 0, 1, 3, 0, 1, 13, 0, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 3, 14, 0, 0, 1, 0, 0, 0, 0, 15, 0, 1, 0, 16, 1, 0,
+        17, 1, 0, 0, 0, 0, 18, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 5, 0, 12, 0, 19, 1, 0, 2, 1,
+        0, 1, 5, 19, 0, 1, 1, 0, 0, 0, 0, 0, 12, 0, 0, 0, 1, 0, 1, 1,
+        0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 20, 21, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 12, 0, 0, 0, 1, 17,
+        19, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 23,
+        4, 0, 4, 0, 0, 0, 24, 0, 0, 0, 0, 25, 4, 0, 1, 0, 3, 0, 1, 1,
+        4, 0, 26, 1, 4, 27, 12, 2, 4, 0, 1, 1, 0, 0, 1, 5, 19, 1, 4, 28,
+        17, 17, 26, 29, 1, 30, 0, 1, 4, 1, 1, 19, 0, 0, 1, 0, 0, 0, 3, 0,
+        0, 0, 0, 14, 0, 0, 1, 31, 25, 0, 32, 3, 0, 28, 33, 2, 0, 34, 5, 2,
+        19, 17, 3, 12, 4, 35, 1, 0, 0, 0, 34, 1, 0, 0, 0, 0, 0, 0, 0, 4,
+        0, 0, 8, 0, 1, 2, 36, 1, 34, 1, 17, 0, 0, 1, 4, 37, 0, 0, 0, 0,
+        0, 2, 0, 0, 20, 0, 1, 0, 4, 0, 3, 1, 0, 0, 0, 0, 7, 2, 1, 34,
+        12, 0, 5, 0, 19, 17, 1, 0, 1, 4, 38, 2, 25, 32, 25, 19, 39, 4, 0, 0,
+        4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 4, 2, 1, 34, 0, 0, 0,
+        0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 2, 34,
+        5, 40, 17, 0, 1, 8, 0, 1, 2, 34, 2, 20, 19, 0, 0, 1, 0, 0, 0, 41,
+        0, 0, 0, 20, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 2, 42, 25, 43, 12, 19,
+        17, 4, 44, 4, 0, 1, 38, 2, 34, 45, 0, 1, 19, 17, 0, 44, 4, 0, 0, 0,
+        0, 0, 0, 0, 18, 2, 5, 4, 1, 17, 2, 5, 46, 7, 1, 0, 4, 0, 0, 47,
+        0, 5, 4, 0, 0, 0, 5, 0, 1, 1, 1, 4, 48, 49, 32, 0, 0, 0, 0, 1,
+        1, 0, 1, 1, 0, 0, 32, 0, 4, 2, 0, 5, 50, 17, 3, 32, 4, 31, 1, 0,
+        34, 5, 51, 8, 1, 2, 34, 0, 17, 0, 4, 52, 38, 0, 0, 0, 0, 0, 0, 2,
+        53, 0, 0, 4, 0, 0, 0, 0, 1, 2, 1, 0, 19, 17, 54, 3, 4, 2, 34, 22,
+        0, 0, 19, 55, 56, 57, 4, 1, 1, 1, 1, 5, 1, 32, 19, 17, 1, 58, 12, 25,
+        0, 2, 0, 2, 7, 0, 1, 59, 1, 60, 61, 24, 0, 0, 0, 0, 0, 0, 2, 34,
+        1, 1, 1, 62, 0, 0, 0, 0, 0, 5, 2, 1, 0, 19, 17, 0, 4, 1, 32, 1,
+        1, 63, 42, 0, 0, 19, 0, 0, 1, 4, 64, 65, 1, 29, 0, 1, 0, 19, 17, 0,
+        4, 65, 66, 1, 0, 67, 25, 0, 7, 0, 8, 2, 68, 34, 5, 25, 69, 0, 0, 1,
+        0, 0, 1, 4, 0, 1, 2, 19, 0, 4, 0, 0, 0, 0, 1, 0, 0, 0, 5, 0,
+        0, 0, 1, 0, 0, 8, 0, 0, 0, 2, 4, 70, 19, 1, 17, 3, 1, 71, 5, 11,
+        4, 2, 19, 72, 2, 1, 4, 32, 0, 4, 1, 1, 12, 1, 12, 1, 25, 4, 12, 2,
+        20, 5, 5, 73, 0, 2, 74, 20, 1, 1, 17, 19, 7, 1, 0, 34, 17, 4, 0, 5,
+        8, 0, 0, 75, 19, 0, 0, 0, 1, 1, 7, 76, 48, 0, 0, 20, 11, 1, 0, 77,
+        8, 5, 1, 2, 34, 78, 30, 0, 17, 0, 0, 4, 0, 2, 79, 0, 0, 5, 0, 73,
+        4, 80, 81, 1, 82, 83, 17, 2, 0, 34, 0, 0, 84, 1, 1, 17, 3, 0, 4, 85,
+        0, 34, 0, 7, 5, 5, 0, 20, 0, 17, 86, 0, 17, 0, 2, 34, 0, 19, 0, 0,
+        0, 0, 1, 87, 34, 1, 19, 0, 88, 76, 2, 5, 0, 0, 1, 89, 1, 1, 12, 29,
+        4, 4, 0, 2, 2, 19, 4, 2, 34, 25, 44, 19, 0, 25, 18, 0, 90, 7, 1, 2,
+        12, 4, 3, 0, 91, 0, 57, 92, 0, 0, 0, 4, 93, 4, 50, 14, 92, 0, 0, 90,
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 1, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 32,
+        19, 17, 3, 4, 48, 4, 1, 0, 5, 17, 0, 94, 25, 0, 0, 20, 20, 5, 0, 1,
+        95, 1, 17, 0, 90, 4, 0, 12, 19, 0, 0, 34, 5, 7, 0, 1, 17, 1, 0, 1,
+        4, 1, 19, 38, 0, 5, 0, 0, 2, 18, 0, 1, 17, 96, 3, 44, 8, 0, 0, 0,
+        0, 4, 11, 0, 8, 19, 32, 1, 4, 1, 5, 0, 0, 97, 34, 5, 35, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 4, 0, 89, 0, 1, 2, 36, 98, 19, 59,
+        0, 4, 99, 0, 2, 5, 34, 19, 1, 1, 1, 100, 0, 4, 0, 1, 2, 34, 5, 1,
+        71, 4, 2, 20, 34, 74, 19, 0, 1, 0, 0, 0, 48, 0, 0, 17, 0, 0, 0, 34,
+        101, 0, 0, 11, 1, 8, 87, 0, 0, 12, 0, 0, 3, 0, 0, 0, 8, 2, 97, 0,
+        25, 0, 12, 19, 17, 3, 96, 0, 0, 32, 0, 0, 102, 0, 0, 1, 0, 0, 0, 2,
+        12, 0, 35, 0, 0, 0, 0, 0, 2, 1, 2, 34, 0, 1, 4, 17, 0, 5, 4, 0,
+        66, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 5, 0, 0, 0, 0, 0, 0, 0, 5,
+        2, 19, 17, 0, 0, 44, 0, 0, 0, 0, 1, 1, 2, 0, 103, 91, 1, 17, 92, 3,
+        4, 0, 0, 1, 34, 5, 5, 104, 0, 1, 1, 0, 24, 0, 17, 0, 0, 0, 97, 25,
+        0, 17, 0, 4, 46, 0, 0, 34, 5, 5, 0, 66, 19, 20, 0, 0, 0, 4, 1, 31,
+        1, 0, 0, 0, 0, 0, 2, 0, 17, 5, 0, 4, 4, 0, 0, 0, 5, 0, 17, 0,
+        7, 1, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+        0, 4, 0, 0, 0, 26, 0, 0, 0, 4, 0, 0, 76, 0, 0, 0, 0, 31, 2, 97,
+        1, 1, 17, 4, 105, 5, 8, 4, 0, 25, 1, 1, 7, 0, 19, 1, 17, 0, 19, 1,
+        34, 106, 0, 3, 1, 1, 0, 2, 4, 36, 5, 91, 8, 0, 0, 0, 0, 0, 1, 74,
+        19, 0, 1, 47, 26, 107, 108, 38, 1, 1, 17, 34, 0, 4, 1, 0, 0, 2, 37, 82,
+        23, 3, 1, 4, 2, 20, 19, 5, 3, 1, 1, 48, 34, 1, 32, 17, 57, 8, 5, 20,
+        1, 4, 20, 0, 34, 5, 0, 12, 12, 1, 1, 109, 110, 4, 1, 111, 112, 1, 1, 12,
+        26, 5, 7, 0, 48, 113, 1, 1, 0, 0, 1, 32, 5, 20, 0, 32, 0, 1, 23, 114,
+        115, 4, 116, 99, 1, 0, 17, 1, 0, 5, 71, 113, 17, 1, 0, 0, 90, 0, 96, 117,
+        0, 17, 0, 0, 118, 0, 71, 31, 106, 1, 0, 112, 0, 4, 31, 5, 4, 0, 0, 0,
+        119, 66, 1, 1, 12, 17, 120, 121, 122, 0, 0, 70, 1, 0, 0, 0, 93, 74, 8, 1,
+        123, 0, 10, 0, 0, 5, 124, 0, 0, 5, 0, 3, 0, 0, 0, 14, 1, 19, 0, 0,
+        7, 0, 17, 0, 0, 0, 0, 0, 0, 34, 11, 0, 125, 1, 0, 0, 2, 48, 2, 0,
+        0, 0, 74, 4, 0, 0, 0, 0, 90, 0, 0, 0, 0, 126, 0, 0, 0, 127, 31, 5,
+        1, 32, 1, 128, 0, 1, 1, 112, 4, 2, 19, 1, 4, 1, 2, 0, 19, 129, 4, 1,
+        1, 17, 4, 95, 2, 0, 1, 4, 26, 105, 2, 5, 3, 34, 11, 19, 17, 1, 23, 1,
+        74, 1, 1, 130, 4, 131, 132, 34, 17, 7, 4, 1, 0, 23, 17, 4, 1, 0, 19, 133,
+        4, 4, 7, 2, 34, 1, 122, 12, 134, 11, 4, 70, 1, 5, 25, 1, 7, 5, 0, 2,
+        5, 1, 135, 32, 17, 5, 0, 1, 0, 1, 1, 25, 1, 7, 1, 6, 96, 1, 136, 19,
+        0, 5, 4, 35, 32, 1, 19, 0, 35, 1, 0, 1, 23, 2, 137, 96, 0, 17, 0, 4,
+        89, 0, 1, 138, 139, 2, 34, 19, 1, 140, 4, 36, 19, 1, 4, 1, 141, 19, 142, 5,
+        131, 143, 142, 4, 34, 1, 1, 4, 1, 2, 1, 11, 1, 0, 95, 23, 5, 35, 3, 74,
+        23, 1, 0, 93, 4, 1, 0, 19, 0, 0, 32, 0, 3, 0, 5, 144, 1, 5, 48, 17,
+        4, 0, 4, 8, 1, 3, 2, 1, 74, 1, 7, 145, 146, 0, 0, 1, 1, 25, 1, 1,
+        102, 5, 2, 19, 43, 4, 0, 74, 12, 2, 25, 0, 1, 8, 4, 0, 1, 3, 34, 1,
+        4, 1, 34, 31, 1, 1, 3, 1, 4, 7, 4, 12, 1, 1, 147, 0, 1, 80, 17, 80,
+        3, 0, 31, 3, 17, 0, 0, 1, 1, 0, 0, 0, 1, 148, 34, 1, 4, 0, 0, 4,
+        0, 3, 57, 32, 34, 0, 0, 2, 149, 0, 5, 0, 0, 4, 0, 3, 1, 4, 2, 17,
+        1, 19, 0, 1, 4, 0, 17, 4, 0, 0, 1, 0, 17, 17, 17, 32, 32, 4, 4, 19,
+        19, 1, 1, 7, 7, 17, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0,
+        7, 32, 5, 0, 4, 12, 25, 48, 2, 19, 133, 0, 1, 0, 0, 26, 50, 1, 0, 0,
+        2, 4, 19, 4, 26, 1, 0, 5, 76, 2, 4, 0, 102, 150, 95, 1, 0, 2, 19, 151,
+        152, 7, 17, 1, 17, 17, 3, 0, 34, 44, 4, 1, 1, 1, 1, 3, 1, 5, 27, 4,
+        1, 17, 0, 74, 90, 32, 17, 17, 1, 25, 153, 31, 102, 0, 32, 25, 0, 3, 25, 154,
+        1, 32, 20, 96, 5, 1, 7, 2, 97, 0, 0, 155, 34, 23, 51, 156, 5, 5, 1, 8,
+        31, 26, 157, 5, 1, 19, 17, 158, 4, 1, 16, 159, 1, 0, 131, 4, 2, 97, 0, 2,
+        1, 160, 26, 6, 19, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0,
+        2, 97, 20, 7, 1, 5, 12, 4, 5, 5, 4, 38, 2, 0, 20, 71, 112, 4, 32, 0,
+        48, 8, 0, 1, 1, 1, 4, 1, 20, 5, 1, 0, 11, 1, 17, 102, 1, 1, 25, 4,
+        135, 34, 19, 5, 1, 2, 19, 2, 19, 1, 2, 19, 4, 0, 5, 7, 29, 29, 2, 148,
+        19, 1, 5, 0, 1, 5, 17, 1, 34, 32, 19, 17, 112, 4, 112, 2, 20, 1, 1, 26,
+        2, 1, 1, 1, 1, 5, 29, 1, 161, 4, 0, 32, 162, 32, 12, 1, 70, 1, 131, 0,
+        131, 2, 1, 64, 20, 0, 1, 5, 17, 1, 1, 12, 163, 1, 1, 7, 1, 4, 19, 0,
+        1, 2, 2, 92, 1, 12, 1, 76, 96, 1, 1, 1, 0, 1, 4, 164, 0, 2, 1, 1,
+        7, 118, 0, 2, 19, 8, 0, 34, 0, 4, 0, 12, 0, 1, 89, 0, 31, 26, 0, 11,
+        1, 1, 1, 74, 3, 35, 26, 50, 3, 1, 4, 0, 92, 1, 4, 4, 48, 0, 1, 0,
+        0, 0, 90, 3, 0, 4, 153, 31, 165, 102, 0, 1, 1, 4, 19, 1, 12, 12, 42, 8,
+        5, 5, 2, 2, 5, 17, 34, 76, 2, 87, 51, 2, 4, 26, 1, 1, 1, 2, 137, 148,
+        1, 1, 8, 12, 8, 1, 166, 1, 167, 141, 1, 168, 80, 1, 169, 170, 20, 171, 17, 23,
+        7, 1, 1, 69, 0, 17, 17, 20, 19, 0, 12, 101, 1, 16, 0, 1, 1, 20, 5, 0,
+        109, 76, 32, 12, 74, 0, 4, 0, 5, 17, 5, 17, 76, 5, 18, 80, 0, 1, 80, 1,
+        161, 76, 76, 1, 0, 20, 1, 20, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 0, 0, 0, 0, 113, 0, 7, 17, 25, 1, 0, 172, 1, 20, 1, 173, 1,
+        131, 1, 20, 174, 4, 1, 17, 7, 48, 1, 4, 7, 1, 76, 0, 1, 19, 3, 0, 32,
+        0, 1, 8, 44, 1, 7, 8, 48, 23, 0, 102, 8, 113, 90, 26, 48, 0, 175, 11, 8,
+        3, 0, 36, 0, 0, 0, 0, 17, 1, 8, 1, 0, 10, 0, 11, 46, 12, 50, 51, 0,
+        0, 27, 0, 24, 14, 0, 17, 5, 8, 92, 0, 0, 0, 4, 48, 0, 78, 20, 17, 48,
+        1, 0, 1, 0, 1, 8, 1, 0, 0, 71, 0, 0, 0, 0, 17, 0, 2, 0, 4, 0,
+        19, 0, 90, 175, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 11, 0, 0, 0, 0,
+        8, 1, 0, 10, 0, 2, 5, 46, 1, 1, 1, 32, 5, 50, 0, 4, 176, 4, 0, 32,
+        58, 24, 4, 14, 0, 5, 131, 0, 0, 92, 1, 0, 48, 0, 0, 1, 76, 17, 4, 177,
+        0, 4, 0, 4, 19, 0, 19, 0, 0, 71, 48, 2, 0, 0, 0, 19, 0, 66, 0, 0,
+        19, 0, 0, 0, 0, 0, 0, 0, 1, 102, 4, 4, 12, 1, 1, 20, 3, 1, 71, 0,
+        1, 1, 0, 31, 32, 18, 1, 5, 1, 7, 70, 0, 90, 72, 96, 1, 4, 2, 4, 1,
+        96, 4, 1, 4, 4, 17, 4, 1, 34, 4, 1, 19, 1, 7, 20, 4, 1, 1, 17, 12,
+        0, 4, 50, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0, 4, 0, 0, 0, 0, 4, 0,
+        1, 25, 19, 17, 97, 34, 3, 19, 1, 19, 7, 32, 20, 1, 1, 1, 5, 1, 17, 69,
+        2, 34, 5, 1, 3, 4, 1, 1, 5, 34, 8, 38, 2, 34, 178, 0, 1, 34, 4, 17,
+        0, 7, 5, 0, 1, 0, 1, 141, 106, 0, 0, 0, 0, 0, 0, 0, 5, 138, 0, 0,
+        1, 0, 19, 69, 137, 0, 158, 10, 0, 0, 5, 46, 0, 0, 32, 0, 0, 0, 176, 0,
+        0, 5, 57, 0, 24, 0, 0, 0, 0, 0, 32, 0, 0, 4, 0, 1, 1, 4, 0, 0,
+        0, 0, 0, 161, 4, 1, 11, 0, 2, 34, 1, 3, 1, 0, 90, 0, 0, 17, 0, 0,
+        0, 0, 4, 38, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0,
+        1, 0, 17, 0, 0, 0, 0, 0, 90, 1, 1, 0, 25, 106, 0, 0, 0, 42, 0, 116,
+        1, 0, 1, 7, 5, 25, 1, 4, 5, 0, 1, 5, 1, 20, 7, 5, 7, 1, 17, 1,
+        19, 1, 18, 179, 2, 0, 19, 17, 3, 4, 1, 0, 1, 1, 2, 1, 7, 17, 0, 1,
+        0, 0, 19, 0, 0, 34, 1, 19, 17, 3, 8, 180, 133, 1, 5, 1, 0, 175, 72, 4,
+        2, 0, 90, 181, 19, 5, 1, 19, 182, 36, 20, 11, 4, 87, 36, 5, 3, 19, 17, 3,
+        96, 89, 1, 0, 159, 0, 183, 4, 4, 184, 19, 66, 0, 34, 0, 0, 17, 8, 3, 7,
+        7, 101, 1, 0, 117, 185, 1, 17, 34, 5, 0, 17, 0, 34, 19, 17, 4, 1, 1, 34,
+        19, 2, 19, 4, 4, 186, 34, 74, 1, 1, 137, 19, 0, 8, 0, 2, 1, 4, 1, 2,
+        19, 3, 4, 187, 4, 3, 4, 19, 0, 3, 1, 2, 34, 5, 4, 2, 25, 1, 4, 0,
+        7, 34, 0, 102, 19, 0, 48, 5, 1, 48, 19, 5, 0, 19, 17, 3, 4, 1, 38, 70,
+        0, 0, 11, 1, 4, 1, 2, 25, 128, 96, 0, 4, 8, 34, 23, 4, 0, 0, 0, 5,
+        34, 5, 0, 3, 2, 34, 4, 5, 2, 34, 0, 3, 4, 38, 2, 23, 19, 17, 3, 4,
+        1, 183, 0, 19, 4, 0, 1, 19, 0, 3, 2, 0, 1, 4, 1, 0, 66, 0, 0, 0,
+        111, 2, 34, 35, 19, 17, 3, 4, 1, 3, 8, 4, 4, 4, 0, 8, 1, 4, 1, 1,
+        4, 17, 4, 32, 4, 78, 0, 0, 1, 0, 4, 48, 4, 0, 2, 1, 0, 1, 17, 4,
+        1, 0, 2, 74, 188, 189, 177, 0, 95, 2, 1, 4, 42, 128, 90, 12, 1, 20, 1, 2,
+        48, 146, 0, 5, 96, 1, 5, 0, 175, 4, 17, 34, 25, 5, 1, 1, 190, 133, 14, 7,
+        1, 0, 0, 0, 34, 0, 191, 70, 74, 0, 120, 5, 5, 192, 122, 70, 153, 8, 0, 14,
+        31, 4, 19, 128, 8, 4, 0, 32, 0, 2, 12, 0, 185, 109, 193, 0, 20, 0, 19, 0,
+        17, 1, 193, 5, 20, 1, 1, 17, 2, 1, 19, 34, 0, 1, 1, 4, 161, 5, 1, 5,
+        1, 4, 1, 1, 1, 1, 1, 0, 1, 1, 7, 158, 17, 4, 97, 1, 194, 0, 4, 7,
+        26, 14, 8, 133, 4, 1, 0, 0, 0, 0, 1, 0, 0, 0, 138, 0, 0, 0, 19, 0,
+        0, 10, 0, 0, 5, 46, 0, 32, 51, 4, 0, 0, 0, 1, 58, 24, 34, 0, 0, 0,
+        0, 0, 0, 70, 19, 3, 0, 0, 0, 34, 0, 0, 0, 1, 113, 17, 48, 17, 17, 0,
+        4, 1, 19, 7, 195, 0, 0, 0, 17, 17, 0, 2, 0, 0, 2, 0, 3, 0, 0, 0,
+        0, 12, 0, 0, 0, 102, 3, 0, 0, 26, 0, 0, 7, 93, 74, 8, 4, 0, 24, 14,
+        26, 17, 131, 1, 92, 74, 4, 48, 0, 8, 196, 8, 11, 0, 71, 0, 0, 0, 0, 0,
+        90, 109, 26, 25, 1, 1, 12, 0, 12, 4, 16, 1, 175, 25, 0, 4, 1, 42, 19, 95,
+        1, 1, 0, 197, 48, 0, 198, 0, 25, 4, 74, 5, 19, 1, 5, 1, 4, 34, 19, 5,
+        66, 3, 26, 38, 1, 0, 34, 1, 3, 0, 0, 0, 0, 23, 7, 1, 0, 1, 7, 199,
+        131, 102, 10, 0, 1, 1, 200, 5, 1, 1, 12, 101, 133, 12, 0, 4, 1, 32, 1, 5,
+        4, 7, 201, 1, 1, 137, 59, 42, 59, 90, 161, 113, 4, 1, 4, 4, 4, 17, 1, 96,
+        109, 7, 4, 7, 42, 17, 0, 0, 118, 128, 0, 131, 2, 3, 3, 1, 23, 0, 3, 7,
+        6, 4, 137, 0, 73, 80, 7, 19, 26, 80, 0, 19, 11, 8, 50, 25, 31, 4, 1, 159,
+        176, 5, 4, 2, 4, 1, 19, 60, 59, 11, 24, 34, 131, 4, 5, 1, 66, 92, 1, 8,
+        26, 19, 1, 23, 80, 167, 78, 95, 34, 17, 3, 202, 161, 1, 12, 4, 7, 137, 11, 42,
+        1, 101, 11, 8, 66, 0, 109, 0, 5, 8, 117, 96, 4, 66, 0, 0, 4, 0, 0, 0,
+        0, 0, 34, 109, 0, 0, 175, 133, 26, 1, 137, 25, 1, 193, 19, 1, 36, 74, 11, 8,
+        47, 4, 1, 102, 5, 96, 96, 23, 1, 0, 1, 203, 1, 25, 0, 0, 92, 4, 48, 204,
+        7, 19, 17, 34, 32, 17, 24, 90, 1, 205, 4, 1, 23, 20, 4, 1, 1, 1, 1, 4,
+        0, 1, 92, 19, 1, 1, 17, 1, 32, 0, 0, 32, 4, 4, 32, 4, 3, 2, 3, 1,
+        5, 5, 0, 1, 5, 1, 206, 4, 7, 102, 3, 3, 4, 0, 32, 1, 1, 5, 4, 133,
+        1, 20, 0, 0, 0, 1, 35, 11, 19, 32, 11, 34, 3, 44, 1, 17, 3, 1, 1, 1,
+        0, 17, 1, 2, 5, 34, 207, 26, 34, 3, 2, 25, 17, 1, 27, 4, 0, 19, 4, 19,
+        1, 34, 25, 1, 76, 17, 4, 2, 34, 20, 1, 4, 1, 17, 19, 19, 23, 128, 69, 4,
+        19, 19, 1, 5, 3, 1, 5, 12, 5, 32, 1, 3, 0, 31, 34, 1, 1, 3, 1, 0,
+        0, 3, 32, 17, 1, 32, 1, 3, 1, 3, 32, 1, 1, 96, 34, 4, 25, 91, 8, 7,
+        93, 8, 128, 0, 74, 193, 208, 209, 1, 167, 1, 210, 185, 7, 4, 3, 1, 0, 1, 0,
+        1, 32, 1, 25, 2, 101, 70, 1, 34, 17, 19, 1, 158, 1, 17, 34, 0, 0, 0, 23,
+        70, 7, 0, 23, 153, 8, 19, 10, 95, 0, 0, 0, 51, 0, 0, 0, 0, 76, 0, 0,
+        0, 0, 0, 2, 60, 24, 14, 26, 211, 92, 0, 0, 78, 4, 1, 0, 0, 0, 117, 0,
+        102, 0, 0, 0, 90, 0, 0, 193, 1, 0, 1, 0, 1, 0, 102, 27, 1, 25, 133, 10,
+        23, 113, 1, 74, 1, 1, 4, 1, 1, 5, 74, 18, 2, 113, 31, 203, 8, 1, 23, 92,
+        4, 76, 0, 0, 17, 1, 0, 2, 2, 5, 96, 70, 74, 96, 20, 0, 3, 1, 23, 133,
+        1, 4, 32, 17, 91, 122, 0, 133, 1, 26, 23, 8, 101, 11, 8, 17, 70, 0, 1, 32,
+        66, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 133, 0, 32, 26, 7, 20, 5, 18, 7,
+        4, 1, 1, 5, 17, 19, 4, 1, 0, 109, 32, 4, 4, 42, 0, 4, 19, 0, 2, 34,
+        17, 5, 4, 0, 0, 26, 23, 11, 0, 138, 167, 0, 8, 0, 0, 10, 0, 0, 46, 1,
+        32, 50, 0, 4, 0, 60, 24, 14, 0, 131, 5, 8, 92, 3, 4, 0, 0, 48, 23, 78,
+        76, 0, 48, 17, 17, 0, 1, 19, 0, 0, 0, 71, 48, 17, 0, 0, 0, 0, 0, 0,
+        90, 0, 0, 25, 0, 0, 31, 1, 102, 1, 19, 1, 32, 7, 8, 44, 44, 42, 2, 11,
+        8, 23, 11, 0, 4, 23, 0, 1, 0, 133, 34, 11, 4, 0, 7, 6, 212, 43, 17, 26,
+        0, 2, 31, 4, 159, 131, 176, 1, 57, 1, 17, 0, 1, 1, 1, 4, 74, 17, 23, 80,
+        80, 170, 0, 0, 1, 19, 7, 7, 42, 117, 95, 19, 1, 101, 175, 11, 8, 0, 213, 0,
+        8, 1, 17, 96, 74, 0, 4, 17, 0, 0, 0, 0, 183, 0, 0, 0, 0, 133, 0, 4,
+        1, 106, 3, 0, 74, 47, 4, 131, 102, 0, 11, 8, 36, 31, 112, 23, 26, 0, 19, 7,
+        6, 0, 1, 2, 0, 32, 19, 74, 80, 3, 1, 4, 26, 1, 0, 23, 51, 27, 214, 4,
+        31, 26, 0, 1, 0, 25, 8, 1, 4, 0, 11, 78, 158, 34, 48, 0, 4, 1, 8, 7,
+        59, 89, 42, 117, 116, 101, 175, 11, 8, 66, 90, 213, 0, 185, 117, 96, 74, 137, 17, 0,
+        128, 8, 0, 0, 1, 3, 0, 1, 0, 0, 90, 17, 146, 175, 17, 26, 4, 193, 19, 1,
+        109, 74, 8, 47, 4, 1, 1, 0, 215, 0, 17, 4, 0, 4, 19, 96, 1, 161, 4, 4,
+        4, 1, 11, 31, 26, 70, 0, 7, 32, 138, 0, 0, 1, 2, 5, 1, 2, 0, 4, 4,
+        27, 2, 31, 1, 48, 158, 76, 4, 48, 0, 19, 7, 71, 17, 0, 0, 8, 1, 4, 2,
+        17, 0, 17, 17, 0, 8, 102, 1, 4, 66, 1, 7, 7, 1, 17, 29, 4, 4, 4, 1,
+        116, 1, 17, 3, 4, 0, 93, 34, 0, 92, 32, 11, 1, 36, 17, 112, 23, 4, 0, 5,
+        0, 133, 7, 34, 11, 1, 7, 4, 32, 19, 17, 7, 0, 34, 1, 1, 0, 17, 1, 3,
+        8, 80, 5, 3, 1, 1, 1, 1, 19, 25, 43, 0, 17, 26, 0, 19, 2, 1, 34, 4,
+        1, 1, 32, 1, 50, 17, 0, 32, 3, 31, 4, 216, 7, 0, 4, 27, 4, 17, 58, 0,
+        4, 0, 0, 2, 4, 64, 0, 19, 66, 118, 8, 5, 1, 3, 3, 8, 32, 3, 17, 3,
+        1, 1, 8, 0, 1, 48, 0, 11, 8, 1, 73, 76, 17, 29, 1, 1, 4, 8, 34, 0,
+        32, 48, 32, 17, 4, 0, 23, 4, 34, 4, 1, 0, 1, 1, 8, 7, 3, 76, 2, 71,
+        66, 48, 17, 1, 2, 101, 175, 11, 8, 90, 182, 19, 0, 185, 8, 0, 96, 19, 17, 186,
+        0, 4, 1, 34, 66, 0, 128, 8, 4, 0, 0, 0, 0, 0, 0, 2, 19, 0, 0, 177,
+        90, 17, 3, 0, 5, 0, 133, 0, 25, 17, 0, 217, 19, 0, 5, 8, 14, 113, 90, 120,
+        25, 4, 0, 36, 8, 47, 26, 131, 102, 195, 23, 25, 66, 7, 26, 5, 1, 1, 1, 1,
+        4, 1, 1, 0, 1, 1, 2, 0, 17, 4, 5, 95, 1, 2, 1, 2, 19, 25, 2, 17,
+        7, 20, 1, 161, 218, 219, 1, 220, 17, 0, 1, 1, 1, 161, 17, 1, 71, 1, 19, 19,
+        185, 95, 2, 1, 7, 2, 221, 1, 1, 132, 2, 1, 222, 102, 131, 223, 137, 224, 7, 1,
+        1, 102, 4, 4, 12, 5, 1, 2, 5, 5, 25, 33, 5, 225, 7, 23, 7, 0, 7, 72,
+        4, 1, 4, 1, 7, 36, 5, 195, 4, 0, 5, 5, 5, 1, 113, 19, 20, 226, 17, 17,
+        1, 227, 228, 131, 185, 1, 1, 148, 19, 92, 131, 1, 0, 1, 42, 28, 19, 131, 95, 66,
+        1, 2, 19, 4, 25, 1, 4, 8, 0, 36, 7, 229, 34, 7, 32, 1, 0, 25, 230, 231,
+        232, 131, 185, 4, 231, 57, 5, 7, 0, 19, 4, 110, 34, 117, 95, 2, 5, 26, 19, 7,
+        4, 1, 4, 0, 26, 95, 167, 11, 8, 233, 90, 0, 1, 2, 33, 4, 1, 4, 3, 0,
+        0, 1, 1, 5, 1, 5, 7, 8, 102, 17, 3, 91, 93, 34, 4, 60, 11, 70, 0, 36,
+        17, 1, 4, 5, 3, 17, 0, 11, 1, 1, 17, 7, 6, 0, 131, 1, 35, 4, 1, 19,
+        0, 5, 1, 0, 1, 19, 25, 43, 19, 1, 26, 185, 2, 1, 34, 1, 0, 4, 27, 4,
+        32, 3, 0, 0, 19, 4, 143, 1, 4, 5, 1, 19, 118, 8, 92, 1, 17, 1, 8, 0,
+        75, 48, 1, 73, 20, 76, 17, 4, 80, 161, 0, 4, 90, 48, 101, 175, 11, 8, 66, 0,
+        8, 0, 185, 8, 117, 74, 0, 4, 0, 0, 0, 0, 4, 0, 0, 183, 0, 0, 4, 0,
+        131, 146, 175, 133, 234, 25, 17, 0, 19, 34, 1, 153, 2, 36, 8, 31, 4, 91, 11, 36,
+        31, 1, 0, 0, 11, 1, 131, 176, 23, 80, 1, 42, 116, 133, 175, 11, 8, 161, 8, 0,
+        0, 0, 183, 3, 95, 1, 74, 0, 7, 4, 0, 25, 1, 1, 1, 231, 232, 0, 4, 1,
+        0, 1, 1, 4, 5, 23, 235, 1, 119, 19, 5, 17, 91, 0, 14, 92, 92, 0, 0, 90,
+        133, 4, 1, 175, 8, 213, 0, 2, 1, 4, 26, 19, 1, 185, 102, 95, 27, 236, 170, 133,
+        90, 1, 213, 8, 5, 70, 11, 128, 237, 1, 0, 113, 3, 1, 0, 19, 238, 3, 66, 111,
+        66, 4, 5, 185, 146, 231, 31, 96, 23, 8, 239, 240, 23, 74, 5, 12, 23, 7, 23, 7,
+        5, 32, 1, 0, 0, 23, 161, 0, 0, 0, 26, 3, 112, 23, 1, 8, 23, 4, 4, 1,
+        32, 1, 26, 1, 93, 11, 34, 1, 1, 32, 0, 1, 191, 1, 12, 1, 3, 1, 1, 5,
+        17, 3, 91, 122, 216, 112, 241, 7, 3, 91, 112, 133, 0, 138, 93, 8, 5, 10, 26, 24,
+        14, 1, 0, 92, 17, 26, 48, 0, 78, 48, 0, 7, 71, 48, 17, 0, 0, 0, 17, 186,
+        11, 0, 0, 109, 90, 0, 133, 74, 108, 102, 242, 48, 0, 7, 11, 77, 8, 90, 48, 101,
+        11, 8, 90, 8, 74, 131, 17, 0, 7, 113, 183, 7, 109, 0, 146, 109, 0, 0, 26, 0,
+        133, 0, 8, 26, 0, 175, 109, 74, 50, 23, 42, 71, 8, 0, 153, 102, 4, 117, 1, 20,
+        4, 12, 70, 23, 17, 0, 32, 1, 3, 11, 34, 0, 19, 0, 80, 4, 31, 26, 4, 0,
+        48, 11, 78, 4, 4, 32, 1, 8, 213, 66, 0, 31, 1, 7, 1, 1, 146, 17, 0, 93,
+        50, 60, 208, 31, 7, 0, 0, 0, 80, 1, 19, 43, 1, 26, 95, 2, 1, 23, 51, 7,
+        24, 4, 0, 31, 0, 74, 17, 1, 8, 0, 80, 161, 0, 23, 4, 1, 8, 7, 3, 1,
+        42, 117, 101, 175, 11, 8, 66, 5, 161, 0, 0, 8, 4, 0, 0, 0, 4, 1, 1, 109,
+        131, 146, 175, 133, 26, 0, 19, 0, 36, 11, 91, 122, 4, 26, 70, 23, 1, 7, 185, 95,
+        1, 26, 17, 1, 34, 4, 133, 90, 1, 161, 66, 117, 137, 0, 70, 23, 11, 128, 96, 7,
+        117, 113, 4, 1, 131, 185, 0, 4, 0, 74, 177, 131, 0, 7, 109, 153, 119, 91, 122, 42,
+        70, 23, 36, 1, 93, 4, 185, 95, 243, 1, 5, 153, 11, 7, 23, 80, 1, 167, 17, 1,
+        161, 66, 70, 23, 11, 1, 7, 117, 1, 113, 1, 66, 66, 0, 66, 109, 74, 177, 131, 133,
+        31, 92, 90, 1, 17, 25, 17, 4, 0, 8, 11, 4, 4, 1, 5, 244, 8, 23, 3, 17,
+        1, 175, 11, 8, 66, 66, 185, 175, 4, 4, 19, 91, 122, 60, 59, 31, 4, 26, 70, 23,
+        1, 6, 4, 1, 3, 32, 1, 0, 32, 185, 95, 23, 51, 5, 60, 24, 2, 19, 1, 31,
+        4, 1, 26, 1, 5, 4, 26, 18, 166, 1, 90, 5, 18, 17, 4, 1, 19, 32, 133, 72,
+        5, 161, 66, 117, 185, 137, 131, 70, 97, 11, 1, 7, 117, 1, 113, 183, 1, 19, 238, 131,
+        3, 28, 131, 95, 12, 1, 4, 32, 1, 109, 74, 188, 20, 146, 109, 7, 175, 26, 7, 1,
+        0, 5, 4, 34, 3, 109, 153, 31, 1, 1, 245, 1, 0, 1, 5, 12, 1, 246, 16, 46,
+        0, 0, 31, 7, 5, 5, 1, 17, 12, 70, 247, 175, 4, 32, 1, 7, 5, 32, 1, 1,
+        1, 109, 11, 3, 3, 4, 4, 133, 1, 31, 112, 70, 23, 1, 1, 1, 2, 1, 1, 0,
+        0, 0, 46, 0, 0, 0, 74, 0, 17, 0, 0, 0, 0, 0, 60, 24, 2, 1, 1, 26,
+        4, 26, 17, 4, 1, 17, 90, 3, 5, 161, 4, 137, 131, 19, 3, 70, 11, 128, 4, 1,
+        8, 7, 117, 1, 17, 3, 1, 238, 3, 28, 131, 109, 188, 248, 5, 70, 23, 19, 133, 70,
+        23, 7, 117, 113, 1, 177, 131, 7, 4, 19, 7, 5, 128, 5, 26, 95, 0, 8, 96, 70,
+        0, 90, 26, 161, 4, 204, 112, 7, 219, 249, 183, 36, 23, 26, 5, 0, 19, 7, 29, 0,
+        0, 0, 3, 32, 25, 7, 6, 5, 66, 0, 11, 8, 17, 1, 101, 11, 8, 66, 213, 0,
+        117, 96, 0, 7, 32, 113, 1, 146, 250, 23, 167, 133, 70, 11, 251, 252, 91, 133, 7, 93,
+        8, 24, 14, 23, 80, 0, 66, 70, 1, 113, 2, 19, 23, 133, 93, 3, 8, 4, 26, 153,
+        34, 91, 122, 1, 6, 93, 4, 32, 1, 2, 1, 1, 26, 4, 26, 17, 1, 133, 70, 23,
+        11, 11, 128, 96, 1, 7, 1, 1, 28, 131, 3, 4, 109, 153, 131, 223, 20, 42, 28, 109,
+        7, 2, 26, 111, 28, 95, 8, 1, 32, 32, 1, 3, 4, 2, 1, 0, 102, 253, 4, 7,
+        71, 3, 0, 0, 26, 0, 11, 7, 2, 0, 74, 8, 1, 0, 43, 10, 0, 0, 4, 32,
+        58, 14, 0, 131, 8, 92, 48, 0, 78, 254, 48, 1, 17, 0, 4, 8, 7, 0, 71, 48,
+        0, 161, 117, 0, 66, 0, 0, 90, 146, 0, 1, 0, 102, 1, 90, 48, 113, 1, 0, 4,
+        117, 185, 1, 28, 1, 109, 131, 23, 80, 133, 161, 66, 70, 23, 11, 175, 11, 8, 161, 4,
+        0, 8, 36, 31, 112, 23, 133, 93, 4, 80, 1, 26, 95, 23, 51, 255, 31, 26, 23, 8,
+        83, 0, 4, 42, 117, 101, 175, 11, 8, 74, 131, 70, 11, 113, 3, 111, 133, 26, 31, 92,
+        17, 1, 91, 122, 17, 1, 133,
+    ],
+    &[
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 6, 3), Edit::new(0, 9, 4), Edit::new(0, 6, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 13, 3), Edit::new(0, 16, 4), Edit::new(0, 13, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 20, 3), Edit::new(0, 23, 4), Edit::new(0, 20, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 2, 1), Edit::new(0, 3, 3), Edit::new(0, 2, 1)],
+        [Edit::new(0, 27, 2), Edit::new(3, 29, 4), Edit::new(0, 3, 3), Edit::new(3, 29, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 3, 3), Edit::new(0, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 23, 4), Edit::new(0, 0, 0)],
+        [Edit::new(1, 33, 2), Edit::new(1, 35, 2), Edit::new(0, 3, 2), Edit::new(1, 37, 3)],
+        [Edit::new(0, 27, 2), Edit::new(1, 35, 2), Edit::new(0, 3, 3), Edit::new(0, 40, 2)],
+        [Edit::new(0, 0, 1), Edit::new(1, 20, 1), Edit::new(0, 3, 3), Edit::new(1, 20, 1)],
+        [Edit::new(0, 27, 2), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 42, 3), Edit::new(0, 45, 4), Edit::new(0, 42, 3)],
+        [Edit::new(0, 27, 2), Edit::new(2, 49, 4), Edit::new(0, 3, 3), Edit::new(0, 40, 2)],
+        [Edit::new(0, 27, 2), Edit::new(0, 1, 2), Edit::new(0, 53, 4), Edit::new(0, 1, 2)],
+        [Edit::new(1, 0, 1), Edit::new(1, 2, 1), Edit::new(1, 3, 3), Edit::new(1, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 57, 3), Edit::new(0, 60, 4), Edit::new(0, 57, 3)],
+        [Edit::new(1, 0, 1), Edit::new(1, 1, 2), Edit::new(1, 3, 3), Edit::new(1, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 64, 3), Edit::new(0, 67, 4), Edit::new(0, 64, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 71, 3), Edit::new(0, 74, 4), Edit::new(0, 71, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 5, 1), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 0), Edit::new(0, 0, 0), Edit::new(0, 0, 0), Edit::new(0, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(2, 2, 1), Edit::new(0, 3, 3), Edit::new(2, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 78, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 2, 1), Edit::new(2, 81, 4), Edit::new(0, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 41, 2), Edit::new(0, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(4, 85, 3), Edit::new(1, 3, 3), Edit::new(4, 88, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 92, 3), Edit::new(1, 3, 3), Edit::new(3, 92, 3)],
+        [Edit::new(0, 0, 0), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 1, 2)],
+        [Edit::new(1, 0, 1), Edit::new(4, 20, 1), Edit::new(1, 3, 3), Edit::new(4, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 95, 4), Edit::new(0, 3, 3), Edit::new(3, 95, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 99, 3), Edit::new(0, 102, 4), Edit::new(0, 99, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 106, 3), Edit::new(0, 3, 3), Edit::new(3, 106, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 109, 3), Edit::new(0, 2, 4), Edit::new(0, 109, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 112, 3), Edit::new(0, 115, 4), Edit::new(0, 112, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 2, 4), Edit::new(0, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(0, 71, 3), Edit::new(0, 3, 3), Edit::new(0, 71, 3)],
+        [Edit::new(0, 119, 3), Edit::new(0, 122, 3), Edit::new(0, 125, 4), Edit::new(0, 122, 3)],
+        [Edit::new(0, 0, 0), Edit::new(2, 129, 4), Edit::new(3, 133, 4), Edit::new(2, 129, 4)],
+        [Edit::new(0, 0, 1), Edit::new(2, 137, 3), Edit::new(0, 67, 4), Edit::new(2, 140, 3)],
+        [Edit::new(2, 33, 2), Edit::new(2, 37, 3), Edit::new(0, 3, 2), Edit::new(2, 37, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 143, 3), Edit::new(1, 3, 3), Edit::new(1, 146, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 29, 2), Edit::new(0, 149, 4), Edit::new(2, 29, 2)],
+        [Edit::new(0, 153, 3), Edit::new(0, 0, 3), Edit::new(0, 156, 4), Edit::new(0, 0, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 35, 2), Edit::new(1, 3, 3), Edit::new(1, 40, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 160, 3), Edit::new(0, 3, 3), Edit::new(0, 50, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 163, 2), Edit::new(0, 60, 4), Edit::new(2, 163, 2)],
+        [Edit::new(0, 0, 1), Edit::new(1, 35, 2), Edit::new(0, 3, 3), Edit::new(1, 35, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(1, 81, 4), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 165, 3), Edit::new(0, 3, 3), Edit::new(0, 50, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 168, 2), Edit::new(0, 3, 3), Edit::new(2, 168, 2)],
+        [Edit::new(0, 27, 2), Edit::new(0, 20, 1), Edit::new(0, 3, 3), Edit::new(0, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 96, 3), Edit::new(0, 3, 3), Edit::new(3, 96, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 170, 3), Edit::new(0, 173, 4), Edit::new(0, 170, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 49, 4), Edit::new(1, 177, 2), Edit::new(0, 1, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 20, 1), Edit::new(0, 16, 4), Edit::new(0, 20, 1)],
+        [Edit::new(0, 27, 2), Edit::new(0, 0, 3), Edit::new(0, 156, 4), Edit::new(0, 0, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 179, 2), Edit::new(0, 23, 4), Edit::new(2, 179, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 181, 2), Edit::new(0, 60, 4), Edit::new(2, 38, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 179, 2), Edit::new(0, 23, 4), Edit::new(2, 183, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 188, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 0), Edit::new(3, 191, 3), Edit::new(0, 3, 3), Edit::new(3, 191, 3)],
+        [Edit::new(3, 194, 3), Edit::new(3, 197, 3), Edit::new(0, 3, 3), Edit::new(3, 197, 3)],
+        [Edit::new(2, 0, 1), Edit::new(2, 2, 1), Edit::new(1, 3, 3), Edit::new(2, 2, 1)],
+        [Edit::new(1, 0, 1), Edit::new(1, 2, 1), Edit::new(0, 3, 3), Edit::new(1, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(1, 0, 1), Edit::new(2, 0, 0), Edit::new(2, 3, 3), Edit::new(2, 146, 3)],
+        [Edit::new(1, 0, 1), Edit::new(1, 2, 1), Edit::new(2, 3, 2), Edit::new(1, 2, 1)],
+        [Edit::new(1, 0, 1), Edit::new(1, 0, 0), Edit::new(1, 3, 3), Edit::new(1, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(3, 106, 3), Edit::new(0, 3, 3), Edit::new(3, 200, 4)],
+        [Edit::new(0, 0, 1), Edit::new(2, 181, 2), Edit::new(0, 60, 4), Edit::new(0, 0, 0)],
+        [Edit::new(1, 0, 1), Edit::new(1, 20, 3), Edit::new(1, 23, 4), Edit::new(1, 20, 3)],
+        [Edit::new(0, 0, 1), Edit::new(1, 20, 1), Edit::new(1, 3, 3), Edit::new(1, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 204, 2), Edit::new(0, 3, 3), Edit::new(2, 204, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 2, 1), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 109, 2), Edit::new(1, 3, 3), Edit::new(2, 109, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 206, 3), Edit::new(1, 3, 3), Edit::new(3, 209, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 212, 2), Edit::new(2, 81, 4), Edit::new(2, 214, 3)],
+        [Edit::new(1, 0, 1), Edit::new(1, 217, 3), Edit::new(1, 220, 4), Edit::new(1, 217, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 20, 1), Edit::new(0, 3, 3), Edit::new(0, 20, 1)],
+        [Edit::new(1, 0, 1), Edit::new(1, 1, 2), Edit::new(1, 4, 2), Edit::new(1, 1, 2)],
+        [Edit::new(0, 34, 1), Edit::new(0, 2, 1), Edit::new(0, 3, 3), Edit::new(0, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 212, 2), Edit::new(1, 3, 3), Edit::new(2, 214, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 71, 3), Edit::new(0, 74, 3), Edit::new(0, 71, 3)],
+        [Edit::new(0, 0, 0), Edit::new(2, 224, 4), Edit::new(0, 3, 3), Edit::new(2, 224, 4)],
+        [Edit::new(0, 0, 0), Edit::new(0, 0, 0), Edit::new(0, 3, 3), Edit::new(0, 0, 0)],
+        [Edit::new(1, 0, 1), Edit::new(1, 1, 2), Edit::new(1, 3, 3), Edit::new(1, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 228, 3), Edit::new(0, 3, 2), Edit::new(3, 228, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 231, 3), Edit::new(0, 234, 4), Edit::new(0, 231, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 238, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 206, 3), Edit::new(0, 3, 3), Edit::new(3, 241, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 245, 3), Edit::new(0, 3, 3), Edit::new(3, 245, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 137, 3), Edit::new(1, 3, 3), Edit::new(0, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 23, 4), Edit::new(0, 20, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 248, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 160, 2), Edit::new(0, 23, 4), Edit::new(2, 160, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 217, 3), Edit::new(0, 220, 4), Edit::new(0, 217, 3)],
+        [Edit::new(1, 0, 1), Edit::new(1, 112, 3), Edit::new(1, 115, 4), Edit::new(1, 112, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 85, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(1, 3, 3), Edit::new(0, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(2, 251, 2), Edit::new(0, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 253, 3), Edit::new(1, 3, 3), Edit::new(1, 185, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 256, 3), Edit::new(0, 74, 3), Edit::new(0, 201, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 238, 3), Edit::new(0, 3, 3), Edit::new(0, 201, 3)],
+        [Edit::new(0, 259, 3), Edit::new(0, 262, 3), Edit::new(0, 265, 4), Edit::new(0, 262, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 106, 3), Edit::new(1, 3, 3), Edit::new(3, 200, 4)],
+        [Edit::new(0, 0, 0), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 20, 1)],
+        [Edit::new(2, 179, 2), Edit::new(1, 194, 2), Edit::new(0, 23, 4), Edit::new(1, 194, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 206, 3), Edit::new(0, 3, 3), Edit::new(3, 209, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 269, 5), Edit::new(1, 3, 3), Edit::new(4, 269, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 143, 3), Edit::new(1, 3, 3), Edit::new(3, 143, 3)],
+        [Edit::new(0, 0, 1), Edit::new(1, 0, 0), Edit::new(1, 3, 3), Edit::new(1, 185, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 274, 3), Edit::new(0, 3, 3), Edit::new(3, 274, 3)],
+        [Edit::new(0, 0, 1), Edit::new(1, 2, 1), Edit::new(1, 3, 3), Edit::new(1, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 2, 1), Edit::new(0, 0, 1), Edit::new(2, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 277, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 280, 3), Edit::new(0, 3, 3), Edit::new(3, 283, 3)],
+        [Edit::new(0, 0, 1), Edit::new(1, 0, 0), Edit::new(1, 3, 3), Edit::new(1, 146, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 95, 4), Edit::new(1, 3, 3), Edit::new(4, 95, 4)],
+        [Edit::new(1, 0, 1), Edit::new(1, 99, 3), Edit::new(1, 102, 4), Edit::new(1, 99, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 206, 3), Edit::new(1, 3, 3), Edit::new(4, 241, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 3, 3), Edit::new(0, 50, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 228, 3), Edit::new(1, 3, 3), Edit::new(3, 143, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 165, 2), Edit::new(0, 3, 3), Edit::new(0, 50, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 137, 2), Edit::new(0, 67, 4), Edit::new(2, 286, 4)],
+        [Edit::new(0, 0, 1), Edit::new(4, 95, 4), Edit::new(1, 3, 3), Edit::new(4, 95, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 143, 3), Edit::new(1, 3, 3), Edit::new(0, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 38, 2), Edit::new(0, 60, 4), Edit::new(2, 38, 2)],
+        [Edit::new(0, 27, 2), Edit::new(1, 20, 1), Edit::new(0, 3, 3), Edit::new(1, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(0, 20, 1), Edit::new(0, 3, 2), Edit::new(0, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 283, 3), Edit::new(0, 3, 3), Edit::new(3, 283, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 4, 2), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 269, 5), Edit::new(0, 3, 3), Edit::new(3, 269, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 269, 5), Edit::new(2, 81, 4), Edit::new(3, 269, 5)],
+        [Edit::new(1, 0, 1), Edit::new(1, 57, 3), Edit::new(1, 60, 4), Edit::new(1, 57, 3)],
+        [Edit::new(0, 290, 3), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(1, 0, 0), Edit::new(1, 3, 3), Edit::new(1, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(3, 0, 2), Edit::new(1, 3, 3), Edit::new(3, 293, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 13, 3), Edit::new(0, 16, 3), Edit::new(0, 13, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 277, 3), Edit::new(1, 3, 3), Edit::new(3, 296, 4)],
+        [Edit::new(1, 0, 1), Edit::new(4, 300, 2), Edit::new(2, 3, 3), Edit::new(4, 300, 2)],
+        [Edit::new(1, 0, 1), Edit::new(1, 20, 1), Edit::new(1, 302, 4), Edit::new(1, 20, 1)],
+        [Edit::new(1, 0, 1), Edit::new(4, 283, 3), Edit::new(1, 3, 3), Edit::new(4, 283, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 137, 3), Edit::new(0, 3, 3), Edit::new(3, 137, 3)],
+        [Edit::new(1, 27, 2), Edit::new(5, 306, 5), Edit::new(1, 3, 3), Edit::new(5, 306, 5)],
+        [Edit::new(0, 27, 2), Edit::new(4, 306, 5), Edit::new(0, 3, 3), Edit::new(4, 306, 5)],
+        [Edit::new(0, 0, 0), Edit::new(3, 311, 4), Edit::new(3, 315, 6), Edit::new(3, 311, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 2), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 0), Edit::new(1, 3, 3), Edit::new(0, 0, 1), Edit::new(4, 88, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 321, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 27, 2), Edit::new(0, 1, 2), Edit::new(0, 3, 2), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(4, 160, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(4, 248, 3), Edit::new(1, 3, 3), Edit::new(4, 324, 4)],
+        [Edit::new(4, 328, 2), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 1, 2)],
+        [Edit::new(1, 0, 1), Edit::new(4, 330, 3), Edit::new(1, 3, 3), Edit::new(4, 333, 4)],
+        [Edit::new(1, 0, 1), Edit::new(5, 85, 3), Edit::new(2, 3, 3), Edit::new(5, 88, 4)],
+        [Edit::new(1, 0, 1), Edit::new(4, 269, 5), Edit::new(1, 3, 3), Edit::new(4, 269, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 20, 1), Edit::new(0, 3, 3), Edit::new(3, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(1, 2, 1), Edit::new(0, 3, 3), Edit::new(1, 2, 1)],
+        [Edit::new(2, 337, 3), Edit::new(2, 41, 2), Edit::new(1, 3, 3), Edit::new(2, 340, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 20, 1), Edit::new(0, 3, 3), Edit::new(2, 20, 1)],
+        [Edit::new(1, 343, 3), Edit::new(1, 231, 3), Edit::new(1, 234, 4), Edit::new(1, 231, 3)],
+        [Edit::new(0, 291, 2), Edit::new(0, 2, 1), Edit::new(1, 3, 3), Edit::new(0, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(4, 245, 3), Edit::new(0, 3, 3), Edit::new(4, 245, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 346, 6), Edit::new(0, 3, 3), Edit::new(3, 346, 6)],
+        [Edit::new(1, 0, 1), Edit::new(3, 2, 1), Edit::new(1, 3, 3), Edit::new(3, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 300, 2), Edit::new(1, 3, 3), Edit::new(3, 300, 2)],
+        [Edit::new(1, 0, 1), Edit::new(5, 206, 3), Edit::new(2, 3, 3), Edit::new(5, 209, 3)],
+        [Edit::new(1, 0, 1), Edit::new(1, 20, 3), Edit::new(1, 23, 4), Edit::new(1, 0, 0)],
+        [Edit::new(1, 0, 1), Edit::new(1, 2, 1), Edit::new(3, 81, 4), Edit::new(1, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 352, 3), Edit::new(0, 3, 3), Edit::new(3, 355, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 181, 2), Edit::new(0, 60, 4), Edit::new(2, 181, 2)],
+        [Edit::new(0, 0, 0), Edit::new(3, 358, 3), Edit::new(1, 3, 3), Edit::new(3, 358, 3)],
+        [Edit::new(1, 0, 0), Edit::new(1, 0, 0), Edit::new(1, 3, 3), Edit::new(1, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(3, 361, 3), Edit::new(0, 3, 3), Edit::new(3, 361, 3)],
+        [Edit::new(1, 337, 3), Edit::new(1, 41, 2), Edit::new(0, 3, 3), Edit::new(1, 340, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 137, 2), Edit::new(0, 67, 4), Edit::new(2, 355, 2)],
+        [Edit::new(0, 0, 1), Edit::new(0, 2, 1), Edit::new(1, 3, 2), Edit::new(0, 2, 1)],
+        [Edit::new(0, 0, 1), Edit::new(2, 364, 3), Edit::new(0, 3, 3), Edit::new(2, 364, 3)],
+        [Edit::new(0, 27, 2), Edit::new(4, 269, 5), Edit::new(0, 3, 3), Edit::new(4, 269, 5)],
+        [Edit::new(0, 0, 0), Edit::new(3, 129, 4), Edit::new(0, 3, 3), Edit::new(3, 129, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(3, 209, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 333, 3), Edit::new(0, 3, 3), Edit::new(3, 333, 4)],
+        [Edit::new(1, 0, 0), Edit::new(1, 1, 2), Edit::new(1, 3, 3), Edit::new(1, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 367, 3), Edit::new(0, 3, 3), Edit::new(3, 370, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 41, 2), Edit::new(0, 3, 3), Edit::new(1, 3, 2)],
+        [Edit::new(0, 0, 1), Edit::new(4, 253, 3), Edit::new(0, 3, 3), Edit::new(4, 373, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(3, 377, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 106, 3), Edit::new(1, 3, 3), Edit::new(2, 382, 4)],
+        [Edit::new(0, 0, 1), Edit::new(4, 269, 5), Edit::new(0, 3, 3), Edit::new(4, 269, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 92, 3), Edit::new(1, 3, 3), Edit::new(2, 382, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 302, 4), Edit::new(0, 1, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 143, 2), Edit::new(0, 3, 3), Edit::new(3, 386, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 256, 3), Edit::new(0, 3, 2), Edit::new(0, 50, 2)],
+        [Edit::new(1, 0, 1), Edit::new(4, 280, 3), Edit::new(1, 3, 3), Edit::new(4, 283, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 224, 4), Edit::new(0, 3, 3), Edit::new(3, 224, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 206, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(1, 0, 1), Edit::new(5, 248, 3), Edit::new(2, 3, 3), Edit::new(5, 324, 4)],
+        [Edit::new(0, 0, 1), Edit::new(0, 50, 2), Edit::new(4, 269, 5), Edit::new(0, 50, 2)],
+        [Edit::new(1, 0, 1), Edit::new(3, 179, 2), Edit::new(1, 23, 4), Edit::new(3, 183, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 2, 1), Edit::new(1, 3, 3), Edit::new(3, 2, 1)],
+        [Edit::new(1, 0, 1), Edit::new(1, 20, 1), Edit::new(1, 3, 3), Edit::new(1, 20, 1)],
+        [Edit::new(0, 0, 1), Edit::new(3, 41, 3), Edit::new(1, 3, 3), Edit::new(1, 40, 2)],
+        [Edit::new(0, 0, 1), Edit::new(4, 391, 6), Edit::new(1, 3, 3), Edit::new(4, 391, 6)],
+        [Edit::new(1, 0, 1), Edit::new(1, 13, 3), Edit::new(1, 16, 4), Edit::new(1, 13, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 137, 3), Edit::new(1, 3, 3), Edit::new(1, 0, 0)],
+        [Edit::new(1, 337, 3), Edit::new(1, 397, 3), Edit::new(0, 3, 2), Edit::new(1, 397, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 0, 0), Edit::new(0, 2, 4), Edit::new(0, 146, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 41, 3), Edit::new(1, 3, 3), Edit::new(0, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(4, 400, 4), Edit::new(0, 3, 3), Edit::new(4, 404, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 333, 3), Edit::new(1, 3, 3), Edit::new(3, 333, 4)],
+        [Edit::new(1, 0, 1), Edit::new(3, 204, 2), Edit::new(1, 3, 3), Edit::new(3, 204, 2)],
+        [Edit::new(0, 0, 1), Edit::new(3, 163, 3), Edit::new(1, 3, 3), Edit::new(3, 163, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(5, 306, 5)],
+        [Edit::new(0, 0, 1), Edit::new(0, 99, 3), Edit::new(0, 3, 3), Edit::new(0, 99, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 228, 3), Edit::new(0, 2, 4), Edit::new(0, 146, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 143, 2), Edit::new(1, 3, 3), Edit::new(4, 386, 5)],
+        [Edit::new(1, 0, 1), Edit::new(1, 109, 3), Edit::new(1, 2, 4), Edit::new(1, 109, 3)],
+        [Edit::new(0, 0, 1), Edit::new(2, 20, 1), Edit::new(1, 3, 3), Edit::new(2, 20, 1)],
+        [Edit::new(1, 0, 1), Edit::new(4, 163, 3), Edit::new(2, 3, 3), Edit::new(4, 163, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 408, 4), Edit::new(1, 3, 3), Edit::new(4, 412, 4)],
+        [Edit::new(0, 0, 1), Edit::new(3, 416, 3), Edit::new(1, 3, 3), Edit::new(3, 416, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 370, 3), Edit::new(0, 3, 3), Edit::new(3, 370, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 248, 3), Edit::new(2, 3, 3), Edit::new(1, 4, 1)],
+        [Edit::new(0, 0, 1), Edit::new(4, 106, 3), Edit::new(1, 3, 3), Edit::new(4, 106, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 333, 3), Edit::new(1, 3, 3), Edit::new(4, 333, 4)],
+        [Edit::new(1, 0, 1), Edit::new(4, 92, 3), Edit::new(1, 3, 3), Edit::new(4, 92, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 92, 3), Edit::new(1, 3, 3), Edit::new(4, 92, 3)],
+        [Edit::new(0, 0, 1), Edit::new(0, 1, 2), Edit::new(0, 3, 3), Edit::new(0, 50, 2)],
+        [Edit::new(0, 0, 1), Edit::new(1, 397, 3), Edit::new(0, 3, 3), Edit::new(1, 397, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 206, 3), Edit::new(1, 3, 3), Edit::new(4, 209, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 206, 3), Edit::new(1, 3, 3), Edit::new(4, 241, 4)],
+        [Edit::new(0, 0, 1), Edit::new(2, 300, 2), Edit::new(1, 3, 3), Edit::new(2, 300, 2)],
+        [Edit::new(0, 0, 1), Edit::new(2, 41, 2), Edit::new(0, 3, 3), Edit::new(2, 41, 2)],
+        [Edit::new(1, 0, 0), Edit::new(4, 129, 4), Edit::new(1, 3, 3), Edit::new(4, 129, 4)],
+        [Edit::new(0, 0, 1), Edit::new(4, 419, 4), Edit::new(1, 3, 3), Edit::new(4, 419, 4)],
+        [Edit::new(0, 0, 1), Edit::new(2, 160, 2), Edit::new(0, 23, 4), Edit::new(0, 185, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 92, 3), Edit::new(0, 3, 3), Edit::new(3, 92, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 106, 3), Edit::new(1, 3, 3), Edit::new(4, 200, 4)],
+        [Edit::new(0, 0, 1), Edit::new(4, 106, 3), Edit::new(1, 3, 3), Edit::new(4, 200, 4)],
+        [Edit::new(0, 0, 1), Edit::new(4, 423, 4), Edit::new(1, 3, 3), Edit::new(4, 427, 5)],
+        [Edit::new(0, 0, 1), Edit::new(2, 432, 3), Edit::new(0, 23, 4), Edit::new(0, 185, 3)],
+        [Edit::new(1, 0, 1), Edit::new(4, 333, 3), Edit::new(1, 3, 3), Edit::new(4, 333, 4)],
+        [Edit::new(0, 0, 1), Edit::new(2, 137, 3), Edit::new(0, 67, 4), Edit::new(0, 0, 0)],
+        [Edit::new(1, 0, 1), Edit::new(1, 64, 3), Edit::new(1, 67, 4), Edit::new(1, 64, 3)],
+        [Edit::new(0, 0, 1), Edit::new(4, 367, 3), Edit::new(1, 3, 3), Edit::new(4, 435, 4)],
+        [Edit::new(7, 439, 8), Edit::new(10, 447, 10), Edit::new(0, 3, 3), Edit::new(10, 447, 10)],
+        [Edit::new(0, 0, 1), Edit::new(3, 143, 2), Edit::new(0, 3, 3), Edit::new(3, 143, 2)],
+        [Edit::new(1, 0, 1), Edit::new(1, 1, 2), Edit::new(1, 3, 3), Edit::new(1, 0, 0)],
+        [Edit::new(0, 0, 1), Edit::new(2, 361, 3), Edit::new(0, 102, 4), Edit::new(2, 361, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 280, 3), Edit::new(0, 3, 3), Edit::new(3, 457, 5)],
+        [Edit::new(0, 0, 1), Edit::new(3, 367, 3), Edit::new(0, 3, 3), Edit::new(3, 462, 5)],
+        [Edit::new(0, 0, 1), Edit::new(2, 20, 1), Edit::new(0, 3, 3), Edit::new(2, 184, 4)],
+        [Edit::new(1, 337, 3), Edit::new(1, 212, 2), Edit::new(0, 3, 3), Edit::new(1, 214, 3)],
+        [Edit::new(0, 0, 1), Edit::new(3, 49, 4), Edit::new(1, 3, 3), Edit::new(1, 40, 2)],
+    ],
+    "sedingbedbingredringtedtingesughtizidyunnewedwingwenteingnedningmedmingkedkingaveyingozeozenuckoundledlingokededfedfingzeszedzingouldableameomeodedengingsessingateonellltqedqingwnotanottenauehadstaddaokenptoreornayaincedcingightadevedvingookorneeldoveawoteeukxesxedxingoughtoodoseangungomenethsenosenftpingaughturfturvingoueovenitaleoleniesownvesroughtampumpustoldideankunkotenollenckenoddenoughteiedanckunckangeungepteeldeorveorveneetunkesthroughuckthroughungenunken",
+);
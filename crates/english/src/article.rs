@@ -0,0 +1,83 @@
+use crate::English;
+use alloc::{format, string::String};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+
+/// Curated words whose first sound contradicts their spelling, with the
+/// article they take. Case is kept so that initialisms read as words
+/// (`"NASA"`) stay apart from those spelled out letter by letter. Sorted for
+/// binary search.
+static ARTICLE_EXCEPTIONS: &[(&str, &str)] = &[
+    ("NAFTA", "a"),
+    ("NASA", "a"),
+    ("NATO", "a"),
+    ("RAM", "a"),
+    ("SIM", "a"),
+    ("SWAT", "a"),
+    ("nth", "an"),
+    ("oneness", "a"),
+    ("onesie", "a"),
+    ("ouija", "a"),
+    ("unanimous", "a"),
+    ("unary", "a"),
+    ("unidirectional", "a"),
+    ("unimodal", "a"),
+    ("uranium", "a"),
+    ("uvula", "a"),
+    ("ytterbium", "an"),
+    ("yttrium", "an"),
+];
+
+fn get_article(word: &str) -> Option<&'static str> {
+    ARTICLE_EXCEPTIONS
+        .binary_search_by_key(&word, |(exception, _)| exception)
+        .ok()
+        .map(|index| ARTICLE_EXCEPTIONS[index].1)
+}
+
+impl English {
+    /// Picks `"a"` or `"an"` by how `word` is pronounced rather than how it
+    /// is spelled.
+    ///
+    /// A short curated list covers words whose first sound contradicts
+    /// their spelling (`"NASA"`, `"ouija"`, `"yttrium"`); it is written by
+    /// hand, not generated from pronunciation data, so rarer words of that
+    /// kind follow their spelling. Anything else is left to the spelling,
+    /// initialism and numeral rules of [`EnglishCore::indefinite_article`],
+    /// which read initialisms letter by letter (`"an SQL"`). Only the first
+    /// word, or the first element of a hyphenated compound, is considered.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::indefinite_article("hour"), "an");
+    /// assert_eq!(English::indefinite_article("university"), "a");
+    /// assert_eq!(English::indefinite_article("MRI"), "an");
+    /// assert_eq!(English::indefinite_article("NASA"), "a");
+    /// assert_eq!(English::indefinite_article("one-off"), "a");
+    /// assert_eq!(English::indefinite_article("8"), "an");
+    /// ```
+    pub fn indefinite_article(word: &str) -> &'static str {
+        let head = EnglishCore::article_head(word);
+        get_article(head)
+            .or_else(|| get_article(&head.to_lowercase()))
+            .unwrap_or_else(|| EnglishCore::indefinite_article(head))
+    }
+
+    /// Puts the article for `det` in front of `word`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Det, English};
+    ///
+    /// assert_eq!(English::with_article("honest man", &Det::Indefinite), "an honest man");
+    /// assert_eq!(English::with_article("hour", &Det::Definite), "the hour");
+    /// ```
+    pub fn with_article(word: &str, det: &Det) -> String {
+        match det {
+            Det::Definite => format!("the {word}"),
+            Det::Indefinite => format!("{} {word}", English::indefinite_article(word)),
+        }
+    }
+}
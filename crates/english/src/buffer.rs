@@ -0,0 +1,294 @@
+#[cfg(feature = "alloc")]
+use crate::casing::is_lowercase;
+use crate::casing::write_with_casing;
+#[cfg(feature = "alloc")]
+use crate::{ADJECTIVES, NOUNS, PartOfSpeech, TableForm, VERBS, resolve_sense, verb_form};
+use crate::{
+    English, get_adjective_forms, get_plural, get_verb_forms, strip_trailing_number, write_forms,
+};
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::ToString,
+};
+use core::fmt;
+use english_core::EnglishCore;
+#[cfg(feature = "alloc")]
+use english_core::collect;
+use english_core::grammar::*;
+
+/// Borrows `form` if the table stores it whole.
+#[cfg(feature = "alloc")]
+fn borrow(form: TableForm<'_>) -> Cow<'static, str> {
+    match form.as_static() {
+        Some(form) => Cow::Borrowed(form),
+        None => Cow::Owned(form.to_string()),
+    }
+}
+
+/// The lemma of `word`, borrowed from `key`, the table key it matched, when
+/// the table stores keys whole.
+#[cfg(feature = "alloc")]
+fn borrow_key(key: TableForm<'_>, word: &str) -> Cow<'static, str> {
+    match key.as_static() {
+        Some(key) => Cow::Borrowed(strip_trailing_number(key)),
+        None => Cow::Owned(strip_trailing_number(word).to_owned()),
+    }
+}
+
+/// Writes the inflection of a lowercase noun, as [`English::noun`] does
+/// once casing is taken care of.
+pub(crate) fn write_noun_lowercase<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    word: &str,
+    number: &Number,
+) -> fmt::Result {
+    #[cfg(feature = "alloc")]
+    let word = &*resolve_sense(PartOfSpeech::Noun, word);
+    #[cfg(not(feature = "alloc"))]
+    let word = crate::strip_sense_label(word);
+    let base_word = strip_trailing_number(word);
+    match number {
+        Number::Singular => out.write_str(base_word),
+        Number::Plural => {
+            if let Some(plural) = get_plural(word) {
+                plural.write_to(out)
+            } else if let Some((start, end)) = EnglishCore::compound_head(base_word) {
+                out.write_str(&base_word[..start])?;
+                write_noun_lowercase(out, &base_word[start..end], number)?;
+                out.write_str(&base_word[end..])
+            } else {
+                EnglishCore::write_noun(out, base_word, number)
+            }
+        }
+    }
+}
+
+/// Writes the comparison of a lowercase adjective; see
+/// [`write_noun_lowercase`].
+pub(crate) fn write_adj_lowercase<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    word: &str,
+    degree: &Degree,
+) -> fmt::Result {
+    #[cfg(feature = "alloc")]
+    let word = &*resolve_sense(PartOfSpeech::Adjective, word);
+    #[cfg(not(feature = "alloc"))]
+    let word = crate::strip_sense_label(word);
+    let base_word = strip_trailing_number(word);
+    match (degree, get_adjective_forms(word)) {
+        (Degree::Positive, _) => out.write_str(base_word),
+        (Degree::Comparative, Some([comp, _])) => comp.write_to(out),
+        (Degree::Superlative, Some([_, sup])) => sup.write_to(out),
+        _ => EnglishCore::write_adjective(out, base_word, degree),
+    }
+}
+
+/// Writes the conjugation of a lowercase verb; see
+/// [`write_noun_lowercase`].
+pub(crate) fn write_verb_lowercase<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    word: &str,
+    person: &Person,
+    number: &Number,
+    tense: &Tense,
+    form: &Form,
+) -> fmt::Result {
+    #[cfg(feature = "alloc")]
+    let word = &*resolve_sense(PartOfSpeech::Verb, word);
+    #[cfg(not(feature = "alloc"))]
+    let word = crate::strip_sense_label(word);
+    let base_word = strip_trailing_number(word);
+    match get_verb_forms(word) {
+        Some(forms) => write_forms(out, base_word, forms, person, number, tense, form),
+        None => EnglishCore::write_verb(out, base_word, person, number, tense, form),
+    }
+}
+
+impl English {
+    /// Inflects a noun like [`English::noun`], borrowing the answer from the
+    /// generated tables instead of allocating when it is there.
+    ///
+    /// Only lowercase words can be borrowed; other casings are restored
+    /// into a new `String`. With the `compact-tables` feature, the tables
+    /// store forms as edits of their key, and only forms that share no
+    /// prefix with the key (`"go" -> "went"`) can be borrowed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Number};
+    /// use std::borrow::Cow;
+    ///
+    /// # #[cfg(not(feature = "compact-tables"))]
+    /// assert!(matches!(English::noun_cow("child", &Number::Plural), Cow::Borrowed("children")));
+    /// # #[cfg(not(feature = "compact-tables"))]
+    /// assert!(matches!(English::noun_cow("die2", &Number::Singular), Cow::Borrowed("die")));
+    /// assert_eq!(English::noun_cow("Child", &Number::Plural), "Children");
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn noun_cow(word: &str, number: &Number) -> Cow<'static, str> {
+        if !is_lowercase(word) {
+            return Cow::Owned(English::noun(word, number));
+        }
+        let word = &*resolve_sense(PartOfSpeech::Noun, word);
+        match (number, NOUNS.get_entry(word)) {
+            (Number::Singular, Some((key, _))) => borrow_key(key, word),
+            (Number::Plural, Some((_, [plural]))) => borrow(plural),
+            _ => Cow::Owned(collect(|out| write_noun_lowercase(out, word, number))),
+        }
+    }
+
+    /// Compares an adjective like [`English::adj`], borrowing the answer from
+    /// the generated tables instead of allocating when it is there.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Degree, English};
+    /// use std::borrow::Cow;
+    ///
+    /// assert!(matches!(English::adj_cow("good", &Degree::Superlative), Cow::Borrowed("best")));
+    /// assert_eq!(English::adj_cow("careful", &Degree::Comparative), "more careful");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn adj_cow(word: &str, degree: &Degree) -> Cow<'static, str> {
+        if !is_lowercase(word) {
+            return Cow::Owned(English::adj(word, degree));
+        }
+        let word = &*resolve_sense(PartOfSpeech::Adjective, word);
+        match (degree, ADJECTIVES.get_entry(word)) {
+            (Degree::Positive, Some((key, _))) => borrow_key(key, word),
+            (Degree::Comparative, Some((_, [comp, _]))) => borrow(comp),
+            (Degree::Superlative, Some((_, [_, sup]))) => borrow(sup),
+            _ => EnglishCore::adjective_cow(strip_trailing_number(word), degree),
+        }
+    }
+
+    /// Conjugates a verb like [`English::verb`], borrowing the answer from
+    /// the generated tables instead of allocating when it is there. The
+    /// future (`"will go"`) is always built.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{English, Form, Number, Person, Tense};
+    /// use std::borrow::Cow;
+    ///
+    /// let went = English::verb_cow("go", &Person::First, &Number::Plural, &Tense::Past, &Form::Finite);
+    /// assert!(matches!(went, Cow::Borrowed("went")));
+    /// let is = English::verb_cow("be", &Person::Third, &Number::Singular, &Tense::Present, &Form::Finite);
+    /// assert!(matches!(is, Cow::Borrowed("is")));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn verb_cow(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> Cow<'static, str> {
+        if !is_lowercase(word) {
+            return Cow::Owned(English::verb(word, person, number, tense, form));
+        }
+        let word = &*resolve_sense(PartOfSpeech::Verb, word);
+        let base_word = strip_trailing_number(word);
+        match VERBS.get_entry(word) {
+            Some((key, forms)) => {
+                let key = match key.as_static() {
+                    Some(key) => TableForm::new("", strip_trailing_number(key)),
+                    None => TableForm::from(base_word),
+                };
+                match verb_form(key, forms, person, number, tense, form) {
+                    Some(form) => borrow(form),
+                    None => Cow::Owned(format!("will {key}")),
+                }
+            }
+            None => EnglishCore::verb_cow(base_word, person, number, tense, form),
+        }
+    }
+
+    /// Appends the inflected noun to `out`, a `String` or any other
+    /// [`fmt::Write`], without building an intermediate `String` for
+    /// lowercase words.
+    ///
+    /// These writers are all that is left without the `alloc` feature.
+    /// There, words that are not lowercase are inflected through fixed
+    /// 128-byte buffers, and longer ones fail with [`fmt::Error`]. Named
+    /// sense keys (`"die#dice"`) select the first sense, since telling
+    /// senses apart builds their forms; numbered keys (`"die2"`) work.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Number};
+    ///
+    /// let mut out = String::new();
+    /// for word in ["child", "box", "mother-in-law"] {
+    ///     English::write_noun(&mut out, word, &Number::Plural).unwrap();
+    ///     out.push(' ');
+    /// }
+    /// assert_eq!(out, "children boxes mothers-in-law ");
+    /// # }
+    /// ```
+    pub fn write_noun<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        number: &Number,
+    ) -> fmt::Result {
+        write_with_casing(out, word, |out, word| {
+            write_noun_lowercase(out, word, number)
+        })
+    }
+
+    /// Appends the compared adjective to `out`; see [`English::write_noun`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Degree, English};
+    ///
+    /// let mut out = String::from("the ");
+    /// English::write_adj(&mut out, "happy", &Degree::Superlative).unwrap();
+    /// assert_eq!(out, "the happiest");
+    /// ```
+    pub fn write_adj<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        degree: &Degree,
+    ) -> fmt::Result {
+        write_with_casing(out, word, |out, word| {
+            write_adj_lowercase(out, word, degree)
+        })
+    }
+
+    /// Appends the conjugated verb to `out`; see [`English::write_noun`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{English, Form, Number, Person, Tense};
+    ///
+    /// let mut out = String::from("she ");
+    /// English::write_verb(&mut out, "swim", &Person::Third, &Number::Singular, &Tense::Past, &Form::Finite).unwrap();
+    /// assert_eq!(out, "she swam");
+    /// # }
+    /// ```
+    pub fn write_verb<W: fmt::Write + ?Sized>(
+        out: &mut W,
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> fmt::Result {
+        write_with_casing(out, word, |out, word| {
+            write_verb_lowercase(out, word, person, number, tense, form)
+        })
+    }
+}
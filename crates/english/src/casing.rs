@@ -0,0 +1,178 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+#[cfg(feature = "alloc")]
+use english_core::collect;
+
+/// Capitalization pattern of an input word, reapplied to its inflection.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Casing {
+    /// `"child"`
+    Lower,
+    /// `"CHILD"`
+    Upper,
+    /// `"Child"`
+    Title,
+    /// `"iPhone"`, `"McDonald"`
+    Mixed,
+}
+
+fn casing(word: &str) -> Casing {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic());
+    let Some(first) = letters.next() else {
+        return Casing::Lower;
+    };
+    let rest_lower = letters.clone().all(|c| c.is_lowercase());
+    let rest_upper = letters.all(|c| c.is_uppercase());
+    match (first.is_uppercase(), rest_lower, rest_upper) {
+        (false, true, _) => Casing::Lower,
+        (true, true, _) => Casing::Title,
+        (true, _, true) => Casing::Upper,
+        _ => Casing::Mixed,
+    }
+}
+
+/// Copies the case of every leading character that `result` shares with
+/// `original` and leaves the rest lowercase (`"PhD" -> "PhDs"`).
+fn write_mixed<W: fmt::Write + ?Sized>(out: &mut W, original: &str, result: &str) -> fmt::Result {
+    let mut original = original.chars();
+    let mut matching = true;
+    for c in result.chars() {
+        match original.next() {
+            Some(o) if matching && o.to_lowercase().eq(c.to_lowercase()) => out.write_char(o)?,
+            _ => {
+                matching = false;
+                out.write_char(c)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// True if `word` needs no case restored, so its inflection can be written
+/// out as the tables and rules produce it.
+pub(crate) fn is_lowercase(word: &str) -> bool {
+    casing(word) == Casing::Lower
+}
+
+/// Runs `inflect` on the lowercased `word`, which is how the generated tables
+/// are keyed, and gives the result the capitalization pattern of `word`.
+#[cfg(feature = "alloc")]
+pub(crate) fn with_casing(word: &str, inflect: impl FnOnce(&str) -> String) -> String {
+    if is_lowercase(word) {
+        return inflect(word);
+    }
+    recase(word, &inflect(&word.to_lowercase()))
+}
+
+/// `result` with the capitalization pattern of `original`.
+#[cfg(feature = "alloc")]
+pub(crate) fn recase(original: &str, result: &str) -> String {
+    collect(|out| write_cased(out, original, result))
+}
+
+/// Scratch space for the lowercased word and its inflection. Without an
+/// allocator, words that do not fit make the write fail with [`fmt::Error`].
+#[cfg(feature = "alloc")]
+type Scratch = String;
+#[cfg(not(feature = "alloc"))]
+type Scratch = StackStr<128>;
+
+/// [`with_casing`] for inflections written to `out`. Lowercase words are
+/// inflected straight into `out`.
+pub(crate) fn write_with_casing<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    word: &str,
+    inflect: impl FnOnce(&mut dyn fmt::Write, &str) -> fmt::Result,
+) -> fmt::Result {
+    // `&mut W` is sized even when `W` is not, so it coerces to `dyn`.
+    let mut out = out;
+    if is_lowercase(word) {
+        return inflect(&mut out, word);
+    }
+    let mut lower = Scratch::default();
+    for c in word.chars() {
+        write!(lower, "{}", c.to_lowercase())?;
+    }
+    let mut result = Scratch::default();
+    inflect(&mut result, &lower)?;
+    write_cased(&mut out, word, &result)
+}
+
+/// A fixed-capacity string for targets without an allocator.
+#[cfg(not(feature = "alloc"))]
+pub(crate) struct StackStr<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<const N: usize> Default for StackStr<N> {
+    fn default() -> Self {
+        StackStr {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<const N: usize> fmt::Write for StackStr<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<const N: usize> core::ops::Deref for StackStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        // Only whole `&str`s are ever copied in.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+fn separators(word: &str) -> impl Iterator<Item = &str> {
+    word.matches([' ', '-'])
+}
+
+/// Writes `result` with the capitalization pattern of `original`.
+fn write_cased<W: fmt::Write + ?Sized>(out: &mut W, original: &str, result: &str) -> fmt::Result {
+    match casing(original) {
+        Casing::Lower => out.write_str(result),
+        Casing::Upper => result
+            .chars()
+            .try_for_each(|c| c.to_uppercase().try_for_each(|c| out.write_char(c))),
+        Casing::Title => {
+            let mut chars = result.chars();
+            if let Some(first) = chars.next() {
+                first.to_uppercase().try_for_each(|c| out.write_char(c))?;
+            }
+            out.write_str(chars.as_str())
+        }
+        Casing::Mixed => {
+            // Compounds ("Mother-in-Law") are cased word by word when the
+            // inflection kept their shape.
+            if separators(original).next().is_none() || !separators(original).eq(separators(result))
+            {
+                return write_mixed(out, original, result);
+            }
+            let mut separators_in = separators(original);
+            let words = original.split([' ', '-']).zip(result.split([' ', '-']));
+            for (i, (original, result)) in words.enumerate() {
+                if i > 0 {
+                    out.write_str(separators_in.next().unwrap_or_default())?;
+                }
+                write_cased(out, original, result)?;
+            }
+            Ok(())
+        }
+    }
+}
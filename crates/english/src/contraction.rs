@@ -0,0 +1,179 @@
+use crate::English;
+use crate::casing::with_casing;
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+
+/// Finite negative verb group split into words, with `"not"` always second.
+fn negative_words(word: &str, person: &Person, number: &Number, tense: &Tense) -> Vec<String> {
+    let phrase = match EnglishCore::as_modal(word) {
+        Some(modal) => format!("{} not", EnglishCore::modal(&modal)),
+        None => English::verb_phrase(
+            word,
+            person,
+            number,
+            tense,
+            &Aspect::Simple,
+            &Voice::Active,
+            &Polarity::Negative,
+        ),
+    };
+    phrase.split(' ').map(str::to_owned).collect()
+}
+
+/// Fuses the leading auxiliary with `"not"` where the register allows it.
+fn contract_negation(mut words: Vec<String>, register: &Register) -> Vec<String> {
+    match register {
+        Register::Formal if words[0] == "can" => {
+            words.splice(0..2, ["cannot".to_owned()]);
+        }
+        Register::Formal => (),
+        Register::Contracted => {
+            if let Some(contracted) = EnglishCore::negative_contraction(&words[0]) {
+                words.splice(0..2, [contracted.to_owned()]);
+            }
+        }
+    }
+    words
+}
+
+impl English {
+    /// Negates a finite verb, using do-support where English needs it and
+    /// `n't` contractions in the [`Register::Contracted`] register.
+    ///
+    /// Modals (`"can"`, `"must"`, ...) are negated directly. The first person
+    /// singular of "be" has no contraction and stays `"am not"`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{English, Number, Person, Register, Tense};
+    ///
+    /// let (p, n) = (&Person::Third, &Number::Singular);
+    /// assert_eq!(English::negative("be", p, n, &Tense::Present, &Register::Contracted), "isn't");
+    /// assert_eq!(English::negative("run", p, n, &Tense::Present, &Register::Contracted), "doesn't run");
+    /// assert_eq!(English::negative("run", p, n, &Tense::Present, &Register::Formal), "does not run");
+    /// assert_eq!(English::negative("go", p, n, &Tense::Future, &Register::Contracted), "won't go");
+    /// assert_eq!(English::negative("can", p, n, &Tense::Present, &Register::Contracted), "can't");
+    /// assert_eq!(English::negative("can", p, n, &Tense::Present, &Register::Formal), "cannot");
+    /// assert_eq!(
+    ///     English::negative("be", &Person::First, &Number::Singular, &Tense::Present, &Register::Contracted),
+    ///     "am not"
+    /// );
+    /// ```
+    pub fn negative(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        register: &Register,
+    ) -> String {
+        with_casing(word, |word| {
+            contract_negation(negative_words(word, person, number, tense), register).join(" ")
+        })
+    }
+
+    /// Builds a negative question with the subject pronoun after the first
+    /// auxiliary (`"doesn't she run"`, formally `"does she not run"`).
+    ///
+    /// The first person singular of "be" uses the irregular `"aren't I"`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{English, Gender, Number, Person, Register, Tense};
+    ///
+    /// assert_eq!(
+    ///     English::negative_question("be", &Person::First, &Number::Singular, &Gender::Neuter,
+    ///         &Tense::Present, &Register::Contracted),
+    ///     "aren't I"
+    /// );
+    /// assert_eq!(
+    ///     English::negative_question("run", &Person::Third, &Number::Singular, &Gender::Feminine,
+    ///         &Tense::Past, &Register::Formal),
+    ///     "did she not run"
+    /// );
+    /// ```
+    pub fn negative_question(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        gender: &Gender,
+        tense: &Tense,
+        register: &Register,
+    ) -> String {
+        with_casing(word, |word| {
+            let (verb_person, verb_number) = English::subject_agreement(person, number, gender);
+            let words = negative_words(word, &verb_person, &verb_number, tense);
+            let subject = English::pronoun(person, number, gender, &Case::Nominative).to_owned();
+            let contracted = match (register, words[0].as_str()) {
+                (Register::Formal, _) => None,
+                (Register::Contracted, "am") => Some("aren't"),
+                (Register::Contracted, aux) => EnglishCore::negative_contraction(aux),
+            };
+            let mut inverted = match contracted {
+                Some(contracted) => vec![contracted.to_owned(), subject],
+                None => vec![words[0].clone(), subject, "not".to_owned()],
+            };
+            inverted.extend(words[2..].iter().cloned());
+            inverted.join(" ")
+        })
+    }
+
+    /// Joins a subject pronoun to a finite auxiliary, reducing the auxiliary
+    /// to its clitic in the [`Register::Contracted`] register (`"I'm"`,
+    /// `"they've"`, `"she'd"`).
+    ///
+    /// `word` is "be", "have" or a modal; auxiliaries without a clitic
+    /// (`"was"`) are left whole.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{English, Gender, Number, Person, Register, Tense};
+    ///
+    /// assert_eq!(
+    ///     English::subject_auxiliary(&Person::First, &Number::Singular, &Gender::Neuter, "be",
+    ///         &Tense::Present, &Register::Contracted),
+    ///     "I'm"
+    /// );
+    /// assert_eq!(
+    ///     English::subject_auxiliary(&Person::Third, &Number::Plural, &Gender::Neuter, "have",
+    ///         &Tense::Present, &Register::Contracted),
+    ///     "they've"
+    /// );
+    /// assert_eq!(
+    ///     English::subject_auxiliary(&Person::Third, &Number::Singular, &Gender::Feminine, "would",
+    ///         &Tense::Present, &Register::Formal),
+    ///     "she would"
+    /// );
+    /// ```
+    pub fn subject_auxiliary(
+        person: &Person,
+        number: &Number,
+        gender: &Gender,
+        word: &str,
+        tense: &Tense,
+        register: &Register,
+    ) -> String {
+        with_casing(word, |word| {
+            let subject = English::pronoun(person, number, gender, &Case::Nominative);
+            let aux = match EnglishCore::as_modal(word) {
+                Some(modal) => EnglishCore::modal(&modal).to_owned(),
+                None => {
+                    let (person, number) = English::subject_agreement(person, number, gender);
+                    English::verb(word, &person, &number, tense, &Form::Finite)
+                }
+            };
+            let (first, rest) = match aux.split_once(' ') {
+                Some((first, rest)) => (first, Some(rest)),
+                None => (aux.as_str(), None),
+            };
+            let head = match (register, EnglishCore::auxiliary_clitic(first)) {
+                (Register::Contracted, Some(clitic)) => format!("{subject}{clitic}"),
+                _ => format!("{subject} {first}"),
+            };
+            match rest {
+                Some(rest) => format!("{head} {rest}"),
+                None => head,
+            }
+        })
+    }
+}
@@ -0,0 +1,252 @@
+use crate::English;
+use alloc::{borrow::ToOwned, format, string::String};
+use english_core::grammar::*;
+
+/// Decides whether a noun counted by a non-trivial amount is singular or
+/// plural.
+///
+/// Whole numbers other than zero and minus one always follow the usual rule
+/// (singular for one, plural otherwise), and so do amounts above one
+/// (`"1.5 hours"`, `"1 1/2 cups"`). Everything English style guides disagree
+/// on is a field:
+///
+/// | field             | default    | example                |
+/// |-------------------|------------|------------------------|
+/// | `zero`            | `Plural`   | `"0 items"`            |
+/// | `minus_one`       | `Singular` | `"-1 point"`           |
+/// | `decimal_fraction`| `Plural`   | `"0.5 hours"`          |
+/// | `common_fraction` | `Singular` | `"1/2 cup"`            |
+///
+/// An amount with a decimal point is never singular on account of being
+/// one: `"1.0 hours"`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CountPolicy {
+    /// Number used for an amount of exactly zero.
+    pub zero: Number,
+    /// Number used for an amount of exactly minus one.
+    pub minus_one: Number,
+    /// Number used for decimals strictly between minus one and one.
+    pub decimal_fraction: Number,
+    /// Number used for fractions written with a slash whose value lies
+    /// strictly between minus one and one.
+    pub common_fraction: Number,
+}
+
+impl Default for CountPolicy {
+    fn default() -> Self {
+        CountPolicy {
+            zero: Number::Plural,
+            minus_one: Number::Singular,
+            decimal_fraction: Number::Plural,
+            common_fraction: Number::Singular,
+        }
+    }
+}
+
+/// How an amount was written, which matters as much as its value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Amount {
+    Whole(f64),
+    Decimal(f64),
+    Fraction(f64),
+    Invalid,
+}
+
+fn parse_amount(amount: &str) -> Amount {
+    let amount = amount.trim();
+    // Mixed numbers ("1 1/2") are always above one in magnitude.
+    if let Some((whole, fraction)) = amount.split_once(' ') {
+        return match (whole.parse::<f64>(), parse_amount(fraction)) {
+            (Ok(whole), Amount::Fraction(part)) => Amount::Decimal(whole + part.copysign(whole)),
+            _ => Amount::Invalid,
+        };
+    }
+    if let Some((numerator, denominator)) = amount.split_once('/') {
+        return match (numerator.parse::<f64>(), denominator.parse::<f64>()) {
+            (Ok(numerator), Ok(denominator)) if denominator != 0.0 => {
+                Amount::Fraction(numerator / denominator)
+            }
+            _ => Amount::Invalid,
+        };
+    }
+    match amount.parse::<f64>() {
+        Ok(value) if amount.contains(['.', 'e', 'E']) => Amount::Decimal(value),
+        Ok(value) => Amount::Whole(value),
+        Err(_) => Amount::Invalid,
+    }
+}
+
+impl CountPolicy {
+    fn number(&self, amount: Amount) -> Number {
+        match amount {
+            Amount::Whole(value) | Amount::Decimal(value) | Amount::Fraction(value)
+                if value == 0.0 =>
+            {
+                self.zero
+            }
+            Amount::Whole(1.0) => Number::Singular,
+            Amount::Whole(-1.0) => self.minus_one,
+            Amount::Decimal(value) if value.abs() < 1.0 => self.decimal_fraction,
+            Amount::Fraction(value) if value.abs() == 1.0 => Number::Singular,
+            Amount::Fraction(value) if value.abs() < 1.0 => self.common_fraction,
+            _ => Number::Plural,
+        }
+    }
+
+    /// Number a noun takes after `amount`, written as a whole number
+    /// (`"3"`), decimal (`"0.5"`), fraction (`"1/2"`) or mixed number
+    /// (`"1 1/2"`). Anything unparseable is treated as plural.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{CountPolicy, Number};
+    ///
+    /// let policy = CountPolicy::default();
+    /// assert_eq!(policy.number_for_str("1"), Number::Singular);
+    /// assert_eq!(policy.number_for_str("1.0"), Number::Plural);
+    /// assert_eq!(policy.number_for_str("1/2"), Number::Singular);
+    /// assert_eq!(policy.number_for_str("0.5"), Number::Plural);
+    /// ```
+    pub fn number_for_str(&self, amount: &str) -> Number {
+        self.number(parse_amount(amount))
+    }
+
+    /// Number a noun takes after `amount`. Values with a fractional part are
+    /// treated as decimals; whole values as whole numbers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{CountPolicy, Number};
+    ///
+    /// let policy = CountPolicy::default();
+    /// assert_eq!(policy.number_for_f64(1.0), Number::Singular);
+    /// assert_eq!(policy.number_for_f64(1.5), Number::Plural);
+    /// assert_eq!(policy.number_for_f64(0.0), Number::Plural);
+    /// ```
+    pub fn number_for_f64(&self, amount: f64) -> Number {
+        if amount % 1.0 == 0.0 {
+            self.number(Amount::Whole(amount))
+        } else {
+            self.number(Amount::Decimal(amount))
+        }
+    }
+}
+
+/// Denominator names used when reading fractions aloud.
+fn fraction_name(denominator: u64, number: &Number) -> String {
+    let singular = match denominator {
+        2 => "half".to_owned(),
+        4 => "quarter".to_owned(),
+        // "a thousandth", "seven hundredths" rather than "one thousandth"
+        _ => {
+            let ordinal = English::ordinal(denominator);
+            ordinal
+                .strip_prefix("one ")
+                .map_or(ordinal.clone(), str::to_owned)
+        }
+    };
+    match (number, denominator) {
+        (Number::Singular, _) => singular,
+        (Number::Plural, 2) => "halves".to_owned(),
+        (Number::Plural, _) => format!("{singular}s"),
+    }
+}
+
+/// Inflect a noun according to an amount written as text and keep the
+/// amount in the output, using the default [`CountPolicy`].
+///
+/// # Examples
+/// ```rust
+/// use english::count_with_amount;
+///
+/// assert_eq!(count_with_amount("hour", "1.5"), "1.5 hours");
+/// assert_eq!(count_with_amount("item", "0"), "0 items");
+/// assert_eq!(count_with_amount("cup", "1/2"), "1/2 cup");
+/// assert_eq!(count_with_amount("cup", "1 1/2"), "1 1/2 cups");
+/// ```
+pub fn count_with_amount(noun: &str, amount: &str) -> String {
+    count_with_policy(noun, amount, &CountPolicy::default())
+}
+
+/// Like [`count_with_amount`], with an explicit [`CountPolicy`].
+///
+/// # Examples
+/// ```rust
+/// use english::{CountPolicy, Number, count_with_policy};
+///
+/// let policy = CountPolicy { minus_one: Number::Plural, ..CountPolicy::default() };
+/// assert_eq!(count_with_policy("point", "-1", &policy), "-1 points");
+/// ```
+pub fn count_with_policy(noun: &str, amount: &str, policy: &CountPolicy) -> String {
+    format!(
+        "{} {}",
+        amount.trim(),
+        English::noun(noun, &policy.number_for_str(amount))
+    )
+}
+
+/// Inflect a noun according to a floating point amount and keep the amount
+/// in the output, using the default [`CountPolicy`].
+///
+/// # Examples
+/// ```rust
+/// use english::count_f64;
+///
+/// assert_eq!(count_f64("hour", 1.5), "1.5 hours");
+/// assert_eq!(count_f64("hour", 1.0), "1 hour");
+/// assert_eq!(count_f64("hour", 0.25), "0.25 hours");
+/// ```
+pub fn count_f64(noun: &str, amount: f64) -> String {
+    let number = CountPolicy::default().number_for_f64(amount);
+    format!("{amount} {}", English::noun(noun, &number))
+}
+
+/// Reads a fraction of a single item aloud (`"half a loaf"`,
+/// `"three quarters of an hour"`).
+///
+/// Fractions that come out whole, zero among them, are counted in words
+/// (`"one pie"`, `"zero pies"`).
+/// Returns `None` for a denominator of zero.
+///
+/// # Examples
+/// ```rust
+/// use english::count_fraction;
+///
+/// assert_eq!(count_fraction("loaf", 1, 2).unwrap(), "half a loaf");
+/// assert_eq!(count_fraction("hour", 3, 4).unwrap(), "three quarters of an hour");
+/// assert_eq!(count_fraction("pie", 1, 3).unwrap(), "a third of a pie");
+/// assert_eq!(count_fraction("loaf", 1, 1000).unwrap(), "a thousandth of a loaf");
+/// assert_eq!(count_fraction("pie", 4, 4).unwrap(), "one pie");
+/// assert_eq!(count_fraction("pie", 1, 0), None);
+/// ```
+pub fn count_fraction(noun: &str, numerator: u64, denominator: u64) -> Option<String> {
+    if denominator == 0 {
+        return None;
+    }
+    if numerator.is_multiple_of(denominator) {
+        let whole = numerator / denominator;
+        let number = if whole == 1 {
+            Number::Singular
+        } else {
+            Number::Plural
+        };
+        return Some(format!(
+            "{} {}",
+            English::cardinal(whole),
+            English::noun(noun, &number)
+        ));
+    }
+    let item = English::with_article(&English::noun(noun, &Number::Singular), &Det::Indefinite);
+    Some(match (numerator, denominator) {
+        (1, 2) => format!("half {item}"),
+        (1, _) => {
+            let part = fraction_name(denominator, &Number::Singular);
+            format!("{} {part} of {item}", English::indefinite_article(&part))
+        }
+        _ => format!(
+            "{} {} of {item}",
+            English::cardinal(numerator),
+            fraction_name(denominator, &Number::Plural)
+        ),
+    })
+}
@@ -0,0 +1,91 @@
+use crate::casing::with_casing;
+use crate::dialect_phf::{DIALECT_PLURAL_MAP, DIALECT_VERB_MAP};
+use crate::{English, conjugate_forms, strip_trailing_number};
+use alloc::{borrow::ToOwned, format, string::String};
+use english_core::grammar::*;
+
+/// Regional plural of `word`, consulting the fallback dialect too.
+pub(crate) fn dialect_plural(word: &str, dialect: &Dialect) -> Option<&'static str> {
+    DIALECT_PLURAL_MAP
+        .get(format!("{}:{word}", dialect.code()).as_str())
+        .map(|[plural]| *plural)
+        .or_else(|| dialect_plural(word, &dialect.fallback()?))
+}
+
+/// Regional principal parts of `infinitive`, consulting the fallback
+/// dialect too.
+pub(crate) fn dialect_verb_forms(infinitive: &str, dialect: &Dialect) -> Option<[&'static str; 4]> {
+    DIALECT_VERB_MAP
+        .get(format!("{}:{infinitive}", dialect.code()).as_str())
+        .copied()
+        .or_else(|| dialect_verb_forms(infinitive, &dialect.fallback()?))
+}
+
+impl English {
+    /// Inflects a noun like [`English::noun`], preferring the plural used in
+    /// `dialect` where the dialect table lists a regional one.
+    ///
+    /// The bundled dialect table is a curated list of common regional forms
+    /// (`"travelled"` and `"learnt"` outside the US, `"gotten"` in the US,
+    /// `"pence"` in the UK), not a full extraction of Wiktionary's regional
+    /// tags.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{Dialect, English, Number};
+    ///
+    /// assert_eq!(English::noun_in("penny", &Number::Plural, &Dialect::UK), "pence");
+    /// assert_eq!(English::noun_in("penny", &Number::Plural, &Dialect::US), "pennies");
+    /// assert_eq!(English::noun_in("child", &Number::Plural, &Dialect::UK), "children");
+    /// # }
+    /// ```
+    pub fn noun_in(word: &str, number: &Number, dialect: &Dialect) -> String {
+        with_casing(word, |word| match (number, dialect_plural(word, dialect)) {
+            (Number::Plural, Some(plural)) => plural.to_owned(),
+            _ => English::noun(word, number),
+        })
+    }
+
+    /// Conjugates a verb like [`English::verb`], preferring the forms used
+    /// in `dialect` (`"travelled"`, `"learnt"` in the UK; `"gotten"` in the
+    /// US). [`Dialect::AU`] falls back to British forms and [`Dialect::CA`]
+    /// to American ones where they have none of their own. Like
+    /// [`English::noun_in`], this only knows the forms in the curated dialect
+    /// table.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Dialect, English, Form, Number, Person, Tense};
+    ///
+    /// let past = |word, dialect| {
+    ///     English::verb_in(word, &Person::First, &Number::Singular, &Tense::Past, &Form::Finite, dialect)
+    /// };
+    /// assert_eq!(past("travel", &Dialect::US), "traveled");
+    /// assert_eq!(past("travel", &Dialect::UK), "travelled");
+    /// assert_eq!(past("travel", &Dialect::CA), "travelled");
+    /// assert_eq!(past("learn", &Dialect::AU), "learnt");
+    /// assert_eq!(past("learn", &Dialect::CA), "learned");
+    /// ```
+    pub fn verb_in(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+        dialect: &Dialect,
+    ) -> String {
+        with_casing(word, |word| match dialect_verb_forms(word, dialect) {
+            Some(forms) => conjugate_forms(
+                strip_trailing_number(word),
+                forms,
+                person,
+                number,
+                tense,
+                form,
+            ),
+            None => English::verb(word, person, number, tense, form),
+        })
+    }
+}
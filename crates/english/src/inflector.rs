@@ -0,0 +1,425 @@
+use crate::casing::with_casing;
+use crate::dialect::{dialect_plural, dialect_verb_forms};
+use crate::{English, conjugate_forms, get_plural, strip_trailing_number};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+#[cfg(feature = "toml")]
+use alloc::{string::ToString, vec};
+use core::fmt;
+use english_core::EnglishCore;
+use english_core::grammar::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// An inflector with a user lexicon layered over the built-in tables.
+///
+/// Entries added at runtime, from code or from a CSV or TOML file, take
+/// precedence over the regional forms of the chosen [`Dialect`], if any, and
+/// then over the generated Wiktionary tables, which in turn take precedence
+/// over the [`EnglishCore`] rules. Words without an entry of
+/// their own inflect exactly like [`English::noun`], [`English::verb`] and
+/// [`English::adj`]. Keys are case-insensitive and results keep the
+/// capitalization of the input.
+///
+/// # Examples
+/// ```rust
+/// use english::{Degree, English, Inflector, Number};
+///
+/// let mut inflector = Inflector::new();
+/// inflector.add_noun("dwarf", "dwarfs").add_noun("orc", "orcs");
+/// assert_eq!(inflector.noun("dwarf", &Number::Plural), "dwarfs");
+/// assert_eq!(inflector.noun("Dwarf", &Number::Plural), "Dwarfs");
+/// assert_eq!(English::noun("dwarf", &Number::Plural), "dwarves");
+/// assert_eq!(inflector.adj("good", &Degree::Comparative), "better");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Inflector {
+    nouns: HashMap<String, String>,
+    verbs: HashMap<String, (String, String, String, String)>,
+    adjectives: HashMap<String, (String, String)>,
+    dialect: Option<Dialect>,
+}
+
+/// Why a user lexicon could not be loaded.
+#[derive(Debug)]
+pub enum LexiconError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// A CSV file had an unknown header or a row with the wrong number of
+    /// fields. Lines are counted from one.
+    Csv { line: usize, message: String },
+    /// A TOML file was malformed or did not have the expected shape.
+    Toml(String),
+}
+
+impl fmt::Display for LexiconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexiconError::Io(err) => write!(f, "could not read lexicon: {err}"),
+            LexiconError::Csv { line, message } => write!(f, "line {line}: {message}"),
+            LexiconError::Toml(message) => write!(f, "invalid lexicon: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LexiconError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LexiconError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for LexiconError {
+    fn from(err: std::io::Error) -> Self {
+        LexiconError::Io(err)
+    }
+}
+
+/// Which table a CSV file fills, told apart by its header.
+enum CsvKind {
+    Nouns,
+    Verbs,
+    Adjectives,
+}
+
+impl Inflector {
+    /// Creates an inflector with no entries of its own.
+    pub fn new() -> Self {
+        Inflector::default()
+    }
+
+    /// Prefers the regional forms of `dialect`, as [`English::noun_in`] and
+    /// [`English::verb_in`] do.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Dialect, Form, Inflector, Number, Person, Tense};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector.set_dialect(Dialect::UK).add_verb("dream", "dreams", "dreamed", "dreaming", "dreamed");
+    /// let past = |word| inflector.verb(word, &Person::First, &Number::Singular, &Tense::Past, &Form::Finite);
+    /// assert_eq!(past("learn"), "learnt");
+    /// assert_eq!(past("dream"), "dreamed");
+    /// ```
+    pub fn set_dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Adds or replaces the plural of a noun.
+    pub fn add_noun(&mut self, singular: &str, plural: &str) -> &mut Self {
+        self.nouns
+            .insert(singular.to_lowercase(), plural.to_lowercase());
+        self
+    }
+
+    /// Adds or replaces the principal parts of a verb, in the order of the
+    /// generated tables: third person singular, past, present participle
+    /// and past participle.
+    pub fn add_verb(
+        &mut self,
+        infinitive: &str,
+        third_person_singular: &str,
+        past: &str,
+        present_participle: &str,
+        past_participle: &str,
+    ) -> &mut Self {
+        let forms = (
+            third_person_singular.to_lowercase(),
+            past.to_lowercase(),
+            present_participle.to_lowercase(),
+            past_participle.to_lowercase(),
+        );
+        self.verbs.insert(infinitive.to_lowercase(), forms);
+        self
+    }
+
+    /// Adds or replaces the comparative and superlative of an adjective.
+    pub fn add_adjective(
+        &mut self,
+        positive: &str,
+        comparative: &str,
+        superlative: &str,
+    ) -> &mut Self {
+        let forms = (comparative.to_lowercase(), superlative.to_lowercase());
+        self.adjectives.insert(positive.to_lowercase(), forms);
+        self
+    }
+
+    /// Looks `word` up as given and with its sense number stripped.
+    fn entry<'a, T>(map: &'a HashMap<String, T>, word: &str) -> Option<&'a T> {
+        map.get(word)
+            .or_else(|| map.get(strip_trailing_number(word)))
+    }
+
+    /// Inflects a noun like [`English::noun`], preferring the user lexicon.
+    ///
+    /// The head of a compound is looked up in the lexicon too
+    /// (`"mother goose" -> "mother gooses"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{Inflector, Number};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector.add_noun("goose", "gooses");
+    /// assert_eq!(inflector.noun("goose", &Number::Plural), "gooses");
+    /// assert_eq!(inflector.noun("mother goose", &Number::Plural), "mother gooses");
+    /// assert_eq!(inflector.noun("child", &Number::Plural), "children");
+    /// # }
+    /// ```
+    pub fn noun(&self, word: &str, number: &Number) -> String {
+        with_casing(word, |word| {
+            let base_word = strip_trailing_number(word);
+            match number {
+                Number::Singular => base_word.to_owned(),
+                Number::Plural => {
+                    if let Some(plural) = Inflector::entry(&self.nouns, word) {
+                        return plural.clone();
+                    }
+                    if let Some(plural) = self.dialect.and_then(|d| dialect_plural(word, &d)) {
+                        return plural.to_owned();
+                    }
+                    if get_plural(word).is_none()
+                        && let Some((start, end)) = EnglishCore::compound_head(base_word)
+                    {
+                        let head = self.noun(&base_word[start..end], number);
+                        return format!("{}{head}{}", &base_word[..start], &base_word[end..]);
+                    }
+                    English::noun(word, number)
+                }
+            }
+        })
+    }
+
+    /// Conjugates a verb like [`English::verb`], preferring the user
+    /// lexicon.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Form, Inflector, Number, Person, Tense};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector.add_verb("smite", "smites", "smit", "smiting", "smitten");
+    /// let past = inflector.verb("smite", &Person::First, &Number::Singular, &Tense::Past, &Form::Finite);
+    /// assert_eq!(past, "smit");
+    /// ```
+    pub fn verb(
+        &self,
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> String {
+        with_casing(word, |word| {
+            let regional = self.dialect.and_then(|d| dialect_verb_forms(word, &d));
+            let forms = match Inflector::entry(&self.verbs, word) {
+                Some((third, past, present_participle, past_participle)) => {
+                    Some([&**third, &**past, &**present_participle, &**past_participle])
+                }
+                None => regional,
+            };
+            match forms {
+                Some(forms) => conjugate_forms(
+                    strip_trailing_number(word),
+                    forms,
+                    person,
+                    number,
+                    tense,
+                    form,
+                ),
+                None => English::verb(word, person, number, tense, form),
+            }
+        })
+    }
+
+    /// Compares an adjective like [`English::adj`], preferring the user
+    /// lexicon.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Degree, Inflector};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector.add_adjective("fun", "funner", "funnest");
+    /// assert_eq!(inflector.adj("fun", &Degree::Superlative), "funnest");
+    /// ```
+    pub fn adj(&self, word: &str, degree: &Degree) -> String {
+        with_casing(word, |word| {
+            match (degree, Inflector::entry(&self.adjectives, word)) {
+                (Degree::Comparative, Some((comparative, _))) => comparative.clone(),
+                (Degree::Superlative, Some((_, superlative))) => superlative.clone(),
+                _ => English::adj(word, degree),
+            }
+        })
+    }
+
+    /// Adds every row of a CSV file in the format the extractor writes.
+    ///
+    /// The header picks the table: `word,plural` for nouns,
+    /// `infinitive,third_person_singular,past,present_participle,past_participle`
+    /// for verbs and `positive,comparative,superlative` for adjectives.
+    /// Blank lines and lines starting with `#` are skipped. Fields are split
+    /// on commas and trimmed; quoting is not supported.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Inflector, Number};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector.load_csv("word,plural\ndwarf,dwarfs\nhoof,hoofs\n").unwrap();
+    /// assert_eq!(inflector.noun("hoof", &Number::Plural), "hoofs");
+    /// assert!(inflector.load_csv("word,plural\nelf\n").is_err());
+    /// ```
+    pub fn load_csv(&mut self, csv: &str) -> Result<&mut Self, LexiconError> {
+        let mut rows = csv
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        let Some((line, header)) = rows.next() else {
+            return Ok(self);
+        };
+        let header: Vec<&str> = header.split(',').map(str::trim).collect();
+        let kind = match header.as_slice() {
+            ["word", "plural"] => CsvKind::Nouns,
+            [
+                "infinitive",
+                "third_person_singular",
+                "past",
+                "present_participle",
+                "past_participle",
+            ] => CsvKind::Verbs,
+            ["positive", "comparative", "superlative"] => CsvKind::Adjectives,
+            _ => {
+                return Err(LexiconError::Csv {
+                    line,
+                    message: format!("unknown header `{}`", header.join(",")),
+                });
+            }
+        };
+        for (line, row) in rows {
+            let fields: Vec<&str> = row.split(',').map(str::trim).collect();
+            match (&kind, fields.as_slice()) {
+                (CsvKind::Nouns, [singular, plural]) => {
+                    self.add_noun(singular, plural);
+                }
+                (CsvKind::Verbs, [infinitive, third, past, present, past_participle]) => {
+                    self.add_verb(infinitive, third, past, present, past_participle);
+                }
+                (CsvKind::Adjectives, [positive, comparative, superlative]) => {
+                    self.add_adjective(positive, comparative, superlative);
+                }
+                _ => {
+                    return Err(LexiconError::Csv {
+                        line,
+                        message: format!(
+                            "expected {} fields, found {}",
+                            header.len(),
+                            fields.len()
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Adds every entry of a TOML document with optional `[nouns]`,
+    /// `[verbs]` and `[adjectives]` tables.
+    ///
+    /// Nouns map to their plural; verbs to an array of the third person
+    /// singular, past, present participle and past participle; adjectives
+    /// to an array of the comparative and superlative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Degree, Inflector, Number};
+    ///
+    /// let mut inflector = Inflector::new();
+    /// inflector
+    ///     .load_toml(
+    ///         r#"
+    ///         [nouns]
+    ///         dwarf = "dwarfs"
+    ///
+    ///         [adjectives]
+    ///         fun = ["funner", "funnest"]
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(inflector.noun("dwarf", &Number::Plural), "dwarfs");
+    /// assert_eq!(inflector.adj("fun", &Degree::Comparative), "funner");
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn load_toml(&mut self, document: &str) -> Result<&mut Self, LexiconError> {
+        let document: toml::Table =
+            toml::from_str(document).map_err(|err| LexiconError::Toml(err.to_string()))?;
+        for (section, entries) in &document {
+            let entries = entries
+                .as_table()
+                .ok_or_else(|| LexiconError::Toml(format!("`{section}` is not a table")))?;
+            for (word, forms) in entries {
+                let forms = toml_forms(section, word, forms)?;
+                match (section.as_str(), forms.as_slice()) {
+                    ("nouns", [plural]) => {
+                        self.add_noun(word, plural);
+                    }
+                    ("verbs", [third, past, present, past_participle]) => {
+                        self.add_verb(word, third, past, present, past_participle);
+                    }
+                    ("adjectives", [comparative, superlative]) => {
+                        self.add_adjective(word, comparative, superlative);
+                    }
+                    ("nouns" | "verbs" | "adjectives", _) => {
+                        return Err(LexiconError::Toml(format!(
+                            "`{section}.{word}` has the wrong number of forms"
+                        )));
+                    }
+                    _ => {
+                        return Err(LexiconError::Toml(format!("unknown section `{section}`")));
+                    }
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Reads a lexicon file, as TOML if its extension is `.toml` and as
+    /// CSV otherwise.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, LexiconError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => self.load_toml(&contents),
+            #[cfg(not(feature = "toml"))]
+            Some("toml") => Err(LexiconError::Toml(
+                "TOML lexicons need the `toml` feature".to_owned(),
+            )),
+            _ => self.load_csv(&contents),
+        }
+    }
+}
+
+/// The forms of one TOML entry: a string or an array of strings.
+#[cfg(feature = "toml")]
+fn toml_forms<'a>(
+    section: &str,
+    word: &str,
+    forms: &'a toml::Value,
+) -> Result<Vec<&'a str>, LexiconError> {
+    let wrong_type = || LexiconError::Toml(format!("`{section}.{word}` must be strings"));
+    match forms {
+        toml::Value::String(form) => Ok(vec![form.as_str()]),
+        toml::Value::Array(forms) => forms
+            .iter()
+            .map(|form| form.as_str().ok_or_else(wrong_type))
+            .collect(),
+        _ => Err(wrong_type()),
+    }
+}
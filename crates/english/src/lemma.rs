@@ -0,0 +1,269 @@
+use crate::{English, strip_trailing_number};
+use alloc::string::String;
+use core::fmt;
+use english_core::grammar::*;
+
+/// A noun lemma with its forms looked up once, on construction.
+///
+/// Accepts everything [`English::noun`] does, including sense keys
+/// (`"die2"`, `"die#dice"`), and keeps the key it was built from so that it
+/// round-trips through [`Noun::as_str`] and, with the `serde` feature, through
+/// serialization as a plain string.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "nouns")]
+/// # {
+/// use english::{Noun, Number};
+///
+/// let child = Noun::from("child");
+/// assert_eq!(child.plural(), "children");
+/// assert_eq!(child.inflect(&Number::Singular), "child");
+/// assert_eq!(Noun::from("die2").plural(), "dice");
+/// assert_eq!(Noun::from("die2").singular(), "die");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub struct Noun {
+    key: String,
+    singular: String,
+    plural: String,
+}
+
+impl Noun {
+    pub fn new(word: impl Into<String>) -> Self {
+        let key = word.into();
+        Noun {
+            singular: English::noun(&key, &Number::Singular),
+            plural: English::noun(&key, &Number::Plural),
+            key,
+        }
+    }
+
+    /// The word or sense key the noun was built from.
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+
+    /// The singular with any sense key removed.
+    pub fn singular(&self) -> &str {
+        &self.singular
+    }
+
+    pub fn plural(&self) -> &str {
+        &self.plural
+    }
+
+    pub fn inflect(&self, number: &Number) -> &str {
+        match number {
+            Number::Singular => &self.singular,
+            Number::Plural => &self.plural,
+        }
+    }
+}
+
+/// A verb lemma with its principal parts looked up once, on construction.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "verbs")]
+/// # {
+/// use english::{Form, Number, Person, Tense, Verb};
+///
+/// let go = Verb::from("go");
+/// assert_eq!(go.past(), "went");
+/// assert_eq!(go.past_participle(), "gone");
+/// assert_eq!(go.third_person(), "goes");
+/// assert_eq!(go.conjugate(&Person::Third, &Number::Plural, &Tense::Future, &Form::Finite), "will go");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub struct Verb {
+    key: String,
+    infinitive: String,
+    third_person: String,
+    past: String,
+    present_participle: String,
+    past_participle: String,
+}
+
+impl Verb {
+    pub fn new(word: impl Into<String>) -> Self {
+        let key = word.into();
+        let form =
+            |person, tense, form| English::verb(&key, person, &Number::Singular, tense, form);
+        Verb {
+            infinitive: form(&Person::First, &Tense::Present, &Form::Infinitive),
+            third_person: form(&Person::Third, &Tense::Present, &Form::Finite),
+            past: form(&Person::Third, &Tense::Past, &Form::Finite),
+            present_participle: form(&Person::Third, &Tense::Present, &Form::Participle),
+            past_participle: form(&Person::Third, &Tense::Past, &Form::Participle),
+            key,
+        }
+    }
+
+    /// The word or sense key the verb was built from.
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+
+    /// The bare infinitive with any sense key removed.
+    pub fn infinitive(&self) -> &str {
+        &self.infinitive
+    }
+
+    /// Third person singular present (`"goes"`).
+    pub fn third_person(&self) -> &str {
+        &self.third_person
+    }
+
+    pub fn past(&self) -> &str {
+        &self.past
+    }
+
+    pub fn present_participle(&self) -> &str {
+        &self.present_participle
+    }
+
+    pub fn past_participle(&self) -> &str {
+        &self.past_participle
+    }
+
+    /// Conjugates the verb like [`English::verb`]. "be", which has more
+    /// present forms than the principal parts record, and the future, whose
+    /// auxiliary takes the casing of the word, are left to [`English::verb`].
+    pub fn conjugate(
+        &self,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+    ) -> String {
+        if strip_trailing_number(&self.key).eq_ignore_ascii_case("be") {
+            return English::verb(&self.key, person, number, tense, form);
+        }
+        match (person, number, tense, form) {
+            (_, _, _, Form::Infinitive) => self.infinitive.clone(),
+            (Person::Third, Number::Singular, Tense::Present, Form::Finite) => {
+                self.third_person.clone()
+            }
+            (_, _, Tense::Present, Form::Finite) => self.infinitive.clone(),
+            (_, _, Tense::Present | Tense::Future, Form::Participle) => {
+                self.present_participle.clone()
+            }
+            (_, _, Tense::Past, Form::Participle) => self.past_participle.clone(),
+            (_, _, Tense::Past, Form::Finite) => self.past.clone(),
+            (_, _, Tense::Future, Form::Finite) => {
+                English::verb(&self.key, person, number, tense, form)
+            }
+        }
+    }
+}
+
+/// An adjective lemma with its comparison looked up once, on construction.
+///
+/// # Examples
+/// ```rust
+/// use english::{Adj, Degree};
+///
+/// let good = Adj::from("good");
+/// assert_eq!(good.comparative(), "better");
+/// assert_eq!(good.superlative(), "best");
+/// assert_eq!(Adj::from("careful").inflect(&Degree::Comparative), "more careful");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub struct Adj {
+    key: String,
+    positive: String,
+    comparative: String,
+    superlative: String,
+}
+
+impl Adj {
+    pub fn new(word: impl Into<String>) -> Self {
+        let key = word.into();
+        Adj {
+            positive: English::adj(&key, &Degree::Positive),
+            comparative: English::adj(&key, &Degree::Comparative),
+            superlative: English::adj(&key, &Degree::Superlative),
+            key,
+        }
+    }
+
+    /// The word or sense key the adjective was built from.
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+
+    /// The positive degree with any sense key removed.
+    pub fn positive(&self) -> &str {
+        &self.positive
+    }
+
+    pub fn comparative(&self) -> &str {
+        &self.comparative
+    }
+
+    pub fn superlative(&self) -> &str {
+        &self.superlative
+    }
+
+    pub fn inflect(&self, degree: &Degree) -> &str {
+        match degree {
+            Degree::Positive => &self.positive,
+            Degree::Comparative => &self.comparative,
+            Degree::Superlative => &self.superlative,
+        }
+    }
+}
+
+macro_rules! lemma_conversions {
+    ($($lemma:ident),*) => {$(
+        impl From<&str> for $lemma {
+            fn from(word: &str) -> Self {
+                $lemma::new(word)
+            }
+        }
+
+        impl From<String> for $lemma {
+            fn from(word: String) -> Self {
+                $lemma::new(word)
+            }
+        }
+
+        impl From<$lemma> for String {
+            fn from(lemma: $lemma) -> Self {
+                lemma.key
+            }
+        }
+
+        impl AsRef<str> for $lemma {
+            fn as_ref(&self) -> &str {
+                &self.key
+            }
+        }
+
+        impl fmt::Display for $lemma {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.key)
+            }
+        }
+    )*};
+}
+
+lemma_conversions!(Noun, Verb, Adj);
@@ -0,0 +1,396 @@
+use crate::casing::{is_lowercase, recase};
+use crate::lexicon::Lexicon;
+use crate::{ADJECTIVES, English, NOUNS, Source, VERBS, strip_trailing_number};
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Grammatical features recovered for a surface form.
+///
+/// Verb agreement is `None` when the form is shared by several persons or
+/// numbers (`"walked"` is the past tense for every subject).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Features {
+    Noun(Number),
+    Verb {
+        person: Option<Person>,
+        number: Option<Number>,
+        tense: Tense,
+        form: Form,
+    },
+    Adj(Degree),
+}
+
+/// One plausible reading of a surface form.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    /// Dictionary form with any sense number stripped (`"die"`).
+    pub lemma: String,
+    /// Key that reproduces the surface form when passed back to
+    /// [`English::noun`], [`English::verb`] or [`English::adj`] (`"die2"`).
+    pub key: String,
+    pub features: Features,
+    pub source: Source,
+}
+
+impl Analysis {
+    fn new(key: &str, features: Features, source: Source) -> Self {
+        Analysis {
+            lemma: strip_trailing_number(key).to_owned(),
+            key: key.to_owned(),
+            features,
+            source,
+        }
+    }
+}
+
+/// Table keys by each form in their rows. Forms are owned, since compact
+/// tables rebuild them from their keys.
+type ReverseIndex = HashMap<String, Vec<String>>;
+
+fn build_index<const N: usize>(map: &'static Lexicon<N>) -> ReverseIndex {
+    let mut index = ReverseIndex::new();
+    map.for_each_entry(|key, row| {
+        for form in row {
+            let keys = index.entry(form.to_string()).or_default();
+            if !keys.iter().any(|k| k == key) {
+                keys.push(key.to_owned());
+            }
+        }
+    });
+    for keys in index.values_mut() {
+        keys.sort_unstable();
+    }
+    index
+}
+
+fn plural_index() -> &'static ReverseIndex {
+    static INDEX: OnceLock<ReverseIndex> = OnceLock::new();
+    INDEX.get_or_init(|| build_index(&NOUNS))
+}
+
+fn verb_index() -> &'static ReverseIndex {
+    static INDEX: OnceLock<ReverseIndex> = OnceLock::new();
+    INDEX.get_or_init(|| build_index(&VERBS))
+}
+
+fn adjective_index() -> &'static ReverseIndex {
+    static INDEX: OnceLock<ReverseIndex> = OnceLock::new();
+    INDEX.get_or_init(|| build_index(&ADJECTIVES))
+}
+
+fn table_keys(index: &'static ReverseIndex, word: &str) -> &'static [String] {
+    index.get(word).map(Vec::as_slice).unwrap_or(&[])
+}
+
+/// True if `word` (or its first numbered sense) is a key in `map`.
+fn is_table_lemma<const N: usize>(map: &Lexicon<N>, word: &str) -> bool {
+    map.contains_key(word) || map.contains_key(format!("{word}2").as_str())
+}
+
+/// Candidate keys paired with the source they would be reported under.
+fn candidates<const N: usize>(
+    map: &Lexicon<N>,
+    index: &'static ReverseIndex,
+    word: &str,
+    rule_candidates: Vec<String>,
+) -> Vec<(String, Source)> {
+    let mut keys: Vec<(String, Source)> = table_keys(index, word)
+        .iter()
+        .map(|key| (key.to_string(), Source::Table))
+        .collect();
+    // "to be" is conjugated from its own paradigm, as irregular as any
+    // table row.
+    keys.extend(rule_candidates.into_iter().map(|key| {
+        let source = if key == "be" {
+            Source::Table
+        } else {
+            Source::Rule
+        };
+        (key, source)
+    }));
+    let identity = if is_table_lemma(map, word) {
+        Source::Table
+    } else {
+        Source::Guess
+    };
+    keys.push((word.to_owned(), identity));
+    keys
+}
+
+fn push_unique(analyses: &mut Vec<Analysis>, analysis: Analysis) {
+    if !analyses
+        .iter()
+        .any(|a| a.key == analysis.key && a.features == analysis.features)
+    {
+        analyses.push(analysis);
+    }
+}
+
+/// Orders analyses by [`Source`], then by key length so that primary senses
+/// (`"die"`) come before numbered ones (`"die2"`).
+///
+/// A rule analysis whose key extends a shorter one with the same features
+/// is only kept as a guess: the letters it adds are the ones the rules put
+/// there (`"running"` is `"run"` with its consonant doubled, not `"runn"`,
+/// and `"boxes"` is `"box"` plus `"es"`, not `"boxe"`).
+fn rank(analyses: &mut [Analysis]) {
+    let extended: Vec<bool> = analyses
+        .iter()
+        .map(|a| {
+            a.source == Source::Rule
+                && analyses.iter().any(|b| {
+                    b.source <= Source::Rule
+                        && b.features == a.features
+                        && b.key.len() < a.key.len()
+                        && a.key.starts_with(b.key.as_str())
+                })
+        })
+        .collect();
+    for (analysis, extended) in analyses.iter_mut().zip(extended) {
+        if extended {
+            analysis.source = Source::Guess;
+        }
+    }
+    analyses.sort_by_key(|a| (a.source, a.key.len()));
+}
+
+/// Analyzes words that are not lowercase through their lowercase form, the
+/// way the tables are keyed, and gives each lemma and key the casing of
+/// `word` so that the key still reproduces it (`"Children"` -> `"Child"`).
+fn with_lowercase(word: &str, analyze: impl FnOnce(&str) -> Vec<Analysis>) -> Vec<Analysis> {
+    if is_lowercase(word) {
+        return analyze(word);
+    }
+    let mut analyses = analyze(&word.to_lowercase());
+    for analysis in &mut analyses {
+        analysis.lemma = recase(word, &analysis.lemma);
+        analysis.key = recase(word, &analysis.key);
+    }
+    analyses
+}
+
+/// Non-"be" verb slots; agreement is only distinguished for the third
+/// person singular present.
+const VERB_SLOTS: &[(Option<Person>, Option<Number>, Tense, Form)] = &[
+    (None, None, Tense::Present, Form::Infinitive),
+    (
+        Some(Person::Third),
+        Some(Number::Singular),
+        Tense::Present,
+        Form::Finite,
+    ),
+    (None, None, Tense::Present, Form::Finite),
+    (None, None, Tense::Past, Form::Finite),
+    (None, None, Tense::Present, Form::Participle),
+    (None, None, Tense::Past, Form::Participle),
+];
+
+fn verb_slots(key: &str) -> Vec<(Option<Person>, Option<Number>, Tense, Form)> {
+    if key != "be" {
+        return VERB_SLOTS.to_vec();
+    }
+    let mut slots = vec![
+        (None, None, Tense::Present, Form::Infinitive),
+        (None, None, Tense::Present, Form::Participle),
+        (None, None, Tense::Past, Form::Participle),
+    ];
+    for tense in [Tense::Present, Tense::Past] {
+        for number in [Number::Singular, Number::Plural] {
+            for person in [Person::First, Person::Second, Person::Third] {
+                slots.push((Some(person), Some(number), tense, Form::Finite));
+            }
+        }
+    }
+    slots
+}
+
+fn noun_analyses(word: &str) -> Vec<Analysis> {
+    let mut analyses = Vec::new();
+    let keys = candidates(
+        &NOUNS,
+        plural_index(),
+        word,
+        EnglishCore::noun_lemma_candidates(word),
+    );
+    for (key, source) in keys {
+        if key == word {
+            push_unique(
+                &mut analyses,
+                Analysis::new(&key, Features::Noun(Number::Singular), source),
+            );
+        }
+        if English::noun(&key, &Number::Plural) == word {
+            let source = if source == Source::Guess {
+                Source::Rule
+            } else {
+                source
+            };
+            push_unique(
+                &mut analyses,
+                Analysis::new(&key, Features::Noun(Number::Plural), source),
+            );
+        }
+    }
+    rank(&mut analyses);
+    analyses
+}
+
+fn verb_analyses(word: &str) -> Vec<Analysis> {
+    let mut analyses = Vec::new();
+    let keys = candidates(
+        &VERBS,
+        verb_index(),
+        word,
+        EnglishCore::verb_lemma_candidates(word),
+    );
+    for (key, source) in keys {
+        for (person, number, tense, form) in verb_slots(&key) {
+            let conjugated = English::verb(
+                &key,
+                &person.unwrap_or(Person::First),
+                &number.unwrap_or(Number::Plural),
+                &tense,
+                &form,
+            );
+            if conjugated == word {
+                let features = Features::Verb {
+                    person,
+                    number,
+                    tense,
+                    form,
+                };
+                push_unique(&mut analyses, Analysis::new(&key, features, source));
+            }
+        }
+    }
+    rank(&mut analyses);
+    analyses
+}
+
+fn adj_analyses(word: &str) -> Vec<Analysis> {
+    let mut analyses = Vec::new();
+    let keys = candidates(
+        &ADJECTIVES,
+        adjective_index(),
+        word,
+        EnglishCore::adjective_lemma_candidates(word),
+    );
+    for (key, source) in keys {
+        for degree in [Degree::Positive, Degree::Comparative, Degree::Superlative] {
+            if English::adj(&key, &degree) == word {
+                push_unique(
+                    &mut analyses,
+                    Analysis::new(&key, Features::Adj(degree), source),
+                );
+            }
+        }
+    }
+    rank(&mut analyses);
+    analyses
+}
+
+impl English {
+    /// Returns every plausible analysis of `word` as a noun, verb or
+    /// adjective, most trustworthy first.
+    ///
+    /// Table hits come before rule-based analyses, which come before the
+    /// guess that `word` is already a lemma. Capitalized words are analyzed
+    /// in lowercase, with lemmas and keys given their casing back
+    /// (`"Children"` -> `"Child"`).
+    ///
+    /// This is the inverse of [`English::noun`], [`English::verb`] and
+    /// [`English::adj`]: table entries are found by inverting the generated
+    /// maps, and anything else by undoing the [`EnglishCore`] suffix rules.
+    /// Every analysis is verified by re-inflecting its `key`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Features, Number};
+    ///
+    /// let analyses = English::lemmatize("children");
+    /// assert_eq!(analyses[0].lemma, "child");
+    /// assert_eq!(analyses[0].features, Features::Noun(Number::Plural));
+    /// # }
+    /// ```
+    pub fn lemmatize(word: &str) -> Vec<Analysis> {
+        let mut analyses = English::lemmatize_noun(word);
+        analyses.extend(English::lemmatize_verb(word));
+        analyses.extend(English::lemmatize_adj(word));
+        rank(&mut analyses);
+        analyses
+    }
+
+    /// Returns every plausible noun analysis of `word`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Features, Number};
+    ///
+    /// let analyses = English::lemmatize_noun("dice");
+    /// assert_eq!(analyses[0].key, "die2");
+    /// assert_eq!(analyses[0].lemma, "die");
+    ///
+    /// let analyses = English::lemmatize_noun("cats");
+    /// assert_eq!(analyses[0].lemma, "cat");
+    /// assert_eq!(analyses[0].features, Features::Noun(Number::Plural));
+    /// # }
+    /// ```
+    pub fn lemmatize_noun(word: &str) -> Vec<Analysis> {
+        with_lowercase(word, noun_analyses)
+    }
+
+    /// Returns every plausible verb analysis of `word`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{English, Features, Form, Tense};
+    ///
+    /// let analyses = English::lemmatize_verb("went");
+    /// assert_eq!(analyses[0].lemma, "go");
+    /// assert_eq!(
+    ///     analyses[0].features,
+    ///     Features::Verb { person: None, number: None, tense: Tense::Past, form: Form::Finite }
+    /// );
+    /// # }
+    /// ```
+    pub fn lemmatize_verb(word: &str) -> Vec<Analysis> {
+        with_lowercase(word, verb_analyses)
+    }
+
+    /// Returns every plausible adjective analysis of `word`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "nouns", feature = "adjectives"))]
+    /// # {
+    /// use english::{Degree, English, Features};
+    ///
+    /// let analyses = English::lemmatize_adj("worst");
+    /// assert_eq!(analyses[0].key, "bad3");
+    /// assert_eq!(analyses[0].features, Features::Adj(Degree::Superlative));
+    ///
+    /// // "better" is the comparative of both "good" and "well".
+    /// let analyses = English::lemmatize_adj("better");
+    /// assert!(analyses.iter().any(|a| a.lemma == "good"));
+    /// assert!(analyses.iter().any(|a| a.lemma == "well"));
+    /// # }
+    /// ```
+    pub fn lemmatize_adj(word: &str) -> Vec<Analysis> {
+        with_lowercase(word, adj_analyses)
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(feature = "compact-tables")]
+use crate::trie::Trie;
+use core::fmt;
+
+/// A generated table, mapping keys to rows of `N` forms.
+#[cfg(not(feature = "compact-tables"))]
+pub(crate) type Table<const N: usize> = phf::Map<&'static str, [&'static str; N]>;
+/// A generated table, mapping keys to rows of `N` forms.
+#[cfg(feature = "compact-tables")]
+pub(crate) type Table<const N: usize> = Trie<N>;
+
+/// A form read from a table: a stem borrowed from the looked up key followed
+/// by a stored suffix. Forms that the tables keep whole have an empty stem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TableForm<'a> {
+    stem: &'a str,
+    suffix: &'static str,
+}
+
+impl<'a> TableForm<'a> {
+    pub(crate) const fn new(stem: &'a str, suffix: &'static str) -> Self {
+        TableForm { stem, suffix }
+    }
+
+    /// The form as a `&'static str`, if nothing of it is borrowed from the
+    /// key.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn as_static(&self) -> Option<&'static str> {
+        self.stem.is_empty().then_some(self.suffix)
+    }
+
+    pub(crate) fn write_to<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        out.write_str(self.stem)?;
+        out.write_str(self.suffix)
+    }
+}
+
+impl<'a> From<&'a str> for TableForm<'a> {
+    fn from(form: &'a str) -> Self {
+        TableForm::new(form, "")
+    }
+}
+
+impl fmt::Display for TableForm<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// One of the generated tables, or none when its `nouns`, `verbs` or
+/// `adjectives` feature is off.
+///
+/// A missing table finds nothing, which leaves every word of its part of
+/// speech to the [`english_core`] rules.
+pub(crate) struct Lexicon<const N: usize> {
+    table: Option<&'static Table<N>>,
+}
+
+impl<const N: usize> Lexicon<N> {
+    pub(crate) const fn new(table: Option<&'static Table<N>>) -> Self {
+        Lexicon { table }
+    }
+
+    pub(crate) fn get<'a>(&self, key: &'a str) -> Option<[TableForm<'a>; N]> {
+        self.get_entry(key).map(|(_, row)| row)
+    }
+
+    /// The row for `key` along with the key itself, which is `'static` when
+    /// the table stores keys whole.
+    pub(crate) fn get_entry<'a>(
+        &self,
+        key: &'a str,
+    ) -> Option<(TableForm<'a>, [TableForm<'a>; N])> {
+        table_entry(self.table?, key)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn contains_key(&self, key: &str) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Calls `f` with every key and row of the table.
+    #[cfg(feature = "std")]
+    pub(crate) fn for_each_entry(&self, mut f: impl FnMut(&str, [TableForm<'_>; N])) {
+        let Some(table) = self.table else {
+            return;
+        };
+        #[cfg(not(feature = "compact-tables"))]
+        for (key, row) in table.entries() {
+            f(key, row.map(|form| TableForm::new("", form)));
+        }
+        #[cfg(feature = "compact-tables")]
+        table.for_each_entry(&mut f);
+    }
+}
+
+#[cfg(not(feature = "compact-tables"))]
+fn table_entry<'a, const N: usize>(
+    table: &'static Table<N>,
+    key: &'a str,
+) -> Option<(TableForm<'a>, [TableForm<'a>; N])> {
+    let (key, row) = table.get_entry(key)?;
+    Some((
+        TableForm::new("", key),
+        row.map(|form| TableForm::new("", form)),
+    ))
+}
+
+#[cfg(feature = "compact-tables")]
+fn table_entry<'a, const N: usize>(
+    table: &'static Table<N>,
+    key: &'a str,
+) -> Option<(TableForm<'a>, [TableForm<'a>; N])> {
+    Some((TableForm::from(key), table.get(key)?))
+}
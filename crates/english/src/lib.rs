@@ -1,29 +1,230 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+#[cfg(feature = "alloc")]
+use buffer::{write_adj_lowercase, write_noun_lowercase, write_verb_lowercase};
+#[cfg(feature = "alloc")]
+use casing::with_casing;
+use core::fmt;
 use english_core::EnglishCore;
+#[cfg(feature = "alloc")]
+use english_core::collect;
 pub use english_core::grammar::*;
 
-mod noun_phf {
+#[cfg(feature = "alloc")]
+mod article;
+mod buffer;
+mod casing;
+#[cfg(feature = "alloc")]
+mod contraction;
+#[cfg(feature = "alloc")]
+mod count_policy;
+#[cfg(feature = "alloc")]
+pub use count_policy::*;
+#[cfg(feature = "alloc")]
+mod dialect;
+#[cfg(feature = "std")]
+mod inflector;
+#[cfg(feature = "std")]
+pub use inflector::*;
+#[cfg(feature = "alloc")]
+mod lemma;
+#[cfg(feature = "alloc")]
+pub use lemma::{Adj, Noun, Verb};
+#[cfg(feature = "std")]
+mod lemmatize;
+#[cfg(feature = "std")]
+pub use lemmatize::*;
+mod lexicon;
+use lexicon::{Lexicon, TableForm};
+#[cfg(feature = "alloc")]
+mod numerals;
+#[cfg(feature = "alloc")]
+pub use numerals::*;
+#[cfg(feature = "alloc")]
+mod possessive;
+#[cfg(feature = "alloc")]
+mod pronoun_set;
+#[cfg(feature = "alloc")]
+pub use pronoun_set::PronounSet;
+#[cfg(feature = "alloc")]
+use pronoun_set::custom_set;
+#[cfg(feature = "alloc")]
+mod senses;
+#[cfg(feature = "alloc")]
+use senses::resolve_sense;
+#[cfg(feature = "alloc")]
+pub use senses::{PartOfSpeech, Sense};
+#[cfg(feature = "compact-tables")]
+// Only the generated tables build tries.
+#[cfg_attr(
+    not(any(feature = "nouns", feature = "verbs", feature = "adjectives")),
+    allow(dead_code)
+)]
+mod trie;
+#[cfg(feature = "alloc")]
+mod variants;
+#[cfg(feature = "alloc")]
+pub use variants::*;
+#[cfg(feature = "alloc")]
+mod verb_phrase;
+
+#[cfg(feature = "nouns")]
+mod noun_table {
+    #[cfg(not(feature = "compact-tables"))]
     include!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/generated/noun_phf.rs"
     ));
+    #[cfg(feature = "compact-tables")]
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/noun_trie.rs"
+    ));
 }
-use noun_phf::*;
-mod adj_phf {
+#[cfg(feature = "adjectives")]
+mod adj_table {
+    #[cfg(not(feature = "compact-tables"))]
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/adj_phf.rs"));
+    #[cfg(feature = "compact-tables")]
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/adj_trie.rs"
+    ));
 }
-use adj_phf::*;
-mod verb_phf {
+#[cfg(feature = "verbs")]
+mod verb_table {
+    #[cfg(not(feature = "compact-tables"))]
     include!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/generated/verb_phf.rs"
     ));
+    #[cfg(feature = "compact-tables")]
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/verb_trie.rs"
+    ));
+}
+
+/// Irregular plurals, keyed by singular or sense key.
+#[cfg(feature = "nouns")]
+static NOUNS: Lexicon<1> = Lexicon::new(Some(&noun_table::PLURAL_MAP));
+#[cfg(not(feature = "nouns"))]
+static NOUNS: Lexicon<1> = Lexicon::new(None);
+
+/// Irregular principal parts
+/// (3rd person singular, past, present participle, past participle),
+/// keyed by infinitive or sense key.
+#[cfg(feature = "verbs")]
+static VERBS: Lexicon<4> = Lexicon::new(Some(&verb_table::VERB_MAP));
+#[cfg(not(feature = "verbs"))]
+static VERBS: Lexicon<4> = Lexicon::new(None);
+
+/// Irregular (comparative, superlative), keyed by positive or sense key.
+#[cfg(feature = "adjectives")]
+static ADJECTIVES: Lexicon<2> = Lexicon::new(Some(&adj_table::ADJECTIVE_MAP));
+#[cfg(not(feature = "adjectives"))]
+static ADJECTIVES: Lexicon<2> = Lexicon::new(None);
+
+fn get_plural(word: &str) -> Option<TableForm<'_>> {
+    NOUNS.get(word).map(|[plural]| plural)
+}
+
+fn get_verb_forms(infinitive: &str) -> Option<[TableForm<'_>; 4]> {
+    VERBS.get(infinitive)
+}
+
+fn get_adjective_forms(positive: &str) -> Option<[TableForm<'_>; 2]> {
+    ADJECTIVES.get(positive)
+}
+
+#[cfg(feature = "alloc")]
+mod dialect_phf {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/dialect_phf.rs"
+    ));
+}
+#[cfg(feature = "alloc")]
+mod sense_phf {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/sense_phf.rs"
+    ));
+}
+#[cfg(feature = "alloc")]
+mod variant_phf {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/generated/variant_phf.rs"
+    ));
 }
-use verb_phf::*;
 
+/// The lemma of a sense key: `"die2" -> "die"`, `"die#dice" -> "die"`.
 fn strip_trailing_number(word: &str) -> &str {
-    match word.as_bytes().last() {
-        Some(b'0'..=b'9') => &word[..word.len() - 1],
-        _ => word,
+    strip_sense_label(word).trim_end_matches(|c: char| c.is_ascii_digit())
+}
+
+/// Drops the label of a named sense key: `"die#dice" -> "die"`.
+fn strip_sense_label(word: &str) -> &str {
+    word.split_once('#').map_or(word, |(lemma, _)| lemma)
+}
+
+/// Picks the requested form out of a verb table row
+/// (3rd person singular, past, present participle, past participle), or
+/// `None` for the future, which takes "will".
+fn verb_form<'a>(
+    base_word: TableForm<'a>,
+    forms: [TableForm<'a>; 4],
+    person: &Person,
+    number: &Number,
+    tense: &Tense,
+    form: &Form,
+) -> Option<TableForm<'a>> {
+    let [third, past, present_participle, past_participle] = forms;
+    match (person, number, tense, form) {
+        (_, _, _, Form::Infinitive) => Some(base_word),
+        (Person::Third, Number::Singular, Tense::Present, Form::Finite) => Some(third),
+        (_, _, Tense::Present, Form::Finite) => Some(base_word),
+        (_, _, Tense::Future, Form::Finite) => None,
+        (_, _, Tense::Present | Tense::Future, Form::Participle) => Some(present_participle),
+        (_, _, Tense::Past, Form::Participle) => Some(past_participle),
+        (_, _, Tense::Past, Form::Finite) => Some(past),
+    }
+}
+
+/// Conjugates a verb from its table row; see [`verb_form`].
+#[cfg(feature = "alloc")]
+fn conjugate_forms(
+    base_word: &str,
+    forms: [&str; 4],
+    person: &Person,
+    number: &Number,
+    tense: &Tense,
+    form: &Form,
+) -> String {
+    let forms = forms.map(TableForm::from);
+    collect(|out| write_forms(out, base_word, forms, person, number, tense, form))
+}
+
+fn write_forms<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    base_word: &str,
+    forms: [TableForm<'_>; 4],
+    person: &Person,
+    number: &Number,
+    tense: &Tense,
+    form: &Form,
+) -> fmt::Result {
+    match verb_form(base_word.into(), forms, person, number, tense, form) {
+        Some(form) => form.write_to(out),
+        None => write!(out, "will {base_word}"),
     }
 }
 
@@ -32,73 +233,130 @@ fn strip_trailing_number(word: &str) -> &str {
 /// `English` is the low-level `&str` API for handling English nouns, verbs,
 /// adjectives, pronouns, and possessives. It delegates irregular forms to
 /// lookup tables and falls back on [`EnglishCore`] for regular inflection
-/// rules. For noun counting ergonomics, see [`count`] and
-/// [`count_with_number`].
+/// rules. Every inflection looks words up in lowercase and gives the result
+/// the capitalization pattern of the input (lowercase, `Title`, `UPPER` or
+/// `miXed`). For noun counting ergonomics, see [`count`],
+/// [`count_with_number`], [`count_with_article`], [`count_with_words`],
+/// [`count_ordinal`] and, for fractional amounts, [`count_with_amount`].
 pub struct English;
 impl English {
     /// Inflects a noun into singular or plural form.
     ///
     /// Handles irregular nouns (e.g., `"child" -> "children"`) and
     /// falls back to regular pluralization rules when no override is found.
-    /// Strips trailing numbers used for sense disambiguation (`"die2" -> "dice"`).
+    /// Strips trailing numbers used for sense disambiguation (`"die2" -> "dice"`)
+    /// and resolves the stable sense keys listed by [`English::senses`]
+    /// (`"die#dice"`).
+    /// The result keeps the capitalization of the input (`"Child" -> "Children"`,
+    /// `"MOUSE" -> "MICE"`, `"CD" -> "CDS"`). Compounds written with spaces or hyphens inflect
+    /// their head (`"mother-in-law" -> "mothers-in-law"`), as found by
+    /// [`EnglishCore::compound_head`]. The bundled noun table has no compound
+    /// rows yet, so compounds the rule misreads (`"knight-errant"`) take a
+    /// trailing "s" until the tables are regenerated from a dump.
     ///
     /// # Examples
     /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
     /// use english::{English, Number};
     ///
     /// assert_eq!(English::noun("cat", &Number::Plural), "cats");
     /// assert_eq!(English::noun("child", &Number::Plural), "children");
     /// assert_eq!(English::noun("die2", &Number::Plural), "dice");
+    /// assert_eq!(English::noun("Child", &Number::Plural), "Children");
+    /// assert_eq!(English::noun("MOUSE", &Number::Plural), "MICE");
+    /// assert_eq!(English::noun("CD", &Number::Plural), "CDS");
+    /// assert_eq!(English::noun("attorney general", &Number::Plural), "attorneys general");
+    /// # }
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn noun(word: &str, number: &Number) -> String {
-        let base_word = strip_trailing_number(word);
-
-        match number {
-            Number::Singular => base_word.to_string(),
-            Number::Plural => {
-                if let Some(x) = get_plural(word) {
-                    x.to_owned()
-                } else {
-                    EnglishCore::noun(base_word, number)
-                }
-            }
+        with_casing(word, |word| {
+            collect(|out| write_noun_lowercase(out, word, number))
+        })
+    }
+
+    /// Inflects a proper noun such as a surname or given name.
+    ///
+    /// Names only ever add `-s`, or `-es` after a sibilant, so that family
+    /// names stay recognizable: no `y -> ies` and no irregular plurals.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{English, Number};
+    ///
+    /// assert_eq!(English::proper_noun("Jones", &Number::Plural), "Joneses");
+    /// assert_eq!(English::proper_noun("Kennedy", &Number::Plural), "Kennedys");
+    /// assert_eq!(English::proper_noun("Mary", &Number::Plural), "Marys");
+    /// assert_eq!(English::proper_noun("Mann", &Number::Plural), "Manns");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn proper_noun(word: &str, number: &Number) -> String {
+        EnglishCore::proper_noun(word, number)
+    }
+
+    /// Inflects `word` as a proper noun if it starts with an uppercase
+    /// letter and is not a noun with an irregular plural, and as a common
+    /// noun otherwise.
+    ///
+    /// This suits running text, where capitals mark names. Capitalized
+    /// words that the noun table or the irregular rules know, in any case,
+    /// keep their common plural (`"Child" -> "Children"`,
+    /// `"MOUSE" -> "MICE"`). Headings and labels capitalize regular common
+    /// nouns too; use [`English::noun`] for those, which keeps the
+    /// capitalization of its input.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Number};
+    ///
+    /// assert_eq!(English::noun_auto("Kennedy", &Number::Plural), "Kennedys");
+    /// assert_eq!(English::noun_auto("baby", &Number::Plural), "babies");
+    /// assert_eq!(English::noun_auto("Child", &Number::Plural), "Children");
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn noun_auto(word: &str, number: &Number) -> String {
+        if EnglishCore::starts_with_uppercase(word) && !English::has_irregular_plural(word) {
+            English::proper_noun(word, number)
+        } else {
+            English::noun(word, number)
         }
     }
 
+    /// True if the noun table, looked up in lowercase, or the irregular
+    /// rules give `word` its own plural.
+    #[cfg(feature = "alloc")]
+    fn has_irregular_plural(word: &str) -> bool {
+        let lower = word.to_lowercase();
+        NOUNS.contains_key(&lower) || EnglishCore::is_irregular_noun(strip_trailing_number(&lower))
+    }
+
     /// Inflects an adjective into positive, comparative, or superlative form.
     ///
     /// Handles irregular adjectives (e.g., `"good" -> "better"/"best"`)
-    /// and falls back to regular periphrastic forms
-    /// (e.g., `"fun" -> "more fun"/"most fun"`).
+    /// and falls back to the regular comparison rules, which pick `-er`/`-est`
+    /// or periphrastic forms by syllable count and ending
+    /// (e.g., `"fast" -> "faster"`, `"careful" -> "more careful"`).
     /// Strips trailing numbers used for disambiguation (`"bad3"` -> `"worse"`).
     ///
     /// # Examples
     /// ```rust
     /// use english::{Degree, English};
     ///
-    /// assert_eq!(English::adj("fast", &Degree::Comparative), "more fast");
+    /// assert_eq!(English::adj("fast", &Degree::Comparative), "faster");
+    /// assert_eq!(English::adj("happy", &Degree::Superlative), "happiest");
+    /// assert_eq!(English::adj("careful", &Degree::Comparative), "more careful");
     /// assert_eq!(English::adj("good2", &Degree::Superlative), "best");
     /// assert_eq!(English::adj("fun", &Degree::Comparative), "more fun");
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn adj(word: &str, degree: &Degree) -> String {
-        let base_word = strip_trailing_number(word);
-        match degree {
-            Degree::Positive => base_word.to_owned(),
-            Degree::Comparative => {
-                if let Some((comp, _)) = get_adjective_forms(word) {
-                    comp.to_owned()
-                } else {
-                    EnglishCore::comparative(base_word)
-                }
-            }
-            Degree::Superlative => {
-                if let Some((_, sup)) = get_adjective_forms(word) {
-                    sup.to_owned()
-                } else {
-                    EnglishCore::superlative(base_word)
-                }
-            }
-        }
+        with_casing(word, |word| {
+            collect(|out| write_adj_lowercase(out, word, degree))
+        })
     }
 
     /// Conjugates a verb into the requested form.
@@ -106,9 +364,13 @@ impl English {
     /// Handles irregular verbs (e.g., `"go" -> "went"`, `"eat" -> "ate"`)
     /// and falls back to regular conjugation rules when no override is found.
     /// Strips trailing numbers used for sense disambiguation (`"lie2"` -> `"lied"`).
+    /// The future has no synthetic form, so finite [`Tense::Future`] comes out
+    /// as `"will go"`; see [`English::verb_phrase`] for full verb groups.
     ///
     /// # Examples
     /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
     /// use english::{English, Form, Number, Person, Tense};
     ///
     /// // Regular verb
@@ -128,7 +390,9 @@ impl English {
     ///     English::verb("go", &Person::Third, &Number::Plural, &Tense::Past, &Form::Participle),
     ///     "gone"
     /// );
+    /// # }
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn verb(
         word: &str,
         person: &Person,
@@ -136,23 +400,98 @@ impl English {
         tense: &Tense,
         form: &Form,
     ) -> String {
-        let base_word = strip_trailing_number(word);
-        match get_verb_forms(word) {
-            Some(wordik) => match (person, number, tense, form) {
-                (_, _, _, Form::Infinitive) => base_word.to_owned(),
-                (Person::Third, Number::Singular, Tense::Present, Form::Finite) => {
-                    wordik.0.to_string()
-                }
-                (_, _, Tense::Present, Form::Finite) => base_word.to_owned(),
-                (_, _, Tense::Present, Form::Participle) => wordik.2.to_owned(),
-                (_, _, Tense::Past, Form::Participle) => wordik.3.to_owned(),
-                (_, _, Tense::Past, Form::Finite) => wordik.1.to_owned(),
-            },
-            None => EnglishCore::verb(base_word, person, number, tense, form),
-        }
+        with_casing(word, |word| {
+            collect(|out| write_verb_lowercase(out, word, person, number, tense, form))
+        })
+    }
+    /// Conjugates a verb in the given [`Mood`].
+    ///
+    /// [`Mood::Indicative`] behaves exactly like [`English::verb`]. The
+    /// present subjunctive and the imperative use the bare form for every
+    /// person, and the past subjunctive of "be" is `"were"`; every other form
+    /// falls through to [`English::verb`]. For negative commands see
+    /// [`English::imperative`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{English, Form, Mood, Number, Person, Tense};
+    ///
+    /// // "if I were you"
+    /// assert_eq!(
+    ///     English::verb_mood("be", &Person::First, &Number::Singular, &Tense::Past, &Form::Finite, &Mood::Subjunctive),
+    ///     "were"
+    /// );
+    /// // "I insist that he go"
+    /// assert_eq!(
+    ///     English::verb_mood("go", &Person::Third, &Number::Singular, &Tense::Present, &Form::Finite, &Mood::Subjunctive),
+    ///     "go"
+    /// );
+    /// // "if she went"
+    /// assert_eq!(
+    ///     English::verb_mood("go", &Person::Third, &Number::Singular, &Tense::Past, &Form::Finite, &Mood::Subjunctive),
+    ///     "went"
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn verb_mood(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        form: &Form,
+        mood: &Mood,
+    ) -> String {
+        with_casing(word, |word| {
+            let base_word = strip_trailing_number(word);
+            let bare_or_be = *form == Form::Finite
+                && match mood {
+                    Mood::Indicative => false,
+                    Mood::Imperative => true,
+                    Mood::Subjunctive => *tense == Tense::Present || base_word == "be",
+                };
+            if bare_or_be {
+                EnglishCore::verb_mood(base_word, person, number, tense, form, mood)
+            } else {
+                English::verb(word, person, number, tense, form)
+            }
+        })
+    }
+
+    /// Builds a command addressed to the listener, with `"don't"` for
+    /// negative commands.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{English, Polarity};
+    ///
+    /// assert_eq!(English::imperative("be", &Polarity::Positive), "be");
+    /// assert_eq!(English::imperative("go", &Polarity::Negative), "don't go");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn imperative(word: &str, polarity: &Polarity) -> String {
+        with_casing(word, |word| {
+            let bare = English::verb_mood(
+                word,
+                &Person::Second,
+                &Number::Singular,
+                &Tense::Present,
+                &Form::Finite,
+                &Mood::Imperative,
+            );
+            match polarity {
+                Polarity::Positive => bare,
+                Polarity::Negative => format!("don't {bare}"),
+            }
+        })
     }
     /// Returns the correct English pronoun for the given grammatical features.
     ///
+    /// A [`Gender::Custom`] gender selects a registered [`PronounSet`] in the
+    /// third person singular.
+    ///
     /// # Examples
     /// ```rust
     /// use english::{Case, English, Gender, Number, Person};
@@ -171,17 +510,62 @@ impl English {
     /// );
     /// ```
     pub fn pronoun(person: &Person, number: &Number, gender: &Gender, case: &Case) -> &'static str {
+        #[cfg(feature = "alloc")]
+        if let Some(set) = custom_set(person, number, gender) {
+            return set.form(case);
+        }
         EnglishCore::pronoun(person, number, gender, case)
     }
+    /// Returns the form of any [`Pronoun`] in the given case, or `None` where
+    /// English has no such form.
+    ///
+    /// Covers singular "they", generic "one", demonstratives,
+    /// interrogatives, relatives, indefinites and reciprocals as well as the
+    /// personal pronouns of [`English::pronoun`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::{Case, English, Number, Pronoun, Proximity, Quantifier, Referent};
+    ///
+    /// assert_eq!(English::pronoun_form(&Pronoun::SingularThey, &Case::Reflexive), Some("themself"));
+    /// assert_eq!(
+    ///     English::pronoun_form(&Pronoun::Demonstrative(Proximity::Far, Number::Plural), &Case::Nominative),
+    ///     Some("those")
+    /// );
+    /// assert_eq!(
+    ///     English::pronoun_form(&Pronoun::Indefinite(Quantifier::No, Referent::One), &Case::Possessive),
+    ///     Some("no one's")
+    /// );
+    /// ```
+    pub fn pronoun_form(pronoun: &Pronoun, case: &Case) -> Option<&'static str> {
+        match pronoun {
+            Pronoun::Personal(person, number, gender) => {
+                Some(English::pronoun(person, number, gender, case))
+            }
+            _ => EnglishCore::pronoun_form(pronoun, case),
+        }
+    }
+
+    /// Person and number a verb must agree with when `pronoun` is its
+    /// subject (`"they are"`, `"everyone is"`).
+    pub fn pronoun_agreement(pronoun: &Pronoun) -> (Person, Number) {
+        EnglishCore::pronoun_agreement(pronoun)
+    }
+
     /// Adds an English possessive suffix (`'s` or `'`) to a word.
     ///
+    /// A final single "s" is taken to mark a plural. When the number is
+    /// known, prefer [`English::possessive`].
+    ///
     /// # Examples
     /// ```rust
     /// use english::English;
     ///
     /// assert_eq!(English::add_possessive("dog"), "dog's");
     /// assert_eq!(English::add_possessive("dogs"), "dogs'");
+    /// assert_eq!(English::add_possessive("boss"), "boss's");
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn add_possessive(word: &str) -> String {
         EnglishCore::add_possessive(word)
     }
@@ -195,6 +579,7 @@ impl English {
     /// assert_eq!(English::capitalize_first(""), "");
     /// assert_eq!(English::capitalize_first("house"), "House");
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn capitalize_first(s: &str) -> String {
         let mut c = s.chars();
         match c.next() {
@@ -213,6 +598,7 @@ impl English {
 /// assert_eq!(count("cat", 1), "cat");
 /// assert_eq!(count("cat", 2), "cats");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn count(noun: &str, count: u32) -> String {
     if count == 1 {
         English::noun(noun, &Number::Singular)
@@ -231,6 +617,27 @@ pub fn count(noun: &str, count: u32) -> String {
 /// assert_eq!(count_with_number("cat", 1), "1 cat");
 /// assert_eq!(count_with_number("cat", 2), "2 cats");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn count_with_number(noun: &str, amount: u32) -> String {
     format!("{} {}", amount, count(noun, amount))
 }
+
+/// Inflect a noun according to a numeric count, using the indefinite article
+/// instead of the number for a single item.
+///
+/// # Examples
+/// ```rust
+/// use english::count_with_article;
+///
+/// assert_eq!(count_with_article("apple", 1), "an apple");
+/// assert_eq!(count_with_article("hour", 1), "an hour");
+/// assert_eq!(count_with_article("apple", 3), "3 apples");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn count_with_article(noun: &str, amount: u32) -> String {
+    if amount == 1 {
+        English::with_article(&count(noun, amount), &Det::Indefinite)
+    } else {
+        count_with_number(noun, amount)
+    }
+}
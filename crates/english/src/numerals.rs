@@ -0,0 +1,281 @@
+use crate::{English, count};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use english_core::grammar::*;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short-scale names for each group of three digits, lowest first.
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Cardinals whose ordinal is not formed by adding `-th`.
+const IRREGULAR_ORDINALS: &[(&str, &str)] = &[
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("five", "fifth"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("twelve", "twelfth"),
+];
+
+/// Spells out a number below one hundred (`"forty-two"`).
+fn below_hundred(n: usize) -> String {
+    match n {
+        0..20 => ONES[n].to_owned(),
+        _ if n.is_multiple_of(10) => TENS[n / 10].to_owned(),
+        _ => format!("{}-{}", TENS[n / 10], ONES[n % 10]),
+    }
+}
+
+/// Spells out a number below one thousand (`"one hundred and five"`).
+fn below_thousand(n: usize) -> String {
+    match (n / 100, n % 100) {
+        (0, rest) => below_hundred(rest),
+        (hundreds, 0) => format!("{} hundred", ONES[hundreds]),
+        (hundreds, rest) => format!("{} hundred and {}", ONES[hundreds], below_hundred(rest)),
+    }
+}
+
+fn spell_unsigned(mut n: u128) -> String {
+    if n == 0 {
+        return ONES[0].to_owned();
+    }
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push((n % 1000) as usize);
+        n /= 1000;
+    }
+
+    let mut words = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        // "one thousand and five": a trailing group below a hundred is
+        // joined with "and" like the tens of a hundred are.
+        if scale == 0 && group < 100 && groups.len() > 1 {
+            words.push("and".to_owned());
+        }
+        words.push(below_thousand(group));
+        if scale > 0 {
+            words.push(SCALES[scale].to_owned());
+        }
+    }
+    words.join(" ")
+}
+
+/// Turns the last word of a spelled-out cardinal into its ordinal.
+fn ordinal_word(word: &str) -> String {
+    if let Some((_, ordinal)) = IRREGULAR_ORDINALS.iter().find(|(card, _)| *card == word) {
+        return (*ordinal).to_owned();
+    }
+    match word.strip_suffix('y') {
+        Some(stem) => format!("{stem}ieth"),
+        None => format!("{word}th"),
+    }
+}
+
+impl English {
+    /// Spells out a whole number in words.
+    ///
+    /// Uses the short scale (`"billion"` is 10⁹) and joins tens and units to
+    /// a hundred or thousand with `"and"`. Negative numbers are prefixed with
+    /// `"minus"`. Accepts any integer type up to `u64`/`i64` and beyond.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::cardinal(3u32), "three");
+    /// assert_eq!(English::cardinal(105u32), "one hundred and five");
+    /// assert_eq!(English::cardinal(2_021u32), "two thousand and twenty-one");
+    /// assert_eq!(English::cardinal(-40i32), "minus forty");
+    /// ```
+    pub fn cardinal(n: impl Into<i128>) -> String {
+        let n = n.into();
+        let words = spell_unsigned(n.unsigned_abs());
+        if n < 0 {
+            format!("minus {words}")
+        } else {
+            words
+        }
+    }
+
+    /// Spells out a whole number as an ordinal in words.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::ordinal(1u32), "first");
+    /// assert_eq!(English::ordinal(21u32), "twenty-first");
+    /// assert_eq!(English::ordinal(40u32), "fortieth");
+    /// assert_eq!(English::ordinal(112u32), "one hundred and twelfth");
+    /// ```
+    pub fn ordinal(n: impl Into<i128>) -> String {
+        let cardinal = English::cardinal(n);
+        let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+        let (head, last) = cardinal.split_at(split);
+        format!("{head}{}", ordinal_word(last))
+    }
+
+    /// Returns the suffix that follows a number written in digits
+    /// (`"st"`, `"nd"`, `"rd"` or `"th"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::ordinal_suffix(22u32), "nd");
+    /// assert_eq!(English::ordinal_suffix(13u32), "th");
+    /// assert_eq!(English::ordinal_suffix(-1i32), "st");
+    /// ```
+    pub fn ordinal_suffix(n: impl Into<i128>) -> &'static str {
+        let n = n.into().unsigned_abs();
+        match (n % 10, n % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+
+    /// Writes a number in digits followed by its ordinal suffix (`"3rd"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::ordinal_digits(3u32), "3rd");
+    /// assert_eq!(English::ordinal_digits(111u32), "111th");
+    /// ```
+    pub fn ordinal_digits(n: impl Into<i128>) -> String {
+        let n = n.into();
+        format!("{n}{}", English::ordinal_suffix(n))
+    }
+
+    /// Names a multiple of twelve in dozens (`"a dozen"`, `"half a dozen"`,
+    /// `"three dozen"`), or returns `None` for any other number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use english::English;
+    ///
+    /// assert_eq!(English::dozens(12u32).as_deref(), Some("a dozen"));
+    /// assert_eq!(English::dozens(36u32).as_deref(), Some("three dozen"));
+    /// assert_eq!(English::dozens(13u32), None);
+    /// ```
+    pub fn dozens(n: impl Into<i128>) -> Option<String> {
+        match n.into() {
+            6 => Some("half a dozen".to_owned()),
+            12 => Some("a dozen".to_owned()),
+            n if n > 0 && n % 12 == 0 && n <= 120 => {
+                Some(format!("{} dozen", English::cardinal(n / 12)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Number an amount of nouns agrees with: singular for one (or minus one),
+/// plural otherwise.
+fn count_number(amount: i128) -> Number {
+    if amount.unsigned_abs() == 1 {
+        Number::Singular
+    } else {
+        Number::Plural
+    }
+}
+
+/// Inflect a noun according to a count and spell the count out in words.
+///
+/// # Examples
+/// ```rust
+/// use english::count_with_words;
+///
+/// assert_eq!(count_with_words("cat", 3u32), "three cats");
+/// assert_eq!(count_with_words("child", 1u64), "one child");
+/// assert_eq!(count_with_words("degree", -5i64), "minus five degrees");
+/// ```
+pub fn count_with_words(noun: &str, amount: impl Into<i128>) -> String {
+    let amount = amount.into();
+    format!(
+        "{} {}",
+        English::cardinal(amount),
+        English::noun(noun, &count_number(amount))
+    )
+}
+
+/// Like [`count_with_words`], but names multiples of twelve in dozens.
+///
+/// # Examples
+/// ```rust
+/// use english::count_with_dozens;
+///
+/// assert_eq!(count_with_dozens("egg", 12u32), "a dozen eggs");
+/// assert_eq!(count_with_dozens("egg", 5u32), "five eggs");
+/// ```
+pub fn count_with_dozens(noun: &str, amount: impl Into<i128>) -> String {
+    let amount = amount.into();
+    match English::dozens(amount) {
+        Some(dozens) => format!("{dozens} {}", count(noun, 2)),
+        None => count_with_words(noun, amount),
+    }
+}
+
+/// Put a noun after the ordinal of a position written in digits.
+///
+/// # Examples
+/// ```rust
+/// use english::count_ordinal;
+///
+/// assert_eq!(count_ordinal("place", 3u32), "3rd place");
+/// assert_eq!(count_ordinal("floor", 21u64), "21st floor");
+/// ```
+pub fn count_ordinal(noun: &str, position: impl Into<i128>) -> String {
+    format!(
+        "{} {}",
+        English::ordinal_digits(position),
+        English::noun(noun, &Number::Singular)
+    )
+}
@@ -0,0 +1,113 @@
+use crate::English;
+use crate::pronoun_set::registered_determiner;
+use alloc::{borrow::ToOwned, string::String};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+
+/// Pronouns other than the personal ones that have a possessive determiner.
+const POSSESSIVE_PRONOUNS: [Pronoun; 2] = [
+    Pronoun::Interrogative(Interrogative::Who),
+    Pronoun::Relative(Relative::Who),
+];
+
+/// Finds the possessive determiner of a subject or object pronoun: a
+/// registered [`PronounSet`](crate::PronounSet), a personal pronoun or
+/// "who". Only the exact form matches, so that acronyms such as "US" or
+/// "IT" are left to the noun rules.
+fn pronoun_determiner(word: &str) -> Option<&'static str> {
+    if let Some(determiner) = registered_determiner(word) {
+        return Some(determiner);
+    }
+    for number in [Number::Singular, Number::Plural] {
+        for person in [Person::First, Person::Second, Person::Third] {
+            for gender in [Gender::Masculine, Gender::Feminine, Gender::Neuter] {
+                let matches = [Case::Nominative, Case::Accusative]
+                    .iter()
+                    .any(|case| English::pronoun(&person, &number, &gender, case) == word);
+                if matches {
+                    return Some(English::pronoun(
+                        &person,
+                        &number,
+                        &gender,
+                        &Case::PersonalPossessive,
+                    ));
+                }
+            }
+        }
+    }
+    POSSESSIVE_PRONOUNS.iter().find_map(|pronoun| {
+        let matches = [Case::Nominative, Case::Accusative]
+            .iter()
+            .any(|case| English::pronoun_form(pronoun, case) == Some(word));
+        if matches {
+            English::pronoun_form(pronoun, &Case::PersonalPossessive)
+        } else {
+            None
+        }
+    })
+}
+
+impl English {
+    /// Inflects a noun for number and makes it possessive.
+    ///
+    /// The noun goes through [`English::noun`] first, so irregular plurals
+    /// are handled in any casing (`"children's"`, `"Children's"`), then the
+    /// genitive ending follows the number of the head: `'s` for singulars
+    /// (`"boss's"`) and for plurals without a final "s", a bare apostrophe
+    /// for other plurals (`"bosses'"`). `style` only matters for names
+    /// ending in "s" (`"James's"` vs `"James'"`). For plural names, use
+    /// [`English::possessive_auto`] (`"the Kennedys'"`).
+    ///
+    /// Personal pronouns, "who" and the subject and object forms of
+    /// registered [`PronounSet`](crate::PronounSet)s, written exactly as
+    /// [`English::pronoun`] writes them, become possessive determiners
+    /// (`"she"` -> `"her"`, `"whom"` -> `"whose"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Number, PossessiveStyle};
+    ///
+    /// let chicago = &PossessiveStyle::Chicago;
+    /// assert_eq!(English::possessive("child", &Number::Plural, chicago), "children's");
+    /// assert_eq!(English::possessive("boss", &Number::Singular, chicago), "boss's");
+    /// assert_eq!(English::possessive("boss", &Number::Plural, chicago), "bosses'");
+    /// assert_eq!(English::possessive("James", &Number::Singular, chicago), "James's");
+    /// assert_eq!(
+    ///     English::possessive("James", &Number::Singular, &PossessiveStyle::AssociatedPress),
+    ///     "James'"
+    /// );
+    /// assert_eq!(English::possessive("they", &Number::Plural, chicago), "their");
+    /// # }
+    /// ```
+    pub fn possessive(noun: &str, number: &Number, style: &PossessiveStyle) -> String {
+        if let Some(determiner) = pronoun_determiner(noun) {
+            return determiner.to_owned();
+        }
+        EnglishCore::genitive(&English::noun(noun, number), number, style)
+    }
+
+    /// [`English::possessive`] with the noun inflected by
+    /// [`English::noun_auto`], so capitalized words without an irregular
+    /// plural take the proper-noun rules (`"Kennedy"` -> `"Kennedys'"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Number, PossessiveStyle};
+    ///
+    /// let chicago = &PossessiveStyle::Chicago;
+    /// assert_eq!(English::possessive_auto("Kennedy", &Number::Plural, chicago), "Kennedys'");
+    /// assert_eq!(English::possessive_auto("Child", &Number::Plural, chicago), "Children's");
+    /// assert_eq!(English::possessive_auto("baby", &Number::Plural, chicago), "babies'");
+    /// # }
+    /// ```
+    pub fn possessive_auto(noun: &str, number: &Number, style: &PossessiveStyle) -> String {
+        if let Some(determiner) = pronoun_determiner(noun) {
+            return determiner.to_owned();
+        }
+        EnglishCore::genitive(&English::noun_auto(noun, number), number, style)
+    }
+}
@@ -0,0 +1,202 @@
+use crate::English;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, vec::Vec};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// A third person singular pronoun set chosen by the user (`"xe/xem"`,
+/// `"ze/hir"`), with the verb agreement it takes.
+///
+/// Register a set once with [`PronounSet::register`] and pass the returned
+/// [`Gender`] anywhere a gender is expected: [`English::pronoun`],
+/// [`English::pronoun_form`] and every function built on them.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use english::{Case, English, Gender, Number, Person, PronounSet};
+///
+/// let xe = PronounSet::new("xe", "xem", "xemself", "xyrs", "xyr", Number::Singular).register();
+/// let third = (&Person::Third, &Number::Singular);
+/// assert_eq!(English::pronoun(third.0, third.1, &xe, &Case::Nominative), "xe");
+/// assert_eq!(English::pronoun(third.0, third.1, &xe, &Case::PersonalPossessive), "xyr");
+/// assert_eq!(English::subject_agreement(third.0, third.1, &xe), (Person::Third, Number::Singular));
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PronounSet {
+    nominative: String,
+    accusative: String,
+    reflexive: String,
+    possessive: String,
+    determiner: String,
+    agreement: Number,
+}
+
+/// Registered sets, indexed by the id carried in [`Gender::Custom`]. Sets
+/// are leaked so that their forms can be handed out as `&'static str`.
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<&'static PronounSet>> = RwLock::new(Vec::new());
+
+impl PronounSet {
+    /// Builds a set from its five case forms, in the order nominative
+    /// ("xe"), accusative ("xem"), reflexive ("xemself"), independent
+    /// possessive ("xyrs") and possessive determiner ("xyr").
+    ///
+    /// `agreement` is the verb number the set takes as a subject: singular
+    /// for "xe is", plural for "they are".
+    pub fn new(
+        nominative: &str,
+        accusative: &str,
+        reflexive: &str,
+        possessive: &str,
+        determiner: &str,
+        agreement: Number,
+    ) -> Self {
+        PronounSet {
+            nominative: nominative.to_owned(),
+            accusative: accusative.to_owned(),
+            reflexive: reflexive.to_owned(),
+            possessive: possessive.to_owned(),
+            determiner: determiner.to_owned(),
+            agreement,
+        }
+    }
+
+    /// Singular "they" with plural agreement ("they are", "themself").
+    pub fn they_them() -> Self {
+        PronounSet::new(
+            "they",
+            "them",
+            "themself",
+            "theirs",
+            "their",
+            Number::Plural,
+        )
+    }
+
+    /// The form of the set in `case`.
+    pub fn form(&self, case: &Case) -> &str {
+        match case {
+            Case::Nominative => &self.nominative,
+            Case::Accusative => &self.accusative,
+            Case::Reflexive => &self.reflexive,
+            Case::Possessive => &self.possessive,
+            Case::PersonalPossessive => &self.determiner,
+        }
+    }
+
+    /// Verb number the set takes as a subject.
+    pub fn agreement(&self) -> Number {
+        self.agreement
+    }
+
+    /// Registers the set for the rest of the program and returns the
+    /// [`Gender`] that selects it. Registering an identical set again
+    /// returns the same gender.
+    ///
+    /// Needs the `std` feature, which provides the lock guarding the
+    /// registry.
+    ///
+    /// # Panics
+    /// Panics if more than `u16::MAX` distinct sets are registered.
+    #[cfg(feature = "std")]
+    pub fn register(self) -> Gender {
+        let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(id) = registry.iter().position(|set| **set == self) {
+            return Gender::Custom(id as u16);
+        }
+        let id = u16::try_from(registry.len()).expect("too many pronoun sets registered");
+        registry.push(Box::leak(Box::new(self)));
+        Gender::Custom(id)
+    }
+
+    /// The registered set selected by `gender`, if any. Always `None`
+    /// without the `std` feature, where no set can be registered.
+    pub fn lookup(gender: &Gender) -> Option<&'static PronounSet> {
+        let Gender::Custom(id) = gender else {
+            return None;
+        };
+        #[cfg(feature = "std")]
+        {
+            let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+            registry.get(*id as usize).copied()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = id;
+            None
+        }
+    }
+}
+
+/// The possessive determiner of the registered set whose nominative or
+/// accusative is exactly `word`.
+pub(crate) fn registered_determiner(word: &str) -> Option<&'static str> {
+    #[cfg(feature = "std")]
+    {
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry
+            .iter()
+            .find(|set| set.nominative == word || set.accusative == word)
+            .map(|set| set.determiner.as_str())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = word;
+        None
+    }
+}
+
+/// The registered set for a third person singular referent of `gender`.
+pub(crate) fn custom_set(
+    person: &Person,
+    number: &Number,
+    gender: &Gender,
+) -> Option<&'static PronounSet> {
+    match (person, number) {
+        (Person::Third, Number::Singular) => PronounSet::lookup(gender),
+        _ => None,
+    }
+}
+
+impl English {
+    /// Person and number a verb takes when the pronoun for `person`,
+    /// `number` and `gender` is its subject.
+    ///
+    /// This is the identity except for registered [`PronounSet`]s with
+    /// plural agreement ("they are") and unregistered [`Gender::Custom`]
+    /// genders, which are rendered as singular "they".
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use english::{English, Gender, Number, Person, PronounSet};
+    ///
+    /// let they = PronounSet::they_them().register();
+    /// assert_eq!(
+    ///     English::subject_agreement(&Person::Third, &Number::Singular, &they),
+    ///     (Person::Third, Number::Plural)
+    /// );
+    /// # }
+    /// ```
+    pub fn subject_agreement(
+        person: &Person,
+        number: &Number,
+        gender: &Gender,
+    ) -> (Person, Number) {
+        match (custom_set(person, number, gender), gender) {
+            (Some(set), _) => (*person, set.agreement()),
+            (None, Gender::Custom(_)) if *person == Person::Third => {
+                EnglishCore::pronoun_agreement(&Pronoun::SingularThey)
+            }
+            _ => (*person, *number),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::sense_phf::{NOUN_SENSE_LABEL_MAP, VERB_SENSE_LABEL_MAP};
+use crate::variants::sense_suffixes;
+use crate::variants::{noun_note, verb_note};
+use crate::{ADJECTIVES, English, NOUNS, Source, VERBS, strip_trailing_number};
+use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
+use english_core::grammar::*;
+
+/// Which table a [`Sense`] comes from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+}
+
+/// A sense of a word that inflects differently from its other senses.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Sense {
+    /// Stable key naming the sense (`"die#dice"`, `"lie#rest"`), accepted
+    /// anywhere a word is. Plain lemma for words with a single sense.
+    pub key: String,
+    /// Numbered key the sense currently maps to (`"die2"`). Numbers follow
+    /// the sort order of the extracted forms and may change when the data
+    /// is regenerated; `key` does not.
+    pub numbered_key: String,
+    pub part_of_speech: PartOfSpeech,
+    /// Usage tags or gloss of the sense, as in [`crate::Variant::note`].
+    pub gloss: Option<&'static str>,
+}
+
+/// A numbered sense with every label that may name it: the generated label
+/// of its gloss followed by its forms.
+struct LabelledSense {
+    key: String,
+    gloss: Option<&'static str>,
+    labels: Vec<String>,
+}
+
+fn labelled_senses(part_of_speech: PartOfSpeech, lemma: &str) -> (Vec<LabelledSense>, Source) {
+    let (suffixes, source) = match part_of_speech {
+        PartOfSpeech::Noun => sense_suffixes(&NOUNS, lemma),
+        PartOfSpeech::Verb => sense_suffixes(&VERBS, lemma),
+        PartOfSpeech::Adjective => sense_suffixes(&ADJECTIVES, lemma),
+    };
+    let senses = suffixes
+        .into_iter()
+        .map(|suffix| {
+            let key = format!("{lemma}{suffix}");
+            let (gloss, label, forms) = match part_of_speech {
+                PartOfSpeech::Noun => (
+                    noun_note(&key),
+                    NOUN_SENSE_LABEL_MAP.get(&key),
+                    vec![English::noun(&key, &Number::Plural)],
+                ),
+                PartOfSpeech::Verb => {
+                    let form = |tense, form| {
+                        English::verb(&key, &Person::Third, &Number::Singular, tense, form)
+                    };
+                    (
+                        verb_note(&key),
+                        VERB_SENSE_LABEL_MAP.get(&key),
+                        vec![
+                            form(&Tense::Past, &Form::Finite),
+                            form(&Tense::Past, &Form::Participle),
+                        ],
+                    )
+                }
+                PartOfSpeech::Adjective => (
+                    None,
+                    None,
+                    vec![
+                        English::adj(&key, &Degree::Comparative),
+                        English::adj(&key, &Degree::Superlative),
+                    ],
+                ),
+            };
+            let labels = label
+                .map(|label| String::from(*label))
+                .into_iter()
+                .chain(forms);
+            LabelledSense {
+                key,
+                gloss,
+                labels: labels.collect(),
+            }
+        })
+        .collect();
+    (senses, source)
+}
+
+/// Resolves a named sense key (`"die#dice"`) to the numbered key it
+/// currently maps to. Labels that name no sense, or more than one, fall
+/// back to the lemma, as documented on [`English::senses`]. Words without a
+/// `#` are returned unchanged.
+pub(crate) fn resolve_sense(part_of_speech: PartOfSpeech, word: &str) -> Cow<'_, str> {
+    let Some((lemma, label)) = word.split_once('#') else {
+        return Cow::Borrowed(word);
+    };
+    let (senses, _) = labelled_senses(part_of_speech, lemma);
+    let mut matching = senses
+        .into_iter()
+        .filter(|sense| sense.labels.iter().any(|l| l == label));
+    match (matching.next(), matching.next()) {
+        (Some(sense), None) => Cow::Owned(sense.key),
+        _ => Cow::Borrowed(lemma),
+    }
+}
+
+impl English {
+    /// Lists the senses of `word` that the generated tables tell apart,
+    /// with a stable key for each.
+    ///
+    /// A key is the lemma, a `#` and a label naming one sense only: the
+    /// keyword of its gloss if that is unambiguous, otherwise one of its
+    /// forms (`"die#dice"`). Any label that names a single sense works, so
+    /// `"lie#lay"` and `"lie#lain"` select the same sense. Words the tables
+    /// have no forms for have no senses to tell apart and return nothing.
+    ///
+    /// Gloss labels come from the generated sense table, not from the glosses
+    /// at run time. The table bundled for now only labels a few words
+    /// (`"die"`, `"lie"`, `"shine"`); the senses of other words are named by
+    /// their forms. A key whose label names no sense of the lemma, or more
+    /// than one, inflects as the bare lemma (`"die#typo"` -> `"dies"`), so
+    /// pass only keys this function returned or forms of the sense wanted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::{English, Form, Number, Person, Tense};
+    ///
+    /// let senses = English::senses("die");
+    /// assert_eq!(senses[1].key, "die#polyhedron");
+    /// assert_eq!(senses[1].numbered_key, "die2");
+    /// assert_eq!(English::noun("die#polyhedron", &Number::Plural), "dice");
+    /// assert_eq!(English::noun("die#dice", &Number::Plural), "dice");
+    ///
+    /// let lied = English::verb("lie#lied", &Person::First, &Number::Singular, &Tense::Past, &Form::Finite);
+    /// assert_eq!(lied, "lied");
+    ///
+    /// assert_eq!(English::noun("die#typo", &Number::Plural), "dies");
+    /// # }
+    /// ```
+    pub fn senses(word: &str) -> Vec<Sense> {
+        let lemma = strip_trailing_number(word).to_lowercase();
+        let mut found = Vec::new();
+        for part_of_speech in [
+            PartOfSpeech::Noun,
+            PartOfSpeech::Verb,
+            PartOfSpeech::Adjective,
+        ] {
+            let (senses, source) = labelled_senses(part_of_speech, &lemma);
+            if source != Source::Table {
+                continue;
+            }
+            let unique = |label: &String| {
+                senses
+                    .iter()
+                    .filter(|sense| sense.labels.contains(label))
+                    .count()
+                    == 1
+            };
+            for sense in &senses {
+                let key = match sense.labels.iter().find(|label| unique(label)) {
+                    Some(label) if senses.len() > 1 => format!("{lemma}#{label}"),
+                    _ => lemma.clone(),
+                };
+                found.push(Sense {
+                    key,
+                    numbered_key: sense.key.clone(),
+                    part_of_speech,
+                    gloss: sense.gloss,
+                });
+            }
+        }
+        found
+    }
+}
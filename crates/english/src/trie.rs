@@ -0,0 +1,115 @@
+use crate::lexicon::TableForm;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+/// A suffix edit turning a key into one of its forms: drop the last `strip`
+/// bytes of the key and append `suffixes[start..start + len]`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Edit {
+    strip: u8,
+    start: u16,
+    len: u8,
+}
+
+impl Edit {
+    pub(crate) const fn new(strip: u8, start: u16, len: u8) -> Self {
+        Edit { strip, start, len }
+    }
+
+    fn apply<'a>(&self, key: &'a str, suffixes: &'static str) -> TableForm<'a> {
+        let start = self.start as usize;
+        TableForm::new(
+            &key[..key.len() - self.strip as usize],
+            &suffixes[start..start + self.len as usize],
+        )
+    }
+}
+
+/// A generated table stored as a radix trie over the bytes of its keys, the
+/// `compact-tables` alternative to a `phf::Map`.
+///
+/// Node 0 is the root, even in an empty trie. Nodes are numbered breadth
+/// first, so the children of a node are contiguous and sorted by their first
+/// byte. Values are indices into a shared list of rows of [`Edit`]s, since
+/// most words inflect like many others (`"abaculus" -> "abaculi"` strips two
+/// bytes and appends `"i"`).
+pub(crate) struct Trie<const N: usize> {
+    /// First byte of the label on the edge into each node (0 for the root).
+    firsts: &'static [u8],
+    /// The rest of the labels, node after node.
+    labels: &'static [u8],
+    /// Start of the rest of each node's label in `labels`, followed by the
+    /// end of the last.
+    label_starts: &'static [u32],
+    /// First child of each node, followed by the node count.
+    children: &'static [u32],
+    /// One more than the row of the key ending at each node, or 0.
+    values: &'static [u16],
+    rows: &'static [[Edit; N]],
+    suffixes: &'static str,
+}
+
+impl<const N: usize> Trie<N> {
+    pub(crate) const fn new(
+        firsts: &'static [u8],
+        labels: &'static [u8],
+        label_starts: &'static [u32],
+        children: &'static [u32],
+        values: &'static [u16],
+        rows: &'static [[Edit; N]],
+        suffixes: &'static str,
+    ) -> Self {
+        Trie {
+            firsts,
+            labels,
+            label_starts,
+            children,
+            values,
+            rows,
+            suffixes,
+        }
+    }
+
+    fn label(&self, node: usize) -> &'static [u8] {
+        &self.labels[self.label_starts[node] as usize..self.label_starts[node + 1] as usize]
+    }
+
+    fn row<'a>(&self, node: usize, key: &'a str) -> Option<[TableForm<'a>; N]> {
+        let row = (self.values[node] as usize).checked_sub(1)?;
+        Some(self.rows[row].map(|edit| edit.apply(key, self.suffixes)))
+    }
+
+    pub(crate) fn get<'a>(&self, key: &'a str) -> Option<[TableForm<'a>; N]> {
+        let mut node = 0;
+        let mut rest = key.as_bytes();
+        while let Some((&first, tail)) = rest.split_first() {
+            let children = self.children[node] as usize..self.children[node + 1] as usize;
+            node = children.start + self.firsts[children].iter().position(|&b| b == first)?;
+            rest = tail.strip_prefix(self.label(node))?;
+        }
+        self.row(node, key)
+    }
+
+    /// Calls `f` with every key and row, in key order.
+    #[cfg(feature = "std")]
+    pub(crate) fn for_each_entry(&self, f: &mut impl FnMut(&str, [TableForm<'_>; N])) {
+        self.walk(0, &mut Vec::new(), f);
+    }
+
+    #[cfg(feature = "std")]
+    fn walk(&self, node: usize, key: &mut Vec<u8>, f: &mut impl FnMut(&str, [TableForm<'_>; N])) {
+        // Paths may split a character at nodes where no key ends.
+        if let Ok(word) = core::str::from_utf8(key)
+            && let Some(row) = self.row(node, word)
+        {
+            f(word, row);
+        }
+        for child in self.children[node] as usize..self.children[node + 1] as usize {
+            let len = key.len();
+            key.push(self.firsts[child]);
+            key.extend_from_slice(self.label(child));
+            self.walk(child, key, f);
+            key.truncate(len);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::lexicon::Lexicon;
+use crate::variant_phf::{NOUN_NOTE_MAP, VERB_NOTE_MAP};
+use crate::{English, NOUNS, VERBS, strip_trailing_number};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use english_core::grammar::*;
+
+/// Where an analysis, [`Variant`] or [`crate::Sense`] came from. Ordered
+/// from most to least trustworthy.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum Source {
+    /// Attested in the generated Wiktionary tables.
+    Table,
+    /// Recovered by undoing the regular [`english_core::EnglishCore`] rules.
+    Rule,
+    /// The word was assumed to already be a lemma.
+    Guess,
+}
+
+/// One attested way of inflecting a word, with the key that selects it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Variant<F> {
+    /// Key that selects this variant when passed to [`English::noun`] or
+    /// [`English::verb`] (`"die2"`).
+    pub key: String,
+    pub forms: F,
+    /// What sets this variant apart: its usage tags (`"UK"`,
+    /// `"hypercorrect"`) or the gloss of the sense it belongs to.
+    pub note: Option<&'static str>,
+    /// [`Source::Table`] if the word has attested forms, [`Source::Rule`] if
+    /// its only form is the one the rules predict.
+    pub source: Source,
+}
+
+/// Principal parts of a verb, in the order of the generated tables.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VerbForms {
+    pub third_person_singular: String,
+    pub past: String,
+    pub present_participle: String,
+    pub past_participle: String,
+}
+
+/// Usage tags or gloss recorded for the noun sense selected by `key`.
+pub(crate) fn noun_note(key: &str) -> Option<&'static str> {
+    NOUN_NOTE_MAP.get(key).copied()
+}
+
+/// Usage tags or gloss recorded for the verb sense selected by `key`.
+pub(crate) fn verb_note(key: &str) -> Option<&'static str> {
+    VERB_NOTE_MAP.get(key).copied()
+}
+
+/// Sense suffixes of `lemma` in `map`: `""` for the lemma itself, whose
+/// forms may be the predicted ones, followed by `"2"`, `"3"`... for as long
+/// as the table has them.
+pub(crate) fn sense_suffixes<const N: usize>(
+    map: &Lexicon<N>,
+    lemma: &str,
+) -> (Vec<String>, Source) {
+    let mut suffixes = vec![String::new()];
+    for index in 2.. {
+        if !map.contains_key(format!("{lemma}{index}").as_str()) {
+            break;
+        }
+        suffixes.push(index.to_string());
+    }
+    let source = if suffixes.len() > 1 || map.contains_key(lemma) {
+        Source::Table
+    } else {
+        Source::Rule
+    };
+    (suffixes, source)
+}
+
+impl English {
+    /// Lists every attested plural of a noun, first sense first.
+    ///
+    /// The key of each variant can be passed back to [`English::noun`] to
+    /// select it, so callers can offer a choice instead of guessing sense
+    /// numbers. A word with no attested alternatives has a single variant.
+    ///
+    /// The extractor keeps plurals Wiktionary tags rare, archaic,
+    /// nonstandard and so on after the untagged ones, with their tags as the
+    /// note. The bundled noun table predates that and still lacks most of
+    /// them (`"octopodes"`), and its notes are a hand-written sample until
+    /// `cargo xtask refresh-data` is run against a dump.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "nouns")]
+    /// # {
+    /// use english::English;
+    ///
+    /// let plurals: Vec<String> = English::noun_variants("octopus")
+    ///     .into_iter()
+    ///     .map(|variant| variant.forms)
+    ///     .collect();
+    /// assert_eq!(plurals[..2], ["octopuses", "octopi"]);
+    ///
+    /// let dice = &English::noun_variants("die")[1];
+    /// assert_eq!((dice.key.as_str(), dice.forms.as_str()), ("die2", "dice"));
+    /// assert!(dice.note.unwrap().contains("games"));
+    /// # }
+    /// ```
+    pub fn noun_variants(word: &str) -> Vec<Variant<String>> {
+        let word = strip_trailing_number(word);
+        let lemma = word.to_lowercase();
+        let (suffixes, source) = sense_suffixes(&NOUNS, &lemma);
+        suffixes
+            .into_iter()
+            .map(|suffix| {
+                let key = format!("{lemma}{suffix}");
+                Variant {
+                    forms: English::noun(&format!("{word}{suffix}"), &Number::Plural),
+                    note: noun_note(&key),
+                    key,
+                    source,
+                }
+            })
+            .collect()
+    }
+
+    /// Lists the principal parts of every attested sense of a verb, first
+    /// sense first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::English;
+    ///
+    /// let pasts: Vec<(String, String)> = English::verb_variants("lie")
+    ///     .into_iter()
+    ///     .map(|variant| (variant.key, variant.forms.past))
+    ///     .collect();
+    /// assert_eq!(pasts, [("lie".into(), "lay".into()), ("lie2".into(), "lied".into())]);
+    /// # }
+    /// ```
+    pub fn verb_variants(word: &str) -> Vec<Variant<VerbForms>> {
+        let word = strip_trailing_number(word);
+        let lemma = word.to_lowercase();
+        let (suffixes, source) = sense_suffixes(&VERBS, &lemma);
+        suffixes
+            .into_iter()
+            .map(|suffix| {
+                let key = format!("{lemma}{suffix}");
+                let sense = format!("{word}{suffix}");
+                let form = |tense, form| {
+                    English::verb(&sense, &Person::Third, &Number::Singular, tense, form)
+                };
+                Variant {
+                    forms: VerbForms {
+                        third_person_singular: form(&Tense::Present, &Form::Finite),
+                        past: form(&Tense::Past, &Form::Finite),
+                        present_participle: form(&Tense::Present, &Form::Participle),
+                        past_participle: form(&Tense::Past, &Form::Participle),
+                    },
+                    note: verb_note(&key),
+                    key,
+                    source,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,169 @@
+use crate::casing::with_casing;
+use crate::{English, strip_trailing_number};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use english_core::EnglishCore;
+use english_core::grammar::*;
+
+/// What a verb in the chain contributes, which fixes the form of the verb
+/// that follows it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Role {
+    Perfect,
+    Progressive,
+    Passive,
+    Lexical,
+}
+
+impl Role {
+    /// (tense, form) required of the next verb in the chain.
+    fn next_form(self) -> (Tense, Form) {
+        match self {
+            Role::Perfect | Role::Passive => (Tense::Past, Form::Participle),
+            Role::Progressive => (Tense::Present, Form::Participle),
+            Role::Lexical => (Tense::Present, Form::Infinitive),
+        }
+    }
+}
+
+/// Builds the non-finite tail of the chain: `have`, `be` and the lexical
+/// verb in the order English stacks them.
+fn verb_chain<'a>(word: &'a str, aspect: &Aspect, voice: &Voice) -> Vec<(&'a str, Role)> {
+    let mut chain = Vec::new();
+    if matches!(aspect, Aspect::Perfect | Aspect::PerfectProgressive) {
+        chain.push(("have", Role::Perfect));
+    }
+    if matches!(aspect, Aspect::Progressive | Aspect::PerfectProgressive) {
+        chain.push(("be", Role::Progressive));
+    }
+    if *voice == Voice::Passive {
+        chain.push(("be", Role::Passive));
+    }
+    chain.push((word, Role::Lexical));
+    chain
+}
+
+/// Inflects every verb after the first according to the role of the verb
+/// before it.
+fn render_tail(chain: &[(&str, Role)], first_role: Role, words: &mut Vec<String>) {
+    let mut previous = first_role;
+    for (lemma, role) in chain {
+        let (tense, form) = previous.next_form();
+        words.push(English::verb(
+            lemma,
+            &Person::Third,
+            &Number::Singular,
+            &tense,
+            &form,
+        ));
+        previous = *role;
+    }
+}
+
+impl English {
+    /// Conjugates a whole verb group: auxiliaries for the requested tense,
+    /// aspect, voice and polarity, followed by the lexical verb.
+    ///
+    /// The first auxiliary agrees with the subject, every later verb takes
+    /// the participle its predecessor requires, and `not` follows the first
+    /// auxiliary. Simple negatives use do-support (`"does not run"`) except
+    /// for the lexical verb "be" (`"is not"`). The future uses "will".
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{Aspect, English, Number, Person, Polarity, Tense, Voice};
+    ///
+    /// assert_eq!(
+    ///     English::verb_phrase("eat", &Person::First, &Number::Singular, &Tense::Future,
+    ///         &Aspect::PerfectProgressive, &Voice::Active, &Polarity::Positive),
+    ///     "will have been eating"
+    /// );
+    /// assert_eq!(
+    ///     English::verb_phrase("go", &Person::Third, &Number::Plural, &Tense::Past,
+    ///         &Aspect::Perfect, &Voice::Active, &Polarity::Positive),
+    ///     "had gone"
+    /// );
+    /// assert_eq!(
+    ///     English::verb_phrase("eat", &Person::Third, &Number::Singular, &Tense::Present,
+    ///         &Aspect::Progressive, &Voice::Passive, &Polarity::Positive),
+    ///     "is being eaten"
+    /// );
+    /// assert_eq!(
+    ///     English::verb_phrase("run", &Person::Third, &Number::Singular, &Tense::Present,
+    ///         &Aspect::Simple, &Voice::Active, &Polarity::Negative),
+    ///     "does not run"
+    /// );
+    /// # }
+    /// ```
+    pub fn verb_phrase(
+        word: &str,
+        person: &Person,
+        number: &Number,
+        tense: &Tense,
+        aspect: &Aspect,
+        voice: &Voice,
+        polarity: &Polarity,
+    ) -> String {
+        with_casing(word, |word| {
+            if *tense == Tense::Future {
+                return English::modal_phrase(&Modal::Will, word, aspect, voice, polarity);
+            }
+            let mut chain = verb_chain(word, aspect, voice);
+            let needs_do_support = *polarity == Polarity::Negative
+                && chain.len() == 1
+                && strip_trailing_number(word) != "be";
+            if needs_do_support {
+                chain.insert(0, ("do", Role::Lexical));
+            }
+
+            let (first, first_role) = chain[0];
+            let mut words = vec![English::verb(first, person, number, tense, &Form::Finite)];
+            if *polarity == Polarity::Negative {
+                words.push("not".to_owned());
+            }
+            render_tail(&chain[1..], first_role, &mut words);
+            words.join(" ")
+        })
+    }
+
+    /// Conjugates a verb group headed by a modal (`"could have been
+    /// eaten"`, `"must not run"`).
+    ///
+    /// Modals do not agree with their subject, so no person or number is
+    /// needed; everything after the modal follows the same rules as
+    /// [`English::verb_phrase`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "verbs")]
+    /// # {
+    /// use english::{Aspect, English, Modal, Polarity, Voice};
+    ///
+    /// assert_eq!(
+    ///     English::modal_phrase(&Modal::Could, "eat", &Aspect::Perfect, &Voice::Passive, &Polarity::Positive),
+    ///     "could have been eaten"
+    /// );
+    /// assert_eq!(
+    ///     English::modal_phrase(&Modal::Must, "run", &Aspect::Simple, &Voice::Active, &Polarity::Negative),
+    ///     "must not run"
+    /// );
+    /// # }
+    /// ```
+    pub fn modal_phrase(
+        modal: &Modal,
+        word: &str,
+        aspect: &Aspect,
+        voice: &Voice,
+        polarity: &Polarity,
+    ) -> String {
+        with_casing(word, |word| {
+            let mut words = vec![EnglishCore::modal(modal).to_owned()];
+            if *polarity == Polarity::Negative {
+                words.push("not".to_owned());
+            }
+            render_tail(&verb_chain(word, aspect, voice), Role::Lexical, &mut words);
+            words.join(" ")
+        })
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(all(feature = "alloc", feature = "nouns"))]
+
+use english::*;
+
+#[test]
+fn article_follows_spelling_for_ordinary_words() {
+    for (word, article) in [
+        ("apple", "an"),
+        ("cat", "a"),
+        ("egg", "an"),
+        ("umbrella", "an"),
+        ("house", "a"),
+        ("yellow", "a"),
+    ] {
+        assert_eq!(English::indefinite_article(word), article, "{word}");
+    }
+}
+
+#[test]
+fn article_follows_sound_for_exceptions() {
+    for (word, article) in [
+        ("hour", "an"),
+        ("honest", "an"),
+        ("heir", "an"),
+        ("university", "a"),
+        ("unidentified", "an"),
+        ("union", "a"),
+        ("European", "a"),
+        ("one", "a"),
+        ("one-off", "a"),
+        ("onerous", "an"),
+        ("ouija", "a"),
+        ("yttrium", "an"),
+    ] {
+        assert_eq!(English::indefinite_article(word), article, "{word}");
+    }
+}
+
+#[test]
+fn article_reads_initialisms_and_numerals_aloud() {
+    for (word, article) in [
+        ("MRI", "an"),
+        ("FBI", "an"),
+        ("UFO", "a"),
+        ("NASA", "a"),
+        ("x-ray", "an"),
+        ("8", "an"),
+        ("80", "an"),
+        ("11", "an"),
+        ("18", "an"),
+        ("110", "a"),
+        ("11,000", "an"),
+        ("11000", "an"),
+        ("SQL", "an"),
+        ("1", "a"),
+        ("100", "a"),
+    ] {
+        assert_eq!(English::indefinite_article(word), article, "{word}");
+    }
+}
+
+#[test]
+fn article_finds_every_curated_exception() {
+    for (word, article) in [
+        ("NAFTA", "a"),
+        ("NATO", "a"),
+        ("SWAT", "a"),
+        ("nth", "an"),
+        ("Onesie", "a"),
+        ("unary", "a"),
+        ("Uranium", "a"),
+        ("uvula", "a"),
+        ("ytterbium", "an"),
+    ] {
+        assert_eq!(English::indefinite_article(word), article, "{word}");
+    }
+}
+
+#[test]
+fn article_only_looks_at_the_first_word() {
+    assert_eq!(
+        English::with_article("unusual hour", &Det::Indefinite),
+        "an unusual hour"
+    );
+    assert_eq!(English::with_article("hour", &Det::Definite), "the hour");
+}
+
+#[test]
+fn count_with_article_uses_article_for_one() {
+    assert_eq!(count_with_article("hour", 1), "an hour");
+    assert_eq!(count_with_article("child", 1), "a child");
+    assert_eq!(count_with_article("child", 2), "2 children");
+    assert_eq!(count_with_article("child", 0), "0 children");
+}
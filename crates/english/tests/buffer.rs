@@ -0,0 +1,137 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "nouns",
+    feature = "verbs",
+    feature = "adjectives"
+))]
+
+use english::*;
+use english_core::EnglishCore;
+use std::borrow::Cow;
+
+const WORDS: &[&str] = &[
+    "child",
+    "cat",
+    "box",
+    "die2",
+    "die#dice",
+    "mother-in-law",
+    "Child",
+    "MOUSE",
+    "iPhone",
+    "zzzzz",
+];
+const VERBS: &[&str] = &["go", "walk", "be", "have", "lie2", "travel", "Swim", "try"];
+const ADJECTIVES: &[&str] = &["good", "big", "careful", "happy", "bad3", "Fast"];
+
+fn verb_forms() -> Vec<(Person, Number, Tense, Form)> {
+    let mut forms = Vec::new();
+    for person in [Person::First, Person::Second, Person::Third] {
+        for number in [Number::Singular, Number::Plural] {
+            for tense in [Tense::Present, Tense::Past, Tense::Future] {
+                for form in [Form::Finite, Form::Participle, Form::Infinitive] {
+                    forms.push((person, number, tense, form));
+                }
+            }
+        }
+    }
+    forms
+}
+
+#[test]
+fn cow_and_write_variants_match_the_string_api() {
+    for word in WORDS {
+        for number in [Number::Singular, Number::Plural] {
+            let expected = English::noun(word, &number);
+            assert_eq!(English::noun_cow(word, &number), expected);
+            let mut out = String::from(">");
+            English::write_noun(&mut out, word, &number).unwrap();
+            assert_eq!(out, format!(">{expected}"));
+        }
+    }
+    for word in VERBS {
+        for (person, number, tense, form) in verb_forms() {
+            let expected = English::verb(word, &person, &number, &tense, &form);
+            assert_eq!(
+                English::verb_cow(word, &person, &number, &tense, &form),
+                expected
+            );
+            let mut out = String::new();
+            English::write_verb(&mut out, word, &person, &number, &tense, &form).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+    for word in ADJECTIVES {
+        for degree in [Degree::Positive, Degree::Comparative, Degree::Superlative] {
+            let expected = English::adj(word, &degree);
+            assert_eq!(English::adj_cow(word, &degree), expected);
+            let mut out = String::new();
+            English::write_adj(&mut out, word, &degree).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+}
+
+// Compact tables rebuild most forms from their key.
+#[cfg(not(feature = "compact-tables"))]
+#[test]
+fn table_hits_are_borrowed() {
+    assert!(matches!(
+        English::noun_cow("die#dice", &Number::Plural),
+        Cow::Borrowed("dice")
+    ));
+    assert!(matches!(
+        English::verb_cow(
+            "lie2",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Participle
+        ),
+        Cow::Borrowed("lied")
+    ));
+    assert!(matches!(
+        English::adj_cow("bad3", &Degree::Positive),
+        Cow::Borrowed("bad")
+    ));
+    assert!(matches!(
+        English::noun_cow("zzzzz", &Number::Plural),
+        Cow::Owned(_)
+    ));
+}
+
+#[test]
+fn core_writers_match_core_rules() {
+    let mut out = String::new();
+    for word in ["bus", "city", "attorney general", "leaf"] {
+        out.clear();
+        EnglishCore::write_noun(&mut out, word, &Number::Plural).unwrap();
+        assert_eq!(out, EnglishCore::noun(word, &Number::Plural));
+    }
+    for word in ["big", "happy", "large", "careful", "good"] {
+        for degree in [Degree::Comparative, Degree::Superlative] {
+            assert_eq!(
+                EnglishCore::adjective_cow(word, &degree),
+                EnglishCore::adjective(word, &degree)
+            );
+        }
+    }
+    assert!(matches!(
+        EnglishCore::adjective_cow("good", &Degree::Comparative),
+        Cow::Borrowed("better")
+    ));
+}
+
+#[test]
+fn writers_accept_any_fmt_write() {
+    struct Counter(usize);
+    impl std::fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    English::write_noun(&mut counter, "mother-in-law", &Number::Plural).unwrap();
+    assert_eq!(counter.0, "mothers-in-law".len());
+}
@@ -0,0 +1,123 @@
+#![cfg(all(feature = "alloc", feature = "nouns", feature = "verbs"))]
+
+use english::*;
+
+#[test]
+fn title_case_hits_the_tables() {
+    assert_eq!(English::noun("Child", &Number::Plural), "Children");
+    assert_eq!(English::noun("Die2", &Number::Plural), "Dice");
+    assert_eq!(English::adj("Good", &Degree::Superlative), "Best");
+    assert_eq!(
+        English::verb(
+            "Go",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "Went"
+    );
+}
+
+#[test]
+fn upper_case_stays_upper() {
+    assert_eq!(English::noun("MOUSE", &Number::Plural), "MICE");
+    assert_eq!(English::noun("CHILD", &Number::Plural), "CHILDREN");
+    assert_eq!(English::noun("CITY", &Number::Plural), "CITIES");
+    assert_eq!(
+        English::adj("CAREFUL", &Degree::Comparative),
+        "MORE CAREFUL"
+    );
+    assert_eq!(
+        English::verb(
+            "SWIM",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "SWAM"
+    );
+}
+
+#[test]
+fn upper_case_uppercases_a_regular_ending() {
+    assert_eq!(English::noun("CAT", &Number::Plural), "CATS");
+    assert_eq!(English::noun("BOX", &Number::Plural), "BOXES");
+    assert_eq!(English::noun("CD", &Number::Plural), "CDS");
+    assert_eq!(
+        English::noun("CAT", &Number::Plural),
+        English::proper_noun("CAT", &Number::Plural)
+    );
+    assert_eq!(count_with_number("CD", 2), "2 CDS");
+    assert_eq!(
+        English::verb(
+            "CAT",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "CATTED"
+    );
+    assert_eq!(
+        English::verb(
+            "RUN",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Participle
+        ),
+        "RUNNING"
+    );
+}
+
+#[test]
+fn lower_case_is_unchanged() {
+    assert_eq!(English::noun("mouse", &Number::Plural), "mice");
+    assert_eq!(English::adj("big", &Degree::Comparative), "bigger");
+}
+
+#[test]
+fn mixed_case_keeps_the_shared_prefix() {
+    assert_eq!(English::noun("iPhone", &Number::Plural), "iPhones");
+    assert_eq!(English::noun("McDonald", &Number::Plural), "McDonalds");
+    assert_eq!(English::noun("PhD", &Number::Plural), "PhDs");
+    assert_eq!(
+        English::noun("GrandChild", &Number::Plural),
+        "GrandChildren"
+    );
+}
+
+#[test]
+fn phrases_take_the_casing_of_the_verb() {
+    assert_eq!(
+        English::verb_phrase(
+            "Eat",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Future,
+            &Aspect::Perfect,
+            &Voice::Active,
+            &Polarity::Positive
+        ),
+        "Will have eaten"
+    );
+    assert_eq!(
+        English::negative(
+            "RUN",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Register::Contracted
+        ),
+        "DOESN'T RUN"
+    );
+    assert_eq!(English::imperative("Go", &Polarity::Negative), "Don't go");
+}
+
+#[test]
+fn count_helpers_inherit_casing() {
+    assert_eq!(count("Child", 2), "Children");
+    assert_eq!(count_with_number("GOOSE", 3), "3 GEESE");
+}
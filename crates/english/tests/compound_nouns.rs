@@ -0,0 +1,62 @@
+#![cfg(feature = "alloc")]
+
+use english::*;
+
+fn plural(word: &str) -> String {
+    English::noun(word, &Number::Plural)
+}
+
+#[test]
+fn linking_prepositions_pluralize_the_first_word() {
+    assert_eq!(plural("mother-in-law"), "mothers-in-law");
+    assert_eq!(plural("man-of-war"), "men-of-war");
+    assert_eq!(plural("commander-in-chief"), "commanders-in-chief");
+    assert_eq!(plural("jack-in-the-box"), "jacks-in-the-box");
+    assert_eq!(plural("bill of sale"), "bills of sale");
+    assert_eq!(plural("attorney-at-law"), "attorneys-at-law");
+}
+
+#[test]
+fn agent_nouns_before_particles() {
+    assert_eq!(plural("passer-by"), "passers-by");
+    assert_eq!(plural("runner-up"), "runners-up");
+    assert_eq!(plural("hanger-on"), "hangers-on");
+    assert_eq!(plural("looker-on"), "lookers-on");
+    assert_eq!(plural("diner-out"), "diners-out");
+    // No agent noun: the particle word is the head.
+    assert_eq!(plural("break-in"), "break-ins");
+    assert_eq!(plural("power-up"), "power-ups");
+    assert_eq!(plural("cover-up"), "cover-ups");
+}
+
+#[test]
+fn indefinite_pronoun_endings_pluralize_the_last_word() {
+    assert_eq!(plural("good-for-nothing"), "good-for-nothings");
+    assert_eq!(plural("free-for-all"), "free-for-alls");
+    assert_eq!(plural("know-it-all"), "know-it-alls");
+}
+
+#[test]
+fn postpositive_adjectives() {
+    assert_eq!(plural("attorney general"), "attorneys general");
+    assert_eq!(plural("court martial"), "courts martial");
+    assert_eq!(plural("heir apparent"), "heirs apparent");
+    assert_eq!(plural("major general"), "major generals");
+    assert_eq!(plural("lieutenant general"), "lieutenant generals");
+    assert_eq!(plural("brigadier general"), "brigadier generals");
+    assert_eq!(plural("surgeon general"), "surgeons general");
+}
+
+#[test]
+fn other_compounds_pluralize_the_last_word() {
+    assert_eq!(plural("ice cream"), "ice creams");
+    assert_eq!(plural("grown-up"), "grown-ups");
+    assert_eq!(plural("fire truck"), "fire trucks");
+    assert_eq!(plural("sea mouse"), "sea mice");
+}
+
+#[test]
+fn compounds_keep_casing() {
+    assert_eq!(plural("Mother-in-Law"), "Mothers-in-Law");
+    assert_eq!(plural("ATTORNEY GENERAL"), "ATTORNEYS GENERAL");
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "alloc")]
+
+use english::*;
+
+#[test]
+fn negatives_in_both_registers() {
+    let cases = [
+        (
+            "be",
+            Person::Third,
+            Number::Singular,
+            Tense::Past,
+            "wasn't",
+            "was not",
+        ),
+        (
+            "be",
+            Person::Second,
+            Number::Singular,
+            Tense::Present,
+            "aren't",
+            "are not",
+        ),
+        (
+            "have",
+            Person::Third,
+            Number::Singular,
+            Tense::Present,
+            "doesn't have",
+            "does not have",
+        ),
+        (
+            "eat",
+            Person::First,
+            Number::Plural,
+            Tense::Past,
+            "didn't eat",
+            "did not eat",
+        ),
+        (
+            "be",
+            Person::First,
+            Number::Singular,
+            Tense::Future,
+            "won't be",
+            "will not be",
+        ),
+        (
+            "should",
+            Person::First,
+            Number::Singular,
+            Tense::Present,
+            "shouldn't",
+            "should not",
+        ),
+        (
+            "shall",
+            Person::First,
+            Number::Plural,
+            Tense::Present,
+            "shan't",
+            "shall not",
+        ),
+    ];
+    for (word, person, number, tense, contracted, formal) in cases {
+        assert_eq!(
+            English::negative(word, &person, &number, &tense, &Register::Contracted),
+            contracted
+        );
+        assert_eq!(
+            English::negative(word, &person, &number, &tense, &Register::Formal),
+            formal
+        );
+    }
+}
+
+#[test]
+fn negative_questions_invert_subject() {
+    assert_eq!(
+        English::negative_question(
+            "be",
+            &Person::First,
+            &Number::Singular,
+            &Gender::Neuter,
+            &Tense::Present,
+            &Register::Formal
+        ),
+        "am I not"
+    );
+    assert_eq!(
+        English::negative_question(
+            "go",
+            &Person::Third,
+            &Number::Plural,
+            &Gender::Neuter,
+            &Tense::Future,
+            &Register::Contracted
+        ),
+        "won't they go"
+    );
+    assert_eq!(
+        English::negative_question(
+            "run",
+            &Person::Third,
+            &Number::Singular,
+            &Gender::Masculine,
+            &Tense::Present,
+            &Register::Contracted
+        ),
+        "doesn't he run"
+    );
+}
+
+#[test]
+fn subject_auxiliary_clitics() {
+    let contracted = |person, number, gender, word, tense| {
+        English::subject_auxiliary(
+            &person,
+            &number,
+            &gender,
+            word,
+            &tense,
+            &Register::Contracted,
+        )
+    };
+    use Gender::*;
+    use Number::*;
+    use Person::*;
+    assert_eq!(
+        contracted(Third, Singular, Feminine, "would", Tense::Present),
+        "she'd"
+    );
+    assert_eq!(
+        contracted(Third, Singular, Feminine, "have", Tense::Past),
+        "she'd"
+    );
+    assert_eq!(
+        contracted(Third, Singular, Masculine, "be", Tense::Present),
+        "he's"
+    );
+    assert_eq!(
+        contracted(Second, Plural, Neuter, "be", Tense::Present),
+        "you're"
+    );
+    assert_eq!(
+        contracted(First, Plural, Neuter, "be", Tense::Future),
+        "we'll be"
+    );
+    assert_eq!(
+        contracted(First, Singular, Neuter, "will", Tense::Present),
+        "I'll"
+    );
+    assert_eq!(
+        contracted(Third, Singular, Neuter, "be", Tense::Past),
+        "it was"
+    );
+}
@@ -0,0 +1,115 @@
+#![cfg(all(feature = "alloc", feature = "nouns"))]
+
+use english::*;
+
+#[test]
+fn default_policy_numbers() {
+    let policy = CountPolicy::default();
+    for (amount, number) in [
+        ("1", Number::Singular),
+        ("2", Number::Plural),
+        ("0", Number::Plural),
+        ("0.0", Number::Plural),
+        ("-1", Number::Singular),
+        ("-2", Number::Plural),
+        ("1.0", Number::Plural),
+        ("1.5", Number::Plural),
+        ("0.5", Number::Plural),
+        ("-0.5", Number::Plural),
+        ("1/2", Number::Singular),
+        ("3/4", Number::Singular),
+        ("2/2", Number::Singular),
+        ("3/2", Number::Plural),
+        ("1 1/2", Number::Plural),
+        ("lots", Number::Plural),
+        ("1/0", Number::Plural),
+    ] {
+        assert_eq!(policy.number_for_str(amount), number, "{amount}");
+    }
+}
+
+#[test]
+fn policy_fields_override_contested_cases() {
+    let policy = CountPolicy {
+        zero: Number::Singular,
+        minus_one: Number::Plural,
+        decimal_fraction: Number::Singular,
+        common_fraction: Number::Plural,
+    };
+    assert_eq!(count_with_policy("item", "0", &policy), "0 item");
+    assert_eq!(count_with_policy("point", "-1", &policy), "-1 points");
+    assert_eq!(count_with_policy("hour", "0.5", &policy), "0.5 hour");
+    assert_eq!(count_with_policy("cup", "1/2", &policy), "1/2 cups");
+    // Uncontested cases are unaffected.
+    assert_eq!(count_with_policy("hour", "1.5", &policy), "1.5 hours");
+    assert_eq!(count_with_policy("hour", "1", &policy), "1 hour");
+}
+
+#[test]
+fn float_counts() {
+    assert_eq!(count_f64("hour", 1.5), "1.5 hours");
+    assert_eq!(count_f64("hour", 1.0), "1 hour");
+    assert_eq!(count_f64("child", 2.0), "2 children");
+    assert_eq!(count_f64("item", 0.0), "0 items");
+    assert_eq!(
+        CountPolicy::default().number_for_f64(f64::NAN),
+        Number::Plural
+    );
+}
+
+#[test]
+fn fractions_read_aloud() {
+    assert_eq!(count_fraction("loaf", 1, 2).unwrap(), "half a loaf");
+    assert_eq!(count_fraction("hour", 1, 2).unwrap(), "half an hour");
+    assert_eq!(
+        count_fraction("hour", 1, 4).unwrap(),
+        "a quarter of an hour"
+    );
+    assert_eq!(
+        count_fraction("cake", 2, 3).unwrap(),
+        "two thirds of a cake"
+    );
+    assert_eq!(
+        count_fraction("apple", 1, 8).unwrap(),
+        "an eighth of an apple"
+    );
+    assert_eq!(
+        count_fraction("loaf", 3, 2).unwrap(),
+        "three halves of a loaf"
+    );
+}
+
+#[test]
+fn fractions_that_come_out_whole_are_counted() {
+    assert_eq!(count_fraction("pie", 1, 1).unwrap(), "one pie");
+    assert_eq!(count_fraction("pie", 4, 4).unwrap(), "one pie");
+    assert_eq!(count_fraction("pie", 6, 3).unwrap(), "two pies");
+    assert_eq!(count_fraction("pie", 0, 4).unwrap(), "zero pies");
+    assert_eq!(count_fraction("loaf", 0, 5).unwrap(), "zero loaves");
+}
+
+#[test]
+fn powers_of_ten_drop_the_leading_one() {
+    assert_eq!(
+        count_fraction("loaf", 1, 1000).unwrap(),
+        "a thousandth of a loaf"
+    );
+    assert_eq!(
+        count_fraction("loaf", 7, 100).unwrap(),
+        "seven hundredths of a loaf"
+    );
+    assert_eq!(
+        count_fraction("meter", 3, 1_000_000).unwrap(),
+        "three millionths of a meter"
+    );
+    assert_eq!(
+        count_fraction("apple", 1, 10).unwrap(),
+        "a tenth of an apple"
+    );
+}
+
+#[test]
+fn fractions_reject_a_zero_denominator() {
+    assert_eq!(count_fraction("pie", 3, 0), None);
+    assert_eq!(count_fraction("pie", 0, 0), None);
+}
@@ -0,0 +1,119 @@
+#![cfg(all(feature = "std", feature = "nouns", feature = "verbs"))]
+
+use english::*;
+
+fn past(word: &str, dialect: &Dialect) -> String {
+    English::verb_in(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Past,
+        &Form::Finite,
+        dialect,
+    )
+}
+
+fn past_participle(word: &str, dialect: &Dialect) -> String {
+    English::verb_in(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Past,
+        &Form::Participle,
+        dialect,
+    )
+}
+
+#[test]
+fn british_forms_double_l_and_keep_t_pasts() {
+    assert_eq!(past("travel", &Dialect::UK), "travelled");
+    assert_eq!(past("cancel", &Dialect::UK), "cancelled");
+    assert_eq!(past("learn", &Dialect::UK), "learnt");
+    assert_eq!(past("dream", &Dialect::UK), "dreamt");
+    assert_eq!(
+        English::verb_in(
+            "travel",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Participle,
+            &Dialect::UK
+        ),
+        "travelling"
+    );
+    assert_eq!(
+        English::verb_in(
+            "travel",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Finite,
+            &Dialect::UK
+        ),
+        "travels"
+    );
+}
+
+#[test]
+fn american_forms_match_the_default_tables_except_where_recorded() {
+    for word in ["travel", "learn", "dream", "go", "walk"] {
+        assert_eq!(
+            past(word, &Dialect::US),
+            English::verb(
+                word,
+                &Person::Third,
+                &Number::Singular,
+                &Tense::Past,
+                &Form::Finite
+            )
+        );
+    }
+    assert_eq!(past_participle("get", &Dialect::US), "gotten");
+    assert_eq!(past_participle("get", &Dialect::UK), "got");
+    assert_eq!(past("fit", &Dialect::US), "fit");
+    assert_eq!(past("fit", &Dialect::UK), "fitted");
+}
+
+#[test]
+fn australian_and_canadian_fall_back() {
+    assert_eq!(past("travel", &Dialect::AU), "travelled");
+    assert_eq!(past("learn", &Dialect::AU), "learnt");
+    assert_eq!(past("travel", &Dialect::CA), "travelled");
+    assert_eq!(past("learn", &Dialect::CA), "learned");
+    assert_eq!(past_participle("get", &Dialect::CA), "gotten");
+}
+
+#[test]
+fn dialect_forms_keep_casing() {
+    assert_eq!(past("Travel", &Dialect::UK), "Travelled");
+    assert_eq!(
+        English::noun_in("PENNY", &Number::Plural, &Dialect::UK),
+        "PENCE"
+    );
+    assert_eq!(
+        English::noun_in("penny", &Number::Singular, &Dialect::UK),
+        "penny"
+    );
+}
+
+#[test]
+fn inflector_dialect_sits_between_overrides_and_tables() {
+    let mut inflector = Inflector::new();
+    inflector
+        .set_dialect(Dialect::UK)
+        .add_verb("spell", "spells", "spelled", "spelling", "spelled");
+    let past = |word| {
+        inflector.verb(
+            word,
+            &Person::First,
+            &Number::Plural,
+            &Tense::Past,
+            &Form::Finite,
+        )
+    };
+    assert_eq!(past("spell"), "spelled");
+    assert_eq!(past("burn"), "burnt");
+    assert_eq!(past("go"), "went");
+    assert_eq!(inflector.noun("penny", &Number::Plural), "pence");
+    assert_eq!(inflector.noun("child", &Number::Plural), "children");
+}
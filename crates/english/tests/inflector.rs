@@ -0,0 +1,150 @@
+#![cfg(all(feature = "std", feature = "verbs"))]
+
+use english::*;
+
+const PLURAL: &Number = &Number::Plural;
+
+#[test]
+fn overrides_win_over_the_tables_and_rules() {
+    let mut inflector = Inflector::new();
+    inflector
+        .add_noun("dwarf", "dwarfs")
+        .add_noun("child", "childs")
+        .add_verb("dive", "dives", "dove", "diving", "dived")
+        .add_adjective("fun", "funner", "funnest");
+
+    assert_eq!(inflector.noun("dwarf", PLURAL), "dwarfs");
+    assert_eq!(inflector.noun("child", PLURAL), "childs");
+    assert_eq!(
+        inflector.verb(
+            "dive",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "dove"
+    );
+    assert_eq!(inflector.adj("fun", &Degree::Comparative), "funner");
+    assert_eq!(inflector.adj("fun", &Degree::Positive), "fun");
+}
+
+#[test]
+fn words_without_overrides_match_english() {
+    let inflector = Inflector::new();
+    for word in ["child", "die2", "attorney general", "Mouse", "cat"] {
+        assert_eq!(inflector.noun(word, PLURAL), English::noun(word, PLURAL));
+    }
+    assert_eq!(
+        inflector.verb(
+            "go",
+            &Person::First,
+            &Number::Plural,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "went"
+    );
+    assert_eq!(inflector.adj("good", &Degree::Superlative), "best");
+}
+
+#[test]
+fn overrides_keep_casing_and_reach_compound_heads() {
+    let mut inflector = Inflector::new();
+    inflector.add_noun("Dwarf", "Dwarfs");
+    assert_eq!(inflector.noun("DWARF", PLURAL), "DWARFS");
+    assert_eq!(inflector.noun("Dwarf", PLURAL), "Dwarfs");
+    assert_eq!(inflector.noun("dwarf2", PLURAL), "dwarfs");
+    assert_eq!(inflector.noun("mountain dwarf", PLURAL), "mountain dwarfs");
+    assert_eq!(inflector.noun("dwarf-in-chief", PLURAL), "dwarfs-in-chief");
+}
+
+#[test]
+fn csv_lexicons_use_the_extractor_format() {
+    let mut inflector = Inflector::new();
+    inflector
+        .load_csv("# house style\nword,plural\ndwarf,dwarfs\n\nroof , roofs\n")
+        .unwrap()
+        .load_csv(
+            "infinitive,third_person_singular,past,present_participle,past_participle\n\
+             sneak,sneaks,snuck,sneaking,snuck\n",
+        )
+        .unwrap()
+        .load_csv("positive,comparative,superlative\nfun,funner,funnest\n")
+        .unwrap();
+    assert_eq!(inflector.noun("roof", PLURAL), "roofs");
+    assert_eq!(
+        inflector.verb(
+            "sneak",
+            &Person::First,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Participle
+        ),
+        "snuck"
+    );
+    assert_eq!(inflector.adj("fun", &Degree::Superlative), "funnest");
+}
+
+#[test]
+fn malformed_csv_reports_the_line() {
+    let mut inflector = Inflector::new();
+    match inflector.load_csv("word,plural\ndwarf,dwarfs\nelf\n") {
+        Err(LexiconError::Csv { line, .. }) => assert_eq!(line, 3),
+        other => panic!("unexpected {other:?}"),
+    }
+    assert!(matches!(
+        inflector.load_csv("singular,plural\n"),
+        Err(LexiconError::Csv { line: 1, .. })
+    ));
+}
+
+#[test]
+fn lexicon_files_load_by_extension() {
+    let path = std::env::temp_dir().join(format!("english-lexicon-{}.csv", std::process::id()));
+    std::fs::write(&path, "word,plural\nhoof,hoofs\n").unwrap();
+    let mut inflector = Inflector::new();
+    inflector.load_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(inflector.noun("hoof", PLURAL), "hoofs");
+    assert!(matches!(
+        inflector.load_file(&path),
+        Err(LexiconError::Io(_))
+    ));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_lexicons_fill_every_table() {
+    let mut inflector = Inflector::new();
+    inflector
+        .load_toml(
+            r#"
+            [nouns]
+            dwarf = "dwarfs"
+
+            [verbs]
+            sneak = ["sneaks", "snuck", "sneaking", "snuck"]
+
+            [adjectives]
+            fun = ["funner", "funnest"]
+            "#,
+        )
+        .unwrap();
+    assert_eq!(inflector.noun("dwarf", PLURAL), "dwarfs");
+    assert_eq!(
+        inflector.verb(
+            "sneak",
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite
+        ),
+        "snuck"
+    );
+    assert_eq!(inflector.adj("fun", &Degree::Comparative), "funner");
+    assert!(matches!(
+        inflector.load_toml("[verbs]\nsneak = \"snuck\"\n"),
+        Err(LexiconError::Toml(_))
+    ));
+}
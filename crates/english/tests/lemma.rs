@@ -0,0 +1,66 @@
+#![cfg(all(feature = "alloc", feature = "nouns", feature = "verbs"))]
+
+use english::*;
+
+#[test]
+fn lemmas_hold_their_forms() {
+    let mouse = Noun::from("mouse");
+    assert_eq!(mouse.plural(), "mice");
+    assert_eq!(mouse.inflect(&Number::Plural), "mice");
+    let run = Verb::from(String::from("run"));
+    assert_eq!(run.past(), "ran");
+    assert_eq!(run.present_participle(), "running");
+    assert_eq!(run.past_participle(), "run");
+    assert_eq!(Adj::from("big").superlative(), "biggest");
+}
+
+#[test]
+fn lemmas_keep_casing_and_sense_keys() {
+    let dice = Noun::from("Die#dice");
+    assert_eq!(dice.as_str(), "Die#dice");
+    assert_eq!(dice.singular(), "Die");
+    assert_eq!(dice.plural(), "Dice");
+    let lie = Verb::from("lie2");
+    assert_eq!(lie.infinitive(), "lie");
+    assert_eq!(lie.past(), "lied");
+    assert_eq!(lie.to_string(), "lie2");
+}
+
+#[test]
+fn conjugate_matches_english_verb() {
+    let persons = [Person::First, Person::Second, Person::Third];
+    let numbers = [Number::Singular, Number::Plural];
+    let tenses = [Tense::Present, Tense::Past, Tense::Future];
+    let forms = [Form::Finite, Form::Participle, Form::Infinitive];
+    for word in ["be", "go", "have", "walk", "lie2", "Swim"] {
+        let verb = Verb::from(word);
+        for person in &persons {
+            for number in &numbers {
+                for tense in &tenses {
+                    for form in &forms {
+                        assert_eq!(
+                            verb.conjugate(person, number, tense, form),
+                            English::verb(word, person, number, tense, form),
+                            "{word} {person:?} {number:?} {tense:?} {form:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn lemmas_compare_by_key() {
+    assert_eq!(Noun::from("cat"), Noun::from(String::from("cat")));
+    assert_ne!(Noun::from("die"), Noun::from("die2"));
+    assert_eq!(String::from(Adj::from("red")), "red");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn lemmas_serialize_as_strings() {
+    let noun: Noun = serde_json::from_str("\"child\"").unwrap();
+    assert_eq!(noun.plural(), "children");
+    assert_eq!(serde_json::to_string(&noun).unwrap(), "\"child\"");
+}
@@ -0,0 +1,166 @@
+#![cfg(all(
+    feature = "std",
+    feature = "nouns",
+    feature = "verbs",
+    feature = "adjectives"
+))]
+
+use english::*;
+
+fn lemmas(analyses: &[Analysis]) -> Vec<(&str, Features)> {
+    analyses
+        .iter()
+        .map(|a| (a.lemma.as_str(), a.features))
+        .collect()
+}
+
+#[test]
+fn irregular_forms_invert_table_entries() {
+    let children = English::lemmatize_noun("children");
+    assert_eq!(children[0].lemma, "child");
+    assert_eq!(children[0].source, Source::Table);
+
+    let went = English::lemmatize_verb("went");
+    assert_eq!(went[0].lemma, "go");
+
+    let gone = English::lemmatize_verb("gone");
+    assert_eq!(
+        gone[0].features,
+        Features::Verb {
+            person: None,
+            number: None,
+            tense: Tense::Past,
+            form: Form::Participle
+        }
+    );
+
+    let worst = English::lemmatize_adj("worst");
+    assert_eq!(worst[0].key, "bad3");
+    assert_eq!(worst[0].features, Features::Adj(Degree::Superlative));
+}
+
+#[test]
+fn numbered_sense_keys_round_trip() {
+    for analysis in English::lemmatize("lied") {
+        if let Features::Verb {
+            person,
+            number,
+            tense,
+            form,
+        } = analysis.features
+        {
+            assert_eq!(
+                English::verb(
+                    &analysis.key,
+                    &person.unwrap_or(Person::First),
+                    &number.unwrap_or(Number::Plural),
+                    &tense,
+                    &form
+                ),
+                "lied"
+            );
+        }
+    }
+    assert!(
+        English::lemmatize_verb("lied")
+            .iter()
+            .any(|a| a.key == "lie2" && a.source == Source::Table)
+    );
+}
+
+#[test]
+fn regular_forms_fall_back_to_core_rules() {
+    let zorbed = English::lemmatize_verb("zorbed");
+    assert!(lemmas(&zorbed).contains(&(
+        "zorb",
+        Features::Verb {
+            person: None,
+            number: None,
+            tense: Tense::Past,
+            form: Form::Finite
+        }
+    )));
+
+    let zotting = English::lemmatize_verb("zotting");
+    assert_eq!(zotting[0].lemma, "zot");
+    let panicked = English::lemmatize_verb("zanicked");
+    assert_eq!(panicked[0].lemma, "zanic");
+    let tying = English::lemmatize_verb("zying");
+    assert!(tying.iter().any(|a| a.lemma == "zie"));
+
+    let boxes = English::lemmatize_noun("zorboxes");
+    assert_eq!(boxes[0].lemma, "zorbox");
+    assert_eq!(boxes[0].source, Source::Rule);
+
+    let ladies = English::lemmatize_noun("zorbladies");
+    assert_eq!(ladies[0].lemma, "zorblady");
+
+    let happiest = English::lemmatize_adj("zappiest");
+    assert_eq!(happiest[0].lemma, "zappy");
+    assert_eq!(happiest[0].features, Features::Adj(Degree::Superlative));
+}
+
+#[test]
+fn to_be_reports_agreement() {
+    let am = English::lemmatize_verb("am");
+    assert_eq!(am[0].lemma, "be");
+    assert_eq!(
+        am[0].features,
+        Features::Verb {
+            person: Some(Person::First),
+            number: Some(Number::Singular),
+            tense: Tense::Present,
+            form: Form::Finite
+        }
+    );
+    let were = English::lemmatize_verb("were");
+    assert_eq!(were.iter().filter(|a| a.lemma == "be").count(), 4);
+}
+
+#[test]
+fn combined_lemmatize_ranks_table_hits_first() {
+    let analyses = English::lemmatize("better");
+    assert_eq!(analyses[0].source, Source::Table);
+    assert!(
+        analyses
+            .windows(2)
+            .all(|pair| pair[0].source <= pair[1].source)
+    );
+}
+
+#[test]
+fn forms_of_be_rank_as_table_hits() {
+    let was = English::lemmatize("was");
+    assert_eq!(was[0].lemma, "be");
+    assert_eq!(was[0].source, Source::Table);
+}
+
+#[test]
+fn rule_readings_undo_the_whole_rule() {
+    for (word, lemma, junk) in [
+        ("running", "run", "runn"),
+        ("stopped", "stop", "stopp"),
+        ("boxes", "box", "boxe"),
+    ] {
+        let analyses = English::lemmatize(word);
+        assert_eq!(analyses[0].lemma, lemma);
+        assert!(
+            analyses
+                .iter()
+                .filter(|a| a.lemma == junk)
+                .all(|a| a.source == Source::Guess),
+            "{junk} ranked above a guess for {word}"
+        );
+    }
+}
+
+#[test]
+fn capitalized_words_keep_their_casing() {
+    let children = English::lemmatize("Children");
+    assert_eq!(children[0].lemma, "Child");
+    assert_eq!(children[0].source, Source::Table);
+    assert_eq!(English::noun(&children[0].key, &Number::Plural), "Children");
+
+    let went = English::lemmatize_verb("WENT");
+    assert_eq!(went[0].lemma, "GO");
+}
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "alloc", feature = "verbs"))]
+
+use english::*;
+
+fn finite(word: &str, person: Person, number: Number, tense: Tense, mood: Mood) -> String {
+    English::verb_mood(word, &person, &number, &tense, &Form::Finite, &mood)
+}
+
+#[test]
+fn subjunctive_uses_bare_form_and_were() {
+    use Mood::Subjunctive;
+    assert_eq!(
+        finite(
+            "be",
+            Person::Third,
+            Number::Singular,
+            Tense::Past,
+            Subjunctive
+        ),
+        "were"
+    );
+    assert_eq!(
+        finite(
+            "be",
+            Person::Third,
+            Number::Singular,
+            Tense::Present,
+            Subjunctive
+        ),
+        "be"
+    );
+    assert_eq!(
+        finite(
+            "go",
+            Person::Third,
+            Number::Singular,
+            Tense::Present,
+            Subjunctive
+        ),
+        "go"
+    );
+    assert_eq!(
+        finite(
+            "have",
+            Person::Third,
+            Number::Singular,
+            Tense::Present,
+            Subjunctive
+        ),
+        "have"
+    );
+    assert_eq!(
+        finite(
+            "eat",
+            Person::First,
+            Number::Singular,
+            Tense::Past,
+            Subjunctive
+        ),
+        "ate"
+    );
+    assert_eq!(
+        finite(
+            "die2",
+            Person::Third,
+            Number::Singular,
+            Tense::Present,
+            Subjunctive
+        ),
+        "die"
+    );
+}
+
+#[test]
+fn indicative_matches_verb() {
+    for word in ["be", "go", "walk", "lie2"] {
+        for tense in [Tense::Present, Tense::Past, Tense::Future] {
+            assert_eq!(
+                finite(
+                    word,
+                    Person::Third,
+                    Number::Singular,
+                    tense,
+                    Mood::Indicative
+                ),
+                English::verb(
+                    word,
+                    &Person::Third,
+                    &Number::Singular,
+                    &tense,
+                    &Form::Finite
+                )
+            );
+        }
+    }
+}
+
+#[test]
+fn imperative_commands() {
+    assert_eq!(
+        finite(
+            "be",
+            Person::Second,
+            Number::Plural,
+            Tense::Present,
+            Mood::Imperative
+        ),
+        "be"
+    );
+    assert_eq!(English::imperative("run", &Polarity::Positive), "run");
+    assert_eq!(English::imperative("be", &Polarity::Negative), "don't be");
+}
@@ -0,0 +1,86 @@
+#![cfg(all(feature = "alloc", feature = "nouns"))]
+
+use english::*;
+
+#[test]
+fn cardinals_spell_out_every_group() {
+    for (n, words) in [
+        (0, "zero"),
+        (13, "thirteen"),
+        (40, "forty"),
+        (99, "ninety-nine"),
+        (100, "one hundred"),
+        (105, "one hundred and five"),
+        (1_000, "one thousand"),
+        (1_005, "one thousand and five"),
+        (1_100, "one thousand one hundred"),
+        (
+            1_234_567,
+            "one million two hundred and thirty-four thousand five hundred and sixty-seven",
+        ),
+        (-7, "minus seven"),
+    ] {
+        assert_eq!(English::cardinal(n), words, "{n}");
+    }
+}
+
+#[test]
+fn cardinals_cover_the_full_u64_and_i64_range() {
+    assert_eq!(
+        English::cardinal(u64::MAX),
+        "eighteen quintillion four hundred and forty-six quadrillion seven hundred and forty-four \
+         trillion seventy-three billion seven hundred and nine million five hundred and fifty-one \
+         thousand six hundred and fifteen"
+    );
+    assert!(English::cardinal(i64::MIN).starts_with("minus nine quintillion"));
+}
+
+#[test]
+fn ordinals_change_only_the_last_word() {
+    for (n, words) in [
+        (1, "first"),
+        (2, "second"),
+        (3, "third"),
+        (5, "fifth"),
+        (8, "eighth"),
+        (9, "ninth"),
+        (12, "twelfth"),
+        (20, "twentieth"),
+        (21, "twenty-first"),
+        (100, "one hundredth"),
+        (1_000_003, "one million and third"),
+    ] {
+        assert_eq!(English::ordinal(n), words, "{n}");
+    }
+}
+
+#[test]
+fn ordinal_suffixes_handle_the_teens() {
+    for (n, digits) in [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (112, "112th"),
+        (1_001, "1001st"),
+        (-2, "-2nd"),
+    ] {
+        assert_eq!(English::ordinal_digits(n), digits, "{n}");
+    }
+}
+
+#[test]
+fn count_helpers_spell_numbers() {
+    assert_eq!(count_with_words("cat", 3u32), "three cats");
+    assert_eq!(count_with_words("cat", 0u32), "zero cats");
+    assert_eq!(count_with_words("die2", 1u32), "one die");
+    assert_eq!(count_with_words("child", 21u64), "twenty-one children");
+    assert_eq!(count_with_dozens("egg", 12u32), "a dozen eggs");
+    assert_eq!(count_with_dozens("egg", 6u32), "half a dozen eggs");
+    assert_eq!(count_with_dozens("egg", 24u32), "two dozen eggs");
+    assert_eq!(count_ordinal("place", 3u32), "3rd place");
+}
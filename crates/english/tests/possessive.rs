@@ -0,0 +1,166 @@
+#![cfg(all(feature = "std", feature = "nouns"))]
+
+use english::*;
+
+const CHICAGO: &PossessiveStyle = &PossessiveStyle::Chicago;
+const AP: &PossessiveStyle = &PossessiveStyle::AssociatedPress;
+
+#[test]
+fn singular_heads_take_apostrophe_s() {
+    assert_eq!(
+        English::possessive("dog", &Number::Singular, CHICAGO),
+        "dog's"
+    );
+    assert_eq!(
+        English::possessive("boss", &Number::Singular, CHICAGO),
+        "boss's"
+    );
+    assert_eq!(English::possessive("boss", &Number::Singular, AP), "boss's");
+    assert_eq!(English::possessive("bus", &Number::Singular, AP), "bus's");
+}
+
+#[test]
+fn plural_heads_depend_on_their_ending() {
+    assert_eq!(
+        English::possessive("dog", &Number::Plural, CHICAGO),
+        "dogs'"
+    );
+    assert_eq!(
+        English::possessive("boss", &Number::Plural, CHICAGO),
+        "bosses'"
+    );
+    assert_eq!(
+        English::possessive("child", &Number::Plural, CHICAGO),
+        "children's"
+    );
+    assert_eq!(English::possessive("mouse", &Number::Plural, AP), "mice's");
+    assert_eq!(
+        English::possessive("Child", &Number::Plural, CHICAGO),
+        "Children's"
+    );
+    assert_eq!(English::possessive("MOUSE", &Number::Plural, AP), "MICE's");
+    assert_eq!(
+        English::possessive("mother-in-law", &Number::Plural, CHICAGO),
+        "mothers-in-law's"
+    );
+}
+
+#[test]
+fn names_ending_in_s_follow_the_style() {
+    assert_eq!(
+        English::possessive("James", &Number::Singular, CHICAGO),
+        "James's"
+    );
+    assert_eq!(
+        English::possessive("James", &Number::Singular, AP),
+        "James'"
+    );
+    assert_eq!(English::possessive("Mary", &Number::Singular, AP), "Mary's");
+    assert_eq!(
+        English::possessive("Jones", &Number::Plural, AP),
+        "Joneses'"
+    );
+    assert_eq!(
+        English::add_possessive(&English::proper_noun("Kennedy", &Number::Plural)),
+        "Kennedys'"
+    );
+}
+
+#[test]
+fn auto_routes_capitalized_heads_to_the_proper_noun_rules() {
+    assert_eq!(
+        English::possessive_auto("Kennedy", &Number::Plural, CHICAGO),
+        "Kennedys'"
+    );
+    assert_eq!(
+        English::possessive_auto("Mary", &Number::Plural, AP),
+        "Marys'"
+    );
+    assert_eq!(
+        English::possessive_auto("Jones", &Number::Plural, CHICAGO),
+        "Joneses'"
+    );
+    assert_eq!(
+        English::possessive_auto("Child", &Number::Plural, CHICAGO),
+        "Children's"
+    );
+    assert_eq!(
+        English::possessive_auto("city", &Number::Plural, CHICAGO),
+        "cities'"
+    );
+    assert_eq!(
+        English::possessive_auto("she", &Number::Singular, CHICAGO),
+        "her"
+    );
+}
+
+#[test]
+fn capitalized_common_nouns_follow_the_noun_rules() {
+    assert_eq!(
+        English::possessive("City", &Number::Plural, CHICAGO),
+        "Cities'"
+    );
+    assert_eq!(
+        English::possessive("Box", &Number::Plural, CHICAGO),
+        "Boxes'"
+    );
+    assert_eq!(
+        English::possessive("Child", &Number::Plural, AP),
+        "Children's"
+    );
+}
+
+#[test]
+fn acronyms_are_not_pronouns() {
+    assert_eq!(
+        English::possessive("US", &Number::Singular, CHICAGO),
+        "US's"
+    );
+    assert_eq!(English::possessive("IT", &Number::Singular, AP), "IT's");
+    assert_eq!(English::possessive("HIM", &Number::Singular, AP), "HIM's");
+}
+
+#[test]
+fn pronouns_become_possessive_determiners() {
+    for (pronoun, possessive) in [
+        ("I", "my"),
+        ("me", "my"),
+        ("you", "your"),
+        ("he", "his"),
+        ("her", "her"),
+        ("it", "its"),
+        ("we", "our"),
+        ("them", "their"),
+    ] {
+        assert_eq!(
+            English::possessive(pronoun, &Number::Singular, CHICAGO),
+            possessive,
+            "{pronoun}"
+        );
+    }
+}
+
+#[test]
+fn who_and_registered_sets_take_their_determiner() {
+    assert_eq!(
+        English::possessive("who", &Number::Singular, CHICAGO),
+        "whose"
+    );
+    assert_eq!(
+        English::possessive("whom", &Number::Singular, CHICAGO),
+        "whose"
+    );
+    PronounSet::new("ze", "hir", "hirself", "hirs", "hir", Number::Singular).register();
+    assert_eq!(English::possessive("ze", &Number::Singular, CHICAGO), "hir");
+    assert_eq!(
+        English::possessive("hir", &Number::Singular, CHICAGO),
+        "hir"
+    );
+}
+
+#[test]
+fn add_possessive_keeps_singular_sibilants() {
+    assert_eq!(English::add_possessive("boss"), "boss's");
+    assert_eq!(English::add_possessive("dogs"), "dogs'");
+    assert_eq!(English::add_possessive("children"), "children's");
+}
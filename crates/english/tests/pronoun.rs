@@ -0,0 +1,161 @@
+#![cfg(feature = "alloc")]
+
+use english::*;
+
+const CASES: [Case; 5] = [
+    Case::Nominative,
+    Case::Accusative,
+    Case::Reflexive,
+    Case::Possessive,
+    Case::PersonalPossessive,
+];
+
+fn forms(pronoun: Pronoun) -> Vec<Option<&'static str>> {
+    CASES
+        .iter()
+        .map(|case| English::pronoun_form(&pronoun, case))
+        .collect()
+}
+
+#[test]
+fn singular_they_and_generic_one() {
+    assert_eq!(
+        forms(Pronoun::SingularThey),
+        [
+            Some("they"),
+            Some("them"),
+            Some("themself"),
+            Some("theirs"),
+            Some("their")
+        ]
+    );
+    assert_eq!(
+        forms(Pronoun::Generic),
+        [
+            Some("one"),
+            Some("one"),
+            Some("oneself"),
+            Some("one's"),
+            Some("one's")
+        ]
+    );
+}
+
+#[test]
+fn personal_pronouns_match_the_old_api() {
+    let pronoun = Pronoun::Personal(Person::Third, Number::Plural, Gender::Neuter);
+    assert_eq!(
+        English::pronoun_form(&pronoun, &Case::Reflexive),
+        Some("themselves")
+    );
+    assert_eq!(
+        English::pronoun_form(&pronoun, &Case::PersonalPossessive),
+        Some(English::pronoun(
+            &Person::Third,
+            &Number::Plural,
+            &Gender::Neuter,
+            &Case::PersonalPossessive
+        ))
+    );
+}
+
+#[test]
+fn demonstratives_interrogatives_and_relatives() {
+    assert_eq!(
+        forms(Pronoun::Demonstrative(Proximity::Near, Number::Singular)),
+        [Some("this"), Some("this"), None, None, None]
+    );
+    assert_eq!(
+        forms(Pronoun::Demonstrative(Proximity::Near, Number::Plural))[0],
+        Some("these")
+    );
+    assert_eq!(
+        forms(Pronoun::Interrogative(Interrogative::Who)),
+        [
+            Some("who"),
+            Some("whom"),
+            None,
+            Some("whose"),
+            Some("whose")
+        ]
+    );
+    assert_eq!(
+        forms(Pronoun::Interrogative(Interrogative::Which))[1],
+        Some("which")
+    );
+    assert_eq!(
+        forms(Pronoun::Relative(Relative::Which)),
+        [Some("which"), Some("which"), None, None, Some("whose")]
+    );
+    assert_eq!(
+        forms(Pronoun::Relative(Relative::That)),
+        [Some("that"), Some("that"), None, None, None]
+    );
+}
+
+#[test]
+fn indefinites_and_reciprocals() {
+    assert_eq!(
+        forms(Pronoun::Indefinite(Quantifier::Some, Referent::One)),
+        [
+            Some("someone"),
+            Some("someone"),
+            None,
+            Some("someone's"),
+            Some("someone's")
+        ]
+    );
+    assert_eq!(
+        forms(Pronoun::Indefinite(Quantifier::Any, Referent::Body))[0],
+        Some("anybody")
+    );
+    assert_eq!(
+        forms(Pronoun::Indefinite(Quantifier::No, Referent::Thing))[0],
+        Some("nothing")
+    );
+    assert_eq!(
+        forms(Pronoun::Reciprocal(Reciprocal::EachOther)),
+        [
+            None,
+            Some("each other"),
+            None,
+            Some("each other's"),
+            Some("each other's")
+        ]
+    );
+}
+
+#[test]
+fn agreement() {
+    assert_eq!(
+        English::pronoun_agreement(&Pronoun::SingularThey),
+        (Person::Third, Number::Plural)
+    );
+    assert_eq!(
+        English::pronoun_agreement(&Pronoun::Indefinite(Quantifier::Every, Referent::Body)),
+        (Person::Third, Number::Singular)
+    );
+    assert_eq!(
+        English::pronoun_agreement(&Pronoun::Personal(
+            Person::First,
+            Number::Singular,
+            Gender::Neuter
+        )),
+        (Person::First, Number::Singular)
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn misspelled_case_still_resolves() {
+    assert_eq!(Case::PersonalPossesive, Case::PersonalPossessive);
+    assert_eq!(
+        English::pronoun(
+            &Person::First,
+            &Number::Singular,
+            &Gender::Neuter,
+            &Case::PersonalPossesive
+        ),
+        "my"
+    );
+}
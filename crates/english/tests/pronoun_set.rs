@@ -0,0 +1,105 @@
+#![cfg(feature = "std")]
+
+use english::*;
+
+const THIRD: &Person = &Person::Third;
+const SINGULAR: &Number = &Number::Singular;
+
+#[test]
+fn registered_sets_supply_every_case() {
+    let ze = PronounSet::new("ze", "hir", "hirself", "hirs", "hir", Number::Singular).register();
+    let forms: Vec<&str> = [
+        Case::Nominative,
+        Case::Accusative,
+        Case::Reflexive,
+        Case::Possessive,
+        Case::PersonalPossessive,
+    ]
+    .iter()
+    .map(|case| English::pronoun(THIRD, SINGULAR, &ze, case))
+    .collect();
+    assert_eq!(forms, ["ze", "hir", "hirself", "hirs", "hir"]);
+    assert_eq!(
+        English::pronoun_form(
+            &Pronoun::Personal(Person::Third, Number::Singular, ze),
+            &Case::Reflexive
+        ),
+        Some("hirself")
+    );
+}
+
+#[test]
+fn registering_twice_returns_the_same_gender() {
+    let a = PronounSet::new("ey", "em", "emself", "eirs", "eir", Number::Singular).register();
+    let b = PronounSet::new("ey", "em", "emself", "eirs", "eir", Number::Singular).register();
+    assert_eq!(a, b);
+    assert_eq!(
+        PronounSet::lookup(&a).map(|set| set.form(&Case::Nominative)),
+        Some("ey")
+    );
+}
+
+#[test]
+fn agreement_follows_the_set() {
+    let they = PronounSet::they_them().register();
+    let xe = PronounSet::new("xe", "xem", "xemself", "xyrs", "xyr", Number::Singular).register();
+    assert_eq!(
+        English::subject_auxiliary(
+            THIRD,
+            SINGULAR,
+            &they,
+            "be",
+            &Tense::Present,
+            &Register::Formal
+        ),
+        "they are"
+    );
+    assert_eq!(
+        English::subject_auxiliary(
+            THIRD,
+            SINGULAR,
+            &xe,
+            "be",
+            &Tense::Present,
+            &Register::Contracted
+        ),
+        "xe's"
+    );
+    assert_eq!(
+        English::negative_question(
+            "be",
+            THIRD,
+            SINGULAR,
+            &they,
+            &Tense::Past,
+            &Register::Contracted
+        ),
+        "weren't they"
+    );
+}
+
+#[test]
+fn sets_only_apply_to_the_third_person_singular() {
+    let xe = PronounSet::new("xe", "xem", "xemself", "xyrs", "xyr", Number::Singular).register();
+    assert_eq!(
+        English::pronoun(THIRD, &Number::Plural, &xe, &Case::Nominative),
+        "they"
+    );
+    assert_eq!(
+        English::pronoun(&Person::First, SINGULAR, &xe, &Case::Nominative),
+        "I"
+    );
+}
+
+#[test]
+fn unregistered_custom_genders_fall_back_to_singular_they() {
+    let unknown = Gender::Custom(u16::MAX);
+    assert_eq!(
+        English::pronoun(THIRD, SINGULAR, &unknown, &Case::Reflexive),
+        "themself"
+    );
+    assert_eq!(
+        English::subject_agreement(THIRD, SINGULAR, &unknown),
+        (Person::Third, Number::Plural)
+    );
+}
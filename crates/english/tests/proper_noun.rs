@@ -0,0 +1,43 @@
+#![cfg(all(feature = "alloc", feature = "nouns"))]
+
+use english::*;
+
+#[test]
+fn surnames_take_plain_plurals() {
+    for (name, plural) in [
+        ("Jones", "Joneses"),
+        ("Kennedy", "Kennedys"),
+        ("Mary", "Marys"),
+        ("Mann", "Manns"),
+        ("Wolf", "Wolfs"),
+        ("Marx", "Marxes"),
+        ("Lopez", "Lopezes"),
+        ("Church", "Churches"),
+        ("Bush", "Bushes"),
+        ("Child", "Childs"),
+        ("McCarthy", "McCarthys"),
+        ("JONES", "JONESES"),
+    ] {
+        assert_eq!(
+            English::proper_noun(name, &Number::Plural),
+            plural,
+            "{name}"
+        );
+        assert_eq!(English::proper_noun(name, &Number::Singular), name);
+    }
+}
+
+#[test]
+fn auto_routing_uses_the_first_letter() {
+    assert_eq!(English::noun_auto("Kennedy", &Number::Plural), "Kennedys");
+    assert_eq!(English::noun_auto("Mann", &Number::Plural), "Manns");
+    assert_eq!(English::noun_auto("man", &Number::Plural), "men");
+    assert_eq!(English::noun_auto("baby", &Number::Plural), "babies");
+    // Irregular common nouns stay common whatever their casing.
+    assert_eq!(English::noun_auto("Child", &Number::Plural), "Children");
+    assert_eq!(English::noun_auto("MOUSE", &Number::Plural), "MICE");
+    assert_eq!(English::noun_auto("Foot", &Number::Plural), "Feet");
+    assert_eq!(English::noun_auto("JONES", &Number::Plural), "JONESES");
+    // The casing-preserving common noun path is unaffected.
+    assert_eq!(English::noun("Child", &Number::Plural), "Children");
+}
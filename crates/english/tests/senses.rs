@@ -0,0 +1,89 @@
+#![cfg(all(feature = "alloc", feature = "nouns", feature = "verbs"))]
+
+use english::*;
+
+fn past(word: &str) -> String {
+    English::verb(
+        word,
+        &Person::Third,
+        &Number::Singular,
+        &Tense::Past,
+        &Form::Finite,
+    )
+}
+
+#[test]
+fn senses_pair_stable_keys_with_glosses() {
+    let lie: Vec<(String, String, PartOfSpeech)> = English::senses("lie")
+        .into_iter()
+        .filter(|sense| sense.part_of_speech == PartOfSpeech::Verb)
+        .map(|sense| (sense.key, sense.numbered_key, sense.part_of_speech))
+        .collect();
+    assert_eq!(
+        lie,
+        [
+            ("lie#rest".into(), "lie".into(), PartOfSpeech::Verb),
+            ("lie#false".into(), "lie2".into(), PartOfSpeech::Verb),
+        ]
+    );
+    let senses = English::senses("lie");
+    let lied = senses
+        .iter()
+        .find(|sense| sense.key == "lie#false")
+        .unwrap();
+    assert_eq!(lied.gloss, Some("To give false information intentionally."));
+}
+
+#[test]
+fn every_reported_key_selects_its_sense() {
+    for word in ["die", "lie", "octopus", "cactus", "shine", "person"] {
+        for sense in English::senses(word) {
+            match sense.part_of_speech {
+                PartOfSpeech::Noun => assert_eq!(
+                    English::noun(&sense.key, &Number::Plural),
+                    English::noun(&sense.numbered_key, &Number::Plural),
+                    "{}",
+                    sense.key
+                ),
+                PartOfSpeech::Verb => {
+                    assert_eq!(past(&sense.key), past(&sense.numbered_key), "{}", sense.key)
+                }
+                PartOfSpeech::Adjective => assert_eq!(
+                    English::adj(&sense.key, &Degree::Comparative),
+                    English::adj(&sense.numbered_key, &Degree::Comparative)
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn forms_name_senses_too() {
+    assert_eq!(English::noun("die#dice", &Number::Plural), "dice");
+    assert_eq!(English::noun("die#dies", &Number::Plural), "dies");
+    assert_eq!(English::noun("octopus#octopi", &Number::Plural), "octopi");
+    assert_eq!(past("lie#lain"), "lay");
+    assert_eq!(past("lie#lied"), "lied");
+    assert_eq!(past("shine#shone"), "shone");
+    assert_eq!(English::noun("Die#Dice", &Number::Plural), "Dice");
+}
+
+#[test]
+fn unknown_labels_fall_back_to_the_lemma() {
+    assert_eq!(English::noun("die#nonsense", &Number::Plural), "dies");
+    assert_eq!(English::noun("die#typo", &Number::Plural), "dies");
+    // A gloss label names its sense only through the generated table, not
+    // through any other word of the gloss.
+    assert_eq!(English::noun("die#games", &Number::Plural), "dies");
+    assert_eq!(English::noun("die#polyhedron", &Number::Plural), "dice");
+    assert_eq!(English::noun("cat#pet", &Number::Plural), "cats");
+    assert_eq!(English::noun("die#dice", &Number::Singular), "die");
+    assert_eq!(past("walk#stroll"), "walked");
+}
+
+#[test]
+fn single_sense_words_use_the_lemma() {
+    let go = English::senses("go");
+    assert!(go.iter().all(|sense| sense.key == "go"));
+    assert!(English::senses("window").is_empty());
+}
@@ -1,3 +1,10 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "nouns",
+    feature = "verbs",
+    feature = "adjectives"
+))]
+
 use english::*;
 
 #[test]
@@ -57,7 +64,7 @@ fn base_surface_helpers_work() {
     assert_eq!(English::noun("cat2", &Number::Singular), "cat");
     assert_eq!(count("man", 2), "men");
     assert_eq!(count_with_number("nickel", 3), "3 nickels");
-    assert_eq!(English::adj("bad", &Degree::Comparative), "more bad");
+    assert_eq!(English::adj("bad", &Degree::Comparative), "worse");
     assert_eq!(English::adj("fun", &Degree::Comparative), "more fun");
     assert_eq!(English::adj("bad2", &Degree::Comparative), "badder");
     assert_eq!(English::adj("bad3", &Degree::Positive), "bad");
@@ -0,0 +1,80 @@
+#![cfg(all(feature = "alloc", feature = "nouns", feature = "verbs"))]
+
+use english::*;
+
+#[test]
+fn every_attested_plural_is_listed_with_its_key() {
+    let variants = English::noun_variants("cactus");
+    let listed: Vec<(&str, &str)> = variants
+        .iter()
+        .map(|variant| (variant.key.as_str(), variant.forms.as_str()))
+        .collect();
+    assert_eq!(
+        listed,
+        [
+            ("cactus", "cactuses"),
+            ("cactus2", "cacti"),
+            ("cactus3", "cactus"),
+            ("cactus4", "cactusses"),
+        ]
+    );
+    for variant in &variants {
+        assert_eq!(English::noun(&variant.key, &Number::Plural), variant.forms);
+        assert_eq!(variant.source, Source::Table);
+    }
+}
+
+#[test]
+fn variants_carry_notes() {
+    let die = English::noun_variants("die");
+    assert!(die[0].note.unwrap().contains("cutting"));
+    assert!(die[1].note.unwrap().contains("games"));
+    let penny = English::noun_variants("penny");
+    assert_eq!(penny[1].forms, "pence");
+    assert_eq!(penny[1].note, Some("UK"));
+    assert_eq!(English::noun_variants("cactus")[1].note, None);
+}
+
+#[test]
+fn regular_words_have_one_predicted_variant() {
+    let cat = English::noun_variants("cat");
+    assert_eq!(cat.len(), 1);
+    assert_eq!(cat[0].forms, "cats");
+    assert_eq!(cat[0].source, Source::Rule);
+    let walk = English::verb_variants("walk");
+    assert_eq!(walk.len(), 1);
+    assert_eq!(walk[0].forms.past, "walked");
+}
+
+#[test]
+fn verb_variants_list_principal_parts() {
+    let lie = English::verb_variants("lie");
+    assert_eq!(
+        lie[0].forms,
+        VerbForms {
+            third_person_singular: "lies".into(),
+            past: "lay".into(),
+            present_participle: "lying".into(),
+            past_participle: "lain".into(),
+        }
+    );
+    assert_eq!(lie[1].forms.past_participle, "lied");
+    assert!(lie[1].note.unwrap().contains("false"));
+
+    let shine = English::verb_variants("shine");
+    assert_eq!(shine[0].forms.past, "shined");
+    assert_eq!(shine[1].forms.past, "shone");
+}
+
+#[test]
+fn variants_keep_casing_and_ignore_sense_numbers() {
+    let octopus = English::noun_variants("Octopus2");
+    assert_eq!(octopus[0].key, "octopus");
+    assert_eq!(octopus[1].forms, "Octopi");
+}
+
+#[test]
+fn multi_digit_sense_numbers_are_stripped() {
+    assert_eq!(English::noun("die12", &Number::Singular), "die");
+    assert_eq!(English::noun("cat10", &Number::Plural), "cats");
+}
@@ -0,0 +1,115 @@
+#![cfg(all(feature = "alloc", feature = "verbs"))]
+
+use english::*;
+
+fn phrase(
+    word: &str,
+    person: Person,
+    number: Number,
+    tense: Tense,
+    aspect: Aspect,
+    voice: Voice,
+    polarity: Polarity,
+) -> String {
+    English::verb_phrase(word, &person, &number, &tense, &aspect, &voice, &polarity)
+}
+
+#[test]
+fn first_auxiliary_agrees_with_subject() {
+    use Aspect::*;
+    use Number::*;
+    use Person::*;
+    use Tense::*;
+    use Voice::*;
+    let pos = Polarity::Positive;
+
+    assert_eq!(
+        phrase("eat", First, Singular, Present, Progressive, Active, pos),
+        "am eating"
+    );
+    assert_eq!(
+        phrase("eat", Third, Plural, Present, Progressive, Active, pos),
+        "are eating"
+    );
+    assert_eq!(
+        phrase("eat", Third, Singular, Present, Perfect, Active, pos),
+        "has eaten"
+    );
+    assert_eq!(
+        phrase("eat", First, Plural, Present, Perfect, Active, pos),
+        "have eaten"
+    );
+    assert_eq!(
+        phrase("eat", First, Singular, Past, Progressive, Active, pos),
+        "was eating"
+    );
+    assert_eq!(
+        phrase("eat", Second, Singular, Past, Simple, Passive, pos),
+        "were eaten"
+    );
+    assert_eq!(
+        phrase("go", Third, Singular, Present, Simple, Active, pos),
+        "goes"
+    );
+    assert_eq!(
+        phrase("go", Third, Singular, Future, Simple, Active, pos),
+        "will go"
+    );
+    assert_eq!(
+        phrase(
+            "eat",
+            Third,
+            Singular,
+            Past,
+            PerfectProgressive,
+            Passive,
+            pos
+        ),
+        "had been being eaten"
+    );
+}
+
+#[test]
+fn negation_goes_after_first_auxiliary() {
+    use Aspect::*;
+    use Number::*;
+    use Person::*;
+    use Tense::*;
+    use Voice::*;
+    let neg = Polarity::Negative;
+
+    assert_eq!(
+        phrase("run", First, Singular, Present, Simple, Active, neg),
+        "do not run"
+    );
+    assert_eq!(
+        phrase("run", Third, Singular, Past, Simple, Active, neg),
+        "did not run"
+    );
+    assert_eq!(
+        phrase("have", Third, Singular, Present, Simple, Active, neg),
+        "does not have"
+    );
+    assert_eq!(
+        phrase("be", Third, Singular, Present, Simple, Active, neg),
+        "is not"
+    );
+    assert_eq!(
+        phrase("run", Third, Singular, Present, Perfect, Active, neg),
+        "has not run"
+    );
+    assert_eq!(
+        phrase("see", First, Plural, Future, Perfect, Passive, neg),
+        "will not have been seen"
+    );
+    assert_eq!(
+        English::modal_phrase(
+            &Modal::Should,
+            "go",
+            &Aspect::Progressive,
+            &Voice::Active,
+            &neg
+        ),
+        "should not be going"
+    );
+}
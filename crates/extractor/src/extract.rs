@@ -1,8 +1,9 @@
 use crate::helpers::{
-    AdjParts, Entry, VerbParts, base_setup, contains_bad_tag, entry_is_proper, suffix_rule,
-    word_is_proper,
+    AdjParts, DIALECTS, Entry, Forms, VerbParts, base_setup, compound_entry_is_proper,
+    compound_is_proper, contains_bad_tag, dialects_from_tags, drop_fallback_rows, entry_gloss,
+    entry_is_proper, regional_forms, suffix_rule, variant_note, word_is_proper,
 };
-use csv::{ReaderBuilder, WriterBuilder};
+use csv::{ReaderBuilder, Writer, WriterBuilder};
 use english_core::*;
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
@@ -10,16 +11,28 @@ use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 
+/// Writes every plural the rules do not predict, numbering the senses of
+/// words with several (`"die2"`), and a note for each sense key to
+/// `notes_path`.
+///
+/// Plurals Wiktionary tags rare, archaic, nonstandard and the like are
+/// kept, numbered after the untagged ones and noted with their tags, so
+/// that they never become the first sense.
 pub fn extract_irregular_nouns(
     input_path: impl AsRef<Path>,
     output_path: impl AsRef<Path>,
+    notes_path: impl AsRef<Path>,
 ) -> Result<(), Box<dyn Error>> {
     let input_path = input_path.as_ref();
     let output_path = output_path.as_ref();
-    let mut forms_map: HashMap<String, HashSet<String>> = HashMap::new();
+    // Plural => whether every listing of it carries one of the `BAD_TAGS`.
+    let mut forms_map: HashMap<String, HashMap<String, bool>> = HashMap::new();
+    let mut notes: HashMap<(String, String), String> = HashMap::new();
 
     let (reader, mut writer) = base_setup(input_path, output_path);
-    writer.write_record(&["word", "plural"])?;
+    writer.write_record(["word", "plural"])?;
+    let mut notes_writer = Writer::from_path(notes_path.as_ref())?;
+    notes_writer.write_record(["key", "note"])?;
 
     for line in reader.lines() {
         let line = line?;
@@ -31,12 +44,13 @@ pub fn extract_irregular_nouns(
             }
         };
 
-        if !entry_is_proper(&entry, "noun") {
+        if !compound_entry_is_proper(&entry, "noun") {
             continue;
         }
 
         let infinitive = entry.word.to_lowercase();
         forms_map.entry(infinitive.clone()).or_default();
+        let gloss = entry_gloss(&entry);
 
         if let Some(forms) = entry.forms {
             for form in &forms {
@@ -45,15 +59,26 @@ pub fn extract_irregular_nouns(
                 if entry_form == "dubious" {
                     continue;
                 }
-                if !word_is_proper(&entry_form) || contains_bad_tag(tags.clone()) {
+                if !compound_is_proper(&entry_form) || !tags.contains(&"plural".into()) {
                     continue;
                 }
 
-                if tags.contains(&"plural".into()) {
-                    forms_map
-                        .get_mut(&infinitive)
-                        .expect("noun entry should exist")
-                        .insert(entry_form.clone());
+                let tagged = contains_bad_tag(tags.clone());
+                let was_tagged = forms_map
+                    .get_mut(&infinitive)
+                    .expect("noun entry should exist")
+                    .entry(entry_form.clone())
+                    .or_insert(tagged);
+                // An untagged listing outweighs tagged ones, note included.
+                let replaces_note = *was_tagged && !tagged;
+                *was_tagged &= tagged;
+                if let Some(note) = variant_note(tags, gloss.as_deref()) {
+                    let key = (infinitive.clone(), entry_form.clone());
+                    if replaces_note {
+                        notes.insert(key, note);
+                    } else {
+                        notes.entry(key).or_insert(note);
+                    }
                 }
             }
         }
@@ -65,28 +90,40 @@ pub fn extract_irregular_nouns(
             continue;
         }
 
-        let mut index = if forms.remove(&predicted_plural) {
+        let first_index = if forms.remove(&predicted_plural).is_some() {
+            // The predicted plural is the first sense and needs no entry,
+            // but it still gets a note.
+            if let Some(note) = notes.get(&(infinitive.clone(), predicted_plural.clone())) {
+                notes_writer.write_record([infinitive.as_str(), note])?;
+            }
+            2
+        } else if forms.values().all(|tagged| *tagged) {
+            // Only tagged plurals are listed: the rules still give the first.
             2
         } else {
             1
         };
-        let mut sorted_forms: Vec<String> = forms.clone().into_iter().collect();
+        let mut sorted_forms: Vec<(bool, String)> = forms
+            .iter()
+            .map(|(form, tagged)| (*tagged, form.clone()))
+            .collect();
         sorted_forms.sort();
 
-        for form in &sorted_forms {
+        for (index, (_, form)) in (first_index..).zip(&sorted_forms) {
             let word_key = if index == 1 {
                 infinitive.clone()
             } else {
                 format!("{infinitive}{index}")
             };
-            if index < 10 {
-                writer.write_record(&[word_key, form.clone()])?;
+            if let Some(note) = notes.get(&(infinitive.clone(), form.clone())) {
+                notes_writer.write_record([word_key.as_str(), note])?;
             }
-            index += 1;
+            writer.write_record([word_key, form.clone()])?;
         }
     }
 
     writer.flush()?;
+    notes_writer.flush()?;
     println!("Done! Output written to {}", output_path.display());
     Ok(())
 }
@@ -99,7 +136,7 @@ pub fn extract_irregular_adjectives(
     let output_path = output_path.as_ref();
     let mut forms_map: HashMap<String, HashSet<AdjParts>> = HashMap::new();
     let (reader, mut writer) = base_setup(input_path, output_path);
-    writer.write_record(&["positive", "comparative", "superlative"])?;
+    writer.write_record(["positive", "comparative", "superlative"])?;
 
     for line in reader.lines() {
         let line = line?;
@@ -167,18 +204,17 @@ pub fn extract_irregular_adjectives(
             continue;
         }
 
-        let mut index = if forms.remove(&predicted) { 2 } else { 1 };
+        let first_index = if forms.remove(&predicted) { 2 } else { 1 };
         let mut sorted_forms: Vec<AdjParts> = forms.clone().into_iter().collect();
         sorted_forms.sort();
 
-        for form in &sorted_forms {
+        for (index, form) in (first_index..).zip(&sorted_forms) {
             let word_key = if index == 1 {
                 infinitive.clone()
             } else {
                 format!("{infinitive}{index}")
             };
-            writer.write_record(&[word_key, form.comparative.clone(), form.superlative.clone()])?;
-            index += 1;
+            writer.write_record([word_key, form.comparative.clone(), form.superlative.clone()])?;
         }
     }
 
@@ -187,21 +223,104 @@ pub fn extract_irregular_adjectives(
     Ok(())
 }
 
+/// Principal parts of `infinitive` read from `forms`, the first matching
+/// form filling each slot and [`EnglishCore`] predicting the ones left
+/// empty. `None` if no third person singular form is attested.
+fn verb_parts<'a>(
+    infinitive: &str,
+    forms: impl IntoIterator<Item = &'a Forms>,
+) -> Option<VerbParts> {
+    let mut has_third_person = false;
+    let mut verb = VerbParts {
+        inf: infinitive.to_owned(),
+        ..VerbParts::default()
+    };
+
+    for form in forms {
+        let tags = &form.tags;
+        let entry_form = form.form.to_lowercase();
+        if !word_is_proper(&entry_form) || contains_bad_tag(tags.clone()) {
+            continue;
+        }
+
+        if tags.contains(&"third-person".into())
+            && tags.contains(&"singular".into())
+            && tags.contains(&"present".into())
+            && !has_third_person
+        {
+            has_third_person = true;
+            verb.third = entry_form.clone();
+        }
+
+        if tags.contains(&"past".into())
+            && !tags.contains(&"participle".into())
+            && verb.past.is_empty()
+        {
+            verb.past = entry_form.clone();
+        }
+
+        if tags.contains(&"participle".into())
+            && tags.contains(&"present".into())
+            && verb.present_part.is_empty()
+        {
+            verb.present_part = entry_form.clone();
+        }
+
+        if tags.contains(&"participle".into())
+            && tags.contains(&"past".into())
+            && verb.past_part.is_empty()
+        {
+            verb.past_part = entry_form.clone();
+        }
+    }
+
+    if verb.past.is_empty() {
+        verb.past = EnglishCore::verb(
+            infinitive,
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Past,
+            &Form::Finite,
+        );
+    }
+    if verb.past_part.is_empty() {
+        verb.past_part = verb.past.clone();
+    }
+    if verb.present_part.is_empty() {
+        verb.present_part = EnglishCore::verb(
+            infinitive,
+            &Person::Third,
+            &Number::Singular,
+            &Tense::Present,
+            &Form::Participle,
+        );
+    }
+
+    has_third_person.then_some(verb)
+}
+
+/// Writes the principal parts of every verb the rules do not predict,
+/// numbering the senses of verbs with several (`"lie2"`), and the gloss of
+/// each sense key to `notes_path`.
 pub fn extract_verb_conjugations(
     input_path: impl AsRef<Path>,
     output_path: impl AsRef<Path>,
+    notes_path: impl AsRef<Path>,
 ) -> Result<(), Box<dyn Error>> {
     let input_path = input_path.as_ref();
     let output_path = output_path.as_ref();
     let mut forms_map: HashMap<String, HashSet<VerbParts>> = HashMap::new();
+    let mut notes: HashMap<VerbParts, String> = HashMap::new();
     let (reader, mut writer) = base_setup(input_path, output_path);
-    writer.write_record(&[
+    writer.write_record([
         "infinitive",
         "third_person_singular",
         "past",
         "present_participle",
         "past_participle",
     ])?;
+    let mut notes_writer = Writer::from_path(notes_path.as_ref())?;
+    notes_writer.write_record(["key", "note"])?;
 
     for line in reader.lines() {
         let line = line?;
@@ -219,82 +338,16 @@ pub fn extract_verb_conjugations(
         let infinitive = entry.word.to_lowercase();
         forms_map.entry(infinitive.clone()).or_default();
 
-        let mut has_third_person = false;
-        let mut verb = VerbParts {
-            inf: infinitive.clone(),
-            ..VerbParts::default()
-        };
-
-        if verb.inf == "be" {
+        if infinitive == "be" {
             continue;
         }
 
-        if let Some(forms) = entry.forms {
-            for form in &forms {
-                let tags = &form.tags;
-                let entry_form = form.form.to_lowercase();
-                if !word_is_proper(&entry_form) || contains_bad_tag(tags.clone()) {
-                    continue;
-                }
-
-                if tags.contains(&"third-person".into())
-                    && tags.contains(&"singular".into())
-                    && tags.contains(&"present".into())
-                    && !has_third_person
-                {
-                    has_third_person = true;
-                    verb.third = entry_form.clone();
-                }
-
-                if tags.contains(&"past".into())
-                    && !tags.contains(&"participle".into())
-                    && verb.past.is_empty()
-                {
-                    verb.past = entry_form.clone();
-                }
-
-                if tags.contains(&"participle".into())
-                    && tags.contains(&"present".into())
-                    && verb.present_part.is_empty()
-                {
-                    verb.present_part = entry_form.clone();
-                }
-
-                if tags.contains(&"participle".into())
-                    && tags.contains(&"past".into())
-                    && verb.past_part.is_empty()
-                {
-                    verb.past_part = entry_form.clone();
-                }
+        let gloss = entry_gloss(&entry);
+        let forms = entry.forms.unwrap_or_default();
+        if let Some(verb) = verb_parts(&infinitive, &forms) {
+            if let Some(gloss) = gloss {
+                notes.entry(verb.clone()).or_insert(gloss);
             }
-        }
-
-        let predicted_past = EnglishCore::verb(
-            &infinitive,
-            &Person::Third,
-            &Number::Singular,
-            &Tense::Past,
-            &Form::Finite,
-        );
-        let predicted_participle = EnglishCore::verb(
-            &infinitive,
-            &Person::Third,
-            &Number::Singular,
-            &Tense::Present,
-            &Form::Participle,
-        );
-
-        if verb.past.is_empty() {
-            verb.past = predicted_past.clone();
-        }
-        if verb.past_part.is_empty() {
-            verb.past_part = verb.past.clone();
-        }
-        if verb.present_part.is_empty() {
-            verb.present_part = predicted_participle.clone();
-        }
-
-        if has_third_person {
             forms_map
                 .get_mut(&infinitive)
                 .expect("verb entry should exist")
@@ -338,27 +391,177 @@ pub fn extract_verb_conjugations(
             continue;
         }
 
-        let mut index = if forms.remove(&predicted) { 2 } else { 1 };
+        let first_index = if forms.remove(&predicted) {
+            if let Some(note) = notes.get(&predicted) {
+                notes_writer.write_record([infinitive.as_str(), note])?;
+            }
+            2
+        } else {
+            1
+        };
         let mut sorted_forms: Vec<VerbParts> = forms.clone().into_iter().collect();
         sorted_forms.sort();
 
-        for form in &sorted_forms {
+        for (index, form) in (first_index..).zip(&sorted_forms) {
             let word_key = if index == 1 {
                 infinitive.clone()
             } else {
                 format!("{infinitive}{index}")
             };
-            writer.write_record(&[
+            if let Some(note) = notes.get(form) {
+                notes_writer.write_record([word_key.as_str(), note])?;
+            }
+            writer.write_record([
                 word_key,
                 form.third.clone(),
                 form.past.clone(),
                 form.present_part.clone(),
                 form.past_part.clone(),
             ])?;
-            index += 1;
         }
     }
 
+    writer.flush()?;
+    notes_writer.flush()?;
+    println!("Done! Output written to {}", output_path.display());
+    Ok(())
+}
+
+/// Records the principal parts a dialect prefers wherever they differ from
+/// those [`extract_verb_conjugations`] picks (`"travelled"` in the UK).
+pub fn extract_dialect_verbs(
+    input_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+) -> Result<(), Box<dyn Error>> {
+    let input_path = input_path.as_ref();
+    let output_path = output_path.as_ref();
+    let mut regional: HashMap<(&'static str, String), VerbParts> = HashMap::new();
+    let (reader, mut writer) = base_setup(input_path, output_path);
+    writer.write_record([
+        "dialect",
+        "infinitive",
+        "third_person_singular",
+        "past",
+        "present_participle",
+        "past_participle",
+    ])?;
+
+    for line in reader.lines() {
+        let line = line?;
+        let entry: Entry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(error) => {
+                println!("{:#?}", error);
+                continue;
+            }
+        };
+        if !entry_is_proper(&entry, "verb") {
+            continue;
+        }
+
+        let infinitive = entry.word.to_lowercase();
+        let forms = entry.forms.unwrap_or_default();
+        if infinitive == "be"
+            || forms
+                .iter()
+                .all(|form| dialects_from_tags(&form.tags).is_empty())
+        {
+            continue;
+        }
+        let Some(neutral) = verb_parts(&infinitive, &forms) else {
+            continue;
+        };
+        for dialect in DIALECTS {
+            let key = (dialect.code(), infinitive.clone());
+            if regional.contains_key(&key) {
+                continue;
+            }
+            match verb_parts(&infinitive, regional_forms(&forms, dialect)) {
+                Some(verb) if verb != neutral => {
+                    regional.insert(key, verb);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    drop_fallback_rows(&mut regional);
+    let mut rows: Vec<_> = regional.into_iter().collect();
+    rows.sort();
+    for ((dialect, infinitive), verb) in rows {
+        writer.write_record([
+            dialect,
+            &infinitive,
+            &verb.third,
+            &verb.past,
+            &verb.present_part,
+            &verb.past_part,
+        ])?;
+    }
+
+    writer.flush()?;
+    println!("Done! Output written to {}", output_path.display());
+    Ok(())
+}
+
+/// Records the plural a dialect prefers wherever Wiktionary tags one plural
+/// as regional and lists another (`"pence"` in the UK).
+pub fn extract_dialect_nouns(
+    input_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+) -> Result<(), Box<dyn Error>> {
+    let input_path = input_path.as_ref();
+    let output_path = output_path.as_ref();
+    let mut regional: HashMap<(&'static str, String), String> = HashMap::new();
+    let (reader, mut writer) = base_setup(input_path, output_path);
+    writer.write_record(["dialect", "word", "plural"])?;
+
+    for line in reader.lines() {
+        let line = line?;
+        let entry: Entry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(error) => {
+                println!("{:#?}", error);
+                continue;
+            }
+        };
+        if !compound_entry_is_proper(&entry, "noun") {
+            continue;
+        }
+
+        let word = entry.word.to_lowercase();
+        let plurals: Vec<&Forms> = entry
+            .forms
+            .iter()
+            .flatten()
+            .filter(|form| {
+                form.tags.contains(&"plural".into())
+                    && compound_is_proper(&form.form)
+                    && !contains_bad_tag(form.tags.clone())
+            })
+            .collect();
+        for dialect in DIALECTS {
+            let tagged = plurals
+                .iter()
+                .find(|form| dialects_from_tags(&form.tags).contains(&dialect));
+            let has_alternative = plurals
+                .iter()
+                .any(|form| !dialects_from_tags(&form.tags).contains(&dialect));
+            if let (Some(tagged), true) = (tagged, has_alternative) {
+                regional
+                    .entry((dialect.code(), word.clone()))
+                    .or_insert_with(|| tagged.form.to_lowercase());
+            }
+        }
+    }
+
+    drop_fallback_rows(&mut regional);
+    let mut rows: Vec<_> = regional.into_iter().collect();
+    rows.sort();
+    for ((dialect, word), plural) in rows {
+        writer.write_record([dialect, &word, &plural])?;
+    }
+
     writer.flush()?;
     println!("Done! Output written to {}", output_path.display());
     Ok(())
@@ -424,10 +627,10 @@ pub fn analyze_and_write_suffix_rules(
     });
 
     let mut writer = WriterBuilder::new().from_path(output_path)?;
-    writer.write_record(&["singular_suffix", "plural_suffix", "count"])?;
+    writer.write_record(["singular_suffix", "plural_suffix", "count"])?;
 
     for ((singular_suffix, plural_suffix), count) in frequency_rows {
-        writer.write_record(&[singular_suffix, plural_suffix, count.to_string()])?;
+        writer.write_record([singular_suffix, plural_suffix, count.to_string()])?;
     }
 
     writer.flush()?;
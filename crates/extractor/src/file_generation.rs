@@ -1,69 +1,337 @@
+use crate::extract::strip_trailing_number;
+use crate::helpers::{gloss_keyword, suffix_edit};
+use csv::Reader;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 
-pub fn generate_nouns_phf(
-    inputik: impl AsRef<Path>,
-    outputik: impl AsRef<Path>,
-) -> std::io::Result<()> {
-    let input = File::open(inputik)?;
-    let reader = BufReader::new(input);
+/// How the noun, verb and adjective tables are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Storage {
+    /// A `phf::Map` from each key to its forms, all stored whole.
+    Phf,
+    /// A radix trie over the keys, each form stored as a suffix edit of its
+    /// key. Read by `english` with the `compact-tables` feature.
+    Trie,
+}
 
-    let mut pairs: Vec<(String, String)> = reader
+impl Storage {
+    /// File holding the `table` table (`"noun"`, `"verb"`).
+    pub fn file_name(self, table: &str) -> String {
+        match self {
+            Storage::Phf => format!("{table}_phf.rs"),
+            Storage::Trie => format!("{table}_trie.rs"),
+        }
+    }
+}
+
+/// Rows of a table CSV: a key followed by its `N` forms, sorted by key for
+/// determinism (not required by phf, but helps reproducibility).
+fn read_rows<const N: usize>(
+    input: impl AsRef<Path>,
+) -> std::io::Result<Vec<(String, [String; N])>> {
+    let reader = BufReader::new(File::open(input)?);
+    let mut rows: Vec<(String, [String; N])> = reader
         .lines()
         .skip(1) // Skip header
         .filter_map(|line| {
             let line = line.ok()?;
-            let mut parts = line.split(',');
-            Some((
-                parts.next()?.trim().to_string(),
-                parts.next()?.trim().to_string(),
-            ))
+            let mut parts = line.split(',').map(|part| part.trim().to_string());
+            let key = parts.next()?;
+            let forms: Vec<String> = parts.take(N).collect();
+            Some((key, forms.try_into().ok()?))
         })
         .collect();
+    rows.sort();
+    Ok(rows)
+}
 
-    // Sort by word for determinism (not required by phf, but helps reproducibility)
-    pairs.sort_by_key(|(word, _)| word.clone());
+/// Reads the table CSV `input` and writes it to `output`.
+fn generate_table<const N: usize>(
+    input: impl AsRef<Path>,
+    name: &str,
+    doc: Option<&str>,
+    storage: Storage,
+    output: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    let rows = read_rows::<N>(input)?;
+    let mut output = File::create(output)?;
+    match storage {
+        Storage::Phf => write_phf(&mut output, name, doc, &rows),
+        Storage::Trie => write_trie(&mut output, name, doc, &rows),
+    }
+}
 
-    let mut output = File::create(outputik)?;
+/// Writes the noun table.
+pub fn generate_nouns(
+    inputik: impl AsRef<Path>,
+    storage: Storage,
+    outputik: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    generate_table::<1>(inputik, "PLURAL_MAP", None, storage, outputik)
+}
 
-    // Start file with imports
-    writeln!(output, "use phf::phf_map;\n")?;
+/// Writes the verb table.
+pub fn generate_verbs(
+    inputik: impl AsRef<Path>,
+    storage: Storage,
+    outputik: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    generate_table::<4>(
+        inputik,
+        "VERB_MAP",
+        Some("(3rd person singular, past, present participle, past participle)"),
+        storage,
+        outputik,
+    )
+}
 
+/// Writes the adjective table.
+pub fn generate_adjectives(
+    inputik: impl AsRef<Path>,
+    storage: Storage,
+    outputik: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    generate_table::<2>(
+        inputik,
+        "ADJECTIVE_MAP",
+        Some("(comparative, superlative)"),
+        storage,
+        outputik,
+    )
+}
+
+fn write_phf<const N: usize>(
+    output: &mut impl Write,
+    name: &str,
+    doc: Option<&str>,
+    rows: &[(String, [String; N])],
+) -> std::io::Result<()> {
+    // Start file with imports
+    writeln!(output, "use phf::phf_map;")?;
+    writeln!(output)?;
+    if let Some(doc) = doc {
+        writeln!(output, "/// {doc}")?;
+    }
     writeln!(
         output,
-        "pub static PLURAL_MAP: phf::Map<&'static str, &'static str> = phf_map! {{"
+        "pub static {name}: phf::Map<&'static str, [&'static str; {N}]> = phf_map! {{"
     )?;
 
-    for (word, plural) in &pairs {
-        writeln!(output, "    \"{}\" => \"{}\",", word, plural)?;
+    for (key, forms) in rows {
+        let forms: Vec<String> = forms.iter().map(|form| format!("\"{form}\"")).collect();
+        writeln!(output, "    \"{}\" => [{}],", key, forms.join(", "))?;
     }
 
-    writeln!(output, "}};\n")?;
+    writeln!(output, "}};")?;
+    Ok(())
+}
 
-    writeln!(
-        output,
-        "pub fn get_plural(word: &str) -> Option<&'static str> {{ PLURAL_MAP.get(word).copied() }}"
-    )?;
+fn invalid_data(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// A table laid out as the radix trie that `english` reads with the
+/// `compact-tables` feature; see its `Trie` for the meaning of each field.
+#[derive(Debug, Default)]
+struct TrieLayout<const N: usize> {
+    firsts: Vec<u8>,
+    labels: Vec<u8>,
+    label_starts: Vec<u32>,
+    children: Vec<u32>,
+    values: Vec<u16>,
+    /// Rows of `(strip, suffix start, suffix length)` edits.
+    rows: Vec<[(u8, u16, u8); N]>,
+    suffixes: String,
+}
+
+impl<const N: usize> TrieLayout<N> {
+    /// Lays out `rows`, which must be sorted by key.
+    fn build(rows: &[(String, [String; N])]) -> std::io::Result<Self> {
+        let mut layout = TrieLayout::default();
+        let mut row_ids: HashMap<[(u8, u16, u8); N], u16> = HashMap::new();
+
+        // Each node covers the run of keys that share the path to it, `depth`
+        // bytes long, and is laid out when it leaves the queue, so that nodes
+        // are numbered breadth first and siblings are contiguous.
+        let mut queue = std::collections::VecDeque::from([(0, rows, &b""[..])]);
+        let mut node_count = 1;
+        while let Some((depth, run, label)) = queue.pop_front() {
+            let (first, rest) = label.split_first().unwrap_or((&0, label));
+            layout.firsts.push(*first);
+            layout.label_starts.push(layout.labels.len() as u32);
+            layout.labels.extend_from_slice(rest);
+            layout.children.push(node_count);
+
+            let (value, mut run) = match run.split_first() {
+                Some(((key, forms), tail)) if key.len() == depth => {
+                    (layout.row_id(key, forms, &mut row_ids)?, tail)
+                }
+                _ => (0, run),
+            };
+            layout.values.push(value);
+
+            while let Some((key, _)) = run.first() {
+                let byte = key.as_bytes()[depth];
+                let len = run
+                    .iter()
+                    .take_while(|(other, _)| other.as_bytes()[depth] == byte)
+                    .count();
+                let (group, tail) = run.split_at(len);
+                let last = &group[len - 1].0;
+                let common = key
+                    .bytes()
+                    .zip(last.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                queue.push_back((common, group, &key.as_bytes()[depth..common]));
+                node_count += 1;
+                run = tail;
+            }
+        }
+        layout.label_starts.push(layout.labels.len() as u32);
+        layout.children.push(node_count);
+        Ok(layout)
+    }
 
+    /// One more than the index of the row of edits turning `key` into
+    /// `forms`, adding the row if it is new.
+    fn row_id(
+        &mut self,
+        key: &str,
+        forms: &[String; N],
+        row_ids: &mut HashMap<[(u8, u16, u8); N], u16>,
+    ) -> std::io::Result<u16> {
+        let mut row = [(0, 0, 0); N];
+        for (edit, form) in row.iter_mut().zip(forms) {
+            let (strip, suffix) = suffix_edit(key, form);
+            let start = match self.suffixes.find(suffix) {
+                Some(start) => start,
+                None => {
+                    self.suffixes.push_str(suffix);
+                    self.suffixes.len() - suffix.len()
+                }
+            };
+            *edit = (
+                u8::try_from(strip).map_err(|_| invalid_data(format!("{key}: strip too long")))?,
+                u16::try_from(start).map_err(|_| invalid_data("too many suffixes".into()))?,
+                u8::try_from(suffix.len())
+                    .map_err(|_| invalid_data(format!("{key}: suffix too long")))?,
+            );
+        }
+        let next = row_ids.len() + 1;
+        let id = *row_ids.entry(row).or_insert_with(|| {
+            self.rows.push(row);
+            next as u16
+        });
+        if self.rows.len() >= u16::MAX as usize {
+            return Err(invalid_data("too many distinct rows".into()));
+        }
+        Ok(id)
+    }
+}
+
+/// Bytes as a byte string literal, escaping all but letters and digits so
+/// that the literal can be wrapped anywhere.
+fn byte_string(bytes: &[u8]) -> String {
+    let mut literal = String::from("b\"");
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 && i % 96 == 0 {
+            literal.push_str("\\\n        ");
+        }
+        if byte.is_ascii_alphanumeric() {
+            literal.push(*byte as char);
+        } else {
+            literal.push_str(&format!("\\x{byte:02x}"));
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Numbers as a slice literal, wrapped to a readable width.
+fn number_slice(numbers: impl IntoIterator<Item = impl ToString>) -> String {
+    let numbers: Vec<String> = numbers.into_iter().map(|n| n.to_string()).collect();
+    let lines: Vec<String> = numbers
+        .chunks(20)
+        .map(|chunk| format!("        {},", chunk.join(", ")))
+        .collect();
+    format!("&[\n{}\n    ]", lines.join("\n"))
+}
+
+fn write_trie<const N: usize>(
+    output: &mut impl Write,
+    name: &str,
+    doc: Option<&str>,
+    rows: &[(String, [String; N])],
+) -> std::io::Result<()> {
+    let layout = TrieLayout::build(rows)?;
+
+    if layout.rows.is_empty() {
+        writeln!(output, "use crate::trie::Trie;")?;
+    } else {
+        writeln!(output, "use crate::trie::{{Edit, Trie}};")?;
+    }
+    writeln!(output)?;
+    if let Some(doc) = doc {
+        writeln!(output, "/// {doc}")?;
+    }
+    writeln!(output, "pub static {name}: Trie<{N}> = Trie::new(")?;
+    writeln!(output, "    {},", byte_string(&layout.firsts))?;
+    writeln!(output, "    {},", byte_string(&layout.labels))?;
+    writeln!(output, "    {},", number_slice(&layout.label_starts))?;
+    writeln!(output, "    {},", number_slice(&layout.children))?;
+    writeln!(output, "    {},", number_slice(&layout.values))?;
+    if layout.rows.is_empty() {
+        writeln!(output, "    &[],")?;
+    } else {
+        writeln!(output, "    &[")?;
+    }
+    for row in &layout.rows {
+        let edits: Vec<String> = row
+            .iter()
+            .map(|(strip, start, len)| format!("Edit::new({strip}, {start}, {len})"))
+            .collect();
+        writeln!(output, "        [{}],", edits.join(", "))?;
+    }
+    if !layout.rows.is_empty() {
+        writeln!(output, "    ],")?;
+    }
+    writeln!(output, "    {:?},", layout.suffixes)?;
+    writeln!(output, ");")?;
     Ok(())
 }
 
-pub fn generate_verbs_phf(
-    inputik: impl AsRef<Path>,
+pub fn generate_dialects_phf(
+    nouns_input: impl AsRef<Path>,
+    verbs_input: impl AsRef<Path>,
     outputik: impl AsRef<Path>,
 ) -> std::io::Result<()> {
-    let input = File::open(inputik)?;
-    let reader = BufReader::new(input);
+    let nouns = BufReader::new(File::open(nouns_input)?);
+    let mut plurals: Vec<(String, String)> = nouns
+        .lines()
+        .skip(1) // Skip header
+        .filter_map(|line| {
+            let line = line.ok()?;
+            let mut parts = line.split(',');
+            Some((
+                format!("{}:{}", parts.next()?.trim(), parts.next()?.trim()), // dialect:word
+                parts.next()?.trim().to_string(),                             // plural
+            ))
+        })
+        .collect();
+    plurals.sort();
 
-    let mut entries: Vec<(String, (String, String, String, String))> = reader
+    let verbs = BufReader::new(File::open(verbs_input)?);
+    let mut conjugations: Vec<(String, (String, String, String, String))> = verbs
         .lines()
         .skip(1) // Skip header
         .filter_map(|line| {
             let line = line.ok()?;
             let mut parts = line.split(',');
             Some((
-                parts.next()?.trim().to_string(), // infinitive
+                format!("{}:{}", parts.next()?.trim(), parts.next()?.trim()), // dialect:infinitive
                 (
                     parts.next()?.trim().to_string(), // 3rd person singular
                     parts.next()?.trim().to_string(), // past
@@ -73,95 +341,123 @@ pub fn generate_verbs_phf(
             ))
         })
         .collect();
-
-    // Sort for determinism
-    entries.sort_by_key(|(inf, _)| inf.clone());
+    conjugations.sort();
 
     let mut output = File::create(outputik)?;
 
     writeln!(output, "use phf::phf_map;")?;
     writeln!(output)?;
+    writeln!(output, "/// \"dialect:word\" => regional plural")?;
     writeln!(
         output,
-        "/// (3rd person singular, past, present participle, past participle)"
+        "pub static DIALECT_PLURAL_MAP: phf::Map<&'static str, [&'static str; 1]> = phf_map! {{"
     )?;
+    for (key, plural) in &plurals {
+        writeln!(output, "    \"{}\" => [\"{}\"],", key, plural)?;
+    }
+    writeln!(output, "}};")?;
+    writeln!(output)?;
     writeln!(
         output,
-        "pub static VERB_MAP: phf::Map<&'static str, (&'static str, &'static str, &'static str, &'static str)> = phf_map! {{"
+        "/// \"dialect:infinitive\" => (3rd person singular, past, present participle, past participle)"
     )?;
-
-    for (inf, (third, past, pres_part, past_part)) in &entries {
+    writeln!(
+        output,
+        "pub static DIALECT_VERB_MAP: phf::Map<&'static str, [&'static str; 4]> = phf_map! {{"
+    )?;
+    for (key, (third, past, pres_part, past_part)) in &conjugations {
         writeln!(
             output,
-            "    \"{}\" => (\"{}\", \"{}\", \"{}\", \"{}\"),",
-            inf, third, past, pres_part, past_part
+            "    \"{}\" => [\"{}\", \"{}\", \"{}\", \"{}\"],",
+            key, third, past, pres_part, past_part
         )?;
     }
-
     writeln!(output, "}};")?;
-    writeln!(output)?;
-    writeln!(
-        output,
-        "pub fn get_verb_forms(infinitive: &str) -> Option<(&'static str, &'static str, &'static str, &'static str)> {{"
-    )?;
-    writeln!(output, "    VERB_MAP.get(infinitive).copied()")?;
-    writeln!(output, "}}")?;
 
     Ok(())
 }
 
-pub fn generate_adjectives_phf(
-    inputik: impl AsRef<Path>,
+/// Reads a `key,note` CSV. Notes are glosses, so they are read as proper
+/// CSV rather than split on commas.
+fn read_notes(input: impl AsRef<Path>) -> std::io::Result<Vec<(String, String)>> {
+    let mut reader = Reader::from_path(input)?;
+    let mut notes: Vec<(String, String)> = reader
+        .records()
+        .filter_map(|record| {
+            let record = record.ok()?;
+            Some((record.get(0)?.to_string(), record.get(1)?.to_string()))
+        })
+        .collect();
+    notes.sort();
+    Ok(notes)
+}
+
+pub fn generate_variants_phf(
+    nouns_input: impl AsRef<Path>,
+    verbs_input: impl AsRef<Path>,
     outputik: impl AsRef<Path>,
 ) -> std::io::Result<()> {
-    let input = File::open(inputik)?;
-    let reader = BufReader::new(input);
+    let mut output = File::create(outputik)?;
 
-    let mut entries: Vec<(String, (String, String))> = reader
-        .lines()
-        .skip(1) // Skip header
-        .filter_map(|line| {
-            let line = line.ok()?;
-            let mut parts = line.split(',');
-            Some((
-                parts.next()?.trim().to_string(), // positive
-                (
-                    parts.next()?.trim().to_string(), // comparative
-                    parts.next()?.trim().to_string(), // superlative
-                ),
-            ))
-        })
-        .collect();
+    writeln!(output, "use phf::phf_map;")?;
+    for (name, input) in [
+        ("NOUN_NOTE_MAP", nouns_input.as_ref()),
+        ("VERB_NOTE_MAP", verbs_input.as_ref()),
+    ] {
+        writeln!(output)?;
+        writeln!(output, "/// sense key => usage tags or gloss")?;
+        writeln!(
+            output,
+            "pub static {name}: phf::Map<&'static str, &'static str> = phf_map! {{"
+        )?;
+        for (key, note) in read_notes(input)? {
+            writeln!(output, "    {:?} => {:?},", key, note)?;
+        }
+        writeln!(output, "}};")?;
+    }
 
-    // Sort for determinism
-    entries.sort_by_key(|(pos, _)| pos.clone());
+    Ok(())
+}
 
+/// Writes the labels that name senses after a `#` (`"die#polyhedron"`): the
+/// keyword of each variant note, for every sense whose keyword no other
+/// sense of the same lemma shares.
+pub fn generate_senses_phf(
+    nouns_input: impl AsRef<Path>,
+    verbs_input: impl AsRef<Path>,
+    outputik: impl AsRef<Path>,
+) -> std::io::Result<()> {
     let mut output = File::create(outputik)?;
 
     writeln!(output, "use phf::phf_map;")?;
-    writeln!(output)?;
-    writeln!(output, "/// (comparative, superlative)")?;
-    writeln!(
-        output,
-        "pub static ADJECTIVE_MAP: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {{"
-    )?;
-
-    for (positive, (comparative, superlative)) in &entries {
+    for (name, input) in [
+        ("NOUN_SENSE_LABEL_MAP", nouns_input.as_ref()),
+        ("VERB_SENSE_LABEL_MAP", verbs_input.as_ref()),
+    ] {
+        let labels: Vec<(String, String)> = read_notes(input)?
+            .into_iter()
+            .filter_map(|(key, note)| Some((key, gloss_keyword(&note)?)))
+            .collect();
+        let shared = |key: &str, label: &str| {
+            labels.iter().any(|(other, other_label)| {
+                other != key
+                    && other_label == label
+                    && strip_trailing_number(other) == strip_trailing_number(key)
+            })
+        };
+        writeln!(output)?;
+        writeln!(output, "/// sense key => label naming it after a `#`")?;
         writeln!(
             output,
-            "    \"{}\" => (\"{}\", \"{}\"),",
-            positive, comparative, superlative
+            "pub static {name}: phf::Map<&'static str, &'static str> = phf_map! {{"
         )?;
+        for (key, label) in &labels {
+            if !shared(key, label) {
+                writeln!(output, "    {:?} => {:?},", key, label)?;
+            }
+        }
+        writeln!(output, "}};")?;
     }
 
-    writeln!(output, "}};")?;
-    writeln!(output)?;
-    writeln!(
-        output,
-        "pub fn get_adjective_forms(positive: &str) -> Option<(&'static str, &'static str)> {{"
-    )?;
-    writeln!(output, "    ADJECTIVE_MAP.get(positive).copied()")?;
-    writeln!(output, "}}")?;
-
     Ok(())
 }
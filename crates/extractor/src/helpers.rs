@@ -1,5 +1,7 @@
 use csv::Writer;
+use english_core::Dialect;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
@@ -53,14 +55,104 @@ pub struct Forms {
     pub tags: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Sense {
+    pub glosses: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Entry {
     pub word: String,
     pub pos: String,
     pub forms: Option<Vec<Forms>>,
+    pub senses: Option<Vec<Sense>>,
     pub lang_code: String,
 }
 
+/// Tags that only say which slot a form fills, not how it differs from the
+/// other forms for that slot.
+pub static SLOT_TAGS: &[&str] = &[
+    "plural",
+    "singular",
+    "past",
+    "present",
+    "participle",
+    "third-person",
+    "canonical",
+];
+
+/// First gloss of an entry. Wiktionary splits words by etymology, so this
+/// tells homographs apart (the "die" with plural "dice" from the one with
+/// "dies").
+pub fn entry_gloss(entry: &Entry) -> Option<String> {
+    entry
+        .senses
+        .iter()
+        .flatten()
+        .find_map(|sense| sense.glosses.as_ref()?.first().cloned())
+}
+
+/// Note describing a variant form: its own tags beyond the slot it fills
+/// ("proscribed", "US"), or else the gloss of the entry it was listed in.
+pub fn variant_note(tags: &[String], gloss: Option<&str>) -> Option<String> {
+    let tags: Vec<&str> = tags
+        .iter()
+        .map(String::as_str)
+        .filter(|tag| !SLOT_TAGS.contains(tag))
+        .collect();
+    if tags.is_empty() {
+        gloss.map(str::to_owned)
+    } else {
+        Some(tags.join(" "))
+    }
+}
+
+/// Words skipped when picking the keyword of a gloss: articles, particles
+/// and verbs too general to tell senses apart.
+pub static GLOSS_STOPWORDS: &[&str] = &[
+    "a",
+    "an",
+    "the",
+    "to",
+    "of",
+    "or",
+    "and",
+    "any",
+    "some",
+    "one",
+    "several",
+    "be",
+    "being",
+    "with",
+    "in",
+    "on",
+    "at",
+    "for",
+    "by",
+    "from",
+    "as",
+    "used",
+    "something",
+    "someone",
+    "make",
+    "give",
+    "take",
+    "have",
+    "get",
+    "do",
+    "cause",
+    "become",
+];
+
+/// First telling word of a variant note (`"To emit light."` -> `"emit"`),
+/// used as the label of its sense.
+pub fn gloss_keyword(gloss: &str) -> Option<String> {
+    gloss
+        .split(|c: char| !c.is_alphabetic())
+        .map(str::to_lowercase)
+        .find(|word| !word.is_empty() && !GLOSS_STOPWORDS.contains(&word.as_str()))
+}
+
 #[derive(Debug, Default, Eq, Hash, PartialEq, Clone, Ord, PartialOrd)]
 pub struct AdjParts {
     pub positive: String,
@@ -88,8 +180,18 @@ pub fn entry_is_proper(entry: &Entry, pos: &str) -> bool {
     true
 }
 
+/// Like [`entry_is_proper`], but also accepts compounds written with single
+/// spaces or hyphens between proper words (`"mother-in-law"`).
+pub fn compound_entry_is_proper(entry: &Entry, pos: &str) -> bool {
+    entry.lang_code == "en" && entry.pos == pos && compound_is_proper(&entry.word)
+}
+
+pub fn compound_is_proper(word: &str) -> bool {
+    word.split([' ', '-']).all(word_is_proper) && word.split([' ', '-']).all(|w| !w.is_empty())
+}
+
 pub fn word_is_proper(word: &str) -> bool {
-    if contains_bad_chars(&word) || !word.is_ascii() || contains_number(&word) {
+    if contains_bad_chars(word) || !word.is_ascii() || contains_number(word) {
         return false;
     }
     true
@@ -124,3 +226,76 @@ pub fn suffix_rule(singular: &str, plural: &str) -> (String, String) {
 
     (singular_suffix.to_string(), plural_suffix.to_string())
 }
+
+/// The shortest suffix edit turning `word` into `form`: how many bytes to
+/// strip from the end of `word` and what to append. Unlike [`suffix_rule`],
+/// no overlap is kept, and lengths are in bytes so the edit can be applied
+/// by slicing.
+pub fn suffix_edit<'a>(word: &str, form: &'a str) -> (usize, &'a str) {
+    let prefix: usize = word
+        .chars()
+        .zip(form.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    (word.len() - prefix, &form[prefix..])
+}
+
+/// Every dialect the generated tables can hold forms for.
+pub static DIALECTS: [Dialect; 4] = [Dialect::US, Dialect::UK, Dialect::AU, Dialect::CA];
+
+/// Dialects named by the regional tags of a Wiktionary form. "Commonwealth"
+/// covers every dialect but [`Dialect::US`].
+pub fn dialects_from_tags(tags: &[String]) -> Vec<Dialect> {
+    let mut dialects = Vec::new();
+    for tag in tags {
+        let named: &[Dialect] = match tag.as_str() {
+            "US" | "American" => &[Dialect::US],
+            "UK" | "British" | "England" => &[Dialect::UK],
+            "Australia" | "Australian" => &[Dialect::AU],
+            "Canada" | "Canadian" => &[Dialect::CA],
+            "Commonwealth" => &[Dialect::UK, Dialect::AU, Dialect::CA],
+            _ => &[],
+        };
+        for dialect in named {
+            if !dialects.contains(dialect) {
+                dialects.push(*dialect);
+            }
+        }
+    }
+    dialects
+}
+
+/// Drops the rows of `regional` that repeat the row of the dialect they
+/// fall back to, since lookups reach that row anyway ("au:travel" when
+/// "uk:travel" matches).
+pub fn drop_fallback_rows<T: PartialEq>(regional: &mut HashMap<(&'static str, String), T>) {
+    let redundant: Vec<_> = regional
+        .iter()
+        .filter(|((code, word), value)| {
+            DIALECTS
+                .iter()
+                .find(|dialect| dialect.code() == *code)
+                .and_then(Dialect::fallback)
+                .and_then(|fallback| regional.get(&(fallback.code(), word.clone())))
+                .is_some_and(|fallback_value| fallback_value == *value)
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    for key in redundant {
+        regional.remove(&key);
+    }
+}
+
+/// The forms a speaker of `dialect` would choose from: those tagged for it
+/// first, then those with no regional tag at all.
+pub fn regional_forms(forms: &[Forms], dialect: Dialect) -> Vec<&Forms> {
+    let (tagged, untagged): (Vec<&Forms>, Vec<&Forms>) = forms
+        .iter()
+        .filter(|form| {
+            let dialects = dialects_from_tags(&form.tags);
+            dialects.is_empty() || dialects.contains(&dialect)
+        })
+        .partition(|form| !dialects_from_tags(&form.tags).is_empty());
+    tagged.into_iter().chain(untagged).collect()
+}
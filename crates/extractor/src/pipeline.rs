@@ -1,10 +1,13 @@
 use crate::args::Config;
 use crate::checks::run_checks;
 use crate::extract::{
-    extract_irregular_adjectives, extract_irregular_nouns, extract_verb_conjugations,
-    filter_english_entries,
+    extract_dialect_nouns, extract_dialect_verbs, extract_irregular_adjectives,
+    extract_irregular_nouns, extract_verb_conjugations, filter_english_entries,
+};
+use crate::file_generation::{
+    Storage, generate_adjectives, generate_dialects_phf, generate_nouns, generate_senses_phf,
+    generate_variants_phf, generate_verbs,
 };
-use crate::file_generation::{generate_adjectives_phf, generate_nouns_phf, generate_verbs_phf};
 use std::error::Error;
 use std::fs;
 
@@ -29,14 +32,38 @@ pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
     let verbs_csv = config.artifacts_dir.join("verb_conjugations.csv");
     let nouns_csv = config.artifacts_dir.join("nouns_with_plurals.csv");
     let adjectives_csv = config.artifacts_dir.join("adjectives.csv");
+    let noun_notes_csv = config.artifacts_dir.join("noun_variant_notes.csv");
+    let verb_notes_csv = config.artifacts_dir.join("verb_variant_notes.csv");
+    let dialect_nouns_csv = config.artifacts_dir.join("dialect_nouns.csv");
+    let dialect_verbs_csv = config.artifacts_dir.join("dialect_verbs.csv");
 
-    extract_verb_conjugations(&filtered_json_path, &verbs_csv)?;
-    extract_irregular_nouns(&filtered_json_path, &nouns_csv)?;
+    extract_verb_conjugations(&filtered_json_path, &verbs_csv, &verb_notes_csv)?;
+    extract_irregular_nouns(&filtered_json_path, &nouns_csv, &noun_notes_csv)?;
     extract_irregular_adjectives(&filtered_json_path, &adjectives_csv)?;
+    extract_dialect_nouns(&filtered_json_path, &dialect_nouns_csv)?;
+    extract_dialect_verbs(&filtered_json_path, &dialect_verbs_csv)?;
 
-    generate_nouns_phf(&nouns_csv, config.generated_dir.join("noun_phf.rs"))?;
-    generate_adjectives_phf(&adjectives_csv, config.generated_dir.join("adj_phf.rs"))?;
-    generate_verbs_phf(&verbs_csv, config.generated_dir.join("verb_phf.rs"))?;
+    for storage in [Storage::Phf, Storage::Trie] {
+        let output = |table: &str| config.generated_dir.join(storage.file_name(table));
+        generate_nouns(&nouns_csv, storage, output("noun"))?;
+        generate_adjectives(&adjectives_csv, storage, output("adj"))?;
+        generate_verbs(&verbs_csv, storage, output("verb"))?;
+    }
+    generate_dialects_phf(
+        &dialect_nouns_csv,
+        &dialect_verbs_csv,
+        config.generated_dir.join("dialect_phf.rs"),
+    )?;
+    generate_variants_phf(
+        &noun_notes_csv,
+        &verb_notes_csv,
+        config.generated_dir.join("variant_phf.rs"),
+    )?;
+    generate_senses_phf(
+        &noun_notes_csv,
+        &verb_notes_csv,
+        config.generated_dir.join("sense_phf.rs"),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,96 @@
+use extractor::Config;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Runs the whole pipeline over `tests/fixtures/dump.jsonl`, writing into a
+/// scratch directory named after `test`, and returns that directory.
+fn run_fixture(test: &str) -> PathBuf {
+    let scratch = std::env::temp_dir().join(format!("extractor-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&scratch);
+    let config = Config {
+        dump_path: Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/dump.jsonl"),
+        generated_dir: scratch.join("generated"),
+        artifacts_dir: scratch.join("intermediate"),
+        run_checks: false,
+    };
+    extractor::pipeline::run(&config).expect("pipeline should run over the fixture");
+    scratch
+}
+
+fn generated(scratch: &Path, file: &str) -> String {
+    fs::read_to_string(scratch.join("generated").join(file)).expect("table should be written")
+}
+
+#[test]
+fn compound_headwords_keep_plurals_the_rules_miss() {
+    let scratch = run_fixture("compounds");
+    let nouns = generated(&scratch, "noun_phf.rs");
+
+    assert!(nouns.contains(r#""knight-errant" => ["knights-errant"],"#));
+    assert!(nouns.contains(r#""man-about-town" => ["men-about-town"],"#));
+    assert!(nouns.contains(r#""child" => ["children"],"#));
+    // Plurals the rules predict, compounds included, need no row.
+    assert!(!nouns.contains(r#""mother-in-law""#));
+    assert!(!nouns.contains(r#""cat""#));
+    assert!(!nouns.contains(r#""chat""#));
+    fs::remove_dir_all(scratch).unwrap();
+}
+
+#[test]
+fn regionally_tagged_forms_fill_the_dialect_table() {
+    let scratch = run_fixture("dialects");
+    let dialects = generated(&scratch, "dialect_phf.rs");
+
+    assert!(dialects.contains(r#""uk:penny" => ["pence"],"#));
+    assert!(
+        dialects.contains(r#""uk:travel" => ["travels", "travelled", "travelling", "travelled"],"#)
+    );
+    // American forms come first in the entry, so they are the neutral ones.
+    assert!(!dialects.contains(r#""us:travel""#));
+    // "Commonwealth" covers Canada, which otherwise follows the US, and
+    // Australia, whose row would repeat the British one it falls back to.
+    assert!(
+        dialects.contains(r#""uk:cancel" => ["cancels", "cancelled", "cancelling", "cancelled"],"#)
+    );
+    assert!(
+        dialects.contains(r#""ca:cancel" => ["cancels", "cancelled", "cancelling", "cancelled"],"#)
+    );
+    assert!(!dialects.contains(r#""au:cancel""#));
+    fs::remove_dir_all(scratch).unwrap();
+}
+
+#[test]
+fn tagged_plurals_are_kept_after_the_untagged_ones() {
+    let scratch = run_fixture("variants");
+    let nouns = generated(&scratch, "noun_phf.rs");
+    let notes = generated(&scratch, "variant_phf.rs");
+
+    assert!(nouns.contains(r#""octopus2" => ["octopi"],"#));
+    assert!(nouns.contains(r#""octopus3" => ["octopii"],"#));
+    assert!(nouns.contains(r#""octopus4" => ["octopodes"],"#));
+    assert!(notes.contains(r#""octopus3" => "nonstandard","#));
+    assert!(notes.contains(r#""octopus4" => "rare","#));
+    assert!(nouns.contains(r#""brother2" => ["brethren"],"#));
+    assert!(notes.contains(r#""brother2" => "archaic","#));
+    // A tagged plural never displaces the one the rules predict.
+    assert!(nouns.contains(r#""cow2" => ["kine"],"#));
+    assert!(!nouns.contains(r#""cow" =>"#));
+    fs::remove_dir_all(scratch).unwrap();
+}
+
+#[test]
+fn every_glossed_sense_key_gets_a_label() {
+    let scratch = run_fixture("senses");
+    let labels = generated(&scratch, "sense_phf.rs");
+
+    assert!(labels.contains(r#""die" => "device","#));
+    assert!(labels.contains(r#""die2" => "polyhedron","#));
+    // "lied" is the predicted past, so the sense with "lay" is numbered.
+    assert!(labels.contains(r#""lie" => "false","#));
+    assert!(labels.contains(r#""lie2" => "rest","#));
+    // Labels drawn from the tags of a variant work the same way.
+    assert!(labels.contains(r#""octopus4" => "rare","#));
+    // Plurals listed under one gloss share its keyword, so it names neither.
+    assert!(!labels.contains(r#""octopus2""#));
+    fs::remove_dir_all(scratch).unwrap();
+}
@@ -60,6 +60,13 @@ pub(crate) fn realize_token(
     index: usize,
     morph: &impl MorphLexicon,
 ) -> Option<String> {
+    let (person, number) =
+        English::subject_agreement(&agreement.person, &agreement.number, &agreement.gender);
+    let agreement = AgreementInfo {
+        person,
+        number,
+        ..agreement
+    };
     let surface = match &token.kind {
         TokenKind::Plain | TokenKind::Name | TokenKind::Prep { .. } | TokenKind::Conj { .. } => {
             token.surface.clone()
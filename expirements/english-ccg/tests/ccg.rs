@@ -1,6 +1,6 @@
 use std::panic::{catch_unwind, AssertUnwindSafe};
 
-use english::{Animacy, Gender, Number, Person};
+use english::{Animacy, Gender, Number, Person, PronounSet};
 use english_ccg::cat;
 use english_ccg::prelude::*;
 
@@ -243,3 +243,36 @@ fn rejects_to_selecting_auxiliary_with_bare_vp() {
     assert!(message.contains("left span: \"Alice ought\" :: S/VP[to]"));
     assert!(message.contains("right span: \"arrive\" :: VP[bare]"));
 }
+
+#[test]
+fn custom_pronoun_sets_drive_case_and_agreement() {
+    let trust = tv("trust");
+    let repair = tv("repair");
+    let bridge = common("bridge", Animacy::Inanimate);
+
+    let xe = PronounSet::new("xe", "xem", "xemself", "xyrs", "xyr", Number::Singular).register();
+    let they = PronounSet::they_them().register();
+    let xe = referent().gender(xe).animate();
+    let they = referent().gender(they).animate();
+
+    let s = pro(&xe) + verb(&trust).past() + pro(&they);
+    assert_eq!(realize_as(&s, RealizeOpts::sentence()), "Xe trusted them.");
+
+    let s = pro(&they)
+        + aux("be").inflecting().present_participle_progressive()
+        + verb(&repair).progressive()
+        + (det("the") + noun(&bridge));
+    assert_eq!(
+        realize_as(&s, RealizeOpts::sentence()),
+        "They are repairing the bridge."
+    );
+
+    let s = pro(&xe)
+        + aux("be").inflecting().present_participle_progressive()
+        + verb(&repair).progressive()
+        + (det("the") + noun(&bridge));
+    assert_eq!(
+        realize_as(&s, RealizeOpts::sentence()),
+        "Xe is repairing the bridge."
+    );
+}
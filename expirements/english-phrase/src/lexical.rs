@@ -62,7 +62,7 @@ impl Pronoun {
     }
 
     pub fn possessive_dependent_form(self) -> &'static str {
-        self.form(Case::PersonalPossesive)
+        self.form(Case::PersonalPossessive)
     }
 
     pub fn possessive_independent_form(self) -> &'static str {
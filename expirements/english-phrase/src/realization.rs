@@ -124,14 +124,7 @@ fn apply_realization_options(mut text: String, options: RealizationOptions) -> S
 }
 
 fn indefinite_article(next: &str) -> &'static str {
-    match next
-        .chars()
-        .find(|ch| ch.is_ascii_alphabetic())
-        .map(|ch| ch.to_ascii_lowercase())
-    {
-        Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
-        _ => "a",
-    }
+    English::indefinite_article(next.trim_start_matches(|ch: char| !ch.is_alphanumeric()))
 }
 
 fn morph_tense(tense: Tense) -> MorphTense {